@@ -30,6 +30,10 @@ from decision_gate import (
     RunpackVerifyResponse,
     ScenarioDefineRequest,
     ScenarioDefineResponse,
+    ScenarioLeaseAcquireRequest,
+    ScenarioLeaseAcquireResponse,
+    ScenarioLeaseReleaseRequest,
+    ScenarioLeaseReleaseResponse,
     ScenarioNextRequest,
     ScenarioNextResponse,
     ScenarioStartRequest,
@@ -42,6 +46,8 @@ from decision_gate import (
     ScenarioTriggerResponse,
     ScenariosListRequest,
     ScenariosListResponse,
+    ScenariosStatusBulkRequest,
+    ScenariosStatusBulkResponse,
     SchemasGetRequest,
     SchemasGetResponse,
     SchemasListRequest,
@@ -57,12 +63,15 @@ from decision_gate import (
     validate_runpack_export_request,
     validate_runpack_verify_request,
     validate_scenario_define_request,
+    validate_scenario_lease_acquire_request,
+    validate_scenario_lease_release_request,
     validate_scenario_next_request,
     validate_scenario_start_request,
     validate_scenario_status_request,
     validate_scenario_submit_request,
     validate_scenario_trigger_request,
     validate_scenarios_list_request,
+    validate_scenarios_status_bulk_request,
     validate_schemas_get_request,
     validate_schemas_list_request,
     validate_schemas_register_request,
@@ -249,6 +258,33 @@ def build_decision_gate_tools(
         )
         return client.decision_gate_docs_search(typed)
 
+    def decision_gate_scenarios_status_bulk(
+        request: dict[str, JsonValue],
+    ) -> ScenariosStatusBulkResponse:
+        """Fetch read-only status snapshots for several runs in one call."""
+        typed: ScenariosStatusBulkRequest = _coerce_request(
+            request, validate_scenarios_status_bulk_request, validate
+        )
+        return client.scenarios_status_bulk(typed)
+
+    def decision_gate_scenario_lease_acquire(
+        request: dict[str, JsonValue],
+    ) -> ScenarioLeaseAcquireResponse:
+        """Acquire or renew an advisory lease that serializes run mutations."""
+        typed: ScenarioLeaseAcquireRequest = _coerce_request(
+            request, validate_scenario_lease_acquire_request, validate
+        )
+        return client.scenario_lease_acquire(typed)
+
+    def decision_gate_scenario_lease_release(
+        request: dict[str, JsonValue],
+    ) -> ScenarioLeaseReleaseResponse:
+        """Release an advisory run lease held by the caller."""
+        typed: ScenarioLeaseReleaseRequest = _coerce_request(
+            request, validate_scenario_lease_release_request, validate
+        )
+        return client.scenario_lease_release(typed)
+
     return [
         FunctionTool(
             decision_gate_precheck,
@@ -340,6 +376,21 @@ def build_decision_gate_tools(
             name="decision_gate_docs_search",
             description="Search Decision Gate documentation for runtime guidance.",
         ),
+        FunctionTool(
+            decision_gate_scenarios_status_bulk,
+            name="decision_gate_scenarios_status_bulk",
+            description="Fetch read-only status snapshots for several runs in one call.",
+        ),
+        FunctionTool(
+            decision_gate_scenario_lease_acquire,
+            name="decision_gate_scenario_lease_acquire",
+            description="Acquire or renew an advisory lease that serializes run mutations.",
+        ),
+        FunctionTool(
+            decision_gate_scenario_lease_release,
+            name="decision_gate_scenario_lease_release",
+            description="Release an advisory run lease held by the caller.",
+        ),
     ]
 
 
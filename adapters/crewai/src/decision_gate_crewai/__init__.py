@@ -17,12 +17,15 @@ from .tools import (
     DecisionGateRunpackExportTool,
     DecisionGateRunpackVerifyTool,
     DecisionGateScenarioDefineTool,
+    DecisionGateScenarioLeaseAcquireTool,
+    DecisionGateScenarioLeaseReleaseTool,
     DecisionGateScenarioNextTool,
     DecisionGateScenarioStatusTool,
     DecisionGateScenarioStartTool,
     DecisionGateScenarioTriggerTool,
     DecisionGateScenarioSubmitTool,
     DecisionGateScenariosListTool,
+    DecisionGateScenariosStatusBulkTool,
     DecisionGateSchemasGetTool,
     DecisionGateSchemasListTool,
     DecisionGateSchemasRegisterTool,
@@ -41,12 +44,15 @@ __all__ = [
     "DecisionGateRunpackExportTool",
     "DecisionGateRunpackVerifyTool",
     "DecisionGateScenarioDefineTool",
+    "DecisionGateScenarioLeaseAcquireTool",
+    "DecisionGateScenarioLeaseReleaseTool",
     "DecisionGateScenarioNextTool",
     "DecisionGateScenarioStatusTool",
     "DecisionGateScenarioStartTool",
     "DecisionGateScenarioSubmitTool",
     "DecisionGateScenarioTriggerTool",
     "DecisionGateScenariosListTool",
+    "DecisionGateScenariosStatusBulkTool",
     "DecisionGateSchemasGetTool",
     "DecisionGateSchemasListTool",
     "DecisionGateSchemasRegisterTool",
@@ -27,12 +27,15 @@ from decision_gate import (
     RunpackExportRequest,
     RunpackVerifyRequest,
     ScenarioDefineRequest,
+    ScenarioLeaseAcquireRequest,
+    ScenarioLeaseReleaseRequest,
     ScenarioNextRequest,
     ScenarioStartRequest,
     ScenarioStatusRequest,
     ScenarioSubmitRequest,
     ScenarioTriggerRequest,
     ScenariosListRequest,
+    ScenariosStatusBulkRequest,
     SchemasGetRequest,
     SchemasListRequest,
     SchemasRegisterRequest,
@@ -45,12 +48,15 @@ from decision_gate import (
     validate_runpack_export_request,
     validate_runpack_verify_request,
     validate_scenario_define_request,
+    validate_scenario_lease_acquire_request,
+    validate_scenario_lease_release_request,
     validate_scenario_next_request,
     validate_scenario_start_request,
     validate_scenario_status_request,
     validate_scenario_submit_request,
     validate_scenario_trigger_request,
     validate_scenarios_list_request,
+    validate_scenarios_status_bulk_request,
     validate_schemas_get_request,
     validate_schemas_list_request,
     validate_schemas_register_request,
@@ -192,6 +198,24 @@ class _DocsSearchInput(BaseModel):
     )
 
 
+class _ScenariosStatusBulkInput(BaseModel):
+    request: dict[str, JsonValue] = Field(
+        ..., description="Decision Gate scenarios_status_bulk request."
+    )
+
+
+class _ScenarioLeaseAcquireInput(BaseModel):
+    request: dict[str, JsonValue] = Field(
+        ..., description="Decision Gate scenario_lease_acquire request."
+    )
+
+
+class _ScenarioLeaseReleaseInput(BaseModel):
+    request: dict[str, JsonValue] = Field(
+        ..., description="Decision Gate scenario_lease_release request."
+    )
+
+
 class DecisionGatePrecheckTool(BaseTool):
     name: str = "decision_gate_precheck"
     description: str = "Run a Decision Gate precheck without mutating run state."
@@ -606,6 +630,75 @@ class DecisionGateDocsSearchTool(BaseTool):
         return _as_json(cast(JsonValue, self._client.decision_gate_docs_search(request)))
 
 
+class DecisionGateScenariosStatusBulkTool(BaseTool):
+    name: str = "decision_gate_scenarios_status_bulk"
+    description: str = "Fetch read-only status snapshots for several runs in one call."
+    args_schema: Type[BaseModel] = _ScenariosStatusBulkInput
+
+    _client: DecisionGateClient = PrivateAttr()
+    _validate: bool = PrivateAttr(default=False)
+
+    def __init__(
+        self,
+        client: DecisionGateClient,
+        validate: bool = False,
+        **kwargs: Unpack[_BaseToolKwargs],
+    ):
+        super().__init__(**kwargs)
+        self._client = client
+        self._validate = validate
+
+    def _run(self, request: ScenariosStatusBulkRequest) -> str:
+        _maybe_validate(self._validate, validate_scenarios_status_bulk_request, request)
+        return _as_json(cast(JsonValue, self._client.scenarios_status_bulk(request)))
+
+
+class DecisionGateScenarioLeaseAcquireTool(BaseTool):
+    name: str = "decision_gate_scenario_lease_acquire"
+    description: str = "Acquire or renew an advisory lease that serializes run mutations."
+    args_schema: Type[BaseModel] = _ScenarioLeaseAcquireInput
+
+    _client: DecisionGateClient = PrivateAttr()
+    _validate: bool = PrivateAttr(default=False)
+
+    def __init__(
+        self,
+        client: DecisionGateClient,
+        validate: bool = False,
+        **kwargs: Unpack[_BaseToolKwargs],
+    ):
+        super().__init__(**kwargs)
+        self._client = client
+        self._validate = validate
+
+    def _run(self, request: ScenarioLeaseAcquireRequest) -> str:
+        _maybe_validate(self._validate, validate_scenario_lease_acquire_request, request)
+        return _as_json(cast(JsonValue, self._client.scenario_lease_acquire(request)))
+
+
+class DecisionGateScenarioLeaseReleaseTool(BaseTool):
+    name: str = "decision_gate_scenario_lease_release"
+    description: str = "Release an advisory run lease held by the caller."
+    args_schema: Type[BaseModel] = _ScenarioLeaseReleaseInput
+
+    _client: DecisionGateClient = PrivateAttr()
+    _validate: bool = PrivateAttr(default=False)
+
+    def __init__(
+        self,
+        client: DecisionGateClient,
+        validate: bool = False,
+        **kwargs: Unpack[_BaseToolKwargs],
+    ):
+        super().__init__(**kwargs)
+        self._client = client
+        self._validate = validate
+
+    def _run(self, request: ScenarioLeaseReleaseRequest) -> str:
+        _maybe_validate(self._validate, validate_scenario_lease_release_request, request)
+        return _as_json(cast(JsonValue, self._client.scenario_lease_release(request)))
+
+
 def build_decision_gate_tools(
     client: DecisionGateClient,
     *,
@@ -631,6 +724,9 @@ def build_decision_gate_tools(
         DecisionGateSchemasListTool(client=client, validate=validate),
         DecisionGateSchemasGetTool(client=client, validate=validate),
         DecisionGateDocsSearchTool(client=client, validate=validate),
+        DecisionGateScenariosStatusBulkTool(client=client, validate=validate),
+        DecisionGateScenarioLeaseAcquireTool(client=client, validate=validate),
+        DecisionGateScenarioLeaseReleaseTool(client=client, validate=validate),
     ]
 
 
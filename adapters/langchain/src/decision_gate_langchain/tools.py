@@ -33,6 +33,10 @@ from decision_gate import (
     RunpackVerifyResponse,
     ScenarioDefineRequest,
     ScenarioDefineResponse,
+    ScenarioLeaseAcquireRequest,
+    ScenarioLeaseAcquireResponse,
+    ScenarioLeaseReleaseRequest,
+    ScenarioLeaseReleaseResponse,
     ScenarioNextRequest,
     ScenarioNextResponse,
     ScenarioStartRequest,
@@ -45,6 +49,8 @@ from decision_gate import (
     ScenarioTriggerResponse,
     ScenariosListRequest,
     ScenariosListResponse,
+    ScenariosStatusBulkRequest,
+    ScenariosStatusBulkResponse,
     SchemasGetRequest,
     SchemasGetResponse,
     SchemasListRequest,
@@ -60,12 +66,15 @@ from decision_gate import (
     validate_runpack_export_request,
     validate_runpack_verify_request,
     validate_scenario_define_request,
+    validate_scenario_lease_acquire_request,
+    validate_scenario_lease_release_request,
     validate_scenario_next_request,
     validate_scenario_start_request,
     validate_scenario_status_request,
     validate_scenario_submit_request,
     validate_scenario_trigger_request,
     validate_scenarios_list_request,
+    validate_scenarios_status_bulk_request,
     validate_schemas_get_request,
     validate_schemas_list_request,
     validate_schemas_register_request,
@@ -244,6 +253,30 @@ def build_decision_gate_tools(
         _maybe_validate(validate, validate_decision_gate_docs_search_request, request)
         return client.decision_gate_docs_search(request)
 
+    @_tool("decision_gate_scenarios_status_bulk", args_schema=DecisionGateToolArgs)
+    def decision_gate_scenarios_status_bulk(
+        request: ScenariosStatusBulkRequest,
+    ) -> ScenariosStatusBulkResponse:
+        """Fetch read-only status snapshots for several runs in one call."""
+        _maybe_validate(validate, validate_scenarios_status_bulk_request, request)
+        return client.scenarios_status_bulk(request)
+
+    @_tool("decision_gate_scenario_lease_acquire", args_schema=DecisionGateToolArgs)
+    def decision_gate_scenario_lease_acquire(
+        request: ScenarioLeaseAcquireRequest,
+    ) -> ScenarioLeaseAcquireResponse:
+        """Acquire or renew an advisory lease that serializes run mutations."""
+        _maybe_validate(validate, validate_scenario_lease_acquire_request, request)
+        return client.scenario_lease_acquire(request)
+
+    @_tool("decision_gate_scenario_lease_release", args_schema=DecisionGateToolArgs)
+    def decision_gate_scenario_lease_release(
+        request: ScenarioLeaseReleaseRequest,
+    ) -> ScenarioLeaseReleaseResponse:
+        """Release an advisory run lease held by the caller."""
+        _maybe_validate(validate, validate_scenario_lease_release_request, request)
+        return client.scenario_lease_release(request)
+
     return [
         decision_gate_precheck,
         decision_gate_scenario_define,
@@ -263,6 +296,9 @@ def build_decision_gate_tools(
         decision_gate_schemas_list,
         decision_gate_schemas_get,
         decision_gate_docs_search,
+        decision_gate_scenarios_status_bulk,
+        decision_gate_scenario_lease_acquire,
+        decision_gate_scenario_lease_release,
     ]
 
 
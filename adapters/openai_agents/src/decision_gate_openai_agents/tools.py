@@ -32,6 +32,10 @@ from decision_gate import (
     RunpackVerifyResponse,
     ScenarioDefineRequest,
     ScenarioDefineResponse,
+    ScenarioLeaseAcquireRequest,
+    ScenarioLeaseAcquireResponse,
+    ScenarioLeaseReleaseRequest,
+    ScenarioLeaseReleaseResponse,
     ScenarioNextRequest,
     ScenarioNextResponse,
     ScenarioStartRequest,
@@ -44,6 +48,8 @@ from decision_gate import (
     ScenarioTriggerResponse,
     ScenariosListRequest,
     ScenariosListResponse,
+    ScenariosStatusBulkRequest,
+    ScenariosStatusBulkResponse,
     SchemasGetRequest,
     SchemasGetResponse,
     SchemasListRequest,
@@ -59,12 +65,15 @@ from decision_gate import (
     validate_runpack_export_request,
     validate_runpack_verify_request,
     validate_scenario_define_request,
+    validate_scenario_lease_acquire_request,
+    validate_scenario_lease_release_request,
     validate_scenario_next_request,
     validate_scenario_start_request,
     validate_scenario_status_request,
     validate_scenario_submit_request,
     validate_scenario_trigger_request,
     validate_scenarios_list_request,
+    validate_scenarios_status_bulk_request,
     validate_schemas_get_request,
     validate_schemas_list_request,
     validate_schemas_register_request,
@@ -264,6 +273,33 @@ def build_decision_gate_tools(
         _maybe_validate(validate, validate_decision_gate_docs_search_request, typed_request)
         return client.decision_gate_docs_search(typed_request)
 
+    @_dg_function_tool("decision_gate_scenarios_status_bulk")
+    def decision_gate_scenarios_status_bulk(
+        request: dict[str, JsonValue],
+    ) -> ScenariosStatusBulkResponse:
+        """Fetch read-only status snapshots for several runs in one call."""
+        typed_request = cast(ScenariosStatusBulkRequest, request)
+        _maybe_validate(validate, validate_scenarios_status_bulk_request, typed_request)
+        return client.scenarios_status_bulk(typed_request)
+
+    @_dg_function_tool("decision_gate_scenario_lease_acquire")
+    def decision_gate_scenario_lease_acquire(
+        request: dict[str, JsonValue],
+    ) -> ScenarioLeaseAcquireResponse:
+        """Acquire or renew an advisory lease that serializes run mutations."""
+        typed_request = cast(ScenarioLeaseAcquireRequest, request)
+        _maybe_validate(validate, validate_scenario_lease_acquire_request, typed_request)
+        return client.scenario_lease_acquire(typed_request)
+
+    @_dg_function_tool("decision_gate_scenario_lease_release")
+    def decision_gate_scenario_lease_release(
+        request: dict[str, JsonValue],
+    ) -> ScenarioLeaseReleaseResponse:
+        """Release an advisory run lease held by the caller."""
+        typed_request = cast(ScenarioLeaseReleaseRequest, request)
+        _maybe_validate(validate, validate_scenario_lease_release_request, typed_request)
+        return client.scenario_lease_release(typed_request)
+
     return [
         decision_gate_precheck,
         decision_gate_scenario_define,
@@ -283,6 +319,9 @@ def build_decision_gate_tools(
         decision_gate_schemas_list,
         decision_gate_schemas_get,
         decision_gate_docs_search,
+        decision_gate_scenarios_status_bulk,
+        decision_gate_scenario_lease_acquire,
+        decision_gate_scenario_lease_release,
     ]
 
 
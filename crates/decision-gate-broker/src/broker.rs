@@ -14,6 +14,7 @@
 //! - Payload hashes must match the envelope or content reference hash.
 //! - Declared content types must match the resolved payload kind.
 //! - Source resolution fails closed on missing or unsupported schemes.
+//! - Cached source payloads are re-validated against the content hash on every hit.
 //!
 //! Security posture: treats content references as untrusted input; see
 //! `Docs/security/threat_model.md`.
@@ -24,7 +25,9 @@
 
 use std::collections::BTreeMap;
 use std::sync::Arc;
+use std::sync::Mutex;
 
+use decision_gate_core::ContentRef;
 use decision_gate_core::DispatchError;
 use decision_gate_core::DispatchReceipt;
 use decision_gate_core::DispatchTarget;
@@ -43,8 +46,11 @@ use crate::payload::Payload;
 use crate::payload::PayloadBody;
 use crate::sink::Sink;
 use crate::sink::SinkError;
+#[cfg(feature = "s3")]
+use crate::source::S3Source;
 use crate::source::Source;
 use crate::source::SourceError;
+use crate::source::SourcePayload;
 
 // ============================================================================
 // SECTION: Broker Errors
@@ -124,6 +130,8 @@ pub struct CompositeBrokerBuilder {
     sources: BTreeMap<String, Arc<dyn Source>>,
     /// Sink used to dispatch resolved payloads.
     sink: Option<Arc<dyn Sink>>,
+    /// Optional byte budget for the source payload cache.
+    cache_max_bytes: Option<usize>,
 }
 
 impl CompositeBrokerBuilder {
@@ -134,6 +142,13 @@ impl CompositeBrokerBuilder {
         self
     }
 
+    /// Registers an S3 source for the `s3` URI scheme.
+    #[cfg(feature = "s3")]
+    #[must_use]
+    pub fn s3_source(self, source: S3Source) -> Self {
+        self.source("s3", source)
+    }
+
     /// Registers the sink used for dispatch.
     #[must_use]
     pub fn sink(mut self, sink: impl Sink + 'static) -> Self {
@@ -141,6 +156,16 @@ impl CompositeBrokerBuilder {
         self
     }
 
+    /// Enables an LRU cache of resolved source payloads bounded by total bytes.
+    ///
+    /// Entries are keyed by content hash and URI. The URI is part of the key so
+    /// a cached payload is never served for a reference its source would deny.
+    #[must_use]
+    pub const fn source_cache(mut self, max_bytes: usize) -> Self {
+        self.cache_max_bytes = Some(max_bytes);
+        self
+    }
+
     /// Builds the composite broker.
     ///
     /// # Errors
@@ -150,6 +175,7 @@ impl CompositeBrokerBuilder {
         Ok(CompositeBroker {
             sources: self.sources,
             sink: self.sink.ok_or(BrokerError::MissingSink)?,
+            cache: self.cache_max_bytes.map(|max_bytes| Mutex::new(SourceCache::new(max_bytes))),
         })
     }
 }
@@ -164,6 +190,8 @@ pub struct CompositeBroker {
     sources: BTreeMap<String, Arc<dyn Source>>,
     /// Sink used to dispatch resolved payloads.
     sink: Arc<dyn Sink>,
+    /// Optional LRU cache of validated source payloads.
+    cache: Option<Mutex<SourceCache>>,
 }
 
 impl CompositeBroker {
@@ -242,8 +270,12 @@ impl CompositeBroker {
                         actual: envelope.content_hash.value.clone(),
                     });
                 }
-                let source = self.resolve_source(&content_ref.uri)?;
-                let resolved = source.fetch(content_ref)?;
+                let cached = self.cache_lookup(content_ref);
+                let from_cache = cached.is_some();
+                let resolved = match cached {
+                    Some(resolved) => resolved,
+                    None => self.resolve_source(&content_ref.uri)?.fetch(content_ref)?,
+                };
                 if let Some(content_type) = &resolved.content_type
                     && !content_type_matches(envelope.content_type.as_str(), content_type)
                 {
@@ -258,6 +290,9 @@ impl CompositeBroker {
                     content_ref.content_hash.algorithm,
                     &content_ref.content_hash,
                 )?;
+                if !from_cache {
+                    self.cache_insert(content_ref, resolved);
+                }
                 Ok(Payload {
                     envelope: envelope.clone(),
                     body,
@@ -266,6 +301,22 @@ impl CompositeBroker {
         }
     }
 
+    /// Returns a cached source payload for the content reference, if any.
+    fn cache_lookup(&self, content_ref: &ContentRef) -> Option<SourcePayload> {
+        let cache = self.cache.as_ref()?;
+        let mut cache = cache.lock().ok()?;
+        cache.get(content_ref)
+    }
+
+    /// Caches a source payload that passed hash validation.
+    fn cache_insert(&self, content_ref: &ContentRef, payload: SourcePayload) {
+        if let Some(cache) = &self.cache
+            && let Ok(mut cache) = cache.lock()
+        {
+            cache.insert(content_ref, payload);
+        }
+    }
+
     /// Builds a payload body from raw bytes and content type.
     fn build_body(bytes: &[u8], content_type: &str) -> Result<PayloadBody, BrokerError> {
         if is_json_content_type(content_type) {
@@ -307,6 +358,105 @@ impl Dispatcher for CompositeBroker {
     }
 }
 
+// ============================================================================
+// SECTION: Source Cache
+// ============================================================================
+
+/// Cache key: content hash value and source URI.
+type CacheKey = (String, String);
+
+/// Cached source payload with recency tracking.
+///
+/// # Invariants
+/// - `payload` passed hash validation for `algorithm` when inserted.
+struct CacheEntry {
+    /// Hash algorithm of the content reference.
+    algorithm: HashAlgorithm,
+    /// Validated source payload.
+    payload: SourcePayload,
+    /// Logical timestamp of the last access.
+    last_used: u64,
+}
+
+/// Byte-bounded LRU cache of source payloads.
+///
+/// # Invariants
+/// - `total_bytes` equals the sum of cached payload sizes and never exceeds `max_bytes`.
+struct SourceCache {
+    /// Maximum total payload bytes retained.
+    max_bytes: usize,
+    /// Current total payload bytes retained.
+    total_bytes: usize,
+    /// Monotonic access counter.
+    clock: u64,
+    /// Cached entries keyed by content hash and URI.
+    entries: BTreeMap<CacheKey, CacheEntry>,
+}
+
+impl SourceCache {
+    /// Creates an empty cache with the given byte budget.
+    const fn new(max_bytes: usize) -> Self {
+        Self {
+            max_bytes,
+            total_bytes: 0,
+            clock: 0,
+            entries: BTreeMap::new(),
+        }
+    }
+
+    /// Returns the cache key for a content reference.
+    fn key(content_ref: &ContentRef) -> CacheKey {
+        (content_ref.content_hash.value.clone(), content_ref.uri.clone())
+    }
+
+    /// Returns a cached payload and marks it as recently used.
+    fn get(&mut self, content_ref: &ContentRef) -> Option<SourcePayload> {
+        self.clock = self.clock.saturating_add(1);
+        let clock = self.clock;
+        let entry = self.entries.get_mut(&Self::key(content_ref))?;
+        if entry.algorithm != content_ref.content_hash.algorithm {
+            return None;
+        }
+        entry.last_used = clock;
+        Some(entry.payload.clone())
+    }
+
+    /// Inserts a payload, evicting least recently used entries to fit the budget.
+    fn insert(&mut self, content_ref: &ContentRef, payload: SourcePayload) {
+        let size = payload.bytes.len();
+        if size > self.max_bytes {
+            return;
+        }
+        let key = Self::key(content_ref);
+        if let Some(previous) = self.entries.remove(&key) {
+            self.total_bytes -= previous.payload.bytes.len();
+        }
+        while self.total_bytes + size > self.max_bytes {
+            let Some(oldest) = self
+                .entries
+                .iter()
+                .min_by_key(|(_, entry)| entry.last_used)
+                .map(|(key, _)| key.clone())
+            else {
+                break;
+            };
+            if let Some(evicted) = self.entries.remove(&oldest) {
+                self.total_bytes -= evicted.payload.bytes.len();
+            }
+        }
+        self.clock = self.clock.saturating_add(1);
+        self.total_bytes += size;
+        self.entries.insert(
+            key,
+            CacheEntry {
+                algorithm: content_ref.content_hash.algorithm,
+                payload,
+                last_used: self.clock,
+            },
+        );
+    }
+}
+
 // ============================================================================
 // SECTION: Helpers
 // ============================================================================
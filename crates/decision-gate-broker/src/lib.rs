@@ -22,6 +22,8 @@
 // ============================================================================
 
 pub mod broker;
+#[cfg(feature = "nats")]
+pub mod nats;
 pub mod payload;
 pub mod sink;
 pub mod source;
@@ -33,12 +35,34 @@ pub mod source;
 pub use broker::BrokerError;
 pub use broker::CompositeBroker;
 pub use broker::CompositeBrokerBuilder;
+#[cfg(feature = "nats")]
+pub use nats::NatsConnection;
+#[cfg(feature = "nats")]
+pub use nats::NatsError;
+#[cfg(feature = "nats")]
+pub use nats::NatsMessage;
+#[cfg(feature = "nats")]
+pub use nats::NatsObject;
+#[cfg(feature = "nats")]
+pub use nats::NatsPublishAck;
+#[cfg(feature = "nats")]
+pub use nats::NatsSubjectPolicy;
 pub use payload::Payload;
 pub use payload::PayloadBody;
 pub use sink::CallbackSink;
 pub use sink::ChannelSink;
 pub use sink::DispatchMessage;
+#[cfg(feature = "kafka")]
+pub use sink::KafkaAck;
+#[cfg(feature = "kafka")]
+pub use sink::KafkaProducer;
+#[cfg(feature = "kafka")]
+pub use sink::KafkaRecord;
+#[cfg(feature = "kafka")]
+pub use sink::KafkaSink;
 pub use sink::LogSink;
+#[cfg(feature = "nats")]
+pub use sink::NatsSink;
 pub use sink::Sink;
 pub use sink::SinkError;
 pub use source::FileSource;
@@ -46,8 +70,15 @@ pub use source::HttpSource;
 pub use source::HttpSourcePolicy;
 pub use source::InlineSource;
 pub use source::MAX_SOURCE_BYTES;
+#[cfg(feature = "nats")]
+pub use source::NatsSource;
+#[cfg(feature = "s3")]
+pub use source::S3Source;
+#[cfg(feature = "s3")]
+pub use source::S3SourceConfig;
 pub use source::Source;
 pub use source::SourceError;
+pub use source::SourceMetadata;
 pub use source::SourcePayload;
 
 #[cfg(test)]
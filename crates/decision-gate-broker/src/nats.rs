@@ -0,0 +1,220 @@
+// crates/decision-gate-broker/src/nats.rs
+// ============================================================================
+// Module: Decision Gate NATS Interface
+// Description: Connection abstraction and subject policy for NATS/JetStream.
+// Purpose: Share publish/object-store plumbing between the NATS sink and source.
+// Dependencies: thiserror, std
+// ============================================================================
+
+//! ## Overview
+//! [`NatsConnection`] is the client boundary used by [`crate::NatsSink`] and
+//! [`crate::NatsSource`]. Implementations wrap a concrete NATS client and must
+//! honor the timeouts and byte limits passed on each call.
+//! [`NatsSubjectPolicy`] allowlists subjects using NATS wildcard semantics
+//! (`*` matches one token, `>` matches one or more trailing tokens).
+//! Invariants:
+//! - Publishes succeed only after a `JetStream` ack.
+//! - Policies deny every subject unless explicitly allowed.
+//!
+//! Security posture: NATS servers are external systems; subjects and object
+//! names come from untrusted content references. See
+//! `Docs/security/threat_model.md`.
+
+// ============================================================================
+// SECTION: Imports
+// ============================================================================
+
+use std::time::Duration;
+
+use thiserror::Error;
+
+// ============================================================================
+// SECTION: Constants
+// ============================================================================
+
+/// Default timeout for NATS publish acks and object fetches.
+pub const DEFAULT_NATS_TIMEOUT: Duration = Duration::from_secs(5);
+
+/// Maximum subject length accepted by the policy.
+const MAX_SUBJECT_LENGTH: usize = 256;
+
+// ============================================================================
+// SECTION: Connection Interface
+// ============================================================================
+
+/// Message published to a NATS subject.
+///
+/// # Invariants
+/// - `subject` is a literal subject (no wildcards).
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct NatsMessage {
+    /// Destination subject.
+    pub subject: String,
+    /// Message headers.
+    pub headers: Vec<(String, String)>,
+    /// Message payload bytes.
+    pub payload: Vec<u8>,
+}
+
+/// `JetStream` publish acknowledgement.
+///
+/// # Invariants
+/// - `stream` and `sequence` identify the stored message.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct NatsPublishAck {
+    /// Stream that stored the message.
+    pub stream: String,
+    /// Stream sequence assigned to the message.
+    pub sequence: u64,
+    /// True when the server deduplicated the message by `Nats-Msg-Id`.
+    pub duplicate: bool,
+}
+
+/// Object fetched from a `JetStream` object store.
+///
+/// # Invariants
+/// - `bytes.len()` does not exceed the requested byte limit.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct NatsObject {
+    /// Object bytes.
+    pub bytes: Vec<u8>,
+    /// Optional content type recorded in object metadata.
+    pub content_type: Option<String>,
+}
+
+/// Errors reported by NATS connections.
+///
+/// # Invariants
+/// - Variants are stable for programmatic handling.
+#[derive(Debug, Error)]
+pub enum NatsError {
+    /// The operation did not complete before the timeout.
+    #[error("nats operation timed out after {0:?}")]
+    Timeout(Duration),
+    /// The requested object does not exist.
+    #[error("nats object not found: {0}")]
+    NotFound(String),
+    /// The object exceeded the requested byte limit.
+    #[error("nats object is {actual_bytes} bytes (max {max_bytes})")]
+    TooLarge {
+        /// Byte limit requested by the caller.
+        max_bytes: usize,
+        /// Object size reported by the server.
+        actual_bytes: usize,
+    },
+    /// Any other connection or server failure.
+    #[error("nats failure: {0}")]
+    Other(String),
+}
+
+/// NATS client boundary used by the broker.
+pub trait NatsConnection: Send + Sync {
+    /// Publishes a message to `JetStream` and waits for the ack.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`NatsError`] when the publish fails or is not acknowledged in time.
+    fn publish(&self, message: NatsMessage, timeout: Duration)
+    -> Result<NatsPublishAck, NatsError>;
+
+    /// Fetches an object from a `JetStream` object store bucket.
+    ///
+    /// Implementations must stop reading once `max_bytes` is exceeded.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`NatsError`] when the object cannot be fetched in time.
+    fn get_object(
+        &self,
+        bucket: &str,
+        name: &str,
+        max_bytes: usize,
+        timeout: Duration,
+    ) -> Result<NatsObject, NatsError>;
+}
+
+// ============================================================================
+// SECTION: Subject Policy
+// ============================================================================
+
+/// Subject allowlist for NATS publishes and object-store reads.
+///
+/// # Invariants
+/// - An empty policy denies every subject.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct NatsSubjectPolicy {
+    /// Allowed subject patterns.
+    allowlist: Vec<String>,
+}
+
+impl NatsSubjectPolicy {
+    /// Creates a policy that denies every subject.
+    #[must_use]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Adds allowed subject patterns.
+    #[must_use]
+    pub fn allow_subjects<I, S>(mut self, subjects: I) -> Self
+    where
+        I: IntoIterator<Item = S>,
+        S: AsRef<str>,
+    {
+        self.allowlist.extend(subjects.into_iter().map(|subject| subject.as_ref().to_string()));
+        self
+    }
+
+    /// Returns true when the literal subject matches an allowed pattern.
+    #[must_use]
+    pub fn is_allowed(&self, subject: &str) -> bool {
+        is_valid_subject(subject, false)
+            && self.allowlist.iter().any(|pattern| subject_matches(pattern, subject))
+    }
+}
+
+/// Returns true when the subject is well formed.
+///
+/// Wildcard tokens are accepted only when `allow_wildcards` is set.
+#[must_use]
+pub fn is_valid_subject(subject: &str, allow_wildcards: bool) -> bool {
+    if subject.is_empty() || subject.len() > MAX_SUBJECT_LENGTH {
+        return false;
+    }
+    let tokens: Vec<&str> = subject.split('.').collect();
+    let last = tokens.len().saturating_sub(1);
+    tokens.iter().enumerate().all(|(index, token)| {
+        if token.is_empty() || token.chars().any(|ch| ch.is_whitespace() || ch.is_control()) {
+            return false;
+        }
+        match *token {
+            "*" => allow_wildcards,
+            ">" => allow_wildcards && index == last,
+            _ => !token.contains(['*', '>']),
+        }
+    })
+}
+
+/// Matches a literal subject against a pattern with NATS wildcard semantics.
+fn subject_matches(pattern: &str, subject: &str) -> bool {
+    if !is_valid_subject(pattern, true) {
+        return false;
+    }
+    let mut subject_tokens = subject.split('.');
+    for pattern_token in pattern.split('.') {
+        match pattern_token {
+            ">" => return subject_tokens.next().is_some(),
+            "*" => {
+                if subject_tokens.next().is_none() {
+                    return false;
+                }
+            }
+            literal => {
+                if subject_tokens.next() != Some(literal) {
+                    return false;
+                }
+            }
+        }
+    }
+    subject_tokens.next().is_none()
+}
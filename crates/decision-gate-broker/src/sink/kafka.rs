@@ -0,0 +1,226 @@
+// crates/decision-gate-broker/src/sink/kafka.rs
+// ============================================================================
+// Module: Decision Gate Kafka Sink
+// Description: Kafka sink for disclosure event streaming.
+// Purpose: Publish resolved payloads to a Kafka topic with envelope headers.
+// Dependencies: decision-gate-core, serde_json, std
+// ============================================================================
+
+//! ## Overview
+//! [`KafkaSink`] produces each payload to a configured topic through a
+//! [`KafkaProducer`]. The envelope content hash is the message key so that
+//! partitioning and consumer-side deduplication follow content identity, and
+//! envelope metadata travels as message headers.
+//! Invariants:
+//! - Receipts are returned only after the producer reports a broker ack.
+//! - JSON payloads are produced as canonical JSON bytes matching `content_hash`.
+//! - Receipt dispatch IDs encode the acknowledged topic, partition, and offset.
+//!
+//! Security posture: Kafka topics are external systems; payloads and headers
+//! are sensitive per `Docs/security/threat_model.md`.
+
+// ============================================================================
+// SECTION: Imports
+// ============================================================================
+
+use decision_gate_core::DispatchReceipt;
+use decision_gate_core::DispatchTarget;
+use decision_gate_core::Timestamp;
+use decision_gate_core::hashing::canonical_json_bytes;
+
+use crate::payload::Payload;
+use crate::payload::PayloadBody;
+use crate::sink::ReceiptFactory;
+use crate::sink::Sink;
+use crate::sink::SinkError;
+
+// ============================================================================
+// SECTION: Limits
+// ============================================================================
+
+/// Maximum Kafka topic name length accepted by brokers.
+const MAX_TOPIC_LENGTH: usize = 249;
+
+// ============================================================================
+// SECTION: Producer Interface
+// ============================================================================
+
+/// Kafka record produced for a single dispatch.
+///
+/// # Invariants
+/// - `key` is the lowercase hex content hash of the payload.
+/// - `headers` are ordered deterministically.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct KafkaRecord {
+    /// Destination topic.
+    pub topic: String,
+    /// Message key used for partitioning and deduplication.
+    pub key: Vec<u8>,
+    /// Message value (payload bytes).
+    pub value: Vec<u8>,
+    /// Message headers carrying envelope metadata.
+    pub headers: Vec<(String, Vec<u8>)>,
+}
+
+/// Broker acknowledgement for a produced record.
+///
+/// # Invariants
+/// - `partition` and `offset` identify the committed record.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct KafkaAck {
+    /// Partition the record was written to.
+    pub partition: i32,
+    /// Offset assigned by the broker.
+    pub offset: i64,
+    /// Broker or log-append timestamp in unix milliseconds, when reported.
+    pub timestamp_ms: Option<i64>,
+}
+
+/// Kafka producer used by [`KafkaSink`].
+///
+/// Implementations wrap a concrete client and must block until the broker
+/// acknowledges the record; a queued-but-unacknowledged record is not a
+/// successful delivery.
+pub trait KafkaProducer: Send + Sync {
+    /// Produces the record and waits for the broker ack.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`SinkError`] when the record is rejected or not acknowledged.
+    fn produce(&self, record: KafkaRecord) -> Result<KafkaAck, SinkError>;
+}
+
+// ============================================================================
+// SECTION: Kafka Sink
+// ============================================================================
+
+/// Kafka-backed payload sink.
+///
+/// # Invariants
+/// - `topic` is a valid Kafka topic name.
+pub struct KafkaSink<P: KafkaProducer> {
+    /// Producer used to publish records.
+    producer: P,
+    /// Destination topic.
+    topic: String,
+    /// Receipt factory for dispatcher naming and fallback timestamps.
+    receipts: ReceiptFactory,
+}
+
+impl<P: KafkaProducer> KafkaSink<P> {
+    /// Creates a Kafka sink with the default dispatcher name.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`SinkError::InvalidConfig`] when the topic name is invalid.
+    pub fn new(producer: P, topic: impl Into<String>) -> Result<Self, SinkError> {
+        Self::with_dispatcher(producer, topic, "kafka")
+    }
+
+    /// Creates a Kafka sink with a custom dispatcher name.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`SinkError::InvalidConfig`] when the topic name is invalid.
+    pub fn with_dispatcher(
+        producer: P,
+        topic: impl Into<String>,
+        dispatcher: impl Into<String>,
+    ) -> Result<Self, SinkError> {
+        let topic = topic.into();
+        validate_topic(&topic)?;
+        Ok(Self {
+            producer,
+            topic,
+            receipts: ReceiptFactory::new(dispatcher),
+        })
+    }
+
+    /// Returns the destination topic.
+    #[must_use]
+    pub fn topic(&self) -> &str {
+        &self.topic
+    }
+}
+
+impl<P: KafkaProducer> Sink for KafkaSink<P> {
+    fn deliver(
+        &self,
+        target: &DispatchTarget,
+        payload: &Payload,
+    ) -> Result<DispatchReceipt, SinkError> {
+        let record = KafkaRecord {
+            topic: self.topic.clone(),
+            key: payload.envelope.content_hash.value.as_bytes().to_vec(),
+            value: payload_bytes(payload)?,
+            headers: envelope_headers(target, payload)?,
+        };
+        let ack = self.producer.produce(record)?;
+        let mut receipt = self.receipts.next(target, payload);
+        receipt.dispatch_id =
+            format!("{}-{}-{}-{}", receipt.dispatcher, self.topic, ack.partition, ack.offset);
+        if let Some(timestamp_ms) = ack.timestamp_ms {
+            receipt.dispatched_at = Timestamp::UnixMillis(timestamp_ms);
+        }
+        Ok(receipt)
+    }
+}
+
+// ============================================================================
+// SECTION: Record Helpers
+// ============================================================================
+
+/// Validates a Kafka topic name.
+fn validate_topic(topic: &str) -> Result<(), SinkError> {
+    if topic.is_empty() || topic.len() > MAX_TOPIC_LENGTH || topic == "." || topic == ".." {
+        return Err(SinkError::InvalidConfig(format!("invalid kafka topic: {topic:?}")));
+    }
+    if !topic.bytes().all(|byte| byte.is_ascii_alphanumeric() || matches!(byte, b'.' | b'_' | b'-'))
+    {
+        return Err(SinkError::InvalidConfig(format!("invalid kafka topic: {topic:?}")));
+    }
+    Ok(())
+}
+
+/// Returns the payload bytes produced as the message value.
+fn payload_bytes(payload: &Payload) -> Result<Vec<u8>, SinkError> {
+    match &payload.body {
+        PayloadBody::Json(value) => canonical_json_bytes(value)
+            .map_err(|err| SinkError::DeliveryFailed(format!("payload encoding failed: {err}"))),
+        PayloadBody::Bytes(bytes) => Ok(bytes.clone()),
+    }
+}
+
+/// Builds message headers from the envelope and dispatch target.
+fn envelope_headers(
+    target: &DispatchTarget,
+    payload: &Payload,
+) -> Result<Vec<(String, Vec<u8>)>, SinkError> {
+    let envelope = &payload.envelope;
+    let encode_err = |err: serde_json::Error| {
+        SinkError::DeliveryFailed(format!("header encoding failed: {err}"))
+    };
+    let mut headers = vec![
+        header("dg-scenario-id", envelope.scenario_id.as_str()),
+        header("dg-run-id", envelope.run_id.as_str()),
+        header("dg-stage-id", envelope.stage_id.as_str()),
+        header("dg-packet-id", envelope.packet_id.as_str()),
+        header("dg-schema-id", envelope.schema_id.as_str()),
+        header("content-type", &envelope.content_type),
+        (
+            "dg-content-hash".to_string(),
+            serde_json::to_vec(&envelope.content_hash).map_err(encode_err)?,
+        ),
+        ("dg-issued-at".to_string(), serde_json::to_vec(&envelope.issued_at).map_err(encode_err)?),
+        ("dg-target".to_string(), serde_json::to_vec(target).map_err(encode_err)?),
+    ];
+    if let Some(correlation_id) = &envelope.correlation_id {
+        headers.push(header("dg-correlation-id", correlation_id.as_str()));
+    }
+    Ok(headers)
+}
+
+/// Builds a UTF-8 string header.
+fn header(name: &str, value: &str) -> (String, Vec<u8>) {
+    (name.to_string(), value.as_bytes().to_vec())
+}
@@ -47,6 +47,9 @@ pub enum SinkError {
     /// Log sink failed to write.
     #[error("log write failed: {0}")]
     LogWriteFailed(String),
+    /// Sink configuration is invalid.
+    #[error("sink configuration invalid: {0}")]
+    InvalidConfig(String),
 }
 
 // ============================================================================
@@ -126,8 +129,22 @@ impl ReceiptFactory {
 
 pub mod callback;
 pub mod channel;
+#[cfg(feature = "kafka")]
+pub mod kafka;
 pub mod log;
+#[cfg(feature = "nats")]
+pub mod nats;
 
 pub use callback::CallbackSink;
 pub use channel::ChannelSink;
+#[cfg(feature = "kafka")]
+pub use kafka::KafkaAck;
+#[cfg(feature = "kafka")]
+pub use kafka::KafkaProducer;
+#[cfg(feature = "kafka")]
+pub use kafka::KafkaRecord;
+#[cfg(feature = "kafka")]
+pub use kafka::KafkaSink;
 pub use log::LogSink;
+#[cfg(feature = "nats")]
+pub use nats::NatsSink;
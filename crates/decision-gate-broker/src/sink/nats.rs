@@ -0,0 +1,186 @@
+// crates/decision-gate-broker/src/sink/nats.rs
+// ============================================================================
+// Module: Decision Gate NATS Sink
+// Description: JetStream sink for disclosure event streaming.
+// Purpose: Publish resolved payloads to allowlisted NATS subjects with acks.
+// Dependencies: decision-gate-core, std
+// ============================================================================
+
+//! ## Overview
+//! [`NatsSink`] publishes each payload to a NATS subject through a
+//! [`NatsConnection`] and waits for the `JetStream` ack. Targets of the form
+//! `external { system = "nats", target = <subject> }` select the subject;
+//! every other target uses the sink's default subject.
+//! Invariants:
+//! - Subjects are checked against the allowlist before publishing.
+//! - Receipts are returned only after a `JetStream` ack within the timeout.
+//! - `Nats-Msg-Id` is `<run>/<packet>/<content hash>` so redelivery of the same packet is
+//!   deduplicated server-side while distinct packets are not.
+//!
+//! Security posture: NATS subjects are external systems; payloads and headers
+//! are sensitive per `Docs/security/threat_model.md`.
+
+// ============================================================================
+// SECTION: Imports
+// ============================================================================
+
+use std::time::Duration;
+
+use decision_gate_core::DispatchReceipt;
+use decision_gate_core::DispatchTarget;
+use decision_gate_core::hashing::canonical_json_bytes;
+
+use crate::nats::DEFAULT_NATS_TIMEOUT;
+use crate::nats::NatsConnection;
+use crate::nats::NatsMessage;
+use crate::nats::NatsSubjectPolicy;
+use crate::nats::is_valid_subject;
+use crate::payload::Payload;
+use crate::payload::PayloadBody;
+use crate::sink::ReceiptFactory;
+use crate::sink::Sink;
+use crate::sink::SinkError;
+
+// ============================================================================
+// SECTION: NATS Sink
+// ============================================================================
+
+/// `JetStream`-backed payload sink.
+///
+/// # Invariants
+/// - `subject` is a literal subject allowed by `policy`.
+pub struct NatsSink<C: NatsConnection> {
+    /// Connection used to publish messages.
+    connection: C,
+    /// Default subject for non-NATS targets.
+    subject: String,
+    /// Subject allowlist.
+    policy: NatsSubjectPolicy,
+    /// Publish ack timeout.
+    timeout: Duration,
+    /// Receipt factory for dispatcher naming and timestamps.
+    receipts: ReceiptFactory,
+}
+
+impl<C: NatsConnection> NatsSink<C> {
+    /// Creates a sink that publishes to `subject`, which is allowlisted by default.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`SinkError::InvalidConfig`] when the subject is malformed.
+    pub fn new(connection: C, subject: impl Into<String>) -> Result<Self, SinkError> {
+        let subject = subject.into();
+        if !is_valid_subject(&subject, false) {
+            return Err(SinkError::InvalidConfig(format!("invalid nats subject: {subject:?}")));
+        }
+        Ok(Self {
+            connection,
+            policy: NatsSubjectPolicy::new().allow_subjects([&subject]),
+            subject,
+            timeout: DEFAULT_NATS_TIMEOUT,
+            receipts: ReceiptFactory::new("nats"),
+        })
+    }
+
+    /// Replaces the subject allowlist.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`SinkError::InvalidConfig`] when the default subject is not allowed.
+    pub fn with_policy(mut self, policy: NatsSubjectPolicy) -> Result<Self, SinkError> {
+        if !policy.is_allowed(&self.subject) {
+            return Err(SinkError::InvalidConfig(format!(
+                "default nats subject not in allowlist: {}",
+                self.subject
+            )));
+        }
+        self.policy = policy;
+        Ok(self)
+    }
+
+    /// Sets the publish ack timeout.
+    #[must_use]
+    pub const fn with_timeout(mut self, timeout: Duration) -> Self {
+        self.timeout = timeout;
+        self
+    }
+
+    /// Sets the dispatcher name used in receipts.
+    #[must_use]
+    pub fn with_dispatcher(mut self, dispatcher: impl Into<String>) -> Self {
+        self.receipts = ReceiptFactory::new(dispatcher);
+        self
+    }
+
+    /// Returns the subject for a dispatch target.
+    fn subject_for<'a>(&'a self, target: &'a DispatchTarget) -> &'a str {
+        match target {
+            DispatchTarget::External {
+                system,
+                target,
+            } if system == "nats" => target,
+            _ => &self.subject,
+        }
+    }
+}
+
+impl<C: NatsConnection> Sink for NatsSink<C> {
+    fn deliver(
+        &self,
+        target: &DispatchTarget,
+        payload: &Payload,
+    ) -> Result<DispatchReceipt, SinkError> {
+        let subject = self.subject_for(target);
+        if !self.policy.is_allowed(subject) {
+            return Err(SinkError::DeliveryFailed(format!(
+                "nats subject not in allowlist: {subject}"
+            )));
+        }
+        let message = NatsMessage {
+            subject: subject.to_string(),
+            headers: envelope_headers(payload),
+            payload: payload_bytes(payload)?,
+        };
+        let ack = self
+            .connection
+            .publish(message, self.timeout)
+            .map_err(|err| SinkError::DeliveryFailed(err.to_string()))?;
+        let mut receipt = self.receipts.next(target, payload);
+        receipt.dispatch_id = format!("{}-{}-{}", receipt.dispatcher, ack.stream, ack.sequence);
+        Ok(receipt)
+    }
+}
+
+// ============================================================================
+// SECTION: Message Helpers
+// ============================================================================
+
+/// Returns the payload bytes published as the message body.
+fn payload_bytes(payload: &Payload) -> Result<Vec<u8>, SinkError> {
+    match &payload.body {
+        PayloadBody::Json(value) => canonical_json_bytes(value)
+            .map_err(|err| SinkError::DeliveryFailed(format!("payload encoding failed: {err}"))),
+        PayloadBody::Bytes(bytes) => Ok(bytes.clone()),
+    }
+}
+
+/// Builds message headers from the envelope.
+fn envelope_headers(payload: &Payload) -> Vec<(String, String)> {
+    let envelope = &payload.envelope;
+    let mut headers = vec![
+        (
+            "Nats-Msg-Id".to_string(),
+            format!("{}/{}/{}", envelope.run_id, envelope.packet_id, envelope.content_hash.value),
+        ),
+        ("Content-Type".to_string(), envelope.content_type.clone()),
+        ("Dg-Scenario-Id".to_string(), envelope.scenario_id.to_string()),
+        ("Dg-Run-Id".to_string(), envelope.run_id.to_string()),
+        ("Dg-Stage-Id".to_string(), envelope.stage_id.to_string()),
+        ("Dg-Packet-Id".to_string(), envelope.packet_id.to_string()),
+        ("Dg-Schema-Id".to_string(), envelope.schema_id.to_string()),
+    ];
+    if let Some(correlation_id) = &envelope.correlation_id {
+        headers.push(("Dg-Correlation-Id".to_string(), correlation_id.to_string()));
+    }
+    headers
+}
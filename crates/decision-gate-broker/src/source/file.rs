@@ -13,6 +13,8 @@
 //! - Rooted reads must not escape the configured root path.
 //! - Symlink components are rejected for rooted reads.
 //! - Payload bytes are capped at [`crate::source::MAX_SOURCE_BYTES`].
+//! - Glob resolution (opt-in) only applies to rooted sources and only to the final path component;
+//!   the lexicographically-latest regular file wins.
 //!
 //! Security posture: treats file paths as untrusted input; see
 //! `Docs/security/threat_model.md`.
@@ -36,10 +38,18 @@ use url::Url;
 
 use crate::source::Source;
 use crate::source::SourceError;
+use crate::source::SourceMetadata;
 use crate::source::SourcePayload;
 use crate::source::enforce_max_bytes;
 use crate::source::max_source_bytes_u64;
 
+// ============================================================================
+// SECTION: Constants
+// ============================================================================
+
+/// Maximum directory entries scanned while resolving a glob.
+const MAX_GLOB_ENTRIES: usize = 10_000;
+
 // ============================================================================
 // SECTION: File Source
 // ============================================================================
@@ -50,10 +60,13 @@ use crate::source::max_source_bytes_u64;
 /// - When configured with a root, reads are confined to that root path.
 /// - Rooted reads reject symlink components.
 /// - Payloads are limited to [`crate::source::MAX_SOURCE_BYTES`].
+/// - Glob patterns are resolved only when enabled and a root is configured.
 #[derive(Debug, Clone)]
 pub struct FileSource {
     /// Optional root directory for path traversal protection.
     root: Option<PathBuf>,
+    /// Whether wildcards in the file name resolve to the latest match.
+    glob: bool,
 }
 
 impl FileSource {
@@ -62,6 +75,7 @@ impl FileSource {
     pub fn new(root: impl Into<PathBuf>) -> Self {
         Self {
             root: Some(root.into()),
+            glob: false,
         }
     }
 
@@ -70,9 +84,22 @@ impl FileSource {
     pub const fn unrestricted() -> Self {
         Self {
             root: None,
+            glob: false,
         }
     }
 
+    /// Enables glob resolution for the final path component.
+    ///
+    /// `*` matches any run of characters and `?` (percent-encoded as `%3F` in
+    /// URIs) matches a single character. A pattern resolves to the
+    /// lexicographically-latest regular file in its directory, e.g. the newest
+    /// of a set of rotated logs. Patterns are rejected for unrooted sources.
+    #[must_use]
+    pub const fn with_glob_resolution(mut self) -> Self {
+        self.glob = true;
+        self
+    }
+
     /// Resolves a file URI into a local path.
     fn resolve_path(uri: &str) -> Result<PathBuf, SourceError> {
         let url = Url::parse(uri).map_err(|err| SourceError::InvalidUri(err.to_string()))?;
@@ -139,6 +166,47 @@ impl FileSource {
         dir.open_with(relative, &options).map_err(|err| map_open_error(&err))
     }
 
+    /// Resolves a glob in the final component to the latest matching file.
+    fn resolve_glob(root: &Path, relative: &Path) -> Result<PathBuf, SourceError> {
+        let pattern = relative
+            .file_name()
+            .and_then(|name| name.to_str())
+            .ok_or_else(|| SourceError::InvalidUri("glob pattern must be utf-8".to_string()))?;
+        let parent = relative.parent().unwrap_or_else(|| Path::new(""));
+        if is_glob_pattern(parent) {
+            return Err(SourceError::InvalidUri(
+                "glob wildcards are only supported in the file name".to_string(),
+            ));
+        }
+        let dir =
+            Dir::open_ambient_dir(root, ambient_authority()).map_err(|err| map_open_error(&err))?;
+        let entries =
+            if parent.as_os_str().is_empty() { dir.entries() } else { dir.read_dir(parent) }
+                .map_err(|err| map_open_error(&err))?;
+        let mut latest: Option<String> = None;
+        for (index, entry) in entries.enumerate() {
+            if index >= MAX_GLOB_ENTRIES {
+                return Err(SourceError::Policy(format!(
+                    "glob scan exceeds {MAX_GLOB_ENTRIES} directory entries"
+                )));
+            }
+            let entry = entry.map_err(|err| SourceError::Io(err.to_string()))?;
+            let is_file = entry.file_type().is_ok_and(|file_type| file_type.is_file());
+            let Ok(name) = entry.file_name().into_string() else {
+                continue;
+            };
+            if is_file
+                && glob_matches(pattern, &name)
+                && latest.as_ref().is_none_or(|current| name > *current)
+            {
+                latest = Some(name);
+            }
+        }
+        latest.map(|name| parent.join(name)).ok_or_else(|| {
+            SourceError::NotFound(format!("no file matches glob: {}", relative.display()))
+        })
+    }
+
     /// Returns true when the path is a directory (without following symlinks).
     fn path_is_directory(path: &Path) -> bool {
         std::fs::symlink_metadata(path).map(|metadata| metadata.is_dir()).unwrap_or(false)
@@ -154,12 +222,16 @@ impl FileSource {
     }
 }
 
-impl Source for FileSource {
-    fn fetch(&self, content_ref: &ContentRef) -> Result<SourcePayload, SourceError> {
+impl FileSource {
+    /// Opens the regular file referenced by a content reference.
+    fn open(&self, content_ref: &ContentRef) -> Result<cap_std::fs::File, SourceError> {
         let path = Self::resolve_path(&content_ref.uri)?;
         let file = if let Some(root) = &self.root {
             let root = Self::normalize_root_path(root)?;
-            let relative = Self::relative_from_root(&root, &path)?;
+            let mut relative = Self::relative_from_root(&root, &path)?;
+            if self.glob && is_glob_pattern(&relative) {
+                relative = Self::resolve_glob(&root, &relative)?;
+            }
             let full_path = root.join(&relative);
             match Self::open_rooted_file(&root, &relative) {
                 Ok(file) => file,
@@ -173,6 +245,11 @@ impl Source for FileSource {
                 }
             }
         } else {
+            if self.glob && is_glob_pattern(&path) {
+                return Err(SourceError::Policy(
+                    "glob resolution requires a configured root".to_string(),
+                ));
+            }
             match cap_std::fs::File::open_ambient(&path, ambient_authority())
                 .map_err(|err| map_open_error(&err))
             {
@@ -188,12 +265,66 @@ impl Source for FileSource {
             }
         };
         Self::ensure_regular_file(&file)?;
+        Ok(file)
+    }
+}
+
+impl Source for FileSource {
+    fn fetch(&self, content_ref: &ContentRef) -> Result<SourcePayload, SourceError> {
+        let file = self.open(content_ref)?;
         let bytes = Self::read_with_limit(file)?;
         Ok(SourcePayload {
             bytes,
             content_type: None,
         })
     }
+
+    fn metadata(&self, content_ref: &ContentRef) -> Result<SourceMetadata, SourceError> {
+        let file = self.open(content_ref)?;
+        let metadata = file.metadata().map_err(|err| SourceError::Io(err.to_string()))?;
+        Ok(SourceMetadata {
+            size_bytes: Some(metadata.len()),
+            content_type: None,
+        })
+    }
+}
+
+// ============================================================================
+// SECTION: Glob Matching
+// ============================================================================
+
+/// Returns true when the path contains glob wildcards.
+fn is_glob_pattern(path: &Path) -> bool {
+    path.to_string_lossy().contains(['*', '?'])
+}
+
+/// Matches a file name against a `*`/`?` glob pattern.
+fn glob_matches(pattern: &str, name: &str) -> bool {
+    let pattern: Vec<char> = pattern.chars().collect();
+    let name: Vec<char> = name.chars().collect();
+    let (mut p, mut n) = (0, 0);
+    let mut backtrack: Option<(usize, usize)> = None;
+    while n < name.len() {
+        match pattern.get(p) {
+            Some('*') => {
+                backtrack = Some((p, n));
+                p += 1;
+            }
+            Some(&ch) if ch == '?' || ch == name[n] => {
+                p += 1;
+                n += 1;
+            }
+            _ => match backtrack {
+                Some((star, matched)) => {
+                    p = star + 1;
+                    n = matched + 1;
+                    backtrack = Some((star, matched + 1));
+                }
+                None => return false,
+            },
+        }
+    }
+    pattern[p ..].iter().all(|ch| *ch == '*')
 }
 
 /// Maps IO errors into source errors with policy context.
@@ -2,7 +2,7 @@
 // ============================================================================
 // Module: Decision Gate HTTP Source
 // Description: HTTP-backed source for external payload resolution.
-// Purpose: Fetch payload bytes via HTTP GET.
+// Purpose: Fetch payload bytes via HTTP GET and metadata via HEAD.
 // Dependencies: decision-gate-core, reqwest, url
 // ============================================================================
 
@@ -29,7 +29,10 @@ use std::net::ToSocketAddrs;
 use std::time::Duration;
 
 use decision_gate_core::ContentRef;
+use reqwest::Method;
 use reqwest::blocking::Client;
+use reqwest::blocking::Response;
+use reqwest::header::CONTENT_LENGTH;
 use reqwest::header::CONTENT_TYPE;
 use reqwest::redirect::Policy;
 use url::Host;
@@ -37,6 +40,7 @@ use url::Url;
 
 use crate::source::Source;
 use crate::source::SourceError;
+use crate::source::SourceMetadata;
 use crate::source::SourcePayload;
 use crate::source::enforce_max_bytes;
 use crate::source::max_source_bytes_u64;
@@ -335,6 +339,49 @@ impl HttpSource {
 
 impl Source for HttpSource {
     fn fetch(&self, content_ref: &ContentRef) -> Result<SourcePayload, SourceError> {
+        let response = self.send(content_ref, &Method::GET)?;
+        let max_bytes = max_source_bytes_u64()?;
+        if let Some(length) = response.content_length()
+            && length > max_bytes
+        {
+            let actual_bytes = usize::try_from(length).unwrap_or(usize::MAX);
+            return Err(SourceError::TooLarge {
+                max_bytes: crate::source::MAX_SOURCE_BYTES,
+                actual_bytes,
+            });
+        }
+        let content_type = response_content_type(&response);
+        let limit = max_bytes.checked_add(1).ok_or(SourceError::LimitOverflow {
+            limit: crate::source::MAX_SOURCE_BYTES,
+        })?;
+        let mut limited = response.take(limit);
+        let mut bytes = Vec::new();
+        limited.read_to_end(&mut bytes).map_err(|err| SourceError::Http(err.to_string()))?;
+        enforce_max_bytes(bytes.len())?;
+        Ok(SourcePayload {
+            bytes,
+            content_type,
+        })
+    }
+
+    fn metadata(&self, content_ref: &ContentRef) -> Result<SourceMetadata, SourceError> {
+        let response = self.send(content_ref, &Method::HEAD)?;
+        // HEAD responses have no body, so read the declared length from the header.
+        let size_bytes = response
+            .headers()
+            .get(CONTENT_LENGTH)
+            .and_then(|value| value.to_str().ok())
+            .and_then(|value| value.trim().parse::<u64>().ok());
+        Ok(SourceMetadata {
+            size_bytes,
+            content_type: response_content_type(&response),
+        })
+    }
+}
+
+impl HttpSource {
+    /// Sends a policy-checked request pinned to each resolved IP in turn.
+    fn send(&self, content_ref: &ContentRef, method: &Method) -> Result<Response, SourceError> {
         let url =
             Url::parse(&content_ref.uri).map_err(|err| SourceError::InvalidUri(err.to_string()))?;
         match url.scheme() {
@@ -352,7 +399,7 @@ impl Source for HttpSource {
                     continue;
                 }
             };
-            let response = match client.get(url.as_str()).send() {
+            let response = match client.request(method.clone(), url.as_str()).send() {
                 Ok(response) => response,
                 Err(err) => {
                     last_error = Some(SourceError::Http(err.to_string()));
@@ -371,42 +418,14 @@ impl Source for HttpSource {
             }
             // Re-validate the pinned peer IP before accepting the response.
             self.policy.enforce_ip_policy(&resolved.host_label, ip)?;
-
-            let max_bytes = max_source_bytes_u64()?;
-            if let Some(length) = response.content_length()
-                && length > max_bytes
-            {
-                let actual_bytes = usize::try_from(length).unwrap_or(usize::MAX);
-                return Err(SourceError::TooLarge {
-                    max_bytes: crate::source::MAX_SOURCE_BYTES,
-                    actual_bytes,
-                });
-            }
-            let content_type = response
-                .headers()
-                .get(CONTENT_TYPE)
-                .and_then(|value| value.to_str().ok())
-                .map(str::to_string);
-            let limit = max_bytes.checked_add(1).ok_or(SourceError::LimitOverflow {
-                limit: crate::source::MAX_SOURCE_BYTES,
-            })?;
-            let mut limited = response.take(limit);
-            let mut bytes = Vec::new();
-            limited.read_to_end(&mut bytes).map_err(|err| SourceError::Http(err.to_string()))?;
-            enforce_max_bytes(bytes.len())?;
-            return Ok(SourcePayload {
-                bytes,
-                content_type,
-            });
+            return Ok(response);
         }
 
         Err(last_error.unwrap_or_else(|| {
             SourceError::Http("request failed for all resolved IPs".to_string())
         }))
     }
-}
 
-impl HttpSource {
     /// Builds a client pinned to the provided IP when required.
     fn client_for_ip(&self, resolved: &ResolvedHost, ip: IpAddr) -> Result<Client, SourceError> {
         let socket_addr = SocketAddr::new(ip, resolved.port);
@@ -422,6 +441,11 @@ impl HttpSource {
     }
 }
 
+/// Returns the response content type header, if present and valid.
+fn response_content_type(response: &Response) -> Option<String> {
+    response.headers().get(CONTENT_TYPE).and_then(|value| value.to_str().ok()).map(str::to_string)
+}
+
 // ============================================================================
 // SECTION: Resolved Host
 // ============================================================================
@@ -8,9 +8,11 @@
 
 //! ## Overview
 //! [`InlineSource`] resolves `inline:` URIs that embed payload bytes directly.
-//! Supported prefixes: `inline+json:`, `inline+bytes:`, and `inline:`.
+//! Supported prefixes: `inline+json:`, `inline+bytes:`, and `inline:` (base64),
+//! plus the content-addressed `inline+base64:` and `inline+hex:` encodings.
 //! Invariants:
-//! - Base64 payloads are size-checked before and after decoding.
+//! - Encoded payloads are size-checked before and after decoding.
+//! - Content-addressed payloads must match the content reference hash.
 //!
 //! Security posture: treats inline payloads as untrusted input; see
 //! `Docs/security/threat_model.md`.
@@ -22,6 +24,7 @@
 use base64::Engine;
 use base64::engine::general_purpose::STANDARD;
 use decision_gate_core::ContentRef;
+use decision_gate_core::hashing::hash_bytes;
 
 use crate::source::Source;
 use crate::source::SourceError;
@@ -56,11 +59,56 @@ impl InlineSource {
         enforce_max_bytes(bytes.len())?;
         Ok(bytes)
     }
+
+    /// Decodes a hex-encoded payload, rejecting odd lengths and non-hex digits.
+    fn decode_hex(encoded: &str) -> Result<Vec<u8>, SourceError> {
+        enforce_max_bytes(encoded.len() / 2)?;
+        if !encoded.len().is_multiple_of(2) {
+            return Err(SourceError::Decode("hex payload has odd length".to_string()));
+        }
+        encoded
+            .as_bytes()
+            .chunks_exact(2)
+            .map(|pair| match (hex_value(pair[0]), hex_value(pair[1])) {
+                (Some(high), Some(low)) => Ok((high << 4) | low),
+                _ => Err(SourceError::Decode("invalid hex digit".to_string())),
+            })
+            .collect()
+    }
+
+    /// Verifies decoded bytes against the content reference hash.
+    fn verify_hash(bytes: &[u8], content_ref: &ContentRef) -> Result<(), SourceError> {
+        let expected = &content_ref.content_hash;
+        let actual = hash_bytes(expected.algorithm, bytes);
+        if actual.value != expected.value {
+            return Err(SourceError::HashMismatch {
+                expected: expected.value.clone(),
+                actual: actual.value,
+            });
+        }
+        Ok(())
+    }
 }
 
 impl Source for InlineSource {
     fn fetch(&self, content_ref: &ContentRef) -> Result<SourcePayload, SourceError> {
         let uri = content_ref.uri.as_str();
+        if let Some(encoded) = uri.strip_prefix("inline+base64:") {
+            let bytes = Self::decode_base64(encoded)?;
+            Self::verify_hash(&bytes, content_ref)?;
+            return Ok(SourcePayload {
+                bytes,
+                content_type: Some("application/octet-stream".to_string()),
+            });
+        }
+        if let Some(encoded) = uri.strip_prefix("inline+hex:") {
+            let bytes = Self::decode_hex(encoded)?;
+            Self::verify_hash(&bytes, content_ref)?;
+            return Ok(SourcePayload {
+                bytes,
+                content_type: Some("application/octet-stream".to_string()),
+            });
+        }
         if let Some(encoded) = uri.strip_prefix("inline+json:") {
             let bytes = Self::decode_base64(encoded)?;
             return Ok(SourcePayload {
@@ -85,3 +133,17 @@ impl Source for InlineSource {
         Err(SourceError::UnsupportedScheme("inline".to_string()))
     }
 }
+
+// ============================================================================
+// SECTION: Helpers
+// ============================================================================
+
+/// Returns the value of an ASCII hex digit.
+const fn hex_value(digit: u8) -> Option<u8> {
+    match digit {
+        b'0' ..= b'9' => Some(digit - b'0'),
+        b'a' ..= b'f' => Some(digit - b'a' + 10),
+        b'A' ..= b'F' => Some(digit - b'A' + 10),
+        _ => None,
+    }
+}
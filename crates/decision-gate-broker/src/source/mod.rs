@@ -21,7 +21,13 @@
 // ============================================================================
 
 use decision_gate_core::ContentRef;
+#[cfg(any(feature = "nats", feature = "s3"))]
+use decision_gate_core::hashing::hash_bytes;
+#[cfg(any(feature = "nats", feature = "s3"))]
+use decision_gate_core::hashing::hash_canonical_json;
 use decision_gate_core::runtime::MAX_PAYLOAD_BYTES;
+#[cfg(any(feature = "nats", feature = "s3"))]
+use serde_json::Value;
 use thiserror::Error;
 
 // ============================================================================
@@ -41,6 +47,19 @@ pub struct SourcePayload {
     pub content_type: Option<String>,
 }
 
+/// Metadata describing a content reference without its payload bytes.
+///
+/// # Invariants
+/// - Returned only when the referenced content exists.
+/// - `size_bytes` is `None` when the source cannot determine the size.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SourceMetadata {
+    /// Payload size in bytes, if known.
+    pub size_bytes: Option<u64>,
+    /// Optional content type hint.
+    pub content_type: Option<String>,
+}
+
 // ============================================================================
 // SECTION: Constants
 // ============================================================================
@@ -87,6 +106,14 @@ pub enum SourceError {
         /// Actual payload size in bytes.
         actual_bytes: usize,
     },
+    /// Resolved bytes do not match the content reference hash.
+    #[error("content hash mismatch: expected {expected}, got {actual}")]
+    HashMismatch {
+        /// Expected hash value.
+        expected: String,
+        /// Actual hash value.
+        actual: String,
+    },
     /// Configured byte limit cannot be represented for I/O operations.
     #[error("payload size limit exceeds platform bounds: {limit} bytes")]
     LimitOverflow {
@@ -113,6 +140,32 @@ pub(crate) fn max_source_bytes_u64() -> Result<u64, SourceError> {
     })
 }
 
+/// Verifies object bytes against the content reference hash.
+///
+/// Bytes match when their raw hash or, for JSON, their canonical JSON hash
+/// equals the expected value.
+#[cfg(any(feature = "nats", feature = "s3"))]
+pub(crate) fn verify_content_hash(
+    bytes: &[u8],
+    content_ref: &ContentRef,
+) -> Result<(), SourceError> {
+    let expected = &content_ref.content_hash;
+    let actual = hash_bytes(expected.algorithm, bytes);
+    if actual.value == expected.value {
+        return Ok(());
+    }
+    if let Ok(value) = serde_json::from_slice::<Value>(bytes)
+        && let Ok(canonical) = hash_canonical_json(expected.algorithm, &value)
+        && canonical.value == expected.value
+    {
+        return Ok(());
+    }
+    Err(SourceError::HashMismatch {
+        expected: expected.value.clone(),
+        actual: actual.value,
+    })
+}
+
 // ============================================================================
 // SECTION: Source Trait
 // ============================================================================
@@ -125,6 +178,22 @@ pub trait Source: Send + Sync {
     ///
     /// Returns [`SourceError`] when the content cannot be resolved.
     fn fetch(&self, content_ref: &ContentRef) -> Result<SourcePayload, SourceError>;
+
+    /// Returns size/type metadata for the content reference without keeping the payload.
+    ///
+    /// The default implementation fetches and discards the payload; sources that
+    /// support metadata-only lookups (HTTP HEAD, file stat) should override it.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`SourceError`] when the content cannot be resolved.
+    fn metadata(&self, content_ref: &ContentRef) -> Result<SourceMetadata, SourceError> {
+        let payload = self.fetch(content_ref)?;
+        Ok(SourceMetadata {
+            size_bytes: u64::try_from(payload.bytes.len()).ok(),
+            content_type: payload.content_type,
+        })
+    }
 }
 
 // ============================================================================
@@ -134,8 +203,18 @@ pub trait Source: Send + Sync {
 pub mod file;
 pub mod http;
 pub mod inline;
+#[cfg(feature = "nats")]
+pub mod nats;
+#[cfg(feature = "s3")]
+pub mod s3;
 
 pub use file::FileSource;
 pub use http::HttpSource;
 pub use http::HttpSourcePolicy;
 pub use inline::InlineSource;
+#[cfg(feature = "nats")]
+pub use nats::NatsSource;
+#[cfg(feature = "s3")]
+pub use s3::S3Source;
+#[cfg(feature = "s3")]
+pub use s3::S3SourceConfig;
@@ -0,0 +1,160 @@
+// crates/decision-gate-broker/src/source/nats.rs
+// ============================================================================
+// Module: Decision Gate NATS Source
+// Description: JetStream object-store source for external payload resolution.
+// Purpose: Fetch payload bytes from allowlisted NATS object-store buckets.
+// Dependencies: decision-gate-core, url
+// ============================================================================
+
+//! ## Overview
+//! [`NatsSource`] resolves `nats://<bucket>/<object>` URIs through a
+//! [`NatsConnection`]. Reads are authorized against the bucket subject
+//! `$O.<bucket>`, so `allow_subjects(["$O.evidence"])` permits the `evidence`
+//! bucket and `$O.*` permits every bucket.
+//! Invariants:
+//! - Policy checks run before any object is requested.
+//! - Payload bytes are capped at [`crate::source::MAX_SOURCE_BYTES`] or a lower
+//!   per-source limit.
+//! - Resolved bytes must match the content reference hash, either directly or as canonical JSON.
+//!
+//! Security posture: object names come from untrusted content references; see
+//! `Docs/security/threat_model.md`.
+
+// ============================================================================
+// SECTION: Imports
+// ============================================================================
+
+use std::time::Duration;
+
+use decision_gate_core::ContentRef;
+use url::Url;
+
+use crate::nats::DEFAULT_NATS_TIMEOUT;
+use crate::nats::NatsConnection;
+use crate::nats::NatsError;
+use crate::nats::NatsSubjectPolicy;
+use crate::source::MAX_SOURCE_BYTES;
+use crate::source::Source;
+use crate::source::SourceError;
+use crate::source::SourcePayload;
+use crate::source::verify_content_hash;
+
+// ============================================================================
+// SECTION: NATS Source
+// ============================================================================
+
+/// `JetStream` object-store payload source.
+///
+/// # Invariants
+/// - Buckets are readable only when `$O.<bucket>` is allowlisted.
+pub struct NatsSource<C: NatsConnection> {
+    /// Connection used to fetch objects.
+    connection: C,
+    /// Bucket subject allowlist.
+    policy: NatsSubjectPolicy,
+    /// Object fetch timeout.
+    timeout: Duration,
+    /// Maximum object size, at most [`MAX_SOURCE_BYTES`].
+    max_bytes: usize,
+}
+
+impl<C: NatsConnection> NatsSource<C> {
+    /// Creates a source restricted by the provided subject policy.
+    #[must_use]
+    pub const fn new(connection: C, policy: NatsSubjectPolicy) -> Self {
+        Self {
+            connection,
+            policy,
+            timeout: DEFAULT_NATS_TIMEOUT,
+            max_bytes: MAX_SOURCE_BYTES,
+        }
+    }
+
+    /// Sets the object fetch timeout.
+    #[must_use]
+    pub const fn with_timeout(mut self, timeout: Duration) -> Self {
+        self.timeout = timeout;
+        self
+    }
+
+    /// Lowers the maximum object size; values above [`MAX_SOURCE_BYTES`] are clamped.
+    #[must_use]
+    pub const fn with_max_bytes(mut self, max_bytes: usize) -> Self {
+        self.max_bytes = if max_bytes < MAX_SOURCE_BYTES { max_bytes } else { MAX_SOURCE_BYTES };
+        self
+    }
+}
+
+impl<C: NatsConnection> Source for NatsSource<C> {
+    fn fetch(&self, content_ref: &ContentRef) -> Result<SourcePayload, SourceError> {
+        let (bucket, name) = parse_object_uri(&content_ref.uri)?;
+        let subject = format!("$O.{bucket}");
+        if !self.policy.is_allowed(&subject) {
+            return Err(SourceError::Policy(format!("nats bucket not in allowlist: {bucket}")));
+        }
+        let object = self
+            .connection
+            .get_object(&bucket, &name, self.max_bytes, self.timeout)
+            .map_err(map_nats_error)?;
+        if object.bytes.len() > self.max_bytes {
+            return Err(SourceError::TooLarge {
+                max_bytes: self.max_bytes,
+                actual_bytes: object.bytes.len(),
+            });
+        }
+        verify_content_hash(&object.bytes, content_ref)?;
+        Ok(SourcePayload {
+            bytes: object.bytes,
+            content_type: object.content_type,
+        })
+    }
+}
+
+// ============================================================================
+// SECTION: Helpers
+// ============================================================================
+
+/// Parses a `nats://<bucket>/<object>` URI into bucket and object name.
+fn parse_object_uri(uri: &str) -> Result<(String, String), SourceError> {
+    let url = Url::parse(uri).map_err(|err| SourceError::InvalidUri(err.to_string()))?;
+    if url.scheme() != "nats" {
+        return Err(SourceError::UnsupportedScheme(url.scheme().to_string()));
+    }
+    if !url.username().is_empty() || url.password().is_some() || url.port().is_some() {
+        return Err(SourceError::InvalidUri("nats uri must not carry credentials or port".into()));
+    }
+    if url.query().is_some() || url.fragment().is_some() {
+        return Err(SourceError::InvalidUri("nats uri must not carry query or fragment".into()));
+    }
+    let bucket = url.host_str().unwrap_or_default();
+    if bucket.is_empty()
+        || !bucket.bytes().all(|byte| byte.is_ascii_alphanumeric() || matches!(byte, b'_' | b'-'))
+    {
+        return Err(SourceError::InvalidUri(format!("invalid nats bucket: {bucket:?}")));
+    }
+    let name = url.path().trim_start_matches('/');
+    if name.is_empty() {
+        return Err(SourceError::InvalidUri("nats object name is empty".to_string()));
+    }
+    if name.contains('%') {
+        return Err(SourceError::InvalidUri(
+            "percent-encoded nats object names are not supported".into(),
+        ));
+    }
+    Ok((bucket.to_string(), name.to_string()))
+}
+
+/// Maps NATS connection errors to source errors.
+fn map_nats_error(err: NatsError) -> SourceError {
+    match err {
+        NatsError::NotFound(name) => SourceError::NotFound(name),
+        NatsError::TooLarge {
+            max_bytes,
+            actual_bytes,
+        } => SourceError::TooLarge {
+            max_bytes,
+            actual_bytes,
+        },
+        NatsError::Timeout(_) | NatsError::Other(_) => SourceError::Io(err.to_string()),
+    }
+}
@@ -0,0 +1,307 @@
+// crates/decision-gate-broker/src/source/s3.rs
+// ============================================================================
+// Module: Decision Gate S3 Source
+// Description: S3 object source for external payload resolution.
+// Purpose: Fetch payload bytes from allowlisted S3 buckets.
+// Dependencies: aws-config, aws-sdk-s3, decision-gate-core, tokio, url
+// ============================================================================
+
+//! ## Overview
+//! [`S3Source`] resolves `s3://<bucket>/<key>` URIs with the AWS SDK. Region,
+//! endpoint, and profile come from [`S3SourceConfig`]; credentials are never
+//! part of the config and resolve through the AWS default provider chain
+//! (environment, shared profile, or instance metadata) unless a provider is
+//! supplied with [`S3Source::with_credentials_provider`].
+//! Invariants:
+//! - Buckets must be allowlisted; an empty allowlist denies every request.
+//! - Policy checks run before any object is requested.
+//! - Payload bytes are capped at [`crate::source::MAX_SOURCE_BYTES`].
+//! - Resolved bytes must match the content reference hash, either directly or as canonical JSON.
+//!
+//! Security posture: bucket and key names come from untrusted content
+//! references; see `Docs/security/threat_model.md`.
+
+// ============================================================================
+// SECTION: Imports
+// ============================================================================
+
+use std::collections::BTreeSet;
+use std::future::Future;
+use std::sync::Arc;
+
+use aws_config::BehaviorVersion;
+use aws_config::Region;
+use aws_sdk_s3::Client;
+use aws_sdk_s3::config::ProvideCredentials;
+use aws_sdk_s3::config::SharedCredentialsProvider;
+use aws_sdk_s3::config::http::HttpResponse;
+use aws_sdk_s3::error::DisplayErrorContext;
+use aws_sdk_s3::error::SdkError;
+use aws_sdk_s3::operation::get_object::GetObjectError;
+use decision_gate_core::ContentRef;
+use tokio::runtime::Handle;
+use tokio::runtime::Runtime;
+use tokio::runtime::RuntimeFlavor;
+use url::Url;
+
+use crate::source::Source;
+use crate::source::SourceError;
+use crate::source::SourceMetadata;
+use crate::source::SourcePayload;
+use crate::source::enforce_max_bytes;
+use crate::source::verify_content_hash;
+
+// ============================================================================
+// SECTION: Configuration
+// ============================================================================
+
+/// Connection settings for [`S3Source`].
+///
+/// # Invariants
+/// - Holds no secrets; credentials resolve through the AWS provider chain.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct S3SourceConfig {
+    /// AWS region; `None` uses the provider chain default.
+    pub region: Option<String>,
+    /// Endpoint override for S3-compatible stores.
+    pub endpoint: Option<String>,
+    /// Uses path-style (`<endpoint>/<bucket>/<key>`) addressing when true.
+    pub force_path_style: bool,
+    /// Shared config profile used for region and credential lookup.
+    pub profile: Option<String>,
+}
+
+// ============================================================================
+// SECTION: S3 Source
+// ============================================================================
+
+/// S3 object payload source.
+///
+/// # Invariants
+/// - Buckets are readable only when listed via [`S3Source::allow_buckets`].
+pub struct S3Source {
+    /// Underlying S3 client.
+    client: Client,
+    /// Bucket allowlist.
+    allowed_buckets: BTreeSet<String>,
+    /// Tokio runtime for blocking S3 operations.
+    runtime: Option<Arc<Runtime>>,
+}
+
+impl Drop for S3Source {
+    fn drop(&mut self) {
+        if let Some(runtime) = self.runtime.take() {
+            let _ = std::thread::spawn(move || drop(runtime));
+        }
+    }
+}
+
+impl S3Source {
+    /// Creates a source using credentials from the AWS default provider chain.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`SourceError::Io`] when the client runtime cannot start.
+    pub fn new(config: &S3SourceConfig) -> Result<Self, SourceError> {
+        Self::build(config, None)
+    }
+
+    /// Creates a source using the provided credentials provider.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`SourceError::Io`] when the client runtime cannot start.
+    pub fn with_credentials_provider(
+        config: &S3SourceConfig,
+        provider: impl ProvideCredentials + 'static,
+    ) -> Result<Self, SourceError> {
+        Self::build(config, Some(SharedCredentialsProvider::new(provider)))
+    }
+
+    /// Allows reads from the listed buckets.
+    #[must_use]
+    pub fn allow_buckets<I, S>(mut self, buckets: I) -> Self
+    where
+        I: IntoIterator<Item = S>,
+        S: Into<String>,
+    {
+        self.allowed_buckets.extend(buckets.into_iter().map(Into::into));
+        self
+    }
+
+    /// Builds the S3 client from config and an optional credentials provider.
+    fn build(
+        config: &S3SourceConfig,
+        credentials: Option<SharedCredentialsProvider>,
+    ) -> Result<Self, SourceError> {
+        let runtime = Runtime::new().map_err(|err| SourceError::Io(err.to_string()))?;
+        let region = config.region.clone();
+        let endpoint = config.endpoint.clone();
+        let profile = config.profile.clone();
+        let shared_config = block_on_with_runtime(&runtime, async move {
+            let mut loader = aws_config::defaults(BehaviorVersion::latest());
+            if let Some(profile) = profile {
+                loader = loader.profile_name(profile);
+            }
+            if let Some(region) = region {
+                loader = loader.region(Region::new(region));
+            }
+            if let Some(endpoint) = endpoint {
+                loader = loader.endpoint_url(endpoint);
+            }
+            if let Some(credentials) = credentials {
+                loader = loader.credentials_provider(credentials);
+            }
+            Ok(loader.load().await)
+        })?;
+        let mut s3_builder = aws_sdk_s3::config::Builder::from(&shared_config);
+        if config.force_path_style {
+            s3_builder = s3_builder.force_path_style(true);
+        }
+        Ok(Self {
+            client: Client::from_conf(s3_builder.build()),
+            allowed_buckets: BTreeSet::new(),
+            runtime: Some(Arc::new(runtime)),
+        })
+    }
+
+    /// Parses the URI and checks the bucket against the allowlist.
+    fn authorize(&self, uri: &str) -> Result<(String, String), SourceError> {
+        let (bucket, key) = parse_object_uri(uri)?;
+        if !self.allowed_buckets.contains(&bucket) {
+            return Err(SourceError::Policy(format!("s3 bucket not in allowlist: {bucket}")));
+        }
+        Ok((bucket, key))
+    }
+
+    /// Returns the runtime or an error if shut down.
+    fn runtime(&self) -> Result<&Runtime, SourceError> {
+        self.runtime
+            .as_ref()
+            .map(AsRef::as_ref)
+            .ok_or_else(|| SourceError::Io("s3 source runtime closed".to_string()))
+    }
+}
+
+impl Source for S3Source {
+    fn fetch(&self, content_ref: &ContentRef) -> Result<SourcePayload, SourceError> {
+        let (bucket, key) = self.authorize(&content_ref.uri)?;
+        let client = self.client.clone();
+        let (bytes, content_type) = block_on_with_runtime(self.runtime()?, async move {
+            let output =
+                client.get_object().bucket(&bucket).key(&key).send().await.map_err(|err| {
+                    if is_not_found(&err)
+                        || err.as_service_error().is_some_and(GetObjectError::is_no_such_key)
+                    {
+                        SourceError::NotFound(format!("s3://{bucket}/{key}"))
+                    } else {
+                        SourceError::Io(DisplayErrorContext(&err).to_string())
+                    }
+                })?;
+            if let Some(length) = output.content_length() {
+                enforce_max_bytes(usize::try_from(length).unwrap_or(usize::MAX))?;
+            }
+            let content_type = output.content_type().map(str::to_string);
+            let mut body = output.body;
+            let mut bytes = Vec::new();
+            while let Some(chunk) =
+                body.try_next().await.map_err(|err| SourceError::Io(err.to_string()))?
+            {
+                enforce_max_bytes(bytes.len().saturating_add(chunk.len()))?;
+                bytes.extend_from_slice(&chunk);
+            }
+            Ok((bytes, content_type))
+        })?;
+        enforce_max_bytes(bytes.len())?;
+        verify_content_hash(&bytes, content_ref)?;
+        Ok(SourcePayload {
+            bytes,
+            content_type,
+        })
+    }
+
+    fn metadata(&self, content_ref: &ContentRef) -> Result<SourceMetadata, SourceError> {
+        let (bucket, key) = self.authorize(&content_ref.uri)?;
+        let client = self.client.clone();
+        block_on_with_runtime(self.runtime()?, async move {
+            let output =
+                client.head_object().bucket(&bucket).key(&key).send().await.map_err(|err| {
+                    if is_not_found(&err) {
+                        SourceError::NotFound(format!("s3://{bucket}/{key}"))
+                    } else {
+                        SourceError::Io(DisplayErrorContext(&err).to_string())
+                    }
+                })?;
+            Ok(SourceMetadata {
+                size_bytes: output.content_length().and_then(|length| u64::try_from(length).ok()),
+                content_type: output.content_type().map(str::to_string),
+            })
+        })
+    }
+}
+
+// ============================================================================
+// SECTION: Helpers
+// ============================================================================
+
+/// Blocks on an S3 future using a compatible runtime.
+fn block_on_with_runtime<F, T>(runtime: &Runtime, future: F) -> Result<T, SourceError>
+where
+    F: Future<Output = Result<T, SourceError>> + Send + 'static,
+    T: Send + 'static,
+{
+    if let Ok(handle) = Handle::try_current() {
+        if matches!(handle.runtime_flavor(), RuntimeFlavor::MultiThread) {
+            return tokio::task::block_in_place(|| handle.block_on(future));
+        }
+        let (tx, rx) = std::sync::mpsc::sync_channel(1);
+        std::thread::spawn(move || {
+            let result = Runtime::new()
+                .map_err(|err| SourceError::Io(err.to_string()))
+                .and_then(|runtime| runtime.block_on(future));
+            let _ = tx.send(result);
+        });
+        return rx
+            .recv()
+            .unwrap_or_else(|_| Err(SourceError::Io("s3 source thread join failed".to_string())));
+    }
+
+    runtime.block_on(future)
+}
+
+/// Returns true when the S3 response status was 404.
+fn is_not_found<E>(err: &SdkError<E, HttpResponse>) -> bool {
+    err.raw_response().is_some_and(|response| response.status().as_u16() == 404)
+}
+
+/// Parses an `s3://<bucket>/<key>` URI into bucket and object key.
+fn parse_object_uri(uri: &str) -> Result<(String, String), SourceError> {
+    let url = Url::parse(uri).map_err(|err| SourceError::InvalidUri(err.to_string()))?;
+    if url.scheme() != "s3" {
+        return Err(SourceError::UnsupportedScheme(url.scheme().to_string()));
+    }
+    if !url.username().is_empty() || url.password().is_some() || url.port().is_some() {
+        return Err(SourceError::InvalidUri("s3 uri must not carry credentials or port".into()));
+    }
+    if url.query().is_some() || url.fragment().is_some() {
+        return Err(SourceError::InvalidUri("s3 uri must not carry query or fragment".into()));
+    }
+    let bucket = url.host_str().unwrap_or_default();
+    if !(3 ..= 63).contains(&bucket.len())
+        || !bucket.bytes().all(|byte| {
+            byte.is_ascii_lowercase() || byte.is_ascii_digit() || matches!(byte, b'.' | b'-')
+        })
+    {
+        return Err(SourceError::InvalidUri(format!("invalid s3 bucket: {bucket:?}")));
+    }
+    let key = url.path().trim_start_matches('/');
+    if key.is_empty() {
+        return Err(SourceError::InvalidUri("s3 object key is empty".to_string()));
+    }
+    if key.contains('%') {
+        return Err(SourceError::InvalidUri(
+            "percent-encoded s3 object keys are not supported".into(),
+        ));
+    }
+    Ok((bucket.to_string(), key.to_string()))
+}
@@ -23,6 +23,10 @@
     reason = "Test-only output and panic-based assertions are permitted."
 )]
 
+use std::sync::Arc;
+use std::sync::atomic::AtomicUsize;
+use std::sync::atomic::Ordering;
+
 use base64::Engine;
 use base64::engine::general_purpose::STANDARD;
 use decision_gate_broker::BrokerError;
@@ -32,6 +36,9 @@ use decision_gate_broker::FileSource;
 use decision_gate_broker::InlineSource;
 use decision_gate_broker::PayloadBody;
 use decision_gate_broker::SinkError;
+use decision_gate_broker::Source;
+use decision_gate_broker::SourceError;
+use decision_gate_broker::SourcePayload;
 use decision_gate_core::ContentRef;
 use decision_gate_core::DispatchReceipt;
 use decision_gate_core::DispatchTarget;
@@ -915,3 +922,99 @@ fn broker_multiple_dispatches_same_payload() {
         assert!(result.is_ok());
     }
 }
+
+// ============================================================================
+// SECTION: Source Cache Tests
+// ============================================================================
+
+/// Source that serves fixed bytes and counts fetches.
+#[derive(Clone)]
+struct CountingSource {
+    fetches: Arc<AtomicUsize>,
+}
+
+impl Source for CountingSource {
+    fn fetch(&self, content_ref: &ContentRef) -> Result<SourcePayload, SourceError> {
+        self.fetches.fetch_add(1, Ordering::SeqCst);
+        let data = content_ref.uri.strip_prefix("counting:").unwrap_or_default();
+        Ok(SourcePayload {
+            bytes: data.as_bytes().to_vec(),
+            content_type: None,
+        })
+    }
+}
+
+/// Builds an external payload and envelope served by the counting source.
+fn counting_payload(data: &str) -> (PacketEnvelope, PacketPayload) {
+    let content_hash = hash_bytes(DEFAULT_HASH_ALGORITHM, data.as_bytes());
+    let envelope = sample_envelope("application/octet-stream", content_hash.clone());
+    let payload = PacketPayload::External {
+        content_ref: ContentRef {
+            uri: format!("counting:{data}"),
+            content_hash,
+            encryption: None,
+        },
+    };
+    (envelope, payload)
+}
+
+/// Tests a second resolve of the same content hash is served from the cache.
+#[test]
+fn broker_source_cache_hits_on_repeat_resolve() {
+    let fetches = Arc::new(AtomicUsize::new(0));
+    let broker = CompositeBroker::builder()
+        .source(
+            "counting",
+            CountingSource {
+                fetches: Arc::clone(&fetches),
+            },
+        )
+        .source_cache(1024)
+        .sink(success_sink())
+        .build()
+        .expect("build broker");
+
+    let (envelope, payload) = counting_payload("cached");
+    broker.dispatch(&sample_target(), &envelope, &payload).expect("first dispatch");
+    broker.dispatch(&sample_target(), &envelope, &payload).expect("second dispatch");
+    assert_eq!(fetches.load(Ordering::SeqCst), 1);
+
+    let (mismatched, _) = counting_payload("other");
+    let err = broker.dispatch(&sample_target(), &mismatched, &payload).unwrap_err();
+    assert!(err.to_string().contains("hash mismatch"), "{err}");
+}
+
+/// Tests cache eviction keeps total cached bytes within the budget.
+#[test]
+fn broker_source_cache_evicts_to_byte_budget() {
+    let fetches = Arc::new(AtomicUsize::new(0));
+    let broker = CompositeBroker::builder()
+        .source(
+            "counting",
+            CountingSource {
+                fetches: Arc::clone(&fetches),
+            },
+        )
+        .source_cache(10)
+        .sink(success_sink())
+        .build()
+        .expect("build broker");
+
+    let (envelope_a, payload_a) = counting_payload("aaaaaa");
+    let (envelope_b, payload_b) = counting_payload("bbbbbb");
+    broker.dispatch(&sample_target(), &envelope_a, &payload_a).expect("a");
+    broker.dispatch(&sample_target(), &envelope_a, &payload_a).expect("a cached");
+    assert_eq!(fetches.load(Ordering::SeqCst), 1);
+
+    broker.dispatch(&sample_target(), &envelope_b, &payload_b).expect("b evicts a");
+    broker.dispatch(&sample_target(), &envelope_b, &payload_b).expect("b cached");
+    assert_eq!(fetches.load(Ordering::SeqCst), 2);
+
+    broker.dispatch(&sample_target(), &envelope_a, &payload_a).expect("a refetched");
+    assert_eq!(fetches.load(Ordering::SeqCst), 3);
+
+    let (envelope_big, payload_big) = counting_payload("larger-than-budget");
+    broker.dispatch(&sample_target(), &envelope_big, &payload_big).expect("big");
+    broker.dispatch(&sample_target(), &envelope_big, &payload_big).expect("big uncached");
+    assert_eq!(fetches.load(Ordering::SeqCst), 5);
+}
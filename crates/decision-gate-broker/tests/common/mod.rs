@@ -23,10 +23,17 @@
     reason = "Test-only output and panic-based assertions are permitted."
 )]
 
+use std::collections::BTreeMap;
 use std::io::Write;
 use std::sync::Arc;
 use std::sync::Mutex;
+use std::time::Duration;
 
+use decision_gate_broker::NatsConnection;
+use decision_gate_broker::NatsError;
+use decision_gate_broker::NatsMessage;
+use decision_gate_broker::NatsObject;
+use decision_gate_broker::NatsPublishAck;
 use decision_gate_core::DispatchTarget;
 use decision_gate_core::PacketEnvelope;
 use decision_gate_core::PacketId;
@@ -179,3 +186,101 @@ impl Write for FailingWriter {
         Ok(())
     }
 }
+
+// ============================================================================
+// SECTION: Mock NATS Connection
+// ============================================================================
+
+/// In-memory NATS connection with a single stream and object store.
+#[derive(Clone, Default)]
+pub struct MockNatsConnection {
+    /// Acknowledged publishes in order.
+    published: Arc<Mutex<Vec<NatsMessage>>>,
+    /// Objects keyed by `(bucket, name)`.
+    objects: Arc<Mutex<BTreeMap<(String, String), NatsObject>>>,
+    /// Timeouts observed on each call.
+    timeouts: Arc<Mutex<Vec<Duration>>>,
+    /// When set, publishes are never acknowledged.
+    withhold_acks: bool,
+}
+
+impl MockNatsConnection {
+    /// Creates a connection whose publishes are never acknowledged.
+    pub fn without_acks() -> Self {
+        Self {
+            withhold_acks: true,
+            ..Self::default()
+        }
+    }
+
+    /// Stores an object in the mock object store.
+    pub fn put_object(&self, bucket: &str, name: &str, bytes: &[u8], content_type: Option<&str>) {
+        self.objects.lock().expect("objects lock").insert(
+            (bucket.to_string(), name.to_string()),
+            NatsObject {
+                bytes: bytes.to_vec(),
+                content_type: content_type.map(ToString::to_string),
+            },
+        );
+    }
+
+    /// Returns acknowledged publishes.
+    pub fn published(&self) -> Vec<NatsMessage> {
+        self.published.lock().expect("published lock").clone()
+    }
+
+    /// Returns timeouts passed by callers.
+    pub fn timeouts(&self) -> Vec<Duration> {
+        self.timeouts.lock().expect("timeouts lock").clone()
+    }
+}
+
+impl NatsConnection for MockNatsConnection {
+    fn publish(
+        &self,
+        message: NatsMessage,
+        timeout: Duration,
+    ) -> Result<NatsPublishAck, NatsError> {
+        self.timeouts.lock().expect("timeouts lock").push(timeout);
+        if self.withhold_acks {
+            return Err(NatsError::Timeout(timeout));
+        }
+        let mut published = self.published.lock().expect("published lock");
+        let msg_id = message.headers.iter().find(|(name, _)| name == "Nats-Msg-Id").cloned();
+        let duplicate = msg_id.is_some()
+            && published
+                .iter()
+                .any(|prior| prior.headers.iter().any(|header| Some(header) == msg_id.as_ref()));
+        if !duplicate {
+            published.push(message);
+        }
+        Ok(NatsPublishAck {
+            stream: "DISCLOSURES".to_string(),
+            sequence: published.len() as u64,
+            duplicate,
+        })
+    }
+
+    fn get_object(
+        &self,
+        bucket: &str,
+        name: &str,
+        max_bytes: usize,
+        timeout: Duration,
+    ) -> Result<NatsObject, NatsError> {
+        self.timeouts.lock().expect("timeouts lock").push(timeout);
+        let objects = self.objects.lock().expect("objects lock");
+        let object = objects
+            .get(&(bucket.to_string(), name.to_string()))
+            .cloned()
+            .ok_or_else(|| NatsError::NotFound(format!("{bucket}/{name}")))?;
+        drop(objects);
+        if object.bytes.len() > max_bytes {
+            return Err(NatsError::TooLarge {
+                max_bytes,
+                actual_bytes: object.bytes.len(),
+            });
+        }
+        Ok(object)
+    }
+}
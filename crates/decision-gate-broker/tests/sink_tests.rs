@@ -33,3 +33,9 @@ mod channel_tests;
 
 #[path = "sinks/log_tests.rs"]
 mod log_tests;
+
+#[path = "sinks/kafka_tests.rs"]
+mod kafka_tests;
+
+#[path = "sinks/nats_tests.rs"]
+mod nats_tests;
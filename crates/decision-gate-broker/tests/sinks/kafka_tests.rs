@@ -0,0 +1,199 @@
+// crates/decision-gate-broker/tests/sinks/kafka_tests.rs
+// ============================================================================
+// Module: KafkaSink Unit Tests
+// Description: Tests for the Kafka disclosure sink against a mock producer.
+// Purpose: Validate record keys, headers, broker acks, and receipt contents.
+// Dependencies: decision-gate-broker, decision-gate-core, serde_json
+// ============================================================================
+
+//! ## Overview
+//! Exercises [`decision_gate_broker::KafkaSink`] with an in-memory producer that
+//! assigns partitions by key and offsets per partition.
+
+#![allow(
+    clippy::panic,
+    clippy::print_stdout,
+    clippy::print_stderr,
+    clippy::unwrap_used,
+    clippy::expect_used,
+    clippy::use_debug,
+    clippy::dbg_macro,
+    clippy::panic_in_result_fn,
+    clippy::unwrap_in_result,
+    reason = "Test-only output and panic-based assertions are permitted."
+)]
+
+use std::collections::BTreeMap;
+use std::sync::Arc;
+use std::sync::Mutex;
+
+use decision_gate_broker::KafkaAck;
+use decision_gate_broker::KafkaProducer;
+use decision_gate_broker::KafkaRecord;
+use decision_gate_broker::KafkaSink;
+use decision_gate_broker::Payload;
+use decision_gate_broker::PayloadBody;
+use decision_gate_broker::Sink;
+use decision_gate_broker::SinkError;
+use decision_gate_core::Timestamp;
+use decision_gate_core::hashing::canonical_json_bytes;
+use serde_json::json;
+
+use super::common::sample_bytes_envelope;
+use super::common::sample_json_envelope;
+use super::common::sample_target;
+
+// ============================================================================
+// SECTION: Mock Producer
+// ============================================================================
+
+/// In-memory producer that acknowledges records like a single-broker cluster.
+#[derive(Clone, Default)]
+struct MockProducer {
+    /// Acknowledged records in produce order.
+    records: Arc<Mutex<Vec<KafkaRecord>>>,
+    /// Next offset per partition.
+    offsets: Arc<Mutex<BTreeMap<i32, i64>>>,
+    /// When set, the broker rejects every record.
+    reject: bool,
+    /// Timestamp reported in acks.
+    timestamp_ms: Option<i64>,
+}
+
+impl MockProducer {
+    /// Returns the acknowledged records.
+    fn records(&self) -> Vec<KafkaRecord> {
+        self.records.lock().expect("records lock").clone()
+    }
+}
+
+impl KafkaProducer for MockProducer {
+    fn produce(&self, record: KafkaRecord) -> Result<KafkaAck, SinkError> {
+        if self.reject {
+            return Err(SinkError::DeliveryFailed("broker rejected record".to_string()));
+        }
+        let partition = i32::from(record.key.iter().fold(0u8, |acc, byte| acc ^ byte) % 4);
+        let mut offsets = self.offsets.lock().expect("offsets lock");
+        let offset = offsets.entry(partition).or_insert(0);
+        let ack = KafkaAck {
+            partition,
+            offset: *offset,
+            timestamp_ms: self.timestamp_ms,
+        };
+        *offset += 1;
+        drop(offsets);
+        self.records.lock().expect("records lock").push(record);
+        Ok(ack)
+    }
+}
+
+/// Returns the value of a named header.
+fn header<'a>(record: &'a KafkaRecord, name: &str) -> &'a [u8] {
+    record
+        .headers
+        .iter()
+        .find_map(|(key, value)| (key == name).then_some(value.as_slice()))
+        .unwrap_or_else(|| panic!("missing header {name}"))
+}
+
+// ============================================================================
+// SECTION: Delivery Tests
+// ============================================================================
+
+/// Tests that payloads are produced with the content hash key and envelope headers.
+#[test]
+fn kafka_sink_produces_keyed_record_with_headers() {
+    let producer = MockProducer::default();
+    let sink = KafkaSink::new(producer.clone(), "dg.disclosures").expect("sink");
+    let payload = Payload {
+        envelope: sample_bytes_envelope(b"data"),
+        body: PayloadBody::Bytes(b"data".to_vec()),
+    };
+
+    let receipt = sink.deliver(&sample_target(), &payload).expect("deliver");
+    let records = producer.records();
+    assert_eq!(records.len(), 1);
+    let record = &records[0];
+    assert_eq!(record.topic, "dg.disclosures");
+    assert_eq!(record.key, payload.envelope.content_hash.value.as_bytes());
+    assert_eq!(record.value, b"data");
+    assert_eq!(header(record, "dg-run-id"), b"test-run");
+    assert_eq!(header(record, "dg-packet-id"), b"test-packet");
+    assert_eq!(header(record, "content-type"), b"application/octet-stream");
+    let target: serde_json::Value = serde_json::from_slice(header(record, "dg-target")).unwrap();
+    assert_eq!(target["agent_id"], "test-agent");
+
+    assert_eq!(receipt.dispatcher, "kafka");
+    assert_eq!(receipt.receipt_hash, payload.envelope.content_hash);
+    assert!(receipt.dispatch_id.starts_with("kafka-dg.disclosures-"), "{}", receipt.dispatch_id);
+    assert!(receipt.dispatch_id.ends_with("-0"), "first offset in partition");
+}
+
+/// Tests that JSON payloads are produced as canonical JSON bytes.
+#[test]
+fn kafka_sink_produces_canonical_json() {
+    let producer = MockProducer::default();
+    let sink = KafkaSink::new(producer.clone(), "disclosures").expect("sink");
+    let value = json!({"z": 1, "a": [true, null]});
+    let payload = Payload {
+        envelope: sample_json_envelope(&value),
+        body: PayloadBody::Json(value.clone()),
+    };
+
+    sink.deliver(&sample_target(), &payload).expect("deliver");
+    assert_eq!(producer.records()[0].value, canonical_json_bytes(&value).unwrap());
+}
+
+/// Tests that identical content shares a key, partition, and advancing offsets.
+#[test]
+fn kafka_sink_receipts_encode_partition_and_offset() {
+    let producer = MockProducer {
+        timestamp_ms: Some(1_700_000_000_000),
+        ..MockProducer::default()
+    };
+    let sink = KafkaSink::with_dispatcher(producer.clone(), "disclosures", "events").expect("sink");
+    let payload = Payload {
+        envelope: sample_bytes_envelope(b"same"),
+        body: PayloadBody::Bytes(b"same".to_vec()),
+    };
+
+    let first = sink.deliver(&sample_target(), &payload).expect("first");
+    let second = sink.deliver(&sample_target(), &payload).expect("second");
+    let records = producer.records();
+    assert_eq!(records[0].key, records[1].key);
+    let prefix = first.dispatch_id.trim_end_matches("-0");
+    assert_eq!(second.dispatch_id, format!("{prefix}-1"));
+    assert!(first.dispatch_id.starts_with("events-disclosures-"));
+    assert_eq!(first.dispatched_at, Timestamp::UnixMillis(1_700_000_000_000));
+}
+
+// ============================================================================
+// SECTION: Error Handling Tests
+// ============================================================================
+
+/// Tests that an unacknowledged record yields no receipt.
+#[test]
+fn kafka_sink_fails_without_broker_ack() {
+    let producer = MockProducer {
+        reject: true,
+        ..MockProducer::default()
+    };
+    let sink = KafkaSink::new(producer.clone(), "disclosures").expect("sink");
+    let payload = Payload {
+        envelope: sample_bytes_envelope(b"data"),
+        body: PayloadBody::Bytes(b"data".to_vec()),
+    };
+
+    let err = sink.deliver(&sample_target(), &payload).expect_err("nack fails delivery");
+    assert!(matches!(err, SinkError::DeliveryFailed(_)));
+    assert!(producer.records().is_empty());
+}
+
+/// Tests that invalid topic names are rejected at construction.
+#[test]
+fn kafka_sink_rejects_invalid_topic() {
+    for topic in ["", ".", "..", "has space", "slash/topic", &"t".repeat(250)] {
+        let result = KafkaSink::new(MockProducer::default(), topic);
+        assert!(matches!(result, Err(SinkError::InvalidConfig(_))), "topic {topic:?}");
+    }
+}
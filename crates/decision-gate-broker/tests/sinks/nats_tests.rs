@@ -0,0 +1,127 @@
+// crates/decision-gate-broker/tests/sinks/nats_tests.rs
+// ============================================================================
+// Module: NatsSink Unit Tests
+// Description: Tests for the NATS sink against a mock JetStream connection.
+// Purpose: Validate publish acks, subject allowlisting, headers, and timeouts.
+// Dependencies: decision-gate-broker, decision-gate-core
+// ============================================================================
+
+//! ## Overview
+//! Exercises [`decision_gate_broker::NatsSink`] publish-ack behavior.
+
+#![allow(
+    clippy::panic,
+    clippy::print_stdout,
+    clippy::print_stderr,
+    clippy::unwrap_used,
+    clippy::expect_used,
+    clippy::use_debug,
+    clippy::dbg_macro,
+    clippy::panic_in_result_fn,
+    clippy::unwrap_in_result,
+    reason = "Test-only output and panic-based assertions are permitted."
+)]
+
+use std::time::Duration;
+
+use decision_gate_broker::NatsSink;
+use decision_gate_broker::NatsSubjectPolicy;
+use decision_gate_broker::Payload;
+use decision_gate_broker::PayloadBody;
+use decision_gate_broker::Sink;
+use decision_gate_broker::SinkError;
+use decision_gate_core::DispatchTarget;
+
+use super::common::MockNatsConnection;
+use super::common::sample_bytes_envelope;
+use super::common::sample_target;
+
+/// Builds a bytes payload.
+fn bytes_payload(data: &[u8]) -> Payload {
+    Payload {
+        envelope: sample_bytes_envelope(data),
+        body: PayloadBody::Bytes(data.to_vec()),
+    }
+}
+
+/// Builds a NATS dispatch target for the subject.
+fn nats_target(subject: &str) -> DispatchTarget {
+    DispatchTarget::External {
+        system: "nats".to_string(),
+        target: subject.to_string(),
+    }
+}
+
+// ============================================================================
+// SECTION: Publish Tests
+// ============================================================================
+
+/// Tests that payloads are published with envelope headers and acked.
+#[test]
+fn nats_sink_publishes_with_ack() {
+    let connection = MockNatsConnection::default();
+    let sink = NatsSink::new(connection.clone(), "dg.disclosures").expect("sink");
+    let payload = bytes_payload(b"data");
+
+    let receipt = sink.deliver(&sample_target(), &payload).expect("deliver");
+    let published = connection.published();
+    assert_eq!(published.len(), 1);
+    assert_eq!(published[0].subject, "dg.disclosures");
+    assert_eq!(published[0].payload, b"data");
+    let msg_id = format!("test-run/test-packet/{}", payload.envelope.content_hash.value);
+    assert!(published[0].headers.contains(&("Nats-Msg-Id".to_string(), msg_id)));
+    assert!(published[0].headers.contains(&("Dg-Run-Id".to_string(), "test-run".to_string())));
+    assert_eq!(receipt.dispatch_id, "nats-DISCLOSURES-1");
+    assert_eq!(receipt.receipt_hash, payload.envelope.content_hash);
+}
+
+/// Tests that NATS targets select allowlisted subjects.
+#[test]
+fn nats_sink_routes_external_targets_through_allowlist() {
+    let connection = MockNatsConnection::default();
+    let sink = NatsSink::new(connection.clone(), "dg.disclosures")
+        .expect("sink")
+        .with_policy(NatsSubjectPolicy::new().allow_subjects(["dg.>"]))
+        .expect("policy");
+
+    sink.deliver(&nats_target("dg.team.alpha"), &bytes_payload(b"a")).expect("allowed");
+    let err = sink.deliver(&nats_target("ops.secrets"), &bytes_payload(b"b")).unwrap_err();
+    assert!(err.to_string().contains("not in allowlist"), "{err}");
+    let err = sink.deliver(&nats_target("dg.*"), &bytes_payload(b"c")).unwrap_err();
+    assert!(err.to_string().contains("not in allowlist"), "wildcards are not publishable");
+
+    let subjects: Vec<String> =
+        connection.published().into_iter().map(|message| message.subject).collect();
+    assert_eq!(subjects, vec!["dg.team.alpha".to_string()]);
+}
+
+// ============================================================================
+// SECTION: Error Handling Tests
+// ============================================================================
+
+/// Tests that a missing ack fails delivery and honors the configured timeout.
+#[test]
+fn nats_sink_fails_without_ack() {
+    let connection = MockNatsConnection::without_acks();
+    let sink = NatsSink::new(connection.clone(), "dg.disclosures")
+        .expect("sink")
+        .with_timeout(Duration::from_millis(250));
+
+    let err = sink.deliver(&sample_target(), &bytes_payload(b"data")).unwrap_err();
+    assert!(matches!(err, SinkError::DeliveryFailed(_)));
+    assert!(err.to_string().contains("timed out"), "{err}");
+    assert_eq!(connection.timeouts(), vec![Duration::from_millis(250)]);
+}
+
+/// Tests that malformed subjects and policies excluding the default are rejected.
+#[test]
+fn nats_sink_rejects_invalid_configuration() {
+    for subject in ["", "dg..x", "dg.*", "dg.>", "has space"] {
+        let result = NatsSink::new(MockNatsConnection::default(), subject);
+        assert!(matches!(result, Err(SinkError::InvalidConfig(_))), "subject {subject:?}");
+    }
+    let result = NatsSink::new(MockNatsConnection::default(), "dg.disclosures")
+        .expect("sink")
+        .with_policy(NatsSubjectPolicy::new().allow_subjects(["ops.>"]));
+    assert!(matches!(result, Err(SinkError::InvalidConfig(_))));
+}
@@ -33,3 +33,9 @@ mod http_tests;
 
 #[path = "sources/inline_tests.rs"]
 mod inline_tests;
+
+#[path = "sources/nats_tests.rs"]
+mod nats_tests;
+
+#[path = "sources/s3_tests.rs"]
+mod s3_tests;
@@ -351,3 +351,88 @@ fn file_source_rejects_symlink_escape() {
     let err = source.fetch(&content_ref).unwrap_err();
     assert!(matches!(err, SourceError::InvalidUri(_) | SourceError::Io(_)));
 }
+
+// ============================================================================
+// SECTION: Glob Resolution Tests
+// ============================================================================
+
+/// Tests file source glob resolves to the lexicographically-latest match.
+#[test]
+fn file_source_glob_resolves_latest_match() {
+    let dir = tempdir().expect("temp dir");
+    let logs = dir.path().join("logs");
+    std::fs::create_dir(&logs).expect("create logs");
+    std::fs::write(logs.join("app-2026-01-01.log"), b"old").expect("write old");
+    std::fs::write(logs.join("app-2026-03-01.log"), b"latest").expect("write latest");
+    std::fs::write(logs.join("app-2026-02-01.log"), b"middle").expect("write middle");
+    std::fs::write(logs.join("other-2027-01-01.log"), b"other").expect("write other");
+    std::fs::create_dir(logs.join("app-2099-01-01.log")).expect("create decoy dir");
+
+    let uri = Url::from_file_path(logs.join("app-*.log")).expect("file url").to_string();
+    let content_ref = ContentRef {
+        uri,
+        content_hash: hash_bytes(DEFAULT_HASH_ALGORITHM, b"latest"),
+        encryption: None,
+    };
+
+    let source = FileSource::new(dir.path()).with_glob_resolution();
+    let payload = source.fetch(&content_ref).expect("glob fetch");
+    assert_eq!(payload.bytes, b"latest");
+
+    let literal = FileSource::new(dir.path());
+    let err = literal.fetch(&content_ref).unwrap_err();
+    assert!(matches!(err, SourceError::NotFound(_)), "globs are opt-in");
+}
+
+/// Tests file source glob resolution still blocks traversal and unrooted use.
+#[test]
+fn file_source_glob_rejects_traversal() {
+    let dir = tempdir().expect("temp dir");
+    let safe_subdir = dir.path().join("safe");
+    std::fs::create_dir(&safe_subdir).expect("create subdir");
+    std::fs::write(dir.path().join("secret.txt"), b"secret").expect("write secret");
+
+    let traversal_path = safe_subdir.join("..").join("*.txt");
+    let content_ref = ContentRef {
+        uri: Url::from_file_path(&traversal_path).expect("file url").to_string(),
+        content_hash: hash_bytes(DEFAULT_HASH_ALGORITHM, b"secret"),
+        encryption: None,
+    };
+
+    let source = FileSource::new(&safe_subdir).with_glob_resolution();
+    let err = source.fetch(&content_ref).unwrap_err();
+    assert!(matches!(err, SourceError::InvalidUri(_)));
+    assert!(err.to_string().contains("escapes configured root"));
+
+    let unrooted = FileSource::unrestricted().with_glob_resolution();
+    let err = unrooted.fetch(&content_ref).unwrap_err();
+    assert!(matches!(err, SourceError::Policy(_)));
+}
+
+// ============================================================================
+// SECTION: Metadata Tests
+// ============================================================================
+
+/// Tests file source metadata stats the file without reading it.
+#[test]
+fn file_source_metadata_returns_size() {
+    let dir = tempdir().expect("temp dir");
+    let path = dir.path().join("payload.bin");
+    std::fs::write(&path, vec![7_u8; 4096]).expect("write file");
+
+    let content_ref = ContentRef {
+        uri: Url::from_file_path(&path).expect("file url").to_string(),
+        content_hash: hash_bytes(DEFAULT_HASH_ALGORITHM, b"unused"),
+        encryption: None,
+    };
+    let source = FileSource::new(dir.path());
+    let metadata = source.metadata(&content_ref).expect("file metadata");
+    assert_eq!(metadata.size_bytes, Some(4096));
+    assert!(metadata.content_type.is_none());
+
+    let missing = ContentRef {
+        uri: Url::from_file_path(dir.path().join("missing.bin")).expect("file url").to_string(),
+        ..content_ref
+    };
+    assert!(matches!(source.metadata(&missing), Err(SourceError::NotFound(_))));
+}
@@ -461,3 +461,34 @@ fn http_source_handles_redirect_codes_as_failure() {
 
     handle.join().expect("server thread");
 }
+
+// ============================================================================
+// SECTION: Metadata Tests
+// ============================================================================
+
+/// Tests http source metadata issues a HEAD and reports size and type.
+#[test]
+fn http_source_metadata_uses_head_request() {
+    let server = Server::http("127.0.0.1:0").expect("http server");
+    let addr = server.server_addr();
+
+    let handle = thread::spawn(move || {
+        let request = server.recv().expect("request");
+        let method = request.method().clone();
+        let response = Response::from_data(b"remote payload".to_vec())
+            .with_header(Header::from_bytes("Content-Type", "application/octet-stream").unwrap());
+        request.respond(response).expect("respond");
+        method
+    });
+
+    let content_ref = ContentRef {
+        uri: format!("http://{addr}/file.bin"),
+        content_hash: hash_bytes(DEFAULT_HASH_ALGORITHM, b"remote payload"),
+        encryption: None,
+    };
+    let metadata = local_source().metadata(&content_ref).expect("http metadata");
+
+    assert_eq!(metadata.size_bytes, Some(14));
+    assert_eq!(metadata.content_type.as_deref(), Some("application/octet-stream"));
+    assert_eq!(handle.join().expect("server thread"), tiny_http::Method::Head);
+}
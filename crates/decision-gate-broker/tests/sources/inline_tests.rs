@@ -378,3 +378,72 @@ fn inline_source_handles_base64_padding_variations() {
         assert_eq!(payload.bytes, data);
     }
 }
+
+// ============================================================================
+// SECTION: Content-Addressed Encoding Tests
+// ============================================================================
+
+/// Tests inline+base64 payloads round-trip with hash verification.
+#[test]
+fn inline_source_base64_scheme_round_trips() {
+    let data = [0_u8, 1, 2, 254, 255];
+    let content_ref = ContentRef {
+        uri: format!("inline+base64:{}", STANDARD.encode(data)),
+        content_hash: hash_bytes(DEFAULT_HASH_ALGORITHM, &data),
+        encryption: None,
+    };
+
+    let payload = InlineSource::new().fetch(&content_ref).expect("inline fetch");
+    assert_eq!(payload.bytes, data);
+    assert_eq!(payload.content_type.as_deref(), Some("application/octet-stream"));
+
+    let tampered = ContentRef {
+        content_hash: hash_bytes(DEFAULT_HASH_ALGORITHM, b"other"),
+        ..content_ref
+    };
+    let err = InlineSource::new().fetch(&tampered).unwrap_err();
+    assert!(matches!(err, SourceError::HashMismatch { .. }));
+}
+
+/// Tests inline+hex payloads round-trip and reject malformed digits.
+#[test]
+fn inline_source_hex_scheme_round_trips() {
+    let data = [0xde_u8, 0xad, 0xBE, 0xef, 0x00];
+    let content_hash = hash_bytes(DEFAULT_HASH_ALGORITHM, &data);
+    for encoded in ["deadbeef00", "DEADBEEF00"] {
+        let content_ref = ContentRef {
+            uri: format!("inline+hex:{encoded}"),
+            content_hash: content_hash.clone(),
+            encryption: None,
+        };
+        let payload = InlineSource::new().fetch(&content_ref).expect("inline fetch");
+        assert_eq!(payload.bytes, data);
+    }
+
+    for encoded in ["abc", "zz", "de ad", "0x00"] {
+        let content_ref = ContentRef {
+            uri: format!("inline+hex:{encoded}"),
+            content_hash: content_hash.clone(),
+            encryption: None,
+        };
+        let err = InlineSource::new().fetch(&content_ref).unwrap_err();
+        assert!(matches!(err, SourceError::Decode(_)), "encoded {encoded:?}");
+    }
+}
+
+/// Tests oversized content-addressed payloads are rejected before decoding.
+#[test]
+fn inline_source_rejects_oversized_encoded_payloads() {
+    let data = vec![0_u8; MAX_SOURCE_BYTES + 1];
+    let content_hash = hash_bytes(DEFAULT_HASH_ALGORITHM, &data);
+    let hex: String = "00".repeat(MAX_SOURCE_BYTES + 1);
+    for uri in [format!("inline+base64:{}", STANDARD.encode(&data)), format!("inline+hex:{hex}")] {
+        let content_ref = ContentRef {
+            uri,
+            content_hash: content_hash.clone(),
+            encryption: None,
+        };
+        let err = InlineSource::new().fetch(&content_ref).unwrap_err();
+        assert!(matches!(err, SourceError::TooLarge { .. }));
+    }
+}
@@ -0,0 +1,150 @@
+// crates/decision-gate-broker/tests/sources/nats_tests.rs
+// ============================================================================
+// Module: NatsSource Unit Tests
+// Description: Tests for the NATS object-store source against a mock connection.
+// Purpose: Validate object fetch, bucket allowlisting, hashing, and size caps.
+// Dependencies: decision-gate-broker, decision-gate-core, serde_json
+// ============================================================================
+
+//! ## Overview
+//! Exercises [`decision_gate_broker::NatsSource`] object resolution.
+
+#![allow(
+    clippy::panic,
+    clippy::print_stdout,
+    clippy::print_stderr,
+    clippy::unwrap_used,
+    clippy::expect_used,
+    clippy::use_debug,
+    clippy::dbg_macro,
+    clippy::panic_in_result_fn,
+    clippy::unwrap_in_result,
+    reason = "Test-only output and panic-based assertions are permitted."
+)]
+
+use std::time::Duration;
+
+use decision_gate_broker::MAX_SOURCE_BYTES;
+use decision_gate_broker::NatsSource;
+use decision_gate_broker::NatsSubjectPolicy;
+use decision_gate_broker::Source;
+use decision_gate_broker::SourceError;
+use decision_gate_core::ContentRef;
+use serde_json::json;
+
+use super::common::MockNatsConnection;
+use super::common::hash_for_bytes;
+use super::common::hash_for_json;
+
+/// Builds a content reference for the URI and bytes.
+fn content_ref(uri: &str, bytes: &[u8]) -> ContentRef {
+    ContentRef {
+        uri: uri.to_string(),
+        content_hash: hash_for_bytes(bytes),
+        encryption: None,
+    }
+}
+
+/// Builds a source that may read the `evidence` bucket.
+fn evidence_source(connection: &MockNatsConnection) -> NatsSource<MockNatsConnection> {
+    NatsSource::new(connection.clone(), NatsSubjectPolicy::new().allow_subjects(["$O.evidence"]))
+}
+
+// ============================================================================
+// SECTION: Success Path Tests
+// ============================================================================
+
+/// Tests that objects are fetched with content type and the configured timeout.
+#[test]
+fn nats_source_fetches_object() {
+    let connection = MockNatsConnection::default();
+    connection.put_object("evidence", "runs/run-1/report.bin", b"report", Some("text/plain"));
+    let source = evidence_source(&connection).with_timeout(Duration::from_millis(750));
+
+    let payload = source
+        .fetch(&content_ref("nats://evidence/runs/run-1/report.bin", b"report"))
+        .expect("fetch");
+    assert_eq!(payload.bytes, b"report");
+    assert_eq!(payload.content_type.as_deref(), Some("text/plain"));
+    assert_eq!(connection.timeouts(), vec![Duration::from_millis(750)]);
+}
+
+/// Tests that JSON objects verify against the canonical JSON hash.
+#[test]
+fn nats_source_accepts_canonical_json_hash() {
+    let connection = MockNatsConnection::default();
+    connection.put_object("evidence", "doc.json", br#"{ "b": 1, "a": 2 }"#, None);
+    let source = evidence_source(&connection);
+
+    let reference = ContentRef {
+        uri: "nats://evidence/doc.json".to_string(),
+        content_hash: hash_for_json(&json!({"a": 2, "b": 1})),
+        encryption: None,
+    };
+    source.fetch(&reference).expect("canonical hash matches");
+}
+
+// ============================================================================
+// SECTION: Policy and Verification Tests
+// ============================================================================
+
+/// Tests that buckets outside the allowlist are never requested.
+#[test]
+fn nats_source_rejects_unlisted_bucket() {
+    let connection = MockNatsConnection::default();
+    connection.put_object("secrets", "key", b"key", None);
+    let source = evidence_source(&connection);
+
+    let err = source.fetch(&content_ref("nats://secrets/key", b"key")).unwrap_err();
+    assert!(matches!(err, SourceError::Policy(_)));
+    assert!(connection.timeouts().is_empty(), "no request sent");
+}
+
+/// Tests that tampered content fails hash verification.
+#[test]
+fn nats_source_rejects_hash_mismatch() {
+    let connection = MockNatsConnection::default();
+    connection.put_object("evidence", "report", b"tampered", None);
+    let source = evidence_source(&connection);
+
+    let err = source.fetch(&content_ref("nats://evidence/report", b"original")).unwrap_err();
+    assert!(matches!(err, SourceError::HashMismatch { .. }));
+}
+
+/// Tests that oversized objects and malformed URIs fail closed.
+#[test]
+fn nats_source_enforces_size_cap_and_uri_shape() {
+    let connection = MockNatsConnection::default();
+    let large = vec![0u8; MAX_SOURCE_BYTES + 1];
+    connection.put_object("evidence", "large", &large, None);
+    let source = evidence_source(&connection);
+
+    let err = source.fetch(&content_ref("nats://evidence/large", &large)).unwrap_err();
+    assert!(matches!(err, SourceError::TooLarge { .. }));
+
+    for uri in [
+        "nats://evidence/",
+        "nats://user:pw@evidence/x",
+        "nats://evidence:4222/x",
+        "nats://evidence/x?y=1",
+        "nats://evi.dence/x",
+        "file:///etc/passwd",
+    ] {
+        assert!(source.fetch(&content_ref(uri, b"x")).is_err(), "uri {uri}");
+    }
+    let err = source.fetch(&content_ref("nats://evidence/missing", b"x")).unwrap_err();
+    assert!(matches!(err, SourceError::NotFound(_)));
+}
+
+/// Tests that a lowered size cap is passed to the connection and enforced.
+#[test]
+fn nats_source_honors_lowered_size_cap() {
+    let connection = MockNatsConnection::default();
+    connection.put_object("evidence", "small", b"1234", None);
+    connection.put_object("evidence", "large", b"12345", None);
+    let source = evidence_source(&connection).with_max_bytes(4);
+
+    assert!(source.fetch(&content_ref("nats://evidence/small", b"1234")).is_ok());
+    let err = source.fetch(&content_ref("nats://evidence/large", b"12345")).unwrap_err();
+    assert!(matches!(err, SourceError::TooLarge { max_bytes: 4, actual_bytes: 5 }));
+}
@@ -0,0 +1,220 @@
+// crates/decision-gate-broker/tests/sources/s3_tests.rs
+// ============================================================================
+// Module: S3Source Unit Tests
+// Description: Tests for the S3 object source against a mock S3 endpoint.
+// Purpose: Validate object fetch, bucket allowlisting, hashing, and dispatch.
+// Dependencies: aws-sdk-s3, decision-gate-broker, decision-gate-core, tiny_http
+// ============================================================================
+
+//! ## Overview
+//! Exercises [`decision_gate_broker::S3Source`] against a path-style mock S3
+//! server with static test credentials.
+
+#![allow(
+    clippy::panic,
+    clippy::print_stdout,
+    clippy::print_stderr,
+    clippy::unwrap_used,
+    clippy::expect_used,
+    clippy::use_debug,
+    clippy::dbg_macro,
+    clippy::panic_in_result_fn,
+    clippy::unwrap_in_result,
+    reason = "Test-only output and panic-based assertions are permitted."
+)]
+
+use std::thread;
+use std::thread::JoinHandle;
+
+use aws_sdk_s3::config::Credentials;
+use decision_gate_broker::CallbackSink;
+use decision_gate_broker::CompositeBroker;
+use decision_gate_broker::PayloadBody;
+use decision_gate_broker::S3Source;
+use decision_gate_broker::S3SourceConfig;
+use decision_gate_broker::Source;
+use decision_gate_broker::SourceError;
+use decision_gate_core::ContentRef;
+use decision_gate_core::DispatchReceipt;
+use decision_gate_core::Dispatcher;
+use decision_gate_core::PacketPayload;
+use decision_gate_core::Timestamp;
+use tiny_http::Header;
+use tiny_http::Response;
+use tiny_http::Server;
+use tiny_http::StatusCode;
+
+use super::common::hash_for_bytes;
+use super::common::sample_envelope;
+use super::common::sample_target;
+
+// ============================================================================
+// SECTION: Helpers
+// ============================================================================
+
+/// S3 error body returned for missing keys.
+const NO_SUCH_KEY: &str = "<Error><Code>NoSuchKey</Code><Message>missing</Message></Error>";
+
+/// Starts a mock S3 endpoint serving `objects` for `requests` requests.
+///
+/// Returns the endpoint URL and a handle yielding `"<METHOD> <path>"` for each
+/// request served.
+fn mock_s3(
+    objects: Vec<(&'static str, &'static [u8])>,
+    requests: usize,
+) -> (String, JoinHandle<Vec<String>>) {
+    let server = Server::http("127.0.0.1:0").expect("mock s3 server");
+    let endpoint = format!("http://{}", server.server_addr());
+    let handle = thread::spawn(move || {
+        let mut seen = Vec::new();
+        for _ in 0 .. requests {
+            let request = server.recv().expect("mock s3 request");
+            let path = request.url().split('?').next().unwrap_or_default().to_string();
+            seen.push(format!("{} {path}", request.method()));
+            let response = match objects.iter().find(|(key, _)| *key == path) {
+                Some((_, body)) => Response::from_data(body.to_vec()).with_header(
+                    Header::from_bytes("Content-Type", "application/octet-stream").unwrap(),
+                ),
+                None => Response::from_string(NO_SUCH_KEY)
+                    .with_status_code(StatusCode(404))
+                    .with_header(Header::from_bytes("Content-Type", "application/xml").unwrap()),
+            };
+            request.respond(response).expect("respond");
+        }
+        seen
+    });
+    (endpoint, handle)
+}
+
+/// Builds a source for the mock endpoint that may read the `evidence` bucket.
+fn evidence_source(endpoint: &str) -> S3Source {
+    let config = S3SourceConfig {
+        region: Some("us-east-1".to_string()),
+        endpoint: Some(endpoint.to_string()),
+        force_path_style: true,
+        profile: None,
+    };
+    let credentials = Credentials::new("test-access-key", "test-secret-key", None, None, "test");
+    S3Source::with_credentials_provider(&config, credentials)
+        .expect("s3 source")
+        .allow_buckets(["evidence"])
+}
+
+/// Builds a content reference whose hash covers `bytes`.
+fn content_ref(uri: &str, bytes: &[u8]) -> ContentRef {
+    ContentRef {
+        uri: uri.to_string(),
+        content_hash: hash_for_bytes(bytes),
+        encryption: None,
+    }
+}
+
+// ============================================================================
+// SECTION: Fetch Tests
+// ============================================================================
+
+/// Tests that an object matching the content hash is returned.
+#[test]
+fn s3_source_returns_payload_on_hash_match() {
+    let (endpoint, handle) = mock_s3(vec![("/evidence/runs/report.bin", b"report")], 1);
+    let source = evidence_source(&endpoint);
+
+    let payload =
+        source.fetch(&content_ref("s3://evidence/runs/report.bin", b"report")).expect("fetch");
+    assert_eq!(payload.bytes, b"report");
+    assert_eq!(payload.content_type.as_deref(), Some("application/octet-stream"));
+    assert_eq!(handle.join().expect("server thread"), vec!["GET /evidence/runs/report.bin"]);
+}
+
+/// Tests that an object not matching the content hash is rejected.
+#[test]
+fn s3_source_rejects_hash_mismatch() {
+    let (endpoint, handle) = mock_s3(vec![("/evidence/report.bin", b"tampered")], 1);
+    let source = evidence_source(&endpoint);
+
+    let err = source.fetch(&content_ref("s3://evidence/report.bin", b"report")).unwrap_err();
+    assert!(matches!(err, SourceError::HashMismatch { .. }), "unexpected error: {err}");
+    handle.join().expect("server thread");
+}
+
+/// Tests that missing keys map to `NotFound`.
+#[test]
+fn s3_source_maps_missing_key_to_not_found() {
+    let (endpoint, handle) = mock_s3(Vec::new(), 1);
+    let source = evidence_source(&endpoint);
+
+    let err = source.fetch(&content_ref("s3://evidence/missing.bin", b"")).unwrap_err();
+    assert!(matches!(err, SourceError::NotFound(_)), "unexpected error: {err}");
+    handle.join().expect("server thread");
+}
+
+/// Tests that metadata lookups use `HEAD` and report the object size.
+#[test]
+fn s3_source_metadata_uses_head_request() {
+    let (endpoint, handle) = mock_s3(vec![("/evidence/report.bin", b"report")], 1);
+    let source = evidence_source(&endpoint);
+
+    let metadata =
+        source.metadata(&content_ref("s3://evidence/report.bin", b"report")).expect("metadata");
+    assert_eq!(metadata.size_bytes, Some(6));
+    assert_eq!(handle.join().expect("server thread"), vec!["HEAD /evidence/report.bin"]);
+}
+
+// ============================================================================
+// SECTION: Policy and URI Tests
+// ============================================================================
+
+/// Tests that unlisted buckets and malformed URIs fail before any request.
+#[test]
+fn s3_source_rejects_unlisted_buckets_and_bad_uris() {
+    let source = evidence_source("http://127.0.0.1:9");
+
+    let err = source.fetch(&content_ref("s3://secrets/report.bin", b"report")).unwrap_err();
+    assert!(matches!(err, SourceError::Policy(_)), "unexpected error: {err}");
+    for uri in [
+        "s3://evidence/",
+        "s3://Evidence/report.bin",
+        "s3://user:pass@evidence/report.bin",
+        "s3://evidence/report.bin?versionId=1",
+        "s3://evidence/report%20final.bin",
+    ] {
+        let err = source.fetch(&content_ref(uri, b"report")).unwrap_err();
+        assert!(matches!(err, SourceError::InvalidUri(_)), "{uri}: unexpected error: {err}");
+    }
+    let err = source.fetch(&content_ref("nats://evidence/report.bin", b"report")).unwrap_err();
+    assert!(matches!(err, SourceError::UnsupportedScheme(_)), "unexpected error: {err}");
+}
+
+// ============================================================================
+// SECTION: Broker Tests
+// ============================================================================
+
+/// Tests that the builder registers the source under the `s3` scheme.
+#[test]
+fn composite_broker_resolves_s3_references() {
+    let (endpoint, handle) = mock_s3(vec![("/evidence/report.bin", b"report")], 1);
+    let content_ref = content_ref("s3://evidence/report.bin", b"report");
+    let envelope = sample_envelope("application/octet-stream", content_ref.content_hash.clone());
+    let payload = PacketPayload::External {
+        content_ref,
+    };
+    let sink = CallbackSink::new(|target, payload| {
+        assert!(matches!(&payload.body, PayloadBody::Bytes(bytes) if bytes == b"report"));
+        Ok(DispatchReceipt {
+            dispatch_id: "s3-1".to_string(),
+            target: target.clone(),
+            receipt_hash: payload.envelope.content_hash.clone(),
+            dispatched_at: Timestamp::Logical(1),
+            dispatcher: "callback".to_string(),
+        })
+    });
+    let broker = CompositeBroker::builder()
+        .s3_source(evidence_source(&endpoint))
+        .sink(sink)
+        .build()
+        .expect("broker build");
+
+    let receipt = broker.dispatch(&sample_target(), &envelope, &payload).expect("dispatch");
+    assert_eq!(receipt.dispatch_id, "s3-1");
+    handle.join().expect("server thread");
+}
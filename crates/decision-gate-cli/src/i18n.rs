@@ -135,6 +135,7 @@ const CATALOG_EN: &[(&str, &str)] = &[
     ("output.stream.stderr", "stderr"),
     ("output.stream.unknown", "output"),
     ("output.write_failed", "Failed to write to {stream}: {error}"),
+    ("output.msgpack_failed", "Failed to encode MessagePack output: {error}"),
     (
         "input.read_too_large",
         "Refusing to read {kind} at {path} because it is {size} bytes (limit {limit}).",
@@ -225,6 +226,20 @@ const CATALOG_EN: &[(&str, &str)] = &[
     ("runpack.verify.md.errors_header", "## Errors"),
     ("runpack.verify.md.error_line", "- {error}"),
     ("runpack.verify.md.no_errors", "- None"),
+    ("runpack.attest.key_required", "Attestation output requires --attestation-key."),
+    ("runpack.attest.out_required", "Attestation key provided without --attestation-out."),
+    ("runpack.attest.failed", "Failed to create verification attestation: {error}"),
+    ("runpack.attest.read_failed", "Failed to read {kind} at {path}: {error}"),
+    ("runpack.attest.parse_failed", "Failed to parse {kind} at {path}: {error}"),
+    ("runpack.attest.kind.attestation", "verification attestation"),
+    ("runpack.attest.kind.report", "verification report"),
+    ("runpack.attest.public_key_kind", "public key"),
+    (
+        "runpack.attest.public_key_invalid",
+        "Public key must be a 32-byte ed25519 key (raw or base64).",
+    ),
+    ("runpack.attest.invalid", "Verification attestation rejected: {error}"),
+    ("runpack.attest.ok", "Verification attestation valid (status: {status}, key: {key_id})"),
     (
         "runpack.pretty.output_dir_failed",
         "Failed to create pretty output directory {path}: {error}",
@@ -266,6 +281,12 @@ const CATALOG_EN: &[(&str, &str)] = &[
     ),
     ("authoring.normalize.write_failed", "Failed to write normalized output to {path}: {error}"),
     ("authoring.normalize.ok", "Normalized scenario written to {path}"),
+    ("authoring.format_dsl.failed", "Failed to format requirement DSL in {path}:\n{error}"),
+    ("authoring.format_dsl.write_failed", "Failed to write formatted DSL to {path}: {error}"),
+    ("authoring.format_dsl.ok", "Formatted DSL written to {path}"),
+    ("authoring.template_failed", "Template error in {path}: {error}"),
+    ("authoring.template_missing_params", "Template {path} is missing parameters: {names}"),
+    ("authoring.render.unused_param", "Warning: template parameter {name} is not used"),
     (
         "authoring.validate.ok",
         "ScenarioSpec valid (scenario_id={scenario_id}, spec_hash={spec_hash})",
@@ -276,6 +297,8 @@ const CATALOG_EN: &[(&str, &str)] = &[
     ("interop.read_failed", "Failed to read {kind} file at {path}: {error}"),
     ("interop.parse_failed", "Failed to parse {kind} JSON at {path}: {error}"),
     ("interop.spec_failed", "ScenarioSpec validation failed for {path}: {error}"),
+    ("interop.run_config_failed", "RunConfig validation failed for {path}: {error}"),
+    ("interop.trigger_failed", "Trigger validation failed for {path}: {error}"),
     ("interop.input_invalid", "Interop input validation failed: {error}"),
     ("interop.execution_failed", "Interop execution failed: {error}"),
     ("interop.report.serialize_failed", "Failed to serialize interop report: {error}"),
@@ -349,6 +372,8 @@ const CATALOG_EN: &[(&str, &str)] = &[
     ("store.verify.hash_algorithm_invalid", "Unsupported hash algorithm: {value}"),
     ("store.prune.keep_invalid", "keep must be >= 1."),
     ("store.prune.failed", "Failed to prune run state versions: {error}"),
+    ("store.gc.no_policies", "No retention policies configured (run_state_store.retention)."),
+    ("store.gc.failed", "Failed to apply retention policies: {error}"),
     ("store.list.header", "Stored runs:"),
     ("store.list.none", "No runs found."),
     (
@@ -364,9 +389,40 @@ const CATALOG_EN: &[(&str, &str)] = &[
     ("store.verify.hash.stored", "stored"),
     ("store.verify.hash.computed", "computed"),
     ("store.verify.bytes", "- State bytes: {bytes}"),
+    ("store.verify.chain.header", "Run state version chain verification:"),
+    (
+        "store.verify.chain.summary",
+        "- Status: {status} ({checked} versions checked, latest {latest})",
+    ),
+    ("store.verify.chain.missing", "- Missing versions {from}..={to}"),
+    ("store.verify.chain.state_hash", "- Version {version}: state hash mismatch"),
+    ("store.verify.chain.prev_mismatch", "- Version {version}: previous-version hash mismatch"),
+    ("store.verify.chain.prev_missing", "- Version {version}: previous-version hash missing"),
+    ("store.diff.header", "Run state diff for {run_id} (version {from} -> {to}):"),
+    ("store.diff.none", "- No changes"),
+    ("store.diff.status", "- Status: {from} -> {to}"),
+    ("store.diff.stage", "- Stage: {from} -> {to}"),
+    ("store.diff.gate", "- Gate {stage_id}/{gate_id}: {change} ({from} -> {to})"),
+    (
+        "store.diff.disclosure",
+        "- Disclosure {packet_id} (stage {stage_id}): {change} (+{added} / -{removed} dispatches)",
+    ),
+    ("store.diff.kind.added", "added"),
+    ("store.diff.kind.removed", "removed"),
+    ("store.diff.kind.changed", "changed"),
+    ("store.diff.gate_status.true", "true"),
+    ("store.diff.gate_status.false", "false"),
+    ("store.diff.gate_status.unknown", "unknown"),
+    ("store.diff.gate_status.absent", "absent"),
     ("store.prune.summary", "Run {run_id}: keep {keep}, pruned {pruned} (dry_run={dry_run})"),
+    (
+        "store.gc.summary",
+        "Checked {runs_checked} runs: pruned {versions_pruned} versions from {runs_pruned} runs \
+         (dry_run={dry_run})",
+    ),
     ("broker.input.kind.resolve", "broker resolve input"),
     ("broker.input.kind.dispatch", "broker dispatch input"),
+    ("broker.input.kind.head", "broker head input"),
     ("broker.input.read_failed", "Failed to read {kind} at {path}: {error}"),
     ("broker.input.parse_failed", "Failed to parse {kind} JSON at {path}: {error}"),
     ("broker.resolve.failed", "Failed to resolve broker payload: {error}"),
@@ -376,6 +432,12 @@ const CATALOG_EN: &[(&str, &str)] = &[
     ),
     ("broker.resolve.unsupported_scheme", "Unsupported URI scheme: {scheme}"),
     ("broker.http.init_failed", "Failed to initialize broker HTTP source: {error}"),
+    (
+        "broker.resolve.nats_unconfigured",
+        "nats:// content references require --nats-url (CLI built with the `nats` feature)",
+    ),
+    ("broker.nats.connect_failed", "Failed to connect to NATS server: {error}"),
+    ("broker.nats.invalid_subject", "Invalid NATS subject in --nats-allow-subject: {subject}"),
     ("broker.resolve.json_parse_failed", "Failed to parse JSON payload: {error}"),
     ("broker.resolve.hash_failed", "Failed to hash payload: {error}"),
     ("broker.resolve.hash_mismatch", "Payload hash mismatch (expected {expected}, got {actual})"),
@@ -385,6 +447,9 @@ const CATALOG_EN: &[(&str, &str)] = &[
     ("broker.resolve.content_type", "Content type: {content_type}"),
     ("broker.resolve.hash", "Content hash: {value}"),
     ("broker.resolve.bytes", "Payload bytes: {bytes}"),
+    ("broker.head.failed", "Failed to read broker content metadata: {error}"),
+    ("broker.head.header", "Broker head result:"),
+    ("broker.head.size", "Size bytes: {size}"),
     ("broker.dispatch.failed", "Failed to dispatch broker payload: {error}"),
     ("broker.dispatch.target_failed", "Failed to serialize broker target: {error}"),
     ("broker.dispatch.header", "Broker dispatch result:"),
@@ -417,6 +482,7 @@ const CATALOG_CA: &[(&str, &str)] = &[
     ("output.stream.stderr", "stderr"),
     ("output.stream.unknown", "sortida"),
     ("output.write_failed", "No s'ha pogut escriure a {stream}: {error}"),
+    ("output.msgpack_failed", "No s'ha pogut codificar la sortida MessagePack: {error}"),
     (
         "input.read_too_large",
         "Es rebutja llegir {kind} a {path} perquè fa {size} bytes (límit {limit}).",
@@ -526,6 +592,23 @@ const CATALOG_CA: &[(&str, &str)] = &[
     ("runpack.verify.md.errors_header", "## Errors"),
     ("runpack.verify.md.error_line", "- {error}"),
     ("runpack.verify.md.no_errors", "- Cap"),
+    ("runpack.attest.key_required", "La sortida d'atestació requereix --attestation-key."),
+    (
+        "runpack.attest.out_required",
+        "S'ha proporcionat una clau d'atestació sense --attestation-out.",
+    ),
+    ("runpack.attest.failed", "No s'ha pogut crear l'atestació de verificació: {error}"),
+    ("runpack.attest.read_failed", "No s'ha pogut llegir {kind} a {path}: {error}"),
+    ("runpack.attest.parse_failed", "No s'ha pogut analitzar {kind} a {path}: {error}"),
+    ("runpack.attest.kind.attestation", "l'atestació de verificació"),
+    ("runpack.attest.kind.report", "l'informe de verificació"),
+    ("runpack.attest.public_key_kind", "clau pública"),
+    (
+        "runpack.attest.public_key_invalid",
+        "La clau pública ha de ser una clau ed25519 de 32 bytes (raw o base64).",
+    ),
+    ("runpack.attest.invalid", "Atestació de verificació rebutjada: {error}"),
+    ("runpack.attest.ok", "Atestació de verificació vàlida (estat: {status}, clau: {key_id})"),
     (
         "runpack.pretty.output_dir_failed",
         "No s'ha pogut crear el directori de sortida formatada {path}: {error}",
@@ -588,6 +671,18 @@ const CATALOG_CA: &[(&str, &str)] = &[
         "No s'ha pogut escriure la sortida normalitzada a {path}: {error}",
     ),
     ("authoring.normalize.ok", "Escenari normalitzat escrit a {path}"),
+    (
+        "authoring.format_dsl.failed",
+        "No s'ha pogut formatar el DSL de requisits a {path}:\n{error}",
+    ),
+    (
+        "authoring.format_dsl.write_failed",
+        "No s'ha pogut escriure el DSL formatat a {path}: {error}",
+    ),
+    ("authoring.format_dsl.ok", "DSL formatat escrit a {path}"),
+    ("authoring.template_failed", "Error de plantilla a {path}: {error}"),
+    ("authoring.template_missing_params", "A la plantilla {path} hi falten paràmetres: {names}"),
+    ("authoring.render.unused_param", "Avís: el paràmetre de plantilla {name} no s'utilitza"),
     (
         "authoring.validate.ok",
         "ScenarioSpec vàlid (scenario_id={scenario_id}, spec_hash={spec_hash})",
@@ -598,6 +693,8 @@ const CATALOG_CA: &[(&str, &str)] = &[
     ("interop.read_failed", "No s'ha pogut llegir el fitxer {kind} a {path}: {error}"),
     ("interop.parse_failed", "No s'ha pogut analitzar el JSON {kind} a {path}: {error}"),
     ("interop.spec_failed", "Validació de ScenarioSpec fallida per a {path}: {error}"),
+    ("interop.run_config_failed", "Validació de RunConfig fallida per a {path}: {error}"),
+    ("interop.trigger_failed", "Validació del disparador fallida per a {path}: {error}"),
     ("interop.input_invalid", "Validació d'entrada d'interoperabilitat fallida: {error}"),
     ("interop.execution_failed", "Execució d'interoperabilitat fallida: {error}"),
     (
@@ -707,6 +804,11 @@ const CATALOG_CA: &[(&str, &str)] = &[
     ("store.verify.hash_algorithm_invalid", "Algorisme de hash no compatible: {value}"),
     ("store.prune.keep_invalid", "keep ha de ser >= 1."),
     ("store.prune.failed", "No s'han pogut esborrar versions d'estat d'execució: {error}"),
+    (
+        "store.gc.no_policies",
+        "No hi ha polítiques de retenció configurades (run_state_store.retention).",
+    ),
+    ("store.gc.failed", "No s'han pogut aplicar les polítiques de retenció: {error}"),
     ("store.list.header", "Execucions emmagatzemades:"),
     ("store.list.none", "No s'han trobat execucions."),
     (
@@ -722,12 +824,46 @@ const CATALOG_CA: &[(&str, &str)] = &[
     ("store.verify.hash.stored", "emmagatzemat"),
     ("store.verify.hash.computed", "calculat"),
     ("store.verify.bytes", "- Bytes de l'estat: {bytes}"),
+    ("store.verify.chain.header", "Verificació de la cadena de versions de l'estat d'execució:"),
+    (
+        "store.verify.chain.summary",
+        "- Estat: {status} ({checked} versions comprovades, darrera {latest})",
+    ),
+    ("store.verify.chain.missing", "- Versions absents {from}..={to}"),
+    ("store.verify.chain.state_hash", "- Versió {version}: el hash de l'estat no coincideix"),
+    (
+        "store.verify.chain.prev_mismatch",
+        "- Versió {version}: el hash de la versió anterior no coincideix",
+    ),
+    ("store.verify.chain.prev_missing", "- Versió {version}: falta el hash de la versió anterior"),
+    ("store.diff.header", "Diferències de l'estat d'execució {run_id} (versió {from} -> {to}):"),
+    ("store.diff.none", "- Cap canvi"),
+    ("store.diff.status", "- Estat: {from} -> {to}"),
+    ("store.diff.stage", "- Etapa: {from} -> {to}"),
+    ("store.diff.gate", "- Porta {stage_id}/{gate_id}: {change} ({from} -> {to})"),
+    (
+        "store.diff.disclosure",
+        "- Divulgació {packet_id} (etapa {stage_id}): {change} (+{added} / -{removed} enviaments)",
+    ),
+    ("store.diff.kind.added", "afegida"),
+    ("store.diff.kind.removed", "eliminada"),
+    ("store.diff.kind.changed", "modificada"),
+    ("store.diff.gate_status.true", "cert"),
+    ("store.diff.gate_status.false", "fals"),
+    ("store.diff.gate_status.unknown", "desconegut"),
+    ("store.diff.gate_status.absent", "absent"),
     (
         "store.prune.summary",
         "Execució {run_id}: conservar {keep}, eliminades {pruned} (dry_run={dry_run})",
     ),
+    (
+        "store.gc.summary",
+        "{runs_checked} execucions comprovades: {versions_pruned} versions eliminades de \
+         {runs_pruned} execucions (dry_run={dry_run})",
+    ),
     ("broker.input.kind.resolve", "entrada de resolució del broker"),
     ("broker.input.kind.dispatch", "entrada de dispatch del broker"),
+    ("broker.input.kind.head", "entrada de head del broker"),
     ("broker.input.read_failed", "No s'ha pogut llegir {kind} a {path}: {error}"),
     ("broker.input.parse_failed", "No s'ha pogut analitzar el JSON de {kind} a {path}: {error}"),
     ("broker.resolve.failed", "No s'ha pogut resoldre el payload del broker: {error}"),
@@ -737,6 +873,12 @@ const CATALOG_CA: &[(&str, &str)] = &[
     ),
     ("broker.resolve.unsupported_scheme", "Esquema d'URI no compatible: {scheme}"),
     ("broker.http.init_failed", "No s'ha pogut inicialitzar la font HTTP del broker: {error}"),
+    (
+        "broker.resolve.nats_unconfigured",
+        "Les referències nats:// requereixen --nats-url (CLI amb la funcionalitat `nats`)",
+    ),
+    ("broker.nats.connect_failed", "No s'ha pogut connectar al servidor NATS: {error}"),
+    ("broker.nats.invalid_subject", "Subjecte NATS no vàlid a --nats-allow-subject: {subject}"),
     ("broker.resolve.json_parse_failed", "No s'ha pogut analitzar el payload JSON: {error}"),
     ("broker.resolve.hash_failed", "No s'ha pogut calcular el hash del payload: {error}"),
     (
@@ -749,6 +891,9 @@ const CATALOG_CA: &[(&str, &str)] = &[
     ("broker.resolve.content_type", "Tipus de contingut: {content_type}"),
     ("broker.resolve.hash", "Hash de contingut: {value}"),
     ("broker.resolve.bytes", "Bytes del payload: {bytes}"),
+    ("broker.head.failed", "No s'han pogut llegir les metadades del contingut del broker: {error}"),
+    ("broker.head.header", "Resultat de head del broker:"),
+    ("broker.head.size", "Mida en bytes: {size}"),
     ("broker.dispatch.failed", "No s'ha pogut enviar el payload del broker: {error}"),
     ("broker.dispatch.target_failed", "No s'ha pogut serialitzar el destí del broker: {error}"),
     ("broker.dispatch.header", "Resultat de dispatch del broker:"),
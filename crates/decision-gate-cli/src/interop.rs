@@ -260,6 +260,7 @@ pub async fn run_interop(config: InteropConfig) -> Result<InteropReport, String>
     let trigger_request = ScenarioTriggerRequest {
         scenario_id: define_response.scenario_id.clone(),
         trigger: config.trigger.clone(),
+        lease_holder: None,
     };
     let trigger_value =
         serde_json::to_value(&trigger_request).map_err(|err| format!("trigger payload: {err}"))?;
@@ -277,6 +278,7 @@ pub async fn run_interop(config: InteropConfig) -> Result<InteropReport, String>
             requested_at: config.status_requested_at,
             correlation_id: config.trigger.correlation_id.clone(),
         },
+        wait_for_change: None,
     };
     let status_value =
         serde_json::to_value(&status_request).map_err(|err| format!("status payload: {err}"))?;
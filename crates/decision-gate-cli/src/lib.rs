@@ -27,11 +27,19 @@ pub(crate) mod mcp_client;
 #[allow(dead_code, reason = "Interop helpers are used by the CLI binary and tests.")]
 pub mod interop;
 
+/// `async-nats` connection for broker `nats://` resolution.
+#[cfg(feature = "nats")]
+pub mod nats;
+
 /// Security utilities (constant-time comparisons).
 pub mod security;
 
 /// CLI server bind policy helpers.
 pub mod serve_policy;
 
+/// Mock MCP server harness for client integration tests.
+#[cfg(feature = "testing")]
+pub mod testing;
+
 #[cfg(test)]
 mod tests;
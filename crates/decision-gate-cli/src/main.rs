@@ -19,6 +19,7 @@
 pub(crate) mod interop;
 #[cfg(test)]
 mod main_tests;
+#[allow(dead_code, reason = "MCP client capability helpers are exercised by client tests.")]
 pub(crate) mod mcp_client;
 
 // ============================================================================
@@ -54,9 +55,21 @@ use decision_gate_broker::FileSource;
 use decision_gate_broker::HttpSource;
 use decision_gate_broker::HttpSourcePolicy;
 use decision_gate_broker::InlineSource;
+#[cfg(feature = "nats")]
+use decision_gate_broker::MAX_SOURCE_BYTES;
+#[cfg(feature = "nats")]
+use decision_gate_broker::NatsConnection;
+#[cfg(feature = "nats")]
+use decision_gate_broker::NatsSource;
+#[cfg(feature = "nats")]
+use decision_gate_broker::NatsSubjectPolicy;
 use decision_gate_broker::Source;
+#[cfg(feature = "nats")]
+use decision_gate_broker::nats::is_valid_subject;
 use decision_gate_cli::i18n::Locale;
 use decision_gate_cli::i18n::set_locale;
+#[cfg(feature = "nats")]
+use decision_gate_cli::nats::NatsClient;
 use decision_gate_cli::serve_policy::ALLOW_NON_LOOPBACK_ENV;
 use decision_gate_cli::serve_policy::BindOutcome;
 use decision_gate_cli::serve_policy::enforce_local_only;
@@ -71,9 +84,11 @@ use decision_gate_contract::types::ToolContract;
 use decision_gate_core::Artifact;
 use decision_gate_core::ArtifactReader;
 use decision_gate_core::ArtifactSink;
+use decision_gate_core::Compression;
 use decision_gate_core::ContentRef;
 use decision_gate_core::DataShapeId;
 use decision_gate_core::DataShapeVersion;
+use decision_gate_core::DiffKind;
 use decision_gate_core::DispatchReceipt;
 use decision_gate_core::DispatchTarget;
 use decision_gate_core::Dispatcher;
@@ -85,6 +100,7 @@ use decision_gate_core::PacketPayload;
 use decision_gate_core::RunConfig;
 use decision_gate_core::RunId;
 use decision_gate_core::RunState;
+use decision_gate_core::RunStateDiff;
 use decision_gate_core::RunStateStore;
 use decision_gate_core::RunStatus;
 use decision_gate_core::RunpackManifest;
@@ -97,9 +113,12 @@ use decision_gate_core::hashing::HashError;
 use decision_gate_core::hashing::canonical_json_bytes_with_limit;
 use decision_gate_core::hashing::hash_bytes;
 use decision_gate_core::hashing::hash_canonical_json;
+use decision_gate_core::runtime::DecodingArtifactReader;
 use decision_gate_core::runtime::MAX_RUNPACK_ARTIFACT_BYTES;
 use decision_gate_core::runtime::RunpackBuilder;
+use decision_gate_core::runtime::RunpackSigner;
 use decision_gate_core::runtime::RunpackVerifier;
+use decision_gate_core::runtime::VerificationAttestation;
 use decision_gate_core::runtime::VerificationReport;
 use decision_gate_core::runtime::VerificationStatus;
 use decision_gate_mcp::DecisionGateConfig;
@@ -111,6 +130,7 @@ use decision_gate_mcp::config::ServerAuthMode;
 use decision_gate_mcp::config::ServerTransport;
 use decision_gate_mcp::runpack_object_store::ObjectStoreRunpackBackend;
 use decision_gate_mcp::runpack_object_store::RunpackObjectKey;
+use decision_gate_store_sqlite::RunChainIssue;
 use decision_gate_store_sqlite::RunSummary;
 use decision_gate_store_sqlite::SqliteRunStateStore;
 use decision_gate_store_sqlite::SqliteStoreConfig;
@@ -118,6 +138,7 @@ use decision_gate_store_sqlite::SqliteStoreMode;
 use decision_gate_store_sqlite::SqliteSyncMode;
 use ed25519_dalek::Signer;
 use ed25519_dalek::SigningKey;
+use ed25519_dalek::VerifyingKey;
 use interop::InteropConfig;
 use interop::InteropTransport;
 use interop::run_interop;
@@ -125,6 +146,8 @@ use interop::validate_inputs;
 use jsonschema::Draft;
 use jsonschema::Registry;
 use jsonschema::Validator;
+use ret_logic::TriState;
+use ret_logic::format_requirement;
 use serde::Deserialize;
 use serde::Serialize;
 use serde::de::DeserializeOwned;
@@ -181,6 +204,9 @@ struct Cli {
     /// Preferred output language (overrides `DECISION_GATE_LANG`).
     #[arg(long, value_enum, value_name = "LANG", global = true)]
     lang: Option<LangArg>,
+    /// Emit failures as a JSON error envelope on stdout instead of stderr text.
+    #[arg(long = "errors-json", action = ArgAction::SetTrue, global = true)]
+    errors_json: bool,
     /// Selected subcommand to execute.
     #[command(subcommand)]
     command: Option<Commands>,
@@ -271,6 +297,9 @@ struct ServeCommand {
     /// Optional config file path (defaults to decision-gate.toml or env override).
     #[arg(long, value_name = "PATH")]
     config: Option<PathBuf>,
+    /// Optional config profile overlay (overrides `DG_PROFILE`).
+    #[arg(long, value_name = "PROFILE")]
+    profile: Option<String>,
     /// Allow binding HTTP/SSE transports to non-loopback addresses (requires TLS or upstream TLS +
     /// auth).
     #[arg(long, action = ArgAction::SetTrue)]
@@ -284,6 +313,8 @@ enum RunpackCommand {
     Export(RunpackExportCommand),
     /// Verify a runpack manifest against its artifacts.
     Verify(RunpackVerifyCommand),
+    /// Verify a signed verification attestation against its report and manifest.
+    VerifyAttestation(RunpackVerifyAttestationCommand),
     /// Render a human-readable runpack view (pretty JSON output).
     Pretty(RunpackPrettyCommand),
 }
@@ -295,6 +326,12 @@ enum AuthoringCommand {
     Validate(AuthoringValidateCommand),
     /// Normalize a `ScenarioSpec` authoring input to canonical JSON.
     Normalize(AuthoringNormalizeCommand),
+    /// Print the canonical spec hash of a `ScenarioSpec` authoring input.
+    Hash(AuthoringHashCommand),
+    /// Render a `ScenarioSpec` template with parameters to canonical JSON.
+    Render(AuthoringRenderCommand),
+    /// Reformat a requirement DSL expression into canonical, indented DSL.
+    FormatDsl(AuthoringFormatDslCommand),
 }
 
 /// Config subcommands.
@@ -343,10 +380,14 @@ enum StoreCommand {
     Get(StoreGetCommand),
     /// Export a stored run state to disk.
     Export(StoreExportCommand),
+    /// Compare two stored run state versions.
+    Diff(StoreDiffCommand),
     /// Verify a stored run state hash/integrity.
     Verify(StoreVerifyCommand),
     /// Prune older run state versions.
     Prune(StorePruneCommand),
+    /// Apply configured retention policies to all runs.
+    Gc(StoreGcCommand),
 }
 
 /// Documentation subcommands.
@@ -365,6 +406,8 @@ enum DocsCommand {
 enum BrokerCommand {
     /// Resolve a content reference via broker sources.
     Resolve(BrokerResolveCommand),
+    /// Report size/type metadata for a content reference without downloading it.
+    Head(BrokerHeadCommand),
     /// Dispatch a payload via broker sources/sinks.
     Dispatch(BrokerDispatchCommand),
 }
@@ -507,6 +550,16 @@ enum OutputFormat {
     Text,
 }
 
+/// Encodings for machine-readable CLI outputs.
+#[derive(ValueEnum, Copy, Clone, Debug)]
+enum EncodingFormat {
+    /// Canonical JSON output.
+    Json,
+    /// Canonical msgpack output (requires the `msgpack` feature).
+    #[cfg(feature = "msgpack")]
+    Msgpack,
+}
+
 /// Arguments for `store list`.
 #[derive(Args, Debug)]
 struct StoreListCommand {
@@ -545,6 +598,9 @@ struct StoreGetCommand {
     /// Optional version override.
     #[arg(long, value_name = "VERSION")]
     version: Option<i64>,
+    /// Output encoding for the run state.
+    #[arg(long, value_enum, default_value_t = EncodingFormat::Json)]
+    format: EncodingFormat,
     /// Optional hash/signature outputs.
     #[command(flatten)]
     output: OutputArtifactsArgs,
@@ -576,6 +632,35 @@ struct StoreExportCommand {
     artifacts: OutputArtifactsArgs,
 }
 
+/// Arguments for `store diff`.
+#[derive(Args, Debug)]
+struct StoreDiffCommand {
+    /// Store location settings.
+    #[command(flatten)]
+    location: StoreLocationArgs,
+    /// Tenant identifier.
+    #[arg(long, value_name = "TENANT_ID")]
+    tenant_id: u64,
+    /// Namespace identifier.
+    #[arg(long, value_name = "NAMESPACE_ID")]
+    namespace_id: u64,
+    /// Run identifier.
+    #[arg(long, value_name = "RUN_ID")]
+    run_id: String,
+    /// Earlier version to compare from.
+    #[arg(long, value_name = "VERSION")]
+    from_version: i64,
+    /// Later version to compare to.
+    #[arg(long, value_name = "VERSION")]
+    to_version: i64,
+    /// Output format for the diff.
+    #[arg(long, value_enum, default_value_t = OutputFormat::Json)]
+    format: OutputFormat,
+    /// Optional hash/signature outputs.
+    #[command(flatten)]
+    output: OutputArtifactsArgs,
+}
+
 /// Arguments for `store verify`.
 #[derive(Args, Debug)]
 struct StoreVerifyCommand {
@@ -594,6 +679,9 @@ struct StoreVerifyCommand {
     /// Optional version override.
     #[arg(long, value_name = "VERSION")]
     version: Option<i64>,
+    /// Verify the previous-version hash chain across all stored versions.
+    #[arg(long, conflicts_with = "version")]
+    chain: bool,
     /// Output format for verification summaries.
     #[arg(long, value_enum, default_value_t = OutputFormat::Json)]
     format: OutputFormat,
@@ -631,6 +719,23 @@ struct StorePruneCommand {
     output: OutputArtifactsArgs,
 }
 
+/// Arguments for `store gc`.
+#[derive(Args, Debug)]
+struct StoreGcCommand {
+    /// Store location settings.
+    #[command(flatten)]
+    location: StoreLocationArgs,
+    /// Dry-run without deleting records.
+    #[arg(long, action = ArgAction::SetTrue)]
+    dry_run: bool,
+    /// Output format for gc summaries.
+    #[arg(long, value_enum, default_value_t = OutputFormat::Json)]
+    format: OutputFormat,
+    /// Optional hash/signature outputs.
+    #[command(flatten)]
+    output: OutputArtifactsArgs,
+}
+
 /// Arguments for `broker resolve`.
 #[derive(Args, Debug)]
 struct BrokerResolveCommand {
@@ -649,6 +754,10 @@ struct BrokerResolveCommand {
     /// Allow private/link-local IPs for HTTP resolution.
     #[arg(long, action = ArgAction::SetTrue)]
     allow_private: bool,
+    /// NATS connection for nats:// resolution.
+    #[cfg(feature = "nats")]
+    #[command(flatten)]
+    nats: BrokerNatsArgs,
     /// Output format for resolution results.
     #[arg(long, value_enum, default_value_t = OutputFormat::Json)]
     format: OutputFormat,
@@ -657,6 +766,54 @@ struct BrokerResolveCommand {
     output: OutputArtifactsArgs,
 }
 
+/// Arguments for `broker head`.
+#[derive(Args, Debug)]
+struct BrokerHeadCommand {
+    /// JSON input containing a `ContentRef`.
+    #[arg(long, value_name = "PATH")]
+    input: PathBuf,
+    /// Optional root directory for file:// resolution.
+    #[arg(long, value_name = "DIR")]
+    file_root: Option<PathBuf>,
+    /// Optional HTTP allowlist host entry (repeatable).
+    #[arg(long = "allow-host", value_name = "HOST")]
+    allow_hosts: Vec<String>,
+    /// Optional HTTP denylist host entry (repeatable).
+    #[arg(long = "deny-host", value_name = "HOST")]
+    deny_hosts: Vec<String>,
+    /// Allow private/link-local IPs for HTTP resolution.
+    #[arg(long, action = ArgAction::SetTrue)]
+    allow_private: bool,
+    /// NATS connection for nats:// resolution.
+    #[cfg(feature = "nats")]
+    #[command(flatten)]
+    nats: BrokerNatsArgs,
+    /// Output format for metadata results.
+    #[arg(long, value_enum, default_value_t = OutputFormat::Json)]
+    format: OutputFormat,
+    /// Optional hash/signature outputs.
+    #[command(flatten)]
+    output: OutputArtifactsArgs,
+}
+
+/// NATS connection arguments for broker resolution.
+#[cfg(feature = "nats")]
+#[derive(Args, Debug)]
+struct BrokerNatsArgs {
+    /// NATS server URL; enables nats:// resolution.
+    #[arg(long = "nats-url", value_name = "URL")]
+    url: Option<String>,
+    /// Allowed object-store subject, e.g. `$O.evidence` (repeatable).
+    #[arg(long = "nats-allow-subject", value_name = "SUBJECT")]
+    allow_subjects: Vec<String>,
+    /// NATS connect and fetch timeout in milliseconds.
+    #[arg(long = "nats-timeout-ms", value_name = "MS", default_value_t = 5_000)]
+    timeout_ms: u64,
+    /// Maximum object size in bytes for nats:// resolution.
+    #[arg(long = "nats-max-bytes", value_name = "BYTES", default_value_t = MAX_SOURCE_BYTES)]
+    max_bytes: usize,
+}
+
 /// Arguments for `broker dispatch`.
 #[derive(Args, Debug)]
 struct BrokerDispatchCommand {
@@ -735,6 +892,8 @@ enum InteropCommand {
 /// MCP client subcommands.
 #[derive(Subcommand, Debug)]
 enum McpCommand {
+    /// Negotiate and print server capabilities via `initialize`.
+    Capabilities(McpCapabilitiesCommand),
     /// MCP tools commands.
     Tools {
         /// Selected tools subcommand.
@@ -812,6 +971,12 @@ enum McpToolCommand {
     Precheck(McpToolInputCommand),
     /// `decision_gate_docs_search` tool.
     DecisionGateDocsSearch(McpToolInputCommand),
+    /// `scenarios_status_bulk` tool.
+    ScenariosStatusBulk(McpToolInputCommand),
+    /// `scenario_lease_acquire` tool.
+    ScenarioLeaseAcquire(McpToolInputCommand),
+    /// `scenario_lease_release` tool.
+    ScenarioLeaseRelease(McpToolInputCommand),
 }
 
 /// Contract subcommands.
@@ -928,6 +1093,14 @@ struct McpClientArgs {
     auth_config: Option<PathBuf>,
 }
 
+/// Arguments for `mcp capabilities`.
+#[derive(Args, Debug)]
+struct McpCapabilitiesCommand {
+    /// MCP client connection settings.
+    #[command(flatten)]
+    client: McpClientArgs,
+}
+
 /// Arguments for `mcp tools list`.
 #[derive(Args, Debug)]
 struct McpToolsListCommand {
@@ -1048,6 +1221,12 @@ enum McpToolNameArg {
     Precheck,
     /// `decision_gate_docs_search`
     DecisionGateDocsSearch,
+    /// `scenarios_status_bulk`
+    ScenariosStatusBulk,
+    /// `scenario_lease_acquire`
+    ScenarioLeaseAcquire,
+    /// `scenario_lease_release`
+    ScenarioLeaseRelease,
 }
 
 /// Arguments for contract generation.
@@ -1081,6 +1260,12 @@ struct SdkGenerateCommand {
     /// `OpenAPI` output file.
     #[arg(long, value_name = "FILE", default_value = "Docs/generated/openapi/decision-gate.json")]
     openapi_out: PathBuf,
+    /// Optional Go SDK output file (`generated.go`).
+    #[arg(long, value_name = "FILE")]
+    go_out: Option<PathBuf>,
+    /// Optional JSON Schema bundle output file (`schemas.json`).
+    #[arg(long, value_name = "FILE")]
+    schema_bundle_out: Option<PathBuf>,
 }
 
 /// Arguments for SDK verification.
@@ -1098,6 +1283,12 @@ struct SdkCheckCommand {
     /// `OpenAPI` output file.
     #[arg(long, value_name = "FILE", default_value = "Docs/generated/openapi/decision-gate.json")]
     openapi_out: PathBuf,
+    /// Optional Go SDK output file (`generated.go`).
+    #[arg(long, value_name = "FILE")]
+    go_out: Option<PathBuf>,
+    /// Optional JSON Schema bundle output file (`schemas.json`).
+    #[arg(long, value_name = "FILE")]
+    schema_bundle_out: Option<PathBuf>,
 }
 
 /// Supported authoring formats for `ScenarioSpec` inputs.
@@ -1134,12 +1325,57 @@ struct AuthoringNormalizeCommand {
     output: Option<PathBuf>,
 }
 
+/// Arguments for authoring template rendering.
+#[derive(Args, Debug)]
+struct AuthoringRenderCommand {
+    /// Path to the `ScenarioSpec` template with `${param}` placeholders.
+    #[arg(long, value_name = "PATH")]
+    template: PathBuf,
+    /// Path to a JSON object of template parameters.
+    #[arg(long, value_name = "PATH")]
+    params: PathBuf,
+    /// Explicit authoring format override for the template.
+    #[arg(long, value_enum, value_name = "FORMAT")]
+    format: Option<AuthoringFormatArg>,
+    /// Output path for canonical JSON (defaults to stdout).
+    #[arg(long, value_name = "PATH")]
+    output: Option<PathBuf>,
+}
+
+/// Arguments for authoring spec hashing.
+#[derive(Args, Debug)]
+struct AuthoringHashCommand {
+    /// Path to the `ScenarioSpec` authoring input.
+    #[arg(long, value_name = "PATH")]
+    input: PathBuf,
+    /// Explicit authoring format override.
+    #[arg(long, value_enum, value_name = "FORMAT")]
+    format: Option<AuthoringFormatArg>,
+    /// Also write the hashed canonical JSON bytes to this path.
+    #[arg(long, value_name = "PATH")]
+    canonical_out: Option<PathBuf>,
+}
+
+/// Arguments for requirement DSL formatting.
+#[derive(Args, Debug)]
+struct AuthoringFormatDslCommand {
+    /// Path to the requirement DSL source.
+    #[arg(long, value_name = "PATH")]
+    input: PathBuf,
+    /// Optional output path for the formatted DSL (defaults to stdout).
+    #[arg(long, value_name = "PATH")]
+    output: Option<PathBuf>,
+}
+
 /// Arguments for config validation.
 #[derive(Args, Debug)]
 struct ConfigValidateCommand {
     /// Optional config file path (defaults to decision-gate.toml or env override).
     #[arg(long, value_name = "PATH")]
     config: Option<PathBuf>,
+    /// Optional config profile overlay (overrides `DG_PROFILE`).
+    #[arg(long, value_name = "PROFILE")]
+    profile: Option<String>,
 }
 
 /// Arguments for runpack export.
@@ -1167,6 +1403,9 @@ struct RunpackExportCommand {
     /// Upload the runpack to the configured storage backend (object store).
     #[arg(long, action = ArgAction::SetTrue)]
     storage: bool,
+    /// Gzip-compress artifacts (hashes still cover the uncompressed bytes).
+    #[arg(long, action = ArgAction::SetTrue)]
+    compress: bool,
     /// Override `generated_at` timestamp (unix milliseconds).
     #[arg(long, value_name = "UNIX_MS")]
     generated_at_unix_ms: Option<i64>,
@@ -1191,6 +1430,32 @@ struct RunpackVerifyCommand {
     /// Output format for the verification report.
     #[arg(long, value_enum, default_value_t = VerifyFormat::Json)]
     format: VerifyFormat,
+    /// Optional output path for a signed verification attestation.
+    #[arg(long = "attestation-out", value_name = "PATH")]
+    attestation_out: Option<PathBuf>,
+    /// Attestation signing key path (ed25519 private key, raw 32 bytes or base64).
+    #[arg(long = "attestation-key", value_name = "PATH")]
+    attestation_key: Option<PathBuf>,
+    /// Override attestation `verified_at` timestamp (unix milliseconds).
+    #[arg(long, value_name = "UNIX_MS")]
+    verified_at_unix_ms: Option<i64>,
+}
+
+/// Arguments for verification attestation checks.
+#[derive(Args, Debug)]
+struct RunpackVerifyAttestationCommand {
+    /// Path to the verification attestation JSON file.
+    #[arg(long, value_name = "PATH")]
+    attestation: PathBuf,
+    /// Path to the runpack manifest JSON file.
+    #[arg(long, value_name = "PATH")]
+    manifest: PathBuf,
+    /// Path to the JSON verification report covered by the attestation.
+    #[arg(long, value_name = "PATH")]
+    report: PathBuf,
+    /// Verifier public key path (ed25519, raw 32 bytes or base64).
+    #[arg(long = "public-key", value_name = "PATH")]
+    public_key: PathBuf,
 }
 
 /// Arguments for runpack pretty output.
@@ -1214,24 +1479,83 @@ enum VerifyFormat {
     Json,
     /// Markdown summary output.
     Markdown,
+    /// Canonical msgpack output (requires the `msgpack` feature).
+    #[cfg(feature = "msgpack")]
+    Msgpack,
 }
 
 // ============================================================================
 // SECTION: Errors
 // ============================================================================
 
+/// Stable failure categories reported in `--errors-json` envelopes.
+///
+/// # Invariants
+/// - [`CliErrorKind::as_str`] labels are stable for scripts.
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+enum CliErrorKind {
+    /// Command-line arguments were rejected by the parser.
+    Usage,
+    /// Input files, flags, or payloads are malformed or out of bounds.
+    InvalidInput,
+    /// Reading or writing a local file, directory, or stream failed.
+    Io,
+    /// Configuration is missing, unreadable, or invalid.
+    Config,
+    /// A requested run, version, or provider does not exist.
+    NotFound,
+    /// The operation was refused by policy.
+    Denied,
+    /// An integrity, attestation, or drift check did not pass.
+    Verification,
+    /// A remote server, broker source, or storage backend failed.
+    Remote,
+    /// Any other failure.
+    Internal,
+}
+
+impl CliErrorKind {
+    /// Returns the stable `snake_case` label for this kind.
+    const fn as_str(self) -> &'static str {
+        match self {
+            Self::Usage => "usage",
+            Self::InvalidInput => "invalid_input",
+            Self::Io => "io",
+            Self::Config => "config",
+            Self::NotFound => "not_found",
+            Self::Denied => "denied",
+            Self::Verification => "verification",
+            Self::Remote => "remote",
+            Self::Internal => "internal",
+        }
+    }
+
+    /// Returns the process exit code for this kind.
+    ///
+    /// Usage errors keep clap's exit code; all other failures exit with 1.
+    const fn exit_code(self) -> u8 {
+        match self {
+            Self::Usage => 2,
+            _ => 1,
+        }
+    }
+}
+
 /// CLI error wrapper for localized error messages.
 #[derive(Debug, Error)]
 #[error("{message}")]
 struct CliError {
+    /// Failure category.
+    kind: CliErrorKind,
     /// Human-readable error message.
     message: String,
 }
 
 impl CliError {
-    /// Constructs a new [`CliError`] from a localized message.
-    const fn new(message: String) -> Self {
+    /// Constructs a new [`CliError`] from a kind and localized message.
+    const fn new(kind: CliErrorKind, message: String) -> Self {
         Self {
+            kind,
             message,
         }
     }
@@ -1247,27 +1571,32 @@ type CliResult<T> = Result<T, CliError>;
 /// CLI entry point returning an exit code.
 #[tokio::main(flavor = "multi_thread")]
 async fn main() -> ExitCode {
-    match run().await {
+    let cli = match Cli::try_parse() {
+        Ok(cli) => cli,
+        Err(err) => return emit_parse_error(&err),
+    };
+    let errors_json = cli.errors_json;
+    match run(cli).await {
         Ok(code) => code,
+        Err(err) if errors_json => emit_error_json(&err),
         Err(err) => emit_error(&err.to_string()),
     }
 }
 
 /// Executes the CLI command dispatcher.
-async fn run() -> CliResult<ExitCode> {
-    let cli = Cli::parse();
+async fn run(cli: Cli) -> CliResult<ExitCode> {
     let env_lang = std::env::var(LANG_ENV).ok();
     let locale = resolve_locale(cli.lang, env_lang.as_deref())?;
     set_locale(locale);
     if locale != Locale::En {
         write_stderr_line(&t!("i18n.disclaimer.machine_translated"))
-            .map_err(|err| CliError::new(output_error("stderr", &err)))?;
+            .map_err(|err| CliError::new(CliErrorKind::Io, output_error("stderr", &err)))?;
     }
 
     if cli.show_version {
         let version = env!("CARGO_PKG_VERSION");
         write_stdout_line(&t!("main.version", version = version))
-            .map_err(|err| CliError::new(output_error("stdout", &err)))?;
+            .map_err(|err| CliError::new(CliErrorKind::Io, output_error("stdout", &err)))?;
         return Ok(ExitCode::SUCCESS);
     }
 
@@ -1323,12 +1652,17 @@ async fn run() -> CliResult<ExitCode> {
 
 /// Executes the `serve` command.
 async fn command_serve(command: ServeCommand) -> CliResult<ExitCode> {
-    let config = DecisionGateConfig::load(command.config.as_deref())
-        .map_err(|err| CliError::new(t!("serve.config.load_failed", error = err)))?;
+    let config = DecisionGateConfig::load_with_profile(
+        command.config.as_deref(),
+        command.profile.as_deref(),
+    )
+    .map_err(|err| {
+        CliError::new(CliErrorKind::Config, t!("serve.config.load_failed", error = err))
+    })?;
     let allow_non_loopback = resolve_allow_non_loopback(command.allow_non_loopback)
-        .map_err(|err| CliError::new(err.to_string()))?;
+        .map_err(|err| CliError::new(CliErrorKind::Config, err.to_string()))?;
     let bind_outcome = enforce_local_only(&config, allow_non_loopback)
-        .map_err(|err| CliError::new(err.to_string()))?;
+        .map_err(|err| CliError::new(CliErrorKind::Config, err.to_string()))?;
     warn_local_only(&config)?;
     warn_loopback_only_transport(&bind_outcome, allow_non_loopback)?;
     if bind_outcome.network_exposed {
@@ -1338,11 +1672,16 @@ async fn command_serve(command: ServeCommand) -> CliResult<ExitCode> {
     let server = tokio::task::spawn_blocking(move || McpServer::from_config(config))
         .await
         .map_err(|err| {
-            CliError::new(t!("serve.init_failed", error = format!("init join failed: {err}")))
+            CliError::new(
+                CliErrorKind::Internal,
+                t!("serve.init_failed", error = format!("init join failed: {err}")),
+            )
         })?
-        .map_err(|err| CliError::new(t!("serve.init_failed", error = err)))?;
+        .map_err(|err| {
+            CliError::new(CliErrorKind::Internal, t!("serve.init_failed", error = err))
+        })?;
     server.serve().await.map_err(|err: decision_gate_mcp::server::McpServerError| {
-        CliError::new(t!("serve.failed", error = err))
+        CliError::new(CliErrorKind::Internal, t!("serve.failed", error = err))
     })?;
 
     Ok(ExitCode::SUCCESS)
@@ -1355,7 +1694,7 @@ fn warn_local_only(config: &DecisionGateConfig) -> CliResult<()> {
         return Ok(());
     }
     write_stderr_line(&t!("serve.warn.local_only_auth"))
-        .map_err(|err| CliError::new(output_error("stderr", &err)))?;
+        .map_err(|err| CliError::new(CliErrorKind::Io, output_error("stderr", &err)))?;
     Ok(())
 }
 
@@ -1371,7 +1710,7 @@ fn warn_loopback_only_transport(outcome: &BindOutcome, allow_non_loopback: bool)
         return Ok(());
     }
     write_stderr_line(&t!("serve.warn.loopback_only_transport", env = ALLOW_NON_LOOPBACK_ENV))
-        .map_err(|err| CliError::new(output_error("stderr", &err)))?;
+        .map_err(|err| CliError::new(CliErrorKind::Io, output_error("stderr", &err)))?;
     Ok(())
 }
 
@@ -1411,27 +1750,30 @@ fn warn_network_exposure(outcome: &BindOutcome) -> CliResult<()> {
         ServerAuthMode::Mtls => "mtls",
     };
     write_stderr_line(&t!("serve.warn.network.header"))
-        .map_err(|err| CliError::new(output_error("stderr", &err)))?;
+        .map_err(|err| CliError::new(CliErrorKind::Io, output_error("stderr", &err)))?;
     write_stderr_line(&t!("serve.warn.network.bind", bind = addr))
-        .map_err(|err| CliError::new(output_error("stderr", &err)))?;
+        .map_err(|err| CliError::new(CliErrorKind::Io, output_error("stderr", &err)))?;
     write_stderr_line(&t!("serve.warn.network.auth", mode = auth_mode))
-        .map_err(|err| CliError::new(output_error("stderr", &err)))?;
+        .map_err(|err| CliError::new(CliErrorKind::Io, output_error("stderr", &err)))?;
     write_stderr_line(&t!("serve.warn.network.tls", tls = tls_status))
-        .map_err(|err| CliError::new(output_error("stderr", &err)))?;
+        .map_err(|err| CliError::new(CliErrorKind::Io, output_error("stderr", &err)))?;
     write_stderr_line(&t!("serve.warn.network.audit", status = audit_status))
-        .map_err(|err| CliError::new(output_error("stderr", &err)))?;
+        .map_err(|err| CliError::new(CliErrorKind::Io, output_error("stderr", &err)))?;
     write_stderr_line(&t!("serve.warn.network.rate_limit", status = rate_limit_status))
-        .map_err(|err| CliError::new(output_error("stderr", &err)))?;
+        .map_err(|err| CliError::new(CliErrorKind::Io, output_error("stderr", &err)))?;
     write_stderr_line(&t!("serve.warn.network.footer"))
-        .map_err(|err| CliError::new(output_error("stderr", &err)))?;
+        .map_err(|err| CliError::new(CliErrorKind::Io, output_error("stderr", &err)))?;
     Ok(())
 }
 
 /// Emits the top-level help message for the CLI.
 fn show_help() -> CliResult<()> {
     let mut command = Cli::command();
-    command.print_help().map_err(|err| CliError::new(output_error("stdout", &err)))?;
-    write_stdout_line("").map_err(|err| CliError::new(output_error("stdout", &err)))?;
+    command
+        .print_help()
+        .map_err(|err| CliError::new(CliErrorKind::Io, output_error("stdout", &err)))?;
+    write_stdout_line("")
+        .map_err(|err| CliError::new(CliErrorKind::Io, output_error("stdout", &err)))?;
     Ok(())
 }
 
@@ -1444,6 +1786,7 @@ fn command_runpack(command: RunpackCommand) -> CliResult<ExitCode> {
     match command {
         RunpackCommand::Export(command) => command_runpack_export(&command),
         RunpackCommand::Verify(command) => command_runpack_verify(command),
+        RunpackCommand::VerifyAttestation(command) => command_runpack_verify_attestation(&command),
         RunpackCommand::Pretty(command) => command_runpack_pretty(command),
     }
 }
@@ -1457,6 +1800,9 @@ fn command_authoring(command: AuthoringCommand) -> CliResult<ExitCode> {
     match command {
         AuthoringCommand::Validate(command) => command_authoring_validate(&command),
         AuthoringCommand::Normalize(command) => command_authoring_normalize(&command),
+        AuthoringCommand::Hash(command) => command_authoring_hash(&command),
+        AuthoringCommand::Render(command) => command_authoring_render(&command),
+        AuthoringCommand::FormatDsl(command) => command_authoring_format_dsl(&command),
     }
 }
 
@@ -1473,10 +1819,13 @@ fn command_config(command: ConfigCommand) -> CliResult<ExitCode> {
 
 /// Executes the config validation command.
 fn command_config_validate(command: &ConfigValidateCommand) -> CliResult<ExitCode> {
-    let _config = DecisionGateConfig::load(command.config.as_deref())
-        .map_err(|err| CliError::new(t!("config.load_failed", error = err)))?;
+    let _config = DecisionGateConfig::load_with_profile(
+        command.config.as_deref(),
+        command.profile.as_deref(),
+    )
+    .map_err(|err| CliError::new(CliErrorKind::Config, t!("config.load_failed", error = err)))?;
     write_stdout_line(&t!("config.validate.ok"))
-        .map_err(|err| CliError::new(output_error("stdout", &err)))?;
+        .map_err(|err| CliError::new(CliErrorKind::Io, output_error("stdout", &err)))?;
     Ok(ExitCode::SUCCESS)
 }
 
@@ -1503,16 +1852,21 @@ fn command_provider(command: ProviderCommand) -> CliResult<ExitCode> {
 
 /// Executes `provider contract get`.
 fn command_provider_contract_get(command: &ProviderContractGetCommand) -> CliResult<ExitCode> {
-    let config = DecisionGateConfig::load(command.config.as_deref())
-        .map_err(|err| CliError::new(t!("config.load_failed", error = err)))?;
+    let config = DecisionGateConfig::load(command.config.as_deref()).map_err(|err| {
+        CliError::new(CliErrorKind::Config, t!("config.load_failed", error = err))
+    })?;
     if !config.provider_discovery.is_allowed(&command.provider) {
-        return Err(CliError::new(t!("provider.discovery.denied", provider = command.provider)));
+        return Err(CliError::new(
+            CliErrorKind::Denied,
+            t!("provider.discovery.denied", provider = command.provider),
+        ));
     }
-    let registry = CapabilityRegistry::from_config(&config)
-        .map_err(|err| CliError::new(t!("provider.discovery.failed", error = err)))?;
-    let view = registry
-        .provider_contract_view(&command.provider)
-        .map_err(|err| CliError::new(t!("provider.discovery.failed", error = err)))?;
+    let registry = CapabilityRegistry::from_config(&config).map_err(|err| {
+        CliError::new(CliErrorKind::Config, t!("provider.discovery.failed", error = err))
+    })?;
+    let view = registry.provider_contract_view(&command.provider).map_err(|err| {
+        CliError::new(CliErrorKind::NotFound, t!("provider.discovery.failed", error = err))
+    })?;
     let response = decision_gate_mcp::tools::ProviderContractGetResponse {
         provider_id: view.provider_id,
         contract: view.contract,
@@ -1528,16 +1882,21 @@ fn command_provider_contract_get(command: &ProviderContractGetCommand) -> CliRes
 fn command_provider_check_schema_get(
     command: &ProviderCheckSchemaGetCommand,
 ) -> CliResult<ExitCode> {
-    let config = DecisionGateConfig::load(command.config.as_deref())
-        .map_err(|err| CliError::new(t!("config.load_failed", error = err)))?;
+    let config = DecisionGateConfig::load(command.config.as_deref()).map_err(|err| {
+        CliError::new(CliErrorKind::Config, t!("config.load_failed", error = err))
+    })?;
     if !config.provider_discovery.is_allowed(&command.provider) {
-        return Err(CliError::new(t!("provider.discovery.denied", provider = command.provider)));
+        return Err(CliError::new(
+            CliErrorKind::Denied,
+            t!("provider.discovery.denied", provider = command.provider),
+        ));
     }
-    let registry = CapabilityRegistry::from_config(&config)
-        .map_err(|err| CliError::new(t!("provider.discovery.failed", error = err)))?;
-    let view = registry
-        .check_schema_view(&command.provider, &command.check_id)
-        .map_err(|err| CliError::new(t!("provider.discovery.failed", error = err)))?;
+    let registry = CapabilityRegistry::from_config(&config).map_err(|err| {
+        CliError::new(CliErrorKind::Config, t!("provider.discovery.failed", error = err))
+    })?;
+    let view = registry.check_schema_view(&command.provider, &command.check_id).map_err(|err| {
+        CliError::new(CliErrorKind::NotFound, t!("provider.discovery.failed", error = err))
+    })?;
     let response = decision_gate_mcp::tools::ProviderCheckSchemaGetResponse {
         provider_id: view.provider_id,
         check_id: view.check_id,
@@ -1557,15 +1916,17 @@ fn command_provider_check_schema_get(
 
 /// Executes `provider list`.
 fn command_provider_list(command: &ProviderListCommand) -> CliResult<ExitCode> {
-    let config = DecisionGateConfig::load(command.config.as_deref())
-        .map_err(|err| CliError::new(t!("config.load_failed", error = err)))?;
-    let registry = CapabilityRegistry::from_config(&config)
-        .map_err(|err| CliError::new(t!("provider.discovery.failed", error = err)))?;
+    let config = DecisionGateConfig::load(command.config.as_deref()).map_err(|err| {
+        CliError::new(CliErrorKind::Config, t!("config.load_failed", error = err))
+    })?;
+    let registry = CapabilityRegistry::from_config(&config).map_err(|err| {
+        CliError::new(CliErrorKind::Config, t!("provider.discovery.failed", error = err))
+    })?;
     let mut providers = Vec::new();
     for (provider_id, checks) in registry.list_providers() {
-        let view = registry
-            .provider_contract_view(&provider_id)
-            .map_err(|err| CliError::new(t!("provider.discovery.failed", error = err)))?;
+        let view = registry.provider_contract_view(&provider_id).map_err(|err| {
+            CliError::new(CliErrorKind::NotFound, t!("provider.discovery.failed", error = err))
+        })?;
         let transport = match view.source {
             decision_gate_mcp::capabilities::ProviderContractSource::Builtin => {
                 decision_gate_mcp::tools::ProviderTransport::Builtin
@@ -1619,7 +1980,7 @@ async fn command_schema_register(command: SchemaRegisterCommand) -> CliResult<Ex
     let result = client
         .call_tool(decision_gate_core::ToolName::SchemasRegister, input)
         .await
-        .map_err(|err| CliError::new(t!("mcp.client.failed", error = err)))?;
+        .map_err(|err| CliError::new(CliErrorKind::Remote, t!("mcp.client.failed", error = err)))?;
     write_json_value(&result)?;
     Ok(ExitCode::SUCCESS)
 }
@@ -1647,7 +2008,7 @@ async fn command_schema_list(command: SchemaListCommand) -> CliResult<ExitCode>
     let result = client
         .call_tool(decision_gate_core::ToolName::SchemasList, input)
         .await
-        .map_err(|err| CliError::new(t!("mcp.client.failed", error = err)))?;
+        .map_err(|err| CliError::new(CliErrorKind::Remote, t!("mcp.client.failed", error = err)))?;
     write_json_value(&result)?;
     Ok(ExitCode::SUCCESS)
 }
@@ -1663,13 +2024,14 @@ async fn command_schema_get(command: SchemaGetCommand) -> CliResult<ExitCode> {
         schema_id: DataShapeId::from(command.schema_id.as_str()),
         version: DataShapeVersion::from(command.version.as_str()),
     };
-    let input = serde_json::to_value(&request)
-        .map_err(|err| CliError::new(t!("mcp.client.input_parse_failed", error = err)))?;
+    let input = serde_json::to_value(&request).map_err(|err| {
+        CliError::new(CliErrorKind::InvalidInput, t!("mcp.client.input_parse_failed", error = err))
+    })?;
     validate_mcp_tool_input(decision_gate_core::ToolName::SchemasGet, &input)?;
     let result = client
         .call_tool(decision_gate_core::ToolName::SchemasGet, input)
         .await
-        .map_err(|err| CliError::new(t!("mcp.client.failed", error = err)))?;
+        .map_err(|err| CliError::new(CliErrorKind::Remote, t!("mcp.client.failed", error = err)))?;
     write_json_value(&result)?;
     Ok(ExitCode::SUCCESS)
 }
@@ -1684,8 +2046,10 @@ fn command_store(command: StoreCommand) -> CliResult<ExitCode> {
         StoreCommand::List(command) => command_store_list(&command),
         StoreCommand::Get(command) => command_store_get(&command),
         StoreCommand::Export(command) => command_store_export(&command),
+        StoreCommand::Diff(command) => command_store_diff(&command),
         StoreCommand::Verify(command) => command_store_verify(&command),
         StoreCommand::Prune(command) => command_store_prune(&command),
+        StoreCommand::Gc(command) => command_store_gc(&command),
     }
 }
 
@@ -1696,7 +2060,7 @@ fn command_store_list(command: &StoreListCommand) -> CliResult<ExitCode> {
     let namespace_id = command.namespace_id.map(parse_namespace_id).transpose()?;
     let runs = store
         .list_runs(tenant_id, namespace_id)
-        .map_err(|err| CliError::new(t!("store.list.failed", error = err)))?;
+        .map_err(|err| CliError::new(CliErrorKind::Remote, t!("store.list.failed", error = err)))?;
     let output = StoreListOutput {
         runs,
     };
@@ -1712,19 +2076,34 @@ fn command_store_get(command: &StoreGetCommand) -> CliResult<ExitCode> {
     let namespace_id = parse_namespace_id(command.namespace_id)?;
     let run_id = RunId::new(command.run_id.clone());
     let state = if let Some(version) = command.version {
-        store
-            .load_version(tenant_id, namespace_id, &run_id, version)
-            .map_err(|err| CliError::new(t!("store.get.failed", error = err)))?
+        store.load_version(tenant_id, namespace_id, &run_id, version).map_err(|err| {
+            CliError::new(CliErrorKind::Remote, t!("store.get.failed", error = err))
+        })?
     } else {
-        store
-            .load(&tenant_id, &namespace_id, &run_id)
-            .map_err(|err| CliError::new(t!("store.get.failed", error = err)))?
+        store.load(&tenant_id, &namespace_id, &run_id).map_err(|err| {
+            CliError::new(CliErrorKind::Remote, t!("store.get.failed", error = err))
+        })?
     };
     let Some(state) = state else {
-        return Err(CliError::new(t!("store.get.not_found", run_id = run_id.as_str())));
+        return Err(CliError::new(
+            CliErrorKind::NotFound,
+            t!("store.get.not_found", run_id = run_id.as_str()),
+        ));
+    };
+    let bytes = match command.format {
+        EncodingFormat::Json => {
+            let bytes = canonical_output_bytes(&state)?;
+            write_stdout_bytes_with_newline(&bytes)?;
+            bytes
+        }
+        #[cfg(feature = "msgpack")]
+        EncodingFormat::Msgpack => {
+            let bytes = canonical_msgpack_bytes(&state)?;
+            write_stdout_bytes(&bytes)
+                .map_err(|err| CliError::new(CliErrorKind::Io, output_error("stdout", &err)))?;
+            bytes
+        }
     };
-    let bytes = canonical_output_bytes(&state)?;
-    write_stdout_bytes_with_newline(&bytes)?;
     write_output_artifacts_bytes(&bytes, &command.output)?;
     Ok(ExitCode::SUCCESS)
 }
@@ -1736,55 +2115,115 @@ fn command_store_export(command: &StoreExportCommand) -> CliResult<ExitCode> {
     let namespace_id = parse_namespace_id(command.namespace_id)?;
     let run_id = RunId::new(command.run_id.clone());
     let state = if let Some(version) = command.version {
-        store
-            .load_version(tenant_id, namespace_id, &run_id, version)
-            .map_err(|err| CliError::new(t!("store.get.failed", error = err)))?
+        store.load_version(tenant_id, namespace_id, &run_id, version).map_err(|err| {
+            CliError::new(CliErrorKind::Remote, t!("store.get.failed", error = err))
+        })?
     } else {
-        store
-            .load(&tenant_id, &namespace_id, &run_id)
-            .map_err(|err| CliError::new(t!("store.get.failed", error = err)))?
+        store.load(&tenant_id, &namespace_id, &run_id).map_err(|err| {
+            CliError::new(CliErrorKind::Remote, t!("store.get.failed", error = err))
+        })?
     };
     let Some(state) = state else {
-        return Err(CliError::new(t!("store.get.not_found", run_id = run_id.as_str())));
+        return Err(CliError::new(
+            CliErrorKind::NotFound,
+            t!("store.get.not_found", run_id = run_id.as_str()),
+        ));
     };
     let bytes = canonical_output_bytes(&state)?;
     fs::write(&command.output, &bytes).map_err(|err| {
-        CliError::new(t!("store.export.write_failed", path = command.output.display(), error = err))
+        CliError::new(
+            CliErrorKind::Io,
+            t!("store.export.write_failed", path = command.output.display(), error = err),
+        )
     })?;
     write_output_artifacts_bytes(&bytes, &command.artifacts)?;
     write_stdout_line(&t!("store.export.ok", path = command.output.display()))
-        .map_err(|err| CliError::new(output_error("stdout", &err)))?;
+        .map_err(|err| CliError::new(CliErrorKind::Io, output_error("stdout", &err)))?;
+    Ok(ExitCode::SUCCESS)
+}
+
+/// Executes `store diff`.
+fn command_store_diff(command: &StoreDiffCommand) -> CliResult<ExitCode> {
+    let store = open_sqlite_store(&command.location)?;
+    let tenant_id = parse_tenant_id(command.tenant_id)?;
+    let namespace_id = parse_namespace_id(command.namespace_id)?;
+    let run_id = RunId::new(command.run_id.clone());
+    let load = |version: i64| -> CliResult<RunState> {
+        store
+            .load_version(tenant_id, namespace_id, &run_id, version)
+            .map_err(|err| {
+                CliError::new(CliErrorKind::Remote, t!("store.get.failed", error = err))
+            })?
+            .ok_or_else(|| {
+                CliError::new(
+                    CliErrorKind::NotFound,
+                    t!("store.verify.version_missing", version = version),
+                )
+            })
+    };
+    let from = load(command.from_version)?;
+    let to = load(command.to_version)?;
+    let output = StoreDiffOutput {
+        tenant_id,
+        namespace_id,
+        run_id: run_id.clone(),
+        from_version: command.from_version,
+        to_version: command.to_version,
+        diff: from.diff(&to),
+    };
+    let text = render_store_diff_text(&output);
+    emit_structured_output(&output, command.format, &command.output, text)?;
     Ok(ExitCode::SUCCESS)
 }
 
 /// Executes `store verify`.
 fn command_store_verify(command: &StoreVerifyCommand) -> CliResult<ExitCode> {
+    if command.chain {
+        return command_store_verify_chain(command);
+    }
     let store = open_sqlite_store(&command.location)?;
     let tenant_id = parse_tenant_id(command.tenant_id)?;
     let namespace_id = parse_namespace_id(command.namespace_id)?;
     let run_id = RunId::new(command.run_id.clone());
-    let versions = store
-        .list_run_versions(tenant_id, namespace_id, &run_id)
-        .map_err(|err| CliError::new(t!("store.verify.failed", error = err)))?;
+    let versions = store.list_run_versions(tenant_id, namespace_id, &run_id).map_err(|err| {
+        CliError::new(CliErrorKind::Remote, t!("store.verify.failed", error = err))
+    })?;
     if versions.is_empty() {
-        return Err(CliError::new(t!("store.get.not_found", run_id = run_id.as_str())));
+        return Err(CliError::new(
+            CliErrorKind::NotFound,
+            t!("store.get.not_found", run_id = run_id.as_str()),
+        ));
     }
     let summary = if let Some(version) = command.version {
         versions
             .iter()
             .find(|entry| entry.version == version)
-            .ok_or_else(|| CliError::new(t!("store.verify.version_missing", version = version)))?
+            .ok_or_else(|| {
+                CliError::new(
+                    CliErrorKind::NotFound,
+                    t!("store.verify.version_missing", version = version),
+                )
+            })?
             .clone()
     } else {
-        versions.first().cloned().ok_or_else(|| CliError::new(t!("store.verify.no_versions")))?
+        versions
+            .first()
+            .cloned()
+            .ok_or_else(|| CliError::new(CliErrorKind::NotFound, t!("store.verify.no_versions")))?
     };
     let state = store
         .load_version(tenant_id, namespace_id, &run_id, summary.version)
-        .map_err(|err| CliError::new(t!("store.verify.failed", error = err)))?
-        .ok_or_else(|| CliError::new(t!("store.get.not_found", run_id = run_id.as_str())))?;
+        .map_err(|err| CliError::new(CliErrorKind::Remote, t!("store.verify.failed", error = err)))?
+        .ok_or_else(|| {
+            CliError::new(
+                CliErrorKind::NotFound,
+                t!("store.get.not_found", run_id = run_id.as_str()),
+            )
+        })?;
     let algorithm = parse_hash_algorithm_label(&summary.hash_algorithm)?;
-    let computed = hash_canonical_json(algorithm, &state)
-        .map_err(|err| CliError::new(t!("store.verify.failed", error = err)))?;
+    let computed = hash_canonical_json(algorithm, &state).map_err(|err| {
+        CliError::new(CliErrorKind::Internal, t!("store.verify.failed", error = err))
+    })?;
     let stored = HashDigest {
         algorithm,
         value: summary.state_hash.clone(),
@@ -1814,29 +2253,69 @@ fn command_store_verify(command: &StoreVerifyCommand) -> CliResult<ExitCode> {
     Ok(exit_code)
 }
 
+/// Executes `store verify --chain`.
+fn command_store_verify_chain(command: &StoreVerifyCommand) -> CliResult<ExitCode> {
+    let store = open_sqlite_store(&command.location)?;
+    let tenant_id = parse_tenant_id(command.tenant_id)?;
+    let namespace_id = parse_namespace_id(command.namespace_id)?;
+    let run_id = RunId::new(command.run_id.clone());
+    let report = store
+        .verify_run_chain(tenant_id, namespace_id, &run_id)
+        .map_err(|err| CliError::new(CliErrorKind::Remote, t!("store.verify.failed", error = err)))?
+        .ok_or_else(|| {
+            CliError::new(
+                CliErrorKind::NotFound,
+                t!("store.get.not_found", run_id = run_id.as_str()),
+            )
+        })?;
+    let status = if report.is_intact() { StoreVerifyStatus::Pass } else { StoreVerifyStatus::Fail };
+    let output = StoreChainVerifyOutput {
+        tenant_id,
+        namespace_id,
+        run_id,
+        status,
+        first_version: report.first_version,
+        latest_version: report.latest_version,
+        versions_checked: report.versions_checked,
+        issues: report.issues,
+    };
+    let text = render_store_chain_verify_text(&output);
+    emit_structured_output(&output, command.format, &command.output, text)?;
+    let exit_code = match output.status {
+        StoreVerifyStatus::Pass => ExitCode::SUCCESS,
+        StoreVerifyStatus::Fail => ExitCode::FAILURE,
+    };
+    Ok(exit_code)
+}
+
 /// Executes `store prune`.
 fn command_store_prune(command: &StorePruneCommand) -> CliResult<ExitCode> {
     if command.keep == 0 {
-        return Err(CliError::new(t!("store.prune.keep_invalid")));
+        return Err(CliError::new(CliErrorKind::InvalidInput, t!("store.prune.keep_invalid")));
     }
     let store = open_sqlite_store(&command.location)?;
     let tenant_id = parse_tenant_id(command.tenant_id)?;
     let namespace_id = parse_namespace_id(command.namespace_id)?;
     let run_id = RunId::new(command.run_id.clone());
     let pruned = if command.dry_run {
-        let versions = store
-            .list_run_versions(tenant_id, namespace_id, &run_id)
-            .map_err(|err| CliError::new(t!("store.prune.failed", error = err)))?;
+        let versions =
+            store.list_run_versions(tenant_id, namespace_id, &run_id).map_err(|err| {
+                CliError::new(CliErrorKind::Remote, t!("store.prune.failed", error = err))
+            })?;
         let total = versions.len();
-        let keep = usize::try_from(command.keep)
-            .map_err(|_| CliError::new(t!("store.prune.keep_invalid")))?;
+        let keep = usize::try_from(command.keep).map_err(|_| {
+            CliError::new(CliErrorKind::InvalidInput, t!("store.prune.keep_invalid"))
+        })?;
         u64::try_from(total.saturating_sub(keep)).map_err(|_| {
-            CliError::new(t!("store.prune.failed", error = "pruned count exceeds u64"))
+            CliError::new(
+                CliErrorKind::Internal,
+                t!("store.prune.failed", error = "pruned count exceeds u64"),
+            )
         })?
     } else {
-        store
-            .prune_versions(tenant_id, namespace_id, &run_id, command.keep)
-            .map_err(|err| CliError::new(t!("store.prune.failed", error = err)))?
+        store.prune_versions(tenant_id, namespace_id, &run_id, command.keep).map_err(|err| {
+            CliError::new(CliErrorKind::Remote, t!("store.prune.failed", error = err))
+        })?
     };
     let output = StorePruneOutput {
         tenant_id,
@@ -1851,14 +2330,40 @@ fn command_store_prune(command: &StorePruneCommand) -> CliResult<ExitCode> {
     Ok(ExitCode::SUCCESS)
 }
 
+/// Executes `store gc`.
+fn command_store_gc(command: &StoreGcCommand) -> CliResult<ExitCode> {
+    let config = resolve_sqlite_store_config(&command.location)?;
+    if config.retention.is_empty() {
+        return Err(CliError::new(CliErrorKind::Config, t!("store.gc.no_policies")));
+    }
+    let store = SqliteRunStateStore::new(config)
+        .map_err(|err| CliError::new(CliErrorKind::Io, t!("store.open_failed", error = err)))?;
+    let report = store
+        .apply_retention(command.dry_run)
+        .map_err(|err| CliError::new(CliErrorKind::Remote, t!("store.gc.failed", error = err)))?;
+    let output = StoreGcOutput {
+        runs_checked: report.runs_checked,
+        runs_pruned: report.runs_pruned,
+        versions_pruned: report.versions_pruned,
+        dry_run: command.dry_run,
+    };
+    let text = render_store_gc_text(&output);
+    emit_structured_output(&output, command.format, &command.output, text)?;
+    Ok(ExitCode::SUCCESS)
+}
+
 /// Resolves the `SQLite` store configuration for CLI operations.
 fn resolve_sqlite_store_config(location: &StoreLocationArgs) -> CliResult<SqliteStoreConfig> {
     if let Some(store_path) = &location.store_path {
         if let Some(config_path) = location.config.as_deref() {
-            let config = DecisionGateConfig::load(Some(config_path))
-                .map_err(|err| CliError::new(t!("config.load_failed", error = err)))?;
+            let config = DecisionGateConfig::load(Some(config_path)).map_err(|err| {
+                CliError::new(CliErrorKind::Config, t!("config.load_failed", error = err))
+            })?;
             if config.run_state_store.store_type != config::RunStateStoreType::Sqlite {
-                return Err(CliError::new(t!("store.config.unsupported_backend")));
+                return Err(CliError::new(
+                    CliErrorKind::Config,
+                    t!("store.config.unsupported_backend"),
+                ));
             }
             let sqlite_config = SqliteStoreConfig {
                 path: store_path.clone(),
@@ -1868,6 +2373,7 @@ fn resolve_sqlite_store_config(location: &StoreLocationArgs) -> CliResult<Sqlite
                 max_versions: config.run_state_store.max_versions,
                 schema_registry_max_schema_bytes: None,
                 schema_registry_max_entries: None,
+                retention: config.run_state_store.retention,
             };
             return Ok(sqlite_config);
         }
@@ -1879,18 +2385,20 @@ fn resolve_sqlite_store_config(location: &StoreLocationArgs) -> CliResult<Sqlite
             max_versions: None,
             schema_registry_max_schema_bytes: None,
             schema_registry_max_entries: None,
+            retention: Vec::new(),
         });
     }
-    let config = DecisionGateConfig::load(location.config.as_deref())
-        .map_err(|err| CliError::new(t!("config.load_failed", error = err)))?;
+    let config = DecisionGateConfig::load(location.config.as_deref()).map_err(|err| {
+        CliError::new(CliErrorKind::Config, t!("config.load_failed", error = err))
+    })?;
     if config.run_state_store.store_type != config::RunStateStoreType::Sqlite {
-        return Err(CliError::new(t!("store.config.unsupported_backend")));
+        return Err(CliError::new(CliErrorKind::Config, t!("store.config.unsupported_backend")));
     }
     let path = config
         .run_state_store
         .path
         .clone()
-        .ok_or_else(|| CliError::new(t!("store.config.missing_path")))?;
+        .ok_or_else(|| CliError::new(CliErrorKind::Config, t!("store.config.missing_path")))?;
     Ok(SqliteStoreConfig {
         path,
         busy_timeout_ms: config.run_state_store.busy_timeout_ms,
@@ -1899,6 +2407,7 @@ fn resolve_sqlite_store_config(location: &StoreLocationArgs) -> CliResult<Sqlite
         max_versions: config.run_state_store.max_versions,
         schema_registry_max_schema_bytes: None,
         schema_registry_max_entries: None,
+        retention: config.run_state_store.retention,
     })
 }
 
@@ -1906,7 +2415,7 @@ fn resolve_sqlite_store_config(location: &StoreLocationArgs) -> CliResult<Sqlite
 fn open_sqlite_store(location: &StoreLocationArgs) -> CliResult<SqliteRunStateStore> {
     let config = resolve_sqlite_store_config(location)?;
     SqliteRunStateStore::new(config)
-        .map_err(|err| CliError::new(t!("store.open_failed", error = err)))
+        .map_err(|err| CliError::new(CliErrorKind::Io, t!("store.open_failed", error = err)))
 }
 
 /// Output for `store list`.
@@ -1916,6 +2425,23 @@ struct StoreListOutput {
     runs: Vec<RunSummary>,
 }
 
+/// Output for `store diff`.
+#[derive(Serialize)]
+struct StoreDiffOutput {
+    /// Tenant identifier.
+    tenant_id: TenantId,
+    /// Namespace identifier.
+    namespace_id: NamespaceId,
+    /// Run identifier.
+    run_id: RunId,
+    /// Version compared from.
+    from_version: i64,
+    /// Version compared to.
+    to_version: i64,
+    /// Structural differences between the two versions.
+    diff: RunStateDiff,
+}
+
 /// Verification status for stored run states.
 #[derive(Serialize, Copy, Clone)]
 #[serde(rename_all = "snake_case")]
@@ -1949,6 +2475,27 @@ struct StoreVerifyOutput {
     saved_at: i64,
 }
 
+/// Output for `store verify --chain`.
+#[derive(Serialize)]
+struct StoreChainVerifyOutput {
+    /// Tenant identifier.
+    tenant_id: TenantId,
+    /// Namespace identifier.
+    namespace_id: NamespaceId,
+    /// Run identifier.
+    run_id: RunId,
+    /// Verification status.
+    status: StoreVerifyStatus,
+    /// Oldest retained version.
+    first_version: Option<i64>,
+    /// Latest version recorded for the run.
+    latest_version: i64,
+    /// Number of stored versions checked.
+    versions_checked: usize,
+    /// Chain breaks found, in version order.
+    issues: Vec<RunChainIssue>,
+}
+
 /// Output for `store prune`.
 #[derive(Serialize)]
 struct StorePruneOutput {
@@ -1966,6 +2513,19 @@ struct StorePruneOutput {
     dry_run: bool,
 }
 
+/// Output for `store gc`.
+#[derive(Serialize)]
+struct StoreGcOutput {
+    /// Number of runs covered by a retention policy.
+    runs_checked: u64,
+    /// Number of runs with pruned versions.
+    runs_pruned: u64,
+    /// Number of versions pruned.
+    versions_pruned: u64,
+    /// Whether the gc was a dry run.
+    dry_run: bool,
+}
+
 /// Renders store list output in text form.
 fn render_store_list_text(output: &StoreListOutput) -> String {
     let mut buffer = String::new();
@@ -1990,22 +2550,88 @@ fn render_store_list_text(output: &StoreListOutput) -> String {
     buffer
 }
 
-/// Renders store verification output in text form.
-fn render_store_verify_text(output: &StoreVerifyOutput) -> String {
-    let status = match output.status {
-        StoreVerifyStatus::Pass => t!("store.verify.status.pass"),
-        StoreVerifyStatus::Fail => t!("store.verify.status.fail"),
-    };
-    let mut buffer = String::new();
-    buffer.push_str(&t!("store.verify.header"));
-    buffer.push('\n');
-    buffer.push_str(&t!(
-        "store.verify.summary",
-        status = status,
-        version = output.version,
-        saved_at = output.saved_at
-    ));
-    buffer.push('\n');
+/// Renders store diff output in text form.
+fn render_store_diff_text(output: &StoreDiffOutput) -> String {
+    let diff = &output.diff;
+    let mut lines = vec![t!(
+        "store.diff.header",
+        run_id = output.run_id.as_str(),
+        from = output.from_version,
+        to = output.to_version
+    )];
+    if diff.is_empty() {
+        lines.push(t!("store.diff.none"));
+    }
+    if let Some(change) = &diff.status {
+        lines.push(t!(
+            "store.diff.status",
+            from = format_run_status(change.from),
+            to = format_run_status(change.to)
+        ));
+    }
+    if let Some(change) = &diff.current_stage {
+        lines.push(t!("store.diff.stage", from = change.from.as_str(), to = change.to.as_str()));
+    }
+    for gate in &diff.gates {
+        lines.push(t!(
+            "store.diff.gate",
+            stage_id = gate.stage_id.as_str(),
+            gate_id = gate.gate_id.as_str(),
+            change = format_diff_kind(gate.change),
+            from = format_gate_status(gate.from_status),
+            to = format_gate_status(gate.to_status)
+        ));
+    }
+    for disclosure in &diff.disclosures {
+        lines.push(t!(
+            "store.diff.disclosure",
+            packet_id = disclosure.packet_id.as_str(),
+            stage_id = disclosure.stage_id.as_str(),
+            change = format_diff_kind(disclosure.change),
+            added = disclosure.dispatches_added.len(),
+            removed = disclosure.dispatches_removed.len()
+        ));
+    }
+    let mut buffer = lines.join("\n");
+    buffer.push('\n');
+    buffer
+}
+
+/// Formats a diff entry kind for text output.
+fn format_diff_kind(kind: DiffKind) -> String {
+    match kind {
+        DiffKind::Added => t!("store.diff.kind.added"),
+        DiffKind::Removed => t!("store.diff.kind.removed"),
+        DiffKind::Changed => t!("store.diff.kind.changed"),
+    }
+}
+
+/// Formats a gate status for text output; `None` means the gate is absent.
+fn format_gate_status(status: Option<TriState>) -> String {
+    match status {
+        Some(TriState::True) => t!("store.diff.gate_status.true"),
+        Some(TriState::False) => t!("store.diff.gate_status.false"),
+        Some(TriState::Unknown) => t!("store.diff.gate_status.unknown"),
+        None => t!("store.diff.gate_status.absent"),
+    }
+}
+
+/// Renders store verification output in text form.
+fn render_store_verify_text(output: &StoreVerifyOutput) -> String {
+    let status = match output.status {
+        StoreVerifyStatus::Pass => t!("store.verify.status.pass"),
+        StoreVerifyStatus::Fail => t!("store.verify.status.fail"),
+    };
+    let mut buffer = String::new();
+    buffer.push_str(&t!("store.verify.header"));
+    buffer.push('\n');
+    buffer.push_str(&t!(
+        "store.verify.summary",
+        status = status,
+        version = output.version,
+        saved_at = output.saved_at
+    ));
+    buffer.push('\n');
     buffer.push_str(&t!(
         "store.verify.hash",
         label = t!("store.verify.hash.stored"),
@@ -2023,6 +2649,44 @@ fn render_store_verify_text(output: &StoreVerifyOutput) -> String {
     buffer
 }
 
+/// Renders store chain verification output in text form.
+fn render_store_chain_verify_text(output: &StoreChainVerifyOutput) -> String {
+    let status = match output.status {
+        StoreVerifyStatus::Pass => t!("store.verify.status.pass"),
+        StoreVerifyStatus::Fail => t!("store.verify.status.fail"),
+    };
+    let mut buffer = String::new();
+    buffer.push_str(&t!("store.verify.chain.header"));
+    buffer.push('\n');
+    buffer.push_str(&t!(
+        "store.verify.chain.summary",
+        status = status,
+        checked = output.versions_checked,
+        latest = output.latest_version
+    ));
+    buffer.push('\n');
+    for issue in &output.issues {
+        let line = match issue {
+            RunChainIssue::MissingVersions {
+                from_version,
+                to_version,
+            } => t!("store.verify.chain.missing", from = from_version, to = to_version),
+            RunChainIssue::StateHashMismatch {
+                version,
+            } => t!("store.verify.chain.state_hash", version = version),
+            RunChainIssue::PrevHashMismatch {
+                version,
+            } => t!("store.verify.chain.prev_mismatch", version = version),
+            RunChainIssue::PrevHashMissing {
+                version,
+            } => t!("store.verify.chain.prev_missing", version = version),
+        };
+        buffer.push_str(&line);
+        buffer.push('\n');
+    }
+    buffer
+}
+
 /// Renders store prune output in text form.
 fn render_store_prune_text(output: &StorePruneOutput) -> String {
     t!(
@@ -2034,11 +2698,26 @@ fn render_store_prune_text(output: &StorePruneOutput) -> String {
     )
 }
 
+/// Renders store gc output in text form.
+fn render_store_gc_text(output: &StoreGcOutput) -> String {
+    t!(
+        "store.gc.summary",
+        runs_checked = output.runs_checked,
+        runs_pruned = output.runs_pruned,
+        versions_pruned = output.versions_pruned,
+        dry_run = output.dry_run
+    )
+}
+
 /// Parses a hash algorithm label string.
 fn parse_hash_algorithm_label(label: &str) -> CliResult<HashAlgorithm> {
     match label {
         "sha256" => Ok(HashAlgorithm::Sha256),
-        _ => Err(CliError::new(t!("store.verify.hash_algorithm_invalid", value = label))),
+        "blake3" => Ok(HashAlgorithm::Blake3),
+        _ => Err(CliError::new(
+            CliErrorKind::InvalidInput,
+            t!("store.verify.hash_algorithm_invalid", value = label),
+        )),
     }
 }
 
@@ -2071,7 +2750,7 @@ async fn command_docs_search(command: DocsSearchCommand) -> CliResult<ExitCode>
     let result = client
         .call_tool(decision_gate_core::ToolName::DecisionGateDocsSearch, input)
         .await
-        .map_err(|err| CliError::new(t!("mcp.client.failed", error = err)))?;
+        .map_err(|err| CliError::new(CliErrorKind::Remote, t!("mcp.client.failed", error = err)))?;
     write_json_value(&result)?;
     Ok(ExitCode::SUCCESS)
 }
@@ -2082,7 +2761,7 @@ async fn command_docs_list(command: DocsListCommand) -> CliResult<ExitCode> {
     let resources: Vec<ResourceMetadata> = client
         .list_resources()
         .await
-        .map_err(|err| CliError::new(t!("mcp.client.failed", error = err)))?;
+        .map_err(|err| CliError::new(CliErrorKind::Remote, t!("mcp.client.failed", error = err)))?;
     let output = serde_json::json!({ "resources": resources });
     write_json_value(&output)?;
     Ok(ExitCode::SUCCESS)
@@ -2094,7 +2773,7 @@ async fn command_docs_read(command: DocsReadCommand) -> CliResult<ExitCode> {
     let contents: Vec<ResourceContent> = client
         .read_resource(&command.uri)
         .await
-        .map_err(|err| CliError::new(t!("mcp.client.failed", error = err)))?;
+        .map_err(|err| CliError::new(CliErrorKind::Remote, t!("mcp.client.failed", error = err)))?;
     let output = serde_json::json!({ "contents": contents });
     write_json_value(&output)?;
     Ok(ExitCode::SUCCESS)
@@ -2108,6 +2787,7 @@ async fn command_docs_read(command: DocsReadCommand) -> CliResult<ExitCode> {
 fn command_broker(command: BrokerCommand) -> CliResult<ExitCode> {
     match command {
         BrokerCommand::Resolve(command) => command_broker_resolve(&command),
+        BrokerCommand::Head(command) => command_broker_head(&command),
         BrokerCommand::Dispatch(command) => command_broker_dispatch(&command),
     }
 }
@@ -2123,18 +2803,19 @@ fn command_broker_resolve(command: &BrokerResolveCommand) -> CliResult<ExitCode>
         &command.deny_hosts,
         command.allow_private,
     )?;
+    #[cfg(feature = "nats")]
+    let sources = sources.with_nats(broker_nats_source(&command.nats)?);
     let source = resolve_broker_source(&sources, &content_ref.uri)?;
-    let resolved = source
-        .fetch(&content_ref)
-        .map_err(|err| CliError::new(t!("broker.resolve.failed", error = err)))?;
+    let resolved = source.fetch(&content_ref).map_err(|err| {
+        CliError::new(CliErrorKind::Remote, t!("broker.resolve.failed", error = err))
+    })?;
     if let (Some(expected), Some(actual)) = (&input_content_type, &resolved.content_type)
         && !content_type_matches(expected, actual)
     {
-        return Err(CliError::new(t!(
-            "broker.resolve.content_type_mismatch",
-            expected = expected,
-            actual = actual
-        )));
+        return Err(CliError::new(
+            CliErrorKind::Verification,
+            t!("broker.resolve.content_type_mismatch", expected = expected, actual = actual),
+        ));
     }
     let effective_content_type = input_content_type.or_else(|| resolved.content_type.clone());
     let (payload, inferred_content_type) = resolve_payload_output(
@@ -2154,6 +2835,42 @@ fn command_broker_resolve(command: &BrokerResolveCommand) -> CliResult<ExitCode>
     Ok(ExitCode::SUCCESS)
 }
 
+/// Executes `broker head`.
+fn command_broker_head(command: &BrokerHeadCommand) -> CliResult<ExitCode> {
+    let kind = t!("broker.input.kind.head");
+    let input: BrokerResolveInput = read_broker_json(&command.input, &kind)?;
+    let (content_ref, _) = input.into_parts();
+    let sources = build_broker_sources(
+        command.file_root.clone(),
+        &command.allow_hosts,
+        &command.deny_hosts,
+        command.allow_private,
+    )?;
+    #[cfg(feature = "nats")]
+    let sources = sources.with_nats(broker_nats_source(&command.nats)?);
+    let output = broker_head_output(&sources, &content_ref)?;
+    let text = render_broker_head_text(&output);
+    emit_structured_output(&output, command.format, &command.output, text)?;
+    Ok(ExitCode::SUCCESS)
+}
+
+/// Looks up content metadata through the matching broker source.
+fn broker_head_output(
+    sources: &BrokerSources,
+    content_ref: &ContentRef,
+) -> CliResult<BrokerHeadOutput> {
+    let source = resolve_broker_source(sources, &content_ref.uri)?;
+    let metadata = source.metadata(content_ref).map_err(|err| {
+        CliError::new(CliErrorKind::Remote, t!("broker.head.failed", error = err))
+    })?;
+    Ok(BrokerHeadOutput {
+        uri: content_ref.uri.clone(),
+        content_hash: content_ref.content_hash.clone(),
+        content_type: metadata.content_type,
+        size_bytes: metadata.size_bytes,
+    })
+}
+
 /// Executes `broker dispatch`.
 fn command_broker_dispatch(command: &BrokerDispatchCommand) -> CliResult<ExitCode> {
     let kind = t!("broker.input.kind.dispatch");
@@ -2194,16 +2911,25 @@ fn command_broker_dispatch(command: &BrokerDispatchCommand) -> CliResult<ExitCod
         .source("https", sources.http)
         .sink(sink)
         .build()
-        .map_err(|err| CliError::new(t!("broker.dispatch.failed", error = err)))?;
-    let receipt = broker
-        .dispatch(&input.target, &input.envelope, &input.payload)
-        .map_err(|err| CliError::new(t!("broker.dispatch.failed", error = err)))?;
+        .map_err(|err| {
+            CliError::new(CliErrorKind::Remote, t!("broker.dispatch.failed", error = err))
+        })?;
+    let receipt =
+        broker.dispatch(&input.target, &input.envelope, &input.payload).map_err(|err| {
+            CliError::new(CliErrorKind::Remote, t!("broker.dispatch.failed", error = err))
+        })?;
     let captured = {
-        let mut guard = capture
-            .lock()
-            .map_err(|_| CliError::new(t!("broker.dispatch.failed", error = "capture poisoned")))?;
+        let mut guard = capture.lock().map_err(|_| {
+            CliError::new(
+                CliErrorKind::Remote,
+                t!("broker.dispatch.failed", error = "capture poisoned"),
+            )
+        })?;
         guard.take().ok_or_else(|| {
-            CliError::new(t!("broker.dispatch.failed", error = "no payload captured"))
+            CliError::new(
+                CliErrorKind::Remote,
+                t!("broker.dispatch.failed", error = "no payload captured"),
+            )
         })?
     };
     let output = BrokerDispatchOutput {
@@ -2287,6 +3013,19 @@ struct BrokerResolveOutput {
     bytes: usize,
 }
 
+/// Output for `broker head`.
+#[derive(Serialize)]
+struct BrokerHeadOutput {
+    /// Content reference URI.
+    uri: String,
+    /// Expected content hash from the reference (not verified).
+    content_hash: HashDigest,
+    /// Content type reported by the source, if known.
+    content_type: Option<String>,
+    /// Payload size in bytes reported by the source, if known.
+    size_bytes: Option<u64>,
+}
+
 /// Output for `broker dispatch`.
 #[derive(Serialize)]
 struct BrokerDispatchOutput {
@@ -2315,6 +3054,17 @@ struct BrokerSources {
     http: HttpSource,
     /// Inline source for broker resolution.
     inline: InlineSource,
+    /// NATS object-store source, when a connection is configured.
+    nats: Option<Box<dyn Source>>,
+}
+
+#[cfg(feature = "nats")]
+impl BrokerSources {
+    /// Replaces the NATS source.
+    fn with_nats(mut self, nats: Option<Box<dyn Source>>) -> Self {
+        self.nats = nats;
+        self
+    }
 }
 
 /// Builds broker sources for CLI commands.
@@ -2335,15 +3085,51 @@ fn build_broker_sources(
     if allow_private {
         policy = policy.allow_private_networks();
     }
-    let http = HttpSource::with_policy(policy)
-        .map_err(|err| CliError::new(t!("broker.http.init_failed", error = err)))?;
+    let http = HttpSource::with_policy(policy).map_err(|err| {
+        CliError::new(CliErrorKind::Internal, t!("broker.http.init_failed", error = err))
+    })?;
     Ok(BrokerSources {
         file,
         http,
         inline: InlineSource::new(),
+        nats: None,
     })
 }
 
+/// Connects the NATS source when `--nats-url` is set.
+#[cfg(feature = "nats")]
+fn broker_nats_source(args: &BrokerNatsArgs) -> CliResult<Option<Box<dyn Source>>> {
+    let Some(url) = &args.url else {
+        return Ok(None);
+    };
+    let timeout = Duration::from_millis(args.timeout_ms);
+    let connection = NatsClient::connect(url, timeout).map_err(|err| {
+        CliError::new(CliErrorKind::Remote, t!("broker.nats.connect_failed", error = err))
+    })?;
+    nats_source(connection, args).map(Some)
+}
+
+/// Builds a NATS source over `connection` from the CLI arguments.
+#[cfg(feature = "nats")]
+fn nats_source<C: NatsConnection + 'static>(
+    connection: C,
+    args: &BrokerNatsArgs,
+) -> CliResult<Box<dyn Source>> {
+    if let Some(subject) =
+        args.allow_subjects.iter().find(|subject| !is_valid_subject(subject, true))
+    {
+        return Err(CliError::new(
+            CliErrorKind::InvalidInput,
+            t!("broker.nats.invalid_subject", subject = subject),
+        ));
+    }
+    let policy = NatsSubjectPolicy::new().allow_subjects(&args.allow_subjects);
+    let source = NatsSource::new(connection, policy)
+        .with_timeout(Duration::from_millis(args.timeout_ms))
+        .with_max_bytes(args.max_bytes);
+    Ok(Box::new(source))
+}
+
 /// Resolves the broker source for a URI.
 fn resolve_broker_source<'a>(sources: &'a BrokerSources, uri: &str) -> CliResult<&'a dyn Source> {
     let scheme = uri.split(':').next().unwrap_or_default();
@@ -2352,7 +3138,13 @@ fn resolve_broker_source<'a>(sources: &'a BrokerSources, uri: &str) -> CliResult
         "file" => Ok(&sources.file),
         "http" | "https" => Ok(&sources.http),
         "inline" => Ok(&sources.inline),
-        _ => Err(CliError::new(t!("broker.resolve.unsupported_scheme", scheme = base))),
+        "nats" => sources.nats.as_deref().ok_or_else(|| {
+            CliError::new(CliErrorKind::Config, t!("broker.resolve.nats_unconfigured"))
+        }),
+        _ => Err(CliError::new(
+            CliErrorKind::InvalidInput,
+            t!("broker.resolve.unsupported_scheme", scheme = base),
+        )),
     }
 }
 
@@ -2366,16 +3158,23 @@ fn resolve_payload_output(
     if let Some(content_type) = content_type {
         if is_json_content_type(content_type) {
             let value = serde_json::from_slice::<Value>(bytes).map_err(|err| {
-                CliError::new(t!("broker.resolve.json_parse_failed", error = err))
+                CliError::new(
+                    CliErrorKind::InvalidInput,
+                    t!("broker.resolve.json_parse_failed", error = err),
+                )
+            })?;
+            let digest = hash_canonical_json(algorithm, &value).map_err(|err| {
+                CliError::new(CliErrorKind::Internal, t!("broker.resolve.hash_failed", error = err))
             })?;
-            let digest = hash_canonical_json(algorithm, &value)
-                .map_err(|err| CliError::new(t!("broker.resolve.hash_failed", error = err)))?;
             if digest.value != expected.value {
-                return Err(CliError::new(t!(
-                    "broker.resolve.hash_mismatch",
-                    expected = expected.value,
-                    actual = digest.value
-                )));
+                return Err(CliError::new(
+                    CliErrorKind::Verification,
+                    t!(
+                        "broker.resolve.hash_mismatch",
+                        expected = expected.value,
+                        actual = digest.value
+                    ),
+                ));
             }
             return Ok((
                 BrokerPayloadOutput::Json {
@@ -2386,11 +3185,14 @@ fn resolve_payload_output(
         }
         let digest = hash_bytes(algorithm, bytes);
         if digest.value != expected.value {
-            return Err(CliError::new(t!(
-                "broker.resolve.hash_mismatch",
-                expected = expected.value,
-                actual = digest.value
-            )));
+            return Err(CliError::new(
+                CliErrorKind::Verification,
+                t!(
+                    "broker.resolve.hash_mismatch",
+                    expected = expected.value,
+                    actual = digest.value
+                ),
+            ));
         }
         return Ok((
             BrokerPayloadOutput::Bytes {
@@ -2422,11 +3224,10 @@ fn resolve_payload_output(
         ));
     }
 
-    Err(CliError::new(t!(
-        "broker.resolve.hash_mismatch",
-        expected = expected.value,
-        actual = digest.value
-    )))
+    Err(CliError::new(
+        CliErrorKind::Verification,
+        t!("broker.resolve.hash_mismatch", expected = expected.value, actual = digest.value),
+    ))
 }
 
 /// Returns true when the content type indicates JSON.
@@ -2450,6 +3251,25 @@ fn normalize_content_type(content_type: &str) -> String {
     content_type.split(';').next().unwrap_or(content_type).trim().to_ascii_lowercase()
 }
 
+/// Renders broker head output in text form.
+fn render_broker_head_text(output: &BrokerHeadOutput) -> String {
+    let unknown = t!("broker.resolve.content_type.unknown");
+    let content_type = output.content_type.clone().unwrap_or_else(|| unknown.clone());
+    let size = output.size_bytes.map_or(unknown, |size| size.to_string());
+    let mut buffer = String::new();
+    buffer.push_str(&t!("broker.head.header"));
+    buffer.push('\n');
+    buffer.push_str(&t!("broker.resolve.uri", uri = output.uri.as_str()));
+    buffer.push('\n');
+    buffer.push_str(&t!("broker.resolve.content_type", content_type = content_type));
+    buffer.push('\n');
+    buffer.push_str(&t!("broker.resolve.hash", value = output.content_hash.value));
+    buffer.push('\n');
+    buffer.push_str(&t!("broker.head.size", size = size));
+    buffer.push('\n');
+    buffer
+}
+
 /// Renders broker resolve output in text form.
 fn render_broker_resolve_text(output: &BrokerResolveOutput) -> String {
     let content_type =
@@ -2489,8 +3309,9 @@ fn render_broker_dispatch_text(output: &BrokerDispatchOutput) -> CliResult<Strin
     let mut buffer = String::new();
     buffer.push_str(&t!("broker.dispatch.header"));
     buffer.push('\n');
-    let target = serde_json::to_string(&output.receipt.target)
-        .map_err(|err| CliError::new(t!("broker.dispatch.target_failed", error = err)))?;
+    let target = serde_json::to_string(&output.receipt.target).map_err(|err| {
+        CliError::new(CliErrorKind::Internal, t!("broker.dispatch.target_failed", error = err))
+    })?;
     buffer.push_str(&t!(
         "broker.dispatch.receipt",
         dispatch_id = output.receipt.dispatch_id,
@@ -2511,30 +3332,29 @@ fn render_broker_dispatch_text(output: &BrokerDispatchOutput) -> CliResult<Strin
 /// Reads a JSON payload for broker commands.
 fn read_broker_json<T: DeserializeOwned>(path: &Path, kind: &str) -> CliResult<T> {
     let bytes = read_bytes_with_limit(path, MAX_MCP_INPUT_BYTES).map_err(|err| match err {
-        ReadLimitError::Io(err) => CliError::new(t!(
-            "broker.input.read_failed",
-            kind = kind,
-            path = path.display(),
-            error = err
-        )),
+        ReadLimitError::Io(err) => CliError::new(
+            CliErrorKind::Io,
+            t!("broker.input.read_failed", kind = kind, path = path.display(), error = err),
+        ),
         ReadLimitError::TooLarge {
             size,
             limit,
-        } => CliError::new(t!(
-            "input.read_too_large",
-            kind = kind,
-            path = path.display(),
-            size = size,
-            limit = limit
-        )),
+        } => CliError::new(
+            CliErrorKind::InvalidInput,
+            t!(
+                "input.read_too_large",
+                kind = kind,
+                path = path.display(),
+                size = size,
+                limit = limit
+            ),
+        ),
     })?;
     serde_json::from_slice(&bytes).map_err(|err| {
-        CliError::new(t!(
-            "broker.input.parse_failed",
-            kind = kind,
-            path = path.display(),
-            error = err
-        ))
+        CliError::new(
+            CliErrorKind::InvalidInput,
+            t!("broker.input.parse_failed", kind = kind, path = path.display(), error = err),
+        )
     })
 }
 
@@ -2557,15 +3377,31 @@ async fn command_interop_eval(command: InteropEvalCommand) -> CliResult<ExitCode
 
     let spec: ScenarioSpec = read_interop_json(&command.spec, &spec_label, MAX_INTEROP_SPEC_BYTES)?;
     spec.validate().map_err(|err| {
-        CliError::new(t!("interop.spec_failed", path = command.spec.display(), error = err))
+        CliError::new(
+            CliErrorKind::InvalidInput,
+            t!("interop.spec_failed", path = command.spec.display(), error = err),
+        )
     })?;
     let run_config: RunConfig =
         read_interop_json(&command.run_config, &run_config_label, MAX_INTEROP_RUN_CONFIG_BYTES)?;
+    run_config.validate().map_err(|err| {
+        CliError::new(
+            CliErrorKind::InvalidInput,
+            t!("interop.run_config_failed", path = command.run_config.display(), error = err),
+        )
+    })?;
     let trigger: TriggerEvent =
         read_interop_json(&command.trigger, &trigger_label, MAX_INTEROP_TRIGGER_BYTES)?;
+    let trigger = trigger.validate_and_normalize().map_err(|err| {
+        CliError::new(
+            CliErrorKind::InvalidInput,
+            t!("interop.trigger_failed", path = command.trigger.display(), error = err),
+        )
+    })?;
 
-    validate_inputs(&spec, &run_config, &trigger)
-        .map_err(|err| CliError::new(t!("interop.input_invalid", error = err)))?;
+    validate_inputs(&spec, &run_config, &trigger).map_err(|err| {
+        CliError::new(CliErrorKind::InvalidInput, t!("interop.input_invalid", error = err))
+    })?;
 
     let started_at = resolve_interop_timestamp(
         command.started_at_unix_ms,
@@ -2603,29 +3439,38 @@ async fn command_interop_eval(command: InteropEvalCommand) -> CliResult<ExitCode
         timeout,
     })
     .await
-    .map_err(|err| CliError::new(t!("interop.execution_failed", error = err)))?;
+    .map_err(|err| {
+        CliError::new(CliErrorKind::Internal, t!("interop.execution_failed", error = err))
+    })?;
 
-    let mut report_bytes = serde_jcs::to_vec(&report)
-        .map_err(|err| CliError::new(t!("interop.report.serialize_failed", error = err)))?;
+    let mut report_bytes = serde_jcs::to_vec(&report).map_err(|err| {
+        CliError::new(CliErrorKind::Internal, t!("interop.report.serialize_failed", error = err))
+    })?;
     report_bytes.push(b'\n');
 
     if let Some(output) = &command.output {
         fs::write(output, &report_bytes).map_err(|err| {
-            CliError::new(t!("interop.report.write_failed", path = output.display(), error = err))
+            CliError::new(
+                CliErrorKind::Io,
+                t!("interop.report.write_failed", path = output.display(), error = err),
+            )
         })?;
     } else {
         write_stdout_bytes(&report_bytes)
-            .map_err(|err| CliError::new(output_error("stdout", &err)))?;
+            .map_err(|err| CliError::new(CliErrorKind::Io, output_error("stdout", &err)))?;
     }
 
     if let Some(expected) = command.expect_status {
         let expected_status = run_status_from_arg(expected);
         if report.status.status != expected_status {
-            return Err(CliError::new(t!(
-                "interop.expect_status_mismatch",
-                expected = format_run_status(expected_status),
-                actual = format_run_status(report.status.status)
-            )));
+            return Err(CliError::new(
+                CliErrorKind::Verification,
+                t!(
+                    "interop.expect_status_mismatch",
+                    expected = format_run_status(expected_status),
+                    actual = format_run_status(report.status.status)
+                ),
+            ));
         }
     }
 
@@ -2639,6 +3484,7 @@ async fn command_interop_eval(command: InteropEvalCommand) -> CliResult<ExitCode
 /// Dispatches MCP client subcommands.
 async fn command_mcp(command: McpCommand) -> CliResult<ExitCode> {
     match command {
+        McpCommand::Capabilities(command) => command_mcp_capabilities(command).await,
         McpCommand::Tools {
             command,
         } => match command {
@@ -2657,13 +3503,25 @@ async fn command_mcp(command: McpCommand) -> CliResult<ExitCode> {
     }
 }
 
+/// Executes `mcp capabilities`.
+async fn command_mcp_capabilities(command: McpCapabilitiesCommand) -> CliResult<ExitCode> {
+    let mut client = build_mcp_client(&command.client)?;
+    let capabilities = client
+        .initialize()
+        .await
+        .map_err(|err| CliError::new(CliErrorKind::Remote, t!("mcp.client.failed", error = err)))?;
+    let output = serde_json::json!({ "capabilities": capabilities });
+    write_json_value(&output)?;
+    Ok(ExitCode::SUCCESS)
+}
+
 /// Executes `mcp tools list`.
 async fn command_mcp_tools_list(command: McpToolsListCommand) -> CliResult<ExitCode> {
     let mut client = build_mcp_client(&command.client)?;
     let tools = client
         .list_tools()
         .await
-        .map_err(|err| CliError::new(t!("mcp.client.failed", error = err)))?;
+        .map_err(|err| CliError::new(CliErrorKind::Remote, t!("mcp.client.failed", error = err)))?;
     let output = serde_json::json!({ "tools": tools });
     write_json_value(&output)?;
     Ok(ExitCode::SUCCESS)
@@ -2681,7 +3539,7 @@ async fn command_mcp_resources_list(command: McpResourcesListCommand) -> CliResu
     let resources: Vec<ResourceMetadata> = client
         .list_resources()
         .await
-        .map_err(|err| CliError::new(t!("mcp.client.failed", error = err)))?;
+        .map_err(|err| CliError::new(CliErrorKind::Remote, t!("mcp.client.failed", error = err)))?;
     let output = serde_json::json!({ "resources": resources });
     write_json_value(&output)?;
     Ok(ExitCode::SUCCESS)
@@ -2693,7 +3551,7 @@ async fn command_mcp_resources_read(command: McpResourcesReadCommand) -> CliResu
     let contents: Vec<ResourceContent> = client
         .read_resource(&command.uri)
         .await
-        .map_err(|err| CliError::new(t!("mcp.client.failed", error = err)))?;
+        .map_err(|err| CliError::new(CliErrorKind::Remote, t!("mcp.client.failed", error = err)))?;
     let output = serde_json::json!({ "contents": contents });
     write_json_value(&output)?;
     Ok(ExitCode::SUCCESS)
@@ -2736,6 +3594,15 @@ async fn command_mcp_tool(command: McpToolCommand) -> CliResult<ExitCode> {
         McpToolCommand::DecisionGateDocsSearch(args) => {
             (decision_gate_core::ToolName::DecisionGateDocsSearch, args)
         }
+        McpToolCommand::ScenariosStatusBulk(args) => {
+            (decision_gate_core::ToolName::ScenariosStatusBulk, args)
+        }
+        McpToolCommand::ScenarioLeaseAcquire(args) => {
+            (decision_gate_core::ToolName::ScenarioLeaseAcquire, args)
+        }
+        McpToolCommand::ScenarioLeaseRelease(args) => {
+            (decision_gate_core::ToolName::ScenarioLeaseRelease, args)
+        }
     };
     command_mcp_tool_with_args(&args.client, tool, &args.input, args.no_validate).await
 }
@@ -2755,7 +3622,7 @@ async fn command_mcp_tool_with_args(
     let result = client
         .call_tool(tool, input)
         .await
-        .map_err(|err| CliError::new(t!("mcp.client.failed", error = err)))?;
+        .map_err(|err| CliError::new(CliErrorKind::Remote, t!("mcp.client.failed", error = err)))?;
     write_json_value(&result)?;
     Ok(ExitCode::SUCCESS)
 }
@@ -2785,11 +3652,12 @@ fn command_contract_generate(command: ContractGenerateCommand) -> CliResult<Exit
     let output_dir =
         command.out.unwrap_or_else(decision_gate_contract::ContractBuilder::default_output_dir);
     let builder = decision_gate_contract::ContractBuilder::new(output_dir.clone());
-    builder
-        .write_to(&output_dir)
-        .map_err(|err| CliError::new(t!("contract.generate.failed", error = err)))?;
-    config::write_config_docs(None)
-        .map_err(|err| CliError::new(t!("contract.generate.failed", error = err)))?;
+    builder.write_to(&output_dir).map_err(|err| {
+        CliError::new(CliErrorKind::Internal, t!("contract.generate.failed", error = err))
+    })?;
+    config::write_config_docs(None).map_err(|err| {
+        CliError::new(CliErrorKind::Internal, t!("contract.generate.failed", error = err))
+    })?;
     Ok(ExitCode::SUCCESS)
 }
 
@@ -2798,55 +3666,86 @@ fn command_contract_check(command: ContractCheckCommand) -> CliResult<ExitCode>
     let output_dir =
         command.out.unwrap_or_else(decision_gate_contract::ContractBuilder::default_output_dir);
     let builder = decision_gate_contract::ContractBuilder::new(output_dir.clone());
-    builder
-        .verify_output(&output_dir)
-        .map_err(|err| CliError::new(t!("contract.check.failed", error = err)))?;
-    config::verify_config_docs(None)
-        .map_err(|err| CliError::new(t!("contract.check.failed", error = err)))?;
+    builder.verify_output(&output_dir).map_err(|err| {
+        CliError::new(CliErrorKind::Verification, t!("contract.check.failed", error = err))
+    })?;
+    config::verify_config_docs(None).map_err(|err| {
+        CliError::new(CliErrorKind::Verification, t!("contract.check.failed", error = err))
+    })?;
     Ok(ExitCode::SUCCESS)
 }
 
 /// Executes SDK generation.
 fn command_sdk_generate(command: &SdkGenerateCommand) -> CliResult<ExitCode> {
-    let generator = decision_gate_sdk_gen::SdkGenerator::load(&command.tooling)
-        .map_err(|err| CliError::new(t!("sdk.generate.failed", error = err)))?;
-    let python = generator
-        .generate_python()
-        .map_err(|err| CliError::new(t!("sdk.generate.failed", error = err)))?;
-    let typescript = generator
-        .generate_typescript()
-        .map_err(|err| CliError::new(t!("sdk.generate.failed", error = err)))?;
-    let openapi = generator
-        .generate_openapi()
-        .map_err(|err| CliError::new(t!("sdk.generate.failed", error = err)))?;
+    let generator = decision_gate_sdk_gen::SdkGenerator::load(&command.tooling).map_err(|err| {
+        CliError::new(CliErrorKind::Internal, t!("sdk.generate.failed", error = err))
+    })?;
+    let python = generator.generate_python().map_err(|err| {
+        CliError::new(CliErrorKind::Internal, t!("sdk.generate.failed", error = err))
+    })?;
+    let typescript = generator.generate_typescript().map_err(|err| {
+        CliError::new(CliErrorKind::Internal, t!("sdk.generate.failed", error = err))
+    })?;
+    let openapi = generator.generate_openapi().map_err(|err| {
+        CliError::new(CliErrorKind::Internal, t!("sdk.generate.failed", error = err))
+    })?;
     write_sdk_output(&command.python_out, &python)?;
     write_sdk_output(&command.typescript_out, &typescript)?;
     write_sdk_output(&command.openapi_out, &openapi)?;
+    if let Some(go_out) = &command.go_out {
+        let go = generator.generate_go().map_err(|err| {
+            CliError::new(CliErrorKind::Internal, t!("sdk.generate.failed", error = err))
+        })?;
+        write_sdk_output(go_out, &go)?;
+    }
+    if let Some(schema_bundle_out) = &command.schema_bundle_out {
+        let bundle = generator.generate_schema_bundle().map_err(|err| {
+            CliError::new(CliErrorKind::Internal, t!("sdk.generate.failed", error = err))
+        })?;
+        write_sdk_output(schema_bundle_out, &bundle)?;
+    }
     Ok(ExitCode::SUCCESS)
 }
 
 /// Executes SDK verification.
 fn command_sdk_check(command: &SdkCheckCommand) -> CliResult<ExitCode> {
-    let generator = decision_gate_sdk_gen::SdkGenerator::load(&command.tooling)
-        .map_err(|err| CliError::new(t!("sdk.check.failed", error = err)))?;
+    let generator = decision_gate_sdk_gen::SdkGenerator::load(&command.tooling).map_err(|err| {
+        CliError::new(CliErrorKind::Verification, t!("sdk.check.failed", error = err))
+    })?;
     check_sdk_output(
         &command.python_out,
-        &generator
-            .generate_python()
-            .map_err(|err| CliError::new(t!("sdk.check.failed", error = err)))?,
+        &generator.generate_python().map_err(|err| {
+            CliError::new(CliErrorKind::Verification, t!("sdk.check.failed", error = err))
+        })?,
     )?;
     check_sdk_output(
         &command.typescript_out,
-        &generator
-            .generate_typescript()
-            .map_err(|err| CliError::new(t!("sdk.check.failed", error = err)))?,
+        &generator.generate_typescript().map_err(|err| {
+            CliError::new(CliErrorKind::Verification, t!("sdk.check.failed", error = err))
+        })?,
     )?;
     check_sdk_output(
         &command.openapi_out,
-        &generator
-            .generate_openapi()
-            .map_err(|err| CliError::new(t!("sdk.check.failed", error = err)))?,
+        &generator.generate_openapi().map_err(|err| {
+            CliError::new(CliErrorKind::Verification, t!("sdk.check.failed", error = err))
+        })?,
     )?;
+    if let Some(go_out) = &command.go_out {
+        check_sdk_output(
+            go_out,
+            &generator.generate_go().map_err(|err| {
+                CliError::new(CliErrorKind::Verification, t!("sdk.check.failed", error = err))
+            })?,
+        )?;
+    }
+    if let Some(schema_bundle_out) = &command.schema_bundle_out {
+        check_sdk_output(
+            schema_bundle_out,
+            &generator.generate_schema_bundle().map_err(|err| {
+                CliError::new(CliErrorKind::Verification, t!("sdk.check.failed", error = err))
+            })?,
+        )?;
+    }
     Ok(ExitCode::SUCCESS)
 }
 
@@ -2854,7 +3753,7 @@ fn command_sdk_check(command: &SdkCheckCommand) -> CliResult<ExitCode> {
 fn write_sdk_output(path: &Path, contents: &str) -> CliResult<()> {
     if let Some(parent) = path.parent() {
         fs::create_dir_all(parent)
-            .map_err(|err| CliError::new(t!("sdk.io.failed", error = err)))?;
+            .map_err(|err| CliError::new(CliErrorKind::Io, t!("sdk.io.failed", error = err)))?;
     }
     let temp_path = path.with_extension("tmp");
     let mut file = fs::OpenOptions::new()
@@ -2862,20 +3761,25 @@ fn write_sdk_output(path: &Path, contents: &str) -> CliResult<()> {
         .write(true)
         .truncate(true)
         .open(&temp_path)
-        .map_err(|err| CliError::new(t!("sdk.io.failed", error = err)))?;
+        .map_err(|err| CliError::new(CliErrorKind::Io, t!("sdk.io.failed", error = err)))?;
     file.write_all(contents.as_bytes())
-        .map_err(|err| CliError::new(t!("sdk.io.failed", error = err)))?;
-    file.sync_all().map_err(|err| CliError::new(t!("sdk.io.failed", error = err)))?;
-    fs::rename(&temp_path, path).map_err(|err| CliError::new(t!("sdk.io.failed", error = err)))?;
+        .map_err(|err| CliError::new(CliErrorKind::Io, t!("sdk.io.failed", error = err)))?;
+    file.sync_all()
+        .map_err(|err| CliError::new(CliErrorKind::Io, t!("sdk.io.failed", error = err)))?;
+    fs::rename(&temp_path, path)
+        .map_err(|err| CliError::new(CliErrorKind::Io, t!("sdk.io.failed", error = err)))?;
     Ok(())
 }
 
 /// Checks generated SDK output against the on-disk file.
 fn check_sdk_output(path: &Path, contents: &str) -> CliResult<()> {
-    let existing =
-        fs::read_to_string(path).map_err(|err| CliError::new(t!("sdk.io.failed", error = err)))?;
+    let existing = fs::read_to_string(path)
+        .map_err(|err| CliError::new(CliErrorKind::Io, t!("sdk.io.failed", error = err)))?;
     if existing != contents {
-        return Err(CliError::new(t!("sdk.check.drift", path = path.display())));
+        return Err(CliError::new(
+            CliErrorKind::Verification,
+            t!("sdk.check.drift", path = path.display()),
+        ));
     }
     Ok(())
 }
@@ -2888,36 +3792,116 @@ fn command_authoring_validate(command: &AuthoringValidateCommand) -> CliResult<E
         scenario_id = normalized.spec.scenario_id.as_str(),
         spec_hash = format_hash_digest(&normalized.spec_hash)
     );
-    write_stdout_line(&summary).map_err(|err| CliError::new(output_error("stdout", &err)))?;
+    write_stdout_line(&summary)
+        .map_err(|err| CliError::new(CliErrorKind::Io, output_error("stdout", &err)))?;
     Ok(ExitCode::SUCCESS)
 }
 
 /// Executes the authoring normalization command.
 fn command_authoring_normalize(command: &AuthoringNormalizeCommand) -> CliResult<ExitCode> {
     let normalized = normalize_authoring_input(&command.input, command.format)?;
+    write_normalized_scenario(&normalized, command.output.as_deref())
+}
+
+/// Executes the authoring template render command.
+fn command_authoring_render(command: &AuthoringRenderCommand) -> CliResult<ExitCode> {
+    let template = read_authoring_input(&command.template)?;
+    let format = resolve_authoring_format(&command.template, command.format)?;
+    let params_input = read_authoring_input(&command.params)?;
+    let params: serde_json::Value = serde_json::from_str(&params_input).map_err(|err| {
+        CliError::new(
+            CliErrorKind::InvalidInput,
+            t!(
+                "authoring.parse_failed",
+                format = AuthoringFormat::Json,
+                path = command.params.display(),
+                error = err
+            ),
+        )
+    })?;
+    let rendered = authoring::render_scenario_template(&template, format, &params)
+        .map_err(|err| map_authoring_error(err, &command.template))?;
+    for name in &rendered.unused_params {
+        write_stderr_line(&t!("authoring.render.unused_param", name = name))
+            .map_err(|err| CliError::new(CliErrorKind::Io, output_error("stderr", &err)))?;
+    }
+    write_normalized_scenario(&rendered.normalized, command.output.as_deref())
+}
+
+/// Writes canonical JSON to `output` (or stdout) with a validation summary.
+fn write_normalized_scenario(
+    normalized: &decision_gate_contract::NormalizedScenario,
+    output: Option<&Path>,
+) -> CliResult<ExitCode> {
     let summary = t!(
         "authoring.validate.ok",
         scenario_id = normalized.spec.scenario_id.as_str(),
         spec_hash = format_hash_digest(&normalized.spec_hash)
     );
 
-    if let Some(output) = &command.output {
+    if let Some(output) = output {
         fs::write(output, &normalized.canonical_json).map_err(|err| {
-            CliError::new(t!(
-                "authoring.normalize.write_failed",
-                path = output.display(),
-                error = err
-            ))
+            CliError::new(
+                CliErrorKind::Io,
+                t!("authoring.normalize.write_failed", path = output.display(), error = err),
+            )
         })?;
         write_stdout_line(&t!("authoring.normalize.ok", path = output.display()))
-            .map_err(|err| CliError::new(output_error("stdout", &err)))?;
-        write_stdout_line(&summary).map_err(|err| CliError::new(output_error("stdout", &err)))?;
+            .map_err(|err| CliError::new(CliErrorKind::Io, output_error("stdout", &err)))?;
+        write_stdout_line(&summary)
+            .map_err(|err| CliError::new(CliErrorKind::Io, output_error("stdout", &err)))?;
         return Ok(ExitCode::SUCCESS);
     }
 
     write_stdout_bytes(&normalized.canonical_json)
-        .map_err(|err| CliError::new(output_error("stdout", &err)))?;
-    write_stderr_line(&summary).map_err(|err| CliError::new(output_error("stderr", &err)))?;
+        .map_err(|err| CliError::new(CliErrorKind::Io, output_error("stdout", &err)))?;
+    write_stderr_line(&summary)
+        .map_err(|err| CliError::new(CliErrorKind::Io, output_error("stderr", &err)))?;
+    Ok(ExitCode::SUCCESS)
+}
+
+/// Executes the authoring hash command.
+fn command_authoring_hash(command: &AuthoringHashCommand) -> CliResult<ExitCode> {
+    let normalized = normalize_authoring_input(&command.input, command.format)?;
+    if let Some(output) = &command.canonical_out {
+        fs::write(output, &normalized.canonical_json).map_err(|err| {
+            CliError::new(
+                CliErrorKind::Io,
+                t!("authoring.normalize.write_failed", path = output.display(), error = err),
+            )
+        })?;
+    }
+    write_stdout_line(&format_hash_digest(&normalized.spec_hash))
+        .map_err(|err| CliError::new(CliErrorKind::Io, output_error("stdout", &err)))?;
+    Ok(ExitCode::SUCCESS)
+}
+
+/// Executes the requirement DSL formatting command.
+fn command_authoring_format_dsl(command: &AuthoringFormatDslCommand) -> CliResult<ExitCode> {
+    let source = read_authoring_input(&command.input)?;
+    let formatted = format_requirement(&source).map_err(|err| {
+        CliError::new(
+            CliErrorKind::InvalidInput,
+            t!(
+                "authoring.format_dsl.failed",
+                path = command.input.display(),
+                error = err.render_with_source(&source)
+            ),
+        )
+    })?;
+    if let Some(output) = &command.output {
+        fs::write(output, format!("{formatted}\n")).map_err(|err| {
+            CliError::new(
+                CliErrorKind::Io,
+                t!("authoring.format_dsl.write_failed", path = output.display(), error = err),
+            )
+        })?;
+        write_stdout_line(&t!("authoring.format_dsl.ok", path = output.display()))
+            .map_err(|err| CliError::new(CliErrorKind::Io, output_error("stdout", &err)))?;
+        return Ok(ExitCode::SUCCESS);
+    }
+    write_stdout_line(&formatted)
+        .map_err(|err| CliError::new(CliErrorKind::Io, output_error("stdout", &err)))?;
     Ok(ExitCode::SUCCESS)
 }
 
@@ -2927,48 +3911,62 @@ fn command_runpack_export(command: &RunpackExportCommand) -> CliResult<ExitCode>
     let state_label = t!("runpack.export.kind.state");
     let spec: ScenarioSpec = read_export_json(&command.spec, &spec_label, MAX_SPEC_BYTES)?;
     spec.validate().map_err(|err| {
-        CliError::new(t!("runpack.export.spec_failed", path = command.spec.display(), error = err))
+        CliError::new(
+            CliErrorKind::InvalidInput,
+            t!("runpack.export.spec_failed", path = command.spec.display(), error = err),
+        )
     })?;
     let state: RunState = read_export_json(&command.state, &state_label, MAX_RUN_STATE_BYTES)?;
     let generated_at = resolve_generated_at(command.generated_at_unix_ms)?;
 
     fs::create_dir_all(&command.output_dir).map_err(|err| {
-        CliError::new(t!(
-            "runpack.export.output_dir_failed",
-            path = command.output_dir.display(),
-            error = err
-        ))
+        CliError::new(
+            CliErrorKind::Io,
+            t!(
+                "runpack.export.output_dir_failed",
+                path = command.output_dir.display(),
+                error = err
+            ),
+        )
     })?;
 
     let manifest_path = command.output_dir.join(&command.manifest_name);
     let mut sink = FileArtifactSink::new(command.output_dir.clone(), &command.manifest_name)
         .map_err(|err| {
-            CliError::new(t!(
-                "runpack.export.sink_failed",
-                path = command.output_dir.display(),
-                error = err
-            ))
+            CliError::new(
+                CliErrorKind::Io,
+                t!("runpack.export.sink_failed", path = command.output_dir.display(), error = err),
+            )
         })?;
-    let builder = RunpackBuilder::default();
+    let compression = if command.compress { Compression::Gzip } else { Compression::None };
+    let builder = RunpackBuilder::default().with_compression(compression);
     let manifest = if command.with_verification {
         let reader = FileArtifactReader::new(command.output_dir.clone()).map_err(|err| {
-            CliError::new(t!(
-                "runpack.verify.reader_failed",
-                path = command.output_dir.display(),
-                error = err
-            ))
+            CliError::new(
+                CliErrorKind::Io,
+                t!(
+                    "runpack.verify.reader_failed",
+                    path = command.output_dir.display(),
+                    error = err
+                ),
+            )
         })?;
         let (manifest, report) = builder
             .build_with_verification(&mut sink, &reader, &spec, &state, generated_at)
-            .map_err(|err| CliError::new(t!("runpack.export.build_failed", error = err)))?;
+            .map_err(|err| {
+                CliError::new(
+                    CliErrorKind::Internal,
+                    t!("runpack.export.build_failed", error = err),
+                )
+            })?;
         let status = format_verification_status(report.status);
         write_stdout_line(&t!("runpack.export.verification_status", status = status))
-            .map_err(|err| CliError::new(output_error("stdout", &err)))?;
+            .map_err(|err| CliError::new(CliErrorKind::Io, output_error("stdout", &err)))?;
         manifest
     } else {
-        builder
-            .build(&mut sink, &spec, &state, generated_at)
-            .map_err(|err| CliError::new(t!("runpack.export.build_failed", error = err)))?
+        builder.build(&mut sink, &spec, &state, generated_at).map_err(|err| {
+            CliError::new(CliErrorKind::Internal, t!("runpack.export.build_failed", error = err))
+        })?
     };
 
     if command.storage {
@@ -2979,43 +3977,62 @@ fn command_runpack_export(command: &RunpackExportCommand) -> CliResult<ExitCode>
             command.config.as_deref(),
         )?;
         write_stdout_line(&t!("runpack.export.storage_ok", uri = storage_uri))
-            .map_err(|err| CliError::new(output_error("stdout", &err)))?;
+            .map_err(|err| CliError::new(CliErrorKind::Io, output_error("stdout", &err)))?;
     }
 
     write_stdout_line(&t!("runpack.export.ok", path = manifest_path.display()))
-        .map_err(|err| CliError::new(output_error("stdout", &err)))?;
+        .map_err(|err| CliError::new(CliErrorKind::Io, output_error("stdout", &err)))?;
     Ok(ExitCode::SUCCESS)
 }
 
 /// Executes the runpack verification command.
 fn command_runpack_verify(command: RunpackVerifyCommand) -> CliResult<ExitCode> {
+    if command.attestation_out.is_some() && command.attestation_key.is_none() {
+        return Err(CliError::new(CliErrorKind::InvalidInput, t!("runpack.attest.key_required")));
+    }
+    if command.attestation_key.is_some() && command.attestation_out.is_none() {
+        return Err(CliError::new(CliErrorKind::InvalidInput, t!("runpack.attest.out_required")));
+    }
     let manifest: RunpackManifest = read_manifest_json(&command.manifest, MAX_MANIFEST_BYTES)?;
-    let verifier = RunpackVerifier::new(DEFAULT_HASH_ALGORITHM);
+    let verifier = RunpackVerifier::from_manifest();
     let report = if command.storage {
         let backend = resolve_runpack_object_store_backend(command.config.as_deref())?;
         let key = runpack_object_key_from_manifest(&manifest);
-        let reader = backend
-            .reader(&key)
-            .map_err(|err| CliError::new(t!("runpack.verify.failed", error = err)))?;
-        verifier
-            .verify_manifest(&reader, &manifest)
-            .map_err(|err| CliError::new(t!("runpack.verify.failed", error = err)))?
+        let reader = backend.reader(&key).map_err(|err| {
+            CliError::new(CliErrorKind::Internal, t!("runpack.verify.failed", error = err))
+        })?;
+        verifier.verify_manifest(&reader, &manifest).map_err(|err| {
+            CliError::new(CliErrorKind::Internal, t!("runpack.verify.failed", error = err))
+        })?
     } else {
         let runpack_dir = resolve_runpack_dir(&command.manifest, command.runpack_dir)?;
         let reader = FileArtifactReader::new(runpack_dir.clone()).map_err(|err| {
-            CliError::new(t!(
-                "runpack.verify.reader_failed",
-                path = runpack_dir.display(),
-                error = err
-            ))
+            CliError::new(
+                CliErrorKind::Io,
+                t!("runpack.verify.reader_failed", path = runpack_dir.display(), error = err),
+            )
         })?;
-        verifier
-            .verify_manifest(&reader, &manifest)
-            .map_err(|err| CliError::new(t!("runpack.verify.failed", error = err)))?
+        verifier.verify_manifest(&reader, &manifest).map_err(|err| {
+            CliError::new(CliErrorKind::Internal, t!("runpack.verify.failed", error = err))
+        })?
     };
 
     let output = render_verification_report(command.format, &report)?;
-    write_stdout_line(&output).map_err(|err| CliError::new(output_error("stdout", &err)))?;
+    write_stdout_bytes(&output)
+        .map_err(|err| CliError::new(CliErrorKind::Io, output_error("stdout", &err)))?;
+
+    if let (Some(path), Some(key_path)) = (&command.attestation_out, &command.attestation_key) {
+        let signing_key = load_signing_key(key_path)?;
+        let signer = RunpackSigner {
+            key_id: BASE64.encode(signing_key.verifying_key().to_bytes()),
+            key: signing_key,
+        };
+        let verified_at = resolve_generated_at(command.verified_at_unix_ms)?;
+        let attestation = report.attest(&manifest, verified_at, &signer).map_err(|err| {
+            CliError::new(CliErrorKind::Internal, t!("runpack.attest.failed", error = err))
+        })?;
+        write_output_artifact(path, "attestation", &attestation)?;
+    }
 
     let exit_code = match report.status {
         VerificationStatus::Pass => ExitCode::SUCCESS,
@@ -3025,52 +4042,126 @@ fn command_runpack_verify(command: RunpackVerifyCommand) -> CliResult<ExitCode>
     Ok(exit_code)
 }
 
-/// Executes the runpack pretty output command.
-fn command_runpack_pretty(command: RunpackPrettyCommand) -> CliResult<ExitCode> {
+/// Executes the verification attestation check command.
+fn command_runpack_verify_attestation(
+    command: &RunpackVerifyAttestationCommand,
+) -> CliResult<ExitCode> {
+    let attestation: VerificationAttestation =
+        read_attestation_input(&command.attestation, &t!("runpack.attest.kind.attestation"))?;
+    let report: VerificationReport =
+        read_attestation_input(&command.report, &t!("runpack.attest.kind.report"))?;
     let manifest: RunpackManifest = read_manifest_json(&command.manifest, MAX_MANIFEST_BYTES)?;
-    let runpack_dir = resolve_runpack_dir(&command.manifest, command.runpack_dir)?;
-    fs::create_dir_all(&command.output_dir).map_err(|err| {
-        CliError::new(t!(
-            "runpack.pretty.output_dir_failed",
-            path = command.output_dir.display(),
-            error = err
-        ))
+    let key = load_verifying_key(&command.public_key)?;
+    attestation.verify(&report, &manifest, &key).map_err(|err| {
+        CliError::new(CliErrorKind::Verification, t!("runpack.attest.invalid", error = err))
+    })?;
+    write_stdout_line(&t!(
+        "runpack.attest.ok",
+        status = format_verification_status(attestation.claims.status),
+        key_id = attestation.claims.verifier_key_id
+    ))
+    .map_err(|err| CliError::new(CliErrorKind::Io, output_error("stdout", &err)))?;
+    Ok(ExitCode::SUCCESS)
+}
+
+/// Reads a JSON attestation input (attestation or report) with size limits.
+fn read_attestation_input<T: DeserializeOwned>(path: &Path, kind: &str) -> CliResult<T> {
+    let bytes = read_bytes_with_limit(path, MAX_MANIFEST_BYTES).map_err(|err| match err {
+        ReadLimitError::Io(err) => CliError::new(
+            CliErrorKind::Io,
+            t!("runpack.attest.read_failed", kind = kind, path = path.display(), error = err),
+        ),
+        ReadLimitError::TooLarge {
+            size,
+            limit,
+        } => CliError::new(
+            CliErrorKind::InvalidInput,
+            t!(
+                "input.read_too_large",
+                kind = kind,
+                path = path.display(),
+                size = size,
+                limit = limit
+            ),
+        ),
     })?;
+    serde_json::from_slice(&bytes).map_err(|err| {
+        CliError::new(
+            CliErrorKind::InvalidInput,
+            t!("runpack.attest.parse_failed", kind = kind, path = path.display(), error = err),
+        )
+    })
+}
 
-    let manifest_name = command
-        .manifest
+/// Writes an indented copy of the manifest into `output_dir`.
+///
+/// Returns the manifest file name shared by the pretty artifact sink.
+fn write_pretty_manifest(
+    manifest: &RunpackManifest,
+    manifest_path: &Path,
+    output_dir: &Path,
+) -> CliResult<String> {
+    let manifest_name = manifest_path
         .file_name()
         .ok_or_else(|| {
-            CliError::new(t!(
-                "runpack.pretty.manifest_name_missing",
-                path = command.manifest.display()
-            ))
+            CliError::new(
+                CliErrorKind::InvalidInput,
+                t!("runpack.pretty.manifest_name_missing", path = manifest_path.display()),
+            )
         })?
         .to_string_lossy()
         .to_string();
-    let manifest_out = command.output_dir.join(&manifest_name);
-    let mut pretty_manifest = serde_json::to_string_pretty(&manifest).map_err(|err| {
-        CliError::new(t!(
-            "runpack.pretty.manifest_render_failed",
-            path = command.manifest.display(),
-            error = err
-        ))
+    let manifest_out = output_dir.join(&manifest_name);
+    let mut pretty_manifest = serde_json::to_string_pretty(manifest).map_err(|err| {
+        CliError::new(
+            CliErrorKind::Internal,
+            t!(
+                "runpack.pretty.manifest_render_failed",
+                path = manifest_path.display(),
+                error = err
+            ),
+        )
     })?;
     pretty_manifest.push('\n');
     fs::write(&manifest_out, pretty_manifest).map_err(|err| {
-        CliError::new(t!("runpack.pretty.write_failed", path = manifest_out.display(), error = err))
+        CliError::new(
+            CliErrorKind::Io,
+            t!("runpack.pretty.write_failed", path = manifest_out.display(), error = err),
+        )
     })?;
+    Ok(manifest_name)
+}
+
+/// Executes the runpack pretty output command.
+fn command_runpack_pretty(command: RunpackPrettyCommand) -> CliResult<ExitCode> {
+    let manifest: RunpackManifest = read_manifest_json(&command.manifest, MAX_MANIFEST_BYTES)?;
+    let runpack_dir = resolve_runpack_dir(&command.manifest, command.runpack_dir)?;
+    fs::create_dir_all(&command.output_dir).map_err(|err| {
+        CliError::new(
+            CliErrorKind::Io,
+            t!(
+                "runpack.pretty.output_dir_failed",
+                path = command.output_dir.display(),
+                error = err
+            ),
+        )
+    })?;
+
+    let manifest_name = write_pretty_manifest(&manifest, &command.manifest, &command.output_dir)?;
 
     let reader = FileArtifactReader::new(runpack_dir.clone()).map_err(|err| {
-        CliError::new(t!("runpack.pretty.reader_failed", path = runpack_dir.display(), error = err))
+        CliError::new(
+            CliErrorKind::Io,
+            t!("runpack.pretty.reader_failed", path = runpack_dir.display(), error = err),
+        )
     })?;
+    let reader = DecodingArtifactReader::new(&reader, &manifest);
     let mut sink =
         FileArtifactSink::new(command.output_dir.clone(), &manifest_name).map_err(|err| {
-            CliError::new(t!(
-                "runpack.pretty.sink_failed",
-                path = command.output_dir.display(),
-                error = err
-            ))
+            CliError::new(
+                CliErrorKind::Io,
+                t!("runpack.pretty.sink_failed", path = command.output_dir.display(), error = err),
+            )
         })?;
 
     let mut json_count = 0usize;
@@ -3083,13 +4174,22 @@ fn command_runpack_pretty(command: RunpackPrettyCommand) -> CliResult<ExitCode>
 
         let bytes =
             reader.read_with_limit(&artifact.path, MAX_RUNPACK_ARTIFACT_BYTES).map_err(|err| {
-                CliError::new(t!("runpack.pretty.read_failed", path = artifact.path, error = err))
+                CliError::new(
+                    CliErrorKind::Io,
+                    t!("runpack.pretty.read_failed", path = artifact.path, error = err),
+                )
             })?;
         let value: Value = serde_json::from_slice(&bytes).map_err(|err| {
-            CliError::new(t!("runpack.pretty.parse_failed", path = artifact.path, error = err))
+            CliError::new(
+                CliErrorKind::InvalidInput,
+                t!("runpack.pretty.parse_failed", path = artifact.path, error = err),
+            )
         })?;
         let mut pretty_json = serde_json::to_string_pretty(&value).map_err(|err| {
-            CliError::new(t!("runpack.pretty.render_failed", path = artifact.path, error = err))
+            CliError::new(
+                CliErrorKind::Internal,
+                t!("runpack.pretty.render_failed", path = artifact.path, error = err),
+            )
         })?;
         pretty_json.push('\n');
         let payload = Artifact {
@@ -3100,7 +4200,10 @@ fn command_runpack_pretty(command: RunpackPrettyCommand) -> CliResult<ExitCode>
             required: artifact.required,
         };
         sink.write(&payload).map_err(|err| {
-            CliError::new(t!("runpack.pretty.write_failed", path = payload.path, error = err))
+            CliError::new(
+                CliErrorKind::Io,
+                t!("runpack.pretty.write_failed", path = payload.path, error = err),
+            )
         })?;
         json_count = json_count.saturating_add(1);
     }
@@ -3111,7 +4214,7 @@ fn command_runpack_pretty(command: RunpackPrettyCommand) -> CliResult<ExitCode>
         json = json_count,
         skipped = skipped_count
     ))
-    .map_err(|err| CliError::new(output_error("stdout", &err)))?;
+    .map_err(|err| CliError::new(CliErrorKind::Io, output_error("stdout", &err)))?;
 
     Ok(ExitCode::SUCCESS)
 }
@@ -3171,30 +4274,29 @@ fn read_export_json<T: DeserializeOwned>(
     max_bytes: usize,
 ) -> CliResult<T> {
     let bytes = read_bytes_with_limit(path, max_bytes).map_err(|err| match err {
-        ReadLimitError::Io(err) => CliError::new(t!(
-            "runpack.export.read_failed",
-            kind = kind,
-            path = path.display(),
-            error = err
-        )),
+        ReadLimitError::Io(err) => CliError::new(
+            CliErrorKind::Io,
+            t!("runpack.export.read_failed", kind = kind, path = path.display(), error = err),
+        ),
         ReadLimitError::TooLarge {
             size,
             limit,
-        } => CliError::new(t!(
-            "input.read_too_large",
-            kind = kind,
-            path = path.display(),
-            size = size,
-            limit = limit
-        )),
+        } => CliError::new(
+            CliErrorKind::InvalidInput,
+            t!(
+                "input.read_too_large",
+                kind = kind,
+                path = path.display(),
+                size = size,
+                limit = limit
+            ),
+        ),
     })?;
     serde_json::from_slice(&bytes).map_err(|err| {
-        CliError::new(t!(
-            "runpack.export.parse_failed",
-            kind = kind,
-            path = path.display(),
-            error = err
-        ))
+        CliError::new(
+            CliErrorKind::InvalidInput,
+            t!("runpack.export.parse_failed", kind = kind, path = path.display(), error = err),
+        )
     })
 }
 
@@ -3205,47 +4307,58 @@ fn read_interop_json<T: DeserializeOwned>(
     max_bytes: usize,
 ) -> CliResult<T> {
     let bytes = read_bytes_with_limit(path, max_bytes).map_err(|err| match err {
-        ReadLimitError::Io(err) => CliError::new(t!(
-            "interop.read_failed",
-            kind = kind,
-            path = path.display(),
-            error = err
-        )),
+        ReadLimitError::Io(err) => CliError::new(
+            CliErrorKind::Io,
+            t!("interop.read_failed", kind = kind, path = path.display(), error = err),
+        ),
         ReadLimitError::TooLarge {
             size,
             limit,
-        } => CliError::new(t!(
-            "input.read_too_large",
-            kind = kind,
-            path = path.display(),
-            size = size,
-            limit = limit
-        )),
+        } => CliError::new(
+            CliErrorKind::InvalidInput,
+            t!(
+                "input.read_too_large",
+                kind = kind,
+                path = path.display(),
+                size = size,
+                limit = limit
+            ),
+        ),
     })?;
     serde_json::from_slice(&bytes).map_err(|err| {
-        CliError::new(t!("interop.parse_failed", kind = kind, path = path.display(), error = err))
+        CliError::new(
+            CliErrorKind::InvalidInput,
+            t!("interop.parse_failed", kind = kind, path = path.display(), error = err),
+        )
     })
 }
 
 /// Reads a JSON manifest file for runpack verification.
 fn read_manifest_json<T: DeserializeOwned>(path: &Path, max_bytes: usize) -> CliResult<T> {
     let bytes = read_bytes_with_limit(path, max_bytes).map_err(|err| match err {
-        ReadLimitError::Io(err) => {
-            CliError::new(t!("runpack.verify.read_failed", path = path.display(), error = err))
-        }
+        ReadLimitError::Io(err) => CliError::new(
+            CliErrorKind::Io,
+            t!("runpack.verify.read_failed", path = path.display(), error = err),
+        ),
         ReadLimitError::TooLarge {
             size,
             limit,
-        } => CliError::new(t!(
-            "input.read_too_large",
-            kind = t!("runpack.verify.kind.manifest"),
-            path = path.display(),
-            size = size,
-            limit = limit
-        )),
+        } => CliError::new(
+            CliErrorKind::InvalidInput,
+            t!(
+                "input.read_too_large",
+                kind = t!("runpack.verify.kind.manifest"),
+                path = path.display(),
+                size = size,
+                limit = limit
+            ),
+        ),
     })?;
     serde_json::from_slice(&bytes).map_err(|err| {
-        CliError::new(t!("runpack.verify.parse_failed", path = path.display(), error = err))
+        CliError::new(
+            CliErrorKind::InvalidInput,
+            t!("runpack.verify.parse_failed", path = path.display(), error = err),
+        )
     })
 }
 
@@ -3260,7 +4373,10 @@ fn resolve_runpack_dir(manifest: &Path, override_dir: Option<PathBuf>) -> CliRes
     }
 
     std::env::current_dir().map_err(|err| {
-        CliError::new(t!("runpack.verify.reader_failed", path = manifest.display(), error = err))
+        CliError::new(
+            CliErrorKind::Io,
+            t!("runpack.verify.reader_failed", path = manifest.display(), error = err),
+        )
     })
 }
 
@@ -3282,16 +4398,19 @@ fn artifact_is_json(artifact: &decision_gate_core::ArtifactRecord) -> bool {
 fn resolve_generated_at(override_unix_ms: Option<i64>) -> CliResult<Timestamp> {
     if let Some(value) = override_unix_ms {
         if value < 0 {
-            return Err(CliError::new(t!("runpack.export.time.negative")));
+            return Err(CliError::new(
+                CliErrorKind::InvalidInput,
+                t!("runpack.export.time.negative"),
+            ));
         }
         return Ok(Timestamp::UnixMillis(value));
     }
 
-    let duration = SystemTime::now()
-        .duration_since(UNIX_EPOCH)
-        .map_err(|err| CliError::new(t!("runpack.export.time.system_failed", error = err)))?;
+    let duration = SystemTime::now().duration_since(UNIX_EPOCH).map_err(|err| {
+        CliError::new(CliErrorKind::Internal, t!("runpack.export.time.system_failed", error = err))
+    })?;
     let millis = i64::try_from(duration.as_millis())
-        .map_err(|_| CliError::new(t!("runpack.export.time.overflow")))?;
+        .map_err(|_| CliError::new(CliErrorKind::Internal, t!("runpack.export.time.overflow")))?;
     Ok(Timestamp::UnixMillis(millis))
 }
 
@@ -3299,15 +4418,20 @@ fn resolve_generated_at(override_unix_ms: Option<i64>) -> CliResult<Timestamp> {
 fn resolve_runpack_object_store_backend(
     config_path: Option<&Path>,
 ) -> CliResult<ObjectStoreRunpackBackend> {
-    let config = DecisionGateConfig::load(config_path)
-        .map_err(|err| CliError::new(t!("config.load_failed", error = err)))?;
+    let config = DecisionGateConfig::load(config_path).map_err(|err| {
+        CliError::new(CliErrorKind::Config, t!("config.load_failed", error = err))
+    })?;
     let Some(storage) = &config.runpack_storage else {
-        return Err(CliError::new(t!("runpack.storage.missing")));
+        return Err(CliError::new(CliErrorKind::Config, t!("runpack.storage.missing")));
     };
     match storage {
         config::RunpackStorageConfig::ObjectStore(storage) => {
-            ObjectStoreRunpackBackend::new(storage)
-                .map_err(|err| CliError::new(t!("runpack.storage.init_failed", error = err)))
+            ObjectStoreRunpackBackend::new(storage).map_err(|err| {
+                CliError::new(
+                    CliErrorKind::Internal,
+                    t!("runpack.storage.init_failed", error = err),
+                )
+            })
         }
     }
 }
@@ -3332,15 +4456,20 @@ fn upload_runpack_to_object_store(
 ) -> CliResult<String> {
     let backend = resolve_runpack_object_store_backend(config_path)?;
     let key = runpack_object_key_from_manifest(manifest);
-    let reader = FileArtifactReader::new(output_dir.to_path_buf())
-        .map_err(|err| CliError::new(t!("runpack.storage.upload_failed", error = err)))?;
-    let mut sink = backend
-        .sink(&key, manifest_name)
-        .map_err(|err| CliError::new(t!("runpack.storage.upload_failed", error = err)))?;
+    let reader = FileArtifactReader::new(output_dir.to_path_buf()).map_err(|err| {
+        CliError::new(CliErrorKind::Remote, t!("runpack.storage.upload_failed", error = err))
+    })?;
+    let mut sink = backend.sink(&key, manifest_name).map_err(|err| {
+        CliError::new(CliErrorKind::Remote, t!("runpack.storage.upload_failed", error = err))
+    })?;
     for artifact in &manifest.artifacts {
-        let bytes = reader
-            .read_with_limit(&artifact.path, MAX_RUNPACK_ARTIFACT_BYTES)
-            .map_err(|err| CliError::new(t!("runpack.storage.upload_failed", error = err)))?;
+        let bytes =
+            reader.read_with_limit(&artifact.path, MAX_RUNPACK_ARTIFACT_BYTES).map_err(|err| {
+                CliError::new(
+                    CliErrorKind::Remote,
+                    t!("runpack.storage.upload_failed", error = err),
+                )
+            })?;
         let payload = Artifact {
             kind: artifact.kind,
             path: artifact.path.clone(),
@@ -3348,29 +4477,40 @@ fn upload_runpack_to_object_store(
             bytes,
             required: artifact.required,
         };
-        sink.write(&payload)
-            .map_err(|err| CliError::new(t!("runpack.storage.upload_failed", error = err)))?;
+        sink.write(&payload).map_err(|err| {
+            CliError::new(CliErrorKind::Remote, t!("runpack.storage.upload_failed", error = err))
+        })?;
     }
-    sink.finalize(manifest)
-        .map_err(|err| CliError::new(t!("runpack.storage.upload_failed", error = err)))?;
-    backend
-        .storage_uri(&key)
-        .map_err(|err| CliError::new(t!("runpack.storage.upload_failed", error = err)))
+    sink.finalize(manifest).map_err(|err| {
+        CliError::new(CliErrorKind::Remote, t!("runpack.storage.upload_failed", error = err))
+    })?;
+    backend.storage_uri(&key).map_err(|err| {
+        CliError::new(CliErrorKind::Remote, t!("runpack.storage.upload_failed", error = err))
+    })
 }
 
 /// Renders a verification report in the requested format.
+///
+/// Text formats end with a newline; msgpack output is emitted verbatim.
 fn render_verification_report(
     format: VerifyFormat,
     report: &VerificationReport,
-) -> CliResult<String> {
+) -> CliResult<Vec<u8>> {
     match format {
         VerifyFormat::Json => {
-            let bytes = serde_jcs::to_vec(report)
-                .map_err(|err| CliError::new(t!("runpack.verify.failed", error = err)))?;
-            String::from_utf8(bytes)
-                .map_err(|err| CliError::new(t!("runpack.verify.failed", error = err)))
+            let mut bytes = serde_jcs::to_vec(report).map_err(|err| {
+                CliError::new(CliErrorKind::Internal, t!("runpack.verify.failed", error = err))
+            })?;
+            bytes.push(b'\n');
+            Ok(bytes)
+        }
+        VerifyFormat::Markdown => {
+            let mut output = render_verification_markdown(report);
+            output.push('\n');
+            Ok(output.into_bytes())
         }
-        VerifyFormat::Markdown => Ok(render_verification_markdown(report)),
+        #[cfg(feature = "msgpack")]
+        VerifyFormat::Msgpack => canonical_msgpack_bytes(report),
     }
 }
 
@@ -3421,10 +4561,16 @@ fn resolve_interop_timestamp(
     label: &str,
 ) -> CliResult<Timestamp> {
     match (unix_ms, logical) {
-        (Some(_), Some(_)) => Err(CliError::new(t!("interop.timestamp.conflict", label = label))),
+        (Some(_), Some(_)) => Err(CliError::new(
+            CliErrorKind::InvalidInput,
+            t!("interop.timestamp.conflict", label = label),
+        )),
         (Some(value), None) => {
             if value < 0 {
-                return Err(CliError::new(t!("interop.timestamp.negative", label = label)));
+                return Err(CliError::new(
+                    CliErrorKind::InvalidInput,
+                    t!("interop.timestamp.negative", label = label),
+                ));
             }
             Ok(Timestamp::UnixMillis(value))
         }
@@ -3480,35 +4626,47 @@ fn build_mcp_client(args: &McpClientArgs) -> CliResult<McpClient> {
         bearer_token: auth.bearer_token,
         client_subject: auth.client_subject,
     };
-    McpClient::new(config).map_err(|err| CliError::new(t!("mcp.client.config_failed", error = err)))
+    McpClient::new(config).map_err(|err| {
+        CliError::new(CliErrorKind::Config, t!("mcp.client.config_failed", error = err))
+    })
 }
 
 /// Reads MCP tool input JSON from flags or files.
 fn read_mcp_tool_input(args: &McpToolInputArgs) -> CliResult<Value> {
     if let Some(json) = &args.json {
-        return serde_json::from_str(json)
-            .map_err(|err| CliError::new(t!("mcp.client.input_parse_failed", error = err)));
+        return serde_json::from_str(json).map_err(|err| {
+            CliError::new(
+                CliErrorKind::InvalidInput,
+                t!("mcp.client.input_parse_failed", error = err),
+            )
+        });
     }
     if let Some(path) = &args.input {
         let bytes = read_bytes_with_limit(path, MAX_MCP_INPUT_BYTES).map_err(|err| match err {
-            ReadLimitError::Io(err) => CliError::new(t!(
-                "mcp.client.input_read_failed",
-                path = path.display(),
-                error = err
-            )),
+            ReadLimitError::Io(err) => CliError::new(
+                CliErrorKind::Io,
+                t!("mcp.client.input_read_failed", path = path.display(), error = err),
+            ),
             ReadLimitError::TooLarge {
                 size,
                 limit,
-            } => CliError::new(t!(
-                "input.read_too_large",
-                kind = "mcp tool input",
-                path = path.display(),
-                size = size,
-                limit = limit
-            )),
+            } => CliError::new(
+                CliErrorKind::InvalidInput,
+                t!(
+                    "input.read_too_large",
+                    kind = "mcp tool input",
+                    path = path.display(),
+                    size = size,
+                    limit = limit
+                ),
+            ),
         })?;
-        return serde_json::from_slice(&bytes)
-            .map_err(|err| CliError::new(t!("mcp.client.input_parse_failed", error = err)));
+        return serde_json::from_slice(&bytes).map_err(|err| {
+            CliError::new(
+                CliErrorKind::InvalidInput,
+                t!("mcp.client.input_parse_failed", error = err),
+            )
+        });
     }
     Ok(serde_json::json!({}))
 }
@@ -3525,13 +4683,22 @@ fn parse_stdio_env(values: &[String]) -> CliResult<Vec<(String, String)>> {
     for entry in values {
         if entry.contains('\0') {
             let display = entry.replace('\0', "\\0");
-            return Err(CliError::new(t!("mcp.client.invalid_stdio_env", value = display)));
+            return Err(CliError::new(
+                CliErrorKind::InvalidInput,
+                t!("mcp.client.invalid_stdio_env", value = display),
+            ));
         }
-        let (key, value) = entry
-            .split_once('=')
-            .ok_or_else(|| CliError::new(t!("mcp.client.invalid_stdio_env", value = entry)))?;
+        let (key, value) = entry.split_once('=').ok_or_else(|| {
+            CliError::new(
+                CliErrorKind::InvalidInput,
+                t!("mcp.client.invalid_stdio_env", value = entry),
+            )
+        })?;
         if key.is_empty() {
-            return Err(CliError::new(t!("mcp.client.invalid_stdio_env", value = entry)));
+            return Err(CliError::new(
+                CliErrorKind::InvalidInput,
+                t!("mcp.client.invalid_stdio_env", value = entry),
+            ));
         }
         env.push((key.to_string(), value.to_string()));
     }
@@ -3540,14 +4707,21 @@ fn parse_stdio_env(values: &[String]) -> CliResult<Vec<(String, String)>> {
 
 /// Parses a tenant identifier from CLI inputs.
 fn parse_tenant_id(value: u64) -> CliResult<TenantId> {
-    TenantId::from_raw(value)
-        .ok_or_else(|| CliError::new(t!("schema.invalid_id", field = "tenant_id", value = value)))
+    TenantId::from_raw(value).ok_or_else(|| {
+        CliError::new(
+            CliErrorKind::InvalidInput,
+            t!("schema.invalid_id", field = "tenant_id", value = value),
+        )
+    })
 }
 
 /// Parses a namespace identifier from CLI inputs.
 fn parse_namespace_id(value: u64) -> CliResult<NamespaceId> {
     NamespaceId::from_raw(value).ok_or_else(|| {
-        CliError::new(t!("schema.invalid_id", field = "namespace_id", value = value))
+        CliError::new(
+            CliErrorKind::InvalidInput,
+            t!("schema.invalid_id", field = "namespace_id", value = value),
+        )
     })
 }
 
@@ -3564,7 +4738,10 @@ fn resolve_auth(args: &McpClientArgs) -> CliResult<ResolvedAuth> {
     let config_path = resolve_auth_config_path(args.auth_config.as_deref());
     let profiles = load_auth_profiles(&config_path)?;
     let profile = profiles.get(profile_name).ok_or_else(|| {
-        CliError::new(t!("mcp.client.auth_profile_missing", profile = profile_name))
+        CliError::new(
+            CliErrorKind::Config,
+            t!("mcp.client.auth_profile_missing", profile = profile_name),
+        )
     })?;
 
     Ok(ResolvedAuth {
@@ -3610,19 +4787,29 @@ struct CliClientConfig {
 /// Loads auth profiles from a config file.
 fn load_auth_profiles(path: &Path) -> CliResult<BTreeMap<String, AuthProfileConfig>> {
     let bytes = read_bytes_with_limit(path, MAX_AUTH_CONFIG_BYTES).map_err(|err| match err {
-        ReadLimitError::Io(err) => CliError::new(t!(
-            "mcp.client.auth_config_read_failed",
-            path = path.display(),
-            error = err
-        )),
+        ReadLimitError::Io(err) => CliError::new(
+            CliErrorKind::Io,
+            t!("mcp.client.auth_config_read_failed", path = path.display(), error = err),
+        ),
         ReadLimitError::TooLarge {
             ..
-        } => CliError::new(t!("mcp.client.auth_config_too_large", path = path.display())),
+        } => CliError::new(
+            CliErrorKind::InvalidInput,
+            t!("mcp.client.auth_config_too_large", path = path.display()),
+        ),
+    })?;
+    let content = std::str::from_utf8(&bytes).map_err(|err| {
+        CliError::new(
+            CliErrorKind::InvalidInput,
+            t!("mcp.client.auth_config_parse_failed", error = err),
+        )
+    })?;
+    let parsed: CliConfig = toml::from_str(content).map_err(|err| {
+        CliError::new(
+            CliErrorKind::InvalidInput,
+            t!("mcp.client.auth_config_parse_failed", error = err),
+        )
     })?;
-    let content = std::str::from_utf8(&bytes)
-        .map_err(|err| CliError::new(t!("mcp.client.auth_config_parse_failed", error = err)))?;
-    let parsed: CliConfig = toml::from_str(content)
-        .map_err(|err| CliError::new(t!("mcp.client.auth_config_parse_failed", error = err)))?;
     Ok(parsed.client.and_then(|client| client.auth_profiles).unwrap_or_default())
 }
 
@@ -3640,14 +4827,16 @@ impl ToolSchemaValidator {
     /// Builds a tool schema validator from canonical contracts.
     fn new() -> CliResult<Self> {
         let scenario_schema = decision_gate_contract::schemas::scenario_schema();
-        let id = scenario_schema
-            .get("$id")
-            .and_then(Value::as_str)
-            .ok_or_else(|| CliError::new(t!("mcp.client.schema_registry_missing")))?;
+        let id = scenario_schema.get("$id").and_then(Value::as_str).ok_or_else(|| {
+            CliError::new(CliErrorKind::Internal, t!("mcp.client.schema_registry_missing"))
+        })?;
         let registry =
             Registry::try_new(id, Draft::Draft202012.create_resource(scenario_schema.clone()))
                 .map_err(|err| {
-                    CliError::new(t!("mcp.client.schema_registry_failed", error = err))
+                    CliError::new(
+                        CliErrorKind::Internal,
+                        t!("mcp.client.schema_registry_failed", error = err),
+                    )
                 })?;
         let mut contracts = BTreeMap::new();
         for contract in tool_contracts() {
@@ -3663,7 +4852,10 @@ impl ToolSchemaValidator {
     /// Validates an input payload against the tool schema.
     fn validate(&mut self, tool: decision_gate_core::ToolName, input: &Value) -> CliResult<()> {
         let contract = self.contracts.get(&tool).ok_or_else(|| {
-            CliError::new(t!("mcp.client.schema_unknown_tool", tool = tool.as_str()))
+            CliError::new(
+                CliErrorKind::InvalidInput,
+                t!("mcp.client.schema_unknown_tool", tool = tool.as_str()),
+            )
         })?;
         let validator = if let Some(existing) = self.validators.get(&tool) {
             existing
@@ -3671,7 +4863,10 @@ impl ToolSchemaValidator {
             let compiled = compile_schema(&contract.input_schema, &self.registry)?;
             self.validators.insert(tool, compiled);
             self.validators.get(&tool).ok_or_else(|| {
-                CliError::new(t!("mcp.client.schema_compile_failed", error = "validator missing"))
+                CliError::new(
+                    CliErrorKind::Internal,
+                    t!("mcp.client.schema_compile_failed", error = "validator missing"),
+                )
             })?
         };
         if !validator.is_valid(input) {
@@ -3679,11 +4874,10 @@ impl ToolSchemaValidator {
             let message = errors
                 .next()
                 .map_or_else(|| "schema validation failed".to_string(), |err| err.to_string());
-            return Err(CliError::new(t!(
-                "mcp.client.schema_validation_failed",
-                tool = tool.as_str(),
-                error = message
-            )));
+            return Err(CliError::new(
+                CliErrorKind::InvalidInput,
+                t!("mcp.client.schema_validation_failed", tool = tool.as_str(), error = message),
+            ));
         }
         Ok(())
     }
@@ -3698,10 +4892,15 @@ fn tool_schema_validator() -> CliResult<std::sync::MutexGuard<'static, ToolSchem
         let validator = ToolSchemaValidator::new()?;
         let _ = VALIDATOR.set(std::sync::Mutex::new(validator));
         VALIDATOR.get().ok_or_else(|| {
-            CliError::new(t!("mcp.client.schema_compile_failed", error = "validator missing"))
+            CliError::new(
+                CliErrorKind::Internal,
+                t!("mcp.client.schema_compile_failed", error = "validator missing"),
+            )
         })?
     };
-    mutex.lock().map_err(|_| CliError::new(t!("mcp.client.schema_lock_failed")))
+    mutex
+        .lock()
+        .map_err(|_| CliError::new(CliErrorKind::Internal, t!("mcp.client.schema_lock_failed")))
 }
 
 /// Compiles a JSON schema validator with the shared registry.
@@ -3710,7 +4909,12 @@ fn compile_schema(schema: &Value, registry: &Registry) -> CliResult<Validator> {
         .with_draft(Draft::Draft202012)
         .with_registry(registry.clone())
         .build(schema)
-        .map_err(|err| CliError::new(t!("mcp.client.schema_compile_failed", error = err)))
+        .map_err(|err| {
+            CliError::new(
+                CliErrorKind::Internal,
+                t!("mcp.client.schema_compile_failed", error = err),
+            )
+        })
 }
 
 /// Resolves the CLI locale from flags or environment.
@@ -3720,7 +4924,10 @@ fn resolve_locale(lang: Option<LangArg>, env_lang: Option<&str>) -> CliResult<Lo
     }
     if let Some(value) = env_lang {
         return Locale::parse(value).ok_or_else(|| {
-            CliError::new(t!("i18n.lang.invalid_env", env = LANG_ENV, value = value))
+            CliError::new(
+                CliErrorKind::Config,
+                t!("i18n.lang.invalid_env", env = LANG_ENV, value = value),
+            )
         });
     }
     Ok(Locale::En)
@@ -3738,29 +4945,40 @@ fn resolve_authoring_format(
     if let Some(format) = format {
         return Ok(format.into());
     }
-    authoring::detect_format(path)
-        .ok_or_else(|| CliError::new(t!("authoring.format.missing", path = path.display())))
+    authoring::detect_format(path).ok_or_else(|| {
+        CliError::new(
+            CliErrorKind::InvalidInput,
+            t!("authoring.format.missing", path = path.display()),
+        )
+    })
 }
 
 /// Reads authoring input from disk.
 fn read_authoring_input(path: &Path) -> CliResult<String> {
     let bytes = read_bytes_with_limit(path, MAX_AUTHORING_BYTES).map_err(|err| match err {
-        ReadLimitError::Io(err) => {
-            CliError::new(t!("authoring.read_failed", path = path.display(), error = err))
-        }
+        ReadLimitError::Io(err) => CliError::new(
+            CliErrorKind::Io,
+            t!("authoring.read_failed", path = path.display(), error = err),
+        ),
         ReadLimitError::TooLarge {
             size,
             limit,
-        } => CliError::new(t!(
-            "input.read_too_large",
-            kind = t!("authoring.kind.input"),
-            path = path.display(),
-            size = size,
-            limit = limit
-        )),
+        } => CliError::new(
+            CliErrorKind::InvalidInput,
+            t!(
+                "input.read_too_large",
+                kind = t!("authoring.kind.input"),
+                path = path.display(),
+                size = size,
+                limit = limit
+            ),
+        ),
     })?;
     String::from_utf8(bytes).map_err(|err| {
-        CliError::new(t!("authoring.read_failed", path = path.display(), error = err))
+        CliError::new(
+            CliErrorKind::Io,
+            t!("authoring.read_failed", path = path.display(), error = err),
+        )
     })
 }
 
@@ -3830,14 +5048,25 @@ fn map_authoring_error(error: AuthoringError, path: &Path) -> CliError {
             size = actual_bytes,
             limit = max_bytes
         ),
+        AuthoringError::Template {
+            error,
+        } => {
+            t!("authoring.template_failed", path = path.display(), error = error)
+        }
+        AuthoringError::MissingTemplateParams {
+            names,
+        } => {
+            t!("authoring.template_missing_params", path = path.display(), names = names.join(", "))
+        }
     };
-    CliError::new(message)
+    CliError::new(CliErrorKind::InvalidInput, message)
 }
 
 /// Formats a hash digest for CLI output.
 fn format_hash_digest(digest: &decision_gate_core::HashDigest) -> String {
     let algorithm = match digest.algorithm {
         HashAlgorithm::Sha256 => "sha256",
+        HashAlgorithm::Blake3 => "blake3",
     };
     format!("{algorithm}:{}", digest.value)
 }
@@ -3906,6 +5135,9 @@ impl From<McpToolNameArg> for decision_gate_core::ToolName {
             McpToolNameArg::ScenariosList => Self::ScenariosList,
             McpToolNameArg::Precheck => Self::Precheck,
             McpToolNameArg::DecisionGateDocsSearch => Self::DecisionGateDocsSearch,
+            McpToolNameArg::ScenariosStatusBulk => Self::ScenariosStatusBulk,
+            McpToolNameArg::ScenarioLeaseAcquire => Self::ScenarioLeaseAcquire,
+            McpToolNameArg::ScenarioLeaseRelease => Self::ScenarioLeaseRelease,
         }
     }
 }
@@ -3930,7 +5162,8 @@ fn write_stdout_bytes(bytes: &[u8]) -> std::io::Result<()> {
 fn write_stdout_bytes_with_newline(bytes: &[u8]) -> CliResult<()> {
     let mut buffer = bytes.to_vec();
     buffer.push(b'\n');
-    write_stdout_bytes(&buffer).map_err(|err| CliError::new(output_error("stdout", &err)))
+    write_stdout_bytes(&buffer)
+        .map_err(|err| CliError::new(CliErrorKind::Io, output_error("stdout", &err)))
 }
 
 /// Writes canonical JSON to stdout with a size limit.
@@ -3948,23 +5181,45 @@ fn write_canonical_json<T: Serialize>(value: &T, max_bytes: usize) -> CliResult<
                 error = format!("response exceeds size limit ({actual} > {limit})")
             ),
         };
-        CliError::new(message)
+        CliError::new(CliErrorKind::Internal, message)
     })?;
     bytes.push(b'\n');
-    write_stdout_bytes(&bytes).map_err(|err| CliError::new(output_error("stdout", &err)))
+    write_stdout_bytes(&bytes)
+        .map_err(|err| CliError::new(CliErrorKind::Io, output_error("stdout", &err)))
 }
 
 /// Writes a canonical JSON value to stdout.
 fn write_json_value(value: &Value) -> CliResult<()> {
-    let mut bytes = serde_jcs::to_vec(value)
-        .map_err(|err| CliError::new(t!("mcp.client.json_failed", error = err)))?;
+    let mut bytes = serde_jcs::to_vec(value).map_err(|err| {
+        CliError::new(CliErrorKind::Internal, t!("mcp.client.json_failed", error = err))
+    })?;
     bytes.push(b'\n');
-    write_stdout_bytes(&bytes).map_err(|err| CliError::new(output_error("stdout", &err)))
+    write_stdout_bytes(&bytes)
+        .map_err(|err| CliError::new(CliErrorKind::Io, output_error("stdout", &err)))
 }
 
 /// Computes canonical JSON bytes for output rendering.
 fn canonical_output_bytes<T: Serialize>(value: &T) -> CliResult<Vec<u8>> {
-    serde_jcs::to_vec(value).map_err(|err| CliError::new(t!("mcp.client.json_failed", error = err)))
+    serde_jcs::to_vec(value).map_err(|err| {
+        CliError::new(CliErrorKind::Internal, t!("mcp.client.json_failed", error = err))
+    })
+}
+
+/// Serializes a value as canonical `MessagePack`.
+///
+/// The value is reduced to its RFC 8785 canonical JSON data model first, so
+/// maps keep canonical key order, integers use their smallest `MessagePack`
+/// width, and other numbers encode as float64. Equal values always produce
+/// identical bytes and therefore identical hashes.
+#[cfg(feature = "msgpack")]
+fn canonical_msgpack_bytes<T: Serialize>(value: &T) -> CliResult<Vec<u8>> {
+    let canonical = canonical_output_bytes(value)?;
+    let value: Value = serde_json::from_slice(&canonical).map_err(|err| {
+        CliError::new(CliErrorKind::Internal, t!("output.msgpack_failed", error = err))
+    })?;
+    rmp_serde::to_vec(&value).map_err(|err| {
+        CliError::new(CliErrorKind::Internal, t!("output.msgpack_failed", error = err))
+    })
 }
 
 /// Emits structured output with optional hash/signature artifacts.
@@ -3985,7 +5240,7 @@ fn emit_structured_output<T: Serialize>(
                 output.push('\n');
             }
             write_stdout_bytes(output.as_bytes())
-                .map_err(|err| CliError::new(output_error("stdout", &err)))?;
+                .map_err(|err| CliError::new(CliErrorKind::Io, output_error("stdout", &err)))?;
         }
     }
     write_output_artifacts_bytes(&bytes, artifacts)?;
@@ -4014,20 +5269,19 @@ fn write_output_artifacts_bytes(bytes: &[u8], artifacts: &OutputArtifactsArgs) -
         return Ok(());
     }
     if artifacts.signature_out.is_some() && artifacts.signing_key.is_none() {
-        return Err(CliError::new(t!("output.signature.key_required")));
+        return Err(CliError::new(CliErrorKind::InvalidInput, t!("output.signature.key_required")));
     }
     if artifacts.signing_key.is_some() && artifacts.signature_out.is_none() {
-        return Err(CliError::new(t!("output.signature.out_required")));
+        return Err(CliError::new(CliErrorKind::InvalidInput, t!("output.signature.out_required")));
     }
     let digest = hash_bytes(DEFAULT_HASH_ALGORITHM, bytes);
     if let Some(path) = &artifacts.hash_out {
         write_output_artifact(path, "hash", &digest)?;
     }
     if let Some(path) = &artifacts.signature_out {
-        let key_path = artifacts
-            .signing_key
-            .as_ref()
-            .ok_or_else(|| CliError::new(t!("output.signature.key_required")))?;
+        let key_path = artifacts.signing_key.as_ref().ok_or_else(|| {
+            CliError::new(CliErrorKind::InvalidInput, t!("output.signature.key_required"))
+        })?;
         let signing_key = load_signing_key(key_path)?;
         let signature = signing_key.sign(bytes);
         let key_id = BASE64.encode(signing_key.verifying_key().to_bytes());
@@ -4045,15 +5299,16 @@ fn write_output_artifacts_bytes(bytes: &[u8], artifacts: &OutputArtifactsArgs) -
 /// Writes a canonical JSON artifact to disk.
 fn write_output_artifact<T: Serialize>(path: &Path, kind: &str, value: &T) -> CliResult<()> {
     let bytes = serde_jcs::to_vec(value).map_err(|err| {
-        CliError::new(t!("output.artifact.serialize_failed", kind = kind, error = err))
+        CliError::new(
+            CliErrorKind::Internal,
+            t!("output.artifact.serialize_failed", kind = kind, error = err),
+        )
     })?;
     fs::write(path, bytes).map_err(|err| {
-        CliError::new(t!(
-            "output.artifact.write_failed",
-            kind = kind,
-            path = path.display(),
-            error = err
-        ))
+        CliError::new(
+            CliErrorKind::Io,
+            t!("output.artifact.write_failed", kind = kind, path = path.display(), error = err),
+        )
     })?;
     Ok(())
 }
@@ -4061,44 +5316,74 @@ fn write_output_artifact<T: Serialize>(path: &Path, kind: &str, value: &T) -> Cl
 /// Loads a signing key from disk.
 fn load_signing_key(path: &Path) -> CliResult<SigningKey> {
     let bytes = read_bytes_with_limit(path, MAX_SIGNING_KEY_BYTES).map_err(|err| match err {
-        ReadLimitError::Io(err) => CliError::new(t!(
-            "output.signature.key_read_failed",
-            path = path.display(),
-            error = err
-        )),
+        ReadLimitError::Io(err) => CliError::new(
+            CliErrorKind::Io,
+            t!("output.signature.key_read_failed", path = path.display(), error = err),
+        ),
         ReadLimitError::TooLarge {
             size,
             limit,
-        } => CliError::new(t!(
-            "input.read_too_large",
-            kind = t!("output.signature.key_kind"),
-            path = path.display(),
-            size = size,
-            limit = limit
-        )),
+        } => CliError::new(
+            CliErrorKind::InvalidInput,
+            t!(
+                "input.read_too_large",
+                kind = t!("output.signature.key_kind"),
+                path = path.display(),
+                size = size,
+                limit = limit
+            ),
+        ),
+    })?;
+    let key = decode_ed25519_key_bytes(&bytes).ok_or_else(|| {
+        CliError::new(CliErrorKind::InvalidInput, t!("output.signature.key_invalid"))
     })?;
-    if bytes.len() == 32 {
-        let key: [u8; 32] = bytes
-            .as_slice()
-            .try_into()
-            .map_err(|_| CliError::new(t!("output.signature.key_invalid")))?;
-        return Ok(SigningKey::from_bytes(&key));
-    }
-    let text = std::str::from_utf8(&bytes)
-        .map_err(|_| CliError::new(t!("output.signature.key_invalid")))?;
-    let decoded = BASE64
-        .decode(text.trim().as_bytes())
-        .map_err(|_| CliError::new(t!("output.signature.key_invalid")))?;
-    if decoded.len() != 32 {
-        return Err(CliError::new(t!("output.signature.key_invalid")));
-    }
-    let key: [u8; 32] = decoded
-        .as_slice()
-        .try_into()
-        .map_err(|_| CliError::new(t!("output.signature.key_invalid")))?;
     Ok(SigningKey::from_bytes(&key))
 }
 
+/// Loads an ed25519 public key from disk.
+fn load_verifying_key(path: &Path) -> CliResult<VerifyingKey> {
+    let bytes = read_bytes_with_limit(path, MAX_SIGNING_KEY_BYTES).map_err(|err| match err {
+        ReadLimitError::Io(err) => CliError::new(
+            CliErrorKind::Io,
+            t!(
+                "runpack.attest.read_failed",
+                kind = t!("runpack.attest.public_key_kind"),
+                path = path.display(),
+                error = err
+            ),
+        ),
+        ReadLimitError::TooLarge {
+            size,
+            limit,
+        } => CliError::new(
+            CliErrorKind::InvalidInput,
+            t!(
+                "input.read_too_large",
+                kind = t!("runpack.attest.public_key_kind"),
+                path = path.display(),
+                size = size,
+                limit = limit
+            ),
+        ),
+    })?;
+    let key = decode_ed25519_key_bytes(&bytes).ok_or_else(|| {
+        CliError::new(CliErrorKind::InvalidInput, t!("runpack.attest.public_key_invalid"))
+    })?;
+    VerifyingKey::from_bytes(&key).map_err(|_| {
+        CliError::new(CliErrorKind::InvalidInput, t!("runpack.attest.public_key_invalid"))
+    })
+}
+
+/// Decodes 32-byte ed25519 key material from raw bytes or base64 text.
+fn decode_ed25519_key_bytes(bytes: &[u8]) -> Option<[u8; 32]> {
+    if let Ok(key) = <[u8; 32]>::try_from(bytes) {
+        return Some(key);
+    }
+    let text = std::str::from_utf8(bytes).ok()?;
+    let decoded = BASE64.decode(text.trim().as_bytes()).ok()?;
+    <[u8; 32]>::try_from(decoded.as_slice()).ok()
+}
+
 /// Renders provider list output in text form.
 fn render_provider_list_text(
     response: &decision_gate_mcp::tools::ProvidersListResponse,
@@ -4120,7 +5405,8 @@ fn render_provider_list_text(
         ));
         output.push('\n');
     }
-    write_stdout_bytes(output.as_bytes()).map_err(|err| CliError::new(output_error("stdout", &err)))
+    write_stdout_bytes(output.as_bytes())
+        .map_err(|err| CliError::new(CliErrorKind::Io, output_error("stdout", &err)))
 }
 
 /// Writes a single line to stderr.
@@ -4144,3 +5430,35 @@ fn emit_error(message: &str) -> ExitCode {
     let _ = write_stderr_line(message);
     ExitCode::FAILURE
 }
+
+/// Emits `error` as a JSON envelope on stdout and returns its exit code.
+///
+/// The envelope is `{"error":{"code":<exit code>,"kind":<kind>,"message":<text>}}`.
+fn emit_error_json(error: &CliError) -> ExitCode {
+    let code = error.kind.exit_code();
+    let envelope = serde_json::json!({
+        "error": {
+            "code": code,
+            "kind": error.kind.as_str(),
+            "message": error.message,
+        }
+    });
+    let _ = write_stdout_line(&envelope.to_string());
+    ExitCode::from(code)
+}
+
+/// Reports an argument parsing failure and returns its exit code.
+///
+/// Help and version output, and errors without `--errors-json` among the raw
+/// arguments, are left to clap. Otherwise the first line of clap's message is
+/// emitted as a [`CliErrorKind::Usage`] envelope.
+fn emit_parse_error(error: &clap::Error) -> ExitCode {
+    let errors_json = std::env::args_os().skip(1).any(|arg| arg == "--errors-json");
+    if !errors_json || !error.use_stderr() {
+        error.exit();
+    }
+    let rendered = error.to_string();
+    let message = rendered.lines().next().unwrap_or_default();
+    let message = message.strip_prefix("error: ").unwrap_or(message);
+    emit_error_json(&CliError::new(CliErrorKind::Usage, message.to_string()))
+}
@@ -30,24 +30,86 @@
 
 use std::fs;
 use std::path::PathBuf;
+#[cfg(feature = "nats")]
+use std::time::Duration;
 use std::time::SystemTime;
 use std::time::UNIX_EPOCH;
 
+#[cfg(feature = "nats")]
+use decision_gate_broker::MAX_SOURCE_BYTES;
+#[cfg(feature = "nats")]
+use decision_gate_broker::NatsConnection;
+#[cfg(feature = "nats")]
+use decision_gate_broker::NatsError;
+#[cfg(feature = "nats")]
+use decision_gate_broker::NatsMessage;
+#[cfg(feature = "nats")]
+use decision_gate_broker::NatsObject;
+#[cfg(feature = "nats")]
+use decision_gate_broker::NatsPublishAck;
+use decision_gate_core::ContentRef;
+use decision_gate_core::HashAlgorithm;
+use decision_gate_core::hashing::DEFAULT_HASH_ALGORITHM;
+use decision_gate_core::hashing::hash_bytes;
+
+#[cfg(feature = "nats")]
+use super::BrokerNatsArgs;
 use super::McpClientArgs;
 use super::McpTransportArg;
 use super::ReadLimitError;
+use super::broker_head_output;
+use super::build_broker_sources;
 use super::load_auth_profiles;
+#[cfg(feature = "nats")]
+use super::nats_source;
+use super::parse_hash_algorithm_label;
 use super::parse_namespace_id;
 use super::parse_stdio_env;
 use super::parse_tenant_id;
 use super::read_bytes_with_limit;
 use super::resolve_auth;
 use super::resolve_auth_config_path;
+use super::resolve_broker_source;
 
 // ============================================================================
 // SECTION: Helpers
 // ============================================================================
 
+/// NATS connection serving a single object.
+#[cfg(feature = "nats")]
+struct MockNatsConnection {
+    bucket: String,
+    name: String,
+    bytes: Vec<u8>,
+}
+
+#[cfg(feature = "nats")]
+impl NatsConnection for MockNatsConnection {
+    fn publish(
+        &self,
+        _message: NatsMessage,
+        _timeout: Duration,
+    ) -> Result<NatsPublishAck, NatsError> {
+        Err(NatsError::Other("publish unsupported".to_string()))
+    }
+
+    fn get_object(
+        &self,
+        bucket: &str,
+        name: &str,
+        _max_bytes: usize,
+        _timeout: Duration,
+    ) -> Result<NatsObject, NatsError> {
+        if bucket != self.bucket || name != self.name {
+            return Err(NatsError::NotFound(format!("{bucket}/{name}")));
+        }
+        Ok(NatsObject {
+            bytes: self.bytes.clone(),
+            content_type: Some("application/octet-stream".to_string()),
+        })
+    }
+}
+
 fn temp_file(label: &str) -> PathBuf {
     let nanos = SystemTime::now().duration_since(UNIX_EPOCH).expect("clock drift").as_nanos();
     let mut path = std::env::temp_dir();
@@ -231,6 +293,92 @@ fn parse_namespace_id_accepts_max() {
     assert!(namespace.get() > 0);
 }
 
+#[test]
+fn parse_hash_algorithm_label_accepts_supported_algorithms() {
+    assert_eq!(parse_hash_algorithm_label("sha256").unwrap(), HashAlgorithm::Sha256);
+    assert_eq!(parse_hash_algorithm_label("blake3").unwrap(), HashAlgorithm::Blake3);
+    assert!(parse_hash_algorithm_label("BLAKE3").is_err());
+    assert!(parse_hash_algorithm_label("md5").is_err());
+}
+
+#[test]
+fn resolve_broker_source_requires_nats_connection() {
+    let sources = build_broker_sources(None, &[], &[], false).expect("broker sources");
+    assert!(resolve_broker_source(&sources, "inline+json:e30").is_ok());
+    let err = resolve_broker_source(&sources, "nats://evidence/report")
+        .err()
+        .expect("nats requires --nats-url");
+    assert!(err.to_string().contains("--nats-url"));
+    assert!(resolve_broker_source(&sources, "ftp://host/file").is_err());
+}
+
+#[cfg(feature = "nats")]
+#[test]
+fn resolve_broker_source_fetches_nats_object() {
+    let connection = MockNatsConnection {
+        bucket: "evidence".to_string(),
+        name: "runs/run-1/report.bin".to_string(),
+        bytes: b"report".to_vec(),
+    };
+    let args = BrokerNatsArgs {
+        url: None,
+        allow_subjects: vec!["$O.evidence".to_string()],
+        timeout_ms: 500,
+        max_bytes: MAX_SOURCE_BYTES,
+    };
+    let nats = nats_source(connection, &args).expect("nats source");
+    let sources =
+        build_broker_sources(None, &[], &[], false).expect("broker sources").with_nats(Some(nats));
+    let content_ref = ContentRef {
+        uri: "nats://evidence/runs/run-1/report.bin".to_string(),
+        content_hash: hash_bytes(DEFAULT_HASH_ALGORITHM, b"report"),
+        encryption: None,
+    };
+    let source = resolve_broker_source(&sources, &content_ref.uri).expect("nats source");
+    let payload = source.fetch(&content_ref).expect("fetch");
+    assert_eq!(payload.bytes, b"report");
+    assert_eq!(payload.content_type.as_deref(), Some("application/octet-stream"));
+    let denied = ContentRef {
+        uri: "nats://secrets/key".to_string(),
+        ..content_ref
+    };
+    assert!(source.fetch(&denied).is_err());
+}
+
+#[cfg(feature = "nats")]
+#[test]
+fn nats_source_rejects_invalid_allow_subject() {
+    let connection = MockNatsConnection {
+        bucket: String::new(),
+        name: String::new(),
+        bytes: Vec::new(),
+    };
+    let args = BrokerNatsArgs {
+        url: None,
+        allow_subjects: vec!["$O. evidence".to_string()],
+        timeout_ms: 500,
+        max_bytes: MAX_SOURCE_BYTES,
+    };
+    let err = nats_source(connection, &args).err().expect("invalid subject");
+    assert!(err.to_string().contains("$O. evidence"));
+}
+
+#[test]
+fn broker_head_reports_file_size_without_hash_check() {
+    let path = temp_file("broker-head");
+    fs::write(&path, b"metadata only").expect("write payload");
+    let content_ref = ContentRef {
+        uri: format!("file://{}", path.display()),
+        content_hash: hash_bytes(DEFAULT_HASH_ALGORITHM, b"not the payload"),
+        encryption: None,
+    };
+    let sources = build_broker_sources(None, &[], &[], false).expect("broker sources");
+    let output = broker_head_output(&sources, &content_ref).expect("head output");
+    assert_eq!(output.size_bytes, Some(13));
+    assert!(output.content_type.is_none());
+    let _ = fs::remove_file(&path);
+}
+
 // ============================================================================
 // SECTION: Size Limit Enforcement Tests
 // ============================================================================
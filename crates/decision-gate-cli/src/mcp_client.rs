@@ -7,8 +7,10 @@
 // ============================================================================
 
 //! ## Overview
-//! Provides a minimal MCP client for the CLI to call `tools/list`, `tools/call`,
-//! `resources/list`, and `resources/read` across HTTP, SSE, or stdio transports.
+//! Provides a minimal MCP client for the CLI to call `initialize`, `tools/list`,
+//! `tools/call`, `resources/list`, and `resources/read` across HTTP, SSE, or
+//! stdio transports. Capabilities negotiated via `initialize` are stored so the
+//! client can refuse unadvertised features and respect server request limits.
 //!
 //! Security posture: inputs and server responses are untrusted; apply size
 //! limits, fail closed on parsing errors, and never log secrets.
@@ -52,6 +54,9 @@ use thiserror::Error;
 /// Maximum MCP response body size accepted by the CLI.
 pub const MAX_MCP_RESPONSE_BYTES: usize = decision_gate_core::runtime::MAX_RUNPACK_ARTIFACT_BYTES;
 
+/// MCP protocol revision requested during `initialize`.
+pub const MCP_PROTOCOL_VERSION: &str = "2024-11-05";
+
 // ============================================================================
 // SECTION: Types
 // ============================================================================
@@ -139,6 +144,101 @@ pub enum McpClientError {
         /// Maximum size in bytes.
         limit: usize,
     },
+    /// Request size exceeds the limit advertised by the server.
+    #[error("mcp request exceeds server limit ({actual} > {limit})")]
+    RequestTooLarge {
+        /// Actual size in bytes.
+        actual: usize,
+        /// Maximum size in bytes.
+        limit: usize,
+    },
+    /// Feature was not advertised by the server.
+    #[error("mcp feature not supported by server: {0}")]
+    Unsupported(&'static str),
+}
+
+/// Optional MCP features negotiated during `initialize`.
+///
+/// # Invariants
+/// - Variants map one-to-one onto `capabilities.features` flags.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum McpFeature {
+    /// JSON-RPC batch requests.
+    Batch,
+    /// Server-sent event response streaming.
+    Streaming,
+    /// Websocket transport.
+    Websocket,
+}
+
+impl McpFeature {
+    /// Returns the wire label for the feature.
+    #[must_use]
+    pub const fn as_str(self) -> &'static str {
+        match self {
+            Self::Batch => "batch",
+            Self::Streaming => "streaming",
+            Self::Websocket => "websocket",
+        }
+    }
+}
+
+/// Server capabilities returned by `initialize`.
+///
+/// # Invariants
+/// - Values are untrusted; missing flags default to unsupported.
+#[derive(Debug, Clone, Default, Deserialize, Serialize)]
+pub struct ServerCapabilities {
+    /// Optional protocol features.
+    #[serde(default)]
+    pub features: ServerFeatures,
+    /// Request limits enforced by the server.
+    #[serde(default)]
+    pub limits: ServerLimits,
+}
+
+impl ServerCapabilities {
+    /// Returns true when the server advertised the feature.
+    #[must_use]
+    pub const fn supports(&self, feature: McpFeature) -> bool {
+        match feature {
+            McpFeature::Batch => self.features.batch,
+            McpFeature::Streaming => self.features.streaming,
+            McpFeature::Websocket => self.features.websocket,
+        }
+    }
+}
+
+/// Optional protocol feature flags advertised by the server.
+///
+/// # Invariants
+/// - Flags default to `false` when omitted by the server.
+#[derive(Debug, Clone, Copy, Default, Deserialize, Serialize)]
+#[allow(clippy::struct_excessive_bools, reason = "Feature flags mirror the wire format.")]
+pub struct ServerFeatures {
+    /// Whether JSON-RPC batch requests are accepted.
+    #[serde(default)]
+    pub batch: bool,
+    /// Whether responses are streamed as server-sent events.
+    #[serde(default)]
+    pub streaming: bool,
+    /// Whether a websocket transport is available.
+    #[serde(default)]
+    pub websocket: bool,
+}
+
+/// Request limits advertised by the server.
+///
+/// # Invariants
+/// - `None` means the server did not advertise a limit.
+#[derive(Debug, Clone, Copy, Default, Deserialize, Serialize)]
+pub struct ServerLimits {
+    /// Maximum request body size in bytes.
+    #[serde(default)]
+    pub max_body_bytes: Option<usize>,
+    /// Maximum JSON-RPC batch size.
+    #[serde(default)]
+    pub max_batch_size: Option<usize>,
 }
 
 /// MCP resource metadata returned by `resources/list`.
@@ -178,11 +278,14 @@ pub struct ResourceContent {
 /// # Invariants
 /// - `next_id` is strictly increasing for each request sent by this client.
 /// - `transport` is fully initialized and ready to send requests.
+/// - `capabilities` is `None` until `initialize` succeeds.
 pub struct McpClient {
     /// Selected transport client.
     transport: McpTransportClient,
     /// Next JSON-RPC request identifier.
     next_id: u64,
+    /// Capabilities negotiated via `initialize`.
+    capabilities: Option<ServerCapabilities>,
 }
 
 // ============================================================================
@@ -218,6 +321,14 @@ struct JsonRpcError {
     message: String,
 }
 
+/// `initialize` result payload.
+#[derive(Debug, Deserialize)]
+struct InitializeResult {
+    /// Capabilities advertised by the server.
+    #[serde(default)]
+    capabilities: ServerCapabilities,
+}
+
 /// `tools/list` result payload.
 #[derive(Debug, Deserialize)]
 struct ToolListResult {
@@ -286,9 +397,60 @@ impl McpClient {
         Ok(Self {
             transport,
             next_id: 1,
+            capabilities: None,
+        })
+    }
+
+    /// Performs the `initialize` handshake and stores the advertised capabilities.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`McpClientError`] when the transport or parsing fails, or
+    /// [`McpClientError::Unsupported`] when the SSE transport is selected but the
+    /// server does not advertise streaming (capabilities are still recorded).
+    pub async fn initialize(&mut self) -> Result<&ServerCapabilities, McpClientError> {
+        let params = serde_json::json!({
+            "protocolVersion": MCP_PROTOCOL_VERSION,
+            "clientInfo": {
+                "name": "decision-gate-cli",
+                "version": env!("CARGO_PKG_VERSION"),
+            },
+            "capabilities": {},
+        });
+        let response = self.send_request("initialize", Some(params)).await?;
+        let result = response.result.ok_or_else(|| {
+            McpClientError::Protocol("missing result in initialize response".into())
+        })?;
+        let parsed: InitializeResult = serde_json::from_value(result)
+            .map_err(|err| McpClientError::Json(format!("invalid initialize payload: {err}")))?;
+        self.capabilities = Some(parsed.capabilities);
+        if matches!(self.transport, McpTransportClient::Sse(_)) {
+            self.require_feature(McpFeature::Streaming)?;
+        }
+        self.capabilities.as_ref().ok_or_else(|| {
+            McpClientError::Protocol("initialize capabilities were not recorded".to_string())
         })
     }
 
+    /// Returns the capabilities negotiated via `initialize`, if any.
+    #[must_use]
+    pub const fn capabilities(&self) -> Option<&ServerCapabilities> {
+        self.capabilities.as_ref()
+    }
+
+    /// Ensures the server advertised `feature` during `initialize`.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`McpClientError::Unsupported`] when capabilities were not
+    /// negotiated or the feature was not advertised.
+    pub const fn require_feature(&self, feature: McpFeature) -> Result<(), McpClientError> {
+        match &self.capabilities {
+            Some(capabilities) if capabilities.supports(feature) => Ok(()),
+            _ => Err(McpClientError::Unsupported(feature.as_str())),
+        }
+    }
+
     #[cfg(test)]
     #[allow(dead_code, reason = "Test-only helper for request id overflow coverage.")]
     pub(crate) const fn set_next_id_for_test(&mut self, next_id: u64) {
@@ -416,10 +578,20 @@ impl McpClient {
             method,
             params,
         };
+        let payload = serde_json::to_vec(&request)
+            .map_err(|err| McpClientError::Json(format!("jsonrpc serialization failed: {err}")))?;
+        if let Some(limit) = self.capabilities.as_ref().and_then(|caps| caps.limits.max_body_bytes)
+            && payload.len() > limit
+        {
+            return Err(McpClientError::RequestTooLarge {
+                actual: payload.len(),
+                limit,
+            });
+        }
         match &mut self.transport {
-            McpTransportClient::Http(client) => client.send_request(&request, false).await,
-            McpTransportClient::Sse(client) => client.send_request(&request, true).await,
-            McpTransportClient::Stdio(client) => client.send_request(&request).await,
+            McpTransportClient::Http(client) => client.send_request(payload, false).await,
+            McpTransportClient::Sse(client) => client.send_request(payload, true).await,
+            McpTransportClient::Stdio(client) => client.send_request(payload).await,
         }
     }
 }
@@ -471,11 +643,9 @@ impl HttpMcpClient {
     /// Returns [`McpClientError`] when the request fails or the response is invalid.
     async fn send_request(
         &self,
-        request: &JsonRpcRequest<'_>,
+        payload: Vec<u8>,
         sse: bool,
     ) -> Result<JsonRpcResponse, McpClientError> {
-        let payload = serde_json::to_vec(request)
-            .map_err(|err| McpClientError::Json(format!("jsonrpc serialization failed: {err}")))?;
         let headers = self.headers(sse)?;
         let response = self
             .client
@@ -644,12 +814,7 @@ impl StdioMcpClient {
     /// # Errors
     ///
     /// Returns [`McpClientError`] when I/O or parsing fails.
-    async fn send_request(
-        &self,
-        request: &JsonRpcRequest<'_>,
-    ) -> Result<JsonRpcResponse, McpClientError> {
-        let payload = serde_json::to_vec(request)
-            .map_err(|err| McpClientError::Json(format!("jsonrpc serialization failed: {err}")))?;
+    async fn send_request(&self, payload: Vec<u8>) -> Result<JsonRpcResponse, McpClientError> {
         let stdin = Arc::clone(&self.stdin);
         let stdout = Arc::clone(&self.stdout);
         tokio::task::spawn_blocking(move || {
@@ -0,0 +1,276 @@
+// crates/decision-gate-cli/src/nats.rs
+// ============================================================================
+// Module: CLI NATS Connection
+// Description: async-nats backed NATS connection for broker commands.
+// Purpose: Resolve nats:// content references from `decision-gate broker`.
+// Dependencies: async-nats, decision-gate-broker, tokio
+// ============================================================================
+
+//! ## Overview
+//! [`NatsClient`] implements the broker's blocking [`NatsConnection`] over an
+//! `async-nats` client. The client and its runtime live on a dedicated worker
+//! thread and calls are forwarded over a channel, so the connection can be
+//! used and dropped from inside the CLI's async runtime.
+//! Invariants:
+//! - Every call is bounded by the timeout passed to it.
+//! - Object reads stop once the requested byte limit is exceeded.
+//!
+//! Security posture: NATS servers are external systems; see
+//! `Docs/security/threat_model.md`.
+
+// ============================================================================
+// SECTION: Imports
+// ============================================================================
+
+use std::sync::mpsc;
+use std::thread;
+use std::time::Duration;
+
+use async_nats::ConnectOptions;
+use async_nats::HeaderMap;
+use async_nats::jetstream;
+use async_nats::jetstream::object_store::GetErrorKind;
+use decision_gate_broker::NatsConnection;
+use decision_gate_broker::NatsError;
+use decision_gate_broker::NatsMessage;
+use decision_gate_broker::NatsObject;
+use decision_gate_broker::NatsPublishAck;
+use tokio::io::AsyncReadExt;
+
+// ============================================================================
+// SECTION: Client
+// ============================================================================
+
+/// Operation forwarded to the worker thread.
+enum Request {
+    /// Publish a message and wait for the `JetStream` ack.
+    Publish {
+        /// Message to publish.
+        message: NatsMessage,
+        /// Ack timeout.
+        timeout: Duration,
+        /// Reply channel for the result.
+        reply: mpsc::Sender<Result<NatsPublishAck, NatsError>>,
+    },
+    /// Fetch an object from an object-store bucket.
+    GetObject {
+        /// Object-store bucket.
+        bucket: String,
+        /// Object name.
+        name: String,
+        /// Byte limit for the object.
+        max_bytes: usize,
+        /// Fetch timeout.
+        timeout: Duration,
+        /// Reply channel for the result.
+        reply: mpsc::Sender<Result<NatsObject, NatsError>>,
+    },
+}
+
+/// NATS connection backed by `async-nats`.
+///
+/// # Invariants
+/// - The worker thread exits once the client is dropped.
+pub struct NatsClient {
+    /// Request channel to the worker thread.
+    requests: mpsc::Sender<Request>,
+}
+
+impl NatsClient {
+    /// Connects to the NATS server at `url`, waiting at most `timeout`.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`NatsError`] when the worker cannot start or the server is not
+    /// reachable in time.
+    pub fn connect(url: &str, timeout: Duration) -> Result<Self, NatsError> {
+        let (requests, receiver) = mpsc::channel();
+        let (ready, connected) = mpsc::channel();
+        let url = url.to_string();
+        thread::Builder::new()
+            .name("decision-gate-nats".to_string())
+            .spawn(move || {
+                let runtime =
+                    match tokio::runtime::Builder::new_current_thread().enable_all().build() {
+                        Ok(runtime) => runtime,
+                        Err(err) => {
+                            let _ = ready.send(Err(NatsError::Other(err.to_string())));
+                            return;
+                        }
+                    };
+                let client = match runtime.block_on(connect_client(&url, timeout)) {
+                    Ok(client) => client,
+                    Err(err) => {
+                        let _ = ready.send(Err(err));
+                        return;
+                    }
+                };
+                let _ = ready.send(Ok(()));
+                let context = jetstream::new(client);
+                while let Ok(request) = receiver.recv() {
+                    runtime.block_on(serve(&context, request));
+                }
+            })
+            .map_err(|err| NatsError::Other(err.to_string()))?;
+        connected.recv().map_err(|_| worker_stopped())??;
+        Ok(Self {
+            requests,
+        })
+    }
+}
+
+impl NatsConnection for NatsClient {
+    fn publish(
+        &self,
+        message: NatsMessage,
+        timeout: Duration,
+    ) -> Result<NatsPublishAck, NatsError> {
+        let (reply, response) = mpsc::channel();
+        self.requests
+            .send(Request::Publish {
+                message,
+                timeout,
+                reply,
+            })
+            .map_err(|_| worker_stopped())?;
+        response.recv().map_err(|_| worker_stopped())?
+    }
+
+    fn get_object(
+        &self,
+        bucket: &str,
+        name: &str,
+        max_bytes: usize,
+        timeout: Duration,
+    ) -> Result<NatsObject, NatsError> {
+        let (reply, response) = mpsc::channel();
+        self.requests
+            .send(Request::GetObject {
+                bucket: bucket.to_string(),
+                name: name.to_string(),
+                max_bytes,
+                timeout,
+                reply,
+            })
+            .map_err(|_| worker_stopped())?;
+        response.recv().map_err(|_| worker_stopped())?
+    }
+}
+
+// ============================================================================
+// SECTION: Worker
+// ============================================================================
+
+/// Connects the underlying client within `timeout`.
+async fn connect_client(
+    url: &str,
+    timeout: Duration,
+) -> Result<async_nats::Client, NatsError> {
+    let connect = ConnectOptions::new().connection_timeout(timeout).connect(url);
+    tokio::time::timeout(timeout, connect)
+        .await
+        .map_err(|_| NatsError::Timeout(timeout))?
+        .map_err(|err| NatsError::Other(err.to_string()))
+}
+
+/// Runs one request and sends its result back to the caller.
+async fn serve(context: &jetstream::Context, request: Request) {
+    match request {
+        Request::Publish {
+            message,
+            timeout,
+            reply,
+        } => {
+            let result = tokio::time::timeout(timeout, publish(context, message))
+                .await
+                .unwrap_or(Err(NatsError::Timeout(timeout)));
+            let _ = reply.send(result);
+        }
+        Request::GetObject {
+            bucket,
+            name,
+            max_bytes,
+            timeout,
+            reply,
+        } => {
+            let fetch = get_object(context, &bucket, &name, max_bytes);
+            let result = tokio::time::timeout(timeout, fetch)
+                .await
+                .unwrap_or(Err(NatsError::Timeout(timeout)));
+            let _ = reply.send(result);
+        }
+    }
+}
+
+/// Publishes a message and waits for its ack.
+async fn publish(
+    context: &jetstream::Context,
+    message: NatsMessage,
+) -> Result<NatsPublishAck, NatsError> {
+    let mut headers = HeaderMap::new();
+    for (name, value) in &message.headers {
+        headers.insert(name.as_str(), value.as_str());
+    }
+    let ack = context
+        .publish_with_headers(message.subject, headers, message.payload.into())
+        .await
+        .map_err(|err| NatsError::Other(err.to_string()))?
+        .await
+        .map_err(|err| NatsError::Other(err.to_string()))?;
+    Ok(NatsPublishAck {
+        stream: ack.stream,
+        sequence: ack.sequence,
+        duplicate: ack.duplicate,
+    })
+}
+
+/// Reads an object, failing once it exceeds `max_bytes`.
+async fn get_object(
+    context: &jetstream::Context,
+    bucket: &str,
+    name: &str,
+    max_bytes: usize,
+) -> Result<NatsObject, NatsError> {
+    let store = context
+        .get_object_store(bucket)
+        .await
+        .map_err(|err| NatsError::Other(err.to_string()))?;
+    let mut object = store.get(name).await.map_err(|err| match err.kind() {
+        GetErrorKind::NotFound => NatsError::NotFound(format!("{bucket}/{name}")),
+        _ => NatsError::Other(err.to_string()),
+    })?;
+    if object.info.size > max_bytes {
+        return Err(NatsError::TooLarge {
+            max_bytes,
+            actual_bytes: object.info.size,
+        });
+    }
+    let content_type = object
+        .info
+        .headers
+        .as_ref()
+        .and_then(|headers| headers.get("Content-Type"))
+        .map(|value| value.as_str().to_string());
+    let limit = u64::try_from(max_bytes).unwrap_or(u64::MAX).saturating_add(1);
+    let mut bytes = Vec::new();
+    (&mut object)
+        .take(limit)
+        .read_to_end(&mut bytes)
+        .await
+        .map_err(|err| NatsError::Other(err.to_string()))?;
+    if bytes.len() > max_bytes {
+        return Err(NatsError::TooLarge {
+            max_bytes,
+            actual_bytes: bytes.len(),
+        });
+    }
+    Ok(NatsObject {
+        bytes,
+        content_type,
+    })
+}
+
+/// Error reported when the worker thread is gone.
+fn worker_stopped() -> NatsError {
+    NatsError::Other("nats worker stopped".to_string())
+}
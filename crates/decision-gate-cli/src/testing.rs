@@ -0,0 +1,412 @@
+// crates/decision-gate-cli/src/testing.rs
+// ============================================================================
+// Module: Mock MCP Server
+// Description: In-process MCP server with canned tool responses for tests.
+// Purpose: Exercise MCP clients without a full control plane.
+// Dependencies: decision-gate-contract, serde_json, std::net
+// ============================================================================
+
+//! ## Overview
+//! [`MockMcpServer`] answers the JSON-RPC surface the CLI client speaks
+//! (`initialize`, `tools/list`, `tools/call`, `resources/list`,
+//! `resources/read`) from canned per-tool responses and records every request
+//! for assertions. It serves HTTP (JSON or SSE, chosen by the `Accept` header)
+//! on loopback via [`MockMcpServer::serve_http`], and stdio framing over any
+//! reader/writer pair via [`MockMcpServer::serve_stdio`] so integrators can
+//! wrap it in a child-process binary.
+//!
+//! Error responses mirror the real server: JSON-RPC error objects with codes
+//! `-32600`, `-32601`, `-32602`, or a registered tool error, sent with HTTP 400.
+//!
+//! Security posture: test-only harness; binds to loopback and performs no
+//! authentication. Enabled by the `testing` feature.
+
+// ============================================================================
+// SECTION: Imports
+// ============================================================================
+
+use std::collections::BTreeMap;
+use std::io;
+use std::io::BufRead;
+use std::io::BufReader;
+use std::io::Read;
+use std::io::Write;
+use std::net::SocketAddr;
+use std::net::TcpListener;
+use std::net::TcpStream;
+use std::sync::Arc;
+use std::sync::Mutex;
+use std::sync::PoisonError;
+use std::sync::atomic::AtomicBool;
+use std::sync::atomic::Ordering;
+use std::thread;
+use std::thread::JoinHandle;
+
+use decision_gate_contract::tooling::tool_definitions;
+use serde_json::Value;
+use serde_json::json;
+
+use crate::mcp_client::MAX_MCP_RESPONSE_BYTES;
+use crate::mcp_client::MCP_PROTOCOL_VERSION;
+use crate::mcp_client::McpClientError;
+use crate::mcp_client::read_framed;
+use crate::mcp_client::write_framed;
+
+// ============================================================================
+// SECTION: Types
+// ============================================================================
+
+/// Canned response for a mocked tool.
+///
+/// # Invariants
+/// - `Json` payloads are wrapped in a single `json` content entry.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum MockToolResponse {
+    /// Successful tool result payload.
+    Json(Value),
+    /// JSON-RPC error returned for the tool.
+    Error {
+        /// JSON-RPC error code.
+        code: i64,
+        /// Error message.
+        message: String,
+    },
+}
+
+/// Request received by the mock server.
+///
+/// # Invariants
+/// - `headers` is empty for stdio requests; HTTP header names are lowercase.
+/// - Requests that fail to parse are recorded with an empty `method`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct MockRequest {
+    /// JSON-RPC method name.
+    pub method: String,
+    /// JSON-RPC request id.
+    pub id: Value,
+    /// JSON-RPC params, if present.
+    pub params: Option<Value>,
+    /// HTTP request headers.
+    pub headers: Vec<(String, String)>,
+}
+
+/// Shared mock server state.
+#[derive(Debug)]
+struct MockState {
+    /// Capabilities returned by `initialize`.
+    capabilities: Value,
+    /// Canned responses keyed by tool name.
+    tools: BTreeMap<String, MockToolResponse>,
+    /// Requests in arrival order.
+    requests: Vec<MockRequest>,
+}
+
+/// In-process MCP server returning canned responses.
+///
+/// # Invariants
+/// - Clones share registered responses and recorded requests.
+/// - Unregistered tools fail with `-32601` (`unknown tool`).
+#[derive(Debug, Clone)]
+pub struct MockMcpServer {
+    /// Shared state across transports and clones.
+    state: Arc<Mutex<MockState>>,
+}
+
+/// Running HTTP transport for a [`MockMcpServer`].
+///
+/// # Invariants
+/// - Binds to loopback only; connections are served one at a time.
+#[derive(Debug)]
+pub struct MockHttpServer {
+    /// Bound listener address.
+    addr: SocketAddr,
+    /// Set when the accept loop should exit.
+    stop: Arc<AtomicBool>,
+    /// Accept loop thread.
+    handle: Option<JoinHandle<()>>,
+}
+
+// ============================================================================
+// SECTION: Mock Server
+// ============================================================================
+
+impl MockMcpServer {
+    /// Creates a mock server with empty capabilities and no tools.
+    #[must_use]
+    pub fn new() -> Self {
+        Self {
+            state: Arc::new(Mutex::new(MockState {
+                capabilities: json!({}),
+                tools: BTreeMap::new(),
+                requests: Vec::new(),
+            })),
+        }
+    }
+
+    /// Sets the capabilities returned by `initialize`.
+    #[must_use]
+    pub fn with_capabilities(self, capabilities: Value) -> Self {
+        self.lock().capabilities = capabilities;
+        self
+    }
+
+    /// Registers a successful JSON result for `tool`.
+    #[must_use]
+    pub fn with_tool(self, tool: &str, result: Value) -> Self {
+        self.lock().tools.insert(tool.to_string(), MockToolResponse::Json(result));
+        self
+    }
+
+    /// Registers a JSON-RPC error for `tool`.
+    #[must_use]
+    pub fn with_tool_error(self, tool: &str, code: i64, message: &str) -> Self {
+        self.lock().tools.insert(
+            tool.to_string(),
+            MockToolResponse::Error {
+                code,
+                message: message.to_string(),
+            },
+        );
+        self
+    }
+
+    /// Returns the requests received so far, in arrival order.
+    #[must_use]
+    pub fn requests(&self) -> Vec<MockRequest> {
+        self.lock().requests.clone()
+    }
+
+    /// Handles one JSON-RPC request body and returns `(is_error, response)`.
+    ///
+    /// The request is recorded before dispatch, including malformed ones.
+    #[must_use]
+    pub fn handle(&self, body: &[u8], headers: Vec<(String, String)>) -> (bool, Value) {
+        let Ok(request) = serde_json::from_slice::<Value>(body) else {
+            self.record(String::new(), Value::Null, None, headers);
+            return (true, error_response(&Value::Null, -32600, "invalid json-rpc request"));
+        };
+        let id = request.get("id").cloned().unwrap_or(Value::Null);
+        let method = request.get("method").and_then(Value::as_str).unwrap_or_default();
+        let params = request.get("params").filter(|params| !params.is_null()).cloned();
+        self.record(method.to_string(), id.clone(), params.clone(), headers);
+        if request.get("jsonrpc").and_then(Value::as_str) != Some("2.0") {
+            return (true, error_response(&id, -32600, "invalid json-rpc version"));
+        }
+        match method {
+            "initialize" => (false, result_response(&id, &self.initialize_result())),
+            "tools/list" => (false, result_response(&id, &self.tools_list_result())),
+            "tools/call" => self.tools_call(&id, params.as_ref()),
+            "resources/list" => (false, result_response(&id, &json!({ "resources": [] }))),
+            "resources/read" => (false, result_response(&id, &json!({ "contents": [] }))),
+            _ => (true, error_response(&id, -32601, "method not found")),
+        }
+    }
+
+    /// Serves framed JSON-RPC requests from `input` until it closes.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`io::Error`] when framing is invalid or writing fails.
+    pub fn serve_stdio(&self, input: impl Read, mut output: impl Write) -> io::Result<()> {
+        let mut reader = BufReader::new(input);
+        loop {
+            let body = match read_framed(&mut reader) {
+                Ok(body) => body,
+                Err(McpClientError::Transport(message)) if message == "stdio closed" => {
+                    return Ok(());
+                }
+                Err(err) => return Err(io::Error::other(err.to_string())),
+            };
+            let (_, response) = self.handle(&body, Vec::new());
+            let payload = serde_json::to_vec(&response).map_err(io::Error::other)?;
+            write_framed(&mut output, &payload).map_err(|err| io::Error::other(err.to_string()))?;
+        }
+    }
+
+    /// Starts the HTTP transport on an ephemeral loopback port.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`io::Error`] when the listener cannot be bound.
+    pub fn serve_http(&self) -> io::Result<MockHttpServer> {
+        let listener = TcpListener::bind("127.0.0.1:0")?;
+        let addr = listener.local_addr()?;
+        let stop = Arc::new(AtomicBool::new(false));
+        let server = self.clone();
+        let stop_flag = Arc::clone(&stop);
+        let handle = thread::spawn(move || {
+            for stream in listener.incoming() {
+                if stop_flag.load(Ordering::SeqCst) {
+                    break;
+                }
+                if let Ok(stream) = stream {
+                    let _ = server.serve_http_connection(stream);
+                }
+            }
+        });
+        Ok(MockHttpServer {
+            addr,
+            stop,
+            handle: Some(handle),
+        })
+    }
+
+    /// Reads one HTTP request from `stream` and writes the JSON-RPC response.
+    fn serve_http_connection(&self, stream: TcpStream) -> io::Result<()> {
+        let mut reader = BufReader::new(stream.try_clone()?);
+        let mut line = String::new();
+        reader.read_line(&mut line)?;
+        let mut headers = Vec::new();
+        let mut content_length = 0usize;
+        loop {
+            line.clear();
+            if reader.read_line(&mut line)? == 0 || line.trim().is_empty() {
+                break;
+            }
+            if let Some((name, value)) = line.split_once(':') {
+                let name = name.trim().to_ascii_lowercase();
+                let value = value.trim().to_string();
+                if name == "content-length" {
+                    content_length = value.parse().map_err(io::Error::other)?;
+                }
+                headers.push((name, value));
+            }
+        }
+        if content_length > MAX_MCP_RESPONSE_BYTES {
+            return Err(io::Error::other("mock request exceeds size limit"));
+        }
+        let mut body = vec![0u8; content_length];
+        reader.read_exact(&mut body)?;
+        let sse = headers
+            .iter()
+            .any(|(name, value)| name == "accept" && value.contains("text/event-stream"));
+        let (is_error, response) = self.handle(&body, headers);
+        let payload = serde_json::to_string(&response).map_err(io::Error::other)?;
+        let (content_type, payload) = if sse {
+            ("text/event-stream", format!("data: {payload}\n\n"))
+        } else {
+            ("application/json", payload)
+        };
+        let status = if is_error { "400 Bad Request" } else { "200 OK" };
+        let mut stream = stream;
+        write!(
+            stream,
+            "HTTP/1.1 {status}\r\nContent-Type: {content_type}\r\nContent-Length: \
+             {}\r\nConnection: close\r\n\r\n{payload}",
+            payload.len()
+        )?;
+        stream.flush()
+    }
+
+    /// Builds the `initialize` result.
+    fn initialize_result(&self) -> Value {
+        json!({
+            "protocolVersion": MCP_PROTOCOL_VERSION,
+            "serverInfo": { "name": "decision-gate-mock", "version": env!("CARGO_PKG_VERSION") },
+            "capabilities": self.lock().capabilities.clone(),
+        })
+    }
+
+    /// Builds the `tools/list` result from contract definitions of registered tools.
+    fn tools_list_result(&self) -> Value {
+        let state = self.lock();
+        let tools: Vec<_> = tool_definitions()
+            .into_iter()
+            .filter(|definition| state.tools.contains_key(definition.name.as_str()))
+            .collect();
+        json!({ "tools": tools })
+    }
+
+    /// Answers `tools/call` from the registered canned responses.
+    fn tools_call(&self, id: &Value, params: Option<&Value>) -> (bool, Value) {
+        let Some(name) = params.and_then(|params| params.get("name")).and_then(Value::as_str)
+        else {
+            return (true, error_response(id, -32602, "invalid tool params"));
+        };
+        let response = self.lock().tools.get(name).cloned();
+        match response {
+            Some(MockToolResponse::Json(json)) => (
+                false,
+                result_response(id, &json!({ "content": [{ "type": "json", "json": json }] })),
+            ),
+            Some(MockToolResponse::Error {
+                code,
+                message,
+            }) => (true, error_response(id, code, &message)),
+            None => (true, error_response(id, -32601, "unknown tool")),
+        }
+    }
+
+    /// Records a received request.
+    fn record(
+        &self,
+        method: String,
+        id: Value,
+        params: Option<Value>,
+        headers: Vec<(String, String)>,
+    ) {
+        self.lock().requests.push(MockRequest {
+            method,
+            id,
+            params,
+            headers,
+        });
+    }
+
+    /// Locks the shared state, recovering from poisoning.
+    fn lock(&self) -> std::sync::MutexGuard<'_, MockState> {
+        self.state.lock().unwrap_or_else(PoisonError::into_inner)
+    }
+}
+
+impl Default for MockMcpServer {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+// ============================================================================
+// SECTION: HTTP Transport
+// ============================================================================
+
+impl MockHttpServer {
+    /// Returns the endpoint URL for MCP clients.
+    #[must_use]
+    pub fn url(&self) -> String {
+        format!("http://{}/rpc", self.addr)
+    }
+
+    /// Stops the accept loop and waits for it to exit.
+    pub fn shutdown(mut self) {
+        self.stop_and_join();
+    }
+
+    /// Signals the accept loop and unblocks it with a loopback connection.
+    fn stop_and_join(&mut self) {
+        if let Some(handle) = self.handle.take() {
+            self.stop.store(true, Ordering::SeqCst);
+            let _ = TcpStream::connect(self.addr);
+            let _ = handle.join();
+        }
+    }
+}
+
+impl Drop for MockHttpServer {
+    fn drop(&mut self) {
+        self.stop_and_join();
+    }
+}
+
+// ============================================================================
+// SECTION: JSON-RPC Helpers
+// ============================================================================
+
+/// Builds a JSON-RPC success response.
+fn result_response(id: &Value, result: &Value) -> Value {
+    json!({ "jsonrpc": "2.0", "id": id, "result": result })
+}
+
+/// Builds a JSON-RPC error response.
+fn error_response(id: &Value, code: i64, message: &str) -> Value {
+    json!({ "jsonrpc": "2.0", "id": id, "error": { "code": code, "message": message } })
+}
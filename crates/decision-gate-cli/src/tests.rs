@@ -34,6 +34,7 @@ mod auth;
 mod i18n;
 mod interop;
 mod mcp_client;
+mod mock_mcp;
 mod protocol;
 mod resource_limits;
 mod serve_policy;
@@ -178,6 +178,7 @@ fn status_for(run_config: &RunConfig, last_decision: Option<DecisionRecord>) ->
         last_decision,
         issued_packet_ids: Vec::new(),
         safe_summary: None,
+        version: 0,
     }
 }
 
@@ -550,6 +551,7 @@ async fn run_interop_executes_full_sequence() {
     let trigger_args = serde_json::to_value(ScenarioTriggerRequest {
         scenario_id: spec.scenario_id.clone(),
         trigger: trigger.clone(),
+        lease_holder: None,
     })
     .expect("serialize trigger");
     let status_args = serde_json::to_value(ScenarioStatusRequest {
@@ -561,6 +563,7 @@ async fn run_interop_executes_full_sequence() {
             requested_at: status_requested_at,
             correlation_id: trigger.correlation_id.clone(),
         },
+        wait_for_change: None,
     })
     .expect("serialize status");
 
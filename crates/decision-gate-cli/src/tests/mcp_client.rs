@@ -22,6 +22,7 @@ use crate::mcp_client::MAX_MCP_RESPONSE_BYTES;
 use crate::mcp_client::McpClient;
 use crate::mcp_client::McpClientConfig;
 use crate::mcp_client::McpClientError;
+use crate::mcp_client::McpFeature;
 use crate::mcp_client::McpTransport;
 use crate::mcp_client::parse_sse_body;
 use crate::mcp_client::read_framed;
@@ -341,6 +342,89 @@ async fn request_id_increments_across_requests() {
     server.shutdown().await;
 }
 
+// ============================================================================
+// SECTION: Capability Negotiation Tests
+// ============================================================================
+
+fn initialize_result(features: &serde_json::Value, max_body_bytes: usize) -> serde_json::Value {
+    jsonrpc_result(&serde_json::json!({
+        "protocolVersion": "2024-11-05",
+        "serverInfo": { "name": "decision-gate", "version": "0.0.0" },
+        "capabilities": {
+            "tools": {},
+            "features": features,
+            "limits": { "max_body_bytes": max_body_bytes }
+        }
+    }))
+}
+
+#[tokio::test]
+async fn initialize_stores_advertised_capabilities() {
+    let server = TestHttpServer::start(|_| {
+        TestResponse::json(&initialize_result(
+            &serde_json::json!({ "batch": false, "streaming": true, "websocket": false }),
+            4096,
+        ))
+    })
+    .await;
+    let config = http_client_config(server.url(), Duration::from_millis(2_000));
+    let mut client = McpClient::new(config).expect("client");
+    assert!(client.capabilities().is_none());
+    let capabilities = client.initialize().await.expect("initialize").clone();
+    assert!(capabilities.supports(McpFeature::Streaming));
+    assert!(!capabilities.supports(McpFeature::Batch));
+    assert_eq!(capabilities.limits.max_body_bytes, Some(4096));
+    assert!(client.capabilities().is_some());
+    let requests = server.requests().await;
+    let request: serde_json::Value = serde_json::from_slice(&requests[0].body).expect("json");
+    assert_eq!(request["method"], "initialize");
+    assert_eq!(request["params"]["protocolVersion"], "2024-11-05");
+    server.shutdown().await;
+}
+
+#[tokio::test]
+async fn require_feature_refuses_unadvertised_feature() {
+    let server = TestHttpServer::start(|_| {
+        TestResponse::json(&initialize_result(&serde_json::json!({ "streaming": true }), 4096))
+    })
+    .await;
+    let config = http_client_config(server.url(), Duration::from_millis(2_000));
+    let mut client = McpClient::new(config).expect("client");
+    let err = client.require_feature(McpFeature::Streaming).expect_err("not negotiated");
+    assert!(matches!(err, McpClientError::Unsupported("streaming")));
+    client.initialize().await.expect("initialize");
+    client.require_feature(McpFeature::Streaming).expect("streaming advertised");
+    let err = client.require_feature(McpFeature::Batch).expect_err("batch not advertised");
+    assert!(matches!(err, McpClientError::Unsupported("batch")));
+    let err = client.require_feature(McpFeature::Websocket).expect_err("websocket omitted");
+    assert!(matches!(err, McpClientError::Unsupported("websocket")));
+    server.shutdown().await;
+}
+
+#[tokio::test]
+async fn requests_over_advertised_body_limit_are_not_sent() {
+    let server = TestHttpServer::start(|_| {
+        TestResponse::json(&initialize_result(&serde_json::json!({}), 64))
+    })
+    .await;
+    let config = http_client_config(server.url(), Duration::from_millis(2_000));
+    let mut client = McpClient::new(config).expect("client");
+    client.initialize().await.expect("initialize");
+    let err = client
+        .call_tool_raw("scenario_status", serde_json::json!({ "padding": "x".repeat(128) }))
+        .await
+        .expect_err("oversized request");
+    assert!(matches!(
+        err,
+        McpClientError::RequestTooLarge {
+            limit: 64,
+            ..
+        }
+    ));
+    assert_eq!(server.requests().await.len(), 1);
+    server.shutdown().await;
+}
+
 // ============================================================================
 // SECTION: Stdio Process Management Tests
 // ============================================================================
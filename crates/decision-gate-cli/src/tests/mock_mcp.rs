@@ -0,0 +1,145 @@
+// crates/decision-gate-cli/src/tests/mock_mcp.rs
+// ============================================================================
+// Module: Mock MCP Server Tests
+// Description: Client round-trips against the mock MCP server harness.
+// Purpose: Verify request shapes sent by the client and parsing of responses.
+// Dependencies: decision-gate-cli testing, mcp_client
+// ============================================================================
+
+//! ## Overview
+//! Drives [`McpClient`] against [`MockMcpServer`] over HTTP and SSE, and the
+//! mock's stdio framing directly, asserting recorded requests and parsed results.
+
+use std::io::BufReader;
+use std::io::Cursor;
+use std::time::Duration;
+
+use decision_gate_core::ToolName;
+use serde_json::Value;
+use serde_json::json;
+
+use crate::mcp_client::McpClient;
+use crate::mcp_client::McpClientConfig;
+use crate::mcp_client::McpFeature;
+use crate::mcp_client::McpTransport;
+use crate::mcp_client::read_framed;
+use crate::mcp_client::write_framed;
+use crate::testing::MockMcpServer;
+
+fn client_config(transport: McpTransport, endpoint: String) -> McpClientConfig {
+    McpClientConfig {
+        transport,
+        endpoint: Some(endpoint),
+        bearer_token: Some("token-1".to_string()),
+        client_subject: None,
+        timeout: Duration::from_millis(2_000),
+        stdio_command: None,
+        stdio_args: vec![],
+        stdio_env: vec![],
+    }
+}
+
+fn header<'a>(headers: &'a [(String, String)], name: &str) -> Option<&'a str> {
+    headers.iter().find(|(key, _)| key == name).map(|(_, value)| value.as_str())
+}
+
+#[tokio::test]
+async fn mock_http_records_requests_and_returns_canned_results() {
+    let mock = MockMcpServer::new()
+        .with_capabilities(json!({ "limits": { "max_body_bytes": 4096 } }))
+        .with_tool("scenario_status", json!({ "status": "active" }));
+    let server = mock.serve_http().expect("serve http");
+    let mut client =
+        McpClient::new(client_config(McpTransport::Http, server.url())).expect("client");
+
+    let capabilities = client.initialize().await.expect("initialize");
+    assert_eq!(capabilities.limits.max_body_bytes, Some(4096));
+    let tools = client.list_tools().await.expect("list tools");
+    assert_eq!(tools.len(), 1);
+    assert_eq!(tools[0].name, ToolName::ScenarioStatus);
+    let result = client
+        .call_tool(ToolName::ScenarioStatus, json!({ "scenario_id": "demo" }))
+        .await
+        .expect("call tool");
+    assert_eq!(result, json!({ "status": "active" }));
+    server.shutdown();
+
+    let requests = mock.requests();
+    let methods: Vec<&str> = requests.iter().map(|request| request.method.as_str()).collect();
+    assert_eq!(methods, ["initialize", "tools/list", "tools/call"]);
+    let ids: Vec<&Value> = requests.iter().map(|request| &request.id).collect();
+    assert_eq!(ids, [&json!(1), &json!(2), &json!(3)]);
+    let init = requests[0].params.as_ref().expect("initialize params");
+    assert_eq!(init["protocolVersion"], json!("2024-11-05"));
+    assert_eq!(init["clientInfo"]["name"], json!("decision-gate-cli"));
+    assert!(requests[1].params.is_none());
+    assert_eq!(
+        requests[2].params,
+        Some(json!({ "name": "scenario_status", "arguments": { "scenario_id": "demo" } }))
+    );
+    assert_eq!(header(&requests[2].headers, "content-type"), Some("application/json"));
+    assert_eq!(header(&requests[2].headers, "authorization"), Some("Bearer token-1"));
+}
+
+#[tokio::test]
+async fn mock_http_tool_errors_surface_to_client() {
+    let mock = MockMcpServer::new().with_tool_error("scenario_next", -32602, "missing run_id");
+    let server = mock.serve_http().expect("serve http");
+    let mut client =
+        McpClient::new(client_config(McpTransport::Http, server.url())).expect("client");
+
+    let err = client.call_tool(ToolName::ScenarioNext, json!({})).await.expect_err("tool error");
+    assert!(err.to_string().contains("missing run_id"));
+    let err = client.call_tool_raw("not_a_tool", json!({})).await.expect_err("unknown tool");
+    assert!(err.to_string().contains("unknown tool"));
+    server.shutdown();
+
+    assert_eq!(mock.requests().len(), 2);
+}
+
+#[tokio::test]
+async fn mock_sse_answers_streaming_clients() {
+    let mock = MockMcpServer::new()
+        .with_capabilities(json!({ "features": { "streaming": true } }))
+        .with_tool("scenario_start", json!({ "run_id": "run-1" }));
+    let server = mock.serve_http().expect("serve http");
+    let mut client =
+        McpClient::new(client_config(McpTransport::Sse, server.url())).expect("client");
+
+    client.initialize().await.expect("initialize");
+    client.require_feature(McpFeature::Streaming).expect("streaming advertised");
+    let result = client.call_tool(ToolName::ScenarioStart, json!({})).await.expect("call tool");
+    assert_eq!(result, json!({ "run_id": "run-1" }));
+    server.shutdown();
+
+    let requests = mock.requests();
+    assert_eq!(header(&requests[1].headers, "accept"), Some("text/event-stream"));
+}
+
+#[test]
+fn mock_stdio_serves_framed_requests() {
+    let mock = MockMcpServer::new().with_tool("scenario_status", json!({ "status": "done" }));
+    let mut input = Vec::new();
+    let call = json!({
+        "jsonrpc": "2.0",
+        "id": 7,
+        "method": "tools/call",
+        "params": { "name": "scenario_status", "arguments": {} }
+    });
+    let unknown = json!({ "jsonrpc": "2.0", "id": 8, "method": "tools/describe_all" });
+    write_framed(&mut input, &serde_json::to_vec(&call).expect("encode")).expect("frame");
+    write_framed(&mut input, &serde_json::to_vec(&unknown).expect("encode")).expect("frame");
+
+    let mut output = Vec::new();
+    mock.serve_stdio(Cursor::new(input), &mut output).expect("serve stdio");
+
+    let mut reader = BufReader::new(Cursor::new(output));
+    let first: Value = serde_json::from_slice(&read_framed(&mut reader).expect("first")).unwrap();
+    assert_eq!(first["id"], json!(7));
+    assert_eq!(first["result"]["content"][0]["json"], json!({ "status": "done" }));
+    let second: Value = serde_json::from_slice(&read_framed(&mut reader).expect("second")).unwrap();
+    assert_eq!(second["error"]["code"], json!(-32601));
+    let requests = mock.requests();
+    assert_eq!(requests.len(), 2);
+    assert!(requests.iter().all(|request| request.headers.is_empty()));
+}
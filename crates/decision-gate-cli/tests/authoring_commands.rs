@@ -143,3 +143,117 @@ fn cli_authoring_normalize_writes_output() {
 
     cleanup(&root);
 }
+
+/// Verifies authoring hash prints the spec hash reported by normalize.
+#[test]
+fn cli_authoring_hash_matches_normalize_spec_hash() {
+    let root = temp_root("authoring-hash");
+    let input_path = root.join("scenario.ron");
+    let canonical_path = root.join("scenario.json");
+    let ron = examples::scenario_example_ron().expect("ron example");
+    write_text(&input_path, &ron);
+
+    let hash = Command::new(decision_gate_bin())
+        .args([
+            "authoring",
+            "hash",
+            "--input",
+            input_path.to_string_lossy().as_ref(),
+            "--canonical-out",
+            canonical_path.to_string_lossy().as_ref(),
+        ])
+        .output()
+        .expect("authoring hash");
+    assert!(hash.status.success());
+    let stdout = String::from_utf8_lossy(&hash.stdout);
+    let spec_hash = stdout.trim();
+    assert!(spec_hash.starts_with("sha256:"), "unexpected stdout: {stdout}");
+    assert_eq!(stdout.lines().count(), 1, "hash output must be a single line");
+
+    let normalize = Command::new(decision_gate_bin())
+        .args(["authoring", "normalize", "--input", input_path.to_string_lossy().as_ref()])
+        .output()
+        .expect("authoring normalize");
+    assert!(normalize.status.success());
+    let stderr = String::from_utf8_lossy(&normalize.stderr);
+    assert!(stderr.contains(&format!("spec_hash={spec_hash})")), "unexpected stderr: {stderr}");
+    assert_eq!(fs::read(&canonical_path).expect("read canonical"), normalize.stdout);
+
+    cleanup(&root);
+}
+
+/// Verifies authoring render substitutes params and warns on unused ones.
+#[test]
+fn cli_authoring_render_warns_on_unused_params() {
+    let root = temp_root("authoring-render");
+    let template_path = root.join("template.json");
+    let params_path = root.join("params.json");
+    let mut template = serde_json::to_value(examples::scenario_example()).expect("example json");
+    template["scenario_id"] = Value::from("${team}-scenario");
+    write_json(&template_path, &template);
+    write_json(&params_path, &serde_json::json!({"team": "payments", "region": "eu-west-1"}));
+
+    let output = Command::new(decision_gate_bin())
+        .args([
+            "authoring",
+            "render",
+            "--template",
+            template_path.to_string_lossy().as_ref(),
+            "--params",
+            params_path.to_string_lossy().as_ref(),
+        ])
+        .output()
+        .expect("authoring render");
+
+    assert!(output.status.success());
+    let rendered: Value = serde_json::from_slice(&output.stdout).expect("parse rendered json");
+    assert_eq!(rendered.get("scenario_id").and_then(Value::as_str), Some("payments-scenario"));
+    let stderr = String::from_utf8_lossy(&output.stderr);
+    assert!(stderr.contains("template parameter region is not used"), "stderr: {stderr}");
+
+    cleanup(&root);
+}
+
+/// Verifies authoring format-dsl emits canonical DSL and reports parse errors.
+#[test]
+fn cli_authoring_format_dsl_canonicalizes_input() {
+    let root = temp_root("authoring-format-dsl");
+    let input_path = root.join("requirement.dsl");
+    write_text(&input_path, "  stunned ||(is_alive&&has_ap)\n");
+
+    let output = Command::new(decision_gate_bin())
+        .args(["authoring", "format-dsl", "--input", input_path.to_str().unwrap()])
+        .output()
+        .expect("run authoring format-dsl");
+    assert!(output.status.success(), "stderr: {}", String::from_utf8_lossy(&output.stderr));
+    assert_eq!(String::from_utf8_lossy(&output.stdout), "any(all(has_ap, is_alive), stunned)\n");
+
+    let output_path = root.join("formatted.dsl");
+    let output = Command::new(decision_gate_bin())
+        .args([
+            "authoring",
+            "format-dsl",
+            "--input",
+            input_path.to_str().unwrap(),
+            "--output",
+            output_path.to_str().unwrap(),
+        ])
+        .output()
+        .expect("run authoring format-dsl with output");
+    assert!(output.status.success(), "stderr: {}", String::from_utf8_lossy(&output.stderr));
+    assert_eq!(
+        fs::read_to_string(&output_path).expect("read formatted"),
+        "any(all(has_ap, is_alive), stunned)\n"
+    );
+
+    write_text(&input_path, "all(is_alive,\n    some_of(has_ap))");
+    let output = Command::new(decision_gate_bin())
+        .args(["authoring", "format-dsl", "--input", input_path.to_str().unwrap()])
+        .output()
+        .expect("run authoring format-dsl on invalid input");
+    assert!(!output.status.success());
+    let stderr = String::from_utf8_lossy(&output.stderr);
+    assert!(stderr.contains("line 2, column 5"), "stderr: {stderr}");
+
+    cleanup(&root);
+}
@@ -0,0 +1,168 @@
+// crates/decision-gate-cli/tests/error_envelope.rs
+// ============================================================================
+// Module: CLI Error Envelope Tests
+// Description: Integration tests for `--errors-json` failure reporting.
+// Purpose: Ensure failing commands emit a parseable JSON error envelope.
+// Dependencies: decision-gate-cli binary
+// ============================================================================
+
+//! ## Overview
+//! Runs failing CLI commands with `--errors-json` and checks that stdout holds
+//! a single `{"error":{"code","kind","message"}}` envelope whose code matches
+//! the process exit code.
+
+#![allow(
+    clippy::panic,
+    clippy::print_stdout,
+    clippy::print_stderr,
+    clippy::unwrap_used,
+    clippy::expect_used,
+    clippy::use_debug,
+    clippy::dbg_macro,
+    clippy::panic_in_result_fn,
+    clippy::unwrap_in_result,
+    reason = "Test-only output and panic-based assertions are permitted."
+)]
+
+// ============================================================================
+// SECTION: Imports
+// ============================================================================
+
+use std::fs;
+use std::path::PathBuf;
+use std::process::Command;
+use std::process::Output;
+use std::time::SystemTime;
+use std::time::UNIX_EPOCH;
+
+use serde_json::Value;
+
+// ============================================================================
+// SECTION: Helpers
+// ============================================================================
+
+fn decision_gate_bin() -> PathBuf {
+    PathBuf::from(env!("CARGO_BIN_EXE_decision-gate"))
+}
+
+fn temp_root(label: &str) -> PathBuf {
+    let nanos = SystemTime::now().duration_since(UNIX_EPOCH).expect("clock drift").as_nanos();
+    let mut path = std::env::temp_dir();
+    path.push(format!("decision-gate-cli-{label}-{nanos}"));
+    fs::create_dir_all(&path).expect("create temp dir");
+    path
+}
+
+fn cleanup(path: &PathBuf) {
+    let _ = fs::remove_dir_all(path);
+}
+
+fn run_cli(args: &[&str]) -> Output {
+    Command::new(decision_gate_bin())
+        .args(args)
+        .env_remove("DECISION_GATE_LANG")
+        .output()
+        .expect("run decision-gate")
+}
+
+/// Parses the envelope on stdout and checks it against the exit status.
+fn error_envelope(output: &Output) -> Value {
+    assert!(!output.status.success());
+    assert!(output.stderr.is_empty(), "stderr: {}", String::from_utf8_lossy(&output.stderr));
+    let stdout = String::from_utf8_lossy(&output.stdout);
+    let envelope: Value = serde_json::from_str(stdout.trim()).expect("stdout is one JSON value");
+    let error = &envelope["error"];
+    let code = error["code"].as_i64().expect("numeric code");
+    assert_eq!(output.status.code().map(i64::from), Some(code));
+    assert!(error["message"].as_str().is_some_and(|message| !message.is_empty()));
+    assert_eq!(envelope.as_object().map(serde_json::Map::len), Some(1));
+    error.clone()
+}
+
+// ============================================================================
+// SECTION: Tests
+// ============================================================================
+
+/// Verifies a rejected config reports a `config` envelope instead of stderr text.
+#[test]
+fn errors_json_reports_config_failures() {
+    let root = temp_root("errors-json-config");
+    let config_path = root.join("decision-gate.toml");
+    fs::write(&config_path, "[server]\ntransport = \"http\"\nbind = \"0.0.0.0:8080\"\n")
+        .expect("write config");
+
+    let output = run_cli(&[
+        "--errors-json",
+        "config",
+        "validate",
+        "--config",
+        config_path.to_string_lossy().as_ref(),
+    ]);
+    let error = error_envelope(&output);
+    assert_eq!(error["kind"], "config");
+    assert_eq!(error["code"], 1);
+    assert!(
+        error["message"].as_str().is_some_and(|message| message.contains("Failed to load config")),
+        "unexpected error: {error}"
+    );
+
+    cleanup(&root);
+}
+
+/// Verifies malformed and unreadable inputs are told apart by kind.
+#[test]
+fn errors_json_distinguishes_input_and_io_failures() {
+    let root = temp_root("errors-json-input");
+    let input_path = root.join("scenario.json");
+    fs::write(&input_path, "{ not json").expect("write input");
+
+    let malformed = run_cli(&[
+        "authoring",
+        "validate",
+        "--input",
+        input_path.to_string_lossy().as_ref(),
+        "--errors-json",
+    ]);
+    assert_eq!(error_envelope(&malformed)["kind"], "invalid_input");
+
+    let missing_path = root.join("missing.json");
+    let missing = run_cli(&[
+        "--errors-json",
+        "authoring",
+        "validate",
+        "--input",
+        missing_path.to_string_lossy().as_ref(),
+    ]);
+    assert_eq!(error_envelope(&missing)["kind"], "io");
+
+    cleanup(&root);
+}
+
+/// Verifies argument errors become `usage` envelopes with clap's exit code.
+#[test]
+fn errors_json_reports_usage_errors() {
+    let output = run_cli(&["--errors-json", "config", "validate", "--no-such-flag"]);
+    let error = error_envelope(&output);
+    assert_eq!(error["kind"], "usage");
+    assert_eq!(error["code"], 2);
+    assert!(
+        error["message"].as_str().is_some_and(|message| message.contains("--no-such-flag")),
+        "unexpected error: {error}"
+    );
+}
+
+/// Verifies failures stay plain stderr text without `--errors-json`.
+#[test]
+fn errors_without_flag_stay_on_stderr() {
+    let root = temp_root("errors-json-off");
+    let missing_path = root.join("missing.json");
+
+    let output =
+        run_cli(&["authoring", "validate", "--input", missing_path.to_string_lossy().as_ref()]);
+    assert_eq!(output.status.code(), Some(1));
+    assert!(output.stdout.is_empty());
+    let stderr = String::from_utf8_lossy(&output.stderr);
+    assert!(serde_json::from_str::<Value>(stderr.trim()).is_err(), "stderr: {stderr}");
+
+    cleanup(&root);
+}
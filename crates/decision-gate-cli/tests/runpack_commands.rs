@@ -40,6 +40,8 @@ use std::time::SystemTime;
 use std::time::UNIX_EPOCH;
 
 use decision_gate_core::AdvanceTo;
+use decision_gate_core::Compression;
+use decision_gate_core::HashAlgorithm;
 use decision_gate_core::InMemoryDataShapeRegistry;
 use decision_gate_core::InMemoryRunStateStore;
 use decision_gate_core::NamespaceId;
@@ -60,10 +62,13 @@ use decision_gate_core::Timestamp;
 use decision_gate_core::hashing::DEFAULT_HASH_ALGORITHM;
 use decision_gate_core::hashing::hash_bytes;
 use decision_gate_core::hashing::hash_canonical_json;
+use decision_gate_core::runtime::RunpackBuilder;
+use decision_gate_core::runtime::VerificationAttestation;
 use decision_gate_core::runtime::VerificationReport;
 use decision_gate_core::runtime::VerificationStatus;
 use decision_gate_mcp::DefaultToolAuthz;
 use decision_gate_mcp::FederatedEvidenceProvider;
+use decision_gate_mcp::FileArtifactSink;
 use decision_gate_mcp::McpNoopAuditSink;
 use decision_gate_mcp::NoopAuditSink;
 use decision_gate_mcp::NoopNamespaceAuthority;
@@ -340,6 +345,7 @@ fn build_mcp_router(store: SharedRunStateStore) -> ToolRouter {
         tools: config.server.tools.clone(),
         docs_provider: None,
         tool_visibility_resolver: None,
+        tool_concurrency: config.server.limits.tool_concurrency.clone(),
         allow_default_namespace: config.allow_default_namespace(),
         default_namespace_tenants,
         namespace_authority: std::sync::Arc::new(NoopNamespaceAuthority),
@@ -460,6 +466,7 @@ fn cli_and_mcp_runpack_export_produce_same_hashes() {
         manifest_name: Some("runpack.json".to_string()),
         generated_at: Timestamp::UnixMillis(1_700_000_000_000),
         include_verification: false,
+        include_evaluation_audit: false,
     };
     let export_value = handle_tool_call_sync(
         &router,
@@ -517,6 +524,158 @@ fn cli_runpack_verify_outputs_json_report() {
     cleanup(&root);
 }
 
+/// Verifies runpack verification follows a BLAKE3 manifest's recorded algorithm.
+#[test]
+fn cli_runpack_verify_accepts_blake3_runpack() {
+    let root = temp_root("verify-blake3");
+    let spec = minimal_spec();
+    let state = minimal_state(&spec);
+    let mut sink = FileArtifactSink::new(root.clone(), "runpack.json").expect("artifact sink");
+    let builder = RunpackBuilder {
+        hash_algorithm: HashAlgorithm::Blake3,
+        ..RunpackBuilder::default()
+    };
+    builder.build(&mut sink, &spec, &state, Timestamp::Logical(1)).expect("runpack build");
+    let manifest_path = root.join("runpack.json");
+    let manifest = read_manifest(&manifest_path);
+    assert_eq!(manifest.hash_algorithm, HashAlgorithm::Blake3);
+    assert_manifest_integrity(&manifest, &root);
+
+    let output = Command::new(decision_gate_bin())
+        .args([
+            "runpack",
+            "verify",
+            "--manifest",
+            manifest_path.to_string_lossy().as_ref(),
+            "--format",
+            "json",
+        ])
+        .output()
+        .expect("runpack verify");
+
+    assert!(output.status.success(), "verify failed: {}", String::from_utf8_lossy(&output.stderr));
+    let report: VerificationReport = serde_json::from_slice(&output.stdout).expect("parse report");
+    assert_eq!(report.status, VerificationStatus::Pass, "errors: {:?}", report.errors);
+
+    cleanup(&root);
+}
+
+/// Verifies a `--compress` export stores gzip artifacts that verify and pretty-print.
+#[test]
+fn cli_runpack_export_compress_round_trips_through_verify() {
+    let root = temp_root("export-compress");
+    let spec = minimal_spec();
+    let state = minimal_state(&spec);
+    let spec_path = root.join("spec.json");
+    let state_path = root.join("state.json");
+    write_json(&spec_path, &spec);
+    write_json(&state_path, &state);
+    let runpack_dir = root.join("runpack");
+
+    let output = Command::new(decision_gate_bin())
+        .args([
+            "runpack",
+            "export",
+            "--spec",
+            spec_path.to_string_lossy().as_ref(),
+            "--state",
+            state_path.to_string_lossy().as_ref(),
+            "--output-dir",
+            runpack_dir.to_string_lossy().as_ref(),
+            "--with-verification",
+            "--compress",
+            "--generated-at-unix-ms",
+            "1700000000000",
+        ])
+        .output()
+        .expect("runpack export");
+    assert!(output.status.success(), "export failed: {}", String::from_utf8_lossy(&output.stderr));
+
+    let manifest_path = runpack_dir.join("runpack.json");
+    let manifest = read_manifest(&manifest_path);
+    assert_eq!(manifest.manifest_version.0, "v2");
+    for artifact in &manifest.artifacts {
+        assert_eq!(artifact.content_encoding, Compression::Gzip, "{}", artifact.path);
+        let stored = fs::read(runpack_dir.join(&artifact.path)).expect("read artifact");
+        assert_eq!(&stored[.. 2], &[0x1F, 0x8B], "{} is not gzip", artifact.path);
+        assert_ne!(
+            hash_bytes(manifest.hash_algorithm, &stored),
+            artifact.hash,
+            "{} hash must cover the uncompressed bytes",
+            artifact.path
+        );
+    }
+
+    let output = Command::new(decision_gate_bin())
+        .args([
+            "runpack",
+            "verify",
+            "--manifest",
+            manifest_path.to_string_lossy().as_ref(),
+            "--format",
+            "json",
+        ])
+        .output()
+        .expect("runpack verify");
+    assert!(output.status.success(), "verify failed: {}", String::from_utf8_lossy(&output.stderr));
+    let report: VerificationReport = serde_json::from_slice(&output.stdout).expect("parse report");
+    assert_eq!(report.status, VerificationStatus::Pass, "errors: {:?}", report.errors);
+    assert_eq!(report.checked_files, manifest.integrity.file_hashes.len());
+
+    let pretty_dir = root.join("pretty");
+    let output = Command::new(decision_gate_bin())
+        .args([
+            "runpack",
+            "pretty",
+            "--manifest",
+            manifest_path.to_string_lossy().as_ref(),
+            "--output-dir",
+            pretty_dir.to_string_lossy().as_ref(),
+        ])
+        .output()
+        .expect("runpack pretty");
+    assert!(output.status.success(), "pretty failed: {}", String::from_utf8_lossy(&output.stderr));
+    assert_pretty_json(&pretty_dir.join("artifacts").join("gate_evals.json"));
+
+    cleanup(&root);
+}
+
+/// Verifies `MessagePack` verification output round-trips and hashes stably.
+#[cfg(feature = "msgpack")]
+#[test]
+fn cli_runpack_verify_outputs_stable_msgpack_report() {
+    let root = temp_root("verify-msgpack");
+    let manifest = export_runpack(&root);
+    let verify = |format: &str| {
+        let output = Command::new(decision_gate_bin())
+            .args([
+                "runpack",
+                "verify",
+                "--manifest",
+                manifest.to_string_lossy().as_ref(),
+                "--format",
+                format,
+            ])
+            .output()
+            .expect("runpack verify");
+        assert!(output.status.success(), "verify --format {format} failed");
+        output.stdout
+    };
+
+    let json: VerificationReport = serde_json::from_slice(&verify("json")).expect("json report");
+    let first = verify("msgpack");
+    let decoded: VerificationReport = rmp_serde::from_slice(&first).expect("decode msgpack");
+    assert_eq!(decoded, json);
+
+    let second = verify("msgpack");
+    assert_eq!(
+        hash_bytes(DEFAULT_HASH_ALGORITHM, &first),
+        hash_bytes(DEFAULT_HASH_ALGORITHM, &second)
+    );
+
+    cleanup(&root);
+}
+
 /// Verifies runpack verification renders markdown summaries.
 #[test]
 fn cli_runpack_verify_outputs_markdown_report() {
@@ -543,6 +702,77 @@ fn cli_runpack_verify_outputs_markdown_report() {
     cleanup(&root);
 }
 
+/// Verifies signed verification attestations round-trip and reject edited reports.
+#[test]
+fn cli_runpack_verify_writes_attestation_that_verifies() {
+    let root = temp_root("verify-attestation");
+    let manifest = export_runpack(&root);
+    let signing_key_path = root.join("verifier.key");
+    let public_key_path = root.join("verifier.pub");
+    let attestation_path = root.join("attestation.json");
+    let report_path = root.join("report.json");
+    fs::write(&signing_key_path, [7_u8; 32]).expect("write signing key");
+    let public_key = ed25519_dalek::SigningKey::from_bytes(&[7_u8; 32]).verifying_key();
+    fs::write(&public_key_path, public_key.to_bytes()).expect("write public key");
+
+    let output = Command::new(decision_gate_bin())
+        .args([
+            "runpack",
+            "verify",
+            "--manifest",
+            manifest.to_string_lossy().as_ref(),
+            "--attestation-out",
+            attestation_path.to_string_lossy().as_ref(),
+            "--attestation-key",
+            signing_key_path.to_string_lossy().as_ref(),
+            "--verified-at-unix-ms",
+            "1700000000000",
+        ])
+        .output()
+        .expect("runpack verify with attestation");
+    assert!(output.status.success(), "stderr: {}", String::from_utf8_lossy(&output.stderr));
+    fs::write(&report_path, &output.stdout).expect("write report");
+
+    let attestation: VerificationAttestation =
+        serde_json::from_slice(&fs::read(&attestation_path).expect("read attestation"))
+            .expect("parse attestation");
+    assert_eq!(attestation.claims.status, VerificationStatus::Pass);
+    assert_eq!(attestation.claims.verified_at, Timestamp::UnixMillis(1_700_000_000_000));
+
+    let verify_attestation = |report: &Path| {
+        Command::new(decision_gate_bin())
+            .args([
+                "runpack",
+                "verify-attestation",
+                "--attestation",
+                attestation_path.to_string_lossy().as_ref(),
+                "--manifest",
+                manifest.to_string_lossy().as_ref(),
+                "--report",
+                report.to_string_lossy().as_ref(),
+                "--public-key",
+                public_key_path.to_string_lossy().as_ref(),
+            ])
+            .output()
+            .expect("runpack verify-attestation")
+    };
+
+    let output = verify_attestation(&report_path);
+    assert!(output.status.success(), "stderr: {}", String::from_utf8_lossy(&output.stderr));
+    assert!(String::from_utf8_lossy(&output.stdout).contains("Verification attestation valid"));
+
+    let mut report: VerificationReport =
+        serde_json::from_slice(&fs::read(&report_path).expect("read report")).expect("report");
+    report.errors.push("edited".to_string());
+    let tampered_path = root.join("tampered_report.json");
+    write_json(&tampered_path, &report);
+    let output = verify_attestation(&tampered_path);
+    assert!(!output.status.success());
+    assert!(String::from_utf8_lossy(&output.stderr).contains("report hash mismatch"));
+
+    cleanup(&root);
+}
+
 // ============================================================================
 // SECTION: Runpack Pretty Tests
 // ============================================================================
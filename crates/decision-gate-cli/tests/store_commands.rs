@@ -0,0 +1,219 @@
+// crates/decision-gate-cli/tests/store_commands.rs
+// ============================================================================
+// Module: CLI Store Command Tests
+// Description: Integration tests for CLI run state store administration.
+// Purpose: Validate `store` command wiring against a real SQLite store.
+// Dependencies: decision-gate-cli binary, decision-gate-core, decision-gate-store-sqlite,
+// rusqlite
+// ============================================================================
+//! ## Overview
+//! Saves run state versions into a temporary `SQLite` store and runs the CLI
+//! binary against it, checking JSON and text outputs.
+//!
+//! Security posture: CLI inputs are untrusted and must fail closed.
+
+#![allow(
+    clippy::panic,
+    clippy::print_stdout,
+    clippy::print_stderr,
+    clippy::unwrap_used,
+    clippy::expect_used,
+    clippy::use_debug,
+    clippy::dbg_macro,
+    clippy::panic_in_result_fn,
+    clippy::unwrap_in_result,
+    reason = "Test-only output and panic-based assertions are permitted."
+)]
+
+// ============================================================================
+// SECTION: Imports
+// ============================================================================
+
+use std::fs;
+use std::path::Path;
+use std::path::PathBuf;
+use std::process::Command;
+use std::process::Output;
+use std::time::SystemTime;
+use std::time::UNIX_EPOCH;
+
+use decision_gate_core::GateEvalRecord;
+use decision_gate_core::GateEvaluation;
+use decision_gate_core::GateId;
+use decision_gate_core::NamespaceId;
+use decision_gate_core::RunId;
+use decision_gate_core::RunState;
+use decision_gate_core::RunStateStore;
+use decision_gate_core::RunStatus;
+use decision_gate_core::ScenarioId;
+use decision_gate_core::StageId;
+use decision_gate_core::TenantId;
+use decision_gate_core::Timestamp;
+use decision_gate_core::TriggerId;
+use decision_gate_core::hashing::DEFAULT_HASH_ALGORITHM;
+use decision_gate_core::hashing::hash_bytes;
+use decision_gate_store_sqlite::SqliteRunStateStore;
+use decision_gate_store_sqlite::SqliteStoreConfig;
+use decision_gate_store_sqlite::SqliteStoreMode;
+use decision_gate_store_sqlite::SqliteSyncMode;
+use ret_logic::TriState;
+use rusqlite::Connection;
+use serde_json::Value;
+
+// ============================================================================
+// SECTION: Helpers
+// ============================================================================
+
+fn decision_gate_bin() -> PathBuf {
+    PathBuf::from(env!("CARGO_BIN_EXE_decision-gate"))
+}
+
+fn temp_root(label: &str) -> PathBuf {
+    let nanos = SystemTime::now().duration_since(UNIX_EPOCH).expect("clock drift").as_nanos();
+    let mut path = std::env::temp_dir();
+    path.push(format!("decision-gate-cli-{label}-{nanos}"));
+    fs::create_dir_all(&path).expect("create temp dir");
+    path
+}
+
+fn cleanup(path: &PathBuf) {
+    let _ = fs::remove_dir_all(path);
+}
+
+fn open_store(path: &Path) -> SqliteRunStateStore {
+    SqliteRunStateStore::new(SqliteStoreConfig {
+        path: path.to_path_buf(),
+        busy_timeout_ms: 5_000,
+        journal_mode: SqliteStoreMode::default(),
+        sync_mode: SqliteSyncMode::default(),
+        max_versions: None,
+        schema_registry_max_schema_bytes: None,
+        schema_registry_max_entries: None,
+        retention: Vec::new(),
+    })
+    .expect("open sqlite store")
+}
+
+fn sample_state() -> RunState {
+    RunState {
+        tenant_id: TenantId::from_raw(1).expect("nonzero tenantid"),
+        namespace_id: NamespaceId::from_raw(1).expect("nonzero namespaceid"),
+        run_id: RunId::new("run-1"),
+        scenario_id: ScenarioId::new("scenario"),
+        spec_hash: hash_bytes(DEFAULT_HASH_ALGORITHM, b"spec"),
+        current_stage_id: StageId::new("stage-1"),
+        stage_entered_at: Timestamp::Logical(0),
+        status: RunStatus::Active,
+        dispatch_targets: Vec::new(),
+        triggers: Vec::new(),
+        gate_evals: Vec::new(),
+        decisions: Vec::new(),
+        packets: Vec::new(),
+        submissions: Vec::new(),
+        tool_calls: Vec::new(),
+    }
+}
+
+fn gate_eval(gate: &str, status: TriState) -> GateEvalRecord {
+    GateEvalRecord {
+        trigger_id: TriggerId::new("trigger-1"),
+        stage_id: StageId::new("stage-1"),
+        evaluation: GateEvaluation {
+            gate_id: GateId::new(gate),
+            status,
+            trace: Vec::new(),
+        },
+        evidence: Vec::new(),
+    }
+}
+
+fn store_diff(store_path: &Path, from: &str, to: &str, format: &str) -> Output {
+    Command::new(decision_gate_bin())
+        .args(["store", "diff", "--store-path"])
+        .arg(store_path)
+        .args(["--tenant-id", "1", "--namespace-id", "1", "--run-id", "run-1"])
+        .args(["--from-version", from, "--to-version", to, "--format", format])
+        .output()
+        .expect("run store diff")
+}
+
+// ============================================================================
+// SECTION: Tests
+// ============================================================================
+
+/// Verifies `store diff` reports changes between two stored versions.
+#[test]
+fn cli_store_diff_reports_changes_between_versions() {
+    let root = temp_root("store-diff");
+    let store_path = root.join("store.db");
+    let store = open_store(&store_path);
+    let mut state = sample_state();
+    state.gate_evals.push(gate_eval("gate-a", TriState::Unknown));
+    store.save(&state).expect("save version 1");
+    state.gate_evals.push(gate_eval("gate-a", TriState::True));
+    state.status = RunStatus::Completed;
+    store.save(&state).expect("save version 2");
+    drop(store);
+
+    let output = store_diff(&store_path, "1", "2", "json");
+    assert!(output.status.success(), "stderr: {}", String::from_utf8_lossy(&output.stderr));
+    let json: Value = serde_json::from_slice(&output.stdout).expect("diff json");
+    assert_eq!(json["from_version"], 1);
+    assert_eq!(json["to_version"], 2);
+    assert_eq!(json["diff"]["status"]["from"], "active");
+    assert_eq!(json["diff"]["status"]["to"], "completed");
+    assert_eq!(json["diff"]["gates"][0]["gate_id"], "gate-a");
+    assert_eq!(json["diff"]["gates"][0]["change"], "changed");
+
+    let output = store_diff(&store_path, "1", "2", "text");
+    assert!(output.status.success(), "stderr: {}", String::from_utf8_lossy(&output.stderr));
+    let text = String::from_utf8_lossy(&output.stdout);
+    assert!(text.contains("- Status: active -> completed"), "text: {text}");
+    assert!(text.contains("- Gate stage-1/gate-a: changed (unknown -> true)"), "text: {text}");
+
+    let output = store_diff(&store_path, "2", "2", "text");
+    assert!(output.status.success(), "stderr: {}", String::from_utf8_lossy(&output.stderr));
+    assert!(String::from_utf8_lossy(&output.stdout).contains("- No changes"));
+
+    let output = store_diff(&store_path, "1", "9", "json");
+    assert!(!output.status.success());
+    assert!(String::from_utf8_lossy(&output.stderr).contains("Run version not found: 9"));
+
+    cleanup(&root);
+}
+
+/// Verifies store backend failures surface as `remote` in `--errors-json`.
+#[test]
+fn cli_store_backend_failure_reports_remote_kind() {
+    let root = temp_root("store-remote");
+    let store_path = root.join("store.db");
+    let store = open_store(&store_path);
+    store.save(&sample_state()).expect("save version 1");
+    drop(store);
+    let connection = Connection::open(&store_path).expect("open sqlite");
+    connection
+        .execute("UPDATE run_state_versions SET state_hash = 'tampered'", [])
+        .expect("tamper state hash");
+    drop(connection);
+
+    let output = Command::new(decision_gate_bin())
+        .args(["--errors-json", "store", "diff", "--store-path"])
+        .arg(&store_path)
+        .args(["--tenant-id", "1", "--namespace-id", "1", "--run-id", "run-1"])
+        .args(["--from-version", "1", "--to-version", "1"])
+        .env_remove("DECISION_GATE_LANG")
+        .output()
+        .expect("run store diff");
+    assert_eq!(output.status.code(), Some(1));
+    let envelope: Value = serde_json::from_slice(&output.stdout).expect("error envelope");
+    assert_eq!(envelope["error"]["kind"], "remote");
+    assert_eq!(envelope["error"]["code"], 1);
+    assert!(
+        envelope["error"]["message"]
+            .as_str()
+            .is_some_and(|message| message.contains("hash mismatch")),
+        "unexpected error: {envelope}"
+    );
+
+    cleanup(&root);
+}
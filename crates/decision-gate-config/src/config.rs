@@ -35,8 +35,11 @@ use decision_gate_core::ToolName;
 use decision_gate_core::TrustLane;
 use decision_gate_core::TrustRequirement;
 use decision_gate_core::is_builtin_provider_id;
+use decision_gate_store_sqlite::SqliteRetentionPolicy;
+use decision_gate_store_sqlite::SqliteStoreError;
 use decision_gate_store_sqlite::SqliteStoreMode;
 use decision_gate_store_sqlite::SqliteSyncMode;
+use decision_gate_store_sqlite::validate_retention_policies;
 use serde::Deserialize;
 use serde::Serialize;
 use thiserror::Error;
@@ -44,6 +47,10 @@ use thiserror::Error;
 use crate::policy::DispatchPolicy;
 use crate::policy::PolicyEngine;
 use crate::policy::StaticPolicyConfig;
+use crate::profile::merge_overlay;
+use crate::profile::profile_overlay_path;
+use crate::profile::resolve_profile;
+use crate::secrets::resolve_secret_in_place;
 // ============================================================================
 // SECTION: Constants
 // ============================================================================
@@ -68,16 +75,56 @@ pub(crate) const MAX_AUTH_TOOL_RULES: usize = 128;
 pub(crate) const MAX_AUTH_SUBJECT_LENGTH: usize = 512;
 /// Maximum number of principal role bindings.
 pub(crate) const MAX_PRINCIPAL_ROLES: usize = 128;
+/// Maximum number of tenant identifiers in a principal scope.
+pub(crate) const MAX_PRINCIPAL_SCOPE_TENANTS: usize = 128;
+/// Maximum length of a JWT issuer or audience string.
+pub(crate) const MAX_JWT_CLAIM_LENGTH: usize = 512;
+/// Maximum number of allowlisted JWKS hosts.
+pub(crate) const MAX_JWKS_ALLOWED_HOSTS: usize = 16;
+/// Minimum JWKS fetch timeout in milliseconds.
+pub(crate) const MIN_JWKS_TIMEOUT_MS: u64 = 100;
+/// Maximum JWKS fetch timeout in milliseconds.
+pub(crate) const MAX_JWKS_TIMEOUT_MS: u64 = 30_000;
+/// Minimum JWKS cache lifetime in milliseconds.
+pub(crate) const MIN_JWKS_CACHE_TTL_MS: u64 = 10_000;
+/// Maximum JWKS cache lifetime in milliseconds.
+pub(crate) const MAX_JWKS_CACHE_TTL_MS: u64 = 86_400_000;
+/// Maximum tolerated JWT clock skew in seconds.
+pub(crate) const MAX_JWT_CLOCK_SKEW_SECS: u64 = 300;
 /// Maximum number of tool visibility entries.
 pub(crate) const MAX_TOOL_VISIBILITY_RULES: usize = 128;
 /// Maximum number of registry ACL rules.
 pub(crate) const MAX_REGISTRY_ACL_RULES: usize = 256;
 /// Default maximum inflight requests for MCP servers.
 pub(crate) const DEFAULT_MAX_INFLIGHT: usize = 256;
+/// Maximum concurrent calls allowed by a per-tool concurrency limit.
+pub(crate) const MAX_TOOL_CONCURRENCY: usize = 4_096;
+/// Maximum time a tool call may queue for a concurrency slot in milliseconds.
+pub(crate) const MAX_TOOL_QUEUE_WAIT_MS: u64 = 30_000;
 /// Minimum allowed rate limit window in milliseconds.
 pub(crate) const MIN_RATE_LIMIT_WINDOW_MS: u64 = 100;
 /// Maximum allowed rate limit window in milliseconds.
 pub(crate) const MAX_RATE_LIMIT_WINDOW_MS: u64 = 60_000;
+/// Default SSE heartbeat interval in milliseconds.
+pub(crate) const DEFAULT_SSE_HEARTBEAT_INTERVAL_MS: u64 = 15_000;
+/// Minimum SSE heartbeat interval in milliseconds.
+pub(crate) const MIN_SSE_HEARTBEAT_INTERVAL_MS: u64 = 100;
+/// Maximum SSE heartbeat interval in milliseconds.
+pub(crate) const MAX_SSE_HEARTBEAT_INTERVAL_MS: u64 = 300_000;
+/// Default SSE stream poll interval in milliseconds.
+pub(crate) const DEFAULT_SSE_POLL_INTERVAL_MS: u64 = 1_000;
+/// Minimum SSE stream poll interval in milliseconds.
+pub(crate) const MIN_SSE_POLL_INTERVAL_MS: u64 = 50;
+/// Maximum SSE stream poll interval in milliseconds.
+pub(crate) const MAX_SSE_POLL_INTERVAL_MS: u64 = 60_000;
+/// Default per-consumer SSE event buffer.
+pub(crate) const DEFAULT_SSE_STREAM_BUFFER: usize = 64;
+/// Maximum per-consumer SSE event buffer.
+pub(crate) const MAX_SSE_STREAM_BUFFER: usize = 4_096;
+/// Default `Cache-Control` max-age for the live `OpenAPI` document in seconds.
+pub(crate) const DEFAULT_OPENAPI_MAX_AGE_SECS: u64 = 300;
+/// Maximum `Cache-Control` max-age for the live `OpenAPI` document in seconds.
+pub(crate) const MAX_OPENAPI_MAX_AGE_SECS: u64 = 86_400;
 /// Maximum allowed requests per rate limit window.
 pub(crate) const MAX_RATE_LIMIT_REQUESTS: u32 = 100_000;
 /// Maximum number of tracked rate limit entries.
@@ -88,6 +135,38 @@ pub(crate) const DEFAULT_RATE_LIMIT_MAX_REQUESTS: u32 = 1_000;
 pub(crate) const DEFAULT_RATE_LIMIT_WINDOW_MS: u64 = 1_000;
 /// Default max tracked rate limit entries when enabled.
 pub(crate) const DEFAULT_RATE_LIMIT_MAX_ENTRIES: usize = 4_096;
+/// Maximum consecutive store failures before the circuit breaker opens.
+pub(crate) const MAX_STORE_BREAKER_FAILURE_THRESHOLD: u32 = 1_000;
+/// Maximum successful probes required to close the circuit breaker.
+pub(crate) const MAX_STORE_BREAKER_SUCCESS_THRESHOLD: u32 = 100;
+/// Minimum circuit breaker open duration in milliseconds.
+pub(crate) const MIN_STORE_BREAKER_OPEN_MS: u64 = 100;
+/// Maximum circuit breaker open duration in milliseconds.
+pub(crate) const MAX_STORE_BREAKER_OPEN_MS: u64 = 300_000;
+/// Maximum slow-call threshold for store calls in milliseconds.
+pub(crate) const MAX_STORE_BREAKER_SLOW_CALL_MS: u64 = 60_000;
+/// Default consecutive store failures before the circuit breaker opens.
+pub(crate) const DEFAULT_STORE_BREAKER_FAILURE_THRESHOLD: u32 = 5;
+/// Default successful probes required to close the circuit breaker.
+pub(crate) const DEFAULT_STORE_BREAKER_SUCCESS_THRESHOLD: u32 = 2;
+/// Default circuit breaker open duration in milliseconds.
+pub(crate) const DEFAULT_STORE_BREAKER_OPEN_MS: u64 = 5_000;
+/// Maximum cached run states.
+pub(crate) const MAX_RUN_STATE_CACHE_ENTRIES: usize = 65_536;
+/// Default cached run states.
+pub(crate) const DEFAULT_RUN_STATE_CACHE_ENTRIES: usize = 1_024;
+/// Minimum write-back flush interval in milliseconds.
+pub(crate) const MIN_RUN_STATE_CACHE_FLUSH_INTERVAL_MS: u64 = 100;
+/// Maximum write-back flush interval in milliseconds.
+pub(crate) const MAX_RUN_STATE_CACHE_FLUSH_INTERVAL_MS: u64 = 60_000;
+/// Default write-back flush interval in milliseconds.
+pub(crate) const DEFAULT_RUN_STATE_CACHE_FLUSH_INTERVAL_MS: u64 = 1_000;
+/// Minimum retention maintenance interval in milliseconds.
+pub(crate) const MIN_STORE_RETENTION_INTERVAL_MS: u64 = 1_000;
+/// Maximum retention maintenance interval in milliseconds.
+pub(crate) const MAX_STORE_RETENTION_INTERVAL_MS: u64 = 86_400_000;
+/// Default retention maintenance interval in milliseconds.
+pub(crate) const DEFAULT_STORE_RETENTION_INTERVAL_MS: u64 = 3_600_000;
 /// Minimum MCP provider connect timeout in milliseconds.
 pub(crate) const MIN_PROVIDER_CONNECT_TIMEOUT_MS: u64 = 100;
 /// Maximum MCP provider connect timeout in milliseconds.
@@ -96,6 +175,10 @@ pub(crate) const MAX_PROVIDER_CONNECT_TIMEOUT_MS: u64 = 10_000;
 pub(crate) const MIN_PROVIDER_REQUEST_TIMEOUT_MS: u64 = 500;
 /// Maximum MCP provider request timeout in milliseconds.
 pub(crate) const MAX_PROVIDER_REQUEST_TIMEOUT_MS: u64 = 30_000;
+/// Minimum evidence query timeout in milliseconds.
+pub(crate) const MIN_EVIDENCE_QUERY_TIMEOUT_MS: u64 = 10;
+/// Maximum evidence query timeout in milliseconds.
+pub(crate) const MAX_EVIDENCE_QUERY_TIMEOUT_MS: u64 = 300_000;
 /// Default max schema size accepted by registry (bytes).
 pub(crate) const DEFAULT_SCHEMA_MAX_BYTES: usize = 1024 * 1024;
 /// Maximum allowed schema size in bytes.
@@ -112,6 +195,16 @@ pub(crate) const MAX_NAMESPACE_AUTH_CONNECT_TIMEOUT_MS: u64 = 10_000;
 pub(crate) const MIN_NAMESPACE_AUTH_REQUEST_TIMEOUT_MS: u64 = 500;
 /// Maximum namespace authority request timeout in milliseconds.
 pub(crate) const MAX_NAMESPACE_AUTH_REQUEST_TIMEOUT_MS: u64 = 30_000;
+/// Minimum TLS certificate reload poll interval in milliseconds.
+pub(crate) const MIN_TLS_RELOAD_INTERVAL_MS: u64 = 1_000;
+/// Maximum TLS certificate reload poll interval in milliseconds.
+pub(crate) const MAX_TLS_RELOAD_INTERVAL_MS: u64 = 3_600_000;
+/// Maximum configurable client certificate chain depth (leaf included).
+pub(crate) const MAX_TLS_CLIENT_CHAIN_DEPTH: usize = 16;
+/// Maximum number of required client certificate EKU OIDs.
+pub(crate) const MAX_TLS_REQUIRED_EKUS: usize = 16;
+/// Maximum length of an EKU OID string.
+pub(crate) const MAX_TLS_EKU_OID_LENGTH: usize = 128;
 /// Default maximum provider discovery response size in bytes.
 pub(crate) const DEFAULT_PROVIDER_DISCOVERY_MAX_BYTES: usize = 1024 * 1024;
 /// Default maximum size for a single docs entry in bytes.
@@ -190,23 +283,107 @@ pub struct DecisionGateConfig {
 impl DecisionGateConfig {
     /// Loads configuration from disk using the default resolution rules.
     ///
+    /// The active profile overlay, if any, is selected by `DG_PROFILE`.
+    ///
     /// # Errors
     ///
     /// Returns [`ConfigError`] when loading or validation fails. Config files
     /// larger than [`MAX_CONFIG_FILE_SIZE`] are rejected before parsing.
     pub fn load(path: Option<&Path>) -> Result<Self, ConfigError> {
+        Self::load_with_profile(path, None)
+    }
+
+    /// Loads configuration from disk with an optional profile overlay.
+    ///
+    /// An explicit `profile` overrides `DG_PROFILE`. When a profile is active
+    /// the overlay file `<stem>.<profile>.toml` next to the base config must
+    /// exist; it is deep-merged onto the base before validation (see
+    /// [`crate::profile`] for merge semantics).
+    ///
+    /// # Errors
+    ///
+    /// Returns [`ConfigError`] when loading, merging, or validation fails.
+    /// Base and overlay files are each subject to [`MAX_CONFIG_FILE_SIZE`].
+    pub fn load_with_profile(
+        path: Option<&Path>,
+        profile: Option<&str>,
+    ) -> Result<Self, ConfigError> {
         let resolved = resolve_path(path)?;
         validate_path(&resolved)?;
-        let bytes = read_config_bytes(&resolved)?;
-        let content = std::str::from_utf8(&bytes)
-            .map_err(|_| ConfigError::Invalid("config file must be utf-8".to_string()))?;
-        let mut config: Self =
-            toml::from_str(content).map_err(|err| ConfigError::Parse(err.to_string()))?;
-        config.source_modified_at = fs::metadata(&resolved).and_then(|meta| meta.modified()).ok();
+        let content = read_config_text(&resolved)?;
+        let mut modified_at = fs::metadata(&resolved).and_then(|meta| meta.modified()).ok();
+        let mut config: Self = match resolve_profile(profile)? {
+            None => toml::from_str(&content).map_err(|err| ConfigError::Parse(err.to_string()))?,
+            Some(profile) => {
+                let overlay_path = profile_overlay_path(&resolved, &profile);
+                validate_path(&overlay_path)?;
+                let overlay_content = read_config_text(&overlay_path)?;
+                let mut base: toml::Table =
+                    toml::from_str(&content).map_err(|err| ConfigError::Parse(err.to_string()))?;
+                let overlay: toml::Table = toml::from_str(&overlay_content)
+                    .map_err(|err| ConfigError::Parse(format!("profile {profile}: {err}")))?;
+                merge_overlay(&mut base, overlay)?;
+                let overlay_modified_at =
+                    fs::metadata(&overlay_path).and_then(|meta| meta.modified()).ok();
+                modified_at = modified_at.max(overlay_modified_at);
+                toml::Value::Table(base)
+                    .try_into()
+                    .map_err(|err: toml::de::Error| ConfigError::Parse(err.to_string()))?
+            }
+        };
+        config.source_modified_at = modified_at;
+        config.resolve_secrets()?;
         config.validate()?;
         Ok(config)
     }
 
+    /// Resolves `secret://` references in designated secret fields using the
+    /// process environment.
+    ///
+    /// Designated secret fields are `server.auth.bearer_tokens`,
+    /// `namespace.authority.assetcore.auth_token`, and
+    /// `providers[].auth.bearer_token`. [`Self::load`] calls this after
+    /// parsing and before [`Self::validate`], so resolved values are subject
+    /// to the same validation as literals.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`ConfigError`] when a reference is malformed or resolves to a
+    /// missing or empty secret.
+    pub fn resolve_secrets(&mut self) -> Result<(), ConfigError> {
+        self.resolve_secrets_with(&|name| env::var(name).ok())
+    }
+
+    /// Resolves `secret://` references using an injected environment lookup.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`ConfigError`] when a reference is malformed or resolves to a
+    /// missing or empty secret.
+    pub fn resolve_secrets_with(
+        &mut self,
+        lookup_env: &dyn Fn(&str) -> Option<String>,
+    ) -> Result<(), ConfigError> {
+        if let Some(auth) = &mut self.server.auth {
+            for token in &mut auth.bearer_tokens {
+                resolve_secret_in_place("server.auth.bearer_tokens", token, lookup_env)?;
+            }
+        }
+        if let Some(assetcore) = &mut self.namespace.authority.assetcore
+            && let Some(token) = &mut assetcore.auth_token
+        {
+            resolve_secret_in_place("namespace.authority.assetcore.auth_token", token, lookup_env)?;
+        }
+        for provider in &mut self.providers {
+            if let Some(auth) = &mut provider.auth
+                && let Some(token) = &mut auth.bearer_token
+            {
+                resolve_secret_in_place("providers.auth.bearer_token", token, lookup_env)?;
+            }
+        }
+        Ok(())
+    }
+
     /// Validates the configuration for internal consistency.
     ///
     /// # Errors
@@ -223,6 +400,7 @@ impl DecisionGateConfig {
         self.anchors.validate()?;
         self.provider_discovery.validate()?;
         self.docs.validate()?;
+        self.evidence.validate()?;
         if let Some(storage) = &self.runpack_storage {
             storage.validate()?;
         }
@@ -375,6 +553,15 @@ pub struct ServerConfig {
     /// Tool visibility configuration for MCP tool listings.
     #[serde(default)]
     pub tools: ServerToolsConfig,
+    /// Streaming settings for the SSE transport.
+    #[serde(default)]
+    pub sse: ServerSseConfig,
+    /// Live `OpenAPI` endpoint settings for HTTP/SSE transports.
+    #[serde(default)]
+    pub openapi: ServerOpenApiConfig,
+    /// Evidence provider warm-up mode.
+    #[serde(default)]
+    pub provider_warm_up: ProviderWarmUpMode,
 }
 
 impl Default for ServerConfig {
@@ -391,6 +578,9 @@ impl Default for ServerConfig {
             audit: ServerAuditConfig::default(),
             feedback: ServerFeedbackConfig::default(),
             tools: ServerToolsConfig::default(),
+            sse: ServerSseConfig::default(),
+            openapi: ServerOpenApiConfig::default(),
+            provider_warm_up: ProviderWarmUpMode::default(),
         }
     }
 }
@@ -413,6 +603,8 @@ impl ServerConfig {
         self.audit.validate()?;
         self.feedback.validate()?;
         self.tools.validate()?;
+        self.sse.validate()?;
+        self.openapi.validate()?;
         let auth_mode = self.auth.as_ref().map_or(ServerAuthMode::LocalOnly, |auth| auth.mode);
         match self.transport {
             ServerTransport::Http | ServerTransport::Sse => {
@@ -622,6 +814,9 @@ pub struct ServerLimitsConfig {
     /// Optional rate limit configuration.
     #[serde(default)]
     pub rate_limit: Option<RateLimitConfig>,
+    /// Per-tool concurrency limits applied on top of `max_inflight`.
+    #[serde(default)]
+    pub tool_concurrency: Vec<ToolConcurrencyConfig>,
 }
 
 impl Default for ServerLimitsConfig {
@@ -629,6 +824,7 @@ impl Default for ServerLimitsConfig {
         Self {
             max_inflight: default_max_inflight(),
             rate_limit: None,
+            tool_concurrency: Vec::new(),
         }
     }
 }
@@ -642,6 +838,137 @@ impl ServerLimitsConfig {
         if let Some(rate_limit) = &self.rate_limit {
             rate_limit.validate()?;
         }
+        let mut seen = BTreeSet::new();
+        for limit in &self.tool_concurrency {
+            limit.validate()?;
+            if !seen.insert(limit.tool.as_str()) {
+                return Err(ConfigError::Invalid(format!(
+                    "duplicate server.limits.tool_concurrency entry: {}",
+                    limit.tool
+                )));
+            }
+        }
+        Ok(())
+    }
+}
+
+/// Concurrency limit for a single MCP tool.
+#[derive(Debug, Clone, Deserialize)]
+pub struct ToolConcurrencyConfig {
+    /// Tool name the limit applies to.
+    pub tool: String,
+    /// Maximum concurrent calls of the tool.
+    pub max_concurrent: usize,
+    /// Time a call may queue for a free slot in milliseconds; 0 rejects immediately.
+    #[serde(default)]
+    pub max_queue_wait_ms: u64,
+}
+
+impl ToolConcurrencyConfig {
+    /// Validates a per-tool concurrency limit.
+    fn validate(&self) -> Result<(), ConfigError> {
+        if ToolName::parse(&self.tool).is_none() {
+            return Err(ConfigError::Invalid(format!(
+                "unknown tool in server.limits.tool_concurrency: {}",
+                self.tool
+            )));
+        }
+        if self.max_concurrent == 0 || self.max_concurrent > MAX_TOOL_CONCURRENCY {
+            return Err(ConfigError::Invalid(format!(
+                "server.limits.tool_concurrency.max_concurrent must be between 1 and \
+                 {MAX_TOOL_CONCURRENCY}"
+            )));
+        }
+        if self.max_queue_wait_ms > MAX_TOOL_QUEUE_WAIT_MS {
+            return Err(ConfigError::Invalid(format!(
+                "server.limits.tool_concurrency.max_queue_wait_ms must be <= \
+                 {MAX_TOOL_QUEUE_WAIT_MS}"
+            )));
+        }
+        Ok(())
+    }
+}
+
+/// Streaming settings for the SSE transport.
+#[derive(Debug, Clone, Deserialize)]
+pub struct ServerSseConfig {
+    /// Idle interval between heartbeat events in milliseconds.
+    #[serde(default = "default_sse_heartbeat_interval_ms")]
+    pub heartbeat_interval_ms: u64,
+    /// Interval between polls for new stream items in milliseconds.
+    #[serde(default = "default_sse_poll_interval_ms")]
+    pub poll_interval_ms: u64,
+    /// Events buffered per consumer before the consumer is dropped.
+    #[serde(default = "default_sse_stream_buffer")]
+    pub stream_buffer: usize,
+}
+
+impl Default for ServerSseConfig {
+    fn default() -> Self {
+        Self {
+            heartbeat_interval_ms: default_sse_heartbeat_interval_ms(),
+            poll_interval_ms: default_sse_poll_interval_ms(),
+            stream_buffer: default_sse_stream_buffer(),
+        }
+    }
+}
+
+impl ServerSseConfig {
+    /// Validates SSE streaming settings.
+    fn validate(&self) -> Result<(), ConfigError> {
+        validate_timeout_range(
+            "server.sse.heartbeat_interval_ms",
+            self.heartbeat_interval_ms,
+            MIN_SSE_HEARTBEAT_INTERVAL_MS,
+            MAX_SSE_HEARTBEAT_INTERVAL_MS,
+        )?;
+        validate_timeout_range(
+            "server.sse.poll_interval_ms",
+            self.poll_interval_ms,
+            MIN_SSE_POLL_INTERVAL_MS,
+            MAX_SSE_POLL_INTERVAL_MS,
+        )?;
+        if self.stream_buffer == 0 || self.stream_buffer > MAX_SSE_STREAM_BUFFER {
+            return Err(ConfigError::Invalid(format!(
+                "server.sse.stream_buffer must be between 1 and {MAX_SSE_STREAM_BUFFER}"
+            )));
+        }
+        Ok(())
+    }
+}
+
+/// Live `OpenAPI` endpoint settings for HTTP/SSE transports.
+#[derive(Debug, Clone, Deserialize)]
+pub struct ServerOpenApiConfig {
+    /// Serve `GET /openapi.json`.
+    #[serde(default = "default_openapi_enabled")]
+    pub enabled: bool,
+    /// Require server auth for the endpoint; when false it is auth-exempt.
+    #[serde(default = "default_openapi_require_auth")]
+    pub require_auth: bool,
+    /// `Cache-Control` max-age advertised for the document in seconds.
+    #[serde(default = "default_openapi_max_age_secs")]
+    pub max_age_secs: u64,
+}
+
+impl Default for ServerOpenApiConfig {
+    fn default() -> Self {
+        Self {
+            enabled: default_openapi_enabled(),
+            require_auth: default_openapi_require_auth(),
+            max_age_secs: default_openapi_max_age_secs(),
+        }
+    }
+}
+
+impl ServerOpenApiConfig {
+    /// Validates `OpenAPI` endpoint settings.
+    fn validate(&self) -> Result<(), ConfigError> {
+        if self.max_age_secs > MAX_OPENAPI_MAX_AGE_SECS {
+            return Err(ConfigError::Invalid(format!(
+                "server.openapi.max_age_secs must be <= {MAX_OPENAPI_MAX_AGE_SECS}"
+            )));
+        }
         Ok(())
     }
 }
@@ -702,6 +1029,18 @@ pub struct ServerTlsConfig {
     /// Require client certificates when a client CA bundle is configured.
     #[serde(default = "default_tls_require_client_cert")]
     pub require_client_cert: bool,
+    /// Optional poll interval for certificate hot-reload (disabled when unset).
+    #[serde(default)]
+    pub reload_interval_ms: Option<u64>,
+    /// Optional maximum client certificate chain depth, leaf included.
+    #[serde(default)]
+    pub max_client_chain_depth: Option<usize>,
+    /// Extended key usage OIDs every client certificate must carry.
+    #[serde(default)]
+    pub client_required_ekus: Vec<String>,
+    /// Optional DER-encoded OCSP response stapled to the server certificate.
+    #[serde(default)]
+    pub ocsp_response_path: Option<String>,
 }
 
 impl ServerTlsConfig {
@@ -712,10 +1051,62 @@ impl ServerTlsConfig {
         if let Some(path) = &self.client_ca_path {
             validate_path_string("tls.client_ca_path", path)?;
         }
+        if let Some(interval_ms) = self.reload_interval_ms {
+            validate_timeout_range(
+                "tls.reload_interval_ms",
+                interval_ms,
+                MIN_TLS_RELOAD_INTERVAL_MS,
+                MAX_TLS_RELOAD_INTERVAL_MS,
+            )?;
+        }
+        if let Some(path) = &self.ocsp_response_path {
+            validate_path_string("tls.ocsp_response_path", path)?;
+        }
+        if let Some(depth) = self.max_client_chain_depth
+            && (depth == 0 || depth > MAX_TLS_CLIENT_CHAIN_DEPTH)
+        {
+            return Err(ConfigError::Invalid(format!(
+                "tls.max_client_chain_depth must be between 1 and {MAX_TLS_CLIENT_CHAIN_DEPTH}"
+            )));
+        }
+        if self.client_required_ekus.len() > MAX_TLS_REQUIRED_EKUS {
+            return Err(ConfigError::Invalid(
+                "tls.client_required_ekus too many entries".to_string(),
+            ));
+        }
+        for oid in &self.client_required_ekus {
+            if !is_dotted_oid(oid) {
+                return Err(ConfigError::Invalid(format!(
+                    "tls.client_required_ekus entry is not a dotted OID: {oid}"
+                )));
+            }
+        }
+        let has_client_policy =
+            self.max_client_chain_depth.is_some() || !self.client_required_ekus.is_empty();
+        if has_client_policy && self.client_ca_path.is_none() {
+            return Err(ConfigError::Invalid(
+                "tls client certificate policy requires tls.client_ca_path".to_string(),
+            ));
+        }
         Ok(())
     }
 }
 
+/// Returns true when the value is a dotted-decimal OID (for example `1.3.6.1`).
+fn is_dotted_oid(value: &str) -> bool {
+    if value.is_empty() || value.len() > MAX_TLS_EKU_OID_LENGTH {
+        return false;
+    }
+    let mut arcs = 0usize;
+    for arc in value.split('.') {
+        if arc.is_empty() || !arc.bytes().all(|byte| byte.is_ascii_digit()) {
+            return false;
+        }
+        arcs += 1;
+    }
+    arcs >= 2
+}
+
 /// Audit logging configuration for MCP server requests.
 #[derive(Debug, Clone, Deserialize)]
 pub struct ServerAuditConfig {
@@ -785,6 +1176,22 @@ pub enum ServerTlsTermination {
     Upstream,
 }
 
+/// Evidence provider warm-up mode.
+///
+/// # Invariants
+/// - Variants are stable for configuration parsing.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Deserialize, Serialize, Default)]
+#[serde(rename_all = "snake_case")]
+pub enum ProviderWarmUpMode {
+    /// Providers initialize on first use.
+    #[default]
+    Off,
+    /// Providers are warmed up at startup; failures abort startup.
+    Startup,
+    /// Providers are warmed up on every readiness probe; failures report not ready.
+    Readiness,
+}
+
 /// Inbound auth modes for MCP server tool calls.
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Deserialize, Default)]
 #[serde(rename_all = "snake_case")]
@@ -804,9 +1211,12 @@ pub struct ServerAuthConfig {
     /// Auth mode for inbound MCP tool calls.
     #[serde(default)]
     pub mode: ServerAuthMode,
-    /// Accepted bearer tokens (required for `bearer_token` mode).
+    /// Accepted bearer tokens (required for `bearer_token` mode without `jwt`).
     #[serde(default)]
     pub bearer_tokens: Vec<String>,
+    /// Optional JWT validation for bearer credentials.
+    #[serde(default)]
+    pub jwt: Option<JwtAuthConfig>,
     /// Allowed mTLS subjects (required for mtls mode).
     #[serde(default)]
     pub mtls_subjects: Vec<String>,
@@ -818,6 +1228,124 @@ pub struct ServerAuthConfig {
     pub principals: Vec<PrincipalConfig>,
 }
 
+/// JWT bearer validation configuration.
+///
+/// # Invariants
+/// - `jwks_url` uses https unless `allow_http` is set.
+/// - The `jwks_url` host is listed in `allowed_jwks_hosts`.
+#[derive(Debug, Clone, Deserialize)]
+pub struct JwtAuthConfig {
+    /// Required `iss` claim value.
+    pub issuer: String,
+    /// Required `aud` claim value.
+    pub audience: String,
+    /// JWKS endpoint URL for signing keys.
+    pub jwks_url: String,
+    /// Hosts permitted for JWKS fetches.
+    #[serde(default)]
+    pub allowed_jwks_hosts: Vec<String>,
+    /// Allow non-TLS JWKS endpoints (explicit opt-in).
+    #[serde(default)]
+    pub allow_http: bool,
+    /// Accepted signature algorithms.
+    #[serde(default = "default_jwt_algorithms")]
+    pub algorithms: Vec<JwtAlgorithm>,
+    /// JWKS fetch timeout in milliseconds.
+    #[serde(default = "default_jwks_timeout_ms")]
+    pub jwks_timeout_ms: u64,
+    /// JWKS cache lifetime in milliseconds.
+    #[serde(default = "default_jwks_cache_ttl_ms")]
+    pub jwks_cache_ttl_ms: u64,
+    /// Tolerated clock skew for `exp` and `nbf` checks in seconds.
+    #[serde(default = "default_jwt_clock_skew_secs")]
+    pub clock_skew_secs: u64,
+}
+
+impl JwtAuthConfig {
+    /// Validates JWT auth configuration.
+    fn validate(&self) -> Result<(), ConfigError> {
+        for (field, value) in [("issuer", &self.issuer), ("audience", &self.audience)] {
+            if value.trim().is_empty() {
+                return Err(ConfigError::Invalid(format!("auth.jwt.{field} must be non-empty")));
+            }
+            if value.len() > MAX_JWT_CLAIM_LENGTH {
+                return Err(ConfigError::Invalid(format!("auth.jwt.{field} too long")));
+            }
+        }
+        let url = self.jwks_url.trim();
+        if !(url.starts_with("https://") || url.starts_with("http://")) {
+            return Err(ConfigError::Invalid(
+                "auth.jwt.jwks_url must include http:// or https://".to_string(),
+            ));
+        }
+        if url.starts_with("http://") && !self.allow_http {
+            return Err(ConfigError::Invalid(
+                "auth.jwt.jwks_url uses http:// without allow_http".to_string(),
+            ));
+        }
+        if self.allowed_jwks_hosts.is_empty() {
+            return Err(ConfigError::Invalid(
+                "auth.jwt.allowed_jwks_hosts must list the jwks_url host".to_string(),
+            ));
+        }
+        if self.allowed_jwks_hosts.len() > MAX_JWKS_ALLOWED_HOSTS {
+            return Err(ConfigError::Invalid(
+                "auth.jwt.allowed_jwks_hosts exceeds max entries".to_string(),
+            ));
+        }
+        let host = url_host(url)
+            .ok_or_else(|| ConfigError::Invalid("auth.jwt.jwks_url host is invalid".to_string()))?;
+        if !self.allowed_jwks_hosts.iter().any(|allowed| allowed.eq_ignore_ascii_case(&host)) {
+            return Err(ConfigError::Invalid(format!(
+                "auth.jwt.jwks_url host not in allowed_jwks_hosts: {host}"
+            )));
+        }
+        if self.algorithms.is_empty() {
+            return Err(ConfigError::Invalid("auth.jwt.algorithms must be non-empty".to_string()));
+        }
+        validate_timeout_range(
+            "auth.jwt.jwks_timeout_ms",
+            self.jwks_timeout_ms,
+            MIN_JWKS_TIMEOUT_MS,
+            MAX_JWKS_TIMEOUT_MS,
+        )?;
+        validate_timeout_range(
+            "auth.jwt.jwks_cache_ttl_ms",
+            self.jwks_cache_ttl_ms,
+            MIN_JWKS_CACHE_TTL_MS,
+            MAX_JWKS_CACHE_TTL_MS,
+        )?;
+        if self.clock_skew_secs > MAX_JWT_CLOCK_SKEW_SECS {
+            return Err(ConfigError::Invalid(format!(
+                "auth.jwt.clock_skew_secs must be at most {MAX_JWT_CLOCK_SKEW_SECS}"
+            )));
+        }
+        Ok(())
+    }
+}
+
+/// JWT signature algorithms accepted for bearer validation.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Deserialize, Serialize)]
+pub enum JwtAlgorithm {
+    /// RSASSA-PKCS1-v1_5 with SHA-256.
+    #[serde(rename = "RS256")]
+    Rs256,
+    /// ECDSA P-256 with SHA-256.
+    #[serde(rename = "ES256")]
+    Es256,
+}
+
+impl JwtAlgorithm {
+    /// Returns the JOSE `alg` header value.
+    #[must_use]
+    pub const fn as_str(self) -> &'static str {
+        match self {
+            Self::Rs256 => "RS256",
+            Self::Es256 => "ES256",
+        }
+    }
+}
+
 /// Tool visibility configuration for MCP tool listings.
 #[derive(Debug, Clone, Deserialize, Default)]
 pub struct ServerToolsConfig {
@@ -914,12 +1442,20 @@ impl ServerAuthConfig {
         for principal in &self.principals {
             principal.validate()?;
         }
+        if let Some(jwt) = &self.jwt {
+            if self.mode != ServerAuthMode::BearerToken {
+                return Err(ConfigError::Invalid(
+                    "auth.jwt requires bearer_token auth mode".to_string(),
+                ));
+            }
+            jwt.validate()?;
+        }
         match self.mode {
             ServerAuthMode::LocalOnly => Ok(()),
             ServerAuthMode::BearerToken => {
-                if self.bearer_tokens.is_empty() {
+                if self.bearer_tokens.is_empty() && self.jwt.is_none() {
                     return Err(ConfigError::Invalid(
-                        "bearer_token auth requires bearer_tokens".to_string(),
+                        "bearer_token auth requires bearer_tokens or jwt".to_string(),
                     ));
                 }
                 Ok(())
@@ -947,6 +1483,9 @@ pub struct PrincipalConfig {
     /// Role bindings for this principal.
     #[serde(default)]
     pub roles: Vec<PrincipalRoleConfig>,
+    /// Least-privilege tool and tenant scopes for this principal.
+    #[serde(default)]
+    pub scopes: PrincipalScopesConfig,
 }
 
 impl PrincipalConfig {
@@ -972,7 +1511,7 @@ impl PrincipalConfig {
         for role in &self.roles {
             role.validate()?;
         }
-        Ok(())
+        self.scopes.validate()
     }
 }
 
@@ -1001,6 +1540,47 @@ impl PrincipalRoleConfig {
     }
 }
 
+/// Least-privilege scopes narrowing what a principal may call.
+///
+/// Scopes only narrow access granted by the server-wide auth policy; the
+/// default value is unrestricted.
+#[derive(Debug, Clone, Default, Deserialize)]
+pub struct PrincipalScopesConfig {
+    /// Restrict the principal to read-only tools.
+    #[serde(default)]
+    pub read_only: bool,
+    /// Optional per-principal tool allowlist (empty permits all tools).
+    #[serde(default)]
+    pub allowed_tools: Vec<String>,
+    /// Optional tenant allowlist (empty permits all tenants).
+    #[serde(default)]
+    pub tenant_ids: Vec<TenantId>,
+}
+
+impl PrincipalScopesConfig {
+    /// Validates principal scope constraints.
+    fn validate(&self) -> Result<(), ConfigError> {
+        if self.allowed_tools.len() > MAX_AUTH_TOOL_RULES {
+            return Err(ConfigError::Invalid(
+                "auth.principals.scopes.allowed_tools exceeds max entries".to_string(),
+            ));
+        }
+        for tool_name in &self.allowed_tools {
+            if ToolName::parse(tool_name).is_none() {
+                return Err(ConfigError::Invalid(format!(
+                    "unknown tool in auth.principals.scopes.allowed_tools: {tool_name}"
+                )));
+            }
+        }
+        if self.tenant_ids.len() > MAX_PRINCIPAL_SCOPE_TENANTS {
+            return Err(ConfigError::Invalid(
+                "auth.principals.scopes.tenant_ids exceeds max entries".to_string(),
+            ));
+        }
+        Ok(())
+    }
+}
+
 /// Trust configuration for evidence providers.
 #[derive(Debug, Clone, Deserialize)]
 pub struct TrustConfig {
@@ -1231,6 +1811,9 @@ pub struct EvidencePolicyConfig {
     /// Require provider opt-in for raw value disclosure.
     #[serde(default = "default_require_provider_opt_in")]
     pub require_provider_opt_in: bool,
+    /// Default evidence query timeout applied to every provider (unset disables).
+    #[serde(default)]
+    pub query_timeout_ms: Option<u64>,
 }
 
 /// Documentation search and resources configuration.
@@ -1315,10 +1898,26 @@ impl Default for EvidencePolicyConfig {
         Self {
             allow_raw_values: false,
             require_provider_opt_in: true,
+            query_timeout_ms: None,
         }
     }
 }
 
+impl EvidencePolicyConfig {
+    /// Validates evidence policy configuration.
+    fn validate(&self) -> Result<(), ConfigError> {
+        if let Some(timeout_ms) = self.query_timeout_ms {
+            validate_timeout_range(
+                "evidence.query_timeout_ms",
+                timeout_ms,
+                MIN_EVIDENCE_QUERY_TIMEOUT_MS,
+                MAX_EVIDENCE_QUERY_TIMEOUT_MS,
+            )?;
+        }
+        Ok(())
+    }
+}
+
 /// Provider contract discovery configuration.
 #[derive(Debug, Clone, Deserialize)]
 pub struct ProviderDiscoveryConfig {
@@ -1328,7 +1927,7 @@ pub struct ProviderDiscoveryConfig {
     /// Provider denylist for contract/schema disclosure.
     #[serde(default)]
     pub denylist: Vec<String>,
-    /// Maximum response size for provider discovery tools.
+    /// Maximum response size for provider discovery tools and `tools/describe`.
     #[serde(default = "default_provider_discovery_max_bytes")]
     pub max_response_bytes: usize,
 }
@@ -1484,6 +2083,9 @@ pub struct ValidationConfig {
     /// Enable deep equality comparator family.
     #[serde(default)]
     pub enable_deep_equals: bool,
+    /// Validate tool responses against contract output schemas (debug aid).
+    #[serde(default)]
+    pub validate_tool_outputs: bool,
 }
 
 impl Default for ValidationConfig {
@@ -1494,6 +2096,7 @@ impl Default for ValidationConfig {
             allow_permissive: false,
             enable_lexicographic: false,
             enable_deep_equals: false,
+            validate_tool_outputs: false,
         }
     }
 }
@@ -1540,6 +2143,18 @@ pub struct RunStateStoreConfig {
     /// Optional max versions to retain per run.
     #[serde(default)]
     pub max_versions: Option<u64>,
+    /// Optional circuit breaker that fails store calls fast while unhealthy.
+    #[serde(default)]
+    pub circuit_breaker: Option<StoreCircuitBreakerConfig>,
+    /// Optional in-memory cache of latest run states in front of the store.
+    #[serde(default)]
+    pub cache: Option<RunStateCacheConfig>,
+    /// Per-tenant/namespace version retention policies.
+    #[serde(default)]
+    pub retention: Vec<SqliteRetentionPolicy>,
+    /// Interval between background retention passes in milliseconds.
+    #[serde(default = "default_store_retention_interval_ms")]
+    pub retention_interval_ms: u64,
 }
 
 impl Default for RunStateStoreConfig {
@@ -1551,6 +2166,10 @@ impl Default for RunStateStoreConfig {
             journal_mode: SqliteStoreMode::default(),
             sync_mode: SqliteSyncMode::default(),
             max_versions: None,
+            circuit_breaker: None,
+            cache: None,
+            retention: Vec::new(),
+            retention_interval_ms: default_store_retention_interval_ms(),
         }
     }
 }
@@ -1558,6 +2177,12 @@ impl Default for RunStateStoreConfig {
 impl RunStateStoreConfig {
     /// Validates run state store configuration.
     fn validate(&self) -> Result<(), ConfigError> {
+        if let Some(circuit_breaker) = &self.circuit_breaker {
+            circuit_breaker.validate()?;
+        }
+        if let Some(cache) = &self.cache {
+            cache.validate()?;
+        }
         match self.store_type {
             RunStateStoreType::Memory => {
                 if self.path.is_some() {
@@ -1565,6 +2190,11 @@ impl RunStateStoreConfig {
                         "memory run_state_store must not set path".to_string(),
                     ));
                 }
+                if !self.retention.is_empty() {
+                    return Err(ConfigError::Invalid(
+                        "memory run_state_store does not support retention".to_string(),
+                    ));
+                }
                 Ok(())
             }
             RunStateStoreType::Sqlite => {
@@ -1577,12 +2207,157 @@ impl RunStateStoreConfig {
                         "run_state_store max_versions must be greater than zero".to_string(),
                     ));
                 }
+                validate_retention_policies(&self.retention).map_err(|err| match err {
+                    SqliteStoreError::Invalid(message) => {
+                        ConfigError::Invalid(format!("run_state_store {message}"))
+                    }
+                    other => ConfigError::Invalid(other.to_string()),
+                })?;
+                if self.retention_interval_ms < MIN_STORE_RETENTION_INTERVAL_MS
+                    || self.retention_interval_ms > MAX_STORE_RETENTION_INTERVAL_MS
+                {
+                    return Err(ConfigError::Invalid(format!(
+                        "run_state_store retention_interval_ms must be between \
+                         {MIN_STORE_RETENTION_INTERVAL_MS} and {MAX_STORE_RETENTION_INTERVAL_MS}",
+                    )));
+                }
                 Ok(())
             }
         }
     }
 }
 
+/// Circuit breaker settings for run state store access.
+///
+/// # Invariants
+/// - The breaker opens after `failure_threshold` consecutive failed or slow calls, rejects calls
+///   for `open_ms`, then closes after `success_threshold` consecutive successful probes.
+#[derive(Debug, Clone, Deserialize)]
+pub struct StoreCircuitBreakerConfig {
+    /// Consecutive failures that open the breaker.
+    #[serde(default = "default_store_breaker_failure_threshold")]
+    pub failure_threshold: u32,
+    /// Time the breaker stays open before probing, in milliseconds.
+    #[serde(default = "default_store_breaker_open_ms")]
+    pub open_ms: u64,
+    /// Consecutive successful probes that close the breaker.
+    #[serde(default = "default_store_breaker_success_threshold")]
+    pub success_threshold: u32,
+    /// Optional call duration, in milliseconds, counted as a failure.
+    #[serde(default)]
+    pub slow_call_ms: Option<u64>,
+}
+
+impl Default for StoreCircuitBreakerConfig {
+    fn default() -> Self {
+        Self {
+            failure_threshold: default_store_breaker_failure_threshold(),
+            open_ms: default_store_breaker_open_ms(),
+            success_threshold: default_store_breaker_success_threshold(),
+            slow_call_ms: None,
+        }
+    }
+}
+
+impl StoreCircuitBreakerConfig {
+    /// Validates circuit breaker settings.
+    fn validate(&self) -> Result<(), ConfigError> {
+        if self.failure_threshold == 0
+            || self.failure_threshold > MAX_STORE_BREAKER_FAILURE_THRESHOLD
+        {
+            return Err(ConfigError::Invalid(format!(
+                "run_state_store.circuit_breaker failure_threshold must be between 1 and \
+                 {MAX_STORE_BREAKER_FAILURE_THRESHOLD}",
+            )));
+        }
+        if self.open_ms < MIN_STORE_BREAKER_OPEN_MS || self.open_ms > MAX_STORE_BREAKER_OPEN_MS {
+            return Err(ConfigError::Invalid(format!(
+                "run_state_store.circuit_breaker open_ms must be between \
+                 {MIN_STORE_BREAKER_OPEN_MS} and {MAX_STORE_BREAKER_OPEN_MS}",
+            )));
+        }
+        if self.success_threshold == 0
+            || self.success_threshold > MAX_STORE_BREAKER_SUCCESS_THRESHOLD
+        {
+            return Err(ConfigError::Invalid(format!(
+                "run_state_store.circuit_breaker success_threshold must be between 1 and \
+                 {MAX_STORE_BREAKER_SUCCESS_THRESHOLD}",
+            )));
+        }
+        if let Some(slow_call_ms) = self.slow_call_ms
+            && (slow_call_ms == 0 || slow_call_ms > MAX_STORE_BREAKER_SLOW_CALL_MS)
+        {
+            return Err(ConfigError::Invalid(format!(
+                "run_state_store.circuit_breaker slow_call_ms must be between 1 and \
+                 {MAX_STORE_BREAKER_SLOW_CALL_MS}",
+            )));
+        }
+        Ok(())
+    }
+}
+
+/// In-memory run state cache settings.
+///
+/// # Invariants
+/// - The cache assumes this server is the only writer to the backing store.
+/// - Write-back entries are persisted every `flush_interval_ms`, on eviction, and when the server
+///   shuts down.
+#[derive(Debug, Clone, Deserialize)]
+pub struct RunStateCacheConfig {
+    /// Write policy for cached run states.
+    #[serde(default)]
+    pub mode: RunStateCacheMode,
+    /// Maximum cached run states.
+    #[serde(default = "default_run_state_cache_max_entries")]
+    pub max_entries: usize,
+    /// Write-back flush interval in milliseconds.
+    #[serde(default = "default_run_state_cache_flush_interval_ms")]
+    pub flush_interval_ms: u64,
+}
+
+impl Default for RunStateCacheConfig {
+    fn default() -> Self {
+        Self {
+            mode: RunStateCacheMode::default(),
+            max_entries: default_run_state_cache_max_entries(),
+            flush_interval_ms: default_run_state_cache_flush_interval_ms(),
+        }
+    }
+}
+
+impl RunStateCacheConfig {
+    /// Validates run state cache settings.
+    fn validate(&self) -> Result<(), ConfigError> {
+        if self.max_entries == 0 || self.max_entries > MAX_RUN_STATE_CACHE_ENTRIES {
+            return Err(ConfigError::Invalid(format!(
+                "run_state_store.cache max_entries must be between 1 and \
+                 {MAX_RUN_STATE_CACHE_ENTRIES}",
+            )));
+        }
+        if self.flush_interval_ms < MIN_RUN_STATE_CACHE_FLUSH_INTERVAL_MS
+            || self.flush_interval_ms > MAX_RUN_STATE_CACHE_FLUSH_INTERVAL_MS
+        {
+            return Err(ConfigError::Invalid(format!(
+                "run_state_store.cache flush_interval_ms must be between \
+                 {MIN_RUN_STATE_CACHE_FLUSH_INTERVAL_MS} and \
+                 {MAX_RUN_STATE_CACHE_FLUSH_INTERVAL_MS}",
+            )));
+        }
+        Ok(())
+    }
+}
+
+/// Run state cache write policy.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Deserialize, Default)]
+#[serde(rename_all = "snake_case")]
+pub enum RunStateCacheMode {
+    /// Persist every save before updating the cache.
+    #[default]
+    WriteThrough,
+    /// Cache saves and persist them on a schedule.
+    WriteBack,
+}
+
 /// Run state store backend type.
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Deserialize, Default)]
 #[serde(rename_all = "snake_case")]
@@ -1835,7 +2610,7 @@ pub enum TrustPolicy {
     },
 }
 
-/// Timeout configuration for MCP provider HTTP requests.
+/// Timeout configuration for MCP provider HTTP requests and evidence queries.
 #[derive(Debug, Clone, Deserialize)]
 pub struct ProviderTimeoutConfig {
     /// Maximum time to establish the HTTP connection.
@@ -1844,6 +2619,9 @@ pub struct ProviderTimeoutConfig {
     /// Maximum end-to-end request time (connect + body).
     #[serde(default = "default_provider_request_timeout_ms")]
     pub request_timeout_ms: u64,
+    /// Evidence query timeout overriding `evidence.query_timeout_ms` for this provider.
+    #[serde(default)]
+    pub query_timeout_ms: Option<u64>,
 }
 
 impl Default for ProviderTimeoutConfig {
@@ -1851,6 +2629,7 @@ impl Default for ProviderTimeoutConfig {
         Self {
             connect_timeout_ms: default_provider_connect_timeout_ms(),
             request_timeout_ms: default_provider_request_timeout_ms(),
+            query_timeout_ms: None,
         }
     }
 }
@@ -1875,6 +2654,14 @@ impl ProviderTimeoutConfig {
                 "providers.timeouts.request_timeout_ms must be >= connect_timeout_ms".to_string(),
             ));
         }
+        if let Some(timeout_ms) = self.query_timeout_ms {
+            validate_timeout_range(
+                "providers.timeouts.query_timeout_ms",
+                timeout_ms,
+                MIN_EVIDENCE_QUERY_TIMEOUT_MS,
+                MAX_EVIDENCE_QUERY_TIMEOUT_MS,
+            )?;
+        }
         Ok(())
     }
 }
@@ -1908,7 +2695,7 @@ pub struct ProviderConfig {
     /// Provider opt-in for raw evidence disclosure.
     #[serde(default)]
     pub allow_raw: bool,
-    /// Provider timeout overrides (HTTP MCP providers).
+    /// Provider timeout overrides (HTTP and query timeouts).
     #[serde(default)]
     pub timeouts: ProviderTimeoutConfig,
     /// Provider-specific configuration blob for built-ins.
@@ -2074,6 +2861,13 @@ fn resolve_path(path: Option<&Path>) -> Result<PathBuf, ConfigError> {
     Ok(PathBuf::from(DEFAULT_CONFIG_NAME))
 }
 
+/// Reads the configuration file as UTF-8 text with a hard size limit.
+fn read_config_text(path: &Path) -> Result<String, ConfigError> {
+    let bytes = read_config_bytes(path)?;
+    String::from_utf8(bytes)
+        .map_err(|_| ConfigError::Invalid("config file must be utf-8".to_string()))
+}
+
 /// Reads the configuration file with a hard size limit.
 fn read_config_bytes(path: &Path) -> Result<Vec<u8>, ConfigError> {
     let file = fs::File::open(path).map_err(|err| ConfigError::Io(err.to_string()))?;
@@ -2221,6 +3015,36 @@ pub(crate) const fn default_max_inflight() -> usize {
     DEFAULT_MAX_INFLIGHT
 }
 
+/// Default SSE heartbeat interval in milliseconds.
+pub(crate) const fn default_sse_heartbeat_interval_ms() -> u64 {
+    DEFAULT_SSE_HEARTBEAT_INTERVAL_MS
+}
+
+/// Default SSE stream poll interval in milliseconds.
+pub(crate) const fn default_sse_poll_interval_ms() -> u64 {
+    DEFAULT_SSE_POLL_INTERVAL_MS
+}
+
+/// Default per-consumer SSE event buffer.
+pub(crate) const fn default_sse_stream_buffer() -> usize {
+    DEFAULT_SSE_STREAM_BUFFER
+}
+
+/// Default to serving the live `OpenAPI` document.
+pub(crate) const fn default_openapi_enabled() -> bool {
+    true
+}
+
+/// Default to requiring server auth for the live `OpenAPI` document.
+pub(crate) const fn default_openapi_require_auth() -> bool {
+    true
+}
+
+/// Default `Cache-Control` max-age for the live `OpenAPI` document.
+pub(crate) const fn default_openapi_max_age_secs() -> u64 {
+    DEFAULT_OPENAPI_MAX_AGE_SECS
+}
+
 /// Default max requests per rate limit window.
 pub(crate) const fn default_rate_limit_max_requests() -> u32 {
     DEFAULT_RATE_LIMIT_MAX_REQUESTS
@@ -2306,11 +3130,41 @@ pub(crate) const fn default_doc_max_sections() -> u32 {
     DEFAULT_DOC_MAX_SECTIONS
 }
 
+/// Default consecutive store failures before the circuit breaker opens.
+pub(crate) const fn default_store_breaker_failure_threshold() -> u32 {
+    DEFAULT_STORE_BREAKER_FAILURE_THRESHOLD
+}
+
+/// Default circuit breaker open duration in milliseconds.
+pub(crate) const fn default_store_breaker_open_ms() -> u64 {
+    DEFAULT_STORE_BREAKER_OPEN_MS
+}
+
+/// Default successful probes required to close the circuit breaker.
+pub(crate) const fn default_store_breaker_success_threshold() -> u32 {
+    DEFAULT_STORE_BREAKER_SUCCESS_THRESHOLD
+}
+
+/// Default cached run states.
+pub(crate) const fn default_run_state_cache_max_entries() -> usize {
+    DEFAULT_RUN_STATE_CACHE_ENTRIES
+}
+
+/// Default write-back flush interval in milliseconds.
+pub(crate) const fn default_run_state_cache_flush_interval_ms() -> u64 {
+    DEFAULT_RUN_STATE_CACHE_FLUSH_INTERVAL_MS
+}
+
 /// Default busy timeout for the `SQLite` store (ms).
 pub(crate) const fn default_store_busy_timeout_ms() -> u64 {
     5_000
 }
 
+/// Default retention maintenance interval for the run state store (ms).
+pub(crate) const fn default_store_retention_interval_ms() -> u64 {
+    DEFAULT_STORE_RETENTION_INTERVAL_MS
+}
+
 /// Default max schema size for registry payloads.
 pub(crate) const fn default_schema_max_bytes() -> usize {
     DEFAULT_SCHEMA_MAX_BYTES
@@ -2356,6 +3210,46 @@ pub(crate) const fn default_namespace_auth_request_timeout_ms() -> u64 {
     DEFAULT_NAMESPACE_AUTH_REQUEST_TIMEOUT_MS
 }
 
+/// Default accepted JWT signature algorithms.
+pub(crate) fn default_jwt_algorithms() -> Vec<JwtAlgorithm> {
+    vec![JwtAlgorithm::Rs256, JwtAlgorithm::Es256]
+}
+
+/// Default JWKS fetch timeout in milliseconds.
+pub(crate) const fn default_jwks_timeout_ms() -> u64 {
+    2_000
+}
+
+/// Default JWKS cache lifetime in milliseconds.
+pub(crate) const fn default_jwks_cache_ttl_ms() -> u64 {
+    300_000
+}
+
+/// Default tolerated JWT clock skew in seconds.
+pub(crate) const fn default_jwt_clock_skew_secs() -> u64 {
+    60
+}
+
+/// Extracts the lowercased host from an http(s) URL.
+///
+/// Returns `None` when the URL carries userinfo or has an empty host.
+fn url_host(url: &str) -> Option<String> {
+    let (_, rest) = url.split_once("://")?;
+    let authority = rest.split(['/', '?', '#']).next()?;
+    if authority.contains('@') {
+        return None;
+    }
+    let host = if let Some(bracketed) = authority.strip_prefix('[') {
+        bracketed.split_once(']')?.0
+    } else {
+        authority.rsplit_once(':').map_or(authority, |(host, _)| host)
+    };
+    if host.is_empty() {
+        return None;
+    }
+    Some(host.to_ascii_lowercase())
+}
+
 /// Validates a timeout value against bounds.
 fn validate_timeout_range(
     field: &str,
@@ -2824,6 +3718,70 @@ mod tests {
         assert!(result.is_err(), "whitespace-only tool name should fail");
     }
 
+    // ============================================================================
+    // SECTION: ServerSseConfig::validate() Tests (4 tests)
+    // ============================================================================
+
+    #[test]
+    fn server_sse_validate_default_passes() {
+        assert!(
+            ServerSseConfig::default().validate().is_ok(),
+            "default ServerSseConfig should pass"
+        );
+    }
+
+    #[test]
+    fn server_sse_validate_heartbeat_below_minimum_rejected() {
+        let config = ServerSseConfig {
+            heartbeat_interval_ms: MIN_SSE_HEARTBEAT_INTERVAL_MS - 1,
+            ..ServerSseConfig::default()
+        };
+        let err = config.validate().unwrap_err().to_string();
+        assert!(err.contains("server.sse.heartbeat_interval_ms"), "error should name the field");
+    }
+
+    #[test]
+    fn server_sse_validate_poll_above_maximum_rejected() {
+        let config = ServerSseConfig {
+            poll_interval_ms: MAX_SSE_POLL_INTERVAL_MS + 1,
+            ..ServerSseConfig::default()
+        };
+        assert!(config.validate().is_err(), "poll interval above maximum should fail");
+    }
+
+    #[test]
+    fn server_sse_validate_stream_buffer_bounds() {
+        for stream_buffer in [0, MAX_SSE_STREAM_BUFFER + 1] {
+            let config = ServerSseConfig {
+                stream_buffer,
+                ..ServerSseConfig::default()
+            };
+            assert!(config.validate().is_err(), "stream_buffer {stream_buffer} should fail");
+        }
+    }
+
+    // ============================================================================
+    // SECTION: ServerOpenApiConfig::validate() Tests (2 tests)
+    // ============================================================================
+
+    #[test]
+    fn server_openapi_validate_default_passes() {
+        assert!(
+            ServerOpenApiConfig::default().validate().is_ok(),
+            "default ServerOpenApiConfig should pass"
+        );
+    }
+
+    #[test]
+    fn server_openapi_validate_max_age_above_maximum_rejected() {
+        let config = ServerOpenApiConfig {
+            max_age_secs: MAX_OPENAPI_MAX_AGE_SECS + 1,
+            ..ServerOpenApiConfig::default()
+        };
+        let err = config.validate().unwrap_err().to_string();
+        assert!(err.contains("server.openapi.max_age_secs"), "error should name the field");
+    }
+
     // ============================================================================
     // SECTION: validate_timeout_range() Tests (18 tests)
     // ============================================================================
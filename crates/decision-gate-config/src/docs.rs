@@ -82,6 +82,32 @@ pub fn config_docs_markdown() -> Result<String, DocsError> {
     out.push_str("disclosure defaults, and provider registry. All inputs are validated and\n");
     out.push_str("fail closed on errors.\n\n");
 
+    out.push_str("## Profile Overlays\n\n");
+    out.push_str("Select a profile with `--profile <name>` or `DG_PROFILE=<name>` to deep-merge\n");
+    out.push_str("`<stem>.<name>.toml` (next to the base file) onto the base config before\n");
+    out.push_str("validation. Profile names use `[a-z0-9_-]` (max 64 chars); a missing overlay\n");
+    out.push_str("file fails closed.\n\n");
+    out.push_str("- Tables merge recursively; scalars are replaced by the overlay value.\n");
+    out.push_str("- Arrays are replaced unless listed in `[overlay].append_arrays` (dotted key\n");
+    out.push_str("  paths), in which case overlay items are appended after base items.\n\n");
+    out.push_str("```toml\n");
+    out.push_str("# decision-gate.prod.toml\n");
+    out.push_str("[overlay]\n");
+    out.push_str("append_arrays = [\"providers\"]\n\n");
+    out.push_str("[server]\n");
+    out.push_str("max_body_bytes = 2097152\n");
+    out.push_str("```\n\n");
+
+    out.push_str("## Secret References\n\n");
+    out.push_str("`server.auth.bearer_tokens`, `namespace.authority.assetcore.auth_token`, and\n");
+    out.push_str("`providers[].auth.bearer_token` accept secret references resolved at load\n");
+    out.push_str("time, after parsing and before validation:\n\n");
+    out.push_str("- `secret://env/NAME` reads environment variable `NAME`.\n");
+    out.push_str("- `secret://file/<path>` reads absolute file `/<path>` (max 64 KiB; one\n");
+    out.push_str("  trailing newline is stripped).\n\n");
+    out.push_str("Missing, unreadable, or empty secrets fail closed. Errors name the reference,\n");
+    out.push_str("never the resolved value.\n\n");
+
     out.push_str("## Top-Level Sections\n\n");
 
     let sections = build_sections();
@@ -112,12 +138,25 @@ pub fn config_docs_markdown() -> Result<String, DocsError> {
     );
     out.push_str(
         "- `json`:\n  - `root` (string)\n  - `root_id` (string)\n  - `max_bytes` (integer)\n  - \
-         `allow_yaml` (bool)\n",
+         `allow_yaml` (bool)\n  - `max_jsonpath_nodes` (integer)\n",
     );
     out.push_str(
         "- `http`:\n  - `allow_http` (bool)\n  - `timeout_ms` (integer)\n  - `max_response_bytes` \
          (integer)\n  - `allowed_hosts` (array)\n  - `user_agent` (string)\n  - `hash_algorithm` \
-         (string)\n",
+         (string)\n  - `query_templates` (table)\n  - `max_pages` (integer)\n  - \
+         `max_total_bytes` (integer)\n  - `response_transforms` (table)\n  - `max_retries` \
+         (integer)\n  - `base_delay_ms` (integer)\n  - `max_delay_ms` (integer)\n  - \
+         `retry_deadline_ms` (integer)\n  - `cache_max_entries` (integer)\n  - `cache_ttl_ms` \
+         (integer)\n",
+    );
+    out.push_str(
+        "- `sqlite`:\n  - `path` (string)\n  - `queries` (table)\n  - `max_rows` (integer)\n  - \
+         `max_value_bytes` (integer)\n  - `timeout_ms` (integer)\n",
+    );
+    out.push_str(
+        "- `postgres` (requires the `postgres` feature):\n  - `dsn_env` (string)\n  - `queries` \
+         (table)\n  - `max_rows` (integer)\n  - `max_value_bytes` (integer)\n  - \
+         `statement_timeout_ms` (integer)\n  - `connect_timeout_ms` (integer)\n",
     );
 
     Ok(out)
@@ -217,6 +256,9 @@ fn build_sections() -> Vec<SectionSpec> {
                 "audit",
                 "feedback",
                 "tools",
+                "sse",
+                "openapi",
+                "provider_warm_up",
             ],
             include_required: false,
             default_overrides: &[
@@ -228,6 +270,8 @@ fn build_sections() -> Vec<SectionSpec> {
                     field: "tools",
                     default_value: "{ mode = \"filter\", allowlist = [], denylist = [] }",
                 },
+                FieldOverride { field: "sse", default_value: "{ heartbeat_interval_ms = 15000 }" },
+                FieldOverride { field: "openapi", default_value: "{ enabled = true }" },
             ],
             extra: Some(
                 "HTTP/SSE require `bind`; non-loopback requires explicit CLI opt-in plus TLS \
@@ -238,10 +282,18 @@ or `tls_termination = \"upstream\"` + non-local auth.",
             heading: "[server.auth]",
             description: "Inbound authn/authz for MCP tool calls.",
             path: &[SchemaPath::Property("server"), SchemaPath::Property("auth")],
-            fields: &["mode", "bearer_tokens", "mtls_subjects", "allowed_tools", "principals"],
+            fields: &[
+                "mode",
+                "bearer_tokens",
+                "jwt",
+                "mtls_subjects",
+                "allowed_tools",
+                "principals",
+            ],
             include_required: false,
             default_overrides: &[
                 FieldOverride { field: "bearer_tokens", default_value: "[]" },
+                FieldOverride { field: "jwt", default_value: "null" },
                 FieldOverride { field: "mtls_subjects", default_value: "[]" },
                 FieldOverride { field: "allowed_tools", default_value: "[]" },
                 FieldOverride { field: "principals", default_value: "[]" },
@@ -250,6 +302,82 @@ or `tls_termination = \"upstream\"` + non-local auth.",
                 "Bearer token example:\n\n```toml\n[server.auth]\nmode = \"bearer_token\"\nbearer_tokens = [\"token-1\", \"token-2\"]\nallowed_tools = [\"scenario_define\", \"scenario_start\", \"scenario_next\"]\n```\n\nmTLS subject example (via trusted proxy header):\n\n```toml\n[server.auth]\nmode = \"mtls\"\nmtls_subjects = [\"CN=decision-gate-client,O=Example Corp\"]\n```\n\nWhen using `mtls` mode, the server expects the `x-decision-gate-client-subject` header from a trusted TLS-terminating proxy.\n\nPrincipal mapping example (registry ACL):\n\n```toml\n[[server.auth.principals]]\nsubject = \"loopback\"\npolicy_class = \"prod\"\n\n[[server.auth.principals.roles]]\nname = \"TenantAdmin\"\ntenant_id = 1\nnamespace_id = 1\n```\n\nBuilt-in registry ACL expects `policy_class` values like `prod`, `project`, or `scratch` (case-insensitive). Unknown values are treated as `prod`.",
             ),
         },
+        SectionSpec {
+            heading: "[server.auth.jwt]",
+            description: "JWT bearer validation against a JWKS endpoint.",
+            path: &[
+                SchemaPath::Property("server"),
+                SchemaPath::Property("auth"),
+                SchemaPath::Property("jwt"),
+            ],
+            fields: &[
+                "issuer",
+                "audience",
+                "jwks_url",
+                "allowed_jwks_hosts",
+                "allow_http",
+                "algorithms",
+                "jwks_timeout_ms",
+                "jwks_cache_ttl_ms",
+                "clock_skew_secs",
+            ],
+            include_required: true,
+            default_overrides: &[FieldOverride {
+                field: "algorithms",
+                default_value: "[\"RS256\", \"ES256\"]",
+            }],
+            extra: Some(
+                "Requires `mode = \"bearer_token\"`. Bearer credentials that do not match a \
+                 static `bearer_tokens` entry are validated as compact JWS tokens: signature \
+                 against the JWKS key named by `kid`, `iss`, `aud`, `exp`, and `nbf`. Any \
+                 failure rejects the request. The caller subject is the `sub` claim, so \
+                 `[[server.auth.principals]]` entries keyed by `sub` attach roles and scopes. \
+                 Subjects without a principal entry get scopes from the `scope` claim: \
+                 `dg:read` (read-only tools), `dg:write` (all tools), `dg:tool:<name>` (tool \
+                 allowlist), and `dg:tenant:<id>` or `dg:tenant:*` (tenants). Scopes are \
+                 additive: a `dg:tool:<name>` scope adds that tool, even when it mutates state \
+                 such as `dg:tool:scenario_submit`, to the tools `dg:read` or `dg:write` \
+                 already permit. Without a `dg:read`, `dg:write`, or `dg:tool:` scope no \
+                 tool is permitted, and without a `dg:tenant:` scope no tenant is. Keys are \
+                 cached for `jwks_cache_ttl_ms`; an unknown `kid` triggers an early refetch at \
+                 most every 30 seconds to pick up key rotation. JWKS fetches never follow \
+                 redirects.\n\n```toml\n[server.auth]\nmode = \"bearer_token\"\n\n\
+                 [server.auth.jwt]\nissuer = \"https://idp.example.com\"\naudience = \
+                 \"decision-gate\"\njwks_url = \"https://idp.example.com/.well-known/jwks.json\"\n\
+                 allowed_jwks_hosts = [\"idp.example.com\"]\n```",
+            ),
+        },
+        SectionSpec {
+            heading: "[server.auth.principals.scopes]",
+            description: "Least-privilege tool and tenant scopes for a principal.",
+            path: &[
+                SchemaPath::Property("server"),
+                SchemaPath::Property("auth"),
+                SchemaPath::Property("principals"),
+                SchemaPath::Items,
+                SchemaPath::Property("scopes"),
+            ],
+            fields: &["read_only", "allowed_tools", "tenant_ids"],
+            include_required: false,
+            default_overrides: &[
+                FieldOverride { field: "allowed_tools", default_value: "[]" },
+                FieldOverride { field: "tenant_ids", default_value: "[]" },
+            ],
+            extra: Some(
+                "Scopes narrow access for the principal whose `subject` matches the caller \
+                 (`stdio`, `loopback`, the mTLS subject, or `token:<sha256>` for bearer tokens); \
+                 they never widen `server.auth.allowed_tools`. Read-only principals may only call \
+                 tools that do not mutate state (`scenario_status`, `scenarios_list`, \
+                 `schemas_list`, `schemas_get`, `evidence_query`, `precheck`, `providers_list`, \
+                 `provider_contract_get`, `provider_check_schema_get`, `runpack_verify`, \
+                 `decision_gate_docs_search`, `scenarios_status_bulk`). `tenant_ids` applies to \
+                 tool calls that carry a tenant identifier. `tools/list` hides tools outside the \
+                 caller's scopes and the `server.auth.allowed_tools` allowlist; hidden tools are \
+                 still denied if called directly.\n\n```toml\n[[server.auth.principals]]\nsubject = \
+                 \"CN=reporting,O=Example Corp\"\n\n[server.auth.principals.scopes]\nread_only = \
+                 true\ntenant_ids = [1]\n```",
+            ),
+        },
         SectionSpec {
             heading: "[server.audit]",
             description: "Structured audit logging configuration.",
@@ -314,13 +442,38 @@ or `tls_termination = \"upstream\"` + non-local auth.",
                 "Visibility is separate from auth: hidden tools are omitted from tools/list and treated as unknown when called.",
             ),
         },
+        SectionSpec {
+            heading: "[server.sse]",
+            description: "Streaming settings for the SSE transport.",
+            path: &[SchemaPath::Property("server"), SchemaPath::Property("sse")],
+            fields: &["heartbeat_interval_ms", "poll_interval_ms", "stream_buffer"],
+            include_required: false,
+            default_overrides: &[],
+            extra: Some(
+                "Applies to `GET /rpc/scenarios/stream`. Idle streams emit `heartbeat` events; consumers that fall `stream_buffer` events behind are disconnected and resume via `Last-Event-ID`.",
+            ),
+        },
+        SectionSpec {
+            heading: "[server.openapi]",
+            description: "Live OpenAPI endpoint settings for HTTP/SSE transports.",
+            path: &[SchemaPath::Property("server"), SchemaPath::Property("openapi")],
+            fields: &["enabled", "require_auth", "max_age_secs"],
+            include_required: false,
+            default_overrides: &[],
+            extra: Some(
+                "`GET /openapi.json` renders the `tools/call` OpenAPI view from the live contract, limited to tools visible under `[server.tools]` (and, with `require_auth`, the caller's auth scopes). Responses carry an `ETag` and honor `If-None-Match`.",
+            ),
+        },
         SectionSpec {
             heading: "[server.limits]",
             description: "Request concurrency and rate limits.",
             path: &[SchemaPath::Property("server"), SchemaPath::Property("limits")],
-            fields: &["max_inflight", "rate_limit"],
+            fields: &["max_inflight", "rate_limit", "tool_concurrency"],
             include_required: false,
-            default_overrides: &[FieldOverride { field: "rate_limit", default_value: "null" }],
+            default_overrides: &[
+                FieldOverride { field: "rate_limit", default_value: "null" },
+                FieldOverride { field: "tool_concurrency", default_value: "[]" },
+            ],
             extra: None,
         },
         SectionSpec {
@@ -336,14 +489,53 @@ or `tls_termination = \"upstream\"` + non-local auth.",
             default_overrides: &[],
             extra: None,
         },
+        SectionSpec {
+            heading: "[[server.limits.tool_concurrency]]",
+            description: "Per-tool concurrency limits.",
+            path: &[
+                SchemaPath::Property("server"),
+                SchemaPath::Property("limits"),
+                SchemaPath::Property("tool_concurrency"),
+                SchemaPath::Items,
+            ],
+            fields: &["tool", "max_concurrent", "max_queue_wait_ms"],
+            include_required: true,
+            default_overrides: &[],
+            extra: Some(
+                "Calls beyond `max_concurrent` wait up to `max_queue_wait_ms` for a slot, then fail with retryable error `-32072` (`inflight_limit`). Waiting calls still hold a `max_inflight` slot, and tools without an entry are unaffected.\n\n```toml\n[[server.limits.tool_concurrency]]\ntool = \"scenario_submit\"\nmax_concurrent = 4\nmax_queue_wait_ms = 250\n```",
+            ),
+        },
         SectionSpec {
             heading: "[server.tls]",
             description: "TLS configuration for HTTP/SSE transports.",
             path: &[SchemaPath::Property("server"), SchemaPath::Property("tls")],
-            fields: &["cert_path", "key_path", "client_ca_path", "require_client_cert"],
+            fields: &[
+                "cert_path",
+                "key_path",
+                "client_ca_path",
+                "require_client_cert",
+                "reload_interval_ms",
+                "max_client_chain_depth",
+                "client_required_ekus",
+                "ocsp_response_path",
+            ],
             include_required: false,
-            default_overrides: &[FieldOverride { field: "client_ca_path", default_value: "null" }],
-            extra: None,
+            default_overrides: &[
+                FieldOverride { field: "client_ca_path", default_value: "null" },
+                FieldOverride { field: "reload_interval_ms", default_value: "null" },
+                FieldOverride { field: "max_client_chain_depth", default_value: "null" },
+                FieldOverride { field: "client_required_ekus", default_value: "[]" },
+                FieldOverride { field: "ocsp_response_path", default_value: "null" },
+            ],
+            extra: Some(
+                "When `reload_interval_ms` is set, cert/key/CA/OCSP files are polled and swapped \
+                 for new connections without dropping existing ones; invalid replacements are \
+                 rejected and the previous certificate stays active.\n\n`max_client_chain_depth` \
+                 and `client_required_ekus` (dotted OIDs, e.g. `1.3.6.1.5.5.7.3.2` for clientAuth) \
+                 require `client_ca_path`. Client certificates violating either policy fail the \
+                 TLS handshake and are recorded as `tls_client_cert_rejected` security audit \
+                 events.",
+            ),
         },
         SectionSpec {
             heading: "[dev]",
@@ -414,9 +606,9 @@ or `tls_termination = \"upstream\"` + non-local auth.",
         },
         SectionSpec {
             heading: "[evidence]",
-            description: "Evidence disclosure policy defaults.",
+            description: "Evidence disclosure and query policy defaults.",
             path: &[SchemaPath::Property("evidence")],
-            fields: &["allow_raw_values", "require_provider_opt_in"],
+            fields: &["allow_raw_values", "require_provider_opt_in", "query_timeout_ms"],
             include_required: false,
             default_overrides: &[],
             extra: None,
@@ -530,11 +722,12 @@ or `tls_termination = \"upstream\"` + non-local auth.",
                 "allow_permissive",
                 "enable_lexicographic",
                 "enable_deep_equals",
+                "validate_tool_outputs",
             ],
             include_required: false,
             default_overrides: &[],
             extra: Some(
-                "Strict validation (default):\n\n```toml\n[validation]\nstrict = true\nprofile = \"strict_core_v1\"\n```\n\nPermissive validation (explicit opt-in):\n\n```toml\n[validation]\nstrict = false\nallow_permissive = true\n```\n\nOptional comparator families:\n\n```toml\n[validation]\nenable_lexicographic = true\nenable_deep_equals = true\n```",
+                "Strict validation (default):\n\n```toml\n[validation]\nstrict = true\nprofile = \"strict_core_v1\"\n```\n\nPermissive validation (explicit opt-in):\n\n```toml\n[validation]\nstrict = false\nallow_permissive = true\n```\n\nOptional comparator families:\n\n```toml\n[validation]\nenable_lexicographic = true\nenable_deep_equals = true\n```\n\nTool params are always validated against the contract input schemas. Output validation is a debug aid that rejects schema-violating responses with an internal error:\n\n```toml\n[validation]\nvalidate_tool_outputs = true\n```",
             ),
         },
         SectionSpec {
@@ -570,16 +763,75 @@ or `tls_termination = \"upstream\"` + non-local auth.",
                 "journal_mode",
                 "sync_mode",
                 "max_versions",
+                "circuit_breaker",
+                "cache",
+                "retention",
+                "retention_interval_ms",
             ],
             include_required: false,
             default_overrides: &[
                 FieldOverride { field: "path", default_value: "null" },
                 FieldOverride { field: "max_versions", default_value: "null" },
+                FieldOverride { field: "circuit_breaker", default_value: "null" },
+                FieldOverride { field: "cache", default_value: "null" },
+                FieldOverride { field: "retention", default_value: "[]" },
             ],
             extra: Some(
                 "SQLite example:\n\n```toml\n[run_state_store]\ntype = \"sqlite\"\npath = \"decision-gate.db\"\njournal_mode = \"wal\"\nsync_mode = \"full\"\nbusy_timeout_ms = 5000\nmax_versions = 1000\n```",
             ),
         },
+        SectionSpec {
+            heading: "[run_state_store.circuit_breaker]",
+            description: "Optional circuit breaker for run state store access.",
+            path: &[
+                SchemaPath::Property("run_state_store"),
+                SchemaPath::Property("circuit_breaker"),
+            ],
+            fields: &["failure_threshold", "open_ms", "success_threshold", "slow_call_ms"],
+            include_required: false,
+            default_overrides: &[FieldOverride { field: "slow_call_ms", default_value: "null" }],
+            extra: Some(
+                "I/O and backend errors, plus calls slower than `slow_call_ms`, count as failures; invalid or corrupt data does not. While open, store calls fail immediately with retryable error `-32074` (`store_unavailable`) and `/readyz` reports not ready. After `open_ms`, one call at a time probes the store; `success_threshold` consecutive successes close the breaker and any failure reopens it.\n\n```toml\n[run_state_store.circuit_breaker]\nfailure_threshold = 5\nopen_ms = 5000\nsuccess_threshold = 2\nslow_call_ms = 2000\n```",
+            ),
+        },
+        SectionSpec {
+            heading: "[run_state_store.cache]",
+            description: "Optional in-memory cache of latest run states.",
+            path: &[SchemaPath::Property("run_state_store"), SchemaPath::Property("cache")],
+            fields: &["mode", "max_entries", "flush_interval_ms"],
+            include_required: false,
+            default_overrides: &[],
+            extra: Some(
+                "Loads are served from the cache after the first read, so the cache assumes this server is the only writer to the store. `write_through` persists every save before caching it. `write_back` acknowledges saves from memory and persists dirty runs every `flush_interval_ms`, when they are evicted, and when the server shuts down on Ctrl-C or SIGTERM; saves made since the last flush are lost if the process crashes. Every saved version is queued and written in order, so version history and the state hash chain match `write_through`; a run with 64 unflushed versions is persisted on its next save.\n\n```toml\n[run_state_store.cache]\nmode = \"write_back\"\nmax_entries = 1024\nflush_interval_ms = 1000\n```",
+            ),
+        },
+        SectionSpec {
+            heading: "[[run_state_store.retention]]",
+            description: "Per-tenant/namespace run state version retention policy.",
+            path: &[
+                SchemaPath::Property("run_state_store"),
+                SchemaPath::Property("retention"),
+                SchemaPath::Items,
+            ],
+            fields: &["tenant_id", "namespace_id", "keep_versions", "max_age_days"],
+            include_required: true,
+            default_overrides: &[
+                FieldOverride { field: "namespace_id", default_value: "null" },
+                FieldOverride { field: "keep_versions", default_value: "null" },
+                FieldOverride { field: "max_age_days", default_value: "null" },
+            ],
+            extra: Some(
+                "Requires the sqlite backend. Each policy sets `keep_versions`, `max_age_days`, or \
+                 both; a version is pruned only when every configured bound allows it. A policy \
+                 with `namespace_id` overrides the tenant-wide policy for that namespace. Pruning \
+                 removes the oldest versions first and always keeps the latest, so the version \
+                 chain stays contiguous. The MCP server applies policies every \
+                 `retention_interval_ms`; `decision-gate store gc` applies them on demand.\n\n\
+                 ```toml\n[[run_state_store.retention]]\ntenant_id = 1\nkeep_versions = \
+                 100\n\n[[run_state_store.retention]]\ntenant_id = 1\nnamespace_id = \
+                 2\nkeep_versions = 10\nmax_age_days = 30\n```",
+            ),
+        },
         SectionSpec {
             heading: "[schema_registry]",
             description: "Schema registry persistence and limits.",
@@ -696,18 +948,18 @@ or `tls_termination = \"upstream\"` + non-local auth.",
                 FieldOverride { field: "config", default_value: "null" },
             ],
             extra: Some(
-                "`auth` form:\n\n```toml\nauth = { bearer_token = \"token\" }\n```\n\n`trust` override form:\n\n```toml\ntrust = { require_signature = { keys = [\"provider.pub\"] } }\n```\n\n`capabilities_path` example for MCP providers:\n\n```toml\n[[providers]]\nname = \"mongo\"\ntype = \"mcp\"\ncommand = [\"mongo-provider\", \"--stdio\"]\ncapabilities_path = \"contracts/mongo_provider.json\"\n```\n\n`timeouts` form (HTTP MCP providers):\n\n```toml\ntimeouts = { connect_timeout_ms = 2000, request_timeout_ms = 10000 }\n```\n\nHTTP provider example with timeouts:\n\n```toml\n[[providers]]\nname = \"ci\"\ntype = \"mcp\"\nurl = \"https://ci.example.com/rpc\"\ncapabilities_path = \"contracts/ci_provider.json\"\ntimeouts = { connect_timeout_ms = 2000, request_timeout_ms = 10000 }\n```\n\nTimeout constraints:\n\n- `connect_timeout_ms` must be between 100 and 10000.\n- `request_timeout_ms` must be between 500 and 30000 and >= `connect_timeout_ms`.",
+                "`auth` form:\n\n```toml\nauth = { bearer_token = \"token\" }\n```\n\n`trust` override form:\n\n```toml\ntrust = { require_signature = { keys = [\"provider.pub\"] } }\n```\n\n`capabilities_path` example for MCP providers:\n\n```toml\n[[providers]]\nname = \"mongo\"\ntype = \"mcp\"\ncommand = [\"mongo-provider\", \"--stdio\"]\ncapabilities_path = \"contracts/mongo_provider.json\"\n```\n\n`timeouts` form (HTTP MCP providers):\n\n```toml\ntimeouts = { connect_timeout_ms = 2000, request_timeout_ms = 10000 }\n```\n\nHTTP provider example with timeouts:\n\n```toml\n[[providers]]\nname = \"ci\"\ntype = \"mcp\"\nurl = \"https://ci.example.com/rpc\"\ncapabilities_path = \"contracts/ci_provider.json\"\ntimeouts = { connect_timeout_ms = 2000, request_timeout_ms = 10000 }\n```\n\nTimeout constraints:\n\n- `connect_timeout_ms` must be between 100 and 10000.\n- `request_timeout_ms` must be between 500 and 30000 and >= `connect_timeout_ms`.\n- `query_timeout_ms` (any provider type) must be between 10 and 300000 and overrides `evidence.query_timeout_ms`.",
             ),
         },
         SectionSpec {
             heading: "[providers.timeouts]",
-            description: "Timeout overrides for HTTP MCP providers.",
+            description: "Timeout overrides for HTTP MCP providers and evidence queries.",
             path: &[
                 SchemaPath::Property("providers"),
                 SchemaPath::Items,
                 SchemaPath::Property("timeouts"),
             ],
-            fields: &["connect_timeout_ms", "request_timeout_ms"],
+            fields: &["connect_timeout_ms", "request_timeout_ms", "query_timeout_ms"],
             include_required: false,
             default_overrides: &[],
             extra: None,
@@ -22,7 +22,9 @@ pub mod config;
 pub mod docs;
 pub mod examples;
 pub mod policy;
+pub mod profile;
 pub mod schema;
+pub mod secrets;
 
 // ============================================================================
 // SECTION: Re-Exports
@@ -34,4 +36,6 @@ pub use docs::verify_config_docs;
 pub use docs::write_config_docs;
 pub use examples::config_toml_example;
 pub use policy::*;
+pub use profile::PROFILE_ENV_VAR;
 pub use schema::config_schema;
+pub use secrets::SECRET_REF_PREFIX;
@@ -0,0 +1,235 @@
+// crates/decision-gate-config/src/profile.rs
+// ============================================================================
+// Module: Decision Gate Config Profiles
+// Description: Environment profile overlays for decision-gate.toml.
+// Purpose: Deep-merge a profile overlay onto a base config before validation.
+// Dependencies: toml
+// ============================================================================
+
+//! ## Overview
+//! A profile overlay is a sibling TOML file named `<stem>.<profile>.toml`
+//! (for example `decision-gate.prod.toml`) that is deep-merged onto the base
+//! config before deserialization and validation.
+//!
+//! Merge semantics:
+//! - Tables merge recursively; keys present only in the base are preserved.
+//! - Scalars and mismatched types are replaced by the overlay value.
+//! - Arrays are replaced wholesale unless the overlay lists the dotted key path in
+//!   `[overlay].append_arrays`, in which case overlay items are appended after the base items.
+//!
+//! The reserved `[overlay]` table is consumed by the merge and never reaches
+//! the config model.
+//!
+//! Security posture: overlays are untrusted config input and are subject to
+//! the same size, path, and validation limits as the base file; see
+//! `Docs/security/threat_model.md`.
+
+// ============================================================================
+// SECTION: Imports
+// ============================================================================
+
+use std::collections::BTreeSet;
+use std::env;
+use std::path::Path;
+use std::path::PathBuf;
+
+use toml::Table;
+use toml::Value;
+
+use crate::config::ConfigError;
+
+// ============================================================================
+// SECTION: Constants
+// ============================================================================
+
+/// Environment variable used to select a config profile overlay.
+pub const PROFILE_ENV_VAR: &str = "DG_PROFILE";
+/// Maximum length of a profile name.
+pub(crate) const MAX_PROFILE_NAME_LENGTH: usize = 64;
+/// Reserved overlay table holding merge directives.
+pub(crate) const OVERLAY_DIRECTIVES_KEY: &str = "overlay";
+/// Maximum number of `append_arrays` entries in an overlay.
+pub(crate) const MAX_OVERLAY_APPEND_PATHS: usize = 64;
+/// Maximum table nesting depth traversed while merging.
+pub(crate) const MAX_OVERLAY_MERGE_DEPTH: usize = 32;
+
+// ============================================================================
+// SECTION: Types
+// ============================================================================
+
+/// Array merge behavior for a single overlay key path.
+///
+/// # Invariants
+/// - `Replace` is the default for every array not listed in `append_arrays`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ArrayMergeMode {
+    /// Overlay array replaces the base array.
+    Replace,
+    /// Overlay array items are appended after base array items.
+    Append,
+}
+
+/// Parsed merge directives from the reserved `[overlay]` table.
+#[derive(Debug, Clone, Default)]
+struct OverlayDirectives {
+    /// Dotted key paths whose arrays are appended instead of replaced.
+    append_arrays: BTreeSet<String>,
+}
+
+impl OverlayDirectives {
+    /// Returns the array merge mode for a dotted key path.
+    fn array_mode(&self, path: &str) -> ArrayMergeMode {
+        if self.append_arrays.contains(path) {
+            ArrayMergeMode::Append
+        } else {
+            ArrayMergeMode::Replace
+        }
+    }
+}
+
+// ============================================================================
+// SECTION: Public API
+// ============================================================================
+
+/// Resolves the active profile from an explicit selection or `DG_PROFILE`.
+///
+/// An explicit selection always wins over the environment.
+///
+/// # Errors
+///
+/// Returns [`ConfigError`] when the selected profile name is invalid.
+pub fn resolve_profile(explicit: Option<&str>) -> Result<Option<String>, ConfigError> {
+    let selected = explicit
+        .map_or_else(|| env::var(PROFILE_ENV_VAR).ok(), |profile| Some(profile.to_string()));
+    if let Some(profile) = &selected {
+        validate_profile_name(profile)?;
+    }
+    Ok(selected)
+}
+
+/// Returns the overlay path for `profile` next to the base config file.
+#[must_use]
+pub fn profile_overlay_path(base: &Path, profile: &str) -> PathBuf {
+    let stem = base
+        .file_stem()
+        .map_or_else(|| "decision-gate".to_string(), |stem| stem.to_string_lossy().into_owned());
+    base.with_file_name(format!("{stem}.{profile}.toml"))
+}
+
+/// Deep-merges an overlay table onto a base table.
+///
+/// The reserved `[overlay]` table is removed from the overlay and interpreted
+/// as merge directives before merging.
+///
+/// # Errors
+///
+/// Returns [`ConfigError`] when directives are malformed, an append path does
+/// not hold arrays, or nesting exceeds [`MAX_OVERLAY_MERGE_DEPTH`].
+pub fn merge_overlay(base: &mut Table, mut overlay: Table) -> Result<(), ConfigError> {
+    let directives = match overlay.remove(OVERLAY_DIRECTIVES_KEY) {
+        Some(value) => parse_directives(value)?,
+        None => OverlayDirectives::default(),
+    };
+    merge_tables(base, overlay, &directives, "", 0)
+}
+
+// ============================================================================
+// SECTION: Helpers
+// ============================================================================
+
+/// Validates a profile name for use in an overlay file name.
+fn validate_profile_name(profile: &str) -> Result<(), ConfigError> {
+    if profile.is_empty() {
+        return Err(ConfigError::Invalid("config profile must be non-empty".to_string()));
+    }
+    if profile.len() > MAX_PROFILE_NAME_LENGTH {
+        return Err(ConfigError::Invalid("config profile exceeds max length".to_string()));
+    }
+    let valid = profile
+        .chars()
+        .all(|ch| ch.is_ascii_lowercase() || ch.is_ascii_digit() || ch == '-' || ch == '_');
+    if !valid {
+        return Err(ConfigError::Invalid(
+            "config profile must contain only [a-z0-9_-]".to_string(),
+        ));
+    }
+    Ok(())
+}
+
+/// Parses the reserved `[overlay]` directives table.
+fn parse_directives(value: Value) -> Result<OverlayDirectives, ConfigError> {
+    let Value::Table(table) = value else {
+        return Err(ConfigError::Invalid("overlay directives must be a table".to_string()));
+    };
+    let mut directives = OverlayDirectives::default();
+    for (key, value) in table {
+        match key.as_str() {
+            "append_arrays" => {
+                let Value::Array(paths) = value else {
+                    return Err(ConfigError::Invalid(
+                        "overlay.append_arrays must be an array".to_string(),
+                    ));
+                };
+                if paths.len() > MAX_OVERLAY_APPEND_PATHS {
+                    return Err(ConfigError::Invalid(
+                        "overlay.append_arrays exceeds max entries".to_string(),
+                    ));
+                }
+                for path in paths {
+                    let Value::String(path) = path else {
+                        return Err(ConfigError::Invalid(
+                            "overlay.append_arrays entries must be strings".to_string(),
+                        ));
+                    };
+                    if path.trim().is_empty() || path.split('.').any(str::is_empty) {
+                        return Err(ConfigError::Invalid(
+                            "overlay.append_arrays entries must be dotted key paths".to_string(),
+                        ));
+                    }
+                    directives.append_arrays.insert(path);
+                }
+            }
+            other => {
+                return Err(ConfigError::Invalid(format!("unknown overlay directive: {other}")));
+            }
+        }
+    }
+    Ok(directives)
+}
+
+/// Recursively merges `overlay` into `base` at the given dotted path prefix.
+fn merge_tables(
+    base: &mut Table,
+    overlay: Table,
+    directives: &OverlayDirectives,
+    prefix: &str,
+    depth: usize,
+) -> Result<(), ConfigError> {
+    if depth > MAX_OVERLAY_MERGE_DEPTH {
+        return Err(ConfigError::Invalid("config overlay exceeds max nesting depth".to_string()));
+    }
+    for (key, overlay_value) in overlay {
+        let path = if prefix.is_empty() { key.clone() } else { format!("{prefix}.{key}") };
+        let mode = directives.array_mode(&path);
+        match (base.get_mut(&key), overlay_value) {
+            (Some(Value::Table(base_table)), Value::Table(overlay_table)) => {
+                merge_tables(base_table, overlay_table, directives, &path, depth + 1)?;
+            }
+            (Some(Value::Array(base_items)), Value::Array(overlay_items))
+                if mode == ArrayMergeMode::Append =>
+            {
+                base_items.extend(overlay_items);
+            }
+            (base_value, overlay_value) => {
+                let base_is_array = base_value.is_none_or(|value| value.is_array());
+                if mode == ArrayMergeMode::Append && !(base_is_array && overlay_value.is_array()) {
+                    return Err(ConfigError::Invalid(format!(
+                        "overlay.append_arrays path {path} must hold arrays"
+                    )));
+                }
+                base.insert(key, overlay_value);
+            }
+        }
+    }
+    Ok(())
+}
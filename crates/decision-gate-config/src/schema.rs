@@ -19,6 +19,7 @@
 // ============================================================================
 
 use decision_gate_core::ToolName;
+use decision_gate_store_sqlite::MAX_RETENTION_AGE_DAYS;
 use serde_json::Value;
 use serde_json::json;
 
@@ -31,22 +32,55 @@ use crate::config::MAX_DOC_MAX_BYTES;
 use crate::config::MAX_DOC_MAX_DOCS;
 use crate::config::MAX_DOC_MAX_SECTIONS;
 use crate::config::MAX_DOC_MAX_TOTAL_BYTES;
+use crate::config::MAX_EVIDENCE_QUERY_TIMEOUT_MS;
+use crate::config::MAX_JWKS_ALLOWED_HOSTS;
+use crate::config::MAX_JWKS_CACHE_TTL_MS;
+use crate::config::MAX_JWKS_TIMEOUT_MS;
+use crate::config::MAX_JWT_CLAIM_LENGTH;
+use crate::config::MAX_JWT_CLOCK_SKEW_SECS;
 use crate::config::MAX_NAMESPACE_AUTH_CONNECT_TIMEOUT_MS;
 use crate::config::MAX_NAMESPACE_AUTH_REQUEST_TIMEOUT_MS;
+use crate::config::MAX_OPENAPI_MAX_AGE_SECS;
 use crate::config::MAX_PRINCIPAL_ROLES;
+use crate::config::MAX_PRINCIPAL_SCOPE_TENANTS;
 use crate::config::MAX_PROVIDER_CONNECT_TIMEOUT_MS;
 use crate::config::MAX_PROVIDER_REQUEST_TIMEOUT_MS;
 use crate::config::MAX_RATE_LIMIT_ENTRIES;
 use crate::config::MAX_RATE_LIMIT_REQUESTS;
 use crate::config::MAX_RATE_LIMIT_WINDOW_MS;
 use crate::config::MAX_REGISTRY_ACL_RULES;
+use crate::config::MAX_RUN_STATE_CACHE_ENTRIES;
+use crate::config::MAX_RUN_STATE_CACHE_FLUSH_INTERVAL_MS;
 use crate::config::MAX_SCHEMA_MAX_BYTES;
+use crate::config::MAX_SSE_HEARTBEAT_INTERVAL_MS;
+use crate::config::MAX_SSE_POLL_INTERVAL_MS;
+use crate::config::MAX_SSE_STREAM_BUFFER;
+use crate::config::MAX_STORE_BREAKER_FAILURE_THRESHOLD;
+use crate::config::MAX_STORE_BREAKER_OPEN_MS;
+use crate::config::MAX_STORE_BREAKER_SLOW_CALL_MS;
+use crate::config::MAX_STORE_BREAKER_SUCCESS_THRESHOLD;
+use crate::config::MAX_STORE_RETENTION_INTERVAL_MS;
+use crate::config::MAX_TLS_CLIENT_CHAIN_DEPTH;
+use crate::config::MAX_TLS_EKU_OID_LENGTH;
+use crate::config::MAX_TLS_RELOAD_INTERVAL_MS;
+use crate::config::MAX_TLS_REQUIRED_EKUS;
+use crate::config::MAX_TOOL_CONCURRENCY;
+use crate::config::MAX_TOOL_QUEUE_WAIT_MS;
 use crate::config::MAX_TOOL_VISIBILITY_RULES;
+use crate::config::MIN_EVIDENCE_QUERY_TIMEOUT_MS;
+use crate::config::MIN_JWKS_CACHE_TTL_MS;
+use crate::config::MIN_JWKS_TIMEOUT_MS;
 use crate::config::MIN_NAMESPACE_AUTH_CONNECT_TIMEOUT_MS;
 use crate::config::MIN_NAMESPACE_AUTH_REQUEST_TIMEOUT_MS;
 use crate::config::MIN_PROVIDER_CONNECT_TIMEOUT_MS;
 use crate::config::MIN_PROVIDER_REQUEST_TIMEOUT_MS;
 use crate::config::MIN_RATE_LIMIT_WINDOW_MS;
+use crate::config::MIN_RUN_STATE_CACHE_FLUSH_INTERVAL_MS;
+use crate::config::MIN_SSE_HEARTBEAT_INTERVAL_MS;
+use crate::config::MIN_SSE_POLL_INTERVAL_MS;
+use crate::config::MIN_STORE_BREAKER_OPEN_MS;
+use crate::config::MIN_STORE_RETENTION_INTERVAL_MS;
+use crate::config::MIN_TLS_RELOAD_INTERVAL_MS;
 use crate::config::default_audit_enabled;
 use crate::config::default_dev_permissive_exempt_providers;
 use crate::config::default_dev_permissive_warn;
@@ -60,6 +94,9 @@ use crate::config::default_docs_enabled;
 use crate::config::default_docs_include_default;
 use crate::config::default_max_body_bytes;
 use crate::config::default_max_inflight;
+use crate::config::default_openapi_enabled;
+use crate::config::default_openapi_max_age_secs;
+use crate::config::default_openapi_require_auth;
 use crate::config::default_provider_connect_timeout_ms;
 use crate::config::default_provider_discovery_max_bytes;
 use crate::config::default_provider_request_timeout_ms;
@@ -68,9 +105,18 @@ use crate::config::default_rate_limit_max_requests;
 use crate::config::default_rate_limit_window_ms;
 use crate::config::default_registry_acl_allow_local_only;
 use crate::config::default_require_provider_opt_in;
+use crate::config::default_run_state_cache_flush_interval_ms;
+use crate::config::default_run_state_cache_max_entries;
 use crate::config::default_scenario_next_trace_subjects;
 use crate::config::default_schema_max_bytes;
+use crate::config::default_sse_heartbeat_interval_ms;
+use crate::config::default_sse_poll_interval_ms;
+use crate::config::default_sse_stream_buffer;
+use crate::config::default_store_breaker_failure_threshold;
+use crate::config::default_store_breaker_open_ms;
+use crate::config::default_store_breaker_success_threshold;
 use crate::config::default_store_busy_timeout_ms;
+use crate::config::default_store_retention_interval_ms;
 use crate::config::default_tls_require_client_cert;
 use crate::config::default_validation_strict;
 
@@ -162,7 +208,15 @@ fn server_config_schema() -> Value {
             "tls": nullable_schema(&server_tls_schema()),
             "audit": server_audit_schema(),
             "feedback": server_feedback_schema(),
-            "tools": server_tools_schema()
+            "tools": server_tools_schema(),
+            "sse": server_sse_schema(),
+            "openapi": server_openapi_schema(),
+            "provider_warm_up": {
+                "type": "string",
+                "enum": ["off", "startup", "readiness"],
+                "default": "off",
+                "description": "Evidence provider warm-up: off (lazy), startup (fail startup on error), or readiness (report not ready on error)."
+            }
         },
         "allOf": [
             {
@@ -300,6 +354,101 @@ fn server_limits_schema() -> Value {
                 ],
                 "default": null,
                 "description": "Optional rate limit configuration."
+            },
+            "tool_concurrency": {
+                "type": "array",
+                "items": tool_concurrency_schema(),
+                "default": [],
+                "description": "Per-tool concurrency limits applied on top of max_inflight."
+            }
+        },
+        "additionalProperties": false
+    })
+}
+
+/// Schema for a per-tool concurrency limit.
+fn tool_concurrency_schema() -> Value {
+    json!({
+        "type": "object",
+        "required": ["tool", "max_concurrent"],
+        "properties": {
+            "tool": {
+                "type": "string",
+                "enum": tool_name_schema()["enum"].clone(),
+                "description": "Tool name the limit applies to."
+            },
+            "max_concurrent": {
+                "type": "integer",
+                "minimum": 1,
+                "maximum": MAX_TOOL_CONCURRENCY,
+                "description": "Maximum concurrent calls of the tool."
+            },
+            "max_queue_wait_ms": {
+                "type": "integer",
+                "minimum": 0,
+                "maximum": MAX_TOOL_QUEUE_WAIT_MS,
+                "default": 0,
+                "description": "Time a call may queue for a free slot in milliseconds; 0 rejects immediately."
+            }
+        },
+        "additionalProperties": false
+    })
+}
+
+/// Schema for live `OpenAPI` endpoint settings.
+fn server_openapi_schema() -> Value {
+    json!({
+        "type": "object",
+        "description": "Live OpenAPI endpoint settings for HTTP/SSE transports.",
+        "properties": {
+            "enabled": {
+                "type": "boolean",
+                "default": default_openapi_enabled(),
+                "description": "Serve GET /openapi.json."
+            },
+            "require_auth": {
+                "type": "boolean",
+                "default": default_openapi_require_auth(),
+                "description": "Require server auth for the endpoint; when false it is auth-exempt."
+            },
+            "max_age_secs": {
+                "type": "integer",
+                "minimum": 0,
+                "maximum": MAX_OPENAPI_MAX_AGE_SECS,
+                "default": default_openapi_max_age_secs(),
+                "description": "Cache-Control max-age advertised for the document in seconds."
+            }
+        },
+        "additionalProperties": false
+    })
+}
+
+/// Schema for SSE streaming settings.
+fn server_sse_schema() -> Value {
+    json!({
+        "type": "object",
+        "description": "Streaming settings for the SSE transport.",
+        "properties": {
+            "heartbeat_interval_ms": {
+                "type": "integer",
+                "minimum": MIN_SSE_HEARTBEAT_INTERVAL_MS,
+                "maximum": MAX_SSE_HEARTBEAT_INTERVAL_MS,
+                "default": default_sse_heartbeat_interval_ms(),
+                "description": "Idle interval between heartbeat events in milliseconds."
+            },
+            "poll_interval_ms": {
+                "type": "integer",
+                "minimum": MIN_SSE_POLL_INTERVAL_MS,
+                "maximum": MAX_SSE_POLL_INTERVAL_MS,
+                "default": default_sse_poll_interval_ms(),
+                "description": "Interval between polls for new stream items in milliseconds."
+            },
+            "stream_buffer": {
+                "type": "integer",
+                "minimum": 1,
+                "maximum": MAX_SSE_STREAM_BUFFER,
+                "default": default_sse_stream_buffer(),
+                "description": "Events buffered per consumer before the consumer is dropped."
             }
         },
         "additionalProperties": false
@@ -356,6 +505,7 @@ fn server_auth_schema() -> Value {
                 "default": [],
                 "description": "Allowed bearer tokens."
             },
+            "jwt": nullable_schema(&jwt_auth_schema()),
             "mtls_subjects": {
                 "type": "array",
                 "items": schema_for_mtls_subject("mTLS subject string."),
@@ -381,7 +531,12 @@ fn server_auth_schema() -> Value {
         "allOf": [
             {
                 "if": { "properties": { "mode": { "const": "bearer_token" } } },
-                "then": { "required": ["bearer_tokens"], "properties": { "bearer_tokens": { "minItems": 1 } } }
+                "then": {
+                    "anyOf": [
+                        { "required": ["bearer_tokens"], "properties": { "bearer_tokens": { "minItems": 1 } } },
+                        { "required": ["jwt"], "properties": { "jwt": { "type": "object" } } }
+                    ]
+                }
             },
             {
                 "if": { "properties": { "mode": { "const": "mtls" } } },
@@ -392,6 +547,74 @@ fn server_auth_schema() -> Value {
     })
 }
 
+/// Schema for JWT bearer validation settings.
+fn jwt_auth_schema() -> Value {
+    json!({
+        "type": "object",
+        "required": ["issuer", "audience", "jwks_url", "allowed_jwks_hosts"],
+        "properties": {
+            "issuer": {
+                "type": "string",
+                "minLength": 1,
+                "maxLength": MAX_JWT_CLAIM_LENGTH,
+                "description": "Required `iss` claim value."
+            },
+            "audience": {
+                "type": "string",
+                "minLength": 1,
+                "maxLength": MAX_JWT_CLAIM_LENGTH,
+                "description": "Required `aud` claim value."
+            },
+            "jwks_url": {
+                "type": "string",
+                "pattern": "^https?://",
+                "description": "JWKS endpoint URL (https unless allow_http)."
+            },
+            "allowed_jwks_hosts": {
+                "type": "array",
+                "items": schema_for_non_empty_string("Permitted JWKS host."),
+                "minItems": 1,
+                "maxItems": MAX_JWKS_ALLOWED_HOSTS,
+                "description": "Hosts permitted for JWKS fetches; must include the jwks_url host."
+            },
+            "allow_http": {
+                "type": "boolean",
+                "default": false,
+                "description": "Allow non-TLS JWKS endpoints."
+            },
+            "algorithms": {
+                "type": "array",
+                "items": { "type": "string", "enum": ["RS256", "ES256"] },
+                "minItems": 1,
+                "default": ["RS256", "ES256"],
+                "description": "Accepted JWT signature algorithms."
+            },
+            "jwks_timeout_ms": {
+                "type": "integer",
+                "minimum": MIN_JWKS_TIMEOUT_MS,
+                "maximum": MAX_JWKS_TIMEOUT_MS,
+                "default": crate::config::default_jwks_timeout_ms(),
+                "description": "JWKS fetch timeout (ms)."
+            },
+            "jwks_cache_ttl_ms": {
+                "type": "integer",
+                "minimum": MIN_JWKS_CACHE_TTL_MS,
+                "maximum": MAX_JWKS_CACHE_TTL_MS,
+                "default": crate::config::default_jwks_cache_ttl_ms(),
+                "description": "JWKS cache lifetime (ms)."
+            },
+            "clock_skew_secs": {
+                "type": "integer",
+                "minimum": 0,
+                "maximum": MAX_JWT_CLOCK_SKEW_SECS,
+                "default": crate::config::default_jwt_clock_skew_secs(),
+                "description": "Tolerated clock skew for exp/nbf checks (seconds)."
+            }
+        },
+        "additionalProperties": false
+    })
+}
+
 /// Schema for principal mappings in auth configuration.
 fn principal_schema() -> Value {
     json!({
@@ -412,8 +635,40 @@ fn principal_schema() -> Value {
                 "maxItems": MAX_PRINCIPAL_ROLES,
                 "default": [],
                 "description": "Role bindings for this principal."
+            },
+            "scopes": principal_scopes_schema()
+        },
+        "additionalProperties": false
+    })
+}
+
+/// Schema for least-privilege scopes attached to principals.
+fn principal_scopes_schema() -> Value {
+    json!({
+        "type": "object",
+        "description": "Least-privilege tool and tenant scopes for this principal.",
+        "properties": {
+            "read_only": {
+                "type": "boolean",
+                "default": false,
+                "description": "Restrict the principal to read-only tools."
+            },
+            "allowed_tools": {
+                "type": "array",
+                "items": tool_name_schema(),
+                "maxItems": MAX_AUTH_TOOL_RULES,
+                "default": [],
+                "description": "Per-principal tool allowlist (empty permits all tools)."
+            },
+            "tenant_ids": {
+                "type": "array",
+                "items": schema_for_numeric_identifier("Tenant identifier."),
+                "maxItems": MAX_PRINCIPAL_SCOPE_TENANTS,
+                "default": [],
+                "description": "Tenant allowlist (empty permits all tenants)."
             }
         },
+        "default": {},
         "additionalProperties": false
     })
 }
@@ -463,6 +718,48 @@ fn server_tls_schema() -> Value {
                 "type": "boolean",
                 "default": default_tls_require_client_cert(),
                 "description": "Require client certificate for mTLS."
+            },
+            "reload_interval_ms": {
+                "oneOf": [
+                    { "type": "null" },
+                    {
+                        "type": "integer",
+                        "minimum": MIN_TLS_RELOAD_INTERVAL_MS,
+                        "maximum": MAX_TLS_RELOAD_INTERVAL_MS,
+                        "description": "Poll interval for certificate hot-reload."
+                    }
+                ],
+                "default": null
+            },
+            "max_client_chain_depth": {
+                "oneOf": [
+                    { "type": "null" },
+                    {
+                        "type": "integer",
+                        "minimum": 1,
+                        "maximum": MAX_TLS_CLIENT_CHAIN_DEPTH,
+                        "description": "Maximum client certificate chain depth (leaf included)."
+                    }
+                ],
+                "default": null
+            },
+            "client_required_ekus": {
+                "type": "array",
+                "items": {
+                    "type": "string",
+                    "pattern": "^[0-9]+(\\.[0-9]+)+$",
+                    "maxLength": MAX_TLS_EKU_OID_LENGTH
+                },
+                "maxItems": MAX_TLS_REQUIRED_EKUS,
+                "default": [],
+                "description": "Extended key usage OIDs required on client certificates."
+            },
+            "ocsp_response_path": {
+                "oneOf": [
+                    { "type": "null" },
+                    schema_for_non_empty_string("DER-encoded OCSP response stapled to the server certificate.")
+                ],
+                "default": null
             }
         },
         "additionalProperties": false
@@ -688,7 +985,7 @@ fn trust_lane_schema() -> Value {
 fn evidence_policy_schema() -> Value {
     json!({
         "type": "object",
-        "description": "Evidence disclosure policy defaults.",
+        "description": "Evidence disclosure and query policy defaults.",
         "properties": {
             "allow_raw_values": {
                 "type": "boolean",
@@ -699,6 +996,18 @@ fn evidence_policy_schema() -> Value {
                 "type": "boolean",
                 "default": default_require_provider_opt_in(),
                 "description": "Require provider opt-in for raw disclosure."
+            },
+            "query_timeout_ms": {
+                "oneOf": [
+                    { "type": "null" },
+                    {
+                        "type": "integer",
+                        "minimum": MIN_EVIDENCE_QUERY_TIMEOUT_MS,
+                        "maximum": MAX_EVIDENCE_QUERY_TIMEOUT_MS,
+                        "description": "Default evidence query timeout for every provider (ms)."
+                    }
+                ],
+                "default": null
             }
         },
         "additionalProperties": false
@@ -727,7 +1036,7 @@ fn provider_discovery_config_schema() -> Value {
                 "type": "integer",
                 "minimum": 1,
                 "default": default_provider_discovery_max_bytes(),
-                "description": "Maximum response size for provider discovery tools."
+                "description": "Maximum response size for provider discovery tools and tools/describe."
             }
         },
         "additionalProperties": false
@@ -800,6 +1109,11 @@ fn validation_config_schema() -> Value {
                 "type": "boolean",
                 "default": false,
                 "description": "Enable deep equality comparators (opt-in per schema)."
+            },
+            "validate_tool_outputs": {
+                "type": "boolean",
+                "default": false,
+                "description": "Validate tool responses against contract output schemas (debug aid; responses that fail return an internal error)."
             }
         },
         "allOf": [
@@ -990,6 +1304,139 @@ fn run_state_store_schema() -> Value {
                 ],
                 "default": null,
                 "description": "Optional max versions retained per run."
+            },
+            "circuit_breaker": {
+                "oneOf": [
+                    { "type": "null" },
+                    store_circuit_breaker_schema()
+                ],
+                "default": null,
+                "description": "Optional circuit breaker for run state store access."
+            },
+            "cache": {
+                "oneOf": [
+                    { "type": "null" },
+                    run_state_cache_schema()
+                ],
+                "default": null,
+                "description": "Optional in-memory cache of latest run states."
+            },
+            "retention": {
+                "type": "array",
+                "items": run_state_retention_schema(),
+                "default": [],
+                "description": "Per-tenant/namespace version retention policies (sqlite only)."
+            },
+            "retention_interval_ms": {
+                "type": "integer",
+                "minimum": MIN_STORE_RETENTION_INTERVAL_MS,
+                "maximum": MAX_STORE_RETENTION_INTERVAL_MS,
+                "default": default_store_retention_interval_ms(),
+                "description": "Interval between background retention passes (ms)."
+            }
+        },
+        "additionalProperties": false
+    })
+}
+
+/// Schema for a run state retention policy.
+fn run_state_retention_schema() -> Value {
+    json!({
+        "type": "object",
+        "required": ["tenant_id"],
+        "properties": {
+            "tenant_id": schema_for_numeric_identifier("Tenant the policy applies to."),
+            "namespace_id": {
+                "oneOf": [
+                    { "type": "null" },
+                    schema_for_numeric_identifier("Namespace scope; overrides the tenant-wide policy.")
+                ],
+                "default": null
+            },
+            "keep_versions": {
+                "oneOf": [
+                    { "type": "null" },
+                    { "type": "integer", "minimum": 1 }
+                ],
+                "default": null,
+                "description": "Keep at most this many of the most recent versions per run."
+            },
+            "max_age_days": {
+                "oneOf": [
+                    { "type": "null" },
+                    { "type": "integer", "minimum": 1, "maximum": MAX_RETENTION_AGE_DAYS }
+                ],
+                "default": null,
+                "description": "Keep versions saved within this many days."
+            }
+        },
+        "additionalProperties": false
+    })
+}
+
+/// Schema for run state cache settings.
+fn run_state_cache_schema() -> Value {
+    json!({
+        "type": "object",
+        "properties": {
+            "mode": {
+                "type": "string",
+                "enum": ["write_through", "write_back"],
+                "default": "write_through",
+                "description": "Write policy: persist on every save, or batch saves on a schedule."
+            },
+            "max_entries": {
+                "type": "integer",
+                "minimum": 1,
+                "maximum": MAX_RUN_STATE_CACHE_ENTRIES,
+                "default": default_run_state_cache_max_entries(),
+                "description": "Maximum cached run states."
+            },
+            "flush_interval_ms": {
+                "type": "integer",
+                "minimum": MIN_RUN_STATE_CACHE_FLUSH_INTERVAL_MS,
+                "maximum": MAX_RUN_STATE_CACHE_FLUSH_INTERVAL_MS,
+                "default": default_run_state_cache_flush_interval_ms(),
+                "description": "Write-back flush interval (ms)."
+            }
+        },
+        "additionalProperties": false
+    })
+}
+
+/// Schema for run state store circuit breaker settings.
+fn store_circuit_breaker_schema() -> Value {
+    json!({
+        "type": "object",
+        "properties": {
+            "failure_threshold": {
+                "type": "integer",
+                "minimum": 1,
+                "maximum": MAX_STORE_BREAKER_FAILURE_THRESHOLD,
+                "default": default_store_breaker_failure_threshold(),
+                "description": "Consecutive failed or slow store calls that open the breaker."
+            },
+            "open_ms": {
+                "type": "integer",
+                "minimum": MIN_STORE_BREAKER_OPEN_MS,
+                "maximum": MAX_STORE_BREAKER_OPEN_MS,
+                "default": default_store_breaker_open_ms(),
+                "description": "Time the breaker rejects calls before probing (ms)."
+            },
+            "success_threshold": {
+                "type": "integer",
+                "minimum": 1,
+                "maximum": MAX_STORE_BREAKER_SUCCESS_THRESHOLD,
+                "default": default_store_breaker_success_threshold(),
+                "description": "Consecutive successful probes that close the breaker."
+            },
+            "slow_call_ms": {
+                "oneOf": [
+                    { "type": "null" },
+                    { "type": "integer", "minimum": 1, "maximum": MAX_STORE_BREAKER_SLOW_CALL_MS }
+                ],
+                "default": null,
+                "description": "Optional store call duration counted as a failure (ms)."
             }
         },
         "additionalProperties": false
@@ -1324,7 +1771,7 @@ fn provider_auth_schema() -> Value {
 fn provider_timeouts_schema() -> Value {
     json!({
         "type": "object",
-        "description": "HTTP timeout overrides for MCP providers.",
+        "description": "HTTP and evidence query timeout overrides for providers.",
         "properties": {
             "connect_timeout_ms": {
                 "type": "integer",
@@ -1339,6 +1786,18 @@ fn provider_timeouts_schema() -> Value {
                 "maximum": MAX_PROVIDER_REQUEST_TIMEOUT_MS,
                 "default": default_provider_request_timeout_ms(),
                 "description": "Total request timeout (ms)."
+            },
+            "query_timeout_ms": {
+                "oneOf": [
+                    { "type": "null" },
+                    {
+                        "type": "integer",
+                        "minimum": MIN_EVIDENCE_QUERY_TIMEOUT_MS,
+                        "maximum": MAX_EVIDENCE_QUERY_TIMEOUT_MS,
+                        "description": "Evidence query timeout overriding evidence.query_timeout_ms (ms)."
+                    }
+                ],
+                "default": null
             }
         },
         "additionalProperties": false
@@ -0,0 +1,176 @@
+// crates/decision-gate-config/src/secrets.rs
+// ============================================================================
+// Module: Decision Gate Config Secrets
+// Description: Secret reference resolution for sensitive config values.
+// Purpose: Keep bearer tokens and similar credentials out of config files.
+// Dependencies: std
+// ============================================================================
+
+//! ## Overview
+//! Designated secret fields accept either a literal value or a secret
+//! reference:
+//! - `secret://env/NAME` reads the environment variable `NAME`.
+//! - `secret://file/<path>` reads the absolute file `/<path>` (for example
+//!   `secret://file/run/secrets/dg_token` reads `/run/secrets/dg_token`).
+//!
+//! Invariants:
+//! - Resolution fails closed on malformed references, missing sources, and empty values.
+//! - Error messages name the reference, never the resolved secret.
+//! - A single trailing newline is stripped from file secrets.
+//!
+//! Security posture: secret sources are untrusted input and are size-bounded;
+//! see `Docs/security/threat_model.md`.
+
+// ============================================================================
+// SECTION: Imports
+// ============================================================================
+
+use std::fs;
+use std::io::Read;
+use std::path::Path;
+use std::path::PathBuf;
+
+use crate::config::ConfigError;
+use crate::config::MAX_PATH_COMPONENT_LENGTH;
+use crate::config::MAX_TOTAL_PATH_LENGTH;
+
+// ============================================================================
+// SECTION: Constants
+// ============================================================================
+
+/// Prefix identifying a secret reference.
+pub const SECRET_REF_PREFIX: &str = "secret://";
+/// Maximum size of a file-backed secret in bytes.
+pub(crate) const MAX_SECRET_FILE_SIZE: usize = 64 * 1024;
+/// Maximum length of an environment variable name in a secret reference.
+pub(crate) const MAX_SECRET_ENV_NAME_LENGTH: usize = 256;
+
+// ============================================================================
+// SECTION: Types
+// ============================================================================
+
+/// Parsed secret reference.
+///
+/// # Invariants
+/// - `Env` names match `[A-Za-z_][A-Za-z0-9_]*`.
+/// - `File` paths are absolute and within config path limits.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum SecretRef {
+    /// Environment variable secret source.
+    Env(String),
+    /// File secret source.
+    File(PathBuf),
+}
+
+// ============================================================================
+// SECTION: Public API
+// ============================================================================
+
+/// Parses a secret reference, returning `None` for literal values.
+///
+/// # Errors
+///
+/// Returns [`ConfigError`] when the value uses the `secret://` prefix but is
+/// not a well-formed reference.
+pub fn parse_secret_ref(field: &str, value: &str) -> Result<Option<SecretRef>, ConfigError> {
+    let Some(rest) = value.strip_prefix(SECRET_REF_PREFIX) else {
+        return Ok(None);
+    };
+    if let Some(name) = rest.strip_prefix("env/") {
+        validate_env_name(field, name)?;
+        return Ok(Some(SecretRef::Env(name.to_string())));
+    }
+    if let Some(path) = rest.strip_prefix("file/") {
+        let path = PathBuf::from(format!("/{path}"));
+        validate_secret_path(field, &path)?;
+        return Ok(Some(SecretRef::File(path)));
+    }
+    Err(ConfigError::Invalid(format!("{field} has unsupported secret reference scheme")))
+}
+
+/// Resolves a designated secret field value in place.
+///
+/// Literal values are left untouched. `lookup_env` supplies environment
+/// variables so callers can inject a deterministic environment in tests.
+///
+/// # Errors
+///
+/// Returns [`ConfigError`] when the reference is malformed, its source is
+/// missing or unreadable, or the resolved secret is empty.
+pub fn resolve_secret_in_place(
+    field: &str,
+    value: &mut String,
+    lookup_env: &dyn Fn(&str) -> Option<String>,
+) -> Result<(), ConfigError> {
+    let Some(reference) = parse_secret_ref(field, value)? else {
+        return Ok(());
+    };
+    let resolved = match &reference {
+        SecretRef::Env(name) => lookup_env(name).ok_or_else(|| {
+            ConfigError::Invalid(format!("{field} secret env var {name} is not set"))
+        })?,
+        SecretRef::File(path) => read_secret_file(field, path)?,
+    };
+    if resolved.trim().is_empty() {
+        return Err(ConfigError::Invalid(format!("{field} secret resolved to an empty value")));
+    }
+    *value = resolved;
+    Ok(())
+}
+
+// ============================================================================
+// SECTION: Helpers
+// ============================================================================
+
+/// Validates the environment variable name of a secret reference.
+fn validate_env_name(field: &str, name: &str) -> Result<(), ConfigError> {
+    if name.is_empty() || name.len() > MAX_SECRET_ENV_NAME_LENGTH {
+        return Err(ConfigError::Invalid(format!("{field} secret env var name is invalid")));
+    }
+    let mut chars = name.chars();
+    let valid_start = chars.next().is_some_and(|ch| ch.is_ascii_alphabetic() || ch == '_');
+    let valid_rest = chars.all(|ch| ch.is_ascii_alphanumeric() || ch == '_');
+    if !(valid_start && valid_rest) {
+        return Err(ConfigError::Invalid(format!("{field} secret env var name is invalid")));
+    }
+    Ok(())
+}
+
+/// Validates the file path of a secret reference against config path limits.
+fn validate_secret_path(field: &str, path: &Path) -> Result<(), ConfigError> {
+    let text = path.to_string_lossy();
+    if text.len() <= 1 || text.len() > MAX_TOTAL_PATH_LENGTH {
+        return Err(ConfigError::Invalid(format!("{field} secret file path is invalid")));
+    }
+    for component in path.components() {
+        if component.as_os_str().len() > MAX_PATH_COMPONENT_LENGTH {
+            return Err(ConfigError::Invalid(format!(
+                "{field} secret file path component too long"
+            )));
+        }
+    }
+    Ok(())
+}
+
+/// Reads a file-backed secret with a hard size limit.
+fn read_secret_file(field: &str, path: &Path) -> Result<String, ConfigError> {
+    let file = fs::File::open(path)
+        .map_err(|err| ConfigError::Io(format!("{field} secret file {}: {err}", path.display())))?;
+    let mut limited = file.take((MAX_SECRET_FILE_SIZE + 1) as u64);
+    let mut bytes = Vec::new();
+    limited
+        .read_to_end(&mut bytes)
+        .map_err(|err| ConfigError::Io(format!("{field} secret file {}: {err}", path.display())))?;
+    if bytes.len() > MAX_SECRET_FILE_SIZE {
+        return Err(ConfigError::Invalid(format!("{field} secret file exceeds size limit")));
+    }
+    let mut text = String::from_utf8(bytes)
+        .map_err(|_| ConfigError::Invalid(format!("{field} secret file must be utf-8")))?;
+    if text.ends_with('\n') {
+        text.pop();
+        if text.ends_with('\r') {
+            text.pop();
+        }
+    }
+    Ok(text)
+}
@@ -11,6 +11,7 @@ use std::num::NonZeroU64;
 use decision_gate_config::ConfigError;
 use decision_gate_config::PrincipalConfig;
 use decision_gate_config::PrincipalRoleConfig;
+use decision_gate_config::PrincipalScopesConfig;
 use decision_gate_config::ServerAuthConfig;
 use decision_gate_config::ServerAuthMode;
 use decision_gate_core::NamespaceId;
@@ -52,6 +53,7 @@ fn auth_bearer_token_at_max_length_256() -> TestResult {
     let auth = ServerAuthConfig {
         mode: ServerAuthMode::BearerToken,
         bearer_tokens: vec![token],
+        jwt: None,
         mtls_subjects: Vec::new(),
         allowed_tools: Vec::new(),
         principals: Vec::new(),
@@ -67,6 +69,7 @@ fn auth_bearer_token_exceeds_max_length_257() -> TestResult {
     let auth = ServerAuthConfig {
         mode: ServerAuthMode::BearerToken,
         bearer_tokens: vec![token],
+        jwt: None,
         mtls_subjects: Vec::new(),
         allowed_tools: Vec::new(),
         principals: Vec::new(),
@@ -81,6 +84,7 @@ fn auth_bearer_token_empty_string() -> TestResult {
     let auth = ServerAuthConfig {
         mode: ServerAuthMode::BearerToken,
         bearer_tokens: vec![String::new()],
+        jwt: None,
         mtls_subjects: Vec::new(),
         allowed_tools: Vec::new(),
         principals: Vec::new(),
@@ -95,6 +99,7 @@ fn auth_bearer_token_whitespace_only() -> TestResult {
     let auth = ServerAuthConfig {
         mode: ServerAuthMode::BearerToken,
         bearer_tokens: vec!["   ".to_string()],
+        jwt: None,
         mtls_subjects: Vec::new(),
         allowed_tools: Vec::new(),
         principals: Vec::new(),
@@ -109,6 +114,7 @@ fn auth_bearer_token_with_leading_whitespace() -> TestResult {
     let auth = ServerAuthConfig {
         mode: ServerAuthMode::BearerToken,
         bearer_tokens: vec![" token".to_string()],
+        jwt: None,
         mtls_subjects: Vec::new(),
         allowed_tools: Vec::new(),
         principals: Vec::new(),
@@ -123,6 +129,7 @@ fn auth_bearer_token_with_trailing_whitespace() -> TestResult {
     let auth = ServerAuthConfig {
         mode: ServerAuthMode::BearerToken,
         bearer_tokens: vec!["token ".to_string()],
+        jwt: None,
         mtls_subjects: Vec::new(),
         allowed_tools: Vec::new(),
         principals: Vec::new(),
@@ -137,6 +144,7 @@ fn auth_bearer_token_with_internal_whitespace() -> TestResult {
     let auth = ServerAuthConfig {
         mode: ServerAuthMode::BearerToken,
         bearer_tokens: vec!["to ken".to_string()],
+        jwt: None,
         mtls_subjects: Vec::new(),
         allowed_tools: Vec::new(),
         principals: Vec::new(),
@@ -151,6 +159,7 @@ fn auth_bearer_token_with_newline() -> TestResult {
     let auth = ServerAuthConfig {
         mode: ServerAuthMode::BearerToken,
         bearer_tokens: vec!["token\nvalue".to_string()],
+        jwt: None,
         mtls_subjects: Vec::new(),
         allowed_tools: Vec::new(),
         principals: Vec::new(),
@@ -165,6 +174,7 @@ fn auth_bearer_token_with_tab() -> TestResult {
     let auth = ServerAuthConfig {
         mode: ServerAuthMode::BearerToken,
         bearer_tokens: vec!["token\tvalue".to_string()],
+        jwt: None,
         mtls_subjects: Vec::new(),
         allowed_tools: Vec::new(),
         principals: Vec::new(),
@@ -184,6 +194,7 @@ fn auth_bearer_tokens_array_at_max_64() -> TestResult {
     let auth = ServerAuthConfig {
         mode: ServerAuthMode::BearerToken,
         bearer_tokens: tokens,
+        jwt: None,
         mtls_subjects: Vec::new(),
         allowed_tools: Vec::new(),
         principals: Vec::new(),
@@ -199,6 +210,7 @@ fn auth_bearer_tokens_array_exceeds_max_65() -> TestResult {
     let auth = ServerAuthConfig {
         mode: ServerAuthMode::BearerToken,
         bearer_tokens: tokens,
+        jwt: None,
         mtls_subjects: Vec::new(),
         allowed_tools: Vec::new(),
         principals: Vec::new(),
@@ -213,6 +225,7 @@ fn auth_bearer_tokens_empty_array_local_only_mode() -> TestResult {
     let auth = ServerAuthConfig {
         mode: ServerAuthMode::LocalOnly,
         bearer_tokens: Vec::new(),
+        jwt: None,
         mtls_subjects: Vec::new(),
         allowed_tools: Vec::new(),
         principals: Vec::new(),
@@ -227,6 +240,7 @@ fn auth_bearer_tokens_empty_array_bearer_mode() -> TestResult {
     let auth = ServerAuthConfig {
         mode: ServerAuthMode::BearerToken,
         bearer_tokens: Vec::new(),
+        jwt: None,
         mtls_subjects: Vec::new(),
         allowed_tools: Vec::new(),
         principals: Vec::new(),
@@ -246,6 +260,7 @@ fn auth_mtls_subject_at_max_length_512() -> TestResult {
     let auth = ServerAuthConfig {
         mode: ServerAuthMode::Mtls,
         bearer_tokens: Vec::new(),
+        jwt: None,
         mtls_subjects: vec![subject],
         allowed_tools: Vec::new(),
         principals: Vec::new(),
@@ -261,6 +276,7 @@ fn auth_mtls_subject_exceeds_max_length_513() -> TestResult {
     let auth = ServerAuthConfig {
         mode: ServerAuthMode::Mtls,
         bearer_tokens: Vec::new(),
+        jwt: None,
         mtls_subjects: vec![subject],
         allowed_tools: Vec::new(),
         principals: Vec::new(),
@@ -275,6 +291,7 @@ fn auth_mtls_subject_empty_string() -> TestResult {
     let auth = ServerAuthConfig {
         mode: ServerAuthMode::Mtls,
         bearer_tokens: Vec::new(),
+        jwt: None,
         mtls_subjects: vec![String::new()],
         allowed_tools: Vec::new(),
         principals: Vec::new(),
@@ -289,6 +306,7 @@ fn auth_mtls_subject_whitespace_only() -> TestResult {
     let auth = ServerAuthConfig {
         mode: ServerAuthMode::Mtls,
         bearer_tokens: Vec::new(),
+        jwt: None,
         mtls_subjects: vec!["   ".to_string()],
         allowed_tools: Vec::new(),
         principals: Vec::new(),
@@ -304,6 +322,7 @@ fn auth_mtls_subject_with_whitespace_allowed() -> TestResult {
     let auth = ServerAuthConfig {
         mode: ServerAuthMode::Mtls,
         bearer_tokens: Vec::new(),
+        jwt: None,
         mtls_subjects: vec!["CN=Test User, OU=Engineering".to_string()],
         allowed_tools: Vec::new(),
         principals: Vec::new(),
@@ -323,6 +342,7 @@ fn auth_mtls_subjects_array_at_max_64() -> TestResult {
     let auth = ServerAuthConfig {
         mode: ServerAuthMode::Mtls,
         bearer_tokens: Vec::new(),
+        jwt: None,
         mtls_subjects: subjects,
         allowed_tools: Vec::new(),
         principals: Vec::new(),
@@ -338,6 +358,7 @@ fn auth_mtls_subjects_array_exceeds_max_65() -> TestResult {
     let auth = ServerAuthConfig {
         mode: ServerAuthMode::Mtls,
         bearer_tokens: Vec::new(),
+        jwt: None,
         mtls_subjects: subjects,
         allowed_tools: Vec::new(),
         principals: Vec::new(),
@@ -352,6 +373,7 @@ fn auth_mtls_subjects_empty_array_mtls_mode() -> TestResult {
     let auth = ServerAuthConfig {
         mode: ServerAuthMode::Mtls,
         bearer_tokens: Vec::new(),
+        jwt: None,
         mtls_subjects: Vec::new(),
         allowed_tools: Vec::new(),
         principals: Vec::new(),
@@ -370,6 +392,7 @@ fn auth_allowed_tools_valid_tool_names() -> TestResult {
     let auth = ServerAuthConfig {
         mode: ServerAuthMode::LocalOnly,
         bearer_tokens: Vec::new(),
+        jwt: None,
         mtls_subjects: Vec::new(),
         allowed_tools: vec!["precheck".to_string(), "scenario_next".to_string()],
         principals: Vec::new(),
@@ -384,6 +407,7 @@ fn auth_allowed_tools_invalid_tool_name() -> TestResult {
     let auth = ServerAuthConfig {
         mode: ServerAuthMode::LocalOnly,
         bearer_tokens: Vec::new(),
+        jwt: None,
         mtls_subjects: Vec::new(),
         allowed_tools: vec!["invalid.tool.name".to_string()],
         principals: Vec::new(),
@@ -408,6 +432,7 @@ fn auth_allowed_tools_array_at_max_128() -> TestResult {
     let auth = ServerAuthConfig {
         mode: ServerAuthMode::LocalOnly,
         bearer_tokens: Vec::new(),
+        jwt: None,
         mtls_subjects: Vec::new(),
         allowed_tools: tools,
         principals: Vec::new(),
@@ -430,6 +455,7 @@ fn auth_allowed_tools_array_exceeds_max_129() -> TestResult {
     let auth = ServerAuthConfig {
         mode: ServerAuthMode::LocalOnly,
         bearer_tokens: Vec::new(),
+        jwt: None,
         mtls_subjects: Vec::new(),
         allowed_tools: tools,
         principals: Vec::new(),
@@ -444,6 +470,7 @@ fn auth_allowed_tools_empty_array() -> TestResult {
     let auth = ServerAuthConfig {
         mode: ServerAuthMode::LocalOnly,
         bearer_tokens: Vec::new(),
+        jwt: None,
         mtls_subjects: Vec::new(),
         allowed_tools: Vec::new(),
         principals: Vec::new(),
@@ -462,11 +489,13 @@ fn auth_principal_subject_valid() -> TestResult {
     let principal = PrincipalConfig {
         subject: "user@example.com".to_string(),
         policy_class: None,
+        scopes: PrincipalScopesConfig::default(),
         roles: Vec::new(),
     };
     let auth = ServerAuthConfig {
         mode: ServerAuthMode::LocalOnly,
         bearer_tokens: Vec::new(),
+        jwt: None,
         mtls_subjects: Vec::new(),
         allowed_tools: Vec::new(),
         principals: vec![principal],
@@ -481,11 +510,13 @@ fn auth_principal_subject_empty() -> TestResult {
     let principal = PrincipalConfig {
         subject: String::new(),
         policy_class: None,
+        scopes: PrincipalScopesConfig::default(),
         roles: Vec::new(),
     };
     let auth = ServerAuthConfig {
         mode: ServerAuthMode::LocalOnly,
         bearer_tokens: Vec::new(),
+        jwt: None,
         mtls_subjects: Vec::new(),
         allowed_tools: Vec::new(),
         principals: vec![principal],
@@ -500,11 +531,13 @@ fn auth_principal_subject_whitespace() -> TestResult {
     let principal = PrincipalConfig {
         subject: "   ".to_string(),
         policy_class: None,
+        scopes: PrincipalScopesConfig::default(),
         roles: Vec::new(),
     };
     let auth = ServerAuthConfig {
         mode: ServerAuthMode::LocalOnly,
         bearer_tokens: Vec::new(),
+        jwt: None,
         mtls_subjects: Vec::new(),
         allowed_tools: Vec::new(),
         principals: vec![principal],
@@ -519,11 +552,13 @@ fn auth_principal_policy_class_valid() -> TestResult {
     let principal = PrincipalConfig {
         subject: "user@example.com".to_string(),
         policy_class: Some("production".to_string()),
+        scopes: PrincipalScopesConfig::default(),
         roles: Vec::new(),
     };
     let auth = ServerAuthConfig {
         mode: ServerAuthMode::LocalOnly,
         bearer_tokens: Vec::new(),
+        jwt: None,
         mtls_subjects: Vec::new(),
         allowed_tools: Vec::new(),
         principals: vec![principal],
@@ -538,11 +573,13 @@ fn auth_principal_policy_class_empty() -> TestResult {
     let principal = PrincipalConfig {
         subject: "user@example.com".to_string(),
         policy_class: Some(String::new()),
+        scopes: PrincipalScopesConfig::default(),
         roles: Vec::new(),
     };
     let auth = ServerAuthConfig {
         mode: ServerAuthMode::LocalOnly,
         bearer_tokens: Vec::new(),
+        jwt: None,
         mtls_subjects: Vec::new(),
         allowed_tools: Vec::new(),
         principals: vec![principal],
@@ -557,11 +594,13 @@ fn auth_principal_policy_class_whitespace() -> TestResult {
     let principal = PrincipalConfig {
         subject: "user@example.com".to_string(),
         policy_class: Some("   ".to_string()),
+        scopes: PrincipalScopesConfig::default(),
         roles: Vec::new(),
     };
     let auth = ServerAuthConfig {
         mode: ServerAuthMode::LocalOnly,
         bearer_tokens: Vec::new(),
+        jwt: None,
         mtls_subjects: Vec::new(),
         allowed_tools: Vec::new(),
         principals: vec![principal],
@@ -571,6 +610,135 @@ fn auth_principal_policy_class_whitespace() -> TestResult {
     Ok(())
 }
 
+// ============================================================================
+// SECTION: JWT Constraints
+// ============================================================================
+
+fn jwt_auth_toml(jwt_body: &str) -> String {
+    format!(
+        "[server]\ntransport = \"http\"\nbind = \"127.0.0.1:8080\"\n\n[server.auth]\nmode = \
+         \"bearer_token\"\n\n[server.auth.jwt]\nissuer = \"https://idp.example.com\"\naudience = \
+         \"decision-gate\"\n{jwt_body}"
+    )
+}
+
+#[test]
+fn auth_jwt_without_static_tokens_accepted() -> TestResult {
+    let toml = jwt_auth_toml(
+        "jwks_url = \"https://idp.example.com/jwks.json\"\nallowed_jwks_hosts = \
+         [\"IDP.example.com\"]\n",
+    );
+    let mut config = common::config_from_toml(&toml).map_err(|err| err.to_string())?;
+    config.validate().map_err(|err| err.to_string())?;
+    Ok(())
+}
+
+#[test]
+fn auth_jwt_host_must_be_allowlisted() -> TestResult {
+    let toml = jwt_auth_toml(
+        "jwks_url = \"https://keys.example.net/jwks.json\"\nallowed_jwks_hosts = \
+         [\"idp.example.com\"]\n",
+    );
+    let mut config = common::config_from_toml(&toml).map_err(|err| err.to_string())?;
+    assert_invalid(
+        config.validate(),
+        "auth.jwt.jwks_url host not in allowed_jwks_hosts: keys.example.net",
+    )?;
+    let toml = jwt_auth_toml("jwks_url = \"https://idp.example.com/jwks.json\"\n");
+    let mut config = common::config_from_toml(&toml).map_err(|err| err.to_string())?;
+    assert_invalid(config.validate(), "auth.jwt.allowed_jwks_hosts must list the jwks_url host")?;
+    Ok(())
+}
+
+#[test]
+fn auth_jwt_rejects_http_without_opt_in() -> TestResult {
+    let toml = jwt_auth_toml(
+        "jwks_url = \"http://idp.example.com/jwks.json\"\nallowed_jwks_hosts = \
+         [\"idp.example.com\"]\n",
+    );
+    let mut config = common::config_from_toml(&toml).map_err(|err| err.to_string())?;
+    assert_invalid(config.validate(), "auth.jwt.jwks_url uses http:// without allow_http")?;
+    Ok(())
+}
+
+#[test]
+fn auth_jwt_rejects_unknown_algorithm() -> TestResult {
+    let toml = jwt_auth_toml(
+        "jwks_url = \"https://idp.example.com/jwks.json\"\nallowed_jwks_hosts = \
+         [\"idp.example.com\"]\nalgorithms = [\"HS256\"]\n",
+    );
+    if common::config_from_toml(&toml).is_ok() {
+        return Err("expected HS256 to be rejected at parse time".to_string());
+    }
+    Ok(())
+}
+
+#[test]
+fn auth_jwt_requires_bearer_mode() -> TestResult {
+    let toml = jwt_auth_toml(
+        "jwks_url = \"https://idp.example.com/jwks.json\"\nallowed_jwks_hosts = \
+         [\"idp.example.com\"]\n",
+    )
+    .replace("mode = \"bearer_token\"", "mode = \"local_only\"");
+    let mut config = common::config_from_toml(&toml).map_err(|err| err.to_string())?;
+    assert_invalid(config.validate(), "auth.jwt requires bearer_token auth mode")?;
+    Ok(())
+}
+
+// ============================================================================
+// SECTION: Principal Scopes Constraints
+// ============================================================================
+
+#[test]
+fn principal_scopes_accept_known_tools_and_tenants() -> TestResult {
+    let auth = ServerAuthConfig {
+        mode: ServerAuthMode::LocalOnly,
+        bearer_tokens: Vec::new(),
+        jwt: None,
+        mtls_subjects: Vec::new(),
+        allowed_tools: Vec::new(),
+        principals: vec![PrincipalConfig {
+            subject: "stdio".to_string(),
+            policy_class: None,
+            scopes: PrincipalScopesConfig {
+                read_only: true,
+                allowed_tools: vec!["scenario_status".to_string()],
+                tenant_ids: vec![TenantId::new(NonZeroU64::MIN)],
+            },
+            roles: Vec::new(),
+        }],
+    };
+    let mut config = common::config_with_auth(auth).map_err(|err| err.to_string())?;
+    config.validate().map_err(|err| err.to_string())?;
+    Ok(())
+}
+
+#[test]
+fn principal_scopes_reject_unknown_tool() -> TestResult {
+    let auth = ServerAuthConfig {
+        mode: ServerAuthMode::LocalOnly,
+        bearer_tokens: Vec::new(),
+        jwt: None,
+        mtls_subjects: Vec::new(),
+        allowed_tools: Vec::new(),
+        principals: vec![PrincipalConfig {
+            subject: "stdio".to_string(),
+            policy_class: None,
+            scopes: PrincipalScopesConfig {
+                allowed_tools: vec!["not_a_tool".to_string()],
+                ..PrincipalScopesConfig::default()
+            },
+            roles: Vec::new(),
+        }],
+    };
+    let mut config = common::config_with_auth(auth).map_err(|err| err.to_string())?;
+    assert_invalid(
+        config.validate(),
+        "unknown tool in auth.principals.scopes.allowed_tools: not_a_tool",
+    )?;
+    Ok(())
+}
+
 // ============================================================================
 // SECTION: Principal Roles Constraints
 // ============================================================================
@@ -587,11 +755,13 @@ fn auth_principal_roles_at_max_128() -> TestResult {
     let principal = PrincipalConfig {
         subject: "user@example.com".to_string(),
         policy_class: None,
+        scopes: PrincipalScopesConfig::default(),
         roles,
     };
     let auth = ServerAuthConfig {
         mode: ServerAuthMode::LocalOnly,
         bearer_tokens: Vec::new(),
+        jwt: None,
         mtls_subjects: Vec::new(),
         allowed_tools: Vec::new(),
         principals: vec![principal],
@@ -613,11 +783,13 @@ fn auth_principal_roles_exceeds_max_129() -> TestResult {
     let principal = PrincipalConfig {
         subject: "user@example.com".to_string(),
         policy_class: None,
+        scopes: PrincipalScopesConfig::default(),
         roles,
     };
     let auth = ServerAuthConfig {
         mode: ServerAuthMode::LocalOnly,
         bearer_tokens: Vec::new(),
+        jwt: None,
         mtls_subjects: Vec::new(),
         allowed_tools: Vec::new(),
         principals: vec![principal],
@@ -637,12 +809,14 @@ fn auth_principals_array_at_max_64() -> TestResult {
         .map(|i| PrincipalConfig {
             subject: format!("user{i}@example.com"),
             policy_class: None,
+            scopes: PrincipalScopesConfig::default(),
             roles: Vec::new(),
         })
         .collect();
     let auth = ServerAuthConfig {
         mode: ServerAuthMode::LocalOnly,
         bearer_tokens: Vec::new(),
+        jwt: None,
         mtls_subjects: Vec::new(),
         allowed_tools: Vec::new(),
         principals,
@@ -658,12 +832,14 @@ fn auth_principals_array_exceeds_max_65() -> TestResult {
         .map(|i| PrincipalConfig {
             subject: format!("user{i}@example.com"),
             policy_class: None,
+            scopes: PrincipalScopesConfig::default(),
             roles: Vec::new(),
         })
         .collect();
     let auth = ServerAuthConfig {
         mode: ServerAuthMode::LocalOnly,
         bearer_tokens: Vec::new(),
+        jwt: None,
         mtls_subjects: Vec::new(),
         allowed_tools: Vec::new(),
         principals,
@@ -687,11 +863,13 @@ fn auth_role_name_valid() -> TestResult {
     let principal = PrincipalConfig {
         subject: "user@example.com".to_string(),
         policy_class: None,
+        scopes: PrincipalScopesConfig::default(),
         roles: vec![role],
     };
     let auth = ServerAuthConfig {
         mode: ServerAuthMode::LocalOnly,
         bearer_tokens: Vec::new(),
+        jwt: None,
         mtls_subjects: Vec::new(),
         allowed_tools: Vec::new(),
         principals: vec![principal],
@@ -711,11 +889,13 @@ fn auth_role_name_empty() -> TestResult {
     let principal = PrincipalConfig {
         subject: "user@example.com".to_string(),
         policy_class: None,
+        scopes: PrincipalScopesConfig::default(),
         roles: vec![role],
     };
     let auth = ServerAuthConfig {
         mode: ServerAuthMode::LocalOnly,
         bearer_tokens: Vec::new(),
+        jwt: None,
         mtls_subjects: Vec::new(),
         allowed_tools: Vec::new(),
         principals: vec![principal],
@@ -735,11 +915,13 @@ fn auth_role_name_whitespace() -> TestResult {
     let principal = PrincipalConfig {
         subject: "user@example.com".to_string(),
         policy_class: None,
+        scopes: PrincipalScopesConfig::default(),
         roles: vec![role],
     };
     let auth = ServerAuthConfig {
         mode: ServerAuthMode::LocalOnly,
         bearer_tokens: Vec::new(),
+        jwt: None,
         mtls_subjects: Vec::new(),
         allowed_tools: Vec::new(),
         principals: vec![principal],
@@ -759,11 +941,13 @@ fn auth_role_with_tenant_id_only() -> TestResult {
     let principal = PrincipalConfig {
         subject: "user@example.com".to_string(),
         policy_class: None,
+        scopes: PrincipalScopesConfig::default(),
         roles: vec![role],
     };
     let auth = ServerAuthConfig {
         mode: ServerAuthMode::LocalOnly,
         bearer_tokens: Vec::new(),
+        jwt: None,
         mtls_subjects: Vec::new(),
         allowed_tools: Vec::new(),
         principals: vec![principal],
@@ -783,11 +967,13 @@ fn auth_role_with_namespace_id_only() -> TestResult {
     let principal = PrincipalConfig {
         subject: "user@example.com".to_string(),
         policy_class: None,
+        scopes: PrincipalScopesConfig::default(),
         roles: vec![role],
     };
     let auth = ServerAuthConfig {
         mode: ServerAuthMode::LocalOnly,
         bearer_tokens: Vec::new(),
+        jwt: None,
         mtls_subjects: Vec::new(),
         allowed_tools: Vec::new(),
         principals: vec![principal],
@@ -807,11 +993,13 @@ fn auth_role_with_both_tenant_and_namespace() -> TestResult {
     let principal = PrincipalConfig {
         subject: "user@example.com".to_string(),
         policy_class: None,
+        scopes: PrincipalScopesConfig::default(),
         roles: vec![role],
     };
     let auth = ServerAuthConfig {
         mode: ServerAuthMode::LocalOnly,
         bearer_tokens: Vec::new(),
+        jwt: None,
         mtls_subjects: Vec::new(),
         allowed_tools: Vec::new(),
         principals: vec![principal],
@@ -830,6 +1018,7 @@ fn auth_mode_bearer_token_requires_tokens() -> TestResult {
     let auth = ServerAuthConfig {
         mode: ServerAuthMode::BearerToken,
         bearer_tokens: Vec::new(),
+        jwt: None,
         mtls_subjects: Vec::new(),
         allowed_tools: Vec::new(),
         principals: Vec::new(),
@@ -844,6 +1033,7 @@ fn auth_mode_mtls_requires_subjects() -> TestResult {
     let auth = ServerAuthConfig {
         mode: ServerAuthMode::Mtls,
         bearer_tokens: Vec::new(),
+        jwt: None,
         mtls_subjects: Vec::new(),
         allowed_tools: Vec::new(),
         principals: Vec::new(),
@@ -858,6 +1048,7 @@ fn auth_mode_local_only_allows_empty_arrays() -> TestResult {
     let auth = ServerAuthConfig {
         mode: ServerAuthMode::LocalOnly,
         bearer_tokens: Vec::new(),
+        jwt: None,
         mtls_subjects: Vec::new(),
         allowed_tools: Vec::new(),
         principals: Vec::new(),
@@ -6,6 +6,7 @@
 // Purpose: Ensure all numeric and size boundaries are properly tested.
 // =============================================================================
 
+use std::num::NonZeroU64;
 use std::path::PathBuf;
 
 use decision_gate_config::ConfigError;
@@ -14,6 +15,8 @@ use decision_gate_config::RunStateStoreConfig;
 use decision_gate_config::RunStateStoreType;
 use decision_gate_config::ServerAuthConfig;
 use decision_gate_config::ServerAuthMode;
+use decision_gate_core::TenantId;
+use decision_gate_store_sqlite::SqliteRetentionPolicy;
 use decision_gate_store_sqlite::SqliteStoreMode;
 use decision_gate_store_sqlite::SqliteSyncMode;
 
@@ -164,11 +167,73 @@ fn max_versions_zero_rejected() -> TestResult {
         journal_mode: SqliteStoreMode::Wal,
         sync_mode: SqliteSyncMode::Full,
         max_versions: Some(0),
+        circuit_breaker: None,
+        cache: None,
+        retention: Vec::new(),
+        retention_interval_ms: 3_600_000,
     };
     assert_invalid(config.validate(), "run_state_store max_versions must be greater than zero")?;
     Ok(())
 }
 
+fn sqlite_run_state_store() -> RunStateStoreConfig {
+    RunStateStoreConfig {
+        store_type: RunStateStoreType::Sqlite,
+        path: Some(PathBuf::from("store.db")),
+        ..RunStateStoreConfig::default()
+    }
+}
+
+const fn retention_policy(
+    keep_versions: Option<u64>,
+    max_age_days: Option<u64>,
+) -> SqliteRetentionPolicy {
+    SqliteRetentionPolicy {
+        tenant_id: TenantId::new(NonZeroU64::MIN),
+        namespace_id: None,
+        keep_versions,
+        max_age_days,
+    }
+}
+
+#[test]
+fn retention_policy_accepted_for_sqlite() -> TestResult {
+    let mut config = common::minimal_config().map_err(|err| err.to_string())?;
+    config.run_state_store = sqlite_run_state_store();
+    config.run_state_store.retention = vec![retention_policy(Some(10), Some(30))];
+    config.validate().map_err(|err| err.to_string())?;
+    Ok(())
+}
+
+#[test]
+fn retention_policy_without_bound_rejected() -> TestResult {
+    let mut config = common::minimal_config().map_err(|err| err.to_string())?;
+    config.run_state_store = sqlite_run_state_store();
+    config.run_state_store.retention = vec![retention_policy(None, None)];
+    assert_invalid(
+        config.validate(),
+        "run_state_store retention[0] must set keep_versions or max_age_days",
+    )?;
+    Ok(())
+}
+
+#[test]
+fn retention_policy_on_memory_store_rejected() -> TestResult {
+    let mut config = common::minimal_config().map_err(|err| err.to_string())?;
+    config.run_state_store.retention = vec![retention_policy(Some(10), None)];
+    assert_invalid(config.validate(), "memory run_state_store does not support retention")?;
+    Ok(())
+}
+
+#[test]
+fn retention_interval_below_minimum_rejected() -> TestResult {
+    let mut config = common::minimal_config().map_err(|err| err.to_string())?;
+    config.run_state_store = sqlite_run_state_store();
+    config.run_state_store.retention_interval_ms = 999;
+    assert_invalid(config.validate(), "retention_interval_ms must be between")?;
+    Ok(())
+}
+
 // ============================================================================
 // SECTION: Very Long Strings
 // ============================================================================
@@ -179,6 +244,7 @@ fn bearer_token_exactly_256_bytes() -> TestResult {
     let auth = ServerAuthConfig {
         mode: ServerAuthMode::BearerToken,
         bearer_tokens: vec![token],
+        jwt: None,
         mtls_subjects: Vec::new(),
         allowed_tools: Vec::new(),
         principals: Vec::new(),
@@ -194,6 +260,7 @@ fn bearer_token_257_bytes_rejected() -> TestResult {
     let auth = ServerAuthConfig {
         mode: ServerAuthMode::BearerToken,
         bearer_tokens: vec![token],
+        jwt: None,
         mtls_subjects: Vec::new(),
         allowed_tools: Vec::new(),
         principals: Vec::new(),
@@ -209,6 +276,7 @@ fn mtls_subject_exactly_512_bytes() -> TestResult {
     let auth = ServerAuthConfig {
         mode: ServerAuthMode::Mtls,
         bearer_tokens: Vec::new(),
+        jwt: None,
         mtls_subjects: vec![subject],
         allowed_tools: Vec::new(),
         principals: Vec::new(),
@@ -224,6 +292,7 @@ fn mtls_subject_513_bytes_rejected() -> TestResult {
     let auth = ServerAuthConfig {
         mode: ServerAuthMode::Mtls,
         bearer_tokens: Vec::new(),
+        jwt: None,
         mtls_subjects: vec![subject],
         allowed_tools: Vec::new(),
         principals: Vec::new(),
@@ -243,6 +312,7 @@ fn field_empty_string_vs_whitespace_only() -> TestResult {
     let auth1 = ServerAuthConfig {
         mode: ServerAuthMode::BearerToken,
         bearer_tokens: vec![String::new()],
+        jwt: None,
         mtls_subjects: Vec::new(),
         allowed_tools: Vec::new(),
         principals: Vec::new(),
@@ -256,6 +326,7 @@ fn field_empty_string_vs_whitespace_only() -> TestResult {
     let auth2 = ServerAuthConfig {
         mode: ServerAuthMode::BearerToken,
         bearer_tokens: vec!["   ".to_string()],
+        jwt: None,
         mtls_subjects: Vec::new(),
         allowed_tools: Vec::new(),
         principals: Vec::new(),
@@ -274,6 +345,7 @@ fn field_unicode_whitespace_u00a0() -> TestResult {
     let auth = ServerAuthConfig {
         mode: ServerAuthMode::BearerToken,
         bearer_tokens: vec!["token\u{00A0}value".to_string()],
+        jwt: None,
         mtls_subjects: Vec::new(),
         allowed_tools: Vec::new(),
         principals: Vec::new(),
@@ -289,6 +361,7 @@ fn field_unicode_whitespace_u2000() -> TestResult {
     let auth = ServerAuthConfig {
         mode: ServerAuthMode::BearerToken,
         bearer_tokens: vec!["token\u{2000}value".to_string()],
+        jwt: None,
         mtls_subjects: Vec::new(),
         allowed_tools: Vec::new(),
         principals: Vec::new(),
@@ -304,6 +377,7 @@ fn field_tab_vs_space_vs_newline() -> TestResult {
     let auth1 = ServerAuthConfig {
         mode: ServerAuthMode::BearerToken,
         bearer_tokens: vec!["token\tvalue".to_string()],
+        jwt: None,
         mtls_subjects: Vec::new(),
         allowed_tools: Vec::new(),
         principals: Vec::new(),
@@ -315,6 +389,7 @@ fn field_tab_vs_space_vs_newline() -> TestResult {
     let auth2 = ServerAuthConfig {
         mode: ServerAuthMode::BearerToken,
         bearer_tokens: vec!["token value".to_string()],
+        jwt: None,
         mtls_subjects: Vec::new(),
         allowed_tools: Vec::new(),
         principals: Vec::new(),
@@ -326,6 +401,7 @@ fn field_tab_vs_space_vs_newline() -> TestResult {
     let auth3 = ServerAuthConfig {
         mode: ServerAuthMode::BearerToken,
         bearer_tokens: vec!["token\nvalue".to_string()],
+        jwt: None,
         mtls_subjects: Vec::new(),
         allowed_tools: Vec::new(),
         principals: Vec::new(),
@@ -347,6 +423,7 @@ fn all_arrays_tested_at_max_allowed_size() -> TestResult {
     let auth1 = ServerAuthConfig {
         mode: ServerAuthMode::BearerToken,
         bearer_tokens: tokens,
+        jwt: None,
         mtls_subjects: Vec::new(),
         allowed_tools: Vec::new(),
         principals: Vec::new(),
@@ -359,6 +436,7 @@ fn all_arrays_tested_at_max_allowed_size() -> TestResult {
     let auth2 = ServerAuthConfig {
         mode: ServerAuthMode::Mtls,
         bearer_tokens: Vec::new(),
+        jwt: None,
         mtls_subjects: subjects,
         allowed_tools: Vec::new(),
         principals: Vec::new(),
@@ -376,6 +454,7 @@ fn all_arrays_tested_at_max_plus_one() -> TestResult {
     let auth1 = ServerAuthConfig {
         mode: ServerAuthMode::BearerToken,
         bearer_tokens: tokens,
+        jwt: None,
         mtls_subjects: Vec::new(),
         allowed_tools: Vec::new(),
         principals: Vec::new(),
@@ -390,6 +469,7 @@ fn all_arrays_tested_at_max_plus_one() -> TestResult {
     let auth2 = ServerAuthConfig {
         mode: ServerAuthMode::Mtls,
         bearer_tokens: Vec::new(),
+        jwt: None,
         mtls_subjects: subjects,
         allowed_tools: Vec::new(),
         principals: Vec::new(),
@@ -408,6 +488,7 @@ fn empty_arrays_where_valid() -> TestResult {
     let auth = ServerAuthConfig {
         mode: ServerAuthMode::LocalOnly,
         bearer_tokens: Vec::new(),
+        jwt: None,
         mtls_subjects: Vec::new(),
         allowed_tools: Vec::new(),
         principals: Vec::new(),
@@ -423,6 +504,7 @@ fn empty_arrays_where_invalid() -> TestResult {
     let auth1 = ServerAuthConfig {
         mode: ServerAuthMode::BearerToken,
         bearer_tokens: Vec::new(),
+        jwt: None,
         mtls_subjects: Vec::new(),
         allowed_tools: Vec::new(),
         principals: Vec::new(),
@@ -436,6 +518,7 @@ fn empty_arrays_where_invalid() -> TestResult {
     let auth2 = ServerAuthConfig {
         mode: ServerAuthMode::Mtls,
         bearer_tokens: Vec::new(),
+        jwt: None,
         mtls_subjects: Vec::new(),
         allowed_tools: Vec::new(),
         principals: Vec::new(),
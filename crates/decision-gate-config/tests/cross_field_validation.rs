@@ -67,6 +67,7 @@ fn validation_strict_false_requires_allow_permissive() -> TestResult {
         allow_permissive: false,
         enable_lexicographic: false,
         enable_deep_equals: false,
+        validate_tool_outputs: false,
     };
     assert_invalid(
         config.validate(),
@@ -84,6 +85,7 @@ fn validation_strict_false_with_allow_permissive() -> TestResult {
         allow_permissive: true,
         enable_lexicographic: false,
         enable_deep_equals: false,
+        validate_tool_outputs: false,
     };
     config.validate().map_err(|err| err.to_string())?;
     Ok(())
@@ -167,6 +169,7 @@ fn bearer_token_mode_requires_bearer_tokens() -> TestResult {
     let auth = ServerAuthConfig {
         mode: ServerAuthMode::BearerToken,
         bearer_tokens: Vec::new(),
+        jwt: None,
         mtls_subjects: Vec::new(),
         allowed_tools: Vec::new(),
         principals: Vec::new(),
@@ -181,6 +184,7 @@ fn mtls_mode_requires_mtls_subjects() -> TestResult {
     let auth = ServerAuthConfig {
         mode: ServerAuthMode::Mtls,
         bearer_tokens: Vec::new(),
+        jwt: None,
         mtls_subjects: Vec::new(),
         allowed_tools: Vec::new(),
         principals: Vec::new(),
@@ -272,6 +276,7 @@ fn request_timeout_must_exceed_connect_timeout() -> TestResult {
         timeouts: ProviderTimeoutConfig {
             connect_timeout_ms: 2000,
             request_timeout_ms: 1000,
+            query_timeout_ms: None,
         },
         config: None,
     }];
@@ -295,6 +300,7 @@ fn request_timeout_equal_to_connect_timeout() -> TestResult {
         timeouts: ProviderTimeoutConfig {
             connect_timeout_ms: 1000,
             request_timeout_ms: 1000,
+            query_timeout_ms: None,
         },
         config: None,
     }];
@@ -337,6 +343,7 @@ fn stdio_transport_rejects_non_local_auth() -> TestResult {
     config.server.auth = Some(ServerAuthConfig {
         mode: ServerAuthMode::BearerToken,
         bearer_tokens: vec!["token".to_string()],
+        jwt: None,
         mtls_subjects: Vec::new(),
         allowed_tools: Vec::new(),
         principals: Vec::new(),
@@ -354,6 +361,10 @@ fn stdio_transport_rejects_tls() -> TestResult {
         key_path: "key.pem".to_string(),
         client_ca_path: None,
         require_client_cert: false,
+        reload_interval_ms: None,
+        max_client_chain_depth: None,
+        client_required_ekus: Vec::new(),
+        ocsp_response_path: None,
     });
     assert_invalid(config.validate(), "stdio transport does not support tls")?;
     Ok(())
@@ -373,6 +384,10 @@ fn memory_store_rejects_path() -> TestResult {
         journal_mode: SqliteStoreMode::Wal,
         sync_mode: SqliteSyncMode::Full,
         max_versions: None,
+        circuit_breaker: None,
+        cache: None,
+        retention: Vec::new(),
+        retention_interval_ms: 3_600_000,
     };
     assert_invalid(config.validate(), "memory run_state_store must not set path")?;
     Ok(())
@@ -388,6 +403,10 @@ fn sqlite_store_requires_path() -> TestResult {
         journal_mode: SqliteStoreMode::Wal,
         sync_mode: SqliteSyncMode::Full,
         max_versions: None,
+        circuit_breaker: None,
+        cache: None,
+        retention: Vec::new(),
+        retention_interval_ms: 3_600_000,
     };
     assert_invalid(config.validate(), "sqlite run_state_store requires path")?;
     Ok(())
@@ -12,6 +12,7 @@ use decision_gate_config::NamespaceAuthorityConfig;
 use decision_gate_config::NamespaceAuthorityMode;
 use decision_gate_config::PrincipalConfig;
 use decision_gate_config::PrincipalRoleConfig;
+use decision_gate_config::PrincipalScopesConfig;
 use decision_gate_config::ProviderConfig;
 use decision_gate_config::ProviderTimeoutConfig;
 use decision_gate_config::ProviderType;
@@ -78,6 +79,7 @@ fn bearer_tokens_at_max_auth_tokens_64() -> TestResult {
     let auth = ServerAuthConfig {
         mode: ServerAuthMode::BearerToken,
         bearer_tokens: tokens,
+        jwt: None,
         mtls_subjects: Vec::new(),
         allowed_tools: Vec::new(),
         principals: Vec::new(),
@@ -93,6 +95,7 @@ fn bearer_tokens_exceeds_max_auth_tokens_65() -> TestResult {
     let auth = ServerAuthConfig {
         mode: ServerAuthMode::BearerToken,
         bearer_tokens: tokens,
+        jwt: None,
         mtls_subjects: Vec::new(),
         allowed_tools: Vec::new(),
         principals: Vec::new(),
@@ -108,6 +111,7 @@ fn mtls_subjects_at_max_auth_tokens_64() -> TestResult {
     let auth = ServerAuthConfig {
         mode: ServerAuthMode::Mtls,
         bearer_tokens: Vec::new(),
+        jwt: None,
         mtls_subjects: subjects,
         allowed_tools: Vec::new(),
         principals: Vec::new(),
@@ -123,6 +127,7 @@ fn mtls_subjects_exceeds_max_auth_tokens_65() -> TestResult {
     let auth = ServerAuthConfig {
         mode: ServerAuthMode::Mtls,
         bearer_tokens: Vec::new(),
+        jwt: None,
         mtls_subjects: subjects,
         allowed_tools: Vec::new(),
         principals: Vec::new(),
@@ -145,6 +150,7 @@ fn allowed_tools_at_max_auth_tool_rules_128() -> TestResult {
     let auth = ServerAuthConfig {
         mode: ServerAuthMode::LocalOnly,
         bearer_tokens: Vec::new(),
+        jwt: None,
         mtls_subjects: Vec::new(),
         allowed_tools: tools,
         principals: Vec::new(),
@@ -167,6 +173,7 @@ fn allowed_tools_exceeds_max_auth_tool_rules_129() -> TestResult {
     let auth = ServerAuthConfig {
         mode: ServerAuthMode::LocalOnly,
         bearer_tokens: Vec::new(),
+        jwt: None,
         mtls_subjects: Vec::new(),
         allowed_tools: tools,
         principals: Vec::new(),
@@ -182,12 +189,14 @@ fn principals_at_max_auth_tokens_64() -> TestResult {
         .map(|i| PrincipalConfig {
             subject: format!("user{i}@example.com"),
             policy_class: None,
+            scopes: PrincipalScopesConfig::default(),
             roles: Vec::new(),
         })
         .collect();
     let auth = ServerAuthConfig {
         mode: ServerAuthMode::LocalOnly,
         bearer_tokens: Vec::new(),
+        jwt: None,
         mtls_subjects: Vec::new(),
         allowed_tools: Vec::new(),
         principals,
@@ -203,12 +212,14 @@ fn principals_exceeds_max_auth_tokens_65() -> TestResult {
         .map(|i| PrincipalConfig {
             subject: format!("user{i}@example.com"),
             policy_class: None,
+            scopes: PrincipalScopesConfig::default(),
             roles: Vec::new(),
         })
         .collect();
     let auth = ServerAuthConfig {
         mode: ServerAuthMode::LocalOnly,
         bearer_tokens: Vec::new(),
+        jwt: None,
         mtls_subjects: Vec::new(),
         allowed_tools: Vec::new(),
         principals,
@@ -230,11 +241,13 @@ fn principal_roles_at_max_principal_roles_128() -> TestResult {
     let principal = PrincipalConfig {
         subject: "user@example.com".to_string(),
         policy_class: None,
+        scopes: PrincipalScopesConfig::default(),
         roles,
     };
     let auth = ServerAuthConfig {
         mode: ServerAuthMode::LocalOnly,
         bearer_tokens: Vec::new(),
+        jwt: None,
         mtls_subjects: Vec::new(),
         allowed_tools: Vec::new(),
         principals: vec![principal],
@@ -256,11 +269,13 @@ fn principal_roles_exceeds_max_principal_roles_129() -> TestResult {
     let principal = PrincipalConfig {
         subject: "user@example.com".to_string(),
         policy_class: None,
+        scopes: PrincipalScopesConfig::default(),
         roles,
     };
     let auth = ServerAuthConfig {
         mode: ServerAuthMode::LocalOnly,
         bearer_tokens: Vec::new(),
+        jwt: None,
         mtls_subjects: Vec::new(),
         allowed_tools: Vec::new(),
         principals: vec![principal],
@@ -332,6 +347,7 @@ fn bearer_token_at_max_auth_token_length_256() -> TestResult {
     let auth = ServerAuthConfig {
         mode: ServerAuthMode::BearerToken,
         bearer_tokens: vec![token],
+        jwt: None,
         mtls_subjects: Vec::new(),
         allowed_tools: Vec::new(),
         principals: Vec::new(),
@@ -347,6 +363,7 @@ fn bearer_token_exceeds_max_auth_token_length_257() -> TestResult {
     let auth = ServerAuthConfig {
         mode: ServerAuthMode::BearerToken,
         bearer_tokens: vec![token],
+        jwt: None,
         mtls_subjects: Vec::new(),
         allowed_tools: Vec::new(),
         principals: Vec::new(),
@@ -362,6 +379,7 @@ fn mtls_subject_at_max_auth_subject_length_512() -> TestResult {
     let auth = ServerAuthConfig {
         mode: ServerAuthMode::Mtls,
         bearer_tokens: Vec::new(),
+        jwt: None,
         mtls_subjects: vec![subject],
         allowed_tools: Vec::new(),
         principals: Vec::new(),
@@ -377,6 +395,7 @@ fn mtls_subject_exceeds_max_auth_subject_length_513() -> TestResult {
     let auth = ServerAuthConfig {
         mode: ServerAuthMode::Mtls,
         bearer_tokens: Vec::new(),
+        jwt: None,
         mtls_subjects: vec![subject],
         allowed_tools: Vec::new(),
         principals: Vec::new(),
@@ -554,6 +573,7 @@ fn provider_connect_timeout_at_min_100() -> TestResult {
         timeouts: ProviderTimeoutConfig {
             connect_timeout_ms: MIN_PROVIDER_CONNECT_TIMEOUT_MS,
             request_timeout_ms: MIN_PROVIDER_REQUEST_TIMEOUT_MS,
+            query_timeout_ms: None,
         },
         config: None,
     }];
@@ -577,6 +597,7 @@ fn provider_connect_timeout_below_min_99() -> TestResult {
         timeouts: ProviderTimeoutConfig {
             connect_timeout_ms: MIN_PROVIDER_CONNECT_TIMEOUT_MS - 1,
             request_timeout_ms: MIN_PROVIDER_REQUEST_TIMEOUT_MS,
+            query_timeout_ms: None,
         },
         config: None,
     }];
@@ -603,6 +624,7 @@ fn provider_connect_timeout_at_max_10000() -> TestResult {
         timeouts: ProviderTimeoutConfig {
             connect_timeout_ms: MAX_PROVIDER_CONNECT_TIMEOUT_MS,
             request_timeout_ms: MAX_PROVIDER_REQUEST_TIMEOUT_MS,
+            query_timeout_ms: None,
         },
         config: None,
     }];
@@ -626,6 +648,7 @@ fn provider_connect_timeout_above_max_10001() -> TestResult {
         timeouts: ProviderTimeoutConfig {
             connect_timeout_ms: MAX_PROVIDER_CONNECT_TIMEOUT_MS + 1,
             request_timeout_ms: MAX_PROVIDER_REQUEST_TIMEOUT_MS,
+            query_timeout_ms: None,
         },
         config: None,
     }];
@@ -652,6 +675,7 @@ fn provider_request_timeout_at_min_500() -> TestResult {
         timeouts: ProviderTimeoutConfig {
             connect_timeout_ms: MIN_PROVIDER_CONNECT_TIMEOUT_MS,
             request_timeout_ms: MIN_PROVIDER_REQUEST_TIMEOUT_MS,
+            query_timeout_ms: None,
         },
         config: None,
     }];
@@ -675,6 +699,7 @@ fn provider_request_timeout_below_min_499() -> TestResult {
         timeouts: ProviderTimeoutConfig {
             connect_timeout_ms: MIN_PROVIDER_CONNECT_TIMEOUT_MS,
             request_timeout_ms: MIN_PROVIDER_REQUEST_TIMEOUT_MS - 1,
+            query_timeout_ms: None,
         },
         config: None,
     }];
@@ -701,6 +726,7 @@ fn provider_request_timeout_at_max_30000() -> TestResult {
         timeouts: ProviderTimeoutConfig {
             connect_timeout_ms: MIN_PROVIDER_CONNECT_TIMEOUT_MS,
             request_timeout_ms: MAX_PROVIDER_REQUEST_TIMEOUT_MS,
+            query_timeout_ms: None,
         },
         config: None,
     }];
@@ -724,6 +750,7 @@ fn provider_request_timeout_above_max_30001() -> TestResult {
         timeouts: ProviderTimeoutConfig {
             connect_timeout_ms: MIN_PROVIDER_CONNECT_TIMEOUT_MS,
             request_timeout_ms: MAX_PROVIDER_REQUEST_TIMEOUT_MS + 1,
+            query_timeout_ms: None,
         },
         config: None,
     }];
@@ -734,6 +761,45 @@ fn provider_request_timeout_above_max_30001() -> TestResult {
     Ok(())
 }
 
+#[test]
+fn provider_query_timeout_above_max_300001() -> TestResult {
+    let mut config = common::minimal_config().map_err(|err| err.to_string())?;
+    config.providers = vec![ProviderConfig {
+        name: "time".to_string(),
+        provider_type: ProviderType::Builtin,
+        command: Vec::new(),
+        url: None,
+        allow_insecure_http: false,
+        capabilities_path: None,
+        auth: None,
+        trust: None,
+        allow_raw: false,
+        timeouts: ProviderTimeoutConfig {
+            query_timeout_ms: Some(300_001),
+            ..ProviderTimeoutConfig::default()
+        },
+        config: None,
+    }];
+    assert_invalid(
+        config.validate(),
+        "providers.timeouts.query_timeout_ms must be between 10 and 300000 milliseconds",
+    )?;
+    Ok(())
+}
+
+#[test]
+fn evidence_query_timeout_below_min_9() -> TestResult {
+    let mut config = common::minimal_config().map_err(|err| err.to_string())?;
+    config.evidence.query_timeout_ms = Some(10);
+    config.validate().map_err(|err| err.to_string())?;
+    config.evidence.query_timeout_ms = Some(9);
+    assert_invalid(
+        config.validate(),
+        "evidence.query_timeout_ms must be between 10 and 300000 milliseconds",
+    )?;
+    Ok(())
+}
+
 // ============================================================================
 // SECTION: Namespace Authority Timeout Bounds
 // ============================================================================
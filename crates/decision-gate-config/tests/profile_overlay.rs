@@ -0,0 +1,167 @@
+//! Config profile overlay tests for decision-gate-config.
+// crates/decision-gate-config/tests/profile_overlay.rs
+// =============================================================================
+// Module: Config Profile Overlay Tests
+// Description: Validate profile overlay resolution and deep-merge semantics.
+// Purpose: Ensure overlays merge deterministically and fail closed.
+// =============================================================================
+
+use std::fs;
+use std::path::Path;
+use std::path::PathBuf;
+
+use decision_gate_config::ConfigError;
+use decision_gate_config::DecisionGateConfig;
+use decision_gate_config::ServerTransport;
+use tempfile::TempDir;
+
+type TestResult = Result<(), String>;
+
+const BASE_CONFIG: &str = r#"
+[server]
+transport = "stdio"
+max_body_bytes = 4096
+
+[server.limits]
+max_inflight = 16
+
+[[providers]]
+name = "time"
+type = "builtin"
+"#;
+
+fn write_configs(dir: &TempDir, base: &str, overlays: &[(&str, &str)]) -> Result<PathBuf, String> {
+    let base_path = dir.path().join("decision-gate.toml");
+    fs::write(&base_path, base).map_err(|err| err.to_string())?;
+    for (profile, contents) in overlays {
+        let overlay_path = dir.path().join(format!("decision-gate.{profile}.toml"));
+        fs::write(overlay_path, contents).map_err(|err| err.to_string())?;
+    }
+    Ok(base_path)
+}
+
+fn load(path: &Path, profile: &str) -> Result<DecisionGateConfig, ConfigError> {
+    DecisionGateConfig::load_with_profile(Some(path), Some(profile))
+}
+
+fn assert_invalid(result: Result<DecisionGateConfig, ConfigError>, needle: &str) -> TestResult {
+    match result {
+        Err(error) => {
+            let message = error.to_string();
+            if message.contains(needle) {
+                Ok(())
+            } else {
+                Err(format!("error {message} did not contain {needle}"))
+            }
+        }
+        Ok(_) => Err("expected invalid config load".to_string()),
+    }
+}
+
+fn provider_names(config: &DecisionGateConfig) -> Vec<&str> {
+    config.providers.iter().map(|provider| provider.name.as_str()).collect()
+}
+
+#[test]
+fn overlay_overrides_scalar_and_preserves_siblings() -> TestResult {
+    let dir = TempDir::new().map_err(|err| err.to_string())?;
+    let overlay = "[server]\nmax_body_bytes = 8192\n";
+    let path = write_configs(&dir, BASE_CONFIG, &[("prod", overlay)])?;
+    let config = load(&path, "prod").map_err(|err| err.to_string())?;
+    if config.server.max_body_bytes != 8192 {
+        return Err(format!(
+            "expected overlay max_body_bytes, got {}",
+            config.server.max_body_bytes
+        ));
+    }
+    if config.server.limits.max_inflight != 16 {
+        return Err("expected base server.limits to be preserved".to_string());
+    }
+    if config.server.transport != ServerTransport::Stdio {
+        return Err("expected base transport to be preserved".to_string());
+    }
+    Ok(())
+}
+
+#[test]
+fn overlay_replaces_arrays_by_default() -> TestResult {
+    let dir = TempDir::new().map_err(|err| err.to_string())?;
+    let overlay = "[[providers]]\nname = \"env\"\ntype = \"builtin\"\n";
+    let path = write_configs(&dir, BASE_CONFIG, &[("staging", overlay)])?;
+    let config = load(&path, "staging").map_err(|err| err.to_string())?;
+    if provider_names(&config) != ["env"] {
+        return Err(format!("expected replaced providers, got {:?}", provider_names(&config)));
+    }
+    Ok(())
+}
+
+#[test]
+fn overlay_appends_arrays_when_directed() -> TestResult {
+    let dir = TempDir::new().map_err(|err| err.to_string())?;
+    let overlay = r#"
+[overlay]
+append_arrays = ["providers"]
+
+[[providers]]
+name = "env"
+type = "builtin"
+"#;
+    let path = write_configs(&dir, BASE_CONFIG, &[("dev", overlay)])?;
+    let config = load(&path, "dev").map_err(|err| err.to_string())?;
+    if provider_names(&config) != ["time", "env"] {
+        return Err(format!("expected appended providers, got {:?}", provider_names(&config)));
+    }
+    Ok(())
+}
+
+#[test]
+fn merged_result_is_validated() -> TestResult {
+    let dir = TempDir::new().map_err(|err| err.to_string())?;
+    let overlay = r#"
+[overlay]
+append_arrays = ["providers"]
+
+[[providers]]
+name = "time"
+type = "builtin"
+"#;
+    let path = write_configs(&dir, BASE_CONFIG, &[("dup", overlay)])?;
+    assert_invalid(load(&path, "dup"), "duplicate provider name: time")?;
+    Ok(())
+}
+
+#[test]
+fn missing_overlay_file_fails_closed() -> TestResult {
+    let dir = TempDir::new().map_err(|err| err.to_string())?;
+    let path = write_configs(&dir, BASE_CONFIG, &[])?;
+    assert_invalid(load(&path, "prod"), "config io error")?;
+    Ok(())
+}
+
+#[test]
+fn invalid_profile_name_rejected() -> TestResult {
+    let dir = TempDir::new().map_err(|err| err.to_string())?;
+    let path = write_configs(&dir, BASE_CONFIG, &[])?;
+    assert_invalid(load(&path, "../prod"), "config profile must contain only")?;
+    assert_invalid(load(&path, ""), "config profile must be non-empty")?;
+    Ok(())
+}
+
+#[test]
+fn unknown_overlay_directive_rejected() -> TestResult {
+    let dir = TempDir::new().map_err(|err| err.to_string())?;
+    let overlay = "[overlay]\nprepend_arrays = [\"providers\"]\n";
+    let path = write_configs(&dir, BASE_CONFIG, &[("prod", overlay)])?;
+    assert_invalid(load(&path, "prod"), "unknown overlay directive")?;
+    Ok(())
+}
+
+#[test]
+fn append_directive_rejects_non_array_values() -> TestResult {
+    let dir = TempDir::new().map_err(|err| err.to_string())?;
+    let overlay =
+        "[overlay]\nappend_arrays = [\"server.max_body_bytes\"]\n\n[server]\nmax_body_bytes = 1\n";
+    let path = write_configs(&dir, BASE_CONFIG, &[("prod", overlay)])?;
+    assert_invalid(load(&path, "prod"), "must hold arrays")?;
+    Ok(())
+}
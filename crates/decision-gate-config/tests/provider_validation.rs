@@ -349,6 +349,7 @@ fn provider_timeouts_reject_request_below_connect() -> TestResult {
         timeouts: ProviderTimeoutConfig {
             connect_timeout_ms: 2_000,
             request_timeout_ms: 1_000,
+            query_timeout_ms: None,
         },
         config: None,
     }];
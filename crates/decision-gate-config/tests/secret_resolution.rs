@@ -0,0 +1,181 @@
+//! Config secret reference tests for decision-gate-config.
+// crates/decision-gate-config/tests/secret_resolution.rs
+// =============================================================================
+// Module: Config Secret Resolution Tests
+// Description: Validate env/file secret references for sensitive fields.
+// Purpose: Ensure secrets resolve before validation and fail closed.
+// =============================================================================
+
+use std::fs;
+
+use decision_gate_config::ConfigError;
+use decision_gate_config::DecisionGateConfig;
+use tempfile::TempDir;
+
+mod common;
+
+type TestResult = Result<(), String>;
+
+fn bearer_config(token: &str) -> String {
+    format!(
+        "[server]\ntransport = \"http\"\nbind = \"127.0.0.1:8080\"\n\n[server.auth]\nmode = \
+         \"bearer_token\"\nbearer_tokens = [\"{token}\"]\n"
+    )
+}
+
+fn lookup(name: &str) -> Option<String> {
+    match name {
+        "DG_TEST_TOKEN" => Some("env-token-value".to_string()),
+        "DG_TEST_EMPTY" => Some("   ".to_string()),
+        "DG_TEST_PADDED" => Some(" padded-token-value ".to_string()),
+        _ => None,
+    }
+}
+
+fn assert_error<T>(result: Result<T, ConfigError>, needle: &str) -> TestResult {
+    match result {
+        Err(error) => {
+            let message = error.to_string();
+            if message.contains(needle) {
+                Ok(())
+            } else {
+                Err(format!("error {message} did not contain {needle}"))
+            }
+        }
+        Ok(_) => Err("expected secret resolution failure".to_string()),
+    }
+}
+
+fn first_token(config: &DecisionGateConfig) -> Option<&str> {
+    config.server.auth.as_ref()?.bearer_tokens.first().map(String::as_str)
+}
+
+#[test]
+fn env_secret_resolves_before_validation() -> TestResult {
+    let mut config = common::config_from_toml(&bearer_config("secret://env/DG_TEST_TOKEN"))
+        .map_err(|err| err.to_string())?;
+    config.resolve_secrets_with(&lookup).map_err(|err| err.to_string())?;
+    config.validate().map_err(|err| err.to_string())?;
+    if first_token(&config) != Some("env-token-value") {
+        return Err("expected env secret to be resolved".to_string());
+    }
+    Ok(())
+}
+
+#[test]
+fn file_secret_resolves_on_load() -> TestResult {
+    let dir = TempDir::new().map_err(|err| err.to_string())?;
+    let secret_path = dir.path().join("token");
+    fs::write(&secret_path, "file-token-value\n").map_err(|err| err.to_string())?;
+    let reference = format!("secret://file{}", secret_path.display());
+    let config_path = dir.path().join("decision-gate.toml");
+    fs::write(&config_path, bearer_config(&reference)).map_err(|err| err.to_string())?;
+    let config = DecisionGateConfig::load(Some(&config_path)).map_err(|err| err.to_string())?;
+    if first_token(&config) != Some("file-token-value") {
+        return Err(
+            "expected file secret to be resolved with trailing newline stripped".to_string()
+        );
+    }
+    Ok(())
+}
+
+#[test]
+fn missing_env_secret_fails_closed() -> TestResult {
+    let mut config = common::config_from_toml(&bearer_config("secret://env/DG_TEST_MISSING"))
+        .map_err(|err| err.to_string())?;
+    assert_error(
+        config.resolve_secrets_with(&lookup),
+        "server.auth.bearer_tokens secret env var DG_TEST_MISSING is not set",
+    )?;
+    Ok(())
+}
+
+#[test]
+fn empty_env_secret_fails_closed() -> TestResult {
+    let mut config = common::config_from_toml(&bearer_config("secret://env/DG_TEST_EMPTY"))
+        .map_err(|err| err.to_string())?;
+    assert_error(config.resolve_secrets_with(&lookup), "secret resolved to an empty value")?;
+    Ok(())
+}
+
+#[test]
+fn missing_file_secret_fails_closed() -> TestResult {
+    let dir = TempDir::new().map_err(|err| err.to_string())?;
+    let reference = format!("secret://file{}", dir.path().join("absent").display());
+    let config_path = dir.path().join("decision-gate.toml");
+    fs::write(&config_path, bearer_config(&reference)).map_err(|err| err.to_string())?;
+    assert_error(DecisionGateConfig::load(Some(&config_path)), "secret file")?;
+    Ok(())
+}
+
+#[test]
+fn malformed_secret_reference_rejected() -> TestResult {
+    let mut config = common::config_from_toml(&bearer_config("secret://vault/token"))
+        .map_err(|err| err.to_string())?;
+    assert_error(config.resolve_secrets_with(&lookup), "unsupported secret reference scheme")?;
+    let mut config = common::config_from_toml(&bearer_config("secret://env/1BAD"))
+        .map_err(|err| err.to_string())?;
+    assert_error(config.resolve_secrets_with(&lookup), "secret env var name is invalid")?;
+    Ok(())
+}
+
+#[test]
+fn provider_bearer_token_secret_resolves() -> TestResult {
+    let toml = r#"
+[[providers]]
+name = "remote"
+type = "mcp"
+url = "https://provider.example.com/rpc"
+capabilities_path = "contracts/remote.json"
+
+[providers.auth]
+bearer_token = "secret://env/DG_TEST_TOKEN"
+"#;
+    let mut config = common::config_from_toml(toml).map_err(|err| err.to_string())?;
+    config.resolve_secrets_with(&lookup).map_err(|err| err.to_string())?;
+    let token = config
+        .providers
+        .first()
+        .and_then(|provider| provider.auth.as_ref())
+        .and_then(|auth| auth.bearer_token.as_deref());
+    if token != Some("env-token-value") {
+        return Err("expected provider bearer token secret to be resolved".to_string());
+    }
+    Ok(())
+}
+
+#[test]
+fn resolution_errors_do_not_echo_secret_values() -> TestResult {
+    let mut config = common::config_from_toml(&bearer_config("secret://env/DG_TEST_PADDED"))
+        .map_err(|err| err.to_string())?;
+    config.resolve_secrets_with(&lookup).map_err(|err| err.to_string())?;
+    let Err(error) = config.validate() else {
+        return Err("expected padded secret to fail validation".to_string());
+    };
+    let message = error.to_string();
+    if !message.contains("whitespace") {
+        return Err(format!("unexpected error {message}"));
+    }
+    if message.contains("padded-token-value") {
+        return Err("secret value leaked into error message".to_string());
+    }
+
+    let dir = TempDir::new().map_err(|err| err.to_string())?;
+    let secret_path = dir.path().join("token");
+    let long_secret = "long-token-value-".repeat(32);
+    fs::write(&secret_path, &long_secret).map_err(|err| err.to_string())?;
+    let config_path = dir.path().join("decision-gate.toml");
+    let reference = format!("secret://file{}", secret_path.display());
+    fs::write(&config_path, bearer_config(&reference)).map_err(|err| err.to_string())?;
+    let Err(error) = DecisionGateConfig::load(Some(&config_path)) else {
+        return Err("expected oversized secret to fail validation".to_string());
+    };
+    let message = error.to_string();
+    if !message.contains("too long") {
+        return Err(format!("unexpected error {message}"));
+    }
+    if message.contains("long-token-value") {
+        return Err("secret value leaked into error message".to_string());
+    }
+    Ok(())
+}
@@ -57,6 +57,10 @@ fn tls_cert_path_with_parent_directory_traversal() -> TestResult {
         key_path: "key.pem".to_string(),
         client_ca_path: None,
         require_client_cert: false,
+        reload_interval_ms: None,
+        max_client_chain_depth: None,
+        client_required_ekus: Vec::new(),
+        ocsp_response_path: None,
     });
     config.validate().map_err(|err| err.to_string())?;
     Ok(())
@@ -72,6 +76,10 @@ fn tls_key_path_with_dot_dot_component() -> TestResult {
         key_path: "path/../../../secret/key.pem".to_string(),
         client_ca_path: None,
         require_client_cert: false,
+        reload_interval_ms: None,
+        max_client_chain_depth: None,
+        client_required_ekus: Vec::new(),
+        ocsp_response_path: None,
     });
     config.validate().map_err(|err| err.to_string())?;
     Ok(())
@@ -87,6 +95,10 @@ fn tls_client_ca_path_with_traversal() -> TestResult {
         key_path: "key.pem".to_string(),
         client_ca_path: Some("../../ca.pem".to_string()),
         require_client_cert: false,
+        reload_interval_ms: None,
+        max_client_chain_depth: None,
+        client_required_ekus: Vec::new(),
+        ocsp_response_path: None,
     });
     config.validate().map_err(|err| err.to_string())?;
     Ok(())
@@ -114,6 +126,10 @@ fn store_path_with_traversal_attack() -> TestResult {
         journal_mode: SqliteStoreMode::Wal,
         sync_mode: SqliteSyncMode::Full,
         max_versions: None,
+        circuit_breaker: None,
+        cache: None,
+        retention: Vec::new(),
+        retention_interval_ms: 3_600_000,
     };
     config.validate().map_err(|err| err.to_string())?;
     Ok(())
@@ -204,6 +220,7 @@ fn bearer_token_with_sql_injection_payload() -> TestResult {
     let auth = ServerAuthConfig {
         mode: ServerAuthMode::BearerToken,
         bearer_tokens: vec!["'; DROP TABLE users--".to_string()],
+        jwt: None,
         mtls_subjects: Vec::new(),
         allowed_tools: Vec::new(),
         principals: Vec::new(),
@@ -282,6 +299,7 @@ fn auth_token_with_null_byte() -> TestResult {
     let auth = ServerAuthConfig {
         mode: ServerAuthMode::BearerToken,
         bearer_tokens: vec!["token\0value".to_string()],
+        jwt: None,
         mtls_subjects: Vec::new(),
         allowed_tools: Vec::new(),
         principals: Vec::new(),
@@ -378,6 +396,7 @@ fn auth_token_with_control_characters() -> TestResult {
     let auth = ServerAuthConfig {
         mode: ServerAuthMode::BearerToken,
         bearer_tokens: vec!["token\x01\x02\x03".to_string()],
+        jwt: None,
         mtls_subjects: Vec::new(),
         allowed_tools: Vec::new(),
         principals: Vec::new(),
@@ -417,6 +436,7 @@ fn auth_token_with_unicode() -> TestResult {
     let auth = ServerAuthConfig {
         mode: ServerAuthMode::BearerToken,
         bearer_tokens: vec!["tøken-välue-日本語".to_string()],
+        jwt: None,
         mtls_subjects: Vec::new(),
         allowed_tools: Vec::new(),
         principals: Vec::new(),
@@ -444,6 +464,7 @@ fn field_with_unicode_whitespace_u00a0() -> TestResult {
     let auth = ServerAuthConfig {
         mode: ServerAuthMode::BearerToken,
         bearer_tokens: vec!["token\u{00A0}value".to_string()],
+        jwt: None,
         mtls_subjects: Vec::new(),
         allowed_tools: Vec::new(),
         principals: Vec::new(),
@@ -459,6 +480,7 @@ fn field_with_unicode_whitespace_u2000() -> TestResult {
     let auth = ServerAuthConfig {
         mode: ServerAuthMode::BearerToken,
         bearer_tokens: vec!["token\u{2000}value".to_string()],
+        jwt: None,
         mtls_subjects: Vec::new(),
         allowed_tools: Vec::new(),
         principals: Vec::new(),
@@ -478,6 +500,7 @@ fn string_with_very_long_grapheme_cluster() -> TestResult {
     let auth = ServerAuthConfig {
         mode: ServerAuthMode::BearerToken,
         bearer_tokens: vec![base],
+        jwt: None,
         mtls_subjects: Vec::new(),
         allowed_tools: Vec::new(),
         principals: Vec::new(),
@@ -14,6 +14,7 @@ use decision_gate_config::ServerAuthMode;
 use decision_gate_config::ServerLimitsConfig;
 use decision_gate_config::ServerTlsConfig;
 use decision_gate_config::ServerTransport;
+use decision_gate_config::ToolConcurrencyConfig;
 
 mod common;
 
@@ -59,6 +60,7 @@ fn stdio_transport_rejects_non_local_auth() -> TestResult {
     config.server.auth = Some(ServerAuthConfig {
         mode: ServerAuthMode::BearerToken,
         bearer_tokens: vec!["token".to_string()],
+        jwt: None,
         mtls_subjects: Vec::new(),
         allowed_tools: Vec::new(),
         principals: Vec::new(),
@@ -76,6 +78,10 @@ fn stdio_transport_rejects_tls() -> TestResult {
         key_path: "server.key".to_string(),
         client_ca_path: None,
         require_client_cert: true,
+        reload_interval_ms: None,
+        max_client_chain_depth: None,
+        client_required_ekus: Vec::new(),
+        ocsp_response_path: None,
     });
     assert_invalid(config.validate(), "stdio transport does not support tls")?;
     Ok(())
@@ -91,11 +97,86 @@ fn tls_rejects_empty_paths() -> TestResult {
         key_path: String::new(),
         client_ca_path: None,
         require_client_cert: true,
+        reload_interval_ms: None,
+        max_client_chain_depth: None,
+        client_required_ekus: Vec::new(),
+        ocsp_response_path: None,
     });
     assert_invalid(config.validate(), "tls.cert_path must be non-empty")?;
     Ok(())
 }
 
+#[test]
+fn tls_rejects_reload_interval_out_of_range() -> TestResult {
+    let mut config = common::minimal_config().map_err(|err| err.to_string())?;
+    config.server.transport = ServerTransport::Http;
+    config.server.bind = Some("127.0.0.1:8080".to_string());
+    config.server.tls = Some(ServerTlsConfig {
+        cert_path: "server.pem".to_string(),
+        key_path: "server.key".to_string(),
+        client_ca_path: None,
+        require_client_cert: true,
+        reload_interval_ms: Some(10),
+        max_client_chain_depth: None,
+        client_required_ekus: Vec::new(),
+        ocsp_response_path: None,
+    });
+    assert_invalid(config.validate(), "tls.reload_interval_ms must be between")?;
+    Ok(())
+}
+
+fn policy_tls_config() -> ServerTlsConfig {
+    ServerTlsConfig {
+        cert_path: "server.pem".to_string(),
+        key_path: "server.key".to_string(),
+        client_ca_path: Some("ca.pem".to_string()),
+        require_client_cert: true,
+        reload_interval_ms: None,
+        max_client_chain_depth: Some(2),
+        client_required_ekus: vec!["1.3.6.1.5.5.7.3.2".to_string()],
+        ocsp_response_path: Some("server.ocsp".to_string()),
+    }
+}
+
+#[test]
+fn tls_accepts_client_cert_policy() -> TestResult {
+    let mut config = common::minimal_config().map_err(|err| err.to_string())?;
+    config.server.transport = ServerTransport::Http;
+    config.server.bind = Some("127.0.0.1:8080".to_string());
+    config.server.tls = Some(policy_tls_config());
+    config.validate().map_err(|err| err.to_string())?;
+    Ok(())
+}
+
+#[test]
+fn tls_client_cert_policy_requires_client_ca() -> TestResult {
+    let mut config = common::minimal_config().map_err(|err| err.to_string())?;
+    config.server.transport = ServerTransport::Http;
+    config.server.bind = Some("127.0.0.1:8080".to_string());
+    let mut tls = policy_tls_config();
+    tls.client_ca_path = None;
+    config.server.tls = Some(tls);
+    assert_invalid(config.validate(), "tls client certificate policy requires tls.client_ca_path")?;
+    Ok(())
+}
+
+#[test]
+fn tls_rejects_invalid_client_cert_policy() -> TestResult {
+    let mut config = common::minimal_config().map_err(|err| err.to_string())?;
+    config.server.transport = ServerTransport::Http;
+    config.server.bind = Some("127.0.0.1:8080".to_string());
+    let mut tls = policy_tls_config();
+    tls.client_required_ekus = vec!["clientAuth".to_string()];
+    config.server.tls = Some(tls);
+    assert_invalid(config.validate(), "tls.client_required_ekus entry is not a dotted OID")?;
+
+    let mut tls = policy_tls_config();
+    tls.max_client_chain_depth = Some(0);
+    config.server.tls = Some(tls);
+    assert_invalid(config.validate(), "tls.max_client_chain_depth must be between")?;
+    Ok(())
+}
+
 #[test]
 fn audit_rejects_empty_path() -> TestResult {
     let mut config = common::minimal_config().map_err(|err| err.to_string())?;
@@ -118,6 +199,7 @@ fn auth_bearer_requires_tokens() -> TestResult {
     config.server.auth = Some(ServerAuthConfig {
         mode: ServerAuthMode::BearerToken,
         bearer_tokens: Vec::new(),
+        jwt: None,
         mtls_subjects: Vec::new(),
         allowed_tools: Vec::new(),
         principals: Vec::new(),
@@ -134,6 +216,7 @@ fn auth_rejects_unknown_tool_in_allowlist() -> TestResult {
     config.server.auth = Some(ServerAuthConfig {
         mode: ServerAuthMode::LocalOnly,
         bearer_tokens: Vec::new(),
+        jwt: None,
         mtls_subjects: Vec::new(),
         allowed_tools: vec!["not_a_tool".to_string()],
         principals: Vec::new(),
@@ -150,6 +233,7 @@ fn auth_rejects_token_with_whitespace() -> TestResult {
     config.server.auth = Some(ServerAuthConfig {
         mode: ServerAuthMode::BearerToken,
         bearer_tokens: vec![" bad ".to_string()],
+        jwt: None,
         mtls_subjects: Vec::new(),
         allowed_tools: Vec::new(),
         principals: Vec::new(),
@@ -170,7 +254,36 @@ fn rate_limit_rejects_out_of_range_values() -> TestResult {
             window_ms: 50,
             max_entries: 0,
         }),
+        tool_concurrency: Vec::new(),
     };
     assert_invalid(config.validate(), "rate_limit max_requests must be greater than zero")?;
     Ok(())
 }
+
+#[test]
+fn tool_concurrency_rejects_invalid_entries() -> TestResult {
+    let limit = |tool: &str, max_concurrent: usize, max_queue_wait_ms: u64| ToolConcurrencyConfig {
+        tool: tool.to_string(),
+        max_concurrent,
+        max_queue_wait_ms,
+    };
+    let cases = [
+        (vec![limit("not_a_tool", 1, 0)], "unknown tool in server.limits.tool_concurrency"),
+        (vec![limit("scenario_submit", 0, 0)], "max_concurrent must be between 1 and"),
+        (vec![limit("scenario_submit", 1, 30_001)], "max_queue_wait_ms must be <= 30000"),
+        (
+            vec![limit("scenario_submit", 1, 0), limit("scenario_submit", 2, 0)],
+            "duplicate server.limits.tool_concurrency entry: scenario_submit",
+        ),
+    ];
+    for (entries, needle) in cases {
+        let mut config = common::minimal_config().map_err(|err| err.to_string())?;
+        config.server.limits.tool_concurrency = entries;
+        assert_invalid(config.validate(), needle)?;
+    }
+    let mut config = common::minimal_config().map_err(|err| err.to_string())?;
+    config.server.limits.tool_concurrency =
+        vec![limit("scenario_submit", 4, 250), limit("evidence_query", 8, 0)];
+    config.validate().map_err(|err| err.to_string())?;
+    Ok(())
+}
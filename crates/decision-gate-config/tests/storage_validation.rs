@@ -11,8 +11,10 @@ use std::path::PathBuf;
 use decision_gate_config::ConfigError;
 use decision_gate_config::ObjectStoreConfig;
 use decision_gate_config::ObjectStoreProvider;
+use decision_gate_config::RunStateCacheConfig;
 use decision_gate_config::RunStateStoreType;
 use decision_gate_config::RunpackStorageConfig;
+use decision_gate_config::StoreCircuitBreakerConfig;
 
 mod common;
 
@@ -60,6 +62,48 @@ fn run_state_store_sqlite_rejects_zero_max_versions() -> TestResult {
     Ok(())
 }
 
+#[test]
+fn run_state_store_circuit_breaker_rejects_out_of_range_settings() -> TestResult {
+    let mut config = common::minimal_config().map_err(|err| err.to_string())?;
+    config.run_state_store.circuit_breaker = Some(StoreCircuitBreakerConfig::default());
+    config.validate().map_err(|err| err.to_string())?;
+    let cases = [
+        (
+            StoreCircuitBreakerConfig {
+                failure_threshold: 0,
+                ..StoreCircuitBreakerConfig::default()
+            },
+            "failure_threshold must be between 1",
+        ),
+        (
+            StoreCircuitBreakerConfig {
+                open_ms: 10,
+                ..StoreCircuitBreakerConfig::default()
+            },
+            "open_ms must be between 100",
+        ),
+        (
+            StoreCircuitBreakerConfig {
+                success_threshold: 0,
+                ..StoreCircuitBreakerConfig::default()
+            },
+            "success_threshold must be between 1",
+        ),
+        (
+            StoreCircuitBreakerConfig {
+                slow_call_ms: Some(0),
+                ..StoreCircuitBreakerConfig::default()
+            },
+            "slow_call_ms must be between 1",
+        ),
+    ];
+    for (breaker, needle) in cases {
+        config.run_state_store.circuit_breaker = Some(breaker);
+        assert_invalid(config.validate(), needle)?;
+    }
+    Ok(())
+}
+
 #[test]
 fn runpack_storage_requires_bucket() -> TestResult {
     let mut config = common::minimal_config().map_err(|err| err.to_string())?;
@@ -107,3 +151,31 @@ fn runpack_storage_rejects_prefix_traversal() -> TestResult {
     assert_invalid(config.validate(), "runpack_storage.prefix must be relative without traversal")?;
     Ok(())
 }
+
+#[test]
+fn run_state_store_cache_rejects_out_of_range_settings() -> TestResult {
+    let mut config = common::minimal_config().map_err(|err| err.to_string())?;
+    config.run_state_store.cache = Some(RunStateCacheConfig::default());
+    config.validate().map_err(|err| err.to_string())?;
+    let cases = [
+        (
+            RunStateCacheConfig {
+                max_entries: 0,
+                ..RunStateCacheConfig::default()
+            },
+            "max_entries must be between 1",
+        ),
+        (
+            RunStateCacheConfig {
+                flush_interval_ms: 10,
+                ..RunStateCacheConfig::default()
+            },
+            "flush_interval_ms must be between 100",
+        ),
+    ];
+    for (cache, needle) in cases {
+        config.run_state_store.cache = Some(cache);
+        assert_invalid(config.validate(), needle)?;
+    }
+    Ok(())
+}
@@ -9,7 +9,8 @@
 //! ## Overview
 //! This module validates and normalizes [`ScenarioSpec`] authoring inputs. JSON is
 //! the canonical format; RON is accepted for human-friendly authoring and is
-//! normalized into canonical JSON (RFC 8785 / JCS).
+//! normalized into canonical JSON (RFC 8785 / JCS). Templates may carry
+//! `${param}` placeholders that are substituted before normalization.
 //! Security posture: authoring inputs are untrusted; see
 //! `Docs/security/threat_model.md`.
 
@@ -17,6 +18,7 @@
 // SECTION: Imports
 // ============================================================================
 
+use std::collections::BTreeSet;
 use std::fmt;
 use std::fmt::Write;
 use std::path::Path;
@@ -29,6 +31,7 @@ use decision_gate_core::hashing::hash_bytes;
 use decision_gate_core::runtime::MAX_RUNPACK_ARTIFACT_BYTES;
 use jsonschema::Draft;
 use jsonschema::Validator;
+use serde_json::Map;
 use serde_json::Value;
 use thiserror::Error;
 
@@ -111,6 +114,19 @@ pub struct NormalizedScenario {
     pub spec_hash: HashDigest,
 }
 
+/// Rendered scenario template with parameter usage details.
+///
+/// # Invariants
+/// - Every placeholder in the template was substituted before normalization.
+/// - `unused_params` is sorted and lists provided parameters never referenced.
+#[derive(Debug, Clone)]
+pub struct RenderedScenario {
+    /// Normalized scenario produced from the substituted template.
+    pub normalized: NormalizedScenario,
+    /// Provided parameters that the template never referenced.
+    pub unused_params: Vec<String>,
+}
+
 // ============================================================================
 // SECTION: Errors
 // ============================================================================
@@ -177,6 +193,18 @@ pub enum AuthoringError {
         /// Observed size in bytes.
         actual_bytes: usize,
     },
+    /// Template placeholders or parameters are malformed.
+    #[error("template error: {error}")]
+    Template {
+        /// Template error details.
+        error: String,
+    },
+    /// Template placeholders reference parameters that were not provided.
+    #[error("missing template parameters: {}", names.join(", "))]
+    MissingTemplateParams {
+        /// Sorted names of the missing parameters.
+        names: Vec<String>,
+    },
 }
 
 // ============================================================================
@@ -204,6 +232,54 @@ pub fn normalize_scenario(
 ) -> Result<NormalizedScenario, AuthoringError> {
     enforce_input_size_limit(input)?;
     let value = parse_value(input, format)?;
+    normalize_value(value)
+}
+
+/// Substitute `${param}` placeholders in a [`ScenarioSpec`] template, then
+/// normalize the result.
+///
+/// A string that is exactly one placeholder takes the parameter's JSON value
+/// (so numbers and booleans stay typed); placeholders embedded in longer
+/// strings require string, number, or boolean parameters. `$${` escapes a
+/// literal `${`. Every placeholder must have a parameter; unused parameters
+/// are reported in [`RenderedScenario::unused_params`].
+///
+/// # Errors
+///
+/// Returns [`AuthoringError::MissingTemplateParams`] when placeholders lack
+/// parameters, [`AuthoringError::Template`] for malformed placeholders or
+/// parameters, and any [`normalize_scenario`] error for the rendered spec.
+#[must_use = "use the rendered scenario output or handle the error"]
+pub fn render_scenario_template(
+    template: &str,
+    format: AuthoringFormat,
+    params: &Value,
+) -> Result<RenderedScenario, AuthoringError> {
+    let Value::Object(params) = params else {
+        return Err(AuthoringError::Template {
+            error: "template parameters must be a JSON object".to_string(),
+        });
+    };
+    enforce_input_size_limit(template)?;
+    let mut value = parse_value(template, format)?;
+    enforce_depth_limit(&value)?;
+    let mut usage = PlaceholderUsage::default();
+    substitute_placeholders(&mut value, params, &mut usage)?;
+    if !usage.missing.is_empty() {
+        return Err(AuthoringError::MissingTemplateParams {
+            names: usage.missing.into_iter().collect(),
+        });
+    }
+    let unused_params =
+        params.keys().filter(|name| !usage.referenced.contains(*name)).cloned().collect();
+    Ok(RenderedScenario {
+        normalized: normalize_value(value)?,
+        unused_params,
+    })
+}
+
+/// Validate and canonicalize a parsed [`ScenarioSpec`] value.
+fn normalize_value(value: Value) -> Result<NormalizedScenario, AuthoringError> {
     enforce_depth_limit(&value)?;
     validate_scenario_schema(&value)?;
     let spec: ScenarioSpec =
@@ -248,7 +324,8 @@ pub fn authoring_markdown() -> String {
     out.push_str("## Canonical JSON\n\n");
     out.push_str("- Canonical JSON uses RFC 8785 (JCS) for deterministic ordering.\n");
     out.push_str("- ScenarioSpec hashes are computed over canonical JSON bytes.\n");
-    out.push_str("- Canonical JSON is emitted by `decision-gate authoring normalize`.\n\n");
+    out.push_str("- Canonical JSON is emitted by `decision-gate authoring normalize`.\n");
+    out.push_str("- `decision-gate authoring hash` prints only the spec hash (`sha256:...`).\n\n");
     out.push_str("## Supported Inputs\n\n");
     out.push_str("- JSON: canonical format for storage, hashing, and validation.\n");
     out.push_str("- RON: authoring-only format normalized to canonical JSON.\n");
@@ -274,6 +351,16 @@ pub fn authoring_markdown() -> String {
     );
     out.push_str("  --output examples/scenario.json\n");
     out.push_str("```\n\n");
+    out.push_str("Print the spec hash for cache keys:\n\n");
+    out.push_str("```bash\n");
+    out.push_str("decision-gate authoring hash --input examples/scenario.ron\n");
+    out.push_str("```\n\n");
+    out.push_str("## Templates\n\n");
+    out.push_str("`decision-gate authoring render` substitutes `${param}` placeholders from a ");
+    out.push_str("JSON object of parameters before normalization. A string that is exactly one ");
+    out.push_str("placeholder takes the parameter's JSON type; embedded placeholders need ");
+    out.push_str("string, number, or boolean values. `$${` escapes a literal `${`. Missing ");
+    out.push_str("parameters are errors and unused parameters are reported as warnings.\n\n");
     out.push_str("## References\n\n");
     out.push_str("- `examples/scenario.ron`: authoring example in RON.\n");
     out.push_str("- `examples/scenario.json`: canonical JSON output.\n");
@@ -365,6 +452,135 @@ fn max_value_depth(value: &Value) -> usize {
     max_depth
 }
 
+// ============================================================================
+// SECTION: Template Helpers
+// ============================================================================
+
+/// Placeholder references collected while rendering a template.
+#[derive(Debug, Default)]
+struct PlaceholderUsage {
+    /// Parameter names referenced by the template.
+    referenced: BTreeSet<String>,
+    /// Referenced parameter names with no provided value.
+    missing: BTreeSet<String>,
+}
+
+/// Template string segment.
+enum Segment<'a> {
+    /// Literal text copied verbatim.
+    Literal(&'a str),
+    /// `${name}` placeholder.
+    Placeholder(&'a str),
+}
+
+/// Replace placeholders in every string value of the template.
+fn substitute_placeholders(
+    value: &mut Value,
+    params: &Map<String, Value>,
+    usage: &mut PlaceholderUsage,
+) -> Result<(), AuthoringError> {
+    match value {
+        Value::String(text) => {
+            if let Some(rendered) = substitute_string(text, params, usage)? {
+                *value = rendered;
+            }
+        }
+        Value::Array(items) => {
+            for item in items {
+                substitute_placeholders(item, params, usage)?;
+            }
+        }
+        Value::Object(map) => {
+            for item in map.values_mut() {
+                substitute_placeholders(item, params, usage)?;
+            }
+        }
+        Value::Null | Value::Bool(_) | Value::Number(_) => {}
+    }
+    Ok(())
+}
+
+/// Render one template string, returning `None` when it has no placeholders.
+fn substitute_string(
+    text: &str,
+    params: &Map<String, Value>,
+    usage: &mut PlaceholderUsage,
+) -> Result<Option<Value>, AuthoringError> {
+    if !text.contains("${") {
+        return Ok(None);
+    }
+    let segments = split_placeholders(text)?;
+    if let [Segment::Placeholder(name)] = segments.as_slice() {
+        usage.referenced.insert((*name).to_string());
+        let Some(param) = params.get(*name) else {
+            usage.missing.insert((*name).to_string());
+            return Ok(None);
+        };
+        return Ok(Some(param.clone()));
+    }
+    let mut rendered = String::with_capacity(text.len());
+    for segment in segments {
+        match segment {
+            Segment::Literal(literal) => rendered.push_str(literal),
+            Segment::Placeholder(name) => {
+                usage.referenced.insert(name.to_string());
+                match params.get(name) {
+                    Some(Value::String(param)) => rendered.push_str(param),
+                    Some(param @ (Value::Number(_) | Value::Bool(_))) => {
+                        let _ = write!(rendered, "{param}");
+                    }
+                    Some(_) => {
+                        return Err(AuthoringError::Template {
+                            error: format!(
+                                "parameter {name} must be a string, number, or boolean when \
+                                 embedded in text"
+                            ),
+                        });
+                    }
+                    None => {
+                        usage.missing.insert(name.to_string());
+                    }
+                }
+            }
+        }
+    }
+    Ok(Some(Value::String(rendered)))
+}
+
+/// Split a template string into literal and placeholder segments.
+fn split_placeholders(text: &str) -> Result<Vec<Segment<'_>>, AuthoringError> {
+    let mut segments = Vec::new();
+    let mut rest = text;
+    while let Some(start) = rest.find("${") {
+        if rest[.. start].ends_with('$') {
+            segments.push(Segment::Literal(&rest[.. start - 1]));
+            segments.push(Segment::Literal("${"));
+            rest = &rest[start + 2 ..];
+            continue;
+        }
+        segments.push(Segment::Literal(&rest[.. start]));
+        let after = &rest[start + 2 ..];
+        let Some(end) = after.find('}') else {
+            return Err(AuthoringError::Template {
+                error: format!("unterminated placeholder in {text:?}"),
+            });
+        };
+        let name = &after[.. end];
+        if name.is_empty()
+            || !name.chars().all(|ch| ch.is_ascii_alphanumeric() || matches!(ch, '_' | '-' | '.'))
+        {
+            return Err(AuthoringError::Template {
+                error: format!("invalid placeholder name {name:?}"),
+            });
+        }
+        segments.push(Segment::Placeholder(name));
+        rest = &after[end + 1 ..];
+    }
+    segments.push(Segment::Literal(rest));
+    segments.retain(|segment| !matches!(segment, Segment::Literal("")));
+    Ok(segments)
+}
+
 /// Compile the [`ScenarioSpec`] JSON schema for validation.
 fn compile_schema(schema: &Value) -> Result<Validator, AuthoringError> {
     jsonschema::options().with_draft(Draft::Draft202012).build(schema).map_err(|err| {
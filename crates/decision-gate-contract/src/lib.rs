@@ -63,6 +63,7 @@ pub enum ContractError {
 pub use authoring::AuthoringError;
 pub use authoring::AuthoringFormat;
 pub use authoring::NormalizedScenario;
+pub use authoring::RenderedScenario;
 pub use contract::ContractBuilder;
 pub use types::ContractArtifact;
 pub use types::ContractBundle;
@@ -41,6 +41,8 @@ pub fn provider_contracts() -> Vec<ProviderContract> {
         env_provider_contract(),
         json_provider_contract(),
         http_provider_contract(),
+        sqlite_provider_contract(),
+        postgres_provider_contract(),
     ]
 }
 
@@ -277,24 +279,7 @@ fn json_provider_contract() -> ProviderContract {
             "dynamic_type": true
         }
     });
-    let allowed_comparators = canonicalize_comparators(vec![
-        Comparator::Equals,
-        Comparator::NotEquals,
-        Comparator::GreaterThan,
-        Comparator::GreaterThanOrEqual,
-        Comparator::LessThan,
-        Comparator::LessThanOrEqual,
-        Comparator::LexGreaterThan,
-        Comparator::LexGreaterThanOrEqual,
-        Comparator::LexLessThan,
-        Comparator::LexLessThanOrEqual,
-        Comparator::Contains,
-        Comparator::InSet,
-        Comparator::DeepEquals,
-        Comparator::DeepNotEquals,
-        Comparator::Exists,
-        Comparator::NotExists,
-    ]);
+    let allowed_comparators = jsonpath_result_comparators();
     ProviderContract {
         provider_id: String::from("json"),
         name: String::from("JSON Provider"),
@@ -343,6 +328,10 @@ fn json_provider_contract() -> ProviderContract {
             String::from(
                 "Missing JSONPath yields a null value with error metadata (jsonpath_not_found).",
             ),
+            String::from(
+                "Fan-out JSONPath queries over documents larger than max_jsonpath_nodes fail \
+                 closed (jsonpath_budget_exceeded).",
+            ),
         ],
     }
 }
@@ -358,7 +347,8 @@ fn http_provider_contract() -> ProviderContract {
         provider_id: String::from("http"),
         name: String::from("HTTP Provider"),
         description: String::from(
-            "Issues bounded HTTP GET requests and returns status codes or body hashes.",
+            "Issues bounded HTTP GET requests for status codes, body hashes, or paginated JSON \
+             items, and JSON POST queries from configured body templates.",
         ),
         transport: String::from("builtin"),
         config_schema: http_config_schema(),
@@ -398,10 +388,266 @@ fn http_provider_contract() -> ProviderContract {
                     }),
                 }],
             },
+            http_json_query_check(),
+            http_json_pages_check(),
         ],
         notes: vec![
             String::from("Scheme and host allowlists are enforced by configuration."),
             String::from("Responses are size-limited and hashed deterministically."),
+            String::from(
+                "json_query bodies come from config query_templates; only configure templates for \
+                 read-semantics endpoints (GraphQL queries, search APIs).",
+            ),
+            String::from(
+                "Template strings that are exactly {{name}} take the variable's JSON value; \
+                 placeholders inside longer strings take its text.",
+            ),
+            String::from(
+                "json_query fails on non-2xx statuses and non-JSON responses; a JSONPath miss \
+                 yields a null value with error metadata (jsonpath_not_found).",
+            ),
+            String::from(
+                "json_pages follows a body cursor or Link rel=\"next\" header; reaching max_pages \
+                 with a page pending or exceeding max_total_bytes yields a null value with error \
+                 metadata (pagination_limit_reached).",
+            ),
+            String::from(
+                "Response transforms map JSON templates whose \"$...\" strings are JSONPath \
+                 selectors over the result; a selector miss or oversized output yields a null \
+                 value with error metadata (transform_failed).",
+            ),
+            String::from(
+                "With max_retries set, GET checks retry connection errors, timeouts, 429, and 5xx \
+                 with full-jitter backoff, honoring Retry-After within retry_deadline_ms; \
+                 json_query POSTs are never retried.",
+            ),
+            String::from(
+                "With cache_max_entries set, body_hash and json_pages revalidate cached responses \
+                 with If-None-Match/If-Modified-Since and reuse the cached body on 304.",
+            ),
+        ],
+    }
+}
+
+/// Returns the contract for the templated HTTP `json_query` check.
+fn http_json_query_check() -> CheckContract {
+    let query_schema = json!({
+        "description": "JSONPath result value from the JSON response (dynamic JSON type).",
+        "x-decision-gate": {
+            "dynamic_type": true
+        }
+    });
+    CheckContract {
+        check_id: String::from("json_query"),
+        description: String::from(
+            "POST a JSON body rendered from a configured template and select a value from the \
+             JSON response via JSONPath.",
+        ),
+        determinism: DeterminismClass::External,
+        params_required: true,
+        params_schema: http_json_query_schema(),
+        result_schema: query_schema,
+        allowed_comparators: jsonpath_result_comparators(),
+        anchor_types: vec![String::from("url")],
+        content_types: vec![String::from("application/json")],
+        examples: vec![CheckExample {
+            description: String::from("Read a repository status via a GraphQL template."),
+            params: json!({
+                "url": "https://api.example.com/graphql",
+                "template": "repo_status",
+                "variables": { "repo": "decision-gate" },
+                "jsonpath": "$.data.repository.status"
+            }),
+            result: json!("green"),
+        }],
+    }
+}
+
+/// Returns the contract for the paginated HTTP `json_pages` check.
+fn http_json_pages_check() -> CheckContract {
+    let pages_schema = json!({
+        "description": "Items collected across pages (array) or their count (integer).",
+        "x-decision-gate": {
+            "dynamic_type": true
+        }
+    });
+    CheckContract {
+        check_id: String::from("json_pages"),
+        description: String::from(
+            "GET a paginated JSON endpoint, following next-page cursors or Link headers, and \
+             aggregate JSONPath-selected items in page order.",
+        ),
+        determinism: DeterminismClass::External,
+        params_required: true,
+        params_schema: http_json_pages_schema(),
+        result_schema: pages_schema,
+        allowed_comparators: jsonpath_result_comparators(),
+        anchor_types: vec![String::from("url")],
+        content_types: vec![String::from("application/json")],
+        examples: vec![CheckExample {
+            description: String::from("Count open incidents across cursor-paginated pages."),
+            params: json!({
+                "url": "https://api.example.com/incidents?state=open",
+                "items": "$.incidents[*]",
+                "next": { "type": "cursor", "jsonpath": "$.next_cursor", "param": "cursor" },
+                "aggregate": "count"
+            }),
+            result: json!(3),
+        }],
+    }
+}
+
+/// Returns the contract for the built-in sqlite provider.
+#[must_use]
+fn sqlite_provider_contract() -> ProviderContract {
+    let row_schema = json!({
+        "description": "Column value for a single-column query, an object keyed by column name \
+                        otherwise, or null when no rows match (dynamic JSON type).",
+        "x-decision-gate": {
+            "dynamic_type": true
+        }
+    });
+    ProviderContract {
+        provider_id: String::from("sqlite"),
+        name: String::from("SQLite Query Provider"),
+        description: String::from(
+            "Runs allowlisted read-only SELECT statements against a local SQLite database.",
+        ),
+        transport: String::from("builtin"),
+        config_schema: sqlite_config_schema(),
+        checks: vec![CheckContract {
+            check_id: String::from("query"),
+            description: String::from(
+                "Run a named query with named bind values and return its first row.",
+            ),
+            determinism: DeterminismClass::External,
+            params_required: true,
+            params_schema: sqlite_query_schema(),
+            result_schema: row_schema,
+            allowed_comparators: jsonpath_result_comparators(),
+            anchor_types: vec![String::from("sqlite_query")],
+            content_types: vec![
+                String::from("application/json"),
+                String::from("application/octet-stream"),
+            ],
+            examples: vec![
+                CheckExample {
+                    description: String::from("Read a release status by name."),
+                    params: json!({
+                        "query": "release_status",
+                        "params": { "release": "v1.2.3" }
+                    }),
+                    result: json!("approved"),
+                },
+                CheckExample {
+                    description: String::from("Read a multi-column row as an object."),
+                    params: json!({ "query": "latest_build" }),
+                    result: json!({ "id": 42, "status": "passed" }),
+                },
+            ],
+        }],
+        notes: vec![
+            String::from("The database is opened read-only with query_only enabled."),
+            String::from(
+                "Query text comes only from config; every query must be a single read-only \
+                 SELECT using named parameters (:name, @name, $name).",
+            ),
+            String::from(
+                "Each statement parameter must be supplied exactly once; unknown bind names fail.",
+            ),
+            String::from(
+                "Queries producing more than max_rows rows, exceeding timeout_ms, or serializing \
+                 past max_value_bytes fail closed.",
+            ),
+            String::from("Single-column BLOB values are returned as bytes."),
+        ],
+    }
+}
+
+/// Returns the contract for the built-in postgres provider.
+#[must_use]
+fn postgres_provider_contract() -> ProviderContract {
+    let single_row_schema = json!({
+        "description": "Column value for a single-column query, an object keyed by column name \
+                        otherwise, or null when no rows match (dynamic JSON type).",
+        "x-decision-gate": {
+            "dynamic_type": true
+        }
+    });
+    let row_set_schema = json!({
+        "type": "array",
+        "items": { "type": "object" },
+        "description": "Rows as objects keyed by column name, in result order."
+    });
+    let row_set_allowed = allowed_comparators_for_schema(&row_set_schema);
+    ProviderContract {
+        provider_id: String::from("postgres"),
+        name: String::from("Postgres Query Provider"),
+        description: String::from(
+            "Runs allowlisted SELECT statements in read-only transactions against a Postgres \
+             database whose DSN is read from an environment variable.",
+        ),
+        transport: String::from("builtin"),
+        config_schema: postgres_config_schema(),
+        checks: vec![
+            CheckContract {
+                check_id: String::from("query"),
+                description: String::from(
+                    "Run a named query with positional bind values and return its single row.",
+                ),
+                determinism: DeterminismClass::External,
+                params_required: true,
+                params_schema: postgres_query_schema(),
+                result_schema: single_row_schema,
+                allowed_comparators: jsonpath_result_comparators(),
+                anchor_types: vec![String::from("postgres_query")],
+                content_types: vec![
+                    String::from("application/json"),
+                    String::from("application/octet-stream"),
+                ],
+                examples: vec![CheckExample {
+                    description: String::from("Count approvals for a release."),
+                    params: json!({ "query": "release_approvals", "params": ["v1.2.3"] }),
+                    result: json!(2),
+                }],
+            },
+            CheckContract {
+                check_id: String::from("rows"),
+                description: String::from(
+                    "Run a named query with positional bind values and return up to max_rows rows.",
+                ),
+                determinism: DeterminismClass::External,
+                params_required: true,
+                params_schema: postgres_query_schema(),
+                result_schema: row_set_schema,
+                allowed_comparators: row_set_allowed,
+                anchor_types: vec![String::from("postgres_query")],
+                content_types: vec![String::from("application/json")],
+                examples: vec![CheckExample {
+                    description: String::from("List open blockers for a release."),
+                    params: json!({ "query": "open_blockers", "params": ["v1.2.3"] }),
+                    result: json!([{ "id": 7, "title": "flaky migration" }]),
+                }],
+            },
+        ],
+        notes: vec![
+            String::from(
+                "Available only when Decision Gate is built with the postgres feature; other \
+                 builds reject the provider at startup.",
+            ),
+            String::from("The DSN is read from the variable named by dsn_env, never from config."),
+            String::from(
+                "Query text comes only from config; every query must be a SELECT and runs in a \
+                 read-only transaction with statement_timeout_ms applied.",
+            ),
+            String::from(
+                "Bind values fill $1, $2, ... in order and must match the statement's parameter \
+                 count and types.",
+            ),
+            String::from(
+                "query fails closed on more than one row; rows fails closed past max_rows; \
+                 results serializing past max_value_bytes fail closed.",
+            ),
         ],
     }
 }
@@ -410,6 +656,29 @@ fn http_provider_contract() -> ProviderContract {
 // SECTION: Comparator Defaults
 // ============================================================================
 
+/// Returns the comparator allow-list for dynamically typed `JSONPath` results.
+#[must_use]
+fn jsonpath_result_comparators() -> Vec<Comparator> {
+    canonicalize_comparators(vec![
+        Comparator::Equals,
+        Comparator::NotEquals,
+        Comparator::GreaterThan,
+        Comparator::GreaterThanOrEqual,
+        Comparator::LessThan,
+        Comparator::LessThanOrEqual,
+        Comparator::LexGreaterThan,
+        Comparator::LexGreaterThanOrEqual,
+        Comparator::LexLessThan,
+        Comparator::LexLessThanOrEqual,
+        Comparator::Contains,
+        Comparator::InSet,
+        Comparator::DeepEquals,
+        Comparator::DeepNotEquals,
+        Comparator::Exists,
+        Comparator::NotExists,
+    ])
+}
+
 /// Returns the comparator allow-list for a check result schema.
 #[must_use]
 fn allowed_comparators_for_schema(schema: &Value) -> Vec<Comparator> {
@@ -821,6 +1090,13 @@ fn json_config_schema() -> Value {
                 "type": "boolean",
                 "description": "Allow YAML parsing for .yaml/.yml files.",
                 "default": true
+            },
+            "max_jsonpath_nodes": {
+                "type": "integer",
+                "minimum": 1,
+                "description": "Node budget for JSONPath queries that fan out (recursive \
+                                descent, wildcards, filters, slices).",
+                "default": 100_000
             }
         },
         "required": ["root", "root_id"],
@@ -863,11 +1139,152 @@ fn http_config_schema() -> Value {
             },
             "hash_algorithm": {
                 "type": "string",
-                "enum": ["sha256"],
+                "enum": ["sha256", "blake3"],
                 "description": "Hash algorithm used for body_hash responses.",
                 "default": "sha256"
+            },
+            "query_templates": {
+                "type": "object",
+                "additionalProperties": true,
+                "description": "Named JSON body templates for json_query checks.",
+                "default": {}
+            },
+            "max_pages": {
+                "type": "integer",
+                "minimum": 0,
+                "description": "Maximum pages fetched by a json_pages check.",
+                "default": 10
+            },
+            "max_total_bytes": {
+                "type": "integer",
+                "minimum": 0,
+                "description": "Maximum bytes read across all pages of a json_pages check.",
+                "default": 4_194_304
+            },
+            "response_transforms": {
+                "type": "object",
+                "additionalProperties": true,
+                "description": "Named response transforms for json_query and json_pages checks.",
+                "default": {}
+            },
+            "max_retries": {
+                "type": "integer",
+                "minimum": 0,
+                "description": "Retries after the first attempt of a GET check (0 disables).",
+                "default": 0
+            },
+            "base_delay_ms": {
+                "type": "integer",
+                "minimum": 0,
+                "description": "Base delay for full-jitter exponential retry backoff.",
+                "default": 100
+            },
+            "max_delay_ms": {
+                "type": "integer",
+                "minimum": 0,
+                "description": "Upper bound on a single retry backoff delay.",
+                "default": 2_000
+            },
+            "retry_deadline_ms": {
+                "type": "integer",
+                "minimum": 0,
+                "description": "Overall deadline for a GET check including retries.",
+                "default": 15_000
+            },
+            "cache_max_entries": {
+                "type": "integer",
+                "minimum": 0,
+                "description": "Responses kept for ETag/Last-Modified revalidation (0 disables).",
+                "default": 0
+            },
+            "cache_ttl_ms": {
+                "type": "integer",
+                "minimum": 0,
+                "description": "Time a cached response may be revalidated before it is dropped.",
+                "default": 300_000
+            }
+        },
+        "additionalProperties": false
+    })
+}
+
+/// Returns a schema for the sqlite provider config.
+#[must_use]
+fn sqlite_config_schema() -> Value {
+    json!({
+        "type": "object",
+        "properties": {
+            "path": { "type": "string", "description": "Path to the SQLite database file." },
+            "queries": {
+                "type": "object",
+                "additionalProperties": { "type": "string" },
+                "description": "Allowlisted read-only SELECT statements keyed by query name."
+            },
+            "max_rows": {
+                "type": "integer",
+                "minimum": 1,
+                "description": "Maximum rows a query may produce before failing closed.",
+                "default": 1
+            },
+            "max_value_bytes": {
+                "type": "integer",
+                "minimum": 0,
+                "description": "Maximum bytes allowed for the serialized result value.",
+                "default": 65_536
+            },
+            "timeout_ms": {
+                "type": "integer",
+                "minimum": 1,
+                "description": "Maximum wall-clock time for a single query in milliseconds.",
+                "default": 1_000
+            }
+        },
+        "required": ["path", "queries"],
+        "additionalProperties": false
+    })
+}
+
+/// Returns a schema for the postgres provider config.
+#[must_use]
+fn postgres_config_schema() -> Value {
+    json!({
+        "type": "object",
+        "properties": {
+            "dsn_env": {
+                "type": "string",
+                "description": "Environment variable holding the connection DSN."
+            },
+            "queries": {
+                "type": "object",
+                "additionalProperties": { "type": "string" },
+                "description": "Allowlisted SELECT statements keyed by query name."
+            },
+            "max_rows": {
+                "type": "integer",
+                "minimum": 1,
+                "description": "Maximum rows a rows check may return before failing closed.",
+                "default": 100
+            },
+            "max_value_bytes": {
+                "type": "integer",
+                "minimum": 0,
+                "description": "Maximum bytes allowed for the serialized result value.",
+                "default": 65_536
+            },
+            "statement_timeout_ms": {
+                "type": "integer",
+                "minimum": 1,
+                "description": "Server-side statement timeout in milliseconds.",
+                "default": 1_000
+            },
+            "connect_timeout_ms": {
+                "type": "integer",
+                "minimum": 0,
+                "description": "Connection establishment timeout in milliseconds.",
+                "default": 5_000
             }
         },
+        "required": ["dsn_env", "queries"],
         "additionalProperties": false
     })
 }
@@ -904,6 +1321,130 @@ fn http_url_schema() -> Value {
     })
 }
 
+/// Returns a schema for HTTP JSON query parameters.
+#[must_use]
+fn http_json_query_schema() -> Value {
+    json!({
+        "type": "object",
+        "required": ["url", "template"],
+        "properties": {
+            "url": { "type": "string", "description": "URL to POST the query to." },
+            "template": {
+                "type": "string",
+                "description": "Name of a configured query template."
+            },
+            "variables": {
+                "type": "object",
+                "additionalProperties": true,
+                "description": "Values substituted for {{name}} placeholders in the template."
+            },
+            "jsonpath": {
+                "type": "string",
+                "description": "Optional JSONPath selector over the JSON response."
+            },
+            "transform": {
+                "type": "string",
+                "description": "Optional configured response transform applied to the result."
+            }
+        },
+        "additionalProperties": false
+    })
+}
+
+/// Returns a schema for HTTP paginated JSON parameters.
+#[must_use]
+fn http_json_pages_schema() -> Value {
+    json!({
+        "type": "object",
+        "required": ["url", "items", "next"],
+        "properties": {
+            "url": { "type": "string", "description": "URL of the first page." },
+            "items": {
+                "type": "string",
+                "description": "JSONPath selecting the items on each page."
+            },
+            "next": {
+                "oneOf": [
+                    {
+                        "type": "object",
+                        "required": ["type", "jsonpath", "param"],
+                        "properties": {
+                            "type": { "const": "cursor" },
+                            "jsonpath": {
+                                "type": "string",
+                                "description": "JSONPath selecting the next-page cursor; null, \
+                                                missing, or empty ends pagination."
+                            },
+                            "param": {
+                                "type": "string",
+                                "description": "Query parameter carrying the cursor."
+                            }
+                        },
+                        "additionalProperties": false
+                    },
+                    {
+                        "type": "object",
+                        "required": ["type"],
+                        "properties": {
+                            "type": { "const": "link_header" }
+                        },
+                        "additionalProperties": false
+                    }
+                ],
+                "description": "Next-page extractor."
+            },
+            "aggregate": {
+                "type": "string",
+                "enum": ["items", "count"],
+                "description": "Return collected items or their count.",
+                "default": "items"
+            },
+            "transform": {
+                "type": "string",
+                "description": "Optional configured response transform applied to the result."
+            }
+        },
+        "additionalProperties": false
+    })
+}
+
+/// Returns a schema for sqlite query parameters.
+#[must_use]
+fn sqlite_query_schema() -> Value {
+    json!({
+        "type": "object",
+        "required": ["query"],
+        "properties": {
+            "query": { "type": "string", "description": "Name of a configured query." },
+            "params": {
+                "type": "object",
+                "additionalProperties": {
+                    "type": ["null", "boolean", "number", "string"]
+                },
+                "description": "Named bind values for the query parameters."
+            }
+        },
+        "additionalProperties": false
+    })
+}
+
+/// Returns a schema for postgres query parameters.
+#[must_use]
+fn postgres_query_schema() -> Value {
+    json!({
+        "type": "object",
+        "required": ["query"],
+        "properties": {
+            "query": { "type": "string", "description": "Name of a configured query." },
+            "params": {
+                "type": "array",
+                "description": "Positional bind values for $1, $2, ... in order."
+            }
+        },
+        "additionalProperties": false
+    })
+}
+
 /// Returns a schema for checks with no params.
 #[must_use]
 fn empty_params_schema(description: &str) -> Value {
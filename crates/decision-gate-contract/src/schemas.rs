@@ -3,7 +3,7 @@
 // Module: Contract Schemas
 // Description: JSON schema builders for core Decision Gate data shapes.
 // Purpose: Provide canonical validation schemas for scenarios, config, and tools.
-// Dependencies: decision-gate-config, serde_json
+// Dependencies: decision-gate-config, decision-gate-core, serde_json
 // ============================================================================
 
 //! ## Overview
@@ -18,6 +18,10 @@
 // ============================================================================
 
 use decision_gate_config as config;
+use decision_gate_core::MAX_RUN_CONFIG_DISPATCH_TARGETS;
+use decision_gate_core::MAX_RUN_CONFIG_POLICY_TAGS;
+use decision_gate_core::runtime::MAX_LEASE_HOLDER_BYTES;
+use decision_gate_core::runtime::MAX_RUN_LEASE_TTL_MS;
 use serde_json::Map;
 use serde_json::Value;
 use serde_json::json;
@@ -445,9 +449,7 @@ pub fn run_config_schema() -> Value {
         "required": [
             "tenant_id",
             "run_id",
-            "scenario_id",
-            "dispatch_targets",
-            "policy_tags"
+            "scenario_id"
         ],
         "properties": {
             "tenant_id": schema_for_numeric_identifier("Tenant identifier."),
@@ -456,9 +458,16 @@ pub fn run_config_schema() -> Value {
             "scenario_id": schema_for_identifier("Scenario identifier."),
             "dispatch_targets": {
                 "type": "array",
-                "items": dispatch_target_schema()
+                "items": dispatch_target_schema(),
+                "maxItems": MAX_RUN_CONFIG_DISPATCH_TARGETS,
+                "description": "Dispatch targets for disclosures (default: none)."
             },
-            "policy_tags": schema_for_string_array("Policy tags applied to the run.")
+            "policy_tags": {
+                "type": "array",
+                "items": { "type": "string" },
+                "maxItems": MAX_RUN_CONFIG_POLICY_TAGS,
+                "description": "Policy tags applied to the run (default: none)."
+            }
         },
         "additionalProperties": false
     })
@@ -633,7 +642,8 @@ pub fn next_request_schema() -> Value {
                     { "type": "null" },
                     schema_for_identifier("Correlation identifier.")
                 ]
-            }
+            },
+            "lease_holder": lease_holder_schema()
         },
         "additionalProperties": false
     })
@@ -666,12 +676,111 @@ pub fn submit_request_schema() -> Value {
                     { "type": "null" },
                     schema_for_identifier("Correlation identifier.")
                 ]
+            },
+            "expected_version": {
+                "type": "integer",
+                "minimum": 0,
+                "description": "Run version read from scenario_status; conflicts if the run advanced."
+            },
+            "lease_holder": lease_holder_schema()
+        },
+        "additionalProperties": false
+    })
+}
+
+/// Returns the JSON schema for the optional `lease_holder` on mutating requests.
+#[must_use]
+pub fn lease_holder_schema() -> Value {
+    json!({
+        "type": "string",
+        "minLength": 1,
+        "maxLength": MAX_LEASE_HOLDER_BYTES,
+        "description": "Lease holder making the call; required while the run is leased."
+    })
+}
+
+/// Returns the JSON schema for [`decision_gate_core::LeaseAcquireRequest`].
+#[must_use]
+pub fn lease_acquire_request_schema() -> Value {
+    json!({
+        "type": "object",
+        "required": ["tenant_id", "namespace_id", "run_id", "holder_id", "ttl_ms", "requested_at"],
+        "properties": {
+            "tenant_id": schema_for_numeric_identifier("Tenant identifier."),
+            "namespace_id": schema_for_numeric_identifier("Namespace identifier."),
+            "run_id": schema_for_identifier("Run identifier."),
+            "holder_id": lease_holder_id_schema(),
+            "ttl_ms": {
+                "type": "integer",
+                "minimum": 1,
+                "maximum": MAX_RUN_LEASE_TTL_MS,
+                "description": "Lease duration in milliseconds of server time."
+            },
+            "requested_at": timestamp_schema()
+        },
+        "additionalProperties": false
+    })
+}
+
+/// Returns the JSON schema for [`decision_gate_core::LeaseReleaseRequest`].
+#[must_use]
+pub fn lease_release_request_schema() -> Value {
+    json!({
+        "type": "object",
+        "required": ["tenant_id", "namespace_id", "run_id", "holder_id", "requested_at"],
+        "properties": {
+            "tenant_id": schema_for_numeric_identifier("Tenant identifier."),
+            "namespace_id": schema_for_numeric_identifier("Namespace identifier."),
+            "run_id": schema_for_identifier("Run identifier."),
+            "holder_id": lease_holder_id_schema(),
+            "requested_at": timestamp_schema()
+        },
+        "additionalProperties": false
+    })
+}
+
+/// Returns the JSON schema for [`decision_gate_core::RunLease`].
+#[must_use]
+pub fn run_lease_schema() -> Value {
+    json!({
+        "type": "object",
+        "required": ["run_id", "holder_id", "acquired_at", "expires_at"],
+        "properties": {
+            "run_id": schema_for_identifier("Run identifier."),
+            "holder_id": lease_holder_id_schema(),
+            "acquired_at": timestamp_schema(),
+            "expires_at": timestamp_schema()
+        },
+        "additionalProperties": false
+    })
+}
+
+/// Returns the JSON schema for [`decision_gate_core::LeaseReleaseResult`].
+#[must_use]
+pub fn lease_release_result_schema() -> Value {
+    json!({
+        "type": "object",
+        "required": ["released"],
+        "properties": {
+            "released": {
+                "type": "boolean",
+                "description": "Whether an active lease held by the caller was released."
             }
         },
         "additionalProperties": false
     })
 }
 
+/// Returns the JSON schema for lease holder identifiers.
+fn lease_holder_id_schema() -> Value {
+    json!({
+        "type": "string",
+        "minLength": 1,
+        "maxLength": MAX_LEASE_HOLDER_BYTES,
+        "description": "Caller-chosen lease holder identifier."
+    })
+}
+
 /// Returns the JSON schema for [`decision_gate_core::ScenarioStatus`].
 #[must_use]
 pub fn scenario_status_schema() -> Value {
@@ -684,7 +793,8 @@ pub fn scenario_status_schema() -> Value {
             "status",
             "last_decision",
             "issued_packet_ids",
-            "safe_summary"
+            "safe_summary",
+            "version"
         ],
         "properties": {
             "namespace_id": schema_for_numeric_identifier("Namespace identifier."),
@@ -707,6 +817,11 @@ pub fn scenario_status_schema() -> Value {
                     { "type": "null" },
                     safe_summary_schema()
                 ]
+            },
+            "version": {
+                "type": "integer",
+                "minimum": 0,
+                "description": "Run version; advances on each trigger or submission."
             }
         },
         "additionalProperties": false
@@ -871,6 +986,25 @@ pub fn runpack_manifest_schema() -> Value {
             "artifacts": {
                 "type": "array",
                 "items": artifact_record_schema()
+            },
+            "signature": runpack_signature_schema()
+        },
+        "additionalProperties": false
+    })
+}
+
+/// Returns the JSON schema for runpack manifest signatures.
+#[must_use]
+fn runpack_signature_schema() -> Value {
+    json!({
+        "type": "object",
+        "required": ["scheme", "key_id", "signature"],
+        "properties": {
+            "scheme": schema_for_string("Manifest signature scheme identifier."),
+            "key_id": schema_for_string("Signing key identifier."),
+            "signature": {
+                "type": "array",
+                "items": { "type": "integer", "minimum": 0, "maximum": 255 }
             }
         },
         "additionalProperties": false
@@ -930,7 +1064,7 @@ pub fn hash_digest_schema() -> Value {
         "properties": {
             "algorithm": {
                 "type": "string",
-                "enum": ["sha256"]
+                "enum": ["sha256", "blake3"]
             },
             "value": schema_for_string("Lowercase hex digest.")
         },
@@ -1289,19 +1423,19 @@ fn requirement_schema() -> Value {
                 },
                 "additionalProperties": false
             },
+            counted_requirement_schema("RequireGroup", "min"),
+            counted_requirement_schema("RequireExactly", "n"),
+            counted_requirement_schema("RequireAtMost", "max"),
             {
                 "type": "object",
-                "required": ["RequireGroup"],
+                "required": ["Implies"],
                 "properties": {
-                    "RequireGroup": {
+                    "Implies": {
                         "type": "object",
-                        "required": ["min", "reqs"],
+                        "required": ["antecedent", "consequent"],
                         "properties": {
-                            "min": { "type": "integer", "minimum": 0, "maximum": 255 },
-                            "reqs": {
-                                "type": "array",
-                                "items": { "$ref": "#/$defs/Requirement" }
-                            }
+                            "antecedent": { "$ref": "#/$defs/Requirement" },
+                            "consequent": { "$ref": "#/$defs/Requirement" }
                         },
                         "additionalProperties": false
                     }
@@ -1320,6 +1454,30 @@ fn requirement_schema() -> Value {
     })
 }
 
+/// Returns the `Requirement` variant schema for a counted group keyed by `field`.
+#[must_use]
+fn counted_requirement_schema(variant: &str, field: &str) -> Value {
+    json!({
+        "type": "object",
+        "required": [variant],
+        "properties": {
+            variant: {
+                "type": "object",
+                "required": [field, "reqs"],
+                "properties": {
+                    field: { "type": "integer", "minimum": 0, "maximum": 255 },
+                    "reqs": {
+                        "type": "array",
+                        "items": { "$ref": "#/$defs/Requirement" }
+                    }
+                },
+                "additionalProperties": false
+            }
+        },
+        "additionalProperties": false
+    })
+}
+
 /// Returns the JSON schema for [`decision_gate_core::AdvanceTo`].
 #[must_use]
 fn advance_to_schema() -> Value {
@@ -1958,7 +2116,7 @@ fn content_ref_schema() -> Value {
 fn hash_algorithm_schema() -> Value {
     json!({
         "type": "string",
-        "enum": ["sha256"]
+        "enum": ["sha256", "blake3"]
     })
 }
 
@@ -2026,7 +2184,12 @@ fn artifact_record_schema() -> Value {
                 ]
             },
             "hash": hash_digest_schema(),
-            "required": { "type": "boolean" }
+            "required": { "type": "boolean" },
+            "content_encoding": {
+                "type": "string",
+                "enum": ["none", "gzip"],
+                "description": "Encoding of the stored artifact bytes; hashes cover the decoded bytes."
+            }
         },
         "additionalProperties": false
     })
@@ -2047,6 +2210,7 @@ fn artifact_kind_schema() -> Value {
             "evidence_log",
             "submission_log",
             "tool_transcript",
+            "evaluation_audit",
             "verifier_report",
             "custom"
         ]
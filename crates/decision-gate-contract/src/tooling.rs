@@ -62,6 +62,9 @@ pub fn tool_contracts() -> Vec<ToolContract> {
         scenarios_list_contract(),
         precheck_contract(),
         decision_gate_docs_search_contract(),
+        scenarios_status_bulk_contract(),
+        scenario_lease_acquire_contract(),
+        scenario_lease_release_contract(),
     ]
 }
 
@@ -113,6 +116,8 @@ fn scenario_status_contract() -> ToolContract {
             "Use for polling or UI state; does not evaluate gates.".to_string(),
             "Safe summaries omit evidence values and may include retry hints.".to_string(),
             "Returns issued packet IDs to help track disclosures.".to_string(),
+            "Set wait_for_change to long-poll until the run changes or max_wait_ms elapses."
+                .to_string(),
         ],
     )
 }
@@ -129,6 +134,8 @@ fn scenario_next_contract() -> ToolContract {
             "Idempotent by trigger_id; repeated calls return the same decision.".to_string(),
             "Records decision, evidence, and packet disclosures in run state.".to_string(),
             "Requires an active run; completed or failed runs do not advance.".to_string(),
+            "While the run is leased, only the lease holder may advance it; pass lease_holder."
+                .to_string(),
             "Optional feedback can include gate trace or evidence when permitted by server \
              feedback policy."
                 .to_string(),
@@ -149,6 +156,11 @@ fn scenario_submit_contract() -> ToolContract {
             "Payload is persisted in run state/runpack logs; do not send raw secrets.".to_string(),
             "Does not advance the run by itself.".to_string(),
             "Use for artifacts the model or operator supplies.".to_string(),
+            "Set expected_version to the version from scenario_status to reject the submit with a \
+             conflict if the run advanced; re-read and retry."
+                .to_string(),
+            "While the run is leased, only the lease holder may submit; pass lease_holder."
+                .to_string(),
         ],
     )
 }
@@ -166,6 +178,8 @@ fn scenario_trigger_contract() -> ToolContract {
             "Records the trigger event and resulting decision.".to_string(),
             "Payload is persisted in run state/runpack logs; do not send raw secrets.".to_string(),
             "Use for time-based or external system triggers.".to_string(),
+            "While the run is leased, only the lease holder may trigger; pass lease_holder."
+                .to_string(),
         ],
     )
 }
@@ -199,8 +213,11 @@ fn runpack_export_contract() -> ToolContract {
             "Writes manifest and logs to output_dir; generated_at is recorded in the manifest."
                 .to_string(),
             "include_verification adds a verification report artifact.".to_string(),
-            "Export-time report.checked_files excludes verifier_report.json; offline runpack_verify \
-             checked_files includes it (+1 for the same runpack)."
+            "include_evaluation_audit adds evaluation_audit.json: spec, run config, evidence, and \
+             result hashes for every decision and submission."
+                .to_string(),
+            "Export-time report.checked_files excludes verifier_report.json; offline \
+             runpack_verify checked_files includes it (+1 for the same runpack)."
                 .to_string(),
             "Use after runs complete or for audit snapshots.".to_string(),
         ],
@@ -362,6 +379,61 @@ fn decision_gate_docs_search_contract() -> ToolContract {
     )
 }
 
+/// Builds the tool contract for `scenarios_status_bulk`.
+fn scenarios_status_bulk_contract() -> ToolContract {
+    build_tool_contract(
+        ToolName::ScenariosStatusBulk,
+        "Fetch read-only status snapshots for several runs in one call.",
+        scenarios_status_bulk_input_schema(),
+        scenarios_status_bulk_output_schema(),
+        tool_examples(ToolName::ScenariosStatusBulk),
+        vec![
+            "Requires tenant_id and namespace_id; every run is resolved within that scope."
+                .to_string(),
+            "Accepts up to 100 runs; results are returned in request order.".to_string(),
+            "Unknown scenarios or runs yield found=false without failing the call.".to_string(),
+            "Reads do not record tool calls or modify run state.".to_string(),
+        ],
+    )
+}
+
+/// Builds the tool contract for `scenario_lease_acquire`.
+fn scenario_lease_acquire_contract() -> ToolContract {
+    build_tool_contract(
+        ToolName::ScenarioLeaseAcquire,
+        "Acquire or renew an advisory lease so only one holder can advance, submit to, or trigger \
+         a run.",
+        scenario_lease_acquire_input_schema(),
+        schemas::run_lease_schema(),
+        tool_examples(ToolName::ScenarioLeaseAcquire),
+        vec![
+            "While the lease is active, scenario_next, scenario_submit, and scenario_trigger \
+             calls without the matching lease_holder fail with a conflict."
+                .to_string(),
+            "The lease expires ttl_ms after the server receives the request; requested_at is \
+             recorded but never used to judge expiry. The holder renews by acquiring again."
+                .to_string(),
+            "Fails with a conflict while another holder's lease is active.".to_string(),
+            "Leases live in server memory and are lost on restart.".to_string(),
+        ],
+    )
+}
+
+/// Builds the tool contract for `scenario_lease_release`.
+fn scenario_lease_release_contract() -> ToolContract {
+    build_tool_contract(
+        ToolName::ScenarioLeaseRelease,
+        "Release an advisory run lease before it expires.",
+        scenario_lease_release_input_schema(),
+        schemas::lease_release_result_schema(),
+        tool_examples(ToolName::ScenarioLeaseRelease),
+        vec![
+            "Returns released=false when the caller held no active lease.".to_string(),
+            "Fails with a conflict while another holder's lease is active.".to_string(),
+        ],
+    )
+}
+
 /// Returns the MCP tool definitions for tool listing.
 #[must_use]
 pub fn tool_definitions() -> Vec<ToolDefinition> {
@@ -586,6 +658,9 @@ fn tool_examples(tool_name: ToolName) -> Vec<ToolExample> {
         ToolName::ScenariosList => scenarios_list_examples(),
         ToolName::Precheck => precheck_examples(),
         ToolName::DecisionGateDocsSearch => decision_gate_docs_search_examples(),
+        ToolName::ScenariosStatusBulk => scenarios_status_bulk_examples(),
+        ToolName::ScenarioLeaseAcquire => scenario_lease_acquire_examples(),
+        ToolName::ScenarioLeaseRelease => scenario_lease_release_examples(),
     }
 }
 
@@ -651,28 +726,55 @@ fn scenario_start_examples() -> Vec<ToolExample> {
 
 /// Returns example payloads for `scenario_status`.
 fn scenario_status_examples() -> Vec<ToolExample> {
-    vec![ToolExample {
-        description: String::from("Poll run status without advancing the run."),
-        input: json!({
-            "scenario_id": EXAMPLE_SCENARIO_ID,
-            "request": {
-                "tenant_id": EXAMPLE_TENANT_ID,
-                "namespace_id": EXAMPLE_NAMESPACE_ID,
+    vec![
+        ToolExample {
+            description: String::from("Poll run status without advancing the run."),
+            input: json!({
+                "scenario_id": EXAMPLE_SCENARIO_ID,
+                "request": {
+                    "tenant_id": EXAMPLE_TENANT_ID,
+                    "namespace_id": EXAMPLE_NAMESPACE_ID,
+                    "run_id": EXAMPLE_RUN_ID,
+                    "requested_at": example_timestamp(),
+                    "correlation_id": null
+                }
+            }),
+            output: json!({
                 "run_id": EXAMPLE_RUN_ID,
-                "requested_at": example_timestamp(),
-                "correlation_id": null
-            }
-        }),
-        output: json!({
-            "run_id": EXAMPLE_RUN_ID,
-            "scenario_id": EXAMPLE_SCENARIO_ID,
-            "current_stage_id": EXAMPLE_STAGE_ID,
-            "status": "active",
-            "last_decision": null,
-            "issued_packet_ids": [],
-            "safe_summary": null
-        }),
-    }]
+                "scenario_id": EXAMPLE_SCENARIO_ID,
+                "current_stage_id": EXAMPLE_STAGE_ID,
+                "status": "active",
+                "last_decision": null,
+                "issued_packet_ids": [],
+                "safe_summary": null,
+                "version": 0
+            }),
+        },
+        ToolExample {
+            description: String::from("Long-poll run status until it changes or the wait elapses."),
+            input: json!({
+                "scenario_id": EXAMPLE_SCENARIO_ID,
+                "request": {
+                    "tenant_id": EXAMPLE_TENANT_ID,
+                    "namespace_id": EXAMPLE_NAMESPACE_ID,
+                    "run_id": EXAMPLE_RUN_ID,
+                    "requested_at": example_timestamp(),
+                    "correlation_id": null
+                },
+                "wait_for_change": { "max_wait_ms": 10000 }
+            }),
+            output: json!({
+                "run_id": EXAMPLE_RUN_ID,
+                "scenario_id": EXAMPLE_SCENARIO_ID,
+                "current_stage_id": EXAMPLE_STAGE_ID,
+                "status": "active",
+                "last_decision": null,
+                "issued_packet_ids": [],
+                "safe_summary": null,
+                "version": 0
+            }),
+        },
+    ]
 }
 
 /// Returns example payloads for `scenario_next`.
@@ -1072,6 +1174,90 @@ fn decision_gate_docs_search_examples() -> Vec<ToolExample> {
     }]
 }
 
+/// Returns example payloads for `scenarios_status_bulk`.
+fn scenarios_status_bulk_examples() -> Vec<ToolExample> {
+    vec![ToolExample {
+        description: String::from("Fetch status for a known run and a missing run."),
+        input: json!({
+            "tenant_id": EXAMPLE_TENANT_ID,
+            "namespace_id": EXAMPLE_NAMESPACE_ID,
+            "runs": [
+                { "scenario_id": EXAMPLE_SCENARIO_ID, "run_id": EXAMPLE_RUN_ID },
+                { "scenario_id": EXAMPLE_SCENARIO_ID, "run_id": "run-missing" }
+            ]
+        }),
+        output: json!({
+            "results": [
+                {
+                    "scenario_id": EXAMPLE_SCENARIO_ID,
+                    "run_id": EXAMPLE_RUN_ID,
+                    "found": true,
+                    "status": {
+                        "run_id": EXAMPLE_RUN_ID,
+                        "scenario_id": EXAMPLE_SCENARIO_ID,
+                        "current_stage_id": EXAMPLE_STAGE_ID,
+                        "status": "active",
+                        "last_decision": null,
+                        "issued_packet_ids": [],
+                        "safe_summary": null,
+                        "version": 0
+                    }
+                },
+                {
+                    "scenario_id": EXAMPLE_SCENARIO_ID,
+                    "run_id": "run-missing",
+                    "found": false,
+                    "status": null
+                }
+            ]
+        }),
+    }]
+}
+
+/// Returns example payloads for `scenario_lease_acquire`.
+fn scenario_lease_acquire_examples() -> Vec<ToolExample> {
+    vec![ToolExample {
+        description: String::from("Lease a run for 30 seconds before a series of submits."),
+        input: json!({
+            "scenario_id": EXAMPLE_SCENARIO_ID,
+            "request": {
+                "tenant_id": EXAMPLE_TENANT_ID,
+                "namespace_id": EXAMPLE_NAMESPACE_ID,
+                "run_id": EXAMPLE_RUN_ID,
+                "holder_id": EXAMPLE_AGENT_ID,
+                "ttl_ms": 30_000,
+                "requested_at": example_timestamp()
+            }
+        }),
+        output: json!({
+            "run_id": EXAMPLE_RUN_ID,
+            "holder_id": EXAMPLE_AGENT_ID,
+            "acquired_at": example_timestamp(),
+            "expires_at": { "kind": "unix_millis", "value": 1_710_000_030_000_i64 }
+        }),
+    }]
+}
+
+/// Returns example payloads for `scenario_lease_release`.
+fn scenario_lease_release_examples() -> Vec<ToolExample> {
+    vec![ToolExample {
+        description: String::from("Release a run lease once the holder is done."),
+        input: json!({
+            "scenario_id": EXAMPLE_SCENARIO_ID,
+            "request": {
+                "tenant_id": EXAMPLE_TENANT_ID,
+                "namespace_id": EXAMPLE_NAMESPACE_ID,
+                "run_id": EXAMPLE_RUN_ID,
+                "holder_id": EXAMPLE_AGENT_ID,
+                "requested_at": example_timestamp()
+            }
+        }),
+        output: json!({
+            "released": true
+        }),
+    }]
+}
+
 /// Example tenant identifier used in tooling samples.
 const EXAMPLE_TENANT_ID: u64 = 1;
 /// Example namespace identifier used in tooling samples.
@@ -1282,7 +1468,26 @@ fn scenario_status_input_schema() -> Value {
     tool_input_schema(
         &json!({
             "scenario_id": schema_identifier("Scenario identifier."),
-            "request": describe_schema(schemas::status_request_schema(), "Status request payload.")
+            "request": describe_schema(schemas::status_request_schema(), "Status request payload."),
+            "wait_for_change": {
+                "oneOf": [
+                    { "type": "null" },
+                    {
+                        "type": "object",
+                        "properties": {
+                            "max_wait_ms": {
+                                "type": "integer",
+                                "minimum": 1,
+                                "maximum": 30000,
+                                "description": "Maximum time to hold the request before returning the current status."
+                            }
+                        },
+                        "required": ["max_wait_ms"],
+                        "additionalProperties": false
+                    }
+                ],
+                "description": "Optional long-poll options; the server holds the request until the run status changes."
+            }
         }),
         &["scenario_id", "request"],
     )
@@ -1324,7 +1529,8 @@ fn scenario_trigger_input_schema() -> Value {
     tool_input_schema(
         &json!({
             "scenario_id": schema_identifier("Scenario identifier."),
-            "trigger": describe_schema(schemas::trigger_event_schema(), "Trigger event payload.")
+            "trigger": describe_schema(schemas::trigger_event_schema(), "Trigger event payload."),
+            "lease_holder": schemas::lease_holder_schema()
         }),
         &["scenario_id", "trigger"],
     )
@@ -1375,7 +1581,8 @@ fn runpack_export_input_schema() -> Value {
                 ]
             }), "Optional override for the manifest file name."),
             "generated_at": describe_schema(schemas::timestamp_schema(), "Timestamp recorded in the manifest."),
-            "include_verification": describe_schema(json!({ "type": "boolean" }), "Generate a verification report artifact.")
+            "include_verification": describe_schema(json!({ "type": "boolean" }), "Generate a verification report artifact."),
+            "include_evaluation_audit": describe_schema(json!({ "type": "boolean" }), "Include evaluation audit records as an artifact (default false).")
         }),
         &[
             "scenario_id",
@@ -1442,17 +1649,22 @@ fn providers_list_input_schema() -> Value {
 }
 
 /// Builds the output schema for `providers_list`.
+///
+/// Provider summaries live under `$defs` so SDKs render them as a shared
+/// `ProviderSummary` type.
 #[must_use]
 fn providers_list_output_schema() -> Value {
-    tool_output_schema(
+    let mut schema = tool_output_schema(
         &json!({
             "providers": {
                 "type": "array",
-                "items": provider_summary_schema()
+                "items": { "$ref": "#/$defs/ProviderSummary" }
             }
         }),
         &["providers"],
-    )
+    );
+    schema["$defs"] = json!({ "ProviderSummary": provider_summary_schema() });
+    schema
 }
 
 /// Builds the input schema for `provider_contract_get`.
@@ -1792,6 +2004,8 @@ fn decision_gate_docs_search_output_schema() -> Value {
 #[must_use]
 fn provider_summary_schema() -> Value {
     json!({
+        "title": "ProviderSummary",
+        "description": "Registered evidence provider and the checks it serves.",
         "type": "object",
         "required": ["provider_id", "transport", "checks"],
         "properties": {
@@ -1825,6 +2039,86 @@ fn scenario_summary_schema() -> Value {
     })
 }
 
+/// Builds the input schema for `scenarios_status_bulk`.
+#[must_use]
+fn scenarios_status_bulk_input_schema() -> Value {
+    tool_input_schema(
+        &json!({
+            "tenant_id": schema_numeric_identifier("Tenant identifier."),
+            "namespace_id": schema_numeric_identifier("Namespace identifier."),
+            "runs": {
+                "type": "array",
+                "minItems": 1,
+                "maxItems": 100,
+                "items": object_schema(
+                    &json!({
+                        "scenario_id": schema_identifier("Scenario identifier."),
+                        "run_id": schema_identifier("Run identifier.")
+                    }),
+                    &["scenario_id", "run_id"],
+                ),
+                "description": "Runs to fetch status for, in result order."
+            }
+        }),
+        &["tenant_id", "namespace_id", "runs"],
+    )
+}
+
+/// Builds the input schema for `scenario_lease_acquire`.
+#[must_use]
+fn scenario_lease_acquire_input_schema() -> Value {
+    tool_input_schema(
+        &json!({
+            "scenario_id": schema_identifier("Scenario identifier."),
+            "request": describe_schema(schemas::lease_acquire_request_schema(), "Lease acquisition request.")
+        }),
+        &["scenario_id", "request"],
+    )
+}
+
+/// Builds the input schema for `scenario_lease_release`.
+#[must_use]
+fn scenario_lease_release_input_schema() -> Value {
+    tool_input_schema(
+        &json!({
+            "scenario_id": schema_identifier("Scenario identifier."),
+            "request": describe_schema(schemas::lease_release_request_schema(), "Lease release request.")
+        }),
+        &["scenario_id", "request"],
+    )
+}
+
+/// Builds the output schema for `scenarios_status_bulk`.
+#[must_use]
+fn scenarios_status_bulk_output_schema() -> Value {
+    tool_output_schema(
+        &json!({
+            "results": {
+                "type": "array",
+                "items": object_schema(
+                    &json!({
+                        "scenario_id": schema_identifier("Scenario identifier."),
+                        "run_id": schema_identifier("Run identifier."),
+                        "found": {
+                            "type": "boolean",
+                            "description": "Whether the run exists within the requested scope."
+                        },
+                        "status": {
+                            "oneOf": [
+                                { "type": "null" },
+                                schemas::scenario_status_schema()
+                            ],
+                            "description": "Run status snapshot when found."
+                        }
+                    }),
+                    &["scenario_id", "run_id", "found", "status"],
+                )
+            }
+        }),
+        &["results"],
+    )
+}
+
 /// Returns the JSON schema for documentation roles.
 #[must_use]
 fn doc_role_schema() -> Value {
@@ -1859,6 +2153,7 @@ fn build_tool_contract(
         output_schema,
         examples,
         notes,
+        deprecated: false,
     }
 }
 
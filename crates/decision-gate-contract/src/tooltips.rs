@@ -74,7 +74,8 @@ const TOOLTIP_PAIRS: &[(&str, &str)] = &[
         "Submits external artifacts to a run's audit trail for later review. Use this to attach \
          documents, signatures, or receipts for audit and runpack export. Payloads are hashed \
          into content_hash and recorded in the submission log. Submissions are idempotent by \
-         submission_id; conflicting payloads return a conflict error.",
+         submission_id; conflicting payloads return a conflict error. Pass expected_version to \
+         reject the submit when the run advanced since it was read.",
     ),
     (
         "scenario_trigger",
@@ -155,6 +156,24 @@ const TOOLTIP_PAIRS: &[(&str, &str)] = &[
          sections with headings, role metadata, and suggested follow-ups. Use this to answer \
          product or policy questions without leaving the MCP session.",
     ),
+    (
+        "scenarios_status_bulk",
+        "Fetches read-only status snapshots for up to 100 runs in one call, scoped to a single \
+         tenant and namespace. Runs that are missing or outside the scope report found=false \
+         instead of failing the whole request. Use this for dashboards that track many runs.",
+    ),
+    (
+        "scenario_lease_acquire",
+        "Acquires or renews an advisory lease on a run for ttl_ms. While the lease is active, \
+         next calls, submits, and triggers must carry the holder's lease_holder or they fail with \
+         a conflict. Expiry is judged on the server clock, not on caller timestamps. Use this \
+         when a workflow cannot tolerate any concurrent mutation.",
+    ),
+    (
+        "scenario_lease_release",
+        "Releases a run lease early so other callers can mutate the run again. Leases also expire \
+         on their own, so a crashed holder never blocks a run forever.",
+    ),
     // ============================================================================
     // SECTION: CORE TYPES - Scenario & Stage Specifications
     // ============================================================================
@@ -201,16 +220,16 @@ const TOOLTIP_PAIRS: &[(&str, &str)] = &[
     (
         "Requirement",
         "A Requirement Evaluation Tree (RET) is a boolean algebra over tri-state outcomes. It \
-         composes And, Or, Not, RequireGroup, and Condition nodes into a tree. Evaluation uses \
-         strong Kleene logic: false dominates And, true dominates Or, and unknown propagates. \
-         Gates pass only when the root evaluates to true. RETs make gate logic explicit, \
-         auditable, and replayable.",
+         composes And, Or, Not, Implies, RequireGroup, RequireExactly, RequireAtMost, and \
+         Condition nodes into a tree. Evaluation uses strong Kleene logic: false dominates And, \
+         true dominates Or, and unknown propagates. Gates pass only when the root evaluates to \
+         true. RETs make gate logic explicit, auditable, and replayable.",
     ),
     (
         "RET",
         "Requirement Evaluation Tree. A boolean algebra over tri-state outcomes that composes \
-         And, Or, Not, RequireGroup, and Condition nodes. RETs make gate logic explicit and \
-         auditable.",
+         And, Or, Not, Implies, RequireGroup, RequireExactly, RequireAtMost, and Condition nodes. \
+         RETs make gate logic explicit and auditable.",
     ),
     (
         "TriState",
@@ -220,9 +239,18 @@ const TOOLTIP_PAIRS: &[(&str, &str)] = &[
     (
         "requirement",
         "The RET expression that a gate must satisfy. This field contains the root of a \
-         Requirement tree (And/Or/Not/RequireGroup/Condition). The gate passes only when the \
-         entire tree evaluates to true. Design requirements to handle unknown outcomes explicitly \
-         via branching or RequireGroup thresholds.",
+         Requirement tree \
+         (And/Or/Not/Implies/RequireGroup/RequireExactly/RequireAtMost/Condition). The gate \
+         passes only when the entire tree evaluates to true. Design requirements to handle \
+         unknown outcomes explicitly via branching or RequireGroup thresholds.",
+    ),
+    (
+        "Implies",
+        "An implication operator in a Requirement tree with an antecedent and a consequent. \
+         Passes when the antecedent is false or the consequent is true, and fails only when the \
+         antecedent is true and the consequent is false. With unknown operands the logic mode \
+         decides: Kleene returns unknown, while Lukasiewicz treats unknown implies unknown as \
+         true. Use for conditional policies such as requiring approval only in production.",
     ),
     (
         "RequireGroup",
@@ -232,6 +260,22 @@ const TOOLTIP_PAIRS: &[(&str, &str)] = &[
          otherwise returns unknown. Use for multi-party approval, threshold signatures, or \
          redundant checks.",
     ),
+    (
+        "RequireExactly",
+        "An exactly-N-of-M operator in a Requirement tree. Specifies the exact count (n) of child \
+         requirements that must pass; more or fewer fails. Uses tri-state logic: returns true \
+         when exactly n children are true and none are unknown; returns false when more than n \
+         are true or even all unknowns becoming true cannot reach n; otherwise returns unknown. \
+         Use for approval flows that need exactly N signers.",
+    ),
+    (
+        "RequireAtMost",
+        "An at-most-N-of-M operator in a Requirement tree, the upper-bound mirror of \
+         RequireGroup. Specifies the maximum count (max) of child requirements that may pass. \
+         Uses tri-state logic: returns false when more than max children are true; returns true \
+         when even all unknowns becoming true stay within max; otherwise returns unknown. Use for \
+         mutual-exclusion policies such as at most one premium add-on.",
+    ),
     (
         "Condition",
         "A leaf node in a Requirement tree that references a condition_id defined in the \
@@ -881,6 +925,12 @@ const TOOLTIP_PAIRS: &[(&str, &str)] = &[
          from oversized files. Queries for files exceeding this limit fail with a validation \
          error. Size appropriately for your config files.",
     ),
+    (
+        "max_jsonpath_nodes",
+        "Node budget for JSONPath queries in the JSON provider. Queries using recursive descent, \
+         wildcards, filters, or slices are rejected when their estimated traversal exceeds this \
+         budget. Direct paths are not charged.",
+    ),
     (
         "jsonpath",
         "JSONPath selector used by the JSON provider to extract values from documents. Syntax \
@@ -949,6 +999,12 @@ const TOOLTIP_PAIRS: &[(&str, &str)] = &[
          exclusively. Recorded in manifests for forward compatibility. Do not assume other \
          algorithms without checking this field.",
     ),
+    (
+        "include_evaluation_audit",
+        "Flag adding evaluation audit records to a runpack export. Each record binds the spec, \
+         run config, evidence, and result hashes of one decision or submission so auditors can \
+         recompute what produced an outcome.",
+    ),
     (
         "include_verification",
         "Flag indicating whether to emit a verification report alongside the runpack export. When \
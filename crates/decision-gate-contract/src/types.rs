@@ -137,6 +137,9 @@ pub struct ToolContract {
     pub examples: Vec<ToolExample>,
     /// Notes describing tool usage and security considerations.
     pub notes: Vec<String>,
+    /// True when the tool is deprecated; generated SDKs mark and warn on use.
+    #[serde(default, skip_serializing_if = "std::ops::Not::not")]
+    pub deprecated: bool,
 }
 
 /// Tool example with input/output payloads.
@@ -35,8 +35,10 @@ use decision_gate_contract::AuthoringFormat;
 use decision_gate_contract::authoring::MAX_AUTHORING_DEPTH;
 use decision_gate_contract::authoring::MAX_AUTHORING_INPUT_BYTES;
 use decision_gate_contract::authoring::normalize_scenario;
+use decision_gate_contract::authoring::render_scenario_template;
 use decision_gate_contract::examples;
 use decision_gate_core::hashing::canonical_json_bytes;
+use serde_json::Value;
 use serde_json::json;
 
 // ============================================================================
@@ -99,3 +101,65 @@ fn authoring_rejects_overly_deep_inputs() {
     let err = normalize_scenario(&input, AuthoringFormat::Json).unwrap_err();
     assert!(matches!(err, AuthoringError::DepthLimitExceeded { .. }));
 }
+
+/// Builds a template from the example scenario with `${param}` placeholders.
+fn scenario_template() -> Value {
+    let mut template = serde_json::to_value(examples::scenario_example()).expect("example json");
+    template["scenario_id"] = json!("${team}-scenario");
+    template["conditions"][0]["expected"] = json!("${env}");
+    template["conditions"][1]["query"]["params"]["timestamp"] = json!("${freeze_at}");
+    template
+}
+
+/// Confirms template placeholders are substituted before normalization.
+#[test]
+fn render_template_substitutes_params() -> Result<(), Box<dyn std::error::Error>> {
+    let template = serde_json::to_string(&scenario_template())?;
+    let params = json!({"team": "payments", "env": "staging", "freeze_at": 1_720_000_000_000_u64});
+    let rendered = render_scenario_template(&template, AuthoringFormat::Json, &params)?;
+    assert!(rendered.unused_params.is_empty());
+
+    let mut expected = serde_json::to_value(examples::scenario_example())?;
+    expected["scenario_id"] = json!("payments-scenario");
+    expected["conditions"][0]["expected"] = json!("staging");
+    expected["conditions"][1]["query"]["params"]["timestamp"] = json!(1_720_000_000_000_u64);
+    let expected = normalize_scenario(&serde_json::to_string(&expected)?, AuthoringFormat::Json)?;
+    assert_eq!(rendered.normalized.canonical_json, expected.canonical_json);
+    assert_eq!(rendered.normalized.spec_hash, expected.spec_hash);
+    Ok(())
+}
+
+/// Confirms every placeholder must be provided.
+#[test]
+fn render_template_rejects_missing_params() -> Result<(), Box<dyn std::error::Error>> {
+    let template = serde_json::to_string(&scenario_template())?;
+    let params = json!({"team": "payments"});
+    let err = render_scenario_template(&template, AuthoringFormat::Json, &params).unwrap_err();
+    let AuthoringError::MissingTemplateParams {
+        names,
+    } = err
+    else {
+        panic!("expected missing params error, got {err}");
+    };
+    assert_eq!(names, vec!["env".to_string(), "freeze_at".to_string()]);
+
+    let err =
+        render_scenario_template("\"${unterminated\"", AuthoringFormat::Json, &params).unwrap_err();
+    assert!(matches!(err, AuthoringError::Template { .. }));
+    Ok(())
+}
+
+/// Confirms provided parameters that the template never references are reported.
+#[test]
+fn render_template_reports_unused_params() -> Result<(), Box<dyn std::error::Error>> {
+    let template = serde_json::to_string(&scenario_template())?;
+    let params = json!({
+        "team": "payments",
+        "env": "staging",
+        "freeze_at": 1_720_000_000_000_u64,
+        "region": "eu-west-1",
+    });
+    let rendered = render_scenario_template(&template, AuthoringFormat::Json, &params)?;
+    assert_eq!(rendered.unused_params, vec!["region".to_string()]);
+    Ok(())
+}
@@ -0,0 +1,193 @@
+// crates/decision-gate-core/src/core/audit.rs
+// ============================================================================
+// Module: Decision Gate Evaluation Audit Records
+// Description: Hash bindings between evaluation inputs and outcomes.
+// Purpose: Let auditors prove which spec, run config, and evidence produced an outcome.
+// Dependencies: crate::core::{hashing, identifiers, state}, serde
+// ============================================================================
+
+//! ## Overview
+//! Evaluation audit records bind the canonical hashes of a run's inputs (spec,
+//! run config, evidence) to the hash of the outcome they produced. Every hash
+//! is computed over canonical JSON (RFC 8785) of a value held in run state, so
+//! an auditor can recompute each one from the run state log alone.
+//!
+//! Records carry hashes only; they never include evidence values or payloads.
+
+// ============================================================================
+// SECTION: Imports
+// ============================================================================
+
+use serde::Deserialize;
+use serde::Serialize;
+
+use crate::core::hashing::HashAlgorithm;
+use crate::core::hashing::HashDigest;
+use crate::core::hashing::HashError;
+use crate::core::hashing::hash_canonical_json;
+use crate::core::identifiers::DecisionId;
+use crate::core::identifiers::NamespaceId;
+use crate::core::identifiers::RunId;
+use crate::core::identifiers::ScenarioId;
+use crate::core::identifiers::TenantId;
+use crate::core::identifiers::TriggerId;
+use crate::core::state::DecisionRecord;
+use crate::core::state::GateEvalRecord;
+use crate::core::state::RunConfig;
+use crate::core::state::RunState;
+use crate::core::state::SubmissionRecord;
+
+// ============================================================================
+// SECTION: Evaluation Audit Records
+// ============================================================================
+
+/// Outcome an evaluation audit record describes.
+///
+/// # Invariants
+/// - Variants are stable for serialization and contract matching.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(tag = "kind", rename_all = "snake_case")]
+pub enum EvaluationSubject {
+    /// Gate evaluation decision produced by a `scenario_next` or trigger call.
+    Decision {
+        /// Trigger identifier that produced the decision.
+        trigger_id: TriggerId,
+        /// Decision identifier.
+        decision_id: DecisionId,
+        /// Decision sequence within the run.
+        seq: u64,
+    },
+    /// Submission accepted by `scenario_submit`.
+    Submission {
+        /// Submission identifier.
+        submission_id: String,
+    },
+}
+
+/// Canonical hash binding of an evaluation's inputs and outcome.
+///
+/// # Invariants
+/// - `spec_hash` is the run's recorded scenario spec hash.
+/// - `run_config_hash` hashes [`run_config_from_state`] for the run.
+/// - For decisions, `evidence_hash` hashes the trigger's [`GateEvalRecord`]s in log order and
+///   `result_hash` hashes the [`DecisionRecord`].
+/// - For submissions, `evidence_hash` hashes the submitted payload and `result_hash` hashes the
+///   [`SubmissionRecord`].
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct EvaluationAuditRecord {
+    /// Tenant identifier.
+    pub tenant_id: TenantId,
+    /// Namespace identifier.
+    pub namespace_id: NamespaceId,
+    /// Run identifier.
+    pub run_id: RunId,
+    /// Scenario identifier.
+    pub scenario_id: ScenarioId,
+    /// Outcome described by this record.
+    pub subject: EvaluationSubject,
+    /// Canonical hash of the scenario specification.
+    pub spec_hash: HashDigest,
+    /// Canonical hash of the run configuration.
+    pub run_config_hash: HashDigest,
+    /// Canonical hash of the evidence the outcome was derived from.
+    pub evidence_hash: HashDigest,
+    /// Canonical hash of the outcome record.
+    pub result_hash: HashDigest,
+}
+
+impl EvaluationAuditRecord {
+    /// Builds the audit record for a decision logged in `state`.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`HashError`] when canonical hashing fails.
+    pub fn for_decision(
+        state: &RunState,
+        decision: &DecisionRecord,
+        algorithm: HashAlgorithm,
+    ) -> Result<Self, HashError> {
+        let gate_evals: Vec<&GateEvalRecord> = state
+            .gate_evals
+            .iter()
+            .filter(|record| record.trigger_id == decision.trigger_id)
+            .collect();
+        Ok(Self {
+            tenant_id: state.tenant_id,
+            namespace_id: state.namespace_id,
+            run_id: state.run_id.clone(),
+            scenario_id: state.scenario_id.clone(),
+            subject: EvaluationSubject::Decision {
+                trigger_id: decision.trigger_id.clone(),
+                decision_id: decision.decision_id.clone(),
+                seq: decision.seq,
+            },
+            spec_hash: state.spec_hash.clone(),
+            run_config_hash: hash_canonical_json(algorithm, &run_config_from_state(state))?,
+            evidence_hash: hash_canonical_json(algorithm, &gate_evals)?,
+            result_hash: hash_canonical_json(algorithm, decision)?,
+        })
+    }
+
+    /// Builds the audit record for a submission logged in `state`.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`HashError`] when canonical hashing fails.
+    pub fn for_submission(
+        state: &RunState,
+        submission: &SubmissionRecord,
+        algorithm: HashAlgorithm,
+    ) -> Result<Self, HashError> {
+        Ok(Self {
+            tenant_id: state.tenant_id,
+            namespace_id: state.namespace_id,
+            run_id: state.run_id.clone(),
+            scenario_id: state.scenario_id.clone(),
+            subject: EvaluationSubject::Submission {
+                submission_id: submission.submission_id.clone(),
+            },
+            spec_hash: state.spec_hash.clone(),
+            run_config_hash: hash_canonical_json(algorithm, &run_config_from_state(state))?,
+            evidence_hash: hash_canonical_json(algorithm, &submission.payload)?,
+            result_hash: hash_canonical_json(algorithm, submission)?,
+        })
+    }
+}
+
+/// Builds audit records for every decision and submission in `state`.
+///
+/// Decisions come first in sequence order, followed by submissions in log order.
+///
+/// # Errors
+///
+/// Returns [`HashError`] when canonical hashing fails.
+pub fn evaluation_audit_records(
+    state: &RunState,
+    algorithm: HashAlgorithm,
+) -> Result<Vec<EvaluationAuditRecord>, HashError> {
+    let decisions = state
+        .decisions
+        .iter()
+        .map(|decision| EvaluationAuditRecord::for_decision(state, decision, algorithm));
+    let submissions = state
+        .submissions
+        .iter()
+        .map(|submission| EvaluationAuditRecord::for_submission(state, submission, algorithm));
+    decisions.chain(submissions).collect()
+}
+
+/// Reconstructs the run configuration retained in run state.
+///
+/// Run state does not retain `policy_tags`, so they are always empty here;
+/// auditors recomputing `run_config_hash` must hash the same shape.
+#[must_use]
+pub fn run_config_from_state(state: &RunState) -> RunConfig {
+    RunConfig {
+        tenant_id: state.tenant_id,
+        namespace_id: state.namespace_id,
+        run_id: state.run_id.clone(),
+        scenario_id: state.scenario_id.clone(),
+        dispatch_targets: state.dispatch_targets.clone(),
+        policy_tags: Vec::new(),
+    }
+}
@@ -3,12 +3,14 @@
 // Module: Decision Gate Canonical Hashing
 // Description: RFC 8785 JSON canonicalization and content hashing utilities.
 // Purpose: Provide deterministic hashes for specs, logs, and runpack artifacts.
-// Dependencies: serde, serde_jcs, sha2
+// Dependencies: blake3, serde, serde_jcs, sha2
 // ============================================================================
 
 //! ## Overview
 //! Decision Gate hashes all canonical JSON using RFC 8785 (JCS) to guarantee stable,
 //! replayable digests. Binary payloads are hashed directly over raw bytes.
+//! SHA-256 remains the default; BLAKE3 is available for large artifacts where
+//! hashing throughput matters.
 //!
 //! Security posture: hashing is part of audit integrity; see
 //! `Docs/security/threat_model.md`.
@@ -48,6 +50,8 @@ use thiserror::Error;
 pub enum HashAlgorithm {
     /// SHA-256 hashing (FIPS-friendly default).
     Sha256,
+    /// BLAKE3 hashing with a 32-byte digest (faster for large artifacts).
+    Blake3,
 }
 
 /// Default hash algorithm for Decision Gate.
@@ -498,6 +502,9 @@ pub fn hash_bytes(algorithm: HashAlgorithm, bytes: &[u8]) -> HashDigest {
             let digest = hasher.finalize();
             HashDigest::new(HashAlgorithm::Sha256, &digest)
         }
+        HashAlgorithm::Blake3 => {
+            HashDigest::new(HashAlgorithm::Blake3, blake3::hash(bytes).as_bytes())
+        }
     }
 }
 
@@ -15,6 +15,7 @@
 // SECTION: Submodules
 // ============================================================================
 
+pub mod audit;
 pub mod data_shape;
 pub mod disclosure;
 pub mod evidence;
@@ -24,6 +25,7 @@ pub mod providers;
 pub mod runpack;
 pub mod spec;
 pub mod state;
+pub mod state_diff;
 pub mod summary;
 pub mod time;
 
@@ -31,6 +33,10 @@ pub mod time;
 // SECTION: Re-Exports
 // ============================================================================
 
+pub use audit::EvaluationAuditRecord;
+pub use audit::EvaluationSubject;
+pub use audit::evaluation_audit_records;
+pub use audit::run_config_from_state;
 pub use data_shape::DataShapePage;
 pub use data_shape::DataShapeRecord;
 pub use data_shape::DataShapeRef;
@@ -80,10 +86,12 @@ pub use providers::BUILTIN_PROVIDER_IDS;
 pub use providers::is_builtin_provider_id;
 pub use runpack::ArtifactKind;
 pub use runpack::ArtifactRecord;
+pub use runpack::Compression;
 pub use runpack::FileHashEntry;
 pub use runpack::RunpackIntegrity;
 pub use runpack::RunpackManifest;
 pub use runpack::RunpackSecurityContext;
+pub use runpack::RunpackSignature;
 pub use runpack::RunpackVersion;
 pub use runpack::VerifierMode;
 pub use spec::AdvanceTo;
@@ -105,7 +113,15 @@ pub use state::EvidenceRecord;
 pub use state::GateEvalRecord;
 pub use state::GateEvaluation;
 pub use state::GateTraceEntry;
+pub use state::MAX_RUN_CONFIG_DISPATCH_TARGETS;
+pub use state::MAX_RUN_CONFIG_ID_BYTES;
+pub use state::MAX_RUN_CONFIG_POLICY_TAGS;
+pub use state::MAX_TRIGGER_CONTENT_URI_BYTES;
+pub use state::MAX_TRIGGER_ID_BYTES;
+pub use state::MAX_TRIGGER_PAYLOAD_BYTES;
+pub use state::MAX_TRIGGER_UNIX_MILLIS;
 pub use state::RunConfig;
+pub use state::RunConfigError;
 pub use state::RunState;
 pub use state::RunStatus;
 pub use state::SubmissionRecord;
@@ -113,7 +129,13 @@ pub use state::ToolCallError;
 pub use state::ToolCallErrorDetails;
 pub use state::ToolCallRecord;
 pub use state::TriggerEvent;
+pub use state::TriggerEventError;
 pub use state::TriggerKind;
 pub use state::TriggerRecord;
+pub use state_diff::DiffKind;
+pub use state_diff::DisclosureDiff;
+pub use state_diff::FieldChange;
+pub use state_diff::GateDiff;
+pub use state_diff::RunStateDiff;
 pub use summary::SafeSummary;
 pub use time::Timestamp;
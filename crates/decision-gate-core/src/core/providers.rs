@@ -24,7 +24,7 @@
 /// # Invariants
 /// - Identifiers are lowercase ASCII strings.
 /// - Identifiers remain stable for config and contract validation.
-pub const BUILTIN_PROVIDER_IDS: [&str; 4] = ["time", "env", "json", "http"];
+pub const BUILTIN_PROVIDER_IDS: [&str; 6] = ["time", "env", "json", "http", "sqlite", "postgres"];
 
 /// Returns true when the identifier is reserved for a built-in provider.
 #[must_use]
@@ -89,6 +89,24 @@ pub struct RunpackManifest {
     pub integrity: RunpackIntegrity,
     /// Artifact index entries.
     pub artifacts: Vec<ArtifactRecord>,
+    /// Detached signature over the canonical manifest, when signed.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub signature: Option<RunpackSignature>,
+}
+
+/// Detached signature embedded in a runpack manifest.
+///
+/// # Invariants
+/// - `signature` covers the canonical JSON manifest with `signature` omitted.
+/// - `key_id` is informational; verifiers trust only their configured key.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct RunpackSignature {
+    /// Signature scheme identifier.
+    pub scheme: String,
+    /// Identifier for the signing key.
+    pub key_id: String,
+    /// Signature bytes.
+    pub signature: Vec<u8>,
 }
 
 /// Security context metadata for runpack exports.
@@ -130,7 +148,7 @@ pub struct FileHashEntry {
 /// Artifact record indexed by the runpack manifest.
 ///
 /// # Invariants
-/// - `hash` matches the artifact bytes at `path`.
+/// - `hash` matches the artifact bytes at `path` after decoding `content_encoding`.
 #[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
 pub struct ArtifactRecord {
     /// Artifact identifier.
@@ -145,6 +163,32 @@ pub struct ArtifactRecord {
     pub hash: HashDigest,
     /// Indicates whether the artifact is required for verification.
     pub required: bool,
+    /// Encoding applied to the stored bytes; omitted when stored as-is.
+    #[serde(default, skip_serializing_if = "Compression::is_none")]
+    pub content_encoding: Compression,
+}
+
+/// Compression applied to stored runpack artifact bytes.
+///
+/// # Invariants
+/// - Variants are stable for serialization and contract matching.
+/// - Artifact hashes always cover the uncompressed bytes.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum Compression {
+    /// Artifact bytes are stored uncompressed.
+    #[default]
+    None,
+    /// Artifact bytes are stored as a single gzip member.
+    Gzip,
+}
+
+impl Compression {
+    /// Returns true when artifact bytes are stored uncompressed.
+    #[must_use]
+    pub const fn is_none(&self) -> bool {
+        matches!(self, Self::None)
+    }
 }
 
 /// Artifact kinds included in Decision Gate runpacks.
@@ -172,6 +216,8 @@ pub enum ArtifactKind {
     SubmissionLog,
     /// Tool-call transcript artifact.
     ToolTranscript,
+    /// Evaluation audit record artifact.
+    EvaluationAudit,
     /// Verifier output report.
     VerifierReport,
     /// Custom artifact record.
@@ -498,6 +498,13 @@ fn collect_conditions_inner(requirement: &Requirement<ConditionId>, out: &mut Ve
             }
         }
         Requirement::Not(inner) => collect_conditions_inner(inner, out),
+        Requirement::Implies {
+            antecedent,
+            consequent,
+        } => {
+            collect_conditions_inner(antecedent, out);
+            collect_conditions_inner(consequent, out);
+        }
         Requirement::And(reqs) | Requirement::Or(reqs) => {
             for req in reqs {
                 collect_conditions_inner(req, out);
@@ -505,6 +512,12 @@ fn collect_conditions_inner(requirement: &Requirement<ConditionId>, out: &mut Ve
         }
         Requirement::RequireGroup {
             reqs, ..
+        }
+        | Requirement::RequireExactly {
+            reqs, ..
+        }
+        | Requirement::RequireAtMost {
+            reqs, ..
         } => {
             for req in reqs {
                 collect_conditions_inner(req, out);
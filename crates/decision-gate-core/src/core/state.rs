@@ -4,7 +4,7 @@
 // Description: Run state, trigger logs, decisions, and evaluations.
 // Purpose: Capture deterministic run evolution for replay and verification.
 // Dependencies: crate::core::{disclosure, evidence, hashing, identifiers, summary, time},
-// ret-logic, serde
+// ret-logic, serde, thiserror
 // ============================================================================
 
 //! ## Overview
@@ -21,6 +21,7 @@
 use ret_logic::TriState;
 use serde::Deserialize;
 use serde::Serialize;
+use thiserror::Error;
 
 use crate::core::disclosure::DispatchTarget;
 use crate::core::disclosure::PacketPayload;
@@ -28,6 +29,8 @@ use crate::core::disclosure::PacketRecord;
 use crate::core::evidence::EvidenceResult;
 use crate::core::evidence::ProviderMissingError;
 use crate::core::hashing::HashDigest;
+use crate::core::hashing::HashError;
+use crate::core::hashing::canonical_json_bytes_with_limit;
 use crate::core::identifiers::ConditionId;
 use crate::core::identifiers::CorrelationId;
 use crate::core::identifiers::DecisionId;
@@ -45,10 +48,23 @@ use crate::core::time::Timestamp;
 // SECTION: Run Configuration
 // ============================================================================
 
+/// Maximum byte length of run and scenario identifiers in a [`RunConfig`].
+pub const MAX_RUN_CONFIG_ID_BYTES: usize = 256;
+
+/// Maximum number of dispatch targets in a [`RunConfig`].
+pub const MAX_RUN_CONFIG_DISPATCH_TARGETS: usize = 64;
+
+/// Maximum number of policy tags in a [`RunConfig`].
+pub const MAX_RUN_CONFIG_POLICY_TAGS: usize = 64;
+
 /// Configuration required to start a run.
 ///
+/// `dispatch_targets` and `policy_tags` default to empty when omitted, so a
+/// minimal config only names the tenant, namespace, run, and scenario.
+///
 /// # Invariants
 /// - Identifiers must refer to the same tenant/namespace/scenario scope.
+/// - [`RunConfig::validate`] bounds identifier lengths and list sizes.
 #[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
 pub struct RunConfig {
     /// Tenant identifier for the run.
@@ -59,12 +75,129 @@ pub struct RunConfig {
     pub run_id: RunId,
     /// Scenario identifier.
     pub scenario_id: ScenarioId,
-    /// Dispatch targets for disclosures.
+    /// Dispatch targets for disclosures (default: none).
+    #[serde(default)]
     pub dispatch_targets: Vec<DispatchTarget>,
-    /// Optional policy tags for run-level disclosure.
+    /// Optional policy tags for run-level disclosure (default: none).
+    #[serde(default)]
     pub policy_tags: Vec<String>,
 }
 
+impl RunConfig {
+    /// Validates identifier shape, dispatch targets, and policy tags.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`RunConfigError`] describing the first violation found.
+    pub fn validate(&self) -> Result<(), RunConfigError> {
+        validate_identifier("run_id", self.run_id.as_str())?;
+        validate_identifier("scenario_id", self.scenario_id.as_str())?;
+        if self.dispatch_targets.len() > MAX_RUN_CONFIG_DISPATCH_TARGETS {
+            return Err(RunConfigError::TooMany {
+                field: "dispatch_targets",
+                max: MAX_RUN_CONFIG_DISPATCH_TARGETS,
+                actual: self.dispatch_targets.len(),
+            });
+        }
+        for (index, target) in self.dispatch_targets.iter().enumerate() {
+            if dispatch_target_fields(target).iter().any(|value| value.trim().is_empty()) {
+                return Err(RunConfigError::EmptyValue(format!("dispatch_targets[{index}]")));
+            }
+            if self.dispatch_targets[.. index].contains(target) {
+                return Err(RunConfigError::Duplicate(format!("dispatch_targets[{index}]")));
+            }
+        }
+        if self.policy_tags.len() > MAX_RUN_CONFIG_POLICY_TAGS {
+            return Err(RunConfigError::TooMany {
+                field: "policy_tags",
+                max: MAX_RUN_CONFIG_POLICY_TAGS,
+                actual: self.policy_tags.len(),
+            });
+        }
+        for (index, tag) in self.policy_tags.iter().enumerate() {
+            validate_identifier("policy_tags", tag)?;
+            if self.policy_tags[.. index].contains(tag) {
+                return Err(RunConfigError::Duplicate(format!("policy_tags: {tag}")));
+            }
+        }
+        Ok(())
+    }
+}
+
+/// Run configuration validation errors.
+///
+/// # Invariants
+/// - Variants are stable for programmatic handling.
+#[derive(Debug, Clone, PartialEq, Eq, Error)]
+pub enum RunConfigError {
+    /// A required string value is empty or whitespace.
+    #[error("run config {0} must not be empty")]
+    EmptyValue(String),
+    /// An identifier exceeds [`MAX_RUN_CONFIG_ID_BYTES`].
+    #[error("run config {field} is {actual} bytes (max {max})")]
+    TooLong {
+        /// Offending field.
+        field: &'static str,
+        /// Maximum allowed bytes.
+        max: usize,
+        /// Actual byte length.
+        actual: usize,
+    },
+    /// An identifier contains control characters.
+    #[error("run config {0} contains control characters")]
+    ControlCharacters(&'static str),
+    /// A list exceeds its maximum entry count.
+    #[error("run config {field} has {actual} entries (max {max})")]
+    TooMany {
+        /// Offending field.
+        field: &'static str,
+        /// Maximum allowed entries.
+        max: usize,
+        /// Actual entry count.
+        actual: usize,
+    },
+    /// A list contains a duplicate entry.
+    #[error("run config has duplicate {0}")]
+    Duplicate(String),
+}
+
+/// Validates a run config identifier or tag.
+fn validate_identifier(field: &'static str, value: &str) -> Result<(), RunConfigError> {
+    if value.trim().is_empty() {
+        return Err(RunConfigError::EmptyValue(field.to_string()));
+    }
+    if value.len() > MAX_RUN_CONFIG_ID_BYTES {
+        return Err(RunConfigError::TooLong {
+            field,
+            max: MAX_RUN_CONFIG_ID_BYTES,
+            actual: value.len(),
+        });
+    }
+    if value.chars().any(char::is_control) {
+        return Err(RunConfigError::ControlCharacters(field));
+    }
+    Ok(())
+}
+
+/// Returns the string fields of a dispatch target.
+fn dispatch_target_fields(target: &DispatchTarget) -> Vec<&str> {
+    match target {
+        DispatchTarget::Agent {
+            agent_id,
+        } => vec![agent_id],
+        DispatchTarget::Session {
+            session_id,
+        } => vec![session_id],
+        DispatchTarget::External {
+            system,
+            target,
+        } => vec![system, target],
+        DispatchTarget::Channel {
+            channel,
+        } => vec![channel],
+    }
+}
+
 // ============================================================================
 // SECTION: Run Status
 // ============================================================================
@@ -105,11 +238,25 @@ pub enum TriggerKind {
     BackendEvent,
 }
 
+/// Maximum byte length of trigger, source, and correlation identifiers.
+pub const MAX_TRIGGER_ID_BYTES: usize = 256;
+
+/// Maximum size of an inline trigger payload (canonical JSON or raw bytes).
+pub const MAX_TRIGGER_PAYLOAD_BYTES: usize = 1024 * 1024;
+
+/// Maximum byte length of an external trigger payload URI.
+pub const MAX_TRIGGER_CONTENT_URI_BYTES: usize = 4096;
+
+/// Largest accepted unix-millis trigger time (9999-12-31T23:59:59.999Z).
+pub const MAX_TRIGGER_UNIX_MILLIS: i64 = 253_402_300_799_999;
+
 /// Canonical trigger event.
 ///
 /// # Invariants
 /// - Identifiers must refer to the same run scope.
 /// - `payload` is optional and not interpreted by the core.
+/// - Untrusted triggers should pass [`TriggerEvent::validate_and_normalize`] before reaching the
+///   control plane.
 #[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
 pub struct TriggerEvent {
     /// Trigger identifier for idempotency.
@@ -132,6 +279,147 @@ pub struct TriggerEvent {
     pub correlation_id: Option<CorrelationId>,
 }
 
+impl TriggerEvent {
+    /// Validates trigger bounds and canonicalizes the payload.
+    ///
+    /// Unix-millis timestamps must fall within `0..=MAX_TRIGGER_UNIX_MILLIS`,
+    /// identifiers are bounded by [`MAX_TRIGGER_ID_BYTES`], and inline
+    /// payloads by [`MAX_TRIGGER_PAYLOAD_BYTES`]. JSON payloads are rewritten
+    /// in RFC 8785 canonical form so equivalent triggers hash identically.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`TriggerEventError`] describing the first violation found.
+    pub fn validate_and_normalize(mut self) -> Result<Self, TriggerEventError> {
+        if let Timestamp::UnixMillis(value) = self.time
+            && !(0 ..= MAX_TRIGGER_UNIX_MILLIS).contains(&value)
+        {
+            return Err(TriggerEventError::InvalidTimestamp(value));
+        }
+        validate_trigger_field("trigger_id", self.trigger_id.as_str())?;
+        validate_trigger_field("source_id", &self.source_id)?;
+        if let Some(correlation_id) = &self.correlation_id {
+            validate_trigger_field("correlation_id", correlation_id.as_str())?;
+        }
+        self.payload = self.payload.map(normalize_trigger_payload).transpose()?;
+        Ok(self)
+    }
+}
+
+/// Trigger event validation errors.
+///
+/// # Invariants
+/// - Variants are stable for programmatic handling.
+#[derive(Debug, Clone, PartialEq, Eq, Error)]
+pub enum TriggerEventError {
+    /// Unix-millis timestamp is negative or beyond [`MAX_TRIGGER_UNIX_MILLIS`].
+    #[error("trigger time {0} is outside the supported unix millis range")]
+    InvalidTimestamp(i64),
+    /// A required string value is empty or whitespace.
+    #[error("trigger {0} must not be empty")]
+    EmptyValue(&'static str),
+    /// A string value exceeds its byte limit.
+    #[error("trigger {field} is {actual} bytes (max {max})")]
+    TooLong {
+        /// Offending field.
+        field: &'static str,
+        /// Maximum allowed bytes.
+        max: usize,
+        /// Actual byte length.
+        actual: usize,
+    },
+    /// An identifier contains control characters.
+    #[error("trigger {0} contains control characters")]
+    ControlCharacters(&'static str),
+    /// Inline payload exceeds [`MAX_TRIGGER_PAYLOAD_BYTES`].
+    #[error("trigger payload is {actual} bytes (max {max})")]
+    PayloadTooLarge {
+        /// Maximum allowed bytes.
+        max: usize,
+        /// Actual byte length.
+        actual: usize,
+    },
+    /// JSON payload could not be canonicalized.
+    #[error("trigger payload canonicalization failed: {0}")]
+    Canonicalization(String),
+}
+
+/// Validates a trigger identifier or source string.
+fn validate_trigger_field(field: &'static str, value: &str) -> Result<(), TriggerEventError> {
+    if value.trim().is_empty() {
+        return Err(TriggerEventError::EmptyValue(field));
+    }
+    if value.len() > MAX_TRIGGER_ID_BYTES {
+        return Err(TriggerEventError::TooLong {
+            field,
+            max: MAX_TRIGGER_ID_BYTES,
+            actual: value.len(),
+        });
+    }
+    if value.chars().any(char::is_control) {
+        return Err(TriggerEventError::ControlCharacters(field));
+    }
+    Ok(())
+}
+
+/// Bounds a trigger payload and rewrites JSON values in canonical form.
+fn normalize_trigger_payload(payload: PacketPayload) -> Result<PacketPayload, TriggerEventError> {
+    match payload {
+        PacketPayload::Json {
+            value,
+        } => {
+            let bytes = canonical_json_bytes_with_limit(&value, MAX_TRIGGER_PAYLOAD_BYTES)
+                .map_err(|err| match err {
+                    HashError::SizeLimitExceeded {
+                        limit,
+                        actual,
+                    } => TriggerEventError::PayloadTooLarge {
+                        max: limit,
+                        actual,
+                    },
+                    HashError::Canonicalization(message) => {
+                        TriggerEventError::Canonicalization(message)
+                    }
+                })?;
+            let value = serde_json::from_slice(&bytes)
+                .map_err(|err| TriggerEventError::Canonicalization(err.to_string()))?;
+            Ok(PacketPayload::Json {
+                value,
+            })
+        }
+        PacketPayload::Bytes {
+            bytes,
+        } => {
+            if bytes.len() > MAX_TRIGGER_PAYLOAD_BYTES {
+                return Err(TriggerEventError::PayloadTooLarge {
+                    max: MAX_TRIGGER_PAYLOAD_BYTES,
+                    actual: bytes.len(),
+                });
+            }
+            Ok(PacketPayload::Bytes {
+                bytes,
+            })
+        }
+        PacketPayload::External {
+            content_ref,
+        } => {
+            if content_ref.uri.trim().is_empty() {
+                return Err(TriggerEventError::EmptyValue("payload.content_ref.uri"));
+            }
+            if content_ref.uri.len() > MAX_TRIGGER_CONTENT_URI_BYTES {
+                return Err(TriggerEventError::TooLong {
+                    field: "payload.content_ref.uri",
+                    max: MAX_TRIGGER_CONTENT_URI_BYTES,
+                    actual: content_ref.uri.len(),
+                });
+            }
+            Ok(PacketPayload::External {
+                content_ref,
+            })
+        }
+    }
+}
+
 /// Trigger record logged in the run state.
 ///
 /// # Invariants
@@ -392,3 +680,15 @@ pub struct RunState {
     /// Tool-call transcript.
     pub tool_calls: Vec<ToolCallRecord>,
 }
+
+impl RunState {
+    /// Returns the run version used for optimistic concurrency checks.
+    ///
+    /// The version counts recorded triggers and submissions, so it advances on
+    /// every state transition but not on read-only calls such as status.
+    #[must_use]
+    pub fn version(&self) -> u64 {
+        let records = self.triggers.len().saturating_add(self.submissions.len());
+        u64::try_from(records).unwrap_or(u64::MAX)
+    }
+}
@@ -0,0 +1,242 @@
+// crates/decision-gate-core/src/core/state_diff.rs
+// ============================================================================
+// Module: Decision Gate Run State Diff
+// Description: Structural comparison of two run state snapshots.
+// Purpose: Report gate, status, and disclosure changes between run versions.
+// Dependencies: crate::core::{disclosure, identifiers, state}, ret-logic, serde
+// ============================================================================
+
+//! ## Overview
+//! [`RunState::diff`] compares two snapshots of the same run, typically
+//! consecutive store versions, and reports run status and stage transitions,
+//! gates whose latest evaluation appeared, disappeared, or changed status, and
+//! disclosures that were issued, dropped, or gained dispatch receipts.
+//!
+//! Gates are keyed by `(stage_id, gate_id)` and disclosures by `packet_id`;
+//! when a log records the same key more than once, the last entry wins. Entries
+//! are sorted by key so equal inputs always produce byte-identical output.
+
+// ============================================================================
+// SECTION: Imports
+// ============================================================================
+
+use std::collections::BTreeMap;
+use std::collections::BTreeSet;
+
+use ret_logic::TriState;
+use serde::Deserialize;
+use serde::Serialize;
+
+use crate::core::disclosure::PacketRecord;
+use crate::core::identifiers::GateId;
+use crate::core::identifiers::PacketId;
+use crate::core::identifiers::StageId;
+use crate::core::state::GateEvalRecord;
+use crate::core::state::RunState;
+use crate::core::state::RunStatus;
+
+// ============================================================================
+// SECTION: Diff Types
+// ============================================================================
+
+/// Kind of change recorded for a keyed entry.
+///
+/// # Invariants
+/// - Variants are stable for serialization and contract matching.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum DiffKind {
+    /// Entry exists only in the later snapshot.
+    Added,
+    /// Entry exists only in the earlier snapshot.
+    Removed,
+    /// Entry exists in both snapshots with different contents.
+    Changed,
+}
+
+/// Before and after values of a single field.
+///
+/// # Invariants
+/// - `from != to`.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct FieldChange<T> {
+    /// Value in the earlier snapshot.
+    pub from: T,
+    /// Value in the later snapshot.
+    pub to: T,
+}
+
+/// Gate whose latest evaluation differs between snapshots.
+///
+/// # Invariants
+/// - `from_status` is `None` only for [`DiffKind::Added`]; `to_status` is `None` only for
+///   [`DiffKind::Removed`].
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct GateDiff {
+    /// Stage the gate was evaluated in.
+    pub stage_id: StageId,
+    /// Gate identifier.
+    pub gate_id: GateId,
+    /// Kind of change.
+    pub change: DiffKind,
+    /// Latest gate status in the earlier snapshot.
+    pub from_status: Option<TriState>,
+    /// Latest gate status in the later snapshot.
+    pub to_status: Option<TriState>,
+}
+
+/// Disclosure whose packet record differs between snapshots.
+///
+/// # Invariants
+/// - Dispatch identifier lists are sorted and deduplicated.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct DisclosureDiff {
+    /// Packet identifier.
+    pub packet_id: PacketId,
+    /// Stage the packet was issued in.
+    pub stage_id: StageId,
+    /// Kind of change.
+    pub change: DiffKind,
+    /// Dispatch identifiers present only in the later snapshot.
+    pub dispatches_added: Vec<String>,
+    /// Dispatch identifiers present only in the earlier snapshot.
+    pub dispatches_removed: Vec<String>,
+}
+
+/// Structural differences between two run state snapshots.
+///
+/// # Invariants
+/// - `gates` is sorted by `(stage_id, gate_id)` and `disclosures` by `packet_id`.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct RunStateDiff {
+    /// Run lifecycle status transition, when the status changed.
+    pub status: Option<FieldChange<RunStatus>>,
+    /// Current stage transition, when the run moved stages.
+    pub current_stage: Option<FieldChange<StageId>>,
+    /// Gates added, removed, or with a changed latest status.
+    pub gates: Vec<GateDiff>,
+    /// Disclosures added, removed, or changed.
+    pub disclosures: Vec<DisclosureDiff>,
+}
+
+impl RunStateDiff {
+    /// Returns true when the snapshots have no structural differences.
+    #[must_use]
+    pub const fn is_empty(&self) -> bool {
+        self.status.is_none()
+            && self.current_stage.is_none()
+            && self.gates.is_empty()
+            && self.disclosures.is_empty()
+    }
+}
+
+// ============================================================================
+// SECTION: Diff Computation
+// ============================================================================
+
+impl RunState {
+    /// Computes the structural diff from `self` (earlier) to `other` (later).
+    #[must_use]
+    pub fn diff(&self, other: &Self) -> RunStateDiff {
+        RunStateDiff {
+            status: field_change(&self.status, &other.status),
+            current_stage: field_change(&self.current_stage_id, &other.current_stage_id),
+            gates: gate_diffs(self, other),
+            disclosures: disclosure_diffs(self, other),
+        }
+    }
+}
+
+/// Returns a field change when the values differ.
+fn field_change<T: Clone + PartialEq>(from: &T, to: &T) -> Option<FieldChange<T>> {
+    (from != to).then(|| FieldChange {
+        from: from.clone(),
+        to: to.clone(),
+    })
+}
+
+/// Pairs entries of two keyed maps in key order.
+fn paired<'a, K: Ord, V>(
+    from: &'a BTreeMap<K, V>,
+    to: &'a BTreeMap<K, V>,
+) -> impl Iterator<Item = (Option<&'a V>, Option<&'a V>)> {
+    let keys: BTreeSet<&K> = from.keys().chain(to.keys()).collect();
+    keys.into_iter().map(|key| (from.get(key), to.get(key)))
+}
+
+/// Classifies a paired entry, returning `None` when it is unchanged.
+fn classify<V: PartialEq>(from: Option<&V>, to: Option<&V>) -> Option<DiffKind> {
+    match (from, to) {
+        (None, Some(_)) => Some(DiffKind::Added),
+        (Some(_), None) => Some(DiffKind::Removed),
+        (Some(from), Some(to)) if from != to => Some(DiffKind::Changed),
+        _ => None,
+    }
+}
+
+/// Indexes the latest evaluation of each gate by `(stage_id, gate_id)`.
+fn latest_gate_evals(state: &RunState) -> BTreeMap<(&str, &str), &GateEvalRecord> {
+    state
+        .gate_evals
+        .iter()
+        .map(|record| ((record.stage_id.as_str(), record.evaluation.gate_id.as_str()), record))
+        .collect()
+}
+
+/// Computes gate diffs between two snapshots.
+fn gate_diffs(from: &RunState, to: &RunState) -> Vec<GateDiff> {
+    let (from_gates, to_gates) = (latest_gate_evals(from), latest_gate_evals(to));
+    paired(&from_gates, &to_gates)
+        .filter_map(|(before, after)| {
+            let from_status = before.map(|record| record.evaluation.status);
+            let to_status = after.map(|record| record.evaluation.status);
+            let change = classify(from_status.as_ref(), to_status.as_ref())?;
+            let record = after.or(before)?;
+            Some(GateDiff {
+                stage_id: record.stage_id.clone(),
+                gate_id: record.evaluation.gate_id.clone(),
+                change,
+                from_status,
+                to_status,
+            })
+        })
+        .collect()
+}
+
+/// Indexes packet records by `packet_id`.
+fn packets_by_id(state: &RunState) -> BTreeMap<&str, &PacketRecord> {
+    state.packets.iter().map(|record| (record.envelope.packet_id.as_str(), record)).collect()
+}
+
+/// Returns the sorted set of dispatch identifiers recorded for a packet.
+fn dispatch_ids(record: Option<&PacketRecord>) -> BTreeSet<&str> {
+    record
+        .map(|record| record.receipts.iter().map(|receipt| receipt.dispatch_id.as_str()).collect())
+        .unwrap_or_default()
+}
+
+/// Computes disclosure diffs between two snapshots.
+fn disclosure_diffs(from: &RunState, to: &RunState) -> Vec<DisclosureDiff> {
+    let (from_packets, to_packets) = (packets_by_id(from), packets_by_id(to));
+    paired(&from_packets, &to_packets)
+        .filter_map(|(before, after)| {
+            let change = classify(before, after)?;
+            let record = after.or(before)?;
+            let (before_ids, after_ids) =
+                (dispatch_ids(before.copied()), dispatch_ids(after.copied()));
+            Some(DisclosureDiff {
+                packet_id: record.envelope.packet_id.clone(),
+                stage_id: record.envelope.stage_id.clone(),
+                change,
+                dispatches_added: after_ids
+                    .difference(&before_ids)
+                    .map(ToString::to_string)
+                    .collect(),
+                dispatches_removed: before_ids
+                    .difference(&after_ids)
+                    .map(ToString::to_string)
+                    .collect(),
+            })
+        })
+        .collect()
+}
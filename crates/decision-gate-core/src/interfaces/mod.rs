@@ -106,6 +106,18 @@ pub trait EvidenceProvider {
     ///
     /// Returns [`ProviderMissingError`] when required providers are missing or blocked.
     fn validate_providers(&self, spec: &ScenarioSpec) -> Result<(), ProviderMissingError>;
+
+    /// Eagerly initializes provider resources ahead of the first query.
+    ///
+    /// The default implementation is a no-op for providers without deferred
+    /// setup.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`EvidenceError`] when the provider cannot be initialized.
+    fn warm_up(&self) -> Result<(), EvidenceError> {
+        Ok(())
+    }
 }
 
 // ============================================================================
@@ -252,6 +264,17 @@ pub enum StoreError {
     /// Store reported an error.
     #[error("run state store error: {0}")]
     Store(String),
+    /// Store is temporarily unavailable; the call was not attempted.
+    #[error("run state store unavailable: {0}")]
+    Unavailable(String),
+    /// Stored run version differed from the expected version; nothing was saved.
+    #[error("run state store version conflict: expected {expected}, found {actual}")]
+    VersionConflict {
+        /// Version the caller expected.
+        expected: u64,
+        /// Version currently stored.
+        actual: u64,
+    },
 }
 
 /// Run state store for persistence.
@@ -275,6 +298,24 @@ pub trait RunStateStore {
     /// Returns [`StoreError`] when saving fails.
     fn save(&self, state: &RunState) -> Result<(), StoreError>;
 
+    /// Saves run state only if the stored run is still at `expected_version`.
+    ///
+    /// Versions are compared with [`RunState::version`], which tool-call
+    /// records alone do not advance. The default implementation loads and
+    /// saves without holding a lock between the two steps; stores shared
+    /// across processes override it with an atomic compare-and-swap.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`StoreError::VersionConflict`] when the stored version differs,
+    /// [`StoreError::Invalid`] when the run does not exist, and [`StoreError`]
+    /// when loading or saving fails.
+    fn save_if_version(&self, state: &RunState, expected_version: u64) -> Result<(), StoreError> {
+        let current = self.load(&state.tenant_id, &state.namespace_id, &state.run_id)?;
+        check_run_version(current.as_ref(), expected_version)?;
+        self.save(state)
+    }
+
     /// Reports store readiness for liveness/readiness probes.
     ///
     /// # Errors
@@ -285,6 +326,31 @@ pub trait RunStateStore {
     }
 }
 
+/// Checks that a stored run exists and is at `expected_version`.
+///
+/// Shared by [`RunStateStore::save_if_version`] implementations so every store
+/// reports conflicts the same way.
+///
+/// # Errors
+///
+/// Returns [`StoreError::Invalid`] when the run is missing and
+/// [`StoreError::VersionConflict`] when its version differs.
+pub fn check_run_version(
+    current: Option<&RunState>,
+    expected_version: u64,
+) -> Result<(), StoreError> {
+    let current = current
+        .ok_or_else(|| StoreError::Invalid("run state missing for versioned save".to_string()))?;
+    let actual = current.version();
+    if actual != expected_version {
+        return Err(StoreError::VersionConflict {
+            expected: expected_version,
+            actual,
+        });
+    }
+    Ok(())
+}
+
 // ============================================================================
 // SECTION: Data Shape Registry
 // ============================================================================
@@ -45,6 +45,7 @@ pub use interfaces::PolicyError;
 pub use interfaces::RunStateStore;
 pub use interfaces::StoreError;
 pub use interfaces::TriggerSource;
+pub use interfaces::check_run_version;
 pub use runtime::ControlPlane;
 pub use runtime::ControlPlaneConfig;
 pub use runtime::ControlPlaneError;
@@ -52,10 +53,14 @@ pub use runtime::EvaluationResult;
 pub use runtime::GateEvaluator;
 pub use runtime::InMemoryDataShapeRegistry;
 pub use runtime::InMemoryRunStateStore;
+pub use runtime::LeaseAcquireRequest;
+pub use runtime::LeaseReleaseRequest;
+pub use runtime::LeaseReleaseResult;
 pub use runtime::NextRequest;
 pub use runtime::NextResult;
 pub use runtime::PrecheckRequest;
 pub use runtime::PrecheckResult;
+pub use runtime::RunLease;
 pub use runtime::RunpackBuilder;
 pub use runtime::RunpackError;
 pub use runtime::RunpackVerifier;
@@ -66,6 +71,8 @@ pub use runtime::StatusRequest;
 pub use runtime::SubmitRequest;
 pub use runtime::SubmitResult;
 pub use runtime::TriggerResult;
+pub use runtime::VerificationAttestation;
+pub use runtime::VerificationClaims;
 pub use runtime::VerificationReport;
 pub use runtime::VerificationStatus;
 pub use tooling::ToolName;
@@ -0,0 +1,104 @@
+// crates/decision-gate-core/src/runtime/clock.rs
+// ============================================================================
+// Module: Clock Abstraction
+// Description: Injectable time source for leases, caches, expiry, and rate limits.
+// Purpose: Let tests drive time-dependent behavior deterministically.
+// Dependencies: std
+// ============================================================================
+
+//! ## Overview
+//! [`Clock`] supplies both monotonic time (for run leases, cache lifetimes,
+//! and rate-limit windows) and wall-clock Unix seconds (for token expiry).
+//! Production code uses [`SystemClock`]; tests inject a [`ManualClock`] and
+//! advance it explicitly instead of sleeping.
+
+use std::sync::atomic::AtomicU64;
+use std::sync::atomic::Ordering;
+use std::time::Duration;
+use std::time::Instant;
+use std::time::SystemTime;
+use std::time::UNIX_EPOCH;
+
+// ============================================================================
+// SECTION: Clock Trait
+// ============================================================================
+
+/// Time source used by time-dependent runtime and server components.
+pub trait Clock: Send + Sync {
+    /// Returns the current monotonic instant.
+    fn now(&self) -> Instant;
+
+    /// Returns the current wall-clock time in Unix seconds.
+    fn unix_secs(&self) -> u64;
+}
+
+// ============================================================================
+// SECTION: System Clock
+// ============================================================================
+
+/// Clock backed by the operating system.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct SystemClock;
+
+impl Clock for SystemClock {
+    fn now(&self) -> Instant {
+        Instant::now()
+    }
+
+    fn unix_secs(&self) -> u64 {
+        SystemTime::now().duration_since(UNIX_EPOCH).map_or(0, |elapsed| elapsed.as_secs())
+    }
+}
+
+// ============================================================================
+// SECTION: Manual Clock
+// ============================================================================
+
+/// Clock that only moves when advanced explicitly.
+///
+/// # Invariants
+/// - Monotonic and wall-clock readings advance together by the same amount.
+#[derive(Debug)]
+pub struct ManualClock {
+    /// Monotonic reading at construction.
+    origin: Instant,
+    /// Wall-clock Unix seconds at construction.
+    unix_origin: u64,
+    /// Total time advanced since construction, in nanoseconds.
+    elapsed_nanos: AtomicU64,
+}
+
+impl ManualClock {
+    /// Creates a manual clock whose wall-clock reading starts at `unix_secs`.
+    #[must_use]
+    pub fn new(unix_secs: u64) -> Self {
+        Self {
+            origin: Instant::now(),
+            unix_origin: unix_secs,
+            elapsed_nanos: AtomicU64::new(0),
+        }
+    }
+
+    /// Moves the clock forward by `step`.
+    pub fn advance(&self, step: Duration) {
+        let step = u64::try_from(step.as_nanos()).unwrap_or(u64::MAX);
+        let _ = self.elapsed_nanos.fetch_update(Ordering::SeqCst, Ordering::SeqCst, |elapsed| {
+            Some(elapsed.saturating_add(step))
+        });
+    }
+
+    /// Returns the total time advanced since construction.
+    fn elapsed(&self) -> Duration {
+        Duration::from_nanos(self.elapsed_nanos.load(Ordering::SeqCst))
+    }
+}
+
+impl Clock for ManualClock {
+    fn now(&self) -> Instant {
+        self.origin + self.elapsed()
+    }
+
+    fn unix_secs(&self) -> u64 {
+        self.unix_origin.saturating_add(self.elapsed().as_secs())
+    }
+}
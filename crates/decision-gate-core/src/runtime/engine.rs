@@ -19,6 +19,14 @@
 // ============================================================================
 
 use std::collections::BTreeMap;
+use std::collections::BTreeSet;
+use std::sync::Arc;
+use std::sync::Condvar;
+use std::sync::Mutex;
+use std::sync::MutexGuard;
+use std::sync::PoisonError;
+use std::time::Duration;
+use std::time::Instant;
 
 use ret_logic::LogicMode;
 use ret_logic::TriState;
@@ -75,6 +83,7 @@ use crate::core::hashing::DEFAULT_HASH_ALGORITHM;
 use crate::core::hashing::HashAlgorithm;
 use crate::core::hashing::HashDigest;
 use crate::core::hashing::HashError;
+use crate::core::hashing::canonical_json_bytes_with_limit;
 use crate::core::hashing::hash_bytes;
 use crate::core::hashing::hash_canonical_json;
 use crate::core::hashing::hash_canonical_json_with_limit;
@@ -91,7 +100,10 @@ use crate::interfaces::PolicyError;
 use crate::interfaces::RunStateStore;
 use crate::interfaces::StoreError;
 use crate::runtime::GateEvaluator;
+use crate::runtime::clock::Clock;
+use crate::runtime::clock::SystemClock;
 use crate::runtime::comparator::evaluate_comparator;
+use crate::runtime::gate::EvidenceBudgetExceeded;
 use crate::runtime::gate::EvidenceSnapshot;
 use crate::runtime::gate::collect_conditions;
 
@@ -103,6 +115,12 @@ use crate::runtime::gate::collect_conditions;
 pub const MAX_EVIDENCE_VALUE_BYTES: usize = 1024 * 1024;
 /// Maximum bytes allowed for packet and submission payloads before hashing.
 pub const MAX_PAYLOAD_BYTES: usize = 2 * 1024 * 1024;
+/// Default cap on total evidence bytes accepted during one evaluation.
+pub const DEFAULT_MAX_EVIDENCE_BYTES_PER_EVALUATION: usize = 16 * MAX_EVIDENCE_VALUE_BYTES;
+/// Maximum run lease duration in milliseconds of server time.
+pub const MAX_RUN_LEASE_TTL_MS: u64 = 60 * 60 * 1000;
+/// Maximum bytes allowed for a run lease holder identifier.
+pub const MAX_LEASE_HOLDER_BYTES: usize = 256;
 
 // ============================================================================
 // SECTION: Control Plane Configuration
@@ -126,6 +144,8 @@ pub struct ControlPlaneConfig {
     pub provider_trust_overrides: BTreeMap<String, TrustRequirement>,
     /// Condition evaluation ordering strategy.
     pub condition_eval_order: ConditionEvalOrder,
+    /// Maximum total evidence bytes accepted across all conditions in one evaluation.
+    pub max_evidence_bytes_per_evaluation: usize,
 }
 
 /// Strategy for ordering condition evaluation.
@@ -161,6 +181,7 @@ impl Default for ControlPlaneConfig {
             anchor_policy: EvidenceAnchorPolicy::default(),
             provider_trust_overrides: BTreeMap::new(),
             condition_eval_order: ConditionEvalOrder::default(),
+            max_evidence_bytes_per_evaluation: DEFAULT_MAX_EVIDENCE_BYTES_PER_EVALUATION,
         }
     }
 }
@@ -186,6 +207,57 @@ pub struct ControlPlane<P, D, S, Pol> {
     policy: Option<Pol>,
     /// Control plane configuration.
     config: ControlPlaneConfig,
+    /// Serializes load-modify-save cycles per run within this process.
+    run_locks: RunLocks,
+    /// Advisory run leases keyed by run scope; only touched under the run's write lock.
+    leases: Mutex<BTreeMap<LeaseKey, LeaseEntry>>,
+    /// Server-side time source for lease expiry.
+    clock: Arc<dyn Clock>,
+}
+
+/// Lease table entry.
+struct LeaseEntry {
+    /// Lease as reported to callers.
+    lease: RunLease,
+    /// Server-clock instant at which the lease lapses.
+    deadline: Instant,
+}
+
+impl LeaseEntry {
+    /// Returns the lease if it is still active at `now`.
+    fn active(&self, now: Instant) -> Option<&RunLease> {
+        (now < self.deadline).then_some(&self.lease)
+    }
+}
+
+/// Run scope key for the lease table and run write locks.
+type LeaseKey = (TenantId, NamespaceId, String);
+
+/// Runs with a load-modify-save cycle in progress.
+#[derive(Default)]
+struct RunLocks {
+    /// Run keys currently held.
+    held: Mutex<BTreeSet<LeaseKey>>,
+    /// Signalled whenever a run key is released.
+    released: Condvar,
+}
+
+/// Holds one run's write lock until dropped.
+struct RunWriteGuard<'a> {
+    /// Lock set the key is released into.
+    locks: &'a RunLocks,
+    /// Held run key.
+    key: LeaseKey,
+}
+
+impl Drop for RunWriteGuard<'_> {
+    fn drop(&mut self) {
+        // The set is only inserted into or removed from, so a panic cannot leave it inconsistent.
+        let mut held = self.locks.held.lock().unwrap_or_else(PoisonError::into_inner);
+        held.remove(&self.key);
+        drop(held);
+        self.locks.released.notify_all();
+    }
 }
 
 impl<P, D, S, Pol> ControlPlane<P, D, S, Pol>
@@ -216,9 +288,19 @@ where
             store,
             policy,
             config,
+            run_locks: RunLocks::default(),
+            leases: Mutex::new(BTreeMap::new()),
+            clock: Arc::new(SystemClock),
         })
     }
 
+    /// Replaces the clock used to expire run leases.
+    #[must_use]
+    pub fn with_clock(mut self, clock: Arc<dyn Clock>) -> Self {
+        self.clock = clock;
+        self
+    }
+
     /// Starts a new run and optionally issues initial stage packets.
     ///
     /// # Errors
@@ -314,7 +396,9 @@ where
         &self,
         request: &StatusRequest,
     ) -> Result<ScenarioStatus, ControlPlaneError> {
+        let _write = self.write_guard(request.tenant_id, request.namespace_id, &request.run_id);
         let mut state = self.load_run(request.tenant_id, request.namespace_id, &request.run_id)?;
+        let base_version = state.version();
         let status = ScenarioStatus::from_state(&state);
         let call_id = format!("call-{}", state.tool_calls.len() + 1);
         let tool_record = build_tool_call_record(
@@ -327,10 +411,30 @@ where
             request.correlation_id.clone(),
         )?;
         state.tool_calls.push(tool_record);
-        self.store.save(&state)?;
+        self.save_run(&state, base_version)?;
         Ok(status)
     }
 
+    /// Returns the current status for a run without recording a tool call.
+    ///
+    /// Runs that are missing or belong to a different scenario yield `None`;
+    /// the run state is never written.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`ControlPlaneError`] when the run state cannot be loaded.
+    pub fn peek_scenario_status(
+        &self,
+        tenant_id: TenantId,
+        namespace_id: NamespaceId,
+        run_id: &RunId,
+    ) -> Result<Option<ScenarioStatus>, ControlPlaneError> {
+        let state = self.store.load(&tenant_id, &namespace_id, run_id)?;
+        Ok(state
+            .filter(|state| state.scenario_id == self.spec.scenario_id)
+            .map(|state| ScenarioStatus::from_state(&state)))
+    }
+
     /// Processes a pull-mode `scenario.next` request.
     ///
     /// # Errors
@@ -349,7 +453,19 @@ where
             correlation_id: request.correlation_id.clone(),
         };
 
+        let _write = self.write_guard(request.tenant_id, request.namespace_id, &request.run_id);
         let mut state = self.load_run(request.tenant_id, request.namespace_id, &request.run_id)?;
+        let base_version = state.version();
+        if let Err(err) = self.check_lease(&state, request.lease_holder.as_deref()) {
+            return Err(self.reject_call(
+                state,
+                "scenario.next",
+                request,
+                request.time,
+                request.correlation_id.clone(),
+                err,
+            ));
+        }
         if let Err(err) = self.evidence.validate_providers(&self.spec) {
             let tool_error = provider_missing_tool_error(&err);
             let call_id = format!("call-{}", state.tool_calls.len() + 1);
@@ -363,7 +479,7 @@ where
                 request.correlation_id.clone(),
             )?;
             state.tool_calls.push(tool_record);
-            self.store.save(&state)?;
+            self.save_run(&state, base_version)?;
             return Err(ControlPlaneError::ProviderMissing(err));
         }
 
@@ -380,7 +496,7 @@ where
             request.correlation_id.clone(),
         )?;
         state.tool_calls.push(tool_record);
-        self.store.save(&state)?;
+        self.save_run(&state, base_version)?;
 
         Ok(next_result)
     }
@@ -394,7 +510,19 @@ where
         &self,
         request: &SubmitRequest,
     ) -> Result<SubmitResult, ControlPlaneError> {
+        let _write = self.write_guard(request.tenant_id, request.namespace_id, &request.run_id);
         let mut state = self.load_run(request.tenant_id, request.namespace_id, &request.run_id)?;
+        let base_version = state.version();
+        if let Err(err) = self.check_lease(&state, request.lease_holder.as_deref()) {
+            return Err(self.reject_call(
+                state,
+                "scenario.submit",
+                request,
+                request.submitted_at,
+                request.correlation_id.clone(),
+                err,
+            ));
+        }
         if let Some(existing) = state
             .submissions
             .iter()
@@ -417,7 +545,7 @@ where
                     request.correlation_id.clone(),
                 )?;
                 state.tool_calls.push(tool_record);
-                self.store.save(&state)?;
+                self.save_run(&state, base_version)?;
                 return Ok(submit_result);
             }
 
@@ -439,10 +567,16 @@ where
                 request.correlation_id.clone(),
             )?;
             state.tool_calls.push(tool_record);
-            self.store.save(&state)?;
+            self.save_run(&state, base_version)?;
             return Err(ControlPlaneError::SubmissionConflict(request.submission_id.clone()));
         }
 
+        if let Some(expected) = request.expected_version
+            && expected != base_version
+        {
+            return Err(self.reject_stale_submit(state, request, expected));
+        }
+
         let content_hash = payload_hash(&request.payload, self.config.hash_algorithm)?;
         let record = SubmissionRecord {
             submission_id: request.submission_id.clone(),
@@ -468,7 +602,7 @@ where
             request.correlation_id.clone(),
         )?;
         state.tool_calls.push(tool_record);
-        self.store.save(&state)?;
+        self.save_run(&state, base_version)?;
 
         Ok(submit_result)
     }
@@ -479,7 +613,36 @@ where
     ///
     /// Returns [`ControlPlaneError`] when trigger evaluation fails.
     pub fn trigger(&self, trigger: &TriggerEvent) -> Result<TriggerResult, ControlPlaneError> {
+        self.trigger_with_lease(trigger, None)
+    }
+
+    /// Processes an external trigger event on behalf of a lease holder.
+    ///
+    /// While another holder's lease is active the trigger is rejected; a
+    /// `lease_holder` without an active lease is rejected as well.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`ControlPlaneError::LeaseHeld`] or [`ControlPlaneError::LeaseNotHeld`] when the
+    /// lease check fails, and [`ControlPlaneError`] when trigger evaluation fails.
+    pub fn trigger_with_lease(
+        &self,
+        trigger: &TriggerEvent,
+        lease_holder: Option<&str>,
+    ) -> Result<TriggerResult, ControlPlaneError> {
+        let _write = self.write_guard(trigger.tenant_id, trigger.namespace_id, &trigger.run_id);
         let mut state = self.load_run(trigger.tenant_id, trigger.namespace_id, &trigger.run_id)?;
+        let base_version = state.version();
+        if let Err(err) = self.check_lease(&state, lease_holder) {
+            return Err(self.reject_call(
+                state,
+                "scenario.trigger",
+                trigger,
+                trigger.time,
+                trigger.correlation_id.clone(),
+                err,
+            ));
+        }
         if let Err(err) = self.evidence.validate_providers(&self.spec) {
             let tool_error = provider_missing_tool_error(&err);
             let call_id = format!("call-{}", state.tool_calls.len() + 1);
@@ -493,7 +656,7 @@ where
                 trigger.correlation_id.clone(),
             )?;
             state.tool_calls.push(tool_record);
-            self.store.save(&state)?;
+            self.save_run(&state, base_version)?;
             return Err(ControlPlaneError::ProviderMissing(err));
         }
 
@@ -510,10 +673,114 @@ where
             trigger.correlation_id.clone(),
         )?;
         state.tool_calls.push(tool_record);
-        self.store.save(&state)?;
+        self.save_run(&state, base_version)?;
         Ok(trigger_result)
     }
 
+    /// Acquires or renews an advisory lease on a run.
+    ///
+    /// The lease expires `ttl_ms` milliseconds later on the control plane's
+    /// clock; caller timestamps never decide expiry. `expires_at` reports the
+    /// same duration added to `requested_at`. Renewing by the current holder
+    /// extends the expiry; leases are kept in memory and are not persisted with
+    /// the run. Expired leases on every run are dropped before the new lease is
+    /// recorded, so abandoned leases do not accumulate.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`ControlPlaneError::InvalidLease`] for an empty or oversized holder or an
+    /// out-of-range TTL, [`ControlPlaneError::RunNotFound`] when the run does not exist, and
+    /// [`ControlPlaneError::LeaseHeld`] while another holder's lease is active.
+    pub fn acquire_lease(
+        &self,
+        request: &LeaseAcquireRequest,
+    ) -> Result<RunLease, ControlPlaneError> {
+        validate_lease_holder(&request.holder_id)?;
+        if request.ttl_ms == 0 || request.ttl_ms > MAX_RUN_LEASE_TTL_MS {
+            return Err(ControlPlaneError::InvalidLease(format!(
+                "ttl_ms must be between 1 and {MAX_RUN_LEASE_TTL_MS}"
+            )));
+        }
+        let _write = self.write_guard(request.tenant_id, request.namespace_id, &request.run_id);
+        let state = self.load_run(request.tenant_id, request.namespace_id, &request.run_id)?;
+        let expires_at = lease_deadline(request.requested_at, request.ttl_ms)?;
+        let now = self.clock.now();
+        let deadline = now
+            .checked_add(Duration::from_millis(request.ttl_ms))
+            .ok_or_else(|| ControlPlaneError::InvalidLease("lease expiry overflows".to_string()))?;
+        let mut leases = self.lease_table();
+        leases.retain(|_, entry| entry.active(now).is_some());
+        let key = lease_key(&state);
+        let acquired_at = match leases.get(&key).and_then(|entry| entry.active(now)) {
+            Some(lease) => {
+                if lease.holder_id != request.holder_id {
+                    return Err(ControlPlaneError::LeaseHeld {
+                        expires_at: lease.expires_at,
+                    });
+                }
+                lease.acquired_at
+            }
+            None => request.requested_at,
+        };
+        let lease = RunLease {
+            run_id: request.run_id.clone(),
+            holder_id: request.holder_id.clone(),
+            acquired_at,
+            expires_at,
+        };
+        leases.insert(
+            key,
+            LeaseEntry {
+                lease: lease.clone(),
+                deadline,
+            },
+        );
+        drop(leases);
+        Ok(lease)
+    }
+
+    /// Releases a run lease held by `holder_id`.
+    ///
+    /// Releasing a run without a lease, or whose lease already expired, is not
+    /// an error; `released` reports whether a lease was removed.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`ControlPlaneError::RunNotFound`] when the run does not exist and
+    /// [`ControlPlaneError::LeaseHeld`] while another holder's lease is active.
+    pub fn release_lease(
+        &self,
+        request: &LeaseReleaseRequest,
+    ) -> Result<LeaseReleaseResult, ControlPlaneError> {
+        let _write = self.write_guard(request.tenant_id, request.namespace_id, &request.run_id);
+        let state = self.load_run(request.tenant_id, request.namespace_id, &request.run_id)?;
+        let now = self.clock.now();
+        let mut leases = self.lease_table();
+        let key = lease_key(&state);
+        let released = match leases.get(&key).and_then(|entry| entry.active(now)) {
+            Some(lease) if lease.holder_id != request.holder_id => {
+                return Err(ControlPlaneError::LeaseHeld {
+                    expires_at: lease.expires_at,
+                });
+            }
+            Some(_) => true,
+            None => false,
+        };
+        leases.remove(&key);
+        drop(leases);
+        Ok(LeaseReleaseResult {
+            released,
+        })
+    }
+
+    /// Returns the number of lease entries currently held in memory.
+    ///
+    /// Entries that expired since the last acquisition are still counted.
+    #[must_use]
+    pub fn lease_entry_count(&self) -> usize {
+        self.lease_table().len()
+    }
+
     /// Evaluates a stage using asserted evidence without mutating run state.
     ///
     /// # Errors
@@ -569,24 +836,26 @@ where
         evidence: &BTreeMap<ConditionId, EvidenceResult>,
     ) -> Result<Vec<EvidenceRecord>, ControlPlaneError> {
         let mut evidence_records = Vec::with_capacity(condition_specs.len());
+        let mut budget = self.gate_evaluator().evidence_budget();
         for spec in condition_specs {
-            let mut result =
-                evidence.get(&spec.condition_id).cloned().unwrap_or_else(|| EvidenceResult {
-                    value: None,
-                    lane: TrustLane::Asserted,
-                    error: None,
-                    evidence_hash: None,
-                    evidence_ref: None,
-                    evidence_anchor: None,
-                    signature: None,
-                    content_type: None,
-                });
+            let mut result = evidence.get(&spec.condition_id).cloned().unwrap_or(EvidenceResult {
+                value: None,
+                lane: TrustLane::Asserted,
+                error: None,
+                evidence_hash: None,
+                evidence_ref: None,
+                evidence_anchor: None,
+                signature: None,
+                content_type: None,
+            });
             if result.error.is_some() {
                 result.value = None;
                 result.evidence_hash = None;
                 result.content_type = None;
             }
-            let normalized = normalize_evidence_result(&result, self.config.hash_algorithm)?;
+            let (normalized, evidence_bytes) =
+                normalize_evidence_result(&result, self.config.hash_algorithm)?;
+            budget.charge(evidence_bytes)?;
             let status = if normalized.error.is_some() {
                 TriState::Unknown
             } else {
@@ -609,7 +878,7 @@ where
         condition_requirements: &BTreeMap<ConditionId, TrustRequirement>,
         evidence_records: &[EvidenceRecord],
     ) -> (Vec<GateEvaluation>, Vec<(GateId, TriState)>) {
-        let evaluator = GateEvaluator::new(self.config.logic_mode);
+        let evaluator = self.gate_evaluator();
         let mut gate_evaluations = Vec::new();
         let mut gate_outcomes = Vec::new();
         for gate in &stage_def.gates {
@@ -747,7 +1016,7 @@ where
             let requirement = base_requirement.stricter(requirement);
             condition_requirements.insert(spec.condition_id.clone(), requirement);
         }
-        let evaluator = GateEvaluator::new(self.config.logic_mode);
+        let evaluator = self.gate_evaluator();
         let mut gate_eval_records = Vec::new();
         let mut gate_outcomes = Vec::new();
 
@@ -1011,6 +1280,7 @@ where
         context: &EvidenceContext,
     ) -> Result<Vec<EvidenceRecord>, ControlPlaneError> {
         let mut records = Vec::with_capacity(condition_specs.len());
+        let mut budget = self.gate_evaluator().evidence_budget();
         for spec in condition_specs {
             let (mut result, mut error) = match self.evidence.query(&spec.query, context) {
                 Ok(result) => {
@@ -1040,7 +1310,9 @@ where
                 result.evidence_hash = None;
                 result.content_type = None;
             }
-            let normalized = normalize_evidence_result(&result, self.config.hash_algorithm)?;
+            let (normalized, evidence_bytes) =
+                normalize_evidence_result(&result, self.config.hash_algorithm)?;
+            budget.charge(evidence_bytes)?;
             let status = if error.is_some() {
                 TriState::Unknown
             } else {
@@ -1055,6 +1327,12 @@ where
         Ok(records)
     }
 
+    /// Returns a gate evaluator configured from the control plane settings.
+    const fn gate_evaluator(&self) -> GateEvaluator {
+        GateEvaluator::new(self.config.logic_mode)
+            .with_evidence_budget(self.config.max_evidence_bytes_per_evaluation)
+    }
+
     /// Returns an empty verified evidence result for error paths.
     const fn empty_verified_result() -> EvidenceResult {
         EvidenceResult {
@@ -1165,6 +1443,136 @@ where
         Ok(receipts)
     }
 
+    /// Records a submit rejected for a stale `expected_version` and returns its error.
+    fn reject_stale_submit(
+        &self,
+        state: RunState,
+        request: &SubmitRequest,
+        expected: u64,
+    ) -> ControlPlaneError {
+        let actual = state.version();
+        self.reject_call(
+            state,
+            "scenario.submit",
+            request,
+            request.submitted_at,
+            request.correlation_id.clone(),
+            ControlPlaneError::VersionConflict {
+                expected,
+                actual,
+            },
+        )
+    }
+
+    /// Records a rejected mutating call in the run's tool-call log and returns its error.
+    ///
+    /// When recording fails, the recording error is returned instead.
+    fn reject_call<T: Serialize>(
+        &self,
+        mut state: RunState,
+        method: &str,
+        request: &T,
+        called_at: Timestamp,
+        correlation_id: Option<crate::core::CorrelationId>,
+        err: ControlPlaneError,
+    ) -> ControlPlaneError {
+        let Some(tool_error) = rejection_tool_error(&err) else {
+            return err;
+        };
+        let call_id = format!("call-{}", state.tool_calls.len() + 1);
+        let recorded = build_tool_call_record_error(
+            method,
+            request,
+            &tool_error,
+            called_at,
+            self.config.hash_algorithm,
+            call_id,
+            correlation_id,
+        )
+        .and_then(|tool_record| {
+            state.tool_calls.push(tool_record);
+            self.save_run(&state, state.version())
+        });
+        match recorded {
+            Ok(()) => err,
+            Err(record_err) => record_err,
+        }
+    }
+
+    /// Checks that a mutation is allowed by the run's lease.
+    ///
+    /// Expiry is judged on the control plane's clock. Without an active lease
+    /// any caller may mutate, except one naming a `lease_holder`, whose lease
+    /// has expired or was never acquired. The lease table is never modified
+    /// here, so a rejected call cannot evict anyone's lease.
+    fn check_lease(
+        &self,
+        state: &RunState,
+        lease_holder: Option<&str>,
+    ) -> Result<(), ControlPlaneError> {
+        let now = self.clock.now();
+        let leases = self.lease_table();
+        let lease = leases.get(&lease_key(state)).and_then(|entry| entry.active(now));
+        let result = match (lease, lease_holder) {
+            (Some(lease), Some(holder)) if lease.holder_id == holder => Ok(()),
+            (Some(lease), _) => Err(ControlPlaneError::LeaseHeld {
+                expires_at: lease.expires_at,
+            }),
+            (None, Some(holder)) => Err(ControlPlaneError::LeaseNotHeld(holder.to_string())),
+            (None, None) => Ok(()),
+        };
+        drop(leases);
+        result
+    }
+
+    /// Locks the lease table.
+    fn lease_table(&self) -> MutexGuard<'_, BTreeMap<LeaseKey, LeaseEntry>> {
+        // Entries are replaced whole, so a panic elsewhere cannot leave one half-written.
+        self.leases.lock().unwrap_or_else(PoisonError::into_inner)
+    }
+
+    /// Holds the run's write lock for the rest of a load-modify-save cycle.
+    ///
+    /// Only calls on the same run wait; other runs proceed concurrently.
+    fn write_guard(
+        &self,
+        tenant_id: TenantId,
+        namespace_id: NamespaceId,
+        run_id: &RunId,
+    ) -> RunWriteGuard<'_> {
+        let key = (tenant_id, namespace_id, run_id.to_string());
+        // The set is only inserted into or removed from, so a panic cannot leave it inconsistent.
+        let held = self.run_locks.held.lock().unwrap_or_else(PoisonError::into_inner);
+        let mut held = self
+            .run_locks
+            .released
+            .wait_while(held, |held| held.contains(&key))
+            .unwrap_or_else(PoisonError::into_inner);
+        held.insert(key.clone());
+        drop(held);
+        RunWriteGuard {
+            locks: &self.run_locks,
+            key,
+        }
+    }
+
+    /// Saves run state if the stored run is still at `base_version`.
+    ///
+    /// The write lock only covers this process; the store's compare-and-swap
+    /// rejects a save when another process sharing the store advanced the run.
+    fn save_run(&self, state: &RunState, base_version: u64) -> Result<(), ControlPlaneError> {
+        self.store.save_if_version(state, base_version).map_err(|err| match err {
+            StoreError::VersionConflict {
+                expected,
+                actual,
+            } => ControlPlaneError::VersionConflict {
+                expected,
+                actual,
+            },
+            err => ControlPlaneError::Store(err),
+        })
+    }
+
     /// Loads the run state or returns an error if missing.
     fn load_run(
         &self,
@@ -1220,6 +1628,9 @@ pub struct NextRequest {
     pub time: Timestamp,
     /// Optional correlation identifier.
     pub correlation_id: Option<crate::core::CorrelationId>,
+    /// Lease holder advancing the run; required while the run is leased.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub lease_holder: Option<String>,
 }
 
 /// Request payload for `scenario.submit`.
@@ -1244,6 +1655,51 @@ pub struct SubmitRequest {
     pub submitted_at: Timestamp,
     /// Optional correlation identifier.
     pub correlation_id: Option<crate::core::CorrelationId>,
+    /// Run version the submitter last read; the submit is rejected if the run has advanced.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub expected_version: Option<u64>,
+    /// Lease holder submitting; required while the run is leased.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub lease_holder: Option<String>,
+}
+
+/// Request payload for acquiring or renewing a run lease.
+///
+/// # Invariants
+/// - Identifiers must refer to the same run scope.
+/// - `ttl_ms` is validated by [`ControlPlane::acquire_lease`].
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct LeaseAcquireRequest {
+    /// Run identifier.
+    pub run_id: RunId,
+    /// Tenant identifier.
+    pub tenant_id: TenantId,
+    /// Namespace identifier.
+    pub namespace_id: NamespaceId,
+    /// Caller-chosen lease holder identifier.
+    pub holder_id: String,
+    /// Lease duration in milliseconds of server time.
+    pub ttl_ms: u64,
+    /// Request timestamp; `expires_at` is reported as `ttl_ms` after it.
+    pub requested_at: Timestamp,
+}
+
+/// Request payload for releasing a run lease.
+///
+/// # Invariants
+/// - Identifiers must refer to the same run scope.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct LeaseReleaseRequest {
+    /// Run identifier.
+    pub run_id: RunId,
+    /// Tenant identifier.
+    pub tenant_id: TenantId,
+    /// Namespace identifier.
+    pub namespace_id: NamespaceId,
+    /// Lease holder identifier.
+    pub holder_id: String,
+    /// Request timestamp; expiry is judged on the server clock, not this value.
+    pub requested_at: Timestamp,
 }
 
 /// Request payload for precheck evaluation.
@@ -1307,6 +1763,34 @@ pub struct TriggerResult {
     pub status: RunStatus,
 }
 
+/// Advisory lease on a run.
+///
+/// # Invariants
+/// - `expires_at` uses the same timestamp kind as `acquired_at`.
+/// - Timestamps are reported in the caller's domain; the control plane enforces expiry on its own
+///   clock.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct RunLease {
+    /// Run identifier.
+    pub run_id: RunId,
+    /// Lease holder identifier.
+    pub holder_id: String,
+    /// When the holder first acquired the lease; renewals keep it.
+    pub acquired_at: Timestamp,
+    /// When the lease lapses unless renewed.
+    pub expires_at: Timestamp,
+}
+
+/// Result returned by [`ControlPlane::release_lease`].
+///
+/// # Invariants
+/// - `released` is true only when an active lease held by the caller was removed.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub struct LeaseReleaseResult {
+    /// Whether an active lease was released.
+    pub released: bool,
+}
+
 /// Result returned by precheck evaluation.
 ///
 /// # Invariants
@@ -1364,6 +1848,9 @@ pub struct ScenarioStatus {
     pub issued_packet_ids: Vec<crate::core::PacketId>,
     /// Safe summary for unmet gates, if applicable.
     pub safe_summary: Option<SafeSummary>,
+    /// Run version to pass as `expected_version` on submit.
+    #[serde(default)]
+    pub version: u64,
 }
 
 impl ScenarioStatus {
@@ -1388,6 +1875,7 @@ impl ScenarioStatus {
             last_decision,
             issued_packet_ids,
             safe_summary,
+            version: state.version(),
         }
     }
 }
@@ -1429,6 +1917,26 @@ pub enum ControlPlaneError {
     /// Submission id conflicts with existing record.
     #[error("submission_id conflict: {0}")]
     SubmissionConflict(String),
+    /// Run advanced past the version the caller expected.
+    #[error("run version conflict: expected {expected}, current {actual}")]
+    VersionConflict {
+        /// Version supplied by the caller.
+        expected: u64,
+        /// Current run version.
+        actual: u64,
+    },
+    /// Another holder's lease on the run is active.
+    #[error("run lease held by another holder until {expires_at:?}")]
+    LeaseHeld {
+        /// When the active lease lapses unless renewed.
+        expires_at: Timestamp,
+    },
+    /// Caller named a lease holder that holds no active lease on the run.
+    #[error("run lease not held: {0}")]
+    LeaseNotHeld(String),
+    /// Lease request failed validation.
+    #[error("invalid lease request: {0}")]
+    InvalidLease(String),
     /// Run state sequence counters exceeded addressable range.
     #[error("run state sequence overflow")]
     SequenceOverflow,
@@ -1440,6 +1948,9 @@ pub enum ControlPlaneError {
         /// Actual payload size in bytes.
         actual: usize,
     },
+    /// Total evidence for one evaluation exceeds the configured budget.
+    #[error(transparent)]
+    EvidenceBudgetExceeded(#[from] EvidenceBudgetExceeded),
     /// Packet payload exceeds size limits.
     #[error("payload exceeds size limit ({actual} > {max})")]
     PayloadTooLarge {
@@ -1701,6 +2212,68 @@ fn timeout_expired(
     }
 }
 
+/// Returns the lease table key for a run.
+fn lease_key(state: &RunState) -> LeaseKey {
+    (state.tenant_id, state.namespace_id, state.run_id.to_string())
+}
+
+/// Returns the expiry of a lease acquired at `start` for `ttl_ms`.
+fn lease_deadline(start: Timestamp, ttl_ms: u64) -> Result<Timestamp, ControlPlaneError> {
+    let overflow = || ControlPlaneError::InvalidLease("lease expiry overflows".to_string());
+    match start {
+        Timestamp::UnixMillis(start) => {
+            let ttl = i64::try_from(ttl_ms).map_err(|_| overflow())?;
+            start.checked_add(ttl).map(Timestamp::UnixMillis).ok_or_else(overflow)
+        }
+        Timestamp::Logical(start) => {
+            start.checked_add(ttl_ms).map(Timestamp::Logical).ok_or_else(overflow)
+        }
+    }
+}
+
+/// Validates a lease holder identifier.
+fn validate_lease_holder(holder_id: &str) -> Result<(), ControlPlaneError> {
+    if holder_id.is_empty() {
+        return Err(ControlPlaneError::InvalidLease("holder_id must not be empty".to_string()));
+    }
+    if holder_id.len() > MAX_LEASE_HOLDER_BYTES {
+        return Err(ControlPlaneError::InvalidLease(format!(
+            "holder_id exceeds {MAX_LEASE_HOLDER_BYTES} bytes"
+        )));
+    }
+    Ok(())
+}
+
+/// Returns the tool-call error recorded for a rejected mutation, if it is recorded.
+fn rejection_tool_error(err: &ControlPlaneError) -> Option<ToolCallError> {
+    let (code, message, info) = match err {
+        ControlPlaneError::VersionConflict {
+            expected,
+            actual,
+        } => (
+            "version_conflict",
+            "run version advanced since it was read",
+            format!("expected_version={expected} current_version={actual}"),
+        ),
+        ControlPlaneError::LeaseHeld {
+            ..
+        } => ("lease_held", "run is leased by another holder", String::new()),
+        ControlPlaneError::LeaseNotHeld(holder) => (
+            "lease_not_held",
+            "lease holder has no active lease on the run",
+            format!("lease_holder={holder}"),
+        ),
+        _ => return None,
+    };
+    Some(ToolCallError {
+        code: code.to_string(),
+        message: message.to_string(),
+        details: (!info.is_empty()).then_some(ToolCallErrorDetails::Message {
+            info,
+        }),
+    })
+}
+
 /// Resolves the next stage based on gate outcomes.
 fn resolve_next_stage(
     spec: &ScenarioSpec,
@@ -1865,16 +2438,23 @@ fn payload_hash(
 }
 
 /// Normalizes evidence results by computing payload hashes.
+///
+/// Returns the normalized result and the evidence byte size charged against the
+/// evaluation budget (canonical JSON length or raw byte length).
 fn normalize_evidence_result(
     result: &EvidenceResult,
     algorithm: HashAlgorithm,
-) -> Result<EvidenceResult, ControlPlaneError> {
+) -> Result<(EvidenceResult, usize), ControlPlaneError> {
     let mut normalized = result.clone();
+    let mut evidence_bytes = 0;
     if let Some(value) = &result.value {
         let hash = match value {
             EvidenceValue::Json(json) => {
-                match hash_canonical_json_with_limit(algorithm, json, MAX_EVIDENCE_VALUE_BYTES) {
-                    Ok(hash) => hash,
+                match canonical_json_bytes_with_limit(json, MAX_EVIDENCE_VALUE_BYTES) {
+                    Ok(bytes) => {
+                        evidence_bytes = bytes.len();
+                        hash_bytes(algorithm, &bytes)
+                    }
                     Err(HashError::SizeLimitExceeded {
                         limit,
                         actual,
@@ -1894,12 +2474,13 @@ fn normalize_evidence_result(
                         actual: bytes.len(),
                     });
                 }
+                evidence_bytes = bytes.len();
                 hash_bytes(algorithm, bytes)
             }
         };
         normalized.evidence_hash = Some(hash);
     }
-    Ok(normalized)
+    Ok((normalized, evidence_bytes))
 }
 
 /// Builds a safe summary for unmet gates.
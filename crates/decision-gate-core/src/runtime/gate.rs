@@ -3,12 +3,14 @@
 // Module: Decision Gate Gate Evaluation
 // Description: Gate evaluation helpers and trace collection.
 // Purpose: Evaluate requirement trees against evidence snapshots deterministically.
-// Dependencies: crate::core, ret-logic
+// Dependencies: crate::core, ret-logic, thiserror
 // ============================================================================
 
 //! ## Overview
 //! Gate evaluation bridges the requirement algebra with evidence snapshots to
-//! produce deterministic tri-state outcomes and trace logs.
+//! produce deterministic tri-state outcomes and trace logs. Each evaluation
+//! also carries an evidence byte budget that bounds the total provider
+//! evidence held while conditions are resolved.
 
 // ============================================================================
 // SECTION: Imports
@@ -19,6 +21,7 @@ use ret_logic::Requirement;
 use ret_logic::RequirementTrace;
 use ret_logic::TriState;
 use ret_logic::TriStateConditionEval;
+use thiserror::Error;
 
 use crate::core::ConditionId;
 use crate::core::GateEvaluation;
@@ -34,26 +37,52 @@ use crate::core::state::EvidenceRecord;
 ///
 /// # Invariants
 /// - Evaluation uses the configured [`LogicMode`] deterministically.
+/// - Each [`EvidenceBudget`] handed out starts empty and is capped at `max_evidence_bytes`.
 pub struct GateEvaluator {
     /// Logic mode used for tri-state evaluation.
     logic: LogicMode,
+    /// Maximum total evidence bytes accepted per evaluation.
+    max_evidence_bytes: usize,
 }
 
 impl GateEvaluator {
-    /// Creates a new gate evaluator with the provided logic mode.
+    /// Creates a new gate evaluator with the provided logic mode and no evidence budget cap.
     #[must_use]
     pub const fn new(logic: LogicMode) -> Self {
         Self {
             logic,
+            max_evidence_bytes: usize::MAX,
         }
     }
 
+    /// Caps the total evidence bytes accepted per evaluation.
+    #[must_use]
+    pub const fn with_evidence_budget(mut self, max_bytes: usize) -> Self {
+        self.max_evidence_bytes = max_bytes;
+        self
+    }
+
     /// Returns the active logic mode.
     #[must_use]
     pub const fn logic(&self) -> LogicMode {
         self.logic
     }
 
+    /// Returns the configured per-evaluation evidence byte cap.
+    #[must_use]
+    pub const fn max_evidence_bytes(&self) -> usize {
+        self.max_evidence_bytes
+    }
+
+    /// Starts a fresh evidence budget for one evaluation.
+    #[must_use]
+    pub const fn evidence_budget(&self) -> EvidenceBudget {
+        EvidenceBudget {
+            limit: self.max_evidence_bytes,
+            used: 0,
+        }
+    }
+
     /// Evaluates a gate against a precomputed evidence snapshot.
     #[must_use]
     pub fn evaluate_gate(&self, gate: &GateSpec, snapshot: &EvidenceSnapshot) -> GateEvaluation {
@@ -71,6 +100,67 @@ impl GateEvaluator {
     }
 }
 
+// ============================================================================
+// SECTION: Evidence Budget
+// ============================================================================
+
+/// Running total of evidence bytes accepted during one evaluation.
+///
+/// # Invariants
+/// - `used <= limit` after every successful charge.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct EvidenceBudget {
+    /// Maximum total bytes accepted.
+    limit: usize,
+    /// Bytes accepted so far.
+    used: usize,
+}
+
+impl EvidenceBudget {
+    /// Records `bytes` of provider evidence against the budget.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`EvidenceBudgetExceeded`] when the running total would exceed the limit; the
+    /// budget is left unchanged so callers can fail closed.
+    pub const fn charge(&mut self, bytes: usize) -> Result<(), EvidenceBudgetExceeded> {
+        let attempted = self.used.saturating_add(bytes);
+        if attempted > self.limit {
+            return Err(EvidenceBudgetExceeded {
+                limit: self.limit,
+                attempted,
+            });
+        }
+        self.used = attempted;
+        Ok(())
+    }
+
+    /// Returns the bytes accepted so far.
+    #[must_use]
+    pub const fn used(&self) -> usize {
+        self.used
+    }
+
+    /// Returns the budget limit in bytes.
+    #[must_use]
+    pub const fn limit(&self) -> usize {
+        self.limit
+    }
+}
+
+/// Error returned when an evaluation exceeds its evidence byte budget.
+///
+/// # Invariants
+/// - `attempted > limit`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Error)]
+#[error("evidence budget exceeded ({attempted} > {limit} bytes)")]
+pub struct EvidenceBudgetExceeded {
+    /// Configured budget in bytes.
+    pub limit: usize,
+    /// Running total that would have been reached.
+    pub attempted: usize,
+}
+
 // ============================================================================
 // SECTION: Evidence Snapshot
 // ============================================================================
@@ -168,6 +258,13 @@ fn collect_conditions_inner(requirement: &Requirement<ConditionId>, out: &mut Ve
             }
         }
         Requirement::Not(inner) => collect_conditions_inner(inner, out),
+        Requirement::Implies {
+            antecedent,
+            consequent,
+        } => {
+            collect_conditions_inner(antecedent, out);
+            collect_conditions_inner(consequent, out);
+        }
         Requirement::And(reqs) | Requirement::Or(reqs) => {
             for req in reqs {
                 collect_conditions_inner(req, out);
@@ -175,6 +272,12 @@ fn collect_conditions_inner(requirement: &Requirement<ConditionId>, out: &mut Ve
         }
         Requirement::RequireGroup {
             reqs, ..
+        }
+        | Requirement::RequireExactly {
+            reqs, ..
+        }
+        | Requirement::RequireAtMost {
+            reqs, ..
         } => {
             for req in reqs {
                 collect_conditions_inner(req, out);
@@ -15,6 +15,7 @@
 // SECTION: Submodules
 // ============================================================================
 
+pub mod clock;
 pub mod comparator;
 pub mod engine;
 pub mod gate;
@@ -25,27 +26,45 @@ pub mod store;
 // SECTION: Re-Exports
 // ============================================================================
 
+pub use clock::Clock;
+pub use clock::ManualClock;
+pub use clock::SystemClock;
 pub use engine::ConditionEvalOrder;
 pub use engine::ControlPlane;
 pub use engine::ControlPlaneConfig;
 pub use engine::ControlPlaneError;
+pub use engine::DEFAULT_MAX_EVIDENCE_BYTES_PER_EVALUATION;
 pub use engine::EvaluationResult;
+pub use engine::LeaseAcquireRequest;
+pub use engine::LeaseReleaseRequest;
+pub use engine::LeaseReleaseResult;
 pub use engine::MAX_EVIDENCE_VALUE_BYTES;
+pub use engine::MAX_LEASE_HOLDER_BYTES;
 pub use engine::MAX_PAYLOAD_BYTES;
+pub use engine::MAX_RUN_LEASE_TTL_MS;
 pub use engine::NextRequest;
 pub use engine::NextResult;
 pub use engine::PrecheckRequest;
 pub use engine::PrecheckResult;
+pub use engine::RunLease;
 pub use engine::ScenarioStatus;
 pub use engine::StatusRequest;
 pub use engine::SubmitRequest;
 pub use engine::SubmitResult;
 pub use engine::TriggerResult;
+pub use gate::EvidenceBudget;
+pub use gate::EvidenceBudgetExceeded;
 pub use gate::GateEvaluator;
+pub use runpack::COMPRESSED_RUNPACK_MANIFEST_VERSION;
+pub use runpack::DecodingArtifactReader;
 pub use runpack::MAX_RUNPACK_ARTIFACT_BYTES;
+pub use runpack::RUNPACK_SIGNATURE_SCHEME_ED25519;
 pub use runpack::RunpackBuilder;
 pub use runpack::RunpackError;
+pub use runpack::RunpackSigner;
 pub use runpack::RunpackVerifier;
+pub use runpack::VerificationAttestation;
+pub use runpack::VerificationClaims;
 pub use runpack::VerificationReport;
 pub use runpack::VerificationStatus;
 pub use store::InMemoryDataShapeRegistry;
@@ -3,7 +3,7 @@
 // Module: Decision Gate Runpack Builder and Verifier
 // Description: Deterministic runpack generation and offline verification.
 // Purpose: Export and validate Decision Gate run artifacts with canonical hashing.
-// Dependencies: crate::{core, interfaces}, serde
+// Dependencies: crate::{core, interfaces}, ed25519-dalek, serde
 // ============================================================================
 
 //! ## Overview
@@ -11,15 +11,36 @@
 //! deterministic artifact bundle. The verifier replays integrity checks and
 //! enforces fail-closed behavior for missing or tampered artifacts.
 //!
+//! Artifacts may be gzip-compressed on export. Each manifest record names its
+//! encoding, hashes always cover the decoded bytes, and compressed exports
+//! declare manifest version `v2` so verifiers without decoding support reject
+//! them as unsupported instead of hashing compressed bytes.
+//!
+//! Manifests may carry an ed25519 signature over their canonical JSON form.
+//! A verifier configured with a public key requires that signature, so the
+//! artifact set is bound to the signer rather than only to its own hashes.
+//! Verification reports can in turn be attested: the verifier signs the report
+//! hash, manifest hash, status, and time, and auditors may countersign.
+//!
 //! Security posture: runpack verification treats artifacts as untrusted; see
 //! `Docs/security/threat_model.md`.
 
+// ============================================================================
+// SECTION: Submodules
+// ============================================================================
+
+mod gzip;
+
 // ============================================================================
 // SECTION: Imports
 // ============================================================================
 
 use std::collections::BTreeMap;
 
+use ed25519_dalek::Signature;
+use ed25519_dalek::Signer;
+use ed25519_dalek::SigningKey;
+use ed25519_dalek::VerifyingKey;
 use serde::Deserialize;
 use serde::Serialize;
 use serde_json::Value;
@@ -36,16 +57,20 @@ use crate::core::ProviderId;
 use crate::core::RunState;
 use crate::core::ScenarioSpec;
 use crate::core::Timestamp;
+use crate::core::audit::evaluation_audit_records;
 use crate::core::hashing::DEFAULT_HASH_ALGORITHM;
 use crate::core::hashing::HashAlgorithm;
+use crate::core::hashing::HashDigest;
 use crate::core::hashing::hash_bytes;
 use crate::core::hashing::hash_canonical_json;
 use crate::core::runpack::ArtifactKind;
 use crate::core::runpack::ArtifactRecord;
+use crate::core::runpack::Compression;
 use crate::core::runpack::FileHashEntry;
 use crate::core::runpack::RunpackIntegrity;
 use crate::core::runpack::RunpackManifest;
 use crate::core::runpack::RunpackSecurityContext;
+use crate::core::runpack::RunpackSignature;
 use crate::core::runpack::RunpackVersion;
 use crate::core::runpack::VerifierMode;
 use crate::interfaces::Artifact;
@@ -71,12 +96,20 @@ const PACKET_LOG_PATH: &str = "artifacts/packets.json";
 const SUBMISSION_LOG_PATH: &str = "artifacts/submissions.json";
 /// Runpack path for tool call logs.
 const TOOL_LOG_PATH: &str = "artifacts/tool_calls.json";
+/// Runpack path for evaluation audit records.
+const EVALUATION_AUDIT_PATH: &str = "artifacts/evaluation_audit.json";
 /// Runpack path for verifier reports.
 const VERIFIER_REPORT_PATH: &str = "artifacts/verifier_report.json";
 /// Maximum artifact size accepted by the runpack verifier.
 pub const MAX_RUNPACK_ARTIFACT_BYTES: usize = 16 * 1024 * 1024;
 /// Supported runpack manifest versions for offline verification.
-const SUPPORTED_RUNPACK_MANIFEST_VERSIONS: [&str; 1] = ["v1"];
+const SUPPORTED_RUNPACK_MANIFEST_VERSIONS: [&str; 2] = ["v1", COMPRESSED_RUNPACK_MANIFEST_VERSION];
+/// Manifest version declared by runpacks with compressed artifacts.
+pub const COMPRESSED_RUNPACK_MANIFEST_VERSION: &str = "v2";
+/// Content type recorded for JSON artifacts.
+const JSON_CONTENT_TYPE: &str = "application/json";
+/// Signature scheme identifier for ed25519 manifest signatures.
+pub const RUNPACK_SIGNATURE_SCHEME_ED25519: &str = "ed25519";
 
 // ============================================================================
 // SECTION: Builder
@@ -98,6 +131,24 @@ pub struct RunpackBuilder {
     pub anchor_policy: EvidenceAnchorPolicy,
     /// Optional security context metadata.
     pub security_context: Option<RunpackSecurityContext>,
+    /// Optional manifest signing key.
+    pub signer: Option<RunpackSigner>,
+    /// Include evaluation audit records as an artifact.
+    pub include_evaluation_audit: bool,
+    /// Compression applied to artifact bytes on export.
+    pub compression: Compression,
+}
+
+/// Ed25519 key used to sign runpack manifests.
+///
+/// # Invariants
+/// - `key_id` is recorded verbatim in the manifest signature block.
+#[derive(Debug, Clone)]
+pub struct RunpackSigner {
+    /// Identifier recorded alongside the signature.
+    pub key_id: String,
+    /// Ed25519 signing key.
+    pub key: SigningKey,
 }
 
 impl Default for RunpackBuilder {
@@ -108,6 +159,9 @@ impl Default for RunpackBuilder {
             verifier_mode: VerifierMode::OfflineStrict,
             anchor_policy: EvidenceAnchorPolicy::default(),
             security_context: None,
+            signer: None,
+            include_evaluation_audit: false,
+            compression: Compression::None,
         }
     }
 }
@@ -129,6 +183,65 @@ impl RunpackBuilder {
         self
     }
 
+    /// Signs exported manifests with the provided ed25519 key.
+    #[must_use]
+    pub fn with_signing_key(mut self, key_id: impl Into<String>, key: SigningKey) -> Self {
+        self.signer = Some(RunpackSigner {
+            key_id: key_id.into(),
+            key,
+        });
+        self
+    }
+
+    /// Adds evaluation audit records for every decision and submission.
+    #[must_use]
+    pub const fn with_evaluation_audit(mut self) -> Self {
+        self.include_evaluation_audit = true;
+        self
+    }
+
+    /// Compresses exported artifacts with the provided encoding.
+    ///
+    /// Hashes still cover the uncompressed bytes. Compressed exports declare
+    /// manifest version [`COMPRESSED_RUNPACK_MANIFEST_VERSION`].
+    #[must_use]
+    pub const fn with_compression(mut self, compression: Compression) -> Self {
+        self.compression = compression;
+        self
+    }
+
+    /// Returns the manifest version recorded for this export.
+    fn export_manifest_version(&self) -> RunpackVersion {
+        match self.compression {
+            Compression::None => self.manifest_version.clone(),
+            Compression::Gzip => RunpackVersion(COMPRESSED_RUNPACK_MANIFEST_VERSION.to_string()),
+        }
+    }
+
+    /// Hashes the spec with the export algorithm after checking it matches the run.
+    ///
+    /// The run recorded its spec hash with its own algorithm, which may differ
+    /// from the algorithm chosen for this export.
+    fn checked_spec_hash(
+        &self,
+        spec: &ScenarioSpec,
+        state: &RunState,
+    ) -> Result<HashDigest, RunpackError> {
+        let spec_hash = spec
+            .canonical_hash_with(self.hash_algorithm)
+            .map_err(|err| RunpackError::Hash(err.to_string()))?;
+        let run_spec_hash = if state.spec_hash.algorithm == self.hash_algorithm {
+            spec_hash.clone()
+        } else {
+            spec.canonical_hash_with(state.spec_hash.algorithm)
+                .map_err(|err| RunpackError::Hash(err.to_string()))?
+        };
+        if run_spec_hash != state.spec_hash {
+            return Err(RunpackError::Hash("run state spec hash mismatch".to_string()));
+        }
+        Ok(spec_hash)
+    }
+
     /// Builds a runpack and writes artifacts to the provided sink.
     ///
     /// # Errors
@@ -141,79 +254,70 @@ impl RunpackBuilder {
         state: &RunState,
         generated_at: Timestamp,
     ) -> Result<RunpackManifest, RunpackError> {
-        let spec_hash = spec
-            .canonical_hash_with(self.hash_algorithm)
-            .map_err(|err| RunpackError::Hash(err.to_string()))?;
-        if spec_hash != state.spec_hash {
-            return Err(RunpackError::Hash("run state spec hash mismatch".to_string()));
-        }
+        let spec_hash = self.checked_spec_hash(spec, state)?;
 
         let mut artifacts = Vec::new();
         let mut file_hashes = Vec::new();
 
-        write_json_artifact(
+        self.write_json_artifact(
             sink,
             spec,
             SCENARIO_SPEC_PATH,
             ArtifactKind::ScenarioSpec,
             &mut artifacts,
             &mut file_hashes,
-            self.hash_algorithm,
         )?;
-        write_json_artifact(
+        self.write_json_artifact(
             sink,
             &state.triggers,
             TRIGGER_LOG_PATH,
             ArtifactKind::TriggerLog,
             &mut artifacts,
             &mut file_hashes,
-            self.hash_algorithm,
         )?;
-        write_json_artifact(
+        self.write_json_artifact(
             sink,
             &state.gate_evals,
             GATE_EVAL_LOG_PATH,
             ArtifactKind::GateEvalLog,
             &mut artifacts,
             &mut file_hashes,
-            self.hash_algorithm,
         )?;
-        write_json_artifact(
+        self.write_json_artifact(
             sink,
             &state.decisions,
             DECISION_LOG_PATH,
             ArtifactKind::DecisionLog,
             &mut artifacts,
             &mut file_hashes,
-            self.hash_algorithm,
         )?;
-        write_json_artifact(
+        self.write_json_artifact(
             sink,
             &state.packets,
             PACKET_LOG_PATH,
             ArtifactKind::PacketLog,
             &mut artifacts,
             &mut file_hashes,
-            self.hash_algorithm,
         )?;
-        write_json_artifact(
+        self.write_json_artifact(
             sink,
             &state.submissions,
             SUBMISSION_LOG_PATH,
             ArtifactKind::SubmissionLog,
             &mut artifacts,
             &mut file_hashes,
-            self.hash_algorithm,
         )?;
-        write_json_artifact(
+        self.write_json_artifact(
             sink,
             &state.tool_calls,
             TOOL_LOG_PATH,
             ArtifactKind::ToolTranscript,
             &mut artifacts,
             &mut file_hashes,
-            self.hash_algorithm,
         )?;
+        if self.include_evaluation_audit {
+            self.write_evaluation_audit(sink, state, &mut artifacts, &mut file_hashes)?;
+        }
 
         let integrity = build_integrity(&file_hashes, self.hash_algorithm)?;
 
@@ -223,8 +327,8 @@ impl RunpackBuilder {
             Some(self.anchor_policy.clone())
         };
 
-        let manifest = RunpackManifest {
-            manifest_version: self.manifest_version.clone(),
+        let mut manifest = RunpackManifest {
+            manifest_version: self.export_manifest_version(),
             generated_at,
             scenario_id: spec.scenario_id.clone(),
             tenant_id: state.tenant_id,
@@ -237,12 +341,88 @@ impl RunpackBuilder {
             security: self.security_context.clone(),
             integrity,
             artifacts,
+            signature: None,
         };
+        self.sign_manifest(&mut manifest)?;
 
         sink.finalize(&manifest)?;
         Ok(manifest)
     }
 
+    /// Writes evaluation audit records derived from run state.
+    fn write_evaluation_audit<S: ArtifactSink>(
+        &self,
+        sink: &mut S,
+        state: &RunState,
+        artifacts: &mut Vec<ArtifactRecord>,
+        file_hashes: &mut Vec<FileHashEntry>,
+    ) -> Result<(), RunpackError> {
+        let records = evaluation_audit_records(state, self.hash_algorithm)
+            .map_err(|err| RunpackError::Hash(err.to_string()))?;
+        self.write_json_artifact(
+            sink,
+            &records,
+            EVALUATION_AUDIT_PATH,
+            ArtifactKind::EvaluationAudit,
+            artifacts,
+            file_hashes,
+        )
+    }
+
+    /// Writes a JSON artifact into the runpack and updates hashes.
+    fn write_json_artifact<S: ArtifactSink, T: Serialize>(
+        &self,
+        sink: &mut S,
+        value: &T,
+        path: &str,
+        kind: ArtifactKind,
+        artifacts: &mut Vec<ArtifactRecord>,
+        file_hashes: &mut Vec<FileHashEntry>,
+    ) -> Result<(), RunpackError> {
+        let bytes = canonical_bytes(value)?;
+        let record = self.write_artifact(sink, bytes, path, kind)?;
+        file_hashes.push(FileHashEntry {
+            path: path.to_string(),
+            hash: record.hash.clone(),
+        });
+        artifacts.push(record);
+        Ok(())
+    }
+
+    /// Hashes, encodes, and writes artifact bytes, returning the manifest record.
+    fn write_artifact<S: ArtifactSink>(
+        &self,
+        sink: &mut S,
+        bytes: Vec<u8>,
+        path: &str,
+        kind: ArtifactKind,
+    ) -> Result<ArtifactRecord, RunpackError> {
+        let hash = hash_bytes(self.hash_algorithm, &bytes);
+        let bytes = match self.compression {
+            Compression::None => bytes,
+            Compression::Gzip => {
+                gzip::compress(&bytes).map_err(|err| ArtifactError::Sink(err.to_string()))?
+            }
+        };
+        let artifact = Artifact {
+            kind,
+            path: path.to_string(),
+            content_type: Some(JSON_CONTENT_TYPE.to_string()),
+            bytes,
+            required: true,
+        };
+        sink.write(&artifact)?;
+        Ok(ArtifactRecord {
+            artifact_id: path.to_string(),
+            kind,
+            path: path.to_string(),
+            content_type: Some(JSON_CONTENT_TYPE.to_string()),
+            hash,
+            required: true,
+            content_encoding: self.compression,
+        })
+    }
+
     /// Builds a runpack and includes an offline verification report.
     ///
     /// # Errors
@@ -257,38 +437,40 @@ impl RunpackBuilder {
         generated_at: Timestamp,
     ) -> Result<(RunpackManifest, VerificationReport), RunpackError> {
         let mut manifest = self.build(sink, spec, state, generated_at)?;
-        let verifier = RunpackVerifier::new(self.hash_algorithm);
+        let mut verifier = RunpackVerifier::new(self.hash_algorithm);
+        if let Some(signer) = &self.signer {
+            verifier = verifier.with_signature_key(signer.key.verifying_key());
+        }
         let report = verifier.verify_manifest(reader, &manifest)?;
 
-        let report_bytes = serde_jcs::to_vec(&report)
-            .map_err(|err| RunpackError::Serialization(err.to_string()))?;
-        let report_hash = hash_bytes(self.hash_algorithm, &report_bytes);
-        let artifact = Artifact {
-            kind: ArtifactKind::VerifierReport,
-            path: VERIFIER_REPORT_PATH.to_string(),
-            content_type: Some("application/json".to_string()),
-            bytes: report_bytes,
-            required: true,
-        };
-        sink.write(&artifact)?;
-
-        manifest.artifacts.push(ArtifactRecord {
-            artifact_id: VERIFIER_REPORT_PATH.to_string(),
-            kind: ArtifactKind::VerifierReport,
-            path: VERIFIER_REPORT_PATH.to_string(),
-            content_type: Some("application/json".to_string()),
-            hash: report_hash.clone(),
-            required: true,
-        });
+        let report_bytes = canonical_bytes(&report)?;
+        let record = self.write_artifact(
+            sink,
+            report_bytes,
+            VERIFIER_REPORT_PATH,
+            ArtifactKind::VerifierReport,
+        )?;
         manifest.integrity.file_hashes.push(FileHashEntry {
             path: VERIFIER_REPORT_PATH.to_string(),
-            hash: report_hash,
+            hash: record.hash.clone(),
         });
+        manifest.artifacts.push(record);
         manifest.integrity = build_integrity(&manifest.integrity.file_hashes, self.hash_algorithm)?;
+        self.sign_manifest(&mut manifest)?;
         sink.finalize(&manifest)?;
 
         Ok((manifest, report))
     }
+
+    /// Replaces the manifest signature when a signer is configured.
+    fn sign_manifest(&self, manifest: &mut RunpackManifest) -> Result<(), RunpackError> {
+        let Some(signer) = &self.signer else {
+            return Ok(());
+        };
+        let payload = manifest_signing_payload(manifest)?;
+        manifest.signature = Some(sign_payload(signer, &payload));
+        Ok(())
+    }
 }
 
 // ============================================================================
@@ -300,21 +482,45 @@ impl RunpackBuilder {
 /// # Invariants
 /// - Verification treats all artifacts as untrusted inputs.
 pub struct RunpackVerifier {
-    /// Hash algorithm used for verification.
-    hash_algorithm: HashAlgorithm,
+    /// Hash algorithm manifests must declare, when pinned.
+    hash_algorithm: Option<HashAlgorithm>,
+    /// Public key required to have signed the manifest, when configured.
+    signature_key: Option<VerifyingKey>,
 }
 
 impl RunpackVerifier {
-    /// Creates a new verifier.
+    /// Creates a verifier that requires manifests to use `hash_algorithm`.
     #[must_use]
     pub const fn new(hash_algorithm: HashAlgorithm) -> Self {
         Self {
-            hash_algorithm,
+            hash_algorithm: Some(hash_algorithm),
+            signature_key: None,
         }
     }
 
+    /// Creates a verifier that hashes with whichever algorithm the manifest records.
+    #[must_use]
+    pub const fn from_manifest() -> Self {
+        Self {
+            hash_algorithm: None,
+            signature_key: None,
+        }
+    }
+
+    /// Requires manifests to carry a valid signature from the provided key.
+    ///
+    /// Without a configured key, embedded manifest signatures are not checked.
+    #[must_use]
+    pub const fn with_signature_key(mut self, key: VerifyingKey) -> Self {
+        self.signature_key = Some(key);
+        self
+    }
+
     /// Verifies a runpack manifest using the provided artifact reader.
     ///
+    /// Artifacts are decoded according to their manifest `content_encoding`
+    /// before hashing, so hashes are checked against the original bytes.
+    ///
     /// # Errors
     ///
     /// Returns [`RunpackError`] when verification fails.
@@ -334,17 +540,24 @@ impl RunpackVerifier {
             });
         }
 
+        let reader = DecodingArtifactReader::new(reader, manifest);
         let mut errors = Vec::new();
         let mut checked = 0usize;
 
-        if manifest.hash_algorithm != self.hash_algorithm {
+        let algorithm = manifest.hash_algorithm;
+        if self.hash_algorithm.is_some_and(|expected| expected != algorithm) {
             errors.push("hash algorithm mismatch".to_string());
         }
+        if let Some(key) = &self.signature_key
+            && let Err(err) = verify_manifest_signature(key, manifest)
+        {
+            errors.push(err);
+        }
 
         for entry in &manifest.integrity.file_hashes {
             match reader.read_with_limit(&entry.path, MAX_RUNPACK_ARTIFACT_BYTES) {
                 Ok(bytes) => {
-                    let actual = hash_bytes(self.hash_algorithm, &bytes);
+                    let actual = hash_bytes(algorithm, &bytes);
                     if actual != entry.hash {
                         errors.push(format!("hash mismatch for {}", entry.path));
                     }
@@ -356,9 +569,7 @@ impl RunpackVerifier {
             }
         }
 
-        if let Ok(root_hash) =
-            hash_canonical_json(self.hash_algorithm, &manifest.integrity.file_hashes)
-        {
+        if let Ok(root_hash) = hash_canonical_json(algorithm, &manifest.integrity.file_hashes) {
             if root_hash != manifest.integrity.root_hash {
                 errors.push("root hash mismatch".to_string());
             }
@@ -366,11 +577,11 @@ impl RunpackVerifier {
             errors.push("failed to compute root hash".to_string());
         }
 
-        if let Err(err) = verify_decisions(reader) {
+        if let Err(err) = verify_decisions(&reader) {
             errors.push(err);
         }
         if let Some(anchor_policy) = &manifest.anchor_policy {
-            match verify_anchor_policy(reader, manifest, anchor_policy) {
+            match verify_anchor_policy(&reader, manifest, anchor_policy) {
                 Ok(anchor_errors) => errors.extend(anchor_errors),
                 Err(err) => errors.push(err),
             }
@@ -387,6 +598,49 @@ impl RunpackVerifier {
     }
 }
 
+// ============================================================================
+// SECTION: Decoding Reader
+// ============================================================================
+
+/// Artifact reader that decodes artifacts according to their manifest records.
+///
+/// # Invariants
+/// - Paths without a manifest record are returned as stored.
+/// - Decoded output is bounded by the caller's `max_bytes` limit.
+pub struct DecodingArtifactReader<'a, R> {
+    /// Reader for stored artifact bytes.
+    inner: &'a R,
+    /// Content encoding for each manifest artifact path.
+    encodings: BTreeMap<&'a str, Compression>,
+}
+
+impl<'a, R: ArtifactReader> DecodingArtifactReader<'a, R> {
+    /// Creates a reader that decodes artifacts listed in `manifest`.
+    #[must_use]
+    pub fn new(inner: &'a R, manifest: &'a RunpackManifest) -> Self {
+        let encodings = manifest
+            .artifacts
+            .iter()
+            .map(|record| (record.path.as_str(), record.content_encoding))
+            .collect();
+        Self {
+            inner,
+            encodings,
+        }
+    }
+}
+
+impl<R: ArtifactReader> ArtifactReader for DecodingArtifactReader<'_, R> {
+    fn read_with_limit(&self, path: &str, max_bytes: usize) -> Result<Vec<u8>, ArtifactError> {
+        let bytes = self.inner.read_with_limit(path, max_bytes)?;
+        match self.encodings.get(path).copied().unwrap_or_default() {
+            Compression::None => Ok(bytes),
+            Compression::Gzip => gzip::decompress(&bytes, max_bytes)
+                .map_err(|err| ArtifactError::Sink(format!("gzip decode failed: {err}"))),
+        }
+    }
+}
+
 // ============================================================================
 // SECTION: Verification Types
 // ============================================================================
@@ -418,6 +672,139 @@ pub struct VerificationReport {
     pub errors: Vec<String>,
 }
 
+impl VerificationReport {
+    /// Produces a signed attestation binding this report to the manifest.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`RunpackError`] when the report or manifest cannot be hashed.
+    pub fn attest(
+        &self,
+        manifest: &RunpackManifest,
+        verified_at: Timestamp,
+        signer: &RunpackSigner,
+    ) -> Result<VerificationAttestation, RunpackError> {
+        let claims = VerificationClaims {
+            status: self.status,
+            checked_files: self.checked_files,
+            manifest_hash: hash_attested(manifest.hash_algorithm, manifest)?,
+            report_hash: hash_attested(manifest.hash_algorithm, self)?,
+            verified_at,
+            verifier_key_id: signer.key_id.clone(),
+        };
+        let payload = canonical_bytes(&claims)?;
+        let signature = sign_payload(signer, &payload);
+        Ok(VerificationAttestation {
+            claims,
+            signature,
+            countersignatures: Vec::new(),
+        })
+    }
+}
+
+/// Claims covered by a verification attestation signature.
+///
+/// # Invariants
+/// - Hashes use the manifest `hash_algorithm` over canonical JSON.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct VerificationClaims {
+    /// Verification status reported by the verifier.
+    pub status: VerificationStatus,
+    /// Count of checked files in the report.
+    pub checked_files: usize,
+    /// Hash of the verified runpack manifest.
+    pub manifest_hash: HashDigest,
+    /// Hash of the verification report.
+    pub report_hash: HashDigest,
+    /// Time the verification was performed.
+    pub verified_at: Timestamp,
+    /// Identifier of the verifier signing key.
+    pub verifier_key_id: String,
+}
+
+/// Signed, countersignable attestation of a runpack verification.
+///
+/// # Invariants
+/// - `signature` covers the canonical JSON of `claims`.
+/// - Each countersignature covers the canonical attestation without `countersignatures`, endorsing
+///   both the claims and the verifier signature.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct VerificationAttestation {
+    /// Attested verification claims.
+    pub claims: VerificationClaims,
+    /// Verifier signature over the claims.
+    pub signature: RunpackSignature,
+    /// Additional signatures endorsing the verifier attestation.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub countersignatures: Vec<RunpackSignature>,
+}
+
+impl VerificationAttestation {
+    /// Appends a countersignature from the provided signer.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`RunpackError`] when the attestation cannot be serialized.
+    pub fn countersign(&mut self, signer: &RunpackSigner) -> Result<(), RunpackError> {
+        let payload = self.countersigning_payload()?;
+        self.countersignatures.push(sign_payload(signer, &payload));
+        Ok(())
+    }
+
+    /// Verifies the attestation against a report, manifest, and verifier key.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`RunpackError::Attestation`] when the claims do not match the
+    /// report or manifest, or the verifier signature is invalid.
+    pub fn verify(
+        &self,
+        report: &VerificationReport,
+        manifest: &RunpackManifest,
+        key: &VerifyingKey,
+    ) -> Result<(), RunpackError> {
+        let claims = &self.claims;
+        if claims.status != report.status || claims.checked_files != report.checked_files {
+            return Err(RunpackError::Attestation("report summary mismatch".to_string()));
+        }
+        if claims.report_hash != hash_attested(claims.report_hash.algorithm, report)? {
+            return Err(RunpackError::Attestation("report hash mismatch".to_string()));
+        }
+        if claims.manifest_hash != hash_attested(claims.manifest_hash.algorithm, manifest)? {
+            return Err(RunpackError::Attestation("manifest hash mismatch".to_string()));
+        }
+        let payload = canonical_bytes(claims)?;
+        verify_payload_signature(key, &self.signature, &payload, "attestation")
+            .map_err(RunpackError::Attestation)
+    }
+
+    /// Verifies that a countersignature from the provided key is present and valid.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`RunpackError::Attestation`] when no countersignature verifies.
+    pub fn verify_countersignature(&self, key: &VerifyingKey) -> Result<(), RunpackError> {
+        let payload = self.countersigning_payload()?;
+        let verified = self.countersignatures.iter().any(|signature| {
+            verify_payload_signature(key, signature, &payload, "countersignature").is_ok()
+        });
+        if verified {
+            Ok(())
+        } else {
+            Err(RunpackError::Attestation("countersignature invalid or missing".to_string()))
+        }
+    }
+
+    /// Returns the canonical bytes covered by countersignatures.
+    fn countersigning_payload(&self) -> Result<Vec<u8>, RunpackError> {
+        let endorsed = Self {
+            countersignatures: Vec::new(),
+            ..self.clone()
+        };
+        canonical_bytes(&endorsed)
+    }
+}
+
 // ============================================================================
 // SECTION: Errors
 // ============================================================================
@@ -437,47 +824,69 @@ pub enum RunpackError {
     /// Serialization errors.
     #[error("serialization error: {0}")]
     Serialization(String),
+    /// Verification attestation errors.
+    #[error("attestation invalid: {0}")]
+    Attestation(String),
 }
 
 // ============================================================================
 // SECTION: Helpers
 // ============================================================================
 
-/// Writes a JSON artifact into the runpack and updates hashes.
-fn write_json_artifact<S: ArtifactSink, T: Serialize>(
-    sink: &mut S,
-    value: &T,
-    path: &str,
-    kind: ArtifactKind,
-    artifacts: &mut Vec<ArtifactRecord>,
-    file_hashes: &mut Vec<FileHashEntry>,
-    algorithm: HashAlgorithm,
-) -> Result<(), RunpackError> {
-    let bytes =
-        serde_jcs::to_vec(value).map_err(|err| RunpackError::Serialization(err.to_string()))?;
-    let hash = hash_bytes(algorithm, &bytes);
-    let artifact = Artifact {
-        kind,
-        path: path.to_string(),
-        content_type: Some("application/json".to_string()),
-        bytes,
-        required: true,
+/// Returns the canonical manifest bytes covered by the manifest signature.
+fn manifest_signing_payload(manifest: &RunpackManifest) -> Result<Vec<u8>, RunpackError> {
+    let unsigned = RunpackManifest {
+        signature: None,
+        ..manifest.clone()
     };
-    sink.write(&artifact)?;
-
-    artifacts.push(ArtifactRecord {
-        artifact_id: path.to_string(),
-        kind,
-        path: path.to_string(),
-        content_type: Some("application/json".to_string()),
-        hash: hash.clone(),
-        required: true,
-    });
-    file_hashes.push(FileHashEntry {
-        path: path.to_string(),
-        hash,
-    });
-    Ok(())
+    canonical_bytes(&unsigned)
+}
+
+/// Verifies the embedded manifest signature against the configured key.
+fn verify_manifest_signature(key: &VerifyingKey, manifest: &RunpackManifest) -> Result<(), String> {
+    let signature =
+        manifest.signature.as_ref().ok_or_else(|| "manifest signature missing".to_string())?;
+    let payload = manifest_signing_payload(manifest)
+        .map_err(|err| format!("manifest signing payload failed: {err}"))?;
+    verify_payload_signature(key, signature, &payload, "manifest")
+}
+
+/// Serializes a value as canonical JSON bytes.
+fn canonical_bytes<T: Serialize>(value: &T) -> Result<Vec<u8>, RunpackError> {
+    serde_jcs::to_vec(value).map_err(|err| RunpackError::Serialization(err.to_string()))
+}
+
+/// Hashes a value bound by a verification attestation.
+fn hash_attested<T: Serialize>(
+    algorithm: HashAlgorithm,
+    value: &T,
+) -> Result<HashDigest, RunpackError> {
+    hash_canonical_json(algorithm, value).map_err(|err| RunpackError::Hash(err.to_string()))
+}
+
+/// Signs a payload with ed25519 and returns the signature block.
+fn sign_payload(signer: &RunpackSigner, payload: &[u8]) -> RunpackSignature {
+    let signature = signer.key.sign(payload);
+    RunpackSignature {
+        scheme: RUNPACK_SIGNATURE_SCHEME_ED25519.to_string(),
+        key_id: signer.key_id.clone(),
+        signature: signature.to_bytes().to_vec(),
+    }
+}
+
+/// Verifies an ed25519 signature block over a payload.
+fn verify_payload_signature(
+    key: &VerifyingKey,
+    signature: &RunpackSignature,
+    payload: &[u8],
+    label: &str,
+) -> Result<(), String> {
+    if signature.scheme != RUNPACK_SIGNATURE_SCHEME_ED25519 {
+        return Err(format!("unsupported {label} signature scheme: {}", signature.scheme));
+    }
+    let signature = Signature::try_from(signature.signature.as_slice())
+        .map_err(|_| format!("{label} signature invalid"))?;
+    key.verify_strict(payload, &signature).map_err(|_| format!("{label} signature invalid"))
 }
 
 /// Builds integrity metadata from file hashes.
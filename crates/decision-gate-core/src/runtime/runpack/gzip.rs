@@ -0,0 +1,109 @@
+// crates/decision-gate-core/src/runtime/runpack/gzip.rs
+// ============================================================================
+// Module: Decision Gate Runpack Gzip
+// Description: Gzip encoder and bounded decoder for runpack artifacts.
+// Purpose: Shrink large runpack artifacts with a standard codec.
+// Dependencies: flate2, thiserror
+// ============================================================================
+
+//! ## Overview
+//! Single-member gzip (RFC 1952) backed by `flate2` with its pure-Rust
+//! backend. The encoder writes a zero timestamp and an "unknown" operating
+//! system byte, so output is deterministic for a given input. The decoder
+//! accepts any standard member, stops once output exceeds the caller's limit,
+//! and rejects trailing bytes after the member.
+//!
+//! Security posture: compressed artifacts are untrusted; decoding is bounded
+//! and fails closed on malformed streams.
+
+// ============================================================================
+// SECTION: Imports
+// ============================================================================
+
+use std::io;
+use std::io::Read;
+use std::io::Write;
+
+use flate2::Compression;
+use flate2::GzBuilder;
+use flate2::bufread::GzDecoder;
+use thiserror::Error;
+
+// ============================================================================
+// SECTION: Errors
+// ============================================================================
+
+/// Gzip encoding and decoding failures.
+#[derive(Debug, Error, PartialEq, Eq)]
+pub(super) enum GzipError {
+    /// Stream is not a well-formed gzip member.
+    #[error("malformed gzip stream: {0}")]
+    Malformed(String),
+    /// Stream ended before the member was complete.
+    #[error("truncated gzip stream")]
+    Truncated,
+    /// Decoded output exceeds the caller's limit.
+    #[error("decoded artifact exceeds {max_bytes} bytes")]
+    TooLarge {
+        /// Maximum decoded size in bytes.
+        max_bytes: usize,
+    },
+    /// Encoder failed to write the member.
+    #[error("gzip encode failed: {0}")]
+    Encode(String),
+}
+
+impl From<io::Error> for GzipError {
+    fn from(err: io::Error) -> Self {
+        if err.kind() == io::ErrorKind::UnexpectedEof {
+            Self::Truncated
+        } else {
+            Self::Malformed(err.to_string())
+        }
+    }
+}
+
+// ============================================================================
+// SECTION: Encoding
+// ============================================================================
+
+/// Compresses `input` into a single gzip member.
+///
+/// # Errors
+///
+/// Returns [`GzipError::Encode`] when the encoder fails.
+pub(super) fn compress(input: &[u8]) -> Result<Vec<u8>, GzipError> {
+    let mut encoder = GzBuilder::new().mtime(0).write(Vec::new(), Compression::default());
+    encoder.write_all(input).map_err(|err| GzipError::Encode(err.to_string()))?;
+    encoder.finish().map_err(|err| GzipError::Encode(err.to_string()))
+}
+
+// ============================================================================
+// SECTION: Decoding
+// ============================================================================
+
+/// Decompresses a single gzip member, failing once output exceeds `max_bytes`.
+///
+/// # Errors
+///
+/// Returns [`GzipError`] when the stream is malformed, truncated, fails its
+/// CRC or length check, carries trailing bytes, or decodes to more than
+/// `max_bytes`.
+pub(super) fn decompress(input: &[u8], max_bytes: usize) -> Result<Vec<u8>, GzipError> {
+    let mut decoder = GzDecoder::new(input);
+    let limit = u64::try_from(max_bytes).unwrap_or(u64::MAX).saturating_add(1);
+    let mut out = Vec::new();
+    (&mut decoder).take(limit).read_to_end(&mut out)?;
+    if out.len() > max_bytes {
+        return Err(GzipError::TooLarge {
+            max_bytes,
+        });
+    }
+    if decoder.header().is_none() {
+        return Err(GzipError::Truncated);
+    }
+    if !decoder.into_inner().is_empty() {
+        return Err(GzipError::Malformed("trailing bytes after gzip member".to_string()));
+    }
+    Ok(out)
+}
@@ -33,6 +33,7 @@ use crate::interfaces::DataShapeRegistry;
 use crate::interfaces::DataShapeRegistryError;
 use crate::interfaces::RunStateStore;
 use crate::interfaces::StoreError;
+use crate::interfaces::check_run_version;
 
 // ============================================================================
 // SECTION: In-Memory Store
@@ -131,6 +132,18 @@ impl RunStateStore for InMemoryRunStateStore {
             .insert(key, state.clone());
         Ok(())
     }
+
+    fn save_if_version(&self, state: &RunState, expected_version: u64) -> Result<(), StoreError> {
+        let key = run_key(state.tenant_id, state.namespace_id, &state.run_id);
+        let mut guard = self
+            .runs
+            .lock()
+            .map_err(|_| StoreError::Store("run state store mutex poisoned".to_string()))?;
+        check_run_version(guard.get(&key), expected_version)?;
+        guard.insert(key, state.clone());
+        drop(guard);
+        Ok(())
+    }
 }
 
 impl DataShapeRegistry for InMemoryDataShapeRegistry {
@@ -289,6 +302,10 @@ impl RunStateStore for SharedRunStateStore {
         self.inner.save(state)
     }
 
+    fn save_if_version(&self, state: &RunState, expected_version: u64) -> Result<(), StoreError> {
+        self.inner.save_if_version(state, expected_version)
+    }
+
     fn readiness(&self) -> Result<(), StoreError> {
         self.inner.readiness()
     }
@@ -71,6 +71,12 @@ pub enum ToolName {
     Precheck,
     /// Search Decision Gate documentation for runtime guidance.
     DecisionGateDocsSearch,
+    /// Fetch read-only status snapshots for several runs in one call.
+    ScenariosStatusBulk,
+    /// Acquire or renew an advisory lease serializing run mutations.
+    ScenarioLeaseAcquire,
+    /// Release an advisory run lease.
+    ScenarioLeaseRelease,
 }
 
 impl ToolName {
@@ -96,6 +102,9 @@ impl ToolName {
             Self::ScenariosList => "scenarios_list",
             Self::Precheck => "precheck",
             Self::DecisionGateDocsSearch => "decision_gate_docs_search",
+            Self::ScenariosStatusBulk => "scenarios_status_bulk",
+            Self::ScenarioLeaseAcquire => "scenario_lease_acquire",
+            Self::ScenarioLeaseRelease => "scenario_lease_release",
         }
     }
 
@@ -121,6 +130,9 @@ impl ToolName {
             Self::ScenariosList,
             Self::Precheck,
             Self::DecisionGateDocsSearch,
+            Self::ScenariosStatusBulk,
+            Self::ScenarioLeaseAcquire,
+            Self::ScenarioLeaseRelease,
         ]
     }
 
@@ -146,6 +158,9 @@ impl ToolName {
             "scenarios_list" => Some(Self::ScenariosList),
             "precheck" => Some(Self::Precheck),
             "decision_gate_docs_search" => Some(Self::DecisionGateDocsSearch),
+            "scenarios_status_bulk" => Some(Self::ScenariosStatusBulk),
+            "scenario_lease_acquire" => Some(Self::ScenarioLeaseAcquire),
+            "scenario_lease_release" => Some(Self::ScenarioLeaseRelease),
             _ => None,
         }
     }
@@ -38,6 +38,7 @@ use decision_gate_core::PolicyDecider;
 use decision_gate_core::PolicyDecision;
 use decision_gate_core::ProviderId;
 use decision_gate_core::RunConfig;
+use decision_gate_core::RunStateStore;
 use decision_gate_core::RunStatus;
 use decision_gate_core::ScenarioId;
 use decision_gate_core::ScenarioSpec;
@@ -215,6 +216,7 @@ fn test_trigger_idempotency() {
         agent_id: "agent-1".to_string(),
         time: Timestamp::Logical(1),
         correlation_id: None,
+        lease_holder: None,
     };
 
     let result_a = engine.scenario_next(&request).unwrap();
@@ -235,6 +237,54 @@ fn test_trigger_idempotency() {
     assert_eq!(status.issued_packet_ids.len(), 1);
 }
 
+/// Tests that peeking run status neither records a tool call nor leaks other scenarios.
+#[test]
+fn peek_scenario_status_is_read_only_and_scenario_scoped() {
+    let store = InMemoryRunStateStore::new();
+    let engine = ControlPlane::new(
+        sample_spec(),
+        TestEvidenceProvider,
+        TestDispatcher,
+        store.clone(),
+        Some(PermitAllPolicy),
+        ControlPlaneConfig::default(),
+    )
+    .unwrap();
+    let tenant_id = TenantId::from_raw(1).expect("nonzero tenantid");
+    let namespace_id = NamespaceId::from_raw(1).expect("nonzero namespaceid");
+    let run_id = decision_gate_core::RunId::new("run-peek");
+    let run_config = RunConfig {
+        tenant_id,
+        namespace_id,
+        run_id: run_id.clone(),
+        scenario_id: ScenarioId::new("scenario"),
+        dispatch_targets: Vec::new(),
+        policy_tags: Vec::new(),
+    };
+    engine.start_run(run_config, Timestamp::Logical(0), false).unwrap();
+    let before = store.load(&tenant_id, &namespace_id, &run_id).unwrap().expect("run state");
+
+    let status = engine.peek_scenario_status(tenant_id, namespace_id, &run_id).unwrap();
+    assert_eq!(status.expect("status").scenario_id, ScenarioId::new("scenario"));
+    let after = store.load(&tenant_id, &namespace_id, &run_id).unwrap().expect("run state");
+    assert_eq!(before, after);
+
+    let mut other_spec = sample_spec();
+    other_spec.scenario_id = ScenarioId::new("other");
+    let other = ControlPlane::new(
+        other_spec,
+        TestEvidenceProvider,
+        TestDispatcher,
+        store,
+        Some(PermitAllPolicy),
+        ControlPlaneConfig::default(),
+    )
+    .unwrap();
+    assert!(other.peek_scenario_status(tenant_id, namespace_id, &run_id).unwrap().is_none());
+    let missing = decision_gate_core::RunId::new("missing");
+    assert!(engine.peek_scenario_status(tenant_id, namespace_id, &missing).unwrap().is_none());
+}
+
 /// Tests scenario next completes after terminal stage evaluation.
 #[test]
 fn scenario_next_completes_after_terminal_stage_evaluation() {
@@ -270,6 +320,7 @@ fn scenario_next_completes_after_terminal_stage_evaluation() {
         agent_id: "agent-1".to_string(),
         time: Timestamp::Logical(1),
         correlation_id: None,
+        lease_holder: None,
     };
     let result_first = engine.scenario_next(&first).unwrap();
     assert_eq!(result_first.status, RunStatus::Active);
@@ -282,6 +333,7 @@ fn scenario_next_completes_after_terminal_stage_evaluation() {
         agent_id: "agent-1".to_string(),
         time: Timestamp::Logical(2),
         correlation_id: None,
+        lease_holder: None,
     };
     let result_second = engine.scenario_next(&second).unwrap();
     assert_eq!(result_second.status, RunStatus::Completed);
@@ -0,0 +1,370 @@
+// crates/decision-gate-core/tests/evaluation_audit.rs
+// ============================================================================
+// Module: Evaluation Audit Tests
+// Description: Ensures evaluation audit records bind canonical input/output hashes.
+// ============================================================================
+//! ## Overview
+//! Validates that evaluation audit records for decisions and submissions carry
+//! hashes matching independently computed canonical hashes, and that runpacks
+//! can include them as a verifiable artifact.
+
+#![allow(
+    clippy::panic,
+    clippy::print_stdout,
+    clippy::print_stderr,
+    clippy::unwrap_used,
+    clippy::expect_used,
+    clippy::use_debug,
+    clippy::dbg_macro,
+    clippy::panic_in_result_fn,
+    clippy::unwrap_in_result,
+    reason = "Test-only output and panic-based assertions are permitted."
+)]
+
+use std::collections::BTreeMap;
+
+use decision_gate_core::AdvanceTo;
+use decision_gate_core::Artifact;
+use decision_gate_core::ArtifactError;
+use decision_gate_core::ArtifactKind;
+use decision_gate_core::ArtifactReader;
+use decision_gate_core::ArtifactRef;
+use decision_gate_core::ArtifactSink;
+use decision_gate_core::Comparator;
+use decision_gate_core::ConditionSpec;
+use decision_gate_core::DispatchReceipt;
+use decision_gate_core::DispatchTarget;
+use decision_gate_core::Dispatcher;
+use decision_gate_core::EvaluationAuditRecord;
+use decision_gate_core::EvaluationSubject;
+use decision_gate_core::EvidenceProvider;
+use decision_gate_core::EvidenceQuery;
+use decision_gate_core::EvidenceResult;
+use decision_gate_core::EvidenceValue;
+use decision_gate_core::GateEvalRecord;
+use decision_gate_core::GateId;
+use decision_gate_core::GateSpec;
+use decision_gate_core::NamespaceId;
+use decision_gate_core::PacketPayload;
+use decision_gate_core::PolicyDecider;
+use decision_gate_core::PolicyDecision;
+use decision_gate_core::ProviderId;
+use decision_gate_core::RunConfig;
+use decision_gate_core::RunId;
+use decision_gate_core::RunState;
+use decision_gate_core::RunStateStore;
+use decision_gate_core::RunpackManifest;
+use decision_gate_core::ScenarioId;
+use decision_gate_core::ScenarioSpec;
+use decision_gate_core::SpecVersion;
+use decision_gate_core::StageId;
+use decision_gate_core::StageSpec;
+use decision_gate_core::TenantId;
+use decision_gate_core::Timestamp;
+use decision_gate_core::TriggerId;
+use decision_gate_core::TrustLane;
+use decision_gate_core::evaluation_audit_records;
+use decision_gate_core::hashing::DEFAULT_HASH_ALGORITHM;
+use decision_gate_core::hashing::hash_bytes;
+use decision_gate_core::hashing::hash_canonical_json;
+use decision_gate_core::runtime::ControlPlane;
+use decision_gate_core::runtime::ControlPlaneConfig;
+use decision_gate_core::runtime::InMemoryRunStateStore;
+use decision_gate_core::runtime::NextRequest;
+use decision_gate_core::runtime::RunpackBuilder;
+use decision_gate_core::runtime::RunpackVerifier;
+use decision_gate_core::runtime::SubmitRequest;
+use decision_gate_core::runtime::VerificationStatus;
+use serde_json::json;
+
+// ============================================================================
+// SECTION: Test Helpers
+// ============================================================================
+
+struct ReadyEvidenceProvider;
+
+impl EvidenceProvider for ReadyEvidenceProvider {
+    fn query(
+        &self,
+        _query: &EvidenceQuery,
+        _ctx: &decision_gate_core::EvidenceContext,
+    ) -> Result<EvidenceResult, decision_gate_core::EvidenceError> {
+        Ok(EvidenceResult {
+            value: Some(EvidenceValue::Json(json!(true))),
+            lane: TrustLane::Verified,
+            error: None,
+            evidence_hash: None,
+            evidence_ref: None,
+            evidence_anchor: None,
+            signature: None,
+            content_type: Some("application/json".to_string()),
+        })
+    }
+
+    fn validate_providers(
+        &self,
+        _spec: &ScenarioSpec,
+    ) -> Result<(), decision_gate_core::ProviderMissingError> {
+        Ok(())
+    }
+}
+
+struct NoopDispatcher;
+
+impl Dispatcher for NoopDispatcher {
+    fn dispatch(
+        &self,
+        target: &DispatchTarget,
+        _envelope: &decision_gate_core::PacketEnvelope,
+        _payload: &PacketPayload,
+    ) -> Result<DispatchReceipt, decision_gate_core::DispatchError> {
+        Ok(DispatchReceipt {
+            dispatch_id: "dispatch-1".to_string(),
+            target: target.clone(),
+            receipt_hash: hash_bytes(DEFAULT_HASH_ALGORITHM, b"receipt"),
+            dispatched_at: Timestamp::Logical(1),
+            dispatcher: "noop".to_string(),
+        })
+    }
+}
+
+struct PermitAllPolicy;
+
+impl PolicyDecider for PermitAllPolicy {
+    fn authorize(
+        &self,
+        _target: &DispatchTarget,
+        _envelope: &decision_gate_core::PacketEnvelope,
+        _payload: &PacketPayload,
+    ) -> Result<PolicyDecision, decision_gate_core::PolicyError> {
+        Ok(PolicyDecision::Permit)
+    }
+}
+
+#[derive(Default)]
+struct InMemoryArtifacts {
+    files: BTreeMap<String, Vec<u8>>,
+}
+
+impl ArtifactSink for InMemoryArtifacts {
+    fn write(&mut self, artifact: &Artifact) -> Result<ArtifactRef, ArtifactError> {
+        self.files.insert(artifact.path.clone(), artifact.bytes.clone());
+        Ok(ArtifactRef {
+            uri: artifact.path.clone(),
+        })
+    }
+
+    fn finalize(&mut self, _manifest: &RunpackManifest) -> Result<ArtifactRef, ArtifactError> {
+        Ok(ArtifactRef {
+            uri: "manifest.json".to_string(),
+        })
+    }
+}
+
+impl ArtifactReader for InMemoryArtifacts {
+    fn read_with_limit(&self, path: &str, _max_bytes: usize) -> Result<Vec<u8>, ArtifactError> {
+        self.files.get(path).cloned().ok_or_else(|| ArtifactError::Sink(format!("missing {path}")))
+    }
+}
+
+fn audit_spec() -> ScenarioSpec {
+    ScenarioSpec {
+        scenario_id: ScenarioId::new("scenario"),
+        namespace_id: NamespaceId::from_raw(1).expect("nonzero namespaceid"),
+        spec_version: SpecVersion::new("1"),
+        stages: vec![StageSpec {
+            stage_id: StageId::new("stage-1"),
+            entry_packets: Vec::new(),
+            gates: vec![GateSpec {
+                gate_id: GateId::new("gate-ready"),
+                requirement: ret_logic::Requirement::condition("ready".into()),
+                trust: None,
+            }],
+            advance_to: AdvanceTo::Terminal,
+            timeout: None,
+            on_timeout: decision_gate_core::TimeoutPolicy::Fail,
+        }],
+        conditions: vec![ConditionSpec {
+            condition_id: "ready".into(),
+            query: EvidenceQuery {
+                provider_id: ProviderId::new("test"),
+                check_id: "ready".to_string(),
+                params: Some(json!({})),
+            },
+            comparator: Comparator::Equals,
+            expected: Some(json!(true)),
+            policy_tags: Vec::new(),
+            trust: None,
+        }],
+        policies: Vec::new(),
+        schemas: Vec::new(),
+        default_tenant_id: None,
+    }
+}
+
+fn run_config() -> RunConfig {
+    RunConfig {
+        tenant_id: TenantId::from_raw(1).expect("nonzero tenantid"),
+        namespace_id: NamespaceId::from_raw(1).expect("nonzero namespaceid"),
+        run_id: RunId::new("run-1"),
+        scenario_id: ScenarioId::new("scenario"),
+        dispatch_targets: vec![DispatchTarget::Agent {
+            agent_id: "agent-1".to_string(),
+        }],
+        policy_tags: Vec::new(),
+    }
+}
+
+/// Runs one submission and one evaluation, returning the persisted run state.
+fn evaluated_state() -> RunState {
+    let store = InMemoryRunStateStore::new();
+    let engine = ControlPlane::new(
+        audit_spec(),
+        ReadyEvidenceProvider,
+        NoopDispatcher,
+        store.clone(),
+        Some(PermitAllPolicy),
+        ControlPlaneConfig::default(),
+    )
+    .unwrap();
+    let config = run_config();
+    engine.start_run(config.clone(), Timestamp::Logical(0), false).unwrap();
+    engine
+        .scenario_submit(&SubmitRequest {
+            run_id: config.run_id.clone(),
+            tenant_id: config.tenant_id,
+            namespace_id: config.namespace_id,
+            submission_id: "submission-1".to_string(),
+            payload: PacketPayload::Json {
+                value: json!({"artifact": "report"}),
+            },
+            content_type: "application/json".to_string(),
+            submitted_at: Timestamp::Logical(1),
+            correlation_id: None,
+            expected_version: None,
+            lease_holder: None,
+        })
+        .unwrap();
+    engine
+        .scenario_next(&NextRequest {
+            run_id: config.run_id.clone(),
+            tenant_id: config.tenant_id,
+            namespace_id: config.namespace_id,
+            trigger_id: TriggerId::new("trigger-1"),
+            agent_id: "agent-1".to_string(),
+            time: Timestamp::Logical(2),
+            correlation_id: None,
+            lease_holder: None,
+        })
+        .unwrap();
+    store.load(&config.tenant_id, &config.namespace_id, &config.run_id).unwrap().unwrap()
+}
+
+// ============================================================================
+// SECTION: Tests
+// ============================================================================
+
+/// Verifies decision records hash the spec, run config, gate evidence, and decision.
+#[test]
+fn decision_record_hashes_match_inputs_and_outputs() {
+    let state = evaluated_state();
+    let decision = &state.decisions[0];
+    let record =
+        EvaluationAuditRecord::for_decision(&state, decision, DEFAULT_HASH_ALGORITHM).unwrap();
+
+    let gate_evals: Vec<GateEvalRecord> = state
+        .gate_evals
+        .iter()
+        .filter(|record| record.trigger_id == decision.trigger_id)
+        .cloned()
+        .collect();
+    assert!(!gate_evals.is_empty());
+    assert!(!gate_evals[0].evidence.is_empty());
+
+    assert_eq!(
+        record.subject,
+        EvaluationSubject::Decision {
+            trigger_id: TriggerId::new("trigger-1"),
+            decision_id: decision.decision_id.clone(),
+            seq: decision.seq,
+        }
+    );
+    assert_eq!(record.spec_hash, audit_spec().canonical_hash_with(DEFAULT_HASH_ALGORITHM).unwrap());
+    assert_eq!(
+        record.run_config_hash,
+        hash_canonical_json(DEFAULT_HASH_ALGORITHM, &run_config()).unwrap()
+    );
+    assert_eq!(
+        record.evidence_hash,
+        hash_canonical_json(DEFAULT_HASH_ALGORITHM, &gate_evals).unwrap()
+    );
+    assert_eq!(record.result_hash, hash_canonical_json(DEFAULT_HASH_ALGORITHM, decision).unwrap());
+}
+
+/// Verifies submission records hash the submitted payload and submission record.
+#[test]
+fn submission_record_hashes_match_inputs_and_outputs() {
+    let state = evaluated_state();
+    let submission = &state.submissions[0];
+    let record =
+        EvaluationAuditRecord::for_submission(&state, submission, DEFAULT_HASH_ALGORITHM).unwrap();
+
+    assert_eq!(
+        record.subject,
+        EvaluationSubject::Submission {
+            submission_id: "submission-1".to_string(),
+        }
+    );
+    assert_eq!(record.spec_hash, state.spec_hash);
+    assert_eq!(
+        record.run_config_hash,
+        hash_canonical_json(DEFAULT_HASH_ALGORITHM, &run_config()).unwrap()
+    );
+    assert_eq!(
+        record.evidence_hash,
+        hash_canonical_json(
+            DEFAULT_HASH_ALGORITHM,
+            &json!({"kind": "json", "value": {"artifact": "report"}})
+        )
+        .unwrap()
+    );
+    assert_eq!(
+        record.result_hash,
+        hash_canonical_json(DEFAULT_HASH_ALGORITHM, submission).unwrap()
+    );
+}
+
+/// Verifies runpacks include evaluation audit records only when requested.
+#[test]
+fn runpack_includes_evaluation_audit_artifact_on_request() {
+    let state = evaluated_state();
+    let spec = audit_spec();
+
+    let mut plain = InMemoryArtifacts::default();
+    let manifest =
+        RunpackBuilder::default().build(&mut plain, &spec, &state, Timestamp::Logical(3)).unwrap();
+    assert!(
+        !manifest.artifacts.iter().any(|artifact| artifact.kind == ArtifactKind::EvaluationAudit)
+    );
+
+    let mut audited = InMemoryArtifacts::default();
+    let manifest = RunpackBuilder::default()
+        .with_evaluation_audit()
+        .build(&mut audited, &spec, &state, Timestamp::Logical(3))
+        .unwrap();
+    let artifact = manifest
+        .artifacts
+        .iter()
+        .find(|artifact| artifact.kind == ArtifactKind::EvaluationAudit)
+        .expect("evaluation audit artifact");
+    let records: Vec<EvaluationAuditRecord> =
+        serde_json::from_slice(&audited.files[&artifact.path]).unwrap();
+    assert_eq!(records, evaluation_audit_records(&state, DEFAULT_HASH_ALGORITHM).unwrap());
+    assert_eq!(records.len(), 2);
+    assert!(matches!(records[0].subject, EvaluationSubject::Decision { .. }));
+    assert!(matches!(records[1].subject, EvaluationSubject::Submission { .. }));
+
+    let report =
+        RunpackVerifier::new(DEFAULT_HASH_ALGORITHM).verify_manifest(&audited, &manifest).unwrap();
+    assert_eq!(report.status, VerificationStatus::Pass);
+}
@@ -214,6 +214,7 @@ fn provider_errors_are_recorded_in_run_state() {
         agent_id: "agent-1".to_string(),
         time: Timestamp::Logical(1),
         correlation_id: None,
+        lease_holder: None,
     };
 
     let result = engine.scenario_next(&request).expect("scenario next");
@@ -282,6 +283,7 @@ fn missing_anchors_are_recorded_as_errors() {
         agent_id: "agent-1".to_string(),
         time: Timestamp::Logical(1),
         correlation_id: None,
+        lease_holder: None,
     };
 
     let _result = engine.scenario_next(&request).expect("scenario next");
@@ -84,3 +84,18 @@ fn test_gate_evaluation_with_snapshot() {
     assert_eq!(result.status, TriState::Unknown);
     assert_eq!(result.trace.len(), 2);
 }
+
+#[test]
+fn evidence_budget_accumulates_and_rejects_overflow() {
+    let evaluator = GateEvaluator::new(LogicMode::Kleene).with_evidence_budget(100);
+    let mut budget = evaluator.evidence_budget();
+
+    budget.charge(60).expect("first charge fits");
+    budget.charge(40).expect("second charge reaches the limit exactly");
+    let err = budget.charge(1).expect_err("overflow must be rejected");
+
+    assert_eq!(err.limit, 100);
+    assert_eq!(err.attempted, 101);
+    assert_eq!(budget.used(), 100);
+    assert_eq!(evaluator.evidence_budget().used(), 0);
+}
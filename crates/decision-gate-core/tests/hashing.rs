@@ -23,6 +23,7 @@
 use std::collections::BTreeMap;
 
 use decision_gate_core::HashAlgorithm;
+use decision_gate_core::hashing::DEFAULT_HASH_ALGORITHM;
 use decision_gate_core::hashing::HashDigest;
 use decision_gate_core::hashing::HashError;
 use decision_gate_core::hashing::canonical_json_bytes;
@@ -414,3 +415,58 @@ fn hash_digest_produces_lowercase_hex() {
     assert_eq!(digest.value, "abcdef12", "Hex must be lowercase");
     assert!(!digest.value.chars().any(|c| c.is_uppercase()), "No uppercase chars allowed");
 }
+
+// ============================================================================
+// SECTION: BLAKE3
+// ============================================================================
+
+/// Returns the official BLAKE3 test-vector input: bytes cycling 0..=250.
+fn blake3_vector_input(len: usize) -> Vec<u8> {
+    (0 .. len).map(|index| u8::try_from(index % 251).expect("below 251")).collect()
+}
+
+#[test]
+fn blake3_matches_reference_vectors() {
+    let cases = [
+        (0, "af1349b9f5f9a1a6a0404dea36dcc9499bcb25c9adc112b7cc9a93cae41f3262"),
+        (1, "2d3adedff11b61f14c886e35afa036736dcd87a74d27b5c1510225d0f592e213"),
+        (1023, "10108970eeda3eb932baac1428c7a2163b0e924c9a9e25b35bba72b28f70bd11"),
+        (1024, "42214739f095a406f3fc83deb889744ac00df831c10daa55189b5d121c855af7"),
+        (1025, "d00278ae47eb27b34faecf67b4fe263f82d5412916c1ffd97c8cb7fb814b8444"),
+        (2048, "e776b6028c7cd22a4d0ba182a8bf62205d2ef576467e838ed6f2529b85fba24a"),
+        (2049, "5f4d72f40d7a5f82b15ca2b2e44b1de3c2ef86c426c95c1af0b6879522563030"),
+        (3073, "7124b49501012f81cc7f11ca069ec9226cecb8a2c850cfe644e327d22d3e1cd3"),
+        (4096, "015094013f57a5277b59d8475c0501042c0b642e531b0a1c8f58d2163229e969"),
+        (8193, "bab6c09cb8ce8cf459261398d2e7aef35700bf488116ceb94a36d0f5f1b7bc3b"),
+        (31744, "62b6960e1a44bcc1eb1a611a8d6235b6b4b78f32e7abc4fb4c6cdcce94895c47"),
+        (102_400, "bc3e3d41a1146b069abffad3c0d44860cf664390afce4d9661f7902e7943e085"),
+    ];
+    for (len, expected) in cases {
+        let digest = hash_bytes(HashAlgorithm::Blake3, &blake3_vector_input(len));
+        assert_eq!(digest.algorithm, HashAlgorithm::Blake3);
+        assert_eq!(digest.value, expected, "BLAKE3 mismatch for input length {len}");
+    }
+    let digest = hash_bytes(HashAlgorithm::Blake3, b"abc");
+    assert_eq!(digest.value, "6437b3ac38465133ffb63b75273a8db548c558465d79db03fd359c6cd5bd9d85");
+}
+
+#[test]
+fn blake3_canonical_hash_differs_from_sha256() {
+    let value = json!({"b": 2, "a": 1});
+    let blake3 = hash_canonical_json(HashAlgorithm::Blake3, &value).expect("blake3");
+    let sha256 = hash_canonical_json(HashAlgorithm::Sha256, &value).expect("sha256");
+    let bytes = canonical_json_bytes(&value).expect("canonical bytes");
+    assert_eq!(blake3, hash_bytes(HashAlgorithm::Blake3, &bytes));
+    assert_ne!(blake3.value, sha256.value);
+    assert_eq!(blake3.value.len(), sha256.value.len(), "Both digests are 32 bytes");
+}
+
+#[test]
+fn blake3_digest_serializes_with_label() {
+    let digest = hash_bytes(HashAlgorithm::Blake3, b"abc");
+    let encoded = serde_json::to_value(&digest).expect("serialize digest");
+    assert_eq!(encoded["algorithm"], json!("blake3"));
+    let decoded: HashDigest = serde_json::from_value(encoded).expect("deserialize digest");
+    assert_eq!(decoded, digest);
+    assert_eq!(DEFAULT_HASH_ALGORITHM, HashAlgorithm::Sha256, "sha256 remains the default");
+}
@@ -0,0 +1,437 @@
+// crates/decision-gate-core/tests/leases.rs
+// ============================================================================
+// Module: Run Lease Tests
+// Description: Ensures run leases serialize submits and triggers.
+// ============================================================================
+//! ## Overview
+//! Validates advisory run leases: exclusive acquisition, server-clock expiry,
+//! release, and rejection of submits, triggers, and next calls from callers
+//! that do not hold the lease.
+
+#![allow(
+    clippy::panic,
+    clippy::print_stdout,
+    clippy::print_stderr,
+    clippy::unwrap_used,
+    clippy::expect_used,
+    clippy::use_debug,
+    clippy::dbg_macro,
+    clippy::panic_in_result_fn,
+    clippy::unwrap_in_result,
+    reason = "Test-only output and panic-based assertions are permitted."
+)]
+
+use std::sync::Arc;
+use std::time::Duration;
+
+use decision_gate_core::AdvanceTo;
+use decision_gate_core::DispatchReceipt;
+use decision_gate_core::DispatchTarget;
+use decision_gate_core::Dispatcher;
+use decision_gate_core::EvidenceContext;
+use decision_gate_core::EvidenceProvider;
+use decision_gate_core::EvidenceQuery;
+use decision_gate_core::EvidenceResult;
+use decision_gate_core::NamespaceId;
+use decision_gate_core::PacketPayload;
+use decision_gate_core::PacketSpec;
+use decision_gate_core::PolicyDecider;
+use decision_gate_core::PolicyDecision;
+use decision_gate_core::RunConfig;
+use decision_gate_core::RunId;
+use decision_gate_core::RunStateStore;
+use decision_gate_core::ScenarioId;
+use decision_gate_core::ScenarioSpec;
+use decision_gate_core::SpecVersion;
+use decision_gate_core::StageId;
+use decision_gate_core::StageSpec;
+use decision_gate_core::TenantId;
+use decision_gate_core::Timestamp;
+use decision_gate_core::TriggerEvent;
+use decision_gate_core::TriggerId;
+use decision_gate_core::TriggerKind;
+use decision_gate_core::hashing::DEFAULT_HASH_ALGORITHM;
+use decision_gate_core::hashing::hash_bytes;
+use decision_gate_core::runtime::ControlPlane;
+use decision_gate_core::runtime::ControlPlaneConfig;
+use decision_gate_core::runtime::ControlPlaneError;
+use decision_gate_core::runtime::InMemoryRunStateStore;
+use decision_gate_core::runtime::LeaseAcquireRequest;
+use decision_gate_core::runtime::LeaseReleaseRequest;
+use decision_gate_core::runtime::MAX_RUN_LEASE_TTL_MS;
+use decision_gate_core::runtime::ManualClock;
+use decision_gate_core::runtime::NextRequest;
+use decision_gate_core::runtime::SubmitRequest;
+use serde_json::json;
+
+struct NoopEvidenceProvider;
+
+impl EvidenceProvider for NoopEvidenceProvider {
+    fn query(
+        &self,
+        _query: &EvidenceQuery,
+        _ctx: &EvidenceContext,
+    ) -> Result<EvidenceResult, decision_gate_core::EvidenceError> {
+        Err(decision_gate_core::EvidenceError::Provider("unexpected evidence query".to_string()))
+    }
+
+    fn validate_providers(
+        &self,
+        _spec: &ScenarioSpec,
+    ) -> Result<(), decision_gate_core::ProviderMissingError> {
+        Ok(())
+    }
+}
+
+struct NoopDispatcher;
+
+impl Dispatcher for NoopDispatcher {
+    fn dispatch(
+        &self,
+        target: &DispatchTarget,
+        _envelope: &decision_gate_core::PacketEnvelope,
+        _payload: &PacketPayload,
+    ) -> Result<DispatchReceipt, decision_gate_core::DispatchError> {
+        Ok(DispatchReceipt {
+            dispatch_id: "dispatch-1".to_string(),
+            target: target.clone(),
+            receipt_hash: hash_bytes(DEFAULT_HASH_ALGORITHM, b"receipt"),
+            dispatched_at: Timestamp::Logical(1),
+            dispatcher: "noop".to_string(),
+        })
+    }
+}
+
+struct PermitAllPolicy;
+
+impl PolicyDecider for PermitAllPolicy {
+    fn authorize(
+        &self,
+        _target: &DispatchTarget,
+        _envelope: &decision_gate_core::PacketEnvelope,
+        _payload: &PacketPayload,
+    ) -> Result<PolicyDecision, decision_gate_core::PolicyError> {
+        Ok(PolicyDecision::Permit)
+    }
+}
+
+fn lease_spec() -> ScenarioSpec {
+    ScenarioSpec {
+        scenario_id: ScenarioId::new("scenario"),
+        namespace_id: NamespaceId::from_raw(1).expect("nonzero namespaceid"),
+        spec_version: SpecVersion::new("1"),
+        stages: vec![StageSpec {
+            stage_id: StageId::new("stage-1"),
+            entry_packets: vec![PacketSpec {
+                packet_id: decision_gate_core::PacketId::new("packet-1"),
+                schema_id: decision_gate_core::SchemaId::new("schema-1"),
+                content_type: "application/json".to_string(),
+                visibility_labels: vec!["public".to_string()],
+                policy_tags: Vec::new(),
+                expiry: None,
+                payload: PacketPayload::Json {
+                    value: json!({"message": "hello"}),
+                },
+            }],
+            gates: Vec::new(),
+            advance_to: AdvanceTo::Terminal,
+            timeout: None,
+            on_timeout: decision_gate_core::TimeoutPolicy::Fail,
+        }],
+        conditions: Vec::new(),
+        policies: Vec::new(),
+        schemas: Vec::new(),
+        default_tenant_id: None,
+    }
+}
+
+type Engine =
+    ControlPlane<NoopEvidenceProvider, NoopDispatcher, InMemoryRunStateStore, PermitAllPolicy>;
+
+fn tenant() -> TenantId {
+    TenantId::from_raw(1).expect("nonzero tenantid")
+}
+
+fn namespace() -> NamespaceId {
+    NamespaceId::from_raw(1).expect("nonzero namespaceid")
+}
+
+fn started_engine() -> (Engine, InMemoryRunStateStore, Arc<ManualClock>) {
+    let store = InMemoryRunStateStore::new();
+    let clock = Arc::new(ManualClock::new(0));
+    let engine = ControlPlane::new(
+        lease_spec(),
+        NoopEvidenceProvider,
+        NoopDispatcher,
+        store.clone(),
+        Some(PermitAllPolicy),
+        ControlPlaneConfig::default(),
+    )
+    .unwrap()
+    .with_clock(clock.clone());
+    let run_config = RunConfig {
+        tenant_id: tenant(),
+        namespace_id: namespace(),
+        run_id: RunId::new("run-1"),
+        scenario_id: ScenarioId::new("scenario"),
+        dispatch_targets: vec![DispatchTarget::Agent {
+            agent_id: "agent-1".to_string(),
+        }],
+        policy_tags: Vec::new(),
+    };
+    engine.start_run(run_config, Timestamp::Logical(0), false).unwrap();
+    (engine, store, clock)
+}
+
+fn acquire(holder_id: &str, ttl_ms: u64, at: u64) -> LeaseAcquireRequest {
+    LeaseAcquireRequest {
+        run_id: RunId::new("run-1"),
+        tenant_id: tenant(),
+        namespace_id: namespace(),
+        holder_id: holder_id.to_string(),
+        ttl_ms,
+        requested_at: Timestamp::Logical(at),
+    }
+}
+
+fn release(holder_id: &str, at: u64) -> LeaseReleaseRequest {
+    LeaseReleaseRequest {
+        run_id: RunId::new("run-1"),
+        tenant_id: tenant(),
+        namespace_id: namespace(),
+        holder_id: holder_id.to_string(),
+        requested_at: Timestamp::Logical(at),
+    }
+}
+
+fn submit(submission_id: &str, lease_holder: Option<&str>, at: u64) -> SubmitRequest {
+    SubmitRequest {
+        run_id: RunId::new("run-1"),
+        tenant_id: tenant(),
+        namespace_id: namespace(),
+        submission_id: submission_id.to_string(),
+        payload: PacketPayload::Json {
+            value: json!({"artifact": submission_id}),
+        },
+        content_type: "application/json".to_string(),
+        submitted_at: Timestamp::Logical(at),
+        correlation_id: None,
+        expected_version: None,
+        lease_holder: lease_holder.map(str::to_string),
+    }
+}
+
+fn next(lease_holder: Option<&str>, at: u64) -> NextRequest {
+    NextRequest {
+        run_id: RunId::new("run-1"),
+        tenant_id: tenant(),
+        namespace_id: namespace(),
+        trigger_id: TriggerId::new(format!("next-{at}")),
+        agent_id: "agent-1".to_string(),
+        time: Timestamp::Logical(at),
+        correlation_id: None,
+        lease_holder: lease_holder.map(str::to_string),
+    }
+}
+
+fn trigger(at: u64) -> TriggerEvent {
+    TriggerEvent {
+        trigger_id: TriggerId::new(format!("trigger-{at}")),
+        tenant_id: tenant(),
+        namespace_id: namespace(),
+        run_id: RunId::new("run-1"),
+        kind: TriggerKind::ExternalEvent,
+        time: Timestamp::Logical(at),
+        source_id: "scheduler".to_string(),
+        payload: None,
+        correlation_id: None,
+    }
+}
+
+#[test]
+fn lease_blocks_second_holder_until_released() {
+    let (engine, _store, _clock) = started_engine();
+
+    let lease = engine.acquire_lease(&acquire("alpha", 100, 1)).unwrap();
+    assert_eq!(lease.holder_id, "alpha");
+    assert_eq!(lease.expires_at, Timestamp::Logical(101));
+
+    match engine.acquire_lease(&acquire("beta", 100, 2)).unwrap_err() {
+        ControlPlaneError::LeaseHeld {
+            expires_at,
+        } => assert_eq!(expires_at, Timestamp::Logical(101)),
+        other => panic!("unexpected error: {other:?}"),
+    }
+    assert!(matches!(
+        engine.release_lease(&release("beta", 2)).unwrap_err(),
+        ControlPlaneError::LeaseHeld { .. }
+    ));
+
+    let renewed = engine.acquire_lease(&acquire("alpha", 100, 50)).unwrap();
+    assert_eq!(renewed.acquired_at, Timestamp::Logical(1));
+    assert_eq!(renewed.expires_at, Timestamp::Logical(150));
+
+    assert!(engine.release_lease(&release("alpha", 60)).unwrap().released);
+    assert!(!engine.release_lease(&release("alpha", 61)).unwrap().released);
+    let lease = engine.acquire_lease(&acquire("beta", 100, 62)).unwrap();
+    assert_eq!(lease.holder_id, "beta");
+}
+
+#[test]
+fn expired_lease_releases_run() {
+    let (engine, _store, clock) = started_engine();
+    engine.acquire_lease(&acquire("alpha", 10, 1)).unwrap();
+
+    clock.advance(Duration::from_millis(9));
+    assert!(matches!(
+        engine.scenario_submit(&submit("early", None, 10)).unwrap_err(),
+        ControlPlaneError::LeaseHeld { .. }
+    ));
+    clock.advance(Duration::from_millis(1));
+    engine.scenario_submit(&submit("late", None, 11)).unwrap();
+
+    match engine.scenario_submit(&submit("stale", Some("alpha"), 12)).unwrap_err() {
+        ControlPlaneError::LeaseNotHeld(holder) => assert_eq!(holder, "alpha"),
+        other => panic!("unexpected error: {other:?}"),
+    }
+    let lease = engine.acquire_lease(&acquire("beta", 10, 12)).unwrap();
+    assert_eq!(lease.acquired_at, Timestamp::Logical(12));
+}
+
+#[test]
+fn acquire_drops_expired_leases_on_other_runs() {
+    let (engine, _store, clock) = started_engine();
+    engine
+        .start_run(
+            RunConfig {
+                tenant_id: tenant(),
+                namespace_id: namespace(),
+                run_id: RunId::new("run-2"),
+                scenario_id: ScenarioId::new("scenario"),
+                dispatch_targets: Vec::new(),
+                policy_tags: Vec::new(),
+            },
+            Timestamp::Logical(0),
+            false,
+        )
+        .unwrap();
+    engine.acquire_lease(&acquire("crashed", 10, 1)).unwrap();
+    assert_eq!(engine.lease_entry_count(), 1);
+
+    clock.advance(Duration::from_millis(10));
+    let mut other = acquire("alpha", 100, 11);
+    other.run_id = RunId::new("run-2");
+    engine.acquire_lease(&other).unwrap();
+    assert_eq!(engine.lease_entry_count(), 1);
+
+    let lease = engine.acquire_lease(&acquire("beta", 10, 12)).unwrap();
+    assert_eq!(lease.acquired_at, Timestamp::Logical(12));
+    assert_eq!(engine.lease_entry_count(), 2);
+}
+
+#[test]
+fn non_holder_submit_and_trigger_are_rejected() {
+    let (engine, store, _clock) = started_engine();
+    engine.acquire_lease(&acquire("alpha", 100, 1)).unwrap();
+
+    for request in [submit("anonymous", None, 2), submit("intruder", Some("beta"), 2)] {
+        assert!(matches!(
+            engine.scenario_submit(&request).unwrap_err(),
+            ControlPlaneError::LeaseHeld { .. }
+        ));
+    }
+    assert!(matches!(
+        engine.trigger_with_lease(&trigger(3), Some("beta")).unwrap_err(),
+        ControlPlaneError::LeaseHeld { .. }
+    ));
+    assert!(matches!(
+        engine.trigger(&trigger(3)).unwrap_err(),
+        ControlPlaneError::LeaseHeld { .. }
+    ));
+
+    let state = store.load(&tenant(), &namespace(), &RunId::new("run-1")).unwrap().unwrap();
+    assert!(state.submissions.is_empty());
+    assert!(state.triggers.is_empty());
+    let codes: Vec<_> = state
+        .tool_calls
+        .iter()
+        .filter_map(|call| call.error.as_ref().map(|error| error.code.as_str()))
+        .collect();
+    assert_eq!(codes, ["lease_held"; 4]);
+
+    engine.scenario_submit(&submit("holder", Some("alpha"), 4)).unwrap();
+    engine.trigger_with_lease(&trigger(5), Some("alpha")).unwrap();
+}
+
+#[test]
+fn lease_requests_are_validated() {
+    let (engine, _store, _clock) = started_engine();
+    for request in
+        [acquire("alpha", 0, 1), acquire("alpha", MAX_RUN_LEASE_TTL_MS + 1, 1), acquire("", 10, 1)]
+    {
+        assert!(matches!(
+            engine.acquire_lease(&request).unwrap_err(),
+            ControlPlaneError::InvalidLease(_)
+        ));
+    }
+    let mut missing = acquire("alpha", 10, 1);
+    missing.run_id = RunId::new("run-missing");
+    assert!(matches!(
+        engine.acquire_lease(&missing).unwrap_err(),
+        ControlPlaneError::RunNotFound(_)
+    ));
+    match engine.scenario_submit(&submit("unleased", Some("alpha"), 2)).unwrap_err() {
+        ControlPlaneError::LeaseNotHeld(holder) => assert_eq!(holder, "alpha"),
+        other => panic!("unexpected error: {other:?}"),
+    }
+}
+
+#[test]
+fn caller_timestamps_do_not_expire_or_evict_lease() {
+    let (engine, _store, _clock) = started_engine();
+    engine.acquire_lease(&acquire("alpha", 100, 1)).unwrap();
+
+    let far_future = 1_000_000;
+    assert!(matches!(
+        engine.scenario_submit(&submit("intruder", None, far_future)).unwrap_err(),
+        ControlPlaneError::LeaseHeld { .. }
+    ));
+    assert!(matches!(
+        engine.trigger(&trigger(far_future)).unwrap_err(),
+        ControlPlaneError::LeaseHeld { .. }
+    ));
+    assert!(matches!(
+        engine.acquire_lease(&acquire("beta", 100, far_future)).unwrap_err(),
+        ControlPlaneError::LeaseHeld { .. }
+    ));
+    assert!(matches!(
+        engine.release_lease(&release("beta", far_future)).unwrap_err(),
+        ControlPlaneError::LeaseHeld { .. }
+    ));
+
+    engine.scenario_submit(&submit("holder", Some("alpha"), 2)).unwrap();
+}
+
+#[test]
+fn scenario_next_requires_lease_holder() {
+    let (engine, store, _clock) = started_engine();
+    engine.acquire_lease(&acquire("alpha", 100, 1)).unwrap();
+
+    assert!(matches!(
+        engine.scenario_next(&next(None, 2)).unwrap_err(),
+        ControlPlaneError::LeaseHeld { .. }
+    ));
+    assert!(matches!(
+        engine.scenario_next(&next(Some("beta"), 3)).unwrap_err(),
+        ControlPlaneError::LeaseHeld { .. }
+    ));
+    let state = store.load(&tenant(), &namespace(), &RunId::new("run-1")).unwrap().unwrap();
+    assert!(state.triggers.is_empty(), "rejected next calls must not advance the run");
+
+    engine.scenario_next(&next(Some("alpha"), 4)).unwrap();
+    assert!(engine.release_lease(&release("alpha", 5)).unwrap().released);
+    match engine.scenario_next(&next(Some("alpha"), 6)).unwrap_err() {
+        ControlPlaneError::LeaseNotHeld(holder) => assert_eq!(holder, "alpha"),
+        other => panic!("unexpected error: {other:?}"),
+    }
+}
@@ -53,6 +53,7 @@ use decision_gate_core::hashing::hash_bytes;
 use decision_gate_core::runtime::ControlPlane;
 use decision_gate_core::runtime::ControlPlaneConfig;
 use decision_gate_core::runtime::ControlPlaneError;
+use decision_gate_core::runtime::EvidenceBudgetExceeded;
 use decision_gate_core::runtime::InMemoryRunStateStore;
 use decision_gate_core::runtime::MAX_EVIDENCE_VALUE_BYTES;
 use decision_gate_core::runtime::MAX_PAYLOAD_BYTES;
@@ -159,6 +160,61 @@ fn minimal_spec() -> ScenarioSpec {
     }
 }
 
+/// Builds a spec whose single gate requires two provider-backed conditions.
+fn two_condition_spec() -> ScenarioSpec {
+    let mut spec = minimal_spec();
+    let mut second = spec.conditions[0].clone();
+    second.condition_id = "steady".into();
+    second.query.check_id = "steady".to_string();
+    spec.conditions.push(second);
+    spec.stages[0].gates[0].requirement = ret_logic::Requirement::and(vec![
+        ret_logic::Requirement::condition("ready".into()),
+        ret_logic::Requirement::condition("steady".into()),
+    ]);
+    spec
+}
+
+/// Starts a run and evaluates one trigger with the given evidence budget.
+fn next_with_evidence_budget(
+    spec: ScenarioSpec,
+    evidence_size: usize,
+    budget: usize,
+) -> Result<decision_gate_core::runtime::NextResult, ControlPlaneError> {
+    let engine = ControlPlane::new(
+        spec,
+        LargeEvidenceProvider {
+            size: evidence_size,
+        },
+        NoopDispatcher,
+        InMemoryRunStateStore::new(),
+        Some(PermitAllPolicy),
+        ControlPlaneConfig {
+            max_evidence_bytes_per_evaluation: budget,
+            ..ControlPlaneConfig::default()
+        },
+    )
+    .expect("control plane");
+    let run_config = RunConfig {
+        tenant_id: TenantId::from_raw(1).expect("nonzero tenantid"),
+        namespace_id: NamespaceId::from_raw(1).expect("nonzero namespaceid"),
+        run_id: decision_gate_core::RunId::new("run-budget"),
+        scenario_id: ScenarioId::new("scenario"),
+        dispatch_targets: vec![],
+        policy_tags: Vec::new(),
+    };
+    engine.start_run(run_config, Timestamp::Logical(0), false).expect("start run");
+    engine.scenario_next(&decision_gate_core::runtime::NextRequest {
+        run_id: decision_gate_core::RunId::new("run-budget"),
+        tenant_id: TenantId::from_raw(1).expect("nonzero tenantid"),
+        namespace_id: NamespaceId::from_raw(1).expect("nonzero namespaceid"),
+        trigger_id: TriggerId::new("trigger-1"),
+        agent_id: "agent-1".to_string(),
+        time: Timestamp::Logical(1),
+        correlation_id: None,
+        lease_holder: None,
+    })
+}
+
 // ============================================================================
 // SECTION: Tests
 // ============================================================================
@@ -198,6 +254,7 @@ fn evidence_payload_size_limit_is_enforced() {
         agent_id: "agent-1".to_string(),
         time: Timestamp::Logical(1),
         correlation_id: None,
+        lease_holder: None,
     };
 
     let err = engine.scenario_next(&request).expect_err("expected size limit error");
@@ -242,8 +299,36 @@ fn payload_size_limit_is_enforced() {
         content_type: "application/octet-stream".to_string(),
         submitted_at: Timestamp::Logical(1),
         correlation_id: None,
+        expected_version: None,
+        lease_holder: None,
     };
 
     let err = engine.scenario_submit(&request).expect_err("expected payload limit error");
     assert!(matches!(err, ControlPlaneError::PayloadTooLarge { .. }));
 }
+
+#[test]
+fn evidence_budget_under_limit_succeeds() {
+    let result = next_with_evidence_budget(two_condition_spec(), 600, 1200);
+    assert!(result.is_ok(), "expected evaluation within budget: {result:?}");
+}
+
+#[test]
+fn evidence_budget_exceeded_across_conditions_fails_closed() {
+    let err = next_with_evidence_budget(two_condition_spec(), 600, 1000)
+        .expect_err("expected evidence budget error");
+    assert!(matches!(
+        err,
+        ControlPlaneError::EvidenceBudgetExceeded(EvidenceBudgetExceeded {
+            limit: 1000,
+            attempted: 1200,
+        })
+    ));
+}
+
+#[test]
+fn evidence_budget_applies_to_single_condition() {
+    let err = next_with_evidence_budget(minimal_spec(), 600, 599)
+        .expect_err("expected evidence budget error");
+    assert!(matches!(err, ControlPlaneError::EvidenceBudgetExceeded(_)));
+}
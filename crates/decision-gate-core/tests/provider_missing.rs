@@ -174,6 +174,7 @@ fn scenario_next_logs_missing_provider_error() {
         agent_id: "agent-1".to_string(),
         time: Timestamp::Logical(1),
         correlation_id: None,
+        lease_holder: None,
     };
 
     let error = engine.scenario_next(&request).expect_err("expected provider missing error");
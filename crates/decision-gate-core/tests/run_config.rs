@@ -0,0 +1,113 @@
+// crates/decision-gate-core/tests/run_config.rs
+// ============================================================================
+// Module: Run Config Validation Tests
+// Description: Tests for run configuration validation and defaults.
+// Purpose: Ensure malformed run configs fail closed before a run starts.
+// Dependencies: decision-gate-core, serde_json
+// ============================================================================
+//! ## Overview
+//! Exercises `RunConfig::validate` and the serde defaults for optional fields.
+//!
+//! Security posture: Run configs arrive from untrusted callers - must fail closed.
+
+#![allow(
+    clippy::panic,
+    clippy::print_stdout,
+    clippy::print_stderr,
+    clippy::unwrap_used,
+    clippy::expect_used,
+    clippy::use_debug,
+    clippy::dbg_macro,
+    clippy::panic_in_result_fn,
+    clippy::unwrap_in_result,
+    reason = "Test-only output and panic-based assertions are permitted."
+)]
+
+use decision_gate_core::DispatchTarget;
+use decision_gate_core::MAX_RUN_CONFIG_ID_BYTES;
+use decision_gate_core::MAX_RUN_CONFIG_POLICY_TAGS;
+use decision_gate_core::NamespaceId;
+use decision_gate_core::RunConfig;
+use decision_gate_core::RunConfigError;
+use decision_gate_core::RunId;
+use decision_gate_core::ScenarioId;
+use decision_gate_core::TenantId;
+use serde_json::json;
+
+/// Builds a run config with one dispatch target and one policy tag.
+fn sample_config() -> RunConfig {
+    RunConfig {
+        tenant_id: TenantId::from_raw(1).expect("nonzero tenant id"),
+        namespace_id: NamespaceId::from_raw(1).expect("nonzero namespace id"),
+        run_id: RunId::new("run-1"),
+        scenario_id: ScenarioId::new("scenario-1"),
+        dispatch_targets: vec![DispatchTarget::Agent {
+            agent_id: "agent-1".to_string(),
+        }],
+        policy_tags: vec!["internal".to_string()],
+    }
+}
+
+// ============================================================================
+// SECTION: Tests
+// ============================================================================
+
+/// Tests that a well-formed config validates.
+#[test]
+fn run_config_accepts_valid_config() {
+    assert_eq!(sample_config().validate(), Ok(()));
+}
+
+/// Tests that out-of-range and malformed fields are rejected.
+#[test]
+fn run_config_rejects_out_of_range_fields() {
+    let mut config = sample_config();
+    config.run_id = RunId::new("r".repeat(MAX_RUN_CONFIG_ID_BYTES + 1));
+    assert!(matches!(
+        config.validate(),
+        Err(RunConfigError::TooLong {
+            field: "run_id",
+            ..
+        })
+    ));
+
+    let mut config = sample_config();
+    config.policy_tags = (0 ..= MAX_RUN_CONFIG_POLICY_TAGS).map(|i| format!("tag-{i}")).collect();
+    assert!(matches!(
+        config.validate(),
+        Err(RunConfigError::TooMany {
+            field: "policy_tags",
+            ..
+        })
+    ));
+
+    let mut config = sample_config();
+    config.scenario_id = ScenarioId::new("  ");
+    assert!(matches!(config.validate(), Err(RunConfigError::EmptyValue(_))));
+
+    let mut config = sample_config();
+    config.dispatch_targets.push(config.dispatch_targets[0].clone());
+    assert!(matches!(config.validate(), Err(RunConfigError::Duplicate(_))));
+
+    let mut config = sample_config();
+    config.dispatch_targets = vec![DispatchTarget::External {
+        system: "nats".to_string(),
+        target: String::new(),
+    }];
+    assert!(matches!(config.validate(), Err(RunConfigError::EmptyValue(_))));
+}
+
+/// Tests that omitted optional fields default to empty lists.
+#[test]
+fn run_config_applies_defaults_to_minimal_config() {
+    let config: RunConfig = serde_json::from_value(json!({
+        "tenant_id": 1,
+        "namespace_id": 1,
+        "run_id": "run-1",
+        "scenario_id": "scenario-1"
+    }))
+    .expect("minimal config");
+    assert!(config.dispatch_targets.is_empty());
+    assert!(config.policy_tags.is_empty());
+    assert_eq!(config.validate(), Ok(()));
+}
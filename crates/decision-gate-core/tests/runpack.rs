@@ -28,6 +28,7 @@ use decision_gate_core::Artifact;
 use decision_gate_core::ArtifactError;
 use decision_gate_core::ArtifactReader;
 use decision_gate_core::ArtifactSink;
+use decision_gate_core::Compression;
 use decision_gate_core::ConditionSpec;
 use decision_gate_core::EvidenceAnchor;
 use decision_gate_core::EvidenceAnchorPolicy;
@@ -39,12 +40,14 @@ use decision_gate_core::GateEvalRecord;
 use decision_gate_core::GateEvaluation;
 use decision_gate_core::GateId;
 use decision_gate_core::GateTraceEntry;
+use decision_gate_core::HashAlgorithm;
 use decision_gate_core::NamespaceId;
 use decision_gate_core::ProviderAnchorPolicy;
 use decision_gate_core::ProviderId;
 use decision_gate_core::RunId;
 use decision_gate_core::RunState;
 use decision_gate_core::RunStatus;
+use decision_gate_core::RunpackManifest;
 use decision_gate_core::RunpackVersion;
 use decision_gate_core::ScenarioId;
 use decision_gate_core::ScenarioSpec;
@@ -54,9 +57,16 @@ use decision_gate_core::StageSpec;
 use decision_gate_core::TenantId;
 use decision_gate_core::Timestamp;
 use decision_gate_core::hashing::DEFAULT_HASH_ALGORITHM;
+use decision_gate_core::hashing::hash_bytes;
 use decision_gate_core::hashing::hash_canonical_json;
+use decision_gate_core::runtime::COMPRESSED_RUNPACK_MANIFEST_VERSION;
+use decision_gate_core::runtime::DecodingArtifactReader;
+use decision_gate_core::runtime::RUNPACK_SIGNATURE_SCHEME_ED25519;
 use decision_gate_core::runtime::RunpackBuilder;
+use decision_gate_core::runtime::RunpackSigner;
 use decision_gate_core::runtime::RunpackVerifier;
+use decision_gate_core::runtime::VerificationReport;
+use ed25519_dalek::SigningKey;
 use ret_logic::TriState;
 use serde_json::json;
 
@@ -1107,3 +1117,517 @@ fn runpack_verifier_rejects_anchor_wrong_field_type() {
         report.errors
     );
 }
+
+// ============================================================================
+// SECTION: Manifest Signature Tests
+// ============================================================================
+
+fn signing_key(seed: u8) -> SigningKey {
+    SigningKey::from_bytes(&[seed; 32])
+}
+
+fn minimal_state(spec: &ScenarioSpec) -> RunState {
+    RunState {
+        tenant_id: TenantId::from_raw(1).expect("nonzero tenantid"),
+        namespace_id: NamespaceId::from_raw(1).expect("nonzero namespaceid"),
+        run_id: RunId::new("run-1"),
+        scenario_id: ScenarioId::new("scenario"),
+        spec_hash: spec.canonical_hash_with(DEFAULT_HASH_ALGORITHM).expect("spec hash"),
+        current_stage_id: StageId::new("stage-1"),
+        stage_entered_at: Timestamp::Logical(0),
+        status: RunStatus::Active,
+        dispatch_targets: vec![],
+        triggers: vec![],
+        gate_evals: vec![],
+        decisions: vec![],
+        packets: vec![],
+        submissions: vec![],
+        tool_calls: vec![],
+    }
+}
+
+fn signed_runpack(key: &SigningKey) -> (InMemoryArtifactStore, RunpackManifest) {
+    let spec = minimal_spec();
+    let state = minimal_state(&spec);
+    let mut store = InMemoryArtifactStore::default();
+    let builder = RunpackBuilder::default().with_signing_key("runpack-key", key.clone());
+    let manifest =
+        builder.build(&mut store, &spec, &state, Timestamp::Logical(1)).expect("runpack build");
+    (store, manifest)
+}
+
+/// Verifies a signed runpack passes verification against the signer's public key.
+#[test]
+fn runpack_verifier_accepts_signed_manifest() {
+    let key = signing_key(7);
+    let (store, manifest) = signed_runpack(&key);
+
+    let signature = manifest.signature.as_ref().expect("manifest signature");
+    assert_eq!(signature.scheme, RUNPACK_SIGNATURE_SCHEME_ED25519);
+    assert_eq!(signature.key_id, "runpack-key");
+
+    let verifier =
+        RunpackVerifier::new(DEFAULT_HASH_ALGORITHM).with_signature_key(key.verifying_key());
+    let report = verifier.verify_manifest(&store, &manifest).expect("runpack verify");
+
+    assert_eq!(report.status, decision_gate_core::runtime::VerificationStatus::Pass);
+}
+
+/// Verifies export-time verification re-signs the finalized manifest.
+#[test]
+fn runpack_build_with_verification_signs_finalized_manifest() {
+    let key = signing_key(7);
+    let spec = minimal_spec();
+    let state = minimal_state(&spec);
+    let mut sink = InMemoryArtifactStore::default();
+    let reader = sink.clone();
+    let builder = RunpackBuilder::default().with_signing_key("runpack-key", key.clone());
+    let (manifest, export_report) = builder
+        .build_with_verification(&mut sink, &reader, &spec, &state, Timestamp::Logical(1))
+        .expect("runpack build_with_verification");
+
+    let verifier =
+        RunpackVerifier::new(DEFAULT_HASH_ALGORITHM).with_signature_key(key.verifying_key());
+    let report = verifier.verify_manifest(&sink, &manifest).expect("runpack verify");
+
+    assert_eq!(export_report.status, decision_gate_core::runtime::VerificationStatus::Pass);
+    assert_eq!(report.status, decision_gate_core::runtime::VerificationStatus::Pass);
+}
+
+/// Verifies artifact tampering with recomputed hashes is caught by the signature.
+#[test]
+fn runpack_verifier_rejects_modified_signed_manifest() {
+    let key = signing_key(7);
+    let (store, mut manifest) = signed_runpack(&key);
+
+    let tampered = br#"[{"forged":true}]"#.to_vec();
+    let tampered_hash = hash_bytes(DEFAULT_HASH_ALGORITHM, &tampered);
+    store.insert_bytes("artifacts/triggers.json", tampered);
+    for entry in &mut manifest.integrity.file_hashes {
+        if entry.path == "artifacts/triggers.json" {
+            entry.hash = tampered_hash.clone();
+        }
+    }
+    manifest.integrity.root_hash =
+        hash_canonical_json(DEFAULT_HASH_ALGORITHM, &manifest.integrity.file_hashes)
+            .expect("root hash");
+
+    let unsigned_verifier = RunpackVerifier::new(DEFAULT_HASH_ALGORITHM);
+    let unsigned_report =
+        unsigned_verifier.verify_manifest(&store, &manifest).expect("runpack verify");
+    assert_eq!(unsigned_report.status, decision_gate_core::runtime::VerificationStatus::Pass);
+
+    let verifier =
+        RunpackVerifier::new(DEFAULT_HASH_ALGORITHM).with_signature_key(key.verifying_key());
+    let report = verifier.verify_manifest(&store, &manifest).expect("runpack verify");
+
+    assert_eq!(report.status, decision_gate_core::runtime::VerificationStatus::Fail);
+    assert!(
+        report.errors.iter().any(|err| err.contains("manifest signature invalid")),
+        "expected signature error, got: {:?}",
+        report.errors
+    );
+}
+
+/// Verifies a manifest signed by a different key fails verification.
+#[test]
+fn runpack_verifier_rejects_signature_from_wrong_key() {
+    let (store, manifest) = signed_runpack(&signing_key(7));
+
+    let verifier = RunpackVerifier::new(DEFAULT_HASH_ALGORITHM)
+        .with_signature_key(signing_key(9).verifying_key());
+    let report = verifier.verify_manifest(&store, &manifest).expect("runpack verify");
+
+    assert_eq!(report.status, decision_gate_core::runtime::VerificationStatus::Fail);
+    assert!(
+        report.errors.iter().any(|err| err.contains("manifest signature invalid")),
+        "expected signature error, got: {:?}",
+        report.errors
+    );
+}
+
+/// Verifies an unsigned manifest fails when a signature key is configured.
+#[test]
+fn runpack_verifier_requires_signature_when_key_configured() {
+    let spec = minimal_spec();
+    let state = minimal_state(&spec);
+    let mut store = InMemoryArtifactStore::default();
+    let manifest = RunpackBuilder::default()
+        .build(&mut store, &spec, &state, Timestamp::Logical(1))
+        .expect("runpack build");
+    assert!(manifest.signature.is_none());
+
+    let verifier = RunpackVerifier::new(DEFAULT_HASH_ALGORITHM)
+        .with_signature_key(signing_key(7).verifying_key());
+    let report = verifier.verify_manifest(&store, &manifest).expect("runpack verify");
+
+    assert_eq!(report.status, decision_gate_core::runtime::VerificationStatus::Fail);
+    assert!(
+        report.errors.iter().any(|err| err.contains("manifest signature missing")),
+        "expected missing signature error, got: {:?}",
+        report.errors
+    );
+}
+
+// ============================================================================
+// SECTION: Verification Attestation Tests
+// ============================================================================
+
+fn runpack_signer(key_id: &str, seed: u8) -> RunpackSigner {
+    RunpackSigner {
+        key_id: key_id.to_string(),
+        key: signing_key(seed),
+    }
+}
+
+fn verified_runpack() -> (RunpackManifest, VerificationReport) {
+    let (store, manifest) = signed_runpack(&signing_key(7));
+    let report = RunpackVerifier::new(DEFAULT_HASH_ALGORITHM)
+        .verify_manifest(&store, &manifest)
+        .expect("runpack verify");
+    (manifest, report)
+}
+
+/// Verifies an attestation over a passing report verifies with the verifier key.
+#[test]
+fn verification_attestation_over_passing_report_verifies() {
+    let (manifest, report) = verified_runpack();
+    let signer = runpack_signer("verifier-key", 11);
+
+    let attestation = report
+        .attest(&manifest, Timestamp::UnixMillis(1_700_000_000_000), &signer)
+        .expect("attest");
+
+    assert_eq!(attestation.claims.status, decision_gate_core::runtime::VerificationStatus::Pass);
+    assert_eq!(attestation.claims.verified_at, Timestamp::UnixMillis(1_700_000_000_000));
+    assert_eq!(attestation.claims.verifier_key_id, "verifier-key");
+    assert_eq!(
+        attestation.claims.manifest_hash,
+        hash_canonical_json(DEFAULT_HASH_ALGORITHM, &manifest).expect("manifest hash")
+    );
+    attestation
+        .verify(&report, &manifest, &signer.key.verifying_key())
+        .expect("attestation verifies");
+}
+
+/// Verifies tampering with the report or claims invalidates the attestation.
+#[test]
+fn verification_attestation_rejects_tampered_report() {
+    let (manifest, report) = verified_runpack();
+    let signer = runpack_signer("verifier-key", 11);
+    let key = signer.key.verifying_key();
+    let attestation = report.attest(&manifest, Timestamp::Logical(5), &signer).expect("attest");
+
+    let mut tampered_report = report.clone();
+    tampered_report.errors.push("injected".to_string());
+    let err = attestation.verify(&tampered_report, &manifest, &key).expect_err("tampered report");
+    assert!(err.to_string().contains("report hash mismatch"), "unexpected error: {err}");
+
+    let mut tampered_claims = attestation.clone();
+    tampered_claims.claims.verified_at = Timestamp::Logical(6);
+    let err = tampered_claims.verify(&report, &manifest, &key).expect_err("tampered claims");
+    assert!(err.to_string().contains("attestation signature invalid"), "unexpected error: {err}");
+
+    let mut tampered_manifest = manifest.clone();
+    tampered_manifest.generated_at = Timestamp::Logical(99);
+    let err = attestation.verify(&report, &tampered_manifest, &key).expect_err("other manifest");
+    assert!(err.to_string().contains("manifest hash mismatch"), "unexpected error: {err}");
+
+    let err = attestation
+        .verify(&report, &manifest, &signing_key(12).verifying_key())
+        .expect_err("wrong key");
+    assert!(err.to_string().contains("attestation signature invalid"), "unexpected error: {err}");
+}
+
+/// Verifies countersignatures endorse the attestation and detect later edits.
+#[test]
+fn verification_attestation_countersignature_round_trip() {
+    let (manifest, report) = verified_runpack();
+    let mut attestation = report
+        .attest(&manifest, Timestamp::Logical(5), &runpack_signer("verifier-key", 11))
+        .expect("attest");
+    let auditor = runpack_signer("auditor-key", 13);
+    let auditor_key = auditor.key.verifying_key();
+
+    assert!(attestation.verify_countersignature(&auditor_key).is_err());
+    attestation.countersign(&auditor).expect("countersign");
+    attestation.verify_countersignature(&auditor_key).expect("countersignature verifies");
+    assert!(attestation.verify_countersignature(&signing_key(12).verifying_key()).is_err());
+
+    attestation.claims.checked_files += 1;
+    assert!(attestation.verify_countersignature(&auditor_key).is_err());
+}
+
+// ============================================================================
+// SECTION: Hash Algorithm Tests
+// ============================================================================
+
+fn blake3_runpack() -> (InMemoryArtifactStore, RunpackManifest) {
+    let spec = minimal_spec();
+    let state = minimal_state(&spec);
+    let mut sink = InMemoryArtifactStore::default();
+    let reader = sink.clone();
+    let builder = RunpackBuilder {
+        hash_algorithm: HashAlgorithm::Blake3,
+        ..RunpackBuilder::default()
+    };
+    let (manifest, export_report) = builder
+        .build_with_verification(&mut sink, &reader, &spec, &state, Timestamp::Logical(1))
+        .expect("runpack build_with_verification");
+    assert_eq!(export_report.status, decision_gate_core::runtime::VerificationStatus::Pass);
+    (sink, manifest)
+}
+
+/// Verifies a BLAKE3 runpack built from a SHA-256 run records BLAKE3 throughout.
+#[test]
+fn runpack_blake3_manifest_records_algorithm() {
+    let (store, manifest) = blake3_runpack();
+
+    assert_eq!(manifest.hash_algorithm, HashAlgorithm::Blake3);
+    assert_eq!(manifest.spec_hash.algorithm, HashAlgorithm::Blake3);
+    assert_eq!(manifest.integrity.root_hash.algorithm, HashAlgorithm::Blake3);
+    for entry in &manifest.integrity.file_hashes {
+        assert_eq!(entry.hash.algorithm, HashAlgorithm::Blake3, "{}", entry.path);
+        let bytes = store.read(&entry.path).expect("artifact bytes");
+        assert_eq!(entry.hash, hash_bytes(HashAlgorithm::Blake3, &bytes), "{}", entry.path);
+    }
+    let encoded = serde_json::to_value(&manifest).expect("serialize manifest");
+    assert_eq!(encoded["hash_algorithm"], json!("blake3"));
+    let decoded: RunpackManifest = serde_json::from_value(encoded).expect("deserialize manifest");
+    assert_eq!(decoded.hash_algorithm, HashAlgorithm::Blake3);
+}
+
+/// Verifies manifest-driven verification picks the algorithm each runpack records.
+#[test]
+fn runpack_verifier_from_manifest_cross_verifies_algorithms() {
+    let (blake3_store, blake3_manifest) = blake3_runpack();
+    let spec = minimal_spec();
+    let state = minimal_state(&spec);
+    let mut sha256_store = InMemoryArtifactStore::default();
+    let sha256_manifest = RunpackBuilder::default()
+        .build(&mut sha256_store, &spec, &state, Timestamp::Logical(1))
+        .expect("runpack build");
+
+    let verifier = RunpackVerifier::from_manifest();
+    let report = verifier.verify_manifest(&blake3_store, &blake3_manifest).expect("verify blake3");
+    assert_eq!(report.status, decision_gate_core::runtime::VerificationStatus::Pass);
+    let report = verifier.verify_manifest(&sha256_store, &sha256_manifest).expect("verify sha256");
+    assert_eq!(report.status, decision_gate_core::runtime::VerificationStatus::Pass);
+
+    let pinned = RunpackVerifier::new(HashAlgorithm::Blake3);
+    let report = pinned.verify_manifest(&blake3_store, &blake3_manifest).expect("verify blake3");
+    assert_eq!(report.status, decision_gate_core::runtime::VerificationStatus::Pass);
+    let report = pinned.verify_manifest(&sha256_store, &sha256_manifest).expect("verify sha256");
+    assert_eq!(report.status, decision_gate_core::runtime::VerificationStatus::Fail);
+    assert!(
+        report.errors.iter().any(|err| err == "hash algorithm mismatch"),
+        "{:?}",
+        report.errors
+    );
+}
+
+/// Verifies a manifest relabelled to the other algorithm fails every hash check.
+#[test]
+fn runpack_verifier_rejects_relabelled_blake3_manifest() {
+    let (store, mut manifest) = blake3_runpack();
+    manifest.hash_algorithm = HashAlgorithm::Sha256;
+
+    let report = RunpackVerifier::from_manifest()
+        .verify_manifest(&store, &manifest)
+        .expect("runpack verify");
+
+    assert_eq!(report.status, decision_gate_core::runtime::VerificationStatus::Fail);
+    assert!(report.errors.iter().any(|err| err == "root hash mismatch"), "{:?}", report.errors);
+    assert!(
+        report.errors.iter().any(|err| err.starts_with("hash mismatch for ")),
+        "{:?}",
+        report.errors
+    );
+}
+
+/// Verifies tampering with a BLAKE3 runpack artifact is detected.
+#[test]
+fn runpack_verifier_detects_tampered_blake3_artifact() {
+    let (store, manifest) = blake3_runpack();
+    store.insert_bytes("artifacts/scenario_spec.json", b"tampered content".to_vec());
+
+    let report = RunpackVerifier::from_manifest()
+        .verify_manifest(&store, &manifest)
+        .expect("runpack verify");
+
+    assert_eq!(report.status, decision_gate_core::runtime::VerificationStatus::Fail);
+    assert!(
+        report.errors.iter().any(|err| err == "hash mismatch for artifacts/scenario_spec.json"),
+        "{:?}",
+        report.errors
+    );
+}
+
+// ============================================================================
+// SECTION: Compression Tests
+// ============================================================================
+
+/// Gzip member produced by a standard encoder (dynamic Huffman block).
+const REFERENCE_GZIP_DYNAMIC: &str = "1f8b080000000000020375923b0a80301005efb2758aecc65f7215b1088810b010a29578770b4104997a7803efed8ea79459927752f7bc1f55922cb9ac72b907e8076cb9d617188140aa86122d818e543d250602110b7a8a287657435bc00cd6d7166d1d6670011dd016f196b88129d90c1fc0fe1b4c375306788167020000";
+
+/// Gzip member holding a stored (uncompressed) DEFLATE block, as from `gzip -0`.
+const REFERENCE_GZIP_STORED: &str = "1f8b0800000000000403010600f9ff73746f7265640bf9435606000000";
+
+fn decode_hex(hex: &str) -> Vec<u8> {
+    (0 .. hex.len())
+        .step_by(2)
+        .map(|index| u8::from_str_radix(&hex[index .. index + 2], 16).expect("hex byte"))
+        .collect()
+}
+
+fn large_state(spec: &ScenarioSpec) -> RunState {
+    let mut state = ordering_state(spec);
+    let template = state.triggers[0].clone();
+    state.triggers = (0 .. 2_000u64)
+        .map(|seq| {
+            let mut record = template.clone();
+            record.seq = seq;
+            record.event.trigger_id = decision_gate_core::TriggerId::new(format!("trigger-{seq}"));
+            record
+        })
+        .collect();
+    state
+}
+
+fn gzip_runpack(state: &RunState) -> (InMemoryArtifactStore, RunpackManifest) {
+    let spec = ordering_spec();
+    let mut sink = InMemoryArtifactStore::default();
+    let reader = sink.clone();
+    let builder = RunpackBuilder::default().with_compression(Compression::Gzip);
+    let (manifest, export_report) = builder
+        .build_with_verification(&mut sink, &reader, &spec, state, Timestamp::Logical(1))
+        .expect("runpack build_with_verification");
+    assert_eq!(export_report.status, decision_gate_core::runtime::VerificationStatus::Pass);
+    (sink, manifest)
+}
+
+/// Verifies gzip exports hash the original bytes and round-trip through verification.
+#[test]
+fn runpack_gzip_export_round_trips_through_verification() {
+    let spec = ordering_spec();
+    for state in [ordering_state(&spec), large_state(&spec)] {
+        let (store, manifest) = gzip_runpack(&state);
+        let mut plain_store = InMemoryArtifactStore::default();
+        let plain = RunpackBuilder::default()
+            .build(&mut plain_store, &spec, &state, Timestamp::Logical(1))
+            .expect("runpack build");
+
+        assert_eq!(manifest.manifest_version.0, COMPRESSED_RUNPACK_MANIFEST_VERSION);
+        for (record, plain_record) in manifest.artifacts.iter().zip(&plain.artifacts) {
+            assert_eq!(record.content_encoding, Compression::Gzip, "{}", record.path);
+            assert_eq!(record.hash, plain_record.hash, "{}", record.path);
+            let stored = store.read(&record.path).expect("stored bytes");
+            let original = plain_store.read(&record.path).expect("original bytes");
+            assert_eq!(&stored[.. 2], &[0x1F, 0x8B], "{}", record.path);
+            assert_ne!(stored, original, "{}", record.path);
+        }
+        let triggers = plain_store.read("artifacts/triggers.json").expect("trigger log");
+        let compressed = store.read("artifacts/triggers.json").expect("trigger log");
+        if triggers.len() > 64 * 1024 {
+            assert!(compressed.len() * 10 < triggers.len(), "{} bytes", compressed.len());
+        }
+        let decoded = DecodingArtifactReader::new(&store, &manifest)
+            .read("artifacts/triggers.json")
+            .expect("decoded trigger log");
+        assert_eq!(decoded, triggers);
+
+        let report = RunpackVerifier::from_manifest()
+            .verify_manifest(&store, &manifest)
+            .expect("runpack verify");
+        assert_eq!(report.status, decision_gate_core::runtime::VerificationStatus::Pass);
+        assert_eq!(report.checked_files, manifest.integrity.file_hashes.len());
+    }
+}
+
+/// Verifies uncompressed exports keep the original manifest shape.
+#[test]
+fn runpack_uncompressed_export_omits_content_encoding() {
+    let spec = minimal_spec();
+    let state = minimal_state(&spec);
+    let mut store = InMemoryArtifactStore::default();
+    let manifest = RunpackBuilder::default()
+        .build(&mut store, &spec, &state, Timestamp::Logical(1))
+        .expect("runpack build");
+
+    assert_eq!(manifest.manifest_version, RunpackVersion("v1".to_string()));
+    let encoded = serde_json::to_value(&manifest).expect("serialize manifest");
+    for record in encoded["artifacts"].as_array().expect("artifacts array") {
+        assert!(record.get("content_encoding").is_none(), "{record}");
+    }
+}
+
+/// Verifies corrupted compressed artifacts fail verification with a decode error.
+#[test]
+fn runpack_verifier_detects_corrupted_gzip_artifact() {
+    let spec = ordering_spec();
+    let (store, manifest) = gzip_runpack(&ordering_state(&spec));
+    let mut bytes = store.read("artifacts/triggers.json").expect("trigger log");
+    let middle = bytes.len() / 2;
+    bytes[middle] ^= 0xFF;
+    store.insert_bytes("artifacts/triggers.json", bytes);
+    store.insert_bytes("artifacts/decisions.json", b"[]".to_vec());
+
+    let report = RunpackVerifier::from_manifest()
+        .verify_manifest(&store, &manifest)
+        .expect("runpack verify");
+
+    assert_eq!(report.status, decision_gate_core::runtime::VerificationStatus::Fail);
+    for path in ["artifacts/triggers.json", "artifacts/decisions.json"] {
+        assert!(
+            report
+                .errors
+                .iter()
+                .any(|err| err.starts_with(&format!("artifact read failed for {path}"))
+                    && err.contains("gzip decode failed")),
+            "{:?}",
+            report.errors
+        );
+    }
+}
+
+/// Verifies artifacts compressed by standard gzip tools decode before hashing.
+#[test]
+fn decoding_reader_accepts_standard_gzip_members() {
+    let items: Vec<_> = (0 .. 24)
+        .map(|id| json!({ "id": id, "status": if id % 3 == 0 { "fail" } else { "pass" } }))
+        .collect();
+    let original = serde_json::to_vec(&items).expect("serialize items");
+    let spec = minimal_spec();
+    let state = minimal_state(&spec);
+    let mut store = InMemoryArtifactStore::default();
+    let mut manifest = RunpackBuilder::default()
+        .with_compression(Compression::Gzip)
+        .build(&mut store, &spec, &state, Timestamp::Logical(1))
+        .expect("runpack build");
+    manifest.artifacts[0].path = "artifacts/external.json".to_string();
+
+    for (hex, expected) in [
+        (REFERENCE_GZIP_DYNAMIC, original.as_slice()),
+        (REFERENCE_GZIP_STORED, b"stored".as_slice()),
+    ] {
+        store.insert_bytes("artifacts/external.json", decode_hex(hex));
+        let decoded = DecodingArtifactReader::new(&store, &manifest)
+            .read("artifacts/external.json")
+            .expect("decode gzip member");
+        assert_eq!(decoded, expected);
+    }
+}
+
+/// Verifies decoding stops once output exceeds the caller's limit.
+#[test]
+fn decoding_reader_bounds_decoded_size() {
+    let spec = ordering_spec();
+    let (store, manifest) = gzip_runpack(&large_state(&spec));
+    let stored = store.read("artifacts/triggers.json").expect("trigger log");
+    let reader = DecodingArtifactReader::new(&store, &manifest);
+
+    let err = reader
+        .read_with_limit("artifacts/triggers.json", stored.len() * 2)
+        .expect_err("decoded output exceeds limit");
+    assert!(err.to_string().contains(&format!("exceeds {} bytes", stored.len() * 2)), "{err}");
+}
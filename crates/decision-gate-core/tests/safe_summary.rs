@@ -202,6 +202,7 @@ fn safe_summary_omits_evidence_values() {
         agent_id: "agent-1".to_string(),
         time: Timestamp::Logical(1),
         correlation_id: None,
+        lease_holder: None,
     };
     let result = engine.scenario_next(&request).unwrap();
 
@@ -0,0 +1,344 @@
+// crates/decision-gate-core/tests/state_diff.rs
+// ============================================================================
+// Module: Run State Diff Tests
+// Description: Tests for structural diffs between run state snapshots.
+// Purpose: Validate gate, status, and disclosure change reporting.
+// Dependencies: decision-gate-core, ret-logic, serde_json
+// ============================================================================
+//! ## Overview
+//! Builds pairs of run states by hand and checks [`RunState::diff`] reports
+//! exactly the expected changes in a stable order.
+
+#![allow(
+    clippy::panic,
+    clippy::print_stdout,
+    clippy::print_stderr,
+    clippy::unwrap_used,
+    clippy::expect_used,
+    clippy::use_debug,
+    clippy::dbg_macro,
+    clippy::panic_in_result_fn,
+    clippy::unwrap_in_result,
+    reason = "Test-only output and panic-based assertions are permitted."
+)]
+
+use decision_gate_core::DecisionId;
+use decision_gate_core::DiffKind;
+use decision_gate_core::DispatchReceipt;
+use decision_gate_core::DispatchTarget;
+use decision_gate_core::FieldChange;
+use decision_gate_core::GateDiff;
+use decision_gate_core::GateEvalRecord;
+use decision_gate_core::GateEvaluation;
+use decision_gate_core::GateId;
+use decision_gate_core::NamespaceId;
+use decision_gate_core::PacketEnvelope;
+use decision_gate_core::PacketId;
+use decision_gate_core::PacketPayload;
+use decision_gate_core::PacketRecord;
+use decision_gate_core::RunId;
+use decision_gate_core::RunState;
+use decision_gate_core::RunStatus;
+use decision_gate_core::ScenarioId;
+use decision_gate_core::SchemaId;
+use decision_gate_core::StageId;
+use decision_gate_core::TenantId;
+use decision_gate_core::Timestamp;
+use decision_gate_core::TriggerId;
+use decision_gate_core::VisibilityPolicy;
+use decision_gate_core::hashing::DEFAULT_HASH_ALGORITHM;
+use decision_gate_core::hashing::hash_bytes;
+use ret_logic::TriState;
+use serde_json::json;
+
+// ============================================================================
+// SECTION: Helpers
+// ============================================================================
+
+/// Builds an active run at `stage-1` with empty logs.
+fn base_state() -> RunState {
+    RunState {
+        tenant_id: TenantId::from_raw(1).expect("nonzero tenantid"),
+        namespace_id: NamespaceId::from_raw(1).expect("nonzero namespaceid"),
+        run_id: RunId::new("run-1"),
+        scenario_id: ScenarioId::new("scenario"),
+        spec_hash: hash_bytes(DEFAULT_HASH_ALGORITHM, b"spec"),
+        current_stage_id: StageId::new("stage-1"),
+        stage_entered_at: Timestamp::Logical(0),
+        status: RunStatus::Active,
+        dispatch_targets: Vec::new(),
+        triggers: Vec::new(),
+        gate_evals: Vec::new(),
+        decisions: Vec::new(),
+        packets: Vec::new(),
+        submissions: Vec::new(),
+        tool_calls: Vec::new(),
+    }
+}
+
+/// Builds a gate evaluation record.
+fn gate_eval(stage: &str, gate: &str, status: TriState) -> GateEvalRecord {
+    GateEvalRecord {
+        trigger_id: TriggerId::new("trigger-1"),
+        stage_id: StageId::new(stage),
+        evaluation: GateEvaluation {
+            gate_id: GateId::new(gate),
+            status,
+            trace: Vec::new(),
+        },
+        evidence: Vec::new(),
+    }
+}
+
+/// Builds a packet record with one receipt per dispatch identifier.
+fn packet(packet_id: &str, dispatch_ids: &[&str]) -> PacketRecord {
+    let target = DispatchTarget::Agent {
+        agent_id: "agent-1".to_string(),
+    };
+    PacketRecord {
+        envelope: PacketEnvelope {
+            scenario_id: ScenarioId::new("scenario"),
+            run_id: RunId::new("run-1"),
+            stage_id: StageId::new("stage-1"),
+            packet_id: PacketId::new(packet_id),
+            schema_id: SchemaId::new("schema-1"),
+            content_type: "application/json".to_string(),
+            content_hash: hash_bytes(DEFAULT_HASH_ALGORITHM, packet_id.as_bytes()),
+            visibility: VisibilityPolicy::new(Vec::new(), Vec::new()),
+            expiry: None,
+            correlation_id: None,
+            issued_at: Timestamp::Logical(1),
+        },
+        payload: PacketPayload::Json {
+            value: json!({"packet": packet_id}),
+        },
+        receipts: dispatch_ids
+            .iter()
+            .map(|dispatch_id| DispatchReceipt {
+                dispatch_id: (*dispatch_id).to_string(),
+                target: target.clone(),
+                receipt_hash: hash_bytes(DEFAULT_HASH_ALGORITHM, dispatch_id.as_bytes()),
+                dispatched_at: Timestamp::Logical(2),
+                dispatcher: "test".to_string(),
+            })
+            .collect(),
+        decision_id: DecisionId::new("decision-1"),
+    }
+}
+
+/// Builds an expected gate diff entry.
+fn gate_diff(
+    stage: &str,
+    gate: &str,
+    change: DiffKind,
+    from_status: Option<TriState>,
+    to_status: Option<TriState>,
+) -> GateDiff {
+    GateDiff {
+        stage_id: StageId::new(stage),
+        gate_id: GateId::new(gate),
+        change,
+        from_status,
+        to_status,
+    }
+}
+
+// ============================================================================
+// SECTION: Tests
+// ============================================================================
+
+#[test]
+fn identical_states_produce_empty_diff() {
+    let mut state = base_state();
+    state.gate_evals.push(gate_eval("stage-1", "gate-a", TriState::True));
+    state.packets.push(packet("packet-1", &["dispatch-1"]));
+
+    let diff = state.diff(&state.clone());
+    assert!(diff.is_empty(), "unexpected diff: {diff:?}");
+    assert!(diff.status.is_none());
+    assert!(diff.current_stage.is_none());
+}
+
+#[test]
+fn status_and_stage_transitions_are_reported() {
+    let from = base_state();
+    let mut to = base_state();
+    to.status = RunStatus::Completed;
+    to.current_stage_id = StageId::new("stage-2");
+
+    let diff = from.diff(&to);
+    assert_eq!(
+        diff.status,
+        Some(FieldChange {
+            from: RunStatus::Active,
+            to: RunStatus::Completed,
+        })
+    );
+    assert_eq!(
+        diff.current_stage,
+        Some(FieldChange {
+            from: StageId::new("stage-1"),
+            to: StageId::new("stage-2"),
+        })
+    );
+    assert!(diff.gates.is_empty());
+    assert!(diff.disclosures.is_empty());
+    assert!(!diff.is_empty());
+}
+
+#[test]
+fn gates_present_in_one_version_are_added_or_removed() {
+    let mut from = base_state();
+    from.gate_evals.push(gate_eval("stage-1", "gate-b", TriState::Unknown));
+    from.gate_evals.push(gate_eval("stage-1", "gate-a", TriState::Unknown));
+    from.gate_evals.push(gate_eval("stage-1", "gate-same", TriState::False));
+    let mut to = base_state();
+    to.gate_evals.push(gate_eval("stage-2", "gate-a", TriState::True));
+    to.gate_evals.push(gate_eval("stage-1", "gate-a", TriState::True));
+    to.gate_evals.push(gate_eval("stage-1", "gate-same", TriState::False));
+
+    let diff = from.diff(&to);
+    assert_eq!(
+        diff.gates,
+        vec![
+            gate_diff(
+                "stage-1",
+                "gate-a",
+                DiffKind::Changed,
+                Some(TriState::Unknown),
+                Some(TriState::True),
+            ),
+            gate_diff("stage-1", "gate-b", DiffKind::Removed, Some(TriState::Unknown), None),
+            gate_diff("stage-2", "gate-a", DiffKind::Added, None, Some(TriState::True)),
+        ]
+    );
+}
+
+#[test]
+fn latest_gate_evaluation_wins() {
+    let mut from = base_state();
+    from.gate_evals.push(gate_eval("stage-1", "gate-a", TriState::True));
+    let mut to = from.clone();
+    to.gate_evals.push(gate_eval("stage-1", "gate-a", TriState::False));
+    to.gate_evals.push(gate_eval("stage-1", "gate-a", TriState::True));
+
+    assert!(from.diff(&to).gates.is_empty(), "re-evaluation to the same status is not a change");
+
+    to.gate_evals.push(gate_eval("stage-1", "gate-a", TriState::False));
+    assert_eq!(
+        from.diff(&to).gates,
+        vec![gate_diff(
+            "stage-1",
+            "gate-a",
+            DiffKind::Changed,
+            Some(TriState::True),
+            Some(TriState::False),
+        )]
+    );
+}
+
+#[test]
+fn disclosure_changes_report_dispatch_deltas() {
+    let mut from = base_state();
+    from.packets.push(packet("packet-2", &["dispatch-a"]));
+    from.packets.push(packet("packet-1", &["dispatch-a", "dispatch-b"]));
+    from.packets.push(packet("packet-unchanged", &["dispatch-a"]));
+    let mut to = base_state();
+    to.packets.push(packet("packet-1", &["dispatch-c", "dispatch-a"]));
+    to.packets.push(packet("packet-3", &["dispatch-z"]));
+    to.packets.push(packet("packet-unchanged", &["dispatch-a"]));
+
+    let diff = from.diff(&to);
+    let summary: Vec<_> = diff
+        .disclosures
+        .iter()
+        .map(|entry| {
+            (
+                entry.packet_id.as_str(),
+                entry.change,
+                entry.dispatches_added.clone(),
+                entry.dispatches_removed.clone(),
+            )
+        })
+        .collect();
+    assert_eq!(
+        summary,
+        vec![
+            (
+                "packet-1",
+                DiffKind::Changed,
+                vec!["dispatch-c".to_string()],
+                vec!["dispatch-b".to_string()],
+            ),
+            ("packet-2", DiffKind::Removed, Vec::new(), vec!["dispatch-a".to_string()]),
+            ("packet-3", DiffKind::Added, vec!["dispatch-z".to_string()], Vec::new()),
+        ]
+    );
+    assert!(diff.disclosures.iter().all(|entry| entry.stage_id.as_str() == "stage-1"));
+}
+
+#[test]
+fn reversed_diff_swaps_added_and_removed() {
+    let mut from = base_state();
+    from.gate_evals.push(gate_eval("stage-1", "gate-a", TriState::True));
+    let mut to = base_state();
+    to.status = RunStatus::Failed;
+    to.packets.push(packet("packet-1", &[]));
+
+    let forward = from.diff(&to);
+    let backward = to.diff(&from);
+    assert_eq!(forward.gates[0].change, DiffKind::Removed);
+    assert_eq!(backward.gates[0].change, DiffKind::Added);
+    assert_eq!(forward.disclosures[0].change, DiffKind::Added);
+    assert_eq!(backward.disclosures[0].change, DiffKind::Removed);
+    assert_eq!(
+        backward.status.map(|change| (change.from, change.to)),
+        Some((RunStatus::Failed, RunStatus::Active))
+    );
+}
+
+#[test]
+fn diff_serializes_deterministically() {
+    let mut from = base_state();
+    from.gate_evals.push(gate_eval("stage-1", "gate-b", TriState::False));
+    from.gate_evals.push(gate_eval("stage-1", "gate-a", TriState::Unknown));
+    let mut to = base_state();
+    to.gate_evals.push(gate_eval("stage-1", "gate-a", TriState::True));
+    to.packets.push(packet("packet-1", &["dispatch-1"]));
+
+    let first = serde_json::to_value(from.diff(&to)).expect("serialize diff");
+    let second = serde_json::to_value(from.diff(&to)).expect("serialize diff");
+    assert_eq!(first, second);
+    assert_eq!(
+        first,
+        json!({
+            "status": null,
+            "current_stage": null,
+            "gates": [
+                {
+                    "stage_id": "stage-1",
+                    "gate_id": "gate-a",
+                    "change": "changed",
+                    "from_status": "Unknown",
+                    "to_status": "True"
+                },
+                {
+                    "stage_id": "stage-1",
+                    "gate_id": "gate-b",
+                    "change": "removed",
+                    "from_status": "False",
+                    "to_status": null
+                }
+            ],
+            "disclosures": [
+                {
+                    "packet_id": "packet-1",
+                    "stage_id": "stage-1",
+                    "change": "added",
+                    "dispatches_added": ["dispatch-1"],
+                    "dispatches_removed": []
+                }
+            ]
+        })
+    );
+}
@@ -33,6 +33,7 @@ use decision_gate_core::RunStateStore;
 use decision_gate_core::RunStatus;
 use decision_gate_core::ScenarioId;
 use decision_gate_core::StageId;
+use decision_gate_core::StoreError;
 use decision_gate_core::TenantId;
 use decision_gate_core::Timestamp;
 use decision_gate_core::hashing::DEFAULT_HASH_ALGORITHM;
@@ -105,3 +106,56 @@ fn store_returns_none_for_missing_run() {
         .unwrap();
     assert!(loaded.is_none());
 }
+
+fn with_submission(mut state: RunState, submission_id: &str) -> RunState {
+    state.submissions.push(decision_gate_core::SubmissionRecord {
+        submission_id: submission_id.to_string(),
+        run_id: state.run_id.clone(),
+        payload: decision_gate_core::PacketPayload::Json {
+            value: serde_json::json!({"submission": submission_id}),
+        },
+        content_type: "application/json".to_string(),
+        content_hash: decision_gate_core::hashing::hash_bytes(
+            DEFAULT_HASH_ALGORITHM,
+            submission_id.as_bytes(),
+        ),
+        submitted_at: Timestamp::Logical(1),
+        correlation_id: None,
+    });
+    state
+}
+
+/// Verifies a versioned save succeeds only against the stored version.
+#[test]
+fn store_save_if_version_rejects_stale_version() {
+    let store = InMemoryRunStateStore::new();
+    let base = sample_state("run-1");
+    store.save(&base).unwrap();
+
+    let first = with_submission(base.clone(), "submission-a");
+    store.save_if_version(&first, base.version()).unwrap();
+
+    let second = with_submission(base.clone(), "submission-b");
+    let err = store.save_if_version(&second, base.version()).unwrap_err();
+    match err {
+        StoreError::VersionConflict {
+            expected,
+            actual,
+        } => {
+            assert_eq!(expected, base.version());
+            assert_eq!(actual, first.version());
+        }
+        other => panic!("unexpected error: {other:?}"),
+    }
+    let loaded =
+        store.load(&base.tenant_id, &base.namespace_id, &base.run_id).unwrap().expect("run state");
+    assert_eq!(loaded, first);
+}
+
+/// Verifies a versioned save of a missing run is rejected.
+#[test]
+fn store_save_if_version_rejects_missing_run() {
+    let store = InMemoryRunStateStore::new();
+    let err = store.save_if_version(&sample_state("run-1"), 0).unwrap_err();
+    assert!(matches!(err, StoreError::Invalid(_)), "unexpected error: {err:?}");
+}
@@ -4,7 +4,8 @@
 // Description: Ensures scenario_submit is idempotent and detects conflicts.
 // ============================================================================
 //! ## Overview
-//! Validates submission idempotency semantics for deterministic run state.
+//! Validates submission idempotency semantics for deterministic run state and
+//! optimistic concurrency via `expected_version`.
 
 #![allow(
     clippy::panic,
@@ -19,6 +20,12 @@
     reason = "Test-only output and panic-based assertions are permitted."
 )]
 
+use std::sync::Arc;
+use std::sync::Barrier;
+use std::sync::atomic::AtomicBool;
+use std::sync::atomic::Ordering;
+use std::thread;
+
 use decision_gate_core::AdvanceTo;
 use decision_gate_core::DispatchReceipt;
 use decision_gate_core::DispatchTarget;
@@ -33,12 +40,14 @@ use decision_gate_core::PacketSpec;
 use decision_gate_core::PolicyDecider;
 use decision_gate_core::PolicyDecision;
 use decision_gate_core::RunConfig;
+use decision_gate_core::RunState;
 use decision_gate_core::RunStateStore;
 use decision_gate_core::ScenarioId;
 use decision_gate_core::ScenarioSpec;
 use decision_gate_core::SpecVersion;
 use decision_gate_core::StageId;
 use decision_gate_core::StageSpec;
+use decision_gate_core::StoreError;
 use decision_gate_core::TenantId;
 use decision_gate_core::Timestamp;
 use decision_gate_core::hashing::DEFAULT_HASH_ALGORITHM;
@@ -47,6 +56,7 @@ use decision_gate_core::runtime::ControlPlane;
 use decision_gate_core::runtime::ControlPlaneConfig;
 use decision_gate_core::runtime::ControlPlaneError;
 use decision_gate_core::runtime::InMemoryRunStateStore;
+use decision_gate_core::runtime::StatusRequest;
 use decision_gate_core::runtime::SubmitRequest;
 use serde_json::json;
 
@@ -168,6 +178,8 @@ fn submission_idempotent_returns_existing_record() {
         content_type: "application/json".to_string(),
         submitted_at: Timestamp::Logical(1),
         correlation_id: None,
+        expected_version: None,
+        lease_holder: None,
     };
 
     let first = engine.scenario_submit(&request).unwrap();
@@ -224,6 +236,8 @@ fn submission_idempotent_conflict_returns_error() {
         content_type: "application/json".to_string(),
         submitted_at: Timestamp::Logical(1),
         correlation_id: None,
+        expected_version: None,
+        lease_holder: None,
     };
     engine.scenario_submit(&first).unwrap();
 
@@ -238,6 +252,8 @@ fn submission_idempotent_conflict_returns_error() {
         content_type: "application/json".to_string(),
         submitted_at: Timestamp::Logical(2),
         correlation_id: None,
+        expected_version: None,
+        lease_holder: None,
     };
 
     let err = engine.scenario_submit(&conflicting).unwrap_err();
@@ -259,3 +275,203 @@ fn submission_idempotent_conflict_returns_error() {
     assert_eq!(state.submissions.len(), 1);
     assert_eq!(state.tool_calls.len(), 2);
 }
+
+fn version_request(submission_id: &str, expected_version: u64) -> SubmitRequest {
+    SubmitRequest {
+        run_id: decision_gate_core::RunId::new("run-1"),
+        tenant_id: TenantId::from_raw(1).expect("nonzero tenantid"),
+        namespace_id: NamespaceId::from_raw(1).expect("nonzero namespaceid"),
+        submission_id: submission_id.to_string(),
+        payload: PacketPayload::Json {
+            value: json!({"artifact": submission_id}),
+        },
+        content_type: "application/json".to_string(),
+        submitted_at: Timestamp::Logical(1),
+        correlation_id: None,
+        expected_version: Some(expected_version),
+        lease_holder: None,
+    }
+}
+
+fn status_version<S: RunStateStore>(
+    engine: &ControlPlane<NoopEvidenceProvider, NoopDispatcher, S, PermitAllPolicy>,
+) -> u64 {
+    engine
+        .scenario_status(&StatusRequest {
+            run_id: decision_gate_core::RunId::new("run-1"),
+            tenant_id: TenantId::from_raw(1).expect("nonzero tenantid"),
+            namespace_id: NamespaceId::from_raw(1).expect("nonzero namespaceid"),
+            requested_at: Timestamp::Logical(1),
+            correlation_id: None,
+        })
+        .unwrap()
+        .version
+}
+
+#[test]
+fn concurrent_submits_with_same_version_conflict_until_reread() {
+    let store = InMemoryRunStateStore::new();
+    let engine = Arc::new(
+        ControlPlane::new(
+            submission_spec(),
+            NoopEvidenceProvider,
+            NoopDispatcher,
+            store.clone(),
+            Some(PermitAllPolicy),
+            ControlPlaneConfig::default(),
+        )
+        .unwrap(),
+    );
+    let run_config = RunConfig {
+        tenant_id: TenantId::from_raw(1).expect("nonzero tenantid"),
+        namespace_id: NamespaceId::from_raw(1).expect("nonzero namespaceid"),
+        run_id: decision_gate_core::RunId::new("run-1"),
+        scenario_id: ScenarioId::new("scenario"),
+        dispatch_targets: vec![DispatchTarget::Agent {
+            agent_id: "agent-1".to_string(),
+        }],
+        policy_tags: Vec::new(),
+    };
+    engine.start_run(run_config, Timestamp::Logical(0), false).unwrap();
+
+    let version = status_version(&engine);
+    assert_eq!(status_version(&engine), version, "status reads must not advance the version");
+
+    let barrier = Arc::new(Barrier::new(2));
+    let spawn_submit = |submission_id: &'static str| {
+        let engine = Arc::clone(&engine);
+        let barrier = Arc::clone(&barrier);
+        thread::spawn(move || {
+            barrier.wait();
+            (submission_id, engine.scenario_submit(&version_request(submission_id, version)))
+        })
+    };
+    let first = spawn_submit("submission-a");
+    let second = spawn_submit("submission-b");
+    let results = [first.join().unwrap(), second.join().unwrap()];
+
+    let accepted = results.iter().filter(|(_, result)| result.is_ok()).count();
+    assert_eq!(accepted, 1, "exactly one submit wins: {results:?}");
+    let (rejected_id, rejected) = results.iter().find(|(_, result)| result.is_err()).unwrap();
+    match rejected {
+        Err(ControlPlaneError::VersionConflict {
+            expected,
+            actual,
+        }) => {
+            assert_eq!(*expected, version);
+            assert_eq!(*actual, version + 1);
+        }
+        other => panic!("unexpected result: {other:?}"),
+    }
+
+    let reread = status_version(&engine);
+    assert_eq!(reread, version + 1);
+    engine.scenario_submit(&version_request(rejected_id, reread)).unwrap();
+
+    let state = store
+        .load(
+            &TenantId::from_raw(1).expect("nonzero tenantid"),
+            &NamespaceId::from_raw(1).expect("nonzero namespaceid"),
+            &decision_gate_core::RunId::new("run-1"),
+        )
+        .unwrap()
+        .expect("run state");
+    assert_eq!(state.submissions.len(), 2);
+    assert_eq!(state.version(), version + 2);
+}
+
+/// Store that lets another writer land a submission just before the first versioned save.
+struct RacingStore {
+    inner: InMemoryRunStateStore,
+    raced: AtomicBool,
+}
+
+impl RunStateStore for RacingStore {
+    fn load(
+        &self,
+        tenant_id: &TenantId,
+        namespace_id: &NamespaceId,
+        run_id: &decision_gate_core::RunId,
+    ) -> Result<Option<RunState>, StoreError> {
+        self.inner.load(tenant_id, namespace_id, run_id)
+    }
+
+    fn save(&self, state: &RunState) -> Result<(), StoreError> {
+        self.inner.save(state)
+    }
+
+    fn save_if_version(&self, state: &RunState, expected_version: u64) -> Result<(), StoreError> {
+        if !self.raced.swap(true, Ordering::SeqCst) {
+            let mut other = self
+                .inner
+                .load(&state.tenant_id, &state.namespace_id, &state.run_id)?
+                .expect("run state");
+            let mut racing = version_request("submission-other", expected_version);
+            racing.expected_version = None;
+            other.submissions.push(decision_gate_core::SubmissionRecord {
+                submission_id: racing.submission_id,
+                run_id: racing.run_id,
+                payload: racing.payload,
+                content_type: racing.content_type,
+                content_hash: hash_bytes(DEFAULT_HASH_ALGORITHM, b"submission-other"),
+                submitted_at: racing.submitted_at,
+                correlation_id: None,
+            });
+            self.inner.save(&other)?;
+        }
+        self.inner.save_if_version(state, expected_version)
+    }
+}
+
+#[test]
+fn submit_rejected_when_another_writer_advances_store_first() {
+    let inner = InMemoryRunStateStore::new();
+    let engine = ControlPlane::new(
+        submission_spec(),
+        NoopEvidenceProvider,
+        NoopDispatcher,
+        RacingStore {
+            inner: inner.clone(),
+            raced: AtomicBool::new(false),
+        },
+        Some(PermitAllPolicy),
+        ControlPlaneConfig::default(),
+    )
+    .unwrap();
+    let run_config = RunConfig {
+        tenant_id: TenantId::from_raw(1).expect("nonzero tenantid"),
+        namespace_id: NamespaceId::from_raw(1).expect("nonzero namespaceid"),
+        run_id: decision_gate_core::RunId::new("run-1"),
+        scenario_id: ScenarioId::new("scenario"),
+        dispatch_targets: Vec::new(),
+        policy_tags: Vec::new(),
+    };
+    engine.start_run(run_config, Timestamp::Logical(0), false).unwrap();
+
+    let mut request = version_request("submission-a", 0);
+    request.expected_version = None;
+    match engine.scenario_submit(&request) {
+        Err(ControlPlaneError::VersionConflict {
+            expected,
+            actual,
+        }) => {
+            assert_eq!(expected, 0);
+            assert_eq!(actual, 1);
+        }
+        other => panic!("unexpected result: {other:?}"),
+    }
+
+    let state = inner
+        .load(
+            &TenantId::from_raw(1).expect("nonzero tenantid"),
+            &NamespaceId::from_raw(1).expect("nonzero namespaceid"),
+            &decision_gate_core::RunId::new("run-1"),
+        )
+        .unwrap()
+        .expect("run state");
+    let ids: Vec<_> =
+        state.submissions.iter().map(|record| record.submission_id.as_str()).collect();
+    assert_eq!(ids, ["submission-other"], "the other writer's submission must not be clobbered");
+
+    engine.scenario_submit(&request).unwrap();
+}
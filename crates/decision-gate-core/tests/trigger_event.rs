@@ -0,0 +1,132 @@
+// crates/decision-gate-core/tests/trigger_event.rs
+// ============================================================================
+// Module: Trigger Event Validation Tests
+// Description: Tests for trigger event validation and payload normalization.
+// Purpose: Ensure malformed triggers fail closed before reaching the control plane.
+// Dependencies: decision-gate-core, serde_json
+// ============================================================================
+//! ## Overview
+//! Exercises `TriggerEvent::validate_and_normalize` bounds and canonicalization.
+//!
+//! Security posture: Triggers arrive from untrusted callers - must fail closed.
+
+#![allow(
+    clippy::panic,
+    clippy::print_stdout,
+    clippy::print_stderr,
+    clippy::unwrap_used,
+    clippy::expect_used,
+    clippy::use_debug,
+    clippy::dbg_macro,
+    clippy::panic_in_result_fn,
+    clippy::unwrap_in_result,
+    reason = "Test-only output and panic-based assertions are permitted."
+)]
+
+use decision_gate_core::CorrelationId;
+use decision_gate_core::MAX_TRIGGER_PAYLOAD_BYTES;
+use decision_gate_core::MAX_TRIGGER_UNIX_MILLIS;
+use decision_gate_core::NamespaceId;
+use decision_gate_core::PacketPayload;
+use decision_gate_core::RunId;
+use decision_gate_core::TenantId;
+use decision_gate_core::Timestamp;
+use decision_gate_core::TriggerEvent;
+use decision_gate_core::TriggerEventError;
+use decision_gate_core::TriggerId;
+use decision_gate_core::TriggerKind;
+use serde_json::json;
+
+/// Builds a trigger with an inline JSON payload.
+fn sample_trigger() -> TriggerEvent {
+    TriggerEvent {
+        trigger_id: TriggerId::new("trigger-1"),
+        tenant_id: TenantId::from_raw(1).expect("nonzero tenant id"),
+        namespace_id: NamespaceId::from_raw(1).expect("nonzero namespace id"),
+        run_id: RunId::new("run-1"),
+        kind: TriggerKind::ExternalEvent,
+        time: Timestamp::UnixMillis(1_710_000_000_000),
+        source_id: "webhook".to_string(),
+        payload: Some(PacketPayload::Json {
+            value: json!({"z": 1.0, "a": [true, null]}),
+        }),
+        correlation_id: Some(CorrelationId::new("corr-1")),
+    }
+}
+
+// ============================================================================
+// SECTION: Tests
+// ============================================================================
+
+/// Tests that a well-formed trigger validates and its payload is canonicalized.
+#[test]
+fn trigger_event_accepts_valid_trigger() {
+    let trigger = sample_trigger().validate_and_normalize().expect("valid trigger");
+    let Some(PacketPayload::Json {
+        value,
+    }) = &trigger.payload
+    else {
+        panic!("expected json payload");
+    };
+    assert_eq!(serde_json::to_string(value).unwrap(), r#"{"a":[true,null],"z":1}"#);
+    assert_eq!(trigger.clone().validate_and_normalize(), Ok(trigger));
+
+    let logical = TriggerEvent {
+        time: Timestamp::Logical(7),
+        payload: None,
+        ..sample_trigger()
+    };
+    assert!(logical.validate_and_normalize().is_ok());
+}
+
+/// Tests that unix-millis timestamps outside the supported range are rejected.
+#[test]
+fn trigger_event_rejects_out_of_range_timestamp() {
+    for millis in [-1, i64::MIN, MAX_TRIGGER_UNIX_MILLIS + 1] {
+        let trigger = TriggerEvent {
+            time: Timestamp::UnixMillis(millis),
+            ..sample_trigger()
+        };
+        assert_eq!(
+            trigger.validate_and_normalize(),
+            Err(TriggerEventError::InvalidTimestamp(millis))
+        );
+    }
+    let trigger = TriggerEvent {
+        time: Timestamp::UnixMillis(MAX_TRIGGER_UNIX_MILLIS),
+        ..sample_trigger()
+    };
+    assert!(trigger.validate_and_normalize().is_ok());
+}
+
+/// Tests that oversized payloads and blank identifiers are rejected.
+#[test]
+fn trigger_event_rejects_oversized_payload() {
+    let trigger = TriggerEvent {
+        payload: Some(PacketPayload::Bytes {
+            bytes: vec![0; MAX_TRIGGER_PAYLOAD_BYTES + 1],
+        }),
+        ..sample_trigger()
+    };
+    assert!(matches!(
+        trigger.validate_and_normalize(),
+        Err(TriggerEventError::PayloadTooLarge { .. })
+    ));
+
+    let trigger = TriggerEvent {
+        payload: Some(PacketPayload::Json {
+            value: json!({"blob": "x".repeat(MAX_TRIGGER_PAYLOAD_BYTES)}),
+        }),
+        ..sample_trigger()
+    };
+    assert!(matches!(
+        trigger.validate_and_normalize(),
+        Err(TriggerEventError::PayloadTooLarge { .. })
+    ));
+
+    let trigger = TriggerEvent {
+        source_id: " ".to_string(),
+        ..sample_trigger()
+    };
+    assert_eq!(trigger.validate_and_normalize(), Err(TriggerEventError::EmptyValue("source_id")));
+}
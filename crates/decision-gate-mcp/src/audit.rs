@@ -26,10 +26,14 @@ use std::time::SystemTime;
 use std::time::UNIX_EPOCH;
 
 use decision_gate_contract::ToolName;
+use decision_gate_core::EvaluationAuditRecord;
 use decision_gate_core::HashDigest;
 use serde::Serialize;
 use serde_json::Value;
 
+use crate::authz_trace::AuthzLayer;
+use crate::authz_trace::AuthzLayerVerdict;
+use crate::authz_trace::AuthzTrace;
 use crate::config::RegistryAclAction;
 use crate::config::ServerTransport;
 use crate::telemetry::McpMethod;
@@ -183,6 +187,12 @@ pub struct TenantAuthzEvent {
     pub tenant_id: Option<String>,
     /// Namespace identifier when provided.
     pub namespace_id: Option<String>,
+    /// Layer verdicts in evaluation order (decision trace events only).
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub layers: Option<Vec<AuthzLayerVerdict>>,
+    /// First layer that denied the request (decision trace events only).
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub denied_by: Option<AuthzLayer>,
 }
 
 /// Usage audit event payload.
@@ -242,6 +252,27 @@ pub struct SecurityAuditEvent {
     pub namespace_authority: String,
 }
 
+/// Evaluation audit event payload (hash-only).
+///
+/// # Invariants
+/// - Payload fields are canonical hashes and identifiers; no evidence values.
+#[derive(Debug, Clone, Serialize)]
+pub struct EvaluationAuditEvent {
+    /// Event identifier.
+    pub event: &'static str,
+    /// Event timestamp (milliseconds since epoch).
+    pub timestamp_ms: u128,
+    /// Unsafe client correlation identifier when provided.
+    pub unsafe_client_correlation_id: Option<String>,
+    /// Server-generated correlation identifier.
+    pub server_correlation_id: String,
+    /// Tool that produced the outcome.
+    pub tool: ToolName,
+    /// Hash binding of the evaluation inputs and outcome.
+    #[serde(flatten)]
+    pub record: EvaluationAuditRecord,
+}
+
 /// Inputs required to construct an audit event.
 ///
 /// # Invariants
@@ -415,6 +446,21 @@ pub struct SecurityAuditEventParams {
     pub namespace_authority: String,
 }
 
+/// Inputs required to construct an evaluation audit event.
+///
+/// # Invariants
+/// - This is a pure input container for event construction.
+pub struct EvaluationAuditEventParams {
+    /// Unsafe client correlation identifier when provided.
+    pub unsafe_client_correlation_id: Option<String>,
+    /// Server-generated correlation identifier.
+    pub server_correlation_id: String,
+    /// Tool that produced the outcome.
+    pub tool: ToolName,
+    /// Hash binding of the evaluation inputs and outcome.
+    pub record: EvaluationAuditRecord,
+}
+
 impl McpAuditEvent {
     /// Creates a new audit event with a consistent timestamp.
     #[must_use]
@@ -512,8 +558,20 @@ impl TenantAuthzEvent {
             principal_id: params.principal_id,
             tenant_id: params.tenant_id,
             namespace_id: params.namespace_id,
+            layers: None,
+            denied_by: None,
         }
     }
+
+    /// Creates an authorization decision trace event from collected layer verdicts.
+    #[must_use]
+    pub fn decision_trace(params: TenantAuthzEventParams, trace: AuthzTrace) -> Self {
+        let mut event = Self::new(params);
+        event.event = "authz_decision_trace";
+        event.denied_by = trace.first_denier().map(|verdict| verdict.layer);
+        event.layers = Some(trace.layers);
+        event
+    }
 }
 
 impl UsageAuditEvent {
@@ -558,6 +616,23 @@ impl SecurityAuditEvent {
     }
 }
 
+impl EvaluationAuditEvent {
+    /// Creates a new evaluation audit event with a consistent timestamp.
+    #[must_use]
+    pub fn new(params: EvaluationAuditEventParams) -> Self {
+        let timestamp_ms =
+            SystemTime::now().duration_since(UNIX_EPOCH).unwrap_or_default().as_millis();
+        Self {
+            event: "evaluation_audit",
+            timestamp_ms,
+            unsafe_client_correlation_id: params.unsafe_client_correlation_id,
+            server_correlation_id: params.server_correlation_id,
+            tool: params.tool,
+            record: params.record,
+        }
+    }
+}
+
 // ============================================================================
 // SECTION: Trait
 // ============================================================================
@@ -581,6 +656,9 @@ pub trait McpAuditSink: Send + Sync {
 
     /// Record a security posture audit event.
     fn record_security(&self, _event: &SecurityAuditEvent) {}
+
+    /// Record an evaluation audit event.
+    fn record_evaluation(&self, _event: &EvaluationAuditEvent) {}
 }
 
 /// Audit sink that logs JSON lines to stderr.
@@ -625,6 +703,12 @@ impl McpAuditSink for McpStderrAuditSink {
             let _ = writeln!(std::io::stderr(), "{payload}");
         }
     }
+
+    fn record_evaluation(&self, event: &EvaluationAuditEvent) {
+        if let Ok(payload) = serde_json::to_string(event) {
+            let _ = writeln!(std::io::stderr(), "{payload}");
+        }
+    }
 }
 
 /// Audit sink that logs JSON lines to a file.
@@ -704,6 +788,15 @@ impl McpAuditSink for McpFileAuditSink {
             let _ = file.flush();
         }
     }
+
+    fn record_evaluation(&self, event: &EvaluationAuditEvent) {
+        if let Ok(payload) = serde_json::to_string(event)
+            && let Ok(mut file) = self.file.lock()
+        {
+            let _ = writeln!(file, "{payload}");
+            let _ = file.flush();
+        }
+    }
 }
 
 /// No-op audit sink.
@@ -724,4 +817,6 @@ impl McpAuditSink for McpNoopAuditSink {
     fn record_usage(&self, _event: &UsageAuditEvent) {}
 
     fn record_security(&self, _event: &SecurityAuditEvent) {}
+
+    fn record_evaluation(&self, _event: &EvaluationAuditEvent) {}
 }
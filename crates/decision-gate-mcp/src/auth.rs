@@ -9,7 +9,11 @@
 //! ## Overview
 //! This module defines the authn/authz interfaces for MCP tool calls and
 //! provides default policies for local-only, bearer token, and mTLS-subject
-//! enforcement. All decisions are fail-closed and emit audit events.
+//! enforcement. Bearer mode optionally validates signed JWTs against a JWKS
+//! (see [`crate::jwt`]). Authenticated callers carry [`AuthScopes`] (read-only access,
+//! tool and tenant allowlists) resolved from `server.auth.principals`, which
+//! narrow the server-wide policy for least-privilege credentials. All
+//! decisions are fail-closed and emit audit events.
 //! Security posture: auth decisions are a trust boundary and must fail closed
 //! on any invalid input; see `Docs/security/threat_model.md`.
 
@@ -17,20 +21,26 @@
 // SECTION: Imports
 // ============================================================================
 
+use std::collections::BTreeMap;
 use std::collections::BTreeSet;
 use std::io::Write;
 use std::net::IpAddr;
 
 use async_trait::async_trait;
 use decision_gate_contract::ToolName;
+use decision_gate_core::TenantId;
 use decision_gate_core::hashing::HashAlgorithm;
 use decision_gate_core::hashing::hash_bytes;
 use serde::Serialize;
 use thiserror::Error;
 
+use crate::authz_trace;
+use crate::authz_trace::AuthzLayer;
+use crate::config::PrincipalScopesConfig;
 use crate::config::ServerAuthConfig;
 use crate::config::ServerAuthMode;
 use crate::config::ServerTransport;
+use crate::jwt::JwtValidator;
 
 // ============================================================================
 // SECTION: Constants
@@ -198,6 +208,8 @@ pub struct AuthContext {
     pub subject: Option<String>,
     /// Token fingerprint for bearer auth (hashed).
     pub token_fingerprint: Option<String>,
+    /// Least-privilege scopes granted to the caller.
+    pub scopes: AuthScopes,
 }
 
 impl AuthContext {
@@ -227,6 +239,179 @@ impl AuthContext {
     }
 }
 
+/// Least-privilege scopes attached to an authenticated caller.
+///
+/// # Invariants
+/// - Scopes only narrow access granted by the server-wide auth policy.
+/// - The default value is unrestricted.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct AuthScopes {
+    /// Restrict the caller to read-only tools.
+    pub read_only: bool,
+    /// Optional tool allowlist (`None` permits all tools).
+    pub allowed_tools: Option<BTreeSet<ToolName>>,
+    /// Optional tenant allowlist (`None` permits all tenants).
+    pub tenant_ids: Option<BTreeSet<TenantId>>,
+}
+
+impl AuthScopes {
+    /// Builds scopes from principal scope configuration.
+    ///
+    /// Unparseable tool names fail closed to an empty allowlist.
+    #[must_use]
+    pub fn from_config(config: &PrincipalScopesConfig) -> Self {
+        let allowed_tools = if config.allowed_tools.is_empty() {
+            None
+        } else {
+            let parsed: Option<BTreeSet<ToolName>> =
+                config.allowed_tools.iter().map(|name| ToolName::parse(name)).collect();
+            Some(parsed.unwrap_or_default())
+        };
+        let tenant_ids = if config.tenant_ids.is_empty() {
+            None
+        } else {
+            Some(config.tenant_ids.iter().copied().collect())
+        };
+        Self {
+            read_only: config.read_only,
+            allowed_tools,
+            tenant_ids,
+        }
+    }
+
+    /// Builds scopes from a JWT `scope` claim for subjects without a
+    /// configured principal entry.
+    ///
+    /// Recognized scopes are `dg:read` (read-only tools), `dg:write` (all
+    /// tools), `dg:tool:<name>` (tool allowlist), and `dg:tenant:<id>` or
+    /// `dg:tenant:*` (tenant allowlist). Other scopes are ignored. Tool scopes
+    /// are additive: `dg:tool:` grants add the listed tools, including mutating
+    /// ones, to whatever `dg:read` or `dg:write` already permits. The result
+    /// fails closed: without `dg:read`, `dg:write`, or a `dg:tool:` entry no
+    /// tool is permitted, and without a `dg:tenant:` entry no tenant is.
+    #[must_use]
+    pub fn from_jwt_scopes(scopes: &[String]) -> Self {
+        let mut read = false;
+        let mut write = false;
+        let mut tools = BTreeSet::new();
+        let mut tenants = Some(BTreeSet::new());
+        for scope in scopes {
+            match scope.as_str() {
+                "dg:read" => read = true,
+                "dg:write" => write = true,
+                "dg:tenant:*" => tenants = None,
+                _ => {
+                    if let Some(name) = scope.strip_prefix("dg:tool:")
+                        && let Some(tool) = ToolName::parse(name)
+                    {
+                        tools.insert(tool);
+                    } else if let Some(raw) = scope.strip_prefix("dg:tenant:")
+                        && let Some(tenant_id) = raw.parse().ok().and_then(TenantId::from_raw)
+                        && let Some(tenants) = &mut tenants
+                    {
+                        tenants.insert(tenant_id);
+                    }
+                }
+            }
+        }
+        let read_only = !write && tools.iter().all(|tool| tool_is_read_only(*tool));
+        let allowed_tools = if write || (read && tools.is_empty()) {
+            None
+        } else {
+            if read {
+                let read_tools = ToolName::all().iter().copied();
+                tools.extend(read_tools.filter(|tool| tool_is_read_only(*tool)));
+            }
+            Some(tools)
+        };
+        Self {
+            read_only,
+            allowed_tools,
+            tenant_ids: tenants,
+        }
+    }
+
+    /// Returns true when the scopes permit calling the tool.
+    #[must_use]
+    pub fn permits_tool(&self, tool: ToolName) -> bool {
+        self.check_tool(tool).is_ok()
+    }
+
+    /// Narrows the tool allowlist to tools also present in `allowed`.
+    pub fn restrict_tools(&mut self, allowed: &BTreeSet<ToolName>) {
+        let narrowed = self.allowed_tools.take().map_or_else(
+            || allowed.clone(),
+            |current| current.intersection(allowed).copied().collect(),
+        );
+        self.allowed_tools = Some(narrowed);
+    }
+
+    /// Checks whether the scopes permit calling the tool.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`AuthError::Unauthorized`] when the tool is outside the scopes.
+    pub fn check_tool(&self, tool: ToolName) -> Result<(), AuthError> {
+        if self.read_only && !tool_is_read_only(tool) {
+            return Err(AuthError::Unauthorized(
+                "read-only scope denies mutating tool".to_string(),
+            ));
+        }
+        if let Some(allowed) = &self.allowed_tools
+            && !allowed.contains(&tool)
+        {
+            return Err(AuthError::Unauthorized(
+                "tool not permitted by principal scopes".to_string(),
+            ));
+        }
+        Ok(())
+    }
+
+    /// Checks whether the scopes permit access to the tenant.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`AuthError::Unauthorized`] when the tenant is outside the scopes.
+    pub fn check_tenant(&self, tenant_id: &TenantId) -> Result<(), AuthError> {
+        if let Some(allowed) = &self.tenant_ids
+            && !allowed.contains(tenant_id)
+        {
+            return Err(AuthError::Unauthorized(
+                "tenant not permitted by principal scopes".to_string(),
+            ));
+        }
+        Ok(())
+    }
+}
+
+/// Returns true when the tool never mutates run state, registries, or storage.
+#[must_use]
+pub const fn tool_is_read_only(tool: ToolName) -> bool {
+    match tool {
+        ToolName::ScenarioStatus
+        | ToolName::ScenariosList
+        | ToolName::SchemasList
+        | ToolName::SchemasGet
+        | ToolName::EvidenceQuery
+        | ToolName::Precheck
+        | ToolName::ProvidersList
+        | ToolName::ProviderContractGet
+        | ToolName::ProviderCheckSchemaGet
+        | ToolName::RunpackVerify
+        | ToolName::DecisionGateDocsSearch
+        | ToolName::ScenariosStatusBulk => true,
+        ToolName::ScenarioDefine
+        | ToolName::ScenarioStart
+        | ToolName::ScenarioNext
+        | ToolName::ScenarioSubmit
+        | ToolName::ScenarioTrigger
+        | ToolName::RunpackExport
+        | ToolName::SchemasRegister
+        | ToolName::ScenarioLeaseAcquire
+        | ToolName::ScenarioLeaseRelease => false,
+    }
+}
+
 /// Authentication method used for the request.
 ///
 /// # Invariants
@@ -249,6 +434,8 @@ pub enum AuthMethod {
 pub enum AuthAction<'a> {
     /// List tools action.
     ListTools,
+    /// Describe tool contracts action.
+    DescribeTools,
     /// Tool call action.
     CallTool(&'a ToolName),
 }
@@ -258,6 +445,7 @@ impl AuthAction<'_> {
     fn label(self) -> String {
         match self {
             AuthAction::ListTools => "tools/list".to_string(),
+            AuthAction::DescribeTools => "tools/describe".to_string(),
             AuthAction::CallTool(tool) => tool.as_str().to_string(),
         }
     }
@@ -323,6 +511,10 @@ pub struct DefaultToolAuthz {
     mtls_subjects: BTreeSet<String>,
     /// Optional tool allowlist.
     allowed_tools: Option<BTreeSet<ToolName>>,
+    /// Scopes keyed by principal identifier.
+    principal_scopes: BTreeMap<String, AuthScopes>,
+    /// Optional JWT validator (an error fails JWT bearer auth closed).
+    jwt: Option<Result<JwtValidator, String>>,
 }
 
 impl DefaultToolAuthz {
@@ -349,11 +541,26 @@ impl DefaultToolAuthz {
             }
             Some(parsed)
         });
+        let principal_scopes = config
+            .map(|cfg| {
+                cfg.principals
+                    .iter()
+                    .map(|principal| {
+                        (principal.subject.clone(), AuthScopes::from_config(&principal.scopes))
+                    })
+                    .collect()
+            })
+            .unwrap_or_default();
+        let jwt = config
+            .and_then(|cfg| cfg.jwt.as_ref())
+            .map(|jwt| JwtValidator::new(jwt).map_err(|err| err.to_string()));
         Self {
             mode,
             bearer_tokens,
             mtls_subjects,
             allowed_tools,
+            principal_scopes,
+            jwt,
         }
     }
 
@@ -362,6 +569,33 @@ impl DefaultToolAuthz {
     pub const fn mode(&self) -> ServerAuthMode {
         self.mode
     }
+
+    /// Authorizes a bearer credential as a static token or, when configured,
+    /// a signed JWT.
+    async fn authorize_bearer_credential(
+        &self,
+        ctx: &RequestContext,
+    ) -> Result<AuthContext, AuthError> {
+        let Some(jwt) = &self.jwt else {
+            return authorize_bearer(ctx, &self.bearer_tokens);
+        };
+        let token = parse_bearer_token(ctx.auth_header.as_deref())?;
+        if self.bearer_tokens.contains(&token) {
+            return authorize_bearer(ctx, &self.bearer_tokens);
+        }
+        let validator = jwt
+            .as_ref()
+            .map_err(|_| AuthError::Unauthenticated("jwt validation unavailable".to_string()))?;
+        let claims = validator.validate(&token).await?;
+        let digest = hash_bytes(HashAlgorithm::Sha256, token.as_bytes());
+        let scopes = AuthScopes::from_jwt_scopes(&claims.scopes);
+        Ok(AuthContext {
+            method: AuthMethod::BearerToken,
+            subject: Some(claims.subject),
+            token_fingerprint: Some(digest.value),
+            scopes,
+        })
+    }
 }
 
 #[async_trait]
@@ -371,18 +605,12 @@ impl ToolAuthz for DefaultToolAuthz {
         ctx: &RequestContext,
         action: AuthAction<'_>,
     ) -> Result<AuthContext, AuthError> {
-        let mut auth = match self.mode {
-            ServerAuthMode::LocalOnly => authorize_local_only(ctx)?,
-            ServerAuthMode::BearerToken => authorize_bearer(ctx, &self.bearer_tokens)?,
-            ServerAuthMode::Mtls => authorize_mtls(ctx, &self.mtls_subjects)?,
+        let authenticated = match self.mode {
+            ServerAuthMode::LocalOnly => authorize_local_only(ctx),
+            ServerAuthMode::BearerToken => self.authorize_bearer_credential(ctx).await,
+            ServerAuthMode::Mtls => authorize_mtls(ctx, &self.mtls_subjects),
         };
-
-        if let AuthAction::CallTool(tool) = action
-            && let Some(allowed) = &self.allowed_tools
-            && !allowed.contains(tool)
-        {
-            return Err(AuthError::Unauthorized("tool not authorized".to_string()));
-        }
+        let mut auth = authz_trace::observe(AuthzLayer::AuthMode, authenticated)?;
 
         if auth.subject.is_none() && matches!(auth.method, AuthMethod::Local) {
             auth.subject = Some(match ctx.transport {
@@ -391,6 +619,22 @@ impl ToolAuthz for DefaultToolAuthz {
             });
         }
 
+        if let Some(scopes) = self.principal_scopes.get(&auth.principal_id()) {
+            auth.scopes = scopes.clone();
+        }
+        if let Some(allowed) = &self.allowed_tools {
+            auth.scopes.restrict_tools(allowed);
+        }
+        if let AuthAction::CallTool(tool) = action {
+            let permitted = match &self.allowed_tools {
+                Some(allowed) if !allowed.contains(tool) => {
+                    Err(AuthError::Unauthorized("tool not authorized".to_string()))
+                }
+                _ => auth.scopes.check_tool(*tool),
+            };
+            authz_trace::observe(AuthzLayer::ToolScope, permitted)?;
+        }
+
         Ok(auth)
     }
 }
@@ -515,6 +759,7 @@ fn authorize_local_only(ctx: &RequestContext) -> Result<AuthContext, AuthError>
             method: AuthMethod::Local,
             subject: Some("stdio".to_string()),
             token_fingerprint: None,
+            scopes: AuthScopes::default(),
         }),
         ServerTransport::Http | ServerTransport::Sse => {
             if ctx.peer_is_loopback() {
@@ -522,6 +767,7 @@ fn authorize_local_only(ctx: &RequestContext) -> Result<AuthContext, AuthError>
                     method: AuthMethod::Local,
                     subject: Some("loopback".to_string()),
                     token_fingerprint: None,
+                    scopes: AuthScopes::default(),
                 })
             } else {
                 Err(AuthError::Unauthenticated(
@@ -546,6 +792,7 @@ fn authorize_bearer(
         method: AuthMethod::BearerToken,
         subject: None,
         token_fingerprint: Some(digest.value),
+        scopes: AuthScopes::default(),
     })
 }
 
@@ -565,6 +812,7 @@ fn authorize_mtls(
         method: AuthMethod::MtlsSubject,
         subject: Some(subject.to_string()),
         token_fingerprint: None,
+        scopes: AuthScopes::default(),
     })
 }
 
@@ -0,0 +1,155 @@
+// crates/decision-gate-mcp/src/authz_trace.rs
+// ============================================================================
+// Module: Authorization Decision Trace
+// Description: Per-request record of authorization layer verdicts.
+// Purpose: Explain which layer allowed or denied a tool call in audit logs.
+// Dependencies: decision-gate-core, serde, tokio
+// ============================================================================
+
+//! ## Overview
+//! Tool calls pass through several authorization layers (auth mode, tool
+//! scope, tenant authz, namespace authority, registry ACL, usage quotas). This
+//! module collects each layer's verdict for the duration of a single tool call
+//! so the router can emit one decision trace naming the first denier.
+//! Recording outside an active trace scope is a no-op.
+//! Security posture: trace reasons are derived from internal decisions and
+//! never include credentials; see `Docs/security/threat_model.md`.
+
+// ============================================================================
+// SECTION: Imports
+// ============================================================================
+
+use std::cell::RefCell;
+use std::fmt::Display;
+use std::future::Future;
+
+use decision_gate_core::NamespaceId;
+use decision_gate_core::TenantId;
+use serde::Serialize;
+
+// ============================================================================
+// SECTION: Types
+// ============================================================================
+
+/// Authorization layer evaluated for a tool call.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum AuthzLayer {
+    /// Transport authentication (local-only, bearer, mTLS).
+    AuthMode,
+    /// Tool allowlists, principal scopes, and tool visibility.
+    ToolScope,
+    /// Tenant authorization (principal scopes and tenant authorizer).
+    TenantAuthz,
+    /// Namespace policy and namespace authority checks.
+    NamespaceAuthority,
+    /// Schema registry ACL checks.
+    RegistryAcl,
+    /// Usage metering and quota checks.
+    Usage,
+}
+
+/// Verdict recorded for a single authorization layer.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct AuthzLayerVerdict {
+    /// Layer that produced the verdict.
+    pub layer: AuthzLayer,
+    /// Whether the layer allowed the request.
+    pub allowed: bool,
+    /// Denial reason when the layer denied the request.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub reason: Option<String>,
+}
+
+/// Authorization decision trace collected for one tool call.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct AuthzTrace {
+    /// Layer verdicts in evaluation order.
+    pub layers: Vec<AuthzLayerVerdict>,
+    /// Principal identifier once authentication succeeds.
+    pub principal_id: Option<String>,
+    /// Tenant identifier evaluated by tenant-scoped layers.
+    pub tenant_id: Option<String>,
+    /// Namespace identifier evaluated by namespace-scoped layers.
+    pub namespace_id: Option<String>,
+}
+
+impl AuthzTrace {
+    /// Returns the first layer verdict that denied the request.
+    #[must_use]
+    pub fn first_denier(&self) -> Option<&AuthzLayerVerdict> {
+        self.layers.iter().find(|verdict| !verdict.allowed)
+    }
+
+    /// Returns true when every recorded layer allowed the request.
+    #[must_use]
+    pub fn allowed(&self) -> bool {
+        self.first_denier().is_none()
+    }
+}
+
+// ============================================================================
+// SECTION: Recording
+// ============================================================================
+
+tokio::task_local! {
+    /// Trace collected for the tool call running on the current task.
+    static AUTHZ_TRACE: RefCell<AuthzTrace>;
+}
+
+/// Runs `future` inside a fresh trace scope and returns its output with the trace.
+pub(crate) async fn scope<F: Future>(future: F) -> (F::Output, AuthzTrace) {
+    AUTHZ_TRACE
+        .scope(RefCell::new(AuthzTrace::default()), async {
+            let output = future.await;
+            let trace = AUTHZ_TRACE.try_with(RefCell::take).unwrap_or_default();
+            (output, trace)
+        })
+        .await
+}
+
+/// Applies `update` to the active trace, if any.
+fn with_trace(update: impl FnOnce(&mut AuthzTrace)) {
+    let _ = AUTHZ_TRACE.try_with(|trace| update(&mut trace.borrow_mut()));
+}
+
+/// Records a layer verdict derived from `result` and returns it unchanged.
+pub(crate) fn observe<T, E: Display>(layer: AuthzLayer, result: Result<T, E>) -> Result<T, E> {
+    record(layer, result.as_ref().err().map(ToString::to_string));
+    result
+}
+
+/// Records a layer verdict; `denial` carries the reason when the layer denied.
+pub(crate) fn record(layer: AuthzLayer, denial: Option<String>) {
+    with_trace(|trace| {
+        trace.layers.push(AuthzLayerVerdict {
+            layer,
+            allowed: denial.is_none(),
+            reason: denial,
+        });
+    });
+}
+
+/// Returns true when the active trace already holds a verdict for `layer`.
+pub(crate) fn has_layer(layer: AuthzLayer) -> bool {
+    AUTHZ_TRACE
+        .try_with(|trace| trace.borrow().layers.iter().any(|verdict| verdict.layer == layer))
+        .unwrap_or(false)
+}
+
+/// Records the authenticated principal for the active trace.
+pub(crate) fn record_principal(principal_id: String) {
+    with_trace(|trace| trace.principal_id = Some(principal_id));
+}
+
+/// Records the tenant and namespace targeted by the active trace.
+pub(crate) fn record_target(tenant_id: Option<&TenantId>, namespace_id: Option<&NamespaceId>) {
+    with_trace(|trace| {
+        if let Some(tenant_id) = tenant_id {
+            trace.tenant_id = Some(tenant_id.to_string());
+        }
+        if let Some(namespace_id) = namespace_id {
+            trace.namespace_id = Some(namespace_id.to_string());
+        }
+    });
+}
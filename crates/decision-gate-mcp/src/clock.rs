@@ -0,0 +1,16 @@
+// crates/decision-gate-mcp/src/clock.rs
+// ============================================================================
+// Module: Clock Abstraction
+// Description: Injectable time source for caches, expiry, and rate limits.
+// Purpose: Let tests drive time-dependent behavior deterministically.
+// Dependencies: decision-gate-core
+// ============================================================================
+
+//! ## Overview
+//! Re-exports the core [`Clock`] so server components and the control plane
+//! share one time source. Production code uses [`SystemClock`]; tests inject a
+//! [`ManualClock`] and advance it explicitly instead of sleeping.
+
+pub use decision_gate_core::runtime::Clock;
+pub use decision_gate_core::runtime::ManualClock;
+pub use decision_gate_core::runtime::SystemClock;
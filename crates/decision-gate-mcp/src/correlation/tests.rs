@@ -94,3 +94,17 @@ fn generator_issues_formatted_ids() {
     assert!(parts[1].chars().all(|ch| ch.is_ascii_hexdigit()));
     assert!(parts[2].chars().all(|ch| ch.is_ascii_hexdigit()));
 }
+
+#[test]
+fn generator_sequence_is_deterministic_within_boot() {
+    let generator = CorrelationIdGenerator::new("dg");
+    let ids: Vec<String> = (0 .. 3).map(|_| generator.issue()).collect();
+    let boot_ids: Vec<&str> = ids.iter().filter_map(|id| id.split('-').nth(1)).collect();
+    assert!(boot_ids.windows(2).all(|pair| pair[0] == pair[1]));
+    let sequence: Vec<u64> = ids
+        .iter()
+        .filter_map(|id| id.split('-').nth(2))
+        .map(|seq| u64::from_str_radix(seq, 16).expect("hex sequence"))
+        .collect();
+    assert_eq!(sequence, vec![1, 2, 3]);
+}
@@ -9,7 +9,8 @@
 //! ## Overview
 //! The federated evidence provider routes evidence queries to built-in providers
 //! or external MCP providers. It enforces trust policies and signature
-//! verification. Security posture: inputs are untrusted and must be validated;
+//! verification, and reports per-provider query counts and latencies through
+//! [`McpMetrics`]. Security posture: inputs are untrusted and must be validated;
 //! see `Docs/security/threat_model.md`.
 
 // ============================================================================
@@ -31,6 +32,7 @@ use std::sync::Mutex;
 use std::sync::atomic::AtomicU64;
 use std::sync::atomic::Ordering;
 use std::time::Duration;
+use std::time::Instant;
 
 use base64::Engine;
 use base64::engine::general_purpose::STANDARD as Base64;
@@ -60,6 +62,11 @@ use crate::config::ProviderConfig;
 use crate::config::ProviderType;
 use crate::config::TrustPolicy;
 use crate::correlation::sanitize_client_correlation_id;
+use crate::telemetry::McpMetrics;
+use crate::telemetry::McpOutcome;
+use crate::telemetry::NoopMetrics;
+use crate::telemetry::ProviderMetricEvent;
+use crate::telemetry::UNREGISTERED_PROVIDER_LABEL;
 
 // ============================================================================
 // SECTION: Constants
@@ -96,10 +103,13 @@ pub struct ProviderClientConfig {
 ///
 /// # Invariants
 /// - Provider registry state is shared and synchronized via the inner Arc.
+/// - Every query emits exactly one provider counter and latency observation.
 #[derive(Clone)]
 pub struct FederatedEvidenceProvider {
     /// Shared registry and policy state.
     inner: Arc<FederatedInner>,
+    /// Metrics sink for per-provider query telemetry.
+    metrics: Arc<dyn McpMetrics>,
 }
 
 // ============================================================================
@@ -151,6 +161,7 @@ impl FederatedEvidenceProvider {
         let mut registry =
             ProviderRegistry::new(decision_gate_providers::ProviderAccessPolicy::default());
         let mut policies = BTreeMap::new();
+        registry.set_default_timeout(config.evidence.query_timeout_ms.map(Duration::from_millis));
 
         let default_policy = ProviderPolicy {
             trust: parse_trust_policy(&config.trust.default_policy)?,
@@ -181,6 +192,10 @@ impl FederatedEvidenceProvider {
                     );
                 }
             }
+            if let Some(timeout_ms) = provider.timeouts.query_timeout_ms {
+                registry
+                    .set_provider_timeout(provider.name.clone(), Duration::from_millis(timeout_ms));
+            }
         }
 
         Ok(Self {
@@ -189,9 +204,17 @@ impl FederatedEvidenceProvider {
                 policies,
                 default_policy,
             }),
+            metrics: Arc::new(NoopMetrics),
         })
     }
 
+    /// Returns a provider that reports query telemetry to `metrics`.
+    #[must_use]
+    pub fn with_metrics(mut self, metrics: Arc<dyn McpMetrics>) -> Self {
+        self.metrics = metrics;
+        self
+    }
+
     /// Returns true if the provider allows raw values to be disclosed.
     #[must_use]
     pub fn provider_allows_raw(&self, provider_id: &str) -> bool {
@@ -208,17 +231,53 @@ impl EvidenceProvider for FederatedEvidenceProvider {
         query: &EvidenceQuery,
         ctx: &EvidenceContext,
     ) -> Result<EvidenceResult, EvidenceError> {
-        let _ = sanitize_context_correlation_id(ctx)?;
+        let started = Instant::now();
+        let outcome = self.query_with_policy(query, ctx);
+        let error_kind = match &outcome {
+            Ok(result) if result.error.is_some() => Some("provider_result"),
+            Ok(_) => None,
+            Err((kind, _)) => Some(*kind),
+        };
         let provider_id = query.provider_id.as_str();
-        let policy = self.inner.policies.get(provider_id).unwrap_or(&self.inner.default_policy);
-        let mut result = self.inner.registry.query(query, ctx)?;
-        apply_signature_policy(&policy.trust, &mut result)?;
-        Ok(result)
+        let event = ProviderMetricEvent {
+            provider_id: if self.inner.policies.contains_key(provider_id) {
+                provider_id.to_string()
+            } else {
+                UNREGISTERED_PROVIDER_LABEL.to_string()
+            },
+            outcome: if error_kind.is_some() { McpOutcome::Error } else { McpOutcome::Ok },
+            error_kind,
+            cache_hit: None,
+        };
+        self.metrics.record_provider_query(event.clone());
+        self.metrics.record_provider_latency(event, started.elapsed());
+        outcome.map_err(|(_, err)| err)
     }
 
     fn validate_providers(&self, spec: &ScenarioSpec) -> Result<(), ProviderMissingError> {
         self.inner.registry.validate_providers(spec)
     }
+
+    fn warm_up(&self) -> Result<(), EvidenceError> {
+        self.inner.registry.warm_up()
+    }
+}
+
+impl FederatedEvidenceProvider {
+    /// Routes a query and applies trust policy, tagging failures with a metric kind.
+    fn query_with_policy(
+        &self,
+        query: &EvidenceQuery,
+        ctx: &EvidenceContext,
+    ) -> Result<EvidenceResult, (&'static str, EvidenceError)> {
+        sanitize_context_correlation_id(ctx).map_err(|err| ("invalid_context", err))?;
+        let provider_id = query.provider_id.as_str();
+        let policy = self.inner.policies.get(provider_id).unwrap_or(&self.inner.default_policy);
+        let mut result =
+            self.inner.registry.query(query, ctx).map_err(|err| ("provider_error", err))?;
+        apply_signature_policy(&policy.trust, &mut result).map_err(|err| ("trust_policy", err))?;
+        Ok(result)
+    }
 }
 
 // ============================================================================
@@ -353,6 +412,28 @@ impl EvidenceProvider for McpProviderClient {
     fn validate_providers(&self, _spec: &ScenarioSpec) -> Result<(), ProviderMissingError> {
         Ok(())
     }
+
+    /// Confirms stdio provider processes are still running.
+    ///
+    /// HTTP providers are not contacted; their clients are built at startup.
+    fn warm_up(&self) -> Result<(), EvidenceError> {
+        let McpTransport::Stdio {
+            process,
+        } = &self.transport
+        else {
+            return Ok(());
+        };
+        let mut process = process
+            .lock()
+            .map_err(|_| EvidenceError::Provider("mcp process lock poisoned".to_string()))?;
+        match process.child.try_wait() {
+            Ok(None) => Ok(()),
+            Ok(Some(_)) => Err(EvidenceError::Provider("mcp provider process exited".to_string())),
+            Err(_) => {
+                Err(EvidenceError::Provider("mcp provider process status unavailable".to_string()))
+            }
+        }
+    }
 }
 
 // ============================================================================
@@ -789,6 +870,27 @@ fn register_builtin_provider(
             let provider = decision_gate_providers::HttpProvider::new(config)?;
             registry.register_provider("http", provider)?;
         }
+        "sqlite" => {
+            let config = provider
+                .parse_config::<decision_gate_providers::SqliteQueryProviderConfig>()
+                .map_err(|err| EvidenceError::Provider(err.to_string()))?;
+            let provider = decision_gate_providers::SqliteQueryProvider::new(config)?;
+            registry.register_provider("sqlite", provider)?;
+        }
+        #[cfg(feature = "postgres")]
+        "postgres" => {
+            let config = provider
+                .parse_config::<decision_gate_providers::PostgresProviderConfig>()
+                .map_err(|err| EvidenceError::Provider(err.to_string()))?;
+            let provider = decision_gate_providers::PostgresProvider::new(config)?;
+            registry.register_provider("postgres", provider)?;
+        }
+        #[cfg(not(feature = "postgres"))]
+        "postgres" => {
+            return Err(EvidenceError::Provider(
+                "postgres provider requires the postgres feature".to_string(),
+            ));
+        }
         _ => {
             return Err(EvidenceError::Provider(format!(
                 "unknown builtin provider: {}",
@@ -38,6 +38,7 @@ use std::io::BufReader;
 use std::io::Cursor;
 use std::sync::Arc;
 use std::sync::Mutex;
+use std::time::Duration;
 
 use axum::Router;
 use axum::http::HeaderMap;
@@ -45,6 +46,7 @@ use axum::http::StatusCode;
 use axum::routing::post;
 use decision_gate_core::CorrelationId;
 use decision_gate_core::EvidenceContext;
+use decision_gate_core::EvidenceError;
 use decision_gate_core::EvidenceProvider;
 use decision_gate_core::EvidenceQuery;
 use decision_gate_core::EvidenceResult;
@@ -62,12 +64,19 @@ use decision_gate_core::TriggerId;
 use decision_gate_core::TrustLane;
 use decision_gate_core::hashing::canonical_json_bytes;
 use decision_gate_core::hashing::hash_bytes;
+use decision_gate_providers::EnvProvider;
+use decision_gate_providers::EnvProviderConfig;
+use decision_gate_providers::ProviderAccessPolicy;
+use decision_gate_providers::ProviderRegistry;
 use ed25519_dalek::Signer;
 use serde_json::json;
 use tokio::sync::oneshot;
 
+use super::FederatedEvidenceProvider;
+use super::FederatedInner;
 use super::McpProviderClient;
 use super::McpTransport;
+use super::ProviderPolicy;
 use super::ProviderTrust;
 use super::apply_signature_policy;
 use super::ensure_evidence_hash;
@@ -79,6 +88,11 @@ use crate::config::ProviderTimeoutConfig;
 use crate::config::ProviderType;
 use crate::config::TrustPolicy;
 use crate::correlation::sanitize_client_correlation_id;
+use crate::telemetry::McpMetricEvent;
+use crate::telemetry::McpMetrics;
+use crate::telemetry::McpOutcome;
+use crate::telemetry::ProviderMetricEvent;
+use crate::telemetry::UNREGISTERED_PROVIDER_LABEL;
 
 // ============================================================================
 // SECTION: Tests
@@ -290,10 +304,125 @@ fn signature_policy_rejects_missing_signature() {
     assert!(err.to_string().contains("missing evidence signature"));
 }
 
+#[test]
+fn provider_query_records_latency_sample() {
+    let metrics = Arc::new(ProviderMetrics::default());
+    let provider = federated_with_metrics(metrics.clone());
+    let query = EvidenceQuery {
+        provider_id: ProviderId::new("env"),
+        check_id: "get".to_string(),
+        params: Some(json!({"key": "DG_METRICS_KEY"})),
+    };
+    provider.query(&query, &sample_context(None)).expect("env query");
+
+    let events = metrics.events.lock().expect("events lock").clone();
+    assert_eq!(events.len(), 1);
+    assert_eq!(events[0].provider_id, "env");
+    assert_eq!(events[0].outcome, McpOutcome::Ok);
+    assert_eq!(events[0].error_kind, None);
+    let latencies = metrics.latencies.lock().expect("latencies lock").clone();
+    assert_eq!(latencies.len(), 1);
+    assert_eq!(latencies[0].0.provider_id, "env");
+}
+
+#[test]
+fn provider_query_failure_increments_error_counter() {
+    let metrics = Arc::new(ProviderMetrics::default());
+    let provider = federated_with_metrics(metrics.clone());
+    let failing = EvidenceQuery {
+        provider_id: ProviderId::new("flaky"),
+        check_id: "read".to_string(),
+        params: None,
+    };
+    provider.query(&failing, &sample_context(None)).expect_err("flaky query");
+    let unknown = EvidenceQuery {
+        provider_id: ProviderId::new("attacker-controlled-label"),
+        check_id: "read".to_string(),
+        params: None,
+    };
+    provider.query(&unknown, &sample_context(None)).expect_err("unregistered query");
+
+    let events = metrics.events.lock().expect("events lock").clone();
+    let errors: Vec<_> = events.iter().filter(|event| event.outcome == McpOutcome::Error).collect();
+    assert_eq!(errors.len(), 2);
+    assert_eq!(errors[0].provider_id, "flaky");
+    assert_eq!(errors[0].error_kind, Some("provider_error"));
+    assert_eq!(errors[1].provider_id, UNREGISTERED_PROVIDER_LABEL);
+    assert_eq!(metrics.latencies.lock().expect("latencies lock").len(), 2);
+}
+
 // ============================================================================
 // SECTION: Fixtures
 // ============================================================================
 
+/// Metrics sink capturing provider query telemetry.
+#[derive(Default)]
+struct ProviderMetrics {
+    events: Mutex<Vec<ProviderMetricEvent>>,
+    latencies: Mutex<Vec<(ProviderMetricEvent, Duration)>>,
+}
+
+impl McpMetrics for ProviderMetrics {
+    fn record_request(&self, _event: McpMetricEvent) {}
+
+    fn record_latency(&self, _event: McpMetricEvent, _latency: Duration) {}
+
+    fn record_provider_query(&self, event: ProviderMetricEvent) {
+        self.events.lock().expect("events lock").push(event);
+    }
+
+    fn record_provider_latency(&self, event: ProviderMetricEvent, latency: Duration) {
+        self.latencies.lock().expect("latencies lock").push((event, latency));
+    }
+}
+
+/// Provider that always fails.
+struct FlakyProvider;
+
+impl EvidenceProvider for FlakyProvider {
+    fn query(
+        &self,
+        _query: &EvidenceQuery,
+        _ctx: &EvidenceContext,
+    ) -> Result<EvidenceResult, EvidenceError> {
+        Err(EvidenceError::Provider("flaky".to_string()))
+    }
+
+    fn validate_providers(
+        &self,
+        _spec: &decision_gate_core::ScenarioSpec,
+    ) -> Result<(), decision_gate_core::ProviderMissingError> {
+        Ok(())
+    }
+}
+
+/// Builds a federated provider with `env` and `flaky` providers and a metrics sink.
+fn federated_with_metrics(metrics: Arc<dyn McpMetrics>) -> FederatedEvidenceProvider {
+    let mut registry = ProviderRegistry::new(ProviderAccessPolicy::allow_all());
+    let env = EnvProviderConfig {
+        overrides: Some(BTreeMap::from([("DG_METRICS_KEY".to_string(), "1".to_string())])),
+        ..EnvProviderConfig::default()
+    };
+    registry.register_provider("env", EnvProvider::new(env)).expect("register env");
+    registry.register_provider("flaky", FlakyProvider).expect("register flaky");
+    let policy = ProviderPolicy {
+        trust: ProviderTrust::Audit,
+        allow_raw: false,
+    };
+    let policies = BTreeMap::from([
+        ("env".to_string(), policy.clone()),
+        ("flaky".to_string(), policy.clone()),
+    ]);
+    FederatedEvidenceProvider {
+        inner: Arc::new(FederatedInner {
+            registry,
+            policies,
+            default_policy: policy,
+        }),
+        metrics,
+    }
+}
+
 fn base_provider_config() -> ProviderConfig {
     ProviderConfig {
         name: "mcp-test".to_string(),
@@ -0,0 +1,575 @@
+// crates/decision-gate-mcp/src/jwt.rs
+// ============================================================================
+// Module: MCP JWT Validation
+// Description: JWT bearer validation against a cached JWKS.
+// Purpose: Authenticate signed bearer tokens issued by an external IdP.
+// Dependencies: aws-lc-rs, base64, reqwest, serde_json
+// ============================================================================
+
+//! ## Overview
+//! [`JwtValidator`] verifies compact JWS bearer tokens (RS256/ES256) against
+//! signing keys fetched from an allowlisted JWKS endpoint. Keys are cached for
+//! `jwks_cache_ttl_ms` and refetched early when a token names an unknown `kid`
+//! (key rotation), at most once per [`JWKS_MIN_REFRESH_INTERVAL`].
+//!
+//! Invariants:
+//! - Signature, `iss`, `aud`, `exp`, and `nbf` are all checked; any failure rejects.
+//! - `alg: none` and algorithms outside the configured set are rejected.
+//! - JWKS fetches are host-allowlisted, time-bounded, size-bounded, and never follow redirects.
+//!
+//! Security posture: bearer tokens and JWKS responses are untrusted input; see
+//! `Docs/security/threat_model.md`.
+
+// ============================================================================
+// SECTION: Imports
+// ============================================================================
+
+use std::collections::BTreeSet;
+use std::sync::Arc;
+use std::time::Duration;
+use std::time::Instant;
+
+use aws_lc_rs::signature;
+use aws_lc_rs::signature::RsaPublicKeyComponents;
+use aws_lc_rs::signature::UnparsedPublicKey;
+use base64::Engine;
+use base64::engine::general_purpose::URL_SAFE_NO_PAD;
+use reqwest::Client;
+use reqwest::Url;
+use serde::Deserialize;
+use serde::de::DeserializeOwned;
+use thiserror::Error;
+use tokio::sync::Mutex;
+
+use crate::auth::AuthError;
+use crate::clock::Clock;
+use crate::clock::SystemClock;
+use crate::config::JwtAlgorithm;
+use crate::config::JwtAuthConfig;
+
+// ============================================================================
+// SECTION: Constants
+// ============================================================================
+
+/// Minimum interval between JWKS refreshes triggered by unknown key ids.
+pub const JWKS_MIN_REFRESH_INTERVAL: Duration = Duration::from_secs(30);
+/// Maximum accepted JWKS response size in bytes.
+const MAX_JWKS_BYTES: usize = 256 * 1024;
+/// Maximum number of keys accepted from a JWKS document.
+const MAX_JWKS_KEYS: usize = 64;
+/// Maximum length of the `sub` claim.
+const MAX_JWT_SUBJECT_LENGTH: usize = 512;
+
+// ============================================================================
+// SECTION: Types
+// ============================================================================
+
+/// Verified JWT claims used to build an auth context.
+///
+/// # Invariants
+/// - Produced only after signature, issuer, audience, and expiry checks pass.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct JwtClaims {
+    /// Token issuer (`iss`).
+    pub issuer: String,
+    /// Token subject (`sub`).
+    pub subject: String,
+    /// Token audiences (`aud`).
+    pub audience: Vec<String>,
+    /// Expiry as unix seconds (`exp`).
+    pub expires_at: u64,
+    /// Space-delimited OAuth scopes (`scope`), if present.
+    pub scopes: Vec<String>,
+}
+
+/// JWT validation failures.
+///
+/// # Invariants
+/// - Messages never include token contents or key material.
+#[derive(Debug, Error, PartialEq, Eq)]
+pub enum JwtError {
+    /// Token is not a well-formed compact JWS.
+    #[error("malformed jwt: {0}")]
+    Malformed(String),
+    /// Token `alg` is not in the accepted set.
+    #[error("unsupported jwt algorithm: {0}")]
+    UnsupportedAlgorithm(String),
+    /// No JWKS key matches the token `kid` and algorithm.
+    #[error("no matching jwks signing key")]
+    UnknownKey,
+    /// Signature verification failed.
+    #[error("jwt signature invalid")]
+    InvalidSignature,
+    /// Token `iss` does not match the configured issuer.
+    #[error("jwt issuer mismatch")]
+    InvalidIssuer,
+    /// Token `aud` does not include the configured audience.
+    #[error("jwt audience mismatch")]
+    InvalidAudience,
+    /// Token `exp` is in the past.
+    #[error("jwt expired")]
+    Expired,
+    /// Token `nbf` is in the future.
+    #[error("jwt not yet valid")]
+    NotYetValid,
+    /// Token `sub` is missing or invalid.
+    #[error("jwt subject missing or invalid")]
+    InvalidSubject,
+    /// JWKS endpoint or document failure.
+    #[error("jwks unavailable: {0}")]
+    Jwks(String),
+}
+
+impl From<JwtError> for AuthError {
+    fn from(error: JwtError) -> Self {
+        Self::Unauthenticated(error.to_string())
+    }
+}
+
+/// Validates JWT bearer tokens against a cached JWKS.
+///
+/// # Invariants
+/// - `jwks_url` is https (or explicitly allowed http) and its host is allowlisted.
+/// - Cached keys are never used past `cache_ttl`.
+pub struct JwtValidator {
+    /// Required issuer.
+    issuer: String,
+    /// Required audience.
+    audience: String,
+    /// Accepted signature algorithms.
+    algorithms: BTreeSet<JwtAlgorithm>,
+    /// Tolerated clock skew in seconds.
+    clock_skew_secs: u64,
+    /// JWKS endpoint.
+    jwks_url: Url,
+    /// HTTP client with timeouts and redirects disabled.
+    client: Client,
+    /// JWKS cache lifetime.
+    cache_ttl: Duration,
+    /// Cached signing keys.
+    cache: Mutex<Option<JwksCache>>,
+    /// Time source for cache age and claim expiry.
+    clock: Arc<dyn Clock>,
+}
+
+/// Cached JWKS document.
+struct JwksCache {
+    /// Parsed signing keys.
+    keys: Vec<SigningKey>,
+    /// Fetch timestamp.
+    fetched_at: Instant,
+}
+
+/// Signing key parsed from a JWK.
+#[derive(Debug, Clone)]
+struct SigningKey {
+    /// Optional key identifier.
+    kid: Option<String>,
+    /// Optional algorithm pin declared by the JWK.
+    alg: Option<String>,
+    /// Public key material.
+    material: KeyMaterial,
+}
+
+/// Public key material for supported key types.
+#[derive(Debug, Clone)]
+enum KeyMaterial {
+    /// RSA modulus and exponent (big-endian).
+    Rsa {
+        /// Modulus bytes.
+        n: Vec<u8>,
+        /// Exponent bytes.
+        e: Vec<u8>,
+    },
+    /// Uncompressed P-256 point (`0x04 || x || y`).
+    EcP256(Vec<u8>),
+}
+
+impl KeyMaterial {
+    /// Returns true when the key type matches the algorithm.
+    const fn supports(&self, alg: JwtAlgorithm) -> bool {
+        matches!(
+            (self, alg),
+            (Self::Rsa { .. }, JwtAlgorithm::Rs256) | (Self::EcP256(_), JwtAlgorithm::Es256)
+        )
+    }
+
+    /// Verifies a signature over `message`.
+    fn verify(&self, alg: JwtAlgorithm, message: &[u8], sig: &[u8]) -> Result<(), JwtError> {
+        let result = match (self, alg) {
+            (
+                Self::Rsa {
+                    n,
+                    e,
+                },
+                JwtAlgorithm::Rs256,
+            ) => RsaPublicKeyComponents {
+                n: n.as_slice(),
+                e: e.as_slice(),
+            }
+            .verify(&signature::RSA_PKCS1_2048_8192_SHA256, message, sig),
+            (Self::EcP256(point), JwtAlgorithm::Es256) => {
+                UnparsedPublicKey::new(&signature::ECDSA_P256_SHA256_FIXED, point)
+                    .verify(message, sig)
+            }
+            _ => return Err(JwtError::UnknownKey),
+        };
+        result.map_err(|_| JwtError::InvalidSignature)
+    }
+}
+
+/// JOSE header fields used for key selection.
+#[derive(Deserialize)]
+struct JwtHeader {
+    /// Signature algorithm.
+    alg: String,
+    /// Optional key identifier.
+    #[serde(default)]
+    kid: Option<String>,
+}
+
+/// Registered claims as received, before validation.
+#[derive(Deserialize)]
+struct RawClaims {
+    /// Issuer.
+    #[serde(default)]
+    iss: Option<String>,
+    /// Subject.
+    #[serde(default)]
+    sub: Option<String>,
+    /// Audience (string or array).
+    #[serde(default)]
+    aud: Option<RawAudience>,
+    /// Expiry (unix seconds).
+    #[serde(default)]
+    exp: Option<u64>,
+    /// Not-before (unix seconds).
+    #[serde(default)]
+    nbf: Option<u64>,
+    /// Space-delimited scopes.
+    #[serde(default)]
+    scope: Option<String>,
+}
+
+/// `aud` claim encoding.
+#[derive(Deserialize)]
+#[serde(untagged)]
+enum RawAudience {
+    /// Single audience.
+    One(String),
+    /// Audience list.
+    Many(Vec<String>),
+}
+
+/// JWKS document envelope.
+#[derive(Deserialize)]
+struct JwkSet {
+    /// Raw keys; unsupported entries are skipped.
+    keys: Vec<serde_json::Value>,
+}
+
+/// JWK fields for RSA and EC public keys.
+#[derive(Deserialize)]
+struct Jwk {
+    /// Key type.
+    kty: String,
+    /// Optional key identifier.
+    #[serde(default)]
+    kid: Option<String>,
+    /// Optional algorithm pin.
+    #[serde(default)]
+    alg: Option<String>,
+    /// Optional key use.
+    #[serde(default, rename = "use")]
+    key_use: Option<String>,
+    /// RSA modulus.
+    #[serde(default)]
+    n: Option<String>,
+    /// RSA exponent.
+    #[serde(default)]
+    e: Option<String>,
+    /// EC curve.
+    #[serde(default)]
+    crv: Option<String>,
+    /// EC x coordinate.
+    #[serde(default)]
+    x: Option<String>,
+    /// EC y coordinate.
+    #[serde(default)]
+    y: Option<String>,
+}
+
+// ============================================================================
+// SECTION: Validator
+// ============================================================================
+
+impl JwtValidator {
+    /// Builds a validator from JWT auth configuration.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`JwtError::Jwks`] when the JWKS URL is not permitted or the
+    /// HTTP client cannot be built.
+    pub fn new(config: &JwtAuthConfig) -> Result<Self, JwtError> {
+        let jwks_url = Url::parse(config.jwks_url.trim())
+            .map_err(|_| JwtError::Jwks("jwks_url is invalid".to_string()))?;
+        match jwks_url.scheme() {
+            "https" => {}
+            "http" if config.allow_http => {}
+            _ => return Err(JwtError::Jwks("jwks_url scheme not permitted".to_string())),
+        }
+        let host = jwks_url.host_str().unwrap_or_default();
+        if !config.allowed_jwks_hosts.iter().any(|allowed| allowed.eq_ignore_ascii_case(host)) {
+            return Err(JwtError::Jwks("jwks_url host not allowlisted".to_string()));
+        }
+        let timeout = Duration::from_millis(config.jwks_timeout_ms);
+        let client = Client::builder()
+            .connect_timeout(timeout)
+            .timeout(timeout)
+            .redirect(reqwest::redirect::Policy::none())
+            .build()
+            .map_err(|_| JwtError::Jwks("http client build failed".to_string()))?;
+        Ok(Self {
+            issuer: config.issuer.clone(),
+            audience: config.audience.clone(),
+            algorithms: config.algorithms.iter().copied().collect(),
+            clock_skew_secs: config.clock_skew_secs,
+            jwks_url,
+            client,
+            cache_ttl: Duration::from_millis(config.jwks_cache_ttl_ms),
+            cache: Mutex::new(None),
+            clock: Arc::new(SystemClock),
+        })
+    }
+
+    /// Replaces the time source used for cache age and claim expiry.
+    #[must_use]
+    pub fn with_clock(mut self, clock: Arc<dyn Clock>) -> Self {
+        self.clock = clock;
+        self
+    }
+
+    /// Validates a compact JWS token and returns its verified claims.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`JwtError`] when the token is malformed, unsigned by a JWKS
+    /// key, or fails issuer, audience, expiry, or subject checks.
+    pub async fn validate(&self, token: &str) -> Result<JwtClaims, JwtError> {
+        let (signing_input, signature_b64) = token
+            .rsplit_once('.')
+            .ok_or_else(|| JwtError::Malformed("expected three segments".to_string()))?;
+        let (header_b64, payload_b64) = signing_input
+            .split_once('.')
+            .ok_or_else(|| JwtError::Malformed("expected three segments".to_string()))?;
+        if payload_b64.contains('.') {
+            return Err(JwtError::Malformed("expected three segments".to_string()));
+        }
+        let header: JwtHeader = decode_json(header_b64, "header")?;
+        let alg = self.algorithm(&header.alg)?;
+        let signature = decode_segment(signature_b64, "signature")?;
+        let key = self.signing_key(header.kid.as_deref(), alg).await?;
+        key.material.verify(alg, signing_input.as_bytes(), &signature)?;
+        let claims: RawClaims = decode_json(payload_b64, "payload")?;
+        self.check_claims(claims, self.clock.unix_secs())
+    }
+
+    /// Resolves the header `alg` against the accepted set.
+    fn algorithm(&self, alg: &str) -> Result<JwtAlgorithm, JwtError> {
+        self.algorithms
+            .iter()
+            .copied()
+            .find(|candidate| candidate.as_str() == alg)
+            .ok_or_else(|| JwtError::UnsupportedAlgorithm(alg.to_string()))
+    }
+
+    /// Checks registered claims against configuration and the current time.
+    fn check_claims(&self, claims: RawClaims, now: u64) -> Result<JwtClaims, JwtError> {
+        if claims.iss.as_deref() != Some(self.issuer.as_str()) {
+            return Err(JwtError::InvalidIssuer);
+        }
+        let audience = match claims.aud {
+            Some(RawAudience::One(value)) => vec![value],
+            Some(RawAudience::Many(values)) => values,
+            None => Vec::new(),
+        };
+        if !audience.iter().any(|value| value == &self.audience) {
+            return Err(JwtError::InvalidAudience);
+        }
+        let expires_at =
+            claims.exp.ok_or_else(|| JwtError::Malformed("missing exp claim".to_string()))?;
+        if now > expires_at.saturating_add(self.clock_skew_secs) {
+            return Err(JwtError::Expired);
+        }
+        if let Some(not_before) = claims.nbf
+            && not_before > now.saturating_add(self.clock_skew_secs)
+        {
+            return Err(JwtError::NotYetValid);
+        }
+        let subject = claims
+            .sub
+            .filter(|sub| !sub.trim().is_empty() && sub.len() <= MAX_JWT_SUBJECT_LENGTH)
+            .ok_or(JwtError::InvalidSubject)?;
+        let scopes = claims
+            .scope
+            .map(|scope| scope.split_whitespace().map(str::to_string).collect())
+            .unwrap_or_default();
+        Ok(JwtClaims {
+            issuer: self.issuer.clone(),
+            subject,
+            audience,
+            expires_at,
+            scopes,
+        })
+    }
+
+    /// Returns the signing key for a token, refreshing the JWKS when needed.
+    async fn signing_key(
+        &self,
+        kid: Option<&str>,
+        alg: JwtAlgorithm,
+    ) -> Result<SigningKey, JwtError> {
+        let mut cache = self.cache.lock().await;
+        let now = self.clock.now();
+        let expired = cache
+            .as_ref()
+            .is_none_or(|entry| now.saturating_duration_since(entry.fetched_at) >= self.cache_ttl);
+        if expired {
+            *cache = Some(self.fetch_jwks().await?);
+        }
+        if let Some(key) = cache.as_ref().and_then(|entry| select_key(&entry.keys, kid, alg)) {
+            return Ok(key);
+        }
+        let may_refresh = !expired
+            && cache.as_ref().is_some_and(|entry| {
+                now.saturating_duration_since(entry.fetched_at) >= JWKS_MIN_REFRESH_INTERVAL
+            });
+        if may_refresh {
+            *cache = Some(self.fetch_jwks().await?);
+            if let Some(key) = cache.as_ref().and_then(|entry| select_key(&entry.keys, kid, alg)) {
+                return Ok(key);
+            }
+        }
+        drop(cache);
+        Err(JwtError::UnknownKey)
+    }
+
+    /// Fetches and parses the JWKS document with size limits.
+    async fn fetch_jwks(&self) -> Result<JwksCache, JwtError> {
+        let mut response = self
+            .client
+            .get(self.jwks_url.clone())
+            .send()
+            .await
+            .map_err(|_| JwtError::Jwks("jwks fetch failed".to_string()))?;
+        if !response.status().is_success() {
+            return Err(JwtError::Jwks(format!(
+                "jwks fetch returned status {}",
+                response.status().as_u16()
+            )));
+        }
+        if response.content_length().is_some_and(|len| len > MAX_JWKS_BYTES as u64) {
+            return Err(JwtError::Jwks("jwks response exceeds size limit".to_string()));
+        }
+        let mut body = Vec::new();
+        while let Some(chunk) =
+            response.chunk().await.map_err(|_| JwtError::Jwks("jwks read failed".to_string()))?
+        {
+            if body.len() + chunk.len() > MAX_JWKS_BYTES {
+                return Err(JwtError::Jwks("jwks response exceeds size limit".to_string()));
+            }
+            body.extend_from_slice(&chunk);
+        }
+        let keys = parse_jwks(&body)?;
+        Ok(JwksCache {
+            keys,
+            fetched_at: self.clock.now(),
+        })
+    }
+}
+
+// ============================================================================
+// SECTION: Helpers
+// ============================================================================
+
+/// Parses a JWKS document into supported signing keys.
+fn parse_jwks(body: &[u8]) -> Result<Vec<SigningKey>, JwtError> {
+    let set: JwkSet = serde_json::from_slice(body)
+        .map_err(|_| JwtError::Jwks("jwks document invalid".to_string()))?;
+    if set.keys.len() > MAX_JWKS_KEYS {
+        return Err(JwtError::Jwks("jwks document has too many keys".to_string()));
+    }
+    Ok(set
+        .keys
+        .into_iter()
+        .filter_map(|value| serde_json::from_value::<Jwk>(value).ok())
+        .filter_map(signing_key_from_jwk)
+        .collect())
+}
+
+/// Converts a JWK into a signing key, skipping unsupported or non-signing keys.
+fn signing_key_from_jwk(jwk: Jwk) -> Option<SigningKey> {
+    if jwk.key_use.as_deref().is_some_and(|key_use| key_use != "sig") {
+        return None;
+    }
+    let material = match jwk.kty.as_str() {
+        "RSA" => KeyMaterial::Rsa {
+            n: URL_SAFE_NO_PAD.decode(jwk.n?).ok()?,
+            e: URL_SAFE_NO_PAD.decode(jwk.e?).ok()?,
+        },
+        "EC" if jwk.crv.as_deref() == Some("P-256") => {
+            let x = URL_SAFE_NO_PAD.decode(jwk.x?).ok()?;
+            let y = URL_SAFE_NO_PAD.decode(jwk.y?).ok()?;
+            if x.len() != 32 || y.len() != 32 {
+                return None;
+            }
+            let mut point = Vec::with_capacity(65);
+            point.push(0x04);
+            point.extend_from_slice(&x);
+            point.extend_from_slice(&y);
+            KeyMaterial::EcP256(point)
+        }
+        _ => return None,
+    };
+    Some(SigningKey {
+        kid: jwk.kid,
+        alg: jwk.alg,
+        material,
+    })
+}
+
+/// Selects the key matching `kid` (or the sole compatible key when absent).
+fn select_key(keys: &[SigningKey], kid: Option<&str>, alg: JwtAlgorithm) -> Option<SigningKey> {
+    let mut compatible = keys.iter().filter(|key| {
+        key.material.supports(alg) && key.alg.as_deref().is_none_or(|pinned| pinned == alg.as_str())
+    });
+    if let Some(kid) = kid {
+        return compatible.find(|key| key.kid.as_deref() == Some(kid)).cloned();
+    }
+    let first = compatible.next()?;
+    if compatible.next().is_some() {
+        return None;
+    }
+    Some(first.clone())
+}
+
+/// Decodes a base64url segment.
+fn decode_segment(segment: &str, label: &str) -> Result<Vec<u8>, JwtError> {
+    URL_SAFE_NO_PAD
+        .decode(segment)
+        .map_err(|_| JwtError::Malformed(format!("{label} is not base64url")))
+}
+
+/// Decodes a base64url JSON segment.
+fn decode_json<T: DeserializeOwned>(segment: &str, label: &str) -> Result<T, JwtError> {
+    let bytes = decode_segment(segment, label)?;
+    serde_json::from_slice(&bytes)
+        .map_err(|_| JwtError::Malformed(format!("{label} is not valid json")))
+}
+
+// ============================================================================
+// SECTION: Tests
+// ============================================================================
+
+#[cfg(test)]
+mod tests;
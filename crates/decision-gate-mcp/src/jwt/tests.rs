@@ -0,0 +1,454 @@
+// crates/decision-gate-mcp/src/jwt/tests.rs
+// ============================================================================
+// Module: JWT Validation Tests
+// Description: Unit tests for JWT bearer validation against a JWKS.
+// Purpose: Validate signature, issuer, audience, and expiry enforcement.
+// Dependencies: decision-gate-mcp, aws-lc-rs, axum
+// ============================================================================
+
+//! ## Overview
+//! Exercises [`JwtValidator`] with locally signed tokens and an in-memory JWKS
+//! server to validate claim extraction and fail-closed rejection paths.
+//!
+//! Security posture: tests cover rejection of expired, mis-addressed, and
+//! forged tokens; see `Docs/security/threat_model.md`.
+
+// ============================================================================
+// SECTION: Lint Configuration
+// ============================================================================
+
+#![allow(
+    clippy::expect_used,
+    clippy::unwrap_used,
+    clippy::panic,
+    clippy::panic_in_result_fn,
+    clippy::unwrap_in_result,
+    reason = "Test-only assertions use unwrap/expect for clarity."
+)]
+
+// ============================================================================
+// SECTION: Imports
+// ============================================================================
+
+use std::sync::Arc;
+use std::sync::atomic::AtomicUsize;
+use std::sync::atomic::Ordering;
+use std::time::Duration;
+
+use aws_lc_rs::rand::SystemRandom;
+use aws_lc_rs::rsa::KeySize;
+use aws_lc_rs::signature::EcdsaKeyPair;
+use aws_lc_rs::signature::KeyPair;
+use aws_lc_rs::signature::RsaKeyPair;
+use axum::Json;
+use axum::Router;
+use axum::extract::State;
+use axum::routing::get;
+use base64::Engine;
+use base64::engine::general_purpose::URL_SAFE_NO_PAD;
+use decision_gate_contract::ToolName;
+use decision_gate_core::TenantId;
+use serde_json::Value;
+use serde_json::json;
+use tokio::sync::oneshot;
+
+use super::JwtError;
+use super::JwtValidator;
+use super::RsaPublicKeyComponents;
+use crate::auth::AuthAction;
+use crate::auth::AuthContext;
+use crate::auth::AuthError;
+use crate::auth::AuthMethod;
+use crate::auth::DefaultToolAuthz;
+use crate::auth::RequestContext;
+use crate::auth::ToolAuthz;
+use crate::clock::Clock;
+use crate::clock::ManualClock;
+use crate::clock::SystemClock;
+use crate::config::JwtAlgorithm;
+use crate::config::JwtAuthConfig;
+use crate::config::PrincipalConfig;
+use crate::config::PrincipalScopesConfig;
+use crate::config::ServerAuthConfig;
+use crate::config::ServerAuthMode;
+use crate::config::ServerTransport;
+
+// ============================================================================
+// SECTION: Fixtures
+// ============================================================================
+
+const ISSUER: &str = "https://issuer.example.com";
+const AUDIENCE: &str = "decision-gate";
+
+struct JwksState {
+    document: Value,
+    hits: AtomicUsize,
+}
+
+async fn jwks_handler(State(state): State<Arc<JwksState>>) -> Json<Value> {
+    state.hits.fetch_add(1, Ordering::SeqCst);
+    Json(state.document.clone())
+}
+
+async fn spawn_jwks_server(document: Value) -> (String, Arc<JwksState>, oneshot::Sender<()>) {
+    let state = Arc::new(JwksState {
+        document,
+        hits: AtomicUsize::new(0),
+    });
+    let app = Router::new().route("/jwks", get(jwks_handler)).with_state(Arc::clone(&state));
+    let listener = tokio::net::TcpListener::bind("127.0.0.1:0").await.expect("bind");
+    let addr = listener.local_addr().expect("addr");
+    let (shutdown_tx, shutdown_rx) = oneshot::channel();
+    tokio::spawn(async move {
+        let _ = axum::serve(listener, app)
+            .with_graceful_shutdown(async move {
+                let _ = shutdown_rx.await;
+            })
+            .await;
+    });
+    (format!("http://{addr}/jwks"), state, shutdown_tx)
+}
+
+fn jwt_config(jwks_url: &str) -> JwtAuthConfig {
+    JwtAuthConfig {
+        issuer: ISSUER.to_string(),
+        audience: AUDIENCE.to_string(),
+        jwks_url: jwks_url.to_string(),
+        allowed_jwks_hosts: vec!["127.0.0.1".to_string()],
+        allow_http: true,
+        algorithms: vec![JwtAlgorithm::Rs256, JwtAlgorithm::Es256],
+        jwks_timeout_ms: 1_000,
+        jwks_cache_ttl_ms: 60_000,
+        clock_skew_secs: 0,
+    }
+}
+
+fn unix_now() -> u64 {
+    SystemClock.unix_secs()
+}
+
+fn encode_json(value: &Value) -> String {
+    URL_SAFE_NO_PAD.encode(serde_json::to_vec(value).expect("encode json"))
+}
+
+struct EcSigner {
+    key_pair: EcdsaKeyPair,
+    kid: String,
+}
+
+impl EcSigner {
+    fn generate(kid: &str) -> Self {
+        let key_pair =
+            EcdsaKeyPair::generate(&aws_lc_rs::signature::ECDSA_P256_SHA256_FIXED_SIGNING)
+                .expect("ec key");
+        Self {
+            key_pair,
+            kid: kid.to_string(),
+        }
+    }
+
+    fn jwk(&self) -> Value {
+        let point = self.key_pair.public_key().as_ref();
+        json!({
+            "kty": "EC",
+            "crv": "P-256",
+            "use": "sig",
+            "kid": self.kid,
+            "x": URL_SAFE_NO_PAD.encode(&point[1..33]),
+            "y": URL_SAFE_NO_PAD.encode(&point[33..65]),
+        })
+    }
+
+    fn sign(&self, claims: &Value) -> String {
+        let header = json!({ "alg": "ES256", "typ": "JWT", "kid": self.kid });
+        let signing_input = format!("{}.{}", encode_json(&header), encode_json(claims));
+        let signature =
+            self.key_pair.sign(&SystemRandom::new(), signing_input.as_bytes()).expect("sign");
+        format!("{signing_input}.{}", URL_SAFE_NO_PAD.encode(signature.as_ref()))
+    }
+}
+
+fn claims(audience: &str, expires_at: u64) -> Value {
+    json!({
+        "iss": ISSUER,
+        "sub": "service-account-7",
+        "aud": audience,
+        "exp": expires_at,
+        "scope": "scenario:read runpack:verify",
+    })
+}
+
+async fn validator_with_keys(
+    keys: Vec<Value>,
+) -> (JwtValidator, Arc<JwksState>, oneshot::Sender<()>) {
+    let (url, state, shutdown) = spawn_jwks_server(json!({ "keys": keys })).await;
+    let validator = JwtValidator::new(&jwt_config(&url)).expect("validator");
+    (validator, state, shutdown)
+}
+
+// ============================================================================
+// SECTION: Tests
+// ============================================================================
+
+#[tokio::test]
+async fn valid_es256_token_yields_claims() {
+    let signer = EcSigner::generate("ec-1");
+    let (validator, state, shutdown) = validator_with_keys(vec![signer.jwk()]).await;
+    let token = signer.sign(&claims(AUDIENCE, unix_now() + 300));
+
+    let verified = validator.validate(&token).await.expect("valid token");
+    assert_eq!(verified.issuer, ISSUER);
+    assert_eq!(verified.subject, "service-account-7");
+    assert_eq!(verified.audience, vec![AUDIENCE.to_string()]);
+    assert_eq!(verified.scopes, vec!["scenario:read".to_string(), "runpack:verify".to_string()]);
+
+    validator.validate(&token).await.expect("cached keys");
+    assert_eq!(state.hits.load(Ordering::SeqCst), 1);
+    let _ = shutdown.send(());
+}
+
+#[tokio::test]
+async fn valid_rs256_token_yields_claims() {
+    let key_pair = RsaKeyPair::generate(KeySize::Rsa2048).expect("rsa key");
+    let components = RsaPublicKeyComponents::<Vec<u8>>::from(key_pair.public_key());
+    let jwk = json!({
+        "kty": "RSA",
+        "alg": "RS256",
+        "kid": "rsa-1",
+        "n": URL_SAFE_NO_PAD.encode(&components.n),
+        "e": URL_SAFE_NO_PAD.encode(&components.e),
+    });
+    let (validator, _state, shutdown) = validator_with_keys(vec![jwk]).await;
+    let header = json!({ "alg": "RS256", "kid": "rsa-1" });
+    let signing_input =
+        format!("{}.{}", encode_json(&header), encode_json(&claims(AUDIENCE, unix_now() + 300)));
+    let mut signature = vec![0_u8; key_pair.public_modulus_len()];
+    key_pair
+        .sign(
+            &aws_lc_rs::signature::RSA_PKCS1_SHA256,
+            &SystemRandom::new(),
+            signing_input.as_bytes(),
+            &mut signature,
+        )
+        .expect("sign");
+    let token = format!("{signing_input}.{}", URL_SAFE_NO_PAD.encode(&signature));
+
+    let verified = validator.validate(&token).await.expect("valid token");
+    assert_eq!(verified.subject, "service-account-7");
+    let _ = shutdown.send(());
+}
+
+#[tokio::test]
+async fn expired_token_rejected() {
+    let signer = EcSigner::generate("ec-1");
+    let (validator, _state, shutdown) = validator_with_keys(vec![signer.jwk()]).await;
+    let token = signer.sign(&claims(AUDIENCE, unix_now() - 60));
+
+    let error = validator.validate(&token).await.expect_err("expired token");
+    assert_eq!(error, JwtError::Expired);
+    let _ = shutdown.send(());
+}
+
+#[tokio::test]
+async fn wrong_audience_rejected() {
+    let signer = EcSigner::generate("ec-1");
+    let (validator, _state, shutdown) = validator_with_keys(vec![signer.jwk()]).await;
+    let token = signer.sign(&claims("some-other-service", unix_now() + 300));
+
+    let error = validator.validate(&token).await.expect_err("wrong audience");
+    assert_eq!(error, JwtError::InvalidAudience);
+    let _ = shutdown.send(());
+}
+
+#[tokio::test]
+async fn forged_and_unsigned_tokens_rejected() {
+    let signer = EcSigner::generate("ec-1");
+    let attacker = EcSigner::generate("ec-1");
+    let (validator, _state, shutdown) = validator_with_keys(vec![signer.jwk()]).await;
+
+    let forged = attacker.sign(&claims(AUDIENCE, unix_now() + 300));
+    assert_eq!(validator.validate(&forged).await, Err(JwtError::InvalidSignature));
+
+    let header = encode_json(&json!({ "alg": "none" }));
+    let unsigned = format!("{header}.{}.", encode_json(&claims(AUDIENCE, unix_now() + 300)));
+    assert_eq!(
+        validator.validate(&unsigned).await,
+        Err(JwtError::UnsupportedAlgorithm("none".to_string()))
+    );
+    let _ = shutdown.send(());
+}
+
+#[tokio::test]
+async fn unknown_kid_rejected() {
+    let signer = EcSigner::generate("ec-1");
+    let rotated = EcSigner::generate("ec-2");
+    let (validator, _state, shutdown) = validator_with_keys(vec![signer.jwk()]).await;
+
+    let token = rotated.sign(&claims(AUDIENCE, unix_now() + 300));
+    assert_eq!(validator.validate(&token).await, Err(JwtError::UnknownKey));
+    let _ = shutdown.send(());
+}
+
+#[tokio::test]
+async fn jwks_cache_expires_on_injected_clock() {
+    let signer = EcSigner::generate("ec-1");
+    let clock = Arc::new(ManualClock::new(1_700_000_000));
+    let (validator, state, shutdown) = validator_with_keys(vec![signer.jwk()]).await;
+    let validator = validator.with_clock(Arc::clone(&clock) as Arc<dyn Clock>);
+    let token = signer.sign(&claims(AUDIENCE, clock.unix_secs() + 3_600));
+
+    validator.validate(&token).await.expect("initial fetch");
+    clock.advance(Duration::from_millis(59_999));
+    validator.validate(&token).await.expect("cached keys");
+    assert_eq!(state.hits.load(Ordering::SeqCst), 1);
+
+    clock.advance(Duration::from_millis(1));
+    validator.validate(&token).await.expect("refetched keys");
+    assert_eq!(state.hits.load(Ordering::SeqCst), 2);
+    let _ = shutdown.send(());
+}
+
+#[tokio::test]
+async fn token_expiry_follows_injected_clock() {
+    let signer = EcSigner::generate("ec-1");
+    let clock = Arc::new(ManualClock::new(1_700_000_000));
+    let (validator, _state, shutdown) = validator_with_keys(vec![signer.jwk()]).await;
+    let validator = validator.with_clock(Arc::clone(&clock) as Arc<dyn Clock>);
+    let token = signer.sign(&claims(AUDIENCE, clock.unix_secs() + 30));
+
+    validator.validate(&token).await.expect("token before expiry");
+    clock.advance(Duration::from_secs(31));
+    assert_eq!(validator.validate(&token).await, Err(JwtError::Expired));
+    let _ = shutdown.send(());
+}
+
+#[test]
+fn jwks_host_must_be_allowlisted() {
+    let mut config = jwt_config("https://keys.attacker.example/jwks");
+    config.allowed_jwks_hosts = vec!["issuer.example.com".to_string()];
+    assert!(matches!(JwtValidator::new(&config), Err(JwtError::Jwks(_))));
+
+    let mut config = jwt_config("http://127.0.0.1/jwks");
+    config.allow_http = false;
+    assert!(matches!(JwtValidator::new(&config), Err(JwtError::Jwks(_))));
+}
+
+#[tokio::test]
+async fn bearer_authz_builds_context_from_jwt_claims() {
+    let signer = EcSigner::generate("ec-1");
+    let (url, _state, shutdown) = spawn_jwks_server(json!({ "keys": [signer.jwk()] })).await;
+    let config = ServerAuthConfig {
+        mode: ServerAuthMode::BearerToken,
+        bearer_tokens: Vec::new(),
+        jwt: Some(jwt_config(&url)),
+        mtls_subjects: Vec::new(),
+        allowed_tools: Vec::new(),
+        principals: Vec::new(),
+    };
+    let authz = DefaultToolAuthz::from_config(Some(&config));
+    let context = |token: &str| {
+        RequestContext::http(
+            ServerTransport::Http,
+            Some([127, 0, 0, 1].into()),
+            Some(format!("Bearer {token}")),
+            None,
+        )
+    };
+
+    let token = signer.sign(&claims(AUDIENCE, unix_now() + 300));
+    let auth = authz.authorize(&context(&token), AuthAction::ListTools).await.expect("jwt auth");
+    assert_eq!(auth.method, AuthMethod::BearerToken);
+    assert_eq!(auth.principal_id(), "service-account-7");
+    assert!(auth.token_fingerprint.is_some());
+
+    let expired = signer.sign(&claims(AUDIENCE, unix_now() - 60));
+    assert!(authz.authorize(&context(&expired), AuthAction::ListTools).await.is_err());
+    assert!(authz.authorize(&context("opaque-token"), AuthAction::ListTools).await.is_err());
+    let _ = shutdown.send(());
+}
+
+async fn authorize_scoped(
+    authz: &DefaultToolAuthz,
+    signer: &EcSigner,
+    scope: &str,
+    tool: ToolName,
+) -> Result<AuthContext, AuthError> {
+    let mut token_claims = claims(AUDIENCE, unix_now() + 300);
+    token_claims["scope"] = json!(scope);
+    let context = RequestContext::http(
+        ServerTransport::Http,
+        Some([127, 0, 0, 1].into()),
+        Some(format!("Bearer {}", signer.sign(&token_claims))),
+        None,
+    );
+    authz.authorize(&context, AuthAction::CallTool(&tool)).await
+}
+
+#[tokio::test]
+async fn jwt_scope_claim_maps_to_auth_scopes() {
+    let signer = EcSigner::generate("ec-1");
+    let (url, _state, shutdown) = spawn_jwks_server(json!({ "keys": [signer.jwk()] })).await;
+    let mut config = ServerAuthConfig {
+        mode: ServerAuthMode::BearerToken,
+        bearer_tokens: Vec::new(),
+        jwt: Some(jwt_config(&url)),
+        mtls_subjects: Vec::new(),
+        allowed_tools: Vec::new(),
+        principals: Vec::new(),
+    };
+    let tenant = |raw| TenantId::from_raw(raw).expect("tenant id");
+    let authz = DefaultToolAuthz::from_config(Some(&config));
+
+    let unscoped =
+        authorize_scoped(&authz, &signer, "scenario:read runpack:verify", ToolName::ScenarioStatus);
+    assert!(unscoped.await.is_err(), "tokens without dg scopes get no tools");
+
+    let read = authorize_scoped(&authz, &signer, "dg:read dg:tenant:1", ToolName::ScenarioStatus)
+        .await
+        .expect("read scope permits status");
+    assert!(read.scopes.read_only);
+    assert!(read.scopes.check_tenant(&tenant(1)).is_ok());
+    assert!(read.scopes.check_tenant(&tenant(2)).is_err());
+    assert!(
+        authorize_scoped(&authz, &signer, "dg:read dg:tenant:*", ToolName::ScenarioStart)
+            .await
+            .is_err()
+    );
+
+    let write = authorize_scoped(&authz, &signer, "dg:write dg:tenant:*", ToolName::ScenarioStart)
+        .await
+        .expect("write scope permits mutation");
+    assert!(write.scopes.check_tenant(&tenant(2)).is_ok());
+    let tool =
+        authorize_scoped(&authz, &signer, "dg:tool:scenario_status", ToolName::ScenarioStatus)
+            .await
+            .expect("tool scope permits listed tool");
+    assert!(tool.scopes.check_tenant(&tenant(1)).is_err(), "no tenant scope grants no tenant");
+    assert!(
+        authorize_scoped(&authz, &signer, "dg:tool:scenario_status", ToolName::ScenariosList)
+            .await
+            .is_err()
+    );
+    let submit_scope = "dg:tool:scenario_submit dg:tenant:*";
+    let submit = authorize_scoped(&authz, &signer, submit_scope, ToolName::ScenarioSubmit)
+        .await
+        .expect("explicit tool scope permits a mutating tool");
+    assert!(!submit.scopes.read_only);
+    assert!(
+        authorize_scoped(&authz, &signer, submit_scope, ToolName::ScenarioStart).await.is_err(),
+        "explicit tool scope denies unlisted mutating tools"
+    );
+
+    config.principals = vec![PrincipalConfig {
+        subject: "service-account-7".to_string(),
+        policy_class: None,
+        roles: Vec::new(),
+        scopes: PrincipalScopesConfig::default(),
+    }];
+    let configured = DefaultToolAuthz::from_config(Some(&config));
+    let principal = authorize_scoped(&configured, &signer, "dg:read", ToolName::ScenarioStart)
+        .await
+        .expect("configured principal scopes take precedence");
+    assert!(principal.scopes.tenant_ids.is_none());
+    let _ = shutdown.send(());
+}
@@ -19,11 +19,14 @@
 
 pub mod audit;
 pub mod auth;
+pub mod authz_trace;
 pub mod capabilities;
+pub mod clock;
 pub mod config;
 pub mod correlation;
 pub mod docs;
 pub mod evidence;
+pub mod jwt;
 pub mod namespace_authority;
 pub mod policy;
 pub mod registry_acl;
@@ -31,8 +34,14 @@ pub mod runpack;
 pub mod runpack_object_store;
 pub mod runpack_storage;
 pub mod server;
+pub mod status_events;
+pub mod store_breaker;
+pub mod store_cache;
 pub mod telemetry;
 pub mod tenant_authz;
+pub mod tls;
+pub mod tool_concurrency;
+pub mod tool_schemas;
 pub mod tools;
 pub mod usage;
 pub mod validation;
@@ -41,6 +50,7 @@ pub mod validation;
 // SECTION: Re-Exports
 // ============================================================================
 
+pub use audit::EvaluationAuditEvent;
 pub use audit::McpAuditEvent;
 pub use audit::McpAuditSink;
 pub use audit::McpFileAuditSink;
@@ -51,6 +61,7 @@ pub use audit::TenantAuthzEvent;
 pub use audit::UsageAuditEvent;
 pub use auth::AuthAuditSink;
 pub use auth::AuthContext;
+pub use auth::AuthScopes;
 pub use auth::DefaultToolAuthz;
 pub use auth::NoopAuditSink;
 pub use auth::RequestContext;
@@ -76,6 +87,10 @@ pub use telemetry::McpMetricEvent;
 pub use telemetry::McpMetrics;
 pub use telemetry::McpOutcome;
 pub use telemetry::NoopMetrics;
+pub use telemetry::ProviderMetricEvent;
+pub use telemetry::ToolQueueMetricEvent;
+pub use telemetry::ToolQueueOutcome;
+pub use telemetry::UNREGISTERED_PROVIDER_LABEL;
 pub use tenant_authz::NoopTenantAuthorizer;
 pub use tenant_authz::TenantAccessRequest;
 pub use tenant_authz::TenantAuthorizer;
@@ -571,6 +571,7 @@ fn runpack_prefix(key: &RunpackObjectKey) -> Result<String, ObjectStoreError> {
 const fn hash_algorithm_label(algorithm: HashAlgorithm) -> &'static str {
     match algorithm {
         HashAlgorithm::Sha256 => "sha256",
+        HashAlgorithm::Blake3 => "blake3",
     }
 }
 
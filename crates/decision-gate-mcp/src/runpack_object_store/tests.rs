@@ -33,6 +33,7 @@
 use decision_gate_core::AdvanceTo;
 use decision_gate_core::ArtifactKind;
 use decision_gate_core::ArtifactRecord;
+use decision_gate_core::Compression;
 use decision_gate_core::ConditionSpec;
 use decision_gate_core::DecisionOutcome;
 use decision_gate_core::DecisionRecord;
@@ -123,7 +124,9 @@ fn sample_manifest() -> RunpackManifest {
             content_type: Some("application/json".to_string()),
             hash: artifact_hash,
             required: true,
+            content_encoding: Compression::None,
         }],
+        signature: None,
     }
 }
 
@@ -34,39 +34,50 @@ use axum::Router;
 use axum::body::Bytes;
 use axum::extract::ConnectInfo;
 use axum::extract::DefaultBodyLimit;
+use axum::extract::Query;
 use axum::extract::State;
 use axum::http::HeaderMap;
 use axum::http::HeaderValue;
 use axum::http::StatusCode;
 use axum::http::header::AUTHORIZATION;
+use axum::http::header::CACHE_CONTROL;
+use axum::http::header::CONTENT_TYPE;
+use axum::http::header::ETAG;
+use axum::http::header::IF_NONE_MATCH;
 use axum::http::header::WWW_AUTHENTICATE;
 use axum::response::IntoResponse;
 use axum::response::Sse;
 use axum::response::sse::Event;
+use axum::response::sse::KeepAlive;
 use axum::routing::get;
 use axum::routing::post;
+use axum_server::tls_rustls::RustlsConfig;
 use decision_gate_contract::ToolName;
+use decision_gate_contract::types::ToolContract;
 use decision_gate_core::DataShapeRegistry;
+use decision_gate_core::EvidenceProvider;
 use decision_gate_core::InMemoryDataShapeRegistry;
 use decision_gate_core::InMemoryRunStateStore;
+use decision_gate_core::NamespaceId;
 use decision_gate_core::RunStateStore;
 use decision_gate_core::RunpackSecurityContext;
 use decision_gate_core::SharedDataShapeRegistry;
 use decision_gate_core::SharedRunStateStore;
+use decision_gate_core::StoreError;
+use decision_gate_core::TenantId;
 use decision_gate_core::TrustRequirement;
 use decision_gate_core::hashing::HashAlgorithm;
 use decision_gate_core::hashing::hash_bytes;
+use decision_gate_core::runtime::ControlPlaneError;
+use decision_gate_store_sqlite::RetentionWorker;
 use decision_gate_store_sqlite::SqliteRunStateStore;
 use decision_gate_store_sqlite::SqliteStoreConfig;
-use rustls::RootCertStore;
-use rustls::pki_types::CertificateDer;
-use rustls::pki_types::PrivateKeyDer;
-use rustls::server::WebPkiClientVerifier;
-use rustls_pki_types::pem::PemObject;
 use serde::Deserialize;
 use serde::Serialize;
 use serde_json::Value;
 use tokio::sync::Semaphore;
+use tokio::sync::mpsc;
+use tokio::sync::mpsc::error::TrySendError;
 use tokio_stream::wrappers::ReceiverStream;
 
 use crate::audit::McpAuditEvent;
@@ -82,15 +93,21 @@ use crate::auth::RequestContext;
 use crate::auth::StderrAuditSink;
 use crate::auth::auth_challenge_for_mode;
 use crate::capabilities::CapabilityRegistry;
+use crate::clock::Clock;
+use crate::clock::SystemClock;
 use crate::config::DecisionGateConfig;
 use crate::config::ProviderType;
+use crate::config::ProviderWarmUpMode;
 use crate::config::RateLimitConfig;
+use crate::config::RunStateCacheMode;
 use crate::config::RunStateStoreType;
 use crate::config::RunpackStorageConfig;
 use crate::config::SchemaRegistryType;
 use crate::config::ServerAuditConfig;
 use crate::config::ServerAuthMode;
 use crate::config::ServerMode;
+use crate::config::ServerOpenApiConfig;
+use crate::config::ServerSseConfig;
 use crate::config::ServerTlsConfig;
 use crate::config::ServerTransport;
 use crate::correlation::CLIENT_CORRELATION_HEADER;
@@ -107,6 +124,8 @@ use crate::registry_acl::PrincipalResolver;
 use crate::registry_acl::RegistryAcl;
 use crate::runpack_object_store::ObjectStoreRunpackBackend;
 use crate::runpack_storage::RunpackStorage;
+use crate::store_breaker::CircuitBreakerRunStateStore;
+use crate::store_cache::CachedRunStateStore;
 use crate::telemetry::McpMethod;
 use crate::telemetry::McpMetricEvent;
 use crate::telemetry::McpMetrics;
@@ -114,8 +133,12 @@ use crate::telemetry::McpOutcome;
 use crate::telemetry::NoopMetrics;
 use crate::tenant_authz::NoopTenantAuthorizer;
 use crate::tenant_authz::TenantAuthorizer;
+use crate::tls::TlsReloader;
+use crate::tls::build_tls_config;
 use crate::tools::DocsProvider;
 use crate::tools::ProviderTransport;
+use crate::tools::ScenarioStreamItem;
+use crate::tools::ScenarioStreamRequest;
 use crate::tools::SchemaRegistryLimits;
 use crate::tools::ToolDefinition;
 use crate::tools::ToolError;
@@ -146,6 +169,10 @@ pub struct McpServer {
     auth_challenge: Option<AuthChallenge>,
     /// Readiness state for health probes.
     readiness: Arc<ReadinessState>,
+    /// Run state cache flushed on shutdown, when configured.
+    run_state_cache: Option<Arc<CachedRunStateStore>>,
+    /// Background retention worker, stopped when the server is dropped.
+    retention_worker: Option<RetentionWorker>,
 }
 
 /// Optional overrides for enterprise deployments.
@@ -234,7 +261,11 @@ impl McpServer {
     ) -> Result<Self, McpServerError> {
         config.validate().map_err(|err| McpServerError::Config(err.to_string()))?;
         let evidence = FederatedEvidenceProvider::from_config(&config)
-            .map_err(|err| McpServerError::Init(err.to_string()))?;
+            .map_err(|err| McpServerError::Init(err.to_string()))?
+            .with_metrics(Arc::clone(&metrics));
+        if config.server.provider_warm_up == ProviderWarmUpMode::Startup {
+            evidence.warm_up().map_err(|err| McpServerError::Init(err.to_string()))?;
+        }
         let capabilities = CapabilityRegistry::from_config(&config)
             .map_err(|err| McpServerError::Init(err.to_string()))?;
         let ServerOverrides {
@@ -249,15 +280,32 @@ impl McpServer {
             docs_provider,
             tool_visibility_resolver,
         } = overrides;
-        let store = match run_state_store {
-            Some(store) => store,
+        let (store, retention_worker) = match run_state_store {
+            Some(store) => (store, None),
             None => build_run_state_store(&config)?,
         };
+        let store = match &config.run_state_store.circuit_breaker {
+            Some(breaker) => {
+                SharedRunStateStore::from_store(CircuitBreakerRunStateStore::new(store, breaker))
+            }
+            None => store,
+        };
+        let run_state_cache = config
+            .run_state_store
+            .cache
+            .as_ref()
+            .map(|cache| Arc::new(CachedRunStateStore::new(store.clone(), cache)));
+        let store =
+            run_state_cache.as_ref().map_or(store, |cache| SharedRunStateStore::new(cache.clone()));
         let schema_registry = match schema_registry {
             Some(registry) => registry,
             None => build_schema_registry(&config)?,
         };
-        let readiness = Arc::new(ReadinessState::new(store.clone(), schema_registry.clone()));
+        let mut readiness = ReadinessState::new(store.clone(), schema_registry.clone());
+        if config.server.provider_warm_up == ProviderWarmUpMode::Readiness {
+            readiness = readiness.with_provider_warm_up(evidence.clone());
+        }
+        let readiness = Arc::new(readiness);
         let provider_transports = build_provider_transports(&config);
         let schema_registry_limits = build_schema_registry_limits(&config)?;
         let default_namespace_tenants =
@@ -318,10 +366,12 @@ impl McpServer {
             tools: config.server.tools.clone(),
             docs_provider,
             tool_visibility_resolver,
+            tool_concurrency: config.server.limits.tool_concurrency.clone(),
             allow_default_namespace: config.allow_default_namespace(),
             default_namespace_tenants,
             namespace_authority,
-        });
+        })
+        .with_metrics(Arc::clone(&metrics));
         emit_security_posture_summary(&config);
         emit_local_only_warning(&config.server);
         emit_registry_acl_warning(&config);
@@ -338,6 +388,8 @@ impl McpServer {
             audit,
             auth_challenge,
             readiness,
+            run_state_cache,
+            retention_worker,
         })
     }
 
@@ -364,7 +416,33 @@ impl McpServer {
     /// # Errors
     ///
     /// Returns [`McpServerError`] when the server fails.
-    pub async fn serve(self) -> Result<(), McpServerError> {
+    pub async fn serve(mut self) -> Result<(), McpServerError> {
+        let _retention_worker = self.retention_worker.take();
+        let Some(cache) = self.run_state_cache.take() else {
+            return Box::pin(self.serve_transport()).await;
+        };
+        let result = match (cache.mode(), &self.config.run_state_store.cache) {
+            (RunStateCacheMode::WriteBack, Some(config)) => {
+                let flusher = cache.spawn_flusher(Duration::from_millis(config.flush_interval_ms));
+                let result = tokio::select! {
+                    result = Box::pin(self.serve_transport()) => result,
+                    () = shutdown_signal() => Ok(()),
+                };
+                flusher.abort();
+                result
+            }
+            _ => Box::pin(self.serve_transport()).await,
+        };
+        let flushed = tokio::task::spawn_blocking(move || cache.flush())
+            .await
+            .map_err(|err| McpServerError::Transport(format!("run state cache flush: {err}")))?;
+        flushed
+            .map_err(|err| McpServerError::Transport(format!("run state cache flush: {err}")))?;
+        result
+    }
+
+    /// Runs the configured transport until it stops.
+    async fn serve_transport(self) -> Result<(), McpServerError> {
         let transport = self.config.server.transport;
         match transport {
             ServerTransport::Stdio => {
@@ -407,9 +485,11 @@ impl McpServer {
 /// Builds the run state store from MCP configuration.
 fn build_run_state_store(
     config: &DecisionGateConfig,
-) -> Result<SharedRunStateStore, McpServerError> {
+) -> Result<(SharedRunStateStore, Option<RetentionWorker>), McpServerError> {
     let store = match config.run_state_store.store_type {
-        RunStateStoreType::Memory => SharedRunStateStore::from_store(InMemoryRunStateStore::new()),
+        RunStateStoreType::Memory => {
+            (SharedRunStateStore::from_store(InMemoryRunStateStore::new()), None)
+        }
         RunStateStoreType::Sqlite => {
             let path = config.run_state_store.path.clone().ok_or_else(|| {
                 McpServerError::Config("sqlite run_state_store requires path".to_string())
@@ -422,10 +502,14 @@ fn build_run_state_store(
                 max_versions: config.run_state_store.max_versions,
                 schema_registry_max_schema_bytes: None,
                 schema_registry_max_entries: None,
+                retention: config.run_state_store.retention.clone(),
             };
             let store = SqliteRunStateStore::new(sqlite_config)
                 .map_err(|err| McpServerError::Init(err.to_string()))?;
-            SharedRunStateStore::from_store(store)
+            let worker = store.spawn_retention_worker(Duration::from_millis(
+                config.run_state_store.retention_interval_ms,
+            ));
+            (SharedRunStateStore::from_store(store), worker)
         }
     };
     Ok(store)
@@ -465,6 +549,7 @@ fn build_schema_registry(
                 max_versions: None,
                 schema_registry_max_schema_bytes: Some(config.schema_registry.max_schema_bytes),
                 schema_registry_max_entries: max_entries,
+                retention: Vec::new(),
             };
             let store = SqliteRunStateStore::new(sqlite_config)
                 .map_err(|err| McpServerError::Init(err.to_string()))?;
@@ -589,74 +674,33 @@ fn build_audit_sink(config: &ServerAuditConfig) -> Result<Arc<dyn McpAuditSink>,
     Ok(Arc::new(McpStderrAuditSink))
 }
 
-/// Builds a TLS config for HTTP/SSE transports.
-fn build_tls_config(
-    config: &ServerTlsConfig,
-) -> Result<axum_server::tls_rustls::RustlsConfig, McpServerError> {
-    let _ = rustls::crypto::aws_lc_rs::default_provider().install_default();
-    let certs = load_certificates(&config.cert_path)?;
-    let key = load_private_key(&config.key_path)?;
-    let builder = if let Some(ca_path) = &config.client_ca_path {
-        let roots = load_root_store(ca_path)?;
-        let roots = Arc::new(roots);
-        let verifier = if config.require_client_cert {
-            WebPkiClientVerifier::builder(roots)
-        } else {
-            WebPkiClientVerifier::builder(roots).allow_unauthenticated()
-        }
-        .build()
-        .map_err(|err| McpServerError::Config(format!("tls client verifier failed: {err}")))?;
-        rustls::ServerConfig::builder().with_client_cert_verifier(verifier)
-    } else {
-        rustls::ServerConfig::builder().with_no_client_auth()
+/// Builds the TLS config, spawning the certificate hot-reload watcher when configured.
+fn start_tls(
+    tls: &ServerTlsConfig,
+    audit: &Arc<dyn McpAuditSink>,
+) -> Result<RustlsConfig, McpServerError> {
+    let Some(interval_ms) = tls.reload_interval_ms else {
+        return build_tls_config(tls, audit);
     };
-    let mut server_config = builder
-        .with_single_cert(certs, key)
-        .map_err(|err| McpServerError::Config(format!("tls config invalid: {err}")))?;
-    server_config.alpn_protocols = vec![b"h2".to_vec(), b"http/1.1".to_vec()];
-    Ok(axum_server::tls_rustls::RustlsConfig::from_config(Arc::new(server_config)))
-}
-
-/// Loads a PEM-encoded certificate chain from disk.
-fn load_certificates(path: &str) -> Result<Vec<CertificateDer<'static>>, McpServerError> {
-    let iter = CertificateDer::pem_file_iter(path)
-        .map_err(|err| McpServerError::Config(format!("tls cert read failed: {err}")))?;
-    let certs = iter
-        .map(|item| {
-            item.map_err(|err| McpServerError::Config(format!("tls cert read failed: {err}")))
-        })
-        .collect::<Result<Vec<_>, _>>()?;
-    if certs.is_empty() {
-        return Err(McpServerError::Config("tls cert file contains no certificates".to_string()));
-    }
-    Ok(certs)
-}
-
-/// Loads a PEM-encoded private key from disk.
-fn load_private_key(path: &str) -> Result<PrivateKeyDer<'static>, McpServerError> {
-    PrivateKeyDer::from_pem_file(path)
-        .map_err(|err| McpServerError::Config(format!("tls key read failed: {err}")))
+    let reloader = TlsReloader::new(tls, Arc::clone(audit))?;
+    let rustls_config = reloader.rustls_config();
+    drop(reloader.spawn(Duration::from_millis(interval_ms)));
+    Ok(rustls_config)
 }
 
-/// Loads a PEM-encoded CA bundle into a root store.
-fn load_root_store(path: &str) -> Result<RootCertStore, McpServerError> {
-    let iter = CertificateDer::pem_file_iter(path)
-        .map_err(|err| McpServerError::Config(format!("tls ca read failed: {err}")))?;
-    let certs = iter
-        .map(|item| {
-            item.map_err(|err| McpServerError::Config(format!("tls ca read failed: {err}")))
-        })
-        .collect::<Result<Vec<_>, _>>()?;
-    if certs.is_empty() {
-        return Err(McpServerError::Config(
-            "tls client ca file contains no certificates".to_string(),
-        ));
-    }
-    let mut store = RootCertStore::empty();
-    for cert in certs {
-        store.add(cert).map_err(|err| McpServerError::Config(format!("tls ca invalid: {err}")))?;
+/// Resolves when the process is asked to stop via Ctrl-C or, on Unix, SIGTERM.
+async fn shutdown_signal() {
+    #[cfg(unix)]
+    if let Ok(mut terminate) =
+        tokio::signal::unix::signal(tokio::signal::unix::SignalKind::terminate())
+    {
+        tokio::select! {
+            _ = tokio::signal::ctrl_c() => {}
+            _ = terminate.recv() => {}
+        }
+        return;
     }
-    Ok(store)
+    let _ = tokio::signal::ctrl_c().await;
 }
 
 // ============================================================================
@@ -672,12 +716,15 @@ async fn serve_stdio(
     server: &crate::config::ServerConfig,
     readiness: Arc<ReadinessState>,
 ) -> Result<(), McpServerError> {
-    let mut reader = BufReader::new(std::io::stdin());
+    let mut frames = spawn_stdin_reader(server.max_body_bytes);
     let mut writer = std::io::stdout();
     let state =
         build_server_state(router.clone(), server, metrics, audit, auth_challenge, readiness);
     loop {
-        let bytes = read_framed(&mut reader, server.max_body_bytes)?;
+        let bytes = frames
+            .recv()
+            .await
+            .ok_or_else(|| McpServerError::Transport("stdio reader stopped".to_string()))??;
         let context = RequestContext::stdio().with_server_correlation_id(state.correlation.issue());
         let response = parse_request(&state, &context, &Bytes::from(bytes)).await;
         let payload = serde_json::to_vec(&response.1)
@@ -686,6 +733,26 @@ async fn serve_stdio(
     }
 }
 
+/// Reads framed stdin messages on a dedicated thread.
+///
+/// Blocking reads stay off the async runtime so the server task can still
+/// observe shutdown signals while waiting for input. The thread stops after
+/// forwarding the first read error, including end of input.
+fn spawn_stdin_reader(max_body_bytes: usize) -> mpsc::Receiver<Result<Vec<u8>, McpServerError>> {
+    let (tx, rx) = mpsc::channel(1);
+    std::thread::spawn(move || {
+        let mut reader = BufReader::new(std::io::stdin());
+        loop {
+            let frame = read_framed(&mut reader, max_body_bytes);
+            let failed = frame.is_err();
+            if tx.blocking_send(frame).is_err() || failed {
+                return;
+            }
+        }
+    });
+    rx
+}
+
 // ============================================================================
 // SECTION: HTTP Transport
 // ============================================================================
@@ -706,6 +773,7 @@ async fn serve_http(
         .ok_or_else(|| McpServerError::Config("bind address required".to_string()))?;
     let addr: SocketAddr =
         bind.parse().map_err(|_| McpServerError::Config("invalid bind address".to_string()))?;
+    let tls_config = config.server.tls.as_ref().map(|tls| start_tls(tls, &audit)).transpose()?;
     let state = Arc::new(build_server_state(
         router,
         &config.server,
@@ -717,11 +785,11 @@ async fn serve_http(
     let app = Router::new()
         .route("/rpc", post(handle_http))
         .route("/healthz", get(handle_health))
-        .route("/readyz", get(handle_ready))
+        .route("/readyz", get(handle_ready));
+    let app = with_openapi_route(app, &config.server.openapi)
         .layer(DefaultBodyLimit::max(config.server.max_body_bytes))
         .with_state(state);
-    if let Some(tls) = &config.server.tls {
-        let tls_config = build_tls_config(tls)?;
+    if let Some(tls_config) = tls_config {
         axum_server::bind_rustls(addr, tls_config)
             .serve(app.into_make_service_with_connect_info::<SocketAddr>())
             .await
@@ -752,6 +820,7 @@ async fn serve_sse(
         .ok_or_else(|| McpServerError::Config("bind address required".to_string()))?;
     let addr: SocketAddr =
         bind.parse().map_err(|_| McpServerError::Config("invalid bind address".to_string()))?;
+    let tls_config = config.server.tls.as_ref().map(|tls| start_tls(tls, &audit)).transpose()?;
     let state = Arc::new(build_server_state(
         router,
         &config.server,
@@ -762,12 +831,13 @@ async fn serve_sse(
     ));
     let app = Router::new()
         .route("/rpc", post(handle_sse))
+        .route("/rpc/scenarios/stream", get(handle_scenarios_stream))
         .route("/healthz", get(handle_health))
-        .route("/readyz", get(handle_ready))
+        .route("/readyz", get(handle_ready));
+    let app = with_openapi_route(app, &config.server.openapi)
         .layer(DefaultBodyLimit::max(config.server.max_body_bytes))
         .with_state(state);
-    if let Some(tls) = &config.server.tls {
-        let tls_config = build_tls_config(tls)?;
+    if let Some(tls_config) = tls_config {
         axum_server::bind_rustls(addr, tls_config)
             .serve(app.into_make_service_with_connect_info::<SocketAddr>())
             .await
@@ -796,6 +866,71 @@ async fn handle_ready(State(state): State<Arc<ServerState>>) -> impl IntoRespons
     }
 }
 
+/// Registers `GET /openapi.json` when the endpoint is enabled.
+fn with_openapi_route(
+    app: Router<Arc<ServerState>>,
+    config: &ServerOpenApiConfig,
+) -> Router<Arc<ServerState>> {
+    if config.enabled { app.route("/openapi.json", get(handle_openapi)) } else { app }
+}
+
+/// Serves an `OpenAPI` document for the tools visible to the caller.
+///
+/// The document is rendered from the live tool contracts, so disabled or
+/// hidden tools never appear. Responses carry a content-hash `ETag` and honor
+/// `If-None-Match` with `304 Not Modified`.
+async fn handle_openapi(
+    State(state): State<Arc<ServerState>>,
+    ConnectInfo(peer): ConnectInfo<SocketAddr>,
+    headers: HeaderMap,
+) -> axum::response::Response {
+    let context = http_request_context(
+        ServerTransport::Http,
+        peer,
+        &headers,
+        None,
+        state.correlation.issue(),
+    );
+    let contracts =
+        match state.router.openapi_tool_contracts(&context, state.openapi.require_auth).await {
+            Ok(contracts) => contracts,
+            Err(error) => {
+                let response = jsonrpc_error(Value::Null, error);
+                return respond_with_correlation_headers(&state, &context, response);
+            }
+        };
+    let Ok(document) = decision_gate_sdk_gen::render_openapi_document(&contracts) else {
+        let error = ToolError::Internal("openapi rendering failed".to_string());
+        return respond_with_correlation_headers(
+            &state,
+            &context,
+            jsonrpc_error(Value::Null, error),
+        );
+    };
+    let digest = hash_bytes(HashAlgorithm::Sha256, document.as_bytes());
+    let etag = format!("\"{}\"", digest.value);
+    let visibility = if state.openapi.require_auth { "private" } else { "public" };
+    let cache_control = format!("{visibility}, max-age={}", state.openapi.max_age_secs);
+    let not_modified = headers
+        .get(IF_NONE_MATCH)
+        .and_then(|value| value.to_str().ok())
+        .is_some_and(|value| value.split(',').any(|tag| tag.trim() == etag || tag.trim() == "*"));
+    let mut http_response = if not_modified {
+        StatusCode::NOT_MODIFIED.into_response()
+    } else {
+        (StatusCode::OK, [(CONTENT_TYPE, "application/json")], document).into_response()
+    };
+    let response_headers = http_response.headers_mut();
+    if let Ok(value) = HeaderValue::from_str(&etag) {
+        response_headers.insert(ETAG, value);
+    }
+    if let Ok(value) = HeaderValue::from_str(&cache_control) {
+        response_headers.insert(CACHE_CONTROL, value);
+    }
+    response_headers.extend(build_correlation_headers(&context));
+    http_response
+}
+
 /// Shared server state for HTTP/SSE handlers.
 #[derive(Clone)]
 struct ServerState {
@@ -817,6 +952,12 @@ struct ServerState {
     auth_challenge: Option<HeaderValue>,
     /// Readiness state for probes.
     readiness: Arc<ReadinessState>,
+    /// Capabilities advertised during `initialize`.
+    capabilities: ServerCapabilities,
+    /// Heartbeat, polling, and buffering settings for SSE streams.
+    sse: ServerSseConfig,
+    /// Auth and caching settings for the live `OpenAPI` endpoint.
+    openapi: ServerOpenApiConfig,
 }
 
 #[derive(Serialize)]
@@ -855,6 +996,8 @@ struct ReadinessState {
     store: SharedRunStateStore,
     /// Data shape registry for readiness checks.
     registry: SharedDataShapeRegistry,
+    /// Evidence providers warmed up on each probe, when enabled.
+    evidence: Option<FederatedEvidenceProvider>,
 }
 
 impl ReadinessState {
@@ -863,13 +1006,23 @@ impl ReadinessState {
         Self {
             store,
             registry,
+            evidence: None,
         }
     }
 
-    /// Executes store + registry readiness checks.
+    /// Adds evidence provider warm-up to readiness checks.
+    fn with_provider_warm_up(mut self, evidence: FederatedEvidenceProvider) -> Self {
+        self.evidence = Some(evidence);
+        self
+    }
+
+    /// Executes store, registry, and optional provider readiness checks.
     fn check(&self) -> Result<(), ReadinessError> {
         self.store.readiness().map_err(|_| ReadinessError::Store)?;
         self.registry.readiness().map_err(|_| ReadinessError::Registry)?;
+        if let Some(evidence) = &self.evidence {
+            evidence.warm_up().map_err(|_| ReadinessError::Providers)?;
+        }
         Ok(())
     }
 }
@@ -880,6 +1033,8 @@ enum ReadinessError {
     Store,
     /// Registry readiness failure.
     Registry,
+    /// Evidence provider warm-up failure.
+    Providers,
 }
 
 /// Builds shared server state used by HTTP/SSE handlers.
@@ -898,6 +1053,7 @@ fn build_server_state(
     let challenge = auth_challenge.or_else(|| auth_challenge_for_mode(auth_mode));
     let auth_challenge =
         challenge.and_then(|challenge| HeaderValue::from_str(challenge.header_value()).ok());
+    let capabilities = ServerCapabilities::from_config(server, router.resources_enabled());
     ServerState {
         router,
         max_body_bytes: server.max_body_bytes,
@@ -908,6 +1064,9 @@ fn build_server_state(
         correlation: Arc::new(CorrelationIdGenerator::new("dg")),
         auth_challenge,
         readiness,
+        capabilities,
+        sse: server.sse.clone(),
+        openapi: server.openapi.clone(),
     }
 }
 
@@ -921,6 +1080,8 @@ struct RateLimiter {
     config: RateLimitConfig,
     /// Per-key request buckets.
     buckets: std::sync::Mutex<HashMap<String, RateLimitBucket>>,
+    /// Time source for window and eviction checks.
+    clock: Arc<dyn Clock>,
 }
 
 /// Rolling state for a single rate limit key.
@@ -949,9 +1110,15 @@ enum RateLimitDecision {
 impl RateLimiter {
     /// Creates a new rate limiter from configuration.
     fn new(config: RateLimitConfig) -> Self {
+        Self::with_clock(config, Arc::new(SystemClock))
+    }
+
+    /// Creates a new rate limiter that reads time from `clock`.
+    fn with_clock(config: RateLimitConfig, clock: Arc<dyn Clock>) -> Self {
         Self {
             config,
             buckets: std::sync::Mutex::new(HashMap::new()),
+            clock,
         }
     }
 
@@ -959,7 +1126,7 @@ impl RateLimiter {
     fn check(&self, key: &str) -> RateLimitDecision {
         let window = Duration::from_millis(self.config.window_ms);
         let ttl = Duration::from_millis(self.config.window_ms.saturating_mul(2));
-        let now = Instant::now();
+        let now = self.clock.now();
         {
             let Ok(mut buckets) = self.buckets.lock() else {
                 return RateLimitDecision::OverCapacity;
@@ -1082,6 +1249,206 @@ async fn handle_sse(
     respond_sse_with_correlation_headers(&state, &context, &response)
 }
 
+// ============================================================================
+// SECTION: SSE Streams
+// ============================================================================
+
+/// Header carrying the last event identifier a reconnecting client received.
+const LAST_EVENT_ID_HEADER: &str = "last-event-id";
+
+/// Page size used when draining scenario definitions into an SSE stream.
+const SCENARIO_STREAM_PAGE_LIMIT: usize = 100;
+
+/// Query parameters for the `scenarios_list` SSE stream.
+#[derive(Debug, Clone, Copy, Deserialize)]
+struct ScenarioStreamQuery {
+    /// Tenant identifier.
+    tenant_id: u64,
+    /// Namespace identifier.
+    namespace_id: u64,
+}
+
+/// Streams `scenarios_list` results over SSE.
+///
+/// Each scenario is sent as a `scenario` event whose id is its definition
+/// sequence, so a reconnecting client resumes after its `Last-Event-ID` and
+/// still receives scenarios defined while it was away. A missing or
+/// non-numeric `Last-Event-ID` replays from the first definition.
+/// Idle streams emit `heartbeat` events, and consumers that fall a full buffer
+/// behind are dropped instead of blocking the stream task.
+async fn handle_scenarios_stream(
+    State(state): State<Arc<ServerState>>,
+    ConnectInfo(peer): ConnectInfo<SocketAddr>,
+    headers: HeaderMap,
+    Query(query): Query<ScenarioStreamQuery>,
+) -> axum::response::Response {
+    let server_correlation_id = state.correlation.issue();
+    let unsafe_client_correlation_id = match extract_correlation_header(&headers) {
+        Ok(value) => value,
+        Err(reason) => {
+            let context = http_request_context(
+                ServerTransport::Sse,
+                peer,
+                &headers,
+                None,
+                server_correlation_id,
+            );
+            record_correlation_rejection(&state, &context, &reason);
+            let response = invalid_correlation_response(&reason);
+            return respond_sse_with_correlation_headers(&state, &context, &response);
+        }
+    };
+    let context = http_request_context(
+        ServerTransport::Sse,
+        peer,
+        &headers,
+        unsafe_client_correlation_id,
+        server_correlation_id,
+    );
+    if let Some(response) = stream_rate_limit_rejection(&state, &context) {
+        return respond_sse_with_correlation_headers(&state, &context, &response);
+    }
+    let cursor = headers
+        .get(LAST_EVENT_ID_HEADER)
+        .and_then(|value| value.to_str().ok())
+        .and_then(|value| value.trim().parse::<u64>().ok());
+    let page = match fetch_scenario_page(&state.router, &context, query, cursor).await {
+        Ok(page) => page,
+        Err(error) => {
+            let response = jsonrpc_error(Value::Null, error);
+            return respond_sse_with_correlation_headers(&state, &context, &response);
+        }
+    };
+    let (tx, rx) = mpsc::channel::<Result<Event, Infallible>>(state.sse.stream_buffer);
+    let keep_alive = KeepAlive::new()
+        .interval(Duration::from_millis(state.sse.heartbeat_interval_ms))
+        .event(Event::default().event("heartbeat"));
+    let headers = build_correlation_headers(&context);
+    tokio::spawn(pump_scenario_stream(Arc::clone(&state), context, query, cursor, page, tx));
+    let mut http_response =
+        Sse::new(ReceiverStream::new(rx)).keep_alive(keep_alive).into_response();
+    http_response.headers_mut().extend(headers);
+    http_response
+}
+
+/// Applies the request rate limiter to a new stream connection.
+fn stream_rate_limit_rejection(
+    state: &ServerState,
+    context: &RequestContext,
+) -> Option<(StatusCode, JsonRpcResponse)> {
+    let rate_limiter = state.rate_limiter.as_ref()?;
+    match rate_limiter.check(&rate_limit_key(context)) {
+        RateLimitDecision::Allow => None,
+        RateLimitDecision::Limited {
+            retry_after_ms,
+        } => Some((
+            StatusCode::TOO_MANY_REQUESTS,
+            jsonrpc_error_response(
+                Value::Null,
+                -32071,
+                "rate limit exceeded".to_string(),
+                None,
+                Some(retry_after_ms),
+            ),
+        )),
+        RateLimitDecision::OverCapacity => Some((
+            StatusCode::SERVICE_UNAVAILABLE,
+            jsonrpc_error_response(
+                Value::Null,
+                -32072,
+                "rate limiter overloaded".to_string(),
+                None,
+                None,
+            ),
+        )),
+    }
+}
+
+/// Fetches one scenario stream page through the tool router.
+///
+/// Routing through the tool router keeps auth, tenant, namespace, and usage
+/// checks in force for every page, including pages fetched while polling.
+async fn fetch_scenario_page(
+    router: &ToolRouter,
+    context: &RequestContext,
+    query: ScenarioStreamQuery,
+    cursor: Option<u64>,
+) -> Result<Vec<ScenarioStreamItem>, ToolError> {
+    let tenant_id = TenantId::from_raw(query.tenant_id)
+        .ok_or_else(|| ToolError::InvalidParams("tenant_id must be nonzero".to_string()))?;
+    let namespace_id = NamespaceId::from_raw(query.namespace_id)
+        .ok_or_else(|| ToolError::InvalidParams("namespace_id must be nonzero".to_string()))?;
+    let request = ScenarioStreamRequest {
+        tenant_id,
+        namespace_id,
+        after_sequence: cursor,
+        limit: SCENARIO_STREAM_PAGE_LIMIT,
+    };
+    router.scenario_stream_page(context, request).await
+}
+
+/// Pushes scenario events to a stream consumer until it disconnects or lags.
+async fn pump_scenario_stream(
+    state: Arc<ServerState>,
+    context: RequestContext,
+    query: ScenarioStreamQuery,
+    mut cursor: Option<u64>,
+    mut page: Vec<ScenarioStreamItem>,
+    tx: mpsc::Sender<Result<Event, Infallible>>,
+) {
+    let poll_interval = Duration::from_millis(state.sse.poll_interval_ms);
+    loop {
+        let drained = page.len() < SCENARIO_STREAM_PAGE_LIMIT;
+        for item in page {
+            let Ok(event) = scenario_event(&item) else {
+                return;
+            };
+            match tx.try_send(Ok(event)) {
+                Ok(()) => cursor = Some(item.sequence),
+                Err(TrySendError::Full(_)) => {
+                    record_stream_consumer_dropped(&state, &context);
+                    return;
+                }
+                Err(TrySendError::Closed(_)) => return,
+            }
+        }
+        if drained {
+            tokio::select! {
+                () = tx.closed() => return,
+                () = tokio::time::sleep(poll_interval) => {}
+            }
+        }
+        page = match fetch_scenario_page(&state.router, &context, query, cursor).await {
+            Ok(page) => page,
+            Err(error) => {
+                let response = jsonrpc_error(Value::Null, error).1;
+                if let Ok(event) = Event::default().event("error").json_data(&response) {
+                    let _ = tx.try_send(Ok(event));
+                }
+                return;
+            }
+        };
+    }
+}
+
+/// Builds the SSE event for a scenario summary, keyed by definition sequence.
+fn scenario_event(item: &ScenarioStreamItem) -> Result<Event, axum::Error> {
+    Event::default().event("scenario").id(item.sequence.to_string()).json_data(&item.summary)
+}
+
+/// Records a security audit event when a lagging stream consumer is dropped.
+fn record_stream_consumer_dropped(state: &ServerState, context: &RequestContext) {
+    let event = SecurityAuditEvent::new(SecurityAuditEventParams {
+        kind: "sse_consumer_dropped".to_string(),
+        message: Some("stream buffer full; consumer must resume via Last-Event-ID".to_string()),
+        unsafe_client_correlation_id: context.unsafe_client_correlation_id.clone(),
+        server_correlation_id: context.server_correlation_id.clone(),
+        dev_permissive: false,
+        namespace_authority: "n/a".to_string(),
+    });
+    state.audit.record_security(&event);
+}
+
 // ============================================================================
 // SECTION: JSON-RPC Handling
 // ============================================================================
@@ -1099,6 +1466,14 @@ struct JsonRpcRequest {
     params: Option<Value>,
 }
 
+/// Minimal probe used to recover the request `id` from rejected payloads.
+#[derive(Debug, Deserialize)]
+struct JsonRpcIdProbe {
+    /// Request identifier when present.
+    #[serde(default)]
+    id: Value,
+}
+
 /// JSON-RPC response envelope.
 #[derive(Debug, Serialize)]
 struct JsonRpcResponse {
@@ -1156,6 +1531,13 @@ struct ToolListResult {
     tools: Vec<ToolDefinition>,
 }
 
+/// Tool describe response payload.
+#[derive(Debug, Serialize)]
+struct ToolDescribeResult {
+    /// Full tool contracts, including schemas and examples.
+    tools: Vec<ToolContract>,
+}
+
 /// Resource list response payload.
 #[derive(Debug, Serialize)]
 struct ResourceListResult {
@@ -1177,6 +1559,89 @@ struct ResourceReadResult {
     contents: Vec<docs::ResourceContent>,
 }
 
+/// MCP protocol revision advertised during `initialize`.
+const MCP_PROTOCOL_VERSION: &str = "2024-11-05";
+
+/// `initialize` response payload.
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+struct InitializeResult {
+    /// MCP protocol revision implemented by the server.
+    protocol_version: &'static str,
+    /// Server identity.
+    server_info: ServerInfo,
+    /// Features and limits advertised to the client.
+    capabilities: ServerCapabilities,
+}
+
+/// Server identity advertised during `initialize`.
+#[derive(Debug, Serialize)]
+struct ServerInfo {
+    /// Server name.
+    name: &'static str,
+    /// Server version.
+    version: &'static str,
+}
+
+/// Capabilities advertised during `initialize`.
+#[derive(Debug, Clone, Serialize)]
+struct ServerCapabilities {
+    /// Marker for `tools/list`, `tools/describe`, and `tools/call` support.
+    tools: CapabilityMarker,
+    /// Marker for `resources/list` + `resources/read` support when enabled.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    resources: Option<CapabilityMarker>,
+    /// Optional protocol features.
+    features: ServerFeatures,
+    /// Request limits enforced by the server.
+    limits: ServerLimits,
+}
+
+/// Empty capability marker serialized as `{}`.
+#[derive(Debug, Clone, Copy, Serialize)]
+struct CapabilityMarker {}
+
+/// Optional protocol features advertised during `initialize`.
+#[derive(Debug, Clone, Copy, Serialize)]
+#[allow(clippy::struct_excessive_bools, reason = "Feature flags mirror the wire format.")]
+struct ServerFeatures {
+    /// Whether JSON-RPC batch requests are accepted.
+    batch: bool,
+    /// Whether responses are streamed as server-sent events.
+    streaming: bool,
+    /// Whether a websocket transport is available.
+    websocket: bool,
+}
+
+/// Request limits advertised during `initialize`.
+#[derive(Debug, Clone, Copy, Serialize)]
+struct ServerLimits {
+    /// Maximum request body size in bytes.
+    max_body_bytes: usize,
+    /// Maximum JSON-RPC batch size when batching is supported.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    max_batch_size: Option<usize>,
+}
+
+impl ServerCapabilities {
+    /// Builds the advertised capabilities for a server configuration.
+    fn from_config(server: &crate::config::ServerConfig, resources_enabled: bool) -> Self {
+        Self {
+            tools: CapabilityMarker {},
+            resources: resources_enabled.then_some(CapabilityMarker {}),
+            features: ServerFeatures {
+                batch: false,
+                streaming: server.transport == ServerTransport::Sse,
+                websocket: false,
+            },
+            limits: ServerLimits {
+                max_body_bytes: server.max_body_bytes,
+                max_batch_size: None,
+            },
+        }
+    }
+}
+
 /// Tool output payloads for JSON-RPC responses.
 #[derive(Debug, Serialize)]
 #[serde(tag = "type", rename_all = "snake_case")]
@@ -1199,6 +1664,7 @@ struct McpRequestInfo {
 /// Dispatches a JSON-RPC request to the tool router.
 async fn handle_request(
     router: &ToolRouter,
+    capabilities: &ServerCapabilities,
     base_context: &RequestContext,
     request: JsonRpcRequest,
 ) -> (StatusCode, JsonRpcResponse, McpRequestInfo) {
@@ -1207,7 +1673,9 @@ async fn handle_request(
         return invalid_version_response(&request);
     }
     match request.method.as_str() {
+        "initialize" => handle_initialize(capabilities, request.id),
         "tools/list" => handle_tools_list(router, &context, request.id).await,
+        "tools/describe" => handle_tools_describe(router, &context, request.id).await,
         "tools/call" => handle_tools_call(router, &context, request.id, request.params).await,
         "resources/list" => {
             if router.resources_enabled() {
@@ -1269,6 +1737,40 @@ fn method_not_found_response(
     )
 }
 
+/// Handles `initialize` requests by advertising server capabilities.
+fn handle_initialize(
+    capabilities: &ServerCapabilities,
+    id: Value,
+) -> (StatusCode, JsonRpcResponse, McpRequestInfo) {
+    let info = McpRequestInfo {
+        method: McpMethod::Initialize,
+        tool: None,
+    };
+    let result = InitializeResult {
+        protocol_version: MCP_PROTOCOL_VERSION,
+        server_info: ServerInfo {
+            name: "decision-gate",
+            version: env!("CARGO_PKG_VERSION"),
+        },
+        capabilities: capabilities.clone(),
+    };
+    if let Ok(value) = serde_json::to_value(result) {
+        (
+            StatusCode::OK,
+            JsonRpcResponse {
+                jsonrpc: "2.0",
+                id,
+                result: Some(value),
+                error: None,
+            },
+            info,
+        )
+    } else {
+        let response = jsonrpc_error(id, ToolError::Serialization);
+        (response.0, response.1, info)
+    }
+}
+
 /// Handles `tools/list` requests and serializes the response.
 async fn handle_tools_list(
     router: &ToolRouter,
@@ -1306,6 +1808,43 @@ async fn handle_tools_list(
     }
 }
 
+/// Handles `tools/describe` requests and serializes the response.
+async fn handle_tools_describe(
+    router: &ToolRouter,
+    context: &RequestContext,
+    id: Value,
+) -> (StatusCode, JsonRpcResponse, McpRequestInfo) {
+    let info = McpRequestInfo {
+        method: McpMethod::ToolsDescribe,
+        tool: None,
+    };
+    match router.describe_tools(context).await {
+        Ok(tools) => {
+            if let Ok(value) = serde_json::to_value(ToolDescribeResult {
+                tools,
+            }) {
+                (
+                    StatusCode::OK,
+                    JsonRpcResponse {
+                        jsonrpc: "2.0",
+                        id,
+                        result: Some(value),
+                        error: None,
+                    },
+                    info,
+                )
+            } else {
+                let response = jsonrpc_error(id, ToolError::Serialization);
+                (response.0, response.1, info)
+            }
+        }
+        Err(err) => {
+            let response = jsonrpc_error(id, err);
+            (response.0, response.1, info)
+        }
+    }
+}
+
 /// Handles `resources/list` requests and serializes the response.
 async fn handle_resources_list(
     router: &ToolRouter,
@@ -1478,23 +2017,37 @@ struct RequestTiming {
     started_at: Instant,
 }
 
+/// JSON-RPC rejection returned before a request reaches the router.
+struct Rejection {
+    /// Request `id` to echo (`Value::Null` when unknown).
+    id: Value,
+    /// HTTP status code.
+    status: StatusCode,
+    /// JSON-RPC error code.
+    code: i64,
+    /// JSON-RPC error message.
+    message: &'static str,
+    /// Optional retry hint in milliseconds.
+    retry_after_ms: Option<u64>,
+}
+
 /// Records metrics/audit and returns a JSON-RPC error response.
 fn reject_request(
     state: &ServerState,
     context: &RequestContext,
-    status: StatusCode,
-    code: i64,
-    message: &str,
+    rejection: Rejection,
     timing: &RequestTiming,
-    retry_after_ms: Option<u64>,
 ) -> (StatusCode, JsonRpcResponse) {
-    let response = jsonrpc_error_response(
-        Value::Null,
+    let Rejection {
+        id,
+        status,
         code,
-        message.to_string(),
-        context.request_id.clone(),
+        message,
         retry_after_ms,
-    );
+    } = rejection;
+    let request_id = context.request_id.clone().or_else(|| (!id.is_null()).then(|| id.to_string()));
+    let response =
+        jsonrpc_error_response(id, code, message.to_string(), request_id, retry_after_ms);
     let info = McpRequestInfo {
         method: McpMethod::Invalid,
         tool: None,
@@ -1511,6 +2064,40 @@ fn reject_request(
     (status, response)
 }
 
+/// Recovers the request `id` from a payload already checked against the body limit.
+///
+/// Only used for malformed-request rejections; load-shedding paths never parse
+/// the body. Returns `Value::Null` when the payload is malformed or lacks an id.
+fn probe_request_id(bytes: &Bytes) -> Value {
+    serde_json::from_slice::<JsonRpcIdProbe>(bytes.as_ref()).map_or(Value::Null, |probe| probe.id)
+}
+
+/// Returns the rejection for a rate-limited caller, if any.
+///
+/// Load-shedding rejections never parse the body, so the `id` is always null.
+fn rate_limit_rejection(state: &ServerState, context: &RequestContext) -> Option<Rejection> {
+    let rate_limiter = state.rate_limiter.as_ref()?;
+    match rate_limiter.check(&rate_limit_key(context)) {
+        RateLimitDecision::Allow => None,
+        RateLimitDecision::Limited {
+            retry_after_ms,
+        } => Some(Rejection {
+            id: Value::Null,
+            status: StatusCode::TOO_MANY_REQUESTS,
+            code: -32071,
+            message: "rate limit exceeded",
+            retry_after_ms: Some(retry_after_ms),
+        }),
+        RateLimitDecision::OverCapacity => Some(Rejection {
+            id: Value::Null,
+            status: StatusCode::SERVICE_UNAVAILABLE,
+            code: -32072,
+            message: "rate limiter overloaded",
+            retry_after_ms: None,
+        }),
+    }
+}
+
 /// Parses and validates a JSON-RPC request payload.
 async fn parse_request(
     state: &ServerState,
@@ -1528,53 +2115,33 @@ async fn parse_request(
         return reject_request(
             state,
             context,
-            StatusCode::SERVICE_UNAVAILABLE,
-            -32072,
-            "server overloaded",
+            Rejection {
+                id: Value::Null,
+                status: StatusCode::SERVICE_UNAVAILABLE,
+                code: -32072,
+                message: "server overloaded",
+                retry_after_ms: None,
+            },
             &timing,
-            None,
         );
     }
 
-    if let Some(rate_limiter) = &state.rate_limiter {
-        match rate_limiter.check(&rate_limit_key(context)) {
-            RateLimitDecision::Allow => {}
-            RateLimitDecision::Limited {
-                retry_after_ms,
-            } => {
-                return reject_request(
-                    state,
-                    context,
-                    StatusCode::TOO_MANY_REQUESTS,
-                    -32071,
-                    "rate limit exceeded",
-                    &timing,
-                    Some(retry_after_ms),
-                );
-            }
-            RateLimitDecision::OverCapacity => {
-                return reject_request(
-                    state,
-                    context,
-                    StatusCode::SERVICE_UNAVAILABLE,
-                    -32072,
-                    "rate limiter overloaded",
-                    &timing,
-                    None,
-                );
-            }
-        }
+    if let Some(rejection) = rate_limit_rejection(state, context) {
+        return reject_request(state, context, rejection, &timing);
     }
 
     if bytes.len() > state.max_body_bytes {
         return reject_request(
             state,
             context,
-            StatusCode::PAYLOAD_TOO_LARGE,
-            -32070,
-            "request body too large",
+            Rejection {
+                id: Value::Null,
+                status: StatusCode::PAYLOAD_TOO_LARGE,
+                code: -32070,
+                message: "request body too large",
+                retry_after_ms: None,
+            },
             &timing,
-            None,
         );
     }
 
@@ -1584,11 +2151,14 @@ async fn parse_request(
             return reject_request(
                 state,
                 context,
-                StatusCode::BAD_REQUEST,
-                -32600,
-                "invalid json-rpc request",
+                Rejection {
+                    id: probe_request_id(bytes),
+                    status: StatusCode::BAD_REQUEST,
+                    code: -32600,
+                    message: "invalid json-rpc request",
+                    retry_after_ms: None,
+                },
                 &timing,
-                None,
             );
         }
     };
@@ -1598,7 +2168,8 @@ async fn parse_request(
         context = context.with_server_correlation_id(state.correlation.issue());
     }
     let context = context.with_request_id(request.id.to_string());
-    let (status, response, info) = handle_request(&state.router, &context, request).await;
+    let (status, response, info) =
+        handle_request(&state.router, &state.capabilities, &context, request).await;
     record_metrics(
         state,
         &context,
@@ -1987,6 +2558,7 @@ const fn error_kind_for_code(code: i64) -> Option<&'static str> {
         -32071 => Some("rate_limited"),
         -32072 => Some("inflight_limit"),
         -32073 => Some("invalid_correlation_id"),
+        -32074 => Some("store_unavailable"),
         _ => None,
     }
 }
@@ -2005,6 +2577,11 @@ fn jsonrpc_error(id: Value, error: ToolError) -> (StatusCode, JsonRpcResponse) {
         }
         ToolError::InvalidParams(message) => (StatusCode::BAD_REQUEST, -32602, message, None),
         ToolError::ResponseTooLarge(message) => (StatusCode::OK, -32070, message, None),
+        ToolError::ConcurrencyLimited(message) => (StatusCode::OK, -32072, message, None),
+        ToolError::StoreUnavailable(message)
+        | ToolError::ControlPlane(ControlPlaneError::Store(StoreError::Unavailable(message))) => {
+            (StatusCode::OK, -32074, format!("run state store unavailable: {message}"), None)
+        }
         ToolError::RateLimited {
             message,
             retry_after_ms,
@@ -2060,7 +2637,7 @@ fn error_kind_label(code: i64) -> &'static str {
 
 /// Returns true when the error code is retryable.
 const fn retryable_for_code(code: i64) -> bool {
-    matches!(code, -32071 | -32072)
+    matches!(code, -32071 | -32072 | -32074)
 }
 
 // ============================================================================
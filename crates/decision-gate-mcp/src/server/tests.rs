@@ -35,17 +35,26 @@
 
 use std::io::BufReader;
 use std::io::Cursor;
+use std::net::SocketAddr;
 use std::path::PathBuf;
 use std::sync::Arc;
 use std::sync::Mutex;
 use std::time::Duration;
 
 use axum::body::Bytes;
+use axum::extract::ConnectInfo;
+use axum::extract::Query;
 use axum::extract::State;
+use axum::http::HeaderMap;
+use axum::http::HeaderValue;
 use axum::http::StatusCode;
+use axum::http::header::CACHE_CONTROL;
 use axum::http::header::CONTENT_TYPE;
+use axum::http::header::ETAG;
+use axum::http::header::IF_NONE_MATCH;
 use axum::http::header::WWW_AUTHENTICATE;
 use axum::response::IntoResponse;
+use decision_gate_contract::ToolName;
 use decision_gate_core::EvidenceContext;
 use decision_gate_core::EvidenceQuery;
 use decision_gate_core::InMemoryDataShapeRegistry;
@@ -61,44 +70,61 @@ use decision_gate_core::StoreError;
 use decision_gate_core::TenantId;
 use decision_gate_core::Timestamp;
 use decision_gate_core::TriggerId;
+use decision_gate_core::runtime::ControlPlaneError;
+use serde_json::Value;
 use serde_json::json;
+use tokio_stream::StreamExt;
 
 use super::JsonRpcResponse;
 use super::McpServer;
+use super::RateLimitDecision;
+use super::RateLimiter;
 use super::ReadinessState;
+use super::ScenarioStreamQuery;
 use super::ServerState;
 use super::build_provider_transports;
 use super::build_response_headers;
 use super::build_schema_registry_limits;
 use super::build_server_state;
 use super::handle_health;
+use super::handle_openapi;
 use super::handle_ready;
+use super::handle_scenarios_stream;
+use super::jsonrpc_error;
 use super::parse_request;
 use super::read_framed;
 use crate::audit::McpAuditEvent;
 use crate::audit::McpAuditSink;
 use crate::audit::McpNoopAuditSink;
+use crate::audit::SecurityAuditEvent;
 use crate::auth::DefaultToolAuthz;
 use crate::auth::NoopAuditSink;
 use crate::auth::RequestContext;
 use crate::capabilities::CapabilityRegistry;
+use crate::clock::Clock;
+use crate::clock::ManualClock;
 use crate::config::DecisionGateConfig;
 use crate::config::DocsConfig;
 use crate::config::EvidencePolicyConfig;
 use crate::config::PolicyConfig;
 use crate::config::PrincipalConfig;
 use crate::config::PrincipalRoleConfig;
+use crate::config::PrincipalScopesConfig;
 use crate::config::ProviderConfig;
 use crate::config::ProviderTimeoutConfig;
 use crate::config::ProviderType;
+use crate::config::ProviderWarmUpMode;
 use crate::config::RateLimitConfig;
 use crate::config::RunStateStoreConfig;
 use crate::config::SchemaRegistryConfig;
 use crate::config::ServerAuthConfig;
 use crate::config::ServerAuthMode;
 use crate::config::ServerConfig;
+use crate::config::ServerOpenApiConfig;
+use crate::config::ServerSseConfig;
 use crate::config::ServerToolsConfig;
 use crate::config::ServerTransport;
+use crate::config::ToolVisibilityMode;
 use crate::config::TrustConfig;
 use crate::config::ValidationConfig;
 use crate::docs::DocsCatalog;
@@ -163,11 +189,13 @@ fn sample_config() -> DecisionGateConfig {
             auth: Some(ServerAuthConfig {
                 mode: ServerAuthMode::LocalOnly,
                 bearer_tokens: Vec::new(),
+                jwt: None,
                 mtls_subjects: Vec::new(),
                 allowed_tools: Vec::new(),
                 principals: vec![PrincipalConfig {
                     subject: "stdio".to_string(),
                     policy_class: Some("prod".to_string()),
+                    scopes: PrincipalScopesConfig::default(),
                     roles: vec![PrincipalRoleConfig {
                         name: "TenantAdmin".to_string(),
                         tenant_id: Some(TenantId::from_raw(100).expect("nonzero tenantid")),
@@ -176,6 +204,9 @@ fn sample_config() -> DecisionGateConfig {
                 }],
             }),
             tools: ServerToolsConfig::default(),
+            sse: ServerSseConfig::default(),
+            openapi: ServerOpenApiConfig::default(),
+            provider_warm_up: ProviderWarmUpMode::default(),
             ..ServerConfig::default()
         },
         namespace: crate::config::NamespaceConfig {
@@ -362,6 +393,7 @@ fn sample_router_with_overrides(
         tools: config.server.tools.clone(),
         docs_provider,
         tool_visibility_resolver,
+        tool_concurrency: config.server.limits.tool_concurrency.clone(),
         allow_default_namespace: config.allow_default_namespace(),
         default_namespace_tenants,
         namespace_authority: Arc::new(NoopNamespaceAuthority),
@@ -475,6 +507,8 @@ fn server_state_from_config(config: DecisionGateConfig) -> ServerState {
         audit,
         auth_challenge,
         readiness,
+        run_state_cache: _,
+        retention_worker: _,
     } = McpServer::from_config(config).expect("server");
     build_server_state(router, &config.server, metrics, audit, auth_challenge, readiness)
 }
@@ -584,6 +618,125 @@ fn metrics_recorded_for_tools_list() {
     drop(latencies);
 }
 
+#[test]
+fn tools_describe_returns_builtin_contracts() {
+    let config = sample_config();
+    let state = server_state_from_config(config);
+    let context = RequestContext::stdio();
+    let request = |method: &str| {
+        let payload = json!({ "jsonrpc": "2.0", "id": 1, "method": method });
+        let bytes = Bytes::from(serde_json::to_vec(&payload).expect("payload bytes"));
+        let response = parse_request_sync(&state, &context, &bytes);
+        assert_eq!(response.0, StatusCode::OK);
+        response.1.result.expect("result")
+    };
+    let listed: Vec<String> = request("tools/list")["tools"]
+        .as_array()
+        .expect("tools array")
+        .iter()
+        .map(|tool| tool["name"].as_str().expect("tool name").to_string())
+        .collect();
+    let expected: Vec<_> = decision_gate_contract::tooling::tool_contracts()
+        .into_iter()
+        .filter(|contract| listed.iter().any(|name| name == contract.name.as_str()))
+        .collect();
+    assert!(!expected.is_empty());
+    let described = request("tools/describe");
+    assert_eq!(described["tools"], serde_json::to_value(expected).expect("contracts"));
+}
+
+#[test]
+fn tools_describe_respects_response_size_cap() {
+    let mut config = sample_config();
+    config.provider_discovery.max_response_bytes = 1024;
+    let metrics = Arc::new(TestMetrics::default());
+    let audit = Arc::new(TestAudit::default());
+    let state = build_server_state(
+        sample_router(&config),
+        &config.server,
+        metrics.clone(),
+        audit,
+        None,
+        readiness_for_tests(),
+    );
+    let context = RequestContext::stdio();
+    let payload = json!({ "jsonrpc": "2.0", "id": 1, "method": "tools/describe" });
+    let bytes = Bytes::from(serde_json::to_vec(&payload).expect("payload bytes"));
+    let response = parse_request_sync(&state, &context, &bytes);
+    let error = response.1.error.expect("error");
+    assert_eq!(error.code, -32070);
+    assert!(error.message.contains("tools/describe response exceeds size limit"));
+
+    let events = metrics.events.lock().expect("events lock");
+    assert_eq!(events[0].method, McpMethod::ToolsDescribe);
+    assert_eq!(events[0].outcome, McpOutcome::Error);
+    drop(events);
+}
+
+fn openapi_response(state: ServerState, headers: HeaderMap) -> axum::response::Response {
+    let peer: SocketAddr = "127.0.0.1:4000".parse().expect("peer");
+    tokio::runtime::Runtime::new().expect("runtime").block_on(handle_openapi(
+        State(Arc::new(state)),
+        ConnectInfo(peer),
+        headers,
+    ))
+}
+
+#[test]
+fn openapi_endpoint_renders_visible_tools_only() {
+    let mut config = sample_config();
+    config.server.tools.mode = ToolVisibilityMode::Filter;
+    config.server.tools.denylist = vec![ToolName::ScenarioDefine.as_str().to_string()];
+    let response = openapi_response(server_state_from_config(config), HeaderMap::new());
+    assert_eq!(response.status(), StatusCode::OK);
+    assert_eq!(response.headers().get(CONTENT_TYPE).expect("content type"), "application/json");
+    assert_eq!(
+        response.headers().get(CACHE_CONTROL).expect("cache control"),
+        "private, max-age=300"
+    );
+    let body = tokio::runtime::Runtime::new()
+        .expect("runtime")
+        .block_on(axum::body::to_bytes(response.into_body(), usize::MAX))
+        .expect("body");
+    let document: serde_json::Value = serde_json::from_slice(&body).expect("openapi json");
+    assert!(document["openapi"].as_str().expect("openapi version").starts_with("3."));
+    assert!(document["paths"]["/rpc"]["post"].is_object());
+    let schemas = document["components"]["schemas"].as_object().expect("schemas");
+    assert!(schemas.contains_key("ScenarioStartToolCallParams"));
+    assert!(!schemas.contains_key("ScenarioDefineToolCallParams"));
+    assert!(!schemas.contains_key("ScenarioDefineToolCallResult"));
+}
+
+#[test]
+fn openapi_endpoint_honors_if_none_match() {
+    let response = openapi_response(sample_server_state(), HeaderMap::new());
+    let etag = response.headers().get(ETAG).expect("etag").clone();
+
+    let mut headers = HeaderMap::new();
+    headers.insert(IF_NONE_MATCH, etag.clone());
+    let response = openapi_response(sample_server_state(), headers);
+    assert_eq!(response.status(), StatusCode::NOT_MODIFIED);
+    assert_eq!(response.headers().get(ETAG), Some(&etag));
+
+    let mut headers = HeaderMap::new();
+    headers.insert(IF_NONE_MATCH, HeaderValue::from_static("\"stale\""));
+    let response = openapi_response(sample_server_state(), headers);
+    assert_eq!(response.status(), StatusCode::OK);
+}
+
+#[test]
+fn openapi_endpoint_public_cache_when_auth_exempt() {
+    let mut config = sample_config();
+    config.server.openapi = ServerOpenApiConfig {
+        require_auth: false,
+        max_age_secs: 60,
+        ..ServerOpenApiConfig::default()
+    };
+    let response = openapi_response(server_state_from_config(config), HeaderMap::new());
+    assert_eq!(response.status(), StatusCode::OK);
+    assert_eq!(response.headers().get(CACHE_CONTROL).expect("cache control"), "public, max-age=60");
+}
+
 #[test]
 fn from_config_tools_list_round_trip() {
     let config = sample_config();
@@ -763,6 +916,7 @@ fn metrics_recorded_for_unauthenticated_list() {
     config.server.auth = Some(ServerAuthConfig {
         mode: ServerAuthMode::BearerToken,
         bearer_tokens: vec!["token".to_string()],
+        jwt: None,
         mtls_subjects: Vec::new(),
         allowed_tools: Vec::new(),
         principals: Vec::new(),
@@ -812,6 +966,7 @@ fn unauthorized_response_includes_www_authenticate_header() {
     config.server.auth = Some(ServerAuthConfig {
         mode: ServerAuthMode::BearerToken,
         bearer_tokens: vec!["token".to_string()],
+        jwt: None,
         mtls_subjects: Vec::new(),
         allowed_tools: Vec::new(),
         principals: Vec::new(),
@@ -888,6 +1043,33 @@ fn rate_limit_rejects_after_threshold() {
     assert!(data.retryable);
 }
 
+#[test]
+fn rate_limit_window_refills_on_injected_clock() {
+    let clock = Arc::new(ManualClock::new(1_700_000_000));
+    let limiter = RateLimiter::with_clock(
+        RateLimitConfig {
+            max_requests: 2,
+            window_ms: 1_000,
+            max_entries: 8,
+        },
+        Arc::clone(&clock) as Arc<dyn Clock>,
+    );
+    assert!(matches!(limiter.check("peer"), RateLimitDecision::Allow));
+    assert!(matches!(limiter.check("peer"), RateLimitDecision::Allow));
+
+    clock.advance(Duration::from_millis(400));
+    assert!(matches!(
+        limiter.check("peer"),
+        RateLimitDecision::Limited {
+            retry_after_ms: 600
+        }
+    ));
+    assert!(matches!(limiter.check("other"), RateLimitDecision::Allow));
+
+    clock.advance(Duration::from_millis(600));
+    assert!(matches!(limiter.check("peer"), RateLimitDecision::Allow));
+}
+
 #[test]
 fn inflight_limit_rejects_when_exhausted() {
     let mut config = sample_config();
@@ -927,6 +1109,21 @@ fn inflight_limit_rejects_when_exhausted() {
     assert!(data.retryable);
 }
 
+#[test]
+fn store_unavailable_maps_to_retryable_error() {
+    let error = ToolError::ControlPlane(ControlPlaneError::Store(StoreError::Unavailable(
+        "circuit breaker open; retry in 100 ms".to_string(),
+    )));
+    let (status, response) = jsonrpc_error(json!(1), error);
+    assert_eq!(status, StatusCode::OK);
+    let error = response.error.expect("store error");
+    assert_eq!(error.code, -32074);
+    assert!(error.message.contains("circuit breaker open"), "{}", error.message);
+    let data = error.data.expect("error data");
+    assert_eq!(data.kind, "store_unavailable");
+    assert!(data.retryable);
+}
+
 #[test]
 fn audit_records_evidence_redaction() {
     let config = sample_config();
@@ -958,3 +1155,298 @@ fn audit_records_evidence_redaction() {
     assert_eq!(event.redaction, "evidence");
     drop(events);
 }
+
+#[test]
+fn initialize_advertises_features_and_limits() {
+    let mut config = sample_config();
+    config.server.max_body_bytes = 4096;
+    let state = build_server_state(
+        sample_router(&config),
+        &config.server,
+        Arc::new(TestMetrics::default()),
+        Arc::new(TestAudit::default()),
+        None,
+        readiness_for_tests(),
+    );
+    let payload = json!({
+        "jsonrpc": "2.0",
+        "id": 1,
+        "method": "initialize",
+        "params": { "protocolVersion": "2024-11-05", "capabilities": {} },
+    });
+    let bytes = Bytes::from(serde_json::to_vec(&payload).expect("payload bytes"));
+    let response = parse_request_sync(&state, &RequestContext::stdio(), &bytes);
+    assert_eq!(response.0, StatusCode::OK);
+    let result = response.1.result.expect("initialize result");
+    assert_eq!(result["protocolVersion"], "2024-11-05");
+    assert_eq!(result["serverInfo"]["name"], "decision-gate");
+    let capabilities = &result["capabilities"];
+    assert_eq!(capabilities["features"]["batch"], false);
+    assert_eq!(capabilities["features"]["streaming"], false);
+    assert_eq!(capabilities["features"]["websocket"], false);
+    assert_eq!(capabilities["limits"]["max_body_bytes"], 4096);
+    assert!(capabilities["limits"].get("max_batch_size").is_none());
+}
+
+#[test]
+fn initialize_advertises_streaming_for_sse_transport() {
+    let mut config = sample_config();
+    config.server.transport = ServerTransport::Sse;
+    let state = build_server_state(
+        sample_router(&config),
+        &config.server,
+        Arc::new(TestMetrics::default()),
+        Arc::new(TestAudit::default()),
+        None,
+        readiness_for_tests(),
+    );
+    let payload = json!({ "jsonrpc": "2.0", "id": 2, "method": "initialize" });
+    let bytes = Bytes::from(serde_json::to_vec(&payload).expect("payload bytes"));
+    let response = parse_request_sync(&state, &RequestContext::stdio(), &bytes);
+    let result = response.1.result.expect("initialize result");
+    assert_eq!(result["capabilities"]["features"]["streaming"], true);
+}
+
+#[test]
+fn responses_echo_request_id() {
+    let state = sample_server_state();
+    for id in [json!(42), json!("req-abc")] {
+        let payload = json!({ "jsonrpc": "2.0", "id": id, "method": "tools/list" });
+        let bytes = Bytes::from(serde_json::to_vec(&payload).expect("payload bytes"));
+        let response = parse_request_sync(&state, &RequestContext::stdio(), &bytes);
+        assert_eq!(response.0, StatusCode::OK);
+        assert_eq!(response.1.id, id);
+    }
+}
+
+#[test]
+fn error_responses_echo_request_id_in_data() {
+    let state = sample_server_state();
+    let payload = json!({ "jsonrpc": "2.0", "id": 7, "method": "no/such/method" });
+    let bytes = Bytes::from(serde_json::to_vec(&payload).expect("payload bytes"));
+    let response = parse_request_sync(&state, &RequestContext::stdio(), &bytes);
+    assert_eq!(response.1.id, json!(7));
+    let error = response.1.error.expect("error");
+    assert_eq!(error.data.expect("error data").request_id.as_deref(), Some("7"));
+}
+
+#[test]
+fn malformed_request_errors_recover_request_id() {
+    let state = sample_server_state();
+    let payload = json!({ "jsonrpc": "2.0", "id": 9 });
+    let bytes = Bytes::from(serde_json::to_vec(&payload).expect("payload bytes"));
+    let response = parse_request_sync(&state, &RequestContext::stdio(), &bytes);
+    assert_eq!(response.0, StatusCode::BAD_REQUEST);
+    assert_eq!(response.1.id, json!(9));
+    let error = response.1.error.expect("error");
+    assert_eq!(error.code, -32600);
+    assert_eq!(error.data.expect("error data").request_id.as_deref(), Some("9"));
+}
+
+#[test]
+fn overloaded_rejections_skip_request_id_probe() {
+    let mut config = sample_config();
+    config.server.limits.max_inflight = 0;
+    let state = build_server_state(
+        sample_router(&config),
+        &config.server,
+        Arc::new(TestMetrics::default()),
+        Arc::new(TestAudit::default()),
+        None,
+        readiness_for_tests(),
+    );
+    let payload = json!({ "jsonrpc": "2.0", "id": "busy-1", "method": "tools/list" });
+    let bytes = Bytes::from(serde_json::to_vec(&payload).expect("payload bytes"));
+    let response = parse_request_sync(&state, &RequestContext::stdio(), &bytes);
+    assert_eq!(response.0, StatusCode::SERVICE_UNAVAILABLE);
+    assert_eq!(response.1.id, json!(null));
+    assert_eq!(response.1.error.expect("error").code, -32072);
+}
+
+// ============================================================================
+// SECTION: SSE Streams
+// ============================================================================
+
+#[derive(Default)]
+struct SecurityKindAudit {
+    kinds: Mutex<Vec<String>>,
+}
+
+impl McpAuditSink for SecurityKindAudit {
+    fn record(&self, _event: &McpAuditEvent) {}
+
+    fn record_security(&self, event: &SecurityAuditEvent) {
+        self.kinds.lock().expect("kinds lock").push(event.kind.clone());
+    }
+}
+
+fn stream_config(sse: ServerSseConfig) -> DecisionGateConfig {
+    let mut config = sample_config();
+    config.server.transport = ServerTransport::Sse;
+    config.server.bind = Some("127.0.0.1:0".to_string());
+    config.server.sse = sse;
+    let auth = config.server.auth.as_mut().expect("sample auth");
+    let mut loopback = auth.principals[0].clone();
+    loopback.subject = "loopback".to_string();
+    auth.principals.push(loopback);
+    config
+}
+
+fn stream_state(config: &DecisionGateConfig, audit: Arc<dyn McpAuditSink>) -> Arc<ServerState> {
+    Arc::new(build_server_state(
+        sample_router(config),
+        &config.server,
+        Arc::new(TestMetrics::default()),
+        audit,
+        None,
+        readiness_for_tests(),
+    ))
+}
+
+async fn define_stream_scenarios(state: &ServerState, ids: &[&str]) {
+    for id in ids {
+        let mut spec = decision_gate_contract::examples::scenario_example();
+        spec.scenario_id = ScenarioId::new(*id);
+        spec.default_tenant_id = Some(TenantId::from_raw(100).expect("nonzero tenantid"));
+        state
+            .router
+            .handle_tool_call(&RequestContext::stdio(), "scenario_define", json!({ "spec": spec }))
+            .await
+            .expect("scenario define");
+    }
+}
+
+async fn open_scenario_stream(
+    state: Arc<ServerState>,
+    last_event_id: Option<&str>,
+) -> axum::body::BodyDataStream {
+    let mut headers = HeaderMap::new();
+    if let Some(last_event_id) = last_event_id {
+        headers.insert("last-event-id", HeaderValue::from_str(last_event_id).expect("header"));
+    }
+    let peer: SocketAddr = "127.0.0.1:4000".parse().expect("peer");
+    let query = ScenarioStreamQuery {
+        tenant_id: 100,
+        namespace_id: 1,
+    };
+    let response =
+        handle_scenarios_stream(State(state), ConnectInfo(peer), headers, Query(query)).await;
+    assert_eq!(response.status(), StatusCode::OK);
+    response.into_body().into_data_stream()
+}
+
+async fn next_frame(stream: &mut axum::body::BodyDataStream) -> Option<String> {
+    let chunk = tokio::time::timeout(Duration::from_secs(5), stream.next())
+        .await
+        .expect("stream frame before timeout")?;
+    Some(String::from_utf8(chunk.expect("body chunk").to_vec()).expect("utf8 frame"))
+}
+
+/// Runs a stream test on a current-thread runtime so task scheduling is deterministic.
+fn block_on_stream_test(test: impl std::future::Future<Output = ()>) {
+    tokio::runtime::Builder::new_current_thread()
+        .enable_all()
+        .build()
+        .expect("runtime")
+        .block_on(test);
+}
+
+fn frame_field<'a>(frame: &'a str, field: &str) -> Option<&'a str> {
+    frame.lines().find_map(|line| line.strip_prefix(field)?.strip_prefix(": "))
+}
+
+fn frame_scenario_id(frame: &str) -> String {
+    let data: Value =
+        serde_json::from_str(frame_field(frame, "data").expect("event data")).expect("event json");
+    data["scenario_id"].as_str().expect("scenario id").to_string()
+}
+
+async fn next_scenario_frames(
+    stream: &mut axum::body::BodyDataStream,
+    count: usize,
+) -> Vec<String> {
+    let mut frames = Vec::new();
+    while frames.len() < count {
+        let frame = next_frame(stream).await.expect("scenario frame");
+        if frame_field(&frame, "event") == Some("scenario") {
+            frames.push(frame);
+        }
+    }
+    frames
+}
+
+#[test]
+fn scenario_stream_emits_heartbeat_when_idle() {
+    let config = stream_config(ServerSseConfig {
+        heartbeat_interval_ms: 100,
+        ..ServerSseConfig::default()
+    });
+    let state = stream_state(&config, Arc::new(McpNoopAuditSink));
+    block_on_stream_test(async {
+        let mut stream = open_scenario_stream(Arc::clone(&state), None).await;
+        let frame = next_frame(&mut stream).await.expect("heartbeat frame");
+        assert_eq!(frame_field(&frame, "event"), Some("heartbeat"));
+    });
+}
+
+#[test]
+fn scenario_stream_resumes_after_last_event_id() {
+    let config = stream_config(ServerSseConfig::default());
+    let state = stream_state(&config, Arc::new(McpNoopAuditSink));
+    block_on_stream_test(async {
+        define_stream_scenarios(&state, &["charlie", "alpha", "bravo"]).await;
+
+        let mut stream = open_scenario_stream(Arc::clone(&state), None).await;
+        let first = next_frame(&mut stream).await.expect("first frame");
+        assert_eq!(frame_field(&first, "event"), Some("scenario"));
+        assert_eq!(frame_field(&first, "id"), Some("1"));
+        assert_eq!(frame_scenario_id(&first), "charlie");
+        drop(stream);
+
+        let mut resumed = open_scenario_stream(Arc::clone(&state), Some("1")).await;
+        let frames = next_scenario_frames(&mut resumed, 2).await;
+        let ids: Vec<_> = frames.iter().map(|frame| frame_field(frame, "id")).collect();
+        let scenarios: Vec<_> = frames.iter().map(|frame| frame_scenario_id(frame)).collect();
+        assert_eq!(ids, vec![Some("2"), Some("3")]);
+        assert_eq!(scenarios, vec!["alpha".to_string(), "bravo".to_string()]);
+    });
+}
+
+#[test]
+fn scenario_stream_resume_emits_scenarios_sorting_before_cursor() {
+    let config = stream_config(ServerSseConfig::default());
+    let state = stream_state(&config, Arc::new(McpNoopAuditSink));
+    block_on_stream_test(async {
+        define_stream_scenarios(&state, &["mike"]).await;
+        let mut stream = open_scenario_stream(Arc::clone(&state), None).await;
+        let first = next_frame(&mut stream).await.expect("first frame");
+        let last_event_id = frame_field(&first, "id").expect("event id").to_string();
+        drop(stream);
+
+        define_stream_scenarios(&state, &["alpha"]).await;
+        let mut resumed = open_scenario_stream(Arc::clone(&state), Some(&last_event_id)).await;
+        let frames = next_scenario_frames(&mut resumed, 1).await;
+        assert_eq!(frame_scenario_id(&frames[0]), "alpha");
+    });
+}
+
+#[test]
+fn scenario_stream_drops_lagging_consumer() {
+    let config = stream_config(ServerSseConfig {
+        stream_buffer: 1,
+        ..ServerSseConfig::default()
+    });
+    let audit = Arc::new(SecurityKindAudit::default());
+    let state = stream_state(&config, audit.clone());
+    block_on_stream_test(async {
+        define_stream_scenarios(&state, &["alpha", "bravo", "charlie"]).await;
+
+        let mut stream = open_scenario_stream(Arc::clone(&state), None).await;
+        let first = next_frame(&mut stream).await.expect("buffered frame");
+        assert_eq!(frame_scenario_id(&first), "alpha");
+        assert!(next_frame(&mut stream).await.is_none(), "lagging consumer should be dropped");
+    });
+    let dropped =
+        audit.kinds.lock().expect("kinds lock").iter().any(|kind| kind == "sse_consumer_dropped");
+    assert!(dropped, "dropped consumer should be audited");
+}
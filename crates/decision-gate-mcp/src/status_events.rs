@@ -0,0 +1,117 @@
+// crates/decision-gate-mcp/src/status_events.rs
+// ============================================================================
+// Module: Run Status Events
+// Description: In-process publish/subscribe for run status changes.
+// Purpose: Wake long-polling `scenario_status` requests when a run changes.
+// Dependencies: decision-gate-core, tokio
+// ============================================================================
+
+//! ## Overview
+//! Mutating scenario tools publish the run they touched; `scenario_status`
+//! long-polls subscribe and re-read run state when their run is published.
+//! Events carry only run identifiers, never run state, so waiters always
+//! re-read status through the authorized tool path. Notifications are
+//! best-effort: lagging subscribers are told to re-check rather than blocking
+//! publishers.
+//! Security posture: events are process-local and never leave the server; see
+//! `Docs/security/threat_model.md`.
+
+// ============================================================================
+// SECTION: Imports
+// ============================================================================
+
+use std::time::Duration;
+
+use decision_gate_core::NamespaceId;
+use decision_gate_core::RunId;
+use decision_gate_core::TenantId;
+use tokio::sync::broadcast;
+use tokio::sync::broadcast::error::RecvError;
+
+// ============================================================================
+// SECTION: Constants
+// ============================================================================
+
+/// Pending notifications buffered per subscriber before it lags.
+const STATUS_EVENT_CAPACITY: usize = 256;
+
+// ============================================================================
+// SECTION: Types
+// ============================================================================
+
+/// Run identity published when a run's state may have changed.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct RunStatusKey {
+    /// Tenant identifier.
+    pub tenant_id: TenantId,
+    /// Namespace identifier.
+    pub namespace_id: NamespaceId,
+    /// Run identifier.
+    pub run_id: RunId,
+}
+
+/// Broadcast hub for run status change notifications.
+#[derive(Debug, Clone)]
+pub struct RunStatusEvents {
+    /// Broadcast sender shared by all publishers.
+    sender: broadcast::Sender<RunStatusKey>,
+}
+
+impl Default for RunStatusEvents {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl RunStatusEvents {
+    /// Creates an empty status event hub.
+    #[must_use]
+    pub fn new() -> Self {
+        let (sender, _) = broadcast::channel(STATUS_EVENT_CAPACITY);
+        Self {
+            sender,
+        }
+    }
+
+    /// Publishes a possible status change for `key`.
+    ///
+    /// Publishing never blocks; the event is dropped when nobody is waiting.
+    pub fn publish(&self, key: RunStatusKey) {
+        let _ = self.sender.send(key);
+    }
+
+    /// Subscribes to notifications published after this call.
+    #[must_use]
+    pub fn subscribe(&self) -> RunStatusSubscription {
+        RunStatusSubscription {
+            receiver: self.sender.subscribe(),
+        }
+    }
+}
+
+/// Subscription to run status change notifications.
+#[derive(Debug)]
+pub struct RunStatusSubscription {
+    /// Broadcast receiver for published run keys.
+    receiver: broadcast::Receiver<RunStatusKey>,
+}
+
+impl RunStatusSubscription {
+    /// Waits up to `timeout` for a notification that may affect `key`.
+    ///
+    /// Returns `true` when `key` was published or notifications were missed,
+    /// and `false` when the timeout elapsed first.
+    pub async fn wait_for(&mut self, key: &RunStatusKey, timeout: Duration) -> bool {
+        let wait = async {
+            loop {
+                match self.receiver.recv().await {
+                    Ok(published) if &published == key => return true,
+                    Ok(_) => {}
+                    Err(RecvError::Lagged(_)) => return true,
+                    Err(RecvError::Closed) => std::future::pending::<()>().await,
+                }
+            }
+        };
+        tokio::time::timeout(timeout, wait).await.unwrap_or(false)
+    }
+}
@@ -0,0 +1,301 @@
+// crates/decision-gate-mcp/src/store_breaker.rs
+// ============================================================================
+// Module: Run State Store Circuit Breaker
+// Description: Fail-fast wrapper around the run state store.
+// Purpose: Shed load while the store is slow or unavailable instead of hanging.
+// Dependencies: decision-gate-config, decision-gate-core
+// ============================================================================
+
+//! ## Overview
+//! [`CircuitBreakerRunStateStore`] wraps a [`RunStateStore`] and tracks
+//! consecutive failed or slow calls. Once `failure_threshold` is reached the
+//! breaker opens and every call fails immediately with
+//! [`StoreError::Unavailable`], which the server reports as retryable. After
+//! `open_ms` a single call at a time probes the store; `success_threshold`
+//! consecutive successful probes close the breaker and any failure reopens
+//! it. Readiness checks pass through the breaker, so `/readyz` reports not
+//! ready while it is open.
+//! Security posture: store failures must not let untrusted callers pile up
+//! blocked requests; see `Docs/security/threat_model.md`.
+
+use std::sync::Mutex;
+use std::time::Duration;
+use std::time::Instant;
+
+use decision_gate_core::NamespaceId;
+use decision_gate_core::RunId;
+use decision_gate_core::RunState;
+use decision_gate_core::RunStateStore;
+use decision_gate_core::SharedRunStateStore;
+use decision_gate_core::StoreError;
+use decision_gate_core::TenantId;
+
+use crate::config::StoreCircuitBreakerConfig;
+
+// ============================================================================
+// SECTION: State
+// ============================================================================
+
+/// Externally visible circuit breaker state.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum CircuitState {
+    /// Calls reach the store.
+    Closed,
+    /// Calls fail fast without reaching the store.
+    Open,
+    /// Calls probe the store one at a time.
+    HalfOpen,
+}
+
+/// Internal breaker state.
+#[derive(Debug, Clone, Copy)]
+enum BreakerState {
+    /// Calls reach the store; tracks consecutive failures.
+    Closed {
+        /// Consecutive failed or slow calls.
+        failures: u32,
+    },
+    /// Calls are rejected until the deadline passes.
+    Open {
+        /// Instant after which a probe is allowed.
+        until: Instant,
+    },
+    /// Calls probe the store one at a time.
+    HalfOpen {
+        /// Consecutive successful probes.
+        successes: u32,
+        /// Whether a probe is currently in flight.
+        probing: bool,
+    },
+}
+
+/// How an admitted call was let through.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Admission {
+    /// Normal call while closed.
+    Call,
+    /// Recovery probe while half-open.
+    Probe,
+}
+
+// ============================================================================
+// SECTION: Store Wrapper
+// ============================================================================
+
+/// Run state store wrapper that fails fast while the store is unhealthy.
+///
+/// # Invariants
+/// - I/O, backend, and unavailable errors count as failures; invalid, corrupt, or
+///   version-mismatched data does not, since the store itself responded.
+/// - Calls slower than `slow_call_ms` count as failures but still return the store's result.
+/// - At most one probe is in flight while half-open.
+pub struct CircuitBreakerRunStateStore {
+    /// Wrapped store.
+    inner: SharedRunStateStore,
+    /// Consecutive failures that open the breaker.
+    failure_threshold: u32,
+    /// Consecutive successful probes that close the breaker.
+    success_threshold: u32,
+    /// Time the breaker stays open before probing.
+    open_for: Duration,
+    /// Call duration counted as a failure.
+    slow_call: Option<Duration>,
+    /// Current breaker state.
+    state: Mutex<BreakerState>,
+}
+
+impl CircuitBreakerRunStateStore {
+    /// Wraps a store with a circuit breaker using `run_state_store.circuit_breaker`.
+    #[must_use]
+    pub fn new(inner: SharedRunStateStore, config: &StoreCircuitBreakerConfig) -> Self {
+        Self {
+            inner,
+            failure_threshold: config.failure_threshold.max(1),
+            success_threshold: config.success_threshold.max(1),
+            open_for: Duration::from_millis(config.open_ms),
+            slow_call: config.slow_call_ms.map(Duration::from_millis),
+            state: Mutex::new(BreakerState::Closed {
+                failures: 0,
+            }),
+        }
+    }
+
+    /// Returns the current breaker state.
+    ///
+    /// An open breaker whose wait has elapsed reports [`CircuitState::HalfOpen`].
+    #[must_use]
+    pub fn state(&self) -> CircuitState {
+        let Ok(guard) = self.state.lock() else {
+            return CircuitState::Open;
+        };
+        match *guard {
+            BreakerState::Closed {
+                ..
+            } => CircuitState::Closed,
+            BreakerState::Open {
+                until,
+            } if Instant::now() < until => CircuitState::Open,
+            BreakerState::Open {
+                ..
+            }
+            | BreakerState::HalfOpen {
+                ..
+            } => CircuitState::HalfOpen,
+        }
+    }
+
+    /// Runs a store call through the breaker.
+    fn call<T>(&self, op: impl FnOnce() -> Result<T, StoreError>) -> Result<T, StoreError> {
+        let admission = self.admit()?;
+        let started = Instant::now();
+        let result = op();
+        let slow = self.slow_call.is_some_and(|limit| started.elapsed() > limit);
+        let failed = slow
+            || matches!(
+                result,
+                Err(StoreError::Io(_) | StoreError::Store(_) | StoreError::Unavailable(_))
+            );
+        self.record(admission, failed);
+        result
+    }
+
+    /// Admits a call or rejects it while the breaker is open.
+    fn admit(&self) -> Result<Admission, StoreError> {
+        let mut guard = self.lock()?;
+        let (next, admission) = admission(*guard, Instant::now());
+        *guard = next;
+        drop(guard);
+        admission
+    }
+
+    /// Records the outcome of an admitted call.
+    fn record(&self, admission: Admission, failed: bool) {
+        let Ok(mut guard) = self.state.lock() else {
+            return;
+        };
+        let next = match (*guard, admission) {
+            (
+                BreakerState::Closed {
+                    failures,
+                },
+                Admission::Call,
+            ) => {
+                let failures = if failed { failures.saturating_add(1) } else { 0 };
+                if failures >= self.failure_threshold {
+                    self.opened()
+                } else {
+                    BreakerState::Closed {
+                        failures,
+                    }
+                }
+            }
+            (
+                BreakerState::HalfOpen {
+                    successes, ..
+                },
+                Admission::Probe,
+            ) => {
+                let successes = successes.saturating_add(1);
+                if failed {
+                    self.opened()
+                } else if successes >= self.success_threshold {
+                    BreakerState::Closed {
+                        failures: 0,
+                    }
+                } else {
+                    BreakerState::HalfOpen {
+                        successes,
+                        probing: false,
+                    }
+                }
+            }
+            // Calls admitted before a state change do not affect the new state.
+            (state, _) => state,
+        };
+        *guard = next;
+    }
+
+    /// Returns an open state that starts now.
+    fn opened(&self) -> BreakerState {
+        BreakerState::Open {
+            until: Instant::now() + self.open_for,
+        }
+    }
+
+    /// Locks the breaker state.
+    fn lock(&self) -> Result<std::sync::MutexGuard<'_, BreakerState>, StoreError> {
+        self.state
+            .lock()
+            .map_err(|_| StoreError::Store("circuit breaker mutex poisoned".to_string()))
+    }
+}
+
+impl RunStateStore for CircuitBreakerRunStateStore {
+    fn load(
+        &self,
+        tenant_id: &TenantId,
+        namespace_id: &NamespaceId,
+        run_id: &RunId,
+    ) -> Result<Option<RunState>, StoreError> {
+        self.call(|| self.inner.load(tenant_id, namespace_id, run_id))
+    }
+
+    fn save(&self, state: &RunState) -> Result<(), StoreError> {
+        self.call(|| self.inner.save(state))
+    }
+
+    fn save_if_version(&self, state: &RunState, expected_version: u64) -> Result<(), StoreError> {
+        self.call(|| self.inner.save_if_version(state, expected_version))
+    }
+
+    fn readiness(&self) -> Result<(), StoreError> {
+        self.call(|| self.inner.readiness())
+    }
+}
+
+// ============================================================================
+// SECTION: Helpers
+// ============================================================================
+
+/// Returns the next breaker state and whether a call is admitted.
+fn admission(state: BreakerState, now: Instant) -> (BreakerState, Result<Admission, StoreError>) {
+    match state {
+        BreakerState::Closed {
+            ..
+        } => (state, Ok(Admission::Call)),
+        BreakerState::Open {
+            until,
+        } if now < until => {
+            let retry_ms = until.saturating_duration_since(now).as_millis();
+            let error =
+                StoreError::Unavailable(format!("circuit breaker open; retry in {retry_ms} ms"));
+            (state, Err(error))
+        }
+        BreakerState::HalfOpen {
+            probing: true, ..
+        } => {
+            let error = StoreError::Unavailable(
+                "circuit breaker half-open; recovery probe in flight".to_string(),
+            );
+            (state, Err(error))
+        }
+        BreakerState::Open {
+            ..
+        } => (
+            BreakerState::HalfOpen {
+                successes: 0,
+                probing: true,
+            },
+            Ok(Admission::Probe),
+        ),
+        BreakerState::HalfOpen {
+            successes, ..
+        } => (
+            BreakerState::HalfOpen {
+                successes,
+                probing: true,
+            },
+            Ok(Admission::Probe),
+        ),
+    }
+}
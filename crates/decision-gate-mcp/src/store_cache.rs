@@ -0,0 +1,348 @@
+// crates/decision-gate-mcp/src/store_cache.rs
+// ============================================================================
+// Module: Run State Cache
+// Description: Bounded in-memory cache of latest run states.
+// Purpose: Serve hot runs from memory instead of the backing store.
+// Dependencies: decision-gate-config, decision-gate-core, tokio
+// ============================================================================
+
+//! ## Overview
+//! [`CachedRunStateStore`] fronts a [`RunStateStore`] with a bounded cache of
+//! the latest state per run, evicting the least recently used entry when
+//! full. In write-through mode every save reaches the backing store before
+//! the cache is updated. In write-back mode saves are acknowledged from memory
+//! and persisted by [`CachedRunStateStore::flush`], which runs on a schedule
+//! via [`CachedRunStateStore::spawn_flusher`], before a dirty entry is
+//! evicted, when the server shuts down, and when the cache is dropped.
+//! Write-back queues every saved version rather than only the latest, and a
+//! flush writes them in save order, so the backing store's version history
+//! and state hash chain match write-through. A run whose queue reaches
+//! [`MAX_PENDING_VERSIONS`] is persisted on its next save.
+//! Security posture: the cache trusts that this process is the only writer to
+//! the backing store; write-back trades durability of unflushed saves for
+//! latency. See `Docs/security/threat_model.md`.
+
+use std::collections::BTreeMap;
+use std::collections::VecDeque;
+use std::sync::Arc;
+use std::sync::Mutex;
+use std::sync::MutexGuard;
+use std::time::Duration;
+
+use decision_gate_core::NamespaceId;
+use decision_gate_core::RunId;
+use decision_gate_core::RunState;
+use decision_gate_core::RunStateStore;
+use decision_gate_core::SharedRunStateStore;
+use decision_gate_core::StoreError;
+use decision_gate_core::TenantId;
+use decision_gate_core::check_run_version;
+
+use crate::config::RunStateCacheConfig;
+use crate::config::RunStateCacheMode;
+
+// ============================================================================
+// SECTION: Limits
+// ============================================================================
+
+/// Maximum unflushed write-back versions queued per run.
+pub const MAX_PENDING_VERSIONS: usize = 64;
+
+// ============================================================================
+// SECTION: Cache State
+// ============================================================================
+
+/// Cached run state.
+struct CacheEntry {
+    /// Latest known run state.
+    state: RunState,
+    /// Saved versions not yet persisted, oldest first (write-back only).
+    pending: VecDeque<RunState>,
+    /// Access tick used for least-recently-used eviction.
+    last_used: u64,
+}
+
+/// Cache contents guarded by one mutex.
+#[derive(Default)]
+struct CacheEntries {
+    /// Entries keyed by tenant, namespace, and run.
+    map: BTreeMap<String, CacheEntry>,
+    /// Monotonic access counter.
+    tick: u64,
+    /// Save counter; a load result is cached only if no save ran during the read.
+    generation: u64,
+}
+
+impl CacheEntries {
+    /// Returns the next access tick.
+    const fn next_tick(&mut self) -> u64 {
+        self.tick = self.tick.wrapping_add(1);
+        self.tick
+    }
+
+    /// Returns the key of the least recently used entry other than `keep`.
+    fn lru_key(&self, keep: &str) -> Option<String> {
+        self.map
+            .iter()
+            .filter(|(key, _)| key.as_str() != keep)
+            .min_by_key(|(_, entry)| entry.last_used)
+            .map(|(key, _)| key.clone())
+    }
+}
+
+// ============================================================================
+// SECTION: Store Wrapper
+// ============================================================================
+
+/// Run state store wrapper that caches the latest state per run.
+///
+/// # Invariants
+/// - At most `max_entries` runs are cached; a save that would need to evict a dirty entry persists
+///   it first and fails without changing the cache if that write fails.
+/// - Cache misses are not cached, so runs created elsewhere are still found.
+/// - Saves and flushes hold the cache lock while writing to the backing store, keeping the store
+///   and cache in save order.
+pub struct CachedRunStateStore {
+    /// Backing store.
+    inner: SharedRunStateStore,
+    /// Write policy.
+    mode: RunStateCacheMode,
+    /// Maximum cached runs.
+    max_entries: usize,
+    /// Cached entries.
+    entries: Mutex<CacheEntries>,
+}
+
+impl CachedRunStateStore {
+    /// Wraps a store with a cache configured by `run_state_store.cache`.
+    #[must_use]
+    pub fn new(inner: SharedRunStateStore, config: &RunStateCacheConfig) -> Self {
+        Self {
+            inner,
+            mode: config.mode,
+            max_entries: config.max_entries.max(1),
+            entries: Mutex::new(CacheEntries::default()),
+        }
+    }
+
+    /// Returns the configured write policy.
+    #[must_use]
+    pub const fn mode(&self) -> RunStateCacheMode {
+        self.mode
+    }
+
+    /// Returns the number of cached runs.
+    #[must_use]
+    pub fn len(&self) -> usize {
+        self.entries.lock().map_or(0, |entries| entries.map.len())
+    }
+
+    /// Returns true when no runs are cached.
+    #[must_use]
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// Returns the number of saves not yet persisted to the backing store.
+    #[must_use]
+    pub fn pending_writes(&self) -> usize {
+        self.entries
+            .lock()
+            .map_or(0, |entries| entries.map.values().map(|entry| entry.pending.len()).sum())
+    }
+
+    /// Persists every pending write-back save to the backing store.
+    ///
+    /// Returns the number of saves written. Always succeeds with zero in
+    /// write-through mode.
+    ///
+    /// # Errors
+    ///
+    /// Returns the first [`StoreError`] from the backing store; saves not yet
+    /// written stay pending for the next flush.
+    pub fn flush(&self) -> Result<usize, StoreError> {
+        let mut entries = self.lock()?;
+        let mut flushed = 0;
+        for entry in entries.map.values_mut() {
+            flushed += self.persist(entry)?;
+        }
+        drop(entries);
+        Ok(flushed)
+    }
+
+    /// Writes an entry's pending versions in save order, returning the count.
+    ///
+    /// Versions written before a failure are dropped from the queue, so a
+    /// retry resumes with the first unwritten version.
+    fn persist(&self, entry: &mut CacheEntry) -> Result<usize, StoreError> {
+        let mut written = 0;
+        while let Some(state) = entry.pending.front() {
+            self.inner.save(state)?;
+            entry.pending.pop_front();
+            written += 1;
+        }
+        Ok(written)
+    }
+
+    /// Spawns a background task flushing pending writes every `interval`.
+    ///
+    /// Failed flushes leave writes pending and are retried on the next tick.
+    #[must_use]
+    pub fn spawn_flusher(self: &Arc<Self>, interval: Duration) -> tokio::task::JoinHandle<()> {
+        let cache = Arc::clone(self);
+        tokio::spawn(async move {
+            let mut ticker = tokio::time::interval(interval);
+            ticker.set_missed_tick_behavior(tokio::time::MissedTickBehavior::Delay);
+            ticker.tick().await;
+            loop {
+                ticker.tick().await;
+                let cache = Arc::clone(&cache);
+                let _ = tokio::task::spawn_blocking(move || cache.flush()).await;
+            }
+        })
+    }
+
+    /// Inserts a state, evicting the least recently used entry when full.
+    ///
+    /// A `dirty` state is queued behind the entry's earlier pending versions.
+    fn insert(
+        &self,
+        entries: &mut CacheEntries,
+        key: String,
+        state: RunState,
+        dirty: bool,
+    ) -> Result<(), StoreError> {
+        if !entries.map.contains_key(&key)
+            && entries.map.len() >= self.max_entries
+            && let Some(victim) = entries.lru_key(&key)
+        {
+            if let Some(entry) = entries.map.get_mut(&victim) {
+                self.persist(entry)?;
+            }
+            entries.map.remove(&victim);
+        }
+        let last_used = entries.next_tick();
+        let mut pending = entries.map.remove(&key).map(|entry| entry.pending).unwrap_or_default();
+        if dirty {
+            pending.push_back(state.clone());
+        }
+        entries.map.insert(
+            key,
+            CacheEntry {
+                state,
+                pending,
+                last_used,
+            },
+        );
+        Ok(())
+    }
+
+    /// Saves a state, checking `expected_version` when given.
+    ///
+    /// Write-through checks in the backing store and drops the cached entry on
+    /// a conflict so the next load re-reads it. Write-back checks the cached
+    /// entry, falling back to the backing store for uncached runs, and first
+    /// persists the run's queue once it holds [`MAX_PENDING_VERSIONS`].
+    fn store(&self, state: &RunState, expected_version: Option<u64>) -> Result<(), StoreError> {
+        let key = cache_key(state.tenant_id, state.namespace_id, &state.run_id);
+        let mut entries = self.lock()?;
+        let dirty = match (self.mode, expected_version) {
+            (RunStateCacheMode::WriteThrough, None) => {
+                self.inner.save(state)?;
+                false
+            }
+            (RunStateCacheMode::WriteThrough, Some(expected_version)) => {
+                let saved = self.inner.save_if_version(state, expected_version);
+                if matches!(saved, Err(StoreError::VersionConflict { .. })) {
+                    entries.map.remove(&key);
+                    entries.generation = entries.generation.wrapping_add(1);
+                }
+                saved?;
+                false
+            }
+            (RunStateCacheMode::WriteBack, None) => true,
+            (RunStateCacheMode::WriteBack, Some(expected_version)) => {
+                if let Some(entry) = entries.map.get(&key) {
+                    check_run_version(Some(&entry.state), expected_version)?;
+                } else {
+                    let current =
+                        self.inner.load(&state.tenant_id, &state.namespace_id, &state.run_id)?;
+                    check_run_version(current.as_ref(), expected_version)?;
+                }
+                true
+            }
+        };
+        if dirty
+            && let Some(entry) = entries.map.get_mut(&key)
+            && entry.pending.len() >= MAX_PENDING_VERSIONS
+        {
+            self.persist(entry)?;
+        }
+        entries.generation = entries.generation.wrapping_add(1);
+        let inserted = self.insert(&mut entries, key, state.clone(), dirty);
+        drop(entries);
+        inserted
+    }
+
+    /// Locks the cache entries.
+    fn lock(&self) -> Result<MutexGuard<'_, CacheEntries>, StoreError> {
+        self.entries
+            .lock()
+            .map_err(|_| StoreError::Store("run state cache mutex poisoned".to_string()))
+    }
+}
+
+impl RunStateStore for CachedRunStateStore {
+    fn load(
+        &self,
+        tenant_id: &TenantId,
+        namespace_id: &NamespaceId,
+        run_id: &RunId,
+    ) -> Result<Option<RunState>, StoreError> {
+        let key = cache_key(*tenant_id, *namespace_id, run_id);
+        let generation = {
+            let mut entries = self.lock()?;
+            let tick = entries.next_tick();
+            if let Some(entry) = entries.map.get_mut(&key) {
+                entry.last_used = tick;
+                return Ok(Some(entry.state.clone()));
+            }
+            entries.generation
+        };
+        let loaded = self.inner.load(tenant_id, namespace_id, run_id)?;
+        if let Some(state) = &loaded {
+            let mut entries = self.lock()?;
+            if entries.generation == generation && !entries.map.contains_key(&key) {
+                self.insert(&mut entries, key, state.clone(), false)?;
+            }
+        }
+        Ok(loaded)
+    }
+
+    fn save(&self, state: &RunState) -> Result<(), StoreError> {
+        self.store(state, None)
+    }
+
+    fn save_if_version(&self, state: &RunState, expected_version: u64) -> Result<(), StoreError> {
+        self.store(state, Some(expected_version))
+    }
+
+    fn readiness(&self) -> Result<(), StoreError> {
+        self.inner.readiness()
+    }
+}
+
+impl Drop for CachedRunStateStore {
+    fn drop(&mut self) {
+        let _ = self.flush();
+    }
+}
+
+// ============================================================================
+// SECTION: Helpers
+// ============================================================================
+
+/// Builds the cache key for a run.
+fn cache_key(tenant_id: TenantId, namespace_id: NamespaceId, run_id: &RunId) -> String {
+    format!("{tenant_id}/{namespace_id}/{run_id}")
+}
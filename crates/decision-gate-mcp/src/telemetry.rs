@@ -8,9 +8,10 @@
 
 //! ## Overview
 //! This module exposes a thin metrics interface for MCP request counters and
-//! latency histograms. It is intentionally dependency-light so downstream
-//! deployments can plug in Prometheus or OpenTelemetry without redesign.
-//! Security posture: telemetry must avoid leaking raw evidence or secrets and
+//! latency histograms, plus per-provider evidence query counters and latencies
+//! labeled by provider id, and per-tool concurrency queueing events. It is intentionally
+//! dependency-light so downstream deployments can plug in Prometheus or OpenTelemetry without
+//! redesign. Security posture: telemetry must avoid leaking raw evidence or secrets and
 //! treat labels as untrusted; see `Docs/security/threat_model.md`.
 
 // ============================================================================
@@ -31,6 +32,12 @@ use crate::config::ServerTransport;
 pub const MCP_LATENCY_BUCKETS_MS: &[u64] =
     &[1, 2, 5, 10, 25, 50, 100, 250, 500, 1_000, 2_500, 5_000, 10_000, 30_000];
 
+/// Provider label used for queries that target an unconfigured provider.
+///
+/// Query provider ids come from untrusted specs; collapsing unknown ids keeps
+/// label cardinality bounded by configuration.
+pub const UNREGISTERED_PROVIDER_LABEL: &str = "unregistered";
+
 // ============================================================================
 // SECTION: Metric Labels
 // ============================================================================
@@ -41,8 +48,12 @@ pub const MCP_LATENCY_BUCKETS_MS: &[u64] =
 /// - Variants are stable for telemetry labeling.
 #[derive(Debug, Clone, Copy, PartialEq, Eq, serde::Serialize)]
 pub enum McpMethod {
+    /// JSON-RPC initialize handshake.
+    Initialize,
     /// JSON-RPC tools/list.
     ToolsList,
+    /// JSON-RPC tools/describe.
+    ToolsDescribe,
     /// JSON-RPC tools/call.
     ToolsCall,
     /// JSON-RPC resources/list.
@@ -60,7 +71,9 @@ impl McpMethod {
     #[must_use]
     pub const fn as_str(self) -> &'static str {
         match self {
+            Self::Initialize => "initialize",
             Self::ToolsList => "tools/list",
+            Self::ToolsDescribe => "tools/describe",
             Self::ToolsCall => "tools/call",
             Self::ResourcesList => "resources/list",
             Self::ResourcesRead => "resources/read",
@@ -121,6 +134,64 @@ pub struct McpMetricEvent {
     pub response_bytes: usize,
 }
 
+/// Evidence provider query metric event payload.
+///
+/// # Invariants
+/// - `provider_id` is a configured provider name or [`UNREGISTERED_PROVIDER_LABEL`].
+/// - `error_kind` is `None` when `outcome` is [`McpOutcome::Ok`].
+/// - `cache_hit` is `None` when the query path has no evidence cache.
+#[derive(Debug, Clone)]
+pub struct ProviderMetricEvent {
+    /// Provider identifier label.
+    pub provider_id: String,
+    /// Query outcome; results carrying provider error metadata count as errors.
+    pub outcome: McpOutcome,
+    /// Normalized error kind label.
+    pub error_kind: Option<&'static str>,
+    /// Whether the result was served from a cache.
+    pub cache_hit: Option<bool>,
+}
+
+/// Outcome of a tool call that found its concurrency limit saturated.
+///
+/// # Invariants
+/// - Variants are stable for telemetry labeling.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, serde::Serialize)]
+pub enum ToolQueueOutcome {
+    /// The call waited and then acquired a slot.
+    Admitted,
+    /// The call waited `max_queue_wait_ms` without acquiring a slot.
+    TimedOut,
+    /// The call was rejected without waiting (queueing disabled).
+    Rejected,
+}
+
+impl ToolQueueOutcome {
+    /// Returns a stable label for the outcome.
+    #[must_use]
+    pub const fn as_str(self) -> &'static str {
+        match self {
+            Self::Admitted => "admitted",
+            Self::TimedOut => "timed_out",
+            Self::Rejected => "rejected",
+        }
+    }
+}
+
+/// Per-tool concurrency queue metric event payload.
+///
+/// # Invariants
+/// - Emitted only when a call could not acquire a slot immediately.
+#[derive(Debug, Clone)]
+pub struct ToolQueueMetricEvent {
+    /// Tool whose concurrency limit was saturated.
+    pub tool: ToolName,
+    /// Queueing outcome.
+    pub outcome: ToolQueueOutcome,
+    /// Configured concurrency limit for the tool.
+    pub max_concurrent: usize,
+}
+
 // ============================================================================
 // SECTION: Trait
 // ============================================================================
@@ -131,6 +202,12 @@ pub trait McpMetrics: Send + Sync {
     fn record_request(&self, event: McpMetricEvent);
     /// Records a latency observation for the request.
     fn record_latency(&self, event: McpMetricEvent, latency: Duration);
+    /// Records an evidence provider query counter event.
+    fn record_provider_query(&self, _event: ProviderMetricEvent) {}
+    /// Records a latency observation for an evidence provider query.
+    fn record_provider_latency(&self, _event: ProviderMetricEvent, _latency: Duration) {}
+    /// Records a saturated tool concurrency limit and the time spent queued.
+    fn record_tool_queue(&self, _event: ToolQueueMetricEvent, _waited: Duration) {}
 }
 
 /// No-op metrics sink.
@@ -0,0 +1,549 @@
+// crates/decision-gate-mcp/src/tls.rs
+// ============================================================================
+// Module: MCP Server TLS
+// Description: TLS material loading, client cert policy, and hot-reload.
+// Purpose: Build rustls server configs and rotate certificates without restart.
+// Dependencies: rustls, axum-server, x509-parser
+// ============================================================================
+
+//! ## Overview
+//! Loads PEM-encoded server certificates, keys, optional client CA bundles,
+//! and an optional DER OCSP response (stapled to the server certificate)
+//! into a rustls server config. When `server.tls.reload_interval_ms` is set,
+//! a [`TlsReloader`] polls the TLS files and swaps the active config for new
+//! connections when their contents change.
+//!
+//! Client certificates are verified against the CA bundle and then against
+//! the optional chain depth and required EKU policy. Rejected client
+//! certificates fail the handshake and are recorded as
+//! `tls_client_cert_rejected` security audit events.
+//!
+//! Invariants:
+//! - Replacement material is fully validated (parsed, key matches chain) before it is swapped in.
+//! - Invalid replacements are rejected and the previous config stays active.
+//! - Existing connections keep the config they were accepted with.
+//! - Required EKUs must be listed explicitly; `anyExtendedKeyUsage` does not satisfy them.
+//!
+//! Security posture: TLS files are untrusted input and are size-bounded; see
+//! `Docs/security/threat_model.md`.
+
+// ============================================================================
+// SECTION: Imports
+// ============================================================================
+
+use std::collections::BTreeSet;
+use std::fmt;
+use std::fs;
+use std::io::Read;
+use std::sync::Arc;
+use std::time::Duration;
+
+use axum_server::tls_rustls::RustlsConfig;
+use rustls::CertificateError;
+use rustls::DigitallySignedStruct;
+use rustls::DistinguishedName;
+use rustls::OtherError;
+use rustls::RootCertStore;
+use rustls::SignatureScheme;
+use rustls::client::danger::HandshakeSignatureValid;
+use rustls::pki_types::CertificateDer;
+use rustls::pki_types::PrivateKeyDer;
+use rustls::pki_types::UnixTime;
+use rustls::server::WebPkiClientVerifier;
+use rustls::server::danger::ClientCertVerified;
+use rustls::server::danger::ClientCertVerifier;
+use rustls_pki_types::pem::PemObject;
+use thiserror::Error;
+use x509_parser::certificate::X509Certificate;
+use x509_parser::prelude::FromDer;
+
+use crate::audit::McpAuditSink;
+use crate::audit::SecurityAuditEvent;
+use crate::audit::SecurityAuditEventParams;
+use crate::config::ServerTlsConfig;
+use crate::server::McpServerError;
+
+// ============================================================================
+// SECTION: Constants
+// ============================================================================
+
+/// Maximum size of a single TLS PEM file in bytes.
+pub(crate) const MAX_TLS_FILE_BYTES: usize = 1024 * 1024;
+/// OIDs of the well-known EKU flags decoded by x509-parser, in field order.
+const KNOWN_EKU_OIDS: [&str; 7] = [
+    "2.5.29.37.0",
+    "1.3.6.1.5.5.7.3.1",
+    "1.3.6.1.5.5.7.3.2",
+    "1.3.6.1.5.5.7.3.3",
+    "1.3.6.1.5.5.7.3.4",
+    "1.3.6.1.5.5.7.3.8",
+    "1.3.6.1.5.5.7.3.9",
+];
+
+// ============================================================================
+// SECTION: TLS Material
+// ============================================================================
+
+/// Raw TLS file contents used to build configs and detect rotation.
+#[derive(Debug, Clone, PartialEq, Eq)]
+struct TlsMaterial {
+    /// Server certificate chain PEM bytes.
+    cert: Vec<u8>,
+    /// Server private key PEM bytes.
+    key: Vec<u8>,
+    /// Optional client CA bundle PEM bytes.
+    client_ca: Option<Vec<u8>>,
+    /// Optional DER-encoded OCSP response bytes.
+    ocsp: Option<Vec<u8>>,
+}
+
+impl TlsMaterial {
+    /// Reads all TLS files referenced by the config.
+    fn read(config: &ServerTlsConfig) -> Result<Self, McpServerError> {
+        let cert = read_tls_file("cert", &config.cert_path)?;
+        let key = read_tls_file("key", &config.key_path)?;
+        let client_ca =
+            config.client_ca_path.as_deref().map(|path| read_tls_file("ca", path)).transpose()?;
+        let ocsp = config
+            .ocsp_response_path
+            .as_deref()
+            .map(|path| read_tls_file("ocsp", path))
+            .transpose()?;
+        Ok(Self {
+            cert,
+            key,
+            client_ca,
+            ocsp,
+        })
+    }
+}
+
+/// Reads a TLS PEM file with a hard size limit.
+fn read_tls_file(label: &str, path: &str) -> Result<Vec<u8>, McpServerError> {
+    let file = fs::File::open(path)
+        .map_err(|err| McpServerError::Config(format!("tls {label} read failed: {err}")))?;
+    let mut limited = file.take((MAX_TLS_FILE_BYTES + 1) as u64);
+    let mut bytes = Vec::new();
+    limited
+        .read_to_end(&mut bytes)
+        .map_err(|err| McpServerError::Config(format!("tls {label} read failed: {err}")))?;
+    if bytes.len() > MAX_TLS_FILE_BYTES {
+        return Err(McpServerError::Config(format!("tls {label} file exceeds size limit")));
+    }
+    Ok(bytes)
+}
+
+// ============================================================================
+// SECTION: Config Construction
+// ============================================================================
+
+/// Builds a TLS config for HTTP/SSE transports.
+///
+/// Client certificate rejections are recorded to `audit`.
+///
+/// # Errors
+///
+/// Returns [`McpServerError`] when TLS files are unreadable or invalid.
+pub fn build_tls_config(
+    config: &ServerTlsConfig,
+    audit: &Arc<dyn McpAuditSink>,
+) -> Result<RustlsConfig, McpServerError> {
+    let material = TlsMaterial::read(config)?;
+    let server_config = build_server_config(config, &material, audit)?;
+    Ok(RustlsConfig::from_config(server_config))
+}
+
+/// Builds and validates a rustls server config from TLS material.
+fn build_server_config(
+    config: &ServerTlsConfig,
+    material: &TlsMaterial,
+    audit: &Arc<dyn McpAuditSink>,
+) -> Result<Arc<rustls::ServerConfig>, McpServerError> {
+    let _ = rustls::crypto::aws_lc_rs::default_provider().install_default();
+    let certs = parse_certificates(&material.cert)?;
+    let key = parse_private_key(&material.key)?;
+    let builder = if let Some(ca) = &material.client_ca {
+        let verifier = build_client_verifier(config, ca, audit)?;
+        rustls::ServerConfig::builder().with_client_cert_verifier(verifier)
+    } else {
+        rustls::ServerConfig::builder().with_no_client_auth()
+    };
+    let server_config = match &material.ocsp {
+        Some(ocsp) => builder.with_single_cert_with_ocsp(certs, key, ocsp.clone()),
+        None => builder.with_single_cert(certs, key),
+    };
+    let mut server_config = server_config
+        .map_err(|err| McpServerError::Config(format!("tls config invalid: {err}")))?;
+    server_config.alpn_protocols = vec![b"h2".to_vec(), b"http/1.1".to_vec()];
+    Ok(Arc::new(server_config))
+}
+
+/// Builds the client certificate verifier with the configured policy.
+fn build_client_verifier(
+    config: &ServerTlsConfig,
+    ca: &[u8],
+    audit: &Arc<dyn McpAuditSink>,
+) -> Result<Arc<dyn ClientCertVerifier>, McpServerError> {
+    let roots = Arc::new(parse_root_store(ca)?);
+    let inner = if config.require_client_cert {
+        WebPkiClientVerifier::builder(roots)
+    } else {
+        WebPkiClientVerifier::builder(roots).allow_unauthenticated()
+    }
+    .build()
+    .map_err(|err| McpServerError::Config(format!("tls client verifier failed: {err}")))?;
+    Ok(Arc::new(ClientCertPolicyVerifier {
+        inner,
+        max_chain_depth: config.max_client_chain_depth,
+        required_ekus: config.client_required_ekus.iter().cloned().collect(),
+        audit: Arc::clone(audit),
+    }))
+}
+
+/// Parses a PEM-encoded certificate chain.
+fn parse_certificates(pem: &[u8]) -> Result<Vec<CertificateDer<'static>>, McpServerError> {
+    let certs = CertificateDer::pem_slice_iter(pem)
+        .map(|item| {
+            item.map_err(|err| McpServerError::Config(format!("tls cert read failed: {err}")))
+        })
+        .collect::<Result<Vec<_>, _>>()?;
+    if certs.is_empty() {
+        return Err(McpServerError::Config("tls cert file contains no certificates".to_string()));
+    }
+    Ok(certs)
+}
+
+/// Parses a PEM-encoded private key.
+fn parse_private_key(pem: &[u8]) -> Result<PrivateKeyDer<'static>, McpServerError> {
+    PrivateKeyDer::from_pem_slice(pem)
+        .map_err(|err| McpServerError::Config(format!("tls key read failed: {err}")))
+}
+
+/// Parses a PEM-encoded CA bundle into a root store.
+fn parse_root_store(pem: &[u8]) -> Result<RootCertStore, McpServerError> {
+    let certs = CertificateDer::pem_slice_iter(pem)
+        .map(|item| {
+            item.map_err(|err| McpServerError::Config(format!("tls ca read failed: {err}")))
+        })
+        .collect::<Result<Vec<_>, _>>()?;
+    if certs.is_empty() {
+        return Err(McpServerError::Config(
+            "tls client ca file contains no certificates".to_string(),
+        ));
+    }
+    let mut store = RootCertStore::empty();
+    for cert in certs {
+        store.add(cert).map_err(|err| McpServerError::Config(format!("tls ca invalid: {err}")))?;
+    }
+    Ok(store)
+}
+
+// ============================================================================
+// SECTION: Client Certificate Policy
+// ============================================================================
+
+/// Client certificate policy violations raised after chain verification.
+#[derive(Debug, Error)]
+enum ClientCertPolicyError {
+    /// Presented chain is longer than the configured depth.
+    #[error("client certificate chain depth {depth} exceeds limit {limit}")]
+    ChainTooDeep {
+        /// Presented chain depth, leaf included.
+        depth: usize,
+        /// Configured maximum depth.
+        limit: usize,
+    },
+    /// Leaf certificate lacks a required extended key usage.
+    #[error("client certificate missing required extended key usage {0}")]
+    MissingEku(String),
+    /// Leaf certificate could not be parsed for policy checks.
+    #[error("client certificate could not be parsed")]
+    Unparseable,
+}
+
+impl From<ClientCertPolicyError> for rustls::Error {
+    fn from(error: ClientCertPolicyError) -> Self {
+        Self::InvalidCertificate(CertificateError::Other(OtherError(Arc::new(error))))
+    }
+}
+
+/// Client certificate verifier enforcing chain depth and EKU policy on top of
+/// CA chain verification.
+///
+/// # Invariants
+/// - Policy checks run only after the inner verifier accepts the chain.
+/// - Every rejection is recorded to the audit sink.
+struct ClientCertPolicyVerifier {
+    /// CA chain verifier.
+    inner: Arc<dyn ClientCertVerifier>,
+    /// Optional maximum presented chain depth, leaf included.
+    max_chain_depth: Option<usize>,
+    /// EKU OIDs the leaf certificate must carry.
+    required_ekus: BTreeSet<String>,
+    /// Audit sink for rejected client certificates.
+    audit: Arc<dyn McpAuditSink>,
+}
+
+impl fmt::Debug for ClientCertPolicyVerifier {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("ClientCertPolicyVerifier")
+            .field("max_chain_depth", &self.max_chain_depth)
+            .field("required_ekus", &self.required_ekus)
+            .finish_non_exhaustive()
+    }
+}
+
+impl ClientCertPolicyVerifier {
+    /// Applies chain depth and EKU policy to a CA-verified chain.
+    fn check_policy(
+        &self,
+        end_entity: &CertificateDer<'_>,
+        intermediates: &[CertificateDer<'_>],
+    ) -> Result<(), ClientCertPolicyError> {
+        let depth = intermediates.len() + 1;
+        if let Some(limit) = self.max_chain_depth
+            && depth > limit
+        {
+            return Err(ClientCertPolicyError::ChainTooDeep {
+                depth,
+                limit,
+            });
+        }
+        if self.required_ekus.is_empty() {
+            return Ok(());
+        }
+        let present = certificate_ekus(end_entity)?;
+        if let Some(missing) = self.required_ekus.iter().find(|oid| !present.contains(*oid)) {
+            return Err(ClientCertPolicyError::MissingEku(missing.clone()));
+        }
+        Ok(())
+    }
+
+    /// Records a rejected client certificate as a security audit event.
+    fn record_rejection(&self, error: &rustls::Error) {
+        self.audit.record_security(&SecurityAuditEvent::new(SecurityAuditEventParams {
+            kind: "tls_client_cert_rejected".to_string(),
+            message: Some(format!("client certificate rejected: {}", describe_rejection(error))),
+            unsafe_client_correlation_id: None,
+            server_correlation_id: None,
+            dev_permissive: false,
+            namespace_authority: "n/a".to_string(),
+        }));
+    }
+}
+
+impl ClientCertVerifier for ClientCertPolicyVerifier {
+    fn offer_client_auth(&self) -> bool {
+        self.inner.offer_client_auth()
+    }
+
+    fn client_auth_mandatory(&self) -> bool {
+        self.inner.client_auth_mandatory()
+    }
+
+    fn root_hint_subjects(&self) -> &[DistinguishedName] {
+        self.inner.root_hint_subjects()
+    }
+
+    fn verify_client_cert(
+        &self,
+        end_entity: &CertificateDer<'_>,
+        intermediates: &[CertificateDer<'_>],
+        now: UnixTime,
+    ) -> Result<ClientCertVerified, rustls::Error> {
+        let result =
+            self.inner.verify_client_cert(end_entity, intermediates, now).and_then(|verified| {
+                self.check_policy(end_entity, intermediates)?;
+                Ok(verified)
+            });
+        if let Err(error) = &result {
+            self.record_rejection(error);
+        }
+        result
+    }
+
+    fn verify_tls12_signature(
+        &self,
+        message: &[u8],
+        cert: &CertificateDer<'_>,
+        dss: &DigitallySignedStruct,
+    ) -> Result<HandshakeSignatureValid, rustls::Error> {
+        self.inner.verify_tls12_signature(message, cert, dss)
+    }
+
+    fn verify_tls13_signature(
+        &self,
+        message: &[u8],
+        cert: &CertificateDer<'_>,
+        dss: &DigitallySignedStruct,
+    ) -> Result<HandshakeSignatureValid, rustls::Error> {
+        self.inner.verify_tls13_signature(message, cert, dss)
+    }
+
+    fn supported_verify_schemes(&self) -> Vec<SignatureScheme> {
+        self.inner.supported_verify_schemes()
+    }
+}
+
+/// Renders a client certificate rejection as a readable reason.
+fn describe_rejection(error: &rustls::Error) -> String {
+    match error {
+        rustls::Error::InvalidCertificate(CertificateError::Other(other)) => other.to_string(),
+        _ => error.to_string(),
+    }
+}
+
+/// Returns the dotted EKU OIDs carried by a DER certificate.
+fn certificate_ekus(der: &[u8]) -> Result<BTreeSet<String>, ClientCertPolicyError> {
+    let (_, cert) =
+        X509Certificate::from_der(der).map_err(|_| ClientCertPolicyError::Unparseable)?;
+    let Some(extension) =
+        cert.extended_key_usage().map_err(|_| ClientCertPolicyError::Unparseable)?
+    else {
+        return Ok(BTreeSet::new());
+    };
+    let eku = extension.value;
+    let flags = [
+        eku.any,
+        eku.server_auth,
+        eku.client_auth,
+        eku.code_signing,
+        eku.email_protection,
+        eku.time_stamping,
+        eku.ocsp_signing,
+    ];
+    let mut oids: BTreeSet<String> = KNOWN_EKU_OIDS
+        .iter()
+        .zip(flags)
+        .filter(|(_, present)| *present)
+        .map(|(oid, _)| (*oid).to_string())
+        .collect();
+    oids.extend(eku.other.iter().map(x509_parser::oid_registry::Oid::to_id_string));
+    Ok(oids)
+}
+
+// ============================================================================
+// SECTION: Hot Reload
+// ============================================================================
+
+/// Result of a single TLS reload poll.
+///
+/// # Invariants
+/// - `Rejected` never changes the active TLS config.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum TlsReloadOutcome {
+    /// TLS files are unchanged since the last poll.
+    Unchanged,
+    /// New TLS material was validated and swapped in.
+    Reloaded,
+    /// New TLS material failed validation; the previous config remains active.
+    Rejected(String),
+}
+
+/// Polls TLS files and swaps the active rustls config on change.
+///
+/// # Invariants
+/// - `active` always reflects the material backing the served config.
+/// - `last_seen` suppresses repeated rejection of the same invalid material.
+pub struct TlsReloader {
+    /// TLS file configuration.
+    config: ServerTlsConfig,
+    /// Audit sink for reload outcomes and client certificate rejections.
+    audit: Arc<dyn McpAuditSink>,
+    /// Shared rustls config handed to the listener.
+    rustls: RustlsConfig,
+    /// Material backing the active config.
+    active: TlsMaterial,
+    /// Most recently observed material (valid or not).
+    last_seen: TlsMaterial,
+}
+
+impl TlsReloader {
+    /// Loads the initial TLS config for hot-reload.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`McpServerError`] when the initial TLS material is invalid.
+    pub fn new(
+        config: &ServerTlsConfig,
+        audit: Arc<dyn McpAuditSink>,
+    ) -> Result<Self, McpServerError> {
+        let material = TlsMaterial::read(config)?;
+        let server_config = build_server_config(config, &material, &audit)?;
+        Ok(Self {
+            config: config.clone(),
+            audit,
+            rustls: RustlsConfig::from_config(server_config),
+            active: material.clone(),
+            last_seen: material,
+        })
+    }
+
+    /// Returns the shared rustls config used by the listener.
+    #[must_use]
+    pub fn rustls_config(&self) -> RustlsConfig {
+        self.rustls.clone()
+    }
+
+    /// Checks TLS files once and reloads when their contents changed.
+    pub fn poll(&mut self) -> TlsReloadOutcome {
+        let material = match TlsMaterial::read(&self.config) {
+            Ok(material) => material,
+            Err(err) => return TlsReloadOutcome::Rejected(err.to_string()),
+        };
+        if material == self.last_seen {
+            return TlsReloadOutcome::Unchanged;
+        }
+        self.last_seen = material.clone();
+        if material == self.active {
+            return TlsReloadOutcome::Unchanged;
+        }
+        match build_server_config(&self.config, &material, &self.audit) {
+            Ok(server_config) => {
+                self.rustls.reload_from_config(server_config);
+                self.active = material;
+                TlsReloadOutcome::Reloaded
+            }
+            Err(err) => TlsReloadOutcome::Rejected(err.to_string()),
+        }
+    }
+
+    /// Spawns a background task polling TLS files at `interval`.
+    ///
+    /// Reloads and rejections are recorded as security audit events.
+    #[must_use]
+    pub fn spawn(mut self, interval: Duration) -> tokio::task::JoinHandle<()> {
+        tokio::spawn(async move {
+            let mut ticker = tokio::time::interval(interval);
+            ticker.set_missed_tick_behavior(tokio::time::MissedTickBehavior::Delay);
+            ticker.tick().await;
+            loop {
+                ticker.tick().await;
+                let (kind, message) = match self.poll() {
+                    TlsReloadOutcome::Unchanged => continue,
+                    TlsReloadOutcome::Reloaded => {
+                        ("tls_reloaded", "tls certificate reloaded".to_string())
+                    }
+                    TlsReloadOutcome::Rejected(reason) => {
+                        ("tls_reload_rejected", format!("tls reload rejected: {reason}"))
+                    }
+                };
+                self.audit.record_security(&SecurityAuditEvent::new(SecurityAuditEventParams {
+                    kind: kind.to_string(),
+                    message: Some(message),
+                    unsafe_client_correlation_id: None,
+                    server_correlation_id: None,
+                    dev_permissive: false,
+                    namespace_authority: "n/a".to_string(),
+                }));
+            }
+        })
+    }
+}
+
+// ============================================================================
+// SECTION: Tests
+// ============================================================================
+
+#[cfg(test)]
+mod tests;
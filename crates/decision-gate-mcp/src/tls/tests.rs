@@ -0,0 +1,369 @@
+// crates/decision-gate-mcp/src/tls/tests.rs
+// ============================================================================
+// Module: Server TLS Tests
+// Description: Unit tests for TLS config construction, client cert policy, and hot-reload.
+// Purpose: Validate rotation pickup and fail-closed rejection of bad certs.
+// Dependencies: decision-gate-mcp, rcgen
+// ============================================================================
+
+//! ## Overview
+//! Exercises the TLS reloader with generated test certificates: a rotated
+//! certificate is picked up, while invalid replacements are rejected and the
+//! previously active config keeps serving. Client certificate policy tests
+//! cover EKU and chain depth enforcement and audit of rejections.
+//!
+//! Security posture: Tests cover untrusted TLS file input; see
+//! `Docs/security/threat_model.md`.
+
+// ============================================================================
+// SECTION: Lint Configuration
+// ============================================================================
+
+#![allow(
+    clippy::unwrap_used,
+    clippy::expect_used,
+    clippy::panic,
+    clippy::use_debug,
+    reason = "Test-only assertions use unwrap/expect for clarity."
+)]
+
+// ============================================================================
+// SECTION: Imports
+// ============================================================================
+
+use std::fs;
+use std::path::Path;
+use std::sync::Arc;
+use std::sync::Mutex;
+
+use rcgen::BasicConstraints;
+use rcgen::CertificateParams;
+use rcgen::CertifiedKey;
+use rcgen::DnType;
+use rcgen::ExtendedKeyUsagePurpose;
+use rcgen::IsCa;
+use rcgen::Issuer;
+use rcgen::KeyPair;
+use rcgen::generate_simple_self_signed;
+use rustls::pki_types::CertificateDer;
+use rustls::pki_types::UnixTime;
+use tempfile::TempDir;
+
+use super::TlsReloadOutcome;
+use super::TlsReloader;
+use super::build_client_verifier;
+use super::build_tls_config;
+use super::describe_rejection;
+use crate::audit::McpAuditEvent;
+use crate::audit::McpAuditSink;
+use crate::audit::McpNoopAuditSink;
+use crate::audit::SecurityAuditEvent;
+use crate::config::ServerTlsConfig;
+
+/// Custom EKU OID used to exercise required EKU policy.
+const CUSTOM_EKU: [u64; 9] = [1, 3, 6, 1, 4, 1, 99999, 1, 1];
+/// Dotted form of [`CUSTOM_EKU`].
+const CUSTOM_EKU_DOTTED: &str = "1.3.6.1.4.1.99999.1.1";
+
+// ============================================================================
+// SECTION: Helpers
+// ============================================================================
+
+/// Generates a PEM certificate and key pair for `localhost`.
+fn generate_pem() -> (String, String) {
+    let CertifiedKey {
+        cert,
+        signing_key,
+    } = generate_simple_self_signed(vec!["localhost".to_string()]).unwrap();
+    (cert.pem(), signing_key.serialize_pem())
+}
+
+/// Writes certificate and key PEM files into the directory.
+fn write_pair(dir: &Path, cert: &str, key: &str) {
+    fs::write(dir.join("server.crt"), cert).unwrap();
+    fs::write(dir.join("server.key"), key).unwrap();
+}
+
+/// Builds a TLS config pointing at the directory's certificate files.
+fn tls_config(dir: &Path) -> ServerTlsConfig {
+    ServerTlsConfig {
+        cert_path: dir.join("server.crt").display().to_string(),
+        key_path: dir.join("server.key").display().to_string(),
+        client_ca_path: None,
+        require_client_cert: true,
+        reload_interval_ms: Some(1_000),
+        max_client_chain_depth: None,
+        client_required_ekus: Vec::new(),
+        ocsp_response_path: None,
+    }
+}
+
+/// Returns an audit sink that discards events.
+fn noop_audit() -> Arc<dyn McpAuditSink> {
+    Arc::new(McpNoopAuditSink)
+}
+
+/// Audit sink capturing security events.
+#[derive(Default)]
+struct CaptureAudit {
+    security: Mutex<Vec<SecurityAuditEvent>>,
+}
+
+impl McpAuditSink for CaptureAudit {
+    fn record(&self, _event: &McpAuditEvent) {}
+
+    fn record_security(&self, event: &SecurityAuditEvent) {
+        self.security.lock().unwrap().push(event.clone());
+    }
+}
+
+/// Test certificate authority able to issue child certificates.
+struct TestCa {
+    /// Issuer used to sign child certificates.
+    issuer: Issuer<'static, KeyPair>,
+    /// CA certificate in DER form.
+    der: CertificateDer<'static>,
+    /// CA certificate in PEM form.
+    pem: String,
+}
+
+/// Builds CA certificate params with the given common name.
+fn ca_params(name: &str) -> CertificateParams {
+    let mut params = CertificateParams::new(Vec::<String>::new()).unwrap();
+    params.is_ca = IsCa::Ca(BasicConstraints::Unconstrained);
+    params.distinguished_name.push(DnType::CommonName, name);
+    params
+}
+
+/// Generates a self-signed root CA.
+fn generate_root_ca() -> TestCa {
+    let params = ca_params("test root ca");
+    let key = KeyPair::generate().unwrap();
+    let cert = params.self_signed(&key).unwrap();
+    TestCa {
+        der: cert.der().clone(),
+        pem: cert.pem(),
+        issuer: Issuer::new(params, key),
+    }
+}
+
+/// Generates an intermediate CA signed by `parent`.
+fn generate_intermediate_ca(parent: &TestCa) -> TestCa {
+    let params = ca_params("test intermediate ca");
+    let key = KeyPair::generate().unwrap();
+    let cert = params.signed_by(&key, &parent.issuer).unwrap();
+    TestCa {
+        der: cert.der().clone(),
+        pem: cert.pem(),
+        issuer: Issuer::new(params, key),
+    }
+}
+
+/// Issues a client leaf certificate with the given EKUs.
+fn issue_client_cert(ca: &TestCa, ekus: Vec<ExtendedKeyUsagePurpose>) -> CertificateDer<'static> {
+    let mut params = CertificateParams::new(vec!["client.local".to_string()]).unwrap();
+    params.distinguished_name.push(DnType::CommonName, "client");
+    params.extended_key_usages = ekus;
+    let key = KeyPair::generate().unwrap();
+    params.signed_by(&key, &ca.issuer).unwrap().der().clone()
+}
+
+/// Builds a TLS config with a client CA and client certificate policy.
+fn policy_config(
+    max_client_chain_depth: Option<usize>,
+    client_required_ekus: Vec<String>,
+) -> ServerTlsConfig {
+    ServerTlsConfig {
+        cert_path: "server.crt".to_string(),
+        key_path: "server.key".to_string(),
+        client_ca_path: Some("ca.pem".to_string()),
+        require_client_cert: true,
+        reload_interval_ms: None,
+        max_client_chain_depth,
+        client_required_ekus,
+        ocsp_response_path: None,
+    }
+}
+
+// ============================================================================
+// SECTION: Reload Tests
+// ============================================================================
+
+#[test]
+fn build_tls_config_accepts_valid_pair() {
+    let dir = TempDir::new().unwrap();
+    let (cert, key) = generate_pem();
+    write_pair(dir.path(), &cert, &key);
+    assert!(build_tls_config(&tls_config(dir.path()), &noop_audit()).is_ok());
+}
+
+#[test]
+fn reloader_reports_unchanged_without_rotation() {
+    let dir = TempDir::new().unwrap();
+    let (cert, key) = generate_pem();
+    write_pair(dir.path(), &cert, &key);
+    let mut reloader = TlsReloader::new(&tls_config(dir.path()), noop_audit()).unwrap();
+    assert_eq!(reloader.poll(), TlsReloadOutcome::Unchanged);
+}
+
+#[test]
+fn reloader_picks_up_rotated_certificate() {
+    let dir = TempDir::new().unwrap();
+    let (cert, key) = generate_pem();
+    write_pair(dir.path(), &cert, &key);
+    let mut reloader = TlsReloader::new(&tls_config(dir.path()), noop_audit()).unwrap();
+    let before = reloader.rustls_config().get_inner();
+
+    let (rotated_cert, rotated_key) = generate_pem();
+    write_pair(dir.path(), &rotated_cert, &rotated_key);
+    assert_eq!(reloader.poll(), TlsReloadOutcome::Reloaded);
+
+    let after = reloader.rustls_config().get_inner();
+    assert!(!Arc::ptr_eq(&before, &after), "rotated config should be swapped in");
+    assert_eq!(reloader.active.cert, rotated_cert.as_bytes());
+    assert_eq!(reloader.poll(), TlsReloadOutcome::Unchanged);
+}
+
+#[test]
+fn reloader_rejects_invalid_certificate_and_keeps_previous() {
+    let dir = TempDir::new().unwrap();
+    let (cert, key) = generate_pem();
+    write_pair(dir.path(), &cert, &key);
+    let mut reloader = TlsReloader::new(&tls_config(dir.path()), noop_audit()).unwrap();
+    let before = reloader.rustls_config().get_inner();
+
+    fs::write(dir.path().join("server.crt"), "not a certificate").unwrap();
+    assert!(matches!(reloader.poll(), TlsReloadOutcome::Rejected(_)));
+    assert!(Arc::ptr_eq(&before, &reloader.rustls_config().get_inner()));
+    assert_eq!(reloader.active.cert, cert.as_bytes());
+    assert_eq!(reloader.poll(), TlsReloadOutcome::Unchanged, "same bad material is not retried");
+
+    let (rotated_cert, rotated_key) = generate_pem();
+    write_pair(dir.path(), &rotated_cert, &rotated_key);
+    assert_eq!(reloader.poll(), TlsReloadOutcome::Reloaded);
+}
+
+#[test]
+fn reloader_rejects_mismatched_key() {
+    let dir = TempDir::new().unwrap();
+    let (cert, key) = generate_pem();
+    write_pair(dir.path(), &cert, &key);
+    let mut reloader = TlsReloader::new(&tls_config(dir.path()), noop_audit()).unwrap();
+    let before = reloader.rustls_config().get_inner();
+
+    let (rotated_cert, _) = generate_pem();
+    fs::write(dir.path().join("server.crt"), rotated_cert).unwrap();
+    let outcome = reloader.poll();
+    assert!(
+        matches!(&outcome, TlsReloadOutcome::Rejected(reason) if reason.contains("tls config invalid")),
+        "unexpected outcome: {outcome:?}"
+    );
+    assert!(Arc::ptr_eq(&before, &reloader.rustls_config().get_inner()));
+}
+
+#[test]
+fn reloader_rejects_missing_files_and_keeps_previous() {
+    let dir = TempDir::new().unwrap();
+    let (cert, key) = generate_pem();
+    write_pair(dir.path(), &cert, &key);
+    let mut reloader = TlsReloader::new(&tls_config(dir.path()), noop_audit()).unwrap();
+    let before = reloader.rustls_config().get_inner();
+
+    fs::remove_file(dir.path().join("server.key")).unwrap();
+    assert!(matches!(reloader.poll(), TlsReloadOutcome::Rejected(_)));
+    assert!(Arc::ptr_eq(&before, &reloader.rustls_config().get_inner()));
+}
+
+#[test]
+fn reloader_picks_up_rotated_ocsp_response() {
+    let dir = TempDir::new().unwrap();
+    let (cert, key) = generate_pem();
+    write_pair(dir.path(), &cert, &key);
+    let ocsp_path = dir.path().join("server.ocsp");
+    fs::write(&ocsp_path, [0x30, 0x03, 0x0a, 0x01, 0x00]).unwrap();
+    let mut config = tls_config(dir.path());
+    config.ocsp_response_path = Some(ocsp_path.display().to_string());
+    assert!(build_tls_config(&config, &noop_audit()).is_ok());
+
+    let mut reloader = TlsReloader::new(&config, noop_audit()).unwrap();
+    fs::write(&ocsp_path, [0x30, 0x03, 0x0a, 0x01, 0x01]).unwrap();
+    assert_eq!(reloader.poll(), TlsReloadOutcome::Reloaded);
+}
+
+// ============================================================================
+// SECTION: Client Certificate Policy Tests
+// ============================================================================
+
+#[test]
+fn client_cert_with_required_eku_accepted() {
+    let ca = generate_root_ca();
+    let audit = Arc::new(CaptureAudit::default());
+    let sink: Arc<dyn McpAuditSink> = audit.clone();
+    let config = policy_config(None, vec![CUSTOM_EKU_DOTTED.to_string()]);
+    let verifier = build_client_verifier(&config, ca.pem.as_bytes(), &sink).unwrap();
+    let leaf = issue_client_cert(
+        &ca,
+        vec![
+            ExtendedKeyUsagePurpose::ClientAuth,
+            ExtendedKeyUsagePurpose::Other(CUSTOM_EKU.to_vec()),
+        ],
+    );
+    verifier.verify_client_cert(&leaf, &[], UnixTime::now()).unwrap();
+    assert!(audit.security.lock().unwrap().is_empty());
+}
+
+#[test]
+fn client_cert_missing_required_eku_rejected_and_audited() {
+    let ca = generate_root_ca();
+    let audit = Arc::new(CaptureAudit::default());
+    let sink: Arc<dyn McpAuditSink> = audit.clone();
+    let config = policy_config(None, vec![CUSTOM_EKU_DOTTED.to_string()]);
+    let verifier = build_client_verifier(&config, ca.pem.as_bytes(), &sink).unwrap();
+    let leaf = issue_client_cert(&ca, vec![ExtendedKeyUsagePurpose::ClientAuth]);
+    let err = verifier.verify_client_cert(&leaf, &[], UnixTime::now()).unwrap_err();
+    assert!(describe_rejection(&err).contains("missing required extended key usage"));
+
+    let events = audit.security.lock().unwrap().clone();
+    assert_eq!(events.len(), 1);
+    assert_eq!(events[0].kind, "tls_client_cert_rejected");
+    let message = events[0].message.as_deref().unwrap();
+    assert!(message.contains(CUSTOM_EKU_DOTTED), "{message}");
+}
+
+#[test]
+fn client_cert_from_unknown_ca_rejected_and_audited() {
+    let ca = generate_root_ca();
+    let other_ca = generate_root_ca();
+    let audit = Arc::new(CaptureAudit::default());
+    let sink: Arc<dyn McpAuditSink> = audit.clone();
+    let config = policy_config(None, Vec::new());
+    let verifier = build_client_verifier(&config, ca.pem.as_bytes(), &sink).unwrap();
+    let leaf = issue_client_cert(&other_ca, vec![ExtendedKeyUsagePurpose::ClientAuth]);
+    assert!(verifier.verify_client_cert(&leaf, &[], UnixTime::now()).is_err());
+    assert_eq!(audit.security.lock().unwrap().len(), 1);
+}
+
+#[test]
+fn client_cert_chain_depth_enforced() {
+    let root = generate_root_ca();
+    let intermediate = generate_intermediate_ca(&root);
+    let leaf = issue_client_cert(&intermediate, vec![ExtendedKeyUsagePurpose::ClientAuth]);
+    let intermediates = [intermediate.der.clone()];
+
+    let strict = build_client_verifier(
+        &policy_config(Some(1), Vec::new()),
+        root.pem.as_bytes(),
+        &noop_audit(),
+    )
+    .unwrap();
+    let err = strict.verify_client_cert(&leaf, &intermediates, UnixTime::now()).unwrap_err();
+    assert!(describe_rejection(&err).contains("chain depth 2 exceeds limit 1"));
+
+    let relaxed = build_client_verifier(
+        &policy_config(Some(2), Vec::new()),
+        root.pem.as_bytes(),
+        &noop_audit(),
+    )
+    .unwrap();
+    relaxed.verify_client_cert(&leaf, &intermediates, UnixTime::now()).unwrap();
+}
@@ -0,0 +1,152 @@
+// crates/decision-gate-mcp/src/tool_concurrency.rs
+// ============================================================================
+// Module: Per-Tool Concurrency Limits
+// Description: Semaphore-backed concurrency caps for individual MCP tools.
+// Purpose: Keep expensive tools from starving cheap ones under load.
+// Dependencies: decision-gate-config, tokio
+// ============================================================================
+
+//! ## Overview
+//! [`ToolConcurrencyLimits`] caps concurrent calls per tool, independent of the
+//! server-wide `max_inflight` semaphore and rate limiter. Calls beyond a
+//! tool's limit wait up to `max_queue_wait_ms` for a slot and are otherwise
+//! rejected with a retryable error. Saturation is reported through
+//! [`McpMetrics::record_tool_queue`].
+//! Security posture: limits bound resource use by untrusted callers; see
+//! `Docs/security/threat_model.md`.
+
+use std::collections::BTreeMap;
+use std::sync::Arc;
+use std::time::Duration;
+use std::time::Instant;
+
+use decision_gate_contract::ToolName;
+use thiserror::Error;
+use tokio::sync::OwnedSemaphorePermit;
+use tokio::sync::Semaphore;
+use tokio::sync::TryAcquireError;
+
+use crate::config::ToolConcurrencyConfig;
+use crate::telemetry::McpMetrics;
+use crate::telemetry::ToolQueueMetricEvent;
+use crate::telemetry::ToolQueueOutcome;
+
+// ============================================================================
+// SECTION: Errors
+// ============================================================================
+
+/// Per-tool concurrency errors.
+#[derive(Debug, Error, Clone, PartialEq, Eq)]
+pub enum ToolConcurrencyError {
+    /// No slot became available within the configured queue wait.
+    #[error("{tool} concurrency limit reached ({max_concurrent} in flight)")]
+    LimitReached {
+        /// Tool whose limit was reached.
+        tool: ToolName,
+        /// Configured concurrency limit.
+        max_concurrent: usize,
+    },
+}
+
+// ============================================================================
+// SECTION: Limits
+// ============================================================================
+
+/// Concurrency limit state for one tool.
+#[derive(Debug, Clone)]
+struct ToolLimit {
+    /// Slots shared by all calls of the tool.
+    permits: Arc<Semaphore>,
+    /// Configured concurrency limit.
+    max_concurrent: usize,
+    /// Maximum time a call waits for a slot.
+    max_queue_wait: Duration,
+}
+
+/// Per-tool concurrency limits derived from configuration.
+///
+/// # Invariants
+/// - Tools without a configured limit are never throttled.
+/// - Clones share the same semaphores.
+#[derive(Debug, Clone, Default)]
+pub struct ToolConcurrencyLimits {
+    /// Limits keyed by tool.
+    limits: BTreeMap<ToolName, ToolLimit>,
+}
+
+impl ToolConcurrencyLimits {
+    /// Builds limits from `server.limits.tool_concurrency` entries.
+    ///
+    /// Entries naming unknown tools are ignored; config validation rejects them.
+    #[must_use]
+    pub fn from_config(entries: &[ToolConcurrencyConfig]) -> Self {
+        let limits = entries
+            .iter()
+            .filter_map(|entry| {
+                let tool = ToolName::parse(&entry.tool)?;
+                let limit = ToolLimit {
+                    permits: Arc::new(Semaphore::new(entry.max_concurrent)),
+                    max_concurrent: entry.max_concurrent,
+                    max_queue_wait: Duration::from_millis(entry.max_queue_wait_ms),
+                };
+                Some((tool, limit))
+            })
+            .collect();
+        Self {
+            limits,
+        }
+    }
+
+    /// Returns the configured concurrency limit for a tool.
+    #[must_use]
+    pub fn limit(&self, tool: ToolName) -> Option<usize> {
+        self.limits.get(&tool).map(|limit| limit.max_concurrent)
+    }
+
+    /// Acquires a concurrency slot for a tool call.
+    ///
+    /// Returns `None` when the tool is unlimited. The returned permit must be
+    /// held for the duration of the call.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`ToolConcurrencyError::LimitReached`] when no slot frees up
+    /// within the configured queue wait.
+    pub async fn acquire(
+        &self,
+        tool: ToolName,
+        metrics: &dyn McpMetrics,
+    ) -> Result<Option<OwnedSemaphorePermit>, ToolConcurrencyError> {
+        let Some(limit) = self.limits.get(&tool) else {
+            return Ok(None);
+        };
+        match Arc::clone(&limit.permits).try_acquire_owned() {
+            Ok(permit) => return Ok(Some(permit)),
+            Err(TryAcquireError::NoPermits | TryAcquireError::Closed) => {}
+        }
+        let started = Instant::now();
+        let permit = if limit.max_queue_wait.is_zero() {
+            None
+        } else {
+            tokio::time::timeout(limit.max_queue_wait, Arc::clone(&limit.permits).acquire_owned())
+                .await
+                .ok()
+                .and_then(Result::ok)
+        };
+        let outcome = match (&permit, limit.max_queue_wait.is_zero()) {
+            (Some(_), _) => ToolQueueOutcome::Admitted,
+            (None, true) => ToolQueueOutcome::Rejected,
+            (None, false) => ToolQueueOutcome::TimedOut,
+        };
+        let event = ToolQueueMetricEvent {
+            tool,
+            outcome,
+            max_concurrent: limit.max_concurrent,
+        };
+        metrics.record_tool_queue(event, started.elapsed());
+        permit.map(Some).ok_or(ToolConcurrencyError::LimitReached {
+            tool,
+            max_concurrent: limit.max_concurrent,
+        })
+    }
+}
@@ -0,0 +1,230 @@
+// crates/decision-gate-mcp/src/tool_schemas.rs
+// ============================================================================
+// Module: Tool Contract Schema Enforcement
+// Description: Validates tool payloads against the canonical tool contracts.
+// Purpose: Reject malformed tool params server-side and flag invalid responses.
+// Dependencies: decision-gate-contract, jsonschema
+// ============================================================================
+
+//! ## Overview
+//! [`ToolSchemaValidator`] checks `tools/call` arguments against each tool's
+//! contract input schema before dispatch, so malformed params are rejected
+//! regardless of client-side validation. Response validation against the
+//! output schema is an opt-in debug aid for catching server bugs.
+//! Security posture: tool params are untrusted; validation fails closed when a
+//! contract schema is missing or fails to compile; see
+//! `Docs/security/threat_model.md`.
+
+use std::collections::BTreeMap;
+use std::sync::OnceLock;
+
+use decision_gate_contract::ToolName;
+use decision_gate_contract::tooling::tool_contracts;
+use jsonschema::Draft;
+use jsonschema::Registry;
+use jsonschema::ValidationError;
+use jsonschema::Validator;
+use jsonschema::error::ValidationErrorKind;
+use serde_json::Value;
+use thiserror::Error;
+
+// ============================================================================
+// SECTION: Limits
+// ============================================================================
+
+/// Maximum number of schema violations reported per payload.
+pub const MAX_REPORTED_VIOLATIONS: usize = 5;
+
+// ============================================================================
+// SECTION: Errors
+// ============================================================================
+
+/// Tool schema validation errors.
+///
+/// # Invariants
+/// - Violation messages never echo payload values.
+#[derive(Debug, Error, Clone, PartialEq, Eq)]
+pub enum ToolSchemaError {
+    /// Tool params do not match the contract input schema.
+    #[error("invalid {tool} params: {violations}")]
+    InvalidInput {
+        /// Tool whose params were rejected.
+        tool: ToolName,
+        /// Violations as `<json pointer>: <message>` joined by `; `.
+        violations: String,
+    },
+    /// Tool response does not match the contract output schema.
+    #[error("{tool} response violates output schema: {violations}")]
+    InvalidOutput {
+        /// Tool whose response was rejected.
+        tool: ToolName,
+        /// Violations as `<json pointer>: <message>` joined by `; `.
+        violations: String,
+    },
+    /// Contract schemas could not be compiled.
+    #[error("tool schemas unavailable: {0}")]
+    Unavailable(String),
+}
+
+// ============================================================================
+// SECTION: Validator
+// ============================================================================
+
+/// Compiled input and output validators for one tool.
+struct CompiledToolSchemas {
+    /// Validator for `tools/call` arguments.
+    input: Validator,
+    /// Validator for tool responses.
+    output: Validator,
+}
+
+/// Validates tool payloads against the built-in tool contracts.
+///
+/// # Invariants
+/// - Contract schemas are compiled once per process and shared.
+/// - When compilation failed, every validation fails closed.
+#[derive(Clone, Copy)]
+pub struct ToolSchemaValidator {
+    /// Compiled schemas keyed by tool, or the compilation failure.
+    schemas: &'static Result<BTreeMap<ToolName, CompiledToolSchemas>, String>,
+    /// Whether responses are validated against output schemas.
+    validate_outputs: bool,
+}
+
+impl ToolSchemaValidator {
+    /// Builds a validator over the built-in tool contracts.
+    #[must_use]
+    pub fn new(validate_outputs: bool) -> Self {
+        static SCHEMAS: OnceLock<Result<BTreeMap<ToolName, CompiledToolSchemas>, String>> =
+            OnceLock::new();
+        Self {
+            schemas: SCHEMAS.get_or_init(compile_contract_schemas),
+            validate_outputs,
+        }
+    }
+
+    /// Returns true when responses are validated against output schemas.
+    #[must_use]
+    pub const fn validates_outputs(&self) -> bool {
+        self.validate_outputs
+    }
+
+    /// Validates tool params against the contract input schema.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`ToolSchemaError::InvalidInput`] when the params do not match.
+    pub fn validate_input(&self, tool: ToolName, payload: &Value) -> Result<(), ToolSchemaError> {
+        let schemas = self.compiled(tool)?;
+        violations(&schemas.input, payload).map_or(Ok(()), |violations| {
+            Err(ToolSchemaError::InvalidInput {
+                tool,
+                violations,
+            })
+        })
+    }
+
+    /// Validates a tool response against the contract output schema.
+    ///
+    /// Always succeeds unless output validation is enabled.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`ToolSchemaError::InvalidOutput`] when the response does not match.
+    pub fn validate_output(&self, tool: ToolName, payload: &Value) -> Result<(), ToolSchemaError> {
+        if !self.validate_outputs {
+            return Ok(());
+        }
+        let schemas = self.compiled(tool)?;
+        violations(&schemas.output, payload).map_or(Ok(()), |violations| {
+            Err(ToolSchemaError::InvalidOutput {
+                tool,
+                violations,
+            })
+        })
+    }
+
+    /// Returns the compiled schemas for a tool.
+    fn compiled(&self, tool: ToolName) -> Result<&'static CompiledToolSchemas, ToolSchemaError> {
+        let schemas =
+            self.schemas.as_ref().map_err(|err| ToolSchemaError::Unavailable(err.clone()))?;
+        schemas.get(&tool).ok_or_else(|| {
+            ToolSchemaError::Unavailable(format!("no contract schema for {}", tool.as_str()))
+        })
+    }
+}
+
+// ============================================================================
+// SECTION: Helpers
+// ============================================================================
+
+/// Compiles input and output validators for every built-in tool contract.
+fn compile_contract_schemas() -> Result<BTreeMap<ToolName, CompiledToolSchemas>, String> {
+    let scenario_schema = decision_gate_contract::schemas::scenario_schema();
+    let id = scenario_schema
+        .get("$id")
+        .and_then(Value::as_str)
+        .ok_or_else(|| "scenario schema missing $id".to_string())?;
+    let registry =
+        Registry::try_new(id, Draft::Draft202012.create_resource(scenario_schema.clone()))
+            .map_err(|err| format!("schema registry failed: {err}"))?;
+    let compile = |tool: ToolName, schema: &Value| {
+        jsonschema::options()
+            .with_draft(Draft::Draft202012)
+            .with_registry(registry.clone())
+            .build(schema)
+            .map_err(|err| format!("{} schema failed to compile: {err}", tool.as_str()))
+    };
+    let mut schemas = BTreeMap::new();
+    for contract in tool_contracts() {
+        let compiled = CompiledToolSchemas {
+            input: compile(contract.name, &contract.input_schema)?,
+            output: compile(contract.name, &contract.output_schema)?,
+        };
+        schemas.insert(contract.name, compiled);
+    }
+    Ok(schemas)
+}
+
+/// Returns masked schema violations, or `None` when the payload is valid.
+fn violations(validator: &Validator, payload: &Value) -> Option<String> {
+    if validator.is_valid(payload) {
+        return None;
+    }
+    let mut messages = Vec::new();
+    for error in validator.iter_errors(payload) {
+        collect_violations(&error, &mut messages);
+    }
+    let total = messages.len();
+    messages.truncate(MAX_REPORTED_VIOLATIONS);
+    if total > messages.len() {
+        messages.push(format!("{} more", total - messages.len()));
+    }
+    Some(messages.join("; "))
+}
+
+/// Appends `<json pointer>: <message>` entries for a validation error.
+///
+/// `oneOf`/`anyOf` failures are expanded into the branch whose errors reach
+/// deepest into the payload, which is usually the branch the caller intended.
+fn collect_violations(error: &ValidationError<'_>, messages: &mut Vec<String>) {
+    if let ValidationErrorKind::OneOfNotValid {
+        context,
+    }
+    | ValidationErrorKind::AnyOf {
+        context,
+    } = error.kind()
+        && let Some(branch) = context.iter().max_by_key(|branch| {
+            branch.iter().map(|error| error.instance_path().to_string().len()).max()
+        })
+        && !branch.is_empty()
+    {
+        for error in branch {
+            collect_violations(error, messages);
+        }
+        return;
+    }
+    let path = error.instance_path().to_string();
+    let path = if path.is_empty() { "/".to_string() } else { path };
+    messages.push(format!("{path}: {}", error.masked()));
+}
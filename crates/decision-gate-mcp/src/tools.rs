@@ -32,11 +32,13 @@ use std::path::Path;
 use std::path::PathBuf;
 use std::sync::Arc;
 use std::sync::Mutex;
+use std::time::Duration;
 
 use decision_gate_contract::ToolName;
 pub use decision_gate_contract::tooling::ToolDefinition;
 use decision_gate_contract::types::CheckExample;
 use decision_gate_contract::types::DeterminismClass;
+use decision_gate_contract::types::ToolContract;
 use decision_gate_core::ArtifactReader;
 use decision_gate_core::Comparator;
 use decision_gate_core::ConditionId;
@@ -50,6 +52,7 @@ use decision_gate_core::DecisionOutcome;
 use decision_gate_core::DispatchReceipt;
 use decision_gate_core::DispatchTarget;
 use decision_gate_core::Dispatcher;
+use decision_gate_core::EvaluationAuditRecord;
 use decision_gate_core::EvidenceAnchorPolicy;
 use decision_gate_core::EvidenceContext;
 use decision_gate_core::EvidenceProvider;
@@ -73,6 +76,7 @@ use decision_gate_core::ScenarioSpec;
 use decision_gate_core::SharedDataShapeRegistry;
 use decision_gate_core::SharedRunStateStore;
 use decision_gate_core::StageId;
+use decision_gate_core::StoreError;
 use decision_gate_core::TenantId;
 use decision_gate_core::Timestamp;
 use decision_gate_core::TriggerEvent;
@@ -85,8 +89,12 @@ use decision_gate_core::hashing::hash_canonical_json;
 use decision_gate_core::runtime::ControlPlane;
 use decision_gate_core::runtime::ControlPlaneConfig;
 use decision_gate_core::runtime::ControlPlaneError;
+use decision_gate_core::runtime::LeaseAcquireRequest;
+use decision_gate_core::runtime::LeaseReleaseRequest;
+use decision_gate_core::runtime::LeaseReleaseResult;
 use decision_gate_core::runtime::NextRequest;
 use decision_gate_core::runtime::NextResult;
+use decision_gate_core::runtime::RunLease;
 use decision_gate_core::runtime::RunpackBuilder;
 use decision_gate_core::runtime::RunpackVerifier;
 use decision_gate_core::runtime::ScenarioStatus;
@@ -103,6 +111,8 @@ use serde::Serialize;
 use serde_json::Value;
 use thiserror::Error;
 
+use crate::audit::EvaluationAuditEvent;
+use crate::audit::EvaluationAuditEventParams;
 use crate::audit::McpAuditSink;
 use crate::audit::PrecheckAuditEvent;
 use crate::audit::PrecheckAuditEventParams;
@@ -117,8 +127,13 @@ use crate::auth::AuthAuditEvent;
 use crate::auth::AuthAuditSink;
 use crate::auth::AuthContext;
 use crate::auth::AuthError;
+use crate::auth::AuthMethod;
+use crate::auth::AuthScopes;
 use crate::auth::RequestContext;
 use crate::auth::ToolAuthz;
+use crate::authz_trace;
+use crate::authz_trace::AuthzLayer;
+use crate::authz_trace::AuthzTrace;
 use crate::capabilities::CapabilityError;
 use crate::capabilities::CapabilityRegistry;
 use crate::capabilities::ProviderContractSource;
@@ -129,6 +144,7 @@ use crate::config::ProviderDiscoveryConfig;
 use crate::config::RegistryAclAction;
 use crate::config::ScenarioNextFeedbackConfig;
 use crate::config::ServerToolsConfig;
+use crate::config::ToolConcurrencyConfig;
 use crate::config::ToolVisibilityMode;
 use crate::config::ValidationConfig;
 use crate::docs::DocEntry;
@@ -148,10 +164,19 @@ use crate::runpack_object_store::RunpackObjectKey;
 use crate::runpack_storage::RunpackStorage;
 use crate::runpack_storage::RunpackStorageError;
 use crate::runpack_storage::RunpackStorageKey;
+use crate::status_events::RunStatusEvents;
+use crate::status_events::RunStatusKey;
+use crate::status_events::RunStatusSubscription;
+use crate::telemetry::McpMetrics;
+use crate::telemetry::NoopMetrics;
 use crate::tenant_authz::TenantAccessRequest;
 use crate::tenant_authz::TenantAuthorizer;
 use crate::tenant_authz::TenantAuthzAction;
 use crate::tenant_authz::TenantAuthzDecision;
+use crate::tool_concurrency::ToolConcurrencyError;
+use crate::tool_concurrency::ToolConcurrencyLimits;
+use crate::tool_schemas::ToolSchemaError;
+use crate::tool_schemas::ToolSchemaValidator;
 use crate::usage::UsageCheckRequest;
 use crate::usage::UsageDecision;
 use crate::usage::UsageMeter;
@@ -163,8 +188,16 @@ use crate::validation::StrictValidator;
 const DEFAULT_LIST_LIMIT: usize = 50;
 /// Maximum page size for list-style tools.
 const MAX_LIST_LIMIT: usize = 1000;
+/// Maximum number of runs accepted by `scenarios_status_bulk`.
+const MAX_STATUS_BULK_RUNS: usize = 100;
 /// Reserved default namespace identifier.
 const DEFAULT_NAMESPACE_ID: u64 = 1;
+/// Maximum time a `scenario_status` long-poll may hold a request.
+const MAX_STATUS_WAIT_MS: u64 = 30_000;
+/// Interval at which long-polls re-read status without a notification.
+///
+/// Covers run state changed by other processes sharing the run state store.
+const STATUS_WAIT_RECHECK_INTERVAL: Duration = Duration::from_secs(1);
 
 // ============================================================================
 // SECTION: Docs + Visibility Providers
@@ -252,6 +285,8 @@ pub struct ToolRouter {
     schema_registry: SharedDataShapeRegistry,
     /// Strict comparator validation.
     validation: StrictValidator,
+    /// Tool contract schema enforcement for params and responses.
+    tool_schemas: ToolSchemaValidator,
     /// Provider transport metadata for discovery.
     provider_transports: BTreeMap<String, ProviderTransport>,
     /// Limits for schema registration.
@@ -294,8 +329,14 @@ pub struct ToolRouter {
     docs_catalog: DocsCatalog,
     /// Docs provider.
     docs_provider: Arc<dyn DocsProvider>,
+    /// Run status change notifications for `scenario_status` long-polls.
+    status_events: RunStatusEvents,
     /// Tool visibility resolver.
     tool_visibility: Arc<dyn ToolVisibilityResolver>,
+    /// Per-tool concurrency limits.
+    tool_concurrency: ToolConcurrencyLimits,
+    /// Metrics sink for tool concurrency queueing.
+    metrics: Arc<dyn McpMetrics>,
     /// Whether to log raw precheck request/response payloads.
     precheck_audit_payloads: bool,
     /// Allow default namespace usage.
@@ -369,6 +410,8 @@ pub struct ToolRouterConfig {
     pub tools: ServerToolsConfig,
     /// Optional tool visibility resolver override.
     pub tool_visibility_resolver: Option<Arc<dyn ToolVisibilityResolver>>,
+    /// Per-tool concurrency limits.
+    pub tool_concurrency: Vec<ToolConcurrencyConfig>,
     /// Whether to log raw precheck request/response payloads.
     pub precheck_audit_payloads: bool,
     /// Allow default namespace usage.
@@ -542,6 +585,7 @@ impl ToolRouter {
             dispatch_policy: config.dispatch_policy,
             store: config.store,
             schema_registry: config.schema_registry,
+            tool_schemas: ToolSchemaValidator::new(config.validation.validate_tool_outputs),
             validation: StrictValidator::new(config.validation),
             provider_transports: config.provider_transports,
             schema_registry_limits: config.schema_registry_limits,
@@ -565,13 +609,23 @@ impl ToolRouter {
             docs_config: config.docs_config,
             docs_catalog: config.docs_catalog,
             docs_provider,
+            status_events: RunStatusEvents::new(),
             tool_visibility,
+            tool_concurrency: ToolConcurrencyLimits::from_config(&config.tool_concurrency),
+            metrics: Arc::new(NoopMetrics),
             allow_default_namespace: config.allow_default_namespace,
             default_namespace_tenants: config.default_namespace_tenants,
             namespace_authority: config.namespace_authority,
         }
     }
 
+    /// Returns a router that reports tool concurrency queueing to `metrics`.
+    #[must_use]
+    pub fn with_metrics(mut self, metrics: Arc<dyn McpMetrics>) -> Self {
+        self.metrics = metrics;
+        self
+    }
+
     /// Lists the MCP tools supported by this server.
     ///
     /// # Errors
@@ -587,6 +641,63 @@ impl ToolRouter {
         Ok(definitions)
     }
 
+    /// Describes the full tool contracts (schemas, examples, notes).
+    ///
+    /// Contracts are filtered like `tools/list` and bounded by the provider
+    /// discovery response size limit.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`ToolError`] when authorization fails or the response exceeds
+    /// the size limit.
+    pub async fn describe_tools(
+        &self,
+        context: &RequestContext,
+    ) -> Result<Vec<ToolContract>, ToolError> {
+        let auth_ctx = self.authorize(context, AuthAction::DescribeTools).await?;
+        let contracts = self.visible_tool_contracts(context, &auth_ctx);
+        self.ensure_discovery_response_size("tools/describe", &contracts)?;
+        Ok(contracts)
+    }
+
+    /// Returns the tool contracts published by the live `OpenAPI` document.
+    ///
+    /// With `require_auth` the caller is authorized like `tools/describe` and
+    /// contracts follow its scopes. Otherwise the caller is treated as an
+    /// unscoped anonymous principal, so only server-level visibility applies.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`ToolError`] when authorization is required and fails.
+    pub async fn openapi_tool_contracts(
+        &self,
+        context: &RequestContext,
+        require_auth: bool,
+    ) -> Result<Vec<ToolContract>, ToolError> {
+        let auth_ctx = if require_auth {
+            self.authorize(context, AuthAction::DescribeTools).await?
+        } else {
+            AuthContext {
+                method: AuthMethod::Local,
+                subject: None,
+                token_fingerprint: None,
+                scopes: AuthScopes::default(),
+            }
+        };
+        Ok(self.visible_tool_contracts(context, &auth_ctx))
+    }
+
+    /// Returns the built-in tool contracts visible to the caller.
+    fn visible_tool_contracts(
+        &self,
+        context: &RequestContext,
+        auth_ctx: &AuthContext,
+    ) -> Vec<ToolContract> {
+        let mut contracts = decision_gate_contract::tooling::tool_contracts();
+        contracts.retain(|tool| self.is_tool_visible_for_list(context, auth_ctx, tool.name));
+        contracts
+    }
+
     /// Lists MCP documentation resources.
     ///
     /// # Errors
@@ -621,15 +732,16 @@ impl ToolRouter {
     }
 
     /// Returns true when a tool is enabled for listing.
+    ///
+    /// Tools the caller cannot invoke (disabled capability or outside the
+    /// caller's auth scopes) are hidden so listings match callable surfaces.
     fn is_tool_visible_for_list(
         &self,
         context: &RequestContext,
         auth_ctx: &AuthContext,
         tool: ToolName,
     ) -> bool {
-        if tool == ToolName::DecisionGateDocsSearch
-            && !self.docs_provider.is_search_enabled(context, auth_ctx)
-        {
+        if !self.is_tool_invocable(context, auth_ctx, tool) {
             return false;
         }
         self.tool_visibility.is_visible_for_list(context, auth_ctx, tool)
@@ -641,13 +753,26 @@ impl ToolRouter {
         context: &RequestContext,
         auth_ctx: &AuthContext,
         tool: ToolName,
+    ) -> bool {
+        if !self.is_tool_invocable(context, auth_ctx, tool) {
+            return false;
+        }
+        self.tool_visibility.is_allowed_for_call(context, auth_ctx, tool)
+    }
+
+    /// Returns true when server capabilities and caller scopes permit the tool.
+    fn is_tool_invocable(
+        &self,
+        context: &RequestContext,
+        auth_ctx: &AuthContext,
+        tool: ToolName,
     ) -> bool {
         if tool == ToolName::DecisionGateDocsSearch
             && !self.docs_provider.is_search_enabled(context, auth_ctx)
         {
             return false;
         }
-        self.tool_visibility.is_allowed_for_call(context, auth_ctx, tool)
+        auth_ctx.scopes.permits_tool(tool)
     }
 
     /// Returns true when resources/list + resources/read are enabled.
@@ -664,6 +789,9 @@ impl ToolRouter {
 
     /// Handles a tool call by name with JSON payload.
     ///
+    /// Emits an authorization decision trace covering every layer evaluated
+    /// for the call, whether the call succeeds or fails.
+    ///
     /// # Errors
     ///
     /// Returns [`ToolError`] when routing fails.
@@ -674,11 +802,23 @@ impl ToolRouter {
         payload: Value,
     ) -> Result<Value, ToolError> {
         let tool = ToolName::parse(name).ok_or(ToolError::UnknownTool)?;
-        let auth_ctx = self.authorize(context, AuthAction::CallTool(&tool)).await?;
-        if !self.is_tool_call_allowed(context, &auth_ctx, tool) {
-            return Err(ToolError::UnknownTool);
-        }
-        match tool {
+        let (result, trace) =
+            authz_trace::scope(self.dispatch_tool_call(context, tool, payload)).await;
+        self.record_authz_trace(context, tool, trace);
+        result
+    }
+
+    /// Authorizes and dispatches a parsed tool call.
+    async fn dispatch_tool_call(
+        &self,
+        context: &RequestContext,
+        tool: ToolName,
+        payload: Value,
+    ) -> Result<Value, ToolError> {
+        let auth_ctx = self.authorize_tool_call(context, tool).await?;
+        self.tool_schemas.validate_input(tool, &payload)?;
+        let _permit = self.tool_concurrency.acquire(tool, self.metrics.as_ref()).await?;
+        let response = match tool {
             ToolName::ScenarioDefine => {
                 self.handle_scenario_define(context, &auth_ctx, payload).await
             }
@@ -721,7 +861,93 @@ impl ToolRouter {
             ToolName::DecisionGateDocsSearch => {
                 self.handle_docs_search(context, &auth_ctx, payload)
             }
+            ToolName::ScenariosStatusBulk => {
+                self.handle_scenarios_status_bulk(context, &auth_ctx, payload).await
+            }
+            ToolName::ScenarioLeaseAcquire => {
+                self.handle_scenario_lease_acquire(context, &auth_ctx, payload).await
+            }
+            ToolName::ScenarioLeaseRelease => {
+                self.handle_scenario_lease_release(context, &auth_ctx, payload).await
+            }
+        }?;
+        self.tool_schemas.validate_output(tool, &response)?;
+        Ok(response)
+    }
+
+    /// Authorizes a tool call and checks that the tool is visible to the caller.
+    async fn authorize_tool_call(
+        &self,
+        context: &RequestContext,
+        tool: ToolName,
+    ) -> Result<AuthContext, ToolError> {
+        let auth_ctx = self.authorize(context, AuthAction::CallTool(&tool)).await?;
+        let visible = self.is_tool_call_allowed(context, &auth_ctx, tool);
+        if !visible || !authz_trace::has_layer(AuthzLayer::ToolScope) {
+            authz_trace::record(
+                AuthzLayer::ToolScope,
+                (!visible).then(|| "tool not visible to caller".to_string()),
+            );
         }
+        if !visible {
+            return Err(ToolError::UnknownTool);
+        }
+        Ok(auth_ctx)
+    }
+
+    /// Fetches one page of the scenario definition stream.
+    ///
+    /// Applies the same authorization, visibility, tenant, namespace, and usage
+    /// checks as `scenarios_list`, but orders items by definition sequence so a
+    /// resumed stream never skips scenarios defined after the cursor.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`ToolError`] when authorization or listing fails.
+    pub(crate) async fn scenario_stream_page(
+        &self,
+        context: &RequestContext,
+        request: ScenarioStreamRequest,
+    ) -> Result<Vec<ScenarioStreamItem>, ToolError> {
+        let tool = ToolName::ScenariosList;
+        let (result, trace) =
+            authz_trace::scope(self.dispatch_scenario_stream_page(context, request)).await;
+        self.record_authz_trace(context, tool, trace);
+        result
+    }
+
+    /// Authorizes and serves one scenario stream page.
+    async fn dispatch_scenario_stream_page(
+        &self,
+        context: &RequestContext,
+        request: ScenarioStreamRequest,
+    ) -> Result<Vec<ScenarioStreamItem>, ToolError> {
+        let tool = ToolName::ScenariosList;
+        let auth_ctx = self.authorize_tool_call(context, tool).await?;
+        let _permit = self.tool_concurrency.acquire(tool, self.metrics.as_ref()).await?;
+        let tenant_id = request.tenant_id;
+        let namespace_id = request.namespace_id;
+        self.ensure_tool_call_allowed(
+            context,
+            &auth_ctx,
+            tool,
+            Some(&tenant_id),
+            Some(&namespace_id),
+        )
+        .await?;
+        self.ensure_namespace_allowed(context, Some(&tenant_id), &namespace_id).await?;
+        let router = self.clone();
+        let items = tokio::task::spawn_blocking(move || router.scenario_stream_items(&request))
+            .await
+            .map_err(|err| ToolError::Internal(format!("scenario stream join failed: {err}")))??;
+        self.record_tool_call_usage(
+            context,
+            &auth_ctx,
+            tool,
+            Some(&tenant_id),
+            Some(&namespace_id),
+        );
+        Ok(items)
     }
 
     /// Handles scenario definition tool requests.
@@ -828,6 +1054,7 @@ impl ToolRouter {
     ) -> Result<Value, ToolError> {
         let tool = ToolName::ScenarioStatus;
         let request = decode::<ScenarioStatusRequest>(payload)?;
+        let max_wait = request.wait_for_change.map(normalize_status_wait).transpose()?;
         let tenant_id = request.request.tenant_id;
         let namespace_id = request.request.namespace_id;
         self.ensure_tool_call_allowed(
@@ -839,23 +1066,86 @@ impl ToolRouter {
         )
         .await?;
         self.ensure_namespace_allowed(context, Some(&tenant_id), &namespace_id).await?;
+        if let Some(max_wait) = max_wait {
+            // Subscribe before the baseline read so a change in between still wakes the wait.
+            let subscription = self.status_events.subscribe();
+            self.wait_for_status_change(&request, max_wait, subscription).await?;
+        }
+        // Only the response returned to the caller is recorded in run state.
+        let response = self.read_status(context, &request).await?;
+        self.record_tool_call_usage(context, auth_ctx, tool, Some(&tenant_id), Some(&namespace_id));
+        serde_json::to_value(response).map_err(|_| ToolError::Serialization)
+    }
+
+    /// Reads scenario status on the blocking pool.
+    async fn read_status(
+        &self,
+        context: &RequestContext,
+        request: &ScenarioStatusRequest,
+    ) -> Result<ScenarioStatus, ToolError> {
         let router = self.clone();
         let context = context.clone();
-        let context_for_status = context.clone();
-        let response =
-            tokio::task::spawn_blocking(move || router.status(&context_for_status, &request))
-                .await
-                .map_err(|err| {
-                    ToolError::Internal(format!("scenario status join failed: {err}"))
-                })??;
-        self.record_tool_call_usage(
-            &context,
-            auth_ctx,
-            tool,
-            Some(&tenant_id),
-            Some(&namespace_id),
-        );
-        serde_json::to_value(response).map_err(|_| ToolError::Serialization)
+        let request = request.clone();
+        tokio::task::spawn_blocking(move || router.status(&context, &request))
+            .await
+            .map_err(|err| ToolError::Internal(format!("scenario status join failed: {err}")))?
+    }
+
+    /// Reads scenario status on the blocking pool without recording a tool call.
+    async fn peek_status(
+        &self,
+        request: &ScenarioStatusRequest,
+    ) -> Result<Option<ScenarioStatus>, ToolError> {
+        let router = self.clone();
+        let request = request.clone();
+        tokio::task::spawn_blocking(move || router.status_peek(&request))
+            .await
+            .map_err(|err| ToolError::Internal(format!("scenario status join failed: {err}")))?
+    }
+
+    /// Holds a status request until the run changes or `max_wait` elapses.
+    ///
+    /// Rechecks only peek at run state; the caller records the final read.
+    async fn wait_for_status_change(
+        &self,
+        request: &ScenarioStatusRequest,
+        max_wait: Duration,
+        mut subscription: RunStatusSubscription,
+    ) -> Result<(), ToolError> {
+        let Some(baseline) = self.peek_status(request).await? else {
+            // Missing runs fail in the recorded read without waiting.
+            return Ok(());
+        };
+        let deadline = tokio::time::Instant::now() + max_wait;
+        let key = RunStatusKey {
+            tenant_id: request.request.tenant_id,
+            namespace_id: request.request.namespace_id,
+            run_id: request.request.run_id.clone(),
+        };
+        loop {
+            let remaining = deadline.saturating_duration_since(tokio::time::Instant::now());
+            if remaining.is_zero() {
+                return Ok(());
+            }
+            subscription.wait_for(&key, remaining.min(STATUS_WAIT_RECHECK_INTERVAL)).await;
+            if self.peek_status(request).await?.as_ref() != Some(&baseline) {
+                return Ok(());
+            }
+        }
+    }
+
+    /// Notifies status long-polls that a run may have changed.
+    fn publish_status_change(
+        &self,
+        tenant_id: TenantId,
+        namespace_id: NamespaceId,
+        run_id: &RunId,
+    ) {
+        self.status_events.publish(RunStatusKey {
+            tenant_id,
+            namespace_id,
+            run_id: run_id.clone(),
+        });
     }
 
     /// Handles scenario next tool requests.
@@ -891,6 +1181,11 @@ impl ToolRouter {
         })
         .await
         .map_err(|err| ToolError::Internal(format!("scenario next join failed: {err}")))??;
+        self.publish_status_change(
+            request.request.tenant_id,
+            request.request.namespace_id,
+            &request.request.run_id,
+        );
         self.record_tool_call_usage(
             &context,
             auth_ctx,
@@ -912,6 +1207,7 @@ impl ToolRouter {
         let request = decode::<ScenarioSubmitRequest>(payload)?;
         let tenant_id = request.request.tenant_id;
         let namespace_id = request.request.namespace_id;
+        let run_id = request.request.run_id.clone();
         self.ensure_tool_call_allowed(
             context,
             auth_ctx,
@@ -930,6 +1226,7 @@ impl ToolRouter {
                 .map_err(|err| {
                     ToolError::Internal(format!("scenario submit join failed: {err}"))
                 })??;
+        self.publish_status_change(tenant_id, namespace_id, &run_id);
         self.record_tool_call_usage(
             &context,
             auth_ctx,
@@ -948,7 +1245,11 @@ impl ToolRouter {
         payload: Value,
     ) -> Result<Value, ToolError> {
         let tool = ToolName::ScenarioTrigger;
-        let request = decode::<ScenarioTriggerRequest>(payload)?;
+        let mut request = decode::<ScenarioTriggerRequest>(payload)?;
+        request.trigger = request
+            .trigger
+            .validate_and_normalize()
+            .map_err(|err| ToolError::InvalidParams(err.to_string()))?;
         self.ensure_tool_call_allowed(
             context,
             auth_ctx,
@@ -972,6 +1273,11 @@ impl ToolRouter {
         })
         .await
         .map_err(|err| ToolError::Internal(format!("scenario trigger join failed: {err}")))??;
+        self.publish_status_change(
+            request.trigger.tenant_id,
+            request.trigger.namespace_id,
+            &request.trigger.run_id,
+        );
         self.record_tool_call_usage(
             &context,
             auth_ctx,
@@ -1132,7 +1438,7 @@ impl ToolRouter {
             source: view.source,
             version: view.version,
         };
-        self.ensure_discovery_response_size(&response)?;
+        self.ensure_discovery_response_size("provider discovery", &response)?;
         serde_json::to_value(response).map_err(|_| ToolError::Serialization)
     }
 
@@ -1165,7 +1471,7 @@ impl ToolRouter {
             examples: view.examples,
             contract_hash: view.contract_hash,
         };
-        self.ensure_discovery_response_size(&response)?;
+        self.ensure_discovery_response_size("provider discovery", &response)?;
         serde_json::to_value(response).map_err(|_| ToolError::Serialization)
     }
 
@@ -1389,6 +1695,98 @@ impl ToolRouter {
         serde_json::to_value(response).map_err(|_| ToolError::Serialization)
     }
 
+    /// Handles bulk scenario status tool requests.
+    async fn handle_scenarios_status_bulk(
+        &self,
+        context: &RequestContext,
+        auth_ctx: &AuthContext,
+        payload: Value,
+    ) -> Result<Value, ToolError> {
+        let tool = ToolName::ScenariosStatusBulk;
+        let request = decode::<ScenariosStatusBulkRequest>(payload)?;
+        if request.runs.is_empty() || request.runs.len() > MAX_STATUS_BULK_RUNS {
+            return Err(ToolError::InvalidParams(format!(
+                "runs must contain between 1 and {MAX_STATUS_BULK_RUNS} entries"
+            )));
+        }
+        let tenant_id = request.tenant_id;
+        let namespace_id = request.namespace_id;
+        self.ensure_tool_call_allowed(
+            context,
+            auth_ctx,
+            tool,
+            Some(&tenant_id),
+            Some(&namespace_id),
+        )
+        .await?;
+        self.ensure_namespace_allowed(context, Some(&tenant_id), &namespace_id).await?;
+        let router = self.clone();
+        let response =
+            tokio::task::spawn_blocking(move || router.status_bulk(&request)).await.map_err(
+                |err| ToolError::Internal(format!("scenarios status bulk join failed: {err}")),
+            )??;
+        self.record_tool_call_usage(context, auth_ctx, tool, Some(&tenant_id), Some(&namespace_id));
+        serde_json::to_value(response).map_err(|_| ToolError::Serialization)
+    }
+
+    /// Handles run lease acquisition tool requests.
+    async fn handle_scenario_lease_acquire(
+        &self,
+        context: &RequestContext,
+        auth_ctx: &AuthContext,
+        payload: Value,
+    ) -> Result<Value, ToolError> {
+        let tool = ToolName::ScenarioLeaseAcquire;
+        let request = decode::<ScenarioLeaseAcquireRequest>(payload)?;
+        let tenant_id = request.request.tenant_id;
+        let namespace_id = request.request.namespace_id;
+        self.ensure_tool_call_allowed(
+            context,
+            auth_ctx,
+            tool,
+            Some(&tenant_id),
+            Some(&namespace_id),
+        )
+        .await?;
+        self.ensure_namespace_allowed(context, Some(&tenant_id), &namespace_id).await?;
+        let router = self.clone();
+        let response =
+            tokio::task::spawn_blocking(move || router.lease_acquire(&request)).await.map_err(
+                |err| ToolError::Internal(format!("scenario lease join failed: {err}")),
+            )??;
+        self.record_tool_call_usage(context, auth_ctx, tool, Some(&tenant_id), Some(&namespace_id));
+        serde_json::to_value(response).map_err(|_| ToolError::Serialization)
+    }
+
+    /// Handles run lease release tool requests.
+    async fn handle_scenario_lease_release(
+        &self,
+        context: &RequestContext,
+        auth_ctx: &AuthContext,
+        payload: Value,
+    ) -> Result<Value, ToolError> {
+        let tool = ToolName::ScenarioLeaseRelease;
+        let request = decode::<ScenarioLeaseReleaseRequest>(payload)?;
+        let tenant_id = request.request.tenant_id;
+        let namespace_id = request.request.namespace_id;
+        self.ensure_tool_call_allowed(
+            context,
+            auth_ctx,
+            tool,
+            Some(&tenant_id),
+            Some(&namespace_id),
+        )
+        .await?;
+        self.ensure_namespace_allowed(context, Some(&tenant_id), &namespace_id).await?;
+        let router = self.clone();
+        let response =
+            tokio::task::spawn_blocking(move || router.lease_release(&request)).await.map_err(
+                |err| ToolError::Internal(format!("scenario lease join failed: {err}")),
+            )??;
+        self.record_tool_call_usage(context, auth_ctx, tool, Some(&tenant_id), Some(&namespace_id));
+        serde_json::to_value(response).map_err(|_| ToolError::Serialization)
+    }
+
     /// Handles precheck tool requests.
     async fn handle_precheck(
         &self,
@@ -1487,15 +1885,19 @@ impl ToolRouter {
         Err(ToolError::Unauthorized("provider contract disclosure denied".to_string()))
     }
 
-    /// Ensures provider discovery responses stay within configured size limits.
-    fn ensure_discovery_response_size<T: Serialize>(&self, payload: &T) -> Result<(), ToolError> {
+    /// Ensures discovery responses stay within configured size limits.
+    fn ensure_discovery_response_size<T: Serialize>(
+        &self,
+        label: &str,
+        payload: &T,
+    ) -> Result<(), ToolError> {
         match canonical_json_bytes_with_limit(payload, self.provider_discovery.max_response_bytes) {
             Ok(_) => Ok(()),
             Err(HashError::SizeLimitExceeded {
                 limit,
                 actual,
             }) => Err(ToolError::ResponseTooLarge(format!(
-                "provider discovery response exceeds size limit ({actual} > {limit})"
+                "{label} response exceeds size limit ({actual} > {limit})"
             ))),
             Err(HashError::Canonicalization(err)) => Err(ToolError::Internal(format!(
                 "failed to canonicalize discovery response: {err}"
@@ -1559,6 +1961,19 @@ pub struct ScenarioStatusRequest {
     pub scenario_id: ScenarioId,
     /// Core status request.
     pub request: StatusRequest,
+    /// Optional long-poll options; returns immediately when absent.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub wait_for_change: Option<StatusWaitOptions>,
+}
+
+/// Long-poll options for `scenario_status`.
+///
+/// # Invariants
+/// - `max_wait_ms` is validated by the tool handler against the server maximum.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub struct StatusWaitOptions {
+    /// Maximum time to hold the request before returning the current status.
+    pub max_wait_ms: u64,
 }
 
 /// Scenario next request wrapper.
@@ -1631,6 +2046,33 @@ pub struct ScenarioTriggerRequest {
     pub scenario_id: ScenarioId,
     /// Trigger event payload.
     pub trigger: TriggerEvent,
+    /// Lease holder triggering; required while the run is leased.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub lease_holder: Option<String>,
+}
+
+/// Scenario lease acquisition request wrapper.
+///
+/// # Invariants
+/// - This is a pure request container; values are validated by the control plane.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ScenarioLeaseAcquireRequest {
+    /// Scenario identifier.
+    pub scenario_id: ScenarioId,
+    /// Core lease acquisition request.
+    pub request: LeaseAcquireRequest,
+}
+
+/// Scenario lease release request wrapper.
+///
+/// # Invariants
+/// - This is a pure request container; values are validated by the control plane.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ScenarioLeaseReleaseRequest {
+    /// Scenario identifier.
+    pub scenario_id: ScenarioId,
+    /// Core lease release request.
+    pub request: LeaseReleaseRequest,
 }
 
 /// Evidence query request wrapper.
@@ -1678,6 +2120,9 @@ pub struct RunpackExportRequest {
     pub generated_at: Timestamp,
     /// Include verification report.
     pub include_verification: bool,
+    /// Include evaluation audit records as a runpack artifact.
+    #[serde(default)]
+    pub include_evaluation_audit: bool,
 }
 
 /// Runpack export response payload.
@@ -1878,6 +2323,7 @@ pub struct SchemasListRequest {
     /// Pagination cursor.
     pub cursor: Option<String>,
     /// Maximum number of records to return.
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub limit: Option<usize>,
 }
 
@@ -1932,6 +2378,7 @@ pub struct ScenariosListRequest {
     /// Pagination cursor.
     pub cursor: Option<String>,
     /// Maximum number of records to return.
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub limit: Option<usize>,
 }
 
@@ -1961,6 +2408,86 @@ pub struct ScenariosListResponse {
     pub next_token: Option<String>,
 }
 
+/// Scenario stream page request used by the SSE transport.
+///
+/// # Invariants
+/// - `after_sequence` is a sequence previously returned by the same router.
+#[derive(Debug, Clone, Copy)]
+pub(crate) struct ScenarioStreamRequest {
+    /// Tenant identifier.
+    pub tenant_id: TenantId,
+    /// Namespace identifier.
+    pub namespace_id: NamespaceId,
+    /// Resume strictly after this definition sequence.
+    pub after_sequence: Option<u64>,
+    /// Maximum number of items to return.
+    pub limit: usize,
+}
+
+/// Scenario summary tagged with its definition sequence.
+///
+/// # Invariants
+/// - `sequence` increases monotonically with definition order.
+#[derive(Debug, Clone)]
+pub(crate) struct ScenarioStreamItem {
+    /// Definition sequence used as the stream resume cursor.
+    pub sequence: u64,
+    /// Scenario summary.
+    pub summary: ScenarioSummary,
+}
+
+/// Run reference accepted by `scenarios_status_bulk`.
+///
+/// # Invariants
+/// - This is a pure request container; values are validated by the tool handler.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ScenarioRunRef {
+    /// Scenario identifier.
+    pub scenario_id: ScenarioId,
+    /// Run identifier.
+    pub run_id: RunId,
+}
+
+/// `scenarios_status_bulk` request payload.
+///
+/// # Invariants
+/// - This is a pure request container; values are validated by the tool handler.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ScenariosStatusBulkRequest {
+    /// Tenant identifier applied to every run.
+    pub tenant_id: TenantId,
+    /// Namespace identifier applied to every run.
+    pub namespace_id: NamespaceId,
+    /// Runs to fetch, in result order.
+    pub runs: Vec<ScenarioRunRef>,
+}
+
+/// Per-run result returned by `scenarios_status_bulk`.
+///
+/// # Invariants
+/// - `status` is present if and only if `found` is true.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ScenarioStatusBulkResult {
+    /// Scenario identifier from the request.
+    pub scenario_id: ScenarioId,
+    /// Run identifier from the request.
+    pub run_id: RunId,
+    /// Whether the run exists within the requested tenant and namespace.
+    pub found: bool,
+    /// Run status snapshot when found.
+    pub status: Option<ScenarioStatus>,
+}
+
+/// `scenarios_status_bulk` response payload.
+///
+/// # Invariants
+/// - `results` preserves the order of the request `runs`.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ScenariosStatusBulkResponse {
+    /// Per-run status results.
+    pub results: Vec<ScenarioStatusBulkResult>,
+}
+
 /// precheck request payload.
 ///
 /// # Invariants
@@ -2004,6 +2531,8 @@ pub struct PrecheckToolResponse {
 struct RouterState {
     /// Scenario runtimes keyed by scenario ID.
     scenarios: BTreeMap<String, Arc<ScenarioRuntime>>,
+    /// Sequence assigned to the next defined scenario.
+    next_definition_sequence: u64,
 }
 
 /// Feedback policy evaluator for `scenario_next`.
@@ -2107,6 +2636,8 @@ struct ScenarioNextFeedbackDecision {
 struct ScenarioRuntime {
     /// Scenario specification.
     spec: ScenarioSpec,
+    /// Monotonic definition order within this router.
+    definition_sequence: u64,
     /// Run state store for the scenario.
     store: SharedRunStateStore,
     /// Control plane instance for the scenario.
@@ -2114,6 +2645,27 @@ struct ScenarioRuntime {
         ControlPlane<FederatedEvidenceProvider, McpDispatcher, SharedRunStateStore, DispatchPolicy>,
 }
 
+impl ScenarioRuntime {
+    /// Returns true when the scenario is listed for the tenant and namespace.
+    fn is_listed_for(&self, tenant_id: TenantId, namespace_id: NamespaceId) -> bool {
+        self.spec.namespace_id == namespace_id
+            && self.spec.default_tenant_id.is_none_or(|tenant| tenant == tenant_id)
+    }
+
+    /// Builds the discovery summary for the scenario.
+    fn summary(&self) -> Result<ScenarioSummary, ToolError> {
+        let spec_hash = self
+            .spec
+            .canonical_hash_with(DEFAULT_HASH_ALGORITHM)
+            .map_err(|err| ToolError::Internal(err.to_string()))?;
+        Ok(ScenarioSummary {
+            scenario_id: self.spec.scenario_id.clone(),
+            namespace_id: self.spec.namespace_id,
+            spec_hash,
+        })
+    }
+}
+
 /// Control plane wrapper for owned or borrowed runtimes.
 enum ControlPlaneWrapper {
     /// Owned control plane instance for ad-hoc execution.
@@ -2181,11 +2733,6 @@ impl ToolRouter {
             .canonical_hash_with(DEFAULT_HASH_ALGORITHM)
             .map_err(|err| ToolError::Internal(err.to_string()))?;
 
-        let runtime = Arc::new(ScenarioRuntime {
-            spec: request.spec.clone(),
-            store,
-            control,
-        });
         {
             let mut guard = self
                 .state
@@ -2194,6 +2741,13 @@ impl ToolRouter {
             if guard.scenarios.contains_key(&scenario_id) {
                 return Err(ToolError::Conflict("scenario already defined".to_string()));
             }
+            guard.next_definition_sequence = guard.next_definition_sequence.saturating_add(1);
+            let runtime = Arc::new(ScenarioRuntime {
+                spec: request.spec.clone(),
+                definition_sequence: guard.next_definition_sequence,
+                store,
+                control,
+            });
             guard.scenarios.insert(scenario_id, runtime);
         }
 
@@ -2229,6 +2783,65 @@ impl ToolRouter {
         Ok(status)
     }
 
+    /// Returns scenario status without recording a tool call.
+    fn status_peek(
+        &self,
+        request: &ScenarioStatusRequest,
+    ) -> Result<Option<ScenarioStatus>, ToolError> {
+        let runtime = self.runtime_for(&request.scenario_id)?;
+        runtime
+            .control
+            .peek_scenario_status(
+                request.request.tenant_id,
+                request.request.namespace_id,
+                &request.request.run_id,
+            )
+            .map_err(ToolError::ControlPlane)
+    }
+
+    /// Returns status for each requested run, scoped to the request tenant.
+    fn status_bulk(
+        &self,
+        request: &ScenariosStatusBulkRequest,
+    ) -> Result<ScenariosStatusBulkResponse, ToolError> {
+        let mut results = Vec::with_capacity(request.runs.len());
+        for run in &request.runs {
+            let status = self.scoped_run_status(request, run)?;
+            results.push(ScenarioStatusBulkResult {
+                scenario_id: run.scenario_id.clone(),
+                run_id: run.run_id.clone(),
+                found: status.is_some(),
+                status,
+            });
+        }
+        Ok(ScenariosStatusBulkResponse {
+            results,
+        })
+    }
+
+    /// Reads one run's status, returning `None` when it is outside the request scope.
+    ///
+    /// Bulk reads never record tool calls or save run state, and runs that
+    /// belong to another scenario are rejected before their status is built.
+    fn scoped_run_status(
+        &self,
+        request: &ScenariosStatusBulkRequest,
+        run: &ScenarioRunRef,
+    ) -> Result<Option<ScenarioStatus>, ToolError> {
+        let runtime = match self.runtime_for(&run.scenario_id) {
+            Ok(runtime) => runtime,
+            Err(ToolError::NotFound(_)) => return Ok(None),
+            Err(err) => return Err(err),
+        };
+        if runtime.spec.namespace_id != request.namespace_id {
+            return Ok(None);
+        }
+        runtime
+            .control
+            .peek_scenario_status(request.tenant_id, request.namespace_id, &run.run_id)
+            .map_err(ToolError::ControlPlane)
+    }
+
     /// Advances a scenario evaluation.
     fn next(
         &self,
@@ -2237,8 +2850,16 @@ impl ToolRouter {
         request: &ScenarioNextRequest,
     ) -> Result<ScenarioNextResponse, ToolError> {
         let runtime = self.runtime_for(&request.scenario_id)?;
-        let result =
-            runtime.control.scenario_next(&request.request).map_err(ToolError::ControlPlane)?;
+        let result = runtime.control.scenario_next(&request.request).map_err(lease_tool_error)?;
+        self.record_evaluation_audit(
+            context,
+            &runtime,
+            ToolName::ScenarioNext,
+            (request.request.tenant_id, request.request.namespace_id, &request.request.run_id),
+            |state| {
+                EvaluationAuditRecord::for_decision(state, &result.decision, DEFAULT_HASH_ALGORITHM)
+            },
+        )?;
         let feedback = self.build_scenario_next_feedback(
             context,
             auth_ctx,
@@ -2291,8 +2912,9 @@ impl ToolRouter {
                 &request.request.namespace_id,
                 &request.request.run_id,
             )
-            .map_err(|err| {
-                ToolError::Internal(format!("scenario next feedback load failed: {err}"))
+            .map_err(|err| match err {
+                StoreError::Unavailable(message) => ToolError::StoreUnavailable(message),
+                _ => ToolError::Internal(format!("scenario next feedback load failed: {err}")),
             })?
             .ok_or_else(|| {
                 ToolError::Internal("scenario next feedback missing run state".to_string())
@@ -2377,7 +2999,7 @@ impl ToolRouter {
     /// Submits external artifacts to a scenario run.
     fn submit(
         &self,
-        _context: &RequestContext,
+        context: &RequestContext,
         request: &ScenarioSubmitRequest,
     ) -> Result<SubmitResult, ToolError> {
         let runtime = self.runtime_for(&request.scenario_id)?;
@@ -2386,22 +3008,66 @@ impl ToolRouter {
                 ControlPlaneError::SubmissionConflict(submission_id) => {
                     ToolError::Conflict(format!("submission_id conflict: {submission_id}"))
                 }
-                _ => ToolError::ControlPlane(err),
+                ControlPlaneError::VersionConflict {
+                    expected,
+                    actual,
+                } => ToolError::Conflict(format!(
+                    "run version conflict: expected {expected}, current {actual}; re-read the run \
+                     and retry"
+                )),
+                _ => lease_tool_error(err),
             })?;
+        self.record_evaluation_audit(
+            context,
+            &runtime,
+            ToolName::ScenarioSubmit,
+            (request.request.tenant_id, request.request.namespace_id, &request.request.run_id),
+            |state| {
+                EvaluationAuditRecord::for_submission(state, &result.record, DEFAULT_HASH_ALGORITHM)
+            },
+        )?;
         Ok(result)
     }
 
     /// Submits a trigger event to a scenario run.
     fn trigger(
         &self,
-        _context: &RequestContext,
+        context: &RequestContext,
         request: &ScenarioTriggerRequest,
     ) -> Result<TriggerResult, ToolError> {
         let runtime = self.runtime_for(&request.scenario_id)?;
-        let result = runtime.control.trigger(&request.trigger).map_err(ToolError::ControlPlane)?;
+        let result = runtime
+            .control
+            .trigger_with_lease(&request.trigger, request.lease_holder.as_deref())
+            .map_err(lease_tool_error)?;
+        let trigger = &request.trigger;
+        self.record_evaluation_audit(
+            context,
+            &runtime,
+            ToolName::ScenarioTrigger,
+            (trigger.tenant_id, trigger.namespace_id, &trigger.run_id),
+            |state| {
+                EvaluationAuditRecord::for_decision(state, &result.decision, DEFAULT_HASH_ALGORITHM)
+            },
+        )?;
         Ok(result)
     }
 
+    /// Acquires or renews a run lease.
+    fn lease_acquire(&self, request: &ScenarioLeaseAcquireRequest) -> Result<RunLease, ToolError> {
+        let runtime = self.runtime_for(&request.scenario_id)?;
+        runtime.control.acquire_lease(&request.request).map_err(lease_tool_error)
+    }
+
+    /// Releases a run lease.
+    fn lease_release(
+        &self,
+        request: &ScenarioLeaseReleaseRequest,
+    ) -> Result<LeaseReleaseResult, ToolError> {
+        let runtime = self.runtime_for(&request.scenario_id)?;
+        runtime.control.release_lease(&request.request).map_err(lease_tool_error)
+    }
+
     /// Queries evidence providers with disclosure policy enforcement.
     fn query_evidence(
         &self,
@@ -2460,12 +3126,18 @@ impl ToolRouter {
         let state = runtime
             .store
             .load(&request.tenant_id, &request.namespace_id, &request.run_id)
-            .map_err(|err| ToolError::Runpack(err.to_string()))?
+            .map_err(|err| match err {
+                StoreError::Unavailable(message) => ToolError::StoreUnavailable(message),
+                _ => ToolError::Runpack(err.to_string()),
+            })?
             .ok_or_else(|| ToolError::NotFound("run not found".to_string()))?;
         let mut builder = RunpackBuilder::new(self.anchor_policy.clone());
         if let Some(context) = self.runpack_security_context.clone() {
             builder = builder.with_security_context(context);
         }
+        if request.include_evaluation_audit {
+            builder = builder.with_evaluation_audit();
+        }
         if let Some(storage) = &self.runpack_storage {
             let temp_dir = tempfile::Builder::new()
                 .prefix("decision-gate-runpack-")
@@ -2614,7 +3286,7 @@ impl ToolRouter {
             .map_err(|err| ToolError::Runpack(err.to_string()))?;
         let manifest: decision_gate_core::RunpackManifest = serde_json::from_slice(&manifest_bytes)
             .map_err(|_| ToolError::Runpack("invalid manifest".to_string()))?;
-        let verifier = RunpackVerifier::new(DEFAULT_HASH_ALGORITHM);
+        let verifier = RunpackVerifier::from_manifest();
         let report = verifier
             .verify_manifest(&reader, &manifest)
             .map_err(|err| ToolError::Runpack(err.to_string()))?;
@@ -2735,34 +3407,16 @@ impl ToolRouter {
             guard
                 .scenarios
                 .values()
-                .filter(|runtime| runtime.spec.namespace_id == request.namespace_id)
-                .filter(|runtime| {
-                    runtime
-                        .spec
-                        .default_tenant_id
-                        .as_ref()
-                        .is_none_or(|tenant| tenant == &request.tenant_id)
-                })
-                .map(|runtime| {
-                    let spec_hash = runtime
-                        .spec
-                        .canonical_hash_with(DEFAULT_HASH_ALGORITHM)
-                        .map_err(|err| ToolError::Internal(err.to_string()))?;
-                    Ok(ScenarioSummary {
-                        scenario_id: runtime.spec.scenario_id.clone(),
-                        namespace_id: runtime.spec.namespace_id,
-                        spec_hash,
-                    })
-                })
+                .filter(|runtime| runtime.is_listed_for(request.tenant_id, request.namespace_id))
+                .map(|runtime| runtime.summary())
                 .collect::<Result<Vec<_>, ToolError>>()?
         };
         items.sort_by(|a, b| a.scenario_id.as_str().cmp(b.scenario_id.as_str()));
-        let start_index = request.cursor.as_ref().map_or(0, |cursor| {
-            items
-                .iter()
-                .position(|item| item.scenario_id.as_str() == cursor)
-                .map_or(0, |idx| idx + 1)
-        });
+        // Resume strictly after the cursor so removed scenarios do not restart paging.
+        let start_index = request
+            .cursor
+            .as_deref()
+            .map_or(0, |cursor| items.partition_point(|item| item.scenario_id.as_str() <= cursor));
         let page: Vec<ScenarioSummary> = items.into_iter().skip(start_index).take(limit).collect();
         let next_token = page.last().map(|item| item.scenario_id.to_string());
         Ok(ScenariosListResponse {
@@ -2771,6 +3425,39 @@ impl ToolRouter {
         })
     }
 
+    /// Lists scenarios defined after a stream sequence, in definition order.
+    fn scenario_stream_items(
+        &self,
+        request: &ScenarioStreamRequest,
+    ) -> Result<Vec<ScenarioStreamItem>, ToolError> {
+        let mut runtimes: Vec<Arc<ScenarioRuntime>> = {
+            let guard = self
+                .state
+                .lock()
+                .map_err(|_| ToolError::Internal("router lock poisoned".to_string()))?;
+            guard
+                .scenarios
+                .values()
+                .filter(|runtime| {
+                    request.after_sequence.is_none_or(|after| runtime.definition_sequence > after)
+                })
+                .filter(|runtime| runtime.is_listed_for(request.tenant_id, request.namespace_id))
+                .cloned()
+                .collect()
+        };
+        runtimes.sort_by_key(|runtime| runtime.definition_sequence);
+        runtimes
+            .iter()
+            .take(request.limit)
+            .map(|runtime| {
+                Ok(ScenarioStreamItem {
+                    sequence: runtime.definition_sequence,
+                    summary: runtime.summary()?,
+                })
+            })
+            .collect()
+    }
+
     /// Evaluates a scenario stage using asserted data without mutating state.
     fn precheck(
         &self,
@@ -2929,12 +3616,24 @@ impl ToolRouter {
         Ok(())
     }
 
-    /// Enforces the default namespace policy.
+    /// Enforces namespace policy and records the namespace authority verdict.
     async fn ensure_namespace_allowed(
         &self,
         context: &RequestContext,
         tenant_id: Option<&TenantId>,
         namespace_id: &NamespaceId,
+    ) -> Result<(), ToolError> {
+        authz_trace::record_target(tenant_id, Some(namespace_id));
+        let result = self.check_namespace(context, tenant_id, namespace_id).await;
+        authz_trace::observe(AuthzLayer::NamespaceAuthority, result)
+    }
+
+    /// Enforces the default namespace policy and namespace authority.
+    async fn check_namespace(
+        &self,
+        context: &RequestContext,
+        tenant_id: Option<&TenantId>,
+        namespace_id: &NamespaceId,
     ) -> Result<(), ToolError> {
         if namespace_id.get() == DEFAULT_NAMESPACE_ID {
             if !self.allow_default_namespace {
@@ -2974,6 +3673,24 @@ impl ToolRouter {
         tenant_id: Option<&TenantId>,
         namespace_id: Option<&NamespaceId>,
     ) -> Result<(), ToolError> {
+        authz_trace::record_target(tenant_id, namespace_id);
+        if let Some(tenant_id) = tenant_id
+            && let Err(err) = auth_ctx.scopes.check_tenant(tenant_id)
+        {
+            let decision = TenantAuthzDecision {
+                allowed: false,
+                reason: err.to_string(),
+            };
+            self.record_tenant_authz(
+                context,
+                auth_ctx,
+                tool,
+                Some(tenant_id),
+                namespace_id,
+                &decision,
+            );
+            return Err(err.into());
+        }
         let decision = self
             .tenant_authorizer
             .authorize(
@@ -2989,6 +3706,32 @@ impl ToolRouter {
         if decision.allowed { Ok(()) } else { Err(ToolError::Unauthorized(decision.reason)) }
     }
 
+    /// Emits the authorization decision trace collected for a tool call.
+    fn record_authz_trace(&self, context: &RequestContext, tool: ToolName, trace: AuthzTrace) {
+        let reason = trace
+            .first_denier()
+            .and_then(|verdict| verdict.reason.clone())
+            .unwrap_or_else(|| "allowed".to_string());
+        let event = TenantAuthzEvent::decision_trace(
+            TenantAuthzEventParams {
+                request_id: context.request_id.clone(),
+                unsafe_client_correlation_id: context.unsafe_client_correlation_id.clone(),
+                server_correlation_id: server_correlation_id_for(context),
+                tool: Some(tool),
+                allowed: trace.allowed(),
+                reason,
+                principal_id: trace
+                    .principal_id
+                    .clone()
+                    .unwrap_or_else(|| "unauthenticated".to_string()),
+                tenant_id: trace.tenant_id.clone(),
+                namespace_id: trace.namespace_id.clone(),
+            },
+            trace,
+        );
+        self.precheck_audit.record_tenant_authz(&event);
+    }
+
     /// Enforces registry ACL decisions and emits registry audit events.
     fn ensure_registry_access(
         &self,
@@ -3001,6 +3744,11 @@ impl ToolRouter {
     ) -> Result<(), ToolError> {
         let principal = self.principal_resolver.resolve(auth_ctx);
         let decision = self.registry_acl.authorize(&principal, action, &tenant_id, &namespace_id);
+        authz_trace::record_target(Some(&tenant_id), Some(&namespace_id));
+        authz_trace::record(
+            AuthzLayer::RegistryAcl,
+            (!decision.allowed).then(|| decision.reason.clone()),
+        );
         self.record_registry_audit(
             context,
             &principal,
@@ -3031,6 +3779,10 @@ impl ToolRouter {
         namespace_id: Option<&NamespaceId>,
         decision: &TenantAuthzDecision,
     ) {
+        authz_trace::record(
+            AuthzLayer::TenantAuthz,
+            (!decision.allowed).then(|| decision.reason.clone()),
+        );
         let event = TenantAuthzEvent::new(TenantAuthzEventParams {
             request_id: context.request_id.clone(),
             unsafe_client_correlation_id: context.unsafe_client_correlation_id.clone(),
@@ -3080,6 +3832,10 @@ impl ToolRouter {
             units,
             &decision,
         );
+        authz_trace::record(
+            AuthzLayer::Usage,
+            (!decision.allowed).then(|| decision.reason.clone()),
+        );
         if decision.allowed { Ok(()) } else { Err(ToolError::Unauthorized(decision.reason)) }
     }
 
@@ -3189,6 +3945,42 @@ impl ToolRouter {
         self.precheck_audit.record_registry(&event);
     }
 
+    /// Emits a hash-only evaluation audit event for a committed outcome.
+    ///
+    /// The run is re-read after the mutation so hashes cover the persisted
+    /// state. Failures surface as errors; retries are idempotent on the
+    /// trigger or submission identifier and re-emit the record.
+    fn record_evaluation_audit<F>(
+        &self,
+        context: &RequestContext,
+        runtime: &ScenarioRuntime,
+        tool: ToolName,
+        run: (TenantId, NamespaceId, &RunId),
+        build: F,
+    ) -> Result<(), ToolError>
+    where
+        F: FnOnce(&RunState) -> Result<EvaluationAuditRecord, HashError>,
+    {
+        let (tenant_id, namespace_id, run_id) = run;
+        let state = runtime
+            .store
+            .load(&tenant_id, &namespace_id, run_id)
+            .map_err(|err| match err {
+                StoreError::Unavailable(message) => ToolError::StoreUnavailable(message),
+                _ => ToolError::Internal(format!("evaluation audit load failed: {err}")),
+            })?
+            .ok_or_else(|| ToolError::Internal("evaluation audit missing run state".to_string()))?;
+        let record = build(&state).map_err(|err| ToolError::Internal(err.to_string()))?;
+        let event = EvaluationAuditEvent::new(EvaluationAuditEventParams {
+            unsafe_client_correlation_id: context.unsafe_client_correlation_id.clone(),
+            server_correlation_id: server_correlation_id_for(context),
+            tool,
+            record,
+        });
+        self.precheck_audit.record_evaluation(&event);
+        Ok(())
+    }
+
     /// Emits a hash-only precheck audit event.
     fn record_precheck_audit(
         &self,
@@ -3245,9 +4037,17 @@ impl ToolRouter {
         context: &RequestContext,
         action: AuthAction<'_>,
     ) -> Result<AuthContext, ToolError> {
-        match self.authz.authorize(context, action).await {
+        let authorized = self.authz.authorize(context, action).await;
+        if !authz_trace::has_layer(AuthzLayer::AuthMode) {
+            authz_trace::record(
+                AuthzLayer::AuthMode,
+                authorized.as_ref().err().map(ToString::to_string),
+            );
+        }
+        match authorized {
             Ok(auth_ctx) => {
                 self.audit.record(&AuthAuditEvent::allowed(context, action, &auth_ctx));
+                authz_trace::record_principal(auth_ctx.principal_id());
                 Ok(auth_ctx)
             }
             Err(err) => {
@@ -3333,6 +4133,23 @@ fn map_namespace_error(error: NamespaceAuthorityError) -> ToolError {
     }
 }
 
+/// Maps run lease errors into conflicts and passes other control plane errors through.
+fn lease_tool_error(error: ControlPlaneError) -> ToolError {
+    match error {
+        ControlPlaneError::LeaseHeld {
+            ..
+        } => ToolError::Conflict(
+            "run is leased by another holder; retry after the lease is released or expires"
+                .to_string(),
+        ),
+        ControlPlaneError::LeaseNotHeld(holder) => {
+            ToolError::Conflict(format!("lease_holder {holder} holds no active lease on the run"))
+        }
+        ControlPlaneError::InvalidLease(message) => ToolError::InvalidParams(message),
+        other => ToolError::ControlPlane(other),
+    }
+}
+
 /// Returns the canonical label for a usage metric.
 const fn usage_metric_label(metric: UsageMetric) -> &'static str {
     match metric {
@@ -3374,6 +4191,12 @@ pub enum ToolError {
     /// Tool response exceeds size limits.
     #[error("response too large: {0}")]
     ResponseTooLarge(String),
+    /// Tool concurrency limit reached.
+    #[error("concurrency limited: {0}")]
+    ConcurrencyLimited(String),
+    /// Run state store temporarily unavailable.
+    #[error("store unavailable: {0}")]
+    StoreUnavailable(String),
     /// Tool request rate-limited.
     #[error("rate limited: {message}")]
     RateLimited {
@@ -3410,6 +4233,12 @@ pub enum ToolError {
     Internal(String),
 }
 
+impl From<ToolConcurrencyError> for ToolError {
+    fn from(error: ToolConcurrencyError) -> Self {
+        Self::ConcurrencyLimited(error.to_string())
+    }
+}
+
 impl From<CapabilityError> for ToolError {
     fn from(error: CapabilityError) -> Self {
         Self::CapabilityViolation {
@@ -3430,6 +4259,20 @@ impl From<DataShapeRegistryError> for ToolError {
     }
 }
 
+impl From<ToolSchemaError> for ToolError {
+    fn from(error: ToolSchemaError) -> Self {
+        match error {
+            ToolSchemaError::InvalidInput {
+                ..
+            } => Self::InvalidParams(error.to_string()),
+            ToolSchemaError::InvalidOutput {
+                ..
+            }
+            | ToolSchemaError::Unavailable(_) => Self::Internal(error.to_string()),
+        }
+    }
+}
+
 impl From<AuthError> for ToolError {
     fn from(error: AuthError) -> Self {
         match error {
@@ -3444,6 +4287,16 @@ fn decode<T: for<'de> Deserialize<'de>>(payload: Value) -> Result<T, ToolError>
     serde_json::from_value(payload).map_err(|err| ToolError::InvalidParams(err.to_string()))
 }
 
+/// Validates long-poll options against the server maximum wait.
+fn normalize_status_wait(wait: StatusWaitOptions) -> Result<Duration, ToolError> {
+    if wait.max_wait_ms == 0 || wait.max_wait_ms > MAX_STATUS_WAIT_MS {
+        return Err(ToolError::InvalidParams(format!(
+            "wait_for_change.max_wait_ms must be between 1 and {MAX_STATUS_WAIT_MS}"
+        )));
+    }
+    Ok(Duration::from_millis(wait.max_wait_ms))
+}
+
 /// Normalizes list limits against configured defaults and bounds.
 fn normalize_limit(limit: Option<usize>) -> Result<usize, ToolError> {
     let limit = limit.unwrap_or(DEFAULT_LIST_LIMIT);
@@ -80,15 +80,19 @@ use crate::config::NamespaceConfig;
 use crate::config::PolicyConfig;
 use crate::config::PrincipalConfig;
 use crate::config::PrincipalRoleConfig;
+use crate::config::PrincipalScopesConfig;
 use crate::config::ProviderConfig;
 use crate::config::ProviderDiscoveryConfig;
 use crate::config::ProviderTimeoutConfig;
 use crate::config::ProviderType;
+use crate::config::ProviderWarmUpMode;
 use crate::config::RunStateStoreConfig;
 use crate::config::SchemaRegistryConfig;
 use crate::config::ServerAuthConfig;
 use crate::config::ServerAuthMode;
 use crate::config::ServerConfig;
+use crate::config::ServerOpenApiConfig;
+use crate::config::ServerSseConfig;
 use crate::config::ServerToolsConfig;
 use crate::config::TrustConfig;
 use crate::config::ValidationConfig;
@@ -352,11 +356,13 @@ fn sample_config() -> DecisionGateConfig {
             auth: Some(ServerAuthConfig {
                 mode: ServerAuthMode::LocalOnly,
                 bearer_tokens: Vec::new(),
+                jwt: None,
                 mtls_subjects: Vec::new(),
                 allowed_tools: Vec::new(),
                 principals: vec![PrincipalConfig {
                     subject: "stdio".to_string(),
                     policy_class: Some("prod".to_string()),
+                    scopes: PrincipalScopesConfig::default(),
                     roles: vec![PrincipalRoleConfig {
                         name: "TenantAdmin".to_string(),
                         tenant_id: None,
@@ -365,6 +371,9 @@ fn sample_config() -> DecisionGateConfig {
                 }],
             }),
             tools: ServerToolsConfig::default(),
+            sse: ServerSseConfig::default(),
+            openapi: ServerOpenApiConfig::default(),
+            provider_warm_up: ProviderWarmUpMode::default(),
             ..ServerConfig::default()
         },
         namespace: NamespaceConfig {
@@ -518,6 +527,7 @@ fn router_with_overrides(
         tools: config.server.tools.clone(),
         docs_provider,
         tool_visibility_resolver,
+        tool_concurrency: config.server.limits.tool_concurrency.clone(),
         allow_default_namespace: config.allow_default_namespace(),
         default_namespace_tenants,
         namespace_authority: Arc::new(NoopNamespaceAuthority),
@@ -580,6 +590,7 @@ fn runpack_export_requires_output_dir_without_backend() {
         manifest_name: None,
         generated_at: Timestamp::UnixMillis(0),
         include_verification: false,
+        include_evaluation_audit: false,
     };
     let err = router.export_runpack(&RequestContext::stdio(), &request).expect_err("error");
     assert!(err.to_string().contains("output_dir"));
@@ -599,6 +610,7 @@ fn runpack_export_uses_object_store_backend() {
         manifest_name: None,
         generated_at: Timestamp::UnixMillis(0),
         include_verification: false,
+        include_evaluation_audit: false,
     };
     let response =
         router.export_runpack(&RequestContext::stdio(), &request).expect("runpack export");
@@ -623,6 +635,7 @@ fn runpack_export_prefers_runpack_storage_over_object_store() {
         manifest_name: None,
         generated_at: Timestamp::UnixMillis(0),
         include_verification: false,
+        include_evaluation_audit: false,
     };
     let response =
         router.export_runpack(&RequestContext::stdio(), &request).expect("runpack export");
@@ -669,7 +682,7 @@ fn list_tools_hides_docs_search_when_denied() {
 }
 
 // ============================================================================
-// SECTION: Tool Visibility Tests (12 tests)
+// SECTION: Tool Visibility Tests (13 tests)
 // ============================================================================
 
 #[test]
@@ -909,6 +922,53 @@ fn tool_visibility_disabled_tool_never_appears() {
     );
 }
 
+#[test]
+fn tool_visibility_denylisted_tool_is_not_callable() {
+    let mut config = sample_config();
+    config.server.tools.mode = ToolVisibilityMode::Filter;
+    config.server.tools.denylist = vec![ToolName::RunpackExport.as_str().to_string()];
+
+    let router = router_with_config_and_backends(config, None, None);
+    let runtime = tokio::runtime::Runtime::new().expect("runtime");
+    let tools = runtime.block_on(router.list_tools(&RequestContext::stdio())).expect("list tools");
+    assert!(!tools.iter().any(|t| t.name == ToolName::RunpackExport));
+
+    // Disabled tools are rejected as unknown before params are inspected
+    let err = runtime
+        .block_on(router.handle_tool_call(
+            &RequestContext::stdio(),
+            ToolName::RunpackExport.as_str(),
+            json!({}),
+        ))
+        .expect_err("denylisted tool call should fail");
+    assert!(matches!(err, ToolError::UnknownTool));
+}
+
+#[test]
+fn tool_visibility_allowlist_rejects_unlisted_tool_calls() {
+    let mut config = sample_config();
+    config.server.tools.mode = ToolVisibilityMode::Filter;
+    config.server.tools.allowlist = vec![ToolName::ProvidersList.as_str().to_string()];
+
+    let router = router_with_config_and_backends(config, None, None);
+    let runtime = tokio::runtime::Runtime::new().expect("runtime");
+    runtime
+        .block_on(router.handle_tool_call(
+            &RequestContext::stdio(),
+            ToolName::ProvidersList.as_str(),
+            json!({}),
+        ))
+        .expect("allowlisted tool call");
+    let err = runtime
+        .block_on(router.handle_tool_call(
+            &RequestContext::stdio(),
+            ToolName::ScenariosList.as_str(),
+            json!({ "tenant_id": 1, "namespace_id": 1 }),
+        ))
+        .expect_err("unlisted tool call should fail");
+    assert!(matches!(err, ToolError::UnknownTool));
+}
+
 // ============================================================================
 // SECTION: Docs Search Handler Tests (12 tests)
 // ============================================================================
@@ -25,6 +25,7 @@
 use std::net::IpAddr;
 
 use decision_gate_contract::ToolName;
+use decision_gate_mcp::AuthScopes;
 use decision_gate_mcp::DefaultToolAuthz;
 use decision_gate_mcp::RequestContext;
 use decision_gate_mcp::ToolAuthz;
@@ -33,6 +34,8 @@ use decision_gate_mcp::auth::AuthAuditEvent;
 use decision_gate_mcp::auth::AuthContext;
 use decision_gate_mcp::auth::AuthError;
 use decision_gate_mcp::auth::AuthMethod;
+use decision_gate_mcp::config::PrincipalConfig;
+use decision_gate_mcp::config::PrincipalScopesConfig;
 use decision_gate_mcp::config::ServerAuthConfig;
 use decision_gate_mcp::config::ServerAuthMode;
 use decision_gate_mcp::config::ServerTransport;
@@ -68,6 +71,7 @@ fn bearer_auth_requires_token() {
     let config = ServerAuthConfig {
         mode: ServerAuthMode::BearerToken,
         bearer_tokens: vec!["token-1".to_string()],
+        jwt: None,
         mtls_subjects: Vec::new(),
         allowed_tools: Vec::new(),
         principals: Vec::new(),
@@ -84,6 +88,7 @@ fn bearer_auth_accepts_valid_token() {
     let config = ServerAuthConfig {
         mode: ServerAuthMode::BearerToken,
         bearer_tokens: vec!["token-1".to_string()],
+        jwt: None,
         mtls_subjects: Vec::new(),
         allowed_tools: Vec::new(),
         principals: Vec::new(),
@@ -104,6 +109,7 @@ fn tool_allowlist_denies_disallowed_tool() {
     let config = ServerAuthConfig {
         mode: ServerAuthMode::BearerToken,
         bearer_tokens: vec!["token-1".to_string()],
+        jwt: None,
         mtls_subjects: Vec::new(),
         allowed_tools: vec!["scenario_define".to_string()],
         principals: Vec::new(),
@@ -126,6 +132,7 @@ fn bearer_auth_rejects_invalid_scheme() {
     let config = ServerAuthConfig {
         mode: ServerAuthMode::BearerToken,
         bearer_tokens: vec!["token-1".to_string()],
+        jwt: None,
         mtls_subjects: Vec::new(),
         allowed_tools: Vec::new(),
         principals: Vec::new(),
@@ -146,6 +153,7 @@ fn bearer_auth_rejects_oversized_header() {
     let config = ServerAuthConfig {
         mode: ServerAuthMode::BearerToken,
         bearer_tokens: vec!["token-1".to_string()],
+        jwt: None,
         mtls_subjects: Vec::new(),
         allowed_tools: Vec::new(),
         principals: Vec::new(),
@@ -167,6 +175,7 @@ fn mtls_requires_subject_header() {
     let config = ServerAuthConfig {
         mode: ServerAuthMode::Mtls,
         bearer_tokens: Vec::new(),
+        jwt: None,
         mtls_subjects: vec!["CN=client".to_string()],
         allowed_tools: Vec::new(),
         principals: Vec::new(),
@@ -183,6 +192,7 @@ fn mtls_rejects_unlisted_subject() {
     let config = ServerAuthConfig {
         mode: ServerAuthMode::Mtls,
         bearer_tokens: Vec::new(),
+        jwt: None,
         mtls_subjects: vec!["CN=client".to_string()],
         allowed_tools: Vec::new(),
         principals: Vec::new(),
@@ -205,6 +215,7 @@ fn audit_event_serializes_with_decision() {
         method: AuthMethod::Local,
         subject: Some("stdio".to_string()),
         token_fingerprint: None,
+        scopes: AuthScopes::default(),
     };
     let event = AuthAuditEvent::allowed(&context, AuthAction::ListTools, &auth);
     let payload = serde_json::to_value(&event).expect("serialize audit event");
@@ -219,3 +230,112 @@ fn audit_event_serializes_with_decision() {
     assert_eq!(payload.get("decision").and_then(Value::as_str), Some("deny"));
     assert!(payload.get("reason").and_then(Value::as_str).is_some());
 }
+
+fn bearer_context(token: &str) -> RequestContext {
+    RequestContext::http(
+        ServerTransport::Http,
+        Some(IpAddr::from([127, 0, 0, 1])),
+        Some(format!("Bearer {token}")),
+        None,
+    )
+}
+
+fn scoped_bearer_config(principal: &str, scopes: PrincipalScopesConfig) -> ServerAuthConfig {
+    ServerAuthConfig {
+        mode: ServerAuthMode::BearerToken,
+        bearer_tokens: vec!["reader-token".to_string(), "writer-token".to_string()],
+        jwt: None,
+        mtls_subjects: Vec::new(),
+        allowed_tools: Vec::new(),
+        principals: vec![PrincipalConfig {
+            subject: principal.to_string(),
+            policy_class: None,
+            scopes,
+            roles: Vec::new(),
+        }],
+    }
+}
+
+fn token_principal(token: &str) -> String {
+    let config = scoped_bearer_config("unused", PrincipalScopesConfig::default());
+    let authz = DefaultToolAuthz::from_config(Some(&config));
+    authorize_sync(&authz, &bearer_context(token), AuthAction::ListTools)
+        .expect("authenticate token")
+        .principal_id()
+}
+
+#[test]
+fn read_only_scope_denies_mutating_tools() {
+    let principal = token_principal("reader-token");
+    let scopes = PrincipalScopesConfig {
+        read_only: true,
+        ..PrincipalScopesConfig::default()
+    };
+    let config = scoped_bearer_config(&principal, scopes);
+    let authz = DefaultToolAuthz::from_config(Some(&config));
+    let context = bearer_context("reader-token");
+
+    let status = authorize_sync(&authz, &context, AuthAction::CallTool(&ToolName::ScenarioStatus))
+        .expect("read-only tool allowed");
+    assert!(status.scopes.read_only);
+    let denied = authorize_sync(&authz, &context, AuthAction::CallTool(&ToolName::ScenarioSubmit));
+    assert!(
+        matches!(denied, Err(AuthError::Unauthorized(message)) if message.contains("read-only"))
+    );
+
+    let writer = bearer_context("writer-token");
+    let allowed = authorize_sync(&authz, &writer, AuthAction::CallTool(&ToolName::ScenarioSubmit));
+    assert!(allowed.is_ok());
+}
+
+#[test]
+fn scope_tool_allowlist_narrows_principal() {
+    let principal = token_principal("reader-token");
+    let scopes = PrincipalScopesConfig {
+        allowed_tools: vec!["precheck".to_string()],
+        ..PrincipalScopesConfig::default()
+    };
+    let config = scoped_bearer_config(&principal, scopes);
+    let authz = DefaultToolAuthz::from_config(Some(&config));
+    let context = bearer_context("reader-token");
+
+    let allowed = authorize_sync(&authz, &context, AuthAction::CallTool(&ToolName::Precheck));
+    assert!(allowed.is_ok());
+    let denied = authorize_sync(&authz, &context, AuthAction::CallTool(&ToolName::ScenarioStatus));
+    assert!(denied.is_err());
+}
+
+#[test]
+fn jwt_scopes_union_read_write_and_tool_grants() {
+    let scopes = |claim: &str| {
+        let claim: Vec<String> = claim.split(' ').map(str::to_string).collect();
+        AuthScopes::from_jwt_scopes(&claim)
+    };
+
+    let read_submit = scopes("dg:read dg:tool:scenario_submit");
+    assert!(!read_submit.read_only);
+    assert!(read_submit.permits_tool(ToolName::ScenarioStatus));
+    assert!(read_submit.permits_tool(ToolName::ProvidersList));
+    assert!(read_submit.permits_tool(ToolName::ScenarioSubmit));
+    assert!(!read_submit.permits_tool(ToolName::ScenarioStart));
+
+    let read_status = scopes("dg:read dg:tool:scenario_status");
+    assert!(read_status.read_only);
+    assert!(read_status.permits_tool(ToolName::SchemasList));
+    assert!(!read_status.permits_tool(ToolName::ScenarioSubmit));
+
+    let write_status = scopes("dg:write dg:tool:scenario_status");
+    assert_eq!(write_status.allowed_tools, None);
+    assert!(write_status.permits_tool(ToolName::ScenarioStart));
+    assert!(write_status.permits_tool(ToolName::SchemasRegister));
+
+    let submit_only = scopes("dg:tool:scenario_submit");
+    assert!(!submit_only.read_only);
+    assert!(submit_only.permits_tool(ToolName::ScenarioSubmit));
+    assert!(!submit_only.permits_tool(ToolName::ScenarioStatus));
+
+    let status_only = scopes("dg:tool:scenario_status");
+    assert!(status_only.read_only);
+    assert!(status_only.permits_tool(ToolName::ScenarioStatus));
+    assert!(!status_only.permits_tool(ToolName::ScenariosList));
+}
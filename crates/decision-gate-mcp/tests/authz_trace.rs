@@ -0,0 +1,208 @@
+// crates/decision-gate-mcp/tests/authz_trace.rs
+// ============================================================================
+// Module: Authorization Decision Trace Tests
+// Description: Verify per-request audit of authorization layer verdicts.
+// Purpose: Ensure decision traces name the denying layer and full allow chains.
+// Dependencies: decision-gate-mcp, decision-gate-core
+// ============================================================================
+
+//! ## Overview
+//! Exercises the authorization decision trace emitted for each tool call and
+//! verifies that denials are attributed to the specific layer that denied.
+//!
+//! Security posture: tests assert that audit traces reflect fail-closed
+//! decisions; see `Docs/security/threat_model.md`.
+
+#![allow(
+    clippy::unwrap_used,
+    clippy::expect_used,
+    clippy::unwrap_in_result,
+    reason = "Test setup uses unwraps for clarity."
+)]
+
+mod common;
+
+use std::sync::Arc;
+use std::sync::Mutex;
+
+use async_trait::async_trait;
+use common::ToolRouterSyncExt;
+use common::local_request_context;
+use common::router_with_precheck_audit;
+use common::sample_config;
+use common::sample_spec;
+use decision_gate_mcp::DecisionGateConfig;
+use decision_gate_mcp::McpAuditEvent;
+use decision_gate_mcp::McpAuditSink;
+use decision_gate_mcp::NoopTenantAuthorizer;
+use decision_gate_mcp::NoopUsageMeter;
+use decision_gate_mcp::TenantAccessRequest;
+use decision_gate_mcp::TenantAuthorizer;
+use decision_gate_mcp::TenantAuthzDecision;
+use decision_gate_mcp::TenantAuthzEvent;
+use decision_gate_mcp::UsageCheckRequest;
+use decision_gate_mcp::UsageDecision;
+use decision_gate_mcp::UsageMeter;
+use decision_gate_mcp::UsageRecord;
+use decision_gate_mcp::authz_trace::AuthzLayer;
+use decision_gate_mcp::config::PrincipalScopesConfig;
+use decision_gate_mcp::tools::ScenarioDefineRequest;
+
+#[derive(Default)]
+struct TraceAuditSink {
+    traces: Mutex<Vec<TenantAuthzEvent>>,
+}
+
+impl McpAuditSink for TraceAuditSink {
+    fn record(&self, _event: &McpAuditEvent) {}
+
+    fn record_tenant_authz(&self, event: &TenantAuthzEvent) {
+        if event.event == "authz_decision_trace" {
+            self.traces.lock().expect("trace lock").push(event.clone());
+        }
+    }
+}
+
+struct DenyTenantAuthorizer;
+
+#[async_trait]
+impl TenantAuthorizer for DenyTenantAuthorizer {
+    async fn authorize(
+        &self,
+        _auth: &decision_gate_mcp::AuthContext,
+        _request: TenantAccessRequest<'_>,
+    ) -> TenantAuthzDecision {
+        TenantAuthzDecision {
+            allowed: false,
+            reason: "tenant_access_denied".to_string(),
+        }
+    }
+}
+
+struct DenyUsageMeter;
+
+impl UsageMeter for DenyUsageMeter {
+    fn check(
+        &self,
+        _auth: &decision_gate_mcp::AuthContext,
+        _request: UsageCheckRequest<'_>,
+    ) -> UsageDecision {
+        UsageDecision {
+            allowed: false,
+            reason: "quota_exceeded".to_string(),
+        }
+    }
+
+    fn record(&self, _auth: &decision_gate_mcp::AuthContext, _record: UsageRecord<'_>) {}
+}
+
+fn define_and_trace(
+    config: &DecisionGateConfig,
+    tenant_authorizer: Arc<dyn TenantAuthorizer>,
+    usage_meter: Arc<dyn UsageMeter>,
+) -> (bool, TenantAuthzEvent) {
+    let audit = Arc::new(TraceAuditSink::default());
+    let router = router_with_precheck_audit(config, tenant_authorizer, usage_meter, audit.clone());
+    let request = ScenarioDefineRequest {
+        spec: sample_spec(),
+    };
+    let succeeded = router
+        .handle_tool_call_sync(
+            &local_request_context(),
+            "scenario_define",
+            serde_json::to_value(&request).unwrap(),
+        )
+        .is_ok();
+    let mut traces = audit.traces.lock().expect("trace lock");
+    assert_eq!(traces.len(), 1, "expected exactly one decision trace per call");
+    (succeeded, traces.remove(0))
+}
+
+fn layers(event: &TenantAuthzEvent) -> Vec<(AuthzLayer, bool)> {
+    event
+        .layers
+        .as_ref()
+        .expect("trace layers")
+        .iter()
+        .map(|verdict| (verdict.layer, verdict.allowed))
+        .collect()
+}
+
+#[test]
+fn allowed_call_records_full_allow_chain() {
+    let (succeeded, event) = define_and_trace(
+        &sample_config(),
+        Arc::new(NoopTenantAuthorizer),
+        Arc::new(NoopUsageMeter),
+    );
+    assert!(succeeded);
+    assert!(event.allowed);
+    assert_eq!(event.denied_by, None);
+    assert_eq!(event.reason, "allowed");
+    assert_eq!(event.principal_id, "stdio");
+    assert_eq!(event.tenant_id.as_deref(), Some("100"));
+    assert_eq!(event.namespace_id.as_deref(), Some("1"));
+    assert_eq!(
+        layers(&event),
+        vec![
+            (AuthzLayer::AuthMode, true),
+            (AuthzLayer::ToolScope, true),
+            (AuthzLayer::TenantAuthz, true),
+            (AuthzLayer::Usage, true),
+            (AuthzLayer::NamespaceAuthority, true),
+        ]
+    );
+}
+
+#[test]
+fn tenant_denial_records_tenant_authz_as_first_denier() {
+    let (succeeded, event) = define_and_trace(
+        &sample_config(),
+        Arc::new(DenyTenantAuthorizer),
+        Arc::new(NoopUsageMeter),
+    );
+    assert!(!succeeded);
+    assert!(!event.allowed);
+    assert_eq!(event.denied_by, Some(AuthzLayer::TenantAuthz));
+    assert_eq!(event.reason, "tenant_access_denied");
+    assert_eq!(
+        layers(&event),
+        vec![
+            (AuthzLayer::AuthMode, true),
+            (AuthzLayer::ToolScope, true),
+            (AuthzLayer::TenantAuthz, false),
+        ]
+    );
+}
+
+#[test]
+fn usage_denial_records_usage_as_first_denier() {
+    let (succeeded, event) = define_and_trace(
+        &sample_config(),
+        Arc::new(NoopTenantAuthorizer),
+        Arc::new(DenyUsageMeter),
+    );
+    assert!(!succeeded);
+    assert_eq!(event.denied_by, Some(AuthzLayer::Usage));
+    assert_eq!(event.reason, "quota_exceeded");
+}
+
+#[test]
+fn read_only_scope_records_tool_scope_as_first_denier() {
+    let mut config = sample_config();
+    let auth = config.server.auth.as_mut().expect("sample auth config");
+    for principal in &mut auth.principals {
+        if principal.subject == "stdio" {
+            principal.scopes = PrincipalScopesConfig {
+                read_only: true,
+                ..PrincipalScopesConfig::default()
+            };
+        }
+    }
+    let (succeeded, event) =
+        define_and_trace(&config, Arc::new(NoopTenantAuthorizer), Arc::new(NoopUsageMeter));
+    assert!(!succeeded);
+    assert_eq!(event.denied_by, Some(AuthzLayer::ToolScope));
+    assert_eq!(layers(&event), vec![(AuthzLayer::AuthMode, true), (AuthzLayer::ToolScope, false)]);
+    assert!(event.tenant_id.is_none());
+}
@@ -52,6 +52,7 @@ use decision_gate_core::Timestamp;
 use decision_gate_core::TriggerId;
 use decision_gate_mcp::DecisionGateConfig;
 use decision_gate_mcp::FederatedEvidenceProvider;
+use decision_gate_mcp::McpAuditSink;
 use decision_gate_mcp::McpNoopAuditSink;
 use decision_gate_mcp::NoopTenantAuthorizer;
 use decision_gate_mcp::NoopUsageMeter;
@@ -71,13 +72,17 @@ use decision_gate_mcp::config::NamespaceConfig;
 use decision_gate_mcp::config::PolicyConfig;
 use decision_gate_mcp::config::PrincipalConfig;
 use decision_gate_mcp::config::PrincipalRoleConfig;
+use decision_gate_mcp::config::PrincipalScopesConfig;
 use decision_gate_mcp::config::ProviderConfig;
 use decision_gate_mcp::config::ProviderTimeoutConfig;
 use decision_gate_mcp::config::ProviderType;
+use decision_gate_mcp::config::ProviderWarmUpMode;
 use decision_gate_mcp::config::RunStateStoreConfig;
 use decision_gate_mcp::config::ServerAuthConfig;
 use decision_gate_mcp::config::ServerAuthMode;
 use decision_gate_mcp::config::ServerConfig;
+use decision_gate_mcp::config::ServerOpenApiConfig;
+use decision_gate_mcp::config::ServerSseConfig;
 use decision_gate_mcp::config::ServerToolsConfig;
 use decision_gate_mcp::config::TrustConfig;
 use decision_gate_mcp::config::ValidationConfig;
@@ -107,12 +112,14 @@ pub fn sample_config() -> DecisionGateConfig {
             auth: Some(ServerAuthConfig {
                 mode: ServerAuthMode::LocalOnly,
                 bearer_tokens: Vec::new(),
+                jwt: None,
                 mtls_subjects: Vec::new(),
                 allowed_tools: Vec::new(),
                 principals: vec![
                     PrincipalConfig {
                         subject: "stdio".to_string(),
                         policy_class: Some("prod".to_string()),
+                        scopes: PrincipalScopesConfig::default(),
                         roles: vec![PrincipalRoleConfig {
                             name: "TenantAdmin".to_string(),
                             tenant_id: None,
@@ -122,6 +129,7 @@ pub fn sample_config() -> DecisionGateConfig {
                     PrincipalConfig {
                         subject: "loopback".to_string(),
                         policy_class: Some("prod".to_string()),
+                        scopes: PrincipalScopesConfig::default(),
                         roles: vec![PrincipalRoleConfig {
                             name: "TenantAdmin".to_string(),
                             tenant_id: None,
@@ -131,6 +139,9 @@ pub fn sample_config() -> DecisionGateConfig {
                 ],
             }),
             tools: ServerToolsConfig::default(),
+            sse: ServerSseConfig::default(),
+            openapi: ServerOpenApiConfig::default(),
+            provider_warm_up: ProviderWarmUpMode::default(),
             ..ServerConfig::default()
         },
         namespace: NamespaceConfig {
@@ -206,12 +217,69 @@ pub fn router_with_authorizer_usage_and_runpack_storage(
     tenant_authorizer: Arc<dyn TenantAuthorizer>,
     usage_meter: Arc<dyn UsageMeter>,
     runpack_storage: Option<Arc<dyn RunpackStorage>>,
+) -> ToolRouter {
+    router_with_components(
+        config,
+        tenant_authorizer,
+        usage_meter,
+        runpack_storage,
+        Arc::new(McpNoopAuditSink),
+        in_memory_store(),
+    )
+}
+
+/// Creates a tool router backed by the provided run state store.
+#[must_use]
+pub fn router_with_store(
+    config: &DecisionGateConfig,
+    store: decision_gate_core::SharedRunStateStore,
+) -> ToolRouter {
+    router_with_components(
+        config,
+        Arc::new(NoopTenantAuthorizer),
+        Arc::new(NoopUsageMeter),
+        None,
+        Arc::new(McpNoopAuditSink),
+        store,
+    )
+}
+
+/// Creates a tool router using custom auth, usage, and a precheck audit sink.
+#[must_use]
+pub fn router_with_precheck_audit(
+    config: &DecisionGateConfig,
+    tenant_authorizer: Arc<dyn TenantAuthorizer>,
+    usage_meter: Arc<dyn UsageMeter>,
+    precheck_audit: Arc<dyn McpAuditSink>,
+) -> ToolRouter {
+    router_with_components(
+        config,
+        tenant_authorizer,
+        usage_meter,
+        None,
+        precheck_audit,
+        in_memory_store(),
+    )
+}
+
+/// Returns a fresh in-memory run state store.
+fn in_memory_store() -> decision_gate_core::SharedRunStateStore {
+    decision_gate_core::SharedRunStateStore::from_store(
+        decision_gate_core::InMemoryRunStateStore::new(),
+    )
+}
+
+/// Creates a tool router from explicit auth, usage, storage, audit, and store components.
+fn router_with_components(
+    config: &DecisionGateConfig,
+    tenant_authorizer: Arc<dyn TenantAuthorizer>,
+    usage_meter: Arc<dyn UsageMeter>,
+    runpack_storage: Option<Arc<dyn RunpackStorage>>,
+    precheck_audit: Arc<dyn McpAuditSink>,
+    store: decision_gate_core::SharedRunStateStore,
 ) -> ToolRouter {
     let evidence = FederatedEvidenceProvider::from_config(config).unwrap();
     let capabilities = CapabilityRegistry::from_config(config).unwrap();
-    let store = decision_gate_core::SharedRunStateStore::from_store(
-        decision_gate_core::InMemoryRunStateStore::new(),
-    );
     let schema_registry = decision_gate_core::SharedDataShapeRegistry::from_registry(
         decision_gate_core::InMemoryDataShapeRegistry::new(),
     );
@@ -289,7 +357,7 @@ pub fn router_with_authorizer_usage_and_runpack_storage(
         anchor_policy,
         provider_trust_overrides,
         runpack_security_context,
-        precheck_audit: Arc::new(McpNoopAuditSink),
+        precheck_audit,
         precheck_audit_payloads,
         registry_acl,
         principal_resolver,
@@ -299,6 +367,7 @@ pub fn router_with_authorizer_usage_and_runpack_storage(
         tools: config.server.tools.clone(),
         docs_provider: None,
         tool_visibility_resolver: None,
+        tool_concurrency: config.server.limits.tool_concurrency.clone(),
         allow_default_namespace,
         default_namespace_tenants,
         namespace_authority: Arc::new(NoopNamespaceAuthority),
@@ -43,6 +43,7 @@ use decision_gate_mcp::config::ProviderAuthConfig;
 use decision_gate_mcp::config::ProviderConfig;
 use decision_gate_mcp::config::ProviderTimeoutConfig;
 use decision_gate_mcp::config::ProviderType;
+use decision_gate_mcp::config::ProviderWarmUpMode;
 use decision_gate_mcp::config::RegistryAclConfig;
 use decision_gate_mcp::config::RunStateStoreConfig;
 use decision_gate_mcp::config::RunpackStorageConfig;
@@ -54,6 +55,8 @@ use decision_gate_mcp::config::ServerConfig;
 use decision_gate_mcp::config::ServerFeedbackConfig;
 use decision_gate_mcp::config::ServerLimitsConfig;
 use decision_gate_mcp::config::ServerMode;
+use decision_gate_mcp::config::ServerOpenApiConfig;
+use decision_gate_mcp::config::ServerSseConfig;
 use decision_gate_mcp::config::ServerTlsConfig;
 use decision_gate_mcp::config::ServerTlsTermination;
 use decision_gate_mcp::config::ServerToolsConfig;
@@ -365,6 +368,9 @@ fn server_stdio_no_bind_required() {
         audit: ServerAuditConfig::default(),
         feedback: ServerFeedbackConfig::default(),
         tools: ServerToolsConfig::default(),
+        sse: ServerSseConfig::default(),
+        openapi: ServerOpenApiConfig::default(),
+        provider_warm_up: ProviderWarmUpMode::default(),
     };
     assert!(validate_server_config(config).is_ok());
 }
@@ -384,6 +390,9 @@ fn server_max_body_bytes_zero_rejected() {
         audit: ServerAuditConfig::default(),
         feedback: ServerFeedbackConfig::default(),
         tools: ServerToolsConfig::default(),
+        sse: ServerSseConfig::default(),
+        openapi: ServerOpenApiConfig::default(),
+        provider_warm_up: ProviderWarmUpMode::default(),
     };
     let result = validate_server_config(config);
     assert!(result.is_err());
@@ -406,6 +415,9 @@ fn server_http_requires_bind() {
         audit: ServerAuditConfig::default(),
         feedback: ServerFeedbackConfig::default(),
         tools: ServerToolsConfig::default(),
+        sse: ServerSseConfig::default(),
+        openapi: ServerOpenApiConfig::default(),
+        provider_warm_up: ProviderWarmUpMode::default(),
     };
     let result = validate_server_config(config);
     assert!(result.is_err());
@@ -428,6 +440,9 @@ fn server_sse_requires_bind() {
         audit: ServerAuditConfig::default(),
         feedback: ServerFeedbackConfig::default(),
         tools: ServerToolsConfig::default(),
+        sse: ServerSseConfig::default(),
+        openapi: ServerOpenApiConfig::default(),
+        provider_warm_up: ProviderWarmUpMode::default(),
     };
     let result = validate_server_config(config);
     assert!(result.is_err());
@@ -450,6 +465,9 @@ fn server_http_loopback_allowed() {
         audit: ServerAuditConfig::default(),
         feedback: ServerFeedbackConfig::default(),
         tools: ServerToolsConfig::default(),
+        sse: ServerSseConfig::default(),
+        openapi: ServerOpenApiConfig::default(),
+        provider_warm_up: ProviderWarmUpMode::default(),
     };
     assert!(validate_server_config(config).is_ok());
 }
@@ -581,6 +599,9 @@ fn server_http_ipv6_loopback_allowed() {
         audit: ServerAuditConfig::default(),
         feedback: ServerFeedbackConfig::default(),
         tools: ServerToolsConfig::default(),
+        sse: ServerSseConfig::default(),
+        openapi: ServerOpenApiConfig::default(),
+        provider_warm_up: ProviderWarmUpMode::default(),
     };
     assert!(validate_server_config(config).is_ok());
 }
@@ -600,6 +621,9 @@ fn server_http_non_loopback_rejected() {
         audit: ServerAuditConfig::default(),
         feedback: ServerFeedbackConfig::default(),
         tools: ServerToolsConfig::default(),
+        sse: ServerSseConfig::default(),
+        openapi: ServerOpenApiConfig::default(),
+        provider_warm_up: ProviderWarmUpMode::default(),
     };
     let result = validate_server_config(config);
     assert!(result.is_err());
@@ -622,6 +646,9 @@ fn server_http_external_ip_rejected() {
         audit: ServerAuditConfig::default(),
         feedback: ServerFeedbackConfig::default(),
         tools: ServerToolsConfig::default(),
+        sse: ServerSseConfig::default(),
+        openapi: ServerOpenApiConfig::default(),
+        provider_warm_up: ProviderWarmUpMode::default(),
     };
     let result = validate_server_config(config);
     assert!(result.is_err());
@@ -642,6 +669,9 @@ fn server_invalid_bind_format_rejected() {
         audit: ServerAuditConfig::default(),
         feedback: ServerFeedbackConfig::default(),
         tools: ServerToolsConfig::default(),
+        sse: ServerSseConfig::default(),
+        openapi: ServerOpenApiConfig::default(),
+        provider_warm_up: ProviderWarmUpMode::default(),
     };
     let result = validate_server_config(config);
     assert!(result.is_err());
@@ -664,6 +694,9 @@ fn server_empty_bind_rejected() {
         audit: ServerAuditConfig::default(),
         feedback: ServerFeedbackConfig::default(),
         tools: ServerToolsConfig::default(),
+        sse: ServerSseConfig::default(),
+        openapi: ServerOpenApiConfig::default(),
+        provider_warm_up: ProviderWarmUpMode::default(),
     };
     let result = validate_server_config(config);
     assert!(result.is_err());
@@ -682,6 +715,7 @@ fn server_http_non_loopback_allowed_with_bearer_auth() {
         auth: Some(ServerAuthConfig {
             mode: ServerAuthMode::BearerToken,
             bearer_tokens: vec!["token-1".to_string()],
+            jwt: None,
             mtls_subjects: Vec::new(),
             allowed_tools: Vec::new(),
             principals: Vec::new(),
@@ -690,6 +724,9 @@ fn server_http_non_loopback_allowed_with_bearer_auth() {
         audit: ServerAuditConfig::default(),
         feedback: ServerFeedbackConfig::default(),
         tools: ServerToolsConfig::default(),
+        sse: ServerSseConfig::default(),
+        openapi: ServerOpenApiConfig::default(),
+        provider_warm_up: ProviderWarmUpMode::default(),
     };
     assert!(validate_server_config(config).is_ok());
 }
@@ -707,6 +744,7 @@ fn server_stdio_rejects_bearer_auth() {
         auth: Some(ServerAuthConfig {
             mode: ServerAuthMode::BearerToken,
             bearer_tokens: vec!["token-1".to_string()],
+            jwt: None,
             mtls_subjects: Vec::new(),
             allowed_tools: Vec::new(),
             principals: Vec::new(),
@@ -715,6 +753,9 @@ fn server_stdio_rejects_bearer_auth() {
         audit: ServerAuditConfig::default(),
         feedback: ServerFeedbackConfig::default(),
         tools: ServerToolsConfig::default(),
+        sse: ServerSseConfig::default(),
+        openapi: ServerOpenApiConfig::default(),
+        provider_warm_up: ProviderWarmUpMode::default(),
     };
     let result = validate_server_config(config);
     assert!(result.is_err());
@@ -733,6 +774,7 @@ fn server_auth_bearer_requires_token() {
         auth: Some(ServerAuthConfig {
             mode: ServerAuthMode::BearerToken,
             bearer_tokens: Vec::new(),
+            jwt: None,
             mtls_subjects: Vec::new(),
             allowed_tools: Vec::new(),
             principals: Vec::new(),
@@ -741,6 +783,9 @@ fn server_auth_bearer_requires_token() {
         audit: ServerAuditConfig::default(),
         feedback: ServerFeedbackConfig::default(),
         tools: ServerToolsConfig::default(),
+        sse: ServerSseConfig::default(),
+        openapi: ServerOpenApiConfig::default(),
+        provider_warm_up: ProviderWarmUpMode::default(),
     };
     let result = validate_server_config(config);
     assert!(result.is_err());
@@ -759,6 +804,7 @@ fn server_auth_rejects_unknown_tool_in_allowlist() {
         auth: Some(ServerAuthConfig {
             mode: ServerAuthMode::BearerToken,
             bearer_tokens: vec!["token-1".to_string()],
+            jwt: None,
             mtls_subjects: Vec::new(),
             allowed_tools: vec!["invalid_tool".to_string()],
             principals: Vec::new(),
@@ -767,6 +813,9 @@ fn server_auth_rejects_unknown_tool_in_allowlist() {
         audit: ServerAuditConfig::default(),
         feedback: ServerFeedbackConfig::default(),
         tools: ServerToolsConfig::default(),
+        sse: ServerSseConfig::default(),
+        openapi: ServerOpenApiConfig::default(),
+        provider_warm_up: ProviderWarmUpMode::default(),
     };
     let result = validate_server_config(config);
     assert!(result.is_err());
@@ -785,6 +834,7 @@ fn server_auth_mtls_requires_subjects() {
         auth: Some(ServerAuthConfig {
             mode: ServerAuthMode::Mtls,
             bearer_tokens: Vec::new(),
+            jwt: None,
             mtls_subjects: Vec::new(),
             allowed_tools: Vec::new(),
             principals: Vec::new(),
@@ -793,6 +843,9 @@ fn server_auth_mtls_requires_subjects() {
         audit: ServerAuditConfig::default(),
         feedback: ServerFeedbackConfig::default(),
         tools: ServerToolsConfig::default(),
+        sse: ServerSseConfig::default(),
+        openapi: ServerOpenApiConfig::default(),
+        provider_warm_up: ProviderWarmUpMode::default(),
     };
     let result = validate_server_config(config);
     assert!(result.is_err());
@@ -810,12 +863,16 @@ fn server_limits_rejects_zero_inflight() {
         limits: ServerLimitsConfig {
             max_inflight: 0,
             rate_limit: None,
+            tool_concurrency: Vec::new(),
         },
         auth: None,
         tls: None,
         audit: ServerAuditConfig::default(),
         feedback: ServerFeedbackConfig::default(),
         tools: ServerToolsConfig::default(),
+        sse: ServerSseConfig::default(),
+        openapi: ServerOpenApiConfig::default(),
+        provider_warm_up: ProviderWarmUpMode::default(),
     };
     let result = validate_server_config(config);
     assert!(result.is_err());
@@ -837,12 +894,16 @@ fn server_rate_limit_rejects_zero_requests() {
                 window_ms: 1_000,
                 max_entries: 8,
             }),
+            tool_concurrency: Vec::new(),
         },
         auth: None,
         tls: None,
         audit: ServerAuditConfig::default(),
         feedback: ServerFeedbackConfig::default(),
         tools: ServerToolsConfig::default(),
+        sse: ServerSseConfig::default(),
+        openapi: ServerOpenApiConfig::default(),
+        provider_warm_up: ProviderWarmUpMode::default(),
     };
     let result = validate_server_config(config);
     assert!(result.is_err());
@@ -864,10 +925,17 @@ fn server_tls_rejects_empty_paths() {
             key_path: String::new(),
             client_ca_path: None,
             require_client_cert: true,
+            reload_interval_ms: None,
+            max_client_chain_depth: None,
+            client_required_ekus: Vec::new(),
+            ocsp_response_path: None,
         }),
         audit: ServerAuditConfig::default(),
         feedback: ServerFeedbackConfig::default(),
         tools: ServerToolsConfig::default(),
+        sse: ServerSseConfig::default(),
+        openapi: ServerOpenApiConfig::default(),
+        provider_warm_up: ProviderWarmUpMode::default(),
     };
     let result = validate_server_config(config);
     assert!(result.is_err());
@@ -889,10 +957,17 @@ fn server_stdio_rejects_tls() {
             key_path: "key.pem".to_string(),
             client_ca_path: None,
             require_client_cert: true,
+            reload_interval_ms: None,
+            max_client_chain_depth: None,
+            client_required_ekus: Vec::new(),
+            ocsp_response_path: None,
         }),
         audit: ServerAuditConfig::default(),
         feedback: ServerFeedbackConfig::default(),
         tools: ServerToolsConfig::default(),
+        sse: ServerSseConfig::default(),
+        openapi: ServerOpenApiConfig::default(),
+        provider_warm_up: ProviderWarmUpMode::default(),
     };
     let result = validate_server_config(config);
     assert!(result.is_err());
@@ -917,6 +992,9 @@ fn server_audit_rejects_empty_path() {
         },
         feedback: ServerFeedbackConfig::default(),
         tools: ServerToolsConfig::default(),
+        sse: ServerSseConfig::default(),
+        openapi: ServerOpenApiConfig::default(),
+        provider_warm_up: ProviderWarmUpMode::default(),
     };
     let result = validate_server_config(config);
     assert!(result.is_err());
@@ -1155,6 +1233,7 @@ fn provider_timeouts_reject_connect_out_of_range() {
         timeouts: ProviderTimeoutConfig {
             connect_timeout_ms: 50,
             request_timeout_ms: 1_000,
+            query_timeout_ms: None,
         },
         config: None,
     };
@@ -1180,6 +1259,7 @@ fn provider_timeouts_reject_request_below_connect() {
         timeouts: ProviderTimeoutConfig {
             connect_timeout_ms: 2_000,
             request_timeout_ms: 1_000,
+            query_timeout_ms: None,
         },
         config: None,
     };
@@ -1307,6 +1387,9 @@ fn dev_permissive_forces_asserted_trust_lane() {
         server: ServerConfig {
             mode: ServerMode::DevPermissive,
             tools: ServerToolsConfig::default(),
+            sse: ServerSseConfig::default(),
+            openapi: ServerOpenApiConfig::default(),
+            provider_warm_up: ProviderWarmUpMode::default(),
             ..ServerConfig::default()
         },
         namespace: NamespaceConfig::default(),
@@ -1337,6 +1420,9 @@ fn strict_mode_uses_configured_trust_lane() {
         server: ServerConfig {
             mode: ServerMode::Strict,
             tools: ServerToolsConfig::default(),
+            sse: ServerSseConfig::default(),
+            openapi: ServerOpenApiConfig::default(),
+            provider_warm_up: ProviderWarmUpMode::default(),
             ..ServerConfig::default()
         },
         namespace: NamespaceConfig::default(),
@@ -1367,6 +1453,9 @@ fn dev_permissive_does_not_override_default_namespace() {
         server: ServerConfig {
             mode: ServerMode::DevPermissive,
             tools: ServerToolsConfig::default(),
+            sse: ServerSseConfig::default(),
+            openapi: ServerOpenApiConfig::default(),
+            provider_warm_up: ProviderWarmUpMode::default(),
             ..ServerConfig::default()
         },
         namespace: NamespaceConfig {
@@ -1397,6 +1486,9 @@ fn strict_mode_requires_explicit_default_namespace_allow() {
         server: ServerConfig {
             mode: ServerMode::Strict,
             tools: ServerToolsConfig::default(),
+            sse: ServerSseConfig::default(),
+            openapi: ServerOpenApiConfig::default(),
+            provider_warm_up: ProviderWarmUpMode::default(),
             ..ServerConfig::default()
         },
         namespace: NamespaceConfig {
@@ -1533,6 +1625,10 @@ fn run_state_store_sqlite_requires_path() {
             journal_mode: decision_gate_store_sqlite::SqliteStoreMode::Wal,
             sync_mode: decision_gate_store_sqlite::SqliteSyncMode::Full,
             max_versions: None,
+            circuit_breaker: None,
+            cache: None,
+            retention: Vec::new(),
+            retention_interval_ms: 3_600_000,
         },
         schema_registry: SchemaRegistryConfig::default(),
         providers: Vec::new(),
@@ -1567,6 +1663,10 @@ fn run_state_store_memory_rejects_path() {
             journal_mode: decision_gate_store_sqlite::SqliteStoreMode::Wal,
             sync_mode: decision_gate_store_sqlite::SqliteSyncMode::Full,
             max_versions: None,
+            circuit_breaker: None,
+            cache: None,
+            retention: Vec::new(),
+            retention_interval_ms: 3_600_000,
         },
         schema_registry: SchemaRegistryConfig::default(),
         providers: Vec::new(),
@@ -1599,6 +1699,10 @@ fn run_state_store_sqlite_accepts_path() {
             journal_mode: decision_gate_store_sqlite::SqliteStoreMode::Wal,
             sync_mode: decision_gate_store_sqlite::SqliteSyncMode::Full,
             max_versions: Some(10),
+            circuit_breaker: None,
+            cache: None,
+            retention: Vec::new(),
+            retention_interval_ms: 3_600_000,
         },
         schema_registry: SchemaRegistryConfig::default(),
         providers: Vec::new(),
@@ -1631,6 +1735,10 @@ fn run_state_store_sqlite_rejects_zero_retention() {
             journal_mode: decision_gate_store_sqlite::SqliteStoreMode::Wal,
             sync_mode: decision_gate_store_sqlite::SqliteSyncMode::Full,
             max_versions: Some(0),
+            circuit_breaker: None,
+            cache: None,
+            retention: Vec::new(),
+            retention_interval_ms: 3_600_000,
         },
         schema_registry: SchemaRegistryConfig::default(),
         providers: Vec::new(),
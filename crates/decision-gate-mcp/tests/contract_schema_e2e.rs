@@ -169,6 +169,7 @@ fn mcp_tool_outputs_match_contract_schemas() -> Result<(), Box<dyn Error>> {
             requested_at: Timestamp::Logical(2),
             correlation_id: None,
         },
+        wait_for_change: None,
     };
     let status_input = serde_json::to_value(&status_request)?;
     let status_schema = tool_schema(&tool_schemas, ToolName::ScenarioStatus)?;
@@ -187,6 +188,7 @@ fn mcp_tool_outputs_match_contract_schemas() -> Result<(), Box<dyn Error>> {
             agent_id: "agent-1".to_string(),
             time: Timestamp::Logical(3),
             correlation_id: None,
+            lease_holder: None,
         },
         feedback: None,
     };
@@ -210,6 +212,8 @@ fn mcp_tool_outputs_match_contract_schemas() -> Result<(), Box<dyn Error>> {
             content_type: "application/json".to_string(),
             submitted_at: Timestamp::Logical(4),
             correlation_id: None,
+            expected_version: None,
+            lease_holder: None,
         },
     };
     let submit_input = serde_json::to_value(&submit_request)?;
@@ -232,6 +236,7 @@ fn mcp_tool_outputs_match_contract_schemas() -> Result<(), Box<dyn Error>> {
             payload: None,
             correlation_id: None,
         },
+        lease_holder: None,
     };
     let trigger_input = serde_json::to_value(&trigger_request)?;
     let trigger_schema = tool_schema(&tool_schemas, ToolName::ScenarioTrigger)?;
@@ -307,6 +312,7 @@ fn mcp_tool_outputs_match_contract_schemas() -> Result<(), Box<dyn Error>> {
         manifest_name: Some(manifest_name.clone()),
         generated_at: Timestamp::Logical(7),
         include_verification: true,
+        include_evaluation_audit: false,
     };
     let export_input = serde_json::to_value(&export_request)?;
     let export_schema = tool_schema(&tool_schemas, ToolName::RunpackExport)?;
@@ -160,6 +160,7 @@ fn router_with_policy(policy: EvidencePolicyConfig) -> ToolRouter {
         tools: config.server.tools,
         docs_provider: None,
         tool_visibility_resolver: None,
+        tool_concurrency: config.server.limits.tool_concurrency,
         allow_default_namespace,
         default_namespace_tenants,
         namespace_authority: std::sync::Arc::new(NoopNamespaceAuthority),
@@ -252,6 +253,7 @@ fn allow_raw_values_returns_value() {
     let policy = EvidencePolicyConfig {
         allow_raw_values: true,
         require_provider_opt_in: false,
+        query_timeout_ms: None,
     };
     let router = router_with_policy(policy);
     let response = query_time_now(&router);
@@ -265,6 +267,7 @@ fn allow_raw_values_includes_content_type() {
     let policy = EvidencePolicyConfig {
         allow_raw_values: true,
         require_provider_opt_in: false,
+        query_timeout_ms: None,
     };
     let router = router_with_policy(policy);
     let response = query_time_now(&router);
@@ -285,6 +288,7 @@ fn provider_opt_in_required_blocks_non_opted() {
     let policy = EvidencePolicyConfig {
         allow_raw_values: true,
         require_provider_opt_in: true,
+        query_timeout_ms: None,
     };
     let router = router_with_policy(policy);
     let response = query_time_now(&router);
@@ -302,6 +306,7 @@ fn provider_opt_in_still_returns_hash() {
     let policy = EvidencePolicyConfig {
         allow_raw_values: true,
         require_provider_opt_in: true,
+        query_timeout_ms: None,
     };
     let router = router_with_policy(policy);
     let response = query_time_now(&router);
@@ -323,6 +328,7 @@ fn allow_raw_false_takes_precedence() {
     let policy = EvidencePolicyConfig {
         allow_raw_values: false,
         require_provider_opt_in: false,
+        query_timeout_ms: None,
     };
     let router = router_with_policy(policy);
     let response = query_time_now(&router);
@@ -353,6 +359,7 @@ fn both_conditions_required_for_raw_values() {
         let policy = EvidencePolicyConfig {
             allow_raw_values: allow_raw,
             require_provider_opt_in: require_opt_in,
+            query_timeout_ms: None,
         };
         let router = router_with_policy(policy);
         let response = query_time_now(&router);
@@ -375,6 +382,7 @@ fn policy_applies_to_all_providers() {
     let policy = EvidencePolicyConfig {
         allow_raw_values: false,
         require_provider_opt_in: true,
+        query_timeout_ms: None,
     };
     let router = router_with_policy(policy);
 
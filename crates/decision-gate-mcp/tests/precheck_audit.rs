@@ -111,7 +111,6 @@ fn build_router(mut config: DecisionGateConfig, audit: Arc<TestAuditSink>) -> To
             .map(|value| usize::try_from(value).unwrap_or(usize::MAX)),
     };
     let authz = Arc::new(DefaultToolAuthz::from_config(config.server.auth.as_ref()));
-    let auth_audit = Arc::new(NoopAuditSink);
     let trust_requirement = config.effective_trust_requirement();
     let allow_default_namespace = config.allow_default_namespace();
     let default_namespace_tenants =
@@ -160,7 +159,7 @@ fn build_router(mut config: DecisionGateConfig, audit: Arc<TestAuditSink>) -> To
         usage_meter: Arc::new(NoopUsageMeter),
         runpack_storage: None,
         runpack_object_store: None,
-        audit: auth_audit,
+        audit: Arc::new(NoopAuditSink),
         trust_requirement,
         anchor_policy,
         provider_trust_overrides,
@@ -175,6 +174,7 @@ fn build_router(mut config: DecisionGateConfig, audit: Arc<TestAuditSink>) -> To
         tools: config.server.tools,
         docs_provider: None,
         tool_visibility_resolver: None,
+        tool_concurrency: config.server.limits.tool_concurrency,
         allow_default_namespace,
         default_namespace_tenants,
         namespace_authority: Arc::new(NoopNamespaceAuthority),
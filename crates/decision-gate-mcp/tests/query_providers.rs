@@ -0,0 +1,203 @@
+// crates/decision-gate-mcp/tests/query_providers.rs
+// ============================================================================
+// Module: Query Provider Config Tests
+// Description: Tests for database query providers loaded from server config.
+// Purpose: Verify builtin query providers register and answer evidence queries.
+// Dependencies: decision-gate-core, decision-gate-mcp, rusqlite
+// ============================================================================
+
+//! ## Overview
+//! Loads the builtin database query providers from `[[providers]]` config
+//! blocks and runs allowlisted named queries through the tool router.
+//! The Postgres query test runs against `DECISION_GATE_TEST_POSTGRES_DSN`
+//! when set; builds without the `postgres` feature verify the provider is
+//! rejected at startup instead.
+//!
+//! Security posture: query text comes only from config; check params select a
+//! query by name and supply bind values.
+
+#![allow(
+    clippy::panic,
+    clippy::unwrap_used,
+    clippy::expect_used,
+    clippy::unwrap_in_result,
+    reason = "Test-only setup assertions."
+)]
+
+mod common;
+
+use decision_gate_core::EvidenceError;
+use decision_gate_core::EvidenceQuery;
+use decision_gate_core::EvidenceValue;
+use decision_gate_core::ProviderId;
+use decision_gate_mcp::DecisionGateConfig;
+use decision_gate_mcp::FederatedEvidenceProvider;
+use decision_gate_mcp::config::ProviderConfig;
+use decision_gate_mcp::tools::EvidenceQueryRequest;
+use decision_gate_mcp::tools::EvidenceQueryResponse;
+use serde_json::json;
+
+use crate::common::ToolRouterSyncExt;
+use crate::common::local_request_context;
+use crate::common::router_with_config;
+use crate::common::sample_config;
+use crate::common::sample_context;
+
+// ============================================================================
+// SECTION: Helpers
+// ============================================================================
+
+/// Runs an `evidence_query` tool call against a router built from `config`.
+fn run_query(config: &DecisionGateConfig, query: EvidenceQuery) -> EvidenceQueryResponse {
+    let router = router_with_config(config);
+    let request = EvidenceQueryRequest {
+        query,
+        context: sample_context(),
+    };
+    let result = router
+        .handle_tool_call_sync(
+            &local_request_context(),
+            "evidence_query",
+            serde_json::to_value(&request).unwrap(),
+        )
+        .expect("evidence query");
+    serde_json::from_value(result).unwrap()
+}
+
+/// Parses a postgres provider block that reads its DSN from `dsn_env`.
+fn postgres_provider(dsn_env: &str, query: &str) -> ProviderConfig {
+    toml::from_str(&format!(
+        r#"
+name = "postgres"
+type = "builtin"
+allow_raw = true
+
+[config]
+dsn_env = "{dsn_env}"
+
+[config.queries]
+echo = "{query}"
+"#
+    ))
+    .expect("postgres provider config")
+}
+
+// ============================================================================
+// SECTION: SQLite Provider
+// ============================================================================
+
+/// Verifies a server configured with the sqlite builtin runs a named query.
+#[test]
+fn sqlite_provider_from_config_runs_named_query() {
+    let dir = tempfile::tempdir().expect("tempdir");
+    let db_path = dir.path().join("releases.db");
+    let conn = rusqlite::Connection::open(&db_path).expect("open db");
+    conn.execute_batch(
+        "CREATE TABLE releases (name TEXT PRIMARY KEY, status TEXT NOT NULL);
+         INSERT INTO releases VALUES ('v1.2.3', 'approved'), ('v1.2.4', 'pending');",
+    )
+    .expect("seed db");
+    drop(conn);
+
+    let path = toml::Value::String(db_path.to_string_lossy().to_string());
+    let provider: ProviderConfig = toml::from_str(&format!(
+        r#"
+name = "sqlite"
+type = "builtin"
+allow_raw = true
+
+[config]
+path = {path}
+
+[config.queries]
+release_status = "SELECT status FROM releases WHERE name = :release"
+"#
+    ))
+    .expect("sqlite provider config");
+    let mut config = sample_config();
+    config.evidence.allow_raw_values = true;
+    config.providers.push(provider);
+    config.validate().expect("config validates");
+
+    let response = run_query(
+        &config,
+        EvidenceQuery {
+            provider_id: ProviderId::new("sqlite"),
+            check_id: "query".to_string(),
+            params: Some(json!({ "query": "release_status", "params": { "release": "v1.2.3" } })),
+        },
+    );
+
+    assert_eq!(response.result.value, Some(EvidenceValue::Json(json!("approved"))));
+    let anchor = response.result.evidence_anchor.expect("anchor");
+    assert_eq!(anchor.anchor_type, "sqlite_query");
+    assert_eq!(anchor.anchor_value, "release_status");
+}
+
+// ============================================================================
+// SECTION: Postgres Provider
+// ============================================================================
+
+/// Environment variable pointing the query test at an existing database.
+#[cfg(feature = "postgres")]
+const EXTERNAL_DSN_ENV: &str = "DECISION_GATE_TEST_POSTGRES_DSN";
+
+/// Verifies builds without the postgres feature reject the provider at startup.
+#[cfg(not(feature = "postgres"))]
+#[test]
+fn postgres_provider_from_config_requires_feature() {
+    let mut config = sample_config();
+    config.providers.push(postgres_provider("DG_POSTGRES_DSN", "SELECT 1"));
+    config.validate().expect("config validates");
+
+    let Err(EvidenceError::Provider(message)) = FederatedEvidenceProvider::from_config(&config)
+    else {
+        panic!("expected postgres registration failure");
+    };
+    assert!(message.contains("postgres feature"), "unexpected error: {message}");
+}
+
+/// Verifies the postgres builtin is constructed from config and reads its DSN env var.
+#[cfg(feature = "postgres")]
+#[test]
+fn postgres_provider_from_config_reads_dsn_env() {
+    let mut config = sample_config();
+    config.providers.push(postgres_provider("DG_MCP_TEST_POSTGRES_DSN_UNSET", "SELECT 1"));
+    config.validate().expect("config validates");
+
+    let Err(EvidenceError::Provider(message)) = FederatedEvidenceProvider::from_config(&config)
+    else {
+        panic!("expected missing dsn failure");
+    };
+    assert!(
+        message.contains("DG_MCP_TEST_POSTGRES_DSN_UNSET is not set"),
+        "unexpected error: {message}"
+    );
+}
+
+/// Verifies a server configured with the postgres builtin runs a named query.
+#[cfg(feature = "postgres")]
+#[test]
+fn postgres_provider_from_config_runs_named_query() {
+    if std::env::var(EXTERNAL_DSN_ENV).is_err() {
+        return;
+    }
+    let mut config = sample_config();
+    config.evidence.allow_raw_values = true;
+    config.providers.push(postgres_provider(EXTERNAL_DSN_ENV, "SELECT $1::text AS echo"));
+    config.validate().expect("config validates");
+
+    let response = run_query(
+        &config,
+        EvidenceQuery {
+            provider_id: ProviderId::new("postgres"),
+            check_id: "query".to_string(),
+            params: Some(json!({ "query": "echo", "params": ["approved"] })),
+        },
+    );
+
+    assert_eq!(response.result.value, Some(EvidenceValue::Json(json!("approved"))));
+    let anchor = response.result.evidence_anchor.expect("anchor");
+    assert_eq!(anchor.anchor_type, "postgres_query");
+    assert_eq!(anchor.anchor_value, "echo");
+}
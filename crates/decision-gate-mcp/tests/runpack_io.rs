@@ -35,6 +35,7 @@ use decision_gate_core::ArtifactKind;
 use decision_gate_core::ArtifactReader;
 use decision_gate_core::ArtifactRecord;
 use decision_gate_core::ArtifactSink;
+use decision_gate_core::Compression;
 use decision_gate_core::ConditionSpec;
 use decision_gate_core::DecisionOutcome;
 use decision_gate_core::DecisionRecord;
@@ -140,7 +141,9 @@ fn sample_manifest() -> RunpackManifest {
             content_type: Some("application/json".to_string()),
             hash: artifact_hash,
             required: true,
+            content_encoding: Compression::None,
         }],
+        signature: None,
     }
 }
 
@@ -0,0 +1,224 @@
+// crates/decision-gate-mcp/tests/store_breaker.rs
+// ============================================================================
+// Module: Store Circuit Breaker Tests
+// Description: Tests for the run state store circuit breaker.
+// Purpose: Ensure unhealthy stores fail fast and recover after probes.
+// Dependencies: decision-gate-config, decision-gate-core, decision-gate-mcp
+// ============================================================================
+
+//! ## Overview
+//! Validates that store failures open the breaker, that an open breaker
+//! rejects calls without reaching the store, and that successful probes close
+//! it again.
+//!
+//! Security posture: store outages must shed load rather than queue requests.
+
+#![allow(
+    clippy::panic,
+    clippy::print_stdout,
+    clippy::print_stderr,
+    clippy::unwrap_used,
+    clippy::expect_used,
+    clippy::use_debug,
+    clippy::dbg_macro,
+    clippy::panic_in_result_fn,
+    clippy::unwrap_in_result,
+    reason = "Test-only output and panic-based assertions are permitted."
+)]
+
+use std::sync::Arc;
+use std::sync::Mutex;
+use std::sync::atomic::AtomicUsize;
+use std::sync::atomic::Ordering;
+use std::time::Duration;
+
+use decision_gate_core::NamespaceId;
+use decision_gate_core::RunId;
+use decision_gate_core::RunState;
+use decision_gate_core::RunStateStore;
+use decision_gate_core::SharedRunStateStore;
+use decision_gate_core::StoreError;
+use decision_gate_core::TenantId;
+use decision_gate_mcp::config::StoreCircuitBreakerConfig;
+use decision_gate_mcp::store_breaker::CircuitBreakerRunStateStore;
+use decision_gate_mcp::store_breaker::CircuitState;
+
+// ============================================================================
+// SECTION: Fixtures
+// ============================================================================
+
+/// Store behavior for the next calls.
+#[derive(Clone, Copy)]
+enum Mode {
+    Healthy,
+    Down,
+    Corrupt,
+    Slow,
+}
+
+/// Store whose health is switched by the test.
+struct ScriptedStore {
+    mode: Mutex<Mode>,
+    calls: AtomicUsize,
+}
+
+impl ScriptedStore {
+    fn new(mode: Mode) -> Arc<Self> {
+        Arc::new(Self {
+            mode: Mutex::new(mode),
+            calls: AtomicUsize::new(0),
+        })
+    }
+
+    fn set(&self, mode: Mode) {
+        *self.mode.lock().unwrap() = mode;
+    }
+
+    fn calls(&self) -> usize {
+        self.calls.load(Ordering::SeqCst)
+    }
+
+    fn respond(&self) -> Result<(), StoreError> {
+        self.calls.fetch_add(1, Ordering::SeqCst);
+        let mode = *self.mode.lock().unwrap();
+        match mode {
+            Mode::Healthy => Ok(()),
+            Mode::Down => Err(StoreError::Io("connection refused".to_string())),
+            Mode::Corrupt => Err(StoreError::Corrupt("hash mismatch".to_string())),
+            Mode::Slow => {
+                std::thread::sleep(Duration::from_millis(20));
+                Ok(())
+            }
+        }
+    }
+}
+
+impl RunStateStore for ScriptedStore {
+    fn load(
+        &self,
+        _tenant_id: &TenantId,
+        _namespace_id: &NamespaceId,
+        _run_id: &RunId,
+    ) -> Result<Option<RunState>, StoreError> {
+        self.respond().map(|()| None)
+    }
+
+    fn save(&self, _state: &RunState) -> Result<(), StoreError> {
+        self.respond()
+    }
+
+    fn readiness(&self) -> Result<(), StoreError> {
+        self.respond()
+    }
+}
+
+fn breaker(
+    store: &Arc<ScriptedStore>,
+    config: &StoreCircuitBreakerConfig,
+) -> CircuitBreakerRunStateStore {
+    let inner: Arc<dyn RunStateStore + Send + Sync> = store.clone();
+    CircuitBreakerRunStateStore::new(SharedRunStateStore::new(inner), config)
+}
+
+const fn config(open_ms: u64) -> StoreCircuitBreakerConfig {
+    StoreCircuitBreakerConfig {
+        failure_threshold: 2,
+        open_ms,
+        success_threshold: 2,
+        slow_call_ms: None,
+    }
+}
+
+fn load(store: &CircuitBreakerRunStateStore) -> Result<Option<RunState>, StoreError> {
+    store.load(
+        &TenantId::from_raw(1).unwrap(),
+        &NamespaceId::from_raw(1).unwrap(),
+        &RunId::new("run-1"),
+    )
+}
+
+// ============================================================================
+// SECTION: Tests
+// ============================================================================
+
+/// Tests that consecutive store failures open the breaker.
+#[test]
+fn store_failures_open_breaker() {
+    let store = ScriptedStore::new(Mode::Down);
+    let breaker = breaker(&store, &config(60_000));
+    assert!(matches!(load(&breaker), Err(StoreError::Io(_))));
+    assert_eq!(breaker.state(), CircuitState::Closed);
+    assert!(matches!(load(&breaker), Err(StoreError::Io(_))));
+    assert_eq!(breaker.state(), CircuitState::Open);
+}
+
+/// Tests that an open breaker rejects calls and readiness without touching the store.
+#[test]
+fn open_breaker_rejects_fast() {
+    let store = ScriptedStore::new(Mode::Down);
+    let breaker = breaker(&store, &config(60_000));
+    let _ = load(&breaker);
+    let _ = load(&breaker);
+    let calls = store.calls();
+    store.set(Mode::Healthy);
+    let error = load(&breaker).unwrap_err();
+    assert!(matches!(error, StoreError::Unavailable(_)));
+    assert!(error.to_string().contains("circuit breaker open"), "{error}");
+    assert!(matches!(breaker.readiness(), Err(StoreError::Unavailable(_))));
+    assert_eq!(store.calls(), calls);
+}
+
+/// Tests that the breaker closes after enough successful probes.
+#[test]
+fn breaker_recovers_after_successful_probes() {
+    let store = ScriptedStore::new(Mode::Down);
+    let breaker = breaker(&store, &config(10));
+    let _ = load(&breaker);
+    let _ = load(&breaker);
+    store.set(Mode::Healthy);
+    std::thread::sleep(Duration::from_millis(20));
+    assert_eq!(breaker.state(), CircuitState::HalfOpen);
+    breaker.readiness().expect("first probe");
+    assert_eq!(breaker.state(), CircuitState::HalfOpen);
+    load(&breaker).expect("second probe");
+    assert_eq!(breaker.state(), CircuitState::Closed);
+}
+
+/// Tests that a failed probe reopens the breaker.
+#[test]
+fn failed_probe_reopens_breaker() {
+    let store = ScriptedStore::new(Mode::Down);
+    let breaker = breaker(&store, &config(10));
+    let _ = load(&breaker);
+    let _ = load(&breaker);
+    std::thread::sleep(Duration::from_millis(20));
+    assert!(matches!(load(&breaker), Err(StoreError::Io(_))));
+    assert_eq!(breaker.state(), CircuitState::Open);
+}
+
+/// Tests that data errors do not count as store failures.
+#[test]
+fn data_errors_keep_breaker_closed() {
+    let store = ScriptedStore::new(Mode::Corrupt);
+    let breaker = breaker(&store, &config(60_000));
+    for _ in 0 .. 3 {
+        assert!(matches!(load(&breaker), Err(StoreError::Corrupt(_))));
+    }
+    assert_eq!(breaker.state(), CircuitState::Closed);
+}
+
+/// Tests that calls slower than `slow_call_ms` count as failures.
+#[test]
+fn slow_calls_open_breaker() {
+    let store = ScriptedStore::new(Mode::Slow);
+    let breaker = breaker(
+        &store,
+        &StoreCircuitBreakerConfig {
+            slow_call_ms: Some(1),
+            ..config(60_000)
+        },
+    );
+    load(&breaker).expect("slow call still returns");
+    load(&breaker).expect("slow call still returns");
+    assert_eq!(breaker.state(), CircuitState::Open);
+}
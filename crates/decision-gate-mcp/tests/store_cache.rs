@@ -0,0 +1,386 @@
+// crates/decision-gate-mcp/tests/store_cache.rs
+// ============================================================================
+// Module: Run State Cache Tests
+// Description: Tests for the bounded run state cache.
+// Purpose: Ensure cached reads, write policies, and flushes stay consistent.
+// Dependencies: decision-gate-config, decision-gate-core, decision-gate-mcp,
+// decision-gate-store-sqlite
+// ============================================================================
+
+//! ## Overview
+//! Validates read-through caching, write-through consistency with the backing
+//! store, bounded eviction, and that write-back saves reach the backing store
+//! on flush and on shutdown.
+//!
+//! Security posture: acknowledged saves must not be lost on orderly shutdown.
+
+#![allow(
+    clippy::panic,
+    clippy::print_stdout,
+    clippy::print_stderr,
+    clippy::unwrap_used,
+    clippy::expect_used,
+    clippy::use_debug,
+    clippy::dbg_macro,
+    clippy::panic_in_result_fn,
+    clippy::unwrap_in_result,
+    reason = "Test-only output and panic-based assertions are permitted."
+)]
+
+use std::collections::BTreeMap;
+use std::sync::Arc;
+use std::sync::Mutex;
+use std::sync::atomic::AtomicBool;
+use std::sync::atomic::AtomicUsize;
+use std::sync::atomic::Ordering;
+use std::time::Duration;
+
+use decision_gate_core::NamespaceId;
+use decision_gate_core::PacketPayload;
+use decision_gate_core::RunId;
+use decision_gate_core::RunState;
+use decision_gate_core::RunStateStore;
+use decision_gate_core::RunStatus;
+use decision_gate_core::ScenarioId;
+use decision_gate_core::SharedRunStateStore;
+use decision_gate_core::StageId;
+use decision_gate_core::StoreError;
+use decision_gate_core::SubmissionRecord;
+use decision_gate_core::TenantId;
+use decision_gate_core::Timestamp;
+use decision_gate_core::hashing::DEFAULT_HASH_ALGORITHM;
+use decision_gate_core::hashing::HashDigest;
+use decision_gate_mcp::config::RunStateCacheConfig;
+use decision_gate_mcp::config::RunStateCacheMode;
+use decision_gate_mcp::store_cache::CachedRunStateStore;
+use decision_gate_mcp::store_cache::MAX_PENDING_VERSIONS;
+use decision_gate_store_sqlite::SqliteRunStateStore;
+use decision_gate_store_sqlite::SqliteStoreConfig;
+use decision_gate_store_sqlite::SqliteStoreMode;
+use decision_gate_store_sqlite::SqliteSyncMode;
+
+// ============================================================================
+// SECTION: Fixtures
+// ============================================================================
+
+/// In-memory backing store that counts calls and records saved stages.
+#[derive(Default)]
+struct CountingStore {
+    runs: Mutex<BTreeMap<String, RunState>>,
+    history: Mutex<Vec<String>>,
+    loads: AtomicUsize,
+    saves: AtomicUsize,
+    failing: AtomicBool,
+}
+
+impl CountingStore {
+    fn stored(&self, run_id: &str) -> Option<RunState> {
+        self.runs.lock().unwrap().get(run_id).cloned()
+    }
+
+    fn history(&self) -> Vec<String> {
+        self.history.lock().unwrap().clone()
+    }
+}
+
+impl RunStateStore for CountingStore {
+    fn load(
+        &self,
+        _tenant_id: &TenantId,
+        _namespace_id: &NamespaceId,
+        run_id: &RunId,
+    ) -> Result<Option<RunState>, StoreError> {
+        self.loads.fetch_add(1, Ordering::SeqCst);
+        Ok(self.runs.lock().unwrap().get(run_id.as_str()).cloned())
+    }
+
+    fn save(&self, state: &RunState) -> Result<(), StoreError> {
+        if self.failing.load(Ordering::SeqCst) {
+            return Err(StoreError::Io("disk full".to_string()));
+        }
+        self.saves.fetch_add(1, Ordering::SeqCst);
+        self.history.lock().unwrap().push(format!("{}:{}", state.run_id, stage(state)));
+        self.runs.lock().unwrap().insert(state.run_id.as_str().to_string(), state.clone());
+        Ok(())
+    }
+}
+
+fn cached(
+    backing: &Arc<CountingStore>,
+    mode: RunStateCacheMode,
+    max_entries: usize,
+) -> Arc<CachedRunStateStore> {
+    let inner: Arc<dyn RunStateStore + Send + Sync> = backing.clone();
+    let config = RunStateCacheConfig {
+        mode,
+        max_entries,
+        ..RunStateCacheConfig::default()
+    };
+    Arc::new(CachedRunStateStore::new(SharedRunStateStore::new(inner), &config))
+}
+
+fn run_state(run_id: &str, stage: &str) -> RunState {
+    RunState {
+        tenant_id: TenantId::from_raw(1).unwrap(),
+        namespace_id: NamespaceId::from_raw(1).unwrap(),
+        run_id: RunId::new(run_id),
+        scenario_id: ScenarioId::new("scenario"),
+        current_stage_id: StageId::new(stage),
+        stage_entered_at: Timestamp::Logical(0),
+        status: RunStatus::Active,
+        dispatch_targets: Vec::new(),
+        triggers: Vec::new(),
+        gate_evals: Vec::new(),
+        decisions: Vec::new(),
+        packets: Vec::new(),
+        submissions: Vec::new(),
+        tool_calls: Vec::new(),
+        spec_hash: HashDigest::new(DEFAULT_HASH_ALGORITHM, b"spec"),
+    }
+}
+
+fn load(store: &CachedRunStateStore, run_id: &str) -> Option<RunState> {
+    store
+        .load(
+            &TenantId::from_raw(1).unwrap(),
+            &NamespaceId::from_raw(1).unwrap(),
+            &RunId::new(run_id),
+        )
+        .expect("load")
+}
+
+fn stage(state: &RunState) -> &str {
+    state.current_stage_id.as_str()
+}
+
+/// Returns `state` with one more submission, advancing its run version.
+fn advanced(mut state: RunState, submission_id: &str) -> RunState {
+    state.submissions.push(SubmissionRecord {
+        submission_id: submission_id.to_string(),
+        run_id: state.run_id.clone(),
+        payload: PacketPayload::Json {
+            value: serde_json::json!({}),
+        },
+        content_type: "application/json".to_string(),
+        content_hash: HashDigest::new(DEFAULT_HASH_ALGORITHM, submission_id.as_bytes()),
+        submitted_at: Timestamp::Logical(1),
+        correlation_id: None,
+    });
+    state
+}
+
+// ============================================================================
+// SECTION: Tests
+// ============================================================================
+
+/// Tests that repeated loads are served from the cache after the first read.
+#[test]
+fn loads_read_through_once() {
+    let backing = Arc::new(CountingStore::default());
+    backing.save(&run_state("run-1", "stage-a")).unwrap();
+    let cache = cached(&backing, RunStateCacheMode::WriteThrough, 8);
+    assert_eq!(stage(&load(&cache, "run-1").unwrap()), "stage-a");
+    assert_eq!(stage(&load(&cache, "run-1").unwrap()), "stage-a");
+    assert_eq!(backing.loads.load(Ordering::SeqCst), 1);
+    assert!(load(&cache, "missing").is_none());
+    assert!(load(&cache, "missing").is_none());
+    assert_eq!(backing.loads.load(Ordering::SeqCst), 3);
+}
+
+/// Tests that write-through saves reach the store and replace cached state.
+#[test]
+fn write_through_keeps_store_and_cache_consistent() {
+    let backing = Arc::new(CountingStore::default());
+    let cache = cached(&backing, RunStateCacheMode::WriteThrough, 8);
+    cache.save(&run_state("run-1", "stage-a")).unwrap();
+    assert_eq!(stage(&load(&cache, "run-1").unwrap()), "stage-a");
+    cache.save(&run_state("run-1", "stage-b")).unwrap();
+    assert_eq!(stage(&backing.stored("run-1").unwrap()), "stage-b");
+    assert_eq!(stage(&load(&cache, "run-1").unwrap()), "stage-b");
+    assert_eq!(backing.loads.load(Ordering::SeqCst), 0);
+    assert_eq!(cache.pending_writes(), 0);
+}
+
+/// Tests that a failed write-through save leaves the cached state untouched.
+#[test]
+fn write_through_failure_does_not_update_cache() {
+    let backing = Arc::new(CountingStore::default());
+    let cache = cached(&backing, RunStateCacheMode::WriteThrough, 8);
+    cache.save(&run_state("run-1", "stage-a")).unwrap();
+    backing.failing.store(true, Ordering::SeqCst);
+    assert!(matches!(cache.save(&run_state("run-1", "stage-b")), Err(StoreError::Io(_))));
+    assert_eq!(stage(&load(&cache, "run-1").unwrap()), "stage-a");
+}
+
+/// Tests that a write-through version conflict drops the stale cached entry.
+#[test]
+fn write_through_version_conflict_evicts_stale_entry() {
+    let backing = Arc::new(CountingStore::default());
+    let cache = cached(&backing, RunStateCacheMode::WriteThrough, 8);
+    let base = run_state("run-1", "stage-a");
+    cache.save(&base).unwrap();
+    backing.save(&advanced(base.clone(), "other-writer")).unwrap();
+
+    let stale = advanced(base.clone(), "this-writer");
+    let err = cache.save_if_version(&stale, base.version()).unwrap_err();
+    assert!(matches!(err, StoreError::VersionConflict { .. }), "unexpected error: {err:?}");
+    let reloaded = load(&cache, "run-1").unwrap();
+    assert_eq!(reloaded.submissions[0].submission_id, "other-writer");
+}
+
+/// Tests that write-back versioned saves are checked against the cached entry.
+#[test]
+fn write_back_version_conflict_checks_cached_entry() {
+    let backing = Arc::new(CountingStore::default());
+    let cache = cached(&backing, RunStateCacheMode::WriteBack, 8);
+    let base = run_state("run-1", "stage-a");
+    cache.save(&base).unwrap();
+    cache.save_if_version(&advanced(base.clone(), "first"), base.version()).unwrap();
+    let err = cache.save_if_version(&advanced(base.clone(), "second"), base.version()).unwrap_err();
+    assert!(matches!(err, StoreError::VersionConflict { .. }), "unexpected error: {err:?}");
+    assert_eq!(load(&cache, "run-1").unwrap().submissions[0].submission_id, "first");
+}
+
+/// Tests that the cache stays bounded and persists dirty entries it evicts.
+#[test]
+fn eviction_is_bounded_and_persists_dirty_entries() {
+    let backing = Arc::new(CountingStore::default());
+    let cache = cached(&backing, RunStateCacheMode::WriteBack, 2);
+    cache.save(&run_state("run-1", "stage-a")).unwrap();
+    cache.save(&run_state("run-2", "stage-a")).unwrap();
+    assert!(load(&cache, "run-1").is_some());
+    cache.save(&run_state("run-3", "stage-a")).unwrap();
+    assert_eq!(cache.len(), 2);
+    assert!(backing.stored("run-1").is_none());
+    assert!(backing.stored("run-2").is_some(), "least recently used entry is flushed on eviction");
+}
+
+/// Tests that write-back saves are deferred until flushed and every version
+/// reaches the backing store in save order.
+#[test]
+fn write_back_defers_saves_until_flush() {
+    let backing = Arc::new(CountingStore::default());
+    let cache = cached(&backing, RunStateCacheMode::WriteBack, 8);
+    cache.save(&run_state("run-1", "stage-a")).unwrap();
+    cache.save(&run_state("run-1", "stage-b")).unwrap();
+    assert!(backing.stored("run-1").is_none());
+    assert_eq!(cache.pending_writes(), 2);
+    assert_eq!(cache.flush().unwrap(), 2);
+    assert_eq!(stage(&backing.stored("run-1").unwrap()), "stage-b");
+    assert_eq!(backing.history(), vec!["run-1:stage-a", "run-1:stage-b"]);
+    assert_eq!(cache.flush().unwrap(), 0);
+}
+
+/// Tests that eviction writes every pending version of the evicted run.
+#[test]
+fn eviction_persists_every_pending_version() {
+    let backing = Arc::new(CountingStore::default());
+    let cache = cached(&backing, RunStateCacheMode::WriteBack, 1);
+    cache.save(&run_state("run-1", "stage-a")).unwrap();
+    cache.save(&run_state("run-1", "stage-b")).unwrap();
+    cache.save(&run_state("run-2", "stage-a")).unwrap();
+    assert_eq!(backing.history(), vec!["run-1:stage-a", "run-1:stage-b"]);
+    assert_eq!(cache.pending_writes(), 1);
+}
+
+/// Tests that write-back keeps the `SQLite` version history and hash chain
+/// identical to write-through.
+#[test]
+fn write_back_preserves_sqlite_version_chain() {
+    let dir = tempfile::tempdir().unwrap();
+    let sqlite = Arc::new(
+        SqliteRunStateStore::new(SqliteStoreConfig {
+            path: dir.path().join("store.db"),
+            busy_timeout_ms: 1_000,
+            journal_mode: SqliteStoreMode::Wal,
+            sync_mode: SqliteSyncMode::Full,
+            max_versions: None,
+            schema_registry_max_schema_bytes: None,
+            schema_registry_max_entries: None,
+            retention: Vec::new(),
+        })
+        .unwrap(),
+    );
+    let inner: Arc<dyn RunStateStore + Send + Sync> = sqlite.clone();
+    let config = RunStateCacheConfig {
+        mode: RunStateCacheMode::WriteBack,
+        ..RunStateCacheConfig::default()
+    };
+    let cache = CachedRunStateStore::new(SharedRunStateStore::new(inner), &config);
+    let first = run_state("run-1", "stage-a");
+    let second = advanced(first.clone(), "sub-1");
+    let third = advanced(second.clone(), "sub-2");
+    for state in [&first, &second, &third] {
+        cache.save(state).unwrap();
+    }
+    assert_eq!(cache.flush().unwrap(), 3);
+
+    let mut versions =
+        sqlite.list_run_versions(first.tenant_id, first.namespace_id, &first.run_id).unwrap();
+    versions.reverse();
+    assert_eq!(versions.iter().map(|version| version.version).collect::<Vec<_>>(), vec![1, 2, 3]);
+    assert_eq!(versions[0].prev_state_hash, None);
+    for pair in versions.windows(2) {
+        assert_eq!(pair[1].prev_state_hash.as_deref(), Some(pair[0].state_hash.as_str()));
+    }
+}
+
+/// Tests that a run's queue is persisted once it reaches the pending cap.
+#[test]
+fn write_back_persists_queue_at_pending_cap() {
+    let backing = Arc::new(CountingStore::default());
+    let cache = cached(&backing, RunStateCacheMode::WriteBack, 8);
+    for index in 0 ..= MAX_PENDING_VERSIONS {
+        cache.save(&run_state("run-1", &format!("stage-{index}"))).unwrap();
+    }
+    assert_eq!(backing.history().len(), MAX_PENDING_VERSIONS);
+    assert_eq!(cache.pending_writes(), 1);
+}
+
+/// Tests that failed flushes keep writes pending for a later retry.
+#[test]
+fn failed_flush_keeps_writes_pending() {
+    let backing = Arc::new(CountingStore::default());
+    let cache = cached(&backing, RunStateCacheMode::WriteBack, 8);
+    cache.save(&run_state("run-1", "stage-a")).unwrap();
+    backing.failing.store(true, Ordering::SeqCst);
+    assert!(cache.flush().is_err());
+    assert_eq!(cache.pending_writes(), 1);
+    backing.failing.store(false, Ordering::SeqCst);
+    assert_eq!(cache.flush().unwrap(), 1);
+    assert!(backing.stored("run-1").is_some());
+}
+
+/// Tests that the scheduled flusher persists write-back saves.
+#[test]
+fn scheduled_flusher_persists_pending_writes() {
+    let backing = Arc::new(CountingStore::default());
+    let cache = cached(&backing, RunStateCacheMode::WriteBack, 8);
+    let runtime = tokio::runtime::Builder::new_current_thread().enable_all().build().unwrap();
+    runtime.block_on(async {
+        let flusher = cache.spawn_flusher(Duration::from_millis(10));
+        cache.save(&run_state("run-1", "stage-a")).unwrap();
+        for _ in 0 .. 100 {
+            if backing.stored("run-1").is_some() {
+                break;
+            }
+            tokio::time::sleep(Duration::from_millis(10)).await;
+        }
+        flusher.abort();
+    });
+    assert!(backing.stored("run-1").is_some());
+    assert_eq!(cache.pending_writes(), 0);
+}
+
+/// Tests that dropping the cache at shutdown persists pending writes.
+#[test]
+fn shutdown_flushes_pending_writes() {
+    let backing = Arc::new(CountingStore::default());
+    let cache = cached(&backing, RunStateCacheMode::WriteBack, 8);
+    cache.save(&run_state("run-1", "stage-a")).unwrap();
+    cache.save(&run_state("run-2", "stage-b")).unwrap();
+    assert!(backing.stored("run-1").is_none());
+    drop(cache);
+    assert_eq!(stage(&backing.stored("run-1").unwrap()), "stage-a");
+    assert_eq!(stage(&backing.stored("run-2").unwrap()), "stage-b");
+}
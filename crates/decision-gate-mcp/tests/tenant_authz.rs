@@ -30,10 +30,12 @@ use common::local_request_context;
 use common::router_with_authorizer;
 use common::sample_config;
 use common::sample_spec;
+use decision_gate_core::TenantId;
 use decision_gate_mcp::TenantAccessRequest;
 use decision_gate_mcp::TenantAuthorizer;
 use decision_gate_mcp::TenantAuthzAction;
 use decision_gate_mcp::TenantAuthzDecision;
+use decision_gate_mcp::config::PrincipalScopesConfig;
 use decision_gate_mcp::tools::ScenarioDefineRequest;
 
 struct DenyTenantAuthorizer;
@@ -105,3 +107,68 @@ fn tenant_authz_receives_tool_action() {
         )
         .expect("tool call should succeed");
 }
+
+struct AllowTenantAuthorizer;
+
+#[async_trait]
+impl TenantAuthorizer for AllowTenantAuthorizer {
+    async fn authorize(
+        &self,
+        _auth: &decision_gate_mcp::AuthContext,
+        _request: TenantAccessRequest<'_>,
+    ) -> TenantAuthzDecision {
+        TenantAuthzDecision {
+            allowed: true,
+            reason: "allow".to_string(),
+        }
+    }
+}
+
+fn scope_stdio_tenants(tenant_ids: &[TenantId]) -> decision_gate_mcp::DecisionGateConfig {
+    let mut config = sample_config();
+    let auth = config.server.auth.as_mut().expect("sample auth config");
+    for principal in &mut auth.principals {
+        if principal.subject == "stdio" {
+            principal.scopes = PrincipalScopesConfig {
+                tenant_ids: tenant_ids.to_vec(),
+                ..PrincipalScopesConfig::default()
+            };
+        }
+    }
+    config
+}
+
+#[test]
+fn principal_tenant_scope_denies_cross_tenant_call() {
+    let other_tenant = TenantId::from_raw(200).expect("nonzero tenantid");
+    let config = scope_stdio_tenants(&[other_tenant]);
+    let router = router_with_authorizer(&config, Arc::new(AllowTenantAuthorizer));
+    let request = ScenarioDefineRequest {
+        spec: sample_spec(),
+    };
+    let error = router
+        .handle_tool_call_sync(
+            &local_request_context(),
+            "scenario_define",
+            serde_json::to_value(&request).unwrap(),
+        )
+        .unwrap_err();
+    assert!(error.to_string().contains("tenant not permitted by principal scopes"));
+}
+
+#[test]
+fn principal_tenant_scope_allows_listed_tenant() {
+    let tenant = sample_spec().default_tenant_id.expect("sample tenant");
+    let config = scope_stdio_tenants(&[tenant]);
+    let router = router_with_authorizer(&config, Arc::new(AllowTenantAuthorizer));
+    let request = ScenarioDefineRequest {
+        spec: sample_spec(),
+    };
+    router
+        .handle_tool_call_sync(
+            &local_request_context(),
+            "scenario_define",
+            serde_json::to_value(&request).unwrap(),
+        )
+        .expect("scoped tenant should be permitted");
+}
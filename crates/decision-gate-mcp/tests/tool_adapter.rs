@@ -70,6 +70,7 @@ use decision_gate_mcp::config::EvidencePolicyConfig;
 use decision_gate_mcp::config::PolicyConfig;
 use decision_gate_mcp::config::PrincipalConfig;
 use decision_gate_mcp::config::PrincipalRoleConfig;
+use decision_gate_mcp::config::PrincipalScopesConfig;
 use decision_gate_mcp::config::ProviderConfig;
 use decision_gate_mcp::config::ProviderTimeoutConfig;
 use decision_gate_mcp::config::ProviderType;
@@ -254,6 +255,7 @@ fn build_router(config: &DecisionGateConfig) -> ToolRouter {
         tools: config.server.tools.clone(),
         docs_provider: None,
         tool_visibility_resolver: None,
+        tool_concurrency: config.server.limits.tool_concurrency.clone(),
         allow_default_namespace: config.allow_default_namespace(),
         default_namespace_tenants,
         namespace_authority: Arc::new(NoopNamespaceAuthority),
@@ -272,11 +274,13 @@ fn mcp_tools_match_core_control_plane() {
             auth: Some(ServerAuthConfig {
                 mode: ServerAuthMode::LocalOnly,
                 bearer_tokens: Vec::new(),
+                jwt: None,
                 mtls_subjects: Vec::new(),
                 allowed_tools: Vec::new(),
                 principals: vec![PrincipalConfig {
                     subject: "stdio".to_string(),
                     policy_class: Some("prod".to_string()),
+                    scopes: PrincipalScopesConfig::default(),
                     roles: vec![PrincipalRoleConfig {
                         name: "TenantAdmin".to_string(),
                         tenant_id: Some(TenantId::from_raw(1).expect("nonzero tenantid")),
@@ -348,6 +352,7 @@ fn mcp_tools_match_core_control_plane() {
         agent_id: "agent-1".to_string(),
         time: Timestamp::Logical(2),
         correlation_id: None,
+        lease_holder: None,
     };
     let tool_request = decision_gate_mcp::tools::ScenarioNextRequest {
         scenario_id: ScenarioId::new("scenario"),
@@ -388,11 +393,13 @@ fn default_config() -> DecisionGateConfig {
             auth: Some(ServerAuthConfig {
                 mode: ServerAuthMode::LocalOnly,
                 bearer_tokens: Vec::new(),
+                jwt: None,
                 mtls_subjects: Vec::new(),
                 allowed_tools: Vec::new(),
                 principals: vec![PrincipalConfig {
                     subject: "stdio".to_string(),
                     policy_class: Some("prod".to_string()),
+                    scopes: PrincipalScopesConfig::default(),
                     roles: vec![PrincipalRoleConfig {
                         name: "TenantAdmin".to_string(),
                         tenant_id: Some(TenantId::from_raw(1).expect("nonzero tenantid")),
@@ -476,6 +483,7 @@ fn parity_scenario_status() {
             requested_at: Timestamp::Logical(2),
             correlation_id: None,
         },
+        wait_for_change: None,
     };
     let mcp_result = router
         .handle_tool_call_sync(
@@ -871,6 +879,7 @@ fn parity_scenario_status_not_found() {
             requested_at: Timestamp::Logical(1),
             correlation_id: None,
         },
+        wait_for_change: None,
     };
     let result = router.handle_tool_call_sync(
         &context,
@@ -0,0 +1,183 @@
+// crates/decision-gate-mcp/tests/tool_concurrency.rs
+// ============================================================================
+// Module: Tool Concurrency Tests
+// Description: Tests for per-tool concurrency limits.
+// Purpose: Ensure saturated tools queue or reject while other tools proceed.
+// Dependencies: decision-gate-config, decision-gate-mcp
+// ============================================================================
+
+//! ## Overview
+//! Validates per-tool concurrency limits: immediate rejection when queueing is
+//! disabled, bounded queueing when enabled, queue metrics, and isolation from
+//! tools without a limit.
+//!
+//! Security posture: limits bound resource use by untrusted callers.
+
+#![allow(
+    clippy::panic,
+    clippy::print_stdout,
+    clippy::print_stderr,
+    clippy::unwrap_used,
+    clippy::expect_used,
+    clippy::use_debug,
+    clippy::dbg_macro,
+    clippy::panic_in_result_fn,
+    clippy::unwrap_in_result,
+    reason = "Test-only output and panic-based assertions are permitted."
+)]
+
+mod common;
+
+use std::sync::Arc;
+use std::sync::Mutex;
+use std::time::Duration;
+
+use common::ToolRouterSyncExt;
+use common::local_request_context;
+use common::router_with_config;
+use common::sample_config;
+use decision_gate_contract::ToolName;
+use decision_gate_mcp::McpMetricEvent;
+use decision_gate_mcp::McpMetrics;
+use decision_gate_mcp::ToolQueueMetricEvent;
+use decision_gate_mcp::ToolQueueOutcome;
+use decision_gate_mcp::config::ToolConcurrencyConfig;
+use decision_gate_mcp::tool_concurrency::ToolConcurrencyError;
+use decision_gate_mcp::tool_concurrency::ToolConcurrencyLimits;
+use decision_gate_mcp::tools::ToolError;
+use serde_json::json;
+
+// ============================================================================
+// SECTION: Fixtures
+// ============================================================================
+
+#[derive(Default)]
+struct QueueMetrics {
+    events: Mutex<Vec<ToolQueueMetricEvent>>,
+}
+
+impl QueueMetrics {
+    fn outcomes(&self) -> Vec<(ToolName, ToolQueueOutcome)> {
+        self.events.lock().unwrap().iter().map(|event| (event.tool, event.outcome)).collect()
+    }
+}
+
+impl McpMetrics for QueueMetrics {
+    fn record_request(&self, _event: McpMetricEvent) {}
+
+    fn record_latency(&self, _event: McpMetricEvent, _latency: Duration) {}
+
+    fn record_tool_queue(&self, event: ToolQueueMetricEvent, _waited: Duration) {
+        self.events.lock().unwrap().push(event);
+    }
+}
+
+fn limits(tool: ToolName, max_concurrent: usize, max_queue_wait_ms: u64) -> ToolConcurrencyLimits {
+    ToolConcurrencyLimits::from_config(&[ToolConcurrencyConfig {
+        tool: tool.as_str().to_string(),
+        max_concurrent,
+        max_queue_wait_ms,
+    }])
+}
+
+fn runtime() -> tokio::runtime::Runtime {
+    tokio::runtime::Builder::new_current_thread().enable_all().build().unwrap()
+}
+
+// ============================================================================
+// SECTION: Limiter
+// ============================================================================
+
+/// Tests that a saturated tool is rejected immediately when queueing is disabled.
+#[test]
+fn saturated_tool_rejects_without_queue() {
+    let limits = limits(ToolName::ScenarioSubmit, 1, 0);
+    let metrics = QueueMetrics::default();
+    runtime().block_on(async {
+        let held = limits.acquire(ToolName::ScenarioSubmit, &metrics).await.unwrap();
+        assert!(held.is_some());
+        let error = limits.acquire(ToolName::ScenarioSubmit, &metrics).await.unwrap_err();
+        drop(held);
+        assert_eq!(
+            error,
+            ToolConcurrencyError::LimitReached {
+                tool: ToolName::ScenarioSubmit,
+                max_concurrent: 1,
+            }
+        );
+        assert!(matches!(ToolError::from(error), ToolError::ConcurrencyLimited(_)));
+    });
+    assert_eq!(metrics.outcomes(), vec![(ToolName::ScenarioSubmit, ToolQueueOutcome::Rejected)]);
+}
+
+/// Tests that tools without a limit proceed while a limited tool is saturated.
+#[test]
+fn other_tools_proceed_while_tool_saturated() {
+    let limits = limits(ToolName::ScenarioSubmit, 1, 0);
+    let metrics = QueueMetrics::default();
+    runtime().block_on(async {
+        let _held = limits.acquire(ToolName::ScenarioSubmit, &metrics).await.unwrap();
+        for _ in 0 .. 3 {
+            let permit = limits.acquire(ToolName::ScenarioStatus, &metrics).await;
+            assert!(permit.unwrap().is_none(), "unlimited tools take no permit");
+        }
+    });
+    assert!(metrics.outcomes().is_empty());
+    assert_eq!(limits.limit(ToolName::ScenarioSubmit), Some(1));
+    assert_eq!(limits.limit(ToolName::ScenarioStatus), None);
+}
+
+/// Tests that a queued call is admitted once a slot frees up.
+#[test]
+fn queued_call_is_admitted_when_slot_frees() {
+    let limits = limits(ToolName::ScenarioSubmit, 1, 5_000);
+    let metrics = Arc::new(QueueMetrics::default());
+    runtime().block_on(async {
+        let held = limits.acquire(ToolName::ScenarioSubmit, metrics.as_ref()).await.unwrap();
+        let waiter = {
+            let limits = limits.clone();
+            let metrics = Arc::clone(&metrics);
+            tokio::spawn(async move {
+                limits.acquire(ToolName::ScenarioSubmit, metrics.as_ref()).await.map(|_| ())
+            })
+        };
+        tokio::time::sleep(Duration::from_millis(20)).await;
+        drop(held);
+        waiter.await.unwrap().unwrap();
+    });
+    assert_eq!(metrics.outcomes(), vec![(ToolName::ScenarioSubmit, ToolQueueOutcome::Admitted)]);
+}
+
+/// Tests that a queued call fails once the queue wait elapses.
+#[test]
+fn queued_call_times_out() {
+    let limits = limits(ToolName::ScenarioSubmit, 1, 20);
+    let metrics = QueueMetrics::default();
+    runtime().block_on(async {
+        let _held = limits.acquire(ToolName::ScenarioSubmit, &metrics).await.unwrap();
+        let error = limits.acquire(ToolName::ScenarioSubmit, &metrics).await.unwrap_err();
+        assert!(error.to_string().contains("scenario_submit concurrency limit reached"));
+    });
+    assert_eq!(metrics.outcomes(), vec![(ToolName::ScenarioSubmit, ToolQueueOutcome::TimedOut)]);
+}
+
+// ============================================================================
+// SECTION: Router
+// ============================================================================
+
+/// Tests that the router releases a limited tool's slot after each call.
+#[test]
+fn router_releases_slot_after_call() {
+    let mut config = sample_config();
+    config.server.limits.tool_concurrency = vec![ToolConcurrencyConfig {
+        tool: ToolName::ProvidersList.as_str().to_string(),
+        max_concurrent: 1,
+        max_queue_wait_ms: 0,
+    }];
+    let router = router_with_config(&config);
+    for _ in 0 .. 3 {
+        router
+            .handle_tool_call_sync(&local_request_context(), "providers_list", json!({}))
+            .expect("providers_list within limit");
+    }
+}
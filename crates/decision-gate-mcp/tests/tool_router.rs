@@ -28,6 +28,8 @@
 mod common;
 
 use std::sync::Mutex;
+use std::time::Duration;
+use std::time::Instant;
 
 use async_trait::async_trait;
 use decision_gate_core::Comparator;
@@ -48,7 +50,11 @@ use decision_gate_core::TriggerEvent;
 use decision_gate_core::TriggerId;
 use decision_gate_core::TriggerKind;
 use decision_gate_core::TrustLane;
+use decision_gate_core::runtime::LeaseAcquireRequest;
+use decision_gate_core::runtime::LeaseReleaseRequest;
+use decision_gate_core::runtime::LeaseReleaseResult;
 use decision_gate_core::runtime::NextRequest;
+use decision_gate_core::runtime::RunLease;
 use decision_gate_core::runtime::ScenarioStatus;
 use decision_gate_core::runtime::StatusRequest;
 use decision_gate_core::runtime::SubmitRequest;
@@ -60,9 +66,12 @@ use decision_gate_mcp::RunpackStorage;
 use decision_gate_mcp::RunpackStorageError;
 use decision_gate_mcp::RunpackStorageKey;
 use decision_gate_mcp::SchemaRegistryConfig;
+use decision_gate_mcp::ToolRouter;
+use decision_gate_mcp::auth::tool_is_read_only;
 use decision_gate_mcp::config::FeedbackLevel;
 use decision_gate_mcp::config::PrincipalConfig;
 use decision_gate_mcp::config::PrincipalRoleConfig;
+use decision_gate_mcp::config::PrincipalScopesConfig;
 use decision_gate_mcp::config::ServerAuthConfig;
 use decision_gate_mcp::config::ServerAuthMode;
 use decision_gate_mcp::config::ServerMode;
@@ -83,20 +92,27 @@ use decision_gate_mcp::tools::RunpackExportRequest;
 use decision_gate_mcp::tools::RunpackExportResponse;
 use decision_gate_mcp::tools::ScenarioDefineRequest;
 use decision_gate_mcp::tools::ScenarioDefineResponse;
+use decision_gate_mcp::tools::ScenarioLeaseAcquireRequest;
+use decision_gate_mcp::tools::ScenarioLeaseReleaseRequest;
 use decision_gate_mcp::tools::ScenarioNextRequest;
 use decision_gate_mcp::tools::ScenarioNextResponse;
+use decision_gate_mcp::tools::ScenarioRunRef;
 use decision_gate_mcp::tools::ScenarioStartRequest;
 use decision_gate_mcp::tools::ScenarioStatusRequest;
 use decision_gate_mcp::tools::ScenarioSubmitRequest;
 use decision_gate_mcp::tools::ScenarioTriggerRequest;
 use decision_gate_mcp::tools::ScenariosListRequest;
 use decision_gate_mcp::tools::ScenariosListResponse;
+use decision_gate_mcp::tools::ScenariosStatusBulkRequest;
+use decision_gate_mcp::tools::ScenariosStatusBulkResponse;
 use decision_gate_mcp::tools::SchemasGetRequest;
 use decision_gate_mcp::tools::SchemasGetResponse;
 use decision_gate_mcp::tools::SchemasListRequest;
 use decision_gate_mcp::tools::SchemasListResponse;
 use decision_gate_mcp::tools::SchemasRegisterRequest;
 use decision_gate_mcp::tools::SchemasRegisterResponse;
+use decision_gate_mcp::tools::StatusWaitOptions;
+use decision_gate_mcp::tools::ToolError;
 use ret_logic::TriState;
 use serde_json::json;
 
@@ -105,6 +121,7 @@ use crate::common::define_scenario;
 use crate::common::local_request_context;
 use crate::common::router_with_authorizer_usage_and_runpack_storage;
 use crate::common::router_with_config;
+use crate::common::router_with_store;
 use crate::common::sample_config;
 use crate::common::sample_context;
 use crate::common::sample_router;
@@ -152,9 +169,9 @@ fn sample_shape_record(schema_id: &str, version: &str) -> DataShapeRecord {
     }
 }
 
-/// Verifies all expected tools are listed.
+/// Verifies exactly the expected tools are listed.
 #[test]
-fn list_tools_returns_all_eighteen_tools() {
+fn list_tools_returns_all_twenty_one_tools() {
     let router = sample_router();
     let tools = router.list_tools_sync(&local_request_context()).unwrap();
 
@@ -177,7 +194,70 @@ fn list_tools_returns_all_eighteen_tools() {
     assert!(names.contains(&"scenarios_list"));
     assert!(names.contains(&"precheck"));
     assert!(names.contains(&"decision_gate_docs_search"));
-    assert_eq!(tools.len(), 18);
+    assert!(names.contains(&"scenarios_status_bulk"));
+    assert!(names.contains(&"scenario_lease_acquire"));
+    assert!(names.contains(&"scenario_lease_release"));
+    assert_eq!(tools.len(), 21);
+}
+
+fn read_only_stdio_config() -> decision_gate_mcp::DecisionGateConfig {
+    let mut config = sample_config();
+    let auth = config.server.auth.as_mut().expect("sample auth config");
+    for principal in &mut auth.principals {
+        if principal.subject == "stdio" {
+            principal.scopes = PrincipalScopesConfig {
+                read_only: true,
+                ..PrincipalScopesConfig::default()
+            };
+        }
+    }
+    config
+}
+
+/// Verifies read-only callers only see read tools.
+#[test]
+fn list_tools_hides_write_tools_from_read_only_caller() {
+    let router = router_with_config(&read_only_stdio_config());
+    let tools = router.list_tools_sync(&local_request_context()).unwrap();
+
+    assert!(!tools.is_empty());
+    assert!(tools.iter().all(|tool| tool_is_read_only(tool.name)));
+    let names: Vec<&str> = tools.iter().map(|t| t.name.as_str()).collect();
+    assert!(names.contains(&"scenario_status"));
+    assert!(!names.contains(&"scenario_define"));
+    assert!(!names.contains(&"schemas_register"));
+}
+
+/// Verifies hidden write tools are still denied when called directly.
+#[test]
+fn hidden_write_tool_call_is_denied() {
+    let router = router_with_config(&read_only_stdio_config());
+    let request = ScenarioDefineRequest {
+        spec: sample_spec(),
+    };
+    let error = router
+        .handle_tool_call_sync(
+            &local_request_context(),
+            "scenario_define",
+            serde_json::to_value(&request).unwrap(),
+        )
+        .unwrap_err();
+    assert!(error.to_string().contains("read-only scope denies mutating tool"));
+}
+
+/// Verifies the server tool allowlist also narrows listings.
+#[test]
+fn list_tools_respects_server_tool_allowlist() {
+    let mut config = sample_config();
+    config.server.auth.as_mut().expect("sample auth config").allowed_tools =
+        vec!["scenario_status".to_string(), "precheck".to_string()];
+    let router = router_with_config(&config);
+    let tools = router.list_tools_sync(&local_request_context()).unwrap();
+
+    let names: Vec<&str> = tools.iter().map(|t| t.name.as_str()).collect();
+    assert_eq!(names.len(), 2);
+    assert!(names.contains(&"scenario_status"));
+    assert!(names.contains(&"precheck"));
 }
 
 // ============================================================================
@@ -351,6 +431,7 @@ fn namespace_authority_denies_tool_call() {
         tools: config.server.tools,
         docs_provider: None,
         tool_visibility_resolver: None,
+        tool_concurrency: config.server.limits.tool_concurrency,
         allow_default_namespace,
         default_namespace_tenants,
         namespace_authority: std::sync::Arc::new(DenyNamespaceAuthority),
@@ -498,6 +579,7 @@ fn scenario_status_returns_status() {
             requested_at: Timestamp::Logical(2),
             correlation_id: None,
         },
+        wait_for_change: None,
     };
     let result = router
         .handle_tool_call_sync(
@@ -525,6 +607,7 @@ fn scenario_status_undefined_scenario_fails() {
             requested_at: Timestamp::Logical(1),
             correlation_id: None,
         },
+        wait_for_change: None,
     };
     let result = router.handle_tool_call_sync(
         &local_request_context(),
@@ -534,6 +617,245 @@ fn scenario_status_undefined_scenario_fails() {
     assert!(result.is_err());
 }
 
+fn status_wait_payload(
+    scenario_id: &ScenarioId,
+    run_id: &RunId,
+    max_wait_ms: u64,
+) -> serde_json::Value {
+    let request = ScenarioStatusRequest {
+        scenario_id: scenario_id.clone(),
+        request: StatusRequest {
+            run_id: run_id.clone(),
+            tenant_id: TenantId::from_raw(1).expect("nonzero tenantid"),
+            namespace_id: NamespaceId::from_raw(1).expect("nonzero namespaceid"),
+            requested_at: Timestamp::Logical(2),
+            correlation_id: None,
+        },
+        wait_for_change: Some(StatusWaitOptions {
+            max_wait_ms,
+        }),
+    };
+    serde_json::to_value(&request).unwrap()
+}
+
+/// Verifies a status change wakes a waiting `scenario_status` long-poll.
+#[test]
+fn scenario_status_wait_wakes_on_status_change() {
+    let (router, scenario_id, run_id) = setup_scenario_with_run();
+    let runtime = tokio::runtime::Runtime::new().expect("runtime");
+    let (status, elapsed) = runtime.block_on(async {
+        let waiter_router = router.clone();
+        let payload = status_wait_payload(&scenario_id, &run_id, 10_000);
+        let started = Instant::now();
+        let waiter = tokio::spawn(async move {
+            waiter_router
+                .handle_tool_call(&local_request_context(), "scenario_status", payload)
+                .await
+        });
+        tokio::time::sleep(Duration::from_millis(250)).await;
+        let next = ScenarioNextRequest {
+            scenario_id: scenario_id.clone(),
+            request: NextRequest {
+                run_id: run_id.clone(),
+                tenant_id: TenantId::from_raw(1).expect("nonzero tenantid"),
+                namespace_id: NamespaceId::from_raw(1).expect("nonzero namespaceid"),
+                trigger_id: TriggerId::new("trigger-wake"),
+                agent_id: "test-agent".to_string(),
+                time: Timestamp::Logical(3),
+                correlation_id: None,
+                lease_holder: None,
+            },
+            feedback: None,
+        };
+        router
+            .handle_tool_call(
+                &local_request_context(),
+                "scenario_next",
+                serde_json::to_value(&next).unwrap(),
+            )
+            .await
+            .unwrap();
+        let value = waiter.await.expect("waiter join").unwrap();
+        (serde_json::from_value::<ScenarioStatus>(value).unwrap(), started.elapsed())
+    });
+    drop(runtime);
+    let decision = status.last_decision.expect("decision after wake");
+    assert_eq!(decision.trigger_id, TriggerId::new("trigger-wake"));
+    assert!(elapsed < Duration::from_secs(5), "waiter should wake before max wait: {elapsed:?}");
+}
+
+/// Verifies a `scenario_status` long-poll returns the unchanged status on timeout.
+#[test]
+fn scenario_status_wait_timeout_returns_unchanged_status() {
+    let (router, scenario_id, run_id) = setup_scenario_with_run();
+    let started = Instant::now();
+    let result = router
+        .handle_tool_call_sync(
+            &local_request_context(),
+            "scenario_status",
+            status_wait_payload(&scenario_id, &run_id, 200),
+        )
+        .unwrap();
+    let elapsed = started.elapsed();
+    let status: ScenarioStatus = serde_json::from_value(result).unwrap();
+    assert!(elapsed >= Duration::from_millis(200), "long-poll returned early: {elapsed:?}");
+    assert_eq!(status.current_stage_id.as_str(), "stage-1");
+    assert!(status.last_decision.is_none());
+}
+
+/// Verifies long-poll rechecks (one per second) do not record tool calls.
+#[test]
+fn scenario_status_wait_records_only_final_response() {
+    let store = decision_gate_core::SharedRunStateStore::from_store(
+        decision_gate_core::InMemoryRunStateStore::new(),
+    );
+    let router = router_with_store(&sample_config(), store.clone());
+    let scenario_id = define_scenario(&router, sample_spec()).unwrap();
+    let run_config = sample_run_config_with_ids(1, "test-run", scenario_id.as_str());
+    start_run(&router, &scenario_id, run_config, Timestamp::Logical(1)).unwrap();
+    let run_id = RunId::new("test-run");
+    let tenant_id = TenantId::from_raw(1).expect("nonzero tenantid");
+    let namespace_id = NamespaceId::from_raw(1).expect("nonzero namespaceid");
+    let tool_calls = || {
+        decision_gate_core::RunStateStore::load(&store, &tenant_id, &namespace_id, &run_id)
+            .unwrap()
+            .expect("run state")
+            .tool_calls
+            .len()
+    };
+    let before = tool_calls();
+
+    router
+        .handle_tool_call_sync(
+            &local_request_context(),
+            "scenario_status",
+            status_wait_payload(&scenario_id, &run_id, 2_500),
+        )
+        .unwrap();
+
+    assert_eq!(tool_calls(), before + 1);
+}
+
+/// Verifies `scenario_status` rejects waits outside the server bounds.
+#[test]
+fn scenario_status_wait_rejects_out_of_range_max_wait() {
+    let (router, scenario_id, run_id) = setup_scenario_with_run();
+    for max_wait_ms in [0, 30_001] {
+        let error = router
+            .handle_tool_call_sync(
+                &local_request_context(),
+                "scenario_status",
+                status_wait_payload(&scenario_id, &run_id, max_wait_ms),
+            )
+            .unwrap_err();
+        assert!(error.to_string().contains("max_wait_ms"), "unexpected error: {error}");
+    }
+}
+
+// ============================================================================
+// SECTION: scenarios_status_bulk Tests
+// ============================================================================
+
+fn status_bulk_payload(runs: &[(&str, &str)]) -> serde_json::Value {
+    let request = ScenariosStatusBulkRequest {
+        tenant_id: TenantId::from_raw(1).expect("nonzero tenantid"),
+        namespace_id: NamespaceId::from_raw(1).expect("nonzero namespaceid"),
+        runs: runs
+            .iter()
+            .map(|(scenario_id, run_id)| ScenarioRunRef {
+                scenario_id: ScenarioId::new(*scenario_id),
+                run_id: RunId::new(*run_id),
+            })
+            .collect(),
+    };
+    serde_json::to_value(&request).unwrap()
+}
+
+/// Verifies `scenarios_status_bulk` reports found and missing runs per entry.
+#[test]
+fn scenarios_status_bulk_reports_mixed_results() {
+    let (router, scenario_id, run_id) = setup_scenario_with_run();
+    let payload = status_bulk_payload(&[
+        (scenario_id.as_str(), run_id.as_str()),
+        (scenario_id.as_str(), "missing-run"),
+        ("undefined-scenario", run_id.as_str()),
+    ]);
+    let result = router
+        .handle_tool_call_sync(&local_request_context(), "scenarios_status_bulk", payload)
+        .unwrap();
+    let response: ScenariosStatusBulkResponse = serde_json::from_value(result).unwrap();
+
+    let summary: Vec<(&str, &str, bool)> = response
+        .results
+        .iter()
+        .map(|result| (result.scenario_id.as_str(), result.run_id.as_str(), result.found))
+        .collect();
+    assert_eq!(
+        summary,
+        vec![
+            (scenario_id.as_str(), run_id.as_str(), true),
+            (scenario_id.as_str(), "missing-run", false),
+            ("undefined-scenario", run_id.as_str(), false),
+        ]
+    );
+    let status = response.results[0].status.as_ref().expect("found run status");
+    assert_eq!(status.run_id, run_id);
+    assert_eq!(status.current_stage_id.as_str(), "stage-1");
+    assert!(response.results[1].status.is_none());
+    assert!(response.results[2].status.is_none());
+}
+
+/// Verifies `scenarios_status_bulk` does not return runs owned by another tenant.
+#[test]
+fn scenarios_status_bulk_scopes_runs_to_request_tenant() {
+    let (router, scenario_id, _) = setup_scenario_with_run();
+    let other_tenant_run = sample_run_config_with_ids(2, "other-tenant-run", scenario_id.as_str());
+    start_run(&router, &scenario_id, other_tenant_run, Timestamp::Logical(1)).unwrap();
+
+    let payload = status_bulk_payload(&[(scenario_id.as_str(), "other-tenant-run")]);
+    let result = router
+        .handle_tool_call_sync(&local_request_context(), "scenarios_status_bulk", payload)
+        .unwrap();
+    let response: ScenariosStatusBulkResponse = serde_json::from_value(result).unwrap();
+
+    assert_eq!(response.results.len(), 1);
+    assert!(!response.results[0].found);
+    assert!(response.results[0].status.is_none());
+}
+
+/// Verifies `scenarios_status_bulk` hides runs that belong to another scenario.
+#[test]
+fn scenarios_status_bulk_rejects_runs_from_other_scenarios() {
+    let (router, _, run_id) = setup_scenario_with_run();
+    let other_id = define_scenario(&router, sample_spec_with_id("other-scenario")).unwrap();
+
+    let payload = status_bulk_payload(&[(other_id.as_str(), run_id.as_str())]);
+    let result = router
+        .handle_tool_call_sync(&local_request_context(), "scenarios_status_bulk", payload)
+        .unwrap();
+    let response: ScenariosStatusBulkResponse = serde_json::from_value(result).unwrap();
+
+    assert!(!response.results[0].found);
+    assert!(response.results[0].status.is_none());
+}
+
+/// Verifies `scenarios_status_bulk` rejects empty and oversized run lists.
+#[test]
+fn scenarios_status_bulk_rejects_out_of_range_run_count() {
+    let (router, scenario_id, run_id) = setup_scenario_with_run();
+    let oversized = vec![(scenario_id.as_str(), run_id.as_str()); 101];
+    for runs in [Vec::new(), oversized] {
+        let error = router
+            .handle_tool_call_sync(
+                &local_request_context(),
+                "scenarios_status_bulk",
+                status_bulk_payload(&runs),
+            )
+            .unwrap_err();
+        assert!(error.to_string().contains("params: /runs:"), "unexpected error: {error}");
+    }
+}
+
 // ============================================================================
 // SECTION: scenario_next Tests
 // ============================================================================
@@ -553,6 +875,7 @@ fn scenario_next_advances_evaluation() {
             agent_id: "test-agent".to_string(),
             time: Timestamp::Logical(2),
             correlation_id: None,
+            lease_holder: None,
         },
         feedback: None,
     };
@@ -584,6 +907,7 @@ fn scenario_next_trace_feedback_returns_gate_evaluations() {
             agent_id: "test-agent".to_string(),
             time: Timestamp::Logical(2),
             correlation_id: None,
+            lease_holder: None,
         },
         feedback: Some(FeedbackLevel::Trace),
     };
@@ -616,6 +940,7 @@ fn scenario_next_evidence_feedback_denied_defaults_to_trace() {
             agent_id: "test-agent".to_string(),
             time: Timestamp::Logical(2),
             correlation_id: None,
+            lease_holder: None,
         },
         feedback: Some(FeedbackLevel::Evidence),
     };
@@ -648,6 +973,7 @@ fn scenario_next_undefined_scenario_fails() {
             agent_id: "test-agent".to_string(),
             time: Timestamp::Logical(1),
             correlation_id: None,
+            lease_holder: None,
         },
         feedback: None,
     };
@@ -681,6 +1007,8 @@ fn scenario_submit_accepts_submissions() {
             content_type: "application/json".to_string(),
             submitted_at: Timestamp::Logical(2),
             correlation_id: None,
+            expected_version: None,
+            lease_holder: None,
         },
     };
     let result = router
@@ -693,6 +1021,44 @@ fn scenario_submit_accepts_submissions() {
     let _submit_result: SubmitResult = serde_json::from_value(result).unwrap();
 }
 
+/// Verifies a stale `expected_version` is rejected as a conflict.
+#[test]
+fn scenario_submit_stale_expected_version_conflicts() {
+    let (router, scenario_id, run_id) = setup_scenario_with_run();
+
+    let submit = |submission_id: &str, expected_version: u64| {
+        let request = ScenarioSubmitRequest {
+            scenario_id: scenario_id.clone(),
+            request: SubmitRequest {
+                run_id: run_id.clone(),
+                tenant_id: TenantId::from_raw(1).expect("nonzero tenantid"),
+                namespace_id: NamespaceId::from_raw(1).expect("nonzero namespaceid"),
+                submission_id: submission_id.to_string(),
+                payload: PacketPayload::Json {
+                    value: json!({"artifact": submission_id}),
+                },
+                content_type: "application/json".to_string(),
+                submitted_at: Timestamp::Logical(2),
+                correlation_id: None,
+                expected_version: Some(expected_version),
+                lease_holder: None,
+            },
+        };
+        router.handle_tool_call_sync(
+            &local_request_context(),
+            "scenario_submit",
+            serde_json::to_value(&request).unwrap(),
+        )
+    };
+    submit("submission-1", 0).unwrap();
+    let err = submit("submission-2", 0).unwrap_err();
+    assert!(
+        matches!(err, ToolError::Conflict(ref message) if message.contains("expected 0, current 1")),
+        "{err:?}"
+    );
+    submit("submission-2", 1).unwrap();
+}
+
 /// Verifies submit for undefined scenario fails.
 #[test]
 fn scenario_submit_undefined_scenario_fails() {
@@ -711,6 +1077,8 @@ fn scenario_submit_undefined_scenario_fails() {
             content_type: "application/json".to_string(),
             submitted_at: Timestamp::Logical(1),
             correlation_id: None,
+            expected_version: None,
+            lease_holder: None,
         },
     };
     let result = router.handle_tool_call_sync(
@@ -721,6 +1089,102 @@ fn scenario_submit_undefined_scenario_fails() {
     assert!(result.is_err());
 }
 
+// ============================================================================
+// SECTION: Run Lease Tests
+// ============================================================================
+
+fn lease_call(
+    router: &ToolRouter,
+    scenario_id: &ScenarioId,
+    run_id: &RunId,
+    holder_id: &str,
+    release: bool,
+) -> Result<serde_json::Value, ToolError> {
+    let tenant_id = TenantId::from_raw(1).expect("nonzero tenantid");
+    let namespace_id = NamespaceId::from_raw(1).expect("nonzero namespaceid");
+    let (tool, payload) = if release {
+        let request = ScenarioLeaseReleaseRequest {
+            scenario_id: scenario_id.clone(),
+            request: LeaseReleaseRequest {
+                run_id: run_id.clone(),
+                tenant_id,
+                namespace_id,
+                holder_id: holder_id.to_string(),
+                requested_at: Timestamp::Logical(2),
+            },
+        };
+        ("scenario_lease_release", serde_json::to_value(&request).unwrap())
+    } else {
+        let request = ScenarioLeaseAcquireRequest {
+            scenario_id: scenario_id.clone(),
+            request: LeaseAcquireRequest {
+                run_id: run_id.clone(),
+                tenant_id,
+                namespace_id,
+                holder_id: holder_id.to_string(),
+                ttl_ms: 1_000,
+                requested_at: Timestamp::Logical(2),
+            },
+        };
+        ("scenario_lease_acquire", serde_json::to_value(&request).unwrap())
+    };
+    router.handle_tool_call_sync(&local_request_context(), tool, payload)
+}
+
+/// Verifies a run lease blocks other holders and non-holder submits until released.
+#[test]
+fn scenario_lease_serializes_submits() {
+    let (router, scenario_id, run_id) = setup_scenario_with_run();
+
+    let lease: RunLease = serde_json::from_value(
+        lease_call(&router, &scenario_id, &run_id, "worker-a", false).unwrap(),
+    )
+    .unwrap();
+    assert_eq!(lease.holder_id, "worker-a");
+    assert_eq!(lease.expires_at, Timestamp::Logical(1_002));
+
+    let err = lease_call(&router, &scenario_id, &run_id, "worker-b", false).unwrap_err();
+    assert!(matches!(err, ToolError::Conflict(_)), "{err:?}");
+
+    let submit = |submission_id: &str, lease_holder: Option<&str>| {
+        let request = ScenarioSubmitRequest {
+            scenario_id: scenario_id.clone(),
+            request: SubmitRequest {
+                run_id: run_id.clone(),
+                tenant_id: TenantId::from_raw(1).expect("nonzero tenantid"),
+                namespace_id: NamespaceId::from_raw(1).expect("nonzero namespaceid"),
+                submission_id: submission_id.to_string(),
+                payload: PacketPayload::Json {
+                    value: json!({"artifact": submission_id}),
+                },
+                content_type: "application/json".to_string(),
+                submitted_at: Timestamp::Logical(3),
+                correlation_id: None,
+                expected_version: None,
+                lease_holder: lease_holder.map(str::to_string),
+            },
+        };
+        router.handle_tool_call_sync(
+            &local_request_context(),
+            "scenario_submit",
+            serde_json::to_value(&request).unwrap(),
+        )
+    };
+    let err = submit("submission-1", None).unwrap_err();
+    assert!(
+        matches!(err, ToolError::Conflict(ref message) if message.contains("leased by another holder")),
+        "{err:?}"
+    );
+    submit("submission-1", Some("worker-a")).unwrap();
+
+    let released: LeaseReleaseResult = serde_json::from_value(
+        lease_call(&router, &scenario_id, &run_id, "worker-a", true).unwrap(),
+    )
+    .unwrap();
+    assert!(released.released);
+    submit("submission-2", None).unwrap();
+}
+
 // ============================================================================
 // SECTION: scenario_trigger Tests
 // ============================================================================
@@ -743,6 +1207,7 @@ fn scenario_trigger_processes_event() {
             payload: None,
             correlation_id: None,
         },
+        lease_holder: None,
     };
     let result = router
         .handle_tool_call_sync(
@@ -772,6 +1237,7 @@ fn scenario_trigger_undefined_scenario_fails() {
             payload: None,
             correlation_id: None,
         },
+        lease_holder: None,
     };
     let result = router.handle_tool_call_sync(
         &local_request_context(),
@@ -895,6 +1361,7 @@ fn runpack_export_uses_storage_backend() {
         manifest_name: Some("manifest.json".to_string()),
         generated_at: Timestamp::Logical(2),
         include_verification: false,
+        include_evaluation_audit: false,
     };
     let response_value = router
         .handle_tool_call_sync(
@@ -931,6 +1398,7 @@ fn runpack_export_missing_run_fails() {
         manifest_name: None,
         generated_at: Timestamp::Logical(1),
         include_verification: false,
+        include_evaluation_audit: false,
     };
     let result = router.handle_tool_call_sync(
         &local_request_context(),
@@ -954,6 +1422,7 @@ fn runpack_export_undefined_scenario_fails() {
         manifest_name: None,
         generated_at: Timestamp::Logical(1),
         include_verification: false,
+        include_evaluation_audit: false,
     };
     let result = router.handle_tool_call_sync(
         &local_request_context(),
@@ -981,6 +1450,7 @@ fn runpack_export_requires_output_dir_without_storage() {
         manifest_name: None,
         generated_at: Timestamp::Logical(2),
         include_verification: false,
+        include_evaluation_audit: false,
     };
     let result = router.handle_tool_call_sync(
         &local_request_context(),
@@ -1068,6 +1538,7 @@ fn scenario_next_idempotent_same_trigger() {
             agent_id: "test-agent".to_string(),
             time: Timestamp::Logical(2),
             correlation_id: None,
+            lease_holder: None,
         },
         feedback: None,
     };
@@ -1158,11 +1629,13 @@ fn schemas_register_allowed_for_namespace_admin() {
     config.server.auth = Some(ServerAuthConfig {
         mode: ServerAuthMode::LocalOnly,
         bearer_tokens: Vec::new(),
+        jwt: None,
         mtls_subjects: Vec::new(),
         allowed_tools: Vec::new(),
         principals: vec![PrincipalConfig {
             subject: "stdio".to_string(),
             policy_class: Some("prod".to_string()),
+            scopes: PrincipalScopesConfig::default(),
             roles: vec![PrincipalRoleConfig {
                 name: "NamespaceAdmin".to_string(),
                 tenant_id: Some(TenantId::from_raw(100).expect("nonzero tenantid")),
@@ -1352,7 +1825,7 @@ fn schemas_list_rejects_zero_limit() {
             serde_json::to_value(&list_request).unwrap(),
         )
         .unwrap_err();
-    assert!(error.to_string().contains("limit must be between"));
+    assert!(error.to_string().contains("params: /limit:"), "unexpected error: {error}");
 }
 
 #[test]
@@ -1506,6 +1979,30 @@ fn scenarios_list_includes_defined_scenario() {
     assert!(response.items.iter().any(|scenario| scenario.scenario_id == spec.scenario_id));
 }
 
+#[test]
+fn scenarios_list_cursor_resumes_after_sorted_position() {
+    let router = sample_router();
+    for id in ["scenario-a", "scenario-c", "scenario-d"] {
+        let _ = define_scenario(&router, sample_spec_with_id(id)).unwrap();
+    }
+    let request = ScenariosListRequest {
+        tenant_id: TenantId::from_raw(100).expect("nonzero tenantid"),
+        namespace_id: NamespaceId::from_raw(1).expect("nonzero namespaceid"),
+        cursor: Some("scenario-b".to_string()),
+        limit: None,
+    };
+    let response = router
+        .handle_tool_call_sync(
+            &local_request_context(),
+            "scenarios_list",
+            serde_json::to_value(&request).unwrap(),
+        )
+        .unwrap();
+    let response: ScenariosListResponse = serde_json::from_value(response).unwrap();
+    let ids: Vec<&str> = response.items.iter().map(|item| item.scenario_id.as_str()).collect();
+    assert_eq!(ids, vec!["scenario-c", "scenario-d"]);
+}
+
 // ============================================================================
 // SECTION: Precheck
 // ============================================================================
@@ -0,0 +1,116 @@
+// crates/decision-gate-mcp/tests/tool_schemas.rs
+// ============================================================================
+// Module: Tool Schema Enforcement Tests
+// Description: Tests for server-side tool contract schema validation.
+// Purpose: Ensure malformed params and responses are caught by the server.
+// Dependencies: decision-gate-contract, decision-gate-mcp
+// ============================================================================
+
+//! ## Overview
+//! Validates that the tool router enforces contract input schemas regardless
+//! of client-side validation, and that opt-in output validation flags
+//! schema-violating responses.
+//!
+//! Security posture: tool params are untrusted and must fail closed.
+
+#![allow(
+    clippy::panic,
+    clippy::print_stdout,
+    clippy::print_stderr,
+    clippy::unwrap_used,
+    clippy::expect_used,
+    clippy::use_debug,
+    clippy::dbg_macro,
+    clippy::panic_in_result_fn,
+    clippy::unwrap_in_result,
+    reason = "Test-only output and panic-based assertions are permitted."
+)]
+
+mod common;
+
+use common::ToolRouterSyncExt;
+use common::local_request_context;
+use common::router_with_config;
+use common::sample_config;
+use common::sample_router;
+use decision_gate_contract::ToolName;
+use decision_gate_mcp::tool_schemas::ToolSchemaError;
+use decision_gate_mcp::tool_schemas::ToolSchemaValidator;
+use decision_gate_mcp::tools::ToolError;
+use serde_json::json;
+
+// ============================================================================
+// SECTION: Input Validation
+// ============================================================================
+
+/// Tests that params serde would accept are still rejected by the contract schema.
+#[test]
+fn raw_params_violating_contract_are_rejected() {
+    let router = sample_router();
+    let payload = json!({ "tenant_id": 1, "namespace_id": 1, "unexpected": true });
+    let error = router
+        .handle_tool_call_sync(&local_request_context(), "scenarios_list", payload)
+        .unwrap_err();
+    let ToolError::InvalidParams(message) = error else {
+        panic!("expected invalid params, got {error:?}");
+    };
+    assert!(message.starts_with("invalid scenarios_list params: /: "), "{message}");
+    assert!(message.contains("unexpected"), "{message}");
+}
+
+/// Tests that violations name the offending field without echoing its value.
+#[test]
+fn input_violations_report_path_without_value() {
+    let router = sample_router();
+    let payload = json!({ "tenant_id": "secret-tenant", "namespace_id": 1 });
+    let error = router
+        .handle_tool_call_sync(&local_request_context(), "scenarios_list", payload)
+        .unwrap_err();
+    let message = error.to_string();
+    assert!(matches!(error, ToolError::InvalidParams(_)));
+    assert!(message.contains("/tenant_id: "), "{message}");
+    assert!(!message.contains("secret-tenant"), "{message}");
+}
+
+// ============================================================================
+// SECTION: Output Validation
+// ============================================================================
+
+/// Tests that output validation flags a schema-violating response.
+#[test]
+fn output_validation_flags_malformed_response() {
+    let validator = ToolSchemaValidator::new(true);
+    let response = json!({ "providers": [{ "provider_id": "time", "transport": "carrier" }] });
+    let error = validator.validate_output(ToolName::ProvidersList, &response).unwrap_err();
+    let ToolSchemaError::InvalidOutput {
+        tool,
+        violations,
+    } = &error
+    else {
+        panic!("expected invalid output, got {error:?}");
+    };
+    assert_eq!(*tool, ToolName::ProvidersList);
+    assert!(violations.contains("/providers/0"), "{violations}");
+    assert!(matches!(ToolError::from(error), ToolError::Internal(_)));
+}
+
+/// Tests that output validation is off unless configured.
+#[test]
+fn output_validation_is_opt_in() {
+    let response = json!({ "providers": "not-a-list" });
+    let validator = ToolSchemaValidator::new(false);
+    assert!(!validator.validates_outputs());
+    assert!(validator.validate_output(ToolName::ProvidersList, &response).is_ok());
+}
+
+/// Tests that well-formed responses pass when output validation is enabled.
+#[test]
+fn output_validation_accepts_conforming_responses() {
+    let mut config = sample_config();
+    config.validation.validate_tool_outputs = true;
+    let router = router_with_config(&config);
+    let response = router
+        .handle_tool_call_sync(&local_request_context(), "providers_list", json!({}))
+        .expect("providers_list");
+    assert!(response["providers"].is_array());
+}
@@ -83,6 +83,7 @@ const fn strict_config() -> ValidationConfig {
         allow_permissive: false,
         enable_lexicographic: false,
         enable_deep_equals: false,
+        validate_tool_outputs: false,
     }
 }
 
@@ -94,6 +95,7 @@ const fn config_with_lexicographic() -> ValidationConfig {
         allow_permissive: false,
         enable_lexicographic: true,
         enable_deep_equals: false,
+        validate_tool_outputs: false,
     }
 }
 
@@ -105,6 +107,7 @@ const fn config_with_deep_equals() -> ValidationConfig {
         allow_permissive: false,
         enable_lexicographic: false,
         enable_deep_equals: true,
+        validate_tool_outputs: false,
     }
 }
 
@@ -116,6 +119,7 @@ const fn permissive_config() -> ValidationConfig {
         allow_permissive: true,
         enable_lexicographic: false,
         enable_deep_equals: false,
+        validate_tool_outputs: false,
     }
 }
 
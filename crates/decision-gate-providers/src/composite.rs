@@ -0,0 +1,502 @@
+// crates/decision-gate-providers/src/composite.rs
+// ============================================================================
+// Module: Composite Evidence Provider
+// Description: Evidence provider that derives values from multiple sub-queries.
+// Purpose: Combine sub-provider results with a bounded, deterministic expression.
+// Dependencies: decision-gate-core, serde, serde_json
+// ============================================================================
+
+//! ## Overview
+//! The composite provider runs a set of named sub-queries against a source
+//! provider (typically a [`crate::ProviderRegistry`]) and evaluates a small
+//! expression over their JSON values. Expressions are a typed JSON tree of
+//! arithmetic, comparison, and boolean operators; evaluation has no loops or
+//! side effects and is bounded by node count and depth.
+//!
+//! Each sub-query, its evidence hash, lane, and anchor are recorded in the
+//! composite evidence anchor so runpacks retain an audit trail of every input.
+//! Any sub-query failure fails the composite closed and names the input.
+//! Security posture: evidence inputs are untrusted; see `Docs/security/threat_model.md`.
+
+// ============================================================================
+// SECTION: Imports
+// ============================================================================
+
+use std::collections::BTreeMap;
+
+use decision_gate_core::DEFAULT_HASH_ALGORITHM;
+use decision_gate_core::EvidenceAnchor;
+use decision_gate_core::EvidenceContext;
+use decision_gate_core::EvidenceError;
+use decision_gate_core::EvidenceProvider;
+use decision_gate_core::EvidenceQuery;
+use decision_gate_core::EvidenceResult;
+use decision_gate_core::EvidenceValue;
+use decision_gate_core::HashDigest;
+use decision_gate_core::ProviderMissingError;
+use decision_gate_core::ScenarioSpec;
+use decision_gate_core::TrustLane;
+use decision_gate_core::hashing::canonical_json_bytes;
+use decision_gate_core::hashing::hash_bytes;
+use decision_gate_core::hashing::hash_canonical_json;
+use serde::Deserialize;
+use serde::Serialize;
+use serde_json::Number;
+use serde_json::Value;
+
+// ============================================================================
+// SECTION: Configuration
+// ============================================================================
+
+/// Configuration for the composite provider.
+///
+/// # Invariants
+/// - `max_inputs`, `max_expression_nodes`, and `max_expression_depth` are hard upper bounds
+///   enforced before any sub-query runs.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct CompositeProviderConfig {
+    /// Maximum number of named sub-queries per composite check.
+    pub max_inputs: usize,
+    /// Maximum number of expression nodes.
+    pub max_expression_nodes: usize,
+    /// Maximum expression nesting depth.
+    pub max_expression_depth: usize,
+}
+
+impl Default for CompositeProviderConfig {
+    fn default() -> Self {
+        Self {
+            max_inputs: 8,
+            max_expression_nodes: 64,
+            max_expression_depth: 16,
+        }
+    }
+}
+
+// ============================================================================
+// SECTION: Expressions
+// ============================================================================
+
+/// Expression evaluated over named sub-query results.
+///
+/// # Invariants
+/// - Arithmetic is exact for integers (checked, no wrapping) and IEEE-754 for floats; non-finite
+///   results are rejected.
+/// - Ordering operators accept numbers only; equality accepts any JSON value.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case", deny_unknown_fields)]
+pub enum CompositeExpression {
+    /// JSON value of a named sub-query result.
+    Input(String),
+    /// Literal JSON value.
+    Literal(Value),
+    /// Numeric addition.
+    Add(Box<[Self; 2]>),
+    /// Numeric subtraction.
+    Sub(Box<[Self; 2]>),
+    /// Numeric multiplication.
+    Mul(Box<[Self; 2]>),
+    /// Numeric division; inexact integer quotients yield floats and division by
+    /// zero fails closed.
+    Div(Box<[Self; 2]>),
+    /// Numeric minimum.
+    Min(Box<[Self; 2]>),
+    /// Numeric maximum.
+    Max(Box<[Self; 2]>),
+    /// JSON equality.
+    Eq(Box<[Self; 2]>),
+    /// JSON inequality.
+    Ne(Box<[Self; 2]>),
+    /// Numeric greater-than.
+    Gt(Box<[Self; 2]>),
+    /// Numeric greater-than-or-equal.
+    Gte(Box<[Self; 2]>),
+    /// Numeric less-than.
+    Lt(Box<[Self; 2]>),
+    /// Numeric less-than-or-equal.
+    Lte(Box<[Self; 2]>),
+    /// Boolean conjunction.
+    And(Box<[Self; 2]>),
+    /// Boolean disjunction.
+    Or(Box<[Self; 2]>),
+    /// Boolean negation.
+    Not(Box<Self>),
+}
+
+impl CompositeExpression {
+    /// Returns the node count and maximum depth of the expression.
+    fn measure(&self) -> (usize, usize) {
+        match self {
+            Self::Input(_) | Self::Literal(_) => (1, 1),
+            Self::Not(inner) => {
+                let (nodes, depth) = inner.measure();
+                (nodes + 1, depth + 1)
+            }
+            Self::Add(args)
+            | Self::Sub(args)
+            | Self::Mul(args)
+            | Self::Div(args)
+            | Self::Min(args)
+            | Self::Max(args)
+            | Self::Eq(args)
+            | Self::Ne(args)
+            | Self::Gt(args)
+            | Self::Gte(args)
+            | Self::Lt(args)
+            | Self::Lte(args)
+            | Self::And(args)
+            | Self::Or(args) => {
+                let (lhs_nodes, lhs_depth) = args[0].measure();
+                let (rhs_nodes, rhs_depth) = args[1].measure();
+                (lhs_nodes + rhs_nodes + 1, lhs_depth.max(rhs_depth) + 1)
+            }
+        }
+    }
+
+    /// Evaluates the expression against resolved input values.
+    fn eval(&self, inputs: &BTreeMap<String, Value>) -> Result<Value, String> {
+        match self {
+            Self::Input(name) => {
+                inputs.get(name).cloned().ok_or_else(|| format!("unknown composite input: {name}"))
+            }
+            Self::Literal(value) => Ok(value.clone()),
+            Self::Not(inner) => Ok(Value::Bool(!as_bool(&inner.eval(inputs)?)?)),
+            Self::And(args) => Ok(Value::Bool(
+                as_bool(&args[0].eval(inputs)?)? && as_bool(&args[1].eval(inputs)?)?,
+            )),
+            Self::Or(args) => Ok(Value::Bool(
+                as_bool(&args[0].eval(inputs)?)? || as_bool(&args[1].eval(inputs)?)?,
+            )),
+            Self::Eq(args) => {
+                Ok(Value::Bool(json_equal(&args[0].eval(inputs)?, &args[1].eval(inputs)?)))
+            }
+            Self::Ne(args) => {
+                Ok(Value::Bool(!json_equal(&args[0].eval(inputs)?, &args[1].eval(inputs)?)))
+            }
+            Self::Gt(args) => compare(args, inputs, std::cmp::Ordering::is_gt),
+            Self::Gte(args) => compare(args, inputs, std::cmp::Ordering::is_ge),
+            Self::Lt(args) => compare(args, inputs, std::cmp::Ordering::is_lt),
+            Self::Lte(args) => compare(args, inputs, std::cmp::Ordering::is_le),
+            Self::Add(args) => arithmetic(args, inputs, ArithmeticOp::Add),
+            Self::Sub(args) => arithmetic(args, inputs, ArithmeticOp::Sub),
+            Self::Mul(args) => arithmetic(args, inputs, ArithmeticOp::Mul),
+            Self::Div(args) => arithmetic(args, inputs, ArithmeticOp::Div),
+            Self::Min(args) => arithmetic(args, inputs, ArithmeticOp::Min),
+            Self::Max(args) => arithmetic(args, inputs, ArithmeticOp::Max),
+        }
+    }
+}
+
+// ============================================================================
+// SECTION: Check Parameters
+// ============================================================================
+
+/// Parameters for the `compute` check.
+///
+/// # Invariants
+/// - `inputs` keys are the names referenced by [`CompositeExpression::Input`].
+#[derive(Debug, Clone, PartialEq, Deserialize)]
+#[serde(deny_unknown_fields)]
+struct CompositeParams {
+    /// Named sub-queries routed to the source provider.
+    inputs: BTreeMap<String, EvidenceQuery>,
+    /// Expression evaluated over the sub-query values.
+    expression: CompositeExpression,
+}
+
+/// Audit record for a single sub-query, embedded in the composite anchor.
+///
+/// # Invariants
+/// - `evidence_hash` is the canonical hash of the sub-query value when present.
+#[derive(Debug, Clone, Serialize)]
+struct CompositeInputRecord<'a> {
+    /// Sub-query issued to the source provider.
+    query: &'a EvidenceQuery,
+    /// Trust lane reported by the sub-provider.
+    lane: TrustLane,
+    /// Canonical hash of the sub-query value.
+    evidence_hash: Option<HashDigest>,
+    /// Anchor reported by the sub-provider.
+    evidence_anchor: Option<&'a EvidenceAnchor>,
+}
+
+// ============================================================================
+// SECTION: Provider Implementation
+// ============================================================================
+
+/// Evidence provider that composes sub-provider results.
+///
+/// # Invariants
+/// - Supports only the `compute` check id.
+/// - Sub-queries run in input-name order against the source provider.
+/// - The result lane is the weakest lane among the sub-results.
+pub struct CompositeEvidenceProvider {
+    /// Provider used to resolve sub-queries.
+    source: Box<dyn EvidenceProvider + Send + Sync>,
+    /// Provider configuration, including expression bounds.
+    config: CompositeProviderConfig,
+}
+
+impl CompositeEvidenceProvider {
+    /// Creates a composite provider resolving sub-queries through `source`.
+    #[must_use]
+    pub fn new(
+        source: impl EvidenceProvider + Send + Sync + 'static,
+        config: CompositeProviderConfig,
+    ) -> Self {
+        Self {
+            source: Box::new(source),
+            config,
+        }
+    }
+}
+
+impl EvidenceProvider for CompositeEvidenceProvider {
+    fn query(
+        &self,
+        query: &EvidenceQuery,
+        ctx: &EvidenceContext,
+    ) -> Result<EvidenceResult, EvidenceError> {
+        if query.check_id.as_str() != "compute" {
+            return Err(EvidenceError::Provider("unsupported composite check".to_string()));
+        }
+        let params = parse_params(query.params.as_ref())?;
+        self.check_bounds(&params)?;
+
+        let mut results = BTreeMap::new();
+        for (name, sub_query) in &params.inputs {
+            let result = self.source.query(sub_query, ctx).map_err(|err| {
+                EvidenceError::Provider(format!("composite input {name} failed: {err}"))
+            })?;
+            if let Some(error) = &result.error {
+                return Err(EvidenceError::Provider(format!(
+                    "composite input {name} failed: {}: {}",
+                    error.code, error.message
+                )));
+            }
+            results.insert(name.as_str(), result);
+        }
+
+        let mut values = BTreeMap::new();
+        let mut records = BTreeMap::new();
+        let mut lane = TrustLane::Verified;
+        for (name, result) in &results {
+            let value = match &result.value {
+                Some(EvidenceValue::Json(value)) => Some(value.clone()),
+                Some(EvidenceValue::Bytes(_)) | None => None,
+            };
+            if let Some(value) = value {
+                values.insert((*name).to_string(), value);
+            }
+            if result.lane == TrustLane::Asserted {
+                lane = TrustLane::Asserted;
+            }
+            records.insert(
+                *name,
+                CompositeInputRecord {
+                    query: &params.inputs[*name],
+                    lane: result.lane,
+                    evidence_hash: value_hash(result.value.as_ref())?,
+                    evidence_anchor: result.evidence_anchor.as_ref(),
+                },
+            );
+        }
+
+        let value = params.expression.eval(&values).map_err(|err| {
+            EvidenceError::Provider(format!("composite expression failed: {err}"))
+        })?;
+        let anchor_value = canonical_json_bytes(&serde_json::json!({ "inputs": records }))
+            .ok()
+            .and_then(|bytes| String::from_utf8(bytes).ok())
+            .ok_or_else(|| {
+                EvidenceError::Provider("composite anchor serialization failed".to_string())
+            })?;
+
+        Ok(EvidenceResult {
+            value: Some(EvidenceValue::Json(value)),
+            lane,
+            error: None,
+            evidence_hash: None,
+            evidence_ref: None,
+            evidence_anchor: Some(EvidenceAnchor {
+                anchor_type: "composite".to_string(),
+                anchor_value,
+            }),
+            signature: None,
+            content_type: Some("application/json".to_string()),
+        })
+    }
+
+    fn validate_providers(&self, _spec: &ScenarioSpec) -> Result<(), ProviderMissingError> {
+        Ok(())
+    }
+
+    fn warm_up(&self) -> Result<(), EvidenceError> {
+        self.source.warm_up()
+    }
+}
+
+impl CompositeEvidenceProvider {
+    /// Enforces input and expression bounds before any sub-query runs.
+    fn check_bounds(&self, params: &CompositeParams) -> Result<(), EvidenceError> {
+        if params.inputs.len() > self.config.max_inputs {
+            return Err(EvidenceError::Provider("composite inputs exceed limit".to_string()));
+        }
+        let (nodes, depth) = params.expression.measure();
+        if nodes > self.config.max_expression_nodes {
+            return Err(EvidenceError::Provider(
+                "composite expression exceeds node limit".to_string(),
+            ));
+        }
+        if depth > self.config.max_expression_depth {
+            return Err(EvidenceError::Provider(
+                "composite expression exceeds depth limit".to_string(),
+            ));
+        }
+        Ok(())
+    }
+}
+
+// ============================================================================
+// SECTION: Helpers
+// ============================================================================
+
+/// Parses composite check parameters.
+fn parse_params(params: Option<&Value>) -> Result<CompositeParams, EvidenceError> {
+    let params = params
+        .ok_or_else(|| EvidenceError::Provider("composite check requires params".to_string()))?;
+    CompositeParams::deserialize(params)
+        .map_err(|err| EvidenceError::Provider(format!("invalid composite params: {err}")))
+}
+
+/// Hashes a sub-query value for the audit record.
+fn value_hash(value: Option<&EvidenceValue>) -> Result<Option<HashDigest>, EvidenceError> {
+    match value {
+        None => Ok(None),
+        Some(EvidenceValue::Bytes(bytes)) => Ok(Some(hash_bytes(DEFAULT_HASH_ALGORITHM, bytes))),
+        Some(EvidenceValue::Json(value)) => hash_canonical_json(DEFAULT_HASH_ALGORITHM, value)
+            .map(Some)
+            .map_err(|_| EvidenceError::Provider("composite input hashing failed".to_string())),
+    }
+}
+
+/// Interprets a JSON value as a boolean operand.
+fn as_bool(value: &Value) -> Result<bool, String> {
+    value.as_bool().ok_or_else(|| "boolean operand expected".to_string())
+}
+
+/// Numeric operand normalized for deterministic arithmetic.
+#[derive(Debug, Clone, Copy)]
+enum Numeric {
+    /// Exact integer operand.
+    Int(i64),
+    /// Floating-point operand.
+    Float(f64),
+}
+
+impl Numeric {
+    /// Parses a JSON number operand.
+    fn from_json(value: &Value) -> Result<Self, String> {
+        let Value::Number(number) = value else {
+            return Err("numeric operand expected".to_string());
+        };
+        if let Some(int) = number.as_i64() {
+            return Ok(Self::Int(int));
+        }
+        number.as_f64().map(Self::Float).ok_or_else(|| "numeric operand out of range".to_string())
+    }
+
+    /// Returns the operand as a float.
+    #[allow(clippy::cast_precision_loss, reason = "Mixed arithmetic promotes integers to f64.")]
+    const fn as_f64(self) -> f64 {
+        match self {
+            Self::Int(int) => int as f64,
+            Self::Float(float) => float,
+        }
+    }
+}
+
+/// Arithmetic operators supported by composite expressions.
+#[derive(Debug, Clone, Copy)]
+enum ArithmeticOp {
+    /// Addition.
+    Add,
+    /// Subtraction.
+    Sub,
+    /// Multiplication.
+    Mul,
+    /// Division.
+    Div,
+    /// Minimum.
+    Min,
+    /// Maximum.
+    Max,
+}
+
+/// Evaluates an arithmetic operator over two numeric operands.
+fn arithmetic(
+    args: &[CompositeExpression; 2],
+    inputs: &BTreeMap<String, Value>,
+    op: ArithmeticOp,
+) -> Result<Value, String> {
+    let lhs = Numeric::from_json(&args[0].eval(inputs)?)?;
+    let rhs = Numeric::from_json(&args[1].eval(inputs)?)?;
+    if let (Numeric::Int(lhs), Numeric::Int(rhs)) = (lhs, rhs) {
+        let result = match op {
+            ArithmeticOp::Add => lhs.checked_add(rhs),
+            ArithmeticOp::Sub => lhs.checked_sub(rhs),
+            ArithmeticOp::Mul => lhs.checked_mul(rhs),
+            ArithmeticOp::Div if rhs == 0 => return Err("division by zero".to_string()),
+            ArithmeticOp::Div if lhs % rhs != 0 => None,
+            ArithmeticOp::Div => lhs.checked_div(rhs),
+            ArithmeticOp::Min => Some(lhs.min(rhs)),
+            ArithmeticOp::Max => Some(lhs.max(rhs)),
+        };
+        if let Some(result) = result {
+            return Ok(Value::Number(result.into()));
+        }
+        if !matches!(op, ArithmeticOp::Div) {
+            return Err("integer overflow".to_string());
+        }
+    }
+    let (lhs, rhs) = (lhs.as_f64(), rhs.as_f64());
+    let result = match op {
+        ArithmeticOp::Add => lhs + rhs,
+        ArithmeticOp::Sub => lhs - rhs,
+        ArithmeticOp::Mul => lhs * rhs,
+        ArithmeticOp::Div if rhs == 0.0 => return Err("division by zero".to_string()),
+        ArithmeticOp::Div => lhs / rhs,
+        ArithmeticOp::Min => lhs.min(rhs),
+        ArithmeticOp::Max => lhs.max(rhs),
+    };
+    Number::from_f64(result).map(Value::Number).ok_or_else(|| "non-finite result".to_string())
+}
+
+/// Evaluates a numeric ordering operator.
+fn compare(
+    args: &[CompositeExpression; 2],
+    inputs: &BTreeMap<String, Value>,
+    predicate: fn(std::cmp::Ordering) -> bool,
+) -> Result<Value, String> {
+    let lhs = Numeric::from_json(&args[0].eval(inputs)?)?;
+    let rhs = Numeric::from_json(&args[1].eval(inputs)?)?;
+    let ordering = match (lhs, rhs) {
+        (Numeric::Int(lhs), Numeric::Int(rhs)) => lhs.cmp(&rhs),
+        (lhs, rhs) => lhs
+            .as_f64()
+            .partial_cmp(&rhs.as_f64())
+            .ok_or_else(|| "numeric operands are not comparable".to_string())?,
+    };
+    Ok(Value::Bool(predicate(ordering)))
+}
+
+/// Compares JSON values, treating numerically equal numbers as equal.
+fn json_equal(lhs: &Value, rhs: &Value) -> bool {
+    match (Numeric::from_json(lhs), Numeric::from_json(rhs)) {
+        (Ok(Numeric::Int(lhs)), Ok(Numeric::Int(rhs))) => lhs == rhs,
+        #[allow(clippy::float_cmp, reason = "Exact equality is the intended semantics.")]
+        (Ok(lhs), Ok(rhs)) => lhs.as_f64() == rhs.as_f64(),
+        _ => lhs == rhs,
+    }
+}
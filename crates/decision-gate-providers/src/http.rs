@@ -2,28 +2,49 @@
 // ============================================================================
 // Module: HTTP Evidence Provider
 // Description: Evidence provider for HTTP endpoint checks.
-// Purpose: Provide status and body-hash evidence with strict limits.
-// Dependencies: decision-gate-core, reqwest, serde_json
+// Purpose: Provide status, body-hash, JSON query, and paginated JSON evidence with strict limits.
+// Dependencies: decision-gate-core, jsonpath_lib, reqwest, serde_json
 // ============================================================================
 
 //! ## Overview
 //! The HTTP provider issues bounded GET requests and returns status codes or
-//! body hashes. It enforces scheme restrictions, host allowlists, redirects
-//! disabled by default, and size limits to preserve fail-closed behavior.
+//! body hashes. The `json_query` check instead POSTs a JSON body rendered from
+//! an operator-configured template and selects evidence from the JSON response
+//! via `JSONPath`. The `json_pages` check follows paginated GET responses
+//! (body cursor or `Link` header) and aggregates selected items in page order,
+//! bounded by page and total byte caps. It enforces scheme restrictions, host
+//! allowlists, redirects disabled by default, and size limits to preserve
+//! fail-closed behavior. Both JSON checks may name an operator-configured
+//! response transform that reshapes the result value before it is returned.
+//! GET checks (`status`, `body_hash`, `json_pages`) can retry connection
+//! errors, timeouts, 429, and 5xx responses with full-jitter exponential
+//! backoff, honoring `Retry-After`, within an overall per-check deadline.
+//! `json_query` POSTs are never retried. With caching enabled, `body_hash` and
+//! `json_pages` keep an in-memory LRU of validated responses per URL and
+//! revalidate them with `If-None-Match`/`If-Modified-Since`, reusing the cached
+//! body on `304 Not Modified`. Retry deadlines, `Retry-After` dates, and cache
+//! lifetimes are judged on an injectable [`Clock`] so tests can pin time.
 //! Security posture: evidence inputs are untrusted; see `Docs/security/threat_model.md`.
 
 // ============================================================================
 // SECTION: Imports
 // ============================================================================
 
+use std::collections::BTreeMap;
 use std::collections::BTreeSet;
 use std::io::Read;
+use std::sync::Arc;
+use std::sync::Mutex;
+use std::sync::MutexGuard;
+use std::sync::PoisonError;
 use std::time::Duration;
+use std::time::Instant;
 
 use decision_gate_core::EvidenceAnchor;
 use decision_gate_core::EvidenceContext;
 use decision_gate_core::EvidenceError;
 use decision_gate_core::EvidenceProvider;
+use decision_gate_core::EvidenceProviderError;
 use decision_gate_core::EvidenceQuery;
 use decision_gate_core::EvidenceRef;
 use decision_gate_core::EvidenceResult;
@@ -34,12 +55,32 @@ use decision_gate_core::ScenarioSpec;
 use decision_gate_core::TrustLane;
 use decision_gate_core::hashing::DEFAULT_HASH_ALGORITHM;
 use decision_gate_core::hashing::hash_bytes;
+use decision_gate_core::runtime::Clock;
+use decision_gate_core::runtime::SystemClock;
+use jsonpath_lib::select;
+use rand::Rng;
+use reqwest::StatusCode;
 use reqwest::Url;
 use reqwest::blocking::Client;
+use reqwest::blocking::RequestBuilder;
+use reqwest::blocking::Response;
+use reqwest::header::ACCEPT;
+use reqwest::header::CONTENT_TYPE;
+use reqwest::header::ETAG;
+use reqwest::header::HeaderMap;
+use reqwest::header::HeaderValue;
+use reqwest::header::IF_MODIFIED_SINCE;
+use reqwest::header::IF_NONE_MATCH;
+use reqwest::header::LAST_MODIFIED;
+use reqwest::header::LINK;
+use reqwest::header::RETRY_AFTER;
 use reqwest::redirect::Policy;
 use serde::Deserialize;
+use serde_json::Map;
 use serde_json::Number;
 use serde_json::Value;
+use time::OffsetDateTime;
+use time::format_description::well_known::Rfc2822;
 
 // ============================================================================
 // SECTION: Configuration
@@ -52,6 +93,13 @@ use serde_json::Value;
 /// - `max_response_bytes` is enforced as a hard upper bound on response bodies.
 /// - If `allowed_hosts` is set, only listed hosts are permitted.
 /// - `timeout_ms` applies to the full request lifecycle.
+/// - `query_templates` should only name read-semantics endpoints; `json_query` POSTs them as-is.
+/// - `max_pages` and `max_total_bytes` bound every `json_pages` check.
+/// - `response_transforms` are validated when the provider is created.
+/// - Retries apply only to GET checks and never start an attempt that could finish after
+///   `retry_deadline_ms`.
+/// - `cache_max_entries = 0` disables response caching; cached bodies are bounded by
+///   `max_response_bytes` like fresh ones.
 #[derive(Debug, Clone, PartialEq, Eq, Deserialize)]
 pub struct HttpProviderConfig {
     /// Allow cleartext HTTP (disabled by default).
@@ -66,6 +114,36 @@ pub struct HttpProviderConfig {
     pub user_agent: String,
     /// Hash algorithm used for body hash responses.
     pub hash_algorithm: HashAlgorithm,
+    /// Named JSON body templates for `json_query` checks.
+    #[serde(default)]
+    pub query_templates: BTreeMap<String, Value>,
+    /// Maximum pages fetched by a `json_pages` check.
+    #[serde(default = "default_max_pages")]
+    pub max_pages: usize,
+    /// Maximum bytes read across all pages of a `json_pages` check.
+    #[serde(default = "default_max_total_bytes")]
+    pub max_total_bytes: usize,
+    /// Named response transforms for `json_query` and `json_pages` checks.
+    #[serde(default)]
+    pub response_transforms: BTreeMap<String, Value>,
+    /// Retries after the first attempt of a GET check (0 disables retries).
+    #[serde(default)]
+    pub max_retries: u32,
+    /// Base delay for exponential retry backoff, in milliseconds.
+    #[serde(default = "default_base_delay_ms")]
+    pub base_delay_ms: u64,
+    /// Upper bound on a single backoff delay, in milliseconds.
+    #[serde(default = "default_max_delay_ms")]
+    pub max_delay_ms: u64,
+    /// Overall deadline for a GET check including retries, in milliseconds.
+    #[serde(default = "default_retry_deadline_ms")]
+    pub retry_deadline_ms: u64,
+    /// Maximum responses kept for conditional revalidation (0 disables caching).
+    #[serde(default)]
+    pub cache_max_entries: usize,
+    /// Time a cached response may be revalidated before it is dropped, in milliseconds.
+    #[serde(default = "default_cache_ttl_ms")]
+    pub cache_ttl_ms: u64,
 }
 
 impl Default for HttpProviderConfig {
@@ -77,10 +155,53 @@ impl Default for HttpProviderConfig {
             allowed_hosts: None,
             user_agent: "decision-gate/0.1".to_string(),
             hash_algorithm: DEFAULT_HASH_ALGORITHM,
+            query_templates: BTreeMap::new(),
+            max_pages: default_max_pages(),
+            max_total_bytes: default_max_total_bytes(),
+            response_transforms: BTreeMap::new(),
+            max_retries: 0,
+            base_delay_ms: default_base_delay_ms(),
+            max_delay_ms: default_max_delay_ms(),
+            retry_deadline_ms: default_retry_deadline_ms(),
+            cache_max_entries: 0,
+            cache_ttl_ms: default_cache_ttl_ms(),
         }
     }
 }
 
+/// Default page cap for `json_pages` checks.
+const fn default_max_pages() -> usize {
+    10
+}
+
+/// Default total byte cap across `json_pages` pages.
+const fn default_max_total_bytes() -> usize {
+    4 * 1024 * 1024
+}
+
+/// Default base delay for retry backoff.
+const fn default_base_delay_ms() -> u64 {
+    100
+}
+
+/// Default cap on a single retry backoff delay.
+const fn default_max_delay_ms() -> u64 {
+    2_000
+}
+
+/// Default overall deadline for a GET check including retries.
+const fn default_retry_deadline_ms() -> u64 {
+    15_000
+}
+
+/// Default lifetime of a cached response.
+const fn default_cache_ttl_ms() -> u64 {
+    300_000
+}
+
+/// Maximum nesting depth of a response transform mapping.
+const MAX_TRANSFORM_DEPTH: usize = 16;
+
 // ============================================================================
 // SECTION: Provider Implementation
 // ============================================================================
@@ -88,7 +209,8 @@ impl Default for HttpProviderConfig {
 /// Evidence provider for HTTP endpoint checks.
 ///
 /// # Invariants
-/// - Only `status` and `body_hash` checks are supported.
+/// - Only `status`, `body_hash`, `json_query`, and `json_pages` checks are supported.
+/// - `json_query` bodies come from configured templates, never from raw params.
 /// - Redirects are not followed.
 /// - Responses exceeding configured limits fail closed.
 pub struct HttpProvider {
@@ -96,6 +218,10 @@ pub struct HttpProvider {
     config: HttpProviderConfig,
     /// HTTP client used for outbound requests.
     client: Client,
+    /// Validated GET responses for conditional requests.
+    cache: Mutex<ResponseCache>,
+    /// Time source for retry deadlines, `Retry-After` dates, and cache TTLs.
+    clock: Arc<dyn Clock>,
 }
 
 impl HttpProvider {
@@ -103,8 +229,19 @@ impl HttpProvider {
     ///
     /// # Errors
     ///
-    /// Returns [`EvidenceError`] when the HTTP client cannot be created.
+    /// Returns [`EvidenceError`] when a response transform is invalid, the
+    /// retry delays are inconsistent, or the HTTP client cannot be created.
     pub fn new(config: HttpProviderConfig) -> Result<Self, EvidenceError> {
+        if config.base_delay_ms > config.max_delay_ms {
+            return Err(EvidenceError::Provider(
+                "base_delay_ms must not exceed max_delay_ms".to_string(),
+            ));
+        }
+        for (name, mapping) in &config.response_transforms {
+            validate_transform(mapping, 0).map_err(|reason| {
+                EvidenceError::Provider(format!("invalid response transform {name}: {reason}"))
+            })?;
+        }
         let client = Client::builder()
             .timeout(Duration::from_millis(config.timeout_ms))
             .user_agent(config.user_agent.clone())
@@ -114,8 +251,321 @@ impl HttpProvider {
         Ok(Self {
             config,
             client,
+            cache: Mutex::new(ResponseCache::default()),
+            clock: Arc::new(SystemClock),
         })
     }
+
+    /// Replaces the time source, typically with a manual clock in tests.
+    #[must_use]
+    pub fn with_clock(mut self, clock: Arc<dyn Clock>) -> Self {
+        self.clock = clock;
+        self
+    }
+
+    /// POSTs a rendered query template and selects evidence from the JSON response.
+    fn json_query(
+        &self,
+        url: &Url,
+        params: Option<&Value>,
+    ) -> Result<EvidenceResult, EvidenceError> {
+        let JsonQueryParams {
+            template,
+            variables,
+            jsonpath,
+        } = extract_json_query_params(params)?;
+        let transform = self.response_transform(params)?;
+        let template = self
+            .config
+            .query_templates
+            .get(template)
+            .ok_or_else(|| EvidenceError::Provider("unknown query template".to_string()))?;
+        let body = render_template(template, variables)?;
+        let body = serde_json::to_vec(&body)
+            .map_err(|_| EvidenceError::Provider("query body serialization failed".to_string()))?;
+        let mut response = self
+            .client
+            .post(url.clone())
+            .header(CONTENT_TYPE, "application/json")
+            .header(ACCEPT, "application/json")
+            .body(body)
+            .send()
+            .map_err(|_| EvidenceError::Provider("http request failed".to_string()))?;
+        let status = response.status();
+        if !status.is_success() {
+            return Err(EvidenceError::Provider(format!(
+                "http query returned status {}",
+                status.as_u16()
+            )));
+        }
+        let body = read_response_limited(&mut response, self.config.max_response_bytes)?;
+        let document: Value = serde_json::from_slice(&body)
+            .map_err(|_| EvidenceError::Provider("http query response is not json".to_string()))?;
+        let value = match jsonpath {
+            Some(path) => select_jsonpath(&document, path)?,
+            None => Some(document),
+        };
+        let error = jsonpath.filter(|_| value.is_none()).map(|path| EvidenceProviderError {
+            code: "jsonpath_not_found".to_string(),
+            message: format!("jsonpath not found: {path}"),
+            details: Some(serde_json::json!({ "jsonpath": path })),
+        });
+        Ok(self.transformed_evidence(url, transform, value, error))
+    }
+
+    /// Follows paginated GET responses and aggregates selected items across pages.
+    ///
+    /// Pages are fetched in order from `url`; items are appended in page order.
+    /// Reaching `max_pages` with a further page pending, or reading more than
+    /// `max_total_bytes`, stops early with a `pagination_limit_reached` error
+    /// and no value.
+    fn json_pages(
+        &self,
+        url: &Url,
+        params: Option<&Value>,
+    ) -> Result<EvidenceResult, EvidenceError> {
+        let JsonPagesParams {
+            items: items_path,
+            next,
+            aggregate,
+        } = extract_json_pages_params(params)?;
+        let transform = self.response_transform(params)?;
+        let mut items = Vec::new();
+        let mut pages = 0usize;
+        let mut total_bytes = 0usize;
+        let mut page_url = url.clone();
+        loop {
+            if pages >= self.config.max_pages {
+                return Ok(self.pagination_limit_result(url, "max_pages", pages, total_bytes));
+            }
+            validate_url(&page_url, &self.config)?;
+            let page = self.fetch_body(&page_url, Some("application/json"))?;
+            if !page.status.is_success() {
+                return Err(EvidenceError::Provider(format!(
+                    "http page returned status {}",
+                    page.status.as_u16()
+                )));
+            }
+            let link_next = match next {
+                NextPage::LinkHeader => next_link(&page.headers, &page_url)?,
+                NextPage::Cursor {
+                    ..
+                } => None,
+            };
+            let body = page.body;
+            pages += 1;
+            total_bytes = total_bytes.saturating_add(body.len());
+            if total_bytes > self.config.max_total_bytes {
+                return Ok(self.pagination_limit_result(
+                    url,
+                    "max_total_bytes",
+                    pages,
+                    total_bytes,
+                ));
+            }
+            let document: Value = serde_json::from_slice(&body)
+                .map_err(|_| EvidenceError::Provider("http page is not json".to_string()))?;
+            let selected = select(&document, items_path)
+                .map_err(|_| EvidenceError::Provider("invalid jsonpath".to_string()))?;
+            items.extend(selected.into_iter().cloned());
+            let next_url = match next {
+                NextPage::Cursor {
+                    jsonpath,
+                    param,
+                } => next_cursor_url(&document, jsonpath, param, url)?,
+                NextPage::LinkHeader => link_next,
+            };
+            let Some(next_url) = next_url else {
+                break;
+            };
+            page_url = next_url;
+        }
+        let value = match aggregate {
+            PageAggregate::Items => Value::Array(items),
+            PageAggregate::Count => Value::Number(Number::from(items.len())),
+        };
+        Ok(self.transformed_evidence(url, transform, Some(value), None))
+    }
+
+    /// Sends an idempotent GET, retrying transient failures with backoff.
+    ///
+    /// Connection errors, timeouts, 429, and 5xx responses are retried up to
+    /// `max_retries` times. Each delay is drawn uniformly from zero to
+    /// `min(max_delay_ms, base_delay_ms * 2^attempt)` unless the response
+    /// carries a `Retry-After`, which is used as-is. A retry is skipped when
+    /// its delay plus a full request timeout would pass `retry_deadline_ms`;
+    /// the last response or error is then returned unchanged.
+    fn send_get(&self, mut request: RequestBuilder) -> Result<Response, EvidenceError> {
+        let started = self.clock.now();
+        let deadline = Duration::from_millis(self.config.retry_deadline_ms);
+        let timeout = Duration::from_millis(self.config.timeout_ms);
+        let mut attempt = 0u32;
+        loop {
+            let next = request.try_clone().filter(|_| attempt < self.config.max_retries);
+            let outcome = request.send();
+            let retry_after = match &outcome {
+                Ok(response) if is_retryable_status(response.status()) => {
+                    Some(retry_after(response, self.clock.unix_secs()))
+                }
+                Err(err) if err.is_connect() || err.is_timeout() => Some(None),
+                _ => None,
+            };
+            let (Some(next), Some(retry_after)) = (next, retry_after) else {
+                return outcome
+                    .map_err(|_| EvidenceError::Provider("http request failed".to_string()));
+            };
+            let delay = retry_after.unwrap_or_else(|| self.backoff_delay(attempt));
+            let elapsed = self.clock.now().saturating_duration_since(started);
+            if elapsed.saturating_add(delay).saturating_add(timeout) > deadline {
+                return outcome
+                    .map_err(|_| EvidenceError::Provider("http request failed".to_string()));
+            }
+            drop(outcome);
+            std::thread::sleep(delay);
+            attempt += 1;
+            request = next;
+        }
+    }
+
+    /// GETs `url` and reads its body, revalidating a cached copy when enabled.
+    ///
+    /// With `cache_max_entries > 0`, a live cache entry for `(url, accept)`
+    /// adds its validators to the request and a `304` returns the cached body
+    /// and `Link` header, restarting the entry's TTL. If the entry was evicted
+    /// while the conditional request was in flight, the `304` carries no body
+    /// to serve, so the URL is fetched again without validators; a second
+    /// `304` fails closed. Successful responses carrying an `ETag` or
+    /// `Last-Modified` replace the entry.
+    fn fetch_body(&self, url: &Url, accept: Option<&str>) -> Result<FetchedBody, EvidenceError> {
+        let mut request = self.get_request(url, accept);
+        if self.config.cache_max_entries == 0 {
+            return read_fetched(self.send_get(request)?, self.config.max_response_bytes);
+        }
+        let key = (url.to_string(), accept.map(str::to_string));
+        let ttl = Duration::from_millis(self.config.cache_ttl_ms);
+        let validators = self.lock_cache().validators(&key, ttl, self.clock.now());
+        if let Some((etag, last_modified)) = validators {
+            if let Some(etag) = etag {
+                request = request.header(IF_NONE_MATCH, etag);
+            }
+            if let Some(last_modified) = last_modified {
+                request = request.header(IF_MODIFIED_SINCE, last_modified);
+            }
+        }
+        let mut response = self.send_get(request)?;
+        if response.status() == StatusCode::NOT_MODIFIED {
+            let cached = self.lock_cache().revalidate(&key, self.clock.now());
+            if let Some(cached) = cached {
+                return Ok(cached);
+            }
+            drop(response);
+            response = self.send_get(self.get_request(url, accept))?;
+            if response.status() == StatusCode::NOT_MODIFIED {
+                return Err(EvidenceError::Provider(
+                    "http 304 without a cached response".to_string(),
+                ));
+            }
+        }
+        let fetched = read_fetched(response, self.config.max_response_bytes)?;
+        if fetched.status.is_success() {
+            self.lock_cache().store(key, &fetched, self.config.cache_max_entries, self.clock.now());
+        }
+        Ok(fetched)
+    }
+
+    /// Builds an unconditional GET for `url` with an optional `Accept` header.
+    fn get_request(&self, url: &Url, accept: Option<&str>) -> RequestBuilder {
+        let mut request = self.client.get(url.clone());
+        if let Some(accept) = accept {
+            request = request.header(ACCEPT, accept);
+        }
+        request
+    }
+
+    /// Locks the response cache, recovering from a poisoned lock.
+    fn lock_cache(&self) -> MutexGuard<'_, ResponseCache> {
+        self.cache.lock().unwrap_or_else(PoisonError::into_inner)
+    }
+
+    /// Draws a full-jitter exponential backoff delay for a retry attempt.
+    fn backoff_delay(&self, attempt: u32) -> Duration {
+        let ceiling = self
+            .config
+            .base_delay_ms
+            .saturating_mul(1u64.checked_shl(attempt).unwrap_or(u64::MAX))
+            .min(self.config.max_delay_ms);
+        Duration::from_millis(rand::thread_rng().gen_range(0 ..= ceiling))
+    }
+
+    /// Resolves the optional `transform` param to a configured response transform.
+    fn response_transform<'a>(
+        &'a self,
+        params: Option<&'a Value>,
+    ) -> Result<Option<(&'a str, &'a Value)>, EvidenceError> {
+        let name = match params.and_then(|params| params.get("transform")) {
+            None => return Ok(None),
+            Some(Value::String(name)) => name.as_str(),
+            Some(_) => {
+                return Err(EvidenceError::Provider(
+                    "transform param must be a string".to_string(),
+                ));
+            }
+        };
+        let mapping = self
+            .config
+            .response_transforms
+            .get(name)
+            .ok_or_else(|| EvidenceError::Provider("unknown response transform".to_string()))?;
+        Ok(Some((name, mapping)))
+    }
+
+    /// Applies the optional response transform and builds the JSON evidence result.
+    ///
+    /// A transform failure discards the value and reports `transform_failed`.
+    fn transformed_evidence(
+        &self,
+        url: &Url,
+        transform: Option<(&str, &Value)>,
+        value: Option<Value>,
+        error: Option<EvidenceProviderError>,
+    ) -> EvidenceResult {
+        let (Some((name, mapping)), Some(input)) = (transform, value.as_ref()) else {
+            return json_evidence(url, value, error);
+        };
+        match apply_transform(mapping, input, self.config.max_response_bytes) {
+            Ok(output) => json_evidence(url, Some(output), error),
+            Err(reason) => {
+                let error = EvidenceProviderError {
+                    code: "transform_failed".to_string(),
+                    message: format!("response transform {name} failed: {reason}"),
+                    details: Some(serde_json::json!({ "transform": name, "reason": reason })),
+                };
+                json_evidence(url, None, Some(error))
+            }
+        }
+    }
+
+    /// Builds the fail-closed result for a `json_pages` check stopped by a cap.
+    fn pagination_limit_result(
+        &self,
+        url: &Url,
+        limit: &str,
+        pages: usize,
+        total_bytes: usize,
+    ) -> EvidenceResult {
+        let error = EvidenceProviderError {
+            code: "pagination_limit_reached".to_string(),
+            message: format!("pagination stopped at {limit} after {pages} pages"),
+            details: Some(serde_json::json!({
+                "limit": limit,
+                "pages": pages,
+                "bytes": total_bytes,
+                "max_pages": self.config.max_pages,
+                "max_total_bytes": self.config.max_total_bytes,
+            })),
+        };
+        json_evidence(url, None, Some(error))
+    }
 }
 
 impl EvidenceProvider for HttpProvider {
@@ -129,11 +579,7 @@ impl EvidenceProvider for HttpProvider {
 
         match query.check_id.as_str() {
             "status" => {
-                let response = self
-                    .client
-                    .get(url.clone())
-                    .send()
-                    .map_err(|_| EvidenceError::Provider("http request failed".to_string()))?;
+                let response = self.send_get(self.client.get(url.clone()))?;
                 let status = response.status().as_u16();
                 Ok(EvidenceResult {
                     value: Some(EvidenceValue::Json(Value::Number(Number::from(status)))),
@@ -152,12 +598,7 @@ impl EvidenceProvider for HttpProvider {
                 })
             }
             "body_hash" => {
-                let mut response = self
-                    .client
-                    .get(url.clone())
-                    .send()
-                    .map_err(|_| EvidenceError::Provider("http request failed".to_string()))?;
-                let body = read_response_limited(&mut response, self.config.max_response_bytes)?;
+                let body = self.fetch_body(&url, None)?.body;
                 let digest = hash_bytes(self.config.hash_algorithm, &body);
                 let hash_value = serde_json::to_value(digest).map_err(|_| {
                     EvidenceError::Provider("hash serialization failed".to_string())
@@ -178,6 +619,8 @@ impl EvidenceProvider for HttpProvider {
                     content_type: Some("application/json".to_string()),
                 })
             }
+            "json_query" => self.json_query(&url, query.params.as_ref()),
+            "json_pages" => self.json_pages(&url, query.params.as_ref()),
             _ => Err(EvidenceError::Provider("unsupported http check".to_string())),
         }
     }
@@ -187,10 +630,152 @@ impl EvidenceProvider for HttpProvider {
     }
 }
 
+// ============================================================================
+// SECTION: Response Cache
+// ============================================================================
+
+/// Cache key: request URL and `Accept` header.
+type CacheKey = (String, Option<String>);
+
+/// Status, headers, and size-limited body of a GET response.
+struct FetchedBody {
+    /// Response status (`200` when served from cache).
+    status: StatusCode,
+    /// Response headers; cached responses keep only `Link`.
+    headers: HeaderMap,
+    /// Response body, at most `max_response_bytes` long.
+    body: Vec<u8>,
+}
+
+/// A validated response kept for conditional revalidation.
+struct CachedResponse {
+    /// `ETag` validator, if the response had one.
+    etag: Option<HeaderValue>,
+    /// `Last-Modified` validator, if the response had one.
+    last_modified: Option<HeaderValue>,
+    /// `Link` header, replayed for `json_pages` link pagination.
+    link: Option<HeaderValue>,
+    /// Cached response body.
+    body: Vec<u8>,
+    /// When the entry was stored or last revalidated.
+    validated_at: Instant,
+    /// Access tick used for least-recently-used eviction.
+    last_used: u64,
+}
+
+/// Least-recently-used cache of validated GET responses.
+#[derive(Default)]
+struct ResponseCache {
+    /// Cached responses by key.
+    entries: BTreeMap<CacheKey, CachedResponse>,
+    /// Monotonic access counter.
+    tick: u64,
+}
+
+impl ResponseCache {
+    /// Returns the validators of a live entry, dropping it once `ttl` has
+    /// passed at `now`.
+    fn validators(
+        &mut self,
+        key: &CacheKey,
+        ttl: Duration,
+        now: Instant,
+    ) -> Option<(Option<HeaderValue>, Option<HeaderValue>)> {
+        let entry = self.entries.get(key)?;
+        if now.saturating_duration_since(entry.validated_at) >= ttl {
+            self.entries.remove(key);
+            return None;
+        }
+        Some((entry.etag.clone(), entry.last_modified.clone()))
+    }
+
+    /// Serves a cached entry after a `304`, restarting its TTL at `now`.
+    fn revalidate(&mut self, key: &CacheKey, now: Instant) -> Option<FetchedBody> {
+        self.tick += 1;
+        let entry = self.entries.get_mut(key)?;
+        entry.validated_at = now;
+        entry.last_used = self.tick;
+        let mut headers = HeaderMap::new();
+        if let Some(link) = &entry.link {
+            headers.insert(LINK, link.clone());
+        }
+        Some(FetchedBody {
+            status: StatusCode::OK,
+            headers,
+            body: entry.body.clone(),
+        })
+    }
+
+    /// Stores a successful response that carries a validator, evicting the
+    /// least recently used entry when `capacity` is reached.
+    fn store(&mut self, key: CacheKey, fetched: &FetchedBody, capacity: usize, now: Instant) {
+        let etag = fetched.headers.get(ETAG).cloned();
+        let last_modified = fetched.headers.get(LAST_MODIFIED).cloned();
+        if etag.is_none() && last_modified.is_none() {
+            self.entries.remove(&key);
+            return;
+        }
+        if !self.entries.contains_key(&key)
+            && self.entries.len() >= capacity
+            && let Some(oldest) = self
+                .entries
+                .iter()
+                .min_by_key(|(_, entry)| entry.last_used)
+                .map(|(key, _)| key.clone())
+        {
+            self.entries.remove(&oldest);
+        }
+        self.tick += 1;
+        self.entries.insert(
+            key,
+            CachedResponse {
+                etag,
+                last_modified,
+                link: fetched.headers.get(LINK).cloned(),
+                body: fetched.body.clone(),
+                validated_at: now,
+                last_used: self.tick,
+            },
+        );
+    }
+}
+
+/// Reads a response into a [`FetchedBody`], enforcing the size limit.
+fn read_fetched(mut response: Response, max_bytes: usize) -> Result<FetchedBody, EvidenceError> {
+    let status = response.status();
+    let headers = response.headers().clone();
+    let body = read_response_limited(&mut response, max_bytes)?;
+    Ok(FetchedBody {
+        status,
+        headers,
+        body,
+    })
+}
+
 // ============================================================================
 // SECTION: Helpers
 // ============================================================================
 
+/// Returns true for statuses worth retrying: 429 and any 5xx.
+fn is_retryable_status(status: StatusCode) -> bool {
+    status == StatusCode::TOO_MANY_REQUESTS || status.is_server_error()
+}
+
+/// Parses a `Retry-After` header as delta-seconds or an HTTP date.
+///
+/// Dates are measured from `now_unix`. Unparseable values are ignored so the
+/// caller falls back to backoff; dates in the past yield a zero delay.
+fn retry_after(response: &Response, now_unix: u64) -> Option<Duration> {
+    let value = response.headers().get(RETRY_AFTER)?.to_str().ok()?.trim();
+    if let Ok(seconds) = value.parse::<u64>() {
+        return Some(Duration::from_secs(seconds));
+    }
+    let at = OffsetDateTime::parse(value, &Rfc2822).ok()?;
+    let now = OffsetDateTime::from_unix_timestamp(i64::try_from(now_unix).ok()?).ok()?;
+    let remaining = at - now;
+    Some(remaining.try_into().unwrap_or(Duration::ZERO))
+}
+
 /// Extracts the URL from query parameters.
 fn extract_url(params: Option<&Value>) -> Result<Url, EvidenceError> {
     let params =
@@ -206,6 +791,346 @@ fn extract_url(params: Option<&Value>) -> Result<Url, EvidenceError> {
     Url::parse(url).map_err(|_| EvidenceError::Provider("invalid url".to_string()))
 }
 
+/// Parameters for a `json_query` check.
+struct JsonQueryParams<'a> {
+    /// Name of the configured query template.
+    template: &'a str,
+    /// Values substituted into template placeholders.
+    variables: Option<&'a Map<String, Value>>,
+    /// Optional `JSONPath` selector over the response.
+    jsonpath: Option<&'a str>,
+}
+
+/// Extracts `json_query` params.
+fn extract_json_query_params(params: Option<&Value>) -> Result<JsonQueryParams<'_>, EvidenceError> {
+    let Some(Value::Object(map)) = params else {
+        return Err(EvidenceError::Provider("http params must be an object".to_string()));
+    };
+    let Some(Value::String(template)) = map.get("template") else {
+        return Err(EvidenceError::Provider("template param must be a string".to_string()));
+    };
+    let variables = match map.get("variables") {
+        None => None,
+        Some(Value::Object(variables)) => Some(variables),
+        Some(_) => {
+            return Err(EvidenceError::Provider("variables param must be an object".to_string()));
+        }
+    };
+    let jsonpath = match map.get("jsonpath") {
+        None => None,
+        Some(Value::String(path)) => Some(path.as_str()),
+        Some(_) => {
+            return Err(EvidenceError::Provider("jsonpath param must be a string".to_string()));
+        }
+    };
+    Ok(JsonQueryParams {
+        template: template.as_str(),
+        variables,
+        jsonpath,
+    })
+}
+
+/// Next-page extractor for a `json_pages` check.
+#[derive(Clone, Copy)]
+enum NextPage<'a> {
+    /// Cursor read from each page body and sent as a query parameter.
+    Cursor {
+        /// `JSONPath` selecting the cursor in the page body.
+        jsonpath: &'a str,
+        /// Query parameter carrying the cursor on the next request.
+        param: &'a str,
+    },
+    /// `Link` response header entry with `rel="next"`.
+    LinkHeader,
+}
+
+/// Aggregation applied to items collected across pages.
+#[derive(Clone, Copy)]
+enum PageAggregate {
+    /// All selected items in page order.
+    Items,
+    /// Number of selected items.
+    Count,
+}
+
+/// Parameters for a `json_pages` check.
+struct JsonPagesParams<'a> {
+    /// `JSONPath` selecting the items on each page.
+    items: &'a str,
+    /// Next-page extractor.
+    next: NextPage<'a>,
+    /// Aggregation over collected items.
+    aggregate: PageAggregate,
+}
+
+/// Extracts `json_pages` params.
+fn extract_json_pages_params(params: Option<&Value>) -> Result<JsonPagesParams<'_>, EvidenceError> {
+    let Some(Value::Object(map)) = params else {
+        return Err(EvidenceError::Provider("http params must be an object".to_string()));
+    };
+    let Some(Value::String(items)) = map.get("items") else {
+        return Err(EvidenceError::Provider("items param must be a string".to_string()));
+    };
+    let Some(Value::Object(next)) = map.get("next") else {
+        return Err(EvidenceError::Provider("next param must be an object".to_string()));
+    };
+    let next = match next.get("type").and_then(Value::as_str) {
+        Some("cursor") => {
+            let (Some(Value::String(jsonpath)), Some(Value::String(param))) =
+                (next.get("jsonpath"), next.get("param"))
+            else {
+                return Err(EvidenceError::Provider(
+                    "cursor pagination requires jsonpath and param strings".to_string(),
+                ));
+            };
+            NextPage::Cursor {
+                jsonpath,
+                param,
+            }
+        }
+        Some("link_header") => NextPage::LinkHeader,
+        _ => {
+            return Err(EvidenceError::Provider(
+                "next.type must be cursor or link_header".to_string(),
+            ));
+        }
+    };
+    let aggregate = match map.get("aggregate") {
+        None => PageAggregate::Items,
+        Some(Value::String(mode)) if mode == "items" => PageAggregate::Items,
+        Some(Value::String(mode)) if mode == "count" => PageAggregate::Count,
+        Some(_) => {
+            return Err(EvidenceError::Provider(
+                "aggregate param must be items or count".to_string(),
+            ));
+        }
+    };
+    Ok(JsonPagesParams {
+        items,
+        next,
+        aggregate,
+    })
+}
+
+/// Returns the next page URL for a body cursor, or `None` on the last page.
+///
+/// A missing, null, or empty cursor ends pagination. The cursor replaces any
+/// existing `param` value on the first page URL.
+fn next_cursor_url(
+    document: &Value,
+    jsonpath: &str,
+    param: &str,
+    first: &Url,
+) -> Result<Option<Url>, EvidenceError> {
+    let cursor = match select_jsonpath(document, jsonpath)? {
+        None | Some(Value::Null) => return Ok(None),
+        Some(Value::String(cursor)) if cursor.is_empty() => return Ok(None),
+        Some(Value::String(cursor)) => cursor,
+        Some(Value::Number(cursor)) => cursor.to_string(),
+        Some(_) => {
+            return Err(EvidenceError::Provider(
+                "pagination cursor must be a string or number".to_string(),
+            ));
+        }
+    };
+    let mut next = first.clone();
+    let retained: Vec<(String, String)> = first
+        .query_pairs()
+        .filter(|(key, _)| key != param)
+        .map(|(key, value)| (key.into_owned(), value.into_owned()))
+        .collect();
+    next.query_pairs_mut().clear().extend_pairs(retained).append_pair(param, &cursor);
+    Ok(Some(next))
+}
+
+/// Returns the `rel="next"` target from `Link` headers, resolved against `current`.
+fn next_link(headers: &HeaderMap, current: &Url) -> Result<Option<Url>, EvidenceError> {
+    for header in headers.get_all(LINK) {
+        let header = header
+            .to_str()
+            .map_err(|_| EvidenceError::Provider("invalid link header".to_string()))?;
+        for entry in header.split(',') {
+            let Some((target, attributes)) =
+                entry.trim().strip_prefix('<').and_then(|rest| rest.split_once('>'))
+            else {
+                continue;
+            };
+            let is_next = attributes.split(';').any(|attribute| {
+                attribute.trim().strip_prefix("rel=").is_some_and(|rel| {
+                    rel.trim_matches('"')
+                        .split_whitespace()
+                        .any(|rel| rel.eq_ignore_ascii_case("next"))
+                })
+            });
+            if is_next {
+                return current
+                    .join(target)
+                    .map(Some)
+                    .map_err(|_| EvidenceError::Provider("invalid link header url".to_string()));
+            }
+        }
+    }
+    Ok(None)
+}
+
+/// Builds a verified JSON evidence result anchored to `url`.
+fn json_evidence(
+    url: &Url,
+    value: Option<Value>,
+    error: Option<EvidenceProviderError>,
+) -> EvidenceResult {
+    EvidenceResult {
+        value: value.map(EvidenceValue::Json),
+        lane: TrustLane::Verified,
+        error,
+        evidence_hash: None,
+        evidence_ref: Some(EvidenceRef {
+            uri: url.to_string(),
+        }),
+        evidence_anchor: Some(EvidenceAnchor {
+            anchor_type: "url".to_string(),
+            anchor_value: url.to_string(),
+        }),
+        signature: None,
+        content_type: Some("application/json".to_string()),
+    }
+}
+
+/// Checks a response transform mapping for depth and selector validity.
+///
+/// Strings starting with `$` are `JSONPath` selectors; `$$` escapes a literal `$`.
+fn validate_transform(mapping: &Value, depth: usize) -> Result<(), String> {
+    if depth > MAX_TRANSFORM_DEPTH {
+        return Err(format!("mapping exceeds depth {MAX_TRANSFORM_DEPTH}"));
+    }
+    match mapping {
+        Value::String(text) if text.starts_with('$') && !text.starts_with("$$") => {
+            select(&Value::Null, text).map(|_| ()).map_err(|_| format!("invalid jsonpath {text}"))
+        }
+        Value::Array(items) => {
+            items.iter().try_for_each(|item| validate_transform(item, depth + 1))
+        }
+        Value::Object(fields) => {
+            fields.values().try_for_each(|field| validate_transform(field, depth + 1))
+        }
+        _ => Ok(()),
+    }
+}
+
+/// Reshapes `input` with a response transform mapping.
+///
+/// Selectors are replaced by their `JSONPath` result over `input` (multiple
+/// matches become an array); other values are copied as literals. A selector
+/// with no match, or output larger than `max_bytes`, is an error.
+fn apply_transform(mapping: &Value, input: &Value, max_bytes: usize) -> Result<Value, String> {
+    let output = map_transform(mapping, input)?;
+    let size = serde_json::to_vec(&output).map_err(|_| "output serialization failed")?.len();
+    if size > max_bytes {
+        return Err(format!("output exceeds {max_bytes} bytes"));
+    }
+    Ok(output)
+}
+
+/// Recursively evaluates a validated transform mapping against `input`.
+fn map_transform(mapping: &Value, input: &Value) -> Result<Value, String> {
+    match mapping {
+        Value::String(text) if text.starts_with("$$") => Ok(Value::String(text[1 ..].to_string())),
+        Value::String(path) if path.starts_with('$') => match select_jsonpath(input, path) {
+            Ok(Some(value)) => Ok(value),
+            Ok(None) => Err(format!("jsonpath not found: {path}")),
+            Err(_) => Err(format!("invalid jsonpath {path}")),
+        },
+        Value::Array(items) => items
+            .iter()
+            .map(|item| map_transform(item, input))
+            .collect::<Result<_, _>>()
+            .map(Value::Array),
+        Value::Object(fields) => fields
+            .iter()
+            .map(|(key, field)| Ok((key.clone(), map_transform(field, input)?)))
+            .collect::<Result<_, String>>()
+            .map(Value::Object),
+        literal => Ok(literal.clone()),
+    }
+}
+
+/// Substitutes `{{name}}` placeholders in a query template with check variables.
+///
+/// A string that is exactly one placeholder becomes the variable's JSON value.
+/// Placeholders inside longer strings take the variable's text: strings
+/// verbatim, numbers and booleans in JSON form. Object keys are left as-is.
+fn render_template(
+    template: &Value,
+    variables: Option<&Map<String, Value>>,
+) -> Result<Value, EvidenceError> {
+    match template {
+        Value::String(text) => render_template_string(text, variables),
+        Value::Array(items) => items
+            .iter()
+            .map(|item| render_template(item, variables))
+            .collect::<Result<Vec<_>, _>>()
+            .map(Value::Array),
+        Value::Object(entries) => {
+            let mut rendered = Map::with_capacity(entries.len());
+            for (key, value) in entries {
+                rendered.insert(key.clone(), render_template(value, variables)?);
+            }
+            Ok(Value::Object(rendered))
+        }
+        Value::Null | Value::Bool(_) | Value::Number(_) => Ok(template.clone()),
+    }
+}
+
+/// Substitutes placeholders within a single template string.
+fn render_template_string(
+    text: &str,
+    variables: Option<&Map<String, Value>>,
+) -> Result<Value, EvidenceError> {
+    let lookup = |name: &str| {
+        variables
+            .and_then(|variables| variables.get(name))
+            .ok_or_else(|| EvidenceError::Provider(format!("missing template variable: {name}")))
+    };
+    if let Some(name) = text.strip_prefix("{{").and_then(|rest| rest.strip_suffix("}}"))
+        && !name.contains("{{")
+        && !name.contains("}}")
+    {
+        return lookup(name.trim()).cloned();
+    }
+    let mut rendered = String::with_capacity(text.len());
+    let mut rest = text;
+    while let Some((before, after)) = rest.split_once("{{") {
+        let (name, remainder) = after.split_once("}}").ok_or_else(|| {
+            EvidenceError::Provider("unterminated template placeholder".to_string())
+        })?;
+        rendered.push_str(before);
+        match lookup(name.trim())? {
+            Value::String(value) => rendered.push_str(value),
+            value @ (Value::Number(_) | Value::Bool(_)) => rendered.push_str(&value.to_string()),
+            _ => {
+                return Err(EvidenceError::Provider(format!(
+                    "template variable {} must be a string, number, or boolean inside a string",
+                    name.trim()
+                )));
+            }
+        }
+        rest = remainder;
+    }
+    rendered.push_str(rest);
+    Ok(Value::String(rendered))
+}
+
+/// Selects values from a JSON response using a `JSONPath` expression.
+fn select_jsonpath(document: &Value, path: &str) -> Result<Option<Value>, EvidenceError> {
+    let matches = select(document, path)
+        .map_err(|_| EvidenceError::Provider("invalid jsonpath".to_string()))?;
+    match matches.as_slice() {
+        [] => Ok(None),
+        [value] => Ok(Some((*value).clone())),
+        values => Ok(Some(Value::Array(values.iter().map(|value| (*value).clone()).collect()))),
+    }
+}
+
 /// Validates URL scheme and allowlist policy.
 fn validate_url(url: &Url, config: &HttpProviderConfig) -> Result<(), EvidenceError> {
     match url.scheme() {
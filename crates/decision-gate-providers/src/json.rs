@@ -10,6 +10,13 @@
 //! The JSON provider loads JSON or YAML files and evaluates `JSONPath` expressions
 //! against their contents. It enforces path restrictions and size limits to
 //! avoid resource exhaustion.
+//!
+//! `JSONPath` evaluation is bounded by a node budget. Each fan-out step in the
+//! expression (recursive descent `..`, wildcards, filters, and slices) may visit
+//! every node of the document, so a path is rejected before evaluation when
+//! `fan-out steps x document nodes` exceeds `max_jsonpath_nodes`. Recursive
+//! descent inside a filter expression is rejected outright because its cost
+//! grows with the square of the document size.
 //! Security posture: evidence inputs are untrusted; see `Docs/security/threat_model.md`.
 
 // ============================================================================
@@ -34,7 +41,7 @@ use decision_gate_core::ProviderMissingError;
 use decision_gate_core::ScenarioSpec;
 use decision_gate_core::TrustLane;
 use decision_gate_core::hashing::canonical_json_bytes;
-use jsonpath_lib::select;
+use jsonpath_lib::Compiled;
 use serde::Deserialize;
 use serde_json::Value;
 
@@ -49,6 +56,7 @@ use serde_json::Value;
 /// - `root_id` is a stable identifier used in evidence anchors.
 /// - `max_bytes` is enforced as a hard upper bound on file size.
 /// - `allow_yaml` gates YAML parsing for `.yaml`/`.yml` files.
+/// - `max_jsonpath_nodes` bounds the estimated nodes visited by one `JSONPath` evaluation.
 #[derive(Debug, Clone, PartialEq, Eq, Deserialize)]
 pub struct JsonProviderConfig {
     /// Root directory for resolving file paths.
@@ -59,6 +67,9 @@ pub struct JsonProviderConfig {
     pub max_bytes: usize,
     /// Allow YAML parsing when file extension is .yaml or .yml.
     pub allow_yaml: bool,
+    /// Maximum document nodes a single `JSONPath` evaluation may visit.
+    #[serde(default = "default_max_jsonpath_nodes")]
+    pub max_jsonpath_nodes: usize,
 }
 
 impl Default for JsonProviderConfig {
@@ -68,10 +79,16 @@ impl Default for JsonProviderConfig {
             root_id: String::new(),
             max_bytes: 1024 * 1024,
             allow_yaml: true,
+            max_jsonpath_nodes: default_max_jsonpath_nodes(),
         }
     }
 }
 
+/// Default `JSONPath` node budget.
+const fn default_max_jsonpath_nodes() -> usize {
+    100_000
+}
+
 // ============================================================================
 // SECTION: Provider Implementation
 // ============================================================================
@@ -152,7 +169,7 @@ impl EvidenceProvider for JsonProvider {
             }
         };
         let value = match jsonpath {
-            Some(path) => match select_jsonpath(&document, &path) {
+            Some(path) => match select_jsonpath(&document, &path, self.config.max_jsonpath_nodes) {
                 Ok(Some(value)) => Some(value),
                 Ok(None) => {
                     let error = provider_error(
@@ -377,6 +394,11 @@ fn validate_config(config: &JsonProviderConfig) -> Result<PathBuf, EvidenceError
     if !root.is_dir() {
         return Err(EvidenceError::Provider("json provider root is not a directory".to_string()));
     }
+    if config.max_jsonpath_nodes == 0 {
+        return Err(EvidenceError::Provider(
+            "json provider max_jsonpath_nodes must be nonzero".to_string(),
+        ));
+    }
     Ok(root)
 }
 
@@ -416,26 +438,135 @@ fn to_posix_relative(path: &Path) -> Result<String, EvidenceProviderError> {
     Ok(parts.join("/"))
 }
 
-/// Selects values using a `JSONPath` expression.
-fn select_jsonpath(document: &Value, path: &str) -> Result<Option<Value>, EvidenceProviderError> {
-    let matches = select(document, path).map_err(|_| {
-        provider_error(
-            "invalid_jsonpath",
-            "invalid jsonpath",
+/// Selects values using a `JSONPath` expression within a node budget.
+fn select_jsonpath(
+    document: &Value,
+    path: &str,
+    max_nodes: usize,
+) -> Result<Option<Value>, EvidenceProviderError> {
+    let compiled = Compiled::compile(path).map_err(|_| invalid_jsonpath(path))?;
+    check_jsonpath_budget(document, path, max_nodes)?;
+    let matches = compiled.select(document).map_err(|_| invalid_jsonpath(path))?;
+    match matches.as_slice() {
+        [] => Ok(None),
+        [value] => Ok(Some((*value).clone())),
+        values => Ok(Some(Value::Array(values.iter().map(|value| (*value).clone()).collect()))),
+    }
+}
+
+/// Builds the error for a `JSONPath` expression that fails to parse or evaluate.
+fn invalid_jsonpath(path: &str) -> EvidenceProviderError {
+    provider_error(
+        "invalid_jsonpath",
+        "invalid jsonpath",
+        Some(serde_json::json!({ "jsonpath": path })),
+    )
+}
+
+/// Rejects expressions whose estimated evaluation cost exceeds `max_nodes`.
+fn check_jsonpath_budget(
+    document: &Value,
+    path: &str,
+    max_nodes: usize,
+) -> Result<(), EvidenceProviderError> {
+    let shape = JsonPathShape::scan(path);
+    if shape.filter_descent {
+        return Err(provider_error(
+            "jsonpath_budget_exceeded",
+            "recursive descent inside a jsonpath filter is not allowed",
             Some(serde_json::json!({ "jsonpath": path })),
-        )
-    })?;
-    if matches.is_empty() {
-        return Ok(None);
+        ));
+    }
+    if shape.fan_out_steps == 0 {
+        return Ok(());
+    }
+    let node_limit = max_nodes / shape.fan_out_steps + 1;
+    let estimated = count_nodes(document, node_limit).saturating_mul(shape.fan_out_steps);
+    if estimated > max_nodes {
+        return Err(provider_error(
+            "jsonpath_budget_exceeded",
+            "jsonpath exceeds node budget",
+            Some(serde_json::json!({
+                "jsonpath": path,
+                "max_nodes": max_nodes,
+                "fan_out_steps": shape.fan_out_steps
+            })),
+        ));
     }
-    if matches.len() == 1 {
-        return Ok(Some(matches[0].clone()));
+    Ok(())
+}
+
+/// Fan-out structure of a `JSONPath` expression, used to bound evaluation cost.
+#[derive(Debug, Default)]
+struct JsonPathShape {
+    /// Steps outside filters that may visit every node: `..`, `*`, `[?(...)]`, and slices.
+    fan_out_steps: usize,
+    /// Whether a filter expression contains a recursive descent.
+    filter_descent: bool,
+}
+
+impl JsonPathShape {
+    /// Scans an expression, skipping quoted names and literals.
+    fn scan(path: &str) -> Self {
+        let mut shape = Self::default();
+        let mut brackets: Vec<bool> = Vec::new();
+        let mut quote = None;
+        let mut chars = path.chars().peekable();
+        while let Some(ch) = chars.next() {
+            if let Some(open) = quote {
+                if ch == '\\' {
+                    chars.next();
+                } else if ch == open {
+                    quote = None;
+                }
+                continue;
+            }
+            let in_filter = brackets.contains(&true);
+            match ch {
+                '\'' | '"' => quote = Some(ch),
+                '[' => {
+                    let filter = chars.peek() == Some(&'?');
+                    if filter && !in_filter {
+                        shape.fan_out_steps += 1;
+                    }
+                    brackets.push(filter);
+                }
+                ']' => {
+                    brackets.pop();
+                }
+                '.' if chars.peek() == Some(&'.') => {
+                    chars.next();
+                    if in_filter {
+                        shape.filter_descent = true;
+                    } else {
+                        shape.fan_out_steps += 1;
+                    }
+                }
+                '*' if !in_filter => shape.fan_out_steps += 1,
+                ':' if !in_filter && !brackets.is_empty() => shape.fan_out_steps += 1,
+                _ => {}
+            }
+        }
+        shape
     }
-    let mut values = Vec::with_capacity(matches.len());
-    for value in matches {
-        values.push(value.clone());
+}
+
+/// Counts document nodes, stopping once `limit` is reached.
+fn count_nodes(document: &Value, limit: usize) -> usize {
+    let mut count = 0usize;
+    let mut pending = vec![document];
+    while let Some(value) = pending.pop() {
+        count += 1;
+        if count >= limit {
+            break;
+        }
+        match value {
+            Value::Array(items) => pending.extend(items),
+            Value::Object(map) => pending.extend(map.values()),
+            _ => {}
+        }
     }
-    Ok(Some(Value::Array(values)))
+    count
 }
 
 /// Builds structured provider error metadata for JSON evidence failures.
@@ -3,12 +3,17 @@
 // Module: Decision Gate Providers
 // Description: Built-in evidence providers and registry utilities.
 // Purpose: Provide zero-config evidence sources aligned with Decision Gate core.
-// Dependencies: decision-gate-core, serde, reqwest, time
+// Dependencies: decision-gate-core, serde, reqwest, rusqlite, time, postgres (optional)
 // ============================================================================
 
 //! ## Overview
 //! This crate ships built-in evidence providers (time, env, json, http) and a
 //! registry implementation that routes evidence queries by provider identifier.
+//! [`SqliteQueryProvider`] is available as a library provider for allowlisted,
+//! read-only queries against a local `SQLite` file, and
+//! [`CompositeEvidenceProvider`] derives evidence from several sub-queries.
+//! With the `postgres` feature, `PostgresProvider` runs allowlisted read-only
+//! queries against a Postgres database whose DSN comes from the environment.
 //! Providers are deterministic with respect to the supplied trigger context and
 //! enforce strict validation and size limits for untrusted inputs.
 //! Invariants:
@@ -21,25 +26,39 @@
 // SECTION: Modules
 // ============================================================================
 
+pub mod composite;
 pub mod env;
 pub mod http;
 pub mod json;
+#[cfg(feature = "postgres")]
+pub mod postgres;
 pub mod registry;
+pub mod sqlite;
 pub mod time;
 
 // ============================================================================
 // SECTION: Re-Exports
 // ============================================================================
 
+pub use composite::CompositeEvidenceProvider;
+pub use composite::CompositeExpression;
+pub use composite::CompositeProviderConfig;
 pub use env::EnvProvider;
 pub use env::EnvProviderConfig;
 pub use http::HttpProvider;
 pub use http::HttpProviderConfig;
 pub use json::JsonProvider;
 pub use json::JsonProviderConfig;
+#[cfg(feature = "postgres")]
+pub use postgres::PostgresProvider;
+#[cfg(feature = "postgres")]
+pub use postgres::PostgresProviderConfig;
 pub use registry::BuiltinProviderConfigs;
+pub use registry::DEFAULT_MAX_PROVIDER_WORKERS;
 pub use registry::ProviderAccessPolicy;
 pub use registry::ProviderRegistry;
+pub use sqlite::SqliteQueryProvider;
+pub use sqlite::SqliteQueryProviderConfig;
 pub use time::TimeProvider;
 pub use time::TimeProviderConfig;
 
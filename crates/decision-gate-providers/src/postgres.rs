@@ -0,0 +1,656 @@
+// crates/decision-gate-providers/src/postgres.rs
+// ============================================================================
+// Module: Postgres Query Evidence Provider
+// Description: Evidence provider for allowlisted read-only Postgres queries.
+// Purpose: Expose a scalar, row, or bounded row set from a Postgres database.
+// Dependencies: decision-gate-core, postgres, serde_json
+// ============================================================================
+
+//! ## Overview
+//! The Postgres query provider runs named, allowlisted `SELECT` statements
+//! against a database whose DSN is read from an environment variable named in
+//! configuration, so connection secrets never appear in config files. Check
+//! params select a query by name and supply positional bind values for `$1`,
+//! `$2`, ..., which are sent as typed parameters and never interpolated into
+//! SQL. Every query runs in a read-only transaction with a statement timeout,
+//! and row and serialized size caps fail closed.
+//!
+//! Results reflect the database at query time; as with the other providers,
+//! determinism is bounded by the supplied trigger context and the recorded
+//! evidence, not by re-running the query.
+//! Security posture: evidence inputs are untrusted; see `Docs/security/threat_model.md`.
+
+// ============================================================================
+// SECTION: Imports
+// ============================================================================
+
+use std::collections::BTreeMap;
+use std::env;
+use std::sync::Mutex;
+use std::time::Duration;
+
+use ::postgres::Client;
+use ::postgres::Config;
+use ::postgres::NoTls;
+use ::postgres::Row;
+use ::postgres::Statement;
+use ::postgres::error::SqlState;
+use ::postgres::fallible_iterator::FallibleIterator;
+use ::postgres::types::FromSql;
+use ::postgres::types::ToSql;
+use ::postgres::types::Type;
+use decision_gate_core::EvidenceAnchor;
+use decision_gate_core::EvidenceContext;
+use decision_gate_core::EvidenceError;
+use decision_gate_core::EvidenceProvider;
+use decision_gate_core::EvidenceQuery;
+use decision_gate_core::EvidenceResult;
+use decision_gate_core::EvidenceValue;
+use decision_gate_core::ProviderMissingError;
+use decision_gate_core::ScenarioSpec;
+use decision_gate_core::TrustLane;
+use serde::Deserialize;
+use serde_json::Map;
+use serde_json::Number;
+use serde_json::Value;
+
+// ============================================================================
+// SECTION: Limits
+// ============================================================================
+
+/// Maximum length of the DSN environment variable name.
+const MAX_DSN_ENV_NAME_LENGTH: usize = 256;
+
+// ============================================================================
+// SECTION: Configuration
+// ============================================================================
+
+/// Configuration for the Postgres query provider.
+///
+/// # Invariants
+/// - `dsn_env` names an environment variable; the DSN itself is never stored in config.
+/// - `queries` maps query names to single read-only `SELECT` statements.
+/// - `max_rows`, `max_value_bytes`, and both timeouts are hard upper bounds.
+#[derive(Debug, Clone, PartialEq, Eq, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct PostgresProviderConfig {
+    /// Environment variable holding the connection DSN.
+    pub dsn_env: String,
+    /// Allowlisted queries keyed by name.
+    pub queries: BTreeMap<String, String>,
+    /// Maximum rows a `rows` check may return before failing closed.
+    #[serde(default = "default_max_rows")]
+    pub max_rows: usize,
+    /// Maximum bytes allowed for the serialized result value.
+    #[serde(default = "default_max_value_bytes")]
+    pub max_value_bytes: usize,
+    /// Server-side statement timeout in milliseconds.
+    #[serde(default = "default_statement_timeout_ms")]
+    pub statement_timeout_ms: u64,
+    /// Connection establishment timeout in milliseconds.
+    #[serde(default = "default_connect_timeout_ms")]
+    pub connect_timeout_ms: u64,
+}
+
+impl Default for PostgresProviderConfig {
+    fn default() -> Self {
+        Self {
+            dsn_env: String::new(),
+            queries: BTreeMap::new(),
+            max_rows: default_max_rows(),
+            max_value_bytes: default_max_value_bytes(),
+            statement_timeout_ms: default_statement_timeout_ms(),
+            connect_timeout_ms: default_connect_timeout_ms(),
+        }
+    }
+}
+
+/// Default row cap for `rows` checks.
+const fn default_max_rows() -> usize {
+    100
+}
+
+/// Default serialized result size cap.
+const fn default_max_value_bytes() -> usize {
+    64 * 1024
+}
+
+/// Default statement timeout in milliseconds.
+const fn default_statement_timeout_ms() -> u64 {
+    1_000
+}
+
+/// Default connection timeout in milliseconds.
+const fn default_connect_timeout_ms() -> u64 {
+    5_000
+}
+
+// ============================================================================
+// SECTION: Provider Implementation
+// ============================================================================
+
+/// Evidence provider for allowlisted Postgres queries.
+///
+/// # Invariants
+/// - Supports the `query` (single row) and `rows` (row set) check ids.
+/// - Every configured query must start with `SELECT`; execution happens in a read-only transaction
+///   with `statement_timeout` set.
+/// - Bind values come from check params and are never interpolated into SQL.
+/// - A single connection is reused and re-established when it closes.
+pub struct PostgresProvider {
+    /// Provider configuration, including query allowlist and limits.
+    config: PostgresProviderConfig,
+    /// Connection settings parsed from the DSN.
+    connection: Config,
+    /// Lazily established client connection.
+    client: Mutex<Option<Client>>,
+}
+
+impl PostgresProvider {
+    /// Creates a Postgres query provider, reading the DSN from the process environment.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`EvidenceError`] when the configuration is invalid or the DSN
+    /// variable is unset, empty, or malformed.
+    pub fn new(config: PostgresProviderConfig) -> Result<Self, EvidenceError> {
+        Self::new_with_env_lookup(config, &|name| env::var(name).ok())
+    }
+
+    /// Creates a Postgres query provider using an injected environment lookup.
+    ///
+    /// No connection is opened until the first query or [`EvidenceProvider::warm_up`].
+    ///
+    /// # Errors
+    ///
+    /// Returns [`EvidenceError`] when the configuration is invalid or the DSN
+    /// variable is unset, empty, or malformed.
+    pub fn new_with_env_lookup(
+        config: PostgresProviderConfig,
+        lookup_env: &dyn Fn(&str) -> Option<String>,
+    ) -> Result<Self, EvidenceError> {
+        validate_config(&config)?;
+        let dsn =
+            lookup_env(&config.dsn_env).filter(|dsn| !dsn.trim().is_empty()).ok_or_else(|| {
+                EvidenceError::Provider(format!(
+                    "postgres dsn env var {} is not set",
+                    config.dsn_env
+                ))
+            })?;
+        let mut connection: Config = dsn.parse().map_err(|_| {
+            EvidenceError::Provider(format!("postgres dsn in {} is invalid", config.dsn_env))
+        })?;
+        connection.connect_timeout(Duration::from_millis(config.connect_timeout_ms));
+        Ok(Self {
+            config,
+            connection,
+            client: Mutex::new(None),
+        })
+    }
+
+    /// Runs `f` against a connected client, reconnecting when needed.
+    fn with_client<T>(
+        &self,
+        f: impl FnOnce(&mut Client) -> Result<T, EvidenceError>,
+    ) -> Result<T, EvidenceError> {
+        let mut slot = self
+            .client
+            .lock()
+            .map_err(|_| EvidenceError::Provider("postgres client lock poisoned".to_string()))?;
+        if slot.as_ref().is_none_or(Client::is_closed) {
+            let client = self.connection.connect(NoTls).map_err(|_| {
+                EvidenceError::Provider("unable to connect to postgres".to_string())
+            })?;
+            *slot = Some(client);
+        }
+        let client = slot.as_mut().ok_or_else(|| {
+            EvidenceError::Provider("postgres connection unavailable".to_string())
+        })?;
+        let result = f(client);
+        drop(slot);
+        result
+    }
+}
+
+impl EvidenceProvider for PostgresProvider {
+    fn query(
+        &self,
+        query: &EvidenceQuery,
+        _ctx: &EvidenceContext,
+    ) -> Result<EvidenceResult, EvidenceError> {
+        let shape = match query.check_id.as_str() {
+            "query" => ResultShape::SingleRow,
+            "rows" => ResultShape::RowSet,
+            _ => return Err(EvidenceError::Provider("unsupported postgres check".to_string())),
+        };
+
+        let (name, binds) = extract_params(query.params.as_ref())?;
+        let sql =
+            self.config.queries.get(name).ok_or_else(|| {
+                EvidenceError::Provider("postgres query not allowlisted".to_string())
+            })?;
+
+        let value = self.with_client(|client| {
+            let mut transaction = client
+                .build_transaction()
+                .read_only(true)
+                .start()
+                .map_err(|err| query_error(&err))?;
+            transaction
+                .batch_execute(&format!(
+                    "SET LOCAL statement_timeout = {}",
+                    self.config.statement_timeout_ms
+                ))
+                .map_err(|_| {
+                    EvidenceError::Provider("postgres timeout setup failed".to_string())
+                })?;
+            let stmt = transaction.prepare(sql).map_err(|err| match err.code() {
+                Some(&SqlState::QUERY_CANCELED) => query_error(&err),
+                _ => EvidenceError::Provider("postgres statement failed to prepare".to_string()),
+            })?;
+            let binds = bind_params(&stmt, binds)?;
+            let params = binds.iter().map(BindValue::as_sql);
+            let mut rows = transaction.query_raw(&stmt, params).map_err(|err| query_error(&err))?;
+            let limit = match shape {
+                ResultShape::SingleRow => 1,
+                ResultShape::RowSet => self.config.max_rows,
+            };
+            let mut collected = Vec::new();
+            while let Some(row) = rows.next().map_err(|err| query_error(&err))? {
+                if collected.len() == limit {
+                    return Err(EvidenceError::Provider(
+                        "postgres query exceeds row limit".to_string(),
+                    ));
+                }
+                collected.push(row);
+            }
+            drop(rows);
+            transaction.commit().map_err(|err| query_error(&err))?;
+            shape.build_value(&collected)
+        })?;
+
+        value.map_or_else(
+            || Ok(empty_result(name)),
+            |value| build_value_result(name, value, self.config.max_value_bytes),
+        )
+    }
+
+    fn validate_providers(&self, _spec: &ScenarioSpec) -> Result<(), ProviderMissingError> {
+        Ok(())
+    }
+
+    fn warm_up(&self) -> Result<(), EvidenceError> {
+        self.with_client(|_| Ok(()))
+    }
+}
+
+// ============================================================================
+// SECTION: Configuration Validation
+// ============================================================================
+
+/// Validates limits, the DSN variable name, and the query allowlist.
+fn validate_config(config: &PostgresProviderConfig) -> Result<(), EvidenceError> {
+    if config.max_rows == 0 {
+        return Err(EvidenceError::Provider("postgres max_rows must be nonzero".to_string()));
+    }
+    if config.statement_timeout_ms == 0 {
+        return Err(EvidenceError::Provider(
+            "postgres statement_timeout_ms must be nonzero".to_string(),
+        ));
+    }
+    if config.connect_timeout_ms == 0 {
+        return Err(EvidenceError::Provider(
+            "postgres connect_timeout_ms must be nonzero".to_string(),
+        ));
+    }
+    if !is_env_name(&config.dsn_env) {
+        return Err(EvidenceError::Provider(
+            "postgres dsn_env must be an environment variable name".to_string(),
+        ));
+    }
+    for (name, sql) in &config.queries {
+        if !starts_with_select(sql) {
+            return Err(EvidenceError::Provider(format!(
+                "postgres query {name}: only SELECT statements are allowed"
+            )));
+        }
+    }
+    Ok(())
+}
+
+/// Returns true when `name` matches `[A-Za-z_][A-Za-z0-9_]*` within length limits.
+fn is_env_name(name: &str) -> bool {
+    let mut chars = name.chars();
+    name.len() <= MAX_DSN_ENV_NAME_LENGTH
+        && chars.next().is_some_and(|ch| ch.is_ascii_alphabetic() || ch == '_')
+        && chars.all(|ch| ch.is_ascii_alphanumeric() || ch == '_')
+}
+
+/// Returns true when the first keyword of the statement is `SELECT`.
+fn starts_with_select(sql: &str) -> bool {
+    let keyword: String = sql.trim_start().chars().take_while(char::is_ascii_alphabetic).collect();
+    keyword.eq_ignore_ascii_case("select")
+}
+
+/// Maps a query execution error, distinguishing statement timeouts.
+fn query_error(err: &::postgres::Error) -> EvidenceError {
+    match err.code() {
+        Some(&SqlState::QUERY_CANCELED) => {
+            EvidenceError::Provider("postgres query timed out".to_string())
+        }
+        Some(&SqlState::READ_ONLY_SQL_TRANSACTION) => {
+            EvidenceError::Provider("postgres statement is not read-only".to_string())
+        }
+        _ => EvidenceError::Provider("postgres query failed".to_string()),
+    }
+}
+
+// ============================================================================
+// SECTION: Value Types
+// ============================================================================
+
+/// Postgres types supported for bind params and result columns.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum ValueKind {
+    /// `bool`.
+    Bool,
+    /// `int2`.
+    Int2,
+    /// `int4`.
+    Int4,
+    /// `int8`.
+    Int8,
+    /// `float4` (result columns only).
+    Float4,
+    /// `float8`.
+    Float8,
+    /// `text`, `varchar`, `bpchar`, and `name`.
+    Text,
+    /// `json` and `jsonb`.
+    Json,
+    /// `bytea` (single-column `query` results only).
+    Bytes,
+}
+
+impl ValueKind {
+    /// Classifies a Postgres type, returning `None` for unsupported types.
+    fn of(ty: &Type) -> Option<Self> {
+        [
+            (Type::BOOL, Self::Bool),
+            (Type::INT2, Self::Int2),
+            (Type::INT4, Self::Int4),
+            (Type::INT8, Self::Int8),
+            (Type::FLOAT4, Self::Float4),
+            (Type::FLOAT8, Self::Float8),
+            (Type::TEXT, Self::Text),
+            (Type::VARCHAR, Self::Text),
+            (Type::BPCHAR, Self::Text),
+            (Type::NAME, Self::Text),
+            (Type::JSON, Self::Json),
+            (Type::JSONB, Self::Json),
+            (Type::BYTEA, Self::Bytes),
+        ]
+        .into_iter()
+        .find_map(|(candidate, kind)| (candidate == *ty).then_some(kind))
+    }
+}
+
+/// Result shape selected by the check id.
+#[derive(Debug, Clone, Copy)]
+enum ResultShape {
+    /// At most one row, returned as a scalar or row object.
+    SingleRow,
+    /// Bounded row set, returned as an array of row objects.
+    RowSet,
+}
+
+impl ResultShape {
+    /// Converts collected rows into an evidence value.
+    fn build_value(self, rows: &[Row]) -> Result<Option<EvidenceValue>, EvidenceError> {
+        match self {
+            Self::SingleRow => rows.first().map(row_to_value).transpose(),
+            Self::RowSet => {
+                let rows = rows.iter().map(row_to_object).collect::<Result<Vec<_>, _>>()?;
+                Ok(Some(EvidenceValue::Json(Value::Array(rows))))
+            }
+        }
+    }
+}
+
+// ============================================================================
+// SECTION: Parameter Binding
+// ============================================================================
+
+/// Bind value converted to the Rust type expected by its statement parameter.
+#[derive(Debug)]
+enum BindValue {
+    /// `bool` parameter.
+    Bool(Option<bool>),
+    /// `int2` parameter.
+    Int2(Option<i16>),
+    /// `int4` parameter.
+    Int4(Option<i32>),
+    /// `int8` parameter.
+    Int8(Option<i64>),
+    /// `float8` parameter.
+    Float8(Option<f64>),
+    /// Text-like parameter.
+    Text(Option<String>),
+    /// `json` or `jsonb` parameter.
+    Json(Option<Value>),
+}
+
+impl BindValue {
+    /// Returns the value as a Postgres parameter.
+    fn as_sql(&self) -> &(dyn ToSql + Sync) {
+        match self {
+            Self::Bool(value) => value,
+            Self::Int2(value) => value,
+            Self::Int4(value) => value,
+            Self::Int8(value) => value,
+            Self::Float8(value) => value,
+            Self::Text(value) => value,
+            Self::Json(value) => value,
+        }
+    }
+}
+
+/// Extracts the query name and positional bind values from the query parameters.
+fn extract_params(params: Option<&Value>) -> Result<(&str, &[Value]), EvidenceError> {
+    let params = params
+        .ok_or_else(|| EvidenceError::Provider("postgres check requires params".to_string()))?;
+    let Value::Object(map) = params else {
+        return Err(EvidenceError::Provider("postgres params must be an object".to_string()));
+    };
+    let Value::String(name) = map
+        .get("query")
+        .ok_or_else(|| EvidenceError::Provider("missing postgres query name".to_string()))?
+    else {
+        return Err(EvidenceError::Provider("postgres query name must be a string".to_string()));
+    };
+    let binds = match map.get("params") {
+        None | Some(Value::Null) => &[][..],
+        Some(Value::Array(binds)) => binds.as_slice(),
+        Some(_) => {
+            return Err(EvidenceError::Provider(
+                "postgres bind params must be an array".to_string(),
+            ));
+        }
+    };
+    Ok((name, binds))
+}
+
+/// Converts bind values to the types declared by the prepared statement.
+///
+/// The number of values must match the statement's parameters exactly, so the
+/// statement shape is fixed by configuration.
+fn bind_params(stmt: &Statement, binds: &[Value]) -> Result<Vec<BindValue>, EvidenceError> {
+    if stmt.params().len() != binds.len() {
+        return Err(EvidenceError::Provider(format!(
+            "postgres query expects {} bind params, got {}",
+            stmt.params().len(),
+            binds.len()
+        )));
+    }
+    stmt.params().iter().zip(binds).map(|(ty, value)| json_to_bind(ty, value)).collect()
+}
+
+/// Converts a JSON bind value into the parameter's declared type.
+fn json_to_bind(ty: &Type, value: &Value) -> Result<BindValue, EvidenceError> {
+    match ValueKind::of(ty) {
+        Some(ValueKind::Bool) => scalar(ty, value, Value::as_bool).map(BindValue::Bool),
+        Some(ValueKind::Int2) => {
+            scalar(ty, value, |value| value.as_i64().and_then(|int| i16::try_from(int).ok()))
+                .map(BindValue::Int2)
+        }
+        Some(ValueKind::Int4) => {
+            scalar(ty, value, |value| value.as_i64().and_then(|int| i32::try_from(int).ok()))
+                .map(BindValue::Int4)
+        }
+        Some(ValueKind::Int8) => scalar(ty, value, Value::as_i64).map(BindValue::Int8),
+        Some(ValueKind::Float8) => scalar(ty, value, Value::as_f64).map(BindValue::Float8),
+        Some(ValueKind::Text) => {
+            scalar(ty, value, |value| value.as_str().map(str::to_string)).map(BindValue::Text)
+        }
+        Some(ValueKind::Json) => Ok(BindValue::Json(Some(value.clone()))),
+        Some(ValueKind::Float4 | ValueKind::Bytes) | None => Err(EvidenceError::Provider(format!(
+            "unsupported postgres bind param type: {}",
+            ty.name()
+        ))),
+    }
+}
+
+/// Converts a nullable JSON scalar, failing closed on a type mismatch.
+fn scalar<T>(
+    ty: &Type,
+    value: &Value,
+    convert: impl FnOnce(&Value) -> Option<T>,
+) -> Result<Option<T>, EvidenceError> {
+    if value.is_null() {
+        return Ok(None);
+    }
+    convert(value).map(Some).ok_or_else(|| {
+        EvidenceError::Provider(format!("postgres bind param does not match type {}", ty.name()))
+    })
+}
+
+// ============================================================================
+// SECTION: Result Extraction
+// ============================================================================
+
+/// Converts a row into a scalar (single column) or object (multiple columns).
+fn row_to_value(row: &Row) -> Result<EvidenceValue, EvidenceError> {
+    if let [column] = row.columns() {
+        if ValueKind::of(column.type_()) == Some(ValueKind::Bytes) {
+            let bytes: Option<Vec<u8>> = read(row, 0)?;
+            return Ok(bytes.map_or(EvidenceValue::Json(Value::Null), EvidenceValue::Bytes));
+        }
+        return column_to_json(row, 0).map(EvidenceValue::Json);
+    }
+    row_to_object(row).map(EvidenceValue::Json)
+}
+
+/// Converts a row into a JSON object keyed by column name.
+fn row_to_object(row: &Row) -> Result<Value, EvidenceError> {
+    let mut object = Map::new();
+    for (index, column) in row.columns().iter().enumerate() {
+        object.insert(column.name().to_string(), column_to_json(row, index)?);
+    }
+    Ok(Value::Object(object))
+}
+
+/// Converts a column value into JSON, rejecting unsupported types.
+fn column_to_json(row: &Row, index: usize) -> Result<Value, EvidenceError> {
+    let ty = row.columns()[index].type_();
+    let value = match ValueKind::of(ty) {
+        Some(ValueKind::Bool) => read::<bool>(row, index)?.map(Value::Bool),
+        Some(ValueKind::Int2) => read::<i16>(row, index)?.map(Value::from),
+        Some(ValueKind::Int4) => read::<i32>(row, index)?.map(Value::from),
+        Some(ValueKind::Int8) => read::<i64>(row, index)?.map(Value::from),
+        Some(ValueKind::Float4) => {
+            read::<f32>(row, index)?.map(f64::from).map(finite).transpose()?
+        }
+        Some(ValueKind::Float8) => read::<f64>(row, index)?.map(finite).transpose()?,
+        Some(ValueKind::Text) => read::<String>(row, index)?.map(Value::String),
+        Some(ValueKind::Json) => read::<Value>(row, index)?,
+        Some(ValueKind::Bytes) => {
+            return Err(EvidenceError::Provider(
+                "postgres bytea columns are only supported as scalars".to_string(),
+            ));
+        }
+        None => {
+            return Err(EvidenceError::Provider(format!(
+                "unsupported postgres column type: {}",
+                ty.name()
+            )));
+        }
+    };
+    Ok(value.unwrap_or(Value::Null))
+}
+
+/// Reads a nullable column value.
+fn read<'row, T: FromSql<'row>>(row: &'row Row, index: usize) -> Result<Option<T>, EvidenceError> {
+    row.try_get(index)
+        .map_err(|_| EvidenceError::Provider("postgres column read failed".to_string()))
+}
+
+/// Converts a float to JSON, rejecting non-finite values.
+fn finite(real: f64) -> Result<Value, EvidenceError> {
+    Number::from_f64(real)
+        .map(Value::Number)
+        .ok_or_else(|| EvidenceError::Provider("postgres float value is not finite".to_string()))
+}
+
+/// Builds a populated evidence result, enforcing value size limits.
+fn build_value_result(
+    name: &str,
+    value: EvidenceValue,
+    max_value_bytes: usize,
+) -> Result<EvidenceResult, EvidenceError> {
+    let (size, content_type) = match &value {
+        EvidenceValue::Bytes(bytes) => (bytes.len(), "application/octet-stream"),
+        EvidenceValue::Json(json) => (
+            serde_json::to_vec(json)
+                .map_err(|_| {
+                    EvidenceError::Provider("postgres value serialization failed".to_string())
+                })?
+                .len(),
+            "application/json",
+        ),
+    };
+    if size > max_value_bytes {
+        return Err(EvidenceError::Provider("postgres value exceeds limit".to_string()));
+    }
+    Ok(EvidenceResult {
+        value: Some(value),
+        lane: TrustLane::Verified,
+        error: None,
+        evidence_hash: None,
+        evidence_ref: None,
+        evidence_anchor: Some(anchor(name)),
+        signature: None,
+        content_type: Some(content_type.to_string()),
+    })
+}
+
+/// Builds an empty evidence result for a `query` check that returned no rows.
+fn empty_result(name: &str) -> EvidenceResult {
+    EvidenceResult {
+        value: None,
+        lane: TrustLane::Verified,
+        error: None,
+        evidence_hash: None,
+        evidence_ref: None,
+        evidence_anchor: Some(anchor(name)),
+        signature: None,
+        content_type: None,
+    }
+}
+
+/// Builds the evidence anchor for a named query.
+fn anchor(name: &str) -> EvidenceAnchor {
+    EvidenceAnchor {
+        anchor_type: "postgres_query".to_string(),
+        anchor_value: name.to_string(),
+    }
+}
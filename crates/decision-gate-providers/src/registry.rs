@@ -10,7 +10,13 @@
 //! The provider registry resolves evidence queries by provider identifier and
 //! enforces allowlist and denylist policies. It implements the core
 //! [`decision_gate_core::EvidenceProvider`] interface for seamless integration
-//! with the control plane engine.
+//! with the control plane engine. Warm-up initializes every registered provider
+//! eagerly so initialization failures surface before the first query.
+//! Query timeouts use a registry-wide default with per-provider overrides; a
+//! timed-out query fails closed while its worker thread runs to completion in
+//! the background. Each provider may hold at most a bounded number of worker
+//! threads, so a hung provider fails new queries closed instead of
+//! accumulating threads without limit.
 //! Security posture: evidence inputs are untrusted; see `Docs/security/threat_model.md`.
 
 // ============================================================================
@@ -19,6 +25,12 @@
 
 use std::collections::BTreeMap;
 use std::collections::BTreeSet;
+use std::sync::Arc;
+use std::sync::atomic::AtomicUsize;
+use std::sync::atomic::Ordering;
+use std::sync::mpsc;
+use std::thread;
+use std::time::Duration;
 
 use decision_gate_core::EvidenceContext;
 use decision_gate_core::EvidenceError;
@@ -34,9 +46,22 @@ use crate::HttpProvider;
 use crate::HttpProviderConfig;
 use crate::JsonProvider;
 use crate::JsonProviderConfig;
+#[cfg(feature = "postgres")]
+use crate::PostgresProvider;
+#[cfg(feature = "postgres")]
+use crate::PostgresProviderConfig;
+use crate::SqliteQueryProvider;
+use crate::SqliteQueryProviderConfig;
 use crate::TimeProvider;
 use crate::TimeProviderConfig;
 
+// ============================================================================
+// SECTION: Limits
+// ============================================================================
+
+/// Default cap on concurrently running timeout workers per provider.
+pub const DEFAULT_MAX_PROVIDER_WORKERS: usize = 16;
+
 // ============================================================================
 // SECTION: Built-in Config
 // ============================================================================
@@ -45,6 +70,7 @@ use crate::TimeProviderConfig;
 ///
 /// # Invariants
 /// - `json` must include a valid root and `root_id`.
+/// - Optional providers are registered only when their config is present.
 #[derive(Debug, Clone, PartialEq, Eq)]
 pub struct BuiltinProviderConfigs {
     /// Configuration for the time provider.
@@ -55,6 +81,11 @@ pub struct BuiltinProviderConfigs {
     pub json: JsonProviderConfig,
     /// Configuration for the http provider.
     pub http: HttpProviderConfig,
+    /// Optional configuration for the sqlite query provider.
+    pub sqlite: Option<SqliteQueryProviderConfig>,
+    /// Optional configuration for the postgres query provider.
+    #[cfg(feature = "postgres")]
+    pub postgres: Option<PostgresProviderConfig>,
 }
 
 impl BuiltinProviderConfigs {
@@ -66,6 +97,9 @@ impl BuiltinProviderConfigs {
             env: EnvProviderConfig::default(),
             json,
             http: HttpProviderConfig::default(),
+            sqlite: None,
+            #[cfg(feature = "postgres")]
+            postgres: None,
         }
     }
 }
@@ -126,11 +160,22 @@ impl Default for ProviderAccessPolicy {
 /// - Provider identifiers are unique within the registry.
 /// - Access policy is enforced on every query.
 /// - Registered providers are `Send + Sync` and stored behind trait objects.
+/// - A per-provider timeout override takes precedence over the default timeout.
+/// - At most `max_workers` timeout workers run per provider, including workers detached after a
+///   timeout.
 pub struct ProviderRegistry {
     /// Provider implementations keyed by provider identifier.
-    providers: BTreeMap<String, Box<dyn EvidenceProvider + Send + Sync>>,
+    providers: BTreeMap<String, Arc<dyn EvidenceProvider + Send + Sync>>,
     /// Access control policy for provider usage.
     policy: ProviderAccessPolicy,
+    /// Query timeout applied when a provider has no override.
+    default_timeout: Option<Duration>,
+    /// Per-provider query timeout overrides.
+    timeouts: BTreeMap<String, Duration>,
+    /// Running timeout workers keyed by provider identifier.
+    workers: BTreeMap<String, Arc<AtomicUsize>>,
+    /// Maximum running timeout workers per provider.
+    max_workers: usize,
 }
 
 impl ProviderRegistry {
@@ -140,6 +185,10 @@ impl ProviderRegistry {
         Self {
             providers: BTreeMap::new(),
             policy,
+            default_timeout: None,
+            timeouts: BTreeMap::new(),
+            workers: BTreeMap::new(),
+            max_workers: DEFAULT_MAX_PROVIDER_WORKERS,
         }
     }
 
@@ -170,10 +219,36 @@ impl ProviderRegistry {
                 "provider already registered: {provider_id}"
             )));
         }
-        self.providers.insert(provider_id, Box::new(provider));
+        self.workers.insert(provider_id.clone(), Arc::new(AtomicUsize::new(0)));
+        self.providers.insert(provider_id, Arc::new(provider));
         Ok(())
     }
 
+    /// Sets the query timeout used by providers without an override.
+    ///
+    /// `None` disables the default, so such providers run without a deadline.
+    pub const fn set_default_timeout(&mut self, timeout: Option<Duration>) {
+        self.default_timeout = timeout;
+    }
+
+    /// Sets a query timeout override for a single provider.
+    pub fn set_provider_timeout(&mut self, provider_id: impl Into<String>, timeout: Duration) {
+        self.timeouts.insert(provider_id.into(), timeout);
+    }
+
+    /// Sets the cap on concurrently running timeout workers per provider.
+    ///
+    /// Queries beyond the cap fail closed until a worker finishes.
+    pub const fn set_max_workers_per_provider(&mut self, max_workers: usize) {
+        self.max_workers = max_workers;
+    }
+
+    /// Returns the effective query timeout for a provider.
+    #[must_use]
+    pub fn timeout_for(&self, provider_id: &str) -> Option<Duration> {
+        self.timeouts.get(provider_id).copied().or(self.default_timeout)
+    }
+
     /// Registers built-in providers with default configuration.
     ///
     /// # Errors
@@ -188,6 +263,13 @@ impl ProviderRegistry {
         self.register_provider("json", JsonProvider::new(configs.json)?)?;
         let http = HttpProvider::new(configs.http)?;
         self.register_provider("http", http)?;
+        if let Some(sqlite) = configs.sqlite {
+            self.register_provider("sqlite", SqliteQueryProvider::new(sqlite)?)?;
+        }
+        #[cfg(feature = "postgres")]
+        if let Some(postgres) = configs.postgres {
+            self.register_provider("postgres", PostgresProvider::new(postgres)?)?;
+        }
         Ok(())
     }
 
@@ -213,7 +295,19 @@ impl EvidenceProvider for ProviderRegistry {
         let Some(provider) = self.providers.get(provider_id) else {
             return Err(EvidenceError::Provider(format!("provider not registered: {provider_id}")));
         };
-        provider.query(query, ctx)
+        let Some(timeout) = self.timeout_for(provider_id) else {
+            return provider.query(query, ctx);
+        };
+        let Some(slot) = self
+            .workers
+            .get(provider_id)
+            .and_then(|workers| WorkerSlot::acquire(workers, self.max_workers))
+        else {
+            return Err(EvidenceError::Provider(format!(
+                "provider worker limit reached: {provider_id}"
+            )));
+        };
+        query_with_timeout(provider, query, ctx, timeout, slot)
     }
 
     fn validate_providers(&self, spec: &ScenarioSpec) -> Result<(), ProviderMissingError> {
@@ -245,4 +339,90 @@ impl EvidenceProvider for ProviderRegistry {
             blocked_by_policy,
         })
     }
+
+    /// Warms up every registered provider in identifier order.
+    ///
+    /// All providers are attempted; failures are reported together so a
+    /// single misconfigured provider does not mask others.
+    fn warm_up(&self) -> Result<(), EvidenceError> {
+        let failures: Vec<String> = self
+            .providers
+            .iter()
+            .filter_map(|(provider_id, provider)| {
+                provider.warm_up().err().map(|err| format!("{provider_id}: {err}"))
+            })
+            .collect();
+        if failures.is_empty() {
+            return Ok(());
+        }
+        Err(EvidenceError::Provider(format!("provider warm-up failed: {}", failures.join("; "))))
+    }
+}
+
+// ============================================================================
+// SECTION: Timeout Enforcement
+// ============================================================================
+
+/// Reservation of one timeout worker for a provider, released on drop.
+struct WorkerSlot {
+    /// Running worker count for the provider.
+    workers: Arc<AtomicUsize>,
+}
+
+impl WorkerSlot {
+    /// Reserves a worker when fewer than `max_workers` are running.
+    fn acquire(workers: &Arc<AtomicUsize>, max_workers: usize) -> Option<Self> {
+        workers
+            .fetch_update(Ordering::AcqRel, Ordering::Acquire, |running| {
+                (running < max_workers).then_some(running + 1)
+            })
+            .ok()
+            .map(|_| Self {
+                workers: Arc::clone(workers),
+            })
+    }
+}
+
+impl Drop for WorkerSlot {
+    fn drop(&mut self) {
+        self.workers.fetch_sub(1, Ordering::AcqRel);
+    }
+}
+
+/// Runs a provider query on a worker thread, failing closed at the deadline.
+///
+/// The worker is detached on timeout; its eventual result is discarded and
+/// its slot is released only when the provider call returns.
+fn query_with_timeout(
+    provider: &Arc<dyn EvidenceProvider + Send + Sync>,
+    query: &EvidenceQuery,
+    ctx: &EvidenceContext,
+    timeout: Duration,
+    slot: WorkerSlot,
+) -> Result<EvidenceResult, EvidenceError> {
+    let provider_id = query.provider_id.to_string();
+    let (sender, receiver) = mpsc::sync_channel(1);
+    let worker_provider = Arc::clone(provider);
+    let worker_query = query.clone();
+    let worker_ctx = ctx.clone();
+    thread::Builder::new()
+        .name(format!("dg-provider-{provider_id}"))
+        .spawn(move || {
+            let result = worker_provider.query(&worker_query, &worker_ctx);
+            drop(slot);
+            let _ = sender.send(result);
+        })
+        .map_err(|_| {
+            EvidenceError::Provider(format!("provider worker spawn failed: {provider_id}"))
+        })?;
+    match receiver.recv_timeout(timeout) {
+        Ok(result) => result,
+        Err(mpsc::RecvTimeoutError::Timeout) => Err(EvidenceError::Provider(format!(
+            "provider timed out after {} ms: {provider_id}",
+            timeout.as_millis()
+        ))),
+        Err(mpsc::RecvTimeoutError::Disconnected) => {
+            Err(EvidenceError::Provider(format!("provider query aborted: {provider_id}")))
+        }
+    }
 }
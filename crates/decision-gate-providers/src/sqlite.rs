@@ -0,0 +1,438 @@
+// crates/decision-gate-providers/src/sqlite.rs
+// ============================================================================
+// Module: SQLite Query Evidence Provider
+// Description: Evidence provider for allowlisted read-only SQLite queries.
+// Purpose: Expose a single row or scalar from a local SQLite database.
+// Dependencies: decision-gate-core, rusqlite, serde_json
+// ============================================================================
+
+//! ## Overview
+//! The `SQLite` query provider runs named, allowlisted `SELECT` statements
+//! against a configured database file opened read-only. Query text comes only
+//! from configuration; check params select a query by name and supply named
+//! bind values, which are bound by `SQLite` and never interpolated into SQL.
+//! Results are capped by row count, serialized size, and wall-clock time, and
+//! every violation fails closed.
+//! Security posture: evidence inputs are untrusted; see `Docs/security/threat_model.md`.
+
+// ============================================================================
+// SECTION: Imports
+// ============================================================================
+
+use std::collections::BTreeMap;
+use std::path::PathBuf;
+use std::time::Duration;
+use std::time::Instant;
+
+use decision_gate_core::EvidenceAnchor;
+use decision_gate_core::EvidenceContext;
+use decision_gate_core::EvidenceError;
+use decision_gate_core::EvidenceProvider;
+use decision_gate_core::EvidenceQuery;
+use decision_gate_core::EvidenceResult;
+use decision_gate_core::EvidenceValue;
+use decision_gate_core::ProviderMissingError;
+use decision_gate_core::ScenarioSpec;
+use decision_gate_core::TrustLane;
+use rusqlite::Connection;
+use rusqlite::OpenFlags;
+use rusqlite::Statement;
+use rusqlite::types::Value as SqlValue;
+use rusqlite::types::ValueRef;
+use serde::Deserialize;
+use serde_json::Map;
+use serde_json::Number;
+use serde_json::Value;
+
+// ============================================================================
+// SECTION: Limits
+// ============================================================================
+
+/// Number of `SQLite` VM instructions between timeout checks.
+const PROGRESS_HANDLER_OPS: i32 = 1_000;
+
+/// Named bind values supplied by check params.
+type BindParams = Map<String, Value>;
+
+// ============================================================================
+// SECTION: Configuration
+// ============================================================================
+
+/// Configuration for the `SQLite` query provider.
+///
+/// # Invariants
+/// - `queries` maps query names to single read-only `SELECT` statements.
+/// - Statements may only use named parameters (`:name`, `@name`, `$name`).
+/// - `max_rows`, `max_value_bytes`, and `timeout_ms` are hard upper bounds.
+#[derive(Debug, Clone, PartialEq, Eq, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct SqliteQueryProviderConfig {
+    /// Path to the `SQLite` database file.
+    pub path: PathBuf,
+    /// Allowlisted queries keyed by name.
+    pub queries: BTreeMap<String, String>,
+    /// Maximum rows a query may produce before failing closed.
+    #[serde(default = "default_max_rows")]
+    pub max_rows: usize,
+    /// Maximum bytes allowed for the serialized result value.
+    #[serde(default = "default_max_value_bytes")]
+    pub max_value_bytes: usize,
+    /// Maximum wall-clock time for a single query in milliseconds.
+    #[serde(default = "default_timeout_ms")]
+    pub timeout_ms: u64,
+}
+
+impl Default for SqliteQueryProviderConfig {
+    fn default() -> Self {
+        Self {
+            path: PathBuf::new(),
+            queries: BTreeMap::new(),
+            max_rows: default_max_rows(),
+            max_value_bytes: default_max_value_bytes(),
+            timeout_ms: default_timeout_ms(),
+        }
+    }
+}
+
+/// Default row cap: a query must resolve to a single row.
+const fn default_max_rows() -> usize {
+    1
+}
+
+/// Default serialized result size cap.
+const fn default_max_value_bytes() -> usize {
+    64 * 1024
+}
+
+/// Default query timeout in milliseconds.
+const fn default_timeout_ms() -> u64 {
+    1_000
+}
+
+// ============================================================================
+// SECTION: Provider Implementation
+// ============================================================================
+
+/// Evidence provider for allowlisted `SQLite` queries.
+///
+/// # Invariants
+/// - Supports only the `query` check id.
+/// - Every configured query is validated as a read-only `SELECT` at construction.
+/// - The database is opened read-only with `query_only` enabled per query.
+/// - Bind values come from check params and are never interpolated into SQL.
+pub struct SqliteQueryProvider {
+    /// Provider configuration, including query allowlist and limits.
+    config: SqliteQueryProviderConfig,
+}
+
+impl SqliteQueryProvider {
+    /// Creates a new `SQLite` query provider with validated configuration.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`EvidenceError`] when the database cannot be opened read-only
+    /// or any configured query is not a single read-only `SELECT`.
+    pub fn new(config: SqliteQueryProviderConfig) -> Result<Self, EvidenceError> {
+        if config.max_rows == 0 {
+            return Err(EvidenceError::Provider("sqlite max_rows must be nonzero".to_string()));
+        }
+        if config.timeout_ms == 0 {
+            return Err(EvidenceError::Provider("sqlite timeout_ms must be nonzero".to_string()));
+        }
+        let conn = open_read_only(&config)?;
+        for (name, sql) in &config.queries {
+            let stmt = prepare_select(&conn, sql)
+                .map_err(|err| EvidenceError::Provider(format!("sqlite query {name}: {err}")))?;
+            reject_positional_params(&stmt)
+                .map_err(|err| EvidenceError::Provider(format!("sqlite query {name}: {err}")))?;
+        }
+        Ok(Self {
+            config,
+        })
+    }
+}
+
+impl EvidenceProvider for SqliteQueryProvider {
+    fn query(
+        &self,
+        query: &EvidenceQuery,
+        _ctx: &EvidenceContext,
+    ) -> Result<EvidenceResult, EvidenceError> {
+        if query.check_id.as_str() != "query" {
+            return Err(EvidenceError::Provider("unsupported sqlite check".to_string()));
+        }
+
+        let (name, binds) = extract_params(query.params.as_ref())?;
+        let sql =
+            self.config.queries.get(name).ok_or_else(|| {
+                EvidenceError::Provider("sqlite query not allowlisted".to_string())
+            })?;
+
+        let conn = open_read_only(&self.config)?;
+        let deadline = Instant::now() + Duration::from_millis(self.config.timeout_ms);
+        conn.progress_handler(PROGRESS_HANDLER_OPS, Some(move || Instant::now() >= deadline))
+            .map_err(|_| EvidenceError::Provider("sqlite timeout setup failed".to_string()))?;
+
+        let mut stmt = prepare_select(&conn, sql).map_err(EvidenceError::Provider)?;
+        bind_params(&mut stmt, binds)?;
+        let row = read_first_row(&mut stmt, self.config.max_rows, deadline)?;
+        row.map_or_else(
+            || Ok(empty_result(name)),
+            |value| build_value_result(name, value, self.config.max_value_bytes),
+        )
+    }
+
+    fn validate_providers(&self, _spec: &ScenarioSpec) -> Result<(), ProviderMissingError> {
+        Ok(())
+    }
+
+    fn warm_up(&self) -> Result<(), EvidenceError> {
+        open_read_only(&self.config).map(drop)
+    }
+}
+
+// ============================================================================
+// SECTION: Connection Helpers
+// ============================================================================
+
+/// Opens the configured database read-only with query-only mode enabled.
+fn open_read_only(config: &SqliteQueryProviderConfig) -> Result<Connection, EvidenceError> {
+    let flags = OpenFlags::SQLITE_OPEN_READ_ONLY | OpenFlags::SQLITE_OPEN_NO_MUTEX;
+    let conn = Connection::open_with_flags(&config.path, flags)
+        .map_err(|_| EvidenceError::Provider("unable to open sqlite database".to_string()))?;
+    conn.busy_timeout(Duration::from_millis(config.timeout_ms))
+        .map_err(|_| EvidenceError::Provider("sqlite busy timeout setup failed".to_string()))?;
+    conn.pragma_update(None, "query_only", true)
+        .map_err(|_| EvidenceError::Provider("sqlite query_only setup failed".to_string()))?;
+    Ok(conn)
+}
+
+/// Prepares a statement and verifies it is a single read-only `SELECT`.
+fn prepare_select<'conn>(conn: &'conn Connection, sql: &str) -> Result<Statement<'conn>, String> {
+    if !starts_with_select(sql) {
+        return Err("only SELECT statements are allowed".to_string());
+    }
+    let stmt = conn.prepare(sql).map_err(|_| "statement failed to prepare".to_string())?;
+    if !stmt.readonly() {
+        return Err("statement is not read-only".to_string());
+    }
+    Ok(stmt)
+}
+
+/// Returns true when the first keyword of the statement is `SELECT`.
+fn starts_with_select(sql: &str) -> bool {
+    let keyword: String = sql.trim_start().chars().take_while(char::is_ascii_alphabetic).collect();
+    keyword.eq_ignore_ascii_case("select")
+}
+
+/// Rejects statements that use positional (`?`) parameters.
+fn reject_positional_params(stmt: &Statement<'_>) -> Result<(), String> {
+    for index in 1 ..= stmt.parameter_count() {
+        if stmt.parameter_name(index).is_none_or(|name| name.starts_with('?')) {
+            return Err("statements must use named parameters".to_string());
+        }
+    }
+    Ok(())
+}
+
+// ============================================================================
+// SECTION: Parameter Binding
+// ============================================================================
+
+/// Extracts the query name and bind values from the query parameters.
+fn extract_params(params: Option<&Value>) -> Result<(&str, Option<&BindParams>), EvidenceError> {
+    let params = params
+        .ok_or_else(|| EvidenceError::Provider("sqlite check requires params".to_string()))?;
+    let Value::Object(map) = params else {
+        return Err(EvidenceError::Provider("sqlite params must be an object".to_string()));
+    };
+    let Value::String(name) = map
+        .get("query")
+        .ok_or_else(|| EvidenceError::Provider("missing sqlite query name".to_string()))?
+    else {
+        return Err(EvidenceError::Provider("sqlite query name must be a string".to_string()));
+    };
+    let binds = match map.get("params") {
+        None | Some(Value::Null) => None,
+        Some(Value::Object(binds)) => Some(binds),
+        Some(_) => {
+            return Err(EvidenceError::Provider(
+                "sqlite bind params must be an object".to_string(),
+            ));
+        }
+    };
+    Ok((name, binds))
+}
+
+/// Binds every statement parameter from the supplied values.
+///
+/// Each statement parameter must be supplied exactly once and unknown bind
+/// names are rejected, so the statement shape is fixed by configuration.
+fn bind_params(stmt: &mut Statement<'_>, binds: Option<&BindParams>) -> Result<(), EvidenceError> {
+    let empty = BindParams::new();
+    let binds = binds.unwrap_or(&empty);
+    let mut bound = 0usize;
+    for index in 1 ..= stmt.parameter_count() {
+        let name = stmt
+            .parameter_name(index)
+            .ok_or_else(|| EvidenceError::Provider("sqlite parameter is unnamed".to_string()))?;
+        let value = binds.get(&name[1 ..]).ok_or_else(|| {
+            EvidenceError::Provider(format!("missing sqlite bind param: {}", &name[1 ..]))
+        })?;
+        let value = json_to_sql(value)?;
+        stmt.raw_bind_parameter(index, value)
+            .map_err(|_| EvidenceError::Provider("sqlite bind failed".to_string()))?;
+        bound += 1;
+    }
+    if bound != binds.len() {
+        return Err(EvidenceError::Provider("unknown sqlite bind param".to_string()));
+    }
+    Ok(())
+}
+
+/// Converts a JSON bind value into a `SQLite` value.
+fn json_to_sql(value: &Value) -> Result<SqlValue, EvidenceError> {
+    match value {
+        Value::Null => Ok(SqlValue::Null),
+        Value::Bool(flag) => Ok(SqlValue::Integer(i64::from(*flag))),
+        Value::Number(number) => number.as_i64().map(SqlValue::Integer).map_or_else(
+            || {
+                number.as_f64().map(SqlValue::Real).ok_or_else(|| {
+                    EvidenceError::Provider("sqlite bind number out of range".to_string())
+                })
+            },
+            Ok,
+        ),
+        Value::String(text) => Ok(SqlValue::Text(text.clone())),
+        Value::Array(_) | Value::Object(_) => {
+            Err(EvidenceError::Provider("sqlite bind params must be scalars".to_string()))
+        }
+    }
+}
+
+// ============================================================================
+// SECTION: Result Extraction
+// ============================================================================
+
+/// Reads the first row, failing closed when the row cap or deadline is exceeded.
+fn read_first_row(
+    stmt: &mut Statement<'_>,
+    max_rows: usize,
+    deadline: Instant,
+) -> Result<Option<EvidenceValue>, EvidenceError> {
+    let columns: Vec<String> = stmt.column_names().into_iter().map(str::to_string).collect();
+    let mut rows = stmt.raw_query();
+    let mut first = None;
+    let mut count = 0usize;
+    loop {
+        let row = match rows.next() {
+            Ok(Some(row)) => row,
+            Ok(None) => break,
+            Err(_) if Instant::now() >= deadline => {
+                return Err(EvidenceError::Provider("sqlite query timed out".to_string()));
+            }
+            Err(_) => return Err(EvidenceError::Provider("sqlite query failed".to_string())),
+        };
+        count += 1;
+        if count > max_rows {
+            return Err(EvidenceError::Provider("sqlite query exceeds row limit".to_string()));
+        }
+        if first.is_none() {
+            first = Some(row_to_value(row, &columns)?);
+        }
+    }
+    Ok(first)
+}
+
+/// Converts a row into a scalar (single column) or object (multiple columns).
+fn row_to_value(
+    row: &rusqlite::Row<'_>,
+    columns: &[String],
+) -> Result<EvidenceValue, EvidenceError> {
+    let read = |index: usize| {
+        row.get_ref(index)
+            .map_err(|_| EvidenceError::Provider("sqlite column read failed".to_string()))
+    };
+    if let [_] = columns {
+        return match read(0)? {
+            ValueRef::Blob(bytes) => Ok(EvidenceValue::Bytes(bytes.to_vec())),
+            other => Ok(EvidenceValue::Json(sql_to_json(other)?)),
+        };
+    }
+    let mut object = Map::new();
+    for (index, column) in columns.iter().enumerate() {
+        object.insert(column.clone(), sql_to_json(read(index)?)?);
+    }
+    Ok(EvidenceValue::Json(Value::Object(object)))
+}
+
+/// Converts a `SQLite` value into JSON, rejecting blobs and non-finite reals.
+fn sql_to_json(value: ValueRef<'_>) -> Result<Value, EvidenceError> {
+    match value {
+        ValueRef::Null => Ok(Value::Null),
+        ValueRef::Integer(int) => Ok(Value::Number(int.into())),
+        ValueRef::Real(real) => Number::from_f64(real)
+            .map(Value::Number)
+            .ok_or_else(|| EvidenceError::Provider("sqlite real value is not finite".to_string())),
+        ValueRef::Text(bytes) => std::str::from_utf8(bytes)
+            .map(|text| Value::String(text.to_string()))
+            .map_err(|_| EvidenceError::Provider("sqlite text is not utf-8".to_string())),
+        ValueRef::Blob(_) => Err(EvidenceError::Provider(
+            "sqlite blob columns are only supported as scalars".to_string(),
+        )),
+    }
+}
+
+/// Builds a populated evidence result, enforcing value size limits.
+fn build_value_result(
+    name: &str,
+    value: EvidenceValue,
+    max_value_bytes: usize,
+) -> Result<EvidenceResult, EvidenceError> {
+    let (size, content_type) = match &value {
+        EvidenceValue::Bytes(bytes) => (bytes.len(), "application/octet-stream"),
+        EvidenceValue::Json(json) => (
+            serde_json::to_vec(json)
+                .map_err(|_| {
+                    EvidenceError::Provider("sqlite value serialization failed".to_string())
+                })?
+                .len(),
+            "application/json",
+        ),
+    };
+    if size > max_value_bytes {
+        return Err(EvidenceError::Provider("sqlite value exceeds limit".to_string()));
+    }
+    Ok(EvidenceResult {
+        value: Some(value),
+        lane: TrustLane::Verified,
+        error: None,
+        evidence_hash: None,
+        evidence_ref: None,
+        evidence_anchor: Some(anchor(name)),
+        signature: None,
+        content_type: Some(content_type.to_string()),
+    })
+}
+
+/// Builds an empty evidence result for a query that returned no rows.
+fn empty_result(name: &str) -> EvidenceResult {
+    EvidenceResult {
+        value: None,
+        lane: TrustLane::Verified,
+        error: None,
+        evidence_hash: None,
+        evidence_ref: None,
+        evidence_anchor: Some(anchor(name)),
+        signature: None,
+        content_type: None,
+    }
+}
+
+/// Builds the evidence anchor for a named query.
+fn anchor(name: &str) -> EvidenceAnchor {
+    EvidenceAnchor {
+        anchor_type: "sqlite_query".to_string(),
+        anchor_value: name.to_string(),
+    }
+}
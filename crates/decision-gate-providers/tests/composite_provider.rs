@@ -0,0 +1,278 @@
+// crates/decision-gate-providers/tests/composite_provider.rs
+// ============================================================================
+// Module: Composite Provider Tests
+// Description: Tests for the composite evidence provider.
+// Purpose: Validate expression evaluation, audit anchors, and failure propagation.
+// Dependencies: decision-gate-providers, decision-gate-core, serde_json
+// ============================================================================
+
+//! ## Overview
+//! Tests the composite provider for:
+//! - Happy path: combining two sub-provider values
+//! - Auditability: every sub-query is recorded in the composite anchor
+//! - Error handling: sub-provider failures name the failing input
+//! - Boundary enforcement: expression node and depth limits
+//!
+//! Security posture: sub-provider results are untrusted; composite checks must
+//! fail closed. See: `Docs/security/threat_model.md`
+
+#![allow(
+    clippy::panic,
+    clippy::print_stdout,
+    clippy::print_stderr,
+    clippy::unwrap_used,
+    clippy::expect_used,
+    clippy::use_debug,
+    clippy::dbg_macro,
+    clippy::panic_in_result_fn,
+    clippy::unwrap_in_result,
+    reason = "Test-only output and panic-based assertions are permitted."
+)]
+
+mod common;
+
+use decision_gate_core::EvidenceAnchor;
+use decision_gate_core::EvidenceContext;
+use decision_gate_core::EvidenceError;
+use decision_gate_core::EvidenceProvider;
+use decision_gate_core::EvidenceQuery;
+use decision_gate_core::EvidenceResult;
+use decision_gate_core::EvidenceValue;
+use decision_gate_core::ProviderId;
+use decision_gate_core::TrustLane;
+use decision_gate_providers::CompositeEvidenceProvider;
+use decision_gate_providers::CompositeProviderConfig;
+use decision_gate_providers::ProviderAccessPolicy;
+use decision_gate_providers::ProviderRegistry;
+use serde_json::Value;
+use serde_json::json;
+
+use crate::common::sample_context;
+
+// ============================================================================
+// SECTION: Fixtures
+// ============================================================================
+
+/// Provider that echoes the `value` param back as evidence.
+struct EchoProvider {
+    /// Lane reported on every result.
+    lane: TrustLane,
+}
+
+impl EvidenceProvider for EchoProvider {
+    fn query(
+        &self,
+        query: &EvidenceQuery,
+        _ctx: &EvidenceContext,
+    ) -> Result<EvidenceResult, EvidenceError> {
+        let value = query.params.as_ref().and_then(|params| params.get("value")).cloned();
+        Ok(EvidenceResult {
+            value: value.map(EvidenceValue::Json),
+            lane: self.lane,
+            error: None,
+            evidence_hash: None,
+            evidence_ref: None,
+            evidence_anchor: Some(EvidenceAnchor {
+                anchor_type: "echo".to_string(),
+                anchor_value: query.check_id.clone(),
+            }),
+            signature: None,
+            content_type: None,
+        })
+    }
+
+    fn validate_providers(
+        &self,
+        _spec: &decision_gate_core::ScenarioSpec,
+    ) -> Result<(), decision_gate_core::ProviderMissingError> {
+        Ok(())
+    }
+}
+
+/// Provider that always fails.
+struct FailingProvider;
+
+impl EvidenceProvider for FailingProvider {
+    fn query(
+        &self,
+        _query: &EvidenceQuery,
+        _ctx: &EvidenceContext,
+    ) -> Result<EvidenceResult, EvidenceError> {
+        Err(EvidenceError::Provider("upstream unavailable".to_string()))
+    }
+
+    fn validate_providers(
+        &self,
+        _spec: &decision_gate_core::ScenarioSpec,
+    ) -> Result<(), decision_gate_core::ProviderMissingError> {
+        Ok(())
+    }
+}
+
+/// Builds a composite provider over `echo`, `asserted`, and `broken` sources.
+fn composite(config: CompositeProviderConfig) -> CompositeEvidenceProvider {
+    let mut registry = ProviderRegistry::new(ProviderAccessPolicy::allow_all());
+    registry
+        .register_provider(
+            "echo",
+            EchoProvider {
+                lane: TrustLane::Verified,
+            },
+        )
+        .unwrap();
+    registry
+        .register_provider(
+            "asserted",
+            EchoProvider {
+                lane: TrustLane::Asserted,
+            },
+        )
+        .unwrap();
+    registry.register_provider("broken", FailingProvider).unwrap();
+    CompositeEvidenceProvider::new(registry, config)
+}
+
+/// Builds a `compute` query from params.
+fn compute(params: Value) -> EvidenceQuery {
+    EvidenceQuery {
+        provider_id: ProviderId::new("composite"),
+        check_id: "compute".to_string(),
+        params: Some(params),
+    }
+}
+
+/// Builds a sub-query that echoes `value` from `provider`.
+fn sub_query(provider: &str, value: &Value) -> Value {
+    json!({ "provider_id": provider, "check_id": "read", "params": { "value": value } })
+}
+
+// ============================================================================
+// SECTION: Happy Path Tests
+// ============================================================================
+
+/// Tests combining two sub-provider values with arithmetic and comparison.
+#[test]
+fn composite_combines_two_sub_provider_values() {
+    let provider = composite(CompositeProviderConfig::default());
+    let query = compute(json!({
+        "inputs": {
+            "observed": sub_query("echo", &json!(42)),
+            "threshold": sub_query("echo", &json!(40)),
+        },
+        "expression": { "gt": [{ "input": "observed" }, { "add": [{ "input": "threshold" }, { "literal": 1 }] }] }
+    }));
+
+    let result = provider.query(&query, &sample_context()).unwrap();
+    assert_eq!(result.value, Some(EvidenceValue::Json(json!(true))));
+    assert_eq!(result.lane, TrustLane::Verified);
+
+    let sum = compute(json!({
+        "inputs": {
+            "a": sub_query("echo", &json!(1.5)),
+            "b": sub_query("echo", &json!(2)),
+        },
+        "expression": { "add": [{ "input": "a" }, { "input": "b" }] }
+    }));
+    let result = provider.query(&sum, &sample_context()).unwrap();
+    assert_eq!(result.value, Some(EvidenceValue::Json(json!(3.5))));
+}
+
+/// Tests that every sub-query is recorded in the composite anchor.
+#[test]
+fn composite_anchor_records_sub_queries() {
+    let provider = composite(CompositeProviderConfig::default());
+    let query = compute(json!({
+        "inputs": {
+            "a": sub_query("echo", &json!(3)),
+            "b": sub_query("asserted", &json!(4)),
+        },
+        "expression": { "mul": [{ "input": "a" }, { "input": "b" }] }
+    }));
+
+    let result = provider.query(&query, &sample_context()).unwrap();
+    assert_eq!(result.value, Some(EvidenceValue::Json(json!(12))));
+    assert_eq!(result.lane, TrustLane::Asserted, "weakest sub-result lane wins");
+
+    let anchor = result.evidence_anchor.unwrap();
+    assert_eq!(anchor.anchor_type, "composite");
+    let audit: Value = serde_json::from_str(&anchor.anchor_value).unwrap();
+    assert_eq!(audit["inputs"]["a"]["query"]["provider_id"], "echo");
+    assert_eq!(audit["inputs"]["b"]["query"]["provider_id"], "asserted");
+    assert_eq!(audit["inputs"]["b"]["lane"], "asserted");
+    assert_eq!(audit["inputs"]["a"]["evidence_anchor"]["anchor_type"], "echo");
+    assert!(audit["inputs"]["a"]["evidence_hash"]["value"].is_string());
+}
+
+// ============================================================================
+// SECTION: Error Handling Tests
+// ============================================================================
+
+/// Tests that a sub-provider failure propagates with the input name.
+#[test]
+fn composite_propagates_sub_provider_failure() {
+    let provider = composite(CompositeProviderConfig::default());
+    let query = compute(json!({
+        "inputs": {
+            "healthy": sub_query("echo", &json!(1)),
+            "upstream": sub_query("broken", &json!(1)),
+        },
+        "expression": { "eq": [{ "input": "healthy" }, { "input": "upstream" }] }
+    }));
+
+    let err = provider.query(&query, &sample_context()).unwrap_err();
+    let message = err.to_string();
+    assert!(message.contains("composite input upstream failed"), "{message}");
+    assert!(message.contains("upstream unavailable"), "{message}");
+}
+
+/// Tests that unregistered sub-providers and type errors fail closed.
+#[test]
+fn composite_rejects_invalid_inputs() {
+    let provider = composite(CompositeProviderConfig::default());
+    let missing = compute(json!({
+        "inputs": { "a": sub_query("nope", &json!(1)) },
+        "expression": { "input": "a" }
+    }));
+    assert!(provider.query(&missing, &sample_context()).is_err());
+
+    let unknown_ref = compute(json!({
+        "inputs": { "a": sub_query("echo", &json!(1)) },
+        "expression": { "input": "b" }
+    }));
+    assert!(provider.query(&unknown_ref, &sample_context()).is_err());
+
+    let divide_by_zero = compute(json!({
+        "inputs": { "a": sub_query("echo", &json!(1)) },
+        "expression": { "div": [{ "input": "a" }, { "literal": 0 }] }
+    }));
+    assert!(provider.query(&divide_by_zero, &sample_context()).is_err());
+
+    let not_numeric = compute(json!({
+        "inputs": { "a": sub_query("echo", &json!("ten")) },
+        "expression": { "gt": [{ "input": "a" }, { "literal": 1 }] }
+    }));
+    assert!(provider.query(&not_numeric, &sample_context()).is_err());
+}
+
+// ============================================================================
+// SECTION: Boundary Enforcement Tests
+// ============================================================================
+
+/// Tests that oversized expressions are rejected before sub-queries run.
+#[test]
+fn composite_enforces_expression_bounds() {
+    let provider = composite(CompositeProviderConfig {
+        max_expression_depth: 3,
+        ..CompositeProviderConfig::default()
+    });
+    let mut expression = json!({ "input": "a" });
+    for _ in 0 .. 3 {
+        expression = json!({ "not": expression });
+    }
+    let query = compute(json!({
+        "inputs": { "a": sub_query("broken", &json!(true)) },
+        "expression": expression
+    }));
+    let err = provider.query(&query, &sample_context()).unwrap_err();
+    assert!(err.to_string().contains("depth limit"), "{err}");
+}
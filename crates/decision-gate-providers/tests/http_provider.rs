@@ -9,9 +9,12 @@
 //! ## Overview
 //! Tests the HTTP provider for:
 //! - Happy path: Status and `body_hash` checks
+//! - JSON POST queries: body template substitution and `JSONPath` extraction
 //! - Boundary enforcement: HTTPS-only, host allowlist, response size limits
 //! - Error handling: Invalid URLs, connection failures, unsupported schemes
 //! - Adversarial: SSRF prevention (internal IP blocking)
+//! - Retries: transient GET failures back off and retry within a deadline
+//! - Caching: opt-in `ETag` revalidation serves cached bodies on 304
 //!
 //! Security posture: Network is adversary-controlled. HTTPS is required by
 //! default, and host allowlists prevent SSRF attacks.
@@ -32,13 +35,20 @@
 
 mod common;
 
+use std::collections::BTreeMap;
 use std::collections::BTreeSet;
+use std::sync::Arc;
+use std::sync::mpsc;
 use std::thread;
+use std::time::Duration;
+use std::time::Instant;
 
+use decision_gate_core::EvidenceError;
 use decision_gate_core::EvidenceProvider;
 use decision_gate_core::EvidenceQuery;
 use decision_gate_core::EvidenceValue;
 use decision_gate_core::ProviderId;
+use decision_gate_core::runtime::ManualClock;
 use decision_gate_providers::HttpProvider;
 use decision_gate_providers::HttpProviderConfig;
 use serde_json::Value;
@@ -679,3 +689,813 @@ fn http_url_with_query_string() {
 
     handle.join().unwrap();
 }
+
+// ============================================================================
+// SECTION: JSON POST Query Tests
+// ============================================================================
+
+/// Request captured by [`spawn_capture_server`].
+struct CapturedRequest {
+    method: String,
+    content_type: Option<String>,
+    body: Value,
+}
+
+/// Spawns a server that records the request and responds with a JSON body.
+fn spawn_capture_server(
+    body: Value,
+    status: u16,
+) -> (String, mpsc::Receiver<CapturedRequest>, thread::JoinHandle<()>) {
+    let server = Server::http("127.0.0.1:0").unwrap();
+    let addr = server.server_addr().to_ip().unwrap();
+    let url = format!("http://{addr}/graphql");
+    let (sender, receiver) = mpsc::channel();
+
+    let handle = thread::spawn(move || {
+        if let Ok(mut request) = server.recv() {
+            let mut raw = String::new();
+            request.as_reader().read_to_string(&mut raw).unwrap();
+            let content_type = request
+                .headers()
+                .iter()
+                .find(|header| header.field.equiv("Content-Type"))
+                .map(|header| header.value.to_string());
+            sender
+                .send(CapturedRequest {
+                    method: request.method().to_string(),
+                    content_type,
+                    body: serde_json::from_str(&raw).unwrap(),
+                })
+                .unwrap();
+            let response = Response::from_string(body.to_string())
+                .with_status_code(status)
+                .with_header(Header::from_bytes("Content-Type", "application/json").unwrap());
+            let _ = request.respond(response);
+        }
+    });
+
+    (url, receiver, handle)
+}
+
+/// Creates a local provider with the given query templates.
+fn query_provider(templates: Vec<(&str, Value)>) -> HttpProvider {
+    let mut allowed_hosts = BTreeSet::new();
+    allowed_hosts.insert("127.0.0.1".to_string());
+    let query_templates: BTreeMap<String, Value> =
+        templates.into_iter().map(|(name, template)| (name.to_string(), template)).collect();
+    HttpProvider::new(HttpProviderConfig {
+        allow_http: true,
+        allowed_hosts: Some(allowed_hosts),
+        query_templates,
+        ..HttpProviderConfig::default()
+    })
+    .unwrap()
+}
+
+fn json_query(params: Value) -> EvidenceQuery {
+    EvidenceQuery {
+        provider_id: ProviderId::new("http"),
+        check_id: "json_query".to_string(),
+        params: Some(params),
+    }
+}
+
+/// Tests a POST query returns the `JSONPath`-selected value from the response.
+#[test]
+fn http_json_query_posts_and_extracts_evidence() {
+    let response = json!({"data": {"repository": {"status": "green", "checks": 12}}});
+    let (url, captured, handle) = spawn_capture_server(response, 200);
+    let provider = query_provider(vec![(
+        "repo_status",
+        json!({"query": "query { repository { status checks } }"}),
+    )]);
+
+    let result = provider
+        .query(
+            &json_query(json!({
+                "url": url,
+                "template": "repo_status",
+                "jsonpath": "$.data.repository.status"
+            })),
+            &sample_context(),
+        )
+        .unwrap();
+
+    assert_eq!(result.value, Some(EvidenceValue::Json(json!("green"))));
+    assert!(result.error.is_none());
+    assert_eq!(result.evidence_anchor.unwrap().anchor_value, url);
+    let request = captured.recv().unwrap();
+    assert_eq!(request.method, "POST");
+    assert_eq!(request.content_type.as_deref(), Some("application/json"));
+    assert_eq!(request.body, json!({"query": "query { repository { status checks } }"}));
+    handle.join().unwrap();
+}
+
+/// Tests body template placeholders are substituted from check params.
+#[test]
+fn http_json_query_substitutes_template_variables() {
+    let (url, captured, handle) = spawn_capture_server(json!({"hits": {"total": 3}}), 200);
+    let provider = query_provider(vec![(
+        "search",
+        json!({
+            "query": "service:{{service}} AND env:{{env}}",
+            "filters": {"min_severity": "{{severity}}", "tags": ["{{service}}", "static"]},
+            "size": 0
+        }),
+    )]);
+
+    let result = provider
+        .query(
+            &json_query(json!({
+                "url": url,
+                "template": "search",
+                "variables": {"service": "billing", "env": "prod", "severity": 4},
+                "jsonpath": "$.hits.total"
+            })),
+            &sample_context(),
+        )
+        .unwrap();
+
+    assert_eq!(result.value, Some(EvidenceValue::Json(json!(3))));
+    let request = captured.recv().unwrap();
+    assert_eq!(
+        request.body,
+        json!({
+            "query": "service:billing AND env:prod",
+            "filters": {"min_severity": 4, "tags": ["billing", "static"]},
+            "size": 0
+        })
+    );
+    handle.join().unwrap();
+}
+
+/// Tests a `JSONPath` miss returns `jsonpath_not_found` error metadata.
+#[test]
+fn http_json_query_reports_jsonpath_miss() {
+    let (url, _captured, handle) = spawn_capture_server(json!({"data": {}}), 200);
+    let provider = query_provider(vec![("probe", json!({"query": "{ probe }"}))]);
+
+    let result = provider
+        .query(
+            &json_query(json!({"url": url, "template": "probe", "jsonpath": "$.data.missing"})),
+            &sample_context(),
+        )
+        .unwrap();
+
+    assert!(result.value.is_none());
+    assert_eq!(result.error.unwrap().code, "jsonpath_not_found");
+    handle.join().unwrap();
+}
+
+/// Tests unknown templates, missing variables, and error statuses fail closed.
+#[test]
+fn http_json_query_rejects_invalid_requests() {
+    let provider = query_provider(vec![("probe", json!({"id": "{{id}}"}))]);
+
+    let unknown = provider.query(
+        &json_query(json!({"url": "http://127.0.0.1:9/", "template": "other"})),
+        &sample_context(),
+    );
+    assert!(unknown.unwrap_err().to_string().contains("unknown query template"));
+
+    let missing = provider.query(
+        &json_query(json!({"url": "http://127.0.0.1:9/", "template": "probe"})),
+        &sample_context(),
+    );
+    assert!(missing.unwrap_err().to_string().contains("missing template variable: id"));
+
+    let blocked = provider.query(
+        &json_query(json!({"url": "http://example.com/", "template": "probe"})),
+        &sample_context(),
+    );
+    assert!(blocked.unwrap_err().to_string().contains("url host not allowed"));
+
+    let (url, _captured, handle) = spawn_capture_server(json!({"errors": ["boom"]}), 500);
+    let failed = provider.query(
+        &json_query(json!({"url": url, "template": "probe", "variables": {"id": 1}})),
+        &sample_context(),
+    );
+    assert!(failed.unwrap_err().to_string().contains("status 500"));
+    handle.join().unwrap();
+}
+
+// ============================================================================
+// SECTION: Paginated JSON Tests
+// ============================================================================
+
+/// Serves `pages` in order, one response per request, and reports request URLs.
+fn spawn_pages_server(
+    pages: Vec<(Option<&'static str>, Value)>,
+) -> (String, mpsc::Receiver<String>, thread::JoinHandle<()>) {
+    let server = Server::http("127.0.0.1:0").unwrap();
+    let addr = server.server_addr().to_ip().unwrap();
+    let url = format!("http://{addr}/items");
+    let (sender, receiver) = mpsc::channel();
+
+    let handle = thread::spawn(move || {
+        for (link, body) in pages {
+            let Ok(request) = server.recv() else {
+                return;
+            };
+            sender.send(request.url().to_string()).unwrap();
+            let mut response = Response::from_string(body.to_string())
+                .with_header(Header::from_bytes("Content-Type", "application/json").unwrap());
+            if let Some(link) = link {
+                response = response.with_header(Header::from_bytes("Link", link).unwrap());
+            }
+            let _ = request.respond(response);
+        }
+    });
+
+    (url, receiver, handle)
+}
+
+/// Creates a local provider with the given page cap.
+fn pages_provider(max_pages: usize) -> HttpProvider {
+    let mut allowed_hosts = BTreeSet::new();
+    allowed_hosts.insert("127.0.0.1".to_string());
+    HttpProvider::new(HttpProviderConfig {
+        allow_http: true,
+        allowed_hosts: Some(allowed_hosts),
+        max_pages,
+        ..HttpProviderConfig::default()
+    })
+    .unwrap()
+}
+
+/// Builds a `json_pages` query with the given params.
+fn json_pages(params: Value) -> EvidenceQuery {
+    EvidenceQuery {
+        provider_id: ProviderId::new("http"),
+        check_id: "json_pages".to_string(),
+        params: Some(params),
+    }
+}
+
+/// Tests cursor pagination aggregates items from two pages in page order.
+#[test]
+fn http_json_pages_aggregates_cursor_pages() {
+    let (url, requests, handle) = spawn_pages_server(vec![
+        (None, json!({"items": [{"id": 1}, {"id": 2}], "next": "abc"})),
+        (None, json!({"items": [{"id": 3}], "next": null})),
+    ]);
+    let provider = pages_provider(10);
+
+    let result = provider
+        .query(
+            &json_pages(json!({
+                "url": url,
+                "items": "$.items[*]",
+                "next": {"type": "cursor", "jsonpath": "$.next", "param": "cursor"}
+            })),
+            &sample_context(),
+        )
+        .unwrap();
+
+    assert!(result.error.is_none());
+    assert_eq!(result.value, Some(EvidenceValue::Json(json!([{"id": 1}, {"id": 2}, {"id": 3}]))));
+    assert_eq!(result.evidence_ref.unwrap().uri, url);
+    assert_eq!(requests.recv().unwrap(), "/items");
+    assert_eq!(requests.recv().unwrap(), "/items?cursor=abc");
+    handle.join().unwrap();
+}
+
+/// Tests `Link` header pagination resolves relative targets and counts items.
+#[test]
+fn http_json_pages_follows_link_header() {
+    let (url, requests, handle) = spawn_pages_server(vec![
+        (Some("</items?page=2>; rel=\"next\""), json!({"items": [1, 2]})),
+        (Some("</items?page=1>; rel=\"prev\""), json!({"items": [3]})),
+    ]);
+    let provider = pages_provider(10);
+
+    let result = provider
+        .query(
+            &json_pages(json!({
+                "url": url,
+                "items": "$.items[*]",
+                "next": {"type": "link_header"},
+                "aggregate": "count"
+            })),
+            &sample_context(),
+        )
+        .unwrap();
+
+    assert_eq!(result.value, Some(EvidenceValue::Json(json!(3))));
+    assert_eq!(requests.recv().unwrap(), "/items");
+    assert_eq!(requests.recv().unwrap(), "/items?page=2");
+    handle.join().unwrap();
+}
+
+/// Tests the page cap stops early with a limit error instead of a partial value.
+#[test]
+fn http_json_pages_stops_at_page_cap() {
+    let (url, requests, handle) =
+        spawn_pages_server(vec![(None, json!({"items": [1, 2], "next": "abc"}))]);
+    let provider = pages_provider(1);
+
+    let result = provider
+        .query(
+            &json_pages(json!({
+                "url": url,
+                "items": "$.items[*]",
+                "next": {"type": "cursor", "jsonpath": "$.next", "param": "cursor"}
+            })),
+            &sample_context(),
+        )
+        .unwrap();
+
+    assert!(result.value.is_none());
+    let error = result.error.unwrap();
+    assert_eq!(error.code, "pagination_limit_reached");
+    let details = error.details.unwrap();
+    assert_eq!(details["limit"], json!("max_pages"));
+    assert_eq!(details["pages"], json!(1));
+    handle.join().unwrap();
+    assert_eq!(requests.try_iter().count(), 1);
+}
+
+/// Tests malformed pagination params are rejected.
+#[test]
+fn http_json_pages_rejects_invalid_params() {
+    let provider = pages_provider(10);
+
+    let missing_next = provider.query(
+        &json_pages(json!({"url": "http://127.0.0.1:9/", "items": "$[*]"})),
+        &sample_context(),
+    );
+    assert!(missing_next.unwrap_err().to_string().contains("next param must be an object"));
+
+    let bad_kind = provider.query(
+        &json_pages(json!({
+            "url": "http://127.0.0.1:9/",
+            "items": "$[*]",
+            "next": {"type": "offset"}
+        })),
+        &sample_context(),
+    );
+    assert!(bad_kind.unwrap_err().to_string().contains("cursor or link_header"));
+}
+
+// ============================================================================
+// SECTION: Response Transform Tests
+// ============================================================================
+
+/// Creates a local provider with a `probe` query template and the given transform.
+fn transform_provider(transform: Value) -> Result<HttpProvider, EvidenceError> {
+    let mut allowed_hosts = BTreeSet::new();
+    allowed_hosts.insert("127.0.0.1".to_string());
+    let mut query_templates = BTreeMap::new();
+    query_templates.insert("probe".to_string(), json!({"query": "{ repository { status } }"}));
+    let mut response_transforms = BTreeMap::new();
+    response_transforms.insert("summary".to_string(), transform);
+    HttpProvider::new(HttpProviderConfig {
+        allow_http: true,
+        allowed_hosts: Some(allowed_hosts),
+        query_templates,
+        response_transforms,
+        ..HttpProviderConfig::default()
+    })
+}
+
+/// Tests a transform flattens nested response fields into the evidence shape.
+#[test]
+fn http_transform_flattens_nested_fields() {
+    let (url, _captured, handle) = spawn_capture_server(
+        json!({"data": {"repository": {"name": "dg", "status": {"state": "green"}}}}),
+        200,
+    );
+    let provider = transform_provider(json!({
+        "name": "$.data.repository.name",
+        "state": "$.data.repository.status.state",
+        "source": "$$graphql"
+    }))
+    .unwrap();
+
+    let result = provider
+        .query(
+            &json_query(json!({"url": url, "template": "probe", "transform": "summary"})),
+            &sample_context(),
+        )
+        .unwrap();
+
+    assert!(result.error.is_none());
+    assert_eq!(
+        result.value,
+        Some(EvidenceValue::Json(json!({"name": "dg", "state": "green", "source": "$graphql"})))
+    );
+    handle.join().unwrap();
+}
+
+/// Tests a transform selector miss fails closed with no value.
+#[test]
+fn http_transform_error_fails_closed() {
+    let (url, _captured, handle) =
+        spawn_capture_server(json!({"data": {"repository": {"name": "dg"}}}), 200);
+    let provider = transform_provider(json!({"state": "$.data.repository.status"})).unwrap();
+
+    let result = provider
+        .query(
+            &json_query(json!({"url": url, "template": "probe", "transform": "summary"})),
+            &sample_context(),
+        )
+        .unwrap();
+
+    assert!(result.value.is_none());
+    let error = result.error.unwrap();
+    assert_eq!(error.code, "transform_failed");
+    assert!(error.message.contains("$.data.repository.status"));
+    handle.join().unwrap();
+}
+
+/// Tests invalid transforms and unknown transform names are rejected.
+#[test]
+fn http_transform_rejects_invalid_config_and_params() {
+    let invalid = transform_provider(json!({"state": "$[?("}));
+    assert!(invalid.err().unwrap().to_string().contains("invalid response transform summary"));
+
+    let mut nested = json!("$.value");
+    for _ in 0 .. 20 {
+        nested = json!({ "inner": nested });
+    }
+    assert!(transform_provider(nested).is_err());
+
+    let provider = transform_provider(json!({"state": "$.state"})).unwrap();
+    let unknown = provider.query(
+        &json_query(
+            json!({"url": "http://127.0.0.1:9/", "template": "probe", "transform": "other"}),
+        ),
+        &sample_context(),
+    );
+    assert!(unknown.unwrap_err().to_string().contains("unknown response transform"));
+}
+
+// ============================================================================
+// SECTION: Retry Tests
+// ============================================================================
+
+/// Spawns a server that answers with each `(status, retry_after)` in turn.
+///
+/// The handle yields the number of requests served; the server stops after
+/// the script ends or once no request arrives for 1.5s.
+fn spawn_script_server(
+    script: Vec<(u16, Option<&'static str>)>,
+) -> (String, thread::JoinHandle<usize>) {
+    let server = Server::http("127.0.0.1:0").unwrap();
+    let addr = server.server_addr().to_ip().unwrap();
+    let url = format!("http://{addr}/health");
+
+    let handle = thread::spawn(move || {
+        let mut requests = 0;
+        for (status, retry_after) in script {
+            let Ok(Some(request)) = server.recv_timeout(Duration::from_millis(1_500)) else {
+                break;
+            };
+            let mut response = Response::from_string("{}").with_status_code(status);
+            if let Some(value) = retry_after {
+                response.add_header(Header::from_bytes("Retry-After", value).unwrap());
+            }
+            let _ = request.respond(response);
+            requests += 1;
+        }
+        requests
+    });
+
+    (url, handle)
+}
+
+/// Creates a local provider that retries GET checks with a 1ms base delay.
+fn retry_provider(max_retries: u32, retry_deadline_ms: u64) -> HttpProvider {
+    let mut allowed_hosts = BTreeSet::new();
+    allowed_hosts.insert("127.0.0.1".to_string());
+    HttpProvider::new(HttpProviderConfig {
+        allow_http: true,
+        allowed_hosts: Some(allowed_hosts),
+        timeout_ms: 1_000,
+        query_templates: BTreeMap::from([("probe".to_string(), json!({}))]),
+        max_retries,
+        base_delay_ms: 1,
+        max_delay_ms: 5,
+        retry_deadline_ms,
+        ..HttpProviderConfig::default()
+    })
+    .unwrap()
+}
+
+/// Runs a `status` check and returns the reported status code.
+fn query_status(provider: &HttpProvider, url: &str) -> u64 {
+    let query = EvidenceQuery {
+        provider_id: ProviderId::new("http"),
+        check_id: "status".to_string(),
+        params: Some(json!({"url": url})),
+    };
+    let result = provider.query(&query, &sample_context()).unwrap();
+    let Some(EvidenceValue::Json(Value::Number(number))) = result.value else {
+        panic!("expected numeric evidence");
+    };
+    number.as_u64().unwrap()
+}
+
+/// Tests a GET that fails twice with 5xx is retried until it succeeds.
+#[test]
+fn http_retry_recovers_after_transient_failures() {
+    let (url, handle) = spawn_script_server(vec![(503, None), (500, None), (200, None)]);
+    let provider = retry_provider(3, 10_000);
+
+    assert_eq!(query_status(&provider, &url), 200);
+    assert_eq!(handle.join().unwrap(), 3);
+}
+
+/// Tests client errors are returned without retrying.
+#[test]
+fn http_retry_skips_client_errors() {
+    let (url, handle) = spawn_script_server(vec![(400, None), (200, None)]);
+    let provider = retry_provider(3, 10_000);
+
+    assert_eq!(query_status(&provider, &url), 400);
+    assert_eq!(handle.join().unwrap(), 1);
+}
+
+/// Tests the final transient response is returned once retries run out.
+#[test]
+fn http_retry_stops_after_max_retries() {
+    let (url, handle) = spawn_script_server(vec![(503, None), (503, None), (200, None)]);
+    let provider = retry_provider(1, 10_000);
+
+    assert_eq!(query_status(&provider, &url), 503);
+    assert_eq!(handle.join().unwrap(), 2);
+}
+
+/// Tests a 429 waits for its `Retry-After` before retrying.
+#[test]
+fn http_retry_honors_retry_after() {
+    let (url, handle) = spawn_script_server(vec![(429, Some("1")), (200, None)]);
+    let provider = retry_provider(2, 10_000);
+
+    let started = Instant::now();
+    assert_eq!(query_status(&provider, &url), 200);
+    assert!(started.elapsed() >= Duration::from_secs(1), "expected Retry-After wait");
+    assert_eq!(handle.join().unwrap(), 2);
+}
+
+/// Tests a retry that would overrun the deadline is not attempted.
+#[test]
+fn http_retry_respects_deadline() {
+    let (url, handle) = spawn_script_server(vec![(503, Some("30")), (200, None)]);
+    let provider = retry_provider(3, 2_000);
+
+    let started = Instant::now();
+    assert_eq!(query_status(&provider, &url), 503);
+    assert!(started.elapsed() < Duration::from_secs(2), "expected no wait past the deadline");
+    assert_eq!(handle.join().unwrap(), 1);
+}
+
+/// Tests a `Retry-After` date is measured from the injected clock.
+///
+/// The clock is pinned to 2023-11-14T22:13:20Z, so a date one hour later
+/// overruns the deadline; the system clock would treat it as past and retry.
+#[test]
+fn http_retry_after_date_uses_injected_clock() {
+    let (url, handle) =
+        spawn_script_server(vec![(503, Some("Tue, 14 Nov 2023 23:13:20 +0000")), (200, None)]);
+    let provider = retry_provider(3, 10_000).with_clock(Arc::new(ManualClock::new(1_700_000_000)));
+
+    assert_eq!(query_status(&provider, &url), 503);
+    assert_eq!(handle.join().unwrap(), 1);
+}
+
+/// Tests `json_query` POSTs are never retried.
+#[test]
+fn http_retry_skips_json_query_posts() {
+    let (url, handle) = spawn_script_server(vec![(503, None), (200, None)]);
+    let provider = retry_provider(3, 10_000);
+
+    let result =
+        provider.query(&json_query(json!({"url": url, "template": "probe"})), &sample_context());
+    assert!(result.unwrap_err().to_string().contains("status 503"));
+    assert_eq!(handle.join().unwrap(), 1);
+}
+
+/// Tests a base delay above the delay cap is rejected.
+#[test]
+fn http_retry_rejects_inverted_delays() {
+    let result = HttpProvider::new(HttpProviderConfig {
+        base_delay_ms: 500,
+        max_delay_ms: 100,
+        ..HttpProviderConfig::default()
+    });
+    assert!(result.err().unwrap().to_string().contains("base_delay_ms"));
+}
+
+// ============================================================================
+// SECTION: Conditional Cache Tests
+// ============================================================================
+
+/// Scripted reply: status, `ETag`, and body.
+type CacheReply = (u16, &'static str, &'static str);
+
+/// Request path and `If-None-Match` value seen by [`spawn_etag_server`].
+type SeenRequest = (String, Option<String>);
+
+/// Spawns a server that answers each request with the next scripted reply.
+///
+/// The handle yields `(path, If-None-Match)` for every request received.
+fn spawn_etag_server(script: Vec<CacheReply>) -> (String, thread::JoinHandle<Vec<SeenRequest>>) {
+    let server = Server::http("127.0.0.1:0").unwrap();
+    let addr = server.server_addr().to_ip().unwrap();
+    let url = format!("http://{addr}");
+
+    let handle = thread::spawn(move || {
+        let mut seen = Vec::new();
+        for (status, etag, body) in script {
+            let Ok(Some(request)) = server.recv_timeout(Duration::from_millis(1_500)) else {
+                break;
+            };
+            let if_none_match = request
+                .headers()
+                .iter()
+                .find(|header| header.field.equiv("If-None-Match"))
+                .map(|header| header.value.to_string());
+            seen.push((request.url().to_string(), if_none_match));
+            let response = Response::from_string(body)
+                .with_status_code(status)
+                .with_header(Header::from_bytes("ETag", etag).unwrap());
+            let _ = request.respond(response);
+        }
+        seen
+    });
+
+    (url, handle)
+}
+
+/// Creates a local provider caching up to `entries` responses for `ttl_ms`.
+fn cache_provider(entries: usize, ttl_ms: u64) -> HttpProvider {
+    let mut allowed_hosts = BTreeSet::new();
+    allowed_hosts.insert("127.0.0.1".to_string());
+    HttpProvider::new(HttpProviderConfig {
+        allow_http: true,
+        allowed_hosts: Some(allowed_hosts),
+        cache_max_entries: entries,
+        cache_ttl_ms: ttl_ms,
+        ..HttpProviderConfig::default()
+    })
+    .unwrap()
+}
+
+/// Runs a `body_hash` check and returns the hash evidence.
+fn query_body_hash(provider: &HttpProvider, url: &str) -> Value {
+    let query = EvidenceQuery {
+        provider_id: ProviderId::new("http"),
+        check_id: "body_hash".to_string(),
+        params: Some(json!({"url": url})),
+    };
+    let Some(EvidenceValue::Json(hash)) = provider.query(&query, &sample_context()).unwrap().value
+    else {
+        panic!("expected hash evidence");
+    };
+    hash
+}
+
+/// Tests a 304 revalidation serves the cached body.
+#[test]
+fn http_cache_serves_cached_body_on_not_modified() {
+    let (url, handle) = spawn_etag_server(vec![(200, "\"v1\"", "alpha"), (304, "\"v1\"", "")]);
+    let provider = cache_provider(8, 60_000);
+
+    let first = query_body_hash(&provider, &url);
+    let second = query_body_hash(&provider, &url);
+
+    assert_eq!(first, second);
+    let seen = handle.join().unwrap();
+    assert_eq!(seen[0].1, None);
+    assert_eq!(seen[1].1.as_deref(), Some("\"v1\""));
+}
+
+/// Tests a changed `ETag` replaces the cached body.
+#[test]
+fn http_cache_refetches_on_changed_etag() {
+    let (url, handle) = spawn_etag_server(vec![
+        (200, "\"v1\"", "alpha"),
+        (200, "\"v2\"", "beta"),
+        (304, "\"v2\"", ""),
+    ]);
+    let provider = cache_provider(8, 60_000);
+
+    let first = query_body_hash(&provider, &url);
+    let second = query_body_hash(&provider, &url);
+    let third = query_body_hash(&provider, &url);
+
+    assert_ne!(first, second);
+    assert_eq!(second, third);
+    let validators: Vec<_> = handle.join().unwrap().into_iter().map(|(_, etag)| etag).collect();
+    assert_eq!(validators, vec![None, Some("\"v1\"".to_string()), Some("\"v2\"".to_string())]);
+}
+
+/// Tests caching is off by default and expired entries are not revalidated.
+#[test]
+fn http_cache_disabled_or_expired_sends_unconditional_requests() {
+    for provider in [local_provider(), cache_provider(8, 0)] {
+        let (url, handle) =
+            spawn_etag_server(vec![(200, "\"v1\"", "alpha"), (200, "\"v1\"", "alpha")]);
+        query_body_hash(&provider, &url);
+        query_body_hash(&provider, &url);
+        assert!(handle.join().unwrap().iter().all(|(_, etag)| etag.is_none()));
+    }
+}
+
+/// Tests entries expire by the injected clock rather than wall time.
+#[test]
+fn http_cache_expires_entries_on_injected_clock() {
+    let (url, handle) = spawn_etag_server(vec![
+        (200, "\"v1\"", "alpha"),
+        (304, "\"v1\"", ""),
+        (200, "\"v1\"", "alpha"),
+    ]);
+    let clock = Arc::new(ManualClock::new(0));
+    let provider = cache_provider(8, 1_000).with_clock(clock.clone());
+
+    query_body_hash(&provider, &url);
+    clock.advance(Duration::from_millis(999));
+    query_body_hash(&provider, &url);
+    clock.advance(Duration::from_millis(1_000));
+    query_body_hash(&provider, &url);
+
+    let validators: Vec<_> = handle.join().unwrap().into_iter().map(|(_, etag)| etag).collect();
+    assert_eq!(validators, vec![None, Some("\"v1\"".to_string()), None]);
+}
+
+/// Tests the least recently used entry is evicted at capacity.
+#[test]
+fn http_cache_evicts_least_recently_used() {
+    let (url, handle) = spawn_etag_server(vec![
+        (200, "\"a\"", "alpha"),
+        (200, "\"b\"", "beta"),
+        (200, "\"a\"", "alpha"),
+    ]);
+    let provider = cache_provider(1, 60_000);
+
+    query_body_hash(&provider, &format!("{url}/a"));
+    query_body_hash(&provider, &format!("{url}/b"));
+    query_body_hash(&provider, &format!("{url}/a"));
+
+    let seen = handle.join().unwrap();
+    assert_eq!(seen[2], ("/a".to_string(), None));
+}
+
+/// Tests a 304 for an entry evicted mid-request refetches the body.
+///
+/// The server holds the conditional request for `/a` until a store for `/b`
+/// has evicted `/a`, then answers `304`; the provider must not hash the empty
+/// `304` body.
+#[test]
+fn http_cache_refetches_when_entry_evicted_before_not_modified() {
+    let server = Server::http("127.0.0.1:0").unwrap();
+    let url = format!("http://{}", server.server_addr().to_ip().unwrap());
+    let (held_tx, held_rx) = mpsc::channel();
+    let (release_tx, release_rx) = mpsc::channel::<()>();
+    let handle = thread::spawn(move || {
+        let mut seen = Vec::new();
+        let mut held = None;
+        while let Ok(Some(request)) = server.recv_timeout(Duration::from_millis(1_500)) {
+            let path = request.url().to_string();
+            let if_none_match = request
+                .headers()
+                .iter()
+                .find(|header| header.field.equiv("If-None-Match"))
+                .map(|header| header.value.to_string());
+            seen.push((path.clone(), if_none_match.clone()));
+            if if_none_match.is_some() {
+                held = Some(request);
+                held_tx.send(()).unwrap();
+                continue;
+            }
+            let (etag, body) = if path == "/b" { ("\"b\"", "beta") } else { ("\"a\"", "alpha") };
+            let response = Response::from_string(body)
+                .with_header(Header::from_bytes("ETag", etag).unwrap());
+            let _ = request.respond(response);
+            if path == "/b" {
+                release_rx.recv().unwrap();
+                let not_modified = Response::from_string("")
+                    .with_status_code(304)
+                    .with_header(Header::from_bytes("ETag", "\"a\"").unwrap());
+                let _ = held.take().unwrap().respond(not_modified);
+            }
+        }
+        seen
+    });
+    let provider = Arc::new(cache_provider(1, 60_000));
+    let (a, b) = (format!("{url}/a"), format!("{url}/b"));
+
+    let expected = query_body_hash(&provider, &a);
+    let revalidating = {
+        let provider = Arc::clone(&provider);
+        thread::spawn(move || query_body_hash(&provider, &a))
+    };
+    held_rx.recv_timeout(Duration::from_secs(5)).unwrap();
+    query_body_hash(&provider, &b);
+    release_tx.send(()).unwrap();
+
+    assert_eq!(revalidating.join().unwrap(), expected);
+    let seen = handle.join().unwrap();
+    assert_eq!(seen.last(), Some(&("/a".to_string(), None)));
+}
@@ -60,6 +60,7 @@ fn provider_with_root(root: &Path) -> JsonProvider {
         root_id: "test-root".to_string(),
         max_bytes: 1024 * 1024,
         allow_yaml: true,
+        ..JsonProviderConfig::default()
     })
     .expect("json provider config should be valid")
 }
@@ -70,6 +71,7 @@ fn provider_with_limits(root: &Path, max_bytes: usize, allow_yaml: bool) -> Json
         root_id: "test-root".to_string(),
         max_bytes,
         allow_yaml,
+        ..JsonProviderConfig::default()
     })
     .expect("json provider config should be valid")
 }
@@ -260,6 +262,7 @@ fn json_provider_rejects_invalid_root_id() {
         root_id: "BadRoot".to_string(),
         max_bytes: 1024,
         allow_yaml: true,
+        ..JsonProviderConfig::default()
     };
     let Err(err) = JsonProvider::new(bad_config) else {
         panic!("expected invalid root_id");
@@ -277,6 +280,7 @@ fn json_provider_rejects_root_id_too_long() {
         root_id: "a".repeat(65),
         max_bytes: 1024,
         allow_yaml: true,
+        ..JsonProviderConfig::default()
     };
     let Err(err) = JsonProvider::new(bad_config) else {
         panic!("expected long root_id error");
@@ -545,6 +549,125 @@ fn json_invalid_jsonpath_rejected() {
     assert_eq!(error.code, "invalid_jsonpath");
 }
 
+// ============================================================================
+// SECTION: JSONPath Filters and Node Budget
+// ============================================================================
+
+fn provider_with_jsonpath_budget(root: &Path, max_jsonpath_nodes: usize) -> JsonProvider {
+    JsonProvider::new(JsonProviderConfig {
+        root: root.to_path_buf(),
+        root_id: "test-root".to_string(),
+        max_jsonpath_nodes,
+        ..JsonProviderConfig::default()
+    })
+    .expect("json provider config should be valid")
+}
+
+fn jsonpath_query(file: &str, jsonpath: &str) -> EvidenceQuery {
+    EvidenceQuery {
+        provider_id: ProviderId::new("json"),
+        check_id: "path".to_string(),
+        params: Some(json!({"file": file, "jsonpath": jsonpath})),
+    }
+}
+
+/// Tests that filter expressions select matching values.
+#[test]
+fn json_jsonpath_filter_expression_selects_match() {
+    let dir = tempdir().unwrap();
+    fs::write(
+        dir.path().join("flags.json"),
+        r#"{"features":[{"name":"alpha","enabled":false},{"name":"beta","enabled":true}]}"#,
+    )
+    .unwrap();
+
+    let provider = provider_with_root(dir.path());
+    let query = jsonpath_query("flags.json", "$.features[?(@.name=='beta')].enabled");
+    let result = provider.query(&query, &sample_context()).unwrap();
+
+    assert!(result.error.is_none(), "unexpected error: {:?}", result.error);
+    assert_eq!(result.value, Some(EvidenceValue::Json(json!(true))));
+}
+
+/// Tests that a non-matching filter returns an explicit empty result.
+#[test]
+fn json_jsonpath_filter_no_match_returns_explicit_empty() {
+    let dir = tempdir().unwrap();
+    fs::write(dir.path().join("flags.json"), r#"{"features":[{"name":"alpha","enabled":false}]}"#)
+        .unwrap();
+
+    let provider = provider_with_root(dir.path());
+    let query = jsonpath_query("flags.json", "$.features[?(@.name=='gamma')].enabled");
+    let result = provider.query(&query, &sample_context()).unwrap();
+
+    assert!(result.value.is_none());
+    let error = result.error.expect("missing error");
+    assert_eq!(error.code, "jsonpath_not_found");
+}
+
+/// Tests that fan-out queries over large documents exceed the node budget.
+#[test]
+fn json_jsonpath_recursive_wildcard_exceeds_budget() {
+    let dir = tempdir().unwrap();
+    let items: Vec<_> = (0 .. 500).map(|id| json!({"id": id})).collect();
+    fs::write(dir.path().join("items.json"), json!({"items": items}).to_string()).unwrap();
+
+    let provider = provider_with_jsonpath_budget(dir.path(), 100);
+    let query = jsonpath_query("items.json", "$..*");
+    let result = provider.query(&query, &sample_context()).unwrap();
+
+    assert!(result.value.is_none());
+    let error = result.error.expect("missing error");
+    assert_eq!(error.code, "jsonpath_budget_exceeded");
+}
+
+/// Tests that direct paths are not charged against the node budget.
+#[test]
+fn json_jsonpath_direct_path_ignores_budget() {
+    let dir = tempdir().unwrap();
+    let items: Vec<_> = (0 .. 500).map(|id| json!({"id": id})).collect();
+    fs::write(dir.path().join("items.json"), json!({"items": items, "a..b*": 7}).to_string())
+        .unwrap();
+
+    let provider = provider_with_jsonpath_budget(dir.path(), 1);
+    let result =
+        provider.query(&jsonpath_query("items.json", "$.items[42].id"), &sample_context()).unwrap();
+    assert_eq!(result.value, Some(EvidenceValue::Json(json!(42))));
+
+    let quoted = jsonpath_query("items.json", "$['a..b*']");
+    let result = provider.query(&quoted, &sample_context()).unwrap();
+    assert_eq!(result.value, Some(EvidenceValue::Json(json!(7))));
+}
+
+/// Tests that recursive descent inside filter expressions is rejected.
+#[test]
+fn json_jsonpath_filter_descent_rejected() {
+    let dir = tempdir().unwrap();
+    fs::write(dir.path().join("data.json"), r#"{"items":[{"nested":{"x":1}}]}"#).unwrap();
+
+    let provider = provider_with_root(dir.path());
+    let query = jsonpath_query("data.json", "$.items[?(@..x == 1)]");
+    let result = provider.query(&query, &sample_context()).unwrap();
+
+    let error = result.error.expect("missing error");
+    assert_eq!(error.code, "jsonpath_budget_exceeded");
+}
+
+/// Tests that a zero node budget is rejected at construction.
+#[test]
+fn json_provider_rejects_zero_jsonpath_budget() {
+    let dir = tempdir().unwrap();
+    let Err(err) = JsonProvider::new(JsonProviderConfig {
+        root: dir.path().to_path_buf(),
+        root_id: "test-root".to_string(),
+        max_jsonpath_nodes: 0,
+        ..JsonProviderConfig::default()
+    }) else {
+        panic!("expected zero budget error");
+    };
+    assert!(format!("{err}").contains("max_jsonpath_nodes"), "unexpected error: {err}");
+}
+
 // ============================================================================
 // SECTION: Error Path Tests - Invalid Parameters
 // ============================================================================
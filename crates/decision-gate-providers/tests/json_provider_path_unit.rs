@@ -79,6 +79,7 @@ fn provider_with_root(root: &Path) -> JsonProvider {
         root_id: "path-unit".to_string(),
         max_bytes: 1024 * 1024,
         allow_yaml: true,
+        ..JsonProviderConfig::default()
     })
     .expect("json provider config should be valid")
 }
@@ -58,6 +58,7 @@ fn provider_with_root() -> (tempfile::TempDir, JsonProvider) {
         root_id: "proptest-root".to_string(),
         max_bytes: 1024 * 1024,
         allow_yaml: true,
+        ..JsonProviderConfig::default()
     })
     .expect("json provider config should be valid");
     (dir, provider)
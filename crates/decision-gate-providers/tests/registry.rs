@@ -28,8 +28,12 @@ mod common;
 
 use std::collections::BTreeSet;
 use std::sync::Arc;
+use std::sync::Condvar;
+use std::sync::Mutex;
 use std::sync::atomic::AtomicU32;
 use std::sync::atomic::Ordering;
+use std::time::Duration;
+use std::time::Instant;
 
 use decision_gate_core::EvidenceContext;
 use decision_gate_core::EvidenceError;
@@ -62,6 +66,7 @@ fn builtin_configs() -> (TempDir, BuiltinProviderConfigs) {
         root_id: "registry-root".to_string(),
         max_bytes: 1024 * 1024,
         allow_yaml: true,
+        ..JsonProviderConfig::default()
     };
     (dir, BuiltinProviderConfigs::new(json))
 }
@@ -165,6 +170,85 @@ impl EvidenceProvider for CountingProvider {
     }
 }
 
+/// Provider that counts warm-ups and optionally fails them.
+struct WarmUpProvider {
+    warm_ups: Arc<AtomicU32>,
+    fail: bool,
+}
+
+impl EvidenceProvider for WarmUpProvider {
+    fn query(
+        &self,
+        _query: &EvidenceQuery,
+        _ctx: &EvidenceContext,
+    ) -> Result<EvidenceResult, EvidenceError> {
+        Err(EvidenceError::Provider("not queried in warm-up tests".to_string()))
+    }
+
+    fn validate_providers(&self, _spec: &ScenarioSpec) -> Result<(), ProviderMissingError> {
+        Ok(())
+    }
+
+    fn warm_up(&self) -> Result<(), EvidenceError> {
+        self.warm_ups.fetch_add(1, Ordering::SeqCst);
+        if self.fail {
+            return Err(EvidenceError::Provider("connection refused".to_string()));
+        }
+        Ok(())
+    }
+}
+
+/// Provider that sleeps before answering, used to exercise query timeouts.
+struct SlowProvider {
+    delay: Duration,
+}
+
+impl EvidenceProvider for SlowProvider {
+    fn query(
+        &self,
+        query: &EvidenceQuery,
+        ctx: &EvidenceContext,
+    ) -> Result<EvidenceResult, EvidenceError> {
+        std::thread::sleep(self.delay);
+        ValueProvider::new("slow").query(query, ctx)
+    }
+
+    fn validate_providers(&self, _spec: &ScenarioSpec) -> Result<(), ProviderMissingError> {
+        Ok(())
+    }
+}
+
+/// Provider that blocks until its gate opens, used to exercise worker caps.
+struct GatedProvider {
+    gate: Arc<(Mutex<bool>, Condvar)>,
+}
+
+impl EvidenceProvider for GatedProvider {
+    fn query(
+        &self,
+        query: &EvidenceQuery,
+        ctx: &EvidenceContext,
+    ) -> Result<EvidenceResult, EvidenceError> {
+        let (open, opened) = &*self.gate;
+        let guard = open.lock().unwrap();
+        drop(opened.wait_while(guard, |open| !*open).unwrap());
+        ValueProvider::new("gated").query(query, ctx)
+    }
+
+    fn validate_providers(&self, _spec: &ScenarioSpec) -> Result<(), ProviderMissingError> {
+        Ok(())
+    }
+}
+
+/// Builds a query against the given provider.
+fn query_for(provider_id: &str) -> EvidenceQuery {
+    EvidenceQuery {
+        provider_id: ProviderId::new(provider_id),
+        check_id: "test".to_string(),
+        params: None,
+    }
+}
+
 /// Builds a spec that references a single provider.
 fn build_spec(provider_id: &str) -> ScenarioSpec {
     build_spec_with_conditions(&[(provider_id, "check")])
@@ -643,6 +727,174 @@ fn policy_accessor_returns_configured_policy() {
     assert!(registry.policy().denylist.contains("denied"));
 }
 
+// ============================================================================
+// SECTION: Timeout Tests
+// ============================================================================
+
+/// Verifies a short per-provider override times out before the global default.
+#[test]
+fn provider_timeout_override_times_out_before_default() {
+    let mut registry = ProviderRegistry::new(ProviderAccessPolicy::allow_all());
+    register_ok(
+        &mut registry,
+        "slow",
+        SlowProvider {
+            delay: Duration::from_millis(500),
+        },
+    );
+    registry.set_default_timeout(Some(Duration::from_secs(5)));
+    registry.set_provider_timeout("slow", Duration::from_millis(20));
+    assert_eq!(registry.timeout_for("slow"), Some(Duration::from_millis(20)));
+
+    let started = Instant::now();
+    let err = registry.query(&query_for("slow"), &sample_context()).expect_err("query times out");
+    assert!(started.elapsed() < Duration::from_millis(500), "override must preempt the delay");
+    let message = err.to_string();
+    assert!(message.contains("provider timed out after 20 ms: slow"), "{message}");
+}
+
+/// Verifies providers without an override use the global default timeout.
+#[test]
+fn provider_timeout_absent_override_uses_default() {
+    let mut registry = ProviderRegistry::new(ProviderAccessPolicy::allow_all());
+    register_ok(
+        &mut registry,
+        "slow",
+        SlowProvider {
+            delay: Duration::from_millis(500),
+        },
+    );
+    register_ok(
+        &mut registry,
+        "fast",
+        SlowProvider {
+            delay: Duration::ZERO,
+        },
+    );
+    registry.set_provider_timeout("fast", Duration::from_secs(5));
+    assert_eq!(registry.timeout_for("slow"), None, "no default configured yet");
+
+    registry.set_default_timeout(Some(Duration::from_millis(20)));
+    assert_eq!(registry.timeout_for("slow"), Some(Duration::from_millis(20)));
+    assert_eq!(registry.timeout_for("fast"), Some(Duration::from_secs(5)));
+
+    let err = registry.query(&query_for("slow"), &sample_context()).expect_err("default applies");
+    assert!(err.to_string().contains("timed out after 20 ms"), "{err}");
+    registry.query(&query_for("fast"), &sample_context()).expect("override allows fast query");
+}
+
+/// Verifies hung workers count against the per-provider cap until they finish.
+#[test]
+fn provider_worker_cap_fails_closed_until_hung_workers_finish() {
+    let gate = Arc::new((Mutex::new(false), Condvar::new()));
+    let mut registry = ProviderRegistry::new(ProviderAccessPolicy::allow_all());
+    register_ok(
+        &mut registry,
+        "hung",
+        GatedProvider {
+            gate: Arc::clone(&gate),
+        },
+    );
+    register_ok(
+        &mut registry,
+        "fast",
+        SlowProvider {
+            delay: Duration::ZERO,
+        },
+    );
+    registry.set_default_timeout(Some(Duration::from_millis(20)));
+    registry.set_max_workers_per_provider(2);
+
+    for _ in 0 .. 2 {
+        let err = registry.query(&query_for("hung"), &sample_context()).expect_err("times out");
+        assert!(err.to_string().contains("timed out after 20 ms"), "{err}");
+    }
+    let err = registry.query(&query_for("hung"), &sample_context()).expect_err("cap reached");
+    assert!(err.to_string().contains("provider worker limit reached: hung"), "{err}");
+    registry.query(&query_for("fast"), &sample_context()).expect("other providers unaffected");
+
+    let (open, opened) = &*gate;
+    *open.lock().unwrap() = true;
+    opened.notify_all();
+    let deadline = Instant::now() + Duration::from_secs(5);
+    while registry.query(&query_for("hung"), &sample_context()).is_err() {
+        assert!(Instant::now() < deadline, "finished workers must release their slots");
+        std::thread::sleep(Duration::from_millis(10));
+    }
+}
+
+// ============================================================================
+// SECTION: Warm-Up Tests
+// ============================================================================
+
+/// Verifies warm-up initializes every registered provider.
+#[test]
+fn warm_up_initializes_all_providers() {
+    let first = Arc::new(AtomicU32::new(0));
+    let second = Arc::new(AtomicU32::new(0));
+    let mut registry = ProviderRegistry::new(ProviderAccessPolicy::allow_all());
+    register_ok(
+        &mut registry,
+        "first",
+        WarmUpProvider {
+            warm_ups: Arc::clone(&first),
+            fail: false,
+        },
+    );
+    register_ok(
+        &mut registry,
+        "second",
+        WarmUpProvider {
+            warm_ups: Arc::clone(&second),
+            fail: false,
+        },
+    );
+    register_ok(&mut registry, "value", ValueProvider::new("value"));
+
+    registry.warm_up().expect("warm-up succeeds");
+    assert_eq!(first.load(Ordering::SeqCst), 1);
+    assert_eq!(second.load(Ordering::SeqCst), 1);
+}
+
+/// Verifies a failing provider is reported at warm-up time without masking others.
+#[test]
+fn warm_up_reports_failing_provider() {
+    let broken = Arc::new(AtomicU32::new(0));
+    let healthy = Arc::new(AtomicU32::new(0));
+    let mut registry = ProviderRegistry::new(ProviderAccessPolicy::allow_all());
+    register_ok(
+        &mut registry,
+        "broken",
+        WarmUpProvider {
+            warm_ups: Arc::clone(&broken),
+            fail: true,
+        },
+    );
+    register_ok(
+        &mut registry,
+        "healthy",
+        WarmUpProvider {
+            warm_ups: Arc::clone(&healthy),
+            fail: false,
+        },
+    );
+
+    let err = registry.warm_up().expect_err("warm-up fails");
+    let message = err.to_string();
+    assert!(message.contains("broken: "), "{message}");
+    assert!(message.contains("connection refused"), "{message}");
+    assert!(!message.contains("healthy"), "{message}");
+    assert_eq!(healthy.load(Ordering::SeqCst), 1, "healthy provider still warmed up");
+}
+
+/// Verifies built-in providers warm up without error.
+#[test]
+fn warm_up_builtin_providers_succeeds() {
+    let (_dir, configs) = builtin_configs();
+    let registry = ProviderRegistry::with_builtin_providers(configs).unwrap();
+    registry.warm_up().expect("built-in warm-up");
+}
+
 // ============================================================================
 // SECTION: Edge Cases
 // ============================================================================
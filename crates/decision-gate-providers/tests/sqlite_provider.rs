@@ -0,0 +1,275 @@
+// crates/decision-gate-providers/tests/sqlite_provider.rs
+// ============================================================================
+// Module: SQLite Query Provider Tests
+// Description: Tests for the allowlisted read-only SQLite query provider.
+// Purpose: Validate scalar results, statement allowlisting, and safe binding.
+// Dependencies: decision-gate-providers, decision-gate-core, rusqlite, tempfile
+// ============================================================================
+
+//! ## Overview
+//! Tests the `SQLite` query provider for:
+//! - Happy path: scalar and row results, empty result sets
+//! - Policy enforcement: non-SELECT statements and unknown queries are rejected
+//! - Adversarial: injection payloads are bound as values, never executed
+//! - Boundary enforcement: row and size limits
+//!
+//! Security posture: query params are untrusted; tests verify fail-closed
+//! behavior. See: `Docs/security/threat_model.md`
+
+#![allow(
+    clippy::panic,
+    clippy::print_stdout,
+    clippy::print_stderr,
+    clippy::unwrap_used,
+    clippy::expect_used,
+    clippy::use_debug,
+    clippy::dbg_macro,
+    clippy::panic_in_result_fn,
+    clippy::unwrap_in_result,
+    reason = "Test-only output and panic-based assertions are permitted."
+)]
+
+mod common;
+
+use std::collections::BTreeMap;
+use std::path::Path;
+
+use decision_gate_core::EvidenceProvider;
+use decision_gate_core::EvidenceQuery;
+use decision_gate_core::EvidenceValue;
+use decision_gate_core::ProviderId;
+use decision_gate_providers::SqliteQueryProvider;
+use decision_gate_providers::SqliteQueryProviderConfig;
+use rusqlite::Connection;
+use serde_json::Value;
+use serde_json::json;
+use tempfile::TempDir;
+
+use crate::common::sample_context;
+
+// ============================================================================
+// SECTION: Fixtures
+// ============================================================================
+
+/// Creates a database with a `releases` table and returns its directory.
+fn seeded_database() -> TempDir {
+    let dir = tempfile::tempdir().unwrap();
+    let conn = Connection::open(database_path(dir.path())).unwrap();
+    conn.execute_batch(
+        "CREATE TABLE releases (name TEXT NOT NULL, approvals INTEGER NOT NULL);
+         INSERT INTO releases VALUES ('v1', 2);
+         INSERT INTO releases VALUES ('v2', 5);",
+    )
+    .unwrap();
+    dir
+}
+
+/// Returns the database path inside a fixture directory.
+fn database_path(dir: &Path) -> std::path::PathBuf {
+    dir.join("evidence.sqlite")
+}
+
+/// Builds a provider config for the fixture with the given queries.
+fn config(dir: &Path, queries: &[(&str, &str)]) -> SqliteQueryProviderConfig {
+    SqliteQueryProviderConfig {
+        path: database_path(dir),
+        queries: queries
+            .iter()
+            .map(|(name, sql)| ((*name).to_string(), (*sql).to_string()))
+            .collect::<BTreeMap<_, _>>(),
+        ..SqliteQueryProviderConfig::default()
+    }
+}
+
+/// Builds a `query` check for the named query with bind params.
+fn query(name: &str, params: Value) -> EvidenceQuery {
+    EvidenceQuery {
+        provider_id: ProviderId::new("sqlite"),
+        check_id: "query".to_string(),
+        params: Some(Value::Object(
+            [("query".to_string(), json!(name)), ("params".to_string(), params)]
+                .into_iter()
+                .collect(),
+        )),
+    }
+}
+
+/// Counts rows in the fixture table through a separate connection.
+fn release_count(dir: &Path) -> i64 {
+    let conn = Connection::open(database_path(dir)).unwrap();
+    conn.query_row("SELECT COUNT(*) FROM releases", [], |row| row.get(0)).unwrap()
+}
+
+// ============================================================================
+// SECTION: Happy Path Tests
+// ============================================================================
+
+/// Tests that a single-column query returns a scalar value.
+#[test]
+fn sqlite_provider_returns_scalar() {
+    let dir = seeded_database();
+    let provider = SqliteQueryProvider::new(config(
+        dir.path(),
+        &[("approvals", "SELECT approvals FROM releases WHERE name = :name")],
+    ))
+    .unwrap();
+
+    let result =
+        provider.query(&query("approvals", json!({"name": "v2"})), &sample_context()).unwrap();
+    assert_eq!(result.value, Some(EvidenceValue::Json(json!(5))));
+    assert_eq!(result.evidence_anchor.unwrap().anchor_value, "approvals");
+}
+
+/// Tests that a multi-column query returns an object keyed by column.
+#[test]
+fn sqlite_provider_returns_row_object() {
+    let dir = seeded_database();
+    let provider = SqliteQueryProvider::new(config(
+        dir.path(),
+        &[("release", "SELECT name, approvals FROM releases WHERE name = :name")],
+    ))
+    .unwrap();
+
+    let result =
+        provider.query(&query("release", json!({"name": "v1"})), &sample_context()).unwrap();
+    assert_eq!(result.value, Some(EvidenceValue::Json(json!({"name": "v1", "approvals": 2}))));
+}
+
+/// Tests that a query with no rows returns an empty result.
+#[test]
+fn sqlite_provider_returns_empty_for_no_rows() {
+    let dir = seeded_database();
+    let provider = SqliteQueryProvider::new(config(
+        dir.path(),
+        &[("approvals", "SELECT approvals FROM releases WHERE name = :name")],
+    ))
+    .unwrap();
+
+    let result =
+        provider.query(&query("approvals", json!({"name": "v9"})), &sample_context()).unwrap();
+    assert!(result.value.is_none());
+}
+
+// ============================================================================
+// SECTION: Policy Enforcement Tests
+// ============================================================================
+
+/// Tests that write statements are rejected when the provider is built.
+#[test]
+fn sqlite_provider_rejects_write_statement() {
+    let dir = seeded_database();
+    for sql in [
+        "DELETE FROM releases",
+        "INSERT INTO releases VALUES ('v3', 0)",
+        "UPDATE releases SET approvals = 0",
+        "DROP TABLE releases",
+        "PRAGMA user_version = 7",
+        "SELECT 1; DELETE FROM releases",
+    ] {
+        let result = SqliteQueryProvider::new(config(dir.path(), &[("write", sql)]));
+        assert!(result.is_err(), "expected rejection for {sql}");
+    }
+    assert_eq!(release_count(dir.path()), 2);
+}
+
+/// Tests that queries outside the allowlist are rejected.
+#[test]
+fn sqlite_provider_rejects_unknown_query() {
+    let dir = seeded_database();
+    let provider = SqliteQueryProvider::new(config(dir.path(), &[])).unwrap();
+    let result = provider.query(&query("SELECT 1", json!({})), &sample_context());
+    assert!(result.is_err());
+}
+
+/// Tests that missing and unknown bind params fail closed.
+#[test]
+fn sqlite_provider_rejects_mismatched_params() {
+    let dir = seeded_database();
+    let provider = SqliteQueryProvider::new(config(
+        dir.path(),
+        &[("approvals", "SELECT approvals FROM releases WHERE name = :name")],
+    ))
+    .unwrap();
+
+    assert!(provider.query(&query("approvals", json!({})), &sample_context()).is_err());
+    assert!(
+        provider
+            .query(&query("approvals", json!({"name": "v1", "extra": 1})), &sample_context())
+            .is_err()
+    );
+    assert!(
+        provider.query(&query("approvals", json!({"name": ["v1"]})), &sample_context()).is_err()
+    );
+}
+
+// ============================================================================
+// SECTION: Adversarial Tests
+// ============================================================================
+
+/// Tests that an injection payload is bound as a literal value.
+#[test]
+fn sqlite_provider_binds_injection_attempt_safely() {
+    let dir = seeded_database();
+    let provider = SqliteQueryProvider::new(config(
+        dir.path(),
+        &[("approvals", "SELECT approvals FROM releases WHERE name = :name")],
+    ))
+    .unwrap();
+
+    for payload in ["v1' OR '1'='1", "v1'; DELETE FROM releases; --"] {
+        let result = provider
+            .query(&query("approvals", json!({"name": payload})), &sample_context())
+            .unwrap();
+        assert!(result.value.is_none(), "payload matched rows: {payload}");
+    }
+    assert_eq!(release_count(dir.path()), 2);
+}
+
+// ============================================================================
+// SECTION: Boundary Enforcement Tests
+// ============================================================================
+
+/// Tests that queries producing more rows than allowed fail closed.
+#[test]
+fn sqlite_provider_enforces_row_limit() {
+    let dir = seeded_database();
+    let provider =
+        SqliteQueryProvider::new(config(dir.path(), &[("all", "SELECT approvals FROM releases")]))
+            .unwrap();
+    assert!(provider.query(&query("all", json!({})), &sample_context()).is_err());
+}
+
+/// Tests that oversized values fail closed.
+#[test]
+fn sqlite_provider_enforces_value_limit() {
+    let dir = seeded_database();
+    let provider = SqliteQueryProvider::new(SqliteQueryProviderConfig {
+        max_value_bytes: 8,
+        ..config(
+            dir.path(),
+            &[("padded", "SELECT printf('%020d', approvals) FROM releases LIMIT 1")],
+        )
+    })
+    .unwrap();
+    assert!(provider.query(&query("padded", json!({})), &sample_context()).is_err());
+}
+
+/// Tests that long-running queries are interrupted at the deadline.
+#[test]
+fn sqlite_provider_times_out() {
+    let dir = seeded_database();
+    let provider = SqliteQueryProvider::new(SqliteQueryProviderConfig {
+        timeout_ms: 50,
+        ..config(
+            dir.path(),
+            &[(
+                "spin",
+                "SELECT (WITH RECURSIVE spin(n) AS (SELECT 1 UNION ALL SELECT n + 1 FROM spin) \
+                 SELECT MAX(n) FROM spin)",
+            )],
+        )
+    })
+    .unwrap();
+    let err = provider.query(&query("spin", json!({})), &sample_context()).unwrap_err();
+    assert!(err.to_string().contains("timed out"));
+}
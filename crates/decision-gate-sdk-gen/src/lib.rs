@@ -2,21 +2,23 @@
 // ============================================================================
 // Module: SDK Generator Library
 // Description: Deterministic generator for Decision Gate client SDK artifacts.
-// Purpose: Render Python/TypeScript SDKs and OpenAPI view from tooling.json.
-// Dependencies: decision-gate-contract, serde_json, thiserror
+// Purpose: Render Python/TypeScript/Go SDKs and OpenAPI view from tooling.json.
+// Dependencies: decision-gate-contract, decision-gate-core, jsonschema, serde_json, thiserror
 // ============================================================================
 
 //! ## Overview
 //! This crate generates Decision Gate client SDK artifacts from the canonical
 //! `Docs/generated/decision-gate/tooling.json` contract. It produces
-//! deterministic Python and TypeScript SDK surfaces plus an `OpenAPI` view of the
-//! JSON-RPC `tools/call` surface.
+//! deterministic Python, TypeScript, and Go SDK surfaces plus an `OpenAPI` view
+//! of the JSON-RPC `tools/call` surface.
 //!
 //! ### Design Notes
 //! - Output is deterministic: schema properties and JSON object keys are sorted before rendering,
 //!   and tool order follows the tooling contract input.
-//! - The generator does not reach out to external schemas; `$ref` values are treated as opaque and
-//!   rendered as `Any`.
+//! - The generator does not reach out to external schemas. Local `#/$defs/...` and
+//!   `#/definitions/...` refs are resolved against the tool schema; object definitions render as
+//!   named types (titled ones shared across tools), and external, cyclic, or overly deep refs fall
+//!   back to `Any`.
 //! - Schema-to-type mapping is best-effort and intentionally conservative to preserve compatibility
 //!   across SDK consumers.
 //!
@@ -26,11 +28,13 @@
 //! `Docs/security/threat_model.md` for the repository threat model.
 //!
 //! ## Index
-//! - Public API: [`SdkGenerator`], [`SdkGenError`], [`DEFAULT_TOOLING_PATH`], [`MAX_TOOLING_BYTES`]
-//! - Rendering: Python, TypeScript, `OpenAPI` (private helpers)
+//! - Public API: [`SdkGenerator`], [`SdkGenError`], [`DEFAULT_TOOLING_PATH`],
+//!   [`MAX_TOOLING_BYTES`], [`LIFECYCLE_EXAMPLE_TOOLS`], [`render_openapi_document`]
+//! - Rendering: Python, TypeScript, Go, `OpenAPI`, lifecycle examples (private helpers)
 //! - Schema helpers: schema inspection, doc normalization, type mapping
 
 use std::collections::BTreeMap;
+use std::collections::BTreeSet;
 use std::fmt;
 use std::fmt::Write;
 use std::fs;
@@ -40,6 +44,8 @@ use std::path::PathBuf;
 
 use decision_gate_contract::types::ToolContract;
 use decision_gate_contract::types::ToolExample;
+use decision_gate_core::hashing::DEFAULT_HASH_ALGORITHM;
+use decision_gate_core::hashing::hash_bytes;
 use serde_json::Value;
 use thiserror::Error;
 
@@ -57,6 +63,67 @@ pub const DEFAULT_TOOLING_PATH: &str = "Docs/generated/decision-gate/tooling.jso
 /// Maximum tooling.json size accepted by the generator.
 pub const MAX_TOOLING_BYTES: u64 = 4 * 1024 * 1024;
 
+/// `$id` of the generated JSON Schema bundle.
+pub const SCHEMA_BUNDLE_ID: &str = "decision-gate://sdk/schemas.json";
+
+/// Tools invoked, in order, by the generated scenario lifecycle example.
+pub const LIFECYCLE_EXAMPLE_TOOLS: [&str; 4] =
+    ["scenario_define", "scenario_start", "scenario_submit", "scenario_status"];
+
+/// Provider-discovery tools whose response schemas are named `OpenAPI` components.
+pub const DISCOVERY_RESPONSE_TOOLS: [&str; 3] =
+    ["providers_list", "provider_contract_get", "provider_check_schema_get"];
+
+/// Indentation unit used by a generated SDK file.
+///
+/// # Invariants
+/// - `Spaces` widths are between 1 and 8 once accepted by [`SdkGenerator::with_options`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum IndentStyle {
+    /// Indent with the given number of spaces per level.
+    Spaces(usize),
+    /// Indent with one tab per level.
+    Tabs,
+}
+
+impl IndentStyle {
+    /// Returns the text emitted for one indentation level.
+    fn unit(self) -> String {
+        match self {
+            Self::Spaces(width) => " ".repeat(width),
+            Self::Tabs => "\t".to_string(),
+        }
+    }
+}
+
+/// Formatting options for generated SDK sources.
+///
+/// # Invariants
+/// - Defaults reproduce the checked-in SDK outputs byte for byte.
+/// - Rendering is deterministic for a fixed option set.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct SdkGenOptions {
+    /// Maximum doc comment line width for Python output.
+    pub python_doc_width: usize,
+    /// Maximum doc comment line width for TypeScript output.
+    pub typescript_doc_width: usize,
+    /// Indentation for Python output.
+    pub python_indent: IndentStyle,
+    /// Indentation for TypeScript output.
+    pub typescript_indent: IndentStyle,
+}
+
+impl Default for SdkGenOptions {
+    fn default() -> Self {
+        Self {
+            python_doc_width: 88,
+            typescript_doc_width: 96,
+            python_indent: IndentStyle::Spaces(4),
+            typescript_indent: IndentStyle::Spaces(2),
+        }
+    }
+}
+
 /// Errors raised by the SDK generator.
 ///
 /// # Invariants
@@ -80,6 +147,9 @@ pub enum SdkGenError {
     /// Tooling contract error.
     #[error("tooling error: {0}")]
     Tooling(String),
+    /// Invalid generation options.
+    #[error("invalid generation options: {0}")]
+    Options(String),
 }
 
 /// SDK generator loaded with tooling contracts.
@@ -114,6 +184,12 @@ pub struct SdkGenerator {
     tooling_path: PathBuf,
     /// Loaded tooling contracts used to render SDK artifacts.
     tools: Vec<ToolContract>,
+    /// Content hash of the raw tooling.json bytes (`sha256:<hex>`).
+    contract_hash: String,
+    /// Formatting options applied to generated SDK sources.
+    options: SdkGenOptions,
+    /// Base server URL emitted in the `OpenAPI` `servers` block.
+    openapi_server: Option<String>,
 }
 
 impl SdkGenerator {
@@ -123,20 +199,101 @@ impl SdkGenerator {
     /// Returns [`SdkGenError`] when the tooling file cannot be read or parsed,
     /// or when the file exceeds [`MAX_TOOLING_BYTES`].
     ///
+    /// Returns [`SdkGenError::Tooling`] naming the offending tool when an entry
+    /// is missing fields or its input/output schema is not a valid JSON Schema.
+    ///
     /// # Notes
-    /// This method performs JSON parsing only; semantic validation is expected
-    /// to happen upstream when the tooling contract is built.
+    /// Schemas are checked against their draft meta-schema only; `$ref` targets
+    /// are not resolved.
     pub fn load(tooling_path: impl AsRef<Path>) -> Result<Self, SdkGenError> {
         let tooling_path = tooling_path.as_ref().to_path_buf();
         let bytes = read_tooling_bytes(&tooling_path)?;
-        let tools: Vec<ToolContract> =
-            serde_json::from_slice(&bytes).map_err(|err| SdkGenError::Json(err.to_string()))?;
+        let tools = parse_tooling(&bytes)?;
+        let digest = hash_bytes(DEFAULT_HASH_ALGORITHM, &bytes);
         Ok(Self {
             tooling_path,
             tools,
+            contract_hash: format!("sha256:{}", digest.value),
+            options: SdkGenOptions::default(),
+            openapi_server: None,
         })
     }
 
+    /// Replaces the formatting options used for Python and TypeScript output.
+    ///
+    /// # Errors
+    /// Returns [`SdkGenError::Options`] when a doc width is zero or a space
+    /// indent is outside `1..=8`.
+    pub fn with_options(mut self, options: SdkGenOptions) -> Result<Self, SdkGenError> {
+        if options.python_doc_width == 0 || options.typescript_doc_width == 0 {
+            return Err(SdkGenError::Options("doc width must be positive".to_string()));
+        }
+        for indent in [options.python_indent, options.typescript_indent] {
+            if let IndentStyle::Spaces(width) = indent
+                && !(1 ..= 8).contains(&width)
+            {
+                return Err(SdkGenError::Options(format!(
+                    "space indent must be between 1 and 8, got {width}"
+                )));
+            }
+        }
+        self.options = options;
+        Ok(self)
+    }
+
+    /// Sets the doc comment wrap widths for Python and TypeScript output.
+    ///
+    /// Shorthand for [`Self::with_options`] that keeps the other options; use
+    /// it to match a project's Black or Prettier line length.
+    ///
+    /// # Errors
+    /// Returns [`SdkGenError::Options`] when either width is zero.
+    pub fn doc_width(self, python: usize, typescript: usize) -> Result<Self, SdkGenError> {
+        let options = SdkGenOptions {
+            python_doc_width: python,
+            typescript_doc_width: typescript,
+            ..self.options
+        };
+        self.with_options(options)
+    }
+
+    /// Sets the base server URL emitted in the `OpenAPI` `servers` block.
+    ///
+    /// The document omits `servers` when no URL is set.
+    #[must_use]
+    pub fn openapi_server(mut self, url: impl Into<String>) -> Self {
+        self.openapi_server = Some(url.into());
+        self
+    }
+
+    /// Returns the formatting options used by this generator.
+    #[must_use]
+    pub const fn options(&self) -> &SdkGenOptions {
+        &self.options
+    }
+
+    /// Returns the content hash of the loaded tooling.json (`sha256:<hex>`).
+    #[must_use]
+    pub fn contract_hash(&self) -> &str {
+        &self.contract_hash
+    }
+
+    /// Returns the stamp recorded next to generated outputs for incremental runs.
+    ///
+    /// The stamp covers the generator version, the contract hash, and the
+    /// output paths, so a change to any of them forces regeneration.
+    #[must_use]
+    pub fn generation_stamp(&self, outputs: &[&Path]) -> String {
+        let mut stamp =
+            format!("generator {}\ncontract {}\n", env!("CARGO_PKG_VERSION"), self.contract_hash);
+        for output in outputs {
+            stamp.push_str("output ");
+            stamp.push_str(&output.display().to_string());
+            stamp.push('\n');
+        }
+        stamp
+    }
+
     /// Returns the tooling.json path used by the generator.
     #[must_use]
     pub fn tooling_path(&self) -> &Path {
@@ -148,7 +305,8 @@ impl SdkGenerator {
     /// # Errors
     /// Returns [`SdkGenError`] if JSON rendering fails.
     pub fn generate_python(&self) -> Result<String, SdkGenError> {
-        render_python(&self.tools)
+        let rendered = render_python(&self.tools, self.options.python_doc_width)?;
+        Ok(reindent(&rendered, PYTHON_INDENT, self.options.python_indent))
     }
 
     /// Generates the TypeScript SDK `_generated.ts` content.
@@ -156,7 +314,19 @@ impl SdkGenerator {
     /// # Errors
     /// Returns [`SdkGenError`] if JSON rendering fails.
     pub fn generate_typescript(&self) -> Result<String, SdkGenError> {
-        render_typescript(&self.tools)
+        let rendered = render_typescript(&self.tools, self.options.typescript_doc_width)?;
+        Ok(reindent(&rendered, TYPESCRIPT_INDENT, self.options.typescript_indent))
+    }
+
+    /// Generates the Go SDK `generated.go` content.
+    ///
+    /// Go output uses gofmt conventions (tab indentation) and is not affected
+    /// by [`SdkGenOptions`].
+    ///
+    /// # Errors
+    /// Returns [`SdkGenError`] if rendering fails.
+    pub fn generate_go(&self) -> Result<String, SdkGenError> {
+        Ok(render_go(&self.tools))
     }
 
     /// Generates the `OpenAPI` JSON document.
@@ -164,10 +334,55 @@ impl SdkGenerator {
     /// # Errors
     /// Returns [`SdkGenError`] if JSON serialization fails.
     pub fn generate_openapi(&self) -> Result<String, SdkGenError> {
-        render_openapi(&self.tools)
+        render_openapi(&self.tools, self.openapi_server.as_deref())
+    }
+
+    /// Generates the JSON Schema bundle `schemas.json` content.
+    ///
+    /// Every tool's input and output schema is listed under `$defs` as
+    /// `{tool}_input` and `{tool}_output`.
+    ///
+    /// # Errors
+    /// Returns [`SdkGenError::Json`] if JSON serialization fails.
+    pub fn generate_schema_bundle(&self) -> Result<String, SdkGenError> {
+        render_schema_bundle(&self.tools)
+    }
+
+    /// Generates the Python lifecycle example `_examples.py` content.
+    ///
+    /// # Errors
+    /// Returns [`SdkGenError`] when a lifecycle tool or its example is missing
+    /// from the tooling contract, or if JSON rendering fails.
+    pub fn generate_python_example(&self) -> Result<String, SdkGenError> {
+        let rendered = render_python_lifecycle_example(&lifecycle_steps(&self.tools)?)?;
+        Ok(reindent(&rendered, PYTHON_INDENT, self.options.python_indent))
+    }
+
+    /// Generates the TypeScript lifecycle example `_examples.ts` content.
+    ///
+    /// # Errors
+    /// Returns [`SdkGenError`] when a lifecycle tool or its example is missing
+    /// from the tooling contract, or if JSON rendering fails.
+    pub fn generate_typescript_example(&self) -> Result<String, SdkGenError> {
+        let rendered = render_typescript_lifecycle_example(&lifecycle_steps(&self.tools)?)?;
+        Ok(reindent(&rendered, TYPESCRIPT_INDENT, self.options.typescript_indent))
     }
 }
 
+/// Renders the `OpenAPI` JSON document for in-memory tool contracts.
+///
+/// Servers use this to publish a live view limited to the tools they expose.
+/// Output matches [`SdkGenerator::generate_openapi`] for the same contracts
+/// when no server URL is set.
+///
+/// # Errors
+/// Returns [`SdkGenError::Tooling`] when definitions sharing a `title` differ,
+/// or [`SdkGenError::Json`] when the document cannot be serialized.
+pub fn render_openapi_document(tools: &[ToolContract]) -> Result<String, SdkGenError> {
+    check_titled_definitions(tools)?;
+    render_openapi(tools, None)
+}
+
 // ============================================================================
 // SECTION: Tooling Input
 // ============================================================================
@@ -195,6 +410,79 @@ fn read_tooling_bytes(path: &Path) -> Result<Vec<u8>, SdkGenError> {
     Ok(bytes)
 }
 
+/// Parses and validates tooling contracts, reporting errors per tool.
+fn parse_tooling(bytes: &[u8]) -> Result<Vec<ToolContract>, SdkGenError> {
+    let value: Value =
+        serde_json::from_slice(bytes).map_err(|err| SdkGenError::Json(err.to_string()))?;
+    let Value::Array(entries) = value else {
+        return Err(SdkGenError::Tooling("tooling input must be a JSON array".to_string()));
+    };
+    let mut tools: Vec<ToolContract> = Vec::with_capacity(entries.len());
+    for (index, entry) in entries.into_iter().enumerate() {
+        let label = entry
+            .get("name")
+            .and_then(Value::as_str)
+            .map_or_else(|| format!("tools[{index}]"), |name| format!("tools[{index}] ({name})"));
+        let tool: ToolContract = serde_json::from_value(entry)
+            .map_err(|err| SdkGenError::Tooling(format!("{label}: {err}")))?;
+        if tools.iter().any(|existing| existing.name == tool.name) {
+            return Err(SdkGenError::Tooling(format!("{label}: duplicate tool name")));
+        }
+        if tool.description.trim().is_empty() {
+            return Err(SdkGenError::Tooling(format!("{label}: description is empty")));
+        }
+        validate_tool_schema(&label, "input_schema", &tool.input_schema)?;
+        validate_tool_schema(&label, "output_schema", &tool.output_schema)?;
+        tools.push(tool);
+    }
+    check_titled_definitions(&tools)?;
+    Ok(tools)
+}
+
+/// Checks that definitions sharing a `title` are structurally equal.
+///
+/// Titled definitions render as one shared type, so two different schemas
+/// under the same title would otherwise merge into a single wrong type.
+fn check_titled_definitions(tools: &[ToolContract]) -> Result<(), SdkGenError> {
+    let mut titled: BTreeMap<&str, (&str, &Value)> = BTreeMap::new();
+    for tool in tools {
+        for schema in [&tool.input_schema, &tool.output_schema] {
+            for keyword in ["$defs", "definitions"] {
+                let Some(entries) = schema.get(keyword).and_then(Value::as_object) else {
+                    continue;
+                };
+                for definition in entries.values() {
+                    let Some(title) = definition.get("title").and_then(Value::as_str) else {
+                        continue;
+                    };
+                    let owner = tool.name.as_str();
+                    let (first, existing) = *titled.entry(title).or_insert((owner, definition));
+                    if existing != definition {
+                        return Err(SdkGenError::Tooling(format!(
+                            "{owner}: definition titled {title} differs from the one in {first}"
+                        )));
+                    }
+                }
+            }
+        }
+    }
+    Ok(())
+}
+
+/// Checks that a tool schema is a JSON Schema object valid against its meta-schema.
+fn validate_tool_schema(label: &str, field: &str, schema: &Value) -> Result<(), SdkGenError> {
+    if !schema.is_object() {
+        return Err(SdkGenError::Tooling(format!("{label}: {field} must be a JSON object")));
+    }
+    jsonschema::meta::validate(schema).map_err(|err| {
+        let location = err.instance_path().to_string();
+        let location = if location.is_empty() { "/".to_string() } else { location };
+        SdkGenError::Tooling(format!(
+            "{label}: {field} is not a valid JSON Schema at {location}: {err}"
+        ))
+    })
+}
+
 // ============================================================================
 // SECTION: Schema Model
 // ============================================================================
@@ -203,7 +491,9 @@ fn read_tooling_bytes(path: &Path) -> Result<Vec<u8>, SdkGenError> {
 ///
 /// # Invariants
 /// - `Union` variants contain at least two distinct, non-`Any` entries.
+/// - `Intersection` variants contain at least two distinct `Named` or `Object` entries.
 /// - `Array` always wraps a fully resolved inner [`TypeSpec`].
+/// - `Discriminated` variants have at least two members with distinct discriminator values.
 #[derive(Debug, Clone, PartialEq)]
 enum TypeSpec {
     /// Arbitrary JSON value.
@@ -214,6 +504,10 @@ enum TypeSpec {
     Bool,
     /// Integer number.
     Int,
+    /// 64-bit integer that may exceed `Number.MAX_SAFE_INTEGER`.
+    ///
+    /// TypeScript renders it as `bigint`; Python and Go keep their native integers.
+    Int64,
     /// Floating point number.
     Number,
     /// String value.
@@ -226,6 +520,27 @@ enum TypeSpec {
     Union(Vec<Self>),
     /// Literal set of JSON values.
     Literal(Vec<Value>),
+    /// String enum carrying a schema `title`; Python renders it as an `Enum` class.
+    TitledEnum {
+        /// Schema `title` naming the enum.
+        title: String,
+        /// Allowed string values in schema order.
+        values: Vec<String>,
+    },
+    /// Named type generated from a local object definition.
+    Named(String),
+    /// Intersection of object types from `allOf`.
+    Intersection(Vec<Self>),
+    /// `oneOf` of objects tagged by a shared required `const` string property.
+    ///
+    /// TypeScript renders named member interfaces and a union alias; other
+    /// languages render it as a plain object.
+    Discriminated {
+        /// Discriminator property name.
+        property: String,
+        /// Discriminator value and object schema per member, in schema order.
+        members: Vec<(String, Value)>,
+    },
 }
 
 /// Object property metadata for SDK type rendering.
@@ -245,6 +560,108 @@ struct Property {
     schema: Value,
 }
 
+/// Direction a generated type travels, used to honor `readOnly`/`writeOnly`.
+///
+/// # Invariants
+/// - Request types omit `readOnly` properties; response types omit `writeOnly` properties.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum SchemaRole {
+    /// Client-to-server payload.
+    Request,
+    /// Server-to-client payload.
+    Response,
+}
+
+/// Maximum number of chained `$ref` hops followed before falling back to `Any`.
+const MAX_REF_DEPTH: usize = 16;
+
+/// Local `$ref` resolution scope for one root schema.
+///
+/// # Invariants
+/// - Only `#/$defs/<name>` and `#/definitions/<name>` refs into `root` resolve.
+/// - `active` holds the refs being followed; re-entering one is a cycle.
+#[derive(Debug, Clone)]
+struct RefScope<'a> {
+    /// Root schema that local refs resolve against.
+    root: &'a Value,
+    /// Prefix for generated definition type names.
+    prefix: &'a str,
+    /// Refs currently being followed, outermost first.
+    active: Vec<String>,
+}
+
+impl<'a> RefScope<'a> {
+    /// Creates a scope for a root schema whose definitions are named with `prefix`.
+    const fn new(root: &'a Value, prefix: &'a str) -> Self {
+        Self {
+            root,
+            prefix,
+            active: Vec::new(),
+        }
+    }
+
+    /// Returns the definition name and target schema for a local ref.
+    fn resolve(&self, reference: &str) -> Option<(&'a str, &'a Value)> {
+        let (keyword, name) =
+            reference.strip_prefix("#/$defs/").map(|name| ("$defs", name)).or_else(|| {
+                reference.strip_prefix("#/definitions/").map(|name| ("definitions", name))
+            })?;
+        let (key, target) = self.root.get(keyword)?.as_object()?.get_key_value(name)?;
+        Some((key.as_str(), target))
+    }
+
+    /// Returns a child scope following `reference`.
+    ///
+    /// Returns `None` when the ref is already active (a cycle) or the chain
+    /// has reached [`MAX_REF_DEPTH`].
+    fn enter(&self, reference: &str) -> Option<Self> {
+        if self.active.len() >= MAX_REF_DEPTH || self.active.iter().any(|item| item == reference) {
+            return None;
+        }
+        let mut child = self.clone();
+        child.active.push(reference.to_string());
+        Some(child)
+    }
+
+    /// Returns the generated type name for a definition.
+    ///
+    /// A definition with a string `title` is named by that title alone, so
+    /// tools defining the same titled type share one generated type.
+    fn type_name(&self, definition: &str, schema: &Value) -> String {
+        schema.get("title").and_then(Value::as_str).map_or_else(
+            || format!("{}{}", self.prefix, identifier_suffix(definition)),
+            identifier_suffix,
+        )
+    }
+
+    /// Returns object definitions that render as named types, sorted by name.
+    ///
+    /// Each entry carries the type name, the scope used to render its
+    /// properties, and the definition schema.
+    fn named_definitions(&self) -> Vec<(String, Self, &'a Value)> {
+        let mut definitions = BTreeMap::new();
+        for keyword in ["$defs", "definitions"] {
+            let Some(entries) = self.root.get(keyword).and_then(Value::as_object) else {
+                continue;
+            };
+            for (name, schema) in entries {
+                let type_name = self.type_name(name, schema);
+                if definitions.contains_key(&type_name) {
+                    continue;
+                }
+                let Some(scope) = self.enter(&format!("#/{keyword}/{name}")) else {
+                    continue;
+                };
+                if !is_object_like(schema, &scope) {
+                    continue;
+                }
+                definitions.insert(type_name, (scope, schema));
+            }
+        }
+        definitions.into_iter().map(|(name, (scope, schema))| (name, scope, schema)).collect()
+    }
+}
+
 // ============================================================================
 // SECTION: Python SDK Rendering
 // ============================================================================
@@ -254,7 +671,7 @@ struct Property {
     clippy::too_many_lines,
     reason = "Generator output is assembled in one pass for determinism."
 )]
-fn render_python(tools: &[ToolContract]) -> Result<String, SdkGenError> {
+fn render_python(tools: &[ToolContract], doc_width: usize) -> Result<String, SdkGenError> {
     let mut out = String::new();
     out.push_str("# This file is @generated by decision-gate-sdk-gen. DO NOT EDIT.\n");
     out.push_str("# Source: ");
@@ -263,6 +680,9 @@ fn render_python(tools: &[ToolContract]) -> Result<String, SdkGenError> {
     out.push_str("# fmt: off\n\n");
     out.push_str("from __future__ import annotations\n\n");
     out.push_str("import json as _json\n");
+    if tools.iter().any(|tool| tool.deprecated) {
+        out.push_str("import warnings as _warnings\n");
+    }
     out.push_str(
         "from typing import Any, Dict, List, Mapping, Sequence, TypedDict, Union, Literal, cast\n",
     );
@@ -275,7 +695,15 @@ fn render_python(tools: &[ToolContract]) -> Result<String, SdkGenError> {
     out.push_str("        class _NotRequired:\n");
     out.push_str("            def __class_getitem__(cls, item):\n");
     out.push_str("                return item\n");
-    out.push_str("        NotRequired = _NotRequired\n\n");
+    out.push_str("        NotRequired = _NotRequired\n");
+    if has_open_schema(tools) {
+        out.push_str("from typing_extensions import TypedDict as _OpenTypedDict\n");
+    }
+    let enums = collect_python_enums(tools);
+    if !enums.is_empty() {
+        out.push_str("from enum import Enum\n");
+    }
+    out.push('\n');
     out.push_str("JsonPrimitive = Union[str, int, float, bool, None]\n");
     out.push_str(
         "JsonValue = Union[JsonPrimitive, List[\"JsonValue\"], Dict[str, \"JsonValue\"]]\n\n",
@@ -313,21 +741,74 @@ fn render_python(tools: &[ToolContract]) -> Result<String, SdkGenError> {
     }
     out.push_str("}\n\n");
 
+    render_python_enums(&mut out, &enums);
+    let mut seen = BTreeSet::new();
     for tool in tools {
         let pascal = pascal_case(tool.name.as_str());
-        let input_type = format!("{pascal}Request");
-        let output_type = format!("{pascal}Response");
-        render_python_typed_dict(&mut out, &input_type, &tool.input_schema);
-        render_python_typed_dict(&mut out, &output_type, &tool.output_schema);
+        for_each_python_typed_dict(tool, &mut seen, |name, schema, role, scope| {
+            render_python_typed_dict(&mut out, name, schema, role, scope, &enums, doc_width);
+        });
         render_python_schema_constant(&mut out, &pascal, "INPUT_SCHEMA", &tool.input_schema)?;
         render_python_schema_constant(&mut out, &pascal, "OUTPUT_SCHEMA", &tool.output_schema)?;
     }
 
-    out.push_str("class GeneratedDecisionGateClient:\n");
-    out.push_str(
-        "    \"\"\"Generated Decision Gate client methods. Implement `_call_tool`.\"\"\"\n\n",
-    );
-    out.push_str("    def _call_tool(self, name: str, arguments: JsonValue) -> JsonValue:\n");
+    render_python_client_class(&mut out, tools, PythonClientKind::Sync)?;
+    render_python_client_class(&mut out, tools, PythonClientKind::Async)?;
+
+    render_python_validation_helpers(&mut out, tools);
+    render_python_exports(&mut out, tools, &enums);
+    Ok(out)
+}
+
+/// Python client class flavor.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum PythonClientKind {
+    /// Blocking client with `def` methods.
+    Sync,
+    /// `asyncio` client with `async def` methods.
+    Async,
+}
+
+impl PythonClientKind {
+    /// Returns the generated class name.
+    const fn class_name(self) -> &'static str {
+        match self {
+            Self::Sync => "GeneratedDecisionGateClient",
+            Self::Async => "AsyncGeneratedDecisionGateClient",
+        }
+    }
+
+    /// Returns the generated class docstring.
+    const fn class_doc(self) -> &'static str {
+        match self {
+            Self::Sync => "Generated Decision Gate client methods. Implement `_call_tool`.",
+            Self::Async => {
+                "Generated asyncio Decision Gate client methods. Implement `_call_tool`."
+            }
+        }
+    }
+}
+
+/// Renders a Python client class; sync and async variants share this renderer
+/// so their method sets and signatures stay structurally identical.
+fn render_python_client_class(
+    out: &mut String,
+    tools: &[ToolContract],
+    kind: PythonClientKind,
+) -> Result<(), SdkGenError> {
+    let (def, await_prefix) = match kind {
+        PythonClientKind::Sync => ("def ", ""),
+        PythonClientKind::Async => ("async def ", "await "),
+    };
+    out.push_str("class ");
+    out.push_str(kind.class_name());
+    out.push_str(":\n");
+    out.push_str("    \"\"\"");
+    out.push_str(kind.class_doc());
+    out.push_str("\"\"\"\n\n");
+    out.push_str("    ");
+    out.push_str(def);
+    out.push_str("_call_tool(self, name: str, arguments: JsonValue) -> JsonValue:\n");
     out.push_str(
         "        raise NotImplementedError(\"_call_tool must be implemented by subclasses\")\n\n",
     );
@@ -336,7 +817,11 @@ fn render_python(tools: &[ToolContract]) -> Result<String, SdkGenError> {
         let pascal = pascal_case(tool.name.as_str());
         let input_type = format!("{pascal}Request");
         let output_type = format!("{pascal}Response");
-        out.push_str("    def ");
+        if tool.deprecated {
+            out.push_str("    # DEPRECATED\n");
+        }
+        out.push_str("    ");
+        out.push_str(def);
         out.push_str(tool.name.as_str());
         out.push_str("(self, request: ");
         out.push_str(&input_type);
@@ -358,35 +843,82 @@ fn render_python(tools: &[ToolContract]) -> Result<String, SdkGenError> {
         }
         if !tool.examples.is_empty() {
             out.push('\n');
-            render_python_examples(&mut out, &tool.examples)?;
+            render_python_examples(out, &tool.examples)?;
         }
         out.push_str("        \"\"\"\n");
+        if tool.deprecated {
+            out.push_str("        _warnings.warn(");
+            out.push_str(&python_string_literal(&format!("{} is deprecated.", tool.name)));
+            out.push_str(", DeprecationWarning, stacklevel=2)\n");
+        }
         out.push_str("        return cast(");
         out.push_str(&output_type);
-        out.push_str(", self._call_tool(\"");
+        out.push_str(", ");
+        out.push_str(await_prefix);
+        out.push_str("self._call_tool(\"");
         out.push_str(tool.name.as_str());
         out.push_str("\", request))\n\n");
     }
+    Ok(())
+}
 
-    render_python_validation_helpers(&mut out, tools);
-    render_python_exports(&mut out, tools);
-    Ok(out)
+/// Calls `visit` with the name, schema, role, and ref scope of every
+/// `TypedDict` rendered for a tool, in render order.
+///
+/// Definitions already named in `seen` are skipped so shared titled types
+/// render once.
+fn for_each_python_typed_dict<F>(tool: &ToolContract, seen: &mut BTreeSet<String>, mut visit: F)
+where
+    F: FnMut(&str, &Value, SchemaRole, &RefScope<'_>),
+{
+    let pascal = pascal_case(tool.name.as_str());
+    let input_type = format!("{pascal}Request");
+    let output_type = format!("{pascal}Response");
+    for (type_name, schema, role) in [
+        (&input_type, &tool.input_schema, SchemaRole::Request),
+        (&output_type, &tool.output_schema, SchemaRole::Response),
+    ] {
+        let scope = RefScope::new(schema, type_name);
+        for (name, definition_scope, definition) in scope.named_definitions() {
+            if seen.insert(name.clone()) {
+                visit(&name, definition, role, &definition_scope);
+            }
+        }
+        visit(type_name, schema, role, &scope);
+    }
 }
 
 /// Renders a `TypedDict` for a JSON object schema.
-fn render_python_typed_dict(out: &mut String, name: &str, schema: &Value) {
+fn render_python_typed_dict(
+    out: &mut String,
+    name: &str,
+    schema: &Value,
+    role: SchemaRole,
+    scope: &RefScope<'_>,
+    enums: &PythonEnums,
+    doc_width: usize,
+) {
     out.push_str("class ");
     out.push_str(name);
-    out.push_str("(TypedDict):\n");
+    if let Some(additional) = additional_properties(schema, scope) {
+        out.push_str("(_OpenTypedDict, extra_items=");
+        out.push_str(&python_type(&additional, enums));
+        out.push_str("):\n");
+    } else {
+        out.push_str("(TypedDict):\n");
+    }
     let class_doc = schema_doc(schema).unwrap_or_else(|| format!("Schema for {name}."));
     out.push_str("    \"\"\"");
     out.push_str(&class_doc);
     out.push_str("\"\"\"\n");
-    match object_properties(schema) {
+    match object_properties(schema, role, scope) {
         Some(properties) if !properties.is_empty() => {
             for property in properties {
+                if is_deprecated(&property.schema) {
+                    out.push_str("    # DEPRECATED\n");
+                }
                 if let Some(comment) = schema_doc(&property.schema) {
-                    for line in wrap_doc(&comment, 88) {
+                    for line in wrap_doc(&comment, doc_width) {
                         out.push_str("    #: ");
                         out.push_str(&line);
                         out.push('\n');
@@ -396,10 +928,10 @@ fn render_python_typed_dict(out: &mut String, name: &str, schema: &Value) {
                 out.push_str(&property.name);
                 out.push_str(": ");
                 if property.required {
-                    out.push_str(&python_type(&property.ty));
+                    out.push_str(&python_type(&property.ty, enums));
                 } else {
                     out.push_str("NotRequired[");
-                    out.push_str(&python_type(&property.ty));
+                    out.push_str(&python_type(&property.ty, enums));
                     out.push(']');
                 }
                 out.push('\n');
@@ -412,6 +944,186 @@ fn render_python_typed_dict(out: &mut String, name: &str, schema: &Value) {
     out.push('\n');
 }
 
+/// Module-level Python names that generated `Enum` classes must not shadow.
+const PYTHON_RESERVED_NAMES: &[&str] = &[
+    "Any",
+    "AsyncGeneratedDecisionGateClient",
+    "Dict",
+    "Enum",
+    "GeneratedDecisionGateClient",
+    "JsonPrimitive",
+    "JsonValue",
+    "List",
+    "Literal",
+    "Mapping",
+    "NotRequired",
+    "SchemaValidationError",
+    "Sequence",
+    "TypedDict",
+    "Union",
+];
+
+/// Python `Enum` classes generated for titled string enums.
+///
+/// # Invariants
+/// - Entries are keyed by `(title, sorted values)`, so identical enums share a class.
+/// - Class names are unique and never shadow `TypedDict`s or reserved names.
+#[derive(Debug, Default)]
+struct PythonEnums {
+    /// Class name and schema-ordered values per `(title, sorted values)` key.
+    classes: BTreeMap<(String, Vec<String>), (String, Vec<String>)>,
+}
+
+impl PythonEnums {
+    /// Returns true when no titled enums were found.
+    fn is_empty(&self) -> bool {
+        self.classes.is_empty()
+    }
+
+    /// Returns the class name generated for a titled enum.
+    fn class_name(&self, title: &str, values: &[String]) -> Option<&str> {
+        let mut sorted = values.to_vec();
+        sorted.sort();
+        self.classes.get(&(title.to_string(), sorted)).map(|(name, _)| name.as_str())
+    }
+}
+
+/// Collects titled string enums from every rendered `TypedDict` property.
+///
+/// Classes are named from the `PascalCase` title; collisions with another
+/// enum, a `TypedDict`, or a reserved name get a numeric suffix in key order.
+fn collect_python_enums(tools: &[ToolContract]) -> PythonEnums {
+    let mut taken: BTreeSet<String> =
+        PYTHON_RESERVED_NAMES.iter().map(|name| (*name).to_string()).collect();
+    let mut found = BTreeMap::new();
+    let mut seen = BTreeSet::new();
+    for tool in tools {
+        for_each_python_typed_dict(tool, &mut seen, |name, schema, role, scope| {
+            taken.insert(name.to_string());
+            let properties = object_properties(schema, role, scope).unwrap_or_default();
+            let additional = additional_properties(schema, scope);
+            for ty in properties.iter().map(|property| &property.ty).chain(additional.as_ref()) {
+                collect_titled_enums(ty, &mut found);
+            }
+        });
+    }
+    let mut classes = BTreeMap::new();
+    for (key, values) in found {
+        let base = python_enum_class_name(&key.0);
+        let mut name = base.clone();
+        let mut suffix = 2;
+        while taken.contains(&name) {
+            name = format!("{base}{suffix}");
+            suffix += 1;
+        }
+        taken.insert(name.clone());
+        classes.insert(key, (name, values));
+    }
+    PythonEnums {
+        classes,
+    }
+}
+
+/// Records titled enums reachable from a type, keeping the first value order seen.
+fn collect_titled_enums(ty: &TypeSpec, found: &mut BTreeMap<(String, Vec<String>), Vec<String>>) {
+    match ty {
+        TypeSpec::TitledEnum {
+            title,
+            values,
+        } => {
+            let mut sorted = values.clone();
+            sorted.sort();
+            found.entry((title.clone(), sorted)).or_insert_with(|| values.clone());
+        }
+        TypeSpec::Array(inner) => collect_titled_enums(inner, found),
+        TypeSpec::Union(types) | TypeSpec::Intersection(types) => {
+            for ty in types {
+                collect_titled_enums(ty, found);
+            }
+        }
+        _ => {}
+    }
+}
+
+/// Renders `class <Name>(str, Enum):` definitions for titled enums.
+fn render_python_enums(out: &mut String, enums: &PythonEnums) {
+    for ((title, _), (name, values)) in &enums.classes {
+        out.push_str("class ");
+        out.push_str(name);
+        out.push_str("(str, Enum):\n");
+        out.push_str("    \"\"\"Allowed values for ");
+        out.push_str(&normalize_doc(title).trim_end_matches('.').replace("\"\"\"", "'''"));
+        out.push_str(".\"\"\"\n");
+        let mut members = BTreeSet::new();
+        for value in values {
+            let base = python_enum_member_name(value);
+            let mut member = base.clone();
+            let mut suffix = 2;
+            while members.contains(&member) {
+                member = format!("{base}_{suffix}");
+                suffix += 1;
+            }
+            out.push_str("    ");
+            out.push_str(&member);
+            out.push_str(" = ");
+            out.push_str(&python_string_literal(value));
+            out.push('\n');
+            members.insert(member);
+        }
+        out.push('\n');
+    }
+}
+
+/// Converts an enum title into a `PascalCase` Python class name.
+fn python_enum_class_name(title: &str) -> String {
+    let mut name = String::new();
+    for segment in title.split(|ch: char| !ch.is_ascii_alphanumeric()) {
+        let mut chars = segment.chars();
+        if let Some(first) = chars.next() {
+            name.push(first.to_ascii_uppercase());
+            name.extend(chars);
+        }
+    }
+    if name.is_empty() || name.starts_with(|ch: char| ch.is_ascii_digit()) {
+        name.insert_str(0, "Enum");
+    }
+    name
+}
+
+/// Converts an enum value into an `UPPER_SNAKE_CASE` Python identifier.
+///
+/// Non-alphanumeric runs and lower-to-upper case boundaries become `_`
+/// (`in-progress` and `inProgress` both become `IN_PROGRESS`). Empty results
+/// become `VALUE`, and leading digits get a `VALUE_` prefix.
+fn python_enum_member_name(value: &str) -> String {
+    let mut name = String::new();
+    let mut previous_lower = false;
+    for ch in value.chars() {
+        if ch.is_ascii_alphanumeric() {
+            if previous_lower && ch.is_ascii_uppercase() {
+                name.push('_');
+            }
+            name.push(ch.to_ascii_uppercase());
+            previous_lower = ch.is_ascii_lowercase() || ch.is_ascii_digit();
+        } else {
+            if !name.is_empty() && !name.ends_with('_') {
+                name.push('_');
+            }
+            previous_lower = false;
+        }
+    }
+    while name.ends_with('_') {
+        name.pop();
+    }
+    if name.is_empty() {
+        return "VALUE".to_string();
+    }
+    if name.starts_with(|ch: char| ch.is_ascii_digit()) {
+        name.insert_str(0, "VALUE_");
+    }
+    name
+}
+
 /// Renders a Python constant holding the JSON schema.
 fn render_python_schema_constant(
     out: &mut String,
@@ -434,7 +1146,7 @@ fn render_python_schema_constant(
 // ============================================================================
 
 /// Renders the TypeScript SDK generated file from tooling contracts.
-fn render_typescript(tools: &[ToolContract]) -> Result<String, SdkGenError> {
+fn render_typescript(tools: &[ToolContract], doc_width: usize) -> Result<String, SdkGenError> {
     let mut out = String::new();
     out.push_str("// This file is @generated by decision-gate-sdk-gen. DO NOT EDIT.\n");
     out.push_str("// Source: ");
@@ -475,20 +1187,72 @@ fn render_typescript(tools: &[ToolContract]) -> Result<String, SdkGenError> {
     }
     out.push_str("};\n\n");
 
+    let mut seen = BTreeSet::new();
     for tool in tools {
         let pascal = pascal_case(tool.name.as_str());
         let input_type = format!("{pascal}Request");
         let output_type = format!("{pascal}Response");
-        render_typescript_interface(&mut out, &input_type, &tool.input_schema);
-        render_typescript_interface(&mut out, &output_type, &tool.output_schema);
+        for (type_name, schema, role) in [
+            (&input_type, &tool.input_schema, SchemaRole::Request),
+            (&output_type, &tool.output_schema, SchemaRole::Response),
+        ] {
+            let scope = RefScope::new(schema, type_name);
+            for (name, definition_scope, definition) in scope.named_definitions() {
+                if !seen.insert(name.clone()) {
+                    continue;
+                }
+                render_typescript_interface(
+                    &mut out,
+                    &name,
+                    definition,
+                    role,
+                    &definition_scope,
+                    None,
+                    doc_width,
+                );
+            }
+            render_typescript_interface(&mut out, type_name, schema, role, &scope, None, doc_width);
+        }
         render_typescript_schema_constant(&mut out, &pascal, "INPUT_SCHEMA", &tool.input_schema)?;
         render_typescript_schema_constant(&mut out, &pascal, "OUTPUT_SCHEMA", &tool.output_schema)?;
     }
 
+    render_typescript_result_types(&mut out, tools, doc_width);
+
+    render_typescript_client_class(&mut out, tools)?;
+
+    render_typescript_validation_helpers(&mut out, tools);
+    Ok(out)
+}
+
+/// Renders the abstract TypeScript client class with throwing and result-typed methods.
+fn render_typescript_client_class(
+    out: &mut String,
+    tools: &[ToolContract],
+) -> Result<(), SdkGenError> {
     out.push_str("export abstract class GeneratedDecisionGateClient {\n");
     out.push_str(
         "  protected abstract callTool<T>(name: string, arguments_: object): Promise<T>;\n\n",
     );
+    out.push_str("  /** Maps a thrown error to a JSON-RPC error; other errors are rethrown. */\n");
+    out.push_str(
+        "  protected jsonRpcErrorFrom(_error: unknown): JsonRpcError | undefined {\n    return \
+         undefined;\n  }\n\n",
+    );
+    out.push_str(
+        "  protected async callToolResult<T>(name: string, arguments_: object): \
+         Promise<ToolResult<T>> {\n",
+    );
+    out.push_str("    try {\n");
+    out.push_str("      return { ok: true, value: await this.callTool<T>(name, arguments_) };\n");
+    out.push_str("    } catch (error) {\n");
+    out.push_str("      const rpcError = this.jsonRpcErrorFrom(error);\n");
+    out.push_str("      if (rpcError === undefined) {\n");
+    out.push_str("        throw error;\n");
+    out.push_str("      }\n");
+    out.push_str("      return { ok: false, error: rpcError };\n");
+    out.push_str("    }\n");
+    out.push_str("  }\n\n");
     for tool in tools {
         let pascal = pascal_case(tool.name.as_str());
         let input_type = format!("{pascal}Request");
@@ -508,7 +1272,11 @@ fn render_typescript(tools: &[ToolContract]) -> Result<String, SdkGenError> {
         }
         if !tool.examples.is_empty() {
             out.push_str("   *\n");
-            render_typescript_examples(&mut out, &tool.examples)?;
+            render_typescript_examples(out, &tool.examples)?;
+        }
+        if tool.deprecated {
+            out.push_str("   *\n");
+            out.push_str("   * @deprecated\n");
         }
         out.push_str("   */\n");
         out.push_str("  public ");
@@ -524,17 +1292,82 @@ fn render_typescript(tools: &[ToolContract]) -> Result<String, SdkGenError> {
         out.push_str(tool.name.as_str());
         out.push_str("\", request);\n");
         out.push_str("  }\n\n");
+        if tool.deprecated {
+            out.push_str("  /**\n   * Calls `");
+            out.push_str(tool.name.as_str());
+            out.push_str("` and returns JSON-RPC errors as a result instead of throwing.\n");
+            out.push_str("   *\n   * @deprecated\n   */\n");
+        } else {
+            out.push_str("  /** Calls `");
+            out.push_str(tool.name.as_str());
+            out.push_str("` and returns JSON-RPC errors as a result instead of throwing. */\n");
+        }
+        out.push_str("  public ");
+        out.push_str(tool.name.as_str());
+        out.push_str("_result(request: ");
+        out.push_str(&input_type);
+        out.push_str("): Promise<");
+        out.push_str(&pascal);
+        out.push_str("Result> {\n");
+        out.push_str("    return this.callToolResult<");
+        out.push_str(&output_type);
+        out.push_str(">(\"");
+        out.push_str(tool.name.as_str());
+        out.push_str("\", request);\n");
+        out.push_str("  }\n\n");
     }
     out.push_str("}\n");
+    Ok(())
+}
 
-    render_typescript_validation_helpers(&mut out, tools);
-    Ok(out)
+/// Renders JSON-RPC error types and per-tool discriminated-union result types.
+fn render_typescript_result_types(out: &mut String, tools: &[ToolContract], doc_width: usize) {
+    let schema = json_rpc_error_data_schema();
+    render_typescript_interface(
+        out,
+        "JsonRpcErrorData",
+        &schema,
+        SchemaRole::Response,
+        &RefScope::new(&schema, "JsonRpcErrorData"),
+        None,
+        doc_width,
+    );
+    out.push_str("export interface JsonRpcError {\n");
+    out.push_str("  code: number;\n");
+    out.push_str("  message: string;\n");
+    out.push_str("  data?: JsonRpcErrorData;\n");
+    out.push_str("}\n\n");
+    out.push_str(
+        "export type ToolResult<T> = { ok: true; value: T } | { ok: false; error: JsonRpcError \
+         };\n\n",
+    );
+    for tool in tools {
+        let pascal = pascal_case(tool.name.as_str());
+        out.push_str("export type ");
+        out.push_str(&pascal);
+        out.push_str("Result = ToolResult<");
+        out.push_str(&pascal);
+        out.push_str("Response>;\n");
+    }
+    out.push('\n');
 }
 
 /// Renders a TypeScript interface for a JSON object schema.
-fn render_typescript_interface(out: &mut String, name: &str, schema: &Value) {
+///
+/// `discriminator` pins one property to a string literal when the interface is
+/// a discriminated union member. Discriminated unions among the properties are
+/// rendered after the interface as named member interfaces and a union alias.
+fn render_typescript_interface(
+    out: &mut String,
+    name: &str,
+    schema: &Value,
+    role: SchemaRole,
+    scope: &RefScope<'_>,
+    discriminator: Option<(&str, &str)>,
+    doc_width: usize,
+) {
     if let Some(doc) = schema_doc(schema) {
-        for line in wrap_doc(&doc, 96) {
+        for line in wrap_doc(&doc, doc_width) {
             out.push_str("/** ");
             out.push_str(&line);
             out.push_str(" */\n");
@@ -543,16 +1376,27 @@ fn render_typescript_interface(out: &mut String, name: &str, schema: &Value) {
     out.push_str("export interface ");
     out.push_str(name);
     out.push_str(" {\n");
-    match object_properties(schema) {
-        Some(properties) if !properties.is_empty() => {
-            for property in properties {
+    let mut unions = Vec::new();
+    match object_properties(schema, role, scope) {
+        Some(mut properties) if !properties.is_empty() => {
+            for property in &mut properties {
+                if let Some((tag, value)) = discriminator
+                    && property.name == tag
+                {
+                    property.ty = TypeSpec::Literal(vec![Value::String(value.to_string())]);
+                }
+                let base = format!("{name}{}", identifier_suffix(&property.name));
+                property.ty = name_discriminated_unions(property.ty.clone(), &base, &mut unions);
                 if let Some(comment) = schema_doc(&property.schema) {
-                    for line in wrap_doc(&comment, 96) {
+                    for line in wrap_doc(&comment, doc_width) {
                         out.push_str("  /** ");
                         out.push_str(&line);
                         out.push_str(" */\n");
                     }
                 }
+                if is_deprecated(&property.schema) {
+                    out.push_str("  /** @deprecated */\n");
+                }
                 out.push_str("  ");
                 out.push_str(&property.name);
                 if !property.required {
@@ -562,57 +1406,497 @@ fn render_typescript_interface(out: &mut String, name: &str, schema: &Value) {
                 out.push_str(&typescript_type(&property.ty));
                 out.push_str(";\n");
             }
+            if let Some(additional) = additional_properties(schema, scope) {
+                render_typescript_index_signature(out, additional, &properties);
+            }
         }
-        _ => {
-            out.push_str("  [key: string]: never;\n");
+        _ => match additional_properties(schema, scope) {
+            Some(additional) => render_typescript_index_signature(out, additional, &[]),
+            None => out.push_str("  [key: string]: never;\n"),
+        },
+    }
+    out.push_str("}\n\n");
+    for (alias, property, members) in unions {
+        render_typescript_discriminated_union(
+            out, &alias, &property, &members, role, scope, doc_width,
+        );
+    }
+}
+
+/// Discriminated union found in a property type: alias, discriminator, members.
+type NamedUnion = (String, String, Vec<(String, Value)>);
+
+/// Replaces discriminated unions in a property type with named aliases.
+///
+/// Aliases are `base`, suffixed with a counter when one property type holds
+/// several unions. The replaced unions are appended to `unions` for rendering.
+fn name_discriminated_unions(ty: TypeSpec, base: &str, unions: &mut Vec<NamedUnion>) -> TypeSpec {
+    match ty {
+        TypeSpec::Discriminated {
+            property,
+            members,
+        } => {
+            let alias = if unions.iter().any(|(alias, ..)| alias == base) {
+                format!("{base}{}", unions.len() + 1)
+            } else {
+                base.to_string()
+            };
+            unions.push((alias.clone(), property, members));
+            TypeSpec::Named(alias)
+        }
+        TypeSpec::Array(inner) => {
+            TypeSpec::Array(Box::new(name_discriminated_unions(*inner, base, unions)))
+        }
+        TypeSpec::Union(types) => TypeSpec::Union(
+            types.into_iter().map(|ty| name_discriminated_unions(ty, base, unions)).collect(),
+        ),
+        other => other,
+    }
+}
+
+/// Renders a discriminated union alias followed by one interface per member.
+///
+/// Members are named after the alias and their discriminator value, falling
+/// back to `Variant<n>` when a value has no identifier characters or collides.
+fn render_typescript_discriminated_union(
+    out: &mut String,
+    alias: &str,
+    property: &str,
+    members: &[(String, Value)],
+    role: SchemaRole,
+    scope: &RefScope<'_>,
+    doc_width: usize,
+) {
+    let mut names: Vec<String> = Vec::with_capacity(members.len());
+    for (index, (value, _)) in members.iter().enumerate() {
+        let suffix = identifier_suffix(value);
+        let name = format!("{alias}{suffix}");
+        if suffix.is_empty() || names.contains(&name) {
+            names.push(format!("{alias}Variant{}", index + 1));
+        } else {
+            names.push(name);
+        }
+    }
+    out.push_str("export type ");
+    out.push_str(alias);
+    out.push_str(" = ");
+    out.push_str(&names.join(" | "));
+    out.push_str(";\n\n");
+    for (name, (value, schema)) in names.iter().zip(members) {
+        render_typescript_interface(
+            out,
+            name,
+            schema,
+            role,
+            scope,
+            Some((property, value)),
+            doc_width,
+        );
+    }
+}
+
+/// Renders an index signature for undeclared keys.
+///
+/// TypeScript requires declared properties to be assignable to the index
+/// signature, so their types are folded into the signature type.
+fn render_typescript_index_signature(
+    out: &mut String,
+    additional: TypeSpec,
+    properties: &[Property],
+) {
+    let ty = union_types(
+        std::iter::once(additional).chain(properties.iter().map(|property| property.ty.clone())),
+    );
+    out.push_str("  [key: string]: ");
+    out.push_str(&typescript_type(&ty));
+    if properties.iter().any(|property| !property.required) {
+        out.push_str(" | undefined");
+    }
+    out.push_str(";\n");
+}
+
+/// Renders a TypeScript constant holding the JSON schema.
+fn render_typescript_schema_constant(
+    out: &mut String,
+    pascal: &str,
+    suffix: &str,
+    schema: &Value,
+) -> Result<(), SdkGenError> {
+    let constant_name = format!("{pascal}_{suffix}");
+    let json =
+        serde_json::to_string_pretty(schema).map_err(|err| SdkGenError::Json(err.to_string()))?;
+    out.push_str("export const ");
+    out.push_str(&constant_name);
+    out.push_str(" = ");
+    out.push_str(&json);
+    out.push_str(" as const;\n\n");
+    Ok(())
+}
+
+// ============================================================================
+// SECTION: Go SDK Rendering
+// ============================================================================
+
+/// Go package name declared by the generated file.
+const GO_PACKAGE: &str = "decisiongate";
+
+/// Doc comment wrap width for Go output.
+const GO_DOC_WIDTH: usize = 96;
+
+/// Go keywords; property names matching one get a trailing underscore.
+const GO_KEYWORDS: [&str; 25] = [
+    "break",
+    "case",
+    "chan",
+    "const",
+    "continue",
+    "default",
+    "defer",
+    "else",
+    "fallthrough",
+    "for",
+    "func",
+    "go",
+    "goto",
+    "if",
+    "import",
+    "interface",
+    "map",
+    "package",
+    "range",
+    "return",
+    "select",
+    "struct",
+    "switch",
+    "type",
+    "var",
+];
+
+/// Renders the Go SDK generated file from tooling contracts.
+///
+/// Output follows gofmt conventions: tab indentation and one blank line
+/// between documented struct fields so no column alignment is required.
+fn render_go(tools: &[ToolContract]) -> String {
+    let mut out = String::new();
+    out.push_str("// Code generated by decision-gate-sdk-gen. DO NOT EDIT.\n");
+    out.push_str("// Source: ");
+    out.push_str(DEFAULT_TOOLING_PATH);
+    out.push_str("\n\n");
+    out.push_str("package ");
+    out.push_str(GO_PACKAGE);
+    out.push_str("\n\n");
+    out.push_str("import (\n\t\"context\"\n\t\"encoding/json\"\n)\n\n");
+
+    out.push_str("// TOOL_NAMES lists the Decision Gate tools in contract order.\n");
+    out.push_str("var TOOL_NAMES = []string{\n");
+    for tool in tools {
+        out.push_str("\t\"");
+        out.push_str(tool.name.as_str());
+        out.push_str("\",\n");
+    }
+    out.push_str("}\n\n");
+
+    out.push_str(
+        "// GeneratedDecisionGateClient is implemented by transports that invoke Decision Gate \
+         tools.\n",
+    );
+    out.push_str("type GeneratedDecisionGateClient interface {\n");
+    out.push_str("\t// CallTool sends a tools/call request and returns the raw JSON result.\n");
+    out.push_str(
+        "\tCallTool(ctx context.Context, name string, arguments any) (json.RawMessage, error)\n",
+    );
+    out.push_str("}\n\n");
+
+    let mut seen = BTreeSet::new();
+    for tool in tools {
+        let pascal = pascal_case(tool.name.as_str());
+        for (type_name, direction, schema, role) in [
+            (format!("{pascal}Request"), "input", &tool.input_schema, SchemaRole::Request),
+            (format!("{pascal}Response"), "output", &tool.output_schema, SchemaRole::Response),
+        ] {
+            let summary = format!("{direction} schema of the {} tool", tool.name.as_str());
+            let scope = RefScope::new(schema, &type_name);
+            for (name, definition_scope, definition) in scope.named_definitions() {
+                if !seen.insert(name.clone()) {
+                    continue;
+                }
+                render_go_struct(
+                    &mut out,
+                    &name,
+                    &format!("definition used by the {summary}"),
+                    definition,
+                    role,
+                    &definition_scope,
+                );
+            }
+            render_go_struct(&mut out, &type_name, &summary, schema, role, &scope);
+        }
+    }
+
+    out.push_str("// callTool invokes a tool and decodes its JSON result into response.\n");
+    out.push_str(
+        "func callTool(ctx context.Context, client GeneratedDecisionGateClient, name string, \
+         request any, response any) error {\n",
+    );
+    out.push_str("\traw, err := client.CallTool(ctx, name, request)\n");
+    out.push_str("\tif err != nil {\n\t\treturn err\n\t}\n");
+    out.push_str("\treturn json.Unmarshal(raw, response)\n");
+    out.push_str("}\n");
+
+    for tool in tools {
+        render_go_tool_function(&mut out, tool);
+    }
+    out
+}
+
+/// Renders a Go struct for a JSON object schema.
+///
+/// Schemas without declared properties render as `struct{}` when closed and
+/// as `map[string]any` when they accept arbitrary keys.
+fn render_go_struct(
+    out: &mut String,
+    name: &str,
+    summary: &str,
+    schema: &Value,
+    role: SchemaRole,
+    scope: &RefScope<'_>,
+) {
+    out.push_str("// ");
+    out.push_str(name);
+    out.push_str(" is the ");
+    out.push_str(summary);
+    out.push_str(".\n");
+    if let Some(doc) = schema_doc(schema) {
+        out.push_str("//\n");
+        render_go_doc_lines(out, "", &doc);
+    }
+    out.push_str("type ");
+    out.push_str(name);
+    let properties = object_properties(schema, role, scope).unwrap_or_default();
+    if properties.is_empty() {
+        if additional_properties(schema, scope).is_some() {
+            out.push_str(" map[string]any\n\n");
+        } else {
+            out.push_str(" struct{}\n\n");
+        }
+        return;
+    }
+    out.push_str(" struct {\n");
+    let mut field_names = Vec::with_capacity(properties.len());
+    for (index, property) in properties.iter().enumerate() {
+        if index > 0 {
+            out.push('\n');
+        }
+        if let Some(doc) = schema_doc(&property.schema) {
+            render_go_doc_lines(out, "\t", &doc);
+        }
+        let base = go_type(&property.ty);
+        if base == "any"
+            && let TypeSpec::Union(members) = &property.ty
+        {
+            let mut rendered: Vec<String> = members
+                .iter()
+                .map(|member| {
+                    if matches!(member, TypeSpec::Null) {
+                        "null".to_string()
+                    } else {
+                        go_type(member)
+                    }
+                })
+                .collect();
+            rendered.sort();
+            rendered.dedup();
+            out.push_str("\t// One of: ");
+            out.push_str(&rendered.join(", "));
+            out.push_str(".\n");
+        }
+        let field_name = go_field_name(&property.name, &field_names);
+        out.push('\t');
+        out.push_str(&field_name);
+        out.push(' ');
+        if !property.required && go_is_pointable(&base) {
+            out.push('*');
+        }
+        out.push_str(&base);
+        out.push_str(" `json:\"");
+        out.push_str(&property.name);
+        if !property.required {
+            out.push_str(",omitempty");
+        }
+        out.push_str("\"`\n");
+        field_names.push(field_name);
+    }
+    out.push_str("}\n\n");
+}
+
+/// Renders the typed Go wrapper function for one tool.
+fn render_go_tool_function(out: &mut String, tool: &ToolContract) {
+    let pascal = pascal_case(tool.name.as_str());
+    out.push('\n');
+    out.push_str("// ");
+    out.push_str(&pascal);
+    out.push_str(" calls the ");
+    out.push_str(tool.name.as_str());
+    out.push_str(" tool.\n//\n");
+    render_go_doc_lines(out, "", &normalize_doc(&tool.description));
+    if !tool.notes.is_empty() {
+        out.push_str("//\n// Notes:\n");
+        for note in &tool.notes {
+            out.push_str("//   - ");
+            out.push_str(&normalize_doc(note));
+            out.push('\n');
+        }
+    }
+    out.push_str("func ");
+    out.push_str(&pascal);
+    out.push_str("(ctx context.Context, client GeneratedDecisionGateClient, request ");
+    out.push_str(&pascal);
+    out.push_str("Request) (");
+    out.push_str(&pascal);
+    out.push_str("Response, error) {\n");
+    out.push_str("\tvar response ");
+    out.push_str(&pascal);
+    out.push_str("Response\n");
+    out.push_str("\terr := callTool(ctx, client, \"");
+    out.push_str(tool.name.as_str());
+    out.push_str("\", request, &response)\n");
+    out.push_str("\treturn response, err\n");
+    out.push_str("}\n");
+}
+
+/// Renders wrapped `//` doc comment lines at the given indentation.
+fn render_go_doc_lines(out: &mut String, indent: &str, doc: &str) {
+    for line in wrap_doc(doc, GO_DOC_WIDTH) {
+        out.push_str(indent);
+        out.push_str("// ");
+        out.push_str(&line);
+        out.push('\n');
+    }
+}
+
+/// Returns the exported Go field name for a JSON property.
+///
+/// Names are converted to `PascalCase`; Go keywords get a trailing underscore,
+/// and collisions with earlier fields append further underscores.
+fn go_field_name(property: &str, existing: &[String]) -> String {
+    let sanitized: String =
+        property.chars().map(|ch| if ch.is_ascii_alphanumeric() { ch } else { '_' }).collect();
+    let mut name = pascal_case(&sanitized);
+    if name.starts_with(|ch: char| ch.is_ascii_digit()) {
+        name.insert(0, 'X');
+    }
+    if GO_KEYWORDS.contains(&property) {
+        name.push('_');
+    }
+    while existing.contains(&name) {
+        name.push('_');
+    }
+    name
+}
+
+/// Renders a Go type for the internal type representation.
+///
+/// A union of one type with `null` maps to a pointer (or the nil-able type
+/// itself); other unions map to `any`.
+fn go_type(ty: &TypeSpec) -> String {
+    match ty {
+        TypeSpec::Any | TypeSpec::Null => "any".to_string(),
+        TypeSpec::Bool => "bool".to_string(),
+        TypeSpec::Int | TypeSpec::Int64 => "int64".to_string(),
+        TypeSpec::Number => "float64".to_string(),
+        TypeSpec::String
+        | TypeSpec::TitledEnum {
+            ..
+        } => "string".to_string(),
+        TypeSpec::Array(inner) => format!("[]{}", go_type(inner)),
+        TypeSpec::Object
+        | TypeSpec::Intersection(_)
+        | TypeSpec::Discriminated {
+            ..
+        } => "map[string]any".to_string(),
+        TypeSpec::Union(types) => match types.as_slice() {
+            [TypeSpec::Null, other] | [other, TypeSpec::Null] => go_nullable(&go_type(other)),
+            _ => "any".to_string(),
+        },
+        TypeSpec::Literal(values) => {
+            let nullable = values.iter().any(Value::is_null);
+            let base = go_literal_type(values.iter().filter(|value| !value.is_null()));
+            if nullable { go_nullable(&base) } else { base }
         }
+        TypeSpec::Named(name) => name.clone(),
     }
-    out.push_str("}\n\n");
 }
 
-/// Renders a TypeScript constant holding the JSON schema.
-fn render_typescript_schema_constant(
-    out: &mut String,
-    pascal: &str,
-    suffix: &str,
-    schema: &Value,
-) -> Result<(), SdkGenError> {
-    let constant_name = format!("{pascal}_{suffix}");
-    let json =
-        serde_json::to_string_pretty(schema).map_err(|err| SdkGenError::Json(err.to_string()))?;
-    out.push_str("export const ");
-    out.push_str(&constant_name);
-    out.push_str(" = ");
-    out.push_str(&json);
-    out.push_str(" as const;\n\n");
-    Ok(())
+/// Returns the shared Go type of non-null literal values, or `any`.
+fn go_literal_type<'a>(mut values: impl Iterator<Item = &'a Value>) -> String {
+    let Some(first) = values.next() else {
+        return "any".to_string();
+    };
+    let kind = |value: &Value| match value {
+        Value::Bool(_) => "bool",
+        Value::Number(number) if number.is_i64() || number.is_u64() => "int64",
+        Value::Number(_) => "float64",
+        Value::String(_) => "string",
+        _ => "any",
+    };
+    let mut shared = kind(first);
+    for value in values {
+        shared = match (shared, kind(value)) {
+            (left, right) if left == right => left,
+            ("int64", "float64") | ("float64", "int64") => "float64",
+            _ => "any",
+        };
+    }
+    shared.to_string()
+}
+
+/// Wraps scalar and struct Go types in a pointer so `null` remains representable.
+fn go_nullable(base: &str) -> String {
+    if go_is_pointable(base) { format!("*{base}") } else { base.to_string() }
+}
+
+/// Returns true for Go types whose zero value cannot represent absence.
+///
+/// Scalars and named structs qualify; slices, maps, `any`, and pointers are
+/// already nil-able.
+fn go_is_pointable(ty: &str) -> bool {
+    matches!(ty, "bool" | "int64" | "float64" | "string")
+        || ty.starts_with(|ch: char| ch.is_ascii_uppercase())
 }
 
 // ============================================================================
 // SECTION: OpenAPI Rendering
 // ============================================================================
 
+/// Returns the JSON-RPC error `data` schema shared by `OpenAPI` and SDK types.
+fn json_rpc_error_data_schema() -> Value {
+    serde_json::json!({
+        "type": "object",
+        "properties": {
+            "kind": { "type": "string" },
+            "retryable": { "type": "boolean" },
+            "request_id": { "type": "string" },
+            "retry_after_ms": { "type": "integer" }
+        },
+        "required": ["kind", "retryable"],
+        "additionalProperties": false
+    })
+}
+
 /// Renders the `OpenAPI` JSON document for the JSON-RPC tools/call surface.
+///
+/// Each tool is also listed under the `x-tools` extension with refs to its
+/// params and result schemas, since the single `/rpc` operation hides them.
+/// Provider-discovery responses are also components named like the SDK
+/// response types (`ProvidersListResponse`), alongside their hoisted
+/// definitions (`ProviderSummary`).
 #[allow(
     clippy::too_many_lines,
     reason = "OpenAPI assembly is kept in one place to mirror the schema output."
 )]
-fn render_openapi(tools: &[ToolContract]) -> Result<String, SdkGenError> {
+fn render_openapi(tools: &[ToolContract], server: Option<&str>) -> Result<String, SdkGenError> {
     let mut schemas = serde_json::Map::new();
-    schemas.insert(
-        "JsonRpcErrorData".to_string(),
-        serde_json::json!({
-            "type": "object",
-            "properties": {
-                "kind": { "type": "string" },
-                "retryable": { "type": "boolean" },
-                "request_id": { "type": "string" },
-                "retry_after_ms": { "type": "integer" }
-            },
-            "required": ["kind", "retryable"],
-            "additionalProperties": false
-        }),
-    );
+    schemas.insert("JsonRpcErrorData".to_string(), json_rpc_error_data_schema());
     schemas.insert(
         "JsonRpcError".to_string(),
         serde_json::json!({
@@ -629,11 +1913,21 @@ fn render_openapi(tools: &[ToolContract]) -> Result<String, SdkGenError> {
 
     let mut tool_param_refs = Vec::new();
     let mut tool_result_refs = Vec::new();
+    let mut tool_index = serde_json::Map::new();
 
     for tool in tools {
         let pascal = pascal_case(tool.name.as_str());
         let params_name = format!("{pascal}ToolCallParams");
         let result_name = format!("{pascal}ToolCallResult");
+        let output = if DISCOVERY_RESPONSE_TOOLS.contains(&tool.name.as_str()) {
+            let output_type = format!("{pascal}Response");
+            let scope = RefScope::new(&tool.output_schema, &output_type);
+            let component = openapi_component(&tool.output_schema, &scope, &mut schemas)?;
+            insert_openapi_component(&mut schemas, output_type.clone(), component)?;
+            serde_json::json!({ "$ref": format!("#/components/schemas/{output_type}") })
+        } else {
+            tool.output_schema.clone()
+        };
 
         schemas.insert(
             params_name.clone(),
@@ -659,7 +1953,7 @@ fn render_openapi(tools: &[ToolContract]) -> Result<String, SdkGenError> {
                             "type": "object",
                             "properties": {
                                 "type": { "const": "json" },
-                                "json": tool.output_schema.clone()
+                                "json": output
                             },
                             "required": ["type", "json"],
                             "additionalProperties": false
@@ -672,12 +1966,21 @@ fn render_openapi(tools: &[ToolContract]) -> Result<String, SdkGenError> {
             }),
         );
 
-        tool_param_refs.push(serde_json::json!({
+        let params_ref = serde_json::json!({
             "$ref": format!("#/components/schemas/{params_name}")
-        }));
-        tool_result_refs.push(serde_json::json!({
+        });
+        let result_ref = serde_json::json!({
             "$ref": format!("#/components/schemas/{result_name}")
-        }));
+        });
+        tool_index.insert(
+            tool.name.as_str().to_string(),
+            serde_json::json!({
+                "params": params_ref.clone(),
+                "result": result_ref.clone()
+            }),
+        );
+        tool_param_refs.push(params_ref);
+        tool_result_refs.push(result_ref);
     }
 
     schemas.insert(
@@ -733,7 +2036,7 @@ fn render_openapi(tools: &[ToolContract]) -> Result<String, SdkGenError> {
         }),
     );
 
-    let openapi = serde_json::json!({
+    let mut openapi = serde_json::json!({
         "openapi": "3.1.0",
         "jsonSchemaDialect": "https://json-schema.org/draft/2020-12/schema",
         "info": {
@@ -744,6 +2047,7 @@ fn render_openapi(tools: &[ToolContract]) -> Result<String, SdkGenError> {
         "paths": {
             "/rpc": {
                 "post": {
+                    "operationId": "toolsCall",
                     "summary": "Invoke a Decision Gate tool via JSON-RPC.",
                     "requestBody": {
                         "required": true,
@@ -768,21 +2072,402 @@ fn render_openapi(tools: &[ToolContract]) -> Result<String, SdkGenError> {
         },
         "components": {
             "schemas": schemas
-        }
+        },
+        "x-tools": tool_index
     });
+    if let (Some(url), Some(document)) = (server, openapi.as_object_mut()) {
+        document.insert("servers".to_string(), serde_json::json!([{ "url": url }]));
+    }
 
     let openapi = sorted_json_value(&openapi);
     serde_json::to_string_pretty(&openapi).map_err(|err| SdkGenError::Json(err.to_string()))
 }
 
+/// Returns `schema` as an `OpenAPI` component, hoisting its local definitions.
+///
+/// Each `$defs`/`definitions` entry is inserted into `schemas` under its SDK
+/// type name and local refs are rewritten to point at it, since `#/$defs/...`
+/// would otherwise resolve against the `OpenAPI` document root.
+///
+/// # Errors
+/// Returns [`SdkGenError::Tooling`] when a hoisted definition collides with a
+/// different component of the same name.
+fn openapi_component(
+    schema: &Value,
+    scope: &RefScope<'_>,
+    schemas: &mut serde_json::Map<String, Value>,
+) -> Result<Value, SdkGenError> {
+    for keyword in ["$defs", "definitions"] {
+        let Some(entries) = schema.get(keyword).and_then(Value::as_object) else {
+            continue;
+        };
+        for (name, definition) in entries {
+            let component = openapi_component_refs(definition, scope);
+            insert_openapi_component(schemas, scope.type_name(name, definition), component)?;
+        }
+    }
+    let mut component = openapi_component_refs(schema, scope);
+    if let Value::Object(map) = &mut component {
+        map.remove("$defs");
+        map.remove("definitions");
+    }
+    Ok(component)
+}
+
+/// Inserts a named component, rejecting a different schema under the same name.
+fn insert_openapi_component(
+    schemas: &mut serde_json::Map<String, Value>,
+    name: String,
+    component: Value,
+) -> Result<(), SdkGenError> {
+    if let Some(existing) = schemas.get(&name) {
+        if existing == &component {
+            return Ok(());
+        }
+        return Err(SdkGenError::Tooling(format!(
+            "openapi component {name} is defined with conflicting schemas"
+        )));
+    }
+    schemas.insert(name, component);
+    Ok(())
+}
+
+/// Rewrites local refs resolvable in `scope` to `#/components/schemas/...`.
+fn openapi_component_refs(value: &Value, scope: &RefScope<'_>) -> Value {
+    match value {
+        Value::Object(map) => Value::Object(
+            map.iter()
+                .map(|(key, value)| {
+                    let target = (key == "$ref")
+                        .then(|| value.as_str().and_then(|reference| scope.resolve(reference)))
+                        .flatten();
+                    let value = target.map_or_else(
+                        || openapi_component_refs(value, scope),
+                        |(name, definition)| {
+                            Value::String(format!(
+                                "#/components/schemas/{}",
+                                scope.type_name(name, definition)
+                            ))
+                        },
+                    );
+                    (key.clone(), value)
+                })
+                .collect(),
+        ),
+        Value::Array(items) => {
+            Value::Array(items.iter().map(|item| openapi_component_refs(item, scope)).collect())
+        }
+        _ => value.clone(),
+    }
+}
+
+// ============================================================================
+// SECTION: Schema Bundle Rendering
+// ============================================================================
+
+/// Renders a JSON Schema 2020-12 bundle of all tool input and output schemas.
+///
+/// Each entry without an `$id` gets `decision-gate://sdk/schemas/{key}.json`,
+/// making it a separate schema resource so local `$ref`s and nested `$schema`
+/// keywords resolve against the tool schema rather than the bundle root.
+fn render_schema_bundle(tools: &[ToolContract]) -> Result<String, SdkGenError> {
+    let mut defs = serde_json::Map::new();
+    for tool in tools {
+        for (suffix, schema) in [("input", &tool.input_schema), ("output", &tool.output_schema)] {
+            let key = format!("{}_{suffix}", tool.name);
+            let mut schema = schema.clone();
+            if let Value::Object(map) = &mut schema {
+                map.entry("$id").or_insert_with(|| {
+                    Value::String(format!("decision-gate://sdk/schemas/{key}.json"))
+                });
+            }
+            defs.insert(key, schema);
+        }
+    }
+    let bundle = serde_json::json!({
+        "$schema": "https://json-schema.org/draft/2020-12/schema",
+        "$id": SCHEMA_BUNDLE_ID,
+        "title": "Decision Gate tool schemas",
+        "$defs": defs
+    });
+    let bundle = sorted_json_value(&bundle);
+    serde_json::to_string_pretty(&bundle).map_err(|err| SdkGenError::Json(err.to_string()))
+}
+
+// ============================================================================
+// SECTION: Lifecycle Example Rendering
+// ============================================================================
+
+/// Resolves each lifecycle tool to its first contract example.
+fn lifecycle_steps(tools: &[ToolContract]) -> Result<Vec<(&str, &ToolExample)>, SdkGenError> {
+    LIFECYCLE_EXAMPLE_TOOLS
+        .iter()
+        .map(|name| {
+            let tool = tools.iter().find(|tool| tool.name.as_str() == *name).ok_or_else(|| {
+                SdkGenError::Tooling(format!("lifecycle example tool missing: {name}"))
+            })?;
+            let example = tool.examples.first().ok_or_else(|| {
+                SdkGenError::Tooling(format!("lifecycle example tool has no examples: {name}"))
+            })?;
+            Ok((*name, example))
+        })
+        .collect()
+}
+
+/// Renders the Python scenario lifecycle example module.
+fn render_python_lifecycle_example(steps: &[(&str, &ToolExample)]) -> Result<String, SdkGenError> {
+    let mut out = String::new();
+    out.push_str("# This file is @generated by decision-gate-sdk-gen. DO NOT EDIT.\n");
+    out.push_str("# Source: ");
+    out.push_str(DEFAULT_TOOLING_PATH);
+    out.push('\n');
+    out.push_str("# fmt: off\n\n");
+    out.push_str("from __future__ import annotations\n\n");
+    out.push_str("import json as _json\n");
+    out.push_str("from typing import Sequence, cast\n\n");
+    out.push_str("from ._generated import (\n");
+    out.push_str("    GeneratedDecisionGateClient,\n");
+    for (name, _) in steps {
+        let _ = writeln!(out, "    {}Request,", pascal_case(name));
+    }
+    let last_name = steps.last().map_or("", |(name, _)| *name);
+    let last_response = format!("{}Response", pascal_case(last_name));
+    let _ = writeln!(out, "    {last_response},");
+    out.push_str(")\n\n");
+    out.push_str("LIFECYCLE_TOOL_ORDER: Sequence[str] = (\n");
+    for (name, _) in steps {
+        let _ = writeln!(out, "    \"{name}\",");
+    }
+    out.push_str(")\n\n");
+    out.push_str("def run_lifecycle_example(client: GeneratedDecisionGateClient) -> ");
+    out.push_str(&last_response);
+    out.push_str(":\n");
+    out.push_str(
+        "    \"\"\"Run a full scenario lifecycle using the tooling contract examples.\"\"\"\n",
+    );
+    for (index, (name, example)) in steps.iter().enumerate() {
+        let pascal = pascal_case(name);
+        let json = serde_json::to_string_pretty(&example.input)
+            .map_err(|err| SdkGenError::Json(err.to_string()))?;
+        let _ = writeln!(out, "    # Step {}: {}", index + 1, normalize_doc(&example.description));
+        let _ = writeln!(out, "    {name}_request = cast({pascal}Request, _json.loads(r\"\"\"");
+        out.push_str(&json);
+        out.push_str("\n\"\"\"))\n");
+        if *name == last_name {
+            let _ = writeln!(out, "    return client.{name}({name}_request)");
+        } else {
+            let _ = writeln!(out, "    client.{name}({name}_request)");
+        }
+    }
+    out.push('\n');
+    out.push_str("__all__ = [\n");
+    out.push_str("    \"LIFECYCLE_TOOL_ORDER\",\n");
+    out.push_str("    \"run_lifecycle_example\",\n");
+    out.push_str("]\n");
+    Ok(out)
+}
+
+/// Renders the TypeScript scenario lifecycle example module.
+fn render_typescript_lifecycle_example(
+    steps: &[(&str, &ToolExample)],
+) -> Result<String, SdkGenError> {
+    let mut out = String::new();
+    out.push_str("// This file is @generated by decision-gate-sdk-gen. DO NOT EDIT.\n");
+    out.push_str("// Source: ");
+    out.push_str(DEFAULT_TOOLING_PATH);
+    out.push('\n');
+    out.push_str("// fmt: off\n\n");
+    out.push_str("import type {\n");
+    out.push_str("  GeneratedDecisionGateClient,\n");
+    for (name, _) in steps {
+        let _ = writeln!(out, "  {}Request,", pascal_case(name));
+    }
+    let last_name = steps.last().map_or("", |(name, _)| *name);
+    let last_response = format!("{}Response", pascal_case(last_name));
+    let _ = writeln!(out, "  {last_response},");
+    out.push_str("} from \"./_generated.js\";\n\n");
+    out.push_str("export const LIFECYCLE_TOOL_ORDER = [\n");
+    for (name, _) in steps {
+        let _ = writeln!(out, "  \"{name}\",");
+    }
+    out.push_str("] as const;\n\n");
+    out.push_str("/** Runs a full scenario lifecycle using the tooling contract examples. */\n");
+    out.push_str("export async function runLifecycleExample(\n");
+    out.push_str("  client: GeneratedDecisionGateClient,\n");
+    let _ = writeln!(out, "): Promise<{last_response}> {{");
+    for (index, (name, example)) in steps.iter().enumerate() {
+        let pascal = pascal_case(name);
+        let variable = camel_case(name);
+        let json = serde_json::to_string_pretty(&example.input)
+            .map_err(|err| SdkGenError::Json(err.to_string()))?;
+        let _ = writeln!(out, "  // Step {}: {}", index + 1, normalize_doc(&example.description));
+        let _ = write!(out, "  const {variable}Request = ");
+        for (line_index, line) in json.lines().enumerate() {
+            if line_index > 0 {
+                out.push_str("\n  ");
+            }
+            out.push_str(line);
+        }
+        let _ = writeln!(out, " as unknown as {pascal}Request;");
+        if *name == last_name {
+            let _ = writeln!(out, "  return client.{name}({variable}Request);");
+        } else {
+            let _ = writeln!(out, "  await client.{name}({variable}Request);");
+        }
+    }
+    out.push_str("}\n");
+    Ok(out)
+}
+
 // ============================================================================
 // SECTION: Schema Introspection and Documentation
 // ============================================================================
 
+/// Returns the type allowed for undeclared keys of an object schema.
+///
+/// Returns `None` for non-object schemas and for `additionalProperties: false`.
+/// A missing keyword follows JSON Schema and allows any value.
+fn additional_properties(schema: &Value, scope: &RefScope<'_>) -> Option<TypeSpec> {
+    if schema.get("additionalProperties").is_none()
+        && let Some(members) = all_of_objects(schema, scope)
+    {
+        return all_of_additional_properties(&members);
+    }
+    if !is_object_schema(schema) {
+        return None;
+    }
+    match schema.get("additionalProperties") {
+        Some(Value::Bool(false)) => None,
+        None | Some(Value::Bool(true)) => Some(TypeSpec::Any),
+        Some(additional) => Some(schema_to_typespec(additional, scope)),
+    }
+}
+
+/// Returns the undeclared-key type for an `allOf` object composition.
+///
+/// The merged object is closed only when every member is closed. Members that
+/// disagree on the undeclared-key type widen it to `Any`.
+fn all_of_additional_properties(members: &[(&Value, RefScope<'_>)]) -> Option<TypeSpec> {
+    let types: Vec<Option<TypeSpec>> =
+        members.iter().map(|(member, scope)| additional_properties(member, scope)).collect();
+    let open: Vec<&TypeSpec> = types.iter().flatten().collect();
+    match open.first() {
+        None => None,
+        Some(first) if open.len() == types.len() && open.iter().all(|ty| ty == first) => {
+            Some((*first).clone())
+        }
+        Some(_) => Some(TypeSpec::Any),
+    }
+}
+
+/// Returns true when a schema describes a JSON object.
+fn is_object_schema(schema: &Value) -> bool {
+    schema.get("type").and_then(Value::as_str) == Some("object")
+        || schema.get("properties").is_some()
+}
+
+/// Returns true when a schema is an object or an `allOf` of objects.
+fn is_object_like(schema: &Value, scope: &RefScope<'_>) -> bool {
+    is_object_schema(schema) || all_of_objects(schema, scope).is_some()
+}
+
+/// Resolves the `allOf` members of a schema when every member is an object.
+///
+/// Local refs are followed, and each member is returned with the scope its
+/// properties render in. Returns `None` when the schema has no `allOf` or any
+/// member is a non-object, an unresolvable ref, or a cyclic ref.
+fn all_of_objects<'s, 'a: 's>(
+    schema: &'s Value,
+    scope: &RefScope<'a>,
+) -> Option<Vec<(&'s Value, RefScope<'a>)>> {
+    let members = schema.get("allOf")?.as_array()?;
+    let mut output = Vec::with_capacity(members.len());
+    for member in members {
+        let (member, member_scope) = match member.get("$ref") {
+            Some(reference) => {
+                let reference = reference.as_str()?;
+                let (_, target) = scope.resolve(reference)?;
+                (target, scope.enter(reference)?)
+            }
+            None => (member, scope.clone()),
+        };
+        if !is_object_like(member, &member_scope) {
+            return None;
+        }
+        output.push((member, member_scope));
+    }
+    (!output.is_empty()).then_some(output)
+}
+
+/// Returns true when any tool schema or object definition accepts undeclared keys.
+fn has_open_schema(tools: &[ToolContract]) -> bool {
+    tools.iter().flat_map(|tool| [&tool.input_schema, &tool.output_schema]).any(|schema| {
+        let scope = RefScope::new(schema, "");
+        additional_properties(schema, &scope).is_some()
+            || scope
+                .named_definitions()
+                .iter()
+                .any(|(_, scope, definition)| additional_properties(definition, scope).is_some())
+    })
+}
+
 /// Extracts top-level object properties from a JSON schema.
 ///
-/// Properties are returned in sorted order for deterministic output.
-fn object_properties(schema: &Value) -> Option<Vec<Property>> {
+/// Properties are returned in sorted order for deterministic output. `readOnly`
+/// properties are dropped from request types and `writeOnly` properties from
+/// response types.
+fn object_properties(
+    schema: &Value,
+    role: SchemaRole,
+    scope: &RefScope<'_>,
+) -> Option<Vec<Property>> {
+    let Some(members) = all_of_objects(schema, scope) else {
+        return declared_properties(schema, role, scope);
+    };
+    let mut merged = BTreeMap::new();
+    for (member, member_scope) in &members {
+        for property in object_properties(member, role, member_scope).into_iter().flatten() {
+            merge_property(&mut merged, property);
+        }
+    }
+    for property in declared_properties(schema, role, scope).into_iter().flatten() {
+        merge_property(&mut merged, property);
+    }
+    for name in schema.get("required").and_then(Value::as_array).into_iter().flatten() {
+        if let Some(property) = name.as_str().and_then(|name| merged.get_mut(name)) {
+            property.required = true;
+        }
+    }
+    Some(merged.into_values().collect())
+}
+
+/// Merges an `allOf` member property into the combined property set.
+///
+/// The last declaration wins when types match; conflicting types widen to
+/// `Any`. A property is required when any member requires it.
+fn merge_property(merged: &mut BTreeMap<String, Property>, property: Property) {
+    let Some(existing) = merged.get(&property.name) else {
+        merged.insert(property.name.clone(), property);
+        return;
+    };
+    let required = existing.required || property.required;
+    let ty = if existing.ty == property.ty { property.ty.clone() } else { TypeSpec::Any };
+    merged.insert(
+        property.name.clone(),
+        Property {
+            ty,
+            required,
+            ..property
+        },
+    );
+}
+
+/// Extracts the properties declared directly on an object schema.
+fn declared_properties(
+    schema: &Value,
+    role: SchemaRole,
+    scope: &RefScope<'_>,
+) -> Option<Vec<Property>> {
     let properties = schema.get("properties")?.as_object()?;
     let required_list = schema.get("required").and_then(|value| value.as_array());
     let mut required = BTreeMap::new();
@@ -798,7 +2483,14 @@ fn object_properties(schema: &Value) -> Option<Vec<Property>> {
     names.sort();
     for name in names {
         let schema = &properties[name];
-        let ty = schema_to_typespec(schema);
+        let hidden_keyword = match role {
+            SchemaRole::Request => "readOnly",
+            SchemaRole::Response => "writeOnly",
+        };
+        if schema.get(hidden_keyword).and_then(Value::as_bool) == Some(true) {
+            continue;
+        }
+        let ty = schema_to_typespec(schema, scope);
         let is_required = required.contains_key(name.as_str());
         output.push(Property {
             name: name.clone(),
@@ -882,12 +2574,17 @@ fn schema_constraints(schema: &Value) -> Vec<String> {
     if let Some(title) = schema.get("title").and_then(Value::as_str) {
         items.push(format!("Title: {}", normalize_doc(title)));
     }
-    if schema.get("deprecated").and_then(Value::as_bool) == Some(true) {
+    if is_deprecated(schema) {
         items.push("Deprecated.".to_string());
     }
     items
 }
 
+/// Returns true when a schema is marked `deprecated: true`.
+fn is_deprecated(schema: &Value) -> bool {
+    schema.get("deprecated").and_then(Value::as_bool) == Some(true)
+}
+
 /// Normalizes documentation strings by collapsing whitespace and defusing
 /// comment or docstring terminators in generated outputs.
 fn normalize_doc(value: &str) -> String {
@@ -901,6 +2598,32 @@ fn json_inline(value: &Value) -> String {
     serde_json::to_string(value).unwrap_or_else(|_| "<unprintable>".to_string())
 }
 
+/// Indentation unit emitted by the Python renderers.
+const PYTHON_INDENT: &str = "    ";
+
+/// Indentation unit emitted by the TypeScript renderers.
+const TYPESCRIPT_INDENT: &str = "  ";
+
+/// Rewrites leading indentation from the renderer's `default_unit` to `style`.
+///
+/// Only whole leading units are replaced; any remaining leading spaces are kept.
+fn reindent(text: &str, default_unit: &str, style: IndentStyle) -> String {
+    let unit = style.unit();
+    if unit == default_unit {
+        return text.to_string();
+    }
+    let mut out = String::with_capacity(text.len());
+    for line in text.split_inclusive('\n') {
+        let mut rest = line;
+        while let Some(stripped) = rest.strip_prefix(default_unit) {
+            out.push_str(&unit);
+            rest = stripped;
+        }
+        out.push_str(rest);
+    }
+    out
+}
+
 /// Wraps documentation text to a target width.
 fn wrap_doc(text: &str, width: usize) -> Vec<String> {
     let mut lines = Vec::new();
@@ -1041,7 +2764,7 @@ fn render_python_validation_helpers(out: &mut String, tools: &[ToolContract]) {
 }
 
 /// Emits the Python `__all__` export list.
-fn render_python_exports(out: &mut String, tools: &[ToolContract]) {
+fn render_python_exports(out: &mut String, tools: &[ToolContract], enums: &PythonEnums) {
     let mut exports = vec![
         "JsonPrimitive".to_string(),
         "JsonValue".to_string(),
@@ -1049,18 +2772,22 @@ fn render_python_exports(out: &mut String, tools: &[ToolContract]) {
         "TOOL_DESCRIPTIONS".to_string(),
         "TOOL_NOTES".to_string(),
         "GeneratedDecisionGateClient".to_string(),
+        "AsyncGeneratedDecisionGateClient".to_string(),
         "SchemaValidationError".to_string(),
         "validate_schema".to_string(),
     ];
+    let mut seen = BTreeSet::new();
     for tool in tools {
         let pascal = pascal_case(tool.name.as_str());
-        exports.push(format!("{pascal}Request"));
-        exports.push(format!("{pascal}Response"));
+        for_each_python_typed_dict(tool, &mut seen, |name, _, _, _| {
+            exports.push(name.to_string());
+        });
         exports.push(format!("{pascal}_INPUT_SCHEMA"));
         exports.push(format!("{pascal}_OUTPUT_SCHEMA"));
         exports.push(format!("validate_{}_request", tool.name.as_str()));
         exports.push(format!("validate_{}_response", tool.name.as_str()));
     }
+    exports.extend(enums.classes.values().map(|(name, _)| name.clone()));
     out.push_str("__all__ = [\n");
     for name in exports {
         out.push_str("    \"");
@@ -1177,42 +2904,125 @@ fn sorted_json_value(value: &Value) -> Value {
 
 /// Maps a JSON schema to an internal type representation.
 ///
-/// The mapping is intentionally conservative: `$ref` becomes `Any`, enums of
-/// JSON literals become `Literal`, and unrecognized types fall back to `Any`.
-fn schema_to_typespec(schema: &Value) -> TypeSpec {
+/// The mapping is intentionally conservative: enums of JSON literals become
+/// `Literal` (or `TitledEnum` for titled string enums), and unrecognized types fall back to `Any`.
+/// Local `$ref`s resolve through `scope`; object definitions become `Named`, and unresolvable,
+/// cyclic, or overly deep refs become `Any`. `allOf` of objects becomes an
+/// `Intersection`; any non-object member makes it `Any`. A `oneOf` of inline
+/// objects sharing a required `const` string property becomes `Discriminated`.
+fn schema_to_typespec(schema: &Value, scope: &RefScope<'_>) -> TypeSpec {
+    if let Some(all_of) = schema.get("allOf").and_then(|value| value.as_array()) {
+        let own = is_object_schema(schema).then_some(TypeSpec::Object);
+        return intersection_types(
+            all_of.iter().map(|item| schema_to_typespec(item, scope)).chain(own),
+        );
+    }
     if let Some(one_of) = schema.get("oneOf").and_then(|value| value.as_array()) {
-        return union_types(one_of.iter().map(schema_to_typespec));
+        if let Some(discriminated) = discriminated_union(one_of) {
+            return discriminated;
+        }
+        return union_types(one_of.iter().map(|item| schema_to_typespec(item, scope)));
     }
     if let Some(any_of) = schema.get("anyOf").and_then(|value| value.as_array()) {
-        return union_types(any_of.iter().map(schema_to_typespec));
+        return union_types(any_of.iter().map(|item| schema_to_typespec(item, scope)));
     }
     if let Some(enum_values) = schema.get("enum").and_then(|value| value.as_array())
         && enum_values.iter().all(is_literal_value)
     {
+        if let Some(title) = schema.get("title").and_then(Value::as_str)
+            && let Some(values) =
+                enum_values.iter().map(|value| value.as_str().map(str::to_string)).collect()
+            && !enum_values.is_empty()
+        {
+            return TypeSpec::TitledEnum {
+                title: title.to_string(),
+                values,
+            };
+        }
         return TypeSpec::Literal(enum_values.clone());
     }
-    if schema.get("$ref").is_some() {
-        return TypeSpec::Any;
+    if let Some(reference) = schema.get("$ref") {
+        return reference
+            .as_str()
+            .map_or(TypeSpec::Any, |reference| ref_typespec(reference, scope));
     }
     match schema.get("type") {
-        Some(Value::String(ty)) => type_from_name(ty, schema),
+        Some(Value::String(ty)) => type_from_name(ty, schema, scope),
         Some(Value::Array(types)) => union_types(
-            types.iter().filter_map(|value| value.as_str()).map(|ty| type_from_name(ty, schema)),
+            types
+                .iter()
+                .filter_map(|value| value.as_str())
+                .map(|ty| type_from_name(ty, schema, scope)),
         ),
         _ => TypeSpec::Any,
     }
 }
 
+/// Detects a `oneOf` whose members are inline objects tagged by a shared property.
+///
+/// The discriminator is the first property, in name order, that every member
+/// requires and pins to a distinct `const` string. Returns `None` when no such
+/// property exists, so the `oneOf` falls back to a plain union.
+fn discriminated_union(one_of: &[Value]) -> Option<TypeSpec> {
+    let [first, rest @ ..] = one_of else {
+        return None;
+    };
+    if rest.is_empty() || !one_of.iter().all(is_object_schema) {
+        return None;
+    }
+    let mut candidates: Vec<&String> = first.get("properties")?.as_object()?.keys().collect();
+    candidates.sort();
+    candidates.into_iter().find_map(|property| {
+        let mut members = Vec::with_capacity(one_of.len());
+        for member in one_of {
+            let value = discriminator_value(member, property)?;
+            if members.iter().any(|(existing, _)| existing == value) {
+                return None;
+            }
+            members.push((value.to_string(), member.clone()));
+        }
+        Some(TypeSpec::Discriminated {
+            property: property.clone(),
+            members,
+        })
+    })
+}
+
+/// Returns the `const` string a member object pins a required property to.
+fn discriminator_value<'s>(member: &'s Value, property: &str) -> Option<&'s str> {
+    let required = member.get("required")?.as_array()?;
+    if !required.iter().any(|name| name.as_str() == Some(property)) {
+        return None;
+    }
+    member.get("properties")?.get(property)?.get("const")?.as_str()
+}
+
+/// Maps a `$ref` to an internal type representation.
+fn ref_typespec(reference: &str, scope: &RefScope<'_>) -> TypeSpec {
+    let Some((name, target)) = scope.resolve(reference) else {
+        return TypeSpec::Any;
+    };
+    let Some(inner) = scope.enter(reference) else {
+        return TypeSpec::Any;
+    };
+    if is_object_like(target, &inner) {
+        return TypeSpec::Named(scope.type_name(name, target));
+    }
+    schema_to_typespec(target, &inner)
+}
+
 /// Maps a JSON schema type tag to an internal type representation.
-fn type_from_name(name: &str, schema: &Value) -> TypeSpec {
+fn type_from_name(name: &str, schema: &Value, scope: &RefScope<'_>) -> TypeSpec {
     match name {
         "null" => TypeSpec::Null,
         "boolean" => TypeSpec::Bool,
+        "integer" if is_int64_schema(schema) => TypeSpec::Int64,
         "integer" => TypeSpec::Int,
         "number" => TypeSpec::Number,
         "string" => TypeSpec::String,
         "array" => {
-            let inner = schema.get("items").map_or(TypeSpec::Any, schema_to_typespec);
+            let inner =
+                schema.get("items").map_or(TypeSpec::Any, |items| schema_to_typespec(items, scope));
             TypeSpec::Array(Box::new(inner))
         }
         "object" => TypeSpec::Object,
@@ -1220,6 +3030,21 @@ fn type_from_name(name: &str, schema: &Value) -> TypeSpec {
     }
 }
 
+/// Largest integer a JavaScript `number` represents exactly (`2^53 - 1`).
+const MAX_SAFE_INTEGER: f64 = 9_007_199_254_740_991.0;
+
+/// Returns true if an integer schema declares `format: int64`/`uint64` or a
+/// `minimum`/`maximum` outside the JavaScript safe integer range.
+fn is_int64_schema(schema: &Value) -> bool {
+    if matches!(schema.get("format").and_then(Value::as_str), Some("int64" | "uint64")) {
+        return true;
+    }
+    ["minimum", "maximum", "exclusiveMinimum", "exclusiveMaximum"]
+        .iter()
+        .filter_map(|keyword| schema.get(*keyword).and_then(Value::as_f64))
+        .any(|bound| bound.abs() > MAX_SAFE_INTEGER)
+}
+
 /// Collapses multiple types into a union, deduplicating where possible.
 ///
 /// If any type is `Any`, the union is treated as `Any` to avoid overconstraining.
@@ -1245,21 +3070,62 @@ where
     }
 }
 
+/// Combines `allOf` member types into an intersection of object types.
+///
+/// Members other than `Named`, `Object`, or nested intersections make the
+/// result `Any`. A generic `Object` is dropped when named members are present.
+fn intersection_types<I>(types: I) -> TypeSpec
+where
+    I: IntoIterator<Item = TypeSpec>,
+{
+    let mut output = Vec::new();
+    for ty in types {
+        let members = match ty {
+            TypeSpec::Intersection(members) => members,
+            TypeSpec::Named(_) | TypeSpec::Object => vec![ty],
+            TypeSpec::Discriminated {
+                ..
+            } => vec![TypeSpec::Object],
+            _ => return TypeSpec::Any,
+        };
+        for member in members {
+            if !output.contains(&member) {
+                output.push(member);
+            }
+        }
+    }
+    if output.len() > 1 {
+        output.retain(|member| *member != TypeSpec::Object);
+    }
+    match output.len() {
+        0 => TypeSpec::Any,
+        1 => output.remove(0),
+        _ => TypeSpec::Intersection(output),
+    }
+}
+
 /// Renders a Python type annotation for the internal type representation.
 ///
-/// Union members are sorted to keep generated output stable.
-fn python_type(ty: &TypeSpec) -> String {
+/// Union members are sorted to keep generated output stable. Python has no
+/// intersection annotation, so inline `allOf` objects render as plain dicts;
+/// named and top-level `allOf` schemas merge into one `TypedDict` instead.
+/// Titled enums render as their `enums` class, or as `Literal` when unregistered.
+fn python_type(ty: &TypeSpec, enums: &PythonEnums) -> String {
     match ty {
         TypeSpec::Any => "JsonValue".to_string(),
         TypeSpec::Null => "None".to_string(),
         TypeSpec::Bool => "bool".to_string(),
-        TypeSpec::Int => "int".to_string(),
+        TypeSpec::Int | TypeSpec::Int64 => "int".to_string(),
         TypeSpec::Number => "float".to_string(),
         TypeSpec::String => "str".to_string(),
-        TypeSpec::Array(inner) => format!("List[{}]", python_type(inner)),
-        TypeSpec::Object => "Dict[str, JsonValue]".to_string(),
+        TypeSpec::Array(inner) => format!("List[{}]", python_type(inner, enums)),
+        TypeSpec::Object
+        | TypeSpec::Intersection(_)
+        | TypeSpec::Discriminated {
+            ..
+        } => "Dict[str, JsonValue]".to_string(),
         TypeSpec::Union(types) => {
-            let mut rendered: Vec<String> = types.iter().map(python_type).collect();
+            let mut rendered: Vec<String> = types.iter().map(|ty| python_type(ty, enums)).collect();
             rendered.sort();
             if rendered.len() == 1 {
                 rendered.remove(0)
@@ -1271,6 +3137,18 @@ fn python_type(ty: &TypeSpec) -> String {
             let literals: Vec<String> = values.iter().map(python_literal_value).collect();
             format!("Literal[{}]", literals.join(", "))
         }
+        TypeSpec::TitledEnum {
+            title,
+            values,
+        } => enums.class_name(title, values).map_or_else(
+            || {
+                let literals: Vec<String> =
+                    values.iter().map(|value| python_string_literal(value)).collect();
+                format!("Literal[{}]", literals.join(", "))
+            },
+            str::to_string,
+        ),
+        TypeSpec::Named(name) => name.clone(),
     }
 }
 
@@ -1287,16 +3165,22 @@ fn python_literal_value(value: &Value) -> String {
 
 /// Renders a TypeScript type annotation for the internal type representation.
 ///
-/// Union members are sorted to keep generated output stable.
+/// Union members are sorted to keep generated output stable. Intersection
+/// members keep their `allOf` order. Discriminated unions render as a plain
+/// record unless [`name_discriminated_unions`] replaced them with their alias.
 fn typescript_type(ty: &TypeSpec) -> String {
     match ty {
         TypeSpec::Any => "JsonValue".to_string(),
         TypeSpec::Null => "null".to_string(),
         TypeSpec::Bool => "boolean".to_string(),
         TypeSpec::Int | TypeSpec::Number => "number".to_string(),
+        TypeSpec::Int64 => "bigint".to_string(),
         TypeSpec::String => "string".to_string(),
         TypeSpec::Array(inner) => format!("Array<{}>", typescript_type(inner)),
-        TypeSpec::Object => "Record<string, JsonValue>".to_string(),
+        TypeSpec::Object
+        | TypeSpec::Discriminated {
+            ..
+        } => "Record<string, JsonValue>".to_string(),
         TypeSpec::Union(types) => {
             let mut rendered: Vec<String> = types.iter().map(typescript_type).collect();
             rendered.sort();
@@ -1306,6 +3190,17 @@ fn typescript_type(ty: &TypeSpec) -> String {
             let literals: Vec<String> = values.iter().map(typescript_literal_value).collect();
             literals.join(" | ")
         }
+        TypeSpec::TitledEnum {
+            values, ..
+        } => {
+            let literals: Vec<String> =
+                values.iter().map(|value| typescript_string_literal(value)).collect();
+            literals.join(" | ")
+        }
+        TypeSpec::Named(name) => name.clone(),
+        TypeSpec::Intersection(types) => {
+            types.iter().map(typescript_type).collect::<Vec<_>>().join(" & ")
+        }
     }
 }
 
@@ -1357,8 +3252,33 @@ fn pascal_case(value: &str) -> String {
     if output.is_empty() { "Tool".to_string() } else { output }
 }
 
+/// Converts a schema key into a `PascalCase` type name suffix.
+///
+/// Runs of non-alphanumeric characters separate words; the first letter of
+/// each word is uppercased and the rest is kept as is.
+fn identifier_suffix(value: &str) -> String {
+    let mut name = String::new();
+    for segment in value.split(|ch: char| !ch.is_ascii_alphanumeric()) {
+        let mut chars = segment.chars();
+        if let Some(first) = chars.next() {
+            name.push(first.to_ascii_uppercase());
+            name.extend(chars);
+        }
+    }
+    name
+}
+
+/// Converts a `snake_case` identifier into `camelCase`.
+fn camel_case(value: &str) -> String {
+    let pascal = pascal_case(value);
+    let mut chars = pascal.chars();
+    chars
+        .next()
+        .map_or_else(String::new, |first| first.to_ascii_lowercase().to_string() + chars.as_str())
+}
+
 impl fmt::Display for TypeSpec {
     fn fmt(&self, formatter: &mut fmt::Formatter<'_>) -> fmt::Result {
-        formatter.write_str(&python_type(self))
+        formatter.write_str(&python_type(self, &PythonEnums::default()))
     }
 }
@@ -34,6 +34,9 @@ use decision_gate_sdk_gen::SdkGenerator;
 // SECTION: CLI Types
 // ============================================================================
 
+/// Default stamp file used by `generate --if-changed`.
+const DEFAULT_STAMP_PATH: &str = "target/decision-gate-sdk-gen.stamp";
+
 /// CLI arguments for SDK generation.
 #[derive(Debug, Parser)]
 #[command(name = "decision-gate-sdk-gen", about = "Generate Decision Gate client SDK artifacts.")]
@@ -68,6 +71,18 @@ enum Command {
             default_value = "Docs/generated/openapi/decision-gate.json"
         )]
         openapi_out: PathBuf,
+        /// Python lifecycle example output file.
+        #[arg(long, value_name = "FILE", default_value = "sdks/python/decision_gate/_examples.py")]
+        python_example_out: PathBuf,
+        /// TypeScript lifecycle example output file.
+        #[arg(long, value_name = "FILE", default_value = "sdks/typescript/src/_examples.ts")]
+        typescript_example_out: PathBuf,
+        /// Stamp file recording the contract hash of the last generation.
+        #[arg(long, value_name = "FILE", default_value = DEFAULT_STAMP_PATH)]
+        stamp: PathBuf,
+        /// Skip generation when the stamp matches the current contract and outputs exist.
+        #[arg(long)]
+        if_changed: bool,
     },
     /// Verify SDK artifacts match the generated output.
     Check {
@@ -91,9 +106,42 @@ enum Command {
             default_value = "Docs/generated/openapi/decision-gate.json"
         )]
         openapi_out: PathBuf,
+        /// Python lifecycle example output file.
+        #[arg(long, value_name = "FILE", default_value = "sdks/python/decision_gate/_examples.py")]
+        python_example_out: PathBuf,
+        /// TypeScript lifecycle example output file.
+        #[arg(long, value_name = "FILE", default_value = "sdks/typescript/src/_examples.ts")]
+        typescript_example_out: PathBuf,
     },
 }
 
+/// Output file paths for generated artifacts.
+struct OutputPaths {
+    /// Python SDK output file.
+    python: PathBuf,
+    /// TypeScript SDK output file.
+    typescript: PathBuf,
+    /// `OpenAPI` output file.
+    openapi: PathBuf,
+    /// Python lifecycle example output file.
+    python_example: PathBuf,
+    /// TypeScript lifecycle example output file.
+    typescript_example: PathBuf,
+}
+
+impl OutputPaths {
+    /// Returns every output path in generation order.
+    fn all(&self) -> [&Path; 5] {
+        [
+            &self.python,
+            &self.typescript,
+            &self.openapi,
+            &self.python_example,
+            &self.typescript_example,
+        ]
+    }
+}
+
 // ============================================================================
 // SECTION: Command Dispatch
 // ============================================================================
@@ -115,49 +163,91 @@ fn run() -> Result<(), SdkGenError> {
             python_out,
             typescript_out,
             openapi_out,
-        } => generate(tooling, &python_out, &typescript_out, &openapi_out),
+            python_example_out,
+            typescript_example_out,
+            stamp,
+            if_changed,
+        } => generate(
+            tooling,
+            &OutputPaths {
+                python: python_out,
+                typescript: typescript_out,
+                openapi: openapi_out,
+                python_example: python_example_out,
+                typescript_example: typescript_example_out,
+            },
+            &stamp,
+            if_changed,
+        ),
         Command::Check {
             tooling,
             python_out,
             typescript_out,
             openapi_out,
-        } => check(tooling, &python_out, &typescript_out, &openapi_out),
+            python_example_out,
+            typescript_example_out,
+        } => check(
+            tooling,
+            &OutputPaths {
+                python: python_out,
+                typescript: typescript_out,
+                openapi: openapi_out,
+                python_example: python_example_out,
+                typescript_example: typescript_example_out,
+            },
+        ),
     }
 }
 
 /// Writes SDK outputs to the configured paths.
 ///
 /// Parent directories are created automatically when missing. Outputs are
-/// written to a temporary file and then moved into place.
+/// written to a temporary file and then moved into place. With `if_changed`,
+/// generation is skipped when the stamp matches and every output exists; the
+/// stamp is rewritten after each successful generation.
 fn generate(
     tooling: PathBuf,
-    python_out: &Path,
-    typescript_out: &Path,
-    openapi_out: &Path,
+    paths: &OutputPaths,
+    stamp_path: &Path,
+    if_changed: bool,
 ) -> Result<(), SdkGenError> {
     let generator = SdkGenerator::load(tooling)?;
+    let stamp = generator.generation_stamp(&paths.all());
+    if if_changed && stamp_is_current(stamp_path, &stamp) && paths.all().iter().all(|p| p.exists())
+    {
+        let mut stdout = std::io::stdout();
+        let _ = writeln!(stdout, "SDK outputs up to date (contract {})", generator.contract_hash());
+        return Ok(());
+    }
     let python = generator.generate_python()?;
     let typescript = generator.generate_typescript()?;
     let openapi = generator.generate_openapi()?;
-    write_output(python_out, &python)?;
-    write_output(typescript_out, &typescript)?;
-    write_output(openapi_out, &openapi)?;
+    let python_example = generator.generate_python_example()?;
+    let typescript_example = generator.generate_typescript_example()?;
+    write_output(&paths.python, &python)?;
+    write_output(&paths.typescript, &typescript)?;
+    write_output(&paths.openapi, &openapi)?;
+    write_output(&paths.python_example, &python_example)?;
+    write_output(&paths.typescript_example, &typescript_example)?;
+    write_output(stamp_path, &stamp)?;
     Ok(())
 }
 
+/// Returns true when the stamp file exists and matches the expected stamp.
+fn stamp_is_current(stamp_path: &Path, expected: &str) -> bool {
+    fs::read_to_string(stamp_path).is_ok_and(|existing| existing == expected)
+}
+
 /// Verifies SDK outputs match the generated content.
 ///
 /// Returns a tooling error when drift is detected.
-fn check(
-    tooling: PathBuf,
-    python_out: &Path,
-    typescript_out: &Path,
-    openapi_out: &Path,
-) -> Result<(), SdkGenError> {
+fn check(tooling: PathBuf, paths: &OutputPaths) -> Result<(), SdkGenError> {
     let generator = SdkGenerator::load(tooling)?;
-    check_output(python_out, &generator.generate_python()?)?;
-    check_output(typescript_out, &generator.generate_typescript()?)?;
-    check_output(openapi_out, &generator.generate_openapi()?)?;
+    check_output(&paths.python, &generator.generate_python()?)?;
+    check_output(&paths.typescript, &generator.generate_typescript()?)?;
+    check_output(&paths.openapi, &generator.generate_openapi()?)?;
+    check_output(&paths.python_example, &generator.generate_python_example()?)?;
+    check_output(&paths.typescript_example, &generator.generate_typescript_example()?)?;
     Ok(())
 }
 
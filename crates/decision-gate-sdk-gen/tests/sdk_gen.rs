@@ -20,8 +20,12 @@ use std::sync::atomic::AtomicUsize;
 use std::sync::atomic::Ordering;
 
 use decision_gate_sdk_gen::DEFAULT_TOOLING_PATH;
+use decision_gate_sdk_gen::IndentStyle;
+use decision_gate_sdk_gen::LIFECYCLE_EXAMPLE_TOOLS;
 use decision_gate_sdk_gen::MAX_TOOLING_BYTES;
+use decision_gate_sdk_gen::SCHEMA_BUNDLE_ID;
 use decision_gate_sdk_gen::SdkGenError;
+use decision_gate_sdk_gen::SdkGenOptions;
 use decision_gate_sdk_gen::SdkGenerator;
 
 // ============================================================================
@@ -69,6 +73,21 @@ fn read_string(path: &Path) -> Result<String, SdkGenError> {
     fs::read_to_string(path).map_err(|err| SdkGenError::Io(err.to_string()))
 }
 
+fn python_class_methods(rendered: &str, class_name: &str, def: &str) -> Vec<String> {
+    let header = format!("class {class_name}:\n");
+    let Some(start) = rendered.find(&header) else {
+        return Vec::new();
+    };
+    let body = &rendered[start + header.len() ..];
+    let end = body.find("\nclass ").unwrap_or(body.len());
+    let prefix = format!("    {def} ");
+    body[.. end]
+        .lines()
+        .filter_map(|line| line.strip_prefix(&prefix))
+        .filter_map(|line| line.split_once('(').map(|(name, _)| name.to_string()))
+        .collect()
+}
+
 // ============================================================================
 // SECTION: Tests
 // ============================================================================
@@ -121,6 +140,83 @@ fn openapi_matches_generated_output() -> Result<(), SdkGenError> {
     Ok(())
 }
 
+#[test]
+fn openapi_emits_server_operation_id_and_tool_index() -> Result<(), Box<dyn std::error::Error>> {
+    let root = workspace_root()?;
+    let generator = SdkGenerator::load(root.join(DEFAULT_TOOLING_PATH))?;
+    let unset: serde_json::Value = serde_json::from_str(&generator.generate_openapi()?)?;
+    if unset.get("servers").is_some() {
+        return Err(std::io::Error::other("servers should be omitted by default").into());
+    }
+
+    let generator = generator.openapi_server("https://gate.example.com/");
+    let rendered = generator.generate_openapi()?;
+    if rendered != generator.generate_openapi()? {
+        return Err(std::io::Error::other("openapi output is not byte-stable").into());
+    }
+    let openapi: serde_json::Value = serde_json::from_str(&rendered)?;
+    let checks = [
+        (&openapi["servers"], serde_json::json!([{ "url": "https://gate.example.com/" }])),
+        (&openapi["paths"]["/rpc"]["post"]["operationId"], serde_json::json!("toolsCall")),
+        (
+            &openapi["x-tools"]["scenario_define"],
+            serde_json::json!({
+                "params": { "$ref": "#/components/schemas/ScenarioDefineToolCallParams" },
+                "result": { "$ref": "#/components/schemas/ScenarioDefineToolCallResult" }
+            }),
+        ),
+    ];
+    for (actual, expected) in checks {
+        if actual != &expected {
+            return Err(std::io::Error::other(format!("expected {expected}, got {actual}")).into());
+        }
+    }
+    let tool_count =
+        openapi["components"]["schemas"]["ToolCallParams"]["oneOf"].as_array().map_or(0, Vec::len);
+    if openapi["x-tools"].as_object().map_or(0, serde_json::Map::len) != tool_count {
+        return Err(std::io::Error::other("x-tools should list every tool").into());
+    }
+    Ok(())
+}
+
+#[test]
+fn schema_bundle_lists_input_and_output_per_tool() -> Result<(), Box<dyn std::error::Error>> {
+    let root = workspace_root()?;
+    let generator = SdkGenerator::load(root.join(DEFAULT_TOOLING_PATH))?;
+    let rendered = generator.generate_schema_bundle()?;
+    if rendered != generator.generate_schema_bundle()? {
+        return Err(std::io::Error::other("schema bundle is not byte-stable").into());
+    }
+    let bundle: serde_json::Value = serde_json::from_str(&rendered)?;
+    if bundle["$id"] != SCHEMA_BUNDLE_ID
+        || bundle["$schema"] != "https://json-schema.org/draft/2020-12/schema"
+    {
+        return Err(std::io::Error::other("bundle is missing $id or 2020-12 dialect").into());
+    }
+    let tooling: serde_json::Value =
+        serde_json::from_str(&read_string(&root.join(DEFAULT_TOOLING_PATH))?)?;
+    let tools = tooling.as_array().ok_or("tooling must be an array")?;
+    let defs = bundle["$defs"].as_object().ok_or("bundle must have $defs")?;
+    if defs.len() != tools.len() * 2 {
+        return Err(std::io::Error::other(format!(
+            "expected {} $defs entries, got {}",
+            tools.len() * 2,
+            defs.len()
+        ))
+        .into());
+    }
+    for tool in tools {
+        let name = tool["name"].as_str().ok_or("tool name must be a string")?;
+        for (suffix, field) in [("input", "input_schema"), ("output", "output_schema")] {
+            let entry = &defs[&format!("{name}_{suffix}")];
+            if entry["properties"] != tool[field]["properties"] {
+                return Err(std::io::Error::other(format!("{name}_{suffix} differs")).into());
+            }
+        }
+    }
+    Ok(())
+}
+
 #[test]
 fn tooling_input_enforces_size_limit() -> Result<(), Box<dyn std::error::Error>> {
     let temp = TempFile::new("tooling-limit");
@@ -134,3 +230,1080 @@ fn tooling_input_enforces_size_limit() -> Result<(), Box<dyn std::error::Error>>
         Err(other) => Err(std::io::Error::other(format!("unexpected error: {other}")).into()),
     }
 }
+
+#[test]
+fn python_example_matches_generated_output() -> Result<(), SdkGenError> {
+    let root = workspace_root()?;
+    let generator = SdkGenerator::load(root.join(DEFAULT_TOOLING_PATH))?;
+    let rendered = generator.generate_python_example()?;
+    let expected = read_string(&root.join("sdks/python/decision_gate/_examples.py"))?;
+    if rendered != expected {
+        return Err(SdkGenError::Tooling(
+            "Python example drift detected. Run decision-gate-sdk-gen generate.".to_string(),
+        ));
+    }
+    Ok(())
+}
+
+#[test]
+fn typescript_example_matches_generated_output() -> Result<(), SdkGenError> {
+    let root = workspace_root()?;
+    let generator = SdkGenerator::load(root.join(DEFAULT_TOOLING_PATH))?;
+    let rendered = generator.generate_typescript_example()?;
+    let expected = read_string(&root.join("sdks/typescript/src/_examples.ts"))?;
+    if rendered != expected {
+        return Err(SdkGenError::Tooling(
+            "TypeScript example drift detected. Run decision-gate-sdk-gen generate.".to_string(),
+        ));
+    }
+    Ok(())
+}
+
+#[test]
+fn lifecycle_examples_call_tools_in_order() -> Result<(), SdkGenError> {
+    let root = workspace_root()?;
+    let generator = SdkGenerator::load(root.join(DEFAULT_TOOLING_PATH))?;
+    for rendered in [generator.generate_python_example()?, generator.generate_typescript_example()?]
+    {
+        let mut last_position = 0;
+        for tool in LIFECYCLE_EXAMPLE_TOOLS {
+            let call = format!("client.{tool}(");
+            let position = rendered.find(&call).ok_or_else(|| {
+                SdkGenError::Tooling(format!("lifecycle example missing call: {call}"))
+            })?;
+            if position < last_position {
+                return Err(SdkGenError::Tooling(format!("lifecycle call out of order: {call}")));
+            }
+            last_position = position;
+        }
+    }
+    Ok(())
+}
+
+#[test]
+fn lifecycle_example_requires_tool_examples() -> Result<(), Box<dyn std::error::Error>> {
+    let root = workspace_root()?;
+    let mut tools: Vec<serde_json::Value> =
+        serde_json::from_str(&read_string(&root.join(DEFAULT_TOOLING_PATH))?)?;
+    for tool in &mut tools {
+        if tool["name"] == "scenario_submit" {
+            tool["examples"] = serde_json::json!([]);
+        }
+    }
+    let temp = TempFile::new("tooling-no-examples");
+    fs::write(&temp.path, serde_json::to_vec(&tools)?)?;
+    let generator = SdkGenerator::load(&temp.path)?;
+    match generator.generate_python_example() {
+        Err(SdkGenError::Tooling(message)) if message.contains("scenario_submit") => Ok(()),
+        other => Err(std::io::Error::other(format!("unexpected result: {other:?}")).into()),
+    }
+}
+
+#[test]
+fn python_async_client_signatures_are_stable() -> Result<(), SdkGenError> {
+    let root = workspace_root()?;
+    let generator = SdkGenerator::load(root.join(DEFAULT_TOOLING_PATH))?;
+    let rendered = generator.generate_python()?;
+    for expected in [
+        "class AsyncGeneratedDecisionGateClient:\n",
+        "    async def _call_tool(self, name: str, arguments: JsonValue) -> JsonValue:\n",
+        "    async def scenario_status(self, request: ScenarioStatusRequest) -> \
+         ScenarioStatusResponse:\n",
+        "        return cast(ScenarioStatusResponse, await self._call_tool(\"scenario_status\", \
+         request))\n",
+        "    \"AsyncGeneratedDecisionGateClient\",\n",
+    ] {
+        if !rendered.contains(expected) {
+            return Err(SdkGenError::Tooling(format!("async client missing: {expected}")));
+        }
+    }
+    Ok(())
+}
+
+#[test]
+fn python_sync_and_async_clients_expose_same_tools() -> Result<(), SdkGenError> {
+    let root = workspace_root()?;
+    let generator = SdkGenerator::load(root.join(DEFAULT_TOOLING_PATH))?;
+    let rendered = generator.generate_python()?;
+    let sync_methods = python_class_methods(&rendered, "GeneratedDecisionGateClient", "def");
+    let async_methods =
+        python_class_methods(&rendered, "AsyncGeneratedDecisionGateClient", "async def");
+    if sync_methods.len() <= 1 || sync_methods != async_methods {
+        return Err(SdkGenError::Tooling(format!(
+            "client tool sets differ: sync={sync_methods:?} async={async_methods:?}"
+        )));
+    }
+    Ok(())
+}
+
+#[test]
+fn typescript_result_union_is_stable() -> Result<(), SdkGenError> {
+    let root = workspace_root()?;
+    let generator = SdkGenerator::load(root.join(DEFAULT_TOOLING_PATH))?;
+    let rendered = generator.generate_typescript()?;
+    for expected in [
+        "export type ToolResult<T> = { ok: true; value: T } | { ok: false; error: JsonRpcError \
+         };\n",
+        "export type ScenarioStatusResult = ToolResult<ScenarioStatusResponse>;\n",
+        "  public scenario_status_result(request: ScenarioStatusRequest): \
+         Promise<ScenarioStatusResult> {\n",
+        "    return this.callToolResult<ScenarioStatusResponse>(\"scenario_status\", request);\n",
+    ] {
+        if !rendered.contains(expected) {
+            return Err(SdkGenError::Tooling(format!("result union missing: {expected}")));
+        }
+    }
+    Ok(())
+}
+
+#[test]
+fn typescript_result_error_branch_carries_error_data() -> Result<(), SdkGenError> {
+    let root = workspace_root()?;
+    let generator = SdkGenerator::load(root.join(DEFAULT_TOOLING_PATH))?;
+    let rendered = generator.generate_typescript()?;
+    for expected in [
+        "export interface JsonRpcErrorData {\n  kind: string;\n  request_id?: string;\n  \
+         retry_after_ms?: number;\n  retryable: boolean;\n}\n",
+        "export interface JsonRpcError {\n  code: number;\n  message: string;\n  data?: \
+         JsonRpcErrorData;\n}\n",
+        "      return { ok: false, error: rpcError };\n",
+    ] {
+        if !rendered.contains(expected) {
+            return Err(SdkGenError::Tooling(format!("error branch missing: {expected}")));
+        }
+    }
+    let openapi: serde_json::Value = serde_json::from_str(&generator.generate_openapi()?)
+        .map_err(|err| SdkGenError::Json(err.to_string()))?;
+    let required = &openapi["components"]["schemas"]["JsonRpcErrorData"]["required"];
+    if required != &serde_json::json!(["kind", "retryable"]) {
+        return Err(SdkGenError::Tooling(format!("openapi error data drifted: {required}")));
+    }
+    Ok(())
+}
+
+#[test]
+fn typed_additional_properties_open_generated_types() -> Result<(), Box<dyn std::error::Error>> {
+    let root = workspace_root()?;
+    let mut tools: Vec<serde_json::Value> =
+        serde_json::from_str(&read_string(&root.join(DEFAULT_TOOLING_PATH))?)?;
+    for tool in &mut tools {
+        if tool["name"] == "scenario_status" {
+            tool["input_schema"] = serde_json::json!({
+                "type": "object",
+                "properties": {
+                    "scenario_id": { "type": "string" },
+                    "note": { "type": "string" }
+                },
+                "required": ["scenario_id"],
+                "additionalProperties": { "type": "integer" }
+            });
+        }
+    }
+    let temp = TempFile::new("tooling-open-schema");
+    fs::write(&temp.path, serde_json::to_vec(&tools)?)?;
+    let generator = SdkGenerator::load(&temp.path)?;
+
+    let python = generator.generate_python()?;
+    for expected in [
+        "from typing_extensions import TypedDict as _OpenTypedDict\n",
+        "class ScenarioStatusRequest(_OpenTypedDict, extra_items=int):\n",
+        "class ScenarioStatusResponse(TypedDict):\n",
+    ] {
+        if !python.contains(expected) {
+            return Err(std::io::Error::other(format!("python missing: {expected}")).into());
+        }
+    }
+    let typescript = generator.generate_typescript()?;
+    let expected = "  scenario_id: string;\n  [key: string]: number | string | undefined;\n}\n";
+    if !typescript.contains(expected) {
+        return Err(std::io::Error::other(format!("typescript missing: {expected}")).into());
+    }
+    Ok(())
+}
+
+#[test]
+fn closed_schemas_forbid_additional_properties() -> Result<(), SdkGenError> {
+    let root = workspace_root()?;
+    let generator = SdkGenerator::load(root.join(DEFAULT_TOOLING_PATH))?;
+    let python = generator.generate_python()?;
+    if python.contains("_OpenTypedDict")
+        || !python.contains("class ScenarioStatusRequest(TypedDict):\n")
+    {
+        return Err(SdkGenError::Tooling("closed schema rendered as open TypedDict".to_string()));
+    }
+    let typescript = generator.generate_typescript()?;
+    let start = typescript
+        .find("export interface ScenarioStatusRequest {")
+        .ok_or_else(|| SdkGenError::Tooling("missing ScenarioStatusRequest".to_string()))?;
+    let interface = &typescript[start ..];
+    let interface = &interface[.. interface.find("}\n").unwrap_or(interface.len())];
+    if interface.contains("[key: string]") {
+        return Err(SdkGenError::Tooling(format!(
+            "closed schema has index signature: {interface}"
+        )));
+    }
+    Ok(())
+}
+
+#[test]
+fn read_only_fields_are_omitted_from_request_types() -> Result<(), Box<dyn std::error::Error>> {
+    let root = workspace_root()?;
+    let mut tools: Vec<serde_json::Value> =
+        serde_json::from_str(&read_string(&root.join(DEFAULT_TOOLING_PATH))?)?;
+    let schema = serde_json::json!({
+        "type": "object",
+        "properties": {
+            "created_at": { "type": "string", "readOnly": true },
+            "label": { "type": "string" },
+            "passphrase": { "type": "string", "writeOnly": true }
+        },
+        "required": ["created_at", "label"],
+        "additionalProperties": false
+    });
+    for tool in &mut tools {
+        if tool["name"] == "scenario_status" {
+            tool["input_schema"] = schema.clone();
+            tool["output_schema"] = schema.clone();
+        }
+    }
+    let temp = TempFile::new("tooling-read-write-only");
+    fs::write(&temp.path, serde_json::to_vec(&tools)?)?;
+    let generator = SdkGenerator::load(&temp.path)?;
+
+    let typescript = generator.generate_typescript()?;
+    for expected in [
+        "export interface ScenarioStatusRequest {\n  label: string;\n  passphrase?: string;\n}\n",
+        "export interface ScenarioStatusResponse {\n  created_at: string;\n  label: string;\n}\n",
+    ] {
+        if !typescript.contains(expected) {
+            return Err(std::io::Error::other(format!("typescript missing: {expected}")).into());
+        }
+    }
+    let python = generator.generate_python()?;
+    for expected in [
+        "class ScenarioStatusRequest(TypedDict):\n    \"\"\"Schema for \
+         ScenarioStatusRequest.\"\"\"\n    label: str\n    passphrase: NotRequired[str]\n\n",
+        "class ScenarioStatusResponse(TypedDict):\n    \"\"\"Schema for \
+         ScenarioStatusResponse.\"\"\"\n    created_at: str\n    label: str\n\n",
+    ] {
+        if !python.contains(expected) {
+            return Err(std::io::Error::other(format!("python missing: {expected}")).into());
+        }
+    }
+    Ok(())
+}
+
+#[test]
+fn local_refs_resolve_to_named_and_inline_types() -> Result<(), Box<dyn std::error::Error>> {
+    let root = workspace_root()?;
+    let mut tools: Vec<serde_json::Value> =
+        serde_json::from_str(&read_string(&root.join(DEFAULT_TOOLING_PATH))?)?;
+    let schema = serde_json::json!({
+        "type": "object",
+        "properties": {
+            "config": { "$ref": "#/$defs/config" },
+            "label": { "$ref": "#/definitions/Label" },
+            "loop": { "$ref": "#/$defs/LoopA" }
+        },
+        "required": ["config", "label", "loop"],
+        "additionalProperties": false,
+        "$defs": {
+            "config": {
+                "type": "object",
+                "properties": {
+                    "parent": { "$ref": "#/$defs/config" },
+                    "retries": { "type": "integer" }
+                },
+                "additionalProperties": false
+            },
+            "LoopA": { "$ref": "#/$defs/LoopB" },
+            "LoopB": { "$ref": "#/$defs/LoopA" }
+        },
+        "definitions": {
+            "Label": { "$ref": "#/definitions/Text" },
+            "Text": { "type": "string" }
+        }
+    });
+    for tool in &mut tools {
+        if tool["name"] == "scenario_status" {
+            tool["input_schema"] = schema.clone();
+        }
+    }
+    let temp = TempFile::new("tooling-local-refs");
+    fs::write(&temp.path, serde_json::to_vec(&tools)?)?;
+    let generator = SdkGenerator::load(&temp.path)?;
+
+    let typescript = generator.generate_typescript()?;
+    for expected in [
+        "export interface ScenarioStatusRequestConfig {\n  parent?: JsonValue;\n  retries?: \
+         number;\n}\n",
+        "export interface ScenarioStatusRequest {\n  config: ScenarioStatusRequestConfig;\n  \
+         label: string;\n  loop: JsonValue;\n}\n",
+    ] {
+        if !typescript.contains(expected) {
+            return Err(std::io::Error::other(format!("typescript missing: {expected}")).into());
+        }
+    }
+    let python = generator.generate_python()?;
+    for expected in [
+        "class ScenarioStatusRequestConfig(TypedDict):\n    \"\"\"Schema for \
+         ScenarioStatusRequestConfig.\"\"\"\n    parent: NotRequired[JsonValue]\n    retries: \
+         NotRequired[int]\n\n",
+        "    config: ScenarioStatusRequestConfig\n    label: str\n    loop: JsonValue\n\n",
+    ] {
+        if !python.contains(expected) {
+            return Err(std::io::Error::other(format!("python missing: {expected}")).into());
+        }
+    }
+    let go = generator.generate_go()?;
+    if !go.contains("\tConfig ScenarioStatusRequestConfig `json:\"config\"`\n") {
+        return Err(std::io::Error::other("go missing named config field").into());
+    }
+    Ok(())
+}
+
+#[test]
+fn provider_discovery_responses_are_typed() -> Result<(), Box<dyn std::error::Error>> {
+    let root = workspace_root()?;
+    let generator = SdkGenerator::load(root.join(DEFAULT_TOOLING_PATH))?;
+
+    let python = generator.generate_python()?;
+    for expected in [
+        "class ProviderSummary(TypedDict):\n",
+        "class ProvidersListResponse(TypedDict):\n    \"\"\"Schema for \
+         ProvidersListResponse.\"\"\"\n    providers: List[ProviderSummary]\n",
+        "class ProviderContractGetResponse(TypedDict):\n",
+        "class ProviderCheckSchemaGetResponse(TypedDict):\n",
+        "    \"ProviderSummary\",\n",
+    ] {
+        if !python.contains(expected) {
+            return Err(std::io::Error::other(format!("python missing: {expected}")).into());
+        }
+    }
+    let typescript = generator.generate_typescript()?;
+    for expected in [
+        "export interface ProviderSummary {\n",
+        "export interface ProvidersListResponse {\n  providers: Array<ProviderSummary>;\n}\n",
+        "export interface ProviderContractGetResponse {\n",
+        "export interface ProviderCheckSchemaGetResponse {\n",
+    ] {
+        if !typescript.contains(expected) {
+            return Err(std::io::Error::other(format!("typescript missing: {expected}")).into());
+        }
+    }
+    let go = generator.generate_go()?;
+    for expected in [
+        "type ProviderSummary struct {\n",
+        "\tProviders []ProviderSummary `json:\"providers\"`\n",
+        "type ProviderContractGetResponse struct {\n",
+    ] {
+        if !go.contains(expected) {
+            return Err(std::io::Error::other(format!("go missing: {expected}")).into());
+        }
+    }
+
+    let openapi: serde_json::Value = serde_json::from_str(&generator.generate_openapi()?)?;
+    let schemas = &openapi["components"]["schemas"];
+    for name in [
+        "ProviderSummary",
+        "ProvidersListResponse",
+        "ProviderContractGetResponse",
+        "ProviderCheckSchemaGetResponse",
+    ] {
+        if !schemas[name].is_object() {
+            return Err(std::io::Error::other(format!("openapi missing component {name}")).into());
+        }
+    }
+    let checks = [
+        (
+            &schemas["ProvidersListResponse"]["properties"]["providers"]["items"],
+            serde_json::json!({ "$ref": "#/components/schemas/ProviderSummary" }),
+        ),
+        (
+            &schemas["ProviderContractGetToolCallResult"]["properties"]["content"]["items"]
+                ["properties"]["json"],
+            serde_json::json!({ "$ref": "#/components/schemas/ProviderContractGetResponse" }),
+        ),
+    ];
+    for (actual, expected) in checks {
+        if actual != &expected {
+            return Err(std::io::Error::other(format!("expected {expected}, got {actual}")).into());
+        }
+    }
+    if schemas["ProvidersListResponse"].get("$defs").is_some() {
+        return Err(std::io::Error::other("hoisted definitions should leave $defs").into());
+    }
+    let undiscovered = ["ScenarioStatusResponse", "ProvidersListRequest"];
+    if undiscovered.iter().any(|name| schemas.get(name).is_some()) {
+        return Err(std::io::Error::other("only discovery responses are components").into());
+    }
+    Ok(())
+}
+
+#[test]
+fn titled_definitions_are_shared_across_tools() -> Result<(), Box<dyn std::error::Error>> {
+    let root = workspace_root()?;
+    let mut tools: Vec<serde_json::Value> =
+        serde_json::from_str(&read_string(&root.join(DEFAULT_TOOLING_PATH))?)?;
+    let schema = serde_json::json!({
+        "type": "object",
+        "properties": {
+            "item": { "$ref": "#/$defs/item" },
+            "extra": { "$ref": "#/$defs/extra" }
+        },
+        "required": ["item", "extra"],
+        "additionalProperties": false,
+        "$defs": {
+            "item": {
+                "title": "SharedItem",
+                "type": "object",
+                "properties": { "id": { "type": "string" } },
+                "additionalProperties": false
+            },
+            "extra": {
+                "type": "object",
+                "properties": { "note": { "type": "string" } },
+                "additionalProperties": false
+            }
+        }
+    });
+    for tool in &mut tools {
+        if tool["name"] == "scenario_status" || tool["name"] == "scenario_next" {
+            tool["output_schema"] = schema.clone();
+        }
+    }
+    let temp = TempFile::new("tooling-shared-defs");
+    fs::write(&temp.path, serde_json::to_vec(&tools)?)?;
+    let generator = SdkGenerator::load(&temp.path)?;
+
+    let python = generator.generate_python()?;
+    let typescript = generator.generate_typescript()?;
+    let go = generator.generate_go()?;
+    for (label, rendered, shared, prefixed) in [
+        (
+            "python",
+            &python,
+            "class SharedItem(TypedDict):\n",
+            "class ScenarioNextResponseExtra(TypedDict):\n",
+        ),
+        (
+            "typescript",
+            &typescript,
+            "export interface SharedItem {\n",
+            "export interface ScenarioNextResponseExtra {\n",
+        ),
+        ("go", &go, "type SharedItem struct {\n", "type ScenarioNextResponseExtra struct {\n"),
+    ] {
+        if rendered.matches(shared).count() != 1 {
+            return Err(
+                std::io::Error::other(format!("{label} should render {shared} once")).into()
+            );
+        }
+        if !rendered.contains(prefixed) {
+            return Err(std::io::Error::other(format!("{label} missing: {prefixed}")).into());
+        }
+    }
+    if python.matches("    \"SharedItem\",\n").count() != 1 {
+        return Err(std::io::Error::other("python should export SharedItem once").into());
+    }
+    Ok(())
+}
+
+#[test]
+fn titled_definitions_must_match_across_tools() -> Result<(), Box<dyn std::error::Error>> {
+    let root = workspace_root()?;
+    let mut tools: Vec<serde_json::Value> =
+        serde_json::from_str(&read_string(&root.join(DEFAULT_TOOLING_PATH))?)?;
+    let schema_with = |property: &str| {
+        serde_json::json!({
+            "type": "object",
+            "properties": { "item": { "$ref": "#/$defs/item" } },
+            "required": ["item"],
+            "additionalProperties": false,
+            "$defs": {
+                "item": {
+                    "title": "SharedItem",
+                    "type": "object",
+                    "properties": { property: { "type": "string" } },
+                    "additionalProperties": false
+                }
+            }
+        })
+    };
+    for tool in &mut tools {
+        if tool["name"] == "scenario_status" {
+            tool["output_schema"] = schema_with("id");
+        } else if tool["name"] == "scenario_next" {
+            tool["output_schema"] = schema_with("name");
+        }
+    }
+    let temp = TempFile::new("tooling-conflicting-defs");
+    fs::write(&temp.path, serde_json::to_vec(&tools)?)?;
+
+    let Err(SdkGenError::Tooling(message)) = SdkGenerator::load(&temp.path) else {
+        return Err(std::io::Error::other("conflicting titled definitions should fail").into());
+    };
+    if !message.contains("SharedItem") {
+        return Err(std::io::Error::other(format!("unexpected error: {message}")).into());
+    }
+    Ok(())
+}
+
+#[test]
+fn all_of_objects_merge_and_intersect() -> Result<(), Box<dyn std::error::Error>> {
+    let root = workspace_root()?;
+    let mut tools: Vec<serde_json::Value> =
+        serde_json::from_str(&read_string(&root.join(DEFAULT_TOOLING_PATH))?)?;
+    let schema = serde_json::json!({
+        "allOf": [
+            { "$ref": "#/$defs/Base" },
+            {
+                "type": "object",
+                "properties": {
+                    "extra": { "type": "boolean" },
+                    "id": { "type": "string" },
+                    "mode": { "type": "integer" }
+                },
+                "required": ["extra"],
+                "additionalProperties": false
+            }
+        ],
+        "properties": {
+            "combo": { "allOf": [{ "$ref": "#/$defs/Base" }, { "$ref": "#/$defs/Tagged" }] },
+            "full": { "$ref": "#/$defs/Full" },
+            "mixed": { "allOf": [{ "$ref": "#/$defs/Base" }, { "type": "string" }] }
+        },
+        "$defs": {
+            "Base": {
+                "type": "object",
+                "properties": {
+                    "id": { "type": "string" },
+                    "mode": { "type": "string" }
+                },
+                "required": ["id"],
+                "additionalProperties": false
+            },
+            "Full": { "allOf": [{ "$ref": "#/$defs/Base" }, { "$ref": "#/$defs/Tagged" }] },
+            "Tagged": {
+                "type": "object",
+                "properties": { "tag": { "type": "string" } },
+                "additionalProperties": false
+            }
+        }
+    });
+    for tool in &mut tools {
+        if tool["name"] == "scenario_status" {
+            tool["input_schema"] = schema.clone();
+        }
+    }
+    let temp = TempFile::new("tooling-all-of");
+    fs::write(&temp.path, serde_json::to_vec(&tools)?)?;
+    let generator = SdkGenerator::load(&temp.path)?;
+
+    let typescript = generator.generate_typescript()?;
+    for expected in [
+        "export interface ScenarioStatusRequest {\n  combo?: ScenarioStatusRequestBase & \
+         ScenarioStatusRequestTagged;\n  extra: boolean;\n  full?: ScenarioStatusRequestFull;\n  \
+         id: string;\n  mixed?: JsonValue;\n  mode?: JsonValue;\n}\n",
+        "export interface ScenarioStatusRequestFull {\n  id: string;\n  mode?: string;\n  tag?: \
+         string;\n}\n",
+    ] {
+        if !typescript.contains(expected) {
+            return Err(std::io::Error::other(format!("typescript missing: {expected}")).into());
+        }
+    }
+    let python = generator.generate_python()?;
+    for expected in
+        [
+            "    combo: NotRequired[Dict[str, JsonValue]]\n    extra: bool\n    full: \
+             NotRequired[ScenarioStatusRequestFull]\n    id: str\n    mixed: \
+             NotRequired[JsonValue]\n    mode: NotRequired[JsonValue]\n\n",
+            "class ScenarioStatusRequestFull(TypedDict):\n    \"\"\"Schema for \
+             ScenarioStatusRequestFull.\"\"\"\n    id: str\n    mode: NotRequired[str]\n    tag: \
+             NotRequired[str]\n\n",
+        ]
+    {
+        if !python.contains(expected) {
+            return Err(std::io::Error::other(format!("python missing: {expected}")).into());
+        }
+    }
+    Ok(())
+}
+
+#[test]
+fn const_tagged_one_of_renders_typescript_discriminated_union()
+-> Result<(), Box<dyn std::error::Error>> {
+    let root = workspace_root()?;
+    let mut tools: Vec<serde_json::Value> =
+        serde_json::from_str(&read_string(&root.join(DEFAULT_TOOLING_PATH))?)?;
+    let schema = serde_json::json!({
+        "type": "object",
+        "properties": {
+            "at": {
+                "oneOf": [
+                    {
+                        "type": "object",
+                        "properties": {
+                            "kind": { "const": "unix_millis" },
+                            "value": { "type": "integer" }
+                        },
+                        "required": ["kind", "value"],
+                        "additionalProperties": false
+                    },
+                    {
+                        "type": "object",
+                        "properties": {
+                            "kind": { "const": "logical" },
+                            "tick": { "type": "integer" }
+                        },
+                        "required": ["kind", "tick"],
+                        "additionalProperties": false
+                    }
+                ]
+            },
+            "loose": {
+                "oneOf": [
+                    { "type": "object", "properties": { "a": { "type": "string" } } },
+                    { "type": "object", "properties": { "b": { "type": "string" } } }
+                ]
+            }
+        },
+        "required": ["at"],
+        "additionalProperties": false
+    });
+    for tool in &mut tools {
+        if tool["name"] == "scenario_status" {
+            tool["input_schema"] = schema.clone();
+        }
+    }
+    let temp = TempFile::new("tooling-discriminated");
+    fs::write(&temp.path, serde_json::to_vec(&tools)?)?;
+    let generator = SdkGenerator::load(&temp.path)?;
+
+    let typescript = generator.generate_typescript()?;
+    for expected in [
+        "  at: ScenarioStatusRequestAt;\n",
+        "  loose?: Record<string, JsonValue>;\n",
+        "export type ScenarioStatusRequestAt = ScenarioStatusRequestAtUnixMillis | \
+         ScenarioStatusRequestAtLogical;\n",
+        "export interface ScenarioStatusRequestAtUnixMillis {\n  /** Constraints: Const: \
+         \"unix_millis\". */\n  kind: \"unix_millis\";\n  value: number;\n}\n",
+        "export interface ScenarioStatusRequestAtLogical {\n  /** Constraints: Const: \
+         \"logical\". */\n  kind: \"logical\";\n  tick: number;\n}\n",
+    ] {
+        if !typescript.contains(expected) {
+            return Err(std::io::Error::other(format!("typescript missing: {expected}")).into());
+        }
+    }
+    let python = generator.generate_python()?;
+    if !python.contains("    at: Dict[str, JsonValue]\n") {
+        return Err(std::io::Error::other("python should keep a plain dict").into());
+    }
+    Ok(())
+}
+
+#[test]
+fn int64_integers_render_typescript_bigint() -> Result<(), Box<dyn std::error::Error>> {
+    let root = workspace_root()?;
+    let mut tools: Vec<serde_json::Value> =
+        serde_json::from_str(&read_string(&root.join(DEFAULT_TOOLING_PATH))?)?;
+    let schema = serde_json::json!({
+        "type": "object",
+        "properties": {
+            "tenant": { "type": "integer", "format": "int64" },
+            "bounded": { "type": "integer", "minimum": 1, "maximum": u64::MAX },
+            "count": { "type": "integer", "minimum": 0, "maximum": 1000 },
+            "ratio": { "type": "number", "format": "int64" }
+        },
+        "required": ["tenant", "bounded", "count", "ratio"],
+        "additionalProperties": false
+    });
+    for tool in &mut tools {
+        if tool["name"] == "scenario_status" {
+            tool["input_schema"] = schema.clone();
+        }
+    }
+    let temp = TempFile::new("tooling-int64");
+    fs::write(&temp.path, serde_json::to_vec(&tools)?)?;
+    let generator = SdkGenerator::load(&temp.path)?;
+
+    let typescript = generator.generate_typescript()?;
+    for expected in
+        ["  tenant: bigint;\n", "  bounded: bigint;\n", "  count: number;\n", "  ratio: number;\n"]
+    {
+        if !typescript.contains(expected) {
+            return Err(std::io::Error::other(format!("typescript missing: {expected}")).into());
+        }
+    }
+    let python = generator.generate_python()?;
+    for expected in ["    tenant: int\n", "    bounded: int\n", "    count: int\n"] {
+        if !python.contains(expected) {
+            return Err(std::io::Error::other(format!("python missing: {expected}")).into());
+        }
+    }
+    Ok(())
+}
+
+#[test]
+fn titled_string_enums_render_python_enum_classes() -> Result<(), Box<dyn std::error::Error>> {
+    let root = workspace_root()?;
+    let mut tools: Vec<serde_json::Value> =
+        serde_json::from_str(&read_string(&root.join(DEFAULT_TOOLING_PATH))?)?;
+    let schema = serde_json::json!({
+        "type": "object",
+        "properties": {
+            "kind": { "enum": ["alpha", "beta"] },
+            "phase": { "title": "Run status", "enum": ["done", "in-progress", "notStarted", "1st"] },
+            "phases": {
+                "type": "array",
+                "items": { "title": "Run status", "enum": ["1st", "done", "notStarted", "in-progress"] }
+            },
+            "legacy": { "title": "Run status", "enum": ["open", "closed"] },
+            "shadow": { "title": "Scenario status request", "enum": ["a"] }
+        },
+        "additionalProperties": false
+    });
+    for tool in &mut tools {
+        if tool["name"] == "scenario_status" {
+            tool["input_schema"] = schema.clone();
+        }
+    }
+    let temp = TempFile::new("tooling-titled-enum");
+    fs::write(&temp.path, serde_json::to_vec(&tools)?)?;
+    let generator = SdkGenerator::load(&temp.path)?;
+
+    let python = generator.generate_python()?;
+    for expected in [
+        "from enum import Enum\n",
+        "class RunStatus(str, Enum):\n    \"\"\"Allowed values for Run status.\"\"\"\n    DONE = \
+         \"done\"\n    IN_PROGRESS = \"in-progress\"\n    NOT_STARTED = \"notStarted\"\n    \
+         VALUE_1ST = \"1st\"\n\n",
+        "class RunStatus2(str, Enum):\n",
+        "class ScenarioStatusRequest2(str, Enum):\n",
+        "    kind: NotRequired[Literal[\"alpha\", \"beta\"]]\n",
+        "    legacy: NotRequired[RunStatus2]\n",
+        "    phase: NotRequired[RunStatus]\n",
+        "    phases: NotRequired[List[RunStatus]]\n",
+        "    shadow: NotRequired[ScenarioStatusRequest2]\n",
+        "    \"RunStatus\",\n",
+    ] {
+        if !python.contains(expected) {
+            return Err(std::io::Error::other(format!("python missing: {expected}")).into());
+        }
+    }
+    let typescript = generator.generate_typescript()?;
+    if !typescript.contains("phase?: \"done\" | \"in-progress\" | \"notStarted\" | \"1st\";") {
+        return Err(std::io::Error::other("typescript should keep literal unions").into());
+    }
+    Ok(())
+}
+
+#[test]
+fn deprecated_properties_and_tools_carry_language_markers() -> Result<(), Box<dyn std::error::Error>>
+{
+    let root = workspace_root()?;
+    let mut tools: Vec<serde_json::Value> =
+        serde_json::from_str(&read_string(&root.join(DEFAULT_TOOLING_PATH))?)?;
+    let schema = serde_json::json!({
+        "type": "object",
+        "properties": {
+            "legacy_id": { "type": "string", "description": "Old identifier.", "deprecated": true },
+            "run_id": { "type": "string" }
+        },
+        "required": ["run_id"],
+        "additionalProperties": false
+    });
+    for tool in &mut tools {
+        if tool["name"] == "scenario_status" {
+            tool["input_schema"] = schema.clone();
+            tool["deprecated"] = serde_json::Value::Bool(true);
+        }
+    }
+    let temp = TempFile::new("tooling-deprecated");
+    fs::write(&temp.path, serde_json::to_vec(&tools)?)?;
+    let generator = SdkGenerator::load(&temp.path)?;
+
+    let typescript = generator.generate_typescript()?;
+    for expected in [
+        "  /** @deprecated */\n  legacy_id?: string;\n",
+        "   * @deprecated\n   */\n  public scenario_status(",
+        "   * @deprecated\n   */\n  public scenario_status_result(",
+    ] {
+        if !typescript.contains(expected) {
+            return Err(std::io::Error::other(format!("typescript missing: {expected}")).into());
+        }
+    }
+    if typescript.contains("/** @deprecated */\n  run_id: string;") {
+        return Err(std::io::Error::other("run_id should not be deprecated").into());
+    }
+    if typescript.matches("@deprecated").count() != 3 {
+        return Err(std::io::Error::other("unexpected @deprecated tags").into());
+    }
+
+    let python = generator.generate_python()?;
+    for expected in [
+        "import warnings as _warnings\n",
+        "    # DEPRECATED\n    #: Old identifier. Constraints: Deprecated..\n",
+        "    # DEPRECATED\n    def scenario_status(",
+        "    # DEPRECATED\n    async def scenario_status(",
+        "        _warnings.warn(\"scenario_status is deprecated.\", DeprecationWarning, \
+         stacklevel=2)\n        return cast(ScenarioStatusResponse, self._call_tool(",
+    ] {
+        if !python.contains(expected) {
+            return Err(std::io::Error::other(format!("python missing: {expected}")).into());
+        }
+    }
+    if python.matches("_warnings.warn(").count() != 2 {
+        return Err(std::io::Error::other("expected sync and async warnings only").into());
+    }
+    Ok(())
+}
+
+#[test]
+fn undeprecated_contract_emits_no_deprecation_markers() -> Result<(), SdkGenError> {
+    let root = workspace_root()?;
+    let generator = SdkGenerator::load(root.join(DEFAULT_TOOLING_PATH))?;
+    let python = generator.generate_python()?;
+    let typescript = generator.generate_typescript()?;
+    if python.contains("_warnings") || python.contains("# DEPRECATED") {
+        return Err(SdkGenError::Tooling("python should not import warnings".to_string()));
+    }
+    if typescript.contains("@deprecated") {
+        return Err(SdkGenError::Tooling("typescript should not carry @deprecated".to_string()));
+    }
+    Ok(())
+}
+
+#[test]
+fn tooling_with_malformed_schema_is_rejected() -> Result<(), Box<dyn std::error::Error>> {
+    let root = workspace_root()?;
+    let mut tools: Vec<serde_json::Value> =
+        serde_json::from_str(&read_string(&root.join(DEFAULT_TOOLING_PATH))?)?;
+    for tool in &mut tools {
+        if tool["name"] == "scenario_status" {
+            tool["input_schema"]["properties"]["scenario_id"]["type"] =
+                serde_json::json!("invalid_type");
+        }
+    }
+    let temp = TempFile::new("tooling-malformed-schema");
+    fs::write(&temp.path, serde_json::to_vec(&tools)?)?;
+    match SdkGenerator::load(&temp.path) {
+        Err(SdkGenError::Tooling(message))
+            if message.contains("(scenario_status): input_schema is not a valid JSON Schema")
+                && message.contains("/properties/scenario_id/type") =>
+        {
+            Ok(())
+        }
+        other => Err(std::io::Error::other(format!("unexpected result: {other:?}")).into()),
+    }
+}
+
+#[test]
+fn tooling_with_missing_fields_names_the_tool() -> Result<(), Box<dyn std::error::Error>> {
+    let root = workspace_root()?;
+    let mut tools: Vec<serde_json::Value> =
+        serde_json::from_str(&read_string(&root.join(DEFAULT_TOOLING_PATH))?)?;
+    let mut index = None;
+    for (position, tool) in tools.iter_mut().enumerate() {
+        if tool["name"] == "scenario_next"
+            && let Some(object) = tool.as_object_mut()
+        {
+            object.remove("output_schema");
+            index = Some(position);
+        }
+    }
+    let index = index.ok_or_else(|| std::io::Error::other("scenario_next missing"))?;
+    let temp = TempFile::new("tooling-missing-field");
+    fs::write(&temp.path, serde_json::to_vec(&tools)?)?;
+    let expected = format!("tools[{index}] (scenario_next): missing field `output_schema`");
+    match SdkGenerator::load(&temp.path) {
+        Err(SdkGenError::Tooling(message)) if message.contains(&expected) => Ok(()),
+        other => Err(std::io::Error::other(format!("unexpected result: {other:?}")).into()),
+    }
+}
+
+#[test]
+fn generation_stamp_tracks_contract_hash() -> Result<(), Box<dyn std::error::Error>> {
+    let root = workspace_root()?;
+    let contents = read_string(&root.join(DEFAULT_TOOLING_PATH))?;
+    let outputs = [Path::new("python/_generated.py"), Path::new("typescript/_generated.ts")];
+
+    let temp = TempFile::new("tooling-stamp");
+    fs::write(&temp.path, &contents)?;
+    let first = SdkGenerator::load(&temp.path)?;
+    let unchanged = SdkGenerator::load(&temp.path)?;
+    if first.generation_stamp(&outputs) != unchanged.generation_stamp(&outputs) {
+        return Err(std::io::Error::other("stamp differs for an unchanged contract").into());
+    }
+    if !first.generation_stamp(&outputs).contains(first.contract_hash()) {
+        return Err(std::io::Error::other("stamp missing contract hash").into());
+    }
+
+    fs::write(&temp.path, format!("{contents}\n"))?;
+    let changed = SdkGenerator::load(&temp.path)?;
+    if changed.contract_hash() == first.contract_hash()
+        || changed.generation_stamp(&outputs) == first.generation_stamp(&outputs)
+    {
+        return Err(std::io::Error::other("stamp unchanged for a changed contract").into());
+    }
+    if first.generation_stamp(&outputs[.. 1]) == first.generation_stamp(&outputs) {
+        return Err(std::io::Error::other("stamp ignores output paths").into());
+    }
+    Ok(())
+}
+
+#[test]
+fn custom_doc_width_changes_line_breaks() -> Result<(), SdkGenError> {
+    let root = workspace_root()?;
+    let default = SdkGenerator::load(root.join(DEFAULT_TOOLING_PATH))?;
+    let narrow = default.clone().with_options(SdkGenOptions {
+        python_doc_width: 40,
+        typescript_doc_width: 40,
+        ..SdkGenOptions::default()
+    })?;
+    let python = narrow.generate_python()?;
+    let typescript = narrow.generate_typescript()?;
+    if python == default.generate_python()? || typescript == default.generate_typescript()? {
+        return Err(SdkGenError::Tooling("custom doc width did not change output".to_string()));
+    }
+    let wrapped = "Optional long-poll options; the server";
+    if !python.contains(&format!("    #: {wrapped}\n"))
+        || !typescript.contains(&format!("  /** {wrapped} */\n"))
+    {
+        return Err(SdkGenError::Tooling("doc lines not wrapped at custom width".to_string()));
+    }
+    Ok(())
+}
+
+#[test]
+fn doc_width_builder_wraps_within_width() -> Result<(), SdkGenError> {
+    let root = workspace_root()?;
+    let default = SdkGenerator::load(root.join(DEFAULT_TOOLING_PATH))?;
+    let wide = default.clone().doc_width(120, 120)?;
+    if wide.options().python_indent != default.options().python_indent {
+        return Err(SdkGenError::Tooling("doc_width changed indentation".to_string()));
+    }
+    let python = wide.generate_python()?;
+    let typescript = wide.generate_typescript()?;
+    let python_docs: Vec<&str> =
+        python.lines().filter_map(|line| line.trim_start().strip_prefix("#: ")).collect();
+    let typescript_docs: Vec<&str> = typescript
+        .lines()
+        .filter_map(|line| line.trim_start().strip_prefix("/** ")?.strip_suffix(" */"))
+        .collect();
+    for doc in python_docs.iter().chain(&typescript_docs) {
+        if doc.len() > 120 && doc.contains(' ') {
+            return Err(SdkGenError::Tooling(format!("doc line exceeds width 120: {doc}")));
+        }
+    }
+    if !python_docs.iter().any(|doc| doc.len() > 88)
+        || !typescript_docs.iter().any(|doc| doc.len() > 96)
+    {
+        return Err(SdkGenError::Tooling("doc width 120 was not applied".to_string()));
+    }
+    if !matches!(default.doc_width(0, 120), Err(SdkGenError::Options(_))) {
+        return Err(SdkGenError::Tooling("zero doc width accepted".to_string()));
+    }
+    Ok(())
+}
+
+#[test]
+fn custom_options_render_deterministically() -> Result<(), SdkGenError> {
+    let root = workspace_root()?;
+    let options = SdkGenOptions {
+        python_doc_width: 72,
+        typescript_doc_width: 80,
+        python_indent: IndentStyle::Tabs,
+        typescript_indent: IndentStyle::Spaces(4),
+    };
+    let first = SdkGenerator::load(root.join(DEFAULT_TOOLING_PATH))?.with_options(options)?;
+    let second = SdkGenerator::load(root.join(DEFAULT_TOOLING_PATH))?.with_options(options)?;
+    if first.generate_python()? != second.generate_python()?
+        || first.generate_typescript()? != second.generate_typescript()?
+        || first.generate_python_example()? != second.generate_python_example()?
+        || first.generate_typescript_example()? != second.generate_typescript_example()?
+    {
+        return Err(SdkGenError::Tooling(
+            "custom options rendered nondeterministically".to_string(),
+        ));
+    }
+    let python = first.generate_python()?;
+    if !python.contains("\n\tdef _call_tool(self, name: str, arguments: JsonValue) -> JsonValue:\n")
+    {
+        return Err(SdkGenError::Tooling("python tab indentation not applied".to_string()));
+    }
+    let typescript = first.generate_typescript()?;
+    if !typescript.contains("\n    protected abstract callTool<T>(") {
+        return Err(SdkGenError::Tooling("typescript indentation not applied".to_string()));
+    }
+    let invalid = first.with_options(SdkGenOptions {
+        python_indent: IndentStyle::Spaces(0),
+        ..SdkGenOptions::default()
+    });
+    if !matches!(invalid, Err(SdkGenError::Options(_))) {
+        return Err(SdkGenError::Tooling("zero-width indent accepted".to_string()));
+    }
+    Ok(())
+}
+
+#[test]
+fn go_sdk_renders_tool_surface() -> Result<(), SdkGenError> {
+    let root = workspace_root()?;
+    let generator = SdkGenerator::load(root.join(DEFAULT_TOOLING_PATH))?;
+    let go = generator.generate_go()?;
+    for expected in [
+        "// Code generated by decision-gate-sdk-gen. DO NOT EDIT.\n",
+        "package decisiongate\n",
+        "var TOOL_NAMES = []string{\n\t\"scenario_define\",\n",
+        "type GeneratedDecisionGateClient interface {\n",
+        "\tCallTool(ctx context.Context, name string, arguments any) (json.RawMessage, error)\n",
+        "type ScenarioStatusRequest struct {\n",
+        "type ScenarioStatusResponse struct {\n",
+        "func ScenarioStatus(ctx context.Context, client GeneratedDecisionGateClient, request \
+         ScenarioStatusRequest) (ScenarioStatusResponse, error) {\n",
+    ] {
+        if !go.contains(expected) {
+            return Err(SdkGenError::Tooling(format!("go missing: {expected}")));
+        }
+    }
+    if go != generator.generate_go()? {
+        return Err(SdkGenError::Tooling("go output is not deterministic".to_string()));
+    }
+    Ok(())
+}
+
+#[test]
+fn go_fields_map_types_tags_and_keywords() -> Result<(), Box<dyn std::error::Error>> {
+    let root = workspace_root()?;
+    let mut tools: Vec<serde_json::Value> =
+        serde_json::from_str(&read_string(&root.join(DEFAULT_TOOLING_PATH))?)?;
+    let schema = serde_json::json!({
+        "type": "object",
+        "properties": {
+            "type": { "type": "string" },
+            "run_count": { "type": "integer" },
+            "metadata": { "type": "object" },
+            "value": { "oneOf": [{ "type": "string" }, { "type": "boolean" }] },
+            "attempt_limit": { "type": ["integer", "null"] }
+        },
+        "required": ["type", "run_count", "metadata", "value"],
+        "additionalProperties": false
+    });
+    for tool in &mut tools {
+        if tool["name"] == "scenario_status" {
+            tool["input_schema"] = schema.clone();
+        }
+    }
+    let temp = TempFile::new("tooling-go-fields");
+    fs::write(&temp.path, serde_json::to_vec(&tools)?)?;
+    let go = SdkGenerator::load(&temp.path)?.generate_go()?;
+    let expected = "type ScenarioStatusRequest struct {\n\tAttemptLimit *int64 \
+                    `json:\"attempt_limit,omitempty\"`\n\n\tMetadata map[string]any \
+                    `json:\"metadata\"`\n\n\tRunCount int64 `json:\"run_count\"`\n\n\tType_ \
+                    string `json:\"type\"`\n\n\t// One of: bool, string.\n\tValue any \
+                    `json:\"value\"`\n}\n";
+    if !go.contains(expected) {
+        return Err(std::io::Error::other(format!("go missing: {expected}")).into());
+    }
+    Ok(())
+}
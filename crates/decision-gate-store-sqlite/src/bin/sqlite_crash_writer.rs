@@ -61,6 +61,7 @@ fn main() -> Result<(), Box<dyn std::error::Error>> {
         max_versions: None,
         schema_registry_max_schema_bytes: None,
         schema_registry_max_entries: None,
+        retention: Vec::new(),
     };
     let _store = SqliteRunStateStore::new(config)?;
     let state = sample_state(&run_id)?;
@@ -23,11 +23,21 @@ pub mod store;
 // SECTION: Re-Exports
 // ============================================================================
 
+pub use store::FreshRead;
+pub use store::MAX_RETENTION_AGE_DAYS;
 pub use store::MAX_STATE_BYTES;
+pub use store::ReadFreshness;
+pub use store::RetentionReport;
+pub use store::RetentionWorker;
+pub use store::RunChainIssue;
+pub use store::RunChainReport;
 pub use store::RunSummary;
 pub use store::RunVersionSummary;
+pub use store::SqliteFollowerConfig;
+pub use store::SqliteRetentionPolicy;
 pub use store::SqliteRunStateStore;
 pub use store::SqliteStoreConfig;
 pub use store::SqliteStoreError;
 pub use store::SqliteStoreMode;
 pub use store::SqliteSyncMode;
+pub use store::validate_retention_policies;
@@ -9,7 +9,11 @@
 //! ## Overview
 //! This module implements a durable [`RunStateStore`] using `SQLite`. Each save
 //! produces a canonical JSON snapshot stored in an append-only version table.
-//! Loads verify integrity via stored hashes and fail closed on corruption.
+//! Each version records the hash of its predecessor, forming a hash chain that
+//! [`SqliteRunStateStore::verify_run_chain`] checks for missing or reordered
+//! versions. Loads verify integrity via stored hashes and fail closed on
+//! corruption. [`SqliteRunStateStore::open_follower`] opens a replica of the
+//! store file read-only and reports how stale its reads are.
 //! Security posture: database contents are untrusted; see
 //! `Docs/security/threat_model.md`.
 
@@ -21,6 +25,9 @@ use std::path::Path;
 use std::path::PathBuf;
 use std::sync::Arc;
 use std::sync::Mutex;
+use std::sync::mpsc;
+use std::thread::JoinHandle;
+use std::time::Duration;
 use std::time::SystemTime;
 use std::time::UNIX_EPOCH;
 
@@ -38,6 +45,7 @@ use decision_gate_core::RunStateStore;
 use decision_gate_core::StoreError;
 use decision_gate_core::TenantId;
 use decision_gate_core::Timestamp;
+use decision_gate_core::check_run_version;
 use decision_gate_core::hashing::DEFAULT_HASH_ALGORITHM;
 use decision_gate_core::hashing::HashAlgorithm;
 use decision_gate_core::hashing::canonical_json_bytes;
@@ -47,6 +55,7 @@ use rusqlite::Connection;
 use rusqlite::ErrorCode;
 use rusqlite::OpenFlags;
 use rusqlite::OptionalExtension;
+use rusqlite::TransactionBehavior;
 use rusqlite::params;
 use serde::Deserialize;
 use serde::Serialize;
@@ -57,7 +66,7 @@ use thiserror::Error;
 // ============================================================================
 
 /// `SQLite` schema version for the store.
-const SCHEMA_VERSION: i64 = 4;
+const SCHEMA_VERSION: i64 = 5;
 /// Default busy timeout (ms).
 const DEFAULT_BUSY_TIMEOUT_MS: u64 = 5_000;
 /// Maximum length of a single path component.
@@ -66,6 +75,10 @@ const MAX_PATH_COMPONENT_LENGTH: usize = 255;
 const MAX_TOTAL_PATH_LENGTH: usize = 4096;
 /// Maximum run state snapshot size accepted by the store.
 pub const MAX_STATE_BYTES: usize = MAX_RUNPACK_ARTIFACT_BYTES;
+/// Milliseconds per day for age-based retention.
+const MILLIS_PER_DAY: i64 = 86_400_000;
+/// Maximum retention age in days.
+pub const MAX_RETENTION_AGE_DAYS: u64 = 36_500;
 /// Maximum schema payload size accepted by the registry.
 /// Acts as a hard upper bound for configurable registry limits.
 pub const MAX_SCHEMA_BYTES: usize = 1024 * 1024;
@@ -142,6 +155,7 @@ impl SqliteSyncMode {
 /// - `schema_registry_max_schema_bytes`, when set, must be greater than zero and no more than
 ///   [`MAX_SCHEMA_BYTES`].
 /// - `schema_registry_max_entries`, when set, must be greater than zero.
+/// - `retention` policies satisfy [`validate_retention_policies`].
 #[derive(Debug, Clone, Deserialize)]
 pub struct SqliteStoreConfig {
     /// Path to the `SQLite` database file.
@@ -164,6 +178,111 @@ pub struct SqliteStoreConfig {
     /// Optional maximum number of schemas per tenant + namespace.
     #[serde(default)]
     pub schema_registry_max_entries: Option<usize>,
+    /// Retention policies applied by [`SqliteRunStateStore::apply_retention`].
+    #[serde(default)]
+    pub retention: Vec<SqliteRetentionPolicy>,
+}
+
+/// Automatic version retention for runs in a tenant or tenant + namespace.
+///
+/// A version is pruned only when every configured bound allows it, and the
+/// latest version of a run is always kept. Pruning removes the oldest versions
+/// first, so the retained versions remain a contiguous hash chain.
+///
+/// # Invariants
+/// - At least one of `keep_versions` or `max_age_days` is set, and set bounds are nonzero.
+/// - A namespace-scoped policy overrides a tenant-wide policy for the same tenant.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct SqliteRetentionPolicy {
+    /// Tenant the policy applies to.
+    pub tenant_id: TenantId,
+    /// Namespace the policy applies to (`None` for every namespace in the tenant).
+    #[serde(default)]
+    pub namespace_id: Option<NamespaceId>,
+    /// Keep at most this many of the most recent versions per run.
+    #[serde(default)]
+    pub keep_versions: Option<u64>,
+    /// Keep versions saved within this many days.
+    #[serde(default)]
+    pub max_age_days: Option<u64>,
+}
+
+/// Configuration for a read-only follower over a replica of the store file.
+///
+/// The replica is produced outside the store (file copy, WAL shipping, or
+/// filesystem replication); the follower never writes to it.
+///
+/// # Invariants
+/// - `path` names an existing store file at the current schema version.
+/// - `max_staleness_ms`, when set, must be greater than zero.
+#[derive(Debug, Clone, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct SqliteFollowerConfig {
+    /// Path to the replicated `SQLite` database file.
+    pub path: PathBuf,
+    /// Busy timeout in milliseconds.
+    #[serde(default = "default_busy_timeout_ms")]
+    pub busy_timeout_ms: u64,
+    /// Reads are flagged stale when the replica's newest write is older than this.
+    #[serde(default)]
+    pub max_staleness_ms: Option<u64>,
+}
+
+/// Validates retention policies.
+///
+/// # Errors
+///
+/// Returns [`SqliteStoreError::Invalid`] when a policy sets no bound, a bound is
+/// zero or out of range, or two policies share a scope.
+pub fn validate_retention_policies(
+    policies: &[SqliteRetentionPolicy],
+) -> Result<(), SqliteStoreError> {
+    for (index, policy) in policies.iter().enumerate() {
+        if policy.keep_versions.is_none() && policy.max_age_days.is_none() {
+            return Err(SqliteStoreError::Invalid(format!(
+                "retention[{index}] must set keep_versions or max_age_days"
+            )));
+        }
+        if policy.keep_versions == Some(0) {
+            return Err(SqliteStoreError::Invalid(format!(
+                "retention[{index}] keep_versions must be greater than zero"
+            )));
+        }
+        if let Some(days) = policy.max_age_days
+            && (days == 0 || days > MAX_RETENTION_AGE_DAYS)
+        {
+            return Err(SqliteStoreError::Invalid(format!(
+                "retention[{index}] max_age_days out of range: {days} (max \
+                 {MAX_RETENTION_AGE_DAYS})"
+            )));
+        }
+        if policies[.. index].iter().any(|other| {
+            other.tenant_id == policy.tenant_id && other.namespace_id == policy.namespace_id
+        }) {
+            return Err(SqliteStoreError::Invalid(format!(
+                "retention[{index}] duplicates the scope of an earlier policy"
+            )));
+        }
+    }
+    Ok(())
+}
+
+/// Returns the most specific retention policy for a run.
+fn retention_policy_for(
+    policies: &[SqliteRetentionPolicy],
+    tenant_id: TenantId,
+    namespace_id: NamespaceId,
+) -> Option<&SqliteRetentionPolicy> {
+    let mut tenant_wide = None;
+    for policy in policies.iter().filter(|policy| policy.tenant_id == tenant_id) {
+        match policy.namespace_id {
+            Some(scope) if scope == namespace_id => return Some(policy),
+            None => tenant_wide = Some(policy),
+            Some(_) => {}
+        }
+    }
+    tenant_wide
 }
 
 /// Returns the default busy timeout for `SQLite` connections.
@@ -223,6 +342,17 @@ pub enum SqliteStoreError {
         /// Actual payload size in bytes.
         actual_bytes: usize,
     },
+    /// Write attempted on a read-only follower.
+    #[error("sqlite store is read-only: {0}")]
+    ReadOnly(String),
+    /// Stored run version differed from the expected version.
+    #[error("sqlite store version conflict: expected {expected}, found {actual}")]
+    VersionConflict {
+        /// Version the caller expected.
+        expected: u64,
+        /// Version currently stored.
+        actual: u64,
+    },
 }
 
 impl From<SqliteStoreError> for StoreError {
@@ -239,6 +369,14 @@ impl From<SqliteStoreError> for StoreError {
             } => Self::Invalid(format!(
                 "state_json exceeds size limit: {actual_bytes} bytes (max {max_bytes})"
             )),
+            SqliteStoreError::ReadOnly(message) => Self::Invalid(format!("read-only: {message}")),
+            SqliteStoreError::VersionConflict {
+                expected,
+                actual,
+            } => Self::VersionConflict {
+                expected,
+                actual,
+            },
         }
     }
 }
@@ -252,12 +390,58 @@ impl From<SqliteStoreError> for StoreError {
 /// # Invariants
 /// - Run state loads verify stored hashes before deserialization.
 /// - `SQLite` connection access is serialized through a mutex.
+/// - Follower stores open the database read-only and reject every write.
 #[derive(Clone)]
 pub struct SqliteRunStateStore {
     /// Store configuration.
     config: SqliteStoreConfig,
     /// Shared `SQLite` connection guarded by a mutex.
     connection: Arc<Mutex<Connection>>,
+    /// Whether this store is the writable primary or a read-only follower.
+    role: StoreRole,
+}
+
+/// Role of an opened store.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum StoreRole {
+    /// Writable primary store.
+    Primary,
+    /// Read-only follower over a replicated store file.
+    Follower {
+        /// Staleness bound for flagging reads.
+        max_staleness_ms: Option<u64>,
+    },
+}
+
+/// Freshness of the data a store read was served from.
+///
+/// Staleness is measured from the newest run state version visible to the
+/// store, so it is an upper bound on replication lag: an idle primary also
+/// ages its replicas.
+///
+/// # Invariants
+/// - On the primary, `staleness_ms` is `Some(0)` and `stale` is false.
+/// - On a follower with no stored versions, `staleness_ms` is `None` and `stale` is true when a
+///   staleness bound is configured.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ReadFreshness {
+    /// Whether the read was served by a read-only follower.
+    pub follower: bool,
+    /// Newest `saved_at` (unix millis) across stored run state versions.
+    pub last_saved_at: Option<i64>,
+    /// Milliseconds between `last_saved_at` and the time of the read.
+    pub staleness_ms: Option<u64>,
+    /// Whether `staleness_ms` exceeds the follower's configured bound.
+    pub stale: bool,
+}
+
+/// Store read result annotated with its freshness.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct FreshRead<T> {
+    /// Read result.
+    pub value: T,
+    /// Freshness of the store when the read was served.
+    pub freshness: ReadFreshness,
 }
 
 /// Summary metadata for a stored run.
@@ -286,10 +470,112 @@ pub struct RunVersionSummary {
     pub state_hash: String,
     /// Stored hash algorithm label.
     pub hash_algorithm: String,
+    /// State hash of the previous version (`None` for the first version).
+    pub prev_state_hash: Option<String>,
     /// Stored payload length in bytes.
     pub state_bytes: usize,
 }
 
+/// Outcome of a retention pass.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize)]
+pub struct RetentionReport {
+    /// Runs covered by a retention policy.
+    pub runs_checked: u64,
+    /// Runs with at least one version pruned (or prunable, on a dry run).
+    pub runs_pruned: u64,
+    /// Versions pruned (or prunable, on a dry run).
+    pub versions_pruned: u64,
+}
+
+/// Handle to a background retention worker.
+///
+/// The worker stops when the handle is dropped.
+#[derive(Debug)]
+pub struct RetentionWorker {
+    /// Stop signal; dropping the sender wakes and ends the worker.
+    stop: Option<mpsc::Sender<()>>,
+    /// Worker thread handle.
+    handle: Option<JoinHandle<()>>,
+}
+
+impl RetentionWorker {
+    /// Stops the worker and waits for an in-flight pass to finish.
+    pub fn stop(mut self) {
+        self.shutdown();
+    }
+
+    /// Signals the worker thread and joins it.
+    fn shutdown(&mut self) {
+        drop(self.stop.take());
+        if let Some(handle) = self.handle.take() {
+            let _ = handle.join();
+        }
+    }
+}
+
+impl Drop for RetentionWorker {
+    fn drop(&mut self) {
+        self.shutdown();
+    }
+}
+
+/// Hash chain verification result for a run's stored versions.
+///
+/// # Invariants
+/// - `issues` is empty when every retained version verifies and links to its predecessor.
+/// - Versions older than `first_version` may have been pruned by retention; the first retained
+///   version's link is not checked unless it is version 1.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct RunChainReport {
+    /// Oldest retained version, if any versions remain.
+    pub first_version: Option<i64>,
+    /// Latest version recorded for the run.
+    pub latest_version: i64,
+    /// Number of stored versions checked.
+    pub versions_checked: usize,
+    /// Chain breaks found, in version order.
+    pub issues: Vec<RunChainIssue>,
+}
+
+impl RunChainReport {
+    /// Returns true when no chain breaks were found.
+    #[must_use]
+    pub const fn is_intact(&self) -> bool {
+        self.issues.is_empty()
+    }
+}
+
+/// Hash chain break detected by [`SqliteRunStateStore::verify_run_chain`].
+///
+/// # Invariants
+/// - Variants are stable for serialization.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(tag = "kind", rename_all = "snake_case")]
+pub enum RunChainIssue {
+    /// Versions absent from the chain (inclusive range).
+    MissingVersions {
+        /// First missing version.
+        from_version: i64,
+        /// Last missing version.
+        to_version: i64,
+    },
+    /// Stored state hash does not match the stored payload.
+    StateHashMismatch {
+        /// Affected version.
+        version: i64,
+    },
+    /// Previous-version hash does not match the preceding version's state hash.
+    PrevHashMismatch {
+        /// Affected version.
+        version: i64,
+    },
+    /// Previous-version hash is absent on a version that follows another.
+    PrevHashMissing {
+        /// Affected version.
+        version: i64,
+    },
+}
+
 impl SqliteRunStateStore {
     /// Opens an `SQLite`-backed run state store.
     ///
@@ -301,14 +587,76 @@ impl SqliteRunStateStore {
         validate_store_path(&config.path)?;
         ensure_parent_dir(&config.path)?;
         validate_schema_registry_limits(&config)?;
+        validate_retention_policies(&config.retention)?;
         let mut connection = open_connection(&config)?;
         initialize_schema(&mut connection)?;
         Ok(Self {
             config,
             connection: Arc::new(Mutex::new(connection)),
+            role: StoreRole::Primary,
+        })
+    }
+
+    /// Opens a read-only follower over a replica of a primary store file.
+    ///
+    /// The follower serves every read the primary does and rejects writes
+    /// with [`SqliteStoreError::ReadOnly`]; writes always go to the primary.
+    /// The replica is never migrated, so it must already be at the current
+    /// schema version.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`SqliteStoreError`] when the replica is missing, cannot be
+    /// opened read-only, or is at a different schema version.
+    pub fn open_follower(follower: SqliteFollowerConfig) -> Result<Self, SqliteStoreError> {
+        validate_store_path(&follower.path)?;
+        if !follower.path.is_file() {
+            return Err(SqliteStoreError::Io(format!(
+                "follower replica not found: {}",
+                follower.path.display()
+            )));
+        }
+        if follower.max_staleness_ms == Some(0) {
+            return Err(SqliteStoreError::Invalid(
+                "max_staleness_ms must be greater than zero".to_string(),
+            ));
+        }
+        let connection = open_follower_connection(&follower)?;
+        check_schema_version(&connection)?;
+        Ok(Self {
+            config: SqliteStoreConfig {
+                path: follower.path,
+                busy_timeout_ms: follower.busy_timeout_ms,
+                journal_mode: SqliteStoreMode::default(),
+                sync_mode: SqliteSyncMode::default(),
+                max_versions: None,
+                schema_registry_max_schema_bytes: None,
+                schema_registry_max_entries: None,
+                retention: Vec::new(),
+            },
+            connection: Arc::new(Mutex::new(connection)),
+            role: StoreRole::Follower {
+                max_staleness_ms: follower.max_staleness_ms,
+            },
         })
     }
 
+    /// Returns true when this store is a read-only follower.
+    #[must_use]
+    pub const fn is_follower(&self) -> bool {
+        matches!(self.role, StoreRole::Follower { .. })
+    }
+
+    /// Rejects writes on a read-only follower.
+    fn ensure_writable(&self) -> Result<(), SqliteStoreError> {
+        if self.is_follower() {
+            return Err(SqliteStoreError::ReadOnly(
+                "follower stores reject writes; write to the primary".to_string(),
+            ));
+        }
+        Ok(())
+    }
+
     /// Verifies the store can execute a simple SQL statement.
     ///
     /// # Errors
@@ -320,7 +668,9 @@ impl SqliteRunStateStore {
                 .connection
                 .lock()
                 .map_err(|_| SqliteStoreError::Io("sqlite mutex poisoned".to_string()))?;
-            guard.execute("SELECT 1", []).map_err(|err| SqliteStoreError::Db(err.to_string()))?;
+            guard
+                .query_row("SELECT 1", [], |row| row.get::<_, i64>(0))
+                .map_err(|err| SqliteStoreError::Db(err.to_string()))?;
         }
         Ok(())
     }
@@ -352,7 +702,11 @@ impl RunStateStore for SqliteRunStateStore {
     }
 
     fn save(&self, state: &RunState) -> Result<(), StoreError> {
-        self.save_state(state).map_err(StoreError::from)
+        self.save_state(state, None).map_err(StoreError::from)
+    }
+
+    fn save_if_version(&self, state: &RunState, expected_version: u64) -> Result<(), StoreError> {
+        self.save_state(state, Some(expected_version)).map_err(StoreError::from)
     }
 
     fn readiness(&self) -> Result<(), StoreError> {
@@ -362,6 +716,7 @@ impl RunStateStore for SqliteRunStateStore {
 
 impl DataShapeRegistry for SqliteRunStateStore {
     fn register(&self, record: DataShapeRecord) -> Result<(), DataShapeRegistryError> {
+        self.ensure_writable().map_err(|err| DataShapeRegistryError::Access(err.to_string()))?;
         let schema_bytes = canonical_json_bytes(&record.schema)
             .map_err(|err| DataShapeRegistryError::Invalid(err.to_string()))?;
         ensure_schema_bytes_within_limit(schema_bytes.len(), self.registry_max_schema_bytes())?;
@@ -557,7 +912,16 @@ impl SqliteRunStateStore {
     }
 
     /// Saves run state to the `SQLite` store.
-    fn save_state(&self, state: &RunState) -> Result<(), SqliteStoreError> {
+    ///
+    /// With `expected_version`, the latest stored state is checked inside the
+    /// write transaction, so the check and the insert are atomic across every
+    /// connection sharing the database file.
+    fn save_state(
+        &self,
+        state: &RunState,
+        expected_version: Option<u64>,
+    ) -> Result<(), SqliteStoreError> {
+        self.ensure_writable()?;
         let canonical_json = canonical_json_bytes(state)
             .map_err(|err| SqliteStoreError::Invalid(err.to_string()))?;
         if canonical_json.len() > MAX_STATE_BYTES {
@@ -573,7 +937,10 @@ impl SqliteRunStateStore {
                 .connection
                 .lock()
                 .map_err(|_| SqliteStoreError::Db("mutex poisoned".to_string()))?;
-            let tx = guard.transaction().map_err(|err| SqliteStoreError::Db(err.to_string()))?;
+            // Take the write lock up front so the version read below cannot go stale.
+            let tx = guard
+                .transaction_with_behavior(TransactionBehavior::Immediate)
+                .map_err(|err| SqliteStoreError::Db(err.to_string()))?;
             let latest_version: Option<i64> = tx
                 .query_row(
                     "SELECT latest_version FROM runs WHERE tenant_id = ?1 AND namespace_id = ?2 \
@@ -604,6 +971,13 @@ impl SqliteRunStateStore {
                     })?
                 }
             };
+            if let Some(expected_version) = expected_version {
+                check_latest_version(&tx, state, latest_version, expected_version)?;
+            }
+            let prev_state_hash = match latest_version {
+                Some(version) => Some(fetch_state_hash(&tx, state, version)?),
+                None => None,
+            };
             tx.execute(
                 "INSERT INTO runs (tenant_id, namespace_id, run_id, latest_version) VALUES (?1, \
                  ?2, ?3, ?4) ON CONFLICT(tenant_id, namespace_id, run_id) DO UPDATE SET \
@@ -618,8 +992,8 @@ impl SqliteRunStateStore {
             .map_err(|err| SqliteStoreError::Db(err.to_string()))?;
             tx.execute(
                 "INSERT INTO run_state_versions (tenant_id, namespace_id, run_id, version, \
-                 state_json, state_hash, hash_algorithm, saved_at) VALUES (?1, ?2, ?3, ?4, ?5, \
-                 ?6, ?7, ?8)",
+                 state_json, state_hash, hash_algorithm, prev_state_hash, saved_at) VALUES (?1, \
+                 ?2, ?3, ?4, ?5, ?6, ?7, ?8, ?9)",
                 params![
                     state.tenant_id.to_string(),
                     state.namespace_id.to_string(),
@@ -628,6 +1002,7 @@ impl SqliteRunStateStore {
                     canonical_json,
                     digest.value,
                     hash_algorithm_label(digest.algorithm),
+                    prev_state_hash,
                     saved_at
                 ],
             )
@@ -731,9 +1106,9 @@ impl SqliteRunStateStore {
             .map_err(|_| SqliteStoreError::Db("mutex poisoned".to_string()))?;
         let mut stmt = guard
             .prepare(
-                "SELECT version, saved_at, state_hash, hash_algorithm, length(state_json) FROM \
-                 run_state_versions WHERE tenant_id = ?1 AND namespace_id = ?2 AND run_id = ?3 \
-                 ORDER BY version DESC",
+                "SELECT version, saved_at, state_hash, hash_algorithm, prev_state_hash, \
+                 length(state_json) FROM run_state_versions WHERE tenant_id = ?1 AND namespace_id \
+                 = ?2 AND run_id = ?3 ORDER BY version DESC",
             )
             .map_err(|err| SqliteStoreError::Db(err.to_string()))?;
         let rows = stmt
@@ -744,14 +1119,15 @@ impl SqliteRunStateStore {
                     let saved_at: i64 = row.get(1)?;
                     let state_hash: String = row.get(2)?;
                     let hash_algorithm: String = row.get(3)?;
-                    let length: i64 = row.get(4)?;
-                    Ok((version, saved_at, state_hash, hash_algorithm, length))
+                    let prev_state_hash: Option<String> = row.get(4)?;
+                    let length: i64 = row.get(5)?;
+                    Ok((version, saved_at, state_hash, hash_algorithm, prev_state_hash, length))
                 },
             )
             .map_err(|err| SqliteStoreError::Db(err.to_string()))?;
         let mut results = Vec::new();
         for row in rows {
-            let (version, saved_at, state_hash, hash_algorithm, length) =
+            let (version, saved_at, state_hash, hash_algorithm, prev_state_hash, length) =
                 row.map_err(|err| SqliteStoreError::Db(err.to_string()))?;
             let length = usize::try_from(length).map_err(|_| {
                 SqliteStoreError::Invalid(format!(
@@ -770,6 +1146,7 @@ impl SqliteRunStateStore {
                 saved_at,
                 state_hash,
                 hash_algorithm,
+                prev_state_hash,
                 state_bytes: length,
             });
         }
@@ -827,6 +1204,288 @@ impl SqliteRunStateStore {
         Ok(Some(state))
     }
 
+    /// Reports the freshness of the data this store currently serves.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`SqliteStoreError`] if the query fails.
+    pub fn freshness(&self) -> Result<ReadFreshness, SqliteStoreError> {
+        self.freshness_at(unix_millis())
+    }
+
+    /// Reports freshness as of `now_ms` (unix millis).
+    ///
+    /// # Errors
+    ///
+    /// Returns [`SqliteStoreError`] if the query fails.
+    pub fn freshness_at(&self, now_ms: i64) -> Result<ReadFreshness, SqliteStoreError> {
+        let last_saved_at: Option<i64> = {
+            let guard = self
+                .connection
+                .lock()
+                .map_err(|_| SqliteStoreError::Db("mutex poisoned".to_string()))?;
+            guard
+                .query_row("SELECT MAX(saved_at) FROM run_state_versions", [], |row| row.get(0))
+                .map_err(|err| SqliteStoreError::Db(err.to_string()))?
+        };
+        let StoreRole::Follower {
+            max_staleness_ms,
+        } = self.role
+        else {
+            return Ok(ReadFreshness {
+                follower: false,
+                last_saved_at,
+                staleness_ms: Some(0),
+                stale: false,
+            });
+        };
+        let staleness_ms = last_saved_at
+            .map(|saved_at| u64::try_from(now_ms.saturating_sub(saved_at)).unwrap_or(0));
+        let stale = match (max_staleness_ms, staleness_ms) {
+            (Some(max), Some(staleness)) => staleness > max,
+            (Some(_), None) => true,
+            (None, _) => false,
+        };
+        Ok(ReadFreshness {
+            follower: true,
+            last_saved_at,
+            staleness_ms,
+            stale,
+        })
+    }
+
+    /// Loads run state together with the store's freshness.
+    ///
+    /// Freshness is sampled before the read, so a replica refreshed mid-read
+    /// is reported as older than the data returned, never newer.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`SqliteStoreError`] if freshness cannot be read or the load fails.
+    pub fn load_with_freshness(
+        &self,
+        tenant_id: TenantId,
+        namespace_id: NamespaceId,
+        run_id: &RunId,
+    ) -> Result<FreshRead<Option<RunState>>, SqliteStoreError> {
+        let freshness = self.freshness()?;
+        let value = self.load_state(tenant_id, namespace_id, run_id)?;
+        Ok(FreshRead {
+            value,
+            freshness,
+        })
+    }
+
+    /// Lists stored runs together with the store's freshness.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`SqliteStoreError`] if freshness cannot be read or the listing fails.
+    pub fn list_runs_with_freshness(
+        &self,
+        tenant_id: Option<TenantId>,
+        namespace_id: Option<NamespaceId>,
+    ) -> Result<FreshRead<Vec<RunSummary>>, SqliteStoreError> {
+        let freshness = self.freshness()?;
+        let value = self.list_runs(tenant_id, namespace_id)?;
+        Ok(FreshRead {
+            value,
+            freshness,
+        })
+    }
+
+    /// Applies the configured retention policies to every stored run.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`SqliteStoreError`] if a query fails or stored IDs cannot be
+    /// parsed.
+    pub fn apply_retention(&self, dry_run: bool) -> Result<RetentionReport, SqliteStoreError> {
+        self.apply_retention_at(unix_millis(), dry_run)
+    }
+
+    /// Applies the configured retention policies as of `now_ms` (unix millis).
+    ///
+    /// Each run's oldest versions are pruned up to the newest version every
+    /// configured bound allows; the latest version is never pruned. With
+    /// `dry_run`, the report counts prunable versions without deleting them.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`SqliteStoreError`] if a query fails or stored IDs cannot be
+    /// parsed.
+    pub fn apply_retention_at(
+        &self,
+        now_ms: i64,
+        dry_run: bool,
+    ) -> Result<RetentionReport, SqliteStoreError> {
+        if !dry_run {
+            self.ensure_writable()?;
+        }
+        let mut report = RetentionReport::default();
+        if self.config.retention.is_empty() {
+            return Ok(report);
+        }
+        let mut guard = self
+            .connection
+            .lock()
+            .map_err(|_| SqliteStoreError::Db("mutex poisoned".to_string()))?;
+        let tx = guard.transaction().map_err(|err| SqliteStoreError::Db(err.to_string()))?;
+        let runs = {
+            let mut stmt = tx
+                .prepare("SELECT tenant_id, namespace_id, run_id, latest_version FROM runs")
+                .map_err(|err| SqliteStoreError::Db(err.to_string()))?;
+            let rows = stmt
+                .query_map([], |row| {
+                    Ok((
+                        row.get::<_, String>(0)?,
+                        row.get::<_, String>(1)?,
+                        row.get::<_, String>(2)?,
+                        row.get::<_, i64>(3)?,
+                    ))
+                })
+                .map_err(|err| SqliteStoreError::Db(err.to_string()))?;
+            rows.collect::<Result<Vec<_>, _>>()
+                .map_err(|err| SqliteStoreError::Db(err.to_string()))?
+        };
+        for (tenant_raw, namespace_raw, run_id, latest_version) in runs {
+            let tenant_id = parse_tenant_id_str(&tenant_raw)?;
+            let namespace_id = parse_namespace_id_str(&namespace_raw)?;
+            let Some(policy) =
+                retention_policy_for(&self.config.retention, tenant_id, namespace_id)
+            else {
+                continue;
+            };
+            report.runs_checked = report.runs_checked.saturating_add(1);
+            let key = params![tenant_raw, namespace_raw, run_id];
+            let Some(prune_through) = retention_cutoff(&tx, policy, key, latest_version, now_ms)?
+            else {
+                continue;
+            };
+            let range = params![tenant_raw, namespace_raw, run_id, prune_through];
+            let pruned: i64 = tx
+                .query_row(
+                    "SELECT COUNT(*) FROM run_state_versions WHERE tenant_id = ?1 AND \
+                     namespace_id = ?2 AND run_id = ?3 AND version <= ?4",
+                    range,
+                    |row| row.get(0),
+                )
+                .map_err(|err| SqliteStoreError::Db(err.to_string()))?;
+            if !dry_run {
+                tx.execute(
+                    "DELETE FROM run_state_versions WHERE tenant_id = ?1 AND namespace_id = ?2 \
+                     AND run_id = ?3 AND version <= ?4",
+                    range,
+                )
+                .map_err(|err| SqliteStoreError::Db(err.to_string()))?;
+            }
+            let pruned = u64::try_from(pruned).unwrap_or(0);
+            if pruned > 0 {
+                report.runs_pruned = report.runs_pruned.saturating_add(1);
+                report.versions_pruned = report.versions_pruned.saturating_add(pruned);
+            }
+        }
+        tx.commit().map_err(|err| SqliteStoreError::Db(err.to_string()))?;
+        drop(guard);
+        Ok(report)
+    }
+
+    /// Spawns a background thread applying retention every `interval`.
+    ///
+    /// Failed passes are retried on the next tick. Returns `None` when no
+    /// retention policies are configured.
+    #[must_use]
+    pub fn spawn_retention_worker(&self, interval: Duration) -> Option<RetentionWorker> {
+        if self.config.retention.is_empty() {
+            return None;
+        }
+        let store = self.clone();
+        let (stop, ticks) = mpsc::channel::<()>();
+        let handle = std::thread::spawn(move || {
+            while ticks.recv_timeout(interval) == Err(mpsc::RecvTimeoutError::Timeout) {
+                let _ = store.apply_retention(false);
+            }
+        });
+        Some(RetentionWorker {
+            stop: Some(stop),
+            handle: Some(handle),
+        })
+    }
+
+    /// Verifies the previous-version hash chain across a run's stored versions.
+    ///
+    /// Each retained version's payload is checked against its stored hash, and
+    /// each version's previous-version hash is checked against the preceding
+    /// version's stored hash. Returns `None` when the run does not exist.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`SqliteStoreError`] if the query fails, a payload exceeds
+    /// [`MAX_STATE_BYTES`], or a stored hash algorithm is unsupported.
+    pub fn verify_run_chain(
+        &self,
+        tenant_id: TenantId,
+        namespace_id: NamespaceId,
+        run_id: &RunId,
+    ) -> Result<Option<RunChainReport>, SqliteStoreError> {
+        let guard = self
+            .connection
+            .lock()
+            .map_err(|_| SqliteStoreError::Db("mutex poisoned".to_string()))?;
+        let key = params![tenant_id.to_string(), namespace_id.to_string(), run_id.as_str()];
+        let latest_version: Option<i64> = guard
+            .query_row(
+                "SELECT latest_version FROM runs WHERE tenant_id = ?1 AND namespace_id = ?2 AND \
+                 run_id = ?3",
+                key,
+                |row| row.get(0),
+            )
+            .optional()
+            .map_err(|err| SqliteStoreError::Db(err.to_string()))?;
+        let Some(latest_version) = latest_version else {
+            return Ok(None);
+        };
+        let mut stmt = guard
+            .prepare(
+                "SELECT version, length(state_json), state_hash, hash_algorithm, prev_state_hash, \
+                 state_json FROM run_state_versions WHERE tenant_id = ?1 AND namespace_id = ?2 \
+                 AND run_id = ?3 ORDER BY version ASC",
+            )
+            .map_err(|err| SqliteStoreError::Db(err.to_string()))?;
+        let mut rows = stmt.query(key).map_err(|err| SqliteStoreError::Db(err.to_string()))?;
+        let mut chain = ChainCursor::default();
+        while let Some(row) = rows.next().map_err(|err| SqliteStoreError::Db(err.to_string()))? {
+            let version: i64 = row.get(0).map_err(|err| SqliteStoreError::Db(err.to_string()))?;
+            let length: i64 = row.get(1).map_err(|err| SqliteStoreError::Db(err.to_string()))?;
+            let length = usize::try_from(length).map_err(|_| {
+                SqliteStoreError::Invalid(format!(
+                    "negative run state length for run {}",
+                    run_id.as_str()
+                ))
+            })?;
+            if length > MAX_STATE_BYTES {
+                return Err(SqliteStoreError::TooLarge {
+                    max_bytes: MAX_STATE_BYTES,
+                    actual_bytes: length,
+                });
+            }
+            let state_hash: String =
+                row.get(2).map_err(|err| SqliteStoreError::Db(err.to_string()))?;
+            let algorithm: String =
+                row.get(3).map_err(|err| SqliteStoreError::Db(err.to_string()))?;
+            let prev_state_hash: Option<String> =
+                row.get(4).map_err(|err| SqliteStoreError::Db(err.to_string()))?;
+            let bytes: Vec<u8> = row.get(5).map_err(|err| SqliteStoreError::Db(err.to_string()))?;
+            let algorithm = parse_hash_algorithm(&algorithm)?;
+            let content_valid = hash_bytes(algorithm, &bytes).value == state_hash;
+            chain.push(version, content_valid, state_hash, prev_state_hash);
+        }
+        drop(rows);
+        drop(stmt);
+        drop(guard);
+        Ok(Some(chain.finish(latest_version)))
+    }
+
     /// Prunes older run state versions, keeping the most recent `keep` entries.
     ///
     /// # Errors
@@ -840,6 +1499,7 @@ impl SqliteRunStateStore {
         run_id: &RunId,
         keep: u64,
     ) -> Result<u64, SqliteStoreError> {
+        self.ensure_writable()?;
         if keep == 0 {
             return Err(SqliteStoreError::Invalid("keep must be >= 1".to_string()));
         }
@@ -946,6 +1606,39 @@ fn open_connection(config: &SqliteStoreConfig) -> Result<Connection, SqliteStore
     Ok(connection)
 }
 
+/// Opens a read-only `SQLite` connection for a follower replica.
+fn open_follower_connection(
+    follower: &SqliteFollowerConfig,
+) -> Result<Connection, SqliteStoreError> {
+    let flags = OpenFlags::SQLITE_OPEN_READ_ONLY | OpenFlags::SQLITE_OPEN_FULL_MUTEX;
+    let connection = Connection::open_with_flags(&follower.path, flags)
+        .map_err(|err| SqliteStoreError::Db(err.to_string()))?;
+    connection
+        .execute_batch("PRAGMA query_only = ON;")
+        .map_err(|err| SqliteStoreError::Db(err.to_string()))?;
+    connection
+        .busy_timeout(Duration::from_millis(follower.busy_timeout_ms))
+        .map_err(|err| SqliteStoreError::Db(err.to_string()))?;
+    Ok(connection)
+}
+
+/// Validates that a replica is at the current schema version without migrating it.
+fn check_schema_version(connection: &Connection) -> Result<(), SqliteStoreError> {
+    let version: Option<i64> = connection
+        .query_row("SELECT version FROM store_meta LIMIT 1", params![], |row| row.get(0))
+        .optional()
+        .map_err(|err| SqliteStoreError::Db(err.to_string()))?;
+    match version {
+        Some(value) if value == SCHEMA_VERSION => Ok(()),
+        Some(value) => Err(SqliteStoreError::VersionMismatch(format!(
+            "follower replica schema version {value} (expected {SCHEMA_VERSION})"
+        ))),
+        None => Err(SqliteStoreError::VersionMismatch(
+            "follower replica has no schema version".to_string(),
+        )),
+    }
+}
+
 /// Applies `SQLite` pragmas required for durability.
 fn apply_pragmas(
     connection: &Connection,
@@ -995,6 +1688,7 @@ fn initialize_schema(connection: &mut Connection) -> Result<(), SqliteStoreError
                     state_json BLOB NOT NULL,
                     state_hash TEXT NOT NULL,
                     hash_algorithm TEXT NOT NULL,
+                    prev_state_hash TEXT,
                     saved_at INTEGER NOT NULL,
                     PRIMARY KEY (tenant_id, namespace_id, run_id, version),
                     FOREIGN KEY (tenant_id, namespace_id, run_id)
@@ -1022,11 +1716,25 @@ fn initialize_schema(connection: &mut Connection) -> Result<(), SqliteStoreError
             )
             .map_err(|err| SqliteStoreError::Db(err.to_string()))?;
         }
-        Some(3) => {
+        Some(value @ (3 | 4)) => {
+            if value == 3 {
+                tx.execute_batch(
+                    "ALTER TABLE data_shapes ADD COLUMN signing_key_id TEXT;
+                     ALTER TABLE data_shapes ADD COLUMN signing_signature TEXT;
+                     ALTER TABLE data_shapes ADD COLUMN signing_algorithm TEXT;",
+                )
+                .map_err(|err| SqliteStoreError::Db(err.to_string()))?;
+            }
+            // Backfilled links attest only to the versions present at upgrade time.
             tx.execute_batch(
-                "ALTER TABLE data_shapes ADD COLUMN signing_key_id TEXT;
-                 ALTER TABLE data_shapes ADD COLUMN signing_signature TEXT;
-                 ALTER TABLE data_shapes ADD COLUMN signing_algorithm TEXT;",
+                "ALTER TABLE run_state_versions ADD COLUMN prev_state_hash TEXT;
+                 UPDATE run_state_versions SET prev_state_hash = (
+                     SELECT prev.state_hash FROM run_state_versions AS prev
+                     WHERE prev.tenant_id = run_state_versions.tenant_id
+                       AND prev.namespace_id = run_state_versions.namespace_id
+                       AND prev.run_id = run_state_versions.run_id
+                       AND prev.version = run_state_versions.version - 1
+                 );",
             )
             .map_err(|err| SqliteStoreError::Db(err.to_string()))?;
             tx.execute("UPDATE store_meta SET version = ?1", params![SCHEMA_VERSION])
@@ -1043,6 +1751,118 @@ fn initialize_schema(connection: &mut Connection) -> Result<(), SqliteStoreError
     Ok(())
 }
 
+/// Returns the newest version a retention policy allows pruning, if any.
+///
+/// Each bound yields a prefix of the run's versions; the intersection is the
+/// shorter prefix, capped below `latest_version`.
+fn retention_cutoff(
+    tx: &rusqlite::Transaction<'_>,
+    policy: &SqliteRetentionPolicy,
+    key: &[&dyn rusqlite::ToSql],
+    latest_version: i64,
+    now_ms: i64,
+) -> Result<Option<i64>, SqliteStoreError> {
+    let mut cutoff = latest_version.saturating_sub(1);
+    if let Some(keep) = policy.keep_versions {
+        let keep = i64::try_from(keep).unwrap_or(i64::MAX);
+        cutoff = cutoff.min(latest_version.saturating_sub(keep));
+    }
+    if let Some(days) = policy.max_age_days {
+        let max_age = i64::try_from(days).unwrap_or(i64::MAX).saturating_mul(MILLIS_PER_DAY);
+        let oldest_kept = now_ms.saturating_sub(max_age);
+        let expired: Option<i64> = tx
+            .query_row(
+                "SELECT MAX(version) FROM run_state_versions WHERE tenant_id = ?1 AND \
+                 namespace_id = ?2 AND run_id = ?3 AND saved_at < ?4",
+                params![key[0], key[1], key[2], oldest_kept],
+                |row| row.get(0),
+            )
+            .map_err(|err| SqliteStoreError::Db(err.to_string()))?;
+        cutoff = cutoff.min(expired.unwrap_or(0));
+    }
+    Ok((cutoff >= 1).then_some(cutoff))
+}
+
+/// Returns the stored state hash for `version`, which must exist.
+fn fetch_state_hash(
+    tx: &rusqlite::Transaction<'_>,
+    state: &RunState,
+    version: i64,
+) -> Result<String, SqliteStoreError> {
+    tx.query_row(
+        "SELECT state_hash FROM run_state_versions WHERE tenant_id = ?1 AND namespace_id = ?2 AND \
+         run_id = ?3 AND version = ?4",
+        params![
+            state.tenant_id.to_string(),
+            state.namespace_id.to_string(),
+            state.run_id.as_str(),
+            version
+        ],
+        |row| row.get(0),
+    )
+    .optional()
+    .map_err(|err| SqliteStoreError::Db(err.to_string()))?
+    .ok_or_else(|| {
+        SqliteStoreError::Corrupt(format!(
+            "latest version {version} missing for run {}",
+            state.run_id.as_str()
+        ))
+    })
+}
+
+/// Checks that the latest stored state for the run is at `expected_version`.
+fn check_latest_version(
+    tx: &rusqlite::Transaction<'_>,
+    state: &RunState,
+    latest_version: Option<i64>,
+    expected_version: u64,
+) -> Result<(), SqliteStoreError> {
+    let current = match latest_version {
+        Some(version) => Some(fetch_state_at(tx, state, version)?),
+        None => None,
+    };
+    check_run_version(current.as_ref(), expected_version).map_err(|err| match err {
+        StoreError::VersionConflict {
+            expected,
+            actual,
+        } => SqliteStoreError::VersionConflict {
+            expected,
+            actual,
+        },
+        StoreError::Invalid(message) => SqliteStoreError::Invalid(message),
+        err => SqliteStoreError::Db(err.to_string()),
+    })
+}
+
+/// Returns the stored run state for `version`, which must exist.
+fn fetch_state_at(
+    tx: &rusqlite::Transaction<'_>,
+    state: &RunState,
+    version: i64,
+) -> Result<RunState, SqliteStoreError> {
+    let bytes: Vec<u8> = tx
+        .query_row(
+            "SELECT state_json FROM run_state_versions WHERE tenant_id = ?1 AND namespace_id = ?2 \
+             AND run_id = ?3 AND version = ?4",
+            params![
+                state.tenant_id.to_string(),
+                state.namespace_id.to_string(),
+                state.run_id.as_str(),
+                version
+            ],
+            |row| row.get(0),
+        )
+        .optional()
+        .map_err(|err| SqliteStoreError::Db(err.to_string()))?
+        .ok_or_else(|| {
+            SqliteStoreError::Corrupt(format!(
+                "latest version {version} missing for run {}",
+                state.run_id.as_str()
+            ))
+        })?;
+    serde_json::from_slice(&bytes).map_err(|err| SqliteStoreError::Invalid(err.to_string()))
+}
+
 /// Enforces version retention if configured.
 fn enforce_retention(
     tx: &rusqlite::Transaction<'_>,
@@ -1084,6 +1904,7 @@ fn unix_millis() -> i64 {
 const fn hash_algorithm_label(algorithm: HashAlgorithm) -> &'static str {
     match algorithm {
         HashAlgorithm::Sha256 => "sha256",
+        HashAlgorithm::Blake3 => "blake3",
     }
 }
 
@@ -1091,10 +1912,88 @@ const fn hash_algorithm_label(algorithm: HashAlgorithm) -> &'static str {
 fn parse_hash_algorithm(label: &str) -> Result<HashAlgorithm, SqliteStoreError> {
     match label {
         "sha256" => Ok(HashAlgorithm::Sha256),
+        "blake3" => Ok(HashAlgorithm::Blake3),
         other => Err(SqliteStoreError::Invalid(format!("unsupported hash algorithm: {other}"))),
     }
 }
 
+/// Incremental hash chain checker over versions in ascending order.
+#[derive(Debug, Default)]
+struct ChainCursor {
+    /// Oldest version seen.
+    first_version: Option<i64>,
+    /// Previous version number and its stored state hash.
+    previous: Option<(i64, String)>,
+    /// Number of versions checked.
+    versions_checked: usize,
+    /// Chain breaks found so far.
+    issues: Vec<RunChainIssue>,
+}
+
+impl ChainCursor {
+    /// Checks the next stored version against its predecessor.
+    fn push(
+        &mut self,
+        version: i64,
+        content_valid: bool,
+        state_hash: String,
+        prev_state_hash: Option<String>,
+    ) {
+        self.versions_checked = self.versions_checked.saturating_add(1);
+        self.first_version.get_or_insert(version);
+        if !content_valid {
+            self.issues.push(RunChainIssue::StateHashMismatch {
+                version,
+            });
+        }
+        match self.previous.take() {
+            Some((prev_version, prev_hash)) if prev_version.checked_add(1) == Some(version) => {
+                match prev_state_hash {
+                    None => self.issues.push(RunChainIssue::PrevHashMissing {
+                        version,
+                    }),
+                    Some(link) if link != prev_hash => {
+                        self.issues.push(RunChainIssue::PrevHashMismatch {
+                            version,
+                        });
+                    }
+                    Some(_) => {}
+                }
+            }
+            Some((prev_version, _)) => {
+                self.issues.push(RunChainIssue::MissingVersions {
+                    from_version: prev_version.saturating_add(1),
+                    to_version: version.saturating_sub(1),
+                });
+            }
+            None if version == 1 && prev_state_hash.is_some() => {
+                self.issues.push(RunChainIssue::PrevHashMismatch {
+                    version,
+                });
+            }
+            None => {}
+        }
+        self.previous = Some((version, state_hash));
+    }
+
+    /// Completes the check against the run's recorded latest version.
+    fn finish(mut self, latest_version: i64) -> RunChainReport {
+        let last_version = self.previous.as_ref().map_or(0, |(version, _)| *version);
+        if last_version < latest_version {
+            self.issues.push(RunChainIssue::MissingVersions {
+                from_version: last_version.saturating_add(1),
+                to_version: latest_version,
+            });
+        }
+        RunChainReport {
+            first_version: self.first_version,
+            latest_version,
+            versions_checked: self.versions_checked,
+            issues: self.issues,
+        }
+    }
+}
+
 /// Raw payload for a stored run state.
 #[derive(Debug)]
 struct RunStatePayload {
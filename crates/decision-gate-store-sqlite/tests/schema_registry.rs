@@ -81,6 +81,7 @@ fn sqlite_fixture() -> SqliteFixture {
         max_versions: None,
         schema_registry_max_schema_bytes: None,
         schema_registry_max_entries: None,
+        retention: Vec::new(),
     };
     let store = SqliteRunStateStore::new(config).expect("store");
     SqliteFixture {
@@ -104,6 +105,7 @@ fn sqlite_fixture_with_limits(
         max_versions: None,
         schema_registry_max_schema_bytes: max_schema_bytes,
         schema_registry_max_entries: max_entries,
+        retention: Vec::new(),
     };
     let store = SqliteRunStateStore::new(config).expect("store");
     SqliteFixture {
@@ -132,6 +134,7 @@ fn insert_oversized_record(fixture: &SqliteFixture, schema_id: &str) -> DataShap
     };
     let hash_algorithm = match hash.algorithm {
         decision_gate_core::hashing::HashAlgorithm::Sha256 => "sha256",
+        decision_gate_core::hashing::HashAlgorithm::Blake3 => "blake3",
     };
     let connection = rusqlite::Connection::open(&fixture.path).expect("open registry db");
     connection
@@ -535,6 +538,7 @@ fn sqlite_registry_concurrent_writes_different_schemas_no_deadlock() {
                 max_versions: None,
                 schema_registry_max_schema_bytes: None,
                 schema_registry_max_entries: None,
+                retention: Vec::new(),
             };
             let store = SqliteRunStateStore::new(config).expect("store");
             for j in 0 .. 3u64 {
@@ -608,6 +612,7 @@ fn sqlite_registry_concurrent_read_write_consistent() {
                 max_versions: None,
                 schema_registry_max_schema_bytes: None,
                 schema_registry_max_entries: None,
+                retention: Vec::new(),
             };
             let store = SqliteRunStateStore::new(config).expect("store");
             for j in 0 .. 5u64 {
@@ -639,6 +644,7 @@ fn sqlite_registry_concurrent_read_write_consistent() {
                 max_versions: None,
                 schema_registry_max_schema_bytes: None,
                 schema_registry_max_entries: None,
+                retention: Vec::new(),
             };
             let store = SqliteRunStateStore::new(config).expect("store");
             for _ in 0 .. 10 {
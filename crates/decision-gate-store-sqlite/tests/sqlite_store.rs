@@ -109,10 +109,26 @@ fn store_for(path: &std::path::Path) -> SqliteRunStateStore {
         max_versions: None,
         schema_registry_max_schema_bytes: None,
         schema_registry_max_entries: None,
+        retention: Vec::new(),
     };
     SqliteRunStateStore::new(config).expect("store init")
 }
 
+fn with_submission(mut state: RunState, submission_id: &str) -> RunState {
+    state.submissions.push(SubmissionRecord {
+        submission_id: submission_id.to_string(),
+        run_id: state.run_id.clone(),
+        payload: PacketPayload::Json {
+            value: serde_json::json!({"submission": submission_id}),
+        },
+        content_type: "application/json".to_string(),
+        content_hash: hash_bytes(DEFAULT_HASH_ALGORITHM, submission_id.as_bytes()),
+        submitted_at: Timestamp::Logical(1),
+        correlation_id: None,
+    });
+    state
+}
+
 // ============================================================================
 // SECTION: Tests
 // ============================================================================
@@ -295,6 +311,7 @@ fn sqlite_store_truncated_database_fails_closed() {
         max_versions: None,
         schema_registry_max_schema_bytes: None,
         schema_registry_max_entries: None,
+        retention: Vec::new(),
     };
     if let Ok(store) = SqliteRunStateStore::new(config) {
         let result = store.load(
@@ -318,6 +335,7 @@ fn sqlite_store_enforces_max_versions() {
         max_versions: Some(2),
         schema_registry_max_schema_bytes: None,
         schema_registry_max_entries: None,
+        retention: Vec::new(),
     };
     let store = SqliteRunStateStore::new(config).expect("store init");
     let mut state = sample_state("run-1");
@@ -355,6 +373,7 @@ fn sqlite_store_rejects_version_mismatch() {
         max_versions: None,
         schema_registry_max_schema_bytes: None,
         schema_registry_max_entries: None,
+        retention: Vec::new(),
     };
     let result = SqliteRunStateStore::new(config);
     assert!(matches!(result, Err(SqliteStoreError::VersionMismatch(_))));
@@ -474,6 +493,7 @@ fn sqlite_store_rejects_directory_path() {
         max_versions: None,
         schema_registry_max_schema_bytes: None,
         schema_registry_max_entries: None,
+        retention: Vec::new(),
     };
     let result = SqliteRunStateStore::new(config);
     assert!(matches!(result, Err(SqliteStoreError::Invalid(_))));
@@ -491,6 +511,7 @@ fn sqlite_store_rejects_overlong_path_component() {
         max_versions: None,
         schema_registry_max_schema_bytes: None,
         schema_registry_max_entries: None,
+        retention: Vec::new(),
     };
     let result = SqliteRunStateStore::new(config);
     assert!(matches!(result, Err(SqliteStoreError::Invalid(_))));
@@ -508,6 +529,7 @@ fn sqlite_store_rejects_overlong_total_path() {
         max_versions: None,
         schema_registry_max_schema_bytes: None,
         schema_registry_max_entries: None,
+        retention: Vec::new(),
     };
     let result = SqliteRunStateStore::new(config);
     assert!(matches!(result, Err(SqliteStoreError::Invalid(_))));
@@ -555,3 +577,35 @@ fn sqlite_store_allows_concurrent_saves() {
     assert_eq!(count, 10);
     assert_eq!(latest, 10);
 }
+
+#[test]
+fn sqlite_store_save_if_version_rejects_stale_writer_across_handles() {
+    let temp = TempDir::new().unwrap();
+    let path = temp.path().join("store.sqlite");
+    let first = store_for(&path);
+    let second = store_for(&path);
+    let base = sample_state("run-1");
+    first.save(&base).unwrap();
+
+    let winner = with_submission(base.clone(), "submission-a");
+    second.save_if_version(&winner, base.version()).unwrap();
+
+    let loser = with_submission(base.clone(), "submission-b");
+    let err = first.save_if_version(&loser, base.version()).unwrap_err();
+    match err {
+        StoreError::VersionConflict {
+            expected,
+            actual,
+        } => {
+            assert_eq!(expected, base.version());
+            assert_eq!(actual, winner.version());
+        }
+        other => panic!("unexpected error: {other:?}"),
+    }
+
+    let loaded = first.load(&base.tenant_id, &base.namespace_id, &base.run_id).unwrap();
+    assert_eq!(loaded, Some(winner));
+    let versions =
+        first.list_run_versions(base.tenant_id, base.namespace_id, &base.run_id).unwrap();
+    assert_eq!(versions.len(), 2, "rejected save must not add a version");
+}
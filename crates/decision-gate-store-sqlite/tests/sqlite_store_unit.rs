@@ -15,6 +15,9 @@
 //! - Hash algorithm validation and payload integrity
 //! - Size limits for state payloads (save/load)
 //! - Retention pruning and list APIs
+//! - Run state version hash chain verification
+//! - Per-tenant/namespace retention policies
+//! - Read-only followers over replicated store files
 //! - Concurrency safety (multi-threaded save/load)
 
 #![allow(
@@ -34,8 +37,14 @@ use std::path::Path;
 use std::path::PathBuf;
 use std::sync::Arc;
 use std::thread;
+use std::time::Duration;
 
 use decision_gate_core::AdvanceTo;
+use decision_gate_core::DataShapeId;
+use decision_gate_core::DataShapeRecord;
+use decision_gate_core::DataShapeRegistry;
+use decision_gate_core::DataShapeVersion;
+use decision_gate_core::HashAlgorithm;
 use decision_gate_core::NamespaceId;
 use decision_gate_core::RunId;
 use decision_gate_core::RunState;
@@ -53,6 +62,9 @@ use decision_gate_core::hashing::DEFAULT_HASH_ALGORITHM;
 use decision_gate_core::hashing::canonical_json_bytes;
 use decision_gate_core::hashing::hash_bytes;
 use decision_gate_store_sqlite::MAX_STATE_BYTES;
+use decision_gate_store_sqlite::RunChainIssue;
+use decision_gate_store_sqlite::SqliteFollowerConfig;
+use decision_gate_store_sqlite::SqliteRetentionPolicy;
 use decision_gate_store_sqlite::SqliteRunStateStore;
 use decision_gate_store_sqlite::SqliteStoreConfig;
 use decision_gate_store_sqlite::SqliteStoreError;
@@ -60,6 +72,7 @@ use decision_gate_store_sqlite::SqliteStoreMode;
 use decision_gate_store_sqlite::SqliteSyncMode;
 use rusqlite::Connection;
 use rusqlite::params;
+use serde_json::json;
 use tempfile::TempDir;
 
 // ============================================================================
@@ -117,6 +130,7 @@ const fn config_for_path(path: PathBuf, max_versions: Option<u64>) -> SqliteStor
         max_versions,
         schema_registry_max_schema_bytes: None,
         schema_registry_max_entries: None,
+        retention: Vec::new(),
     }
 }
 
@@ -295,7 +309,7 @@ fn sqlite_store_upgrades_schema_from_v3() {
     let version: i64 = conn
         .query_row("SELECT version FROM store_meta LIMIT 1", params![], |row| row.get(0))
         .unwrap();
-    assert_eq!(version, 4, "schema version should be upgraded to 4");
+    assert_eq!(version, 5, "schema version should be upgraded to 5");
 
     let mut stmt = conn.prepare("PRAGMA table_info(data_shapes)").unwrap();
     let columns: Vec<String> =
@@ -303,6 +317,11 @@ fn sqlite_store_upgrades_schema_from_v3() {
     assert!(columns.contains(&"signing_key_id".to_string()));
     assert!(columns.contains(&"signing_signature".to_string()));
     assert!(columns.contains(&"signing_algorithm".to_string()));
+
+    let mut stmt = conn.prepare("PRAGMA table_info(run_state_versions)").unwrap();
+    let columns: Vec<String> =
+        stmt.query_map([], |row| row.get::<_, String>(1)).unwrap().filter_map(Result::ok).collect();
+    assert!(columns.contains(&"prev_state_hash".to_string()));
 }
 
 // ============================================================================
@@ -669,6 +688,7 @@ fn sqlite_store_sets_delete_mode() {
         max_versions: None,
         schema_registry_max_schema_bytes: None,
         schema_registry_max_entries: None,
+        retention: Vec::new(),
     };
     let _store = SqliteRunStateStore::new(config).unwrap();
 
@@ -724,3 +744,598 @@ fn sqlite_store_supports_concurrent_writes() {
     let runs = store.list_runs(None, None).unwrap();
     assert_eq!(runs.len(), 4);
 }
+
+// ============================================================================
+// SECTION: Version Hash Chain
+// ============================================================================
+
+fn save_three_versions(store: &SqliteRunStateStore) -> RunState {
+    let mut state = sample_state("run-1");
+    store.save(&state).unwrap();
+    state.current_stage_id = StageId::new("stage-2");
+    store.save(&state).unwrap();
+    state.current_stage_id = StageId::new("stage-3");
+    store.save(&state).unwrap();
+    state
+}
+
+#[test]
+fn sqlite_store_links_versions_by_previous_hash() {
+    let temp = TempDir::new().unwrap();
+    let path = temp.path().join("store.sqlite");
+    let store = store_for(&path, None);
+    let state = save_three_versions(&store);
+
+    let versions =
+        store.list_run_versions(state.tenant_id, state.namespace_id, &state.run_id).unwrap();
+    assert_eq!(versions[2].prev_state_hash, None);
+    assert_eq!(versions[1].prev_state_hash.as_deref(), Some(versions[2].state_hash.as_str()));
+    assert_eq!(versions[0].prev_state_hash.as_deref(), Some(versions[1].state_hash.as_str()));
+
+    let report = store
+        .verify_run_chain(state.tenant_id, state.namespace_id, &state.run_id)
+        .unwrap()
+        .expect("run exists");
+    assert!(report.is_intact(), "unexpected issues: {:?}", report.issues);
+    assert_eq!(report.first_version, Some(1));
+    assert_eq!(report.latest_version, 3);
+    assert_eq!(report.versions_checked, 3);
+}
+
+#[test]
+fn sqlite_store_chain_detects_deleted_intermediate_version() {
+    let temp = TempDir::new().unwrap();
+    let path = temp.path().join("store.sqlite");
+    let store = store_for(&path, None);
+    let state = save_three_versions(&store);
+
+    let conn = Connection::open(&path).unwrap();
+    conn.execute(
+        "DELETE FROM run_state_versions WHERE run_id = ?1 AND version = 2",
+        params![state.run_id.as_str()],
+    )
+    .unwrap();
+
+    let report = store
+        .verify_run_chain(state.tenant_id, state.namespace_id, &state.run_id)
+        .unwrap()
+        .expect("run exists");
+    assert_eq!(
+        report.issues,
+        vec![RunChainIssue::MissingVersions {
+            from_version: 2,
+            to_version: 2,
+        }]
+    );
+    assert_eq!(report.versions_checked, 2);
+}
+
+#[test]
+fn sqlite_store_chain_detects_renumbered_versions() {
+    let temp = TempDir::new().unwrap();
+    let path = temp.path().join("store.sqlite");
+    let store = store_for(&path, None);
+    let state = save_three_versions(&store);
+
+    let conn = Connection::open(&path).unwrap();
+    conn.execute_batch(
+        "DELETE FROM run_state_versions WHERE version = 2;
+         UPDATE run_state_versions SET version = 2 WHERE version = 3;
+         UPDATE runs SET latest_version = 2;",
+    )
+    .unwrap();
+
+    let report = store
+        .verify_run_chain(state.tenant_id, state.namespace_id, &state.run_id)
+        .unwrap()
+        .expect("run exists");
+    assert_eq!(
+        report.issues,
+        vec![RunChainIssue::PrevHashMismatch {
+            version: 2,
+        }]
+    );
+}
+
+#[test]
+fn sqlite_store_chain_detects_truncated_latest_version() {
+    let temp = TempDir::new().unwrap();
+    let path = temp.path().join("store.sqlite");
+    let store = store_for(&path, None);
+    let state = save_three_versions(&store);
+
+    let conn = Connection::open(&path).unwrap();
+    conn.execute(
+        "DELETE FROM run_state_versions WHERE run_id = ?1 AND version = 3",
+        params![state.run_id.as_str()],
+    )
+    .unwrap();
+
+    let report = store
+        .verify_run_chain(state.tenant_id, state.namespace_id, &state.run_id)
+        .unwrap()
+        .expect("run exists");
+    assert_eq!(
+        report.issues,
+        vec![RunChainIssue::MissingVersions {
+            from_version: 3,
+            to_version: 3,
+        }]
+    );
+}
+
+#[test]
+fn sqlite_store_chain_accepts_retention_pruning() {
+    let temp = TempDir::new().unwrap();
+    let path = temp.path().join("store.sqlite");
+    let store = store_for(&path, Some(2));
+    let state = save_three_versions(&store);
+
+    let report = store
+        .verify_run_chain(state.tenant_id, state.namespace_id, &state.run_id)
+        .unwrap()
+        .expect("run exists");
+    assert!(report.is_intact(), "unexpected issues: {:?}", report.issues);
+    assert_eq!(report.first_version, Some(2));
+    assert_eq!(report.versions_checked, 2);
+}
+
+#[test]
+fn sqlite_store_verifies_blake3_hashed_versions() {
+    let temp = TempDir::new().unwrap();
+    let path = temp.path().join("store.sqlite");
+    let store = store_for(&path, None);
+    let state = save_three_versions(&store);
+
+    let conn = Connection::open(&path).unwrap();
+    let rows: Vec<(i64, Vec<u8>)> = conn
+        .prepare("SELECT version, state_json FROM run_state_versions ORDER BY version ASC")
+        .unwrap()
+        .query_map([], |row| Ok((row.get(0)?, row.get(1)?)))
+        .unwrap()
+        .collect::<Result<_, _>>()
+        .unwrap();
+    let mut prev_state_hash: Option<String> = None;
+    for (version, bytes) in rows {
+        let digest = hash_bytes(HashAlgorithm::Blake3, &bytes);
+        conn.execute(
+            "UPDATE run_state_versions SET state_hash = ?1, hash_algorithm = 'blake3', \
+             prev_state_hash = ?2 WHERE version = ?3",
+            params![digest.value, prev_state_hash, version],
+        )
+        .unwrap();
+        prev_state_hash = Some(digest.value);
+    }
+
+    let loaded = store.load(&state.tenant_id, &state.namespace_id, &state.run_id).unwrap();
+    assert_eq!(loaded, Some(state.clone()));
+    let report = store
+        .verify_run_chain(state.tenant_id, state.namespace_id, &state.run_id)
+        .unwrap()
+        .expect("run exists");
+    assert!(report.is_intact(), "unexpected issues: {:?}", report.issues);
+    assert_eq!(report.versions_checked, 3);
+}
+
+#[test]
+fn sqlite_store_chain_returns_none_for_missing_run() {
+    let temp = TempDir::new().unwrap();
+    let path = temp.path().join("store.sqlite");
+    let store = store_for(&path, None);
+    let state = sample_state("run-1");
+
+    let report =
+        store.verify_run_chain(state.tenant_id, state.namespace_id, &state.run_id).unwrap();
+    assert!(report.is_none());
+}
+
+// ============================================================================
+// SECTION: Retention Policies
+// ============================================================================
+
+const DAY_MS: i64 = 86_400_000;
+
+fn policy(
+    tenant_id: u64,
+    namespace_id: Option<u64>,
+    keep_versions: Option<u64>,
+    max_age_days: Option<u64>,
+) -> SqliteRetentionPolicy {
+    SqliteRetentionPolicy {
+        tenant_id: TenantId::from_raw(tenant_id).expect("nonzero tenantid"),
+        namespace_id: namespace_id
+            .map(|value| NamespaceId::from_raw(value).expect("nonzero namespaceid")),
+        keep_versions,
+        max_age_days,
+    }
+}
+
+fn store_with_retention(path: &Path, retention: Vec<SqliteRetentionPolicy>) -> SqliteRunStateStore {
+    let mut config = config_for_path(path.to_path_buf(), None);
+    config.retention = retention;
+    SqliteRunStateStore::new(config).expect("store init")
+}
+
+fn save_versions(store: &SqliteRunStateStore, mut state: RunState, count: usize) -> RunState {
+    for index in 0 .. count {
+        state.current_stage_id = StageId::new(format!("stage-{index}"));
+        store.save(&state).unwrap();
+    }
+    state
+}
+
+fn version_numbers(store: &SqliteRunStateStore, state: &RunState) -> Vec<i64> {
+    store
+        .list_run_versions(state.tenant_id, state.namespace_id, &state.run_id)
+        .unwrap()
+        .iter()
+        .map(|summary| summary.version)
+        .collect()
+}
+
+#[test]
+fn sqlite_store_retention_keep_versions_prunes_oldest() {
+    let temp = TempDir::new().unwrap();
+    let path = temp.path().join("store.sqlite");
+    let store = store_with_retention(&path, vec![policy(1, None, Some(2), None)]);
+    let state = save_versions(&store, sample_state("run-1"), 5);
+
+    let report = store.apply_retention(false).unwrap();
+    assert_eq!(report.runs_checked, 1);
+    assert_eq!(report.runs_pruned, 1);
+    assert_eq!(report.versions_pruned, 3);
+    assert_eq!(version_numbers(&store, &state), vec![5, 4]);
+
+    let again = store.apply_retention(false).unwrap();
+    assert_eq!(again.versions_pruned, 0);
+}
+
+#[test]
+fn sqlite_store_retention_keeps_chain_valid() {
+    let temp = TempDir::new().unwrap();
+    let path = temp.path().join("store.sqlite");
+    let store = store_with_retention(&path, vec![policy(1, None, Some(3), None)]);
+    let state = save_versions(&store, sample_state("run-1"), 6);
+
+    store.apply_retention(false).unwrap();
+    let report = store
+        .verify_run_chain(state.tenant_id, state.namespace_id, &state.run_id)
+        .unwrap()
+        .expect("run exists");
+    assert!(report.is_intact(), "unexpected issues: {:?}", report.issues);
+    assert_eq!(report.first_version, Some(4));
+    assert_eq!(report.latest_version, 6);
+    assert_eq!(report.versions_checked, 3);
+
+    let mut state = state;
+    state.current_stage_id = StageId::new("stage-next");
+    store.save(&state).unwrap();
+    let report = store
+        .verify_run_chain(state.tenant_id, state.namespace_id, &state.run_id)
+        .unwrap()
+        .expect("run exists");
+    assert!(report.is_intact(), "unexpected issues: {:?}", report.issues);
+    assert_eq!(report.latest_version, 7);
+}
+
+#[test]
+fn sqlite_store_retention_dry_run_keeps_versions() {
+    let temp = TempDir::new().unwrap();
+    let path = temp.path().join("store.sqlite");
+    let store = store_with_retention(&path, vec![policy(1, None, Some(1), None)]);
+    let state = save_versions(&store, sample_state("run-1"), 3);
+
+    let report = store.apply_retention(true).unwrap();
+    assert_eq!(report.versions_pruned, 2);
+    assert_eq!(version_numbers(&store, &state), vec![3, 2, 1]);
+}
+
+#[test]
+fn sqlite_store_retention_max_age_prunes_old_versions() {
+    let temp = TempDir::new().unwrap();
+    let path = temp.path().join("store.sqlite");
+    let store = store_with_retention(&path, vec![policy(1, None, None, Some(7))]);
+    let state = save_versions(&store, sample_state("run-1"), 3);
+
+    let now_ms = 100 * DAY_MS;
+    let conn = Connection::open(&path).unwrap();
+    for (version, age_days) in [(1_i64, 30_i64), (2, 10), (3, 1)] {
+        conn.execute(
+            "UPDATE run_state_versions SET saved_at = ?1 WHERE version = ?2",
+            params![now_ms - age_days * DAY_MS, version],
+        )
+        .unwrap();
+    }
+
+    let report = store.apply_retention_at(now_ms, false).unwrap();
+    assert_eq!(report.versions_pruned, 2);
+    assert_eq!(version_numbers(&store, &state), vec![3]);
+}
+
+#[test]
+fn sqlite_store_retention_never_prunes_latest_version() {
+    let temp = TempDir::new().unwrap();
+    let path = temp.path().join("store.sqlite");
+    let store = store_with_retention(&path, vec![policy(1, None, None, Some(1))]);
+    let state = save_versions(&store, sample_state("run-1"), 2);
+
+    let report = store.apply_retention_at(i64::MAX / 2, false).unwrap();
+    assert_eq!(report.versions_pruned, 1);
+    assert_eq!(version_numbers(&store, &state), vec![2]);
+}
+
+#[test]
+fn sqlite_store_retention_combined_bounds_keep_union() {
+    let temp = TempDir::new().unwrap();
+    let path = temp.path().join("store.sqlite");
+    let store = store_with_retention(&path, vec![policy(1, None, Some(1), Some(7))]);
+    let state = save_versions(&store, sample_state("run-1"), 3);
+
+    let now_ms = 100 * DAY_MS;
+    let conn = Connection::open(&path).unwrap();
+    for (version, age_days) in [(1_i64, 30_i64), (2, 2), (3, 1)] {
+        conn.execute(
+            "UPDATE run_state_versions SET saved_at = ?1 WHERE version = ?2",
+            params![now_ms - age_days * DAY_MS, version],
+        )
+        .unwrap();
+    }
+
+    let report = store.apply_retention_at(now_ms, false).unwrap();
+    assert_eq!(report.versions_pruned, 1, "version 2 is within max_age_days");
+    assert_eq!(version_numbers(&store, &state), vec![3, 2]);
+}
+
+#[test]
+fn sqlite_store_retention_namespace_policy_overrides_tenant_policy() {
+    let temp = TempDir::new().unwrap();
+    let path = temp.path().join("store.sqlite");
+    let store = store_with_retention(
+        &path,
+        vec![policy(1, None, Some(1), None), policy(1, Some(2), Some(3), None)],
+    );
+    let default_ns = save_versions(&store, sample_state("run-1"), 4);
+    let mut scoped = sample_state("run-2");
+    scoped.namespace_id = NamespaceId::from_raw(2).expect("nonzero namespaceid");
+    let scoped = save_versions(&store, scoped, 4);
+    let mut other_tenant = sample_state("run-3");
+    other_tenant.tenant_id = TenantId::from_raw(2).expect("nonzero tenantid");
+    let other_tenant = save_versions(&store, other_tenant, 4);
+
+    let report = store.apply_retention(false).unwrap();
+    assert_eq!(report.runs_checked, 2, "tenant 2 has no policy");
+    assert_eq!(report.runs_pruned, 2);
+    assert_eq!(report.versions_pruned, 4);
+    assert_eq!(version_numbers(&store, &default_ns), vec![4]);
+    assert_eq!(version_numbers(&store, &scoped), vec![4, 3, 2]);
+    assert_eq!(version_numbers(&store, &other_tenant), vec![4, 3, 2, 1]);
+}
+
+#[test]
+fn sqlite_store_rejects_invalid_retention_policies() {
+    let cases = [
+        (vec![policy(1, None, None, None)], "keep_versions or max_age_days"),
+        (vec![policy(1, None, Some(0), None)], "keep_versions must be greater than zero"),
+        (vec![policy(1, None, None, Some(0))], "max_age_days out of range"),
+        (vec![policy(1, Some(2), Some(1), None), policy(1, Some(2), Some(5), None)], "duplicates"),
+    ];
+    for (retention, expected) in cases {
+        let temp = TempDir::new().unwrap();
+        let mut config = config_for_path(temp.path().join("store.sqlite"), None);
+        config.retention = retention;
+        let Err(err) = SqliteRunStateStore::new(config) else {
+            panic!("expected retention policy to be rejected: {expected}");
+        };
+        assert!(err.to_string().contains(expected), "unexpected error: {err}");
+    }
+}
+
+#[test]
+fn sqlite_store_retention_worker_applies_policies() {
+    let temp = TempDir::new().unwrap();
+    let path = temp.path().join("store.sqlite");
+    let store = store_with_retention(&path, vec![policy(1, None, Some(1), None)]);
+    let state = save_versions(&store, sample_state("run-1"), 3);
+
+    let worker =
+        store.spawn_retention_worker(Duration::from_millis(10)).expect("policies configured");
+    for _ in 0 .. 500 {
+        if version_numbers(&store, &state).len() == 1 {
+            break;
+        }
+        thread::sleep(Duration::from_millis(10));
+    }
+    worker.stop();
+    assert_eq!(version_numbers(&store, &state), vec![3]);
+
+    let idle = store_for(&temp.path().join("idle.sqlite"), None);
+    assert!(idle.spawn_retention_worker(Duration::from_millis(10)).is_none());
+}
+
+// ============================================================================
+// SECTION: Read-Only Followers
+// ============================================================================
+
+fn follower_config(path: &Path, max_staleness_ms: Option<u64>) -> SqliteFollowerConfig {
+    SqliteFollowerConfig {
+        path: path.to_path_buf(),
+        busy_timeout_ms: 1_000,
+        max_staleness_ms,
+    }
+}
+
+/// Saves `state` on a primary at `primary`, then copies the closed file to `replica`.
+fn copy_replica(primary: &Path, replica: &Path, state: &RunState) {
+    let store = store_for(primary, None);
+    store.save(state).unwrap();
+    drop(store);
+    std::fs::copy(primary, replica).unwrap();
+}
+
+#[test]
+fn sqlite_follower_serves_reads_from_copied_replica() {
+    let temp = TempDir::new().unwrap();
+    let primary = temp.path().join("primary.sqlite");
+    let replica = temp.path().join("replica.sqlite");
+    let state = sample_state("run-1");
+    copy_replica(&primary, &replica, &state);
+
+    let follower = SqliteRunStateStore::open_follower(follower_config(&replica, None)).unwrap();
+    assert!(follower.is_follower());
+    let loaded = follower.load(&state.tenant_id, &state.namespace_id, &state.run_id).unwrap();
+    assert_eq!(loaded, Some(state.clone()));
+    let runs = follower.list_runs(None, None).unwrap();
+    assert_eq!(runs.len(), 1);
+    assert_eq!(runs[0].run_id, state.run_id);
+    let report = follower
+        .verify_run_chain(state.tenant_id, state.namespace_id, &state.run_id)
+        .unwrap()
+        .expect("run exists");
+    assert!(report.is_intact());
+    RunStateStore::readiness(&follower).unwrap();
+}
+
+#[test]
+fn sqlite_follower_sees_writes_to_live_primary() {
+    let temp = TempDir::new().unwrap();
+    let path = temp.path().join("store.sqlite");
+    let primary = store_for(&path, None);
+    let mut state = sample_state("run-1");
+    primary.save(&state).unwrap();
+    let follower = SqliteRunStateStore::open_follower(follower_config(&path, None)).unwrap();
+
+    state.current_stage_id = StageId::new("stage-2");
+    primary.save(&state).unwrap();
+
+    let read =
+        follower.load_with_freshness(state.tenant_id, state.namespace_id, &state.run_id).unwrap();
+    assert_eq!(read.value, Some(state));
+    assert!(read.freshness.follower);
+    assert!(!read.freshness.stale);
+    assert!(!primary.is_follower());
+}
+
+#[test]
+fn sqlite_follower_reports_staleness() {
+    let temp = TempDir::new().unwrap();
+    let primary = temp.path().join("primary.sqlite");
+    let replica = temp.path().join("replica.sqlite");
+    copy_replica(&primary, &replica, &sample_state("run-1"));
+    let saved_at = 10 * DAY_MS;
+    Connection::open(&replica)
+        .unwrap()
+        .execute("UPDATE run_state_versions SET saved_at = ?1", params![saved_at])
+        .unwrap();
+
+    let follower =
+        SqliteRunStateStore::open_follower(follower_config(&replica, Some(60_000))).unwrap();
+    let fresh = follower.freshness_at(saved_at + 30_000).unwrap();
+    assert_eq!(fresh.last_saved_at, Some(saved_at));
+    assert_eq!(fresh.staleness_ms, Some(30_000));
+    assert!(!fresh.stale);
+    let stale = follower.freshness_at(saved_at + 90_000).unwrap();
+    assert_eq!(stale.staleness_ms, Some(90_000));
+    assert!(stale.stale);
+
+    let unbounded = SqliteRunStateStore::open_follower(follower_config(&replica, None)).unwrap();
+    assert!(!unbounded.freshness_at(saved_at + 90_000).unwrap().stale);
+
+    let runs = follower.list_runs_with_freshness(None, None).unwrap();
+    assert_eq!(runs.value.len(), 1);
+    assert!(runs.freshness.stale, "replica saved_at is far in the past");
+
+    let primary_store = store_for(&primary, None);
+    let primary_freshness = primary_store.freshness().unwrap();
+    assert!(!primary_freshness.follower);
+    assert_eq!(primary_freshness.staleness_ms, Some(0));
+    assert!(!primary_freshness.stale);
+}
+
+#[test]
+fn sqlite_follower_flags_empty_replica_as_stale_when_bounded() {
+    let temp = TempDir::new().unwrap();
+    let path = temp.path().join("store.sqlite");
+    drop(store_for(&path, None));
+
+    let follower = SqliteRunStateStore::open_follower(follower_config(&path, Some(1_000))).unwrap();
+    let freshness = follower.freshness().unwrap();
+    assert_eq!(freshness.last_saved_at, None);
+    assert_eq!(freshness.staleness_ms, None);
+    assert!(freshness.stale);
+}
+
+#[test]
+fn sqlite_follower_rejects_writes() {
+    let temp = TempDir::new().unwrap();
+    let primary = temp.path().join("primary.sqlite");
+    let replica = temp.path().join("replica.sqlite");
+    let mut state = sample_state("run-1");
+    copy_replica(&primary, &replica, &state);
+    let follower = SqliteRunStateStore::open_follower(follower_config(&replica, None)).unwrap();
+
+    state.current_stage_id = StageId::new("stage-2");
+    let err = follower.save(&state).unwrap_err();
+    assert!(err.to_string().contains("read-only"), "unexpected error: {err}");
+    let err = follower
+        .register(DataShapeRecord {
+            tenant_id: state.tenant_id,
+            namespace_id: state.namespace_id,
+            schema_id: DataShapeId::new("schema"),
+            version: DataShapeVersion::new("v1"),
+            schema: json!({"type": "object"}),
+            description: None,
+            created_at: Timestamp::Logical(1),
+            signing: None,
+        })
+        .unwrap_err();
+    assert!(err.to_string().contains("read-only"), "unexpected error: {err}");
+    let err =
+        follower.prune_versions(state.tenant_id, state.namespace_id, &state.run_id, 1).unwrap_err();
+    assert!(matches!(err, SqliteStoreError::ReadOnly(_)));
+    let err = follower.apply_retention(false).unwrap_err();
+    assert!(matches!(err, SqliteStoreError::ReadOnly(_)));
+    follower.apply_retention(true).unwrap();
+
+    let versions = follower.list_run_versions(state.tenant_id, state.namespace_id, &state.run_id);
+    assert_eq!(versions.unwrap().len(), 1);
+    let loaded = follower.load(&state.tenant_id, &state.namespace_id, &state.run_id).unwrap();
+    assert_eq!(loaded.map(|loaded| loaded.current_stage_id), Some(StageId::new("stage-1")));
+}
+
+#[test]
+fn sqlite_follower_rejects_missing_or_unversioned_replicas() {
+    let temp = TempDir::new().unwrap();
+    let missing = temp.path().join("missing.sqlite");
+    let Err(err) = SqliteRunStateStore::open_follower(follower_config(&missing, None)) else {
+        panic!("expected missing replica to be rejected");
+    };
+    assert!(matches!(err, SqliteStoreError::Io(_)));
+    assert!(!missing.exists(), "follower must not create the replica");
+
+    let blank = temp.path().join("blank.sqlite");
+    Connection::open(&blank).unwrap().execute_batch("CREATE TABLE other (id INTEGER);").unwrap();
+    let Err(err) = SqliteRunStateStore::open_follower(follower_config(&blank, None)) else {
+        panic!("expected unversioned replica to be rejected");
+    };
+    assert!(matches!(err, SqliteStoreError::Db(_)), "unexpected error: {err}");
+
+    let old = temp.path().join("old.sqlite");
+    Connection::open(&old)
+        .unwrap()
+        .execute_batch(
+            "CREATE TABLE store_meta (version INTEGER NOT NULL);
+             INSERT INTO store_meta (version) VALUES (4);",
+        )
+        .unwrap();
+    let Err(err) = SqliteRunStateStore::open_follower(follower_config(&old, None)) else {
+        panic!("expected outdated replica to be rejected");
+    };
+    assert!(matches!(err, SqliteStoreError::VersionMismatch(_)), "unexpected error: {err}");
+
+    let path = temp.path().join("store.sqlite");
+    drop(store_for(&path, None));
+    let Err(err) = SqliteRunStateStore::open_follower(follower_config(&path, Some(0))) else {
+        panic!("expected zero staleness bound to be rejected");
+    };
+    assert!(matches!(err, SqliteStoreError::Invalid(_)));
+}
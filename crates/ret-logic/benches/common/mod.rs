@@ -0,0 +1,201 @@
+// crates/ret-logic/benches/common/mod.rs
+// ============================================================================
+// Module: Plan Evaluation Bench Workloads
+// Description: Shared requirement shapes and rows for the plan benches.
+// Purpose: Keep the criterion suite and the regression guard on one workload set.
+// Dependencies: ret_logic::{columnar, executor, testing}
+// ============================================================================
+
+//! ## Overview
+//! Builds the wide `and` group, the alternating deep tree, and the columnar
+//! plan measured by `plan_eval` (criterion) and `plan_eval_guard` (baseline
+//! check), each over [`ROWS`] deterministic rows, and the passes both time.
+
+#![allow(dead_code, reason = "Each bench target uses a subset of the shared workloads.")]
+
+use std::hint::black_box;
+
+use ret_logic::BatchConditionEval;
+use ret_logic::ColumnKey;
+use ret_logic::ColumnValue;
+use ret_logic::ColumnarExecutor;
+use ret_logic::ColumnarReader;
+use ret_logic::ConditionEval;
+use ret_logic::Constant;
+use ret_logic::LogicMode;
+use ret_logic::OpCode;
+use ret_logic::Operation;
+use ret_logic::Plan;
+use ret_logic::Requirement;
+use ret_logic::TriState;
+use ret_logic::columnar::row_dispatch_executor;
+use ret_logic::executor::PlanExecutor;
+use ret_logic::testing::FuzzCondition;
+use ret_logic::testing::FuzzReader;
+use ret_logic::testing::FuzzRng;
+use ret_logic::testing::plan_executor;
+
+// ============================================================================
+// SECTION: Sizes
+// ============================================================================
+
+/// Rows evaluated per pass (a multiple of the 64-row block size).
+pub const ROWS: u16 = 1_024;
+
+/// Rows per `eval_block` call.
+pub const BLOCK: usize = 64;
+
+/// Leaf counts for the wide `and` workload.
+pub const WIDE_SIZES: [u16; 3] = [8, 64, 256];
+
+/// Nesting depths for the deep tree workload (below the 64-frame plan stack).
+pub const DEEP_SIZES: [u16; 3] = [8, 32, 60];
+
+// ============================================================================
+// SECTION: Workloads
+// ============================================================================
+
+/// Requirement shape and its evaluation inputs.
+pub struct Workload {
+    /// Shape name (`wide_and` or `deep_tree`).
+    pub shape: &'static str,
+    /// Leaf count or nesting depth.
+    pub size: u16,
+    /// Compiled executor.
+    pub executor: PlanExecutor<FuzzReader>,
+    /// Rows fed to every path.
+    pub reader: FuzzReader,
+}
+
+/// Builds `and(c0, .., cN)` over rows where every condition holds.
+pub fn wide_and(width: u16) -> Workload {
+    let requirement = Requirement::and(
+        (0 .. width).map(|slot| Requirement::condition(FuzzCondition(slot))).collect(),
+    );
+    let mut reader = FuzzReader::new(usize::from(width));
+    let row = vec![TriState::True; usize::from(width)];
+    for _ in 0 .. ROWS {
+        reader.push_row(&row);
+    }
+    Workload {
+        shape: "wide_and",
+        size: width,
+        executor: plan_executor(&requirement),
+        reader,
+    }
+}
+
+/// Builds alternating `and(ci, or(cj, ..))` nesting over random known rows.
+pub fn deep_tree(depth: u16) -> Workload {
+    let mut requirement = Requirement::condition(FuzzCondition(depth));
+    for level in (0 .. depth).rev() {
+        let leaf = Requirement::condition(FuzzCondition(level));
+        requirement = if level % 2 == 0 {
+            Requirement::and(vec![leaf, requirement])
+        } else {
+            Requirement::or(vec![leaf, requirement])
+        };
+    }
+    let width = usize::from(depth) + 1;
+    let mut rng = FuzzRng::new(u64::from(depth));
+    let mut reader = FuzzReader::new(width);
+    for _ in 0 .. ROWS {
+        let row: Vec<TriState> = (0 .. width).map(|_| TriState::from(rng.below(4) != 0)).collect();
+        reader.push_row(&row);
+    }
+    Workload {
+        shape: "deep_tree",
+        size: depth,
+        executor: plan_executor(&requirement),
+        reader,
+    }
+}
+
+/// Columnar plan and the two executors compared over it.
+pub struct ColumnarWorkload {
+    /// Per-row dispatch over the columnar reader.
+    pub rows: PlanExecutor<ColumnarReader>,
+    /// Mask-based block evaluation.
+    pub columnar: ColumnarExecutor,
+    /// Random rows stored column by column.
+    pub reader: ColumnarReader,
+}
+
+/// Builds `and(c0 >= 0.25, c1 <= 50, or(c2 has 0b11, c3 <= 0.5))` over random rows.
+pub fn columnar_workload() -> ColumnarWorkload {
+    let mut plan = Plan::new();
+    let constants =
+        [Constant::Float(0.25), Constant::Int(50), Constant::Flags(0b11), Constant::Float(0.5)];
+    for (column, constant) in (0 ..).zip(constants) {
+        plan.add_column(ColumnKey(column));
+        if plan.add_constant(constant).is_err() {
+            unreachable!("four constants fit in the pool");
+        }
+    }
+    for (opcode, column) in [
+        (OpCode::FloatGte, 0),
+        (OpCode::IntLte, 1),
+        (OpCode::OrStart, 0),
+        (OpCode::HasAllFlags, 2),
+        (OpCode::FloatLte, 3),
+        (OpCode::OrEnd, 0),
+    ] {
+        plan.add_operation(Operation::new(opcode, column, column, 0));
+    }
+
+    let mut rng = FuzzRng::new(u64::from(ROWS));
+    let mut unit = || f32::from(u16::try_from(rng.below(1_000)).unwrap_or(0)) / 1_000.0;
+    let rows: Vec<[ColumnValue; 4]> = (0 .. ROWS)
+        .map(|_| {
+            [
+                ColumnValue::Float(unit()),
+                ColumnValue::Int(i32::from(unit() * 100.0 > 50.0) * 100),
+                ColumnValue::Flags(u64::from(unit() > 0.5) * 0b11),
+                ColumnValue::Float(unit()),
+            ]
+        })
+        .collect();
+    let keys = [ColumnKey(0), ColumnKey(1), ColumnKey(2), ColumnKey(3)];
+    let Ok(reader) = ColumnarReader::from_rows(&keys, rows) else {
+        unreachable!("generated rows share one shape");
+    };
+    ColumnarWorkload {
+        rows: row_dispatch_executor(plan.clone()),
+        columnar: ColumnarExecutor::new(plan),
+        reader,
+    }
+}
+
+/// Returns every wide and deep workload in report order.
+pub fn workloads() -> Vec<Workload> {
+    WIDE_SIZES.into_iter().map(wide_and).chain(DEEP_SIZES.into_iter().map(deep_tree)).collect()
+}
+
+// ============================================================================
+// SECTION: Passes
+// ============================================================================
+
+/// Evaluates every row with `eval_row`, returning the passing count.
+pub fn eval_rows<E: ConditionEval>(executor: &E, reader: &E::Reader<'_>) -> u64 {
+    (0 .. usize::from(ROWS)).map(|row| u64::from(executor.eval_row(black_box(reader), row))).sum()
+}
+
+/// Evaluates every row block with `eval_block`, returning the passing count.
+pub fn eval_blocks<E: BatchConditionEval>(executor: &E, reader: &E::Reader<'_>) -> u64 {
+    (0 .. usize::from(ROWS))
+        .step_by(BLOCK)
+        .map(|start| u64::from(executor.eval_block(black_box(reader), start, BLOCK).count_ones()))
+        .sum()
+}
+
+/// Evaluates every row with `evaluate_lazy` under `mode`, returning the true count.
+pub fn evaluate_lazy(workload: &Workload, mode: LogicMode) -> u64 {
+    let Workload {
+        executor,
+        reader,
+        ..
+    } = workload;
+    (0 .. usize::from(ROWS))
+        .map(|row| u64::from(executor.evaluate_lazy(black_box(reader), row, mode).is_true()))
+        .sum()
+}
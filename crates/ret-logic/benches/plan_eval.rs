@@ -0,0 +1,111 @@
+// crates/ret-logic/benches/plan_eval.rs
+// ============================================================================
+// Module: Plan Evaluation Benchmarks
+// Description: Criterion throughput benchmarks for PlanExecutor.
+// Purpose: Measure the scalar, batch, and lazy evaluation hot paths.
+// Dependencies: criterion, ret_logic::testing
+// ============================================================================
+//! ## Overview
+//! Criterion groups measuring `PlanExecutor` throughput in rows per second:
+//! - `wide_and`: a wide `and` group through `eval_row` and `evaluate_lazy`
+//!   under every logic mode, at several leaf counts.
+//! - `deep_tree`: an alternating AND/OR tree through the same paths at several
+//!   depths.
+//! - `eval_batch`: `eval_block` over both shapes, plus per-row dispatch
+//!   against `ColumnarExecutor` block masks over one `ColumnarReader`.
+//!
+//! ```text
+//! cargo bench -p ret-logic --bench plan_eval
+//! cargo bench -p ret-logic --bench plan_eval -- eval_batch
+//! ```
+//!
+//! The baseline regression guard lives in `plan_eval_guard.rs`.
+
+mod common;
+
+use criterion::BenchmarkGroup;
+use criterion::BenchmarkId;
+use criterion::Criterion;
+use criterion::Throughput;
+use criterion::criterion_group;
+use criterion::criterion_main;
+use criterion::measurement::WallTime;
+use ret_logic::testing::LOGIC_MODES;
+
+use crate::common::DEEP_SIZES;
+use crate::common::ROWS;
+use crate::common::WIDE_SIZES;
+use crate::common::Workload;
+use crate::common::columnar_workload;
+use crate::common::deep_tree;
+use crate::common::eval_blocks;
+use crate::common::eval_rows;
+use crate::common::evaluate_lazy;
+use crate::common::wide_and;
+use crate::common::workloads;
+
+// ============================================================================
+// SECTION: Groups
+// ============================================================================
+
+/// Benchmarks `eval_row` and every `evaluate_lazy` mode for one workload.
+fn bench_scalar_paths(group: &mut BenchmarkGroup<'_, WallTime>, workload: &Workload) {
+    group.bench_function(BenchmarkId::new("eval_row", workload.size), |bench| {
+        bench.iter(|| eval_rows(&workload.executor, &workload.reader));
+    });
+    for mode in LOGIC_MODES {
+        let id = format!("evaluate_lazy/{mode:?}").to_lowercase();
+        group.bench_function(BenchmarkId::new(id, workload.size), |bench| {
+            bench.iter(|| evaluate_lazy(workload, mode));
+        });
+    }
+}
+
+/// Wide `and` group at every leaf count.
+fn wide_and_group(criterion: &mut Criterion) {
+    let mut group = criterion.benchmark_group("wide_and");
+    group.throughput(Throughput::Elements(u64::from(ROWS)));
+    for width in WIDE_SIZES {
+        bench_scalar_paths(&mut group, &wide_and(width));
+    }
+    group.finish();
+}
+
+/// Alternating AND/OR tree at every depth.
+fn deep_tree_group(criterion: &mut Criterion) {
+    let mut group = criterion.benchmark_group("deep_tree");
+    group.throughput(Throughput::Elements(u64::from(ROWS)));
+    for depth in DEEP_SIZES {
+        bench_scalar_paths(&mut group, &deep_tree(depth));
+    }
+    group.finish();
+}
+
+/// Block evaluation over both shapes and the columnar comparison.
+fn eval_batch_group(criterion: &mut Criterion) {
+    let mut group = criterion.benchmark_group("eval_batch");
+    group.throughput(Throughput::Elements(u64::from(ROWS)));
+    for workload in workloads() {
+        group.bench_function(BenchmarkId::new(workload.shape, workload.size), |bench| {
+            bench.iter(|| eval_blocks(&workload.executor, &workload.reader));
+        });
+    }
+    let columnar = columnar_workload();
+    group.bench_function("columnar/row_dispatch/eval_row", |bench| {
+        bench.iter(|| eval_rows(&columnar.rows, &columnar.reader));
+    });
+    group.bench_function("columnar/row_dispatch/eval_block", |bench| {
+        bench.iter(|| eval_blocks(&columnar.rows, &columnar.reader));
+    });
+    group.bench_function("columnar/eval_block", |bench| {
+        bench.iter(|| eval_blocks(&columnar.columnar, &columnar.reader));
+    });
+    group.finish();
+}
+
+// ============================================================================
+// SECTION: Entry Point
+// ============================================================================
+
+criterion_group!(benches, wide_and_group, deep_tree_group, eval_batch_group);
+criterion_main!(benches);
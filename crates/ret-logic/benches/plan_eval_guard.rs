@@ -0,0 +1,247 @@
+// crates/ret-logic/benches/plan_eval_guard.rs
+// ============================================================================
+// Module: Plan Evaluation Regression Guard
+// Description: Baseline throughput check for PlanExecutor hot paths.
+// Purpose: Fail CI when plan evaluation throughput regresses past a tolerance.
+// Dependencies: ret_logic::testing, serde_json
+// ============================================================================
+//! ## Overview
+//! Times the `plan_eval` workloads with a fixed best-of-N sampler and compares
+//! nanoseconds per row against `plan_eval_baseline.json`. Criterion reports
+//! statistics per run but has no pass/fail threshold against a checked-in
+//! baseline, so this guard stays a small custom harness.
+//!
+//! ```text
+//! cargo bench -p ret-logic --bench plan_eval_guard                    # report
+//! cargo bench -p ret-logic --bench plan_eval_guard -- --check         # guard
+//! cargo bench -p ret-logic --bench plan_eval_guard -- --save-baseline # record
+//! ```
+//!
+//! `--check` fails when any case's throughput falls more than the baseline's
+//! `max_throughput_drop` below the recorded value in each of up to
+//! `CHECK_ROUNDS` passes. Baselines are machine specific; re-record them on the
+//! reference runner after intentional changes.
+
+#![allow(
+    clippy::print_stdout,
+    clippy::print_stderr,
+    reason = "Benchmark reports are written to the terminal."
+)]
+
+mod common;
+
+use std::collections::BTreeMap;
+use std::hint::black_box;
+use std::process::ExitCode;
+use std::time::Duration;
+use std::time::Instant;
+
+use ret_logic::testing::LOGIC_MODES;
+use serde_json::Value;
+use serde_json::json;
+
+use crate::common::ColumnarWorkload;
+use crate::common::ROWS;
+use crate::common::Workload;
+use crate::common::columnar_workload;
+use crate::common::eval_blocks;
+use crate::common::eval_rows;
+use crate::common::evaluate_lazy;
+use crate::common::workloads;
+
+// ============================================================================
+// SECTION: Configuration
+// ============================================================================
+
+/// Recorded baseline consulted by `--check`.
+const BASELINE_PATH: &str = concat!(env!("CARGO_MANIFEST_DIR"), "/benches/plan_eval_baseline.json");
+
+/// Throughput drop tolerated by `--check` when recording a new baseline.
+const DEFAULT_MAX_THROUGHPUT_DROP: f64 = 0.3;
+
+/// Timed samples per case; the fastest is reported.
+const SAMPLES: usize = 7;
+
+/// Minimum wall time per sample.
+const SAMPLE_TIME: Duration = Duration::from_millis(40);
+
+/// Full passes `--check` runs before reporting a regression; each case keeps
+/// its best result so a noisy round does not fail the guard on its own.
+const CHECK_ROUNDS: usize = 3;
+
+// ============================================================================
+// SECTION: Measurement
+// ============================================================================
+
+/// Returns the fastest observed nanoseconds per row for `pass`.
+fn measure(mut pass: impl FnMut() -> u64) -> f64 {
+    let mut sink = 0u64;
+    let mut iterations = 1u32;
+    loop {
+        let started = Instant::now();
+        for _ in 0 .. iterations {
+            sink = sink.wrapping_add(pass());
+        }
+        if started.elapsed() >= SAMPLE_TIME {
+            break;
+        }
+        iterations = iterations.saturating_mul(2);
+    }
+    let mut best = f64::INFINITY;
+    for _ in 0 .. SAMPLES {
+        let started = Instant::now();
+        for _ in 0 .. iterations {
+            sink = sink.wrapping_add(pass());
+        }
+        let elapsed = started.elapsed().as_secs_f64() * 1e9;
+        best = best.min(elapsed / f64::from(iterations) / f64::from(ROWS));
+    }
+    black_box(sink);
+    best
+}
+
+/// Measures every evaluation path for a workload.
+fn bench_workload(workload: &Workload, results: &mut BTreeMap<String, f64>) {
+    let name = format!("{}/{}", workload.shape, workload.size);
+    let scalar = measure(|| eval_rows(&workload.executor, &workload.reader));
+    results.insert(format!("{name}/eval_row"), scalar);
+    let batch = measure(|| eval_blocks(&workload.executor, &workload.reader));
+    results.insert(format!("{name}/eval_block"), batch);
+    for mode in LOGIC_MODES {
+        let lazy = measure(|| evaluate_lazy(workload, mode));
+        results.insert(format!("{name}/evaluate_lazy/{mode:?}").to_lowercase(), lazy);
+    }
+}
+
+/// Measures per-row dispatch against columnar block evaluation.
+fn bench_columnar(workload: &ColumnarWorkload, results: &mut BTreeMap<String, f64>) {
+    let ColumnarWorkload {
+        rows,
+        columnar,
+        reader,
+    } = workload;
+    let scalar = measure(|| eval_rows(rows, reader));
+    results.insert("columnar/row_dispatch/eval_row".to_string(), scalar);
+    let dispatch = measure(|| eval_blocks(rows, reader));
+    results.insert("columnar/row_dispatch/eval_block".to_string(), dispatch);
+    let masked = measure(|| eval_blocks(columnar, reader));
+    results.insert("columnar/eval_block".to_string(), masked);
+}
+
+/// Runs one full pass over every case.
+fn run_all(workloads: &[Workload], columnar: &ColumnarWorkload) -> BTreeMap<String, f64> {
+    let mut results = BTreeMap::new();
+    for workload in workloads {
+        bench_workload(workload, &mut results);
+    }
+    bench_columnar(columnar, &mut results);
+    results
+}
+
+// ============================================================================
+// SECTION: Baseline
+// ============================================================================
+
+/// Compares results against the recorded baseline, returning failures.
+fn check_baseline(results: &BTreeMap<String, f64>) -> Result<Vec<String>, String> {
+    let contents = std::fs::read_to_string(BASELINE_PATH)
+        .map_err(|err| format!("failed to read {BASELINE_PATH}: {err}"))?;
+    let baseline: Value = serde_json::from_str(&contents)
+        .map_err(|err| format!("failed to parse {BASELINE_PATH}: {err}"))?;
+    let max_drop = baseline["max_throughput_drop"]
+        .as_f64()
+        .ok_or_else(|| "baseline is missing max_throughput_drop".to_string())?;
+    let cases = baseline["ns_per_row"]
+        .as_object()
+        .ok_or_else(|| "baseline is missing ns_per_row".to_string())?;
+    let mut failures = Vec::new();
+    for (case, recorded) in cases {
+        let Some(recorded) = recorded.as_f64() else {
+            return Err(format!("baseline entry {case} is not a number"));
+        };
+        let Some(current) = results.get(case) else {
+            failures.push(format!("{case}: missing from this run"));
+            continue;
+        };
+        // Throughput is rows per ns, so a drop of `max_drop` means the cost per
+        // row may grow to `recorded / (1 - max_drop)`.
+        let limit = recorded / (1.0 - max_drop);
+        if *current > limit {
+            failures.push(format!(
+                "{case}: {current:.2} ns/row exceeds {limit:.2} (baseline {recorded:.2})"
+            ));
+        }
+    }
+    Ok(failures)
+}
+
+/// Writes the current results as the new baseline.
+fn save_baseline(results: &BTreeMap<String, f64>) -> Result<(), String> {
+    let rounded: BTreeMap<&str, f64> =
+        results.iter().map(|(case, ns)| (case.as_str(), (ns * 100.0).round() / 100.0)).collect();
+    let document = json!({
+        "max_throughput_drop": DEFAULT_MAX_THROUGHPUT_DROP,
+        "ns_per_row": rounded,
+    });
+    let encoded = serde_json::to_string_pretty(&document).map_err(|err| err.to_string())?;
+    std::fs::write(BASELINE_PATH, encoded + "\n")
+        .map_err(|err| format!("failed to write {BASELINE_PATH}: {err}"))
+}
+
+// ============================================================================
+// SECTION: Entry Point
+// ============================================================================
+
+fn main() -> ExitCode {
+    let args: Vec<String> = std::env::args().skip(1).collect();
+    let check = args.iter().any(|arg| arg == "--check");
+    let save = args.iter().any(|arg| arg == "--save-baseline");
+
+    let workloads = workloads();
+    let columnar = columnar_workload();
+    let mut results = run_all(&workloads, &columnar);
+    for (case, ns) in &results {
+        println!("{case:<40} {ns:>10.2} ns/row");
+    }
+    if let (Some(dispatch), Some(masked)) =
+        (results.get("columnar/row_dispatch/eval_block"), results.get("columnar/eval_block"))
+    {
+        println!("columnar speedup over row dispatch: {:.1}x", dispatch / masked);
+    }
+
+    if save {
+        if let Err(err) = save_baseline(&results) {
+            eprintln!("{err}");
+            return ExitCode::FAILURE;
+        }
+        println!("baseline written to {BASELINE_PATH}");
+    }
+    if check {
+        let mut round = 1;
+        loop {
+            match check_baseline(&results) {
+                Ok(failures) if failures.is_empty() => {
+                    println!("throughput within baseline");
+                    break;
+                }
+                Ok(failures) if round >= CHECK_ROUNDS => {
+                    for failure in failures {
+                        eprintln!("regression: {failure}");
+                    }
+                    return ExitCode::FAILURE;
+                }
+                Ok(_) => {
+                    round += 1;
+                    for (case, ns) in run_all(&workloads, &columnar) {
+                        results.entry(case).and_modify(|best: &mut f64| *best = best.min(ns));
+                    }
+                }
+                Err(err) => {
+                    eprintln!("{err}");
+                    return ExitCode::FAILURE;
+                }
+            }
+        }
+    }
+    ExitCode::SUCCESS
+}
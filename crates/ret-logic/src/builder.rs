@@ -328,6 +328,24 @@ pub mod convenience {
         Requirement::require_group(min, requirements)
     }
 
+    /// Creates a requirement requiring exactly N of the given requirements
+    #[must_use]
+    pub fn exactly_n<P>(n: u8, requirements: Vec<Requirement<P>>) -> Requirement<P> {
+        Requirement::require_exactly(n, requirements)
+    }
+
+    /// Creates a requirement requiring at most N of the given requirements
+    #[must_use]
+    pub fn at_most<P>(max: u8, requirements: Vec<Requirement<P>>) -> Requirement<P> {
+        Requirement::require_at_most(max, requirements)
+    }
+
+    /// Creates a requirement whose consequent must hold whenever its antecedent does
+    #[must_use]
+    pub fn implies<P>(antecedent: Requirement<P>, consequent: Requirement<P>) -> Requirement<P> {
+        Requirement::implies(antecedent, consequent)
+    }
+
     /// Creates a requirement from a condition
     #[must_use]
     pub const fn condition<P>(condition: P) -> Requirement<P> {
@@ -0,0 +1,651 @@
+// crates/ret-logic/src/columnar.rs
+// ============================================================================
+// Module: Columnar Storage
+// Description: Column-oriented reader and mask-based plan evaluation.
+// Purpose: Evaluate plans over 64-row chunks without per-row dispatch.
+// Dependencies: crate::{error, executor, plan, traits}, std::collections
+// ============================================================================
+
+//! ## Overview
+//! [`ColumnarReader`] stores each column as one contiguous typed slice, and
+//! [`ColumnarExecutor`] evaluates a [`Plan`] over it one 64-row block at a
+//! time. Every leaf operation becomes a single comparison loop over a column
+//! slice that yields a [`Mask64`], and groups combine those masks with bitwise
+//! `&`, `|`, and `!`. No handler is dispatched per row, so the comparison
+//! loops are free to be auto-vectorized.
+//!
+//! Supported leaves are the built-in comparison and flag opcodes
+//! (`FloatGte`/`FloatLte`/`FloatEq`, `IntGte`/`IntLte`/`IntEq`, and
+//! `HasAllFlags`/`HasAnyFlags`/`HasNoneFlags`). Operand A names the column and
+//! operand B the constant. Unsupported opcodes, missing columns, mismatched
+//! column types, and invalid constants evaluate to `false`, matching the
+//! fail-closed behavior of [`PlanExecutor`].
+//!
+//! Individual cells may be marked missing with
+//! [`ColumnarReader::mark_missing`]. The typed accessors return `None` for
+//! them, so per-row dispatch reports an error (`Unknown` under
+//! [`PlanExecutor::evaluate_lazy`]), and leaf masks never set their bits.
+//!
+//! [`row_dispatch_executor`] builds a [`PlanExecutor`] with the same leaf
+//! semantics evaluated one row at a time; it is the reference the columnar
+//! path is checked against.
+
+use std::collections::BTreeMap;
+use std::fmt;
+
+use crate::error::RequirementError;
+use crate::error::RequirementResult;
+use crate::executor::ExecutorBuilder;
+use crate::executor::MAX_PLAN_STACK_DEPTH;
+use crate::executor::PlanExecutor;
+use crate::plan::ColumnKey;
+use crate::plan::Constant;
+use crate::plan::OpCode;
+use crate::plan::Operation;
+use crate::plan::Plan;
+use crate::traits::BatchConditionEval;
+use crate::traits::ConditionEval;
+use crate::traits::Mask64;
+use crate::traits::ReaderLen;
+use crate::traits::Row;
+
+// ============================================================================
+// SECTION: Columnar Errors
+// ============================================================================
+
+/// Errors raised while assembling a [`ColumnarReader`]
+///
+/// # Invariants
+/// - None. Variants are self-contained error categories.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ColumnarError {
+    /// A column's length differs from the reader's row count.
+    LengthMismatch {
+        /// Column being inserted.
+        column: ColumnKey,
+        /// Row count of the reader.
+        expected: usize,
+        /// Length of the inserted column.
+        actual: usize,
+    },
+    /// An input row has a different number of values than there are columns.
+    RowWidthMismatch {
+        /// Index of the offending row.
+        row: Row,
+        /// Number of column keys.
+        expected: usize,
+        /// Number of values in the row.
+        actual: usize,
+    },
+    /// An input value's type differs from the type of its column.
+    TypeMismatch {
+        /// Column holding the value.
+        column: ColumnKey,
+        /// Index of the offending row.
+        row: Row,
+    },
+    /// A cell was addressed in a column the reader lacks or past its last row.
+    CellOutOfRange {
+        /// Column of the addressed cell.
+        column: ColumnKey,
+        /// Row of the addressed cell.
+        row: Row,
+    },
+}
+
+impl fmt::Display for ColumnarError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::LengthMismatch {
+                column,
+                expected,
+                actual,
+            } => {
+                write!(f, "column {} has {actual} rows but the reader has {expected}", column.id())
+            }
+            Self::RowWidthMismatch {
+                row,
+                expected,
+                actual,
+            } => write!(f, "row {row} has {actual} values but {expected} columns are defined"),
+            Self::TypeMismatch {
+                column,
+                row,
+            } => write!(f, "row {row} has a value of the wrong type for column {}", column.id()),
+            Self::CellOutOfRange {
+                column,
+                row,
+            } => write!(f, "row {row} of column {} is outside the reader", column.id()),
+        }
+    }
+}
+
+impl std::error::Error for ColumnarError {}
+
+// ============================================================================
+// SECTION: Column Types
+// ============================================================================
+
+/// One contiguous, typed column of values
+///
+/// # Invariants
+/// - None. Lengths are checked when the column is added to a reader.
+#[derive(Debug, Clone, PartialEq)]
+pub enum Column {
+    /// Floating-point values compared by the `Float*` opcodes.
+    Float(Vec<f32>),
+    /// Signed integers compared by the `Int*` opcodes.
+    Int(Vec<i32>),
+    /// Bit sets tested by the `Has*Flags` opcodes.
+    Flags(Vec<u64>),
+}
+
+impl Column {
+    /// Returns the number of values in the column
+    #[must_use]
+    pub const fn len(&self) -> usize {
+        match self {
+            Self::Float(values) => values.len(),
+            Self::Int(values) => values.len(),
+            Self::Flags(values) => values.len(),
+        }
+    }
+
+    /// Returns whether the column holds no values
+    #[must_use]
+    pub const fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// Creates an empty column of the same type as `value`
+    fn for_value(value: ColumnValue, capacity: usize) -> Self {
+        match value {
+            ColumnValue::Float(_) => Self::Float(Vec::with_capacity(capacity)),
+            ColumnValue::Int(_) => Self::Int(Vec::with_capacity(capacity)),
+            ColumnValue::Flags(_) => Self::Flags(Vec::with_capacity(capacity)),
+        }
+    }
+
+    /// Appends `value`, returning `false` when its type does not match
+    fn push(&mut self, value: ColumnValue) -> bool {
+        match (self, value) {
+            (Self::Float(values), ColumnValue::Float(value)) => values.push(value),
+            (Self::Int(values), ColumnValue::Int(value)) => values.push(value),
+            (Self::Flags(values), ColumnValue::Flags(value)) => values.push(value),
+            _ => return false,
+        }
+        true
+    }
+}
+
+/// A single typed value in row-oriented input
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum ColumnValue {
+    /// Floating-point value.
+    Float(f32),
+    /// Signed integer value.
+    Int(i32),
+    /// Bit-set value.
+    Flags(u64),
+}
+
+// ============================================================================
+// SECTION: Columnar Reader
+// ============================================================================
+
+/// Reader that stores every column as a contiguous slice
+///
+/// # Invariants
+/// - Every column holds exactly `len` values.
+/// - `missing` only has entries for stored columns, with one bit per row.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct ColumnarReader {
+    /// Number of rows in the reader.
+    len: usize,
+    /// Columns keyed by the identifiers plans reference.
+    columns: BTreeMap<ColumnKey, Column>,
+    /// Per-column bitmaps of missing cells, 64 rows per word.
+    missing: BTreeMap<ColumnKey, Vec<Mask64>>,
+}
+
+impl ColumnarReader {
+    /// Creates a reader with `len` rows and no columns
+    #[must_use]
+    pub const fn new(len: usize) -> Self {
+        Self {
+            len,
+            columns: BTreeMap::new(),
+            missing: BTreeMap::new(),
+        }
+    }
+
+    /// Adds or replaces a column, returning the updated reader
+    ///
+    /// # Errors
+    /// Returns [`ColumnarError::LengthMismatch`] when the column length differs
+    /// from the reader's row count.
+    pub fn with_column(mut self, key: ColumnKey, column: Column) -> Result<Self, ColumnarError> {
+        self.insert_column(key, column)?;
+        Ok(self)
+    }
+
+    /// Adds or replaces a column, clearing any cells marked missing in it
+    ///
+    /// # Errors
+    /// Returns [`ColumnarError::LengthMismatch`] when the column length differs
+    /// from the reader's row count.
+    pub fn insert_column(&mut self, key: ColumnKey, column: Column) -> Result<(), ColumnarError> {
+        if column.len() != self.len {
+            return Err(ColumnarError::LengthMismatch {
+                column: key,
+                expected: self.len,
+                actual: column.len(),
+            });
+        }
+        self.columns.insert(key, column);
+        self.missing.remove(&key);
+        Ok(())
+    }
+
+    /// Marks the cell at `row` in column `key` as missing
+    ///
+    /// The stored value is kept but no longer read: the typed accessors return
+    /// `None` and block evaluation treats every leaf on the cell as `false`.
+    ///
+    /// # Errors
+    /// Returns [`ColumnarError::CellOutOfRange`] when the reader has no column
+    /// `key` or `row` is past its last row.
+    pub fn mark_missing(&mut self, key: ColumnKey, row: Row) -> Result<(), ColumnarError> {
+        if row >= self.len || !self.columns.contains_key(&key) {
+            return Err(ColumnarError::CellOutOfRange {
+                column: key,
+                row,
+            });
+        }
+        let words = self.missing.entry(key).or_insert_with(|| vec![0; self.len.div_ceil(64)]);
+        words[row / 64] |= 1 << (row % 64);
+        Ok(())
+    }
+
+    /// Returns whether the cell at `row` in column `key` is marked missing
+    #[must_use]
+    pub fn is_missing(&self, row: Row, key: ColumnKey) -> bool {
+        self.missing
+            .get(&key)
+            .and_then(|words| words.get(row / 64))
+            .is_some_and(|word| word >> (row % 64) & 1 == 1)
+    }
+
+    /// Returns the missing-cell bits of column `key` for `rows` rows from `start`
+    fn missing_mask(&self, key: ColumnKey, start: Row, rows: usize) -> Mask64 {
+        let Some(words) = self.missing.get(&key) else {
+            return 0;
+        };
+        let (word, shift) = (start / 64, start % 64);
+        let low = words.get(word).map_or(0, |bits| bits >> shift);
+        let high = match shift {
+            0 => 0,
+            _ => words.get(word + 1).map_or(0, |bits| bits << (64 - shift)),
+        };
+        let valid = if rows >= 64 { Mask64::MAX } else { (1 << rows) - 1 };
+        (low | high) & valid
+    }
+
+    /// Transposes row-oriented input into columns
+    ///
+    /// `keys[i]` names the column that receives the `i`-th value of every row.
+    /// Each column takes its type from the first row. With no rows the reader
+    /// is empty and has no columns.
+    ///
+    /// # Errors
+    /// Returns [`ColumnarError::RowWidthMismatch`] when a row's width differs
+    /// from `keys`, or [`ColumnarError::TypeMismatch`] when a value's type
+    /// differs from its column.
+    pub fn from_rows<I, V>(keys: &[ColumnKey], rows: I) -> Result<Self, ColumnarError>
+    where
+        I: IntoIterator<Item = V>,
+        V: AsRef<[ColumnValue]>,
+    {
+        let rows = rows.into_iter();
+        let capacity = rows.size_hint().0;
+        let mut columns: Vec<Column> = Vec::new();
+        let mut len = 0;
+        for (row, values) in rows.enumerate() {
+            let values = values.as_ref();
+            if values.len() != keys.len() {
+                return Err(ColumnarError::RowWidthMismatch {
+                    row,
+                    expected: keys.len(),
+                    actual: values.len(),
+                });
+            }
+            if row == 0 {
+                columns = values.iter().map(|value| Column::for_value(*value, capacity)).collect();
+            }
+            for ((column, value), key) in columns.iter_mut().zip(values).zip(keys) {
+                if !column.push(*value) {
+                    return Err(ColumnarError::TypeMismatch {
+                        column: *key,
+                        row,
+                    });
+                }
+            }
+            len = row + 1;
+        }
+        let mut reader = Self::new(len);
+        for (key, column) in keys.iter().zip(columns) {
+            reader.insert_column(*key, column)?;
+        }
+        Ok(reader)
+    }
+
+    /// Returns the column stored under `key`
+    #[must_use]
+    pub fn column(&self, key: ColumnKey) -> Option<&Column> {
+        self.columns.get(&key)
+    }
+
+    /// Returns the float at `row` in column `column`, unless it is missing
+    #[must_use]
+    pub fn float(&self, row: Row, column: u16) -> Option<f32> {
+        if self.is_missing(row, ColumnKey(column)) {
+            return None;
+        }
+        match self.columns.get(&ColumnKey(column)) {
+            Some(Column::Float(values)) => values.get(row).copied(),
+            _ => None,
+        }
+    }
+
+    /// Returns the integer at `row` in column `column`, unless it is missing
+    #[must_use]
+    pub fn int(&self, row: Row, column: u16) -> Option<i32> {
+        if self.is_missing(row, ColumnKey(column)) {
+            return None;
+        }
+        match self.columns.get(&ColumnKey(column)) {
+            Some(Column::Int(values)) => values.get(row).copied(),
+            _ => None,
+        }
+    }
+
+    /// Returns the flags at `row` in column `column`, unless it is missing
+    #[must_use]
+    pub fn flags(&self, row: Row, column: u16) -> Option<u64> {
+        if self.is_missing(row, ColumnKey(column)) {
+            return None;
+        }
+        match self.columns.get(&ColumnKey(column)) {
+            Some(Column::Flags(values)) => values.get(row).copied(),
+            _ => None,
+        }
+    }
+}
+
+impl ReaderLen for ColumnarReader {
+    fn len(&self) -> usize {
+        self.len
+    }
+}
+
+// ============================================================================
+// SECTION: Columnar Executor
+// ============================================================================
+
+/// Plan executor that evaluates whole 64-row blocks with bitmasks
+///
+/// # Invariants
+/// - Rows at or beyond the reader's length never pass.
+#[derive(Debug, Clone)]
+pub struct ColumnarExecutor {
+    /// The compiled plan to execute.
+    plan: Plan,
+}
+
+impl ColumnarExecutor {
+    /// Creates a columnar executor for `plan`
+    #[must_use]
+    pub const fn new(plan: Plan) -> Self {
+        Self {
+            plan,
+        }
+    }
+
+    /// Returns a reference to the underlying plan
+    #[must_use]
+    pub const fn plan(&self) -> &Plan {
+        &self.plan
+    }
+}
+
+impl ConditionEval for ColumnarExecutor {
+    type Reader<'a> = ColumnarReader;
+
+    fn eval_row(&self, reader: &Self::Reader<'_>, row: Row) -> bool {
+        self.eval_block(reader, row, 1) & 1 == 1
+    }
+}
+
+impl BatchConditionEval for ColumnarExecutor {
+    fn eval_block(&self, reader: &Self::Reader<'_>, start: Row, count: usize) -> Mask64 {
+        let rows = count.min(64).min(reader.len.saturating_sub(start));
+        if rows == 0 {
+            return 0;
+        }
+        let valid = if rows == 64 { Mask64::MAX } else { (1 << rows) - 1 };
+
+        // Each frame holds the group's accumulated mask and whether it is an AND
+        let mut stack_masks = [valid; MAX_PLAN_STACK_DEPTH];
+        let mut stack_is_and = [true; MAX_PLAN_STACK_DEPTH];
+        let mut stack_pointer = 0usize;
+
+        for operation in self.plan.operations() {
+            let value = match operation.opcode {
+                OpCode::AndStart | OpCode::OrStart => {
+                    stack_pointer += 1;
+                    if stack_pointer >= MAX_PLAN_STACK_DEPTH {
+                        return 0;
+                    }
+                    let is_and = operation.opcode == OpCode::AndStart;
+                    stack_is_and[stack_pointer] = is_and;
+                    stack_masks[stack_pointer] = if is_and { valid } else { 0 };
+                    continue;
+                }
+                OpCode::AndEnd | OpCode::OrEnd => {
+                    let is_and = operation.opcode == OpCode::AndEnd;
+                    if stack_pointer == 0 || stack_is_and[stack_pointer] != is_and {
+                        return 0;
+                    }
+                    stack_pointer -= 1;
+                    stack_masks[stack_pointer + 1]
+                }
+                OpCode::Not => {
+                    stack_masks[stack_pointer] = !stack_masks[stack_pointer] & valid;
+                    continue;
+                }
+                _ => {
+                    leaf_mask(reader, *operation, &self.plan.constants, start, rows)
+                        & !reader.missing_mask(ColumnKey(operation.operand_a), start, rows)
+                }
+            };
+            if stack_is_and[stack_pointer] {
+                stack_masks[stack_pointer] &= value;
+            } else {
+                stack_masks[stack_pointer] |= value;
+            }
+        }
+
+        if stack_pointer != 0 {
+            return 0;
+        }
+        stack_masks[0]
+    }
+}
+
+// ============================================================================
+// SECTION: Leaf Kernels
+// ============================================================================
+
+/// Evaluates one leaf over `rows` rows starting at `start`
+fn leaf_mask(
+    reader: &ColumnarReader,
+    operation: Operation,
+    constants: &[Constant],
+    start: Row,
+    rows: usize,
+) -> Mask64 {
+    let Some(column) = reader.columns.get(&ColumnKey(operation.operand_a)) else {
+        return 0;
+    };
+    let Some(constant) = constants.get(usize::from(operation.operand_b)) else {
+        return 0;
+    };
+    let range = start .. start + rows;
+    match (operation.opcode, column) {
+        (OpCode::FloatGte, Column::Float(values)) => constant
+            .as_float()
+            .map_or(0, |threshold| compare_mask(&values[range], |value| value >= threshold)),
+        (OpCode::FloatLte, Column::Float(values)) => constant
+            .as_float()
+            .map_or(0, |threshold| compare_mask(&values[range], |value| value <= threshold)),
+        (OpCode::FloatEq, Column::Float(values)) => constant.as_float().map_or(0, |expected| {
+            compare_mask(&values[range], |value| (value - expected).abs() < f32::EPSILON)
+        }),
+        (OpCode::IntGte, Column::Int(values)) => constant
+            .as_int()
+            .map_or(0, |threshold| compare_mask(&values[range], |value| value >= threshold)),
+        (OpCode::IntLte, Column::Int(values)) => constant
+            .as_int()
+            .map_or(0, |threshold| compare_mask(&values[range], |value| value <= threshold)),
+        (OpCode::IntEq, Column::Int(values)) => constant
+            .as_int()
+            .map_or(0, |expected| compare_mask(&values[range], |value| value == expected)),
+        (OpCode::HasAllFlags, Column::Flags(values)) => constant.as_flags().map_or(0, |required| {
+            compare_mask(&values[range], |value| value & required == required)
+        }),
+        (OpCode::HasAnyFlags, Column::Flags(values)) => constant
+            .as_flags()
+            .map_or(0, |wanted| compare_mask(&values[range], |value| value & wanted != 0)),
+        (OpCode::HasNoneFlags, Column::Flags(values)) => constant
+            .as_flags()
+            .map_or(0, |forbidden| compare_mask(&values[range], |value| value & forbidden == 0)),
+        _ => 0,
+    }
+}
+
+/// Packs `predicate` over up to 64 values into a mask without branching
+#[inline]
+fn compare_mask<T: Copy>(values: &[T], predicate: impl Fn(T) -> bool) -> Mask64 {
+    values
+        .iter()
+        .enumerate()
+        .fold(0, |mask, (bit, &value)| mask | (Mask64::from(predicate(value)) << bit))
+}
+
+// ============================================================================
+// SECTION: Row Dispatch Reference
+// ============================================================================
+
+/// Builds a [`PlanExecutor`] that evaluates the columnar leaf opcodes one row
+/// at a time through the dispatch table
+///
+/// Results match [`ColumnarExecutor`] for every row inside the reader.
+#[must_use]
+pub fn row_dispatch_executor(plan: Plan) -> PlanExecutor<ColumnarReader> {
+    row_dispatch_builder().build(plan)
+}
+
+/// Returns an [`ExecutorBuilder`] with handlers for the columnar leaf opcodes
+///
+/// Comparison and flag-check opcodes read the column in `operand_a` and the
+/// constant in `operand_b`; register further opcodes before building.
+#[must_use]
+pub fn row_dispatch_builder() -> ExecutorBuilder<ColumnarReader> {
+    ExecutorBuilder::<ColumnarReader>::new()
+        .register(OpCode::FloatGte, |reader, row, op, constants| {
+            compare_row(
+                reader.float(row, op.operand_a),
+                constants,
+                op,
+                Constant::as_float,
+                |v, t| v >= t,
+            )
+        })
+        .register(OpCode::FloatLte, |reader, row, op, constants| {
+            compare_row(
+                reader.float(row, op.operand_a),
+                constants,
+                op,
+                Constant::as_float,
+                |v, t| v <= t,
+            )
+        })
+        .register(OpCode::FloatEq, |reader, row, op, constants| {
+            compare_row(
+                reader.float(row, op.operand_a),
+                constants,
+                op,
+                Constant::as_float,
+                |v, t| (v - t).abs() < f32::EPSILON,
+            )
+        })
+        .register(OpCode::IntGte, |reader, row, op, constants| {
+            compare_row(reader.int(row, op.operand_a), constants, op, Constant::as_int, |v, t| {
+                v >= t
+            })
+        })
+        .register(OpCode::IntLte, |reader, row, op, constants| {
+            compare_row(reader.int(row, op.operand_a), constants, op, Constant::as_int, |v, t| {
+                v <= t
+            })
+        })
+        .register(OpCode::IntEq, |reader, row, op, constants| {
+            compare_row(reader.int(row, op.operand_a), constants, op, Constant::as_int, |v, t| {
+                v == t
+            })
+        })
+        .register(OpCode::HasAllFlags, |reader, row, op, constants| {
+            compare_row(
+                reader.flags(row, op.operand_a),
+                constants,
+                op,
+                Constant::as_flags,
+                |v, t| v & t == t,
+            )
+        })
+        .register(OpCode::HasAnyFlags, |reader, row, op, constants| {
+            compare_row(
+                reader.flags(row, op.operand_a),
+                constants,
+                op,
+                Constant::as_flags,
+                |v, t| v & t != 0,
+            )
+        })
+        .register(OpCode::HasNoneFlags, |reader, row, op, constants| {
+            compare_row(
+                reader.flags(row, op.operand_a),
+                constants,
+                op,
+                Constant::as_flags,
+                |v, t| v & t == 0,
+            )
+        })
+}
+
+/// Compares one row value against the operation's constant
+fn compare_row<T>(
+    value: Option<T>,
+    constants: &[Constant],
+    op: Operation,
+    decode: impl Fn(&Constant) -> Option<T>,
+    compare: impl Fn(T, T) -> bool,
+) -> RequirementResult<bool> {
+    let value =
+        value.ok_or_else(|| RequirementError::condition_error("Missing value for comparison"))?;
+    let constant = constants
+        .get(usize::from(op.operand_b))
+        .and_then(decode)
+        .ok_or_else(|| RequirementError::condition_error("Invalid comparison constant"))?;
+    Ok(compare(value, constant))
+}
@@ -0,0 +1,360 @@
+// crates/ret-logic/src/compiled.rs
+// ============================================================================
+// Module: Compiled Requirements
+// Description: Build-once, evaluate-many wrapper around compiled plans.
+// Purpose: Let library users cache the Requirement-to-Plan compilation step.
+// Dependencies: crate::{executor, hashing, plan, requirement, traits, tristate}
+// ============================================================================
+
+//! ## Overview
+//! [`CompiledRequirement`] lowers a [`Requirement`] tree into a [`Plan`] once
+//! and keeps it together with the dispatch table that resolves its opcodes.
+//! Compilation is the expensive step: it walks the tree, calls the
+//! [`ConditionLowering`] for every leaf, expands counted groups, and checks
+//! the result. Evaluation only runs the prepared stack machine, so a compiled
+//! requirement should be built once and reused for every reader and row.
+//!
+//! ```ignore
+//! let compiled = CompiledRequirement::compile(&requirement, &lower, dispatch)?;
+//! for chunk in chunks {
+//!     let passed = compiled.eval(&chunk, row);
+//! }
+//! ```
+
+use crate::executor::ExecutorBuilder;
+use crate::executor::MAX_PLAN_STACK_DEPTH;
+use crate::executor::PlanExecutor;
+use crate::hashing::HashDigest;
+use crate::plan::ColumnKey;
+use crate::plan::OpCode;
+use crate::plan::Operation;
+use crate::plan::Plan;
+use crate::plan::PlanError;
+use crate::requirement::Requirement;
+use crate::traits::BatchConditionEval;
+use crate::traits::ConditionEval;
+use crate::traits::Row;
+use crate::tristate::LogicMode;
+use crate::tristate::TriState;
+
+// ============================================================================
+// SECTION: Condition Lowering
+// ============================================================================
+
+/// Maps requirement conditions to plan operations during compilation
+///
+/// Implementations register any columns and constants a condition needs on
+/// `plan` and return the leaf operation that evaluates it. The returned
+/// opcode must have a handler in the dispatch table passed to
+/// [`CompiledRequirement::compile`]; unhandled opcodes fail closed.
+pub trait ConditionLowering<P> {
+    /// Returns the operation that evaluates `condition`
+    ///
+    /// # Errors
+    /// Returns [`PlanError`] when the condition cannot be represented, such as
+    /// when the constant pool overflows.
+    fn lower(&self, condition: &P, plan: &mut Plan) -> Result<Operation, PlanError>;
+}
+
+impl<P, F> ConditionLowering<P> for F
+where
+    F: Fn(&P, &mut Plan) -> Result<Operation, PlanError>,
+{
+    fn lower(&self, condition: &P, plan: &mut Plan) -> Result<Operation, PlanError> {
+        self(condition, plan)
+    }
+}
+
+// ============================================================================
+// SECTION: Compiled Requirement
+// ============================================================================
+
+/// Requirement compiled to a plan, ready to evaluate against any reader
+///
+/// Holds the [`Plan`], the dispatch table that resolves its opcodes, and
+/// metadata recorded during compilation. Cloning is cheap relative to
+/// recompiling, but sharing one instance by reference is cheaper still.
+///
+/// # Invariants
+/// - Group nesting fits the executor stack; deeper plans are rejected by
+///   [`CompiledRequirement::compile`] rather than failing closed at runtime.
+/// - `structural_hash` is the [`Plan::structural_hash`] of `plan()`.
+pub struct CompiledRequirement<R: 'static> {
+    /// Plan and dispatch table used for every evaluation.
+    executor: PlanExecutor<R>,
+    /// Number of leaf operations produced by the lowering.
+    lowered_conditions: usize,
+    /// Structural digest of the compiled plan.
+    structural_hash: HashDigest,
+}
+
+impl<R: 'static> CompiledRequirement<R> {
+    /// Compiles `requirement` into a plan evaluated with `dispatch`
+    ///
+    /// This is the expensive step; keep the result and reuse it. `Not` is
+    /// wrapped in its own AND frame, `Implies` becomes an implication frame
+    /// over its two operands, `RequireGroup` expands into an OR over
+    /// every `min`-sized AND of its members, `RequireExactly` additionally
+    /// negates the members left out, and `RequireAtMost` negates an
+    /// at-least-`max + 1` group. Counted groups therefore grow
+    /// combinatorially with their width, and each expansion lowers its
+    /// conditions again.
+    ///
+    /// # Errors
+    /// Returns the first [`PlanError`] reported by `lowering`, or
+    /// [`PlanError::NestingTooDeep`] when the plan would overflow the executor
+    /// stack.
+    pub fn compile<P, L>(
+        requirement: &Requirement<P>,
+        lowering: &L,
+        dispatch: ExecutorBuilder<R>,
+    ) -> Result<Self, PlanError>
+    where
+        L: ConditionLowering<P> + ?Sized,
+    {
+        let mut emitter = Emitter {
+            plan: Plan::new(),
+            lowering,
+            lowered_conditions: 0,
+        };
+        emitter.emit(requirement)?;
+        let Emitter {
+            plan,
+            lowered_conditions,
+            ..
+        } = emitter;
+        check_nesting(&plan)?;
+        Ok(Self {
+            structural_hash: plan.structural_hash(),
+            executor: dispatch.build(plan),
+            lowered_conditions,
+        })
+    }
+
+    /// Returns the compiled plan
+    #[must_use]
+    pub const fn plan(&self) -> &Plan {
+        self.executor.plan()
+    }
+
+    /// Returns the executor that runs the compiled plan
+    #[must_use]
+    pub const fn executor(&self) -> &PlanExecutor<R> {
+        &self.executor
+    }
+
+    /// Returns the columns registered by the lowering
+    #[must_use]
+    pub fn required_columns(&self) -> &[ColumnKey] {
+        self.executor.required_columns()
+    }
+
+    /// Returns how many leaf operations the lowering produced
+    ///
+    /// Counted groups lower a condition once per expansion, so this can
+    /// exceed the number of conditions in the source requirement.
+    #[must_use]
+    pub const fn lowered_conditions(&self) -> usize {
+        self.lowered_conditions
+    }
+
+    /// Returns the structural digest of the compiled plan
+    ///
+    /// Suitable as a cache key: equal requirements lowered the same way
+    /// produce equal digests.
+    #[must_use]
+    pub const fn structural_hash(&self) -> &HashDigest {
+        &self.structural_hash
+    }
+
+    /// Evaluates the compiled plan for one row
+    ///
+    /// Handler errors and missing handlers fail closed.
+    #[must_use]
+    pub fn eval(&self, reader: &R, row: Row) -> bool {
+        self.executor.eval_row(reader, row)
+    }
+
+    /// Evaluates the compiled plan for one row with tri-state semantics
+    ///
+    /// See [`PlanExecutor::evaluate_lazy`] for how handler errors and
+    /// short-circuiting behave.
+    #[must_use]
+    pub fn eval_tristate(&self, reader: &R, row: Row, logic: LogicMode) -> TriState {
+        self.executor.evaluate_lazy(reader, row, logic)
+    }
+}
+
+impl<R: 'static> ConditionEval for CompiledRequirement<R> {
+    type Reader<'a> = R;
+
+    fn eval_row(&self, reader: &R, row: Row) -> bool {
+        self.eval(reader, row)
+    }
+}
+
+impl<R: 'static> BatchConditionEval for CompiledRequirement<R> {}
+
+// ============================================================================
+// SECTION: Emission
+// ============================================================================
+
+/// Accumulates plan operations while walking a requirement tree.
+struct Emitter<'a, L: ?Sized> {
+    /// Plan being built.
+    plan: Plan,
+    /// Lowering applied to each condition.
+    lowering: &'a L,
+    /// Leaf operations emitted so far.
+    lowered_conditions: usize,
+}
+
+impl<L: ?Sized> Emitter<'_, L> {
+    /// Appends a group marker.
+    fn marker(&mut self, opcode: OpCode) {
+        self.plan.add_operation(Operation::new(opcode, 0, 0, 0));
+    }
+
+    /// Appends the operations for one requirement node.
+    fn emit<P>(&mut self, requirement: &Requirement<P>) -> Result<(), PlanError>
+    where
+        L: ConditionLowering<P>,
+    {
+        match requirement {
+            Requirement::Condition(condition) => {
+                let operation = self.lowering.lower(condition, &mut self.plan)?;
+                self.plan.add_operation(operation);
+                self.lowered_conditions += 1;
+            }
+            Requirement::Not(inner) => {
+                self.marker(OpCode::AndStart);
+                self.emit(inner)?;
+                self.marker(OpCode::Not);
+                self.marker(OpCode::AndEnd);
+            }
+            Requirement::And(children) => {
+                self.marker(OpCode::AndStart);
+                for child in children {
+                    self.emit(child)?;
+                }
+                self.marker(OpCode::AndEnd);
+            }
+            Requirement::Or(children) => {
+                self.marker(OpCode::OrStart);
+                for child in children {
+                    self.emit(child)?;
+                }
+                self.marker(OpCode::OrEnd);
+            }
+            Requirement::Implies {
+                antecedent,
+                consequent,
+            } => {
+                self.marker(OpCode::ImpliesStart);
+                self.emit(antecedent)?;
+                self.emit(consequent)?;
+                self.marker(OpCode::ImpliesEnd);
+            }
+            Requirement::RequireGroup {
+                min,
+                reqs,
+            } => self.emit_group(reqs, usize::from(*min), false)?,
+            Requirement::RequireExactly {
+                n,
+                reqs,
+            } => self.emit_group(reqs, usize::from(*n), true)?,
+            Requirement::RequireAtMost {
+                max,
+                reqs,
+            } => {
+                self.marker(OpCode::AndStart);
+                self.emit_group(reqs, usize::from(*max) + 1, false)?;
+                self.marker(OpCode::Not);
+                self.marker(OpCode::AndEnd);
+            }
+        }
+        Ok(())
+    }
+
+    /// Appends an OR over every `size`-sized combination of `reqs`.
+    fn emit_group<P>(
+        &mut self,
+        reqs: &[Box<Requirement<P>>],
+        size: usize,
+        exact: bool,
+    ) -> Result<(), PlanError>
+    where
+        L: ConditionLowering<P>,
+    {
+        let children: Vec<&Requirement<P>> = reqs.iter().map(AsRef::as_ref).collect();
+        self.marker(OpCode::OrStart);
+        let mut chosen = Vec::with_capacity(size);
+        self.emit_combinations(&children, size, 0, exact, &mut chosen)?;
+        self.marker(OpCode::OrEnd);
+        Ok(())
+    }
+
+    /// Emits one AND frame per `remaining`-sized combination of `children[start..]`.
+    ///
+    /// When `exact` is set, each frame also negates every child left out of the
+    /// combination.
+    fn emit_combinations<P>(
+        &mut self,
+        children: &[&Requirement<P>],
+        remaining: usize,
+        start: usize,
+        exact: bool,
+        chosen: &mut Vec<usize>,
+    ) -> Result<(), PlanError>
+    where
+        L: ConditionLowering<P>,
+    {
+        if remaining == 0 {
+            self.marker(OpCode::AndStart);
+            for (index, child) in children.iter().enumerate() {
+                if chosen.contains(&index) {
+                    self.emit(child)?;
+                } else if exact {
+                    self.marker(OpCode::AndStart);
+                    self.emit(child)?;
+                    self.marker(OpCode::Not);
+                    self.marker(OpCode::AndEnd);
+                }
+            }
+            self.marker(OpCode::AndEnd);
+            return Ok(());
+        }
+        for index in start .. children.len() {
+            if children.len() - index < remaining {
+                break;
+            }
+            chosen.push(index);
+            self.emit_combinations(children, remaining - 1, index + 1, exact, chosen)?;
+            chosen.pop();
+        }
+        Ok(())
+    }
+}
+
+/// Rejects plans whose group nesting would overflow the executor stack.
+fn check_nesting(plan: &Plan) -> Result<(), PlanError> {
+    let max_depth = MAX_PLAN_STACK_DEPTH - 1;
+    let mut depth = 0usize;
+    for operation in plan.operations() {
+        match operation.opcode {
+            OpCode::AndStart | OpCode::OrStart | OpCode::ImpliesStart => {
+                depth += 1;
+                if depth > max_depth {
+                    return Err(PlanError::NestingTooDeep {
+                        max_depth,
+                    });
+                }
+            }
+            OpCode::AndEnd | OpCode::OrEnd | OpCode::ImpliesEnd => {
+                depth = depth.saturating_sub(1);
+            }
+            _ => {}
+        }
+    }
+    Ok(())
+}
@@ -0,0 +1,355 @@
+// crates/ret-logic/src/csv.rs
+// ============================================================================
+// Module: Delimited Text Import
+// Description: CSV/TSV import into columnar readers with missing cells.
+// Purpose: Feed external datasets into plan evaluation with Unknown handling.
+// Dependencies: crate::{columnar, plan, traits}, std::fmt
+// ============================================================================
+
+//! ## Overview
+//! [`CsvImporter`] reads delimited text with a header row into a
+//! [`ColumnarReader`]. Each mapped header names a [`ColumnKey`] and a
+//! [`CsvType`]; unmapped columns are ignored. Fields may be quoted with `"`,
+//! with `""` escaping a quote, and quoted fields may contain delimiters and
+//! line breaks. Blank lines are skipped.
+//!
+//! Cells that are empty, unparseable for their type, or non-finite floats are
+//! marked missing rather than rejected. Per-row dispatch through
+//! [`crate::columnar::row_dispatch_executor`] then evaluates any leaf on such a
+//! cell to `Unknown` under [`crate::PlanExecutor::evaluate_lazy`], while
+//! [`crate::ColumnarExecutor`] treats it as `false`.
+
+// ============================================================================
+// SECTION: Imports
+// ============================================================================
+
+use std::fmt;
+
+use crate::columnar::Column;
+use crate::columnar::ColumnarError;
+use crate::columnar::ColumnarReader;
+use crate::plan::ColumnKey;
+use crate::traits::Row;
+
+// ============================================================================
+// SECTION: Import Errors
+// ============================================================================
+
+/// Errors raised while importing delimited text
+///
+/// # Invariants
+/// - None. Variants are self-contained error categories.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum CsvError {
+    /// The input has no header row.
+    EmptyInput,
+    /// A mapped header is absent from the header row.
+    MissingHeader {
+        /// The header that was not found.
+        header: String,
+    },
+    /// Two mapped headers target the same column key.
+    DuplicateColumn {
+        /// The column key mapped more than once.
+        column: ColumnKey,
+    },
+    /// A record has a different number of fields than the header row.
+    RowWidthMismatch {
+        /// 1-based line where the record starts.
+        line: usize,
+        /// Number of header fields.
+        expected: usize,
+        /// Number of fields in the record.
+        actual: usize,
+    },
+    /// A quoted field is not closed before the end of input.
+    UnterminatedQuote {
+        /// 1-based line where the record starts.
+        line: usize,
+    },
+    /// The imported columns could not be assembled into a reader.
+    Columnar(ColumnarError),
+}
+
+impl fmt::Display for CsvError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::EmptyInput => write!(f, "input has no header row"),
+            Self::MissingHeader {
+                header,
+            } => write!(f, "header `{header}` not found"),
+            Self::DuplicateColumn {
+                column,
+            } => write!(f, "column {} is mapped more than once", column.id()),
+            Self::RowWidthMismatch {
+                line,
+                expected,
+                actual,
+            } => write!(f, "line {line} has {actual} fields but the header has {expected}"),
+            Self::UnterminatedQuote {
+                line,
+            } => write!(f, "quoted field starting on line {line} is not closed"),
+            Self::Columnar(err) => write!(f, "{err}"),
+        }
+    }
+}
+
+impl std::error::Error for CsvError {}
+
+impl From<ColumnarError> for CsvError {
+    fn from(err: ColumnarError) -> Self {
+        Self::Columnar(err)
+    }
+}
+
+// ============================================================================
+// SECTION: Column Mapping
+// ============================================================================
+
+/// Value type a mapped column is parsed as
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum CsvType {
+    /// Parsed as `f32` into a [`Column::Float`]; non-finite values are missing.
+    Float,
+    /// Parsed as `i32` into a [`Column::Int`].
+    Int,
+    /// Parsed as `u64` into a [`Column::Flags`], in decimal or with a `0x` or
+    /// `0b` prefix.
+    Flags,
+}
+
+/// One header-to-column mapping
+#[derive(Debug, Clone, PartialEq, Eq)]
+struct CsvColumn {
+    /// Header text matched exactly against the header row.
+    header: String,
+    /// Column key plans reference.
+    key: ColumnKey,
+    /// Type cells are parsed as.
+    kind: CsvType,
+}
+
+// ============================================================================
+// SECTION: Importer
+// ============================================================================
+
+/// Imports delimited text with a header row into a [`ColumnarReader`]
+///
+/// # Invariants
+/// - Columns are only read from headers registered with [`Self::column`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct CsvImporter {
+    /// Field delimiter.
+    delimiter: char,
+    /// Registered header mappings, in registration order.
+    columns: Vec<CsvColumn>,
+}
+
+impl CsvImporter {
+    /// Creates an importer splitting fields on `delimiter`
+    #[must_use]
+    pub const fn new(delimiter: char) -> Self {
+        Self {
+            delimiter,
+            columns: Vec::new(),
+        }
+    }
+
+    /// Creates a comma-separated importer
+    #[must_use]
+    pub const fn csv() -> Self {
+        Self::new(',')
+    }
+
+    /// Creates a tab-separated importer
+    #[must_use]
+    pub const fn tsv() -> Self {
+        Self::new('\t')
+    }
+
+    /// Maps the column under `header` to `key`, parsing cells as `kind`
+    #[must_use]
+    pub fn column(mut self, header: impl Into<String>, key: ColumnKey, kind: CsvType) -> Self {
+        self.columns.push(CsvColumn {
+            header: header.into(),
+            key,
+            kind,
+        });
+        self
+    }
+
+    /// Imports `input`, whose first record is the header row
+    ///
+    /// # Errors
+    /// Returns [`CsvError`] when the header row is absent or lacks a mapped
+    /// header, a key is mapped twice, a record's width differs from the
+    /// header, or a quoted field is unterminated. Bad cell values never fail
+    /// the import; they are marked missing.
+    pub fn import(&self, input: &str) -> Result<ColumnarReader, CsvError> {
+        let mut records = split_records(input, self.delimiter)?.into_iter();
+        let (_, header) = records.next().ok_or(CsvError::EmptyInput)?;
+        let mut positions = Vec::with_capacity(self.columns.len());
+        for (index, column) in self.columns.iter().enumerate() {
+            if self.columns[.. index].iter().any(|earlier| earlier.key == column.key) {
+                return Err(CsvError::DuplicateColumn {
+                    column: column.key,
+                });
+            }
+            let position =
+                header.iter().position(|field| *field == column.header).ok_or_else(|| {
+                    CsvError::MissingHeader {
+                        header: column.header.clone(),
+                    }
+                })?;
+            positions.push(position);
+        }
+
+        let mut columns: Vec<Column> =
+            self.columns.iter().map(|column| empty(column.kind)).collect();
+        let mut missing: Vec<(ColumnKey, Row)> = Vec::new();
+        let mut len = 0;
+        for (line, fields) in records {
+            if fields.len() != header.len() {
+                return Err(CsvError::RowWidthMismatch {
+                    line,
+                    expected: header.len(),
+                    actual: fields.len(),
+                });
+            }
+            for ((column, target), position) in
+                self.columns.iter().zip(&mut columns).zip(&positions)
+            {
+                if !push_cell(target, fields[*position].trim()) {
+                    missing.push((column.key, len));
+                }
+            }
+            len += 1;
+        }
+
+        let mut reader = ColumnarReader::new(len);
+        for (column, values) in self.columns.iter().zip(columns) {
+            reader.insert_column(column.key, values)?;
+        }
+        for (key, row) in missing {
+            reader.mark_missing(key, row)?;
+        }
+        Ok(reader)
+    }
+}
+
+// ============================================================================
+// SECTION: Cell Parsing
+// ============================================================================
+
+/// Creates an empty column for `kind`
+const fn empty(kind: CsvType) -> Column {
+    match kind {
+        CsvType::Float => Column::Float(Vec::new()),
+        CsvType::Int => Column::Int(Vec::new()),
+        CsvType::Flags => Column::Flags(Vec::new()),
+    }
+}
+
+/// Appends the parsed `cell`, or a zero placeholder and `false` when it is
+/// empty or unparseable
+fn push_cell(column: &mut Column, cell: &str) -> bool {
+    match column {
+        Column::Float(values) => {
+            let value = cell.parse::<f32>().ok().filter(|value| value.is_finite());
+            values.push(value.unwrap_or(0.0));
+            value.is_some()
+        }
+        Column::Int(values) => {
+            let value = cell.parse::<i32>().ok();
+            values.push(value.unwrap_or(0));
+            value.is_some()
+        }
+        Column::Flags(values) => {
+            let value = parse_flags(cell);
+            values.push(value.unwrap_or(0));
+            value.is_some()
+        }
+    }
+}
+
+/// Parses a flag set in decimal, `0x` hexadecimal, or `0b` binary
+fn parse_flags(cell: &str) -> Option<u64> {
+    let (digits, radix) = match cell.get(.. 2) {
+        Some("0x" | "0X") => (&cell[2 ..], 16),
+        Some("0b" | "0B") => (&cell[2 ..], 2),
+        _ => (cell, 10),
+    };
+    u64::from_str_radix(digits, radix).ok()
+}
+
+// ============================================================================
+// SECTION: Record Splitting
+// ============================================================================
+
+/// Splits `input` into records of fields, each tagged with its 1-based start
+/// line
+///
+/// Blank lines outside quotes produce no record.
+fn split_records(input: &str, delimiter: char) -> Result<Vec<(usize, Vec<String>)>, CsvError> {
+    let mut records = Vec::new();
+    let mut fields = Vec::new();
+    let mut field = String::new();
+    let mut line = 1;
+    let mut record_line = 1;
+    let mut in_quotes = false;
+    let mut chars = input.chars().peekable();
+    while let Some(ch) = chars.next() {
+        if in_quotes {
+            match ch {
+                '"' if chars.peek() == Some(&'"') => {
+                    chars.next();
+                    field.push('"');
+                }
+                '"' => in_quotes = false,
+                _ => {
+                    if ch == '\n' {
+                        line += 1;
+                    }
+                    field.push(ch);
+                }
+            }
+            continue;
+        }
+        match ch {
+            '"' if field.trim().is_empty() => {
+                field.clear();
+                in_quotes = true;
+            }
+            '\r' if chars.peek() == Some(&'\n') => {}
+            '\n' => {
+                end_record(&mut records, &mut fields, &mut field, record_line);
+                line += 1;
+                record_line = line;
+            }
+            _ if ch == delimiter => fields.push(std::mem::take(&mut field)),
+            _ => field.push(ch),
+        }
+    }
+    if in_quotes {
+        return Err(CsvError::UnterminatedQuote {
+            line: record_line,
+        });
+    }
+    end_record(&mut records, &mut fields, &mut field, record_line);
+    Ok(records)
+}
+
+/// Closes the current record, dropping it when the line was blank
+fn end_record(
+    records: &mut Vec<(usize, Vec<String>)>,
+    fields: &mut Vec<String>,
+    field: &mut String,
+    line: usize,
+) {
+    if fields.is_empty() && field.trim().is_empty() {
+        field.clear();
+        return;
+    }
+    fields.push(std::mem::take(field));
+    records.push((line, std::mem::take(fields)));
+}
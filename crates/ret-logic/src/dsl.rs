@@ -2,17 +2,19 @@
 // ============================================================================
 // Module: Requirement DSL Parser
 // Description: Lightweight, author-facing DSL for requirement trees.
-// Purpose: Turn human-readable boolean expressions into `Requirement<P>` with
-//          validation and symbol resolution.
-// Dependencies: crate::requirement, crate::serde_support::RequirementValidator
+// Purpose: Turn human-readable boolean expressions into a serializable
+//          `RequirementAst` and, with symbol resolution, `Requirement<P>`.
+// Dependencies: crate::requirement, crate::serde_support::RequirementValidator, serde
 // ============================================================================
 
 //! ## Overview
 //!
 //! The DSL provides a compact, author-friendly syntax for building requirement
 //! trees without writing nested RON/JSON. It supports boolean composition
-//! (`and`, `or`, `not`), the `require_group`/`at_least` operator, and condition
-//! symbols that are resolved through a user-supplied [`ConditionResolver`].
+//! (`and`, `or`, `not`), implication (`implies`), the
+//! `require_group`/`at_least`, `require_exactly`/`exactly`, and
+//! `require_at_most`/`at_most` operators, and condition symbols that are
+//! resolved through a user-supplied [`ConditionResolver`].
 //! Security posture: DSL input is untrusted; enforce validation and limits per
 //! `Docs/security/threat_model.md`.
 //!
@@ -22,6 +24,9 @@
 //!   - Infix: `a && b`, `a || b`, `!a`
 //!   - Functions: `all(a, b, c)`, `any(a, b)`, `not(a)`
 //! - **Groups**: `at_least(2, a, b, c)` or `require_group(2, a, b, c)`
+//! - **Exact groups**: `exactly(2, a, b, c)` or `require_exactly(2, a, b, c)`
+//! - **Upper bounds**: `at_most(1, a, b, c)` or `require_at_most(1, a, b, c)`
+//! - **Implication**: `implies(a, b)` (antecedent first, then consequent)
 //! - **Parentheses**: `( ... )` for explicit grouping
 //!
 //! ### Example
@@ -44,12 +49,49 @@
 //!
 //! The parser validates structure (depth, group arity) using
 //! [`RequirementValidator`](crate::serde_support::RequirementValidator).
+//!
+//! ### Syntax Trees
+//!
+//! Parsing happens in two steps: the text becomes a [`RequirementAst`] that
+//! keeps condition names as strings, and the AST is then resolved into a
+//! [`Requirement`]. Authoring tools can stop after the first step with
+//! [`parse_requirement_ast`], exchange the AST as JSON, and resolve it later
+//! with [`RequirementAst::resolve`] without ever holding a resolver.
+//!
+//! ```
+//! use ret_logic::dsl::RequirementAst;
+//! use ret_logic::dsl::parse_requirement_ast;
+//!
+//! let ast = parse_requirement_ast("is_alive && !stunned").unwrap();
+//! let json = serde_json::to_string(&ast).unwrap();
+//! assert_eq!(json, r#"{"And":[{"Condition":"is_alive"},{"Not":{"Condition":"stunned"}}]}"#);
+//! assert_eq!(serde_json::from_str::<RequirementAst>(&json).unwrap(), ast);
+//! assert_eq!(ast.to_string(), "all(is_alive, not(stunned))");
+//! ```
+//!
+//! ### Formatting
+//!
+//! [`format_requirement`] re-emits an expression as canonical DSL: function
+//! form only, `all`/`any` members sorted, counted-group members kept in
+//! disclosure order, and long expressions broken one argument per line.
+//! Running it on its own output is a no-op.
+//!
+//! ```
+//! use ret_logic::dsl::format_requirement;
+//!
+//! let formatted = format_requirement("stunned || (is_alive && has_ap)").unwrap();
+//! assert_eq!(formatted, "any(all(has_ap, is_alive), stunned)");
+//! assert_eq!(format_requirement(&formatted).unwrap(), formatted);
+//! ```
 
 use std::collections::BTreeMap;
 use std::collections::HashMap;
 use std::fmt;
 use std::hash::BuildHasher;
 
+use serde::Deserialize;
+use serde::Serialize;
+
 use crate::requirement::Requirement;
 use crate::serde_support::RequirementValidator;
 
@@ -61,6 +103,10 @@ use crate::serde_support::RequirementValidator;
 const MAX_DSL_INPUT_BYTES: usize = 1024 * 1024;
 /// Maximum supported nesting depth for DSL expressions.
 const MAX_DSL_NESTING: usize = 32;
+/// Line width within which [`format_requirement`] keeps an expression inline.
+const FORMAT_WIDTH: usize = 80;
+/// Indentation added per nesting level by [`format_requirement`].
+const FORMAT_INDENT: &str = "    ";
 
 // ============================================================================
 // SECTION: Public API
@@ -89,6 +135,8 @@ pub enum DslError {
         actual_depth: usize,
         /// Byte offset in the original input.
         position: usize,
+        /// Byte range `(start, end)` of the offending input, when known.
+        span: Option<(usize, usize)>,
     },
     /// Unexpected token encountered during parsing.
     UnexpectedToken {
@@ -98,6 +146,8 @@ pub enum DslError {
         found: String,
         /// Byte offset in the original input.
         position: usize,
+        /// Byte range `(start, end)` of the offending input, when known.
+        span: Option<(usize, usize)>,
     },
     /// Condition symbol was not found in the resolver.
     UnknownCondition {
@@ -105,6 +155,8 @@ pub enum DslError {
         name: String,
         /// Byte offset in the original input.
         position: usize,
+        /// Byte range `(start, end)` of the offending input, when known.
+        span: Option<(usize, usize)>,
     },
     /// DSL function name was not recognized.
     UnknownFunction {
@@ -112,6 +164,8 @@ pub enum DslError {
         name: String,
         /// Byte offset in the original input.
         position: usize,
+        /// Byte range `(start, end)` of the offending input, when known.
+        span: Option<(usize, usize)>,
     },
     /// Numeric literal failed to parse or overflowed.
     InvalidNumber {
@@ -119,6 +173,8 @@ pub enum DslError {
         raw: String,
         /// Byte offset in the original input.
         position: usize,
+        /// Byte range `(start, end)` of the offending input, when known.
+        span: Option<(usize, usize)>,
     },
     /// Structural validation failed after parsing.
     Validation(String),
@@ -126,6 +182,8 @@ pub enum DslError {
     TrailingInput {
         /// Byte offset where unexpected input begins.
         position: usize,
+        /// Byte range `(start, end)` of the offending input, when known.
+        span: Option<(usize, usize)>,
     },
 }
 
@@ -143,6 +201,7 @@ impl fmt::Display for DslError {
                 max_depth,
                 actual_depth,
                 position,
+                ..
             } => write!(
                 f,
                 "input nesting exceeds limit: depth {actual_depth} (max {max_depth}) at {position}"
@@ -151,30 +210,34 @@ impl fmt::Display for DslError {
                 expected,
                 found,
                 position,
+                ..
             } => {
                 write!(f, "unexpected token `{found}` at {position}, expected {expected}")
             }
             Self::UnknownCondition {
                 name,
                 position,
+                ..
             } => {
                 write!(f, "unknown condition `{name}` at {position}")
             }
             Self::UnknownFunction {
                 name,
                 position,
+                ..
             } => {
                 write!(f, "unknown function `{name}` at {position}")
             }
             Self::InvalidNumber {
                 raw,
                 position,
+                ..
             } => {
                 write!(f, "invalid number `{raw}` at {position}")
             }
             Self::Validation(msg) => write!(f, "{msg}"),
             Self::TrailingInput {
-                position,
+                position, ..
             } => {
                 write!(f, "unexpected trailing input at {position}")
             }
@@ -182,6 +245,81 @@ impl fmt::Display for DslError {
     }
 }
 
+impl std::error::Error for DslError {}
+
+impl DslError {
+    /// Returns the byte range `(start, end)` of the offending input, when known.
+    #[must_use]
+    pub const fn span(&self) -> Option<(usize, usize)> {
+        match self {
+            Self::NestingTooDeep {
+                span, ..
+            }
+            | Self::UnexpectedToken {
+                span, ..
+            }
+            | Self::UnknownCondition {
+                span, ..
+            }
+            | Self::UnknownFunction {
+                span, ..
+            }
+            | Self::InvalidNumber {
+                span, ..
+            }
+            | Self::TrailingInput {
+                span, ..
+            } => *span,
+            Self::EmptyInput
+            | Self::InputTooLarge {
+                ..
+            }
+            | Self::Validation(_) => None,
+        }
+    }
+
+    /// Returns the 1-based `(line, column)` where the span starts in `source`.
+    ///
+    /// Columns count characters, not bytes. Returns `None` when the error has
+    /// no span or the span does not fall on a character boundary of `source`.
+    #[must_use]
+    pub fn line_column(&self, source: &str) -> Option<(usize, usize)> {
+        let (start, _) = self.span()?;
+        let before = source.get(.. start)?;
+        let line = before.matches('\n').count() + 1;
+        let line_start = before.rfind('\n').map_or(0, |index| index + 1);
+        Some((line, before[line_start ..].chars().count() + 1))
+    }
+
+    /// Renders the error with the offending source line and a caret underline.
+    ///
+    /// Spans that continue past the end of their first line are underlined to
+    /// the end of that line; an empty span (such as end of input) gets a single
+    /// caret. Errors without a span render as their [`Display`](fmt::Display)
+    /// message alone.
+    #[must_use]
+    pub fn render_with_source(&self, source: &str) -> String {
+        let (Some((start, end)), Some((line, column))) = (self.span(), self.line_column(source))
+        else {
+            return self.to_string();
+        };
+        let line_start = source[.. start].rfind('\n').map_or(0, |index| index + 1);
+        let line_end = source[start ..].find('\n').map_or(source.len(), |index| start + index);
+        let text = &source[line_start .. line_end];
+        let underline = source
+            .get(start .. end.min(line_end))
+            .map_or(0, |covered| covered.chars().count())
+            .max(1);
+        let gutter = " ".repeat(line.to_string().len());
+        format!(
+            "{self}\n{gutter}--> line {line}, column {column}\n{gutter} |\n{line} | \
+             {text}\n{gutter} | {}{}",
+            " ".repeat(column - 1),
+            "^".repeat(underline)
+        )
+    }
+}
+
 /// Resolves condition symbols to the domain-specific condition type `P`.
 ///
 /// Implement this for your symbol table so the DSL can turn identifiers into
@@ -212,6 +350,247 @@ where
     }
 }
 
+/// Unresolved syntax tree for a DSL expression.
+///
+/// Mirrors the shape of [`Requirement`] with condition symbols kept as
+/// strings, so it serializes to the same JSON as a `Requirement<String>` and
+/// can be stored or exchanged without a [`ConditionResolver`]. Its
+/// [`Display`](fmt::Display) output is canonical DSL text that parses back to
+/// an equal tree, provided every condition name is a DSL identifier.
+///
+/// # Invariants
+/// - Trees returned by [`parse_requirement_ast`] pass structural validation; deserialized trees are
+///   validated when resolved.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub enum RequirementAst {
+    /// Logical AND over the members.
+    And(Vec<Self>),
+    /// Logical OR over the members.
+    Or(Vec<Self>),
+    /// Logical NOT of the inner expression.
+    Not(Box<Self>),
+    /// At least `min` of the members must hold.
+    RequireGroup {
+        /// Minimum number of satisfied members.
+        min: u8,
+        /// Group members.
+        reqs: Vec<Self>,
+    },
+    /// Exactly `n` of the members must hold.
+    RequireExactly {
+        /// Exact number of satisfied members.
+        n: u8,
+        /// Group members.
+        reqs: Vec<Self>,
+    },
+    /// At most `max` of the members may hold.
+    RequireAtMost {
+        /// Maximum number of satisfied members.
+        max: u8,
+        /// Group members.
+        reqs: Vec<Self>,
+    },
+    /// The antecedent implies the consequent.
+    Implies {
+        /// Left-hand side of the implication.
+        antecedent: Box<Self>,
+        /// Right-hand side of the implication.
+        consequent: Box<Self>,
+    },
+    /// Condition symbol, resolved later through a [`ConditionResolver`].
+    Condition(String),
+}
+
+impl RequirementAst {
+    /// Resolves every condition symbol and returns a validated [`Requirement`].
+    ///
+    /// Use this for trees that did not come straight from DSL text, such as
+    /// deserialized JSON. Unknown symbols carry no source span.
+    ///
+    /// # Errors
+    /// Returns [`DslError::UnknownCondition`] for unresolved symbols and
+    /// [`DslError::Validation`] when the tree fails structural validation.
+    pub fn resolve<P, R>(&self, resolver: &R) -> Result<Requirement<P>, DslError>
+    where
+        R: ConditionResolver<P>,
+    {
+        let requirement = self.build(&mut |name: &str| {
+            resolver.resolve(name).ok_or_else(|| unknown_condition(name, None))
+        })?;
+        validate(&requirement)?;
+        Ok(requirement)
+    }
+
+    /// Returns the canonical function name, count, and members of a combinator.
+    ///
+    /// Returns `None` for condition leaves.
+    fn call_parts(&self) -> Option<(&'static str, Option<u8>, Vec<&Self>)> {
+        match self {
+            Self::And(reqs) => Some(("all", None, reqs.iter().collect())),
+            Self::Or(reqs) => Some(("any", None, reqs.iter().collect())),
+            Self::Not(inner) => Some(("not", None, vec![inner.as_ref()])),
+            Self::RequireGroup {
+                min,
+                reqs,
+            } => Some(("at_least", Some(*min), reqs.iter().collect())),
+            Self::RequireExactly {
+                n,
+                reqs,
+            } => Some(("exactly", Some(*n), reqs.iter().collect())),
+            Self::RequireAtMost {
+                max,
+                reqs,
+            } => Some(("at_most", Some(*max), reqs.iter().collect())),
+            Self::Implies {
+                antecedent,
+                consequent,
+            } => Some(("implies", None, vec![antecedent.as_ref(), consequent.as_ref()])),
+            Self::Condition(_) => None,
+        }
+    }
+
+    /// Returns the canonical form used by [`format_requirement`].
+    ///
+    /// Members of `all` and `any` are order-independent, so they are sorted by
+    /// their rendered text; `all` directly inside `all` (and `any` inside `any`)
+    /// is flattened into its parent. Counted groups keep their members in source
+    /// order because that order is the disclosure order, and `implies` keeps its
+    /// antecedent first.
+    fn canonicalize(self) -> Self {
+        match self {
+            Self::And(reqs) => Self::And(Self::sorted_members(reqs, FlattenKind::And)),
+            Self::Or(reqs) => Self::Or(Self::sorted_members(reqs, FlattenKind::Or)),
+            Self::Not(inner) => Self::Not(Box::new(inner.canonicalize())),
+            Self::RequireGroup {
+                min,
+                reqs,
+            } => Self::RequireGroup {
+                min,
+                reqs: reqs.into_iter().map(Self::canonicalize).collect(),
+            },
+            Self::RequireExactly {
+                n,
+                reqs,
+            } => Self::RequireExactly {
+                n,
+                reqs: reqs.into_iter().map(Self::canonicalize).collect(),
+            },
+            Self::RequireAtMost {
+                max,
+                reqs,
+            } => Self::RequireAtMost {
+                max,
+                reqs: reqs.into_iter().map(Self::canonicalize).collect(),
+            },
+            Self::Implies {
+                antecedent,
+                consequent,
+            } => Self::Implies {
+                antecedent: Box::new(antecedent.canonicalize()),
+                consequent: Box::new(consequent.canonicalize()),
+            },
+            Self::Condition(name) => Self::Condition(name),
+        }
+    }
+
+    /// Canonicalizes, flattens, and sorts `all`/`any` members.
+    fn sorted_members(reqs: Vec<Self>, flatten: FlattenKind) -> Vec<Self> {
+        let mut members = Vec::with_capacity(reqs.len());
+        for member in reqs.into_iter().map(Self::canonicalize) {
+            match (flatten, member) {
+                (FlattenKind::And, Self::And(inner)) | (FlattenKind::Or, Self::Or(inner)) => {
+                    members.extend(inner);
+                }
+                (_, member) => members.push(member),
+            }
+        }
+        members.sort_by_cached_key(ToString::to_string);
+        members
+    }
+
+    /// Appends the indented rendering of this node at `depth` to `out`.
+    fn write_formatted(&self, depth: usize, out: &mut String) {
+        let inline = self.to_string();
+        let fits = depth * FORMAT_INDENT.len() + inline.len() < FORMAT_WIDTH;
+        let Some((name, count, members)) = self.call_parts().filter(|_| !fits) else {
+            out.push_str(&inline);
+            return;
+        };
+        let indent = FORMAT_INDENT.repeat(depth + 1);
+        out.push_str(name);
+        out.push_str("(\n");
+        if let Some(count) = count {
+            out.push_str(&indent);
+            out.push_str(&count.to_string());
+            out.push_str(",\n");
+        }
+        for (index, member) in members.iter().enumerate() {
+            out.push_str(&indent);
+            member.write_formatted(depth + 1, out);
+            if index + 1 < members.len() {
+                out.push(',');
+            }
+            out.push('\n');
+        }
+        out.push_str(&FORMAT_INDENT.repeat(depth));
+        out.push(')');
+    }
+
+    /// Builds a requirement tree, mapping condition symbols in source order.
+    fn build<P>(
+        &self,
+        leaf: &mut impl FnMut(&str) -> Result<P, DslError>,
+    ) -> Result<Requirement<P>, DslError> {
+        let mut members = |reqs: &[Self]| -> Result<Vec<Requirement<P>>, DslError> {
+            reqs.iter().map(|member| member.build(leaf)).collect()
+        };
+        Ok(match self {
+            Self::And(reqs) => Requirement::and(members(reqs)?),
+            Self::Or(reqs) => Requirement::or(members(reqs)?),
+            Self::Not(inner) => Requirement::negate(inner.build(leaf)?),
+            Self::RequireGroup {
+                min,
+                reqs,
+            } => Requirement::require_group(*min, members(reqs)?),
+            Self::RequireExactly {
+                n,
+                reqs,
+            } => Requirement::require_exactly(*n, members(reqs)?),
+            Self::RequireAtMost {
+                max,
+                reqs,
+            } => Requirement::require_at_most(*max, members(reqs)?),
+            Self::Implies {
+                antecedent,
+                consequent,
+            } => Requirement::implies(antecedent.build(leaf)?, consequent.build(leaf)?),
+            Self::Condition(name) => Requirement::condition(leaf(name)?),
+        })
+    }
+}
+
+impl fmt::Display for RequirementAst {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let Some((name, count, members)) = self.call_parts() else {
+            return match self {
+                Self::Condition(name) => f.write_str(name),
+                _ => Ok(()),
+            };
+        };
+        write!(f, "{name}(")?;
+        if let Some(count) = count {
+            write!(f, "{count}")?;
+        }
+        for (index, member) in members.iter().enumerate() {
+            if index > 0 || count.is_some() {
+                f.write_str(", ")?;
+            }
+            write!(f, "{member}")?;
+        }
+        f.write_str(")")
+    }
+}
+
 /// Parses a DSL expression into a validated [`Requirement`] tree.
 ///
 /// # Arguments
@@ -223,9 +602,69 @@ where
 /// trailing input, or post-parse validation failures.
 pub fn parse_requirement<P, R>(input: &str, resolver: &R) -> Result<Requirement<P>, DslError>
 where
-    P: Clone,
     R: ConditionResolver<P>,
 {
+    let parsed = parse_syntax(input)?;
+    let mut spans = parsed.condition_spans.iter().copied();
+    let requirement = parsed.ast.build(&mut |name: &str| {
+        let span = spans.next();
+        resolver.resolve(name).ok_or_else(|| unknown_condition(name, span))
+    })?;
+    validate(&requirement)?;
+    Ok(requirement)
+}
+
+/// Parses a DSL expression into a validated [`RequirementAst`].
+///
+/// Condition symbols are kept as written; no resolver is consulted.
+///
+/// # Errors
+/// Returns [`DslError`] for syntax issues, invalid numbers, trailing input, or
+/// structural validation failures.
+pub fn parse_requirement_ast(input: &str) -> Result<RequirementAst, DslError> {
+    let parsed = parse_syntax(input)?;
+    validate(&parsed.ast.build(&mut |_: &str| Ok(()))?)?;
+    Ok(parsed.ast)
+}
+
+/// Parses a DSL expression and re-emits it as canonical, indented DSL.
+///
+/// The output spells every operator in function form (`all`, `any`, `not`,
+/// `implies`, `at_least`, `exactly`, `at_most`). Members of `all` and `any`
+/// are sorted and same-kind `all`/`any` nesting is flattened, neither of which
+/// changes what the requirement accepts. Counted groups keep their members in
+/// source order, since that order is the disclosure order, and `implies` keeps
+/// its operands in place. An expression that fits within 80 columns at its
+/// indentation stays on one line; otherwise each argument goes on its own
+/// line, indented four spaces. Formatting is idempotent.
+///
+/// # Errors
+/// Returns [`DslError`] when `source` fails [`parse_requirement_ast`].
+pub fn format_requirement(source: &str) -> Result<String, DslError> {
+    let mut out = String::new();
+    parse_requirement_ast(source)?.canonicalize().write_formatted(0, &mut out);
+    Ok(out)
+}
+
+/// Combinator kind flattened into its parent during canonicalization.
+#[derive(Debug, Clone, Copy)]
+enum FlattenKind {
+    /// `all` members nested in `all`.
+    And,
+    /// `any` members nested in `any`.
+    Or,
+}
+
+/// Syntax tree plus the source span of each condition symbol, in source order.
+struct ParsedSyntax {
+    /// Parsed syntax tree.
+    ast: RequirementAst,
+    /// Spans of `Condition` leaves in pre-order.
+    condition_spans: Vec<(usize, usize)>,
+}
+
+/// Lexes and parses `input` without resolving or validating it.
+fn parse_syntax(input: &str) -> Result<ParsedSyntax, DslError> {
     if input.len() > MAX_DSL_INPUT_BYTES {
         return Err(DslError::InputTooLarge {
             max_bytes: MAX_DSL_INPUT_BYTES,
@@ -235,15 +674,30 @@ where
     let mut lexer = Lexer::new(input);
     let tokens = lexer.lex()?;
 
-    let mut parser = Parser::new(input, tokens, resolver);
-    let requirement = parser.parse_expression()?;
+    let mut parser = Parser::new(input, tokens);
+    let ast = parser.parse_expression()?;
     parser.expect_eof()?;
 
+    Ok(ParsedSyntax {
+        ast,
+        condition_spans: parser.condition_spans,
+    })
+}
+
+/// Runs structural validation over a resolved tree.
+fn validate<P>(requirement: &Requirement<P>) -> Result<(), DslError> {
     RequirementValidator::with_defaults()
-        .validate(&requirement)
-        .map_err(|err| DslError::Validation(err.to_string()))?;
+        .validate(requirement)
+        .map_err(|err| DslError::Validation(err.to_string()))
+}
 
-    Ok(requirement)
+/// Builds an unknown-condition error, anchored at `span` when known.
+fn unknown_condition(name: &str, span: Option<(usize, usize)>) -> DslError {
+    DslError::UnknownCondition {
+        name: name.to_string(),
+        position: span.map_or(0, |(start, _)| start),
+        span,
+    }
 }
 
 // ============================================================================
@@ -280,6 +734,15 @@ struct SpannedToken<'a> {
     token: Token<'a>,
     /// Byte offset into the input.
     position: usize,
+    /// Byte offset just past the token.
+    end: usize,
+}
+
+impl SpannedToken<'_> {
+    /// Returns the token's byte range.
+    const fn span(&self) -> (usize, usize) {
+        (self.position, self.end)
+    }
 }
 
 /// Lexer for the requirements DSL.
@@ -311,42 +774,44 @@ impl<'a> Lexer<'a> {
                     self.offset += 1;
                 }
                 b'(' => {
-                    tokens.push(self.simple(Token::LParen));
+                    tokens.push(self.simple(Token::LParen, 1));
                     self.offset += 1;
                 }
                 b')' => {
-                    tokens.push(self.simple(Token::RParen));
+                    tokens.push(self.simple(Token::RParen, 1));
                     self.offset += 1;
                 }
                 b',' => {
-                    tokens.push(self.simple(Token::Comma));
+                    tokens.push(self.simple(Token::Comma, 1));
                     self.offset += 1;
                 }
                 b'!' => {
-                    tokens.push(self.simple(Token::Not));
+                    tokens.push(self.simple(Token::Not, 1));
                     self.offset += 1;
                 }
                 b'&' => {
                     if self.peek_char(bytes) == Some(b'&') {
-                        tokens.push(self.simple(Token::And));
+                        tokens.push(self.simple(Token::And, 2));
                         self.offset += 2;
                     } else {
                         return Err(DslError::UnexpectedToken {
                             expected: "&&",
                             found: "&".to_string(),
                             position: self.offset,
+                            span: Some((self.offset, self.offset + 1)),
                         });
                     }
                 }
                 b'|' => {
                     if self.peek_char(bytes) == Some(b'|') {
-                        tokens.push(self.simple(Token::Or));
+                        tokens.push(self.simple(Token::Or, 2));
                         self.offset += 2;
                     } else {
                         return Err(DslError::UnexpectedToken {
                             expected: "||",
                             found: "|".to_string(),
                             position: self.offset,
+                            span: Some((self.offset, self.offset + 1)),
                         });
                     }
                 }
@@ -357,6 +822,7 @@ impl<'a> Lexer<'a> {
                     tokens.push(SpannedToken {
                         token: Token::Number(slice),
                         position: start,
+                        end: self.offset,
                     });
                 }
                 b'a' ..= b'z' | b'A' ..= b'Z' | b'_' => {
@@ -366,13 +832,17 @@ impl<'a> Lexer<'a> {
                     tokens.push(SpannedToken {
                         token: Self::keyword_or_ident(slice),
                         position: start,
+                        end: self.offset,
                     });
                 }
                 _ => {
+                    let found =
+                        self.input[self.offset ..].chars().next().unwrap_or_else(|| char::from(ch));
                     return Err(DslError::UnexpectedToken {
                         expected: "identifier, number, or operator",
-                        found: char::from(ch).to_string(),
+                        found: found.to_string(),
                         position: self.offset,
+                        span: Some((self.offset, self.offset + found.len_utf8())),
                     });
                 }
             }
@@ -385,15 +855,17 @@ impl<'a> Lexer<'a> {
         tokens.push(SpannedToken {
             token: Token::Eof,
             position: self.offset,
+            end: self.offset,
         });
         Ok(tokens)
     }
 
-    /// Builds a token at the current offset.
-    const fn simple(&self, token: Token<'a>) -> SpannedToken<'a> {
+    /// Builds a `len`-byte token at the current offset.
+    const fn simple(&self, token: Token<'a>, len: usize) -> SpannedToken<'a> {
         SpannedToken {
             token,
             position: self.offset,
+            end: self.offset + len,
         }
     }
 
@@ -432,49 +904,38 @@ impl<'a> Lexer<'a> {
 // ============================================================================
 
 /// Recursive-descent parser for the requirements DSL.
-struct Parser<'input, 'resolver, P, R> {
+struct Parser<'input> {
     /// Original input string (for diagnostics).
-    _input: &'input str,
+    input: &'input str,
     /// Token stream with source positions.
     tokens: Vec<SpannedToken<'input>>,
     /// Current token index.
     index: usize,
-    /// Condition resolver for identifiers.
-    resolver: &'resolver R,
     /// Current nesting depth for bracketed or function expressions.
     nesting: usize,
-    /// Marker for the condition type.
-    _marker: std::marker::PhantomData<P>,
+    /// Spans of condition symbols in source order.
+    condition_spans: Vec<(usize, usize)>,
 }
 
-impl<'input, 'resolver, P, R> Parser<'input, 'resolver, P, R>
-where
-    P: Clone,
-    R: ConditionResolver<P>,
-{
+impl<'input> Parser<'input> {
     /// Creates a parser over the token stream.
-    const fn new(
-        input: &'input str,
-        tokens: Vec<SpannedToken<'input>>,
-        resolver: &'resolver R,
-    ) -> Self {
+    const fn new(input: &'input str, tokens: Vec<SpannedToken<'input>>) -> Self {
         Self {
-            _input: input,
+            input,
             tokens,
             index: 0,
-            resolver,
             nesting: 0,
-            _marker: std::marker::PhantomData,
+            condition_spans: Vec::new(),
         }
     }
 
     /// Parses a full expression.
-    fn parse_expression(&mut self) -> Result<Requirement<P>, DslError> {
+    fn parse_expression(&mut self) -> Result<RequirementAst, DslError> {
         self.parse_or()
     }
 
     /// Parses OR expressions.
-    fn parse_or(&mut self) -> Result<Requirement<P>, DslError> {
+    fn parse_or(&mut self) -> Result<RequirementAst, DslError> {
         let mut parts = Vec::new();
         parts.push(self.parse_and()?);
 
@@ -482,11 +943,11 @@ where
             parts.push(self.parse_and()?);
         }
 
-        if parts.len() == 1 { Ok(parts.remove(0)) } else { Ok(Requirement::or(parts)) }
+        if parts.len() == 1 { Ok(parts.remove(0)) } else { Ok(RequirementAst::Or(parts)) }
     }
 
     /// Parses AND expressions.
-    fn parse_and(&mut self) -> Result<Requirement<P>, DslError> {
+    fn parse_and(&mut self) -> Result<RequirementAst, DslError> {
         let mut parts = Vec::new();
         parts.push(self.parse_unary()?);
 
@@ -494,35 +955,35 @@ where
             parts.push(self.parse_unary()?);
         }
 
-        if parts.len() == 1 { Ok(parts.remove(0)) } else { Ok(Requirement::and(parts)) }
+        if parts.len() == 1 { Ok(parts.remove(0)) } else { Ok(RequirementAst::And(parts)) }
     }
 
     /// Parses unary expressions, including NOT.
-    fn parse_unary(&mut self) -> Result<Requirement<P>, DslError> {
+    fn parse_unary(&mut self) -> Result<RequirementAst, DslError> {
         if self.matches(Token::Not) {
             let requirement = self.parse_unary()?;
-            return Ok(Requirement::negate(requirement));
+            return Ok(RequirementAst::Not(Box::new(requirement)));
         }
         self.parse_primary()
     }
 
     /// Parses a primary expression.
-    fn parse_primary(&mut self) -> Result<Requirement<P>, DslError> {
+    fn parse_primary(&mut self) -> Result<RequirementAst, DslError> {
         match self.current().token {
             Token::Ident(name) => {
-                let pos = self.current().position;
+                let span = self.current().span();
                 self.advance();
 
                 if self.matches(Token::LParen) {
-                    self.parse_function(name, pos)
+                    self.parse_function(name, span)
                 } else {
-                    self.resolve_condition(name, pos)
+                    Ok(self.condition(name, span))
                 }
             }
             Token::LParen => {
-                let pos = self.current().position;
+                let span = self.current().span();
                 self.advance();
-                self.with_nesting(pos, |parser| {
+                self.with_nesting(span, |parser| {
                     let expr = parser.parse_expression()?;
                     parser.expect(Token::RParen, "`)`")?;
                     Ok(expr)
@@ -532,12 +993,14 @@ where
                 expected: "identifier or `(`",
                 found: raw.to_string(),
                 position: self.current().position,
+                span: Some(self.current().span()),
             }),
             Token::RParen | Token::Comma | Token::And | Token::Or | Token::Not | Token::Eof => {
                 Err(DslError::UnexpectedToken {
                     expected: "condition or expression",
                     found: self.describe_current(),
                     position: self.current().position,
+                    span: Some(self.current().span()),
                 })
             }
         }
@@ -547,17 +1010,37 @@ where
     fn parse_function(
         &mut self,
         name: &'input str,
-        name_pos: usize,
-    ) -> Result<Requirement<P>, DslError> {
-        self.with_nesting(name_pos, |parser| match name {
-            "at_least" | "require_group" => parser.parse_group(name_pos),
+        name_span: (usize, usize),
+    ) -> Result<RequirementAst, DslError> {
+        self.with_nesting(name_span, |parser| match name {
+            "at_least" | "require_group" => {
+                let (min, members) = parser.parse_group("`)` after `at_least(...)`")?;
+                Ok(RequirementAst::RequireGroup {
+                    min,
+                    reqs: members,
+                })
+            }
+            "exactly" | "require_exactly" => {
+                let (n, members) = parser.parse_group("`)` after `exactly(...)`")?;
+                Ok(RequirementAst::RequireExactly {
+                    n,
+                    reqs: members,
+                })
+            }
+            "at_most" | "require_at_most" => {
+                let (max, members) = parser.parse_group("`)` after `at_most(...)`")?;
+                Ok(RequirementAst::RequireAtMost {
+                    max,
+                    reqs: members,
+                })
+            }
             "all" | "and" => {
                 let args = parser.parse_argument_list()?;
-                Ok(Requirement::and(args))
+                Ok(RequirementAst::And(args))
             }
             "any" | "or" => {
                 let args = parser.parse_argument_list()?;
-                Ok(Requirement::or(args))
+                Ok(RequirementAst::Or(args))
             }
             "not" => {
                 let args = parser.parse_argument_list()?;
@@ -565,36 +1048,59 @@ where
                     return Err(DslError::UnexpectedToken {
                         expected: "exactly one argument to `not(...)`",
                         found: format!("{} arguments", args.len()),
-                        position: name_pos,
+                        position: name_span.0,
+                        span: Some(name_span),
                     });
                 }
                 let requirement =
                     args.into_iter().next().ok_or_else(|| DslError::UnexpectedToken {
                         expected: "exactly one argument to `not(...)`",
                         found: "0 arguments".to_string(),
-                        position: name_pos,
+                        position: name_span.0,
+                        span: Some(name_span),
+                    })?;
+                Ok(RequirementAst::Not(Box::new(requirement)))
+            }
+            "implies" => {
+                let args = parser.parse_argument_list()?;
+                let count = args.len();
+                let [antecedent, consequent] =
+                    <[RequirementAst; 2]>::try_from(args).map_err(|_| {
+                        DslError::UnexpectedToken {
+                            expected: "exactly two arguments to `implies(...)`",
+                            found: format!("{count} arguments"),
+                            position: name_span.0,
+                            span: Some(name_span),
+                        }
                     })?;
-                Ok(Requirement::negate(requirement))
+                Ok(RequirementAst::Implies {
+                    antecedent: Box::new(antecedent),
+                    consequent: Box::new(consequent),
+                })
             }
             _ => {
                 let args = parser.parse_argument_list()?;
                 if args.is_empty() {
                     // Allow zero-arg condition calls like `is_alive()`.
-                    return parser.resolve_condition(name, name_pos);
+                    return Ok(parser.condition(name, name_span));
                 }
 
                 Err(DslError::UnknownFunction {
                     name: name.to_string(),
-                    position: name_pos,
+                    position: name_span.0,
+                    span: Some(name_span),
                 })
             }
         })
     }
 
-    /// Parses a require-group expression with minimum count.
-    fn parse_group(&mut self, _name_pos: usize) -> Result<Requirement<P>, DslError> {
+    /// Parses the count and members of a group expression.
+    fn parse_group(
+        &mut self,
+        closing: &'static str,
+    ) -> Result<(u8, Vec<RequirementAst>), DslError> {
         // First argument must be a numeric literal.
-        let (min, min_pos) = self.parse_number_literal()?;
+        let (min, min_span) = self.parse_number_literal()?;
         if self.matches(Token::Comma) {
             // consume comma between count and first condition
         }
@@ -604,7 +1110,8 @@ where
             return Err(DslError::UnexpectedToken {
                 expected: "at least one condition after the count",
                 found: ")".to_string(),
-                position: min_pos,
+                position: min_span.0,
+                span: Some(min_span),
             });
         }
 
@@ -613,18 +1120,19 @@ where
             if self.matches(Token::Comma) {
                 continue;
             }
-            self.expect(Token::RParen, "`)` after `at_least(...)`")?;
+            self.expect(Token::RParen, closing)?;
             break;
         }
 
-        Ok(Requirement::require_group(min, members))
+        Ok((min, members))
     }
 
-    /// Parses a numeric literal for group counts.
-    fn parse_number_literal(&mut self) -> Result<(u8, usize), DslError> {
+    /// Parses a numeric literal for group counts, returning it with its span.
+    fn parse_number_literal(&mut self) -> Result<(u8, (usize, usize)), DslError> {
         let SpannedToken {
             token,
             position,
+            end,
         } = *self.current();
 
         match token {
@@ -634,19 +1142,21 @@ where
                 let value: u8 = raw.parse().map_err(|_| DslError::InvalidNumber {
                     raw: raw.to_string(),
                     position,
+                    span: Some((position, end)),
                 })?;
-                Ok((value, position))
+                Ok((value, (position, end)))
             }
             _ => Err(DslError::UnexpectedToken {
                 expected: "numeric literal",
                 found: self.describe_current(),
                 position,
+                span: Some((position, end)),
             }),
         }
     }
 
     /// Parses a comma-separated argument list.
-    fn parse_argument_list(&mut self) -> Result<Vec<Requirement<P>>, DslError> {
+    fn parse_argument_list(&mut self) -> Result<Vec<RequirementAst>, DslError> {
         let mut args = Vec::new();
         if self.matches(Token::RParen) {
             return Ok(args);
@@ -666,7 +1176,7 @@ where
     /// Runs a parser step while enforcing the nesting limit.
     fn with_nesting<T>(
         &mut self,
-        position: usize,
+        span: (usize, usize),
         f: impl FnOnce(&mut Self) -> Result<T, DslError>,
     ) -> Result<T, DslError> {
         let next_depth = self.nesting + 1;
@@ -674,7 +1184,8 @@ where
             return Err(DslError::NestingTooDeep {
                 max_depth: MAX_DSL_NESTING,
                 actual_depth: next_depth,
-                position,
+                position: span.0,
+                span: Some(span),
             });
         }
         self.nesting = next_depth;
@@ -683,18 +1194,10 @@ where
         result
     }
 
-    /// Resolves a condition identifier using the resolver.
-    fn resolve_condition(
-        &self,
-        name: &'input str,
-        position: usize,
-    ) -> Result<Requirement<P>, DslError> {
-        self.resolver.resolve(name).map(Requirement::condition).ok_or_else(|| {
-            DslError::UnknownCondition {
-                name: name.to_string(),
-                position,
-            }
-        })
+    /// Records a condition identifier and its span.
+    fn condition(&mut self, name: &'input str, span: (usize, usize)) -> RequirementAst {
+        self.condition_spans.push(span);
+        RequirementAst::Condition(name.to_string())
     }
 
     /// Consumes the expected token or returns an error.
@@ -707,6 +1210,7 @@ where
                 expected,
                 found: self.describe_current(),
                 position: self.current().position,
+                span: Some(self.current().span()),
             })
         }
     }
@@ -718,6 +1222,7 @@ where
         } else {
             Err(DslError::TrailingInput {
                 position: self.current().position,
+                span: Some((self.current().position, self.input.trim_end().len())),
             })
         }
     }
@@ -3,7 +3,7 @@
 // Module: Requirement Executor
 // Description: Prepared plan execution infrastructure for requirements.
 // Purpose: Run compiled plans using domain dispatch tables and shared helpers.
-// Dependencies: crate::{error, plan, traits}
+// Dependencies: crate::{error, plan, traits, tristate}
 // ============================================================================
 
 //! ## Overview
@@ -11,7 +11,12 @@
 //! exposing helpers for dispatch table construction and optimized operation
 //! implementations. Domains implement [`crate::traits::ConditionEval`] for
 //! [`PlanExecutor`] via their reader types.
-//! Malformed plans or missing opcode handlers fail closed by returning `false`.
+//! Malformed plans or missing opcode handlers fail closed by returning `false`;
+//! an implication frame must hold exactly two operands and no `Not`.
+//! [`PlanExecutor::evaluate_lazy`] is a tri-state path for expensive handlers
+//! that skips operations once their enclosing group is decided, and
+//! [`PlanExecutor::eval_stream`] applies it row by row over a lazily pulled
+//! stream of reader chunks.
 
 // ============================================================================
 // SECTION: Imports
@@ -25,7 +30,11 @@ use super::plan::Operation;
 use super::plan::Plan;
 use super::traits::BatchConditionEval;
 use super::traits::ConditionEval;
+use super::traits::ReaderLen;
 use super::traits::Row;
+use super::tristate::LogicMode;
+use super::tristate::TriLogic;
+use super::tristate::TriState;
 
 // ============================================================================
 // SECTION: Type Aliases
@@ -50,24 +59,95 @@ enum CombineMode {
     And,
     /// Logical OR with short-circuit identity of false.
     Or,
+    /// Implication over exactly two operands, antecedent first.
+    Implies,
 }
 
 impl CombineMode {
+    /// Returns the mode of the frame opened by `opcode`, if it opens one.
+    const fn opened_by(opcode: OpCode) -> Option<Self> {
+        match opcode {
+            OpCode::AndStart => Some(Self::And),
+            OpCode::OrStart => Some(Self::Or),
+            OpCode::ImpliesStart => Some(Self::Implies),
+            _ => None,
+        }
+    }
+
+    /// Returns the mode of the frame closed by `opcode`, if it closes one.
+    const fn closed_by(opcode: OpCode) -> Option<Self> {
+        match opcode {
+            OpCode::AndEnd => Some(Self::And),
+            OpCode::OrEnd => Some(Self::Or),
+            OpCode::ImpliesEnd => Some(Self::Implies),
+            _ => None,
+        }
+    }
+
     /// Returns the identity value for the combine operator.
+    ///
+    /// An implication frame has no identity; its first operand replaces the value.
     const fn identity(self) -> bool {
         match self {
-            Self::And => true,
+            Self::And | Self::Implies => true,
             Self::Or => false,
         }
     }
 
-    /// Combines two boolean values using the configured operator.
-    const fn combine(self, lhs: bool, rhs: bool) -> bool {
+    /// Folds the operand at position `index` into the accumulated value.
+    ///
+    /// Returns `None` when an implication frame already holds both operands.
+    const fn fold(self, acc: bool, index: u8, value: bool) -> Option<bool> {
+        match (self, index) {
+            (Self::And, _) => Some(acc && value),
+            (Self::Or, _) => Some(acc || value),
+            (Self::Implies, 0) => Some(value),
+            (Self::Implies, 1) => Some(!acc || value),
+            (Self::Implies, _) => None,
+        }
+    }
+
+    /// Returns the tri-state identity value for the combine operator.
+    const fn tri_identity(self) -> TriState {
+        match self {
+            Self::And | Self::Implies => TriState::True,
+            Self::Or => TriState::False,
+        }
+    }
+
+    /// Folds the tri-state operand at position `index` using the given logic.
+    ///
+    /// Returns `None` when an implication frame already holds both operands.
+    fn fold_tri(
+        self,
+        logic: LogicMode,
+        acc: TriState,
+        index: u8,
+        value: TriState,
+    ) -> Option<TriState> {
+        match (self, index) {
+            (Self::And, _) => Some(logic.and(acc, value)),
+            (Self::Or, _) => Some(logic.or(acc, value)),
+            (Self::Implies, 0) => Some(value),
+            (Self::Implies, 1) => Some(logic.implies(acc, value)),
+            (Self::Implies, _) => None,
+        }
+    }
+
+    /// Returns true when a frame that folded `operands` values may be closed.
+    const fn is_complete(self, operands: u8) -> bool {
         match self {
-            Self::And => lhs && rhs,
-            Self::Or => lhs || rhs,
+            Self::And | Self::Or => true,
+            Self::Implies => operands == 2,
         }
     }
+
+    /// Returns true when `value` absorbs any further operand under Kleene logic.
+    ///
+    /// Implication frames are never decided early, so both operands are evaluated.
+    const fn is_decided(self, value: TriState) -> bool {
+        matches!((self, value), (Self::And, TriState::False) | (Self::Or, TriState::True))
+    }
 }
 
 // ============================================================================
@@ -75,7 +155,7 @@ impl CombineMode {
 // ============================================================================
 
 /// Maximum stack depth allowed during plan execution.
-const MAX_PLAN_STACK_DEPTH: usize = 64;
+pub(crate) const MAX_PLAN_STACK_DEPTH: usize = 64;
 
 // ============================================================================
 // SECTION: Plan Executor
@@ -130,6 +210,156 @@ impl<R: 'static> PlanExecutor<R> {
     pub fn required_columns(&self) -> &[super::plan::ColumnKey] {
         self.plan.required_columns()
     }
+
+    /// Evaluates the plan for one row with tri-state semantics, skipping
+    /// operations that cannot change the result
+    ///
+    /// Intended for dispatch handlers that wrap expensive IO (HTTP evidence,
+    /// remote lookups). Handler errors become [`TriState::Unknown`] rather than
+    /// `false`, and missing handlers likewise evaluate to `Unknown`.
+    ///
+    /// When [`LogicMode::short_circuits`] holds (Kleene, Łukasiewicz), once an AND group is
+    /// `False` or an OR group is `True` the remaining leaves and nested groups
+    /// of that group are not dispatched; a trailing `Not` still applies. An
+    /// `Unknown` never decides a group, so siblings after it are evaluated.
+    /// Under Bochvar every handler runs because a later `Unknown` would still
+    /// change the result.
+    ///
+    /// An implication frame (`ImpliesStart`/`ImpliesEnd`) combines its
+    /// antecedent and consequent with [`TriLogic::implies`], so the logic mode
+    /// decides `Unknown` operands; under Łukasiewicz `Unknown -> Unknown` is
+    /// `True`. Implication frames are never decided early, so both operands
+    /// are dispatched.
+    ///
+    /// # Arguments
+    /// * `reader` - Reader passed to dispatch handlers
+    /// * `row` - Row to evaluate
+    /// * `logic` - Tri-state logic used to combine results
+    ///
+    /// # Returns
+    /// The tri-state result, or `False` for malformed plans
+    #[must_use]
+    pub fn evaluate_lazy(&self, reader: &R, row: Row, logic: LogicMode) -> TriState {
+        let short_circuit = logic.short_circuits();
+        let mut stack_values = [TriState::True; MAX_PLAN_STACK_DEPTH];
+        let mut stack_modes = [CombineMode::And; MAX_PLAN_STACK_DEPTH];
+        let mut stack_operands = [0u8; MAX_PLAN_STACK_DEPTH];
+        let mut stack_pointer = 0usize;
+        // Depth of the decided group whose nested groups are being skipped
+        let mut skip_above: Option<usize> = None;
+
+        for operation in self.plan.operations() {
+            let decided = skip_above.is_some()
+                || (short_circuit
+                    && stack_modes[stack_pointer].is_decided(stack_values[stack_pointer]));
+            if let Some(mode) = CombineMode::opened_by(operation.opcode) {
+                if decided && skip_above.is_none() {
+                    skip_above = Some(stack_pointer);
+                }
+                stack_pointer += 1;
+                if stack_pointer >= stack_values.len() {
+                    return TriState::False;
+                }
+                stack_modes[stack_pointer] = mode;
+                stack_values[stack_pointer] = mode.tri_identity();
+                stack_operands[stack_pointer] = 0;
+                continue;
+            }
+
+            if let Some(expected) = CombineMode::closed_by(operation.opcode) {
+                if stack_pointer == 0 || stack_modes[stack_pointer] != expected {
+                    return TriState::False;
+                }
+                if skip_above.is_none() && !expected.is_complete(stack_operands[stack_pointer]) {
+                    return TriState::False;
+                }
+                let group_result = stack_values[stack_pointer];
+                stack_pointer -= 1;
+                if skip_above == Some(stack_pointer) {
+                    skip_above = None;
+                } else if skip_above.is_none() {
+                    let Some(value) = stack_modes[stack_pointer].fold_tri(
+                        logic,
+                        stack_values[stack_pointer],
+                        stack_operands[stack_pointer],
+                        group_result,
+                    ) else {
+                        return TriState::False;
+                    };
+                    stack_values[stack_pointer] = value;
+                    stack_operands[stack_pointer] = stack_operands[stack_pointer].saturating_add(1);
+                }
+                continue;
+            }
+
+            if operation.opcode == OpCode::Not {
+                // Negation applies to an accumulated group value, which an
+                // implication frame does not have
+                if stack_modes[stack_pointer] == CombineMode::Implies {
+                    return TriState::False;
+                }
+                if skip_above.is_none() {
+                    stack_values[stack_pointer] = logic.not(stack_values[stack_pointer]);
+                }
+                continue;
+            }
+
+            if decided {
+                continue;
+            }
+            let opcode_index = usize::from(operation.opcode.as_u8());
+            let result = self.eval_table[opcode_index]
+                .and_then(|eval_fn| eval_fn(reader, row, *operation, &self.plan.constants).ok())
+                .map_or(TriState::Unknown, TriState::from);
+            let Some(value) = stack_modes[stack_pointer].fold_tri(
+                logic,
+                stack_values[stack_pointer],
+                stack_operands[stack_pointer],
+                result,
+            ) else {
+                return TriState::False;
+            };
+            stack_values[stack_pointer] = value;
+            stack_operands[stack_pointer] = stack_operands[stack_pointer].saturating_add(1);
+        }
+
+        if stack_pointer != 0 {
+            return TriState::False;
+        }
+
+        stack_values[0]
+    }
+
+    /// Lazily evaluates every row of a stream of reader chunks with
+    /// [`Self::evaluate_lazy`]
+    ///
+    /// Chunks are pulled from `chunks` only once every row of the previous
+    /// chunk has been yielded, so at most one chunk is held at a time and
+    /// memory stays bounded regardless of the total row count. Rows are
+    /// numbered from zero within each chunk, matching the dispatch handlers'
+    /// view of their reader.
+    ///
+    /// # Arguments
+    /// * `chunks` - Source of reader chunks, consumed on demand
+    /// * `logic` - Tri-state logic used to combine results
+    ///
+    /// # Returns
+    /// An iterator yielding one tri-state result per row, in stream order
+    pub fn eval_stream<'a, I>(
+        &'a self,
+        chunks: I,
+        logic: LogicMode,
+    ) -> impl Iterator<Item = TriState> + 'a
+    where
+        I: IntoIterator<Item = R>,
+        I::IntoIter: 'a,
+        R: ReaderLen,
+    {
+        chunks.into_iter().flat_map(move |chunk| {
+            let rows = chunk.len();
+            (0 .. rows).map(move |row| self.evaluate_lazy(&chunk, row, logic))
+        })
+    }
 }
 
 // ============================================================================
@@ -144,6 +374,7 @@ impl<R: 'static> ConditionEval for PlanExecutor<R> {
         let mut stack_values: [bool; MAX_PLAN_STACK_DEPTH] = [false; MAX_PLAN_STACK_DEPTH];
         let mut stack_modes: [CombineMode; MAX_PLAN_STACK_DEPTH] =
             [CombineMode::And; MAX_PLAN_STACK_DEPTH];
+        let mut stack_operands = [0u8; MAX_PLAN_STACK_DEPTH];
         let mut stack_pointer = 0usize;
 
         stack_modes[0] = CombineMode::And;
@@ -151,80 +382,69 @@ impl<R: 'static> ConditionEval for PlanExecutor<R> {
 
         // Execute operations in sequence
         for operation in self.plan.operations() {
-            match operation.opcode {
-                OpCode::AndStart => {
-                    // Push a new AND context
+            let result = match operation.opcode {
+                OpCode::AndStart | OpCode::OrStart | OpCode::ImpliesStart => {
+                    // Push a new group context
                     stack_pointer += 1;
                     if stack_pointer >= stack_values.len() {
                         // Stack overflow protection - treat as false
                         return false;
                     }
-                    stack_modes[stack_pointer] = CombineMode::And;
-                    stack_values[stack_pointer] = CombineMode::And.identity();
-                }
-
-                OpCode::AndEnd => {
-                    // Pop AND context and combine with parent
-                    if stack_pointer == 0 || stack_modes[stack_pointer] != CombineMode::And {
-                        // Malformed plan - no matching start
+                    let Some(mode) = CombineMode::opened_by(operation.opcode) else {
                         return false;
-                    }
-                    let and_result = stack_values[stack_pointer];
-                    stack_pointer -= 1;
-                    stack_values[stack_pointer] =
-                        stack_modes[stack_pointer].combine(stack_values[stack_pointer], and_result);
+                    };
+                    stack_modes[stack_pointer] = mode;
+                    stack_values[stack_pointer] = mode.identity();
+                    stack_operands[stack_pointer] = 0;
+                    continue;
                 }
 
-                OpCode::OrStart => {
-                    // Push a new OR context
-                    stack_pointer += 1;
-                    if stack_pointer >= stack_values.len() {
-                        // Stack overflow protection - treat as false
-                        return false;
-                    }
-                    stack_modes[stack_pointer] = CombineMode::Or;
-                    stack_values[stack_pointer] = CombineMode::Or.identity();
-                }
-
-                OpCode::OrEnd => {
-                    // Pop OR context and combine with parent
-                    if stack_pointer == 0 || stack_modes[stack_pointer] != CombineMode::Or {
-                        // Malformed plan - no matching start
+                OpCode::AndEnd | OpCode::OrEnd | OpCode::ImpliesEnd => {
+                    // Pop the group context and combine with parent
+                    let expected = CombineMode::closed_by(operation.opcode);
+                    if stack_pointer == 0
+                        || Some(stack_modes[stack_pointer]) != expected
+                        || !stack_modes[stack_pointer].is_complete(stack_operands[stack_pointer])
+                    {
+                        // Malformed plan - no matching start or missing operands
                         return false;
                     }
-                    let or_result = stack_values[stack_pointer];
+                    let group_result = stack_values[stack_pointer];
                     stack_pointer -= 1;
-                    stack_values[stack_pointer] =
-                        stack_modes[stack_pointer].combine(stack_values[stack_pointer], or_result);
+                    group_result
                 }
 
                 OpCode::Not => {
-                    // NOT operation inverts the current context
+                    // NOT operation inverts the current context; an implication
+                    // frame has no accumulated value to invert
+                    if stack_modes[stack_pointer] == CombineMode::Implies {
+                        return false;
+                    }
                     stack_values[stack_pointer] = !stack_values[stack_pointer];
+                    continue;
                 }
 
                 _ => {
-                    // Domain-specific operation - delegate to dispatch table
+                    // Domain-specific operation - delegate to dispatch table.
+                    // Evaluation errors and missing handlers are treated as false
+                    // to keep fail-closed semantics.
                     let opcode_index = usize::from(operation.opcode.as_u8());
-                    if let Some(eval_fn) = self.eval_table[opcode_index] {
-                        match eval_fn(reader, row, *operation, &self.plan.constants) {
-                            Ok(result) => {
-                                stack_values[stack_pointer] = stack_modes[stack_pointer]
-                                    .combine(stack_values[stack_pointer], result);
-                            }
-                            Err(_) => {
-                                // Evaluation error - treat as false and keep fail-closed semantics
-                                stack_values[stack_pointer] = stack_modes[stack_pointer]
-                                    .combine(stack_values[stack_pointer], false);
-                            }
-                        }
-                    } else {
-                        // Missing handler - treat as false and keep fail-closed semantics
-                        stack_values[stack_pointer] =
-                            stack_modes[stack_pointer].combine(stack_values[stack_pointer], false);
-                    }
+                    self.eval_table[opcode_index].is_some_and(|eval_fn| {
+                        eval_fn(reader, row, *operation, &self.plan.constants).unwrap_or(false)
+                    })
                 }
-            }
+            };
+
+            let Some(value) = stack_modes[stack_pointer].fold(
+                stack_values[stack_pointer],
+                stack_operands[stack_pointer],
+                result,
+            ) else {
+                // Implication with more than two operands - fail closed
+                return false;
+            };
+            stack_values[stack_pointer] = value;
+            stack_operands[stack_pointer] = stack_operands[stack_pointer].saturating_add(1);
         }
 
         if stack_pointer != 0 {
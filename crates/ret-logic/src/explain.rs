@@ -0,0 +1,426 @@
+// crates/ret-logic/src/explain.rs
+// ============================================================================
+// Module: Requirement Explanations
+// Description: Result trees explaining tri-state requirement evaluation.
+// Purpose: Show which conditions and operators produced a requirement outcome.
+// Dependencies: crate::{requirement, tristate}, serde::{Deserialize, Serialize}, std::fmt
+// ============================================================================
+
+//! ## Overview
+//! [`ExplanationTrace`] is a [`RequirementTrace`] that rebuilds the evaluated
+//! requirement as a tree of [`ExplanationNode`]s from post-order trace hooks.
+//! Each node records its operator, its result, and its children's results, so
+//! callers can report `require_group(2 of 3): false (satisfied 1, ...)` instead
+//! of a bare `false`. Use [`crate::Requirement::eval_tristate_with_explanation`]
+//! for the one-call form.
+
+// ============================================================================
+// SECTION: Imports
+// ============================================================================
+
+use std::fmt;
+use std::num::NonZeroU64;
+
+use serde::Deserialize;
+use serde::Serialize;
+
+use crate::requirement::RequirementId;
+use crate::tristate::RequirementTrace;
+use crate::tristate::TraceOperator;
+use crate::tristate::TriState;
+
+// ============================================================================
+// SECTION: Explanation Tree
+// ============================================================================
+
+/// Evaluated requirement node with its result
+///
+/// # Invariants
+/// - `result` is the value the evaluator produced for this node.
+/// - For `RequireGroup`, `RequireExactly`, and `RequireAtMost`, `satisfied` and `unknown` count
+///   `children` results.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(tag = "kind", rename_all = "snake_case")]
+pub enum ExplanationNode<P> {
+    /// AND over the child nodes
+    And {
+        /// Result of the AND
+        result: TriState,
+        /// Child nodes in evaluation order
+        children: Vec<Self>,
+    },
+    /// OR over the child nodes
+    Or {
+        /// Result of the OR
+        result: TriState,
+        /// Child nodes in evaluation order
+        children: Vec<Self>,
+    },
+    /// NOT over a single child node
+    Not {
+        /// Result of the NOT
+        result: TriState,
+        /// Negated child node
+        child: Box<Self>,
+    },
+    /// Implication from an antecedent node to a consequent node
+    Implies {
+        /// Result of the implication
+        result: TriState,
+        /// Antecedent node
+        antecedent: Box<Self>,
+        /// Consequent node
+        consequent: Box<Self>,
+    },
+    /// At least `min` of the child nodes must be satisfied
+    RequireGroup {
+        /// Result of the group
+        result: TriState,
+        /// Minimum number of satisfied children
+        min: u8,
+        /// Number of children that evaluated to `True`
+        satisfied: usize,
+        /// Number of children that evaluated to `Unknown`
+        unknown: usize,
+        /// Child nodes in evaluation order
+        children: Vec<Self>,
+    },
+    /// Exactly `n` of the child nodes must be satisfied
+    RequireExactly {
+        /// Result of the group
+        result: TriState,
+        /// Exact number of satisfied children
+        n: u8,
+        /// Number of children that evaluated to `True`
+        satisfied: usize,
+        /// Number of children that evaluated to `Unknown`
+        unknown: usize,
+        /// Child nodes in evaluation order
+        children: Vec<Self>,
+    },
+    /// At most `max` of the child nodes may be satisfied
+    RequireAtMost {
+        /// Result of the group
+        result: TriState,
+        /// Maximum number of satisfied children
+        max: u8,
+        /// Number of children that evaluated to `True`
+        satisfied: usize,
+        /// Number of children that evaluated to `Unknown`
+        unknown: usize,
+        /// Child nodes in evaluation order
+        children: Vec<Self>,
+    },
+    /// Condition leaf
+    Condition {
+        /// Leaf position, numbered from 1 in depth-first order
+        id: RequirementId,
+        /// Evaluated condition
+        condition: P,
+        /// Result of the condition
+        result: TriState,
+    },
+}
+
+impl<P> ExplanationNode<P> {
+    /// Returns the result recorded for this node
+    #[must_use]
+    pub const fn result(&self) -> TriState {
+        match self {
+            Self::And {
+                result, ..
+            }
+            | Self::Or {
+                result, ..
+            }
+            | Self::Not {
+                result, ..
+            }
+            | Self::Implies {
+                result, ..
+            }
+            | Self::RequireGroup {
+                result, ..
+            }
+            | Self::RequireExactly {
+                result, ..
+            }
+            | Self::RequireAtMost {
+                result, ..
+            }
+            | Self::Condition {
+                result, ..
+            } => *result,
+        }
+    }
+
+    /// Writes this node and its children, indenting two spaces per level
+    fn write_indented(&self, f: &mut fmt::Formatter<'_>, depth: usize) -> fmt::Result
+    where
+        P: fmt::Display,
+    {
+        let indent = depth * 2;
+        let result = tristate_label(self.result());
+        match self {
+            Self::And {
+                children, ..
+            } => {
+                writeln!(f, "{:indent$}and: {result}", "")?;
+                write_children(f, children, depth)
+            }
+            Self::Or {
+                children, ..
+            } => {
+                writeln!(f, "{:indent$}or: {result}", "")?;
+                write_children(f, children, depth)
+            }
+            Self::Not {
+                child, ..
+            } => {
+                writeln!(f, "{:indent$}not: {result}", "")?;
+                child.write_indented(f, depth + 1)
+            }
+            Self::Implies {
+                antecedent,
+                consequent,
+                ..
+            } => {
+                writeln!(f, "{:indent$}implies: {result}", "")?;
+                antecedent.write_indented(f, depth + 1)?;
+                consequent.write_indented(f, depth + 1)
+            }
+            Self::RequireGroup {
+                min,
+                satisfied,
+                unknown,
+                children,
+                ..
+            } => {
+                writeln!(
+                    f,
+                    "{:indent$}require_group({min} of {}): {result} (satisfied {satisfied}, \
+                     unknown {unknown})",
+                    "",
+                    children.len()
+                )?;
+                write_children(f, children, depth)
+            }
+            Self::RequireExactly {
+                n,
+                satisfied,
+                unknown,
+                children,
+                ..
+            } => {
+                writeln!(
+                    f,
+                    "{:indent$}require_exactly({n} of {}): {result} (satisfied {satisfied}, \
+                     unknown {unknown})",
+                    "",
+                    children.len()
+                )?;
+                write_children(f, children, depth)
+            }
+            Self::RequireAtMost {
+                max,
+                satisfied,
+                unknown,
+                children,
+                ..
+            } => {
+                writeln!(
+                    f,
+                    "{:indent$}require_at_most({max} of {}): {result} (satisfied {satisfied}, \
+                     unknown {unknown})",
+                    "",
+                    children.len()
+                )?;
+                write_children(f, children, depth)
+            }
+            Self::Condition {
+                id,
+                condition,
+                ..
+            } => writeln!(f, "{:indent$}#{} {condition}: {result}", "", id.value()),
+        }
+    }
+}
+
+impl<P: fmt::Display> fmt::Display for ExplanationNode<P> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.write_indented(f, 0)
+    }
+}
+
+/// Writes child nodes one level below their parent
+fn write_children<P: fmt::Display>(
+    f: &mut fmt::Formatter<'_>,
+    children: &[ExplanationNode<P>],
+    depth: usize,
+) -> fmt::Result {
+    for child in children {
+        child.write_indented(f, depth + 1)?;
+    }
+    Ok(())
+}
+
+/// Returns the lowercase label for a tri-state value
+const fn tristate_label(value: TriState) -> &'static str {
+    match value {
+        TriState::True => "true",
+        TriState::False => "false",
+        TriState::Unknown => "unknown",
+    }
+}
+
+/// Tri-state result with its explanation tree
+///
+/// # Invariants
+/// - `root` is `None` only when evaluation failed closed before completing.
+/// - When present, `root.result()` equals `result`.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Explanation<P> {
+    /// Final tri-state result
+    pub result: TriState,
+    /// Explanation tree for the evaluated requirement
+    pub root: Option<ExplanationNode<P>>,
+}
+
+// ============================================================================
+// SECTION: Explanation Trace
+// ============================================================================
+
+/// Trace hook that assembles an [`ExplanationNode`] tree
+///
+/// Relies on the post-order hook contract of [`RequirementTrace`]: children
+/// report before their operator, so each operator pops its children from the
+/// stack and pushes itself.
+///
+/// # Invariants
+/// - After a complete evaluation, `stack` holds exactly the root node.
+/// - `malformed` is set when an operator reports more children than were traced.
+#[derive(Debug)]
+pub struct ExplanationTrace<P> {
+    /// Completed nodes awaiting their parent operator
+    stack: Vec<ExplanationNode<P>>,
+    /// Identifier for the next condition leaf
+    next_id: NonZeroU64,
+    /// Whether hooks arrived out of post-order
+    malformed: bool,
+}
+
+impl<P> ExplanationTrace<P> {
+    /// Creates an empty explanation trace
+    #[must_use]
+    pub const fn new() -> Self {
+        Self {
+            stack: Vec::new(),
+            next_id: NonZeroU64::MIN,
+            malformed: false,
+        }
+    }
+
+    /// Returns the explanation root, or `None` when the trace is incomplete
+    #[must_use]
+    pub fn into_root(mut self) -> Option<ExplanationNode<P>> {
+        if self.malformed || self.stack.len() != 1 {
+            return None;
+        }
+        self.stack.pop()
+    }
+
+    /// Pops the `count` most recent nodes in evaluation order
+    fn pop_children(&mut self, count: usize) -> Vec<ExplanationNode<P>> {
+        if count > self.stack.len() {
+            self.malformed = true;
+        }
+        let start = self.stack.len().saturating_sub(count);
+        self.stack.split_off(start)
+    }
+}
+
+impl<P> Default for ExplanationTrace<P> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<P: Clone> RequirementTrace<P> for ExplanationTrace<P> {
+    fn on_condition_evaluated(&mut self, condition: &P, result: TriState) {
+        let id = RequirementId::new(self.next_id);
+        self.next_id = self.next_id.saturating_add(1);
+        self.stack.push(ExplanationNode::Condition {
+            id,
+            condition: condition.clone(),
+            result,
+        });
+    }
+
+    fn on_operator_evaluated(&mut self, operator: TraceOperator, result: TriState) {
+        let mut children = self.pop_children(operator.children());
+        let node = match operator {
+            TraceOperator::And {
+                ..
+            } => ExplanationNode::And {
+                result,
+                children,
+            },
+            TraceOperator::Or {
+                ..
+            } => ExplanationNode::Or {
+                result,
+                children,
+            },
+            TraceOperator::Not => {
+                let Some(child) = children.pop() else {
+                    return;
+                };
+                ExplanationNode::Not {
+                    result,
+                    child: Box::new(child),
+                }
+            }
+            TraceOperator::Implies => {
+                let (Some(consequent), Some(antecedent)) = (children.pop(), children.pop()) else {
+                    return;
+                };
+                ExplanationNode::Implies {
+                    result,
+                    antecedent: Box::new(antecedent),
+                    consequent: Box::new(consequent),
+                }
+            }
+            TraceOperator::RequireGroup {
+                min,
+                counts,
+            } => ExplanationNode::RequireGroup {
+                result,
+                min,
+                satisfied: counts.satisfied,
+                unknown: counts.unknown,
+                children,
+            },
+            TraceOperator::RequireExactly {
+                n,
+                counts,
+            } => ExplanationNode::RequireExactly {
+                result,
+                n,
+                satisfied: counts.satisfied,
+                unknown: counts.unknown,
+                children,
+            },
+            TraceOperator::RequireAtMost {
+                max,
+                counts,
+            } => ExplanationNode::RequireAtMost {
+                result,
+                max,
+                satisfied: counts.satisfied,
+                unknown: counts.unknown,
+                children,
+            },
+        };
+        self.stack.push(node);
+    }
+}
@@ -0,0 +1,151 @@
+// crates/ret-logic/src/hashing.rs
+// ============================================================================
+// Module: Structural Hashing
+// Description: Stable content digests for requirement trees and plans.
+// Purpose: Provide platform-independent hashes for caching and spec diffing.
+// Dependencies: sha2, std::fmt, std::hash
+// ============================================================================
+
+//! ## Overview
+//! Structural hashes identify requirement trees and compiled plans by content.
+//! Digests are SHA-256 over a tagged, length-prefixed encoding of the tree,
+//! with condition payloads fed through their [`Hash`] implementation. Integer
+//! writes are normalized to little-endian 64-bit values so digests do not
+//! depend on pointer width or endianness.
+//!
+//! Condition types must hash deterministically (names, ids, thresholds) for
+//! digests to be stable across processes; pointer or address hashing defeats
+//! the guarantee.
+
+// ============================================================================
+// SECTION: Imports
+// ============================================================================
+
+use std::fmt;
+use std::fmt::Write as _;
+use std::hash::Hasher;
+
+use sha2::Digest;
+use sha2::Sha256;
+
+// ============================================================================
+// SECTION: Hash Digest
+// ============================================================================
+
+/// SHA-256 structural digest
+///
+/// # Invariants
+/// - Always 32 bytes; `Display` renders lowercase hex.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct HashDigest(pub [u8; 32]);
+
+impl HashDigest {
+    /// Returns the raw digest bytes
+    #[must_use]
+    pub const fn as_bytes(&self) -> &[u8; 32] {
+        &self.0
+    }
+
+    /// Returns the digest as lowercase hex
+    #[must_use]
+    pub fn to_hex(&self) -> String {
+        self.to_string()
+    }
+}
+
+impl fmt::Display for HashDigest {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let mut hex = String::with_capacity(64);
+        for byte in self.0 {
+            let _ = write!(hex, "{byte:02x}");
+        }
+        f.write_str(&hex)
+    }
+}
+
+// ============================================================================
+// SECTION: Structural Hasher
+// ============================================================================
+
+/// Platform-independent [`Hasher`] that accumulates a SHA-256 digest.
+///
+/// # Invariants
+/// - Integer writes are widened to little-endian 64-bit values before hashing.
+pub(crate) struct StructuralHasher(Sha256);
+
+impl StructuralHasher {
+    /// Creates a hasher seeded with a domain separation tag.
+    pub(crate) fn new(domain: &[u8]) -> Self {
+        let mut hasher = Self(Sha256::new());
+        hasher.write_usize(domain.len());
+        hasher.write(domain);
+        hasher
+    }
+
+    /// Consumes the hasher and returns the digest.
+    pub(crate) fn digest(self) -> HashDigest {
+        HashDigest(self.0.finalize().into())
+    }
+}
+
+impl Hasher for StructuralHasher {
+    /// Returns the leading digest bytes; prefer [`StructuralHasher::digest`].
+    fn finish(&self) -> u64 {
+        let digest: [u8; 32] = self.0.clone().finalize().into();
+        let mut prefix = [0u8; 8];
+        prefix.copy_from_slice(&digest[.. 8]);
+        u64::from_le_bytes(prefix)
+    }
+
+    fn write(&mut self, bytes: &[u8]) {
+        self.0.update(bytes);
+    }
+
+    fn write_u8(&mut self, value: u8) {
+        self.write_u64(u64::from(value));
+    }
+
+    fn write_u16(&mut self, value: u16) {
+        self.write_u64(u64::from(value));
+    }
+
+    fn write_u32(&mut self, value: u32) {
+        self.write_u64(u64::from(value));
+    }
+
+    fn write_u64(&mut self, value: u64) {
+        self.write(&value.to_le_bytes());
+    }
+
+    fn write_u128(&mut self, value: u128) {
+        self.write(&value.to_le_bytes());
+    }
+
+    fn write_usize(&mut self, value: usize) {
+        self.write_u64(u64::try_from(value).unwrap_or(u64::MAX));
+    }
+
+    fn write_i8(&mut self, value: i8) {
+        self.write_i64(i64::from(value));
+    }
+
+    fn write_i16(&mut self, value: i16) {
+        self.write_i64(i64::from(value));
+    }
+
+    fn write_i32(&mut self, value: i32) {
+        self.write_i64(i64::from(value));
+    }
+
+    fn write_i64(&mut self, value: i64) {
+        self.write(&value.to_le_bytes());
+    }
+
+    fn write_i128(&mut self, value: i128) {
+        self.write(&value.to_le_bytes());
+    }
+
+    fn write_isize(&mut self, value: isize) {
+        self.write_i64(i64::try_from(value).unwrap_or(i64::MAX));
+    }
+}
@@ -3,8 +3,8 @@
 // Module: Requirement Root
 // Description: Public API surface for the requirement subsystem.
 // Purpose: Wire together core modules, re-exports, and the DSL macro.
-// Dependencies: crate::{builder, dsl, error, executor, plan, requirement, serde_support, traits,
-//              tristate}
+// Dependencies: crate::{builder, columnar, compiled, csv, dsl, error, executor, explain, hashing,
+//              plan, predicates, requirement, serde_support, testing, traits, tristate}
 // ============================================================================
 
 //! ## Overview
@@ -16,12 +16,20 @@
 // ============================================================================
 
 pub mod builder;
+pub mod columnar;
+pub mod compiled;
+pub mod csv;
 pub mod dsl;
 pub mod error;
 pub mod executor;
+pub mod explain;
+pub mod hashing;
 pub mod plan;
+pub mod predicates;
 pub mod requirement;
 pub mod serde_support;
+#[cfg(feature = "testing")]
+pub mod testing;
 pub mod traits;
 pub mod tristate;
 
@@ -32,20 +40,43 @@ mod tests;
 // SECTION: Re-Exports
 // ============================================================================
 
+pub use columnar::Column;
+pub use columnar::ColumnValue;
+pub use columnar::ColumnarError;
+pub use columnar::ColumnarExecutor;
+pub use columnar::ColumnarReader;
+pub use compiled::CompiledRequirement;
+pub use compiled::ConditionLowering;
+pub use csv::CsvError;
+pub use csv::CsvImporter;
+pub use csv::CsvType;
 pub use dsl::ConditionResolver;
 pub use dsl::DslError;
+pub use dsl::RequirementAst;
+pub use dsl::format_requirement;
 pub use dsl::parse_requirement;
+pub use dsl::parse_requirement_ast;
 pub use error::RequirementError;
 pub use error::RequirementResult;
 pub use executor::PlanExecutor;
+pub use explain::Explanation;
+pub use explain::ExplanationNode;
+pub use explain::ExplanationTrace;
+pub use hashing::HashDigest;
 pub use plan::ColumnKey;
 pub use plan::Constant;
 pub use plan::ConstantIndex;
+pub use plan::MIN_COMPATIBLE_PLAN_FORMAT_VERSION;
 pub use plan::OpCode;
 pub use plan::Operation;
+pub use plan::PLAN_FORMAT_VERSION;
 pub use plan::Plan;
 pub use plan::PlanBuilder;
 pub use plan::PlanError;
+pub use predicates::ColumnPredicate;
+pub use predicates::ColumnSource;
+pub use predicates::Scalar;
+pub use predicates::ScalarRef;
 pub use requirement::Requirement;
 pub use requirement::RequirementGroup;
 pub use requirement::RequirementGroupError;
@@ -54,6 +85,7 @@ pub use requirement::RequirementIdError;
 pub use traits::BatchConditionEval;
 pub use traits::BoolAsTri;
 pub use traits::ConditionEval;
+pub use traits::DEFAULT_CONDITION_COST;
 pub use traits::Mask64;
 pub use traits::ReaderLen;
 pub use traits::Row;
@@ -63,8 +95,10 @@ pub use tristate::BochvarLogic;
 pub use tristate::GroupCounts;
 pub use tristate::KleeneLogic;
 pub use tristate::LogicMode;
+pub use tristate::LukasiewiczLogic;
 pub use tristate::NoopTrace;
 pub use tristate::RequirementTrace;
+pub use tristate::TraceOperator;
 pub use tristate::TriLogic;
 pub use tristate::TriState;
 
@@ -100,6 +134,24 @@ pub mod convenience {
         Requirement::require_group(min, requirements)
     }
 
+    /// Creates a requirement requiring exactly N of the given requirements
+    #[must_use]
+    pub fn exactly_n<P>(n: u8, requirements: Vec<Requirement<P>>) -> Requirement<P> {
+        Requirement::require_exactly(n, requirements)
+    }
+
+    /// Creates a requirement requiring at most N of the given requirements
+    #[must_use]
+    pub fn at_most<P>(max: u8, requirements: Vec<Requirement<P>>) -> Requirement<P> {
+        Requirement::require_at_most(max, requirements)
+    }
+
+    /// Creates a requirement whose consequent must hold whenever its antecedent does
+    #[must_use]
+    pub fn implies<P>(antecedent: Requirement<P>, consequent: Requirement<P>) -> Requirement<P> {
+        Requirement::implies(antecedent, consequent)
+    }
+
     /// Creates a requirement from a condition
     #[must_use]
     pub const fn condition<P>(condition: P) -> Requirement<P> {
@@ -157,4 +209,22 @@ macro_rules! requirement {
     (require_group($min:expr, [$($req:tt),* $(,)?])) => {
         $crate::requirement::Requirement::require_group($min, vec![$(requirement!($req)),*])
     };
+
+    // RequireExactly case
+    (require_exactly($n:expr, [$($req:tt),* $(,)?])) => {
+        $crate::requirement::Requirement::require_exactly($n, vec![$(requirement!($req)),*])
+    };
+
+    // Implies case
+    (implies($antecedent:tt, $consequent:tt)) => {
+        $crate::requirement::Requirement::implies(
+            requirement!($antecedent),
+            requirement!($consequent),
+        )
+    };
+
+    // RequireAtMost case
+    (require_at_most($max:expr, [$($req:tt),* $(,)?])) => {
+        $crate::requirement::Requirement::require_at_most($max, vec![$(requirement!($req)),*])
+    };
 }
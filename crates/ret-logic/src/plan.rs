@@ -3,22 +3,30 @@
 // Module: Requirement Plan
 // Description: Compiled representation of requirement evaluation plans.
 // Purpose: Store required columns, operation sequences, and constants for execution.
-// Dependencies: serde::{Deserialize, Serialize}, smallvec::SmallVec
+// Dependencies: crate::hashing, serde::{Deserialize, Serialize}, serde_json, smallvec::SmallVec
 // ============================================================================
 
 //! ## Overview
 //! `Plan` captures the bytecode-like representation of requirement trees,
 //! describing the components to fetch, the operations to run, and the constant pool
 //! so evaluation engines can execute deterministically.
+//! Serialized plans carry a format version; loading rejects versions outside
+//! the compatible range with [`PlanError::IncompatibleFormatVersion`].
 
 // ============================================================================
 // SECTION: Imports
 // ============================================================================
 
+use std::hash::Hasher;
+
 use serde::Deserialize;
 use serde::Serialize;
 use smallvec::SmallVec;
 
+use crate::executor::MAX_PLAN_STACK_DEPTH;
+use crate::hashing::HashDigest;
+use crate::hashing::StructuralHasher;
+
 // ============================================================================
 // SECTION: Column Keys
 // ============================================================================
@@ -29,8 +37,9 @@ use smallvec::SmallVec;
 /// The query system uses this to fetch exactly the required component slices.
 ///
 /// # Invariants
-/// - Treat the inner value as an opaque identifier; no semantic ordering is implied.
-#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+/// - Treat the inner value as an opaque identifier; no semantic ordering is implied. `Ord` compares
+///   raw identifiers only so keys can index ordered maps.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Serialize, Deserialize)]
 pub struct ColumnKey(pub u16);
 
 impl ColumnKey {
@@ -47,6 +56,21 @@ impl ColumnKey {
     }
 }
 
+// ============================================================================
+// SECTION: Format Versions
+// ============================================================================
+
+/// Serialization format version written by this encoder.
+///
+/// Version 2 added the `ImpliesStart`/`ImpliesEnd` opcodes.
+pub const PLAN_FORMAT_VERSION: u16 = 2;
+
+/// Oldest serialization format version this decoder still loads.
+pub const MIN_COMPATIBLE_PLAN_FORMAT_VERSION: u16 = 1;
+
+/// Domain separation tag for plan structural hashes.
+const PLAN_HASH_DOMAIN: &[u8] = b"ret-logic/plan/v1";
+
 // ============================================================================
 // SECTION: Plan Structure
 // ============================================================================
@@ -64,7 +88,8 @@ impl ColumnKey {
 ///   duplicates.
 /// - Operations are executed in order; structural correctness (balanced groups, valid operands) is
 ///   the caller's responsibility.
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(into = "SerializedPlan", try_from = "SerializedPlan")]
 pub struct Plan {
     /// Component columns required for evaluation
     pub(crate) required_columns: SmallVec<[ColumnKey; 8]>,
@@ -93,6 +118,38 @@ pub enum PlanError {
         /// Attempted total after insertion.
         attempted: usize,
     },
+    /// A serialized plan was written by an incompatible encoder version.
+    IncompatibleFormatVersion {
+        /// Format version recorded in the serialized plan.
+        found: u16,
+        /// Oldest format version this decoder loads.
+        min_supported: u16,
+        /// Newest format version this decoder loads.
+        max_supported: u16,
+    },
+    /// A plan could not be encoded or decoded.
+    Serialization(String),
+    /// An operation references a constant beyond the end of the pool.
+    ConstantIndexOutOfRange {
+        /// Position of the operation in the plan.
+        operation: usize,
+        /// Referenced constant index.
+        index: u16,
+        /// Number of constants in the pool.
+        constants: usize,
+    },
+    /// An operation reads a column the plan does not require.
+    UnknownColumn {
+        /// Position of the operation in the plan.
+        operation: usize,
+        /// Referenced column identifier.
+        column: u16,
+    },
+    /// Groups nest deeper than the executor stack allows.
+    NestingTooDeep {
+        /// Maximum group nesting the executor evaluates.
+        max_depth: usize,
+    },
 }
 
 impl std::fmt::Display for PlanError {
@@ -105,6 +162,32 @@ impl std::fmt::Display for PlanError {
                 f,
                 "constant pool overflow: attempted {attempted} constants (max {max_constants})"
             ),
+            Self::IncompatibleFormatVersion {
+                found,
+                min_supported,
+                max_supported,
+            } => write!(
+                f,
+                "incompatible plan format version {found} (supported \
+                 {min_supported}..={max_supported})"
+            ),
+            Self::Serialization(message) => write!(f, "plan serialization failed: {message}"),
+            Self::ConstantIndexOutOfRange {
+                operation,
+                index,
+                constants,
+            } => write!(
+                f,
+                "operation {operation} references constant {index} (pool has {constants} \
+                 constants)"
+            ),
+            Self::UnknownColumn {
+                operation,
+                column,
+            } => write!(f, "operation {operation} reads column {column} not in required columns"),
+            Self::NestingTooDeep {
+                max_depth,
+            } => write!(f, "plan groups nest deeper than {max_depth} levels"),
         }
     }
 }
@@ -184,6 +267,460 @@ impl Plan {
         })?;
         Ok(ConstantIndex(index_u16))
     }
+
+    /// Checks whether a serialized format version can be loaded
+    ///
+    /// # Errors
+    ///
+    /// Returns [`PlanError::IncompatibleFormatVersion`] when `version` is newer than
+    /// [`PLAN_FORMAT_VERSION`] or older than [`MIN_COMPATIBLE_PLAN_FORMAT_VERSION`].
+    pub const fn check_format_version(version: u16) -> Result<(), PlanError> {
+        if version < MIN_COMPATIBLE_PLAN_FORMAT_VERSION || version > PLAN_FORMAT_VERSION {
+            return Err(PlanError::IncompatibleFormatVersion {
+                found: version,
+                min_supported: MIN_COMPATIBLE_PLAN_FORMAT_VERSION,
+                max_supported: PLAN_FORMAT_VERSION,
+            });
+        }
+        Ok(())
+    }
+
+    /// Checks that operand references resolve within this plan
+    ///
+    /// Comparison and flag-check operations read the column in `operand_a`,
+    /// which must be listed in [`Plan::required_columns`], and the constant in
+    /// `operand_b`, which must be inside the constant pool. Logical, spatial,
+    /// and domain-specific opcodes define their own operand layouts and are
+    /// not checked.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`PlanError::UnknownColumn`] or [`PlanError::ConstantIndexOutOfRange`]
+    /// for the first operation with a dangling reference.
+    pub fn validate_references(&self) -> Result<(), PlanError> {
+        for (position, op) in self.operations.iter().enumerate() {
+            if !(op.opcode.is_comparison() || op.opcode.is_flag_check()) {
+                continue;
+            }
+            if !self.required_columns.contains(&ColumnKey(op.operand_a)) {
+                return Err(PlanError::UnknownColumn {
+                    operation: position,
+                    column: op.operand_a,
+                });
+            }
+            if usize::from(op.operand_b) >= self.constants.len() {
+                return Err(PlanError::ConstantIndexOutOfRange {
+                    operation: position,
+                    index: op.operand_b,
+                    constants: self.constants.len(),
+                });
+            }
+        }
+        Ok(())
+    }
+
+    /// Returns a stable structural digest of this plan
+    ///
+    /// Covers required columns, operations (opcode and operands), and the
+    /// constant pool in order. Float constants hash by bit pattern. The digest
+    /// is independent of the serialization format version.
+    #[must_use]
+    pub fn structural_hash(&self) -> HashDigest {
+        let mut hasher = StructuralHasher::new(PLAN_HASH_DOMAIN);
+        hasher.write_usize(self.required_columns.len());
+        for column in &self.required_columns {
+            hasher.write_u16(column.0);
+        }
+        hasher.write_usize(self.operations.len());
+        for op in &self.operations {
+            hasher.write_u8(op.opcode.as_u8());
+            hasher.write_u16(op.operand_a);
+            hasher.write_u16(op.operand_b);
+            hasher.write_u16(op.operand_c);
+        }
+        hasher.write_usize(self.constants.len());
+        for constant in &self.constants {
+            constant.hash_structure(&mut hasher);
+        }
+        hasher.digest()
+    }
+
+    /// Applies semantics-preserving simplifications to the operation stream
+    ///
+    /// Single-child groups are unwrapped, nested groups with the same operator
+    /// are flattened, and adjacent negations cancel. Empty groups are dropped
+    /// only when their identity (`true` for AND, `false` for OR) matches the
+    /// enclosing operator; an empty group of the other operator is a constant
+    /// and is kept. Leaves are never removed, duplicated, or reordered, so the
+    /// result is identical under every [`crate::LogicMode`] and lazy evaluation
+    /// still visits predicates in their original order. Implication groups
+    /// are never flattened; each operand is simplified on its own and keeps
+    /// its position.
+    ///
+    /// Malformed plans (unbalanced or mismatched group ends) and plans that
+    /// exceed the executor stack depth are left untouched so they keep failing
+    /// closed. The required columns and constant pool are not modified, but
+    /// [`Plan::structural_hash`] changes whenever operations are rewritten.
+    pub fn optimize(&mut self) {
+        let Some(root) = parse_plan_items(&self.operations) else {
+            return;
+        };
+        let items = simplify_items(root, GroupKind::And);
+        let mut operations = Vec::with_capacity(self.operations.len());
+        emit_plan_items(&items, &mut operations);
+        self.operations = operations;
+    }
+
+    /// Reorders group members so cheaper leaves are evaluated first
+    ///
+    /// `cost` estimates each leaf operation; a group costs the saturating sum
+    /// of its members. Members of every AND/OR group, including the implicit
+    /// root group, are stably sorted by ascending cost, so lazy evaluation
+    /// dispatches expensive handlers only when cheap ones leave the group
+    /// undecided. Both operators are commutative under every
+    /// [`crate::LogicMode`], so results are unchanged.
+    ///
+    /// A `Not` negates its group's accumulated value at that position, so
+    /// groups that directly contain a `Not` keep their order, as do
+    /// implication groups, whose antecedent must stay first; their nested
+    /// groups are still reordered. Malformed or over-deep plans are left
+    /// untouched, and [`Plan::structural_hash`] changes when members move.
+    pub fn reorder_by_cost(&mut self, cost: impl Fn(Operation) -> u32) {
+        let Some(root) = parse_plan_items(&self.operations) else {
+            return;
+        };
+        let (items, _) = reorder_items_by_cost(root, GroupKind::And, &cost);
+        let mut operations = Vec::with_capacity(self.operations.len());
+        emit_plan_items(&items, &mut operations);
+        self.operations = operations;
+    }
+
+    /// Serializes this plan to JSON tagged with [`PLAN_FORMAT_VERSION`]
+    ///
+    /// # Errors
+    ///
+    /// Returns [`PlanError::Serialization`] when encoding fails.
+    pub fn to_json(&self) -> Result<String, PlanError> {
+        serde_json::to_string(self).map_err(|err| PlanError::Serialization(err.to_string()))
+    }
+
+    /// Deserializes a plan from JSON, enforcing format compatibility
+    ///
+    /// The version tag is checked before the body is decoded, so plans from
+    /// newer encoders are rejected even when their layout is unreadable.
+    /// Operand references are then checked with [`Plan::validate_references`].
+    ///
+    /// # Errors
+    ///
+    /// Returns [`PlanError::IncompatibleFormatVersion`] for unsupported versions,
+    /// [`PlanError::UnknownColumn`] or [`PlanError::ConstantIndexOutOfRange`] for
+    /// dangling references, and [`PlanError::Serialization`] for malformed input.
+    pub fn from_json(input: &str) -> Result<Self, PlanError> {
+        let header: SerializedPlanHeader =
+            serde_json::from_str(input).map_err(|err| PlanError::Serialization(err.to_string()))?;
+        Self::check_format_version(header.format_version)?;
+        let serialized: SerializedPlan =
+            serde_json::from_str(input).map_err(|err| PlanError::Serialization(err.to_string()))?;
+        Self::try_from(serialized)
+    }
+}
+
+// ============================================================================
+// SECTION: Plan Simplification
+// ============================================================================
+
+/// Operator of a parsed plan group
+#[derive(Clone, Copy, PartialEq, Eq)]
+enum GroupKind {
+    /// `AndStart`/`AndEnd` group
+    And,
+    /// `OrStart`/`OrEnd` group
+    Or,
+    /// `ImpliesStart`/`ImpliesEnd` group over an antecedent and a consequent
+    Implies,
+}
+
+impl GroupKind {
+    /// Returns true for commutative operators whose members may be merged or reordered
+    const fn is_commutative(self) -> bool {
+        matches!(self, Self::And | Self::Or)
+    }
+}
+
+/// Parsed view of a plan used by [`Plan::optimize`] and [`Plan::reorder_by_cost`]
+enum PlanItem {
+    /// Leaf operation evaluated by the dispatch table
+    Leaf(Operation),
+    /// Negation of the enclosing group's accumulated value
+    Not(Operation),
+    /// Nested group with its original start and end operations
+    Group {
+        /// Operator combining the group's items
+        kind: GroupKind,
+        /// Opening operation
+        start: Operation,
+        /// Closing operation
+        end: Operation,
+        /// Items evaluated inside the group
+        items: Vec<Self>,
+    },
+}
+
+impl PlanItem {
+    /// Returns true for negation markers
+    const fn is_not(&self) -> bool {
+        matches!(self, Self::Not(_))
+    }
+}
+
+/// Parses an operation stream into the items of the implicit root AND group.
+///
+/// Returns `None` for malformed plans or plans deeper than the executor stack.
+fn parse_plan_items(operations: &[Operation]) -> Option<Vec<PlanItem>> {
+    let mut stack: Vec<(GroupKind, Operation, Vec<PlanItem>)> = Vec::new();
+    let mut root = Vec::new();
+    for op in operations {
+        let opened = match op.opcode {
+            OpCode::AndStart => Some(GroupKind::And),
+            OpCode::OrStart => Some(GroupKind::Or),
+            OpCode::ImpliesStart => Some(GroupKind::Implies),
+            _ => None,
+        };
+        if let Some(kind) = opened {
+            if stack.len() + 1 >= MAX_PLAN_STACK_DEPTH {
+                return None;
+            }
+            stack.push((kind, *op, Vec::new()));
+            continue;
+        }
+        let item = match op.opcode {
+            OpCode::AndEnd | OpCode::OrEnd | OpCode::ImpliesEnd => {
+                let (kind, start, items) = stack.pop()?;
+                let closes = match kind {
+                    GroupKind::And => OpCode::AndEnd,
+                    GroupKind::Or => OpCode::OrEnd,
+                    GroupKind::Implies => OpCode::ImpliesEnd,
+                };
+                if op.opcode != closes {
+                    return None;
+                }
+                PlanItem::Group {
+                    kind,
+                    start,
+                    end: *op,
+                    items,
+                }
+            }
+            OpCode::Not => PlanItem::Not(*op),
+            _ => PlanItem::Leaf(*op),
+        };
+        match stack.last_mut() {
+            Some((_, _, items)) => items.push(item),
+            None => root.push(item),
+        }
+    }
+    stack.is_empty().then_some(root)
+}
+
+/// Simplifies the items of a group combined with `kind`.
+///
+/// Implication operands are simplified in place but never merged, so each
+/// implication keeps exactly its antecedent and consequent.
+fn simplify_items(items: Vec<PlanItem>, kind: GroupKind) -> Vec<PlanItem> {
+    let mut out = Vec::with_capacity(items.len());
+    for item in items {
+        let item = match item {
+            PlanItem::Group {
+                kind: child_kind,
+                start,
+                end,
+                items,
+            } => PlanItem::Group {
+                kind: child_kind,
+                start,
+                end,
+                items: simplify_items(items, child_kind),
+            },
+            other => other,
+        };
+        if kind.is_commutative() {
+            absorb_item(&mut out, item, kind);
+        } else {
+            out.push(unwrap_single(item));
+        }
+    }
+    if kind.is_commutative() { cancel_double_negation(out) } else { out }
+}
+
+/// Replaces an AND/OR group holding a single non-negation item with that item.
+fn unwrap_single(item: PlanItem) -> PlanItem {
+    match item {
+        PlanItem::Group {
+            kind,
+            start,
+            end,
+            items,
+        } if kind.is_commutative() && items.len() == 1 && !items[0].is_not() => {
+            match <[PlanItem; 1]>::try_from(items) {
+                Ok([only]) => unwrap_single(only),
+                Err(items) => PlanItem::Group {
+                    kind,
+                    start,
+                    end,
+                    items,
+                },
+            }
+        }
+        other => other,
+    }
+}
+
+/// Appends a simplified item to a group, unwrapping or flattening nested groups.
+fn absorb_item(out: &mut Vec<PlanItem>, item: PlanItem, parent: GroupKind) {
+    match item {
+        PlanItem::Group {
+            kind,
+            mut items,
+            ..
+        } if kind.is_commutative() && items.len() == 1 && !items[0].is_not() => {
+            if let Some(only) = items.pop() {
+                absorb_item(out, only, parent);
+            }
+        }
+        // A same-operator group folds into its parent when it has no negation
+        // (associativity) or when nothing precedes it, since the parent is
+        // still at its identity and the group's fold is replayed verbatim.
+        PlanItem::Group {
+            kind,
+            items,
+            ..
+        } if kind == parent && (out.is_empty() || !items.iter().any(PlanItem::is_not)) => {
+            out.extend(items);
+        }
+        other => out.push(other),
+    }
+}
+
+/// Removes adjacent negation pairs.
+fn cancel_double_negation(items: Vec<PlanItem>) -> Vec<PlanItem> {
+    let mut out: Vec<PlanItem> = Vec::with_capacity(items.len());
+    for item in items {
+        if item.is_not() && out.last().is_some_and(PlanItem::is_not) {
+            out.pop();
+        } else {
+            out.push(item);
+        }
+    }
+    out
+}
+
+/// Stably sorts negation-free AND/OR groups by ascending cost, returning the total cost.
+///
+/// Implication groups keep their antecedent first.
+fn reorder_items_by_cost(
+    items: Vec<PlanItem>,
+    kind: GroupKind,
+    cost: &impl Fn(Operation) -> u32,
+) -> (Vec<PlanItem>, u32) {
+    let mut costed: Vec<(u32, PlanItem)> = items
+        .into_iter()
+        .map(|item| match item {
+            PlanItem::Leaf(op) => (cost(op), PlanItem::Leaf(op)),
+            PlanItem::Not(op) => (0, PlanItem::Not(op)),
+            PlanItem::Group {
+                kind,
+                start,
+                end,
+                items,
+            } => {
+                let (items, total) = reorder_items_by_cost(items, kind, cost);
+                (
+                    total,
+                    PlanItem::Group {
+                        kind,
+                        start,
+                        end,
+                        items,
+                    },
+                )
+            }
+        })
+        .collect();
+    if kind.is_commutative() && !costed.iter().any(|(_, item)| item.is_not()) {
+        costed.sort_by_key(|(cost, _)| *cost);
+    }
+    let total = costed.iter().fold(0u32, |total, (cost, _)| total.saturating_add(*cost));
+    (costed.into_iter().map(|(_, item)| item).collect(), total)
+}
+
+/// Re-encodes parsed items as a flat operation stream.
+fn emit_plan_items(items: &[PlanItem], operations: &mut Vec<Operation>) {
+    for item in items {
+        match item {
+            PlanItem::Leaf(op) | PlanItem::Not(op) => operations.push(*op),
+            PlanItem::Group {
+                start,
+                end,
+                items,
+                ..
+            } => {
+                operations.push(*start);
+                emit_plan_items(items, operations);
+                operations.push(*end);
+            }
+        }
+    }
+}
+
+// ============================================================================
+// SECTION: Plan Serialization
+// ============================================================================
+
+/// Version-tagged wire form of a [`Plan`].
+#[derive(Serialize, Deserialize)]
+#[serde(deny_unknown_fields)]
+struct SerializedPlan {
+    /// Encoder format version.
+    format_version: u16,
+    /// Component columns required for evaluation.
+    required_columns: SmallVec<[ColumnKey; 8]>,
+    /// Sequence of operations to execute.
+    operations: Vec<Operation>,
+    /// Constant pool entries.
+    constants: Vec<Constant>,
+}
+
+/// Version tag read ahead of the full plan body.
+#[derive(Deserialize)]
+struct SerializedPlanHeader {
+    /// Encoder format version.
+    format_version: u16,
+}
+
+impl From<Plan> for SerializedPlan {
+    fn from(plan: Plan) -> Self {
+        Self {
+            format_version: PLAN_FORMAT_VERSION,
+            required_columns: plan.required_columns,
+            operations: plan.operations,
+            constants: plan.constants,
+        }
+    }
+}
+
+impl TryFrom<SerializedPlan> for Plan {
+    type Error = PlanError;
+
+    fn try_from(serialized: SerializedPlan) -> Result<Self, Self::Error> {
+        Self::check_format_version(serialized.format_version)?;
+        let plan = Self {
+            required_columns: serialized.required_columns,
+            operations: serialized.operations,
+            constants: serialized.constants,
+        };
+        plan.validate_references()?;
+        Ok(plan)
+    }
 }
 
 // ============================================================================
@@ -205,7 +742,7 @@ impl Default for Plan {
 /// # Invariants
 /// - Intended to reference a valid entry in a [`Plan`] constant pool.
 /// - No bounds are enforced by the type itself.
-#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
 pub struct ConstantIndex(pub u16);
 
 // ============================================================================
@@ -219,7 +756,7 @@ pub struct ConstantIndex(pub u16);
 ///
 /// # Invariants
 /// - Operand interpretation is opcode-specific and must be enforced by the domain.
-#[derive(Debug, Clone, Copy)]
+#[derive(Debug, Clone, Copy, Serialize, Deserialize)]
 pub struct Operation {
     /// Operation type and behavior
     pub opcode: OpCode,
@@ -258,7 +795,7 @@ impl Operation {
 ///
 /// # Invariants
 /// - Stable `repr(u8)` values are used for dispatch table indexing.
-#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
 #[repr(u8)]
 pub enum OpCode {
     // Logical operations
@@ -272,6 +809,10 @@ pub enum OpCode {
     OrEnd = 3,
     /// Logical NOT
     Not = 4,
+    /// Begin an implication over exactly two operands, antecedent first
+    ImpliesStart = 5,
+    /// Close an implication
+    ImpliesEnd = 6,
 
     // Comparison operations
     /// Floating-point greater-than-or-equal comparison
@@ -314,7 +855,15 @@ impl OpCode {
     /// Returns true if this is a logical grouping operation
     #[must_use]
     pub const fn is_logical_group(&self) -> bool {
-        matches!(self, Self::AndStart | Self::AndEnd | Self::OrStart | Self::OrEnd)
+        matches!(
+            self,
+            Self::AndStart
+                | Self::AndEnd
+                | Self::OrStart
+                | Self::OrEnd
+                | Self::ImpliesStart
+                | Self::ImpliesEnd
+        )
     }
 
     /// Returns true if this is a comparison operation
@@ -331,6 +880,12 @@ impl OpCode {
         )
     }
 
+    /// Returns true if this is a flag check operation
+    #[must_use]
+    pub const fn is_flag_check(&self) -> bool {
+        matches!(self, Self::HasAllFlags | Self::HasAnyFlags | Self::HasNoneFlags)
+    }
+
     /// Returns the numeric opcode used for dispatch table indexing.
     #[must_use]
     pub const fn as_u8(self) -> u8 {
@@ -340,6 +895,8 @@ impl OpCode {
             Self::OrStart => 2,
             Self::OrEnd => 3,
             Self::Not => 4,
+            Self::ImpliesStart => 5,
+            Self::ImpliesEnd => 6,
             Self::FloatGte => 10,
             Self::FloatLte => 11,
             Self::FloatEq => 12,
@@ -365,7 +922,7 @@ impl OpCode {
 /// # Invariants
 /// - `String` values are valid UTF-8 by construction.
 /// - `Custom` payloads are opaque and domain-defined.
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub enum Constant {
     /// Floating-point value constant
     Float(f32),
@@ -432,6 +989,38 @@ impl Constant {
             _ => None,
         }
     }
+
+    /// Feeds a tagged encoding of this constant into `hasher`.
+    fn hash_structure(&self, hasher: &mut StructuralHasher) {
+        match self {
+            Self::Float(value) => {
+                hasher.write_u8(0);
+                hasher.write_u32(value.to_bits());
+            }
+            Self::Int(value) => {
+                hasher.write_u8(1);
+                hasher.write_i32(*value);
+            }
+            Self::UInt(value) => {
+                hasher.write_u8(2);
+                hasher.write_u32(*value);
+            }
+            Self::String(value) => {
+                hasher.write_u8(3);
+                hasher.write_usize(value.len());
+                hasher.write(value.as_bytes());
+            }
+            Self::Flags(value) => {
+                hasher.write_u8(4);
+                hasher.write_u64(*value);
+            }
+            Self::Custom(bytes) => {
+                hasher.write_u8(5);
+                hasher.write_usize(bytes.len());
+                hasher.write(bytes);
+            }
+        }
+    }
 }
 
 // ============================================================================
@@ -534,6 +1123,18 @@ impl PlanBuilder {
         self.add_op(OpCode::OrEnd, 0, 0, 0)
     }
 
+    /// Starts an implication; add the antecedent and then the consequent
+    #[must_use]
+    pub fn implies_start(self) -> Self {
+        self.add_op(OpCode::ImpliesStart, 0, 0, 0)
+    }
+
+    /// Ends an implication
+    #[must_use]
+    pub fn implies_end(self) -> Self {
+        self.add_op(OpCode::ImpliesEnd, 0, 0, 0)
+    }
+
     /// Builds the final plan
     #[must_use]
     pub fn build(self) -> Plan {
@@ -0,0 +1,342 @@
+// crates/ret-logic/src/predicates.rs
+// ============================================================================
+// Module: Predicate Combinators
+// Description: Reusable column predicates with consistent tri-state handling.
+// Purpose: Replace hand-written conditions for range, membership, and pattern checks.
+// Dependencies: crate::{columnar, plan, traits, tristate}, regex
+// ============================================================================
+
+//! ## Overview
+//! [`ColumnPredicate`] is a ready-made condition that reads one column through
+//! [`ColumnSource`] and tests it with [`in_range`], [`greater_than`],
+//! [`one_of`], or [`matches_regex`]. Every combinator shares the same evidence
+//! rules: a missing value, a value of the wrong kind, or a `NaN` evaluates to
+//! [`TriState::Unknown`], never `False`. Boolean evaluation through
+//! [`ConditionEval`] fails closed by treating `Unknown` as `false`.
+//!
+//! ```ignore
+//! let adult = Requirement::condition(greater_than(AGE, 17.0));
+//! let region = Requirement::condition(one_of(REGION, ["eu", "uk"]));
+//! let policy = Requirement::and(vec![adult, region]);
+//! ```
+
+use std::fmt;
+use std::marker::PhantomData;
+
+use regex::Regex;
+
+use crate::columnar::Column;
+use crate::columnar::ColumnarReader;
+use crate::plan::ColumnKey;
+use crate::traits::ConditionEval;
+use crate::traits::Row;
+use crate::traits::TriStateConditionEval;
+use crate::tristate::TriState;
+
+// ============================================================================
+// SECTION: Column Values
+// ============================================================================
+
+/// Borrowed scalar read from a column for one row
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum ScalarRef<'a> {
+    /// Numeric value; integer columns widen losslessly.
+    Number(f64),
+    /// Text value.
+    Text(&'a str),
+}
+
+/// Owned scalar used as a [`one_of`] candidate
+///
+/// # Invariants
+/// - None. Numbers and text never compare equal to each other.
+#[derive(Debug, Clone, PartialEq)]
+pub enum Scalar {
+    /// Numeric candidate.
+    Number(f64),
+    /// Text candidate.
+    Text(String),
+}
+
+impl Scalar {
+    /// Borrows this scalar as a column value
+    #[must_use]
+    pub fn as_scalar_ref(&self) -> ScalarRef<'_> {
+        match self {
+            Self::Number(value) => ScalarRef::Number(*value),
+            Self::Text(value) => ScalarRef::Text(value),
+        }
+    }
+
+    /// Compares with `value`, returning `None` when their kinds differ
+    #[allow(clippy::float_cmp, reason = "Set membership is exact equality.")]
+    fn matches(&self, value: ScalarRef<'_>) -> Option<bool> {
+        match (self, value) {
+            (Self::Number(expected), ScalarRef::Number(actual)) => Some(*expected == actual),
+            (Self::Text(expected), ScalarRef::Text(actual)) => Some(expected == actual),
+            _ => None,
+        }
+    }
+}
+
+impl fmt::Display for Scalar {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Number(value) => write!(f, "{value}"),
+            Self::Text(value) => write!(f, "\"{value}\""),
+        }
+    }
+}
+
+impl From<f64> for Scalar {
+    fn from(value: f64) -> Self {
+        Self::Number(value)
+    }
+}
+
+impl From<i32> for Scalar {
+    fn from(value: i32) -> Self {
+        Self::Number(f64::from(value))
+    }
+}
+
+impl From<&str> for Scalar {
+    fn from(value: &str) -> Self {
+        Self::Text(value.to_string())
+    }
+}
+
+impl From<String> for Scalar {
+    fn from(value: String) -> Self {
+        Self::Text(value)
+    }
+}
+
+// ============================================================================
+// SECTION: Column Source Trait
+// ============================================================================
+
+/// Reader that exposes row values by column key
+///
+/// Return `None` when the row or column is absent or holds no value; the
+/// predicates report that as [`TriState::Unknown`].
+pub trait ColumnSource {
+    /// Returns the value of `column` at `row`
+    fn scalar(&self, row: Row, column: ColumnKey) -> Option<ScalarRef<'_>>;
+}
+
+impl ColumnSource for ColumnarReader {
+    /// Float and integer columns read as numbers; flag columns are bit sets
+    /// rather than scalars and read as missing.
+    fn scalar(&self, row: Row, column: ColumnKey) -> Option<ScalarRef<'_>> {
+        match self.column(column)? {
+            Column::Float(values) => {
+                values.get(row).map(|value| ScalarRef::Number(f64::from(*value)))
+            }
+            Column::Int(values) => {
+                values.get(row).map(|value| ScalarRef::Number(f64::from(*value)))
+            }
+            Column::Flags(_) => None,
+        }
+    }
+}
+
+// ============================================================================
+// SECTION: Column Predicate
+// ============================================================================
+
+/// Test applied to a column value
+#[derive(Clone)]
+enum PredicateTest {
+    /// Number within `min ..= max`.
+    InRange {
+        /// Inclusive lower bound.
+        min: f64,
+        /// Inclusive upper bound.
+        max: f64,
+    },
+    /// Number strictly greater than the threshold.
+    GreaterThan(f64),
+    /// Value equal to one of the candidates.
+    OneOf(Vec<Scalar>),
+    /// Text matched by the pattern.
+    Matches(Regex),
+}
+
+/// Condition that tests a single column value
+///
+/// Built with [`in_range`], [`greater_than`], [`one_of`], or [`matches_regex`].
+/// `R` is the reader type the condition evaluates against.
+///
+/// # Invariants
+/// - Missing, mistyped, and `NaN` values evaluate to [`TriState::Unknown`].
+pub struct ColumnPredicate<R> {
+    /// Column read for every row.
+    column: ColumnKey,
+    /// Test applied to the value.
+    test: PredicateTest,
+    /// Reader type marker.
+    reader: PhantomData<fn(&R)>,
+}
+
+impl<R> ColumnPredicate<R> {
+    /// Wraps a test on `column`
+    const fn new(column: ColumnKey, test: PredicateTest) -> Self {
+        Self {
+            column,
+            test,
+            reader: PhantomData,
+        }
+    }
+
+    /// Returns the column this predicate reads
+    #[must_use]
+    pub const fn column(&self) -> ColumnKey {
+        self.column
+    }
+
+    /// Tests a value read from the column
+    fn test(&self, value: ScalarRef<'_>) -> TriState {
+        match (&self.test, value) {
+            (_, ScalarRef::Number(number)) if number.is_nan() => TriState::Unknown,
+            (
+                PredicateTest::InRange {
+                    min,
+                    max,
+                },
+                ScalarRef::Number(number),
+            ) => TriState::from(*min <= number && number <= *max),
+            (PredicateTest::GreaterThan(threshold), ScalarRef::Number(number)) => {
+                TriState::from(number > *threshold)
+            }
+            (PredicateTest::OneOf(candidates), value) => membership(candidates, value),
+            (PredicateTest::Matches(pattern), ScalarRef::Text(text)) => {
+                TriState::from(pattern.is_match(text))
+            }
+            _ => TriState::Unknown,
+        }
+    }
+}
+
+/// Tests `value` against candidates, ignoring candidates of another kind
+fn membership(candidates: &[Scalar], value: ScalarRef<'_>) -> TriState {
+    let mut comparable = false;
+    for candidate in candidates {
+        match candidate.matches(value) {
+            Some(true) => return TriState::True,
+            Some(false) => comparable = true,
+            None => {}
+        }
+    }
+    if comparable { TriState::False } else { TriState::Unknown }
+}
+
+impl<R> Clone for ColumnPredicate<R> {
+    fn clone(&self) -> Self {
+        Self::new(self.column, self.test.clone())
+    }
+}
+
+impl<R> fmt::Debug for ColumnPredicate<R> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{self}")
+    }
+}
+
+impl<R> fmt::Display for ColumnPredicate<R> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let column = self.column.id();
+        match &self.test {
+            PredicateTest::InRange {
+                min,
+                max,
+            } => write!(f, "in_range(col{column}, {min}, {max})"),
+            PredicateTest::GreaterThan(threshold) => {
+                write!(f, "greater_than(col{column}, {threshold})")
+            }
+            PredicateTest::OneOf(candidates) => {
+                write!(f, "one_of(col{column}, [")?;
+                for (index, candidate) in candidates.iter().enumerate() {
+                    if index > 0 {
+                        write!(f, ", ")?;
+                    }
+                    write!(f, "{candidate}")?;
+                }
+                write!(f, "])")
+            }
+            PredicateTest::Matches(pattern) => {
+                write!(f, "matches_regex(col{column}, \"{}\")", pattern.as_str())
+            }
+        }
+    }
+}
+
+impl<R: ColumnSource> TriStateConditionEval for ColumnPredicate<R> {
+    type Reader<'a> = R;
+
+    fn eval_row_tristate(&self, reader: &R, row: Row) -> TriState {
+        reader.scalar(row, self.column).map_or(TriState::Unknown, |value| self.test(value))
+    }
+}
+
+impl<R: ColumnSource> ConditionEval for ColumnPredicate<R> {
+    type Reader<'a> = R;
+
+    fn eval_row(&self, reader: &R, row: Row) -> bool {
+        self.eval_row_tristate(reader, row).is_true()
+    }
+}
+
+// ============================================================================
+// SECTION: Combinators
+// ============================================================================
+
+/// Passes when the column's number lies within `min ..= max`
+///
+/// An empty or `NaN` range never passes.
+#[must_use]
+pub const fn in_range<R>(column: ColumnKey, min: f64, max: f64) -> ColumnPredicate<R> {
+    ColumnPredicate::new(
+        column,
+        PredicateTest::InRange {
+            min,
+            max,
+        },
+    )
+}
+
+/// Passes when the column's number is strictly greater than `threshold`
+#[must_use]
+pub const fn greater_than<R>(column: ColumnKey, threshold: f64) -> ColumnPredicate<R> {
+    ColumnPredicate::new(column, PredicateTest::GreaterThan(threshold))
+}
+
+/// Passes when the column's value equals any candidate
+///
+/// Numbers only match numbers and text only matches text. A value that matches
+/// no candidate is `False`, or `Unknown` when no candidate shares its kind.
+#[must_use]
+pub fn one_of<R, I>(column: ColumnKey, candidates: I) -> ColumnPredicate<R>
+where
+    I: IntoIterator,
+    I::Item: Into<Scalar>,
+{
+    ColumnPredicate::new(
+        column,
+        PredicateTest::OneOf(candidates.into_iter().map(Into::into).collect()),
+    )
+}
+
+/// Passes when `pattern` matches the column's text
+///
+/// Matching is unanchored, as with [`Regex::is_match`]; use `^` and `$` to
+/// match the whole value. Numeric values evaluate to `Unknown`.
+///
+/// # Errors
+/// Returns [`regex::Error`] when `pattern` is not a valid regular expression.
+pub fn matches_regex<R>(
+    column: ColumnKey,
+    pattern: &str,
+) -> Result<ColumnPredicate<R>, regex::Error> {
+    Ok(ColumnPredicate::new(column, PredicateTest::Matches(Regex::new(pattern)?)))
+}
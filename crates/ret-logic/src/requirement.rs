@@ -4,7 +4,8 @@
 // Description: Universal Boolean algebra over typed conditions.
 // Purpose: Define `Requirement`, `RequirementId`, and `RequirementGroup` structures along with
 //          helpers.
-// Dependencies: serde::{Deserialize, Serialize}, smallvec::SmallVec, std::fmt, std::num::NonZeroU64
+// Dependencies: crate::{explain, hashing}, serde::{Deserialize, Serialize}, smallvec::SmallVec,
+// std::fmt, std::hash, std::num::NonZeroU64
 // ============================================================================
 
 //! ## Overview
@@ -17,16 +18,25 @@
 // ============================================================================
 
 use std::fmt;
+use std::hash::Hash;
+use std::hash::Hasher;
 use std::num::NonZeroU64;
 
 use serde::Deserialize;
 use serde::Serialize;
+use smallvec::Array;
 use smallvec::SmallVec;
 
+use crate::explain::Explanation;
+use crate::explain::ExplanationTrace;
+use crate::hashing::HashDigest;
+use crate::hashing::StructuralHasher;
+use crate::traits::ConditionEval;
 use crate::traits::TriStateConditionEval;
 use crate::tristate::GroupCounts;
 use crate::tristate::NoopTrace;
 use crate::tristate::RequirementTrace;
+use crate::tristate::TraceOperator;
 use crate::tristate::TriLogic;
 use crate::tristate::TriState;
 
@@ -45,6 +55,30 @@ pub const MAX_EVAL_DEPTH: usize = 32;
 #[derive(Debug, Clone, Copy)]
 struct EvalDepthExceeded;
 
+// ============================================================================
+// SECTION: Structural Hash Tags
+// ============================================================================
+
+/// Domain separation tag for requirement structural hashes.
+const REQUIREMENT_HASH_DOMAIN: &[u8] = b"ret-logic/requirement/v1";
+
+/// Structural hash tag for condition leaves.
+const HASH_TAG_CONDITION: u8 = 0;
+/// Structural hash tag for `And` nodes.
+const HASH_TAG_AND: u8 = 1;
+/// Structural hash tag for `Or` nodes.
+const HASH_TAG_OR: u8 = 2;
+/// Structural hash tag for `Not` nodes.
+const HASH_TAG_NOT: u8 = 3;
+/// Structural hash tag for `RequireGroup` nodes.
+const HASH_TAG_REQUIRE_GROUP: u8 = 4;
+/// Structural hash tag for `RequireExactly` nodes.
+const HASH_TAG_REQUIRE_EXACTLY: u8 = 5;
+/// Structural hash tag for `RequireAtMost` nodes.
+const HASH_TAG_REQUIRE_AT_MOST: u8 = 6;
+/// Structural hash tag for `Implies` nodes.
+const HASH_TAG_IMPLIES: u8 = 7;
+
 // ============================================================================
 // SECTION: Requirement Id
 // ============================================================================
@@ -122,7 +156,8 @@ impl TryFrom<u64> for RequirementId {
 ///
 /// This enum represents the core of the requirement system - a composable
 /// Boolean algebra that works over any domain-specific condition type.
-/// The logical operators (And, Or, Not, `RequireGroup`) are universal and
+/// The logical operators (And, Or, Not, `RequireGroup`, `RequireExactly`,
+/// `RequireAtMost`, `Implies`) are universal and
 /// domain-agnostic, while the Condition variant serves as the boundary
 /// where domain-specific semantics are injected.
 ///
@@ -161,6 +196,44 @@ pub enum Requirement<P> {
         reqs: SmallVec<[Box<Self>; 8]>,
     },
 
+    /// Exact group requirement: Exactly `n` of the sub-requirements must be satisfied
+    ///
+    /// Models "exactly N of M approvers" logic. Unlike `RequireGroup`, surplus
+    /// satisfied sub-requirements fail the group, so evaluation only exits early
+    /// once the satisfied count overshoots `n` or can no longer reach it.
+    RequireExactly {
+        /// Exact number of sub-requirements that must be satisfied
+        n: u8,
+        /// The sub-requirements to count
+        reqs: SmallVec<[Box<Self>; 8]>,
+    },
+
+    /// Upper-bound group requirement: At most `max` of the sub-requirements may be satisfied
+    ///
+    /// The mirror of `RequireGroup` for mutual-exclusion policies such as "at
+    /// most one premium add-on". Evaluation exits early once the satisfied
+    /// count passes `max` or the remaining members can no longer push it past.
+    RequireAtMost {
+        /// Maximum number of sub-requirements that may be satisfied
+        max: u8,
+        /// The sub-requirements to count
+        reqs: SmallVec<[Box<Self>; 8]>,
+    },
+
+    /// Logical implication: `consequent` must hold whenever `antecedent` does
+    ///
+    /// Two-valued evaluation is `!antecedent || consequent` and skips the
+    /// consequent once the antecedent fails. Tri-state evaluation evaluates
+    /// both sides and combines them with [`crate::tristate::TriLogic::implies`],
+    /// so the selected [`crate::LogicMode`] decides the `Unknown` cases; under
+    /// Łukasiewicz logic `Unknown -> Unknown` is `True`.
+    Implies {
+        /// Requirement that triggers the obligation
+        antecedent: Box<Self>,
+        /// Requirement that must hold when the antecedent does
+        consequent: Box<Self>,
+    },
+
     /// Domain-specific atomic condition
     ///
     /// This is the optimization boundary where universal logic hands off
@@ -258,6 +331,13 @@ impl<P> Requirement<P> {
                 Ok(false)
             }
 
+            // Material implication: a failed antecedent satisfies the requirement
+            Self::Implies {
+                antecedent,
+                consequent,
+            } => Ok(!antecedent.eval_with_depth(reader, row, next_depth, max_depth)?
+                || consequent.eval_with_depth(reader, row, next_depth, max_depth)?),
+
             // Optimized group evaluation with mathematical early exit
             Self::RequireGroup {
                 min,
@@ -284,10 +364,66 @@ impl<P> Requirement<P> {
 
                 Ok(satisfied >= usize::from(*min))
             }
+
+            // Exact group evaluation: exit once the count overshoots or falls short
+            Self::RequireExactly {
+                n,
+                reqs,
+            } => {
+                let required = usize::from(*n);
+                let mut satisfied = 0usize;
+                let mut remaining = reqs.len();
+
+                for req in reqs {
+                    if req.eval_with_depth(reader, row, next_depth, max_depth)? {
+                        satisfied += 1;
+                        if satisfied > required {
+                            return Ok(false);
+                        }
+                    }
+
+                    remaining = remaining.saturating_sub(1);
+                    if satisfied + remaining < required {
+                        return Ok(false);
+                    }
+                }
+
+                Ok(satisfied == required)
+            }
+
+            // Upper-bound group evaluation: exit once the count passes the limit or cannot
+            Self::RequireAtMost {
+                max,
+                reqs,
+            } => {
+                let limit = usize::from(*max);
+                let mut satisfied = 0usize;
+                let mut remaining = reqs.len();
+
+                for req in reqs {
+                    if req.eval_with_depth(reader, row, next_depth, max_depth)? {
+                        satisfied += 1;
+                        if satisfied > limit {
+                            return Ok(false);
+                        }
+                    }
+
+                    remaining = remaining.saturating_sub(1);
+                    if satisfied + remaining <= limit {
+                        return Ok(true);
+                    }
+                }
+
+                Ok(satisfied <= limit)
+            }
         }
     }
 
     /// Evaluates a requirement tree in batch mode with an explicit depth limit.
+    #[allow(
+        clippy::too_many_lines,
+        reason = "One arm per operator keeps the mask rules for every operator in one place."
+    )]
     fn eval_block_with_depth(
         &self,
         reader: &P::Reader<'_>,
@@ -339,6 +475,16 @@ impl<P> Requirement<P> {
                 }
                 Ok(mask & valid_mask)
             }
+            Self::Implies {
+                antecedent,
+                consequent,
+            } => {
+                let antecedent_mask =
+                    antecedent.eval_block_with_depth(reader, start, n, next_depth, max_depth)?;
+                let consequent_mask =
+                    consequent.eval_block_with_depth(reader, start, n, next_depth, max_depth)?;
+                Ok((!antecedent_mask | consequent_mask) & valid_mask)
+            }
             Self::RequireGroup {
                 min,
                 reqs,
@@ -351,50 +497,135 @@ impl<P> Requirement<P> {
                     return Ok(0);
                 }
 
-                if min_required == 1 {
-                    let mut mask: super::traits::Mask64 = 0;
-                    for req in reqs {
-                        mask |=
-                            req.eval_block_with_depth(reader, start, n, next_depth, max_depth)?;
-                        if mask == valid_mask {
-                            return Ok(valid_mask);
-                        }
-                    }
-                    return Ok(mask & valid_mask);
+                Self::eval_block_at_least(
+                    reqs,
+                    min_required,
+                    reader,
+                    start,
+                    n,
+                    next_depth,
+                    max_depth,
+                )
+            }
+            Self::RequireExactly {
+                n: required,
+                reqs,
+            } => {
+                let required = usize::from(*required);
+                if required > reqs.len() {
+                    return Ok(0);
                 }
-                if min_required == reqs.len() {
-                    let mut mask = valid_mask;
-                    for req in reqs {
-                        mask &=
-                            req.eval_block_with_depth(reader, start, n, next_depth, max_depth)?;
-                        if mask == 0 {
-                            return Ok(0);
-                        }
-                    }
-                    return Ok(mask & valid_mask);
+                Self::eval_block_counted(
+                    reqs,
+                    |satisfied| satisfied == required,
+                    reader,
+                    start,
+                    n,
+                    next_depth,
+                    max_depth,
+                )
+            }
+            Self::RequireAtMost {
+                max,
+                reqs,
+            } => {
+                let limit = usize::from(*max);
+                if limit >= reqs.len() {
+                    return Ok(valid_mask);
                 }
+                Self::eval_block_counted(
+                    reqs,
+                    |satisfied| satisfied <= limit,
+                    reader,
+                    start,
+                    n,
+                    next_depth,
+                    max_depth,
+                )
+            }
+        }
+    }
 
-                let mut counts: [u8; 64] = [0; 64];
-                for req in reqs {
-                    let mask = req
-                        .eval_block_with_depth(reader, start, n, next_depth, max_depth)?
-                        & valid_mask;
-                    for (idx, count) in counts.iter_mut().enumerate().take(n) {
-                        if ((mask >> idx) & 1) == 1 {
-                            *count = count.saturating_add(1);
-                        }
-                    }
+    /// Evaluates an at-least group in batch mode, using OR/AND fast paths when
+    /// the threshold is one or every member.
+    fn eval_block_at_least(
+        reqs: &[Box<Self>],
+        min_required: usize,
+        reader: &P::Reader<'_>,
+        start: super::traits::Row,
+        count: usize,
+        depth: usize,
+        max_depth: usize,
+    ) -> Result<super::traits::Mask64, EvalDepthExceeded>
+    where
+        P: super::traits::BatchConditionEval,
+    {
+        let valid_mask: super::traits::Mask64 =
+            if count == 64 { super::traits::Mask64::MAX } else { (1u64 << count) - 1 };
+
+        if min_required == 1 {
+            let mut mask: super::traits::Mask64 = 0;
+            for req in reqs {
+                mask |= req.eval_block_with_depth(reader, start, count, depth, max_depth)?;
+                if mask == valid_mask {
+                    return Ok(valid_mask);
                 }
+            }
+            return Ok(mask & valid_mask);
+        }
+        if min_required == reqs.len() {
+            let mut mask = valid_mask;
+            for req in reqs {
+                mask &= req.eval_block_with_depth(reader, start, count, depth, max_depth)?;
+                if mask == 0 {
+                    return Ok(0);
+                }
+            }
+            return Ok(mask & valid_mask);
+        }
 
-                let mut out: super::traits::Mask64 = 0;
-                for (idx, count) in counts.iter().enumerate().take(n) {
-                    if usize::from(*count) >= min_required {
-                        out |= 1u64 << idx;
-                    }
+        Self::eval_block_counted(
+            reqs,
+            |satisfied| satisfied >= min_required,
+            reader,
+            start,
+            count,
+            depth,
+            max_depth,
+        )
+    }
+
+    /// Evaluates a counted group in batch mode, setting rows whose satisfied
+    /// member count passes `accept`.
+    fn eval_block_counted(
+        reqs: &[Box<Self>],
+        accept: impl Fn(usize) -> bool,
+        reader: &P::Reader<'_>,
+        start: super::traits::Row,
+        count: usize,
+        depth: usize,
+        max_depth: usize,
+    ) -> Result<super::traits::Mask64, EvalDepthExceeded>
+    where
+        P: super::traits::BatchConditionEval,
+    {
+        let mut counts: [usize; 64] = [0; 64];
+        for req in reqs {
+            let mask = req.eval_block_with_depth(reader, start, count, depth, max_depth)?;
+            for (idx, satisfied) in counts.iter_mut().enumerate().take(count) {
+                if ((mask >> idx) & 1) == 1 {
+                    *satisfied += 1;
                 }
-                Ok(out & valid_mask)
             }
         }
+
+        let mut out: super::traits::Mask64 = 0;
+        for (idx, satisfied) in counts.iter().enumerate().take(count) {
+            if accept(*satisfied) {
+                out |= 1u64 << idx;
+            }
+        }
+        Ok(out)
     }
 
     // ============================================================================
@@ -439,7 +670,41 @@ impl<P> Requirement<P> {
             .unwrap_or(TriState::Unknown)
     }
 
+    /// Evaluates this requirement with tri-state semantics and explains the result
+    ///
+    /// The explanation mirrors the tree: each operator node carries its result
+    /// and its children's, and each condition leaf carries a [`RequirementId`]
+    /// numbering leaves from 1 in depth-first order. When the tree exceeds
+    /// [`MAX_EVAL_DEPTH`], the result fails closed to `Unknown` and no
+    /// explanation tree is returned.
+    pub fn eval_tristate_with_explanation<L>(
+        &self,
+        reader: &P::Reader<'_>,
+        row: super::traits::Row,
+        logic: &L,
+    ) -> Explanation<P>
+    where
+        P: TriStateConditionEval + Clone,
+        L: TriLogic,
+    {
+        let mut trace = ExplanationTrace::new();
+        match self.eval_tristate_with_depth(reader, row, logic, &mut trace, 0, MAX_EVAL_DEPTH) {
+            Ok(result) => Explanation {
+                result,
+                root: trace.into_root(),
+            },
+            Err(EvalDepthExceeded) => Explanation {
+                result: TriState::Unknown,
+                root: None,
+            },
+        }
+    }
+
     /// Evaluates a requirement tree with tri-state semantics and an explicit depth limit.
+    #[allow(
+        clippy::too_many_lines,
+        reason = "One arm per operator keeps trace hook ordering visible in one place."
+    )]
     fn eval_tristate_with_depth<L, T>(
         &self,
         reader: &P::Reader<'_>,
@@ -465,10 +730,14 @@ impl<P> Requirement<P> {
                 trace.on_condition_evaluated(condition, result);
                 Ok(result)
             }
-            Self::Not(requirement) => Ok(logic.not(
-                requirement
-                    .eval_tristate_with_depth(reader, row, logic, trace, next_depth, max_depth)?,
-            )),
+            Self::Not(requirement) => {
+                let result =
+                    logic.not(requirement.eval_tristate_with_depth(
+                        reader, row, logic, trace, next_depth, max_depth,
+                    )?);
+                trace.on_operator_evaluated(TraceOperator::Not, result);
+                Ok(result)
+            }
             Self::And(requirements) => {
                 let mut acc = TriState::True;
                 for req in requirements {
@@ -479,6 +748,12 @@ impl<P> Requirement<P> {
                         )?,
                     );
                 }
+                trace.on_operator_evaluated(
+                    TraceOperator::And {
+                        children: requirements.len(),
+                    },
+                    acc,
+                );
                 Ok(acc)
             }
             Self::Or(requirements) => {
@@ -491,35 +766,108 @@ impl<P> Requirement<P> {
                         )?,
                     );
                 }
+                trace.on_operator_evaluated(
+                    TraceOperator::Or {
+                        children: requirements.len(),
+                    },
+                    acc,
+                );
                 Ok(acc)
             }
+            Self::Implies {
+                antecedent,
+                consequent,
+            } => {
+                let lhs = antecedent
+                    .eval_tristate_with_depth(reader, row, logic, trace, next_depth, max_depth)?;
+                let rhs = consequent
+                    .eval_tristate_with_depth(reader, row, logic, trace, next_depth, max_depth)?;
+                let result = logic.implies(lhs, rhs);
+                trace.on_operator_evaluated(TraceOperator::Implies, result);
+                Ok(result)
+            }
             Self::RequireGroup {
                 min,
                 reqs,
             } => {
-                let mut satisfied = 0usize;
-                let mut unknown = 0usize;
-
-                for req in reqs {
-                    match req.eval_tristate_with_depth(
-                        reader, row, logic, trace, next_depth, max_depth,
-                    )? {
-                        TriState::True => satisfied += 1,
-                        TriState::Unknown => unknown += 1,
-                        TriState::False => {}
-                    }
-                }
-
-                Ok(logic.require_group(
-                    *min,
-                    GroupCounts {
-                        satisfied,
-                        unknown,
-                        total: reqs.len(),
+                let counts = Self::eval_group_counts(
+                    reqs, reader, row, logic, trace, next_depth, max_depth,
+                )?;
+                let result = logic.require_group(*min, counts);
+                trace.on_operator_evaluated(
+                    TraceOperator::RequireGroup {
+                        min: *min,
+                        counts,
+                    },
+                    result,
+                );
+                Ok(result)
+            }
+            Self::RequireExactly {
+                n,
+                reqs,
+            } => {
+                let counts = Self::eval_group_counts(
+                    reqs, reader, row, logic, trace, next_depth, max_depth,
+                )?;
+                let result = logic.require_exactly(*n, counts);
+                trace.on_operator_evaluated(
+                    TraceOperator::RequireExactly {
+                        n: *n,
+                        counts,
                     },
-                ))
+                    result,
+                );
+                Ok(result)
+            }
+            Self::RequireAtMost {
+                max,
+                reqs,
+            } => {
+                let counts = Self::eval_group_counts(
+                    reqs, reader, row, logic, trace, next_depth, max_depth,
+                )?;
+                let result = logic.require_at_most(*max, counts);
+                trace.on_operator_evaluated(
+                    TraceOperator::RequireAtMost {
+                        max: *max,
+                        counts,
+                    },
+                    result,
+                );
+                Ok(result)
+            }
+        }
+    }
+
+    /// Evaluates group members with tri-state semantics and tallies their outcomes.
+    fn eval_group_counts<L, T>(
+        reqs: &[Box<Self>],
+        reader: &P::Reader<'_>,
+        row: super::traits::Row,
+        logic: &L,
+        trace: &mut T,
+        depth: usize,
+        max_depth: usize,
+    ) -> Result<GroupCounts, EvalDepthExceeded>
+    where
+        P: TriStateConditionEval,
+        L: TriLogic,
+        T: RequirementTrace<P>,
+    {
+        let mut counts = GroupCounts {
+            satisfied: 0,
+            unknown: 0,
+            total: reqs.len(),
+        };
+        for req in reqs {
+            match req.eval_tristate_with_depth(reader, row, logic, trace, depth, max_depth)? {
+                TriState::True => counts.satisfied += 1,
+                TriState::Unknown => counts.unknown += 1,
+                TriState::False => {}
             }
         }
+        Ok(counts)
     }
 
     /// Determines if this requirement is trivially satisfied
@@ -537,6 +885,13 @@ impl<P> Requirement<P> {
             // Not is satisfied if the sub-requirement is trivially unsatisfiable
             Self::Not(req) => req.is_trivially_unsatisfiable(),
 
+            // Implication is satisfied if the antecedent can never hold or the
+            // consequent always does
+            Self::Implies {
+                antecedent,
+                consequent,
+            } => antecedent.is_trivially_unsatisfiable() || consequent.is_trivially_satisfied(),
+
             // Group with min = 0 is trivially satisfied
             Self::RequireGroup {
                 min, ..
@@ -552,6 +907,30 @@ impl<P> Requirement<P> {
                 trivially_satisfied_count >= usize::from(*min)
             }
 
+            // Exact group is satisfied if exactly n members are trivially satisfied
+            // and the rest are trivially unsatisfiable
+            Self::RequireExactly {
+                n,
+                reqs,
+            } => {
+                let trivially_satisfied_count =
+                    reqs.iter().filter(|r| r.is_trivially_satisfied()).count();
+                let trivially_unsatisfiable_count =
+                    reqs.iter().filter(|r| r.is_trivially_unsatisfiable()).count();
+                trivially_satisfied_count == usize::from(*n)
+                    && trivially_satisfied_count + trivially_unsatisfiable_count == reqs.len()
+            }
+
+            // Upper-bound group is satisfied if too few members could ever be satisfied
+            Self::RequireAtMost {
+                max,
+                reqs,
+            } => {
+                let unsatisfiable_count =
+                    reqs.iter().filter(|r| r.is_trivially_unsatisfiable()).count();
+                reqs.len() - unsatisfiable_count <= usize::from(*max)
+            }
+
             // Conditions require domain-specific analysis
             Self::Condition(_) => false,
         }
@@ -572,6 +951,13 @@ impl<P> Requirement<P> {
             // Not is unsatisfiable if the sub-requirement is trivially satisfied
             Self::Not(req) => req.is_trivially_satisfied(),
 
+            // Implication is unsatisfiable if the antecedent always holds and the
+            // consequent never does
+            Self::Implies {
+                antecedent,
+                consequent,
+            } => antecedent.is_trivially_satisfied() && consequent.is_trivially_unsatisfiable(),
+
             // Group is unsatisfiable if min > total requirements
             Self::RequireGroup {
                 min,
@@ -589,6 +975,30 @@ impl<P> Requirement<P> {
                 max_satisfiable < usize::from(*min)
             }
 
+            // Exact group is unsatisfiable if too many members are trivially satisfied
+            // or too many are trivially unsatisfiable
+            Self::RequireExactly {
+                n,
+                reqs,
+            } => {
+                let trivially_satisfied_count =
+                    reqs.iter().filter(|r| r.is_trivially_satisfied()).count();
+                let unsatisfiable_count =
+                    reqs.iter().filter(|r| r.is_trivially_unsatisfiable()).count();
+                let max_satisfiable = reqs.len() - unsatisfiable_count;
+                trivially_satisfied_count > usize::from(*n) || max_satisfiable < usize::from(*n)
+            }
+
+            // Upper-bound group is unsatisfiable if too many members are trivially satisfied
+            Self::RequireAtMost {
+                max,
+                reqs,
+            } => {
+                let trivially_satisfied_count =
+                    reqs.iter().filter(|r| r.is_trivially_satisfied()).count();
+                trivially_satisfied_count > usize::from(*max)
+            }
+
             // Conditions require domain-specific analysis
             Self::Condition(_) => false,
         }
@@ -599,11 +1009,21 @@ impl<P> Requirement<P> {
         match self {
             Self::Condition(_) => 1,
             Self::Not(req) => 1 + req.complexity(),
+            Self::Implies {
+                antecedent,
+                consequent,
+            } => 1 + antecedent.complexity() + consequent.complexity(),
             Self::And(reqs) | Self::Or(reqs) => {
                 1 + reqs.iter().map(|r| r.complexity()).sum::<usize>()
             }
             Self::RequireGroup {
                 reqs, ..
+            }
+            | Self::RequireExactly {
+                reqs, ..
+            }
+            | Self::RequireAtMost {
+                reqs, ..
             } => 1 + reqs.iter().map(|r| r.complexity()).sum::<usize>(),
         }
     }
@@ -637,6 +1057,30 @@ impl<P> Requirement<P> {
         }
     }
 
+    /// Creates an exact group requirement: exactly `n` members must be satisfied
+    pub fn require_exactly(n: u8, requirements: Vec<Self>) -> Self {
+        Self::RequireExactly {
+            n,
+            reqs: requirements.into_iter().map(Box::new).collect(),
+        }
+    }
+
+    /// Creates an upper-bound group requirement: at most `max` members may be satisfied
+    pub fn require_at_most(max: u8, requirements: Vec<Self>) -> Self {
+        Self::RequireAtMost {
+            max,
+            reqs: requirements.into_iter().map(Box::new).collect(),
+        }
+    }
+
+    /// Creates an implication: `consequent` must hold whenever `antecedent` does
+    pub fn implies(antecedent: Self, consequent: Self) -> Self {
+        Self::Implies {
+            antecedent: Box::new(antecedent),
+            consequent: Box::new(consequent),
+        }
+    }
+
     /// Creates a requirement from a condition
     pub const fn condition(condition: P) -> Self {
         Self::Condition(condition)
@@ -651,6 +1095,294 @@ impl<P> std::ops::Not for Requirement<P> {
     }
 }
 
+// ============================================================================
+// SECTION: Structural Hashing
+// ============================================================================
+
+impl<P: Hash> Requirement<P> {
+    /// Returns a stable structural digest of this requirement tree
+    ///
+    /// The digest covers operator tags, child counts, `RequireGroup`,
+    /// `RequireExactly`, and `RequireAtMost` thresholds, and each condition's
+    /// [`Hash`] output, so structurally identical trees hash equally across
+    /// processes and platforms. Member
+    /// order is significant; call [`Requirement::canonicalize`] first to hash
+    /// equivalent `And`/`Or` specs identically.
+    #[must_use]
+    pub fn structural_hash(&self) -> HashDigest {
+        let mut hasher = StructuralHasher::new(REQUIREMENT_HASH_DOMAIN);
+        self.hash_structure(&mut hasher);
+        hasher.digest()
+    }
+
+    /// Feeds the tagged structure of this node into `hasher`.
+    fn hash_structure(&self, hasher: &mut StructuralHasher) {
+        match self {
+            Self::Condition(condition) => {
+                hasher.write_u8(HASH_TAG_CONDITION);
+                condition.hash(hasher);
+            }
+            Self::Not(requirement) => {
+                hasher.write_u8(HASH_TAG_NOT);
+                requirement.hash_structure(hasher);
+            }
+            Self::Implies {
+                antecedent,
+                consequent,
+            } => {
+                hasher.write_u8(HASH_TAG_IMPLIES);
+                antecedent.hash_structure(hasher);
+                consequent.hash_structure(hasher);
+            }
+            Self::And(reqs) => {
+                hasher.write_u8(HASH_TAG_AND);
+                hash_members(hasher, reqs);
+            }
+            Self::Or(reqs) => {
+                hasher.write_u8(HASH_TAG_OR);
+                hash_members(hasher, reqs);
+            }
+            Self::RequireGroup {
+                min,
+                reqs,
+            } => {
+                hasher.write_u8(HASH_TAG_REQUIRE_GROUP);
+                hasher.write_u8(*min);
+                hash_members(hasher, reqs);
+            }
+            Self::RequireExactly {
+                n,
+                reqs,
+            } => {
+                hasher.write_u8(HASH_TAG_REQUIRE_EXACTLY);
+                hasher.write_u8(*n);
+                hash_members(hasher, reqs);
+            }
+            Self::RequireAtMost {
+                max,
+                reqs,
+            } => {
+                hasher.write_u8(HASH_TAG_REQUIRE_AT_MOST);
+                hasher.write_u8(*max);
+                hash_members(hasher, reqs);
+            }
+        }
+    }
+}
+
+/// Feeds a length-prefixed member list into `hasher`.
+fn hash_members<P: Hash>(hasher: &mut StructuralHasher, members: &[Box<Requirement<P>>]) {
+    hasher.write_usize(members.len());
+    for member in members {
+        member.hash_structure(hasher);
+    }
+}
+
+// ============================================================================
+// SECTION: Canonicalization
+// ============================================================================
+
+impl<P: Hash + PartialEq> Requirement<P> {
+    /// Returns the canonical form of this requirement tree
+    ///
+    /// `And` and `Or` members are sorted by [`Requirement::structural_hash`] and
+    /// structurally equal members are removed; both operators are commutative
+    /// and idempotent, so the result is unchanged. Members of `RequireGroup`,
+    /// `RequireExactly`, and `RequireAtMost` keep their order and duplicates
+    /// because each member counts toward the threshold and the order is the
+    /// disclosure order. `Implies` keeps its antecedent and consequent in place.
+    /// Members of every node are canonicalized recursively, so equivalent
+    /// trees canonicalize to equal values and hash identically.
+    ///
+    /// Short-circuit and trace order for `And`/`Or` follow the canonical order.
+    #[must_use]
+    pub fn canonicalize(self) -> Self {
+        match self {
+            Self::Condition(condition) => Self::Condition(condition),
+            Self::Not(requirement) => Self::Not(Box::new(requirement.canonicalize())),
+            Self::Implies {
+                antecedent,
+                consequent,
+            } => Self::Implies {
+                antecedent: Box::new(antecedent.canonicalize()),
+                consequent: Box::new(consequent.canonicalize()),
+            },
+            Self::And(reqs) => Self::And(canonical_set(reqs)),
+            Self::Or(reqs) => Self::Or(canonical_set(reqs)),
+            Self::RequireGroup {
+                min,
+                reqs,
+            } => Self::RequireGroup {
+                min,
+                reqs: canonical_sequence(reqs),
+            },
+            Self::RequireExactly {
+                n,
+                reqs,
+            } => Self::RequireExactly {
+                n,
+                reqs: canonical_sequence(reqs),
+            },
+            Self::RequireAtMost {
+                max,
+                reqs,
+            } => Self::RequireAtMost {
+                max,
+                reqs: canonical_sequence(reqs),
+            },
+        }
+    }
+}
+
+/// Canonicalizes members of a commutative, idempotent node: sort by structural hash and dedup.
+fn canonical_set<P, A>(members: SmallVec<A>) -> SmallVec<A>
+where
+    P: Hash + PartialEq,
+    A: Array<Item = Box<Requirement<P>>>,
+{
+    let mut keyed: Vec<(HashDigest, Box<Requirement<P>>)> = members
+        .into_iter()
+        .map(|member| {
+            let member = Box::new(member.canonicalize());
+            (member.structural_hash(), member)
+        })
+        .collect();
+    keyed.sort_by_key(|(key, _)| *key);
+    keyed.dedup_by(|(lhs_key, lhs), (rhs_key, rhs)| lhs_key == rhs_key && lhs == rhs);
+    keyed.into_iter().map(|(_, member)| member).collect()
+}
+
+/// Canonicalizes members of an order-sensitive node without reordering.
+fn canonical_sequence<P, A>(members: SmallVec<A>) -> SmallVec<A>
+where
+    P: Hash + PartialEq,
+    A: Array<Item = Box<Requirement<P>>>,
+{
+    members.into_iter().map(|member| Box::new(member.canonicalize())).collect()
+}
+
+// ============================================================================
+// SECTION: Cost Ordering
+// ============================================================================
+
+impl<P: ConditionEval> Requirement<P> {
+    /// Returns the estimated cost of evaluating every condition in this tree
+    ///
+    /// The sum (saturating) of [`ConditionEval::cost`] over all leaves.
+    pub fn cost(&self) -> u32 {
+        match self {
+            Self::Condition(condition) => condition.cost(),
+            Self::Not(requirement) => requirement.cost(),
+            Self::Implies {
+                antecedent,
+                consequent,
+            } => antecedent.cost().saturating_add(consequent.cost()),
+            Self::And(reqs) | Self::Or(reqs) => {
+                reqs.iter().fold(0, |total, req| total.saturating_add(req.cost()))
+            }
+            Self::RequireGroup {
+                reqs, ..
+            }
+            | Self::RequireExactly {
+                reqs, ..
+            }
+            | Self::RequireAtMost {
+                reqs, ..
+            } => reqs.iter().fold(0, |total, req| total.saturating_add(req.cost())),
+        }
+    }
+
+    /// Reorders members so cheaper subtrees are evaluated first
+    ///
+    /// Members of `And` and `Or` are stably sorted by ascending
+    /// [`Requirement::cost`], so short-circuiting skips expensive conditions
+    /// whenever a cheap one decides the node. Both operators are commutative
+    /// under every logic mode, so results are unchanged; members of equal cost
+    /// keep their relative order. Counted groups (`RequireGroup`,
+    /// `RequireExactly`, `RequireAtMost`) keep their declared order because it
+    /// is the disclosure order, and `Implies` keeps its antecedent first, though
+    /// their members are reordered internally.
+    ///
+    /// Trees deeper than [`MAX_EVAL_DEPTH`] are left untouched so they keep
+    /// failing closed regardless of which branch evaluation reaches first.
+    /// Trace and explanation order follow the new order, and
+    /// [`Requirement::structural_hash`] changes when members move.
+    pub fn reorder_by_cost(&mut self) {
+        if self.within_eval_depth(0) {
+            self.reorder_members_by_cost();
+        }
+    }
+
+    /// Returns whether no node below `depth` exceeds [`MAX_EVAL_DEPTH`].
+    fn within_eval_depth(&self, depth: usize) -> bool {
+        if depth > MAX_EVAL_DEPTH {
+            return false;
+        }
+        match self {
+            Self::Condition(_) => true,
+            Self::Not(requirement) => requirement.within_eval_depth(depth + 1),
+            Self::Implies {
+                antecedent,
+                consequent,
+            } => {
+                antecedent.within_eval_depth(depth + 1) && consequent.within_eval_depth(depth + 1)
+            }
+            Self::And(reqs) | Self::Or(reqs) => {
+                reqs.iter().all(|req| req.within_eval_depth(depth + 1))
+            }
+            Self::RequireGroup {
+                reqs, ..
+            }
+            | Self::RequireExactly {
+                reqs, ..
+            }
+            | Self::RequireAtMost {
+                reqs, ..
+            } => reqs.iter().all(|req| req.within_eval_depth(depth + 1)),
+        }
+    }
+
+    /// Reorders every `And`/`Or` node and returns the tree's cost.
+    fn reorder_members_by_cost(&mut self) -> u32 {
+        match self {
+            Self::Condition(condition) => condition.cost(),
+            Self::Not(requirement) => requirement.reorder_members_by_cost(),
+            Self::Implies {
+                antecedent,
+                consequent,
+            } => antecedent
+                .reorder_members_by_cost()
+                .saturating_add(consequent.reorder_members_by_cost()),
+            Self::And(reqs) | Self::Or(reqs) => sort_members_by_cost(reqs),
+            Self::RequireGroup {
+                reqs, ..
+            }
+            | Self::RequireExactly {
+                reqs, ..
+            }
+            | Self::RequireAtMost {
+                reqs, ..
+            } => reqs
+                .iter_mut()
+                .fold(0, |total, req| total.saturating_add(req.reorder_members_by_cost())),
+        }
+    }
+}
+
+/// Stably sorts members by ascending cost, returning their total cost.
+fn sort_members_by_cost<P, A>(members: &mut SmallVec<A>) -> u32
+where
+    P: ConditionEval,
+    A: Array<Item = Box<Requirement<P>>>,
+{
+    let mut costed: Vec<(u32, Box<Requirement<P>>)> =
+        members.drain(..).map(|mut member| (member.reorder_members_by_cost(), member)).collect();
+    costed.sort_by_key(|(cost, _)| *cost);
+    let total = costed.iter().fold(0u32, |total, (cost, _)| total.saturating_add(*cost));
+    members.extend(costed.into_iter().map(|(_, member)| member));
+    total
+}
+
 // ============================================================================
 // SECTION: Default Implementations
 // ============================================================================
@@ -759,3 +1491,35 @@ impl<P> RequirementGroup<P> {
         Self::new(requirements, 1)
     }
 }
+
+impl<P: Hash + PartialEq> RequirementGroup<P> {
+    /// Returns the canonical form of this group
+    ///
+    /// Members are canonicalized recursively via [`Requirement::canonicalize`].
+    /// "All" groups (`min_required == requirements.len()`) and "any" groups
+    /// (`min_required == 1`) are order-independent, so their members are sorted
+    /// and deduplicated; an "all" group keeps `min_required` equal to the new
+    /// member count. Quorum groups keep member order and duplicates, since both
+    /// affect counting and disclosure order.
+    #[must_use]
+    pub fn canonicalize(self) -> Self {
+        let total = self.requirements.len();
+        if total > 0 && self.min_required == total {
+            let requirements = canonical_set(self.requirements);
+            return Self {
+                min_required: requirements.len(),
+                requirements,
+            };
+        }
+        if self.min_required == 1 {
+            return Self {
+                requirements: canonical_set(self.requirements),
+                min_required: 1,
+            };
+        }
+        Self {
+            requirements: canonical_sequence(self.requirements),
+            min_required: self.min_required,
+        }
+    }
+}
@@ -226,6 +226,12 @@ impl RequirementValidator {
             }
             Requirement::RequireGroup {
                 reqs, ..
+            }
+            | Requirement::RequireExactly {
+                reqs, ..
+            }
+            | Requirement::RequireAtMost {
+                reqs, ..
             } => {
                 for req in reqs {
                     self.validate_depth(req, current_depth + 1)?;
@@ -234,6 +240,13 @@ impl RequirementValidator {
             Requirement::Not(req) => {
                 self.validate_depth(req, current_depth + 1)?;
             }
+            Requirement::Implies {
+                antecedent,
+                consequent,
+            } => {
+                self.validate_depth(antecedent, current_depth + 1)?;
+                self.validate_depth(consequent, current_depth + 1)?;
+            }
             Requirement::Condition(_) => {
                 // Conditions are leaf nodes - no further depth
             }
@@ -288,10 +301,41 @@ impl RequirementValidator {
                 }
             }
 
+            Requirement::RequireExactly {
+                n,
+                reqs,
+            } => {
+                if usize::from(*n) > reqs.len() {
+                    return Err(SerdeError::InvalidGroup {
+                        min: *n,
+                        total: reqs.len(),
+                    });
+                }
+                for req in reqs {
+                    self.validate_structure(req)?;
+                }
+            }
+
+            Requirement::RequireAtMost {
+                reqs, ..
+            } => {
+                for req in reqs {
+                    self.validate_structure(req)?;
+                }
+            }
+
             Requirement::Not(req) => {
                 self.validate_structure(req)?;
             }
 
+            Requirement::Implies {
+                antecedent,
+                consequent,
+            } => {
+                self.validate_structure(antecedent)?;
+                self.validate_structure(consequent)?;
+            }
+
             Requirement::Condition(_) => {
                 // Conditions are validated by the domain during compilation
             }
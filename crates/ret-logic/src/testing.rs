@@ -0,0 +1,597 @@
+// crates/ret-logic/src/testing.rs
+// ============================================================================
+// Module: Differential Testing Hooks
+// Description: Random requirement generation and a reference interpreter.
+// Purpose: Let fuzz and property tests cross-check the evaluators.
+// Dependencies: crate::{error, executor, plan, requirement, traits, tristate}
+// ============================================================================
+
+//! ## Overview
+//! Differential testing support for the requirement evaluators. The module
+//! generates random [`Requirement`] trees over [`FuzzCondition`] leaves plus
+//! random tri-state row inputs, and exposes [`reference_eval`], a naive
+//! tree-walking interpreter that encodes the documented truth tables
+//! directly. Tests compare [`Requirement::eval_tristate`], [`Requirement::eval`],
+//! and a [`PlanExecutor`] built by [`plan_executor`] against the reference
+//! for every [`LogicMode`].
+//!
+//! Generation is driven by [`FuzzRng`], a seedable `SplitMix64` generator, so
+//! downstream harnesses can map raw fuzzer input or proptest seeds onto
+//! reproducible trees without an extra dependency.
+//!
+//! This module is compiled only with the `testing` feature and is not part
+//! of the runtime evaluation surface.
+
+// ============================================================================
+// SECTION: Imports
+// ============================================================================
+
+use super::error::RequirementError;
+use super::error::RequirementResult;
+use super::executor::ExecutorBuilder;
+use super::executor::PlanExecutor;
+use super::plan::Constant;
+use super::plan::OpCode;
+use super::plan::Operation;
+use super::plan::Plan;
+use super::requirement::Requirement;
+use super::traits::BatchConditionEval;
+use super::traits::ConditionEval;
+use super::traits::ReaderLen;
+use super::traits::Row;
+use super::traits::TriStateConditionEval;
+use super::tristate::LogicMode;
+use super::tristate::TriState;
+
+// ============================================================================
+// SECTION: Constants
+// ============================================================================
+
+/// Every supported logic mode, for exhaustive differential comparisons.
+pub const LOGIC_MODES: [LogicMode; 3] =
+    [LogicMode::Kleene, LogicMode::Bochvar, LogicMode::Lukasiewicz];
+
+/// Opcode used by compiled fuzz plans to read a condition slot.
+///
+/// `operand_a` carries the slot index.
+pub const FUZZ_CONDITION_OPCODE: OpCode = OpCode::DomainStart;
+
+// ============================================================================
+// SECTION: Random Source
+// ============================================================================
+
+/// Seedable `SplitMix64` generator used for requirement and row generation
+///
+/// # Invariants
+/// - Output is fully determined by the seed; identical seeds replay identical trees.
+#[derive(Debug, Clone)]
+pub struct FuzzRng {
+    /// Current generator state.
+    state: u64,
+}
+
+impl FuzzRng {
+    /// Creates a generator from a seed
+    #[must_use]
+    pub const fn new(seed: u64) -> Self {
+        Self {
+            state: seed,
+        }
+    }
+
+    /// Returns the next raw 64-bit value
+    pub const fn next_u64(&mut self) -> u64 {
+        self.state = self.state.wrapping_add(0x9E37_79B9_7F4A_7C15);
+        let mut z = self.state;
+        z = (z ^ (z >> 30)).wrapping_mul(0xBF58_476D_1CE4_E5B9);
+        z = (z ^ (z >> 27)).wrapping_mul(0x94D0_49BB_1331_11EB);
+        z ^ (z >> 31)
+    }
+
+    /// Returns a value in `0 .. bound`, or zero when `bound` is zero
+    pub fn below(&mut self, bound: usize) -> usize {
+        let bound = u64::try_from(bound).unwrap_or(u64::MAX);
+        if bound == 0 {
+            return 0;
+        }
+        usize::try_from(self.next_u64() % bound).unwrap_or(0)
+    }
+}
+
+// ============================================================================
+// SECTION: Fuzz Conditions
+// ============================================================================
+
+/// Leaf condition that reads a tri-state input slot from a [`FuzzReader`]
+///
+/// Boolean evaluation treats only [`TriState::True`] as satisfied.
+///
+/// # Invariants
+/// - Slots outside the reader width evaluate to [`TriState::Unknown`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct FuzzCondition(pub u16);
+
+/// Row-major table of tri-state condition inputs
+///
+/// # Invariants
+/// - `cells.len()` is always a multiple of `width`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct FuzzReader {
+    /// Number of condition slots per row.
+    width: usize,
+    /// Row-major slot values.
+    cells: Vec<TriState>,
+}
+
+impl FuzzReader {
+    /// Creates an empty reader with `width` slots per row
+    #[must_use]
+    pub const fn new(width: usize) -> Self {
+        Self {
+            width,
+            cells: Vec::new(),
+        }
+    }
+
+    /// Appends a row, truncating or padding it with `Unknown` to the reader width
+    pub fn push_row(&mut self, values: &[TriState]) {
+        for slot in 0 .. self.width {
+            self.cells.push(values.get(slot).copied().unwrap_or(TriState::Unknown));
+        }
+    }
+
+    /// Returns the number of slots per row
+    #[must_use]
+    pub const fn width(&self) -> usize {
+        self.width
+    }
+
+    /// Returns the value of `slot` in `row`, or `Unknown` when out of range
+    #[must_use]
+    pub fn value(&self, row: Row, slot: u16) -> TriState {
+        let slot = usize::from(slot);
+        if slot >= self.width {
+            return TriState::Unknown;
+        }
+        row.checked_mul(self.width)
+            .and_then(|base| base.checked_add(slot))
+            .and_then(|index| self.cells.get(index))
+            .copied()
+            .unwrap_or(TriState::Unknown)
+    }
+
+    /// Returns true when every slot in `row` is `True` or `False`
+    #[must_use]
+    pub fn row_is_known(&self, row: Row) -> bool {
+        (0 .. self.width).all(|slot| {
+            u16::try_from(slot).is_ok_and(|slot| self.value(row, slot) != TriState::Unknown)
+        })
+    }
+}
+
+impl ReaderLen for FuzzReader {
+    fn len(&self) -> usize {
+        if self.width == 0 { 0 } else { self.cells.len() / self.width }
+    }
+}
+
+impl ConditionEval for FuzzCondition {
+    type Reader<'a> = FuzzReader;
+
+    fn eval_row(&self, reader: &Self::Reader<'_>, row: Row) -> bool {
+        reader.value(row, self.0) == TriState::True
+    }
+}
+
+impl BatchConditionEval for FuzzCondition {}
+
+impl TriStateConditionEval for FuzzCondition {
+    type Reader<'a> = FuzzReader;
+
+    fn eval_row_tristate(&self, reader: &Self::Reader<'_>, row: Row) -> TriState {
+        reader.value(row, self.0)
+    }
+}
+
+// ============================================================================
+// SECTION: Random Generation
+// ============================================================================
+
+/// Shape limits for [`random_requirement`] and [`random_rows`]
+///
+/// # Invariants
+/// - Plans compiled from generated trees stay within the executor stack limit when `max_depth` is
+///   at most 8.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct RandomRequirementConfig {
+    /// Maximum nesting depth of generated trees.
+    pub max_depth: usize,
+    /// Maximum children per `And`, `Or`, or counted group node.
+    pub max_children: usize,
+    /// Number of distinct condition slots leaves may reference.
+    pub condition_slots: u16,
+}
+
+impl Default for RandomRequirementConfig {
+    fn default() -> Self {
+        Self {
+            max_depth: 4,
+            max_children: 4,
+            condition_slots: 6,
+        }
+    }
+}
+
+/// Generates a random requirement tree within the configured shape limits
+///
+/// Trees cover every operator, including implications, empty groups, and
+/// `RequireGroup`, `RequireExactly`, and `RequireAtMost` thresholds of zero and
+/// above the child count.
+#[must_use]
+pub fn random_requirement(
+    rng: &mut FuzzRng,
+    config: &RandomRequirementConfig,
+) -> Requirement<FuzzCondition> {
+    random_node(rng, config, 0)
+}
+
+/// Generates one node of a random requirement tree.
+fn random_node(
+    rng: &mut FuzzRng,
+    config: &RandomRequirementConfig,
+    depth: usize,
+) -> Requirement<FuzzCondition> {
+    let variant = if depth >= config.max_depth { 0 } else { rng.below(8) };
+    match variant {
+        1 => Requirement::and(random_children(rng, config, depth)),
+        2 => Requirement::or(random_children(rng, config, depth)),
+        3 => Requirement::negate(random_node(rng, config, depth + 1)),
+        4 => {
+            let children = random_children(rng, config, depth);
+            let min = u8::try_from(rng.below(children.len() + 2)).unwrap_or(u8::MAX);
+            Requirement::require_group(min, children)
+        }
+        5 => {
+            let children = random_children(rng, config, depth);
+            let n = u8::try_from(rng.below(children.len() + 2)).unwrap_or(u8::MAX);
+            Requirement::require_exactly(n, children)
+        }
+        6 => {
+            let children = random_children(rng, config, depth);
+            let max = u8::try_from(rng.below(children.len() + 2)).unwrap_or(u8::MAX);
+            Requirement::require_at_most(max, children)
+        }
+        7 => Requirement::implies(
+            random_node(rng, config, depth + 1),
+            random_node(rng, config, depth + 1),
+        ),
+        _ => random_leaf(rng, config),
+    }
+}
+
+/// Generates the children of a random composite node.
+fn random_children(
+    rng: &mut FuzzRng,
+    config: &RandomRequirementConfig,
+    depth: usize,
+) -> Vec<Requirement<FuzzCondition>> {
+    let count = rng.below(config.max_children + 1);
+    (0 .. count).map(|_| random_node(rng, config, depth + 1)).collect()
+}
+
+/// Generates a random leaf condition.
+fn random_leaf(rng: &mut FuzzRng, config: &RandomRequirementConfig) -> Requirement<FuzzCondition> {
+    let slot = u16::try_from(rng.below(usize::from(config.condition_slots))).unwrap_or(0);
+    Requirement::condition(FuzzCondition(slot))
+}
+
+/// Generates `rows` random input rows sized to the configured condition slots
+///
+/// When `allow_unknown` is false every slot is `True` or `False`.
+#[must_use]
+pub fn random_rows(
+    rng: &mut FuzzRng,
+    config: &RandomRequirementConfig,
+    rows: usize,
+    allow_unknown: bool,
+) -> FuzzReader {
+    let width = usize::from(config.condition_slots);
+    let choices = if allow_unknown { 3 } else { 2 };
+    let mut reader = FuzzReader::new(width);
+    let mut values = Vec::with_capacity(width);
+    for _ in 0 .. rows {
+        values.clear();
+        values.extend((0 .. width).map(|_| match rng.below(choices) {
+            0 => TriState::False,
+            1 => TriState::True,
+            _ => TriState::Unknown,
+        }));
+        reader.push_row(&values);
+    }
+    reader
+}
+
+// ============================================================================
+// SECTION: Reference Interpreter
+// ============================================================================
+
+/// Evaluates a requirement with a naive tree-walking interpreter
+///
+/// The truth tables are written out here rather than delegated to
+/// [`crate::tristate::TriLogic`], so disagreements surface bugs in either side.
+/// There is no depth limit; callers should bound tree depth.
+#[must_use]
+pub fn reference_eval(
+    requirement: &Requirement<FuzzCondition>,
+    reader: &FuzzReader,
+    row: Row,
+    mode: LogicMode,
+) -> TriState {
+    match requirement {
+        Requirement::Condition(condition) => reader.value(row, condition.0),
+        Requirement::Not(inner) => match reference_eval(inner, reader, row, mode) {
+            TriState::True => TriState::False,
+            TriState::False => TriState::True,
+            TriState::Unknown => TriState::Unknown,
+        },
+        Requirement::And(children) => {
+            let values: Vec<TriState> =
+                children.iter().map(|child| reference_eval(child, reader, row, mode)).collect();
+            let any_unknown = values.contains(&TriState::Unknown);
+            let any_false = values.contains(&TriState::False);
+            match mode {
+                LogicMode::Bochvar if any_unknown => TriState::Unknown,
+                _ if any_false => TriState::False,
+                _ if any_unknown => TriState::Unknown,
+                _ => TriState::True,
+            }
+        }
+        Requirement::Or(children) => {
+            let values: Vec<TriState> =
+                children.iter().map(|child| reference_eval(child, reader, row, mode)).collect();
+            let any_unknown = values.contains(&TriState::Unknown);
+            let any_true = values.contains(&TriState::True);
+            match mode {
+                LogicMode::Bochvar if any_unknown => TriState::Unknown,
+                _ if any_true => TriState::True,
+                _ if any_unknown => TriState::Unknown,
+                _ => TriState::False,
+            }
+        }
+        Requirement::RequireGroup {
+            min,
+            reqs,
+        } => {
+            let values: Vec<TriState> =
+                reqs.iter().map(|child| reference_eval(child, reader, row, mode)).collect();
+            let satisfied = values.iter().filter(|value| **value == TriState::True).count();
+            let unknown = values.iter().filter(|value| **value == TriState::Unknown).count();
+            let min = usize::from(*min);
+            if satisfied >= min {
+                TriState::True
+            } else if satisfied + unknown < min {
+                TriState::False
+            } else {
+                TriState::Unknown
+            }
+        }
+        Requirement::RequireExactly {
+            n,
+            reqs,
+        } => {
+            let values: Vec<TriState> =
+                reqs.iter().map(|child| reference_eval(child, reader, row, mode)).collect();
+            let satisfied = values.iter().filter(|value| **value == TriState::True).count();
+            let unknown = values.iter().filter(|value| **value == TriState::Unknown).count();
+            let n = usize::from(*n);
+            if satisfied > n || satisfied + unknown < n {
+                TriState::False
+            } else if unknown == 0 {
+                TriState::True
+            } else {
+                TriState::Unknown
+            }
+        }
+        Requirement::RequireAtMost {
+            max,
+            reqs,
+        } => {
+            let values: Vec<TriState> =
+                reqs.iter().map(|child| reference_eval(child, reader, row, mode)).collect();
+            let satisfied = values.iter().filter(|value| **value == TriState::True).count();
+            let unknown = values.iter().filter(|value| **value == TriState::Unknown).count();
+            let max = usize::from(*max);
+            if satisfied > max {
+                TriState::False
+            } else if satisfied + unknown <= max {
+                TriState::True
+            } else {
+                TriState::Unknown
+            }
+        }
+        Requirement::Implies {
+            antecedent,
+            consequent,
+        } => {
+            let lhs = reference_eval(antecedent, reader, row, mode);
+            let rhs = reference_eval(consequent, reader, row, mode);
+            match (mode, lhs, rhs) {
+                (LogicMode::Bochvar, TriState::Unknown, _)
+                | (LogicMode::Bochvar, _, TriState::Unknown) => TriState::Unknown,
+                (_, TriState::False, _)
+                | (_, _, TriState::True)
+                | (LogicMode::Lukasiewicz, TriState::Unknown, TriState::Unknown) => TriState::True,
+                (_, TriState::True, TriState::False) => TriState::False,
+                _ => TriState::Unknown,
+            }
+        }
+    }
+}
+
+// ============================================================================
+// SECTION: Plan Compilation
+// ============================================================================
+
+/// Compiles a fuzz requirement into an executor plan
+///
+/// `Not` is wrapped in its own `AndStart`/`AndEnd` frame because the executor
+/// negates the current frame. `RequireGroup` expands into an `Or` over every
+/// `min`-sized `And` of its children, and `RequireExactly` into an `Or` over
+/// every `n`-sized `And` that also negates the remaining children, and
+/// `RequireAtMost` into the negation of an at-least-`max + 1` group, so
+/// compiled size grows combinatorially with group width. `Implies` maps onto its
+/// own `ImpliesStart`/`ImpliesEnd` frame.
+#[must_use]
+pub fn compile_plan(requirement: &Requirement<FuzzCondition>) -> Plan {
+    let mut plan = Plan::new();
+    emit(&mut plan, requirement);
+    plan
+}
+
+/// Builds a plan executor for a fuzz requirement with the fuzz dispatch table
+#[must_use]
+pub fn plan_executor(requirement: &Requirement<FuzzCondition>) -> PlanExecutor<FuzzReader> {
+    ExecutorBuilder::new()
+        .register(FUZZ_CONDITION_OPCODE, eval_fuzz_condition)
+        .build(compile_plan(requirement))
+}
+
+/// Builds a plan executor for a fuzz requirement after [`Plan::optimize`]
+#[must_use]
+pub fn optimized_plan_executor(
+    requirement: &Requirement<FuzzCondition>,
+) -> PlanExecutor<FuzzReader> {
+    let mut plan = compile_plan(requirement);
+    plan.optimize();
+    ExecutorBuilder::new().register(FUZZ_CONDITION_OPCODE, eval_fuzz_condition).build(plan)
+}
+
+/// Builds a plan executor for a fuzz requirement after [`Plan::reorder_by_cost`]
+#[must_use]
+pub fn cost_ordered_plan_executor(
+    requirement: &Requirement<FuzzCondition>,
+    cost: impl Fn(Operation) -> u32,
+) -> PlanExecutor<FuzzReader> {
+    let mut plan = compile_plan(requirement);
+    plan.reorder_by_cost(cost);
+    ExecutorBuilder::new().register(FUZZ_CONDITION_OPCODE, eval_fuzz_condition).build(plan)
+}
+
+/// Dispatch handler for [`FUZZ_CONDITION_OPCODE`]; unknown inputs fail closed.
+fn eval_fuzz_condition(
+    reader: &FuzzReader,
+    row: Row,
+    op: Operation,
+    _constants: &[Constant],
+) -> RequirementResult<bool> {
+    match reader.value(row, op.operand_a) {
+        TriState::True => Ok(true),
+        TriState::False => Ok(false),
+        TriState::Unknown => Err(RequirementError::condition_error("Unknown fuzz input")),
+    }
+}
+
+/// Appends the operations for one requirement node.
+fn emit(plan: &mut Plan, requirement: &Requirement<FuzzCondition>) {
+    match requirement {
+        Requirement::Condition(condition) => {
+            plan.add_operation(Operation::new(FUZZ_CONDITION_OPCODE, condition.0, 0, 0));
+        }
+        Requirement::Not(inner) => {
+            plan.add_operation(Operation::new(OpCode::AndStart, 0, 0, 0));
+            emit(plan, inner);
+            plan.add_operation(Operation::new(OpCode::Not, 0, 0, 0));
+            plan.add_operation(Operation::new(OpCode::AndEnd, 0, 0, 0));
+        }
+        Requirement::And(children) => {
+            plan.add_operation(Operation::new(OpCode::AndStart, 0, 0, 0));
+            for child in children {
+                emit(plan, child);
+            }
+            plan.add_operation(Operation::new(OpCode::AndEnd, 0, 0, 0));
+        }
+        Requirement::Or(children) => {
+            plan.add_operation(Operation::new(OpCode::OrStart, 0, 0, 0));
+            for child in children {
+                emit(plan, child);
+            }
+            plan.add_operation(Operation::new(OpCode::OrEnd, 0, 0, 0));
+        }
+        Requirement::RequireGroup {
+            min,
+            reqs,
+        } => {
+            emit_group(plan, reqs, usize::from(*min), false);
+        }
+        Requirement::RequireExactly {
+            n,
+            reqs,
+        } => {
+            emit_group(plan, reqs, usize::from(*n), true);
+        }
+        Requirement::RequireAtMost {
+            max,
+            reqs,
+        } => {
+            plan.add_operation(Operation::new(OpCode::AndStart, 0, 0, 0));
+            emit_group(plan, reqs, usize::from(*max) + 1, false);
+            plan.add_operation(Operation::new(OpCode::Not, 0, 0, 0));
+            plan.add_operation(Operation::new(OpCode::AndEnd, 0, 0, 0));
+        }
+        Requirement::Implies {
+            antecedent,
+            consequent,
+        } => {
+            plan.add_operation(Operation::new(OpCode::ImpliesStart, 0, 0, 0));
+            emit(plan, antecedent);
+            emit(plan, consequent);
+            plan.add_operation(Operation::new(OpCode::ImpliesEnd, 0, 0, 0));
+        }
+    }
+}
+
+/// Appends an `Or` over every `size`-sized combination of `reqs`.
+fn emit_group(plan: &mut Plan, reqs: &[Box<Requirement<FuzzCondition>>], size: usize, exact: bool) {
+    let children: Vec<&Requirement<FuzzCondition>> = reqs.iter().map(AsRef::as_ref).collect();
+    plan.add_operation(Operation::new(OpCode::OrStart, 0, 0, 0));
+    let mut chosen = Vec::with_capacity(size);
+    emit_combinations(plan, &children, size, 0, exact, &mut chosen);
+    plan.add_operation(Operation::new(OpCode::OrEnd, 0, 0, 0));
+}
+
+/// Emits one `And` frame per `remaining`-sized combination of `children[start..]`.
+///
+/// When `exact` is set, each frame also negates every child left out of the
+/// combination.
+fn emit_combinations(
+    plan: &mut Plan,
+    children: &[&Requirement<FuzzCondition>],
+    remaining: usize,
+    start: usize,
+    exact: bool,
+    chosen: &mut Vec<usize>,
+) {
+    if remaining == 0 {
+        plan.add_operation(Operation::new(OpCode::AndStart, 0, 0, 0));
+        for (index, child) in children.iter().enumerate() {
+            if chosen.contains(&index) {
+                emit(plan, child);
+            } else if exact {
+                plan.add_operation(Operation::new(OpCode::AndStart, 0, 0, 0));
+                emit(plan, child);
+                plan.add_operation(Operation::new(OpCode::Not, 0, 0, 0));
+                plan.add_operation(Operation::new(OpCode::AndEnd, 0, 0, 0));
+            }
+        }
+        plan.add_operation(Operation::new(OpCode::AndEnd, 0, 0, 0));
+        return;
+    }
+    for index in start .. children.len() {
+        if children.len() - index < remaining {
+            break;
+        }
+        chosen.push(index);
+        emit_combinations(plan, children, remaining - 1, index + 1, exact, chosen);
+        chosen.pop();
+    }
+}
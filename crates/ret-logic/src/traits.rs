@@ -26,6 +26,13 @@ pub type Row = usize;
 /// 64-bit mask for batch evaluation results
 pub type Mask64 = u64;
 
+// ============================================================================
+// SECTION: Constants
+// ============================================================================
+
+/// Cost reported by [`ConditionEval::cost`] when a condition does not override it
+pub const DEFAULT_CONDITION_COST: u32 = 1;
+
 // ============================================================================
 // SECTION: Condition Trait
 // ============================================================================
@@ -60,6 +67,16 @@ pub trait ConditionEval {
     /// # Returns
     /// `true` if the condition is satisfied for this row
     fn eval_row(&self, reader: &Self::Reader<'_>, row: Row) -> bool;
+
+    /// Relative cost of evaluating this condition once
+    ///
+    /// Used by [`crate::Requirement::reorder_by_cost`] to evaluate cheap
+    /// conditions first. Units are arbitrary; only the ordering matters.
+    /// Conditions that wrap IO or heavy computation should report a higher
+    /// value than [`DEFAULT_CONDITION_COST`].
+    fn cost(&self) -> u32 {
+        DEFAULT_CONDITION_COST
+    }
 }
 
 // ============================================================================
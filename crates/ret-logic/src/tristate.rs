@@ -103,6 +103,11 @@ pub trait TriLogic {
     /// Logical NOT for tri-state values
     fn not(&self, value: TriState) -> TriState;
 
+    /// Logical implication (default: material, `not(lhs) or rhs`)
+    fn implies(&self, lhs: TriState, rhs: TriState) -> TriState {
+        self.or(self.not(lhs), rhs)
+    }
+
     /// Group evaluation semantics (default: "insufficient evidence")
     fn require_group(&self, min: u8, counts: GroupCounts) -> TriState {
         let min_required = usize::from(min);
@@ -120,6 +125,40 @@ pub trait TriLogic {
 
         TriState::Unknown
     }
+
+    /// Exact-count group semantics: exactly `n` requirements must be satisfied
+    ///
+    /// Returns `Unknown` while the unknown requirements could still land the
+    /// satisfied count on either side of `n`.
+    fn require_exactly(&self, n: u8, counts: GroupCounts) -> TriState {
+        let required = usize::from(n);
+        if counts.satisfied > required || counts.satisfied + counts.unknown < required {
+            return TriState::False;
+        }
+
+        if counts.unknown == 0 {
+            return TriState::True;
+        }
+
+        TriState::Unknown
+    }
+
+    /// Upper-bound group semantics: at most `max` requirements may be satisfied
+    ///
+    /// Returns `Unknown` while enough unknown requirements remain to push the
+    /// satisfied count past `max`.
+    fn require_at_most(&self, max: u8, counts: GroupCounts) -> TriState {
+        let limit = usize::from(max);
+        if counts.satisfied > limit {
+            return TriState::False;
+        }
+
+        if counts.satisfied + counts.unknown <= limit {
+            return TriState::True;
+        }
+
+        TriState::Unknown
+    }
 }
 
 /// Strong Kleene logic (default)
@@ -188,6 +227,65 @@ impl TriLogic for BochvarLogic {
     }
 }
 
+/// Łukasiewicz three-valued logic (Ł3)
+///
+/// AND, OR, and NOT are `min`, `max`, and `1 - x` over the ordering
+/// `False < Unknown < True`, which coincides with strong Kleene. Implication is
+/// `min(1, 1 - lhs + rhs)`, so `Unknown -> Unknown` is `True`:
+///
+/// | `lhs -> rhs` | `True`    | `Unknown` | `False`   |
+/// |--------------|-----------|-----------|-----------|
+/// | `True`       | `True`    | `Unknown` | `False`   |
+/// | `Unknown`    | `True`    | `True`    | `Unknown` |
+/// | `False`      | `True`    | `True`    | `True`    |
+///
+/// Counted groups keep the default [`TriLogic::require_group`] semantics: an
+/// `Unknown` child is neither satisfied nor failed, so a group is `True` once
+/// `min` children are `True`, `False` once `min` is out of reach even if every
+/// `Unknown` turned `True`, and `Unknown` otherwise. Exact and at-most groups
+/// follow the same counting, matching Kleene.
+///
+/// # Invariants
+/// - Zero-sized marker type; carries no state.
+#[derive(Debug, Clone, Copy)]
+pub struct LukasiewiczLogic;
+
+impl TriLogic for LukasiewiczLogic {
+    fn and(&self, lhs: TriState, rhs: TriState) -> TriState {
+        match (lhs, rhs) {
+            (TriState::False, _) | (_, TriState::False) => TriState::False,
+            (TriState::True, TriState::True) => TriState::True,
+            _ => TriState::Unknown,
+        }
+    }
+
+    fn or(&self, lhs: TriState, rhs: TriState) -> TriState {
+        match (lhs, rhs) {
+            (TriState::True, _) | (_, TriState::True) => TriState::True,
+            (TriState::False, TriState::False) => TriState::False,
+            _ => TriState::Unknown,
+        }
+    }
+
+    fn not(&self, value: TriState) -> TriState {
+        match value {
+            TriState::True => TriState::False,
+            TriState::False => TriState::True,
+            TriState::Unknown => TriState::Unknown,
+        }
+    }
+
+    fn implies(&self, lhs: TriState, rhs: TriState) -> TriState {
+        match (lhs, rhs) {
+            (TriState::False, _) | (_, TriState::True) | (TriState::Unknown, TriState::Unknown) => {
+                TriState::True
+            }
+            (TriState::True, TriState::False) => TriState::False,
+            _ => TriState::Unknown,
+        }
+    }
+}
+
 /// Runtime-selectable logic mode
 ///
 /// # Invariants
@@ -198,6 +296,21 @@ pub enum LogicMode {
     Kleene,
     /// Bochvar logic (infectious unknowns)
     Bochvar,
+    /// Łukasiewicz logic (Kleene connectives, `Unknown -> Unknown` is true)
+    Lukasiewicz,
+}
+
+impl LogicMode {
+    /// Returns true when a definite child result can decide its parent early
+    ///
+    /// Under Kleene and Łukasiewicz logic `False` decides an AND and `True`
+    /// decides an OR, so the remaining siblings cannot change the outcome.
+    /// Under Bochvar logic a later `Unknown` still turns any result `Unknown`,
+    /// so every child must be evaluated.
+    #[must_use]
+    pub const fn short_circuits(self) -> bool {
+        matches!(self, Self::Kleene | Self::Lukasiewicz)
+    }
 }
 
 impl TriLogic for LogicMode {
@@ -205,6 +318,7 @@ impl TriLogic for LogicMode {
         match self {
             Self::Kleene => KleeneLogic.and(lhs, rhs),
             Self::Bochvar => BochvarLogic.and(lhs, rhs),
+            Self::Lukasiewicz => LukasiewiczLogic.and(lhs, rhs),
         }
     }
 
@@ -212,6 +326,7 @@ impl TriLogic for LogicMode {
         match self {
             Self::Kleene => KleeneLogic.or(lhs, rhs),
             Self::Bochvar => BochvarLogic.or(lhs, rhs),
+            Self::Lukasiewicz => LukasiewiczLogic.or(lhs, rhs),
         }
     }
 
@@ -219,12 +334,39 @@ impl TriLogic for LogicMode {
         match self {
             Self::Kleene => KleeneLogic.not(value),
             Self::Bochvar => BochvarLogic.not(value),
+            Self::Lukasiewicz => LukasiewiczLogic.not(value),
+        }
+    }
+
+    fn implies(&self, lhs: TriState, rhs: TriState) -> TriState {
+        match self {
+            Self::Kleene => KleeneLogic.implies(lhs, rhs),
+            Self::Bochvar => BochvarLogic.implies(lhs, rhs),
+            Self::Lukasiewicz => LukasiewiczLogic.implies(lhs, rhs),
         }
     }
 
     fn require_group(&self, min: u8, counts: GroupCounts) -> TriState {
         match self {
-            Self::Kleene | Self::Bochvar => KleeneLogic.require_group(min, counts),
+            Self::Kleene | Self::Bochvar | Self::Lukasiewicz => {
+                KleeneLogic.require_group(min, counts)
+            }
+        }
+    }
+
+    fn require_exactly(&self, n: u8, counts: GroupCounts) -> TriState {
+        match self {
+            Self::Kleene | Self::Bochvar | Self::Lukasiewicz => {
+                KleeneLogic.require_exactly(n, counts)
+            }
+        }
+    }
+
+    fn require_at_most(&self, max: u8, counts: GroupCounts) -> TriState {
+        match self {
+            Self::Kleene | Self::Bochvar | Self::Lukasiewicz => {
+                KleeneLogic.require_at_most(max, counts)
+            }
         }
     }
 }
@@ -233,10 +375,84 @@ impl TriLogic for LogicMode {
 // SECTION: Trace Hooks
 // ============================================================================
 
+/// Logical operator reported to [`RequirementTrace::on_operator_evaluated`]
+///
+/// # Invariants
+/// - Child counts match the number of child results reported before the operator.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum TraceOperator {
+    /// AND over `children` sub-requirements
+    And {
+        /// Number of sub-requirements
+        children: usize,
+    },
+    /// OR over `children` sub-requirements
+    Or {
+        /// Number of sub-requirements
+        children: usize,
+    },
+    /// NOT over a single sub-requirement
+    Not,
+    /// Implication from an antecedent to a consequent, reported in that order
+    Implies,
+    /// Require at least `min` of `counts.total` sub-requirements
+    RequireGroup {
+        /// Minimum number of satisfied sub-requirements
+        min: u8,
+        /// Outcome counts across the group's sub-requirements
+        counts: GroupCounts,
+    },
+    /// Require exactly `n` of `counts.total` sub-requirements
+    RequireExactly {
+        /// Exact number of satisfied sub-requirements
+        n: u8,
+        /// Outcome counts across the group's sub-requirements
+        counts: GroupCounts,
+    },
+    /// Require at most `max` of `counts.total` sub-requirements
+    RequireAtMost {
+        /// Maximum number of satisfied sub-requirements
+        max: u8,
+        /// Outcome counts across the group's sub-requirements
+        counts: GroupCounts,
+    },
+}
+
+impl TraceOperator {
+    /// Returns the number of sub-requirements the operator combined
+    #[must_use]
+    pub const fn children(self) -> usize {
+        match self {
+            Self::And {
+                children,
+            }
+            | Self::Or {
+                children,
+            } => children,
+            Self::Not => 1,
+            Self::Implies => 2,
+            Self::RequireGroup {
+                counts, ..
+            }
+            | Self::RequireExactly {
+                counts, ..
+            }
+            | Self::RequireAtMost {
+                counts, ..
+            } => counts.total,
+        }
+    }
+}
+
 /// Trace hook for condition evaluation
+///
+/// Hooks fire in post-order: a node's children report before the node itself.
 pub trait RequirementTrace<P> {
     /// Called whenever a condition is evaluated
     fn on_condition_evaluated(&mut self, condition: &P, result: TriState);
+
+    /// Called after a logical operator combines its children's results
+    fn on_operator_evaluated(&mut self, _operator: TraceOperator, _result: TriState) {}
 }
 
 /// No-op trace hook for fast paths
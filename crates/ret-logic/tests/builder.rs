@@ -35,6 +35,7 @@ use ret_logic::builder::GroupBuilder;
 use ret_logic::builder::OrBuilder;
 use ret_logic::builder::RequirementBuilder;
 use ret_logic::builder::convenience;
+use ret_logic::requirement;
 use support::TestResult;
 use support::ensure;
 
@@ -518,6 +519,68 @@ fn test_convenience_at_least() -> TestResult {
     Ok(())
 }
 
+/// Tests convenience exactly n and the matching macro arm.
+#[test]
+fn test_convenience_exactly_n() -> TestResult {
+    let members = || {
+        vec![
+            Requirement::condition(MockCondition::AlwaysTrue),
+            Requirement::condition(MockCondition::AlwaysTrue),
+            Requirement::condition(MockCondition::AlwaysFalse),
+        ]
+    };
+    let (values, flags) = (vec![0], vec![0]);
+    let reader = MockReader::new(&values, &flags);
+
+    let req = convenience::exactly_n(2, members());
+    ensure(req.eval(&reader, 0), "Expected convenience::exactly_n to evaluate to true")?;
+    ensure(
+        !convenience::exactly_n(1, members()).eval(&reader, 0),
+        "Expected surplus satisfied members to fail exactly_n",
+    )?;
+
+    let from_macro: Requirement<MockCondition> = requirement!(require_exactly(0, []));
+    ensure(
+        from_macro == convenience::exactly_n(0, Vec::new()),
+        "Expected requirement! require_exactly arm to match exactly_n",
+    )?;
+    Ok(())
+}
+
+/// Tests convenience at most and the matching macro arm.
+#[test]
+fn test_convenience_at_most() -> TestResult {
+    let members = || {
+        vec![
+            Requirement::condition(MockCondition::AlwaysTrue),
+            Requirement::condition(MockCondition::AlwaysFalse),
+            Requirement::condition(MockCondition::AlwaysFalse),
+        ]
+    };
+    let (values, flags) = (vec![0], vec![0]);
+    let reader = MockReader::new(&values, &flags);
+
+    ensure(
+        convenience::at_most(1, members()).eval(&reader, 0),
+        "Expected convenience::at_most to evaluate to true",
+    )?;
+    ensure(
+        !convenience::at_most(0, members()).eval(&reader, 0),
+        "Expected a satisfied member past the limit to fail at_most",
+    )?;
+    ensure(
+        convenience::at_most(1, members()).eval_block(&reader, 0, 1) == 1,
+        "Expected batch at_most to match row evaluation",
+    )?;
+
+    let from_macro: Requirement<MockCondition> = requirement!(require_at_most(0, []));
+    ensure(
+        from_macro == convenience::at_most(0, Vec::new()),
+        "Expected requirement! require_at_most arm to match at_most",
+    )?;
+    Ok(())
+}
+
 /// Tests convenience condition.
 #[test]
 fn test_convenience_condition() -> TestResult {
@@ -0,0 +1,361 @@
+// crates/ret-logic/tests/columnar.rs
+// ============================================================================
+// Module: Columnar Evaluation Tests
+// Description: Tests for ColumnarReader and ColumnarExecutor.
+// Purpose: Validate row conversion and block results against row dispatch.
+// Dependencies: ret_logic::columnar, ret_logic::testing, proptest
+// ============================================================================
+//! ## Overview
+//! Unit tests for building columnar readers, plus property tests checking that
+//! mask-based block evaluation agrees with per-row dispatch on random plans.
+
+#![allow(
+    clippy::panic,
+    clippy::print_stdout,
+    clippy::print_stderr,
+    clippy::unwrap_used,
+    clippy::expect_used,
+    clippy::use_debug,
+    clippy::dbg_macro,
+    clippy::panic_in_result_fn,
+    clippy::unwrap_in_result,
+    reason = "Test-only output and panic-based assertions are permitted."
+)]
+
+mod support;
+
+use proptest::prelude::*;
+use ret_logic::BatchConditionEval;
+use ret_logic::Column;
+use ret_logic::ColumnKey;
+use ret_logic::ColumnValue;
+use ret_logic::ColumnarError;
+use ret_logic::ColumnarExecutor;
+use ret_logic::ColumnarReader;
+use ret_logic::ConditionEval;
+use ret_logic::Constant;
+use ret_logic::LogicMode;
+use ret_logic::Mask64;
+use ret_logic::OpCode;
+use ret_logic::Operation;
+use ret_logic::Plan;
+use ret_logic::ReaderLen;
+use ret_logic::TriState;
+use ret_logic::columnar::row_dispatch_executor;
+use ret_logic::eval_reader_rows;
+use ret_logic::testing::FuzzRng;
+use support::TestResult;
+use support::ensure;
+
+// ============================================================================
+// SECTION: Helpers
+// ============================================================================
+
+/// Leaf opcodes drawn by the random plan generator; `InRange` is unsupported.
+const LEAF_OPCODES: [OpCode; 10] = [
+    OpCode::FloatGte,
+    OpCode::FloatLte,
+    OpCode::FloatEq,
+    OpCode::IntGte,
+    OpCode::IntLte,
+    OpCode::IntEq,
+    OpCode::HasAllFlags,
+    OpCode::HasAnyFlags,
+    OpCode::HasNoneFlags,
+    OpCode::InRange,
+];
+
+/// Columns in generated readers: float, int, flags, float. Column 4 is missing.
+const COLUMN_KEYS: [ColumnKey; 4] = [ColumnKey(0), ColumnKey(1), ColumnKey(2), ColumnKey(3)];
+
+/// Constant pool shared by generated plans, including types no leaf accepts.
+fn constant_pool() -> Vec<Constant> {
+    vec![
+        Constant::Float(0.0),
+        Constant::Float(0.5),
+        Constant::Float(-0.5),
+        Constant::Int(0),
+        Constant::Int(3),
+        Constant::Int(-3),
+        Constant::Flags(0b101),
+        Constant::Flags(0b010),
+        Constant::UInt(1),
+        Constant::String("x".to_string()),
+    ]
+}
+
+/// Appends a random leaf or group of at most `depth` levels.
+fn emit_node(rng: &mut FuzzRng, plan: &mut Plan, constants: u16, depth: usize) {
+    if depth == 0 || rng.below(3) == 0 {
+        let opcode = LEAF_OPCODES[rng.below(LEAF_OPCODES.len())];
+        let column = u16::try_from(rng.below(COLUMN_KEYS.len() + 1)).unwrap();
+        let constant = u16::try_from(rng.below(usize::from(constants))).unwrap();
+        plan.add_operation(Operation::new(opcode, column, constant, 0));
+        return;
+    }
+    let (start, end) = if rng.below(2) == 0 {
+        (OpCode::AndStart, OpCode::AndEnd)
+    } else {
+        (OpCode::OrStart, OpCode::OrEnd)
+    };
+    plan.add_operation(Operation::new(start, 0, 0, 0));
+    for _ in 0 ..= rng.below(4) {
+        emit_node(rng, plan, constants, depth - 1);
+        if rng.below(4) == 0 {
+            plan.add_operation(Operation::new(OpCode::Not, 0, 0, 0));
+        }
+    }
+    plan.add_operation(Operation::new(end, 0, 0, 0));
+}
+
+/// Builds a random plan over [`COLUMN_KEYS`] and [`constant_pool`].
+fn random_plan(rng: &mut FuzzRng) -> Plan {
+    let mut plan = Plan::new();
+    for key in COLUMN_KEYS {
+        plan.add_column(key);
+    }
+    let pool = constant_pool();
+    let constants = u16::try_from(pool.len()).unwrap();
+    for constant in pool {
+        plan.add_constant(constant).unwrap();
+    }
+    emit_node(rng, &mut plan, constants, 4);
+    if rng.below(4) == 0 {
+        plan.add_operation(Operation::new(OpCode::Not, 0, 0, 0));
+    }
+    plan
+}
+
+/// Builds up to 200 random rows in row-oriented form.
+fn random_input(rng: &mut FuzzRng) -> Vec<[ColumnValue; 4]> {
+    const FLOATS: [f32; 5] = [-1.0, -0.5, 0.0, 0.5, 1.0];
+    (0 .. rng.below(200))
+        .map(|_| {
+            [
+                ColumnValue::Float(FLOATS[rng.below(FLOATS.len())]),
+                ColumnValue::Int(i32::try_from(rng.below(9)).unwrap() - 4),
+                ColumnValue::Flags(u64::try_from(rng.below(8)).unwrap()),
+                ColumnValue::Float(FLOATS[rng.below(FLOATS.len())]),
+            ]
+        })
+        .collect()
+}
+
+/// Plan that negates a leaf on a missing column, so every in-range row passes.
+fn negated_missing_column_plan() -> Plan {
+    let mut plan = Plan::new();
+    plan.add_constant(Constant::Float(0.0)).unwrap();
+    plan.add_operation(Operation::new(OpCode::FloatGte, 9, 0, 0));
+    plan.add_operation(Operation::new(OpCode::Not, 0, 0, 0));
+    plan
+}
+
+// ============================================================================
+// SECTION: Conversion Tests
+// ============================================================================
+
+#[test]
+fn from_rows_transposes_rows_into_columns() -> TestResult {
+    let rows = [
+        [ColumnValue::Float(1.5), ColumnValue::Int(-2), ColumnValue::Flags(0b11)],
+        [ColumnValue::Float(2.5), ColumnValue::Int(7), ColumnValue::Flags(0b01)],
+    ];
+    let keys = [ColumnKey(4), ColumnKey(1), ColumnKey(2)];
+    let reader = ColumnarReader::from_rows(&keys, rows)?;
+
+    ensure(reader.len() == 2, "reader has one row per input row")?;
+    ensure(reader.column(ColumnKey(4)) == Some(&Column::Float(vec![1.5, 2.5])), "float column")?;
+    ensure(reader.column(ColumnKey(1)) == Some(&Column::Int(vec![-2, 7])), "int column")?;
+    ensure(reader.column(ColumnKey(2)) == Some(&Column::Flags(vec![0b11, 0b01])), "flags column")?;
+    ensure(reader.float(1, 4) == Some(2.5), "row accessor reads float")?;
+    ensure(reader.int(1, 4).is_none(), "row accessor rejects mismatched type")?;
+    ensure(reader.flags(2, 2).is_none(), "row accessor rejects out-of-range row")?;
+    Ok(())
+}
+
+#[test]
+fn from_rows_without_rows_is_empty() -> TestResult {
+    let reader = ColumnarReader::from_rows(&[ColumnKey(0)], Vec::<Vec<ColumnValue>>::new())?;
+    ensure(reader.is_empty(), "reader has no rows")?;
+    ensure(reader.column(ColumnKey(0)).is_none(), "column type is unknown without rows")?;
+    Ok(())
+}
+
+#[test]
+fn from_rows_rejects_row_width_mismatch() -> TestResult {
+    let rows = vec![vec![ColumnValue::Int(1), ColumnValue::Int(2)], vec![ColumnValue::Int(3)]];
+    let result = ColumnarReader::from_rows(&[ColumnKey(0), ColumnKey(1)], rows);
+    ensure(
+        result
+            == Err(ColumnarError::RowWidthMismatch {
+                row: 1,
+                expected: 2,
+                actual: 1,
+            }),
+        "short row is rejected",
+    )?;
+    Ok(())
+}
+
+#[test]
+fn from_rows_rejects_type_mismatch() -> TestResult {
+    let rows = [[ColumnValue::Int(1)], [ColumnValue::Float(1.0)]];
+    let result = ColumnarReader::from_rows(&[ColumnKey(3)], rows);
+    ensure(
+        result
+            == Err(ColumnarError::TypeMismatch {
+                column: ColumnKey(3),
+                row: 1,
+            }),
+        "value of a different type is rejected",
+    )?;
+    Ok(())
+}
+
+#[test]
+fn with_column_rejects_length_mismatch() -> TestResult {
+    let result = ColumnarReader::new(3).with_column(ColumnKey(0), Column::Int(vec![1, 2]));
+    ensure(
+        result
+            == Err(ColumnarError::LengthMismatch {
+                column: ColumnKey(0),
+                expected: 3,
+                actual: 2,
+            }),
+        "column shorter than the reader is rejected",
+    )?;
+    Ok(())
+}
+
+// ============================================================================
+// SECTION: Block Evaluation Tests
+// ============================================================================
+
+#[test]
+fn eval_block_evaluates_leaves_over_whole_blocks() -> TestResult {
+    let scores: Vec<f32> = (0 .. 100u16).map(f32::from).collect();
+    let flags: Vec<u64> = (0 .. 100).map(|row| row % 4).collect();
+    let reader = ColumnarReader::new(100)
+        .with_column(ColumnKey(0), Column::Float(scores))?
+        .with_column(ColumnKey(1), Column::Flags(flags))?;
+    let mut plan = Plan::new();
+    plan.add_constant(Constant::Float(50.0))?;
+    plan.add_constant(Constant::Flags(0b10))?;
+    plan.add_operation(Operation::new(OpCode::FloatGte, 0, 0, 0));
+    plan.add_operation(Operation::new(OpCode::HasAllFlags, 1, 1, 0));
+    let executor = ColumnarExecutor::new(plan);
+
+    let expected: Vec<usize> = (50 .. 100).filter(|row| row % 4 >= 2).collect();
+    ensure(eval_reader_rows(&executor, &reader) == expected, "rows match both leaves")?;
+    ensure(
+        executor.eval_block(&reader, 64, 64) == 0b1100_1100_1100_1100_1100_1100_1100_1100_1100,
+        "tail block",
+    )?;
+    ensure(executor.eval_row(&reader, 99), "single row evaluation")?;
+    Ok(())
+}
+
+#[test]
+fn eval_block_never_sets_rows_past_reader_end() -> TestResult {
+    let reader = ColumnarReader::new(3);
+    let executor = ColumnarExecutor::new(negated_missing_column_plan());
+
+    ensure(executor.eval_block(&reader, 0, 64) == 0b111, "only in-range rows pass")?;
+    ensure(executor.eval_block(&reader, 3, 64) == 0, "block past the end is empty")?;
+    ensure(!executor.eval_row(&reader, 5), "row past the end fails")?;
+    Ok(())
+}
+
+#[test]
+fn eval_block_fails_closed_on_malformed_plans() -> TestResult {
+    let reader = ColumnarReader::new(4);
+    let mut unclosed = negated_missing_column_plan();
+    unclosed.add_operation(Operation::new(OpCode::OrStart, 0, 0, 0));
+    let mut mismatched = negated_missing_column_plan();
+    mismatched.add_operation(Operation::new(OpCode::AndStart, 0, 0, 0));
+    mismatched.add_operation(Operation::new(OpCode::OrEnd, 0, 0, 0));
+    let mut too_deep = Plan::new();
+    for _ in 0 .. 64 {
+        too_deep.add_operation(Operation::new(OpCode::AndStart, 0, 0, 0));
+    }
+    for _ in 0 .. 64 {
+        too_deep.add_operation(Operation::new(OpCode::AndEnd, 0, 0, 0));
+    }
+
+    for plan in [unclosed, mismatched, too_deep] {
+        ensure(ColumnarExecutor::new(plan).eval_block(&reader, 0, 64) == 0, "malformed plan")?;
+    }
+    Ok(())
+}
+
+#[test]
+fn eval_block_clears_missing_cells() -> TestResult {
+    let mut reader =
+        ColumnarReader::new(100).with_column(ColumnKey(0), Column::Float(vec![75.0; 100]))?;
+    reader.mark_missing(ColumnKey(0), 3)?;
+    reader.mark_missing(ColumnKey(0), 70)?;
+    let mut plan = Plan::new();
+    plan.add_constant(Constant::Float(50.0))?;
+    plan.add_operation(Operation::new(OpCode::FloatGte, 0, 0, 0));
+    let executor = ColumnarExecutor::new(plan.clone());
+
+    ensure(executor.eval_block(&reader, 0, 64) == !(1 << 3), "first block skips row 3")?;
+    ensure(
+        executor.eval_block(&reader, 60, 64) == ((1 << 40) - 1) & !(1 << 10),
+        "block spanning words skips row 70",
+    )?;
+    ensure(reader.float(70, 0).is_none(), "missing cell has no value")?;
+    let lazy = row_dispatch_executor(plan);
+    ensure(
+        lazy.evaluate_lazy(&reader, 70, LogicMode::Kleene) == TriState::Unknown,
+        "missing cell is unknown under lazy evaluation",
+    )?;
+    ensure(
+        reader.mark_missing(ColumnKey(1), 0)
+            == Err(ColumnarError::CellOutOfRange {
+                column: ColumnKey(1),
+                row: 0,
+            }),
+        "unknown column is rejected",
+    )?;
+    ensure(reader.mark_missing(ColumnKey(0), 100).is_err(), "row past the end is rejected")?;
+
+    reader.insert_column(ColumnKey(0), Column::Float(vec![75.0; 100]))?;
+    ensure(!reader.is_missing(70, ColumnKey(0)), "replacing a column clears missing cells")?;
+    Ok(())
+}
+
+// ============================================================================
+// SECTION: Differential Tests
+// ============================================================================
+
+proptest! {
+    #[test]
+    fn columnar_blocks_match_row_dispatch(seed in any::<u64>()) {
+        let mut rng = FuzzRng::new(seed);
+        let plan = random_plan(&mut rng);
+        let reader = ColumnarReader::from_rows(&COLUMN_KEYS, random_input(&mut rng)).unwrap();
+        let columnar = ColumnarExecutor::new(plan.clone());
+        let rows = row_dispatch_executor(plan);
+
+        let expected: Vec<usize> =
+            (0 .. reader.len()).filter(|row| rows.eval_row(&reader, *row)).collect();
+        prop_assert_eq!(eval_reader_rows(&columnar, &reader), expected, "plan {:?}", columnar.plan());
+
+        // Unaligned windows, including ones that run past the last row
+        let start = rng.below(reader.len() + 1);
+        let count = rng.below(65);
+        let in_range = count.min(reader.len() - start);
+        let expected = (0 .. in_range)
+            .filter(|offset| rows.eval_row(&reader, start + offset))
+            .fold(0, |mask: Mask64, offset| mask | (1 << offset));
+        prop_assert_eq!(
+            columnar.eval_block(&reader, start, count),
+            expected,
+            "start {} count {} plan {:?}",
+            start,
+            count,
+            columnar.plan()
+        );
+    }
+}
@@ -0,0 +1,282 @@
+// crates/ret-logic/tests/compiled.rs
+// ============================================================================
+// Module: Compiled Requirement Tests
+// Description: Tests for CompiledRequirement compilation and reuse.
+// Purpose: Validate build-once, evaluate-many behavior across readers.
+// Dependencies: ret_logic::compiled, ret_logic::columnar
+// ============================================================================
+//! ## Overview
+//! Integration tests for [`ret_logic::CompiledRequirement`]: a requirement is
+//! lowered once and then evaluated against several readers, matching the tree
+//! evaluator without recompiling.
+
+#![allow(
+    clippy::panic,
+    clippy::print_stdout,
+    clippy::print_stderr,
+    clippy::unwrap_used,
+    clippy::expect_used,
+    clippy::use_debug,
+    clippy::dbg_macro,
+    clippy::panic_in_result_fn,
+    clippy::unwrap_in_result,
+    reason = "Test-only output and panic-based assertions are permitted."
+)]
+
+mod support;
+
+use std::cell::Cell;
+
+use ret_logic::Column;
+use ret_logic::ColumnKey;
+use ret_logic::ColumnarReader;
+use ret_logic::CompiledRequirement;
+use ret_logic::ConditionEval;
+use ret_logic::ConditionLowering;
+use ret_logic::Constant;
+use ret_logic::LogicMode;
+use ret_logic::OpCode;
+use ret_logic::Operation;
+use ret_logic::Plan;
+use ret_logic::PlanError;
+use ret_logic::ReaderLen;
+use ret_logic::Requirement;
+use ret_logic::Row;
+use ret_logic::TriState;
+use ret_logic::columnar::row_dispatch_builder;
+use support::TestResult;
+use support::ensure;
+
+// ============================================================================
+// SECTION: Helpers
+// ============================================================================
+
+/// Float score column.
+const SCORE: ColumnKey = ColumnKey(0);
+
+/// Role flag column.
+const ROLES: ColumnKey = ColumnKey(1);
+
+/// Domain condition compiled to columnar opcodes.
+#[derive(Debug, Clone, Copy)]
+enum Check {
+    /// Score at least the threshold.
+    MinScore(f32),
+    /// Every listed role flag set.
+    HasRoles(u64),
+}
+
+impl ConditionEval for Check {
+    type Reader<'a> = ColumnarReader;
+
+    fn eval_row(&self, reader: &ColumnarReader, row: Row) -> bool {
+        match *self {
+            Self::MinScore(threshold) => {
+                reader.float(row, SCORE.id()).is_some_and(|score| score >= threshold)
+            }
+            Self::HasRoles(roles) => {
+                reader.flags(row, ROLES.id()).is_some_and(|flags| flags & roles == roles)
+            }
+        }
+    }
+}
+
+/// Lowering that counts how often it runs.
+#[derive(Default)]
+struct CountingLowering {
+    /// Number of conditions lowered so far.
+    calls: Cell<usize>,
+}
+
+impl ConditionLowering<Check> for CountingLowering {
+    fn lower(&self, condition: &Check, plan: &mut Plan) -> Result<Operation, PlanError> {
+        self.calls.set(self.calls.get() + 1);
+        let (opcode, column, constant) = match *condition {
+            Check::MinScore(threshold) => (OpCode::FloatGte, SCORE, Constant::Float(threshold)),
+            Check::HasRoles(roles) => (OpCode::HasAllFlags, ROLES, Constant::Flags(roles)),
+        };
+        plan.add_column(column);
+        let index = plan.add_constant(constant)?;
+        Ok(Operation::new(opcode, column.id(), index.0, 0))
+    }
+}
+
+/// Score of at least 50 and either the admin role or a score of at least 90.
+fn policy() -> Requirement<Check> {
+    Requirement::and(vec![
+        Requirement::condition(Check::MinScore(50.0)),
+        Requirement::or(vec![
+            Requirement::condition(Check::HasRoles(0b10)),
+            Requirement::condition(Check::MinScore(90.0)),
+        ]),
+    ])
+}
+
+/// Builds a reader with one score and role set per row.
+fn reader(scores: Vec<f32>, roles: Vec<u64>) -> ColumnarReader {
+    ColumnarReader::new(scores.len())
+        .with_column(SCORE, Column::Float(scores))
+        .and_then(|reader| reader.with_column(ROLES, Column::Flags(roles)))
+        .expect("columns match reader length")
+}
+
+/// Compiles `requirement` with `lowering` against the columnar dispatch table.
+fn compile(
+    requirement: &Requirement<Check>,
+    lowering: &CountingLowering,
+) -> CompiledRequirement<ColumnarReader> {
+    CompiledRequirement::compile(requirement, lowering, row_dispatch_builder())
+        .expect("requirement compiles")
+}
+
+// ============================================================================
+// SECTION: Reuse Tests
+// ============================================================================
+
+#[test]
+fn compiled_requirement_matches_tree_across_readers() -> TestResult {
+    let requirement = policy();
+    let compiled = compile(&requirement, &CountingLowering::default());
+    let readers = [
+        reader(vec![40.0, 55.0, 95.0], vec![0b10, 0b10, 0b00]),
+        reader(vec![50.0, 89.9], vec![0b00, 0b11]),
+        reader(vec![100.0, 10.0, 60.0, 60.0], vec![0b00, 0b10, 0b01, 0b10]),
+    ];
+
+    for (index, reader) in readers.iter().enumerate() {
+        for row in 0 .. reader.len() {
+            let expected = requirement.eval(reader, row);
+            ensure(
+                compiled.eval(reader, row) == expected,
+                format!("reader {index} row {row}: expected {expected}"),
+            )?;
+            ensure(
+                compiled.eval_tristate(reader, row, LogicMode::Kleene) == TriState::from(expected),
+                format!("reader {index} row {row}: tri-state mismatch"),
+            )?;
+        }
+    }
+    Ok(())
+}
+
+#[test]
+fn compiled_requirement_does_not_recompile_per_evaluation() -> TestResult {
+    let lowering = CountingLowering::default();
+    let compiled = compile(&policy(), &lowering);
+    ensure(lowering.calls.get() == 3, "Each condition is lowered once")?;
+    ensure(compiled.lowered_conditions() == 3, "Compilation records lowered conditions")?;
+    let plan_len = compiled.plan().operations().len();
+
+    let first = reader(vec![60.0; 64], vec![0b10; 64]);
+    let second = reader(vec![95.0; 32], vec![0b00; 32]);
+    for round in 0 .. 10 {
+        for row in 0 .. 64 {
+            ensure(compiled.eval(&first, row), format!("round {round} row {row} should pass"))?;
+        }
+        for row in 0 .. 32 {
+            ensure(
+                compiled.eval_row(&second, row),
+                format!("round {round} row {row} should pass"),
+            )?;
+        }
+    }
+
+    ensure(lowering.calls.get() == 3, "Evaluation must not lower conditions again")?;
+    ensure(compiled.plan().operations().len() == plan_len, "Plan is unchanged by evaluation")
+}
+
+#[test]
+fn compiled_requirement_exposes_plan_metadata() -> TestResult {
+    let requirement = policy();
+    let first = compile(&requirement, &CountingLowering::default());
+    let second = compile(&requirement, &CountingLowering::default());
+    ensure(
+        first.structural_hash() == second.structural_hash(),
+        "Equal requirements compile to equal digests",
+    )?;
+    ensure(
+        *first.structural_hash() == first.plan().structural_hash(),
+        "Digest matches the compiled plan",
+    )?;
+    ensure(first.required_columns() == [SCORE, ROLES], "Lowering registers columns once")?;
+
+    let other =
+        compile(&Requirement::condition(Check::MinScore(50.0)), &CountingLowering::default());
+    ensure(
+        other.structural_hash() != first.structural_hash(),
+        "Different requirements compile to different digests",
+    )
+}
+
+// ============================================================================
+// SECTION: Group Expansion Tests
+// ============================================================================
+
+#[test]
+fn compiled_counted_groups_match_tree_evaluation() -> TestResult {
+    let members = || {
+        vec![
+            Requirement::condition(Check::MinScore(50.0)),
+            Requirement::condition(Check::HasRoles(0b01)),
+            Requirement::condition(Check::HasRoles(0b10)),
+        ]
+    };
+    let requirements = [
+        Requirement::require_group(2, members()),
+        Requirement::require_exactly(1, members()),
+        Requirement::require_at_most(1, members()),
+        Requirement::negate(Requirement::require_group(3, members())),
+    ];
+    let reader = reader(vec![10.0, 60.0, 60.0, 60.0, 10.0], vec![0b00, 0b00, 0b01, 0b11, 0b11]);
+
+    for requirement in &requirements {
+        let compiled = compile(requirement, &CountingLowering::default());
+        for row in 0 .. reader.len() {
+            ensure(
+                compiled.eval(&reader, row) == requirement.eval(&reader, row),
+                format!("row {row} diverges for {requirement:?}"),
+            )?;
+        }
+    }
+    Ok(())
+}
+
+// ============================================================================
+// SECTION: Error Tests
+// ============================================================================
+
+#[test]
+fn compile_propagates_lowering_errors() -> TestResult {
+    let failing = |_: &Check, _: &mut Plan| -> Result<Operation, PlanError> {
+        Err(PlanError::Serialization("unsupported condition".to_string()))
+    };
+    let result = CompiledRequirement::compile(&policy(), &failing, row_dispatch_builder());
+    ensure(
+        matches!(result, Err(PlanError::Serialization(ref message)) if message == "unsupported condition"),
+        "Lowering errors surface from compile",
+    )
+}
+
+#[test]
+fn compile_rejects_plans_deeper_than_executor_stack() -> TestResult {
+    let mut requirement = Requirement::condition(Check::MinScore(1.0));
+    for _ in 0 .. 63 {
+        requirement = Requirement::and(vec![requirement]);
+    }
+    let lowering = CountingLowering::default();
+    ensure(
+        CompiledRequirement::compile(&requirement, &lowering, row_dispatch_builder()).is_ok(),
+        "63 nested groups fit the executor stack",
+    )?;
+    let requirement = Requirement::and(vec![requirement]);
+    let result = CompiledRequirement::compile(&requirement, &lowering, row_dispatch_builder());
+    ensure(
+        matches!(
+            result,
+            Err(PlanError::NestingTooDeep {
+                max_depth: 63
+            })
+        ),
+        "Deeper plans fail at compile time",
+    )
+}
@@ -0,0 +1,192 @@
+// crates/ret-logic/tests/csv.rs
+// ============================================================================
+// Module: CSV Import Tests
+// Description: Tests for importing delimited text into columnar readers.
+// Purpose: Validate parsing, missing-cell handling, and tri-state evaluation.
+// Dependencies: ret_logic::csv, ret_logic::columnar, ret_logic::executor
+// ============================================================================
+//! ## Overview
+//! Integration tests for `CsvImporter`: typed cells, quoting, delimiters,
+//! error reporting, and `Unknown` results for rows with missing cells.
+
+#![allow(
+    clippy::panic,
+    clippy::print_stdout,
+    clippy::print_stderr,
+    clippy::unwrap_used,
+    clippy::expect_used,
+    clippy::use_debug,
+    clippy::dbg_macro,
+    clippy::panic_in_result_fn,
+    clippy::unwrap_in_result,
+    reason = "Test-only output and panic-based assertions are permitted."
+)]
+
+mod support;
+
+use ret_logic::BatchConditionEval;
+use ret_logic::ColumnKey;
+use ret_logic::ColumnarExecutor;
+use ret_logic::CsvError;
+use ret_logic::CsvImporter;
+use ret_logic::CsvType;
+use ret_logic::LogicMode;
+use ret_logic::OpCode;
+use ret_logic::Plan;
+use ret_logic::PlanBuilder;
+use ret_logic::ReaderLen;
+use ret_logic::TriState;
+use ret_logic::columnar::row_dispatch_executor;
+use support::TestResult;
+use support::ensure;
+
+// ============================================================================
+// SECTION: Helpers
+// ============================================================================
+
+const SCORE: ColumnKey = ColumnKey(0);
+const FLAGS: ColumnKey = ColumnKey(1);
+const LEVEL: ColumnKey = ColumnKey(2);
+
+/// Importer for `score` (float), `flags` (flags), and `level` (int).
+fn importer() -> CsvImporter {
+    CsvImporter::csv()
+        .column("score", SCORE, CsvType::Float)
+        .column("flags", FLAGS, CsvType::Flags)
+        .column("level", LEVEL, CsvType::Int)
+}
+
+/// Builds `score >= 50 && has_all_flags(flags, 0b01)`.
+fn score_and_flag_plan() -> TestResult<Plan> {
+    let mut builder = PlanBuilder::new();
+    let threshold = builder.add_float_constant(50.0)?;
+    let flag = builder.add_flags_constant(0b01)?;
+    Ok(builder
+        .and_start()
+        .add_op(OpCode::FloatGte, SCORE.0, threshold.0, 0)
+        .add_op(OpCode::HasAllFlags, FLAGS.0, flag.0, 0)
+        .and_end()
+        .build())
+}
+
+// ============================================================================
+// SECTION: Tri-State Evaluation Tests
+// ============================================================================
+
+/// Tests a row with an empty cell evaluates to `Unknown` unless decided.
+#[test]
+fn missing_cell_evaluates_to_unknown() -> TestResult {
+    let input = "name,score,flags,level\nalpha,75,1,3\nbeta,,1,4\ngamma,,0,5\ndelta,20,1,6\n";
+    let reader = importer().import(input)?;
+    let plan = score_and_flag_plan()?;
+    let executor = row_dispatch_executor(plan.clone());
+
+    let results: Vec<TriState> =
+        executor.eval_stream(std::iter::once(reader.clone()), LogicMode::Kleene).collect();
+
+    ensure(reader.len() == 4, "one row per record")?;
+    ensure(reader.is_missing(1, SCORE), "empty score cell is missing")?;
+    ensure(
+        results == [TriState::True, TriState::Unknown, TriState::False, TriState::False],
+        format!("unexpected results {results:?}"),
+    )?;
+    ensure(
+        ColumnarExecutor::new(plan).eval_block(&reader, 0, 64) == 0b0001,
+        "block evaluation treats the missing cell as false",
+    )?;
+    Ok(())
+}
+
+/// Tests unparseable and non-finite cells are missing rather than errors.
+#[test]
+fn unparseable_cells_are_missing() -> TestResult {
+    let input = "score,flags,level\nhigh,0x3,2.5\nNaN,0b12,-7\n80,,x\n";
+    let reader = importer().import(input)?;
+
+    ensure(reader.is_missing(0, SCORE), "word in a float column")?;
+    ensure(reader.flags(0, FLAGS.0) == Some(0b11), "hex flags parse")?;
+    ensure(reader.is_missing(0, LEVEL), "fraction in an int column")?;
+    ensure(reader.is_missing(1, SCORE), "NaN is missing")?;
+    ensure(reader.is_missing(1, FLAGS), "invalid binary digit")?;
+    ensure(reader.int(1, LEVEL.0) == Some(-7), "negative int parses")?;
+    ensure(reader.float(2, SCORE.0) == Some(80.0), "float parses")?;
+    ensure(reader.is_missing(2, FLAGS) && reader.is_missing(2, LEVEL), "empty and junk cells")?;
+    Ok(())
+}
+
+// ============================================================================
+// SECTION: Parsing Tests
+// ============================================================================
+
+/// Tests quoted fields, CRLF endings, blank lines, and unmapped columns.
+#[test]
+fn quoted_fields_and_line_endings() -> TestResult {
+    let input =
+        "note,score,flags,level\r\n\"a, \"\"quoted\"\"\nnote\", 61 ,2,1\r\n\r\nplain,\"7\",1,0";
+    let reader = importer().import(input)?;
+
+    ensure(reader.len() == 2, "blank line is skipped")?;
+    ensure(reader.float(0, SCORE.0) == Some(61.0), "cells are trimmed")?;
+    ensure(reader.float(1, SCORE.0) == Some(7.0), "quoted numbers parse")?;
+    Ok(())
+}
+
+/// Tests tab-separated input with headers in any order.
+#[test]
+fn tsv_maps_headers_by_name() -> TestResult {
+    let input = "level\tflags\tscore\n9\t4\t1.25\n";
+    let reader = CsvImporter::tsv()
+        .column("score", SCORE, CsvType::Float)
+        .column("level", LEVEL, CsvType::Int)
+        .import(input)?;
+
+    ensure(reader.float(0, SCORE.0) == Some(1.25), "score column")?;
+    ensure(reader.int(0, LEVEL.0) == Some(9), "level column")?;
+    ensure(reader.column(FLAGS).is_none(), "unmapped column is not imported")?;
+    Ok(())
+}
+
+// ============================================================================
+// SECTION: Error Tests
+// ============================================================================
+
+/// Tests structural problems fail the import with their location.
+#[test]
+fn structural_errors_are_reported() -> TestResult {
+    ensure(importer().import("\n\n") == Err(CsvError::EmptyInput), "no header row")?;
+    ensure(
+        importer().import("score,flags\n1,2\n")
+            == Err(CsvError::MissingHeader {
+                header: "level".to_string(),
+            }),
+        "mapped header absent",
+    )?;
+    ensure(
+        importer().import("score,flags,level\n1,2,3\n\n4,5\n")
+            == Err(CsvError::RowWidthMismatch {
+                line: 4,
+                expected: 3,
+                actual: 2,
+            }),
+        "short record reports its line",
+    )?;
+    ensure(
+        importer().import("score,flags,level\n1,2,3\n\"4,5,6\n")
+            == Err(CsvError::UnterminatedQuote {
+                line: 3,
+            }),
+        "unterminated quote reports its line",
+    )?;
+    let duplicate = CsvImporter::csv()
+        .column("score", SCORE, CsvType::Float)
+        .column("level", SCORE, CsvType::Int)
+        .import("score,level\n1,2\n");
+    ensure(
+        duplicate
+            == Err(CsvError::DuplicateColumn {
+                column: SCORE,
+            }),
+        "key mapped twice",
+    )?;
+    Ok(())
+}
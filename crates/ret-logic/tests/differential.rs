@@ -0,0 +1,308 @@
+// crates/ret-logic/tests/differential.rs
+// ============================================================================
+// Module: Differential Evaluation Tests
+// Description: Property tests comparing evaluators against the reference interpreter.
+// Purpose: Detect divergence between tree, tri-state, and compiled plan evaluation.
+// Dependencies: ret_logic::testing, proptest
+// ============================================================================
+//! ## Overview
+//! Differential property tests driven by the `testing` feature hooks. Each case
+//! generates a random requirement tree and random rows from a seed, then checks
+//! every evaluator against [`ret_logic::testing::reference_eval`] in every logic mode.
+
+#![allow(
+    clippy::panic,
+    clippy::print_stdout,
+    clippy::print_stderr,
+    clippy::unwrap_used,
+    clippy::expect_used,
+    clippy::use_debug,
+    clippy::dbg_macro,
+    clippy::panic_in_result_fn,
+    clippy::unwrap_in_result,
+    reason = "Test-only output and panic-based assertions are permitted."
+)]
+
+use proptest::prelude::*;
+use ret_logic::CompiledRequirement;
+use ret_logic::ConditionEval;
+use ret_logic::Constant;
+use ret_logic::LogicMode;
+use ret_logic::Operation;
+use ret_logic::Plan;
+use ret_logic::PlanError;
+use ret_logic::ReaderLen;
+use ret_logic::Requirement;
+use ret_logic::RequirementError;
+use ret_logic::RequirementResult;
+use ret_logic::Row;
+use ret_logic::TriLogic;
+use ret_logic::TriState;
+use ret_logic::executor::ExecutorBuilder;
+use ret_logic::testing::FUZZ_CONDITION_OPCODE;
+use ret_logic::testing::FuzzCondition;
+use ret_logic::testing::FuzzReader;
+use ret_logic::testing::FuzzRng;
+use ret_logic::testing::LOGIC_MODES;
+use ret_logic::testing::RandomRequirementConfig;
+use ret_logic::testing::compile_plan;
+use ret_logic::testing::cost_ordered_plan_executor;
+use ret_logic::testing::optimized_plan_executor;
+use ret_logic::testing::plan_executor;
+use ret_logic::testing::random_requirement;
+use ret_logic::testing::random_rows;
+use ret_logic::testing::reference_eval;
+
+/// Rows generated per case.
+const ROWS_PER_CASE: usize = 8;
+
+/// Lowers a fuzz condition to the fuzz opcode, reading its slot from `operand_a`.
+#[allow(
+    clippy::unnecessary_wraps,
+    clippy::trivially_copy_pass_by_ref,
+    reason = "Signature matches the fallible, by-reference lowering trait."
+)]
+const fn lower_fuzz(condition: &FuzzCondition, _plan: &mut Plan) -> Result<Operation, PlanError> {
+    Ok(Operation::new(FUZZ_CONDITION_OPCODE, condition.0, 0, 0))
+}
+
+/// Evaluates the fuzz opcode; unknown inputs are handler errors.
+fn eval_fuzz(
+    reader: &FuzzReader,
+    row: Row,
+    op: Operation,
+    _constants: &[Constant],
+) -> RequirementResult<bool> {
+    match reader.value(row, op.operand_a) {
+        TriState::True => Ok(true),
+        TriState::False => Ok(false),
+        TriState::Unknown => Err(RequirementError::condition_error("Unknown fuzz input")),
+    }
+}
+
+/// Compiles a fuzz requirement through [`CompiledRequirement`].
+fn compile_fuzz(requirement: &Requirement<FuzzCondition>) -> CompiledRequirement<FuzzReader> {
+    let dispatch = ExecutorBuilder::new().register(FUZZ_CONDITION_OPCODE, eval_fuzz);
+    CompiledRequirement::compile(requirement, &lower_fuzz, dispatch).expect("fuzz plans compile")
+}
+
+proptest! {
+    #[test]
+    fn tristate_eval_matches_reference(seed in any::<u64>()) {
+        let config = RandomRequirementConfig::default();
+        let mut rng = FuzzRng::new(seed);
+        let requirement = random_requirement(&mut rng, &config);
+        let reader = random_rows(&mut rng, &config, ROWS_PER_CASE, true);
+
+        for mode in LOGIC_MODES {
+            for row in 0 .. reader.len() {
+                prop_assert_eq!(
+                    requirement.eval_tristate(&reader, row, &mode),
+                    reference_eval(&requirement, &reader, row, mode),
+                    "mode {:?} row {} requirement {:?}",
+                    mode,
+                    row,
+                    requirement
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn plan_executor_matches_reference_on_known_inputs(seed in any::<u64>()) {
+        let config = RandomRequirementConfig::default();
+        let mut rng = FuzzRng::new(seed);
+        let requirement = random_requirement(&mut rng, &config);
+        let reader = random_rows(&mut rng, &config, ROWS_PER_CASE, false);
+        let executor = plan_executor(&requirement);
+
+        for mode in LOGIC_MODES {
+            for row in 0 .. reader.len() {
+                let expected = reference_eval(&requirement, &reader, row, mode);
+                prop_assert_ne!(expected, TriState::Unknown);
+                prop_assert_eq!(executor.eval_row(&reader, row), expected == TriState::True);
+                prop_assert_eq!(requirement.eval(&reader, row), expected == TriState::True);
+            }
+        }
+    }
+
+    #[test]
+    fn plan_executor_lazy_matches_reference_on_known_inputs(seed in any::<u64>()) {
+        let config = RandomRequirementConfig::default();
+        let mut rng = FuzzRng::new(seed);
+        let requirement = random_requirement(&mut rng, &config);
+        let reader = random_rows(&mut rng, &config, ROWS_PER_CASE, false);
+        let executor = plan_executor(&requirement);
+
+        for mode in LOGIC_MODES {
+            for row in 0 .. reader.len() {
+                prop_assert_eq!(
+                    executor.evaluate_lazy(&reader, row, mode),
+                    reference_eval(&requirement, &reader, row, mode)
+                );
+            }
+        }
+    }
+
+    /// Compiled groups expand into AND/OR combinations, which only match the
+    /// counting semantics under Kleene connectives (shared by Łukasiewicz) when
+    /// inputs are unknown.
+    #[test]
+    fn plan_executor_lazy_matches_kleene_reference(seed in any::<u64>()) {
+        let config = RandomRequirementConfig::default();
+        let mut rng = FuzzRng::new(seed);
+        let requirement = random_requirement(&mut rng, &config);
+        let reader = random_rows(&mut rng, &config, ROWS_PER_CASE, true);
+        let executor = plan_executor(&requirement);
+
+        for mode in [LogicMode::Kleene, LogicMode::Lukasiewicz] {
+            for row in 0 .. reader.len() {
+                prop_assert_eq!(
+                    executor.evaluate_lazy(&reader, row, mode),
+                    reference_eval(&requirement, &reader, row, mode),
+                    "mode {:?} row {} requirement {:?}",
+                    mode,
+                    row,
+                    requirement
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn cost_ordered_plan_matches_original_plan(seed in any::<u64>()) {
+        let config = RandomRequirementConfig::default();
+        let mut rng = FuzzRng::new(seed);
+        let requirement = random_requirement(&mut rng, &config);
+        let reader = random_rows(&mut rng, &config, ROWS_PER_CASE, true);
+        let salt = rng.next_u64();
+        let cost = move |op: Operation| u32::try_from((u64::from(op.operand_a) ^ salt) % 8).unwrap_or(0);
+        let original = plan_executor(&requirement);
+        let reordered = cost_ordered_plan_executor(&requirement, cost);
+        prop_assert_eq!(reordered.plan().operations().len(), original.plan().operations().len());
+
+        for row in 0 .. reader.len() {
+            prop_assert_eq!(reordered.eval_row(&reader, row), original.eval_row(&reader, row));
+            for mode in LOGIC_MODES {
+                prop_assert_eq!(
+                    reordered.evaluate_lazy(&reader, row, mode),
+                    original.evaluate_lazy(&reader, row, mode),
+                    "mode {:?} row {} requirement {:?}",
+                    mode,
+                    row,
+                    requirement
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn optimized_plan_matches_original_plan(seed in any::<u64>()) {
+        let config = RandomRequirementConfig::default();
+        let mut rng = FuzzRng::new(seed);
+        let requirement = random_requirement(&mut rng, &config);
+        let reader = random_rows(&mut rng, &config, ROWS_PER_CASE, true);
+        let original = plan_executor(&requirement);
+        let optimized = optimized_plan_executor(&requirement);
+        prop_assert!(optimized.plan().operations().len() <= original.plan().operations().len());
+
+        for row in 0 .. reader.len() {
+            prop_assert_eq!(optimized.eval_row(&reader, row), original.eval_row(&reader, row));
+            for mode in LOGIC_MODES {
+                prop_assert_eq!(
+                    optimized.evaluate_lazy(&reader, row, mode),
+                    original.evaluate_lazy(&reader, row, mode),
+                    "mode {:?} row {} requirement {:?}",
+                    mode,
+                    row,
+                    requirement
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn lukasiewicz_matches_boolean_logic_on_classical_inputs(
+        seed in any::<u64>(),
+        lhs in any::<bool>(),
+        rhs in any::<bool>(),
+    ) {
+        let logic = LogicMode::Lukasiewicz;
+        let (a, b) = (TriState::from(lhs), TriState::from(rhs));
+        prop_assert_eq!(logic.and(a, b), TriState::from(lhs && rhs));
+        prop_assert_eq!(logic.or(a, b), TriState::from(lhs || rhs));
+        prop_assert_eq!(logic.not(a), TriState::from(!lhs));
+        prop_assert_eq!(logic.implies(a, b), TriState::from(!lhs || rhs));
+
+        let config = RandomRequirementConfig::default();
+        let mut rng = FuzzRng::new(seed);
+        let requirement = random_requirement(&mut rng, &config);
+        let reader = random_rows(&mut rng, &config, ROWS_PER_CASE, false);
+        for row in 0 .. reader.len() {
+            prop_assert_eq!(
+                requirement.eval_tristate(&reader, row, &logic),
+                TriState::from(requirement.eval(&reader, row))
+            );
+        }
+    }
+
+    #[test]
+    fn compiled_requirement_matches_testing_plan(seed in any::<u64>()) {
+        let config = RandomRequirementConfig::default();
+        let mut rng = FuzzRng::new(seed);
+        let requirement = random_requirement(&mut rng, &config);
+        let reader = random_rows(&mut rng, &config, ROWS_PER_CASE, true);
+        let compiled = compile_fuzz(&requirement);
+        let executor = plan_executor(&requirement);
+
+        prop_assert_eq!(compiled.structural_hash(), &compile_plan(&requirement).structural_hash());
+        for mode in LOGIC_MODES {
+            for row in 0 .. reader.len() {
+                prop_assert_eq!(
+                    compiled.eval_tristate(&reader, row, mode),
+                    executor.evaluate_lazy(&reader, row, mode)
+                );
+            }
+        }
+        for row in 0 .. reader.len() {
+            prop_assert_eq!(compiled.eval(&reader, row), requirement.eval(&reader, row));
+        }
+    }
+
+    #[test]
+    fn plan_executor_matches_boolean_tree_eval(seed in any::<u64>()) {
+        let config = RandomRequirementConfig::default();
+        let mut rng = FuzzRng::new(seed);
+        let requirement = random_requirement(&mut rng, &config);
+        let reader = random_rows(&mut rng, &config, ROWS_PER_CASE, true);
+        let executor = plan_executor(&requirement);
+
+        for row in 0 .. reader.len() {
+            prop_assert_eq!(executor.eval_row(&reader, row), requirement.eval(&reader, row));
+        }
+    }
+}
+
+#[test]
+fn generation_is_deterministic_per_seed() {
+    let config = RandomRequirementConfig::default();
+    let mut first = FuzzRng::new(42);
+    let mut second = FuzzRng::new(42);
+    assert_eq!(random_requirement(&mut first, &config), random_requirement(&mut second, &config));
+    assert_eq!(
+        random_rows(&mut first, &config, ROWS_PER_CASE, true),
+        random_rows(&mut second, &config, ROWS_PER_CASE, true)
+    );
+}
+
+#[test]
+fn reader_reports_unknown_for_out_of_range_slots() {
+    let mut reader = FuzzReader::new(2);
+    reader.push_row(&[TriState::True]);
+    assert!(!reader.row_is_known(0));
+    assert_eq!(reader.value(0, 0), TriState::True);
+    assert_eq!(reader.value(0, 1), TriState::Unknown);
+    assert_eq!(reader.value(0, 7), TriState::Unknown);
+    assert_eq!(reader.value(3, 0), TriState::Unknown);
+    assert!(!FuzzCondition(7).eval_row(&reader, 0));
+}
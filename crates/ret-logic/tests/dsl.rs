@@ -28,7 +28,10 @@ use std::fmt;
 
 use ret_logic::Requirement;
 use ret_logic::dsl::DslError;
+use ret_logic::dsl::RequirementAst;
+use ret_logic::dsl::format_requirement;
 use ret_logic::dsl::parse_requirement;
+use ret_logic::dsl::parse_requirement_ast;
 use support::TestResult;
 use support::ensure;
 
@@ -119,6 +122,63 @@ fn parses_group_with_count() -> TestResult {
     Ok(())
 }
 
+/// Tests parses exact group with count.
+#[test]
+fn parses_exact_group_with_count() -> TestResult {
+    let expected = Requirement::require_exactly(
+        2,
+        vec![Requirement::condition(1), Requirement::condition(2), Requirement::condition(4)],
+    );
+
+    for input in
+        ["exactly(2, is_alive, has_ap, in_range)", "require_exactly(2, is_alive, has_ap, in_range)"]
+    {
+        let Ok(req) = parse_requirement(input, &resolver()) else {
+            return fail(format!("Expected parse success for {input}"));
+        };
+        ensure(req == expected, format!("Expected exact group parsing to match {input}"))?;
+    }
+    Ok(())
+}
+
+/// Tests parses upper-bound group with count.
+#[test]
+fn parses_at_most_group_with_count() -> TestResult {
+    let expected = Requirement::require_at_most(
+        1,
+        vec![Requirement::condition(1), Requirement::condition(2), Requirement::condition(4)],
+    );
+
+    for input in
+        ["at_most(1, is_alive, has_ap, in_range)", "require_at_most(1, is_alive, has_ap, in_range)"]
+    {
+        let Ok(req) = parse_requirement(input, &resolver()) else {
+            return fail(format!("Expected parse success for {input}"));
+        };
+        ensure(req == expected, format!("Expected upper-bound group parsing to match {input}"))?;
+    }
+    Ok(())
+}
+
+/// Tests parses implication with antecedent first.
+#[test]
+fn parses_implication() -> TestResult {
+    let expected = Requirement::implies(Requirement::condition(1), Requirement::condition(2));
+    let Ok(req) = parse_requirement("implies(is_alive, has_ap)", &resolver()) else {
+        return fail("Expected parse success for implies");
+    };
+    ensure(req == expected, "Expected implication parsing to keep operand order")?;
+
+    let Err(err) = parse_requirement::<u8, _>("implies(is_alive)", &resolver()) else {
+        return fail("Expected implies arity error");
+    };
+    ensure(
+        matches!(err, DslError::UnexpectedToken { .. }),
+        "Expected unexpected token diagnostic for one-argument implies",
+    )?;
+    Ok(())
+}
+
 /// Tests errors on unknown condition.
 #[test]
 fn errors_on_unknown_condition() -> TestResult {
@@ -168,6 +228,19 @@ fn validation_error_when_group_min_exceeds_total() -> TestResult {
     Ok(())
 }
 
+/// Tests validation error when exact group count exceeds total.
+#[test]
+fn validation_error_when_exact_group_count_exceeds_total() -> TestResult {
+    let Err(err) = parse_requirement::<u8, _>("exactly(2, is_alive)", &resolver()) else {
+        return fail("Expected validation error for invalid exact group count");
+    };
+    ensure(
+        matches!(err, DslError::Validation(msg) if msg.contains("Invalid group")),
+        "Expected validation error for invalid exact group count",
+    )?;
+    Ok(())
+}
+
 /// Tests errors on empty input.
 #[test]
 fn errors_on_empty_input() -> TestResult {
@@ -208,3 +281,317 @@ fn errors_on_excessive_nesting() -> TestResult {
     ensure(matches!(err, DslError::NestingTooDeep { .. }), "Expected nesting depth diagnostic")?;
     Ok(())
 }
+
+// ============================================================================
+// SECTION: Source Span Tests
+// ============================================================================
+
+/// Tests unbalanced brackets report the failing position as a span.
+#[test]
+fn unbalanced_bracket_reports_span() -> TestResult {
+    let input = "all(is_alive, has_ap";
+    let Err(err) = parse_requirement::<u8, _>(input, &resolver()) else {
+        return fail("Expected unbalanced bracket error");
+    };
+    ensure(
+        matches!(
+            err,
+            DslError::UnexpectedToken {
+                expected: "`)` after arguments",
+                ..
+            }
+        ),
+        format!("Expected missing `)` diagnostic, got {err:?}"),
+    )?;
+    ensure(err.span() == Some((20, 20)), format!("Unexpected span {:?}", err.span()))?;
+    ensure(err.line_column(input) == Some((1, 21)), "Expected end-of-input location")?;
+    ensure(
+        err.render_with_source(input)
+            .ends_with("1 | all(is_alive, has_ap\n  |                     ^"),
+        format!("Unexpected rendering:\n{}", err.render_with_source(input)),
+    )?;
+
+    let input = "is_alive)";
+    let Err(err) = parse_requirement::<u8, _>(input, &resolver()) else {
+        return fail("Expected stray bracket error");
+    };
+    ensure(
+        matches!(
+            err,
+            DslError::TrailingInput {
+                position: 8,
+                span: Some((8, 9))
+            }
+        ),
+        format!("Expected trailing `)` span, got {err:?}"),
+    )?;
+    Ok(())
+}
+
+/// Tests a misplaced token mid-expression reports its own span and line/column.
+#[test]
+fn misplaced_token_reports_span() -> TestResult {
+    let input = "any(\n    is_alive,\n    , has_ap)";
+    let Err(err) = parse_requirement::<u8, _>(input, &resolver()) else {
+        return fail("Expected misplaced comma error");
+    };
+    ensure(
+        matches!(&err, DslError::UnexpectedToken { found, span: Some((23, 24)), .. } if found == ","),
+        format!("Expected misplaced comma span, got {err:?}"),
+    )?;
+    ensure(err.line_column(input) == Some((3, 5)), "Expected third-line location")?;
+    let rendered = err.render_with_source(input);
+    ensure(
+        rendered.ends_with(" --> line 3, column 5\n  |\n3 |     , has_ap)\n  |     ^"),
+        format!("Unexpected rendering:\n{rendered}"),
+    )?;
+    Ok(())
+}
+
+/// Tests unknown combinators report the keyword's span and line/column.
+#[test]
+fn unknown_keyword_reports_span() -> TestResult {
+    let input = "all(is_alive,\n    some_of(has_ap, in_range))";
+    let Err(err) = parse_requirement::<u8, _>(input, &resolver()) else {
+        return fail("Expected unknown function error");
+    };
+    ensure(
+        matches!(&err, DslError::UnknownFunction { name, span: Some((18, 25)), .. } if name == "some_of"),
+        format!("Expected unknown function span, got {err:?}"),
+    )?;
+    ensure(err.line_column(input) == Some((2, 5)), "Expected second-line location")?;
+    let rendered = err.render_with_source(input);
+    ensure(
+        rendered
+            == "unknown function `some_of` at 18\n --> line 2, column 5\n  |\n2 |     \
+                some_of(has_ap, in_range))\n  |     ^^^^^^^",
+        format!("Unexpected rendering:\n{rendered}"),
+    )?;
+    Ok(())
+}
+
+/// Tests errors without a position render as their plain message.
+#[test]
+fn spanless_errors_render_message_only() -> TestResult {
+    let Err(err) = parse_requirement::<u8, _>("   ", &resolver()) else {
+        return fail("Expected empty input error");
+    };
+    ensure(err.span().is_none(), "Expected empty input to have no span")?;
+    ensure(err.render_with_source("   ") == err.to_string(), "Expected plain rendering")?;
+    Ok(())
+}
+
+// ============================================================================
+// SECTION: Syntax Tree Tests
+// ============================================================================
+
+/// Shorthand for a condition leaf.
+fn leaf(name: &str) -> RequirementAst {
+    RequirementAst::Condition(name.to_string())
+}
+
+/// Tests a DSL string parses to an AST that survives a JSON round trip.
+#[test]
+fn ast_round_trips_through_json() -> TestResult {
+    let input =
+        "at_least(1, is_alive && !stunned, exactly(1, has_ap, in_range), at_most(0, unregistered))";
+    let ast = parse_requirement_ast(input)?;
+    let expected = RequirementAst::RequireGroup {
+        min: 1,
+        reqs: vec![
+            RequirementAst::And(vec![
+                leaf("is_alive"),
+                RequirementAst::Not(Box::new(leaf("stunned"))),
+            ]),
+            RequirementAst::RequireExactly {
+                n: 1,
+                reqs: vec![leaf("has_ap"), leaf("in_range")],
+            },
+            RequirementAst::RequireAtMost {
+                max: 0,
+                reqs: vec![leaf("unregistered")],
+            },
+        ],
+    };
+    ensure(ast == expected, format!("Unexpected AST: {ast:?}"))?;
+
+    let json = serde_json::to_string(&ast)?;
+    ensure(json.contains(r#"{"Condition":"unregistered"}"#), format!("Unexpected JSON: {json}"))?;
+    let decoded: RequirementAst = serde_json::from_str(&json)?;
+    ensure(decoded == ast, format!("JSON round trip changed the AST: {decoded:?}"))?;
+
+    let rendered = ast.to_string();
+    ensure(
+        parse_requirement_ast(&rendered)? == ast,
+        format!("Rendered DSL does not re-parse to the same AST: {rendered}"),
+    )?;
+    Ok(())
+}
+
+/// Tests the AST uses the same JSON shape as a requirement over names.
+#[test]
+fn ast_json_matches_requirement_of_names() -> TestResult {
+    let input = "any(is_alive, not(stunned), require_exactly(1, has_ap, in_range))";
+    let names = |name: &str| Some(name.to_string());
+    let requirement: Requirement<String> = parse_requirement(input, &names)?;
+    let ast = parse_requirement_ast(input)?;
+    ensure(
+        serde_json::to_value(&ast)? == serde_json::to_value(&requirement)?,
+        "AST JSON should match Requirement<String> JSON",
+    )?;
+    Ok(())
+}
+
+/// Tests resolving an AST matches parsing with the resolver directly.
+#[test]
+fn resolved_ast_matches_direct_parse() -> TestResult {
+    let input = "(is_alive || has_ap) && at_most(1, stunned, in_range)";
+    let ast: RequirementAst =
+        serde_json::from_str(&serde_json::to_string(&parse_requirement_ast(input)?)?)?;
+    let resolved: Requirement<u8> = ast.resolve(&resolver())?;
+    ensure(
+        resolved == parse_requirement(input, &resolver())?,
+        format!("Resolved AST differs from direct parse: {resolved:?}"),
+    )?;
+    Ok(())
+}
+
+/// Tests deserialized trees are resolved and validated without source spans.
+#[test]
+fn deserialized_ast_reports_resolution_and_validation_errors() -> TestResult {
+    let unknown: RequirementAst =
+        serde_json::from_str(r#"{"And":[{"Condition":"is_alive"},{"Condition":"flying"}]}"#)?;
+    let err = unknown.resolve::<u8, _>(&resolver()).err();
+    ensure(
+        matches!(&err, Some(DslError::UnknownCondition { name, span: None, .. }) if name == "flying"),
+        format!("Expected spanless unknown condition, got {err:?}"),
+    )?;
+
+    let oversized: RequirementAst =
+        serde_json::from_str(r#"{"RequireGroup":{"min":2,"reqs":[{"Condition":"is_alive"}]}}"#)?;
+    let err = oversized.resolve::<u8, _>(&resolver()).err();
+    ensure(
+        matches!(err, Some(DslError::Validation(_))),
+        format!("Expected validation error, got {err:?}"),
+    )?;
+    ensure(
+        matches!(parse_requirement_ast("at_least(2, is_alive)"), Err(DslError::Validation(_))),
+        "Parsed ASTs are validated too",
+    )?;
+    Ok(())
+}
+
+/// Tests unknown conditions keep their source span when parsed from text.
+#[test]
+fn unknown_condition_span_follows_source_order() -> TestResult {
+    let input = "any(is_alive, not(has_ap)) && flying";
+    let Err(err) = parse_requirement::<u8, _>(input, &resolver()) else {
+        return fail("Expected unknown condition error");
+    };
+    ensure(
+        matches!(&err, DslError::UnknownCondition { name, position: 30, span: Some((30, 36)) } if name == "flying"),
+        format!("Expected span of the unknown symbol, got {err:?}"),
+    )?;
+    ensure(parse_requirement_ast(input).is_ok(), "Syntax-only parsing ignores symbols")?;
+    Ok(())
+}
+
+// ============================================================================
+// SECTION: Formatter Tests
+// ============================================================================
+
+/// Tests messy input is re-emitted in canonical function form.
+#[test]
+fn formats_messy_input_to_canonical_form() -> TestResult {
+    let formatted = format_requirement("  !has_ap&&(is_alive ||  flying)&&all(b,a)  ")?;
+    ensure(
+        formatted == "all(a, any(flying, is_alive), b, not(has_ap))",
+        format!("Unexpected canonical form: {formatted}"),
+    )?;
+
+    let formatted = format_requirement("exactly(1,z,y) || at_most( 2 , c , b , a )")?;
+    ensure(
+        formatted == "any(at_most(2, c, b, a), exactly(1, z, y))",
+        format!("Unexpected group ordering: {formatted}"),
+    )?;
+    Ok(())
+}
+
+/// Tests counted groups keep their members in disclosure order.
+#[test]
+fn formatting_keeps_counted_group_member_order() -> TestResult {
+    let formatted = format_requirement("at_least(2, c, a, b)")?;
+    ensure(
+        formatted == "at_least(2, c, a, b)",
+        format!("Counted group members were reordered: {formatted}"),
+    )?;
+
+    let formatted = format_requirement("exactly(1, all(z, y), a, a)")?;
+    ensure(
+        formatted == "exactly(1, all(y, z), a, a)",
+        format!("Counted group members were reordered or deduplicated: {formatted}"),
+    )?;
+    Ok(())
+}
+
+/// Tests implication operands keep their order while their members are sorted.
+#[test]
+fn formatting_keeps_implication_operand_order() -> TestResult {
+    let formatted = format_requirement("implies(z, all(b, a))")?;
+    ensure(
+        formatted == "implies(z, all(a, b))",
+        format!("Implication operands were reordered: {formatted}"),
+    )?;
+    Ok(())
+}
+
+/// Tests expressions wider than the line limit wrap one argument per line.
+#[test]
+fn formats_long_expressions_across_lines() -> TestResult {
+    let input = "at_least(2, condition_alpha_is_true, condition_beta_is_true, \
+                 any(condition_gamma_is_true, condition_delta_is_true, condition_epsilon_is_true, \
+                 condition_zeta_is_true))";
+    let expected = "\
+at_least(
+    2,
+    condition_alpha_is_true,
+    condition_beta_is_true,
+    any(
+        condition_delta_is_true,
+        condition_epsilon_is_true,
+        condition_gamma_is_true,
+        condition_zeta_is_true
+    )
+)";
+    let formatted = format_requirement(input)?;
+    ensure(formatted == expected, format!("Unexpected layout:\n{formatted}"))?;
+    Ok(())
+}
+
+/// Tests formatting is idempotent and preserves the parsed meaning.
+#[test]
+fn formatting_is_idempotent() -> TestResult {
+    let inputs = [
+        "a && (b && c) || !d",
+        "not(not(x)) && any(y, any(z, w))",
+        "at_least(2, condition_alpha_is_true, condition_beta_is_true, \
+         any(condition_gamma_is_true, condition_delta_is_true, condition_epsilon_is_true))",
+    ];
+    for input in inputs {
+        let once = format_requirement(input)?;
+        let twice = format_requirement(&once)?;
+        ensure(once == twice, format!("Formatting {input:?} is not idempotent:\n{once}\n{twice}"))?;
+        let reparsed = parse_requirement_ast(&once)?;
+        ensure(
+            reparsed.to_string().replace(' ', "") == once.replace([' ', '\n'], ""),
+            format!("Formatted output does not re-parse to itself: {once}"),
+        )?;
+    }
+
+    let err = format_requirement("all(a,").err();
+    ensure(
+        matches!(err, Some(DslError::UnexpectedToken { .. })),
+        format!("Expected parse error, got {err:?}"),
+    )?;
+    Ok(())
+}
@@ -23,16 +23,22 @@
 
 mod support;
 
+use std::cell::Cell;
+use std::cell::RefCell;
+
 use ret_logic::ColumnKey;
 use ret_logic::ConditionEval;
 use ret_logic::Constant;
+use ret_logic::LogicMode;
 use ret_logic::OpCode;
 use ret_logic::Operation;
 use ret_logic::Plan;
 use ret_logic::PlanBuilder;
+use ret_logic::ReaderLen;
 use ret_logic::RequirementError;
 use ret_logic::RequirementResult;
 use ret_logic::Row;
+use ret_logic::TriState;
 use ret_logic::executor::ExecutorBuilder;
 use ret_logic::executor::PlanExecutor;
 use ret_logic::executor::operations;
@@ -66,6 +72,12 @@ impl TestReader {
     }
 }
 
+impl ReaderLen for TestReader {
+    fn len(&self) -> usize {
+        self.values.len()
+    }
+}
+
 // ============================================================================
 // SECTION: Dispatch Table Handlers
 // ============================================================================
@@ -164,6 +176,8 @@ const fn opcode_index(opcode: OpCode) -> usize {
         OpCode::OrStart => 2,
         OpCode::OrEnd => 3,
         OpCode::Not => 4,
+        OpCode::ImpliesStart => 5,
+        OpCode::ImpliesEnd => 6,
         OpCode::FloatGte => 10,
         OpCode::FloatLte => 11,
         OpCode::FloatEq => 12,
@@ -635,3 +649,325 @@ fn test_operations_invalid_constant() -> TestResult {
     ensure(result.is_err(), "Expected invalid constant to return error")?;
     Ok(())
 }
+
+// ============================================================================
+// SECTION: Lazy Evaluation Tests
+// ============================================================================
+
+/// Reader whose probes return fixed results and record every invocation.
+struct ProbeReader {
+    /// Result per probe index; `None` makes the probe fail (Unknown).
+    results: Vec<Option<bool>>,
+    /// Probe indexes in invocation order.
+    calls: RefCell<Vec<u16>>,
+}
+
+impl ProbeReader {
+    const fn new(results: Vec<Option<bool>>) -> Self {
+        Self {
+            results,
+            calls: RefCell::new(Vec::new()),
+        }
+    }
+
+    fn calls(&self) -> Vec<u16> {
+        self.calls.borrow().clone()
+    }
+}
+
+fn handle_probe(
+    reader: &ProbeReader,
+    _row: Row,
+    op: Operation,
+    _constants: &[Constant],
+) -> RequirementResult<bool> {
+    reader.calls.borrow_mut().push(op.operand_a);
+    reader
+        .results
+        .get(usize::from(op.operand_a))
+        .copied()
+        .flatten()
+        .ok_or_else(|| RequirementError::condition_error("Probe unavailable"))
+}
+
+fn probe_executor(plan: Plan) -> PlanExecutor<ProbeReader> {
+    ExecutorBuilder::new().register(OpCode::DomainStart, handle_probe).build(plan)
+}
+
+fn probe(builder: PlanBuilder, index: u16) -> PlanBuilder {
+    builder.add_op(OpCode::DomainStart, index, 0, 0)
+}
+
+/// Tests a satisfied OR never invokes its remaining probes.
+#[test]
+fn test_evaluate_lazy_skips_or_after_true() -> TestResult {
+    let plan = probe(probe(probe(PlanBuilder::new().or_start(), 0), 1), 2).or_end().build();
+    let executor = probe_executor(plan);
+    let reader = ProbeReader::new(vec![Some(true), Some(false), Some(false)]);
+
+    let result = executor.evaluate_lazy(&reader, 0, LogicMode::Kleene);
+
+    ensure(result == TriState::True, "Expected satisfied OR")?;
+    ensure(reader.calls() == vec![0], "Expected probes after the true child to be skipped")?;
+    Ok(())
+}
+
+/// Tests a failed AND skips nested groups but still applies a trailing NOT.
+#[test]
+fn test_evaluate_lazy_skips_nested_group_after_false() -> TestResult {
+    let builder = probe(PlanBuilder::new().and_start(), 0).or_start();
+    let plan = probe(probe(builder, 1), 2).or_end().add_op(OpCode::Not, 0, 0, 0).and_end().build();
+    let executor = probe_executor(plan);
+    let reader = ProbeReader::new(vec![Some(false), Some(true), Some(true)]);
+
+    let result = executor.evaluate_lazy(&reader, 0, LogicMode::Kleene);
+
+    ensure(result == TriState::True, "Expected NOT over failed AND")?;
+    ensure(reader.calls() == vec![0], "Expected the nested group to be skipped")?;
+    Ok(())
+}
+
+/// Tests an unknown child does not short-circuit its siblings.
+#[test]
+fn test_evaluate_lazy_unknown_does_not_short_circuit() -> TestResult {
+    let or_plan = probe(probe(PlanBuilder::new().or_start(), 0), 1).or_end().build();
+    let reader = ProbeReader::new(vec![None, Some(true)]);
+    let result = probe_executor(or_plan).evaluate_lazy(&reader, 0, LogicMode::Kleene);
+    ensure(result == TriState::True, "Expected OR(unknown, true) to be true")?;
+    ensure(reader.calls() == vec![0, 1], "Expected both OR probes to run")?;
+
+    let and_plan = probe(probe(PlanBuilder::new().and_start(), 0), 1).and_end().build();
+    let reader = ProbeReader::new(vec![None, Some(true)]);
+    let result = probe_executor(and_plan).evaluate_lazy(&reader, 0, LogicMode::Kleene);
+    ensure(result == TriState::Unknown, "Expected AND(unknown, true) to be unknown")?;
+    ensure(reader.calls() == vec![0, 1], "Expected both AND probes to run")?;
+    Ok(())
+}
+
+/// Tests Bochvar logic evaluates every probe because unknowns are infectious.
+#[test]
+fn test_evaluate_lazy_bochvar_evaluates_all() -> TestResult {
+    let plan = probe(probe(PlanBuilder::new().and_start(), 0), 1).and_end().build();
+    let executor = probe_executor(plan);
+    let reader = ProbeReader::new(vec![Some(false), None]);
+
+    let result = executor.evaluate_lazy(&reader, 0, LogicMode::Bochvar);
+
+    ensure(!LogicMode::Bochvar.short_circuits(), "Expected Bochvar to be ineligible")?;
+    ensure(result == TriState::Unknown, "Expected Bochvar AND(false, unknown) to be unknown")?;
+    ensure(reader.calls() == vec![0, 1], "Expected every probe to run under Bochvar")?;
+    Ok(())
+}
+
+/// Tests lazy evaluation fails closed on malformed plans.
+#[test]
+fn test_evaluate_lazy_malformed_plan() -> TestResult {
+    let plan = probe(PlanBuilder::new().and_start(), 0).or_end().build();
+    let reader = ProbeReader::new(vec![Some(true)]);
+
+    let result = probe_executor(plan).evaluate_lazy(&reader, 0, LogicMode::Kleene);
+
+    ensure(result == TriState::False, "Expected malformed plan to fail closed")?;
+    Ok(())
+}
+
+// ============================================================================
+// SECTION: Implication Tests
+// ============================================================================
+
+/// Builds `implies(probe 0, probe 1)`.
+fn implication_plan() -> Plan {
+    probe(probe(PlanBuilder::new().implies_start(), 0), 1).implies_end().build()
+}
+
+/// Tests boolean evaluation of an implication frame follows `!a || b`.
+#[test]
+fn test_executor_implies_truth_table() -> TestResult {
+    let executor = probe_executor(implication_plan());
+    for (antecedent, consequent, expected) in [
+        (true, true, true),
+        (true, false, false),
+        (false, true, true),
+        (false, false, true),
+    ] {
+        let reader = ProbeReader::new(vec![Some(antecedent), Some(consequent)]);
+        ensure(
+            executor.eval_row(&reader, 0) == expected,
+            format!("Expected implies({antecedent}, {consequent}) to be {expected}"),
+        )?;
+        ensure(reader.calls() == vec![0, 1], "Expected both implication probes to run")?;
+    }
+    Ok(())
+}
+
+/// Tests the logic mode decides an implication between two unknown operands.
+#[test]
+fn test_evaluate_lazy_implies_unknown_follows_logic_mode() -> TestResult {
+    let executor = probe_executor(implication_plan());
+    for (mode, expected) in [
+        (LogicMode::Kleene, TriState::Unknown),
+        (LogicMode::Bochvar, TriState::Unknown),
+        (LogicMode::Lukasiewicz, TriState::True),
+    ] {
+        let reader = ProbeReader::new(vec![None, None]);
+        let result = executor.evaluate_lazy(&reader, 0, mode);
+        ensure(
+            result == expected,
+            format!("Expected {mode:?} unknown -> unknown to be {expected:?}"),
+        )?;
+        ensure(reader.calls() == vec![0, 1], "Expected both implication probes to run")?;
+    }
+    Ok(())
+}
+
+/// Tests a false antecedent still dispatches the consequent and yields true.
+#[test]
+fn test_evaluate_lazy_implies_evaluates_consequent() -> TestResult {
+    let executor = probe_executor(implication_plan());
+    for mode in [LogicMode::Kleene, LogicMode::Bochvar, LogicMode::Lukasiewicz] {
+        let reader = ProbeReader::new(vec![Some(false), None]);
+        let result = executor.evaluate_lazy(&reader, 0, mode);
+        let expected =
+            if mode == LogicMode::Bochvar { TriState::Unknown } else { TriState::True };
+        ensure(
+            result == expected,
+            format!("Expected {mode:?} false -> unknown to be {expected:?}"),
+        )?;
+        ensure(reader.calls() == vec![0, 1], "Expected the consequent probe to run")?;
+    }
+    Ok(())
+}
+
+/// Tests an implication nested in a decided group is skipped entirely.
+#[test]
+fn test_evaluate_lazy_skips_implication_after_false() -> TestResult {
+    let builder = probe(PlanBuilder::new().and_start(), 0).implies_start();
+    let plan = probe(probe(builder, 1), 2).implies_end().and_end().build();
+    let executor = probe_executor(plan);
+    let reader = ProbeReader::new(vec![Some(false), Some(true), Some(false)]);
+
+    let result = executor.evaluate_lazy(&reader, 0, LogicMode::Lukasiewicz);
+
+    ensure(result == TriState::False, "Expected failed AND")?;
+    ensure(reader.calls() == vec![0], "Expected the implication to be skipped")?;
+    Ok(())
+}
+
+/// Tests implication frames without exactly two operands, or with a NOT, fail closed.
+#[test]
+fn test_executor_malformed_implication_fails_closed() -> TestResult {
+    let plans = [
+        probe(PlanBuilder::new().implies_start(), 0).implies_end().build(),
+        probe(probe(probe(PlanBuilder::new().implies_start(), 0), 1), 2).implies_end().build(),
+        probe(probe(PlanBuilder::new().implies_start(), 0), 1)
+            .add_op(OpCode::Not, 0, 0, 0)
+            .implies_end()
+            .build(),
+        PlanBuilder::new().implies_start().and_end().build(),
+    ];
+    for plan in plans {
+        let executor = probe_executor(plan);
+        let reader = ProbeReader::new(vec![Some(false), Some(false), Some(false)]);
+        ensure(!executor.eval_row(&reader, 0), "Expected malformed implication to fail closed")?;
+        for mode in [LogicMode::Kleene, LogicMode::Bochvar, LogicMode::Lukasiewicz] {
+            ensure(
+                executor.evaluate_lazy(&reader, 0, mode) == TriState::False,
+                format!("Expected malformed implication to fail closed under {mode:?}"),
+            )?;
+        }
+    }
+    Ok(())
+}
+
+// ============================================================================
+// SECTION: Streaming Evaluation Tests
+// ============================================================================
+
+/// Builds `value >= 50 || (value <= 10 && has_all_flags(FLAG))`.
+fn streaming_executor() -> TestResult<PlanExecutor<TestReader>> {
+    let mut builder = PlanBuilder::new();
+    let high = builder.add_float_constant(50.0)?;
+    let low = builder.add_float_constant(10.0)?;
+    let flags = builder.add_flags_constant(0b01)?;
+    let plan = builder
+        .or_start()
+        .add_op(OpCode::FloatGte, 0, high.0, 0)
+        .and_start()
+        .add_op(OpCode::FloatLte, 0, low.0, 0)
+        .add_op(OpCode::HasAllFlags, 1, flags.0, 0)
+        .and_end()
+        .or_end()
+        .build();
+    Ok(PlanExecutor::new(plan, TEST_DISPATCH_TABLE))
+}
+
+/// Splits rows into readers of at most `size` rows each.
+fn chunked(values: &[f32], flags: &[u64], size: usize) -> Vec<TestReader> {
+    values
+        .chunks(size)
+        .zip(flags.chunks(size))
+        .map(|(values, flags)| TestReader::new(values.to_vec(), flags.to_vec()))
+        .collect()
+}
+
+/// Tests streamed results match evaluating the materialized reader row by row.
+#[test]
+fn test_eval_stream_matches_materialized() -> TestResult {
+    let executor = streaming_executor()?;
+    let values: Vec<f32> = (0u8 .. 23).map(|value| f32::from(value) * 5.0).collect();
+    let flags: Vec<u64> = (0 .. 23).map(|row| row % 2).collect();
+    let materialized = TestReader::new(values.clone(), flags.clone());
+    let expected: Vec<TriState> = (0 .. materialized.len())
+        .map(|row| executor.evaluate_lazy(&materialized, row, LogicMode::Kleene))
+        .collect();
+
+    for size in [1, 4, 23, 64] {
+        let mut chunks = chunked(&values, &flags, size);
+        chunks.insert(1, TestReader::new(Vec::new(), Vec::new()));
+        let streamed: Vec<TriState> = executor.eval_stream(chunks, LogicMode::Kleene).collect();
+        ensure(streamed == expected, format!("Streamed results diverged for chunk size {size}"))?;
+    }
+    ensure(
+        expected
+            .iter()
+            .zip(0 ..)
+            .all(|(result, row)| *result == TriState::from(executor.eval_row(&materialized, row))),
+        "Expected tri-state results to agree with boolean evaluation",
+    )?;
+    Ok(())
+}
+
+/// Tests chunks are pulled only as rows are consumed.
+#[test]
+fn test_eval_stream_pulls_chunks_on_demand() -> TestResult {
+    let executor = streaming_executor()?;
+    let pulled = Cell::new(0usize);
+    let source = std::iter::from_fn(|| {
+        pulled.set(pulled.get() + 1);
+        assert!(pulled.get() <= 3, "chunk source over-read");
+        Some(TestReader::new(vec![75.0, 5.0], vec![0, 1]))
+    });
+
+    let first: Vec<TriState> = executor.eval_stream(source, LogicMode::Kleene).take(5).collect();
+
+    ensure(first.len() == 5, "Expected five streamed rows")?;
+    ensure(pulled.get() == 3, format!("Expected three chunks pulled, got {}", pulled.get()))?;
+    ensure(first.iter().all(|result| *result == TriState::True), "Expected every row to pass")?;
+    Ok(())
+}
+
+/// Tests an unbounded source streams a large row count one chunk at a time.
+#[test]
+fn test_eval_stream_unbounded_source() -> TestResult {
+    let executor = streaming_executor()?;
+    let source = std::iter::repeat_with(|| TestReader::new(vec![75.0; 64], vec![0; 64]));
+
+    let passing = executor
+        .eval_stream(source, LogicMode::Kleene)
+        .take(1_000_000)
+        .filter(|result| *result == TriState::True)
+        .count();
+
+    ensure(passing == 1_000_000, format!("Expected every streamed row to pass, got {passing}"))?;
+    Ok(())
+}
@@ -0,0 +1,254 @@
+// crates/ret-logic/tests/explain.rs
+// ============================================================================
+// Module: Explanation Tests
+// Description: Tests for requirement explanation trees and operator trace hooks.
+// Purpose: Validate that explanations mirror evaluation results node by node.
+// Dependencies: ret_logic::explain, ret_logic::tristate
+// ============================================================================
+//! ## Overview
+//! Validates explanation trees built from tri-state evaluation, including
+//! require-group counts, depth-first leaf identifiers, and fail-closed depth.
+
+#![allow(
+    clippy::panic,
+    clippy::print_stdout,
+    clippy::print_stderr,
+    clippy::unwrap_used,
+    clippy::expect_used,
+    clippy::use_debug,
+    clippy::dbg_macro,
+    clippy::panic_in_result_fn,
+    clippy::unwrap_in_result,
+    reason = "Test-only output and panic-based assertions are permitted."
+)]
+
+mod support;
+
+use std::fmt;
+
+use ret_logic::ExplanationNode;
+use ret_logic::GroupCounts;
+use ret_logic::KleeneLogic;
+use ret_logic::Requirement;
+use ret_logic::RequirementId;
+use ret_logic::RequirementTrace;
+use ret_logic::TraceOperator;
+use ret_logic::TriState;
+use ret_logic::TriStateConditionEval;
+use ret_logic::requirement::MAX_EVAL_DEPTH;
+use support::TestResult;
+use support::ensure;
+
+// ============================================================================
+// SECTION: Test Condition + Reader
+// ============================================================================
+
+/// Named test condition evaluated by index into the reader row
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+struct Check(usize);
+
+impl fmt::Display for Check {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "check_{}", self.0)
+    }
+}
+
+impl TriStateConditionEval for Check {
+    type Reader<'a> = Vec<TriState>;
+
+    fn eval_row_tristate(&self, reader: &Self::Reader<'_>, _row: usize) -> TriState {
+        reader[self.0]
+    }
+}
+
+/// Builds a condition leaf for the given reader index
+const fn check(index: usize) -> Requirement<Check> {
+    Requirement::condition(Check(index))
+}
+
+/// Builds a condition explanation leaf
+fn leaf(id: u64, index: usize, result: TriState) -> ExplanationNode<Check> {
+    ExplanationNode::Condition {
+        id: RequirementId::from_raw(id).expect("nonzero id"),
+        condition: Check(index),
+        result,
+    }
+}
+
+/// Nested plan: `and(check_0, or(check_1, not(check_2)), require_group(2, [check_3..check_5]))`
+fn nested_requirement() -> Requirement<Check> {
+    Requirement::and(vec![
+        check(0),
+        Requirement::or(vec![check(1), Requirement::negate(check(2))]),
+        Requirement::require_group(2, vec![check(3), check(4), check(5)]),
+    ])
+}
+
+// ============================================================================
+// SECTION: Explanation Trees
+// ============================================================================
+
+/// Tests that a nested `and`/`or`/`require_group` explanation mirrors every node result.
+#[test]
+fn test_nested_explanation_reports_require_group_shortfall() -> TestResult {
+    let reader = vec![
+        TriState::True,
+        TriState::False,
+        TriState::False,
+        TriState::True,
+        TriState::False,
+        TriState::False,
+    ];
+    let requirement = nested_requirement();
+
+    let explanation = requirement.eval_tristate_with_explanation(&reader, 0, &KleeneLogic);
+
+    ensure(explanation.result == TriState::False, "Expected nested requirement to fail")?;
+    ensure(
+        explanation.result == requirement.eval_tristate(&reader, 0, &KleeneLogic),
+        "Expected explanation result to match eval_tristate",
+    )?;
+    let expected = ExplanationNode::And {
+        result: TriState::False,
+        children: vec![
+            leaf(1, 0, TriState::True),
+            ExplanationNode::Or {
+                result: TriState::True,
+                children: vec![
+                    leaf(2, 1, TriState::False),
+                    ExplanationNode::Not {
+                        result: TriState::True,
+                        child: Box::new(leaf(3, 2, TriState::False)),
+                    },
+                ],
+            },
+            ExplanationNode::RequireGroup {
+                result: TriState::False,
+                min: 2,
+                satisfied: 1,
+                unknown: 0,
+                children: vec![
+                    leaf(4, 3, TriState::True),
+                    leaf(5, 4, TriState::False),
+                    leaf(6, 5, TriState::False),
+                ],
+            },
+        ],
+    };
+    ensure(
+        explanation.root.as_ref() == Some(&expected),
+        format!("Unexpected explanation tree: {:?}", explanation.root),
+    )?;
+    Ok(())
+}
+
+/// Tests the human-readable rendering of an explanation tree.
+#[test]
+fn test_explanation_display_renders_indented_tree() -> TestResult {
+    let reader = vec![
+        TriState::True,
+        TriState::False,
+        TriState::False,
+        TriState::True,
+        TriState::Unknown,
+        TriState::False,
+    ];
+    let explanation = nested_requirement().eval_tristate_with_explanation(&reader, 0, &KleeneLogic);
+    let root = explanation.root.expect("explanation root");
+
+    let expected = "\
+and: unknown
+  #1 check_0: true
+  or: true
+    #2 check_1: false
+    not: true
+      #3 check_2: false
+  require_group(2 of 3): unknown (satisfied 1, unknown 1)
+    #4 check_3: true
+    #5 check_4: unknown
+    #6 check_5: false
+";
+    ensure(root.to_string() == expected, format!("Unexpected rendering:\n{root}"))?;
+    Ok(())
+}
+
+/// Tests that an exact group explains an overshoot with its counts.
+#[test]
+fn test_require_exactly_explanation_reports_overshoot() -> TestResult {
+    let reader = vec![TriState::True, TriState::True, TriState::True];
+    let requirement = Requirement::require_exactly(2, vec![check(0), check(1), check(2)]);
+
+    let explanation = requirement.eval_tristate_with_explanation(&reader, 0, &KleeneLogic);
+    let root = explanation.root.expect("explanation root");
+
+    let expected = "\
+require_exactly(2 of 3): false (satisfied 3, unknown 0)
+  #1 check_0: true
+  #2 check_1: true
+  #3 check_2: true
+";
+    ensure(explanation.result == TriState::False, "Expected overshoot to fail")?;
+    ensure(root.to_string() == expected, format!("Unexpected rendering:\n{root}"))?;
+    Ok(())
+}
+
+/// Tests that exceeding the evaluation depth fails closed without an explanation.
+#[test]
+fn test_explanation_fails_closed_past_max_depth() -> TestResult {
+    let mut requirement = check(0);
+    for _ in 0 ..= MAX_EVAL_DEPTH {
+        requirement = Requirement::negate(requirement);
+    }
+    let reader = vec![TriState::True];
+
+    let explanation = requirement.eval_tristate_with_explanation(&reader, 0, &KleeneLogic);
+
+    ensure(explanation.result == TriState::Unknown, "Expected depth overflow to be Unknown")?;
+    ensure(explanation.root.is_none(), "Expected no explanation past max depth")?;
+    Ok(())
+}
+
+// ============================================================================
+// SECTION: Operator Trace Hooks
+// ============================================================================
+
+/// Records trace hook events in call order
+#[derive(Default)]
+struct EventTrace {
+    /// Ordered trace events
+    events: Vec<String>,
+}
+
+impl RequirementTrace<Check> for EventTrace {
+    fn on_condition_evaluated(&mut self, condition: &Check, result: TriState) {
+        self.events.push(format!("{condition}={result:?}"));
+    }
+
+    fn on_operator_evaluated(&mut self, operator: TraceOperator, result: TriState) {
+        self.events.push(format!("{operator:?}={result:?}"));
+    }
+}
+
+/// Tests that operator hooks fire in post-order with group counts.
+#[test]
+fn test_operator_hooks_fire_after_children() -> TestResult {
+    let reader = vec![TriState::True, TriState::Unknown];
+    let requirement = Requirement::require_group(1, vec![check(0), check(1)]);
+    let mut trace = EventTrace::default();
+
+    requirement.eval_tristate_with_trace(&reader, 0, &KleeneLogic, &mut trace);
+
+    let group = TraceOperator::RequireGroup {
+        min: 1,
+        counts: GroupCounts {
+            satisfied: 1,
+            unknown: 1,
+            total: 2,
+        },
+    };
+    let expected =
+        vec!["check_0=True".to_string(), "check_1=Unknown".to_string(), format!("{group:?}=True")];
+    ensure(trace.events == expected, format!("Unexpected trace events: {:?}", trace.events))?;
+    ensure(group.children() == 2, "Expected group to report its child count")?;
+    Ok(())
+}
@@ -0,0 +1,158 @@
+// crates/ret-logic/tests/hashing.rs
+// ============================================================================
+// Module: Structural Hashing Tests
+// Description: Tests for requirement and plan structural digests.
+// Purpose: Validate digest stability, sensitivity, and canonicalization interplay.
+// Dependencies: ret_logic::hashing, ret_logic::plan, ret_logic::requirement
+// ============================================================================
+//! ## Overview
+//! Integration tests for [`ret_logic::Requirement::structural_hash`] and
+//! [`ret_logic::Plan::structural_hash`].
+
+#![allow(
+    clippy::panic,
+    clippy::print_stdout,
+    clippy::print_stderr,
+    clippy::unwrap_used,
+    clippy::expect_used,
+    clippy::use_debug,
+    clippy::dbg_macro,
+    clippy::panic_in_result_fn,
+    clippy::unwrap_in_result,
+    reason = "Test-only output and panic-based assertions are permitted."
+)]
+
+mod support;
+
+use ret_logic::OpCode;
+use ret_logic::PlanBuilder;
+use ret_logic::Requirement;
+use support::TestResult;
+use support::ensure;
+
+// ============================================================================
+// SECTION: Fixtures
+// ============================================================================
+
+/// Builds a named condition leaf.
+fn named(name: &str) -> Requirement<String> {
+    Requirement::condition(name.to_string())
+}
+
+/// Builds the reference tree `(a AND b) OR NOT c`.
+fn sample_tree() -> Requirement<String> {
+    Requirement::or(vec![
+        Requirement::and(vec![named("a"), named("b")]),
+        Requirement::negate(named("c")),
+    ])
+}
+
+// ============================================================================
+// SECTION: Requirement Hash Tests
+// ============================================================================
+
+/// Tests structurally identical trees hash equally.
+#[test]
+fn test_structural_hash_matches_for_identical_trees() -> TestResult {
+    let lhs = sample_tree();
+    let rhs = sample_tree();
+    ensure(lhs.structural_hash() == rhs.structural_hash(), "Expected identical trees to match")?;
+    ensure(lhs.structural_hash().to_hex().len() == 64, "Expected 32-byte hex digest")?;
+    Ok(())
+}
+
+/// Tests a changed combinator changes the digest.
+#[test]
+fn test_structural_hash_changes_with_combinator() -> TestResult {
+    let baseline = sample_tree().structural_hash();
+    let swapped = Requirement::and(vec![
+        Requirement::and(vec![named("a"), named("b")]),
+        Requirement::negate(named("c")),
+    ]);
+    ensure(swapped.structural_hash() != baseline, "Expected OR -> AND to change the digest")?;
+
+    let quorum = Requirement::require_group(
+        1,
+        vec![Requirement::and(vec![named("a"), named("b")]), Requirement::negate(named("c"))],
+    );
+    ensure(quorum.structural_hash() != baseline, "Expected OR -> group to change the digest")?;
+
+    let raised = Requirement::require_group(
+        2,
+        vec![Requirement::and(vec![named("a"), named("b")]), Requirement::negate(named("c"))],
+    );
+    ensure(
+        raised.structural_hash() != quorum.structural_hash(),
+        "Expected group threshold to change the digest",
+    )?;
+    Ok(())
+}
+
+/// Tests condition names and nesting boundaries are covered by the digest.
+#[test]
+fn test_structural_hash_covers_names_and_nesting() -> TestResult {
+    let renamed = Requirement::or(vec![
+        Requirement::and(vec![named("a"), named("b")]),
+        Requirement::negate(named("d")),
+    ]);
+    ensure(
+        renamed.structural_hash() != sample_tree().structural_hash(),
+        "Expected renamed condition to change the digest",
+    )?;
+
+    let flat = Requirement::and(vec![named("a"), named("b"), named("c")]);
+    let nested = Requirement::and(vec![Requirement::and(vec![named("a"), named("b")]), named("c")]);
+    ensure(
+        flat.structural_hash() != nested.structural_hash(),
+        "Expected nesting to change the digest",
+    )?;
+    Ok(())
+}
+
+/// Tests canonicalization makes reordered AND/OR members hash equally.
+#[test]
+fn test_structural_hash_after_canonicalize_ignores_member_order() -> TestResult {
+    let reordered = Requirement::or(vec![
+        Requirement::negate(named("c")),
+        Requirement::and(vec![named("b"), named("a")]),
+    ]);
+    ensure(
+        reordered.structural_hash() != sample_tree().structural_hash(),
+        "Expected member order to matter before canonicalization",
+    )?;
+    ensure(
+        reordered.canonicalize().structural_hash()
+            == sample_tree().canonicalize().structural_hash(),
+        "Expected canonical forms to hash equally",
+    )?;
+    Ok(())
+}
+
+// ============================================================================
+// SECTION: Plan Hash Tests
+// ============================================================================
+
+/// Tests plan digests match for identical plans and track changes.
+#[test]
+fn test_plan_structural_hash() -> TestResult {
+    let build = |opcode: OpCode, threshold: i32| -> TestResult<ret_logic::Plan> {
+        let mut builder = PlanBuilder::new();
+        builder.add_int_constant(threshold)?;
+        Ok(builder.and_start().add_op(opcode, 0, 0, 0).and_end().build())
+    };
+
+    let baseline = build(OpCode::IntGte, 10)?.structural_hash();
+    ensure(
+        build(OpCode::IntGte, 10)?.structural_hash() == baseline,
+        "Expected identical plans to match",
+    )?;
+    ensure(
+        build(OpCode::IntLte, 10)?.structural_hash() != baseline,
+        "Expected opcode change to change the digest",
+    )?;
+    ensure(
+        build(OpCode::IntGte, 11)?.structural_hash() != baseline,
+        "Expected constant change to change the digest",
+    )?;
+    Ok(())
+}
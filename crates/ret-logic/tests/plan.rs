@@ -24,12 +24,21 @@
 mod support;
 
 use ret_logic::ColumnKey;
+use ret_logic::ConditionEval;
 use ret_logic::Constant;
 use ret_logic::ConstantIndex;
+use ret_logic::MIN_COMPATIBLE_PLAN_FORMAT_VERSION;
 use ret_logic::OpCode;
 use ret_logic::Operation;
+use ret_logic::PLAN_FORMAT_VERSION;
 use ret_logic::Plan;
 use ret_logic::PlanBuilder;
+use ret_logic::PlanError;
+use ret_logic::RequirementResult;
+use ret_logic::Row;
+use ret_logic::executor::ExecutorBuilder;
+use ret_logic::executor::PlanExecutor;
+use ret_logic::executor::operations;
 use support::TestResult;
 use support::ensure;
 
@@ -181,6 +190,17 @@ fn test_opcode_is_comparison() -> TestResult {
     Ok(())
 }
 
+/// Tests opcode flag check detection.
+#[test]
+fn test_opcode_is_flag_check() -> TestResult {
+    ensure(OpCode::HasAllFlags.is_flag_check(), "Expected HasAllFlags to be a flag check")?;
+    ensure(OpCode::HasAnyFlags.is_flag_check(), "Expected HasAnyFlags to be a flag check")?;
+    ensure(OpCode::HasNoneFlags.is_flag_check(), "Expected HasNoneFlags to be a flag check")?;
+    ensure(!OpCode::FloatGte.is_flag_check(), "Expected FloatGte to not be a flag check")?;
+    ensure(!OpCode::OrStart.is_flag_check(), "Expected OrStart to not be a flag check")?;
+    Ok(())
+}
+
 /// Tests opcode values.
 #[test]
 fn test_opcode_values() -> TestResult {
@@ -513,7 +533,7 @@ fn test_plan_builder_constant_pool_overflow() -> TestResult {
 
     let overflow = builder.add_int_constant(0);
     ensure(
-        matches!(overflow, Err(ret_logic::PlanError::ConstantPoolOverflow { .. })),
+        matches!(overflow, Err(PlanError::ConstantPoolOverflow { .. })),
         "Expected constant pool overflow error",
     )?;
     Ok(())
@@ -585,3 +605,454 @@ fn test_plan_clone() -> TestResult {
     )?;
     Ok(())
 }
+
+// ============================================================================
+// SECTION: Plan Serialization Tests
+// ============================================================================
+
+/// Builds a plan exercising every serialized section.
+fn sample_serialized_plan() -> Result<Plan, PlanError> {
+    let mut builder = PlanBuilder::new();
+    builder.add_float_constant(SAMPLE_FLOAT)?;
+    builder.add_string_constant("badge".to_string())?;
+    builder.add_flags_constant(0b1010)?;
+    Ok(builder
+        .require_column(ColumnKey::new(3))
+        .or_start()
+        .add_op(OpCode::FloatGte, 3, 0, 0)
+        .add_op(OpCode::HasAllFlags, 3, 2, 0)
+        .or_end()
+        .build())
+}
+
+/// Tests plan JSON round trip at the current format version.
+#[test]
+fn test_plan_json_round_trip_current_version() -> TestResult {
+    let plan = sample_serialized_plan()?;
+    let encoded = plan.to_json()?;
+    let value: serde_json::Value = serde_json::from_str(&encoded)?;
+    ensure(
+        value["format_version"] == serde_json::json!(PLAN_FORMAT_VERSION),
+        "Expected serialized plan to carry the current format version",
+    )?;
+
+    let decoded = Plan::from_json(&encoded)?;
+    ensure(
+        decoded.required_columns() == plan.required_columns(),
+        "Expected round trip to preserve required columns",
+    )?;
+    ensure(
+        decoded.operations().len() == plan.operations().len()
+            && decoded
+                .operations()
+                .iter()
+                .zip(plan.operations())
+                .all(|(lhs, rhs)| lhs.opcode == rhs.opcode && lhs.operand_a == rhs.operand_a),
+        "Expected round trip to preserve operations",
+    )?;
+    ensure(
+        decoded.constant(ConstantIndex(0)).and_then(Constant::as_float) == Some(SAMPLE_FLOAT),
+        "Expected round trip to preserve float constants",
+    )?;
+    ensure(
+        decoded.constant(ConstantIndex(1)).and_then(Constant::as_string) == Some("badge"),
+        "Expected round trip to preserve string constants",
+    )?;
+    ensure(
+        decoded.constant(ConstantIndex(2)).and_then(Constant::as_flags) == Some(0b1010),
+        "Expected round trip to preserve flag constants",
+    )?;
+    Ok(())
+}
+
+/// Tests plan decoding rejects a future format version.
+#[test]
+fn test_plan_json_rejects_future_version() -> TestResult {
+    let future = PLAN_FORMAT_VERSION + 1;
+    let encoded = serde_json::json!({
+        "format_version": future,
+        "required_columns": [],
+        "operations": [],
+        "constants": [],
+        "field_from_the_future": true,
+    })
+    .to_string();
+
+    ensure(
+        Plan::from_json(&encoded).err()
+            == Some(PlanError::IncompatibleFormatVersion {
+                found: future,
+                min_supported: MIN_COMPATIBLE_PLAN_FORMAT_VERSION,
+                max_supported: PLAN_FORMAT_VERSION,
+            }),
+        "Expected future format version to be rejected",
+    )?;
+    ensure(
+        serde_json::from_str::<Plan>(&encoded.replace(",\"field_from_the_future\":true", ""))
+            .is_err_and(|err| err.to_string().contains("incompatible plan format version")),
+        "Expected serde decoding to enforce the version gate",
+    )?;
+    Ok(())
+}
+
+/// Tests plan decoding rejects input without a version tag.
+#[test]
+fn test_plan_json_requires_version_tag() -> TestResult {
+    let encoded = r#"{"required_columns":[],"operations":[],"constants":[]}"#;
+    ensure(
+        matches!(Plan::from_json(encoded), Err(PlanError::Serialization(_))),
+        "Expected untagged plan to be rejected",
+    )?;
+    Ok(())
+}
+
+/// Tests older compatible versions pass the version gate.
+#[test]
+fn test_plan_format_version_gate_bounds() -> TestResult {
+    for version in MIN_COMPATIBLE_PLAN_FORMAT_VERSION ..= PLAN_FORMAT_VERSION {
+        ensure(Plan::check_format_version(version).is_ok(), "Expected supported versions to load")?;
+    }
+    ensure(Plan::check_format_version(0).is_err(), "Expected version zero to be rejected")?;
+    Ok(())
+}
+
+/// Column values read by round-trip evaluation handlers.
+struct SampleRows {
+    values: Vec<f32>,
+    flags: Vec<u64>,
+}
+
+fn handle_float_gte(
+    reader: &SampleRows,
+    row: Row,
+    op: Operation,
+    constants: &[Constant],
+) -> RequirementResult<bool> {
+    operations::float_gte(reader, row, &op, constants, |r, row, _col| r.values.get(row).copied())
+}
+
+fn handle_has_all_flags(
+    reader: &SampleRows,
+    row: Row,
+    op: Operation,
+    constants: &[Constant],
+) -> RequirementResult<bool> {
+    operations::has_all_flags(reader, row, &op, constants, |r, row, _col| r.flags.get(row).copied())
+}
+
+fn sample_executor(plan: Plan) -> PlanExecutor<SampleRows> {
+    ExecutorBuilder::new()
+        .register(OpCode::FloatGte, handle_float_gte)
+        .register(OpCode::HasAllFlags, handle_has_all_flags)
+        .build(plan)
+}
+
+/// Tests a reloaded plan evaluates and re-encodes identically.
+#[test]
+fn test_plan_json_round_trip_evaluates_identically() -> TestResult {
+    let plan = sample_serialized_plan()?;
+    let encoded = plan.to_json()?;
+    let decoded = Plan::from_json(&encoded)?;
+    ensure(decoded.to_json()? == encoded, "Expected re-encoding to be byte-identical")?;
+    ensure(
+        decoded.structural_hash() == plan.structural_hash(),
+        "Expected round trip to preserve the structural hash",
+    )?;
+
+    let rows = SampleRows {
+        values: vec![4.0, 1.0, 1.0, SAMPLE_FLOAT],
+        flags: vec![0, 0b1010, 0b0010, 0],
+    };
+    let original = sample_executor(plan);
+    let reloaded = sample_executor(decoded);
+    let expected = [true, true, false, true];
+    for (row, expected) in expected.into_iter().enumerate() {
+        ensure(
+            original.eval_row(&rows, row) == expected && reloaded.eval_row(&rows, row) == expected,
+            format!("Expected row {row} to evaluate to {expected} before and after reload"),
+        )?;
+    }
+    Ok(())
+}
+
+/// Tests plan decoding rejects out-of-range constant indices and unknown columns.
+#[test]
+fn test_plan_json_rejects_dangling_references() -> TestResult {
+    let encoded = sample_serialized_plan()?.to_json()?;
+    let mut value: serde_json::Value = serde_json::from_str(&encoded)?;
+    value["operations"][2]["operand_b"] = serde_json::json!(7);
+    let bad_constant = value.to_string();
+    ensure(
+        Plan::from_json(&bad_constant).err()
+            == Some(PlanError::ConstantIndexOutOfRange {
+                operation: 2,
+                index: 7,
+                constants: 3,
+            }),
+        "Expected out-of-range constant index to be rejected",
+    )?;
+    ensure(
+        serde_json::from_str::<Plan>(&bad_constant)
+            .is_err_and(|err| err.to_string().contains("references constant 7")),
+        "Expected serde decoding to validate constant indices",
+    )?;
+
+    let mut value: serde_json::Value = serde_json::from_str(&encoded)?;
+    value["operations"][1]["operand_a"] = serde_json::json!(9);
+    ensure(
+        Plan::from_json(&value.to_string()).err()
+            == Some(PlanError::UnknownColumn {
+                operation: 1,
+                column: 9,
+            }),
+        "Expected unknown column reference to be rejected",
+    )?;
+
+    let logical = PlanBuilder::new().add_op(OpCode::AndStart, 40, 50, 60).and_end().build();
+    ensure(
+        Plan::from_json(&logical.to_json()?).is_ok(),
+        "Expected logical operands to be left unchecked",
+    )?;
+    Ok(())
+}
+
+// ============================================================================
+// SECTION: Plan Optimization Tests
+// ============================================================================
+
+/// Builds a plan from opcodes, numbering leaf operations by position.
+fn plan_from_opcodes(opcodes: &[OpCode]) -> Plan {
+    let mut plan = Plan::new();
+    for (position, opcode) in (0u16 ..).zip(opcodes) {
+        let operand =
+            if opcode.is_logical_group() || *opcode == OpCode::Not { 0 } else { position };
+        plan.add_operation(Operation::new(*opcode, operand, 0, 0));
+    }
+    plan
+}
+
+/// Returns the opcode and first operand of each plan operation.
+fn plan_shape(plan: &Plan) -> Vec<(OpCode, u16)> {
+    plan.operations().iter().map(|op| (op.opcode, op.operand_a)).collect()
+}
+
+/// Tests single-child groups unwrap and same-operator groups flatten.
+#[test]
+fn test_plan_optimize_unwraps_and_flattens_groups() -> TestResult {
+    let mut plan = plan_from_opcodes(&[
+        OpCode::OrStart,
+        OpCode::OrStart,
+        OpCode::FloatGte,
+        OpCode::FloatGte,
+        OpCode::OrEnd,
+        OpCode::AndStart,
+        OpCode::FloatGte,
+        OpCode::AndEnd,
+        OpCode::OrEnd,
+    ]);
+    plan.optimize();
+    ensure(
+        plan_shape(&plan)
+            == [
+                (OpCode::OrStart, 0),
+                (OpCode::FloatGte, 2),
+                (OpCode::FloatGte, 3),
+                (OpCode::FloatGte, 6),
+                (OpCode::OrEnd, 0),
+            ],
+        format!("Unexpected optimized plan {:?}", plan_shape(&plan)),
+    )?;
+    Ok(())
+}
+
+/// Tests double negation collapses to the negated leaf.
+#[test]
+fn test_plan_optimize_collapses_double_negation() -> TestResult {
+    let mut plan = plan_from_opcodes(&[
+        OpCode::AndStart,
+        OpCode::AndStart,
+        OpCode::FloatGte,
+        OpCode::Not,
+        OpCode::AndEnd,
+        OpCode::Not,
+        OpCode::AndEnd,
+    ]);
+    plan.optimize();
+    ensure(
+        plan_shape(&plan) == [(OpCode::FloatGte, 2)],
+        format!("Unexpected optimized plan {:?}", plan_shape(&plan)),
+    )?;
+    Ok(())
+}
+
+/// Tests empty groups are dropped only when they equal the parent identity.
+#[test]
+fn test_plan_optimize_drops_identity_groups_only() -> TestResult {
+    let mut plan = plan_from_opcodes(&[
+        OpCode::AndStart,
+        OpCode::AndEnd,
+        OpCode::FloatGte,
+        OpCode::OrStart,
+        OpCode::OrEnd,
+    ]);
+    plan.optimize();
+    ensure(
+        plan_shape(&plan) == [(OpCode::FloatGte, 2), (OpCode::OrStart, 0), (OpCode::OrEnd, 0)],
+        format!("Unexpected optimized plan {:?}", plan_shape(&plan)),
+    )?;
+    Ok(())
+}
+
+/// Tests negated groups that do not start their parent are kept intact.
+#[test]
+fn test_plan_optimize_keeps_inner_negation_scope() -> TestResult {
+    let opcodes =
+        [OpCode::FloatGte, OpCode::AndStart, OpCode::FloatGte, OpCode::Not, OpCode::AndEnd];
+    let mut plan = plan_from_opcodes(&opcodes);
+    let expected = plan_shape(&plan);
+    plan.optimize();
+    ensure(plan_shape(&plan) == expected, "Expected negated group to be preserved")?;
+    Ok(())
+}
+
+/// Tests malformed and over-deep plans are left unchanged.
+#[test]
+fn test_plan_optimize_leaves_failing_plans_unchanged() -> TestResult {
+    let mut deep = vec![OpCode::AndStart; 64];
+    deep.push(OpCode::FloatGte);
+    deep.extend([OpCode::AndEnd; 64]);
+    let cases = [
+        vec![OpCode::AndStart, OpCode::FloatGte],
+        vec![OpCode::OrStart, OpCode::FloatGte, OpCode::AndEnd],
+        vec![OpCode::FloatGte, OpCode::OrEnd],
+        deep,
+    ];
+    for opcodes in cases {
+        let mut plan = plan_from_opcodes(&opcodes);
+        let hash = plan.structural_hash();
+        plan.optimize();
+        ensure(
+            plan.structural_hash() == hash,
+            format!("Expected plan of {} operations to be unchanged", opcodes.len()),
+        )?;
+    }
+    Ok(())
+}
+
+/// Tests an optimized plan evaluates identically to the original.
+#[test]
+fn test_plan_optimize_preserves_evaluation() -> TestResult {
+    let mut builder = PlanBuilder::new();
+    builder.add_float_constant(SAMPLE_FLOAT)?;
+    builder.add_flags_constant(0b1010)?;
+    let original = builder
+        .require_column(ColumnKey::new(3))
+        .and_start()
+        .or_start()
+        .or_start()
+        .add_op(OpCode::FloatGte, 3, 0, 0)
+        .or_end()
+        .and_start()
+        .add_op(OpCode::HasAllFlags, 3, 1, 0)
+        .add_op(OpCode::Not, 0, 0, 0)
+        .and_end()
+        .or_end()
+        .and_end()
+        .build();
+    let mut optimized = original.clone();
+    optimized.optimize();
+    ensure(
+        optimized.operations().len() < original.operations().len(),
+        "Expected redundant groups to be removed",
+    )?;
+    let rows = SampleRows {
+        values: vec![4.0, 1.0, 1.0, SAMPLE_FLOAT],
+        flags: vec![0, 0b1010, 0b0010, 0],
+    };
+    let original = sample_executor(original);
+    let optimized = sample_executor(optimized);
+    for row in 0 .. rows.values.len() {
+        ensure(
+            original.eval_row(&rows, row) == optimized.eval_row(&rows, row),
+            format!("Expected row {row} to evaluate identically after optimization"),
+        )?;
+    }
+    Ok(())
+}
+
+/// Cost estimate used by reorder tests: integer equality is expensive.
+fn opcode_cost(op: Operation) -> u32 {
+    if op.opcode == OpCode::IntEq { 50 } else { 1 }
+}
+
+/// Tests reorder by cost moves an expensive leaf after cheaper members.
+#[test]
+fn test_plan_reorder_by_cost_moves_expensive_leaf_last() -> TestResult {
+    let mut plan = plan_from_opcodes(&[
+        OpCode::OrStart,
+        OpCode::IntEq,
+        OpCode::AndStart,
+        OpCode::FloatGte,
+        OpCode::IntEq,
+        OpCode::FloatGte,
+        OpCode::AndEnd,
+        OpCode::FloatGte,
+        OpCode::OrEnd,
+    ]);
+    plan.reorder_by_cost(opcode_cost);
+    ensure(
+        plan_shape(&plan)
+            == [
+                (OpCode::OrStart, 0),
+                (OpCode::FloatGte, 7),
+                (OpCode::IntEq, 1),
+                (OpCode::AndStart, 0),
+                (OpCode::FloatGte, 3),
+                (OpCode::FloatGte, 5),
+                (OpCode::IntEq, 4),
+                (OpCode::AndEnd, 0),
+                (OpCode::OrEnd, 0),
+            ],
+        format!("Unexpected reordered plan {:?}", plan_shape(&plan)),
+    )?;
+    Ok(())
+}
+
+/// Tests reorder by cost keeps the order of groups containing a negation.
+#[test]
+fn test_plan_reorder_by_cost_keeps_negated_group_order() -> TestResult {
+    let opcodes = [
+        OpCode::AndStart,
+        OpCode::IntEq,
+        OpCode::Not,
+        OpCode::OrStart,
+        OpCode::IntEq,
+        OpCode::FloatGte,
+        OpCode::OrEnd,
+        OpCode::AndEnd,
+    ];
+    let mut plan = plan_from_opcodes(&opcodes);
+    plan.reorder_by_cost(opcode_cost);
+    ensure(
+        plan_shape(&plan)
+            == [
+                (OpCode::AndStart, 0),
+                (OpCode::IntEq, 1),
+                (OpCode::Not, 0),
+                (OpCode::OrStart, 0),
+                (OpCode::FloatGte, 5),
+                (OpCode::IntEq, 4),
+                (OpCode::OrEnd, 0),
+                (OpCode::AndEnd, 0),
+            ],
+        format!("Unexpected reordered plan {:?}", plan_shape(&plan)),
+    )?;
+
+    let mut malformed = plan_from_opcodes(&opcodes[.. 7]);
+    malformed.reorder_by_cost(opcode_cost);
+    ensure(
+        plan_shape(&malformed) == plan_shape(&plan_from_opcodes(&opcodes[.. 7])),
+        "Malformed plans are left untouched",
+    )?;
+    Ok(())
+}
@@ -0,0 +1,273 @@
+// crates/ret-logic/tests/predicates.rs
+// ============================================================================
+// Module: Predicate Combinator Tests
+// Description: Tests for in_range, greater_than, one_of, and matches_regex.
+// Purpose: Validate combinator results and missing-value tri-state handling.
+// Dependencies: ret_logic::predicates
+// ============================================================================
+//! ## Overview
+//! Integration tests for the column predicate combinators, including the
+//! shared rule that missing or mistyped values evaluate to `Unknown`.
+
+#![allow(
+    clippy::panic,
+    clippy::print_stdout,
+    clippy::print_stderr,
+    clippy::unwrap_used,
+    clippy::expect_used,
+    clippy::use_debug,
+    clippy::dbg_macro,
+    clippy::panic_in_result_fn,
+    clippy::unwrap_in_result,
+    reason = "Test-only output and panic-based assertions are permitted."
+)]
+
+mod support;
+
+use std::collections::BTreeMap;
+
+use ret_logic::Column;
+use ret_logic::ColumnKey;
+use ret_logic::ColumnPredicate;
+use ret_logic::ColumnSource;
+use ret_logic::ColumnarReader;
+use ret_logic::ConditionEval;
+use ret_logic::KleeneLogic;
+use ret_logic::Requirement;
+use ret_logic::Row;
+use ret_logic::Scalar;
+use ret_logic::ScalarRef;
+use ret_logic::TriState;
+use ret_logic::TriStateConditionEval;
+use ret_logic::predicates::greater_than;
+use ret_logic::predicates::in_range;
+use ret_logic::predicates::matches_regex;
+use ret_logic::predicates::one_of;
+use support::TestResult;
+use support::ensure;
+
+// ============================================================================
+// SECTION: Helpers
+// ============================================================================
+
+/// Numeric column used by the tests.
+const SCORE: ColumnKey = ColumnKey(0);
+
+/// Text column used by the tests.
+const REGION: ColumnKey = ColumnKey(1);
+
+/// Row-oriented reader where any cell may be absent.
+#[derive(Default)]
+struct SparseRows {
+    /// Present cells keyed by row and column.
+    cells: BTreeMap<(Row, ColumnKey), Scalar>,
+}
+
+impl SparseRows {
+    /// Stores a value and returns the reader.
+    fn with(mut self, row: Row, column: ColumnKey, value: impl Into<Scalar>) -> Self {
+        self.cells.insert((row, column), value.into());
+        self
+    }
+}
+
+impl ColumnSource for SparseRows {
+    fn scalar(&self, row: Row, column: ColumnKey) -> Option<ScalarRef<'_>> {
+        self.cells.get(&(row, column)).map(Scalar::as_scalar_ref)
+    }
+}
+
+/// Evaluates `predicate` on each row of `reader`.
+fn eval_rows(
+    predicate: &ColumnPredicate<SparseRows>,
+    reader: &SparseRows,
+    rows: usize,
+) -> Vec<TriState> {
+    (0 .. rows).map(|row| predicate.eval_row_tristate(reader, row)).collect()
+}
+
+/// Reader with row 0 = 10, row 1 = "eu", row 2 = NaN, and row 3 absent.
+fn mixed_scores() -> SparseRows {
+    SparseRows::default().with(0, SCORE, 10.0).with(1, SCORE, "eu").with(2, SCORE, f64::NAN)
+}
+
+// ============================================================================
+// SECTION: Range Tests
+// ============================================================================
+
+#[test]
+fn in_range_includes_both_bounds() -> TestResult {
+    let reader = SparseRows::default()
+        .with(0, SCORE, 4.9)
+        .with(1, SCORE, 5)
+        .with(2, SCORE, 7.5)
+        .with(3, SCORE, 10)
+        .with(4, SCORE, 10.1);
+    let results = eval_rows(&in_range(SCORE, 5.0, 10.0), &reader, 5);
+    ensure(
+        results
+            == [TriState::False, TriState::True, TriState::True, TriState::True, TriState::False],
+        format!("Unexpected range results {results:?}"),
+    )
+}
+
+#[test]
+fn in_range_missing_value_is_unknown() -> TestResult {
+    let results = eval_rows(&in_range(SCORE, 0.0, 100.0), &mixed_scores(), 4);
+    ensure(
+        results == [TriState::True, TriState::Unknown, TriState::Unknown, TriState::Unknown],
+        format!("Text, NaN, and missing values should be unknown: {results:?}"),
+    )?;
+    let empty = in_range(SCORE, 20.0, 10.0).eval_row_tristate(&mixed_scores(), 0);
+    ensure(empty == TriState::False, "An empty range never passes")
+}
+
+// ============================================================================
+// SECTION: Threshold Tests
+// ============================================================================
+
+#[test]
+fn greater_than_is_strict() -> TestResult {
+    let reader = SparseRows::default().with(0, SCORE, 17).with(1, SCORE, 17.5);
+    let results = eval_rows(&greater_than(SCORE, 17.0), &reader, 2);
+    ensure(results == [TriState::False, TriState::True], format!("Unexpected {results:?}"))
+}
+
+#[test]
+fn greater_than_missing_value_is_unknown() -> TestResult {
+    let results = eval_rows(&greater_than(SCORE, 5.0), &mixed_scores(), 4);
+    ensure(
+        results == [TriState::True, TriState::Unknown, TriState::Unknown, TriState::Unknown],
+        format!("Text, NaN, and missing values should be unknown: {results:?}"),
+    )
+}
+
+// ============================================================================
+// SECTION: Membership Tests
+// ============================================================================
+
+#[test]
+fn one_of_matches_text_and_numbers() -> TestResult {
+    let reader = SparseRows::default()
+        .with(0, REGION, "eu")
+        .with(1, REGION, "us")
+        .with(2, REGION, 3)
+        .with(3, REGION, "3");
+    let regions = one_of(REGION, ["eu", "uk"]);
+    let mixed = one_of(REGION, [Scalar::from("us"), Scalar::from(7)]);
+    let results = eval_rows(&mixed, &reader, 4);
+    ensure(
+        results == [TriState::False, TriState::True, TriState::False, TriState::False],
+        format!("Mixed candidates should cover both kinds: {results:?}"),
+    )?;
+    let results = eval_rows(&regions, &reader, 4);
+    ensure(
+        results == [TriState::True, TriState::False, TriState::Unknown, TriState::False],
+        format!("Unexpected text membership {results:?}"),
+    )?;
+
+    let numbers = one_of(REGION, [1, 2, 3]);
+    let results = eval_rows(&numbers, &reader, 4);
+    ensure(
+        results == [TriState::Unknown, TriState::Unknown, TriState::True, TriState::Unknown],
+        format!("Numbers should only match numbers: {results:?}"),
+    )
+}
+
+#[test]
+fn one_of_missing_value_is_unknown() -> TestResult {
+    let results = eval_rows(&one_of(SCORE, [10.0]), &mixed_scores(), 4);
+    ensure(
+        results == [TriState::True, TriState::Unknown, TriState::Unknown, TriState::Unknown],
+        format!("Text, NaN, and missing values should be unknown: {results:?}"),
+    )
+}
+
+// ============================================================================
+// SECTION: Pattern Tests
+// ============================================================================
+
+#[test]
+fn matches_regex_tests_text() -> TestResult {
+    let reader = SparseRows::default()
+        .with(0, REGION, "eu-west-1")
+        .with(1, REGION, "us-east-1")
+        .with(2, REGION, "prod-eu-west");
+    let anchored = matches_regex(REGION, "^eu-")?;
+    let results = eval_rows(&anchored, &reader, 3);
+    ensure(
+        results == [TriState::True, TriState::False, TriState::False],
+        format!("Unexpected anchored matches {results:?}"),
+    )?;
+    let unanchored = matches_regex(REGION, "eu-west")?;
+    let results = eval_rows(&unanchored, &reader, 3);
+    ensure(
+        results == [TriState::True, TriState::False, TriState::True],
+        format!("Unexpected unanchored matches {results:?}"),
+    )
+}
+
+#[test]
+fn matches_regex_missing_value_is_unknown() -> TestResult {
+    let results = eval_rows(&matches_regex(SCORE, ".*")?, &mixed_scores(), 4);
+    ensure(
+        results == [TriState::Unknown, TriState::True, TriState::Unknown, TriState::Unknown],
+        format!("Numeric and missing values should be unknown: {results:?}"),
+    )
+}
+
+#[test]
+fn matches_regex_rejects_invalid_pattern() -> TestResult {
+    ensure(matches_regex::<SparseRows>(REGION, "(unclosed").is_err(), "Invalid pattern accepted")
+}
+
+// ============================================================================
+// SECTION: Integration Tests
+// ============================================================================
+
+#[test]
+fn boolean_evaluation_fails_closed_on_unknown() -> TestResult {
+    let predicate = greater_than(SCORE, 5.0);
+    let reader = mixed_scores();
+    ensure(predicate.eval_row(&reader, 0), "Known passing value should pass")?;
+    ensure(!predicate.eval_row(&reader, 3), "Missing value should fail closed")
+}
+
+#[test]
+fn combinators_compose_in_requirements() -> TestResult {
+    let policy = Requirement::and(vec![
+        Requirement::condition(greater_than(SCORE, 17.0)),
+        Requirement::condition(one_of(REGION, ["eu", "uk"])),
+    ]);
+    let reader = SparseRows::default()
+        .with(0, SCORE, 30)
+        .with(0, REGION, "uk")
+        .with(1, REGION, "uk")
+        .with(2, SCORE, 30)
+        .with(2, REGION, "us");
+    let results: Vec<TriState> =
+        (0 .. 3).map(|row| policy.eval_tristate(&reader, row, &KleeneLogic)).collect();
+    ensure(
+        results == [TriState::True, TriState::Unknown, TriState::False],
+        format!("Unexpected policy results {results:?}"),
+    )?;
+    let rendered = greater_than::<SparseRows>(SCORE, 17.0).to_string();
+    ensure(rendered == "greater_than(col0, 17)", format!("Unexpected rendering {rendered}"))?;
+    let rendered =
+        one_of::<SparseRows, _>(REGION, [Scalar::from("eu"), Scalar::from(2)]).to_string();
+    ensure(rendered == r#"one_of(col1, ["eu", 2])"#, format!("Unexpected rendering {rendered}"))
+}
+
+#[test]
+fn columnar_reader_exposes_numeric_columns() -> TestResult {
+    let reader = ColumnarReader::new(2)
+        .with_column(SCORE, Column::Int(vec![3, 12]))?
+        .with_column(REGION, Column::Flags(vec![1, 2]))?;
+    let adults = greater_than(SCORE, 10.0);
+    ensure(adults.eval_row_tristate(&reader, 0) == TriState::False, "Int widens to number")?;
+    ensure(adults.eval_row_tristate(&reader, 1) == TriState::True, "Int widens to number")?;
+    ensure(
+        one_of(REGION, [1]).eval_row_tristate(&reader, 0) == TriState::Unknown,
+        "Flag columns are not scalar values",
+    )
+}
@@ -34,6 +34,8 @@ use flags::FLAG_C;
 use mocks::MockCondition;
 use mocks::MockReader;
 use ret_logic::BoolAsTri;
+use ret_logic::ConditionEval;
+use ret_logic::DEFAULT_CONDITION_COST;
 use ret_logic::KleeneLogic;
 use ret_logic::Requirement;
 use ret_logic::RequirementGroup;
@@ -1395,6 +1397,159 @@ fn test_requirement_group_any() -> TestResult {
     Ok(())
 }
 
+// ============================================================================
+// SECTION: Canonicalization Tests
+// ============================================================================
+
+/// Returns the std hash of a value for canonical-form comparisons.
+fn std_hash<T: std::hash::Hash>(value: &T) -> u64 {
+    use std::hash::Hasher;
+    let mut hasher = std::collections::hash_map::DefaultHasher::new();
+    value.hash(&mut hasher);
+    hasher.finish()
+}
+
+/// Tests reordered and duplicated and/or members canonicalize identically.
+#[test]
+fn test_canonicalize_and_or_ignores_order_and_duplicates() -> TestResult {
+    let lhs: Requirement<MockCondition> = Requirement::and(vec![
+        Requirement::condition(MockCondition::ValueGte(10)),
+        Requirement::or(vec![
+            Requirement::condition(MockCondition::HasAllFlags(FLAG_A)),
+            Requirement::condition(MockCondition::AlwaysTrue),
+        ]),
+        Requirement::condition(MockCondition::ValueGte(10)),
+    ]);
+    let rhs: Requirement<MockCondition> = Requirement::and(vec![
+        Requirement::or(vec![
+            Requirement::condition(MockCondition::AlwaysTrue),
+            Requirement::condition(MockCondition::HasAllFlags(FLAG_A)),
+            Requirement::condition(MockCondition::AlwaysTrue),
+        ]),
+        Requirement::condition(MockCondition::ValueGte(10)),
+    ]);
+    check!(lhs != rhs);
+
+    let lhs = lhs.canonicalize();
+    let rhs = rhs.canonicalize();
+    check_eq!(lhs, rhs);
+    check_eq!(std_hash(&lhs), std_hash(&rhs));
+    check_eq!(lhs.complexity(), 5);
+    Ok(())
+}
+
+/// Tests canonicalization preserves evaluation results.
+#[test]
+fn test_canonicalize_preserves_semantics() -> TestResult {
+    let req: Requirement<MockCondition> = Requirement::or(vec![
+        Requirement::negate(Requirement::condition(MockCondition::ValueGte(50))),
+        Requirement::and(vec![
+            Requirement::condition(MockCondition::HasAllFlags(FLAG_B)),
+            Requirement::condition(MockCondition::HasAllFlags(FLAG_B)),
+        ]),
+    ]);
+    let canonical = req.clone().canonicalize();
+    let values = vec![10, 60, 60];
+    let flags = vec![0, FLAG_B, 0];
+    let reader = MockReader::new(&values, &flags);
+    for row in 0 .. values.len() {
+        check_eq!(req.eval(&reader, row), canonical.eval(&reader, row));
+    }
+    Ok(())
+}
+
+/// Tests `require_group` member order and duplicates are preserved.
+#[test]
+fn test_canonicalize_require_group_preserves_order() -> TestResult {
+    let req: Requirement<MockCondition> = Requirement::require_group(
+        2,
+        vec![
+            Requirement::condition(MockCondition::ValueGte(10)),
+            Requirement::and(vec![
+                Requirement::condition(MockCondition::AlwaysTrue),
+                Requirement::condition(MockCondition::AlwaysFalse),
+            ]),
+            Requirement::condition(MockCondition::ValueGte(10)),
+        ],
+    );
+    let reordered: Requirement<MockCondition> = Requirement::require_group(
+        2,
+        vec![
+            Requirement::condition(MockCondition::ValueGte(10)),
+            Requirement::condition(MockCondition::ValueGte(10)),
+            Requirement::and(vec![
+                Requirement::condition(MockCondition::AlwaysFalse),
+                Requirement::condition(MockCondition::AlwaysTrue),
+            ]),
+        ],
+    );
+
+    let canonical = req.canonicalize();
+    let Requirement::RequireGroup {
+        min,
+        reqs,
+    } = &canonical
+    else {
+        return Err("Expected canonical require_group".into());
+    };
+    check_eq!(*min, 2);
+    check_eq!(reqs.len(), 3);
+    check_eq!(*reqs[0], Requirement::condition(MockCondition::ValueGte(10)));
+    check_eq!(*reqs[2], Requirement::condition(MockCondition::ValueGte(10)));
+    check!(canonical != reordered.canonicalize());
+    Ok(())
+}
+
+/// Tests equivalent all/any groups canonicalize identically.
+#[test]
+fn test_requirement_group_canonicalize_all_and_any() -> TestResult {
+    let lhs = RequirementGroup::all(vec![
+        Requirement::condition(MockCondition::AlwaysTrue),
+        Requirement::condition(MockCondition::ValueGte(10)),
+        Requirement::condition(MockCondition::AlwaysTrue),
+    ])
+    .canonicalize();
+    let rhs = RequirementGroup::all(vec![
+        Requirement::condition(MockCondition::ValueGte(10)),
+        Requirement::condition(MockCondition::AlwaysTrue),
+    ])
+    .canonicalize();
+    check_eq!(lhs, rhs);
+    check_eq!(lhs.min_required, 2);
+
+    let lhs = RequirementGroup::any(vec![
+        Requirement::condition(MockCondition::HasAllFlags(FLAG_C)),
+        Requirement::condition(MockCondition::AlwaysFalse),
+    ])?
+    .canonicalize();
+    let rhs = RequirementGroup::any(vec![
+        Requirement::condition(MockCondition::AlwaysFalse),
+        Requirement::condition(MockCondition::HasAllFlags(FLAG_C)),
+        Requirement::condition(MockCondition::AlwaysFalse),
+    ])?
+    .canonicalize();
+    check_eq!(lhs, rhs);
+    check_eq!(lhs.min_required, 1);
+    Ok(())
+}
+
+/// Tests quorum groups keep member order and duplicates.
+#[test]
+fn test_requirement_group_canonicalize_preserves_quorum_order() -> TestResult {
+    let members = vec![
+        Requirement::condition(MockCondition::ValueGte(10)),
+        Requirement::condition(MockCondition::AlwaysTrue),
+        Requirement::condition(MockCondition::ValueGte(10)),
+    ];
+    let group = RequirementGroup::new(members.clone(), 2)?.canonicalize();
+    check_eq!(group.min_required, 2);
+    check_eq!(group.requirements.len(), 3);
+    for (member, expected) in group.requirements.iter().zip(&members) {
+        check_eq!(**member, *expected);
+    }
+    Ok(())
+}
+
 // ============================================================================
 // SECTION: Edge Case Tests
 // ============================================================================
@@ -1585,3 +1740,183 @@ fn test_eval_block_respects_start_and_count_window() -> TestResult {
     check_eq!(mask, expected);
     Ok(())
 }
+
+// ============================================================================
+// SECTION: Cost Ordering Tests
+// ============================================================================
+
+/// Mock condition with an explicit evaluation cost.
+#[derive(Debug, Clone, PartialEq)]
+struct CostedCondition(MockCondition, u32);
+
+impl ConditionEval for CostedCondition {
+    type Reader<'a> = MockReader<'a>;
+
+    fn eval_row(&self, reader: &Self::Reader<'_>, row: usize) -> bool {
+        self.0.eval_row(reader, row)
+    }
+
+    fn cost(&self) -> u32 {
+        self.1
+    }
+}
+
+/// Builds a leaf with the given condition and cost.
+const fn costed(condition: MockCondition, cost: u32) -> Requirement<CostedCondition> {
+    Requirement::condition(CostedCondition(condition, cost))
+}
+
+/// Returns the costs of a node's direct members in evaluation order.
+fn member_costs(requirement: &Requirement<CostedCondition>) -> Vec<u32> {
+    match requirement {
+        Requirement::And(reqs) | Requirement::Or(reqs) => reqs.iter().map(|r| r.cost()).collect(),
+        Requirement::RequireGroup {
+            reqs, ..
+        }
+        | Requirement::RequireExactly {
+            reqs, ..
+        }
+        | Requirement::RequireAtMost {
+            reqs, ..
+        } => reqs.iter().map(|r| r.cost()).collect(),
+        Requirement::Implies {
+            antecedent,
+            consequent,
+        } => vec![antecedent.cost(), consequent.cost()],
+        Requirement::Not(req) => vec![req.cost()],
+        Requirement::Condition(condition) => vec![condition.cost()],
+    }
+}
+
+/// Tests default condition cost.
+#[test]
+fn test_condition_default_cost() -> TestResult {
+    check_eq!(MockCondition::AlwaysTrue.cost(), DEFAULT_CONDITION_COST);
+    let tree = Requirement::and(vec![
+        Requirement::condition(MockCondition::AlwaysTrue),
+        Requirement::negate(Requirement::condition(MockCondition::RowIndexEven)),
+    ]);
+    check_eq!(tree.cost(), 2 * DEFAULT_CONDITION_COST);
+    Ok(())
+}
+
+/// Tests reorder by cost moves expensive condition last.
+#[test]
+fn test_reorder_by_cost_moves_expensive_condition_last() -> TestResult {
+    let mut req = Requirement::and(vec![
+        costed(MockCondition::ValueGte(10), 100),
+        costed(MockCondition::HasAnyFlags(FLAG_A), 1),
+        Requirement::or(vec![
+            costed(MockCondition::RowIndexEven, 5),
+            costed(MockCondition::AlwaysFalse, 2),
+        ]),
+        costed(MockCondition::RowIndexLt(6), 1),
+    ]);
+    req.reorder_by_cost();
+
+    check_eq!(member_costs(&req), vec![1, 1, 7, 100]);
+    let Requirement::And(reqs) = &req else {
+        return Err("reordered root should stay an And".into());
+    };
+    check_eq!(*reqs[0], costed(MockCondition::HasAnyFlags(FLAG_A), 1), "ties keep order");
+    check_eq!(member_costs(&reqs[2]), vec![2, 5], "nested groups are reordered");
+    Ok(())
+}
+
+/// Tests reorder by cost keeps at-least group order and thresholds.
+#[test]
+fn test_reorder_by_cost_keeps_group_order() -> TestResult {
+    let original = Requirement::require_group(
+        2,
+        vec![
+            costed(MockCondition::ValueGte(4), 50),
+            costed(MockCondition::RowIndexEven, 1),
+            Requirement::negate(costed(MockCondition::HasAllFlags(FLAG_AB), 20)),
+            Requirement::or(vec![
+                costed(MockCondition::HasAnyFlags(FLAG_C), 3),
+                costed(MockCondition::AlwaysFalse, 2),
+            ]),
+        ],
+    );
+    let mut reordered = original.clone();
+    reordered.reorder_by_cost();
+    check_eq!(member_costs(&reordered), vec![50, 1, 20, 5], "disclosure order is kept");
+    let Requirement::RequireGroup {
+        min,
+        reqs,
+    } = &reordered
+    else {
+        return Err("reordered root should stay RequireGroup".into());
+    };
+    check_eq!(*min, 2);
+    check_eq!(member_costs(&reqs[3]), vec![2, 3], "members are reordered internally");
+
+    let values: Vec<i32> = (0 .. 16).collect();
+    let flags: Vec<u64> = (0 .. 16).map(|row| [0, FLAG_A, FLAG_AB, FLAG_C][row % 4]).collect();
+    let reader = MockReader::new(&values, &flags);
+    for row in 0 .. values.len() {
+        check_eq!(reordered.eval(&reader, row), original.eval(&reader, row), "row {row}");
+    }
+    Ok(())
+}
+
+/// Tests reorder by cost keeps exact group order.
+#[test]
+fn test_reorder_by_cost_keeps_exact_group_order() -> TestResult {
+    let mut req = Requirement::require_exactly(
+        1,
+        vec![
+            costed(MockCondition::AlwaysTrue, 9),
+            Requirement::and(vec![
+                costed(MockCondition::RowIndexEven, 4),
+                costed(MockCondition::AlwaysTrue, 1),
+            ]),
+        ],
+    );
+    req.reorder_by_cost();
+
+    check_eq!(member_costs(&req), vec![9, 5]);
+    let Requirement::RequireExactly {
+        reqs, ..
+    } = &req
+    else {
+        return Err("reordered root should stay RequireExactly".into());
+    };
+    check_eq!(member_costs(&reqs[1]), vec![1, 4], "members are reordered internally");
+    Ok(())
+}
+
+/// Tests reorder by cost keeps the implication antecedent first.
+#[test]
+fn test_reorder_by_cost_keeps_implication_order() -> TestResult {
+    let mut req = Requirement::implies(
+        costed(MockCondition::AlwaysTrue, 9),
+        Requirement::or(vec![
+            costed(MockCondition::RowIndexEven, 4),
+            costed(MockCondition::AlwaysTrue, 1),
+        ]),
+    );
+    req.reorder_by_cost();
+
+    check_eq!(member_costs(&req), vec![9, 5]);
+    let Requirement::Implies {
+        consequent, ..
+    } = &req
+    else {
+        return Err("reordered root should stay Implies".into());
+    };
+    check_eq!(member_costs(consequent), vec![1, 4], "members are reordered internally");
+    Ok(())
+}
+
+/// Tests reorder by cost leaves over-deep trees untouched.
+#[test]
+fn test_reorder_by_cost_skips_over_deep_trees() -> TestResult {
+    let deep = build_deep_not_requirement(costed(MockCondition::AlwaysTrue, 1), MAX_EVAL_DEPTH + 1);
+    let original = Requirement::or(vec![costed(MockCondition::AlwaysTrue, 10), deep]);
+    let mut reordered = original.clone();
+    reordered.reorder_by_cost();
+
+    check_eq!(reordered, original);
+    Ok(())
+}
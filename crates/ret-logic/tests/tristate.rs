@@ -27,6 +27,7 @@ use ret_logic::BochvarLogic;
 use ret_logic::GroupCounts;
 use ret_logic::KleeneLogic;
 use ret_logic::LogicMode;
+use ret_logic::LukasiewiczLogic;
 use ret_logic::Requirement;
 use ret_logic::RequirementTrace;
 use ret_logic::TriLogic;
@@ -209,6 +210,206 @@ fn test_require_group_failure() -> TestResult {
     Ok(())
 }
 
+// ============================================================================
+// SECTION: RequireExactly Semantics
+// ============================================================================
+
+/// Builds `require_exactly(n, [A, B, C])`
+fn exactly_abc(n: u8) -> Requirement<TestCondition> {
+    Requirement::require_exactly(
+        n,
+        vec![
+            Requirement::condition(TestCondition::A),
+            Requirement::condition(TestCondition::B),
+            Requirement::condition(TestCondition::C),
+        ],
+    )
+}
+
+/// Tests require exactly definite outcomes in both logic modes.
+#[test]
+fn test_require_exactly_definite_outcomes() -> TestResult {
+    let cases = [
+        (
+            [TriState::True, TriState::True, TriState::False],
+            TriState::True,
+            "exactly two satisfied",
+        ),
+        ([TriState::True, TriState::False, TriState::False], TriState::False, "one short"),
+        ([TriState::True, TriState::True, TriState::True], TriState::False, "one too many"),
+        ([TriState::False, TriState::False, TriState::False], TriState::False, "none satisfied"),
+    ];
+
+    for (row, expected, label) in cases {
+        let reader = TestReader::new(vec![row]);
+        for mode in [LogicMode::Kleene, LogicMode::Bochvar] {
+            let result = exactly_abc(2).eval_tristate(&reader, 0, &mode);
+            ensure(
+                result == expected,
+                format!("{label} ({mode:?}): expected {expected:?}, got {result:?}"),
+            )?;
+        }
+    }
+    Ok(())
+}
+
+/// Tests require exactly propagates Unknown only while the outcome is undecided.
+#[test]
+fn test_require_exactly_unknown_outcomes() -> TestResult {
+    let cases = [
+        // Unknowns could land the count on either side of n.
+        ([TriState::True, TriState::Unknown, TriState::False], TriState::Unknown),
+        ([TriState::Unknown, TriState::Unknown, TriState::False], TriState::Unknown),
+        // At n: one more satisfied member would overshoot.
+        ([TriState::True, TriState::True, TriState::Unknown], TriState::Unknown),
+        // Even if every unknown is satisfied, n is out of reach.
+        ([TriState::Unknown, TriState::False, TriState::False], TriState::False),
+    ];
+
+    for (row, expected) in cases {
+        let reader = TestReader::new(vec![row]);
+        let result = exactly_abc(2).eval_tristate(&reader, 0, &KleeneLogic);
+        ensure(result == expected, format!("{row:?}: expected {expected:?}, got {result:?}"))?;
+    }
+
+    let overshoot = TestReader::new(vec![[TriState::True, TriState::True, TriState::Unknown]]);
+    ensure(
+        exactly_abc(1).eval_tristate(&overshoot, 0, &KleeneLogic) == TriState::False,
+        "Expected satisfied count past n to be False despite unknowns",
+    )?;
+    Ok(())
+}
+
+/// Tests require exactly group count semantics.
+#[test]
+fn require_exactly_group_counts() -> TestResult {
+    let logic = KleeneLogic;
+    let cases = [
+        (2, 2, 0, TriState::True),
+        (2, 3, 0, TriState::False),
+        (2, 3, 1, TriState::False),
+        (2, 1, 0, TriState::False),
+        (2, 0, 1, TriState::False),
+        (2, 2, 1, TriState::Unknown),
+        (2, 1, 1, TriState::Unknown),
+        (0, 0, 0, TriState::True),
+        (0, 0, 2, TriState::Unknown),
+    ];
+
+    for (n, satisfied, unknown, expected) in cases {
+        let counts = GroupCounts {
+            satisfied,
+            unknown,
+            total: 4,
+        };
+        ensure(
+            logic.require_exactly(n, counts) == expected,
+            format!("n={n} with {counts:?} should be {expected:?}"),
+        )?;
+    }
+    Ok(())
+}
+
+// ============================================================================
+// SECTION: RequireAtMost Semantics
+// ============================================================================
+
+/// Builds `require_at_most(max, [A, B, C])`
+fn at_most_abc(max: u8) -> Requirement<TestCondition> {
+    Requirement::require_at_most(
+        max,
+        vec![
+            Requirement::condition(TestCondition::A),
+            Requirement::condition(TestCondition::B),
+            Requirement::condition(TestCondition::C),
+        ],
+    )
+}
+
+/// Tests require at most outcomes across Kleene and Bochvar logic.
+#[test]
+fn test_require_at_most_outcomes_across_logics() -> TestResult {
+    let cases = [
+        ([TriState::False, TriState::False, TriState::False], TriState::True, "none satisfied"),
+        ([TriState::True, TriState::False, TriState::False], TriState::True, "at the limit"),
+        ([TriState::True, TriState::True, TriState::False], TriState::False, "over the limit"),
+        ([TriState::True, TriState::True, TriState::Unknown], TriState::False, "already over"),
+        ([TriState::True, TriState::Unknown, TriState::False], TriState::Unknown, "could tip over"),
+        ([TriState::Unknown, TriState::False, TriState::False], TriState::True, "cannot tip over"),
+    ];
+
+    for (row, expected, label) in cases {
+        let reader = TestReader::new(vec![row]);
+        let requirement = at_most_abc(1);
+        let kleene = requirement.eval_tristate(&reader, 0, &KleeneLogic);
+        let bochvar = requirement.eval_tristate(&reader, 0, &BochvarLogic);
+        ensure(
+            kleene == expected,
+            format!("{label} (Kleene): expected {expected:?}, got {kleene:?}"),
+        )?;
+        ensure(
+            bochvar == expected,
+            format!("{label} (Bochvar): expected {expected:?}, got {bochvar:?}"),
+        )?;
+    }
+    Ok(())
+}
+
+/// Tests require at most group count semantics.
+#[test]
+fn require_at_most_group_counts() -> TestResult {
+    let cases = [
+        (1, 0, 0, TriState::True),
+        (1, 1, 0, TriState::True),
+        (1, 2, 0, TriState::False),
+        (1, 2, 2, TriState::False),
+        (1, 0, 1, TriState::True),
+        (1, 0, 2, TriState::Unknown),
+        (1, 1, 1, TriState::Unknown),
+        (0, 0, 0, TriState::True),
+        (0, 0, 1, TriState::Unknown),
+        (4, 2, 2, TriState::True),
+    ];
+
+    for logic in [LogicMode::Kleene, LogicMode::Bochvar] {
+        for (max, satisfied, unknown, expected) in cases {
+            let counts = GroupCounts {
+                satisfied,
+                unknown,
+                total: 4,
+            };
+            ensure(
+                logic.require_at_most(max, counts) == expected,
+                format!("max={max} with {counts:?} ({logic:?}) should be {expected:?}"),
+            )?;
+        }
+    }
+    Ok(())
+}
+
+/// Tests require at most under negation and nesting with Bochvar infection.
+#[test]
+fn test_require_at_most_nested_unknowns() -> TestResult {
+    let reader = TestReader::new(vec![[TriState::True, TriState::Unknown, TriState::False]]);
+    let requirement =
+        Requirement::or(vec![at_most_abc(1), Requirement::condition(TestCondition::A)]);
+
+    ensure(
+        requirement.eval_tristate(&reader, 0, &KleeneLogic) == TriState::True,
+        "Expected Kleene OR to resolve past an undecided at_most",
+    )?;
+    ensure(
+        requirement.eval_tristate(&reader, 0, &BochvarLogic) == TriState::Unknown,
+        "Expected Bochvar OR to stay Unknown with an undecided at_most",
+    )?;
+    ensure(
+        Requirement::negate(at_most_abc(1)).eval_tristate(&reader, 0, &KleeneLogic)
+            == TriState::Unknown,
+        "Expected negated undecided at_most to stay Unknown",
+    )?;
+    Ok(())
+}
+
 // ============================================================================
 // SECTION: Trace Hook Tests
 // ============================================================================
@@ -517,6 +718,165 @@ fn bochvar_vs_kleene_key_difference() -> TestResult {
     Ok(())
 }
 
+// ============================================================================
+// SECTION: Łukasiewicz Logic Complete Truth Tables
+// ============================================================================
+
+/// Complete 3x3 truth tables for Łukasiewicz AND and OR
+///
+/// AND and OR are `min` and `max` over `False < Unknown < True`:
+/// AND     | True    | False   | Unknown
+/// --------|---------|---------|--------
+/// True    | True    | False   | Unknown
+/// False   | False   | False   | False
+/// Unknown | Unknown | False   | Unknown
+///
+/// OR      | True    | False   | Unknown
+/// --------|---------|---------|--------
+/// True    | True    | True    | True
+/// False   | True    | False   | Unknown
+/// Unknown | True    | Unknown | Unknown
+#[test]
+fn lukasiewicz_and_or_complete_truth_tables() -> TestResult {
+    use TriState::False;
+    use TriState::True;
+    use TriState::Unknown;
+    let logic = LukasiewiczLogic;
+
+    ensure(logic.and(True, True) == True, "T AND T = T")?;
+    ensure(logic.and(True, False) == False, "T AND F = F")?;
+    ensure(logic.and(True, Unknown) == Unknown, "T AND U = U")?;
+    ensure(logic.and(False, True) == False, "F AND T = F")?;
+    ensure(logic.and(False, False) == False, "F AND F = F")?;
+    ensure(logic.and(False, Unknown) == False, "F AND U = F")?;
+    ensure(logic.and(Unknown, True) == Unknown, "U AND T = U")?;
+    ensure(logic.and(Unknown, False) == False, "U AND F = F")?;
+    ensure(logic.and(Unknown, Unknown) == Unknown, "U AND U = U")?;
+
+    ensure(logic.or(True, True) == True, "T OR T = T")?;
+    ensure(logic.or(True, False) == True, "T OR F = T")?;
+    ensure(logic.or(True, Unknown) == True, "T OR U = T")?;
+    ensure(logic.or(False, True) == True, "F OR T = T")?;
+    ensure(logic.or(False, False) == False, "F OR F = F")?;
+    ensure(logic.or(False, Unknown) == Unknown, "F OR U = U")?;
+    ensure(logic.or(Unknown, True) == True, "U OR T = T")?;
+    ensure(logic.or(Unknown, False) == Unknown, "U OR F = U")?;
+    ensure(logic.or(Unknown, Unknown) == Unknown, "U OR U = U")?;
+
+    ensure(logic.not(True) == False, "NOT T = F")?;
+    ensure(logic.not(False) == True, "NOT F = T")?;
+    ensure(logic.not(Unknown) == Unknown, "NOT U = U")?;
+
+    Ok(())
+}
+
+/// Complete 3x3 truth table for Łukasiewicz implication
+///
+/// IMPLIES | True    | False   | Unknown
+/// --------|---------|---------|--------
+/// True    | True    | False   | Unknown
+/// False   | True    | True    | True
+/// Unknown | True    | Unknown | True
+#[test]
+fn lukasiewicz_implies_complete_truth_table() -> TestResult {
+    use TriState::False;
+    use TriState::True;
+    use TriState::Unknown;
+    let logic = LukasiewiczLogic;
+
+    ensure(logic.implies(True, True) == True, "T -> T = T")?;
+    ensure(logic.implies(True, False) == False, "T -> F = F")?;
+    ensure(logic.implies(True, Unknown) == Unknown, "T -> U = U")?;
+    ensure(logic.implies(False, True) == True, "F -> T = T")?;
+    ensure(logic.implies(False, False) == True, "F -> F = T")?;
+    ensure(logic.implies(False, Unknown) == True, "F -> U = T")?;
+    ensure(logic.implies(Unknown, True) == True, "U -> T = T")?;
+    ensure(logic.implies(Unknown, False) == Unknown, "U -> F = U")?;
+    ensure(logic.implies(Unknown, Unknown) == True, "U -> U = T")?;
+
+    Ok(())
+}
+
+/// Key difference: Łukasiewicz vs material implication on Unknown -> Unknown
+#[test]
+fn lukasiewicz_vs_kleene_implication() -> TestResult {
+    use TriState::Unknown;
+
+    ensure(KleeneLogic.implies(Unknown, Unknown) == Unknown, "Kleene: U -> U = U")?;
+    ensure(BochvarLogic.implies(Unknown, Unknown) == Unknown, "Bochvar: U -> U = U")?;
+    ensure(
+        LukasiewiczLogic.implies(Unknown, Unknown) == TriState::True,
+        "Łukasiewicz: U -> U = T",
+    )?;
+    ensure(
+        LogicMode::Lukasiewicz.implies(Unknown, Unknown) == TriState::True,
+        "Expected Łukasiewicz logic mode to match Łukasiewicz implication",
+    )?;
+    ensure(LogicMode::Lukasiewicz.short_circuits(), "Expected Łukasiewicz to short-circuit")?;
+
+    Ok(())
+}
+
+/// Tests an `Implies` requirement defers its unknown cases to the logic mode.
+#[test]
+fn implies_requirement_follows_logic_mode() -> TestResult {
+    let reader = TestReader::new(vec![[TriState::Unknown, TriState::Unknown, TriState::False]]);
+    let unknown_req = Requirement::implies(
+        Requirement::condition(TestCondition::A),
+        Requirement::condition(TestCondition::B),
+    );
+    let failing_req = Requirement::implies(
+        Requirement::negate(Requirement::condition(TestCondition::C)),
+        Requirement::condition(TestCondition::C),
+    );
+
+    ensure(
+        unknown_req.eval_tristate(&reader, 0, &KleeneLogic) == TriState::Unknown,
+        "Expected Kleene U -> U to resolve to Unknown",
+    )?;
+    ensure(
+        unknown_req.eval_tristate(&reader, 0, &BochvarLogic) == TriState::Unknown,
+        "Expected Bochvar U -> U to resolve to Unknown",
+    )?;
+    ensure(
+        unknown_req.eval_tristate(&reader, 0, &LogicMode::Lukasiewicz) == TriState::True,
+        "Expected Łukasiewicz U -> U to resolve to True",
+    )?;
+    ensure(
+        failing_req.eval_tristate(&reader, 0, &LogicMode::Lukasiewicz) == TriState::False,
+        "Expected T -> F to resolve to False",
+    )?;
+    Ok(())
+}
+
+/// Tests Łukasiewicz group counting matches Kleene for every count split.
+#[test]
+fn lukasiewicz_group_counting_matches_kleene() -> TestResult {
+    for total in 0 ..= 4usize {
+        for satisfied in 0 ..= total {
+            for unknown in 0 ..= total - satisfied {
+                let counts = GroupCounts {
+                    satisfied,
+                    unknown,
+                    total,
+                };
+                for threshold in 0 ..= 5u8 {
+                    ensure(
+                        LogicMode::Lukasiewicz.require_group(threshold, counts)
+                            == KleeneLogic.require_group(threshold, counts)
+                            && LogicMode::Lukasiewicz.require_exactly(threshold, counts)
+                                == KleeneLogic.require_exactly(threshold, counts)
+                            && LogicMode::Lukasiewicz.require_at_most(threshold, counts)
+                                == KleeneLogic.require_at_most(threshold, counts),
+                        format!("Expected Kleene counting for {counts:?} at {threshold}"),
+                    )?;
+                }
+            }
+        }
+    }
+    Ok(())
+}
+
 // ============================================================================
 // SECTION: require_group Complete Boundary Tests
 // ============================================================================
@@ -265,6 +265,7 @@ fn main() -> Result<(), Box<dyn std::error::Error>> {
         agent_id: "agent-1".to_string(),
         time: Timestamp::Logical(1),
         correlation_id: None,
+        lease_holder: None,
     };
     let first_result = engine.scenario_next(&first)?;
     let first_outcome = outcome_summary(&first_result.decision.outcome);
@@ -280,6 +281,7 @@ fn main() -> Result<(), Box<dyn std::error::Error>> {
         agent_id: "agent-1".to_string(),
         time: Timestamp::Logical(2),
         correlation_id: None,
+        lease_holder: None,
     };
     let second_result = engine.scenario_next(&second)?;
     let second_outcome = outcome_summary(&second_result.decision.outcome);
@@ -215,6 +215,7 @@ fn main() -> Result<(), Box<dyn std::error::Error>> {
         agent_id: "agent-1".to_string(),
         time: Timestamp::Logical(1),
         correlation_id: None,
+        lease_holder: None,
     };
     let result = engine.scenario_next(&request)?;
     let outcome = outcome_summary(&result.decision.outcome);
@@ -231,6 +232,8 @@ fn main() -> Result<(), Box<dyn std::error::Error>> {
         content_type: "application/json".to_string(),
         submitted_at: Timestamp::Logical(2),
         correlation_id: None,
+        expected_version: None,
+        lease_holder: None,
     };
     let submit_result = engine.scenario_submit(&submission)?;
     write_line("Recorded submission", &submit_result.record.submission_id)?;
@@ -219,6 +219,7 @@ fn main() -> Result<(), Box<dyn std::error::Error>> {
         agent_id: "agent-1".to_string(),
         time: Timestamp::Logical(1),
         correlation_id: None,
+        lease_holder: None,
     };
     let result = engine.scenario_next(&request)?;
     let outcome = outcome_summary(&result.decision.outcome);
@@ -201,6 +201,13 @@ class RoundtripFixture:
             },
         }
 
+    def scenarios_status_bulk_request(self) -> JsonDict:
+        return {
+            "tenant_id": 1,
+            "namespace_id": 1,
+            "runs": [{"scenario_id": self.scenario_id, "run_id": self.run_id}],
+        }
+
     def scenario_next_request(self) -> JsonDict:
         return {
             "scenario_id": self.scenario_id,
@@ -214,6 +221,31 @@ class RoundtripFixture:
             },
         }
 
+    def scenario_lease_acquire_request(self) -> JsonDict:
+        return {
+            "scenario_id": self.scenario_id,
+            "request": {
+                "tenant_id": 1,
+                "namespace_id": 1,
+                "run_id": self.run_id,
+                "holder_id": "adapter-roundtrip",
+                "ttl_ms": 60000,
+                "requested_at": _timestamp(4),
+            },
+        }
+
+    def scenario_lease_release_request(self) -> JsonDict:
+        return {
+            "scenario_id": self.scenario_id,
+            "request": {
+                "tenant_id": 1,
+                "namespace_id": 1,
+                "run_id": self.run_id,
+                "holder_id": "adapter-roundtrip",
+                "requested_at": _timestamp(6),
+            },
+        }
+
     def scenario_submit_request(self) -> JsonDict:
         return {
             "scenario_id": self.scenario_id,
@@ -225,6 +257,7 @@ class RoundtripFixture:
                 "payload": {"kind": "json", "value": {"deploy_env": "production"}},
                 "content_type": "application/json",
                 "submitted_at": _timestamp(5),
+                "lease_holder": "adapter-roundtrip",
             },
         }
 
@@ -241,6 +274,7 @@ class RoundtripFixture:
                 "source_id": "adapter-roundtrip",
                 "payload": {"kind": "json", "value": {"signal": "ping"}},
             },
+            "lease_holder": "adapter-roundtrip",
         }
 
     def evidence_query_request(self) -> JsonDict:
@@ -460,10 +494,22 @@ def _exercise_roundtrip(
     result = _assert_dict("scenario_status", _parse_result(result))
     _assert_keys("scenario_status", result, ["run_id", "scenario_id", "status"])
 
+    result = caller(
+        tool("decision_gate_scenarios_status_bulk"), fixture.scenarios_status_bulk_request()
+    )
+    result = _assert_dict("scenarios_status_bulk", _parse_result(result))
+    _assert_keys("scenarios_status_bulk", result, ["results"])
+
     result = caller(tool("decision_gate_scenario_next"), fixture.scenario_next_request())
     result = _assert_dict("scenario_next", _parse_result(result))
     _assert_keys("scenario_next", result, ["decision", "status", "packets"])
 
+    result = caller(
+        tool("decision_gate_scenario_lease_acquire"), fixture.scenario_lease_acquire_request()
+    )
+    result = _assert_dict("scenario_lease_acquire", _parse_result(result))
+    _assert_keys("scenario_lease_acquire", result, ["run_id", "holder_id", "expires_at"])
+
     result = caller(tool("decision_gate_scenario_submit"), fixture.scenario_submit_request())
     result = _assert_dict("scenario_submit", _parse_result(result))
     _assert_keys("scenario_submit", result, ["record"])
@@ -472,6 +518,12 @@ def _exercise_roundtrip(
     result = _assert_dict("scenario_trigger", _parse_result(result))
     _assert_keys("scenario_trigger", result, ["decision", "status", "packets"])
 
+    result = caller(
+        tool("decision_gate_scenario_lease_release"), fixture.scenario_lease_release_request()
+    )
+    result = _assert_dict("scenario_lease_release", _parse_result(result))
+    _assert_keys("scenario_lease_release", result, ["released"])
+
     result = caller(tool("decision_gate_evidence_query"), fixture.evidence_query_request())
     result = _assert_dict("evidence_query", _parse_result(result))
     _assert_keys("evidence_query", result, ["result"])
@@ -39,6 +39,9 @@ TOOL_NAMES: Sequence[str] = (
     "scenarios_list",
     "precheck",
     "decision_gate_docs_search",
+    "scenarios_status_bulk",
+    "scenario_lease_acquire",
+    "scenario_lease_release",
 )
 
 TOOL_DESCRIPTIONS: Mapping[str, str] = {
@@ -60,6 +63,9 @@ TOOL_DESCRIPTIONS: Mapping[str, str] = {
     "scenarios_list": "List registered scenarios for a tenant and namespace.",
     "precheck": "Evaluate a scenario against asserted data without mutating state.",
     "decision_gate_docs_search": "Search Decision Gate documentation for runtime guidance.",
+    "scenarios_status_bulk": "Fetch read-only status snapshots for several runs in one call.",
+    "scenario_lease_acquire": "Acquire or renew an advisory lease so only one holder can advance, submit to, or trigger a run.",
+    "scenario_lease_release": "Release an advisory run lease before it expires.",
 }
 
 TOOL_NOTES: Mapping[str, Sequence[str]] = {
@@ -79,22 +85,29 @@ TOOL_NOTES: Mapping[str, Sequence[str]] = {
         "Use for polling or UI state; does not evaluate gates.",
         "Safe summaries omit evidence values and may include retry hints.",
         "Returns issued packet IDs to help track disclosures.",
+        "Set wait_for_change to long-poll until the run changes or max_wait_ms elapses.",
     ],
     "scenario_next": [
         "Idempotent by trigger_id; repeated calls return the same decision.",
         "Records decision, evidence, and packet disclosures in run state.",
         "Requires an active run; completed or failed runs do not advance.",
+        "While the run is leased, only the lease holder may advance it; pass lease_holder.",
         "Optional feedback can include gate trace or evidence when permitted by server feedback policy.",
     ],
     "scenario_submit": [
         "Payload is hashed and stored as a submission record.",
+        "Payload is persisted in run state/runpack logs; do not send raw secrets.",
         "Does not advance the run by itself.",
         "Use for artifacts the model or operator supplies.",
+        "Set expected_version to the version from scenario_status to reject the submit with a conflict if the run advanced; re-read and retry.",
+        "While the run is leased, only the lease holder may submit; pass lease_holder.",
     ],
     "scenario_trigger": [
         "Trigger time is supplied by the caller; no wall-clock reads.",
         "Records the trigger event and resulting decision.",
+        "Payload is persisted in run state/runpack logs; do not send raw secrets.",
         "Use for time-based or external system triggers.",
+        "While the run is leased, only the lease holder may trigger; pass lease_holder.",
     ],
     "evidence_query": [
         "Disclosure policy may redact raw values; hashes/anchors still returned.",
@@ -104,6 +117,8 @@ TOOL_NOTES: Mapping[str, Sequence[str]] = {
     "runpack_export": [
         "Writes manifest and logs to output_dir; generated_at is recorded in the manifest.",
         "include_verification adds a verification report artifact.",
+        "include_evaluation_audit adds evaluation_audit.json: spec, run config, evidence, and result hashes for every decision and submission.",
+        "Export-time report.checked_files excludes verifier_report.json; offline runpack_verify checked_files includes it (+1 for the same runpack).",
         "Use after runs complete or for audit snapshots.",
     ],
     "runpack_verify": [
@@ -150,6 +165,22 @@ TOOL_NOTES: Mapping[str, Sequence[str]] = {
         "Returns ranked sections with role tags and suggested follow-ups.",
         "Search is deterministic and scoped to the configured doc catalog.",
     ],
+    "scenarios_status_bulk": [
+        "Requires tenant_id and namespace_id; every run is resolved within that scope.",
+        "Accepts up to 100 runs; results are returned in request order.",
+        "Unknown scenarios or runs yield found=false without failing the call.",
+        "Reads do not record tool calls or modify run state.",
+    ],
+    "scenario_lease_acquire": [
+        "While the lease is active, scenario_next, scenario_submit, and scenario_trigger calls without the matching lease_holder fail with a conflict.",
+        "The lease expires ttl_ms after the server receives the request; requested_at is recorded but never used to judge expiry. The holder renews by acquiring again.",
+        "Fails with a conflict while another holder's lease is active.",
+        "Leases live in server memory and are lost on restart.",
+    ],
+    "scenario_lease_release": [
+        "Returns released=false when the caller held no active lease.",
+        "Fails with a conflict while another holder's lease is active.",
+    ],
 }
 
 class ScenarioDefineRequest(TypedDict):
@@ -194,7 +225,8 @@ ScenarioDefine_OUTPUT_SCHEMA = _json.loads(r"""
       "properties": {
         "algorithm": {
           "enum": [
-            "sha256"
+            "sha256",
+            "blake3"
           ],
           "type": "string"
         },
@@ -267,6 +299,7 @@ ScenarioStart_INPUT_SCHEMA = _json.loads(r"""
       "description": "Run configuration and dispatch targets.",
       "properties": {
         "dispatch_targets": {
+          "description": "Dispatch targets for disclosures (default: none).",
           "items": {
             "oneOf": [
               {
@@ -344,6 +377,7 @@ ScenarioStart_INPUT_SCHEMA = _json.loads(r"""
               }
             ]
           },
+          "maxItems": 64,
           "type": "array"
         },
         "namespace_id": {
@@ -352,10 +386,11 @@ ScenarioStart_INPUT_SCHEMA = _json.loads(r"""
           "type": "integer"
         },
         "policy_tags": {
-          "description": "Policy tags applied to the run.",
+          "description": "Policy tags applied to the run (default: none).",
           "items": {
             "type": "string"
           },
+          "maxItems": 64,
           "type": "array"
         },
         "run_id": {
@@ -375,9 +410,7 @@ ScenarioStart_INPUT_SCHEMA = _json.loads(r"""
       "required": [
         "tenant_id",
         "run_id",
-        "scenario_id",
-        "dispatch_targets",
-        "policy_tags"
+        "scenario_id"
       ],
       "type": "object"
     },
@@ -894,7 +927,8 @@ ScenarioStart_OUTPUT_SCHEMA = _json.loads(r"""
                           "properties": {
                             "algorithm": {
                               "enum": [
-                                "sha256"
+                                "sha256",
+                                "blake3"
                               ],
                               "type": "string"
                             },
@@ -1101,7 +1135,8 @@ ScenarioStart_OUTPUT_SCHEMA = _json.loads(r"""
                 "properties": {
                   "algorithm": {
                     "enum": [
-                      "sha256"
+                      "sha256",
+                      "blake3"
                     ],
                     "type": "string"
                   },
@@ -1330,7 +1365,8 @@ ScenarioStart_OUTPUT_SCHEMA = _json.loads(r"""
                         "properties": {
                           "algorithm": {
                             "enum": [
-                              "sha256"
+                              "sha256",
+                              "blake3"
                             ],
                             "type": "string"
                           },
@@ -1434,7 +1470,8 @@ ScenarioStart_OUTPUT_SCHEMA = _json.loads(r"""
                   "properties": {
                     "algorithm": {
                       "enum": [
-                        "sha256"
+                        "sha256",
+                        "blake3"
                       ],
                       "type": "string"
                     },
@@ -1562,7 +1599,8 @@ ScenarioStart_OUTPUT_SCHEMA = _json.loads(r"""
       "properties": {
         "algorithm": {
           "enum": [
-            "sha256"
+            "sha256",
+            "blake3"
           ],
           "type": "string"
         },
@@ -1631,7 +1669,8 @@ ScenarioStart_OUTPUT_SCHEMA = _json.loads(r"""
             "properties": {
               "algorithm": {
                 "enum": [
-                  "sha256"
+                  "sha256",
+                  "blake3"
                 ],
                 "type": "string"
               },
@@ -1719,7 +1758,8 @@ ScenarioStart_OUTPUT_SCHEMA = _json.loads(r"""
                         "properties": {
                           "algorithm": {
                             "enum": [
-                              "sha256"
+                              "sha256",
+                              "blake3"
                             ],
                             "type": "string"
                           },
@@ -1984,7 +2024,8 @@ ScenarioStart_OUTPUT_SCHEMA = _json.loads(r"""
             "properties": {
               "algorithm": {
                 "enum": [
-                  "sha256"
+                  "sha256",
+                  "blake3"
                 ],
                 "type": "string"
               },
@@ -2004,7 +2045,8 @@ ScenarioStart_OUTPUT_SCHEMA = _json.loads(r"""
             "properties": {
               "algorithm": {
                 "enum": [
-                  "sha256"
+                  "sha256",
+                  "blake3"
                 ],
                 "type": "string"
               },
@@ -2128,7 +2170,8 @@ ScenarioStart_OUTPUT_SCHEMA = _json.loads(r"""
                                 "properties": {
                                   "algorithm": {
                                     "enum": [
-                                      "sha256"
+                                      "sha256",
+                                      "blake3"
                                     ],
                                     "type": "string"
                                   },
@@ -2287,6 +2330,8 @@ class ScenarioStatusRequest(TypedDict):
     request: Dict[str, JsonValue]
     #: Scenario identifier.
     scenario_id: str
+    #: Optional long-poll options; the server holds the request until the run status changes.
+    wait_for_change: NotRequired[Union[Dict[str, JsonValue], None]]
 
 class ScenarioStatusResponse(TypedDict):
     """Schema for ScenarioStatusResponse."""
@@ -2303,6 +2348,8 @@ class ScenarioStatusResponse(TypedDict):
     scenario_id: str
     #: Constraints: Allowed values: "active", "completed", "failed".
     status: Literal["active", "completed", "failed"]
+    #: Run version; advances on each trigger or submission. Constraints: Minimum: 0.
+    version: int
 
 ScenarioStatus_INPUT_SCHEMA = _json.loads(r"""
 {
@@ -2387,6 +2434,29 @@ ScenarioStatus_INPUT_SCHEMA = _json.loads(r"""
     "scenario_id": {
       "description": "Scenario identifier.",
       "type": "string"
+    },
+    "wait_for_change": {
+      "description": "Optional long-poll options; the server holds the request until the run status changes.",
+      "oneOf": [
+        {
+          "type": "null"
+        },
+        {
+          "additionalProperties": false,
+          "properties": {
+            "max_wait_ms": {
+              "description": "Maximum time to hold the request before returning the current status.",
+              "maximum": 30000,
+              "minimum": 1,
+              "type": "integer"
+            }
+          },
+          "required": [
+            "max_wait_ms"
+          ],
+          "type": "object"
+        }
+      ]
     }
   },
   "required": [
@@ -2701,6 +2771,11 @@ ScenarioStatus_OUTPUT_SCHEMA = _json.loads(r"""
         "failed"
       ],
       "type": "string"
+    },
+    "version": {
+      "description": "Run version; advances on each trigger or submission.",
+      "minimum": 0,
+      "type": "integer"
     }
   },
   "required": [
@@ -2710,7 +2785,8 @@ ScenarioStatus_OUTPUT_SCHEMA = _json.loads(r"""
     "status",
     "last_decision",
     "issued_packet_ids",
-    "safe_summary"
+    "safe_summary",
+    "version"
   ],
   "type": "object"
 }
@@ -2774,6 +2850,12 @@ ScenarioNext_INPUT_SCHEMA = _json.loads(r"""
             }
           ]
         },
+        "lease_holder": {
+          "description": "Lease holder making the call; required while the run is leased.",
+          "maxLength": 256,
+          "minLength": 1,
+          "type": "string"
+        },
         "namespace_id": {
           "description": "Namespace identifier.",
           "minimum": 1,
@@ -3310,7 +3392,8 @@ ScenarioNext_OUTPUT_SCHEMA = _json.loads(r"""
                                       "properties": {
                                         "algorithm": {
                                           "enum": [
-                                            "sha256"
+                                            "sha256",
+                                            "blake3"
                                           ],
                                           "type": "string"
                                         },
@@ -3531,7 +3614,8 @@ ScenarioNext_OUTPUT_SCHEMA = _json.loads(r"""
                 "properties": {
                   "algorithm": {
                     "enum": [
-                      "sha256"
+                      "sha256",
+                      "blake3"
                     ],
                     "type": "string"
                   },
@@ -3760,7 +3844,8 @@ ScenarioNext_OUTPUT_SCHEMA = _json.loads(r"""
                         "properties": {
                           "algorithm": {
                             "enum": [
-                              "sha256"
+                              "sha256",
+                              "blake3"
                             ],
                             "type": "string"
                           },
@@ -3864,7 +3949,8 @@ ScenarioNext_OUTPUT_SCHEMA = _json.loads(r"""
                   "properties": {
                     "algorithm": {
                       "enum": [
-                        "sha256"
+                        "sha256",
+                        "blake3"
                       ],
                       "type": "string"
                     },
@@ -4032,6 +4118,17 @@ ScenarioSubmit_INPUT_SCHEMA = _json.loads(r"""
             }
           ]
         },
+        "expected_version": {
+          "description": "Run version read from scenario_status; conflicts if the run advanced.",
+          "minimum": 0,
+          "type": "integer"
+        },
+        "lease_holder": {
+          "description": "Lease holder making the call; required while the run is leased.",
+          "maxLength": 256,
+          "minLength": 1,
+          "type": "string"
+        },
         "namespace_id": {
           "description": "Namespace identifier.",
           "minimum": 1,
@@ -4095,7 +4192,8 @@ ScenarioSubmit_INPUT_SCHEMA = _json.loads(r"""
                       "properties": {
                         "algorithm": {
                           "enum": [
-                            "sha256"
+                            "sha256",
+                            "blake3"
                           ],
                           "type": "string"
                         },
@@ -4232,7 +4330,8 @@ ScenarioSubmit_OUTPUT_SCHEMA = _json.loads(r"""
           "properties": {
             "algorithm": {
               "enum": [
-                "sha256"
+                "sha256",
+                "blake3"
               ],
               "type": "string"
             },
@@ -4320,7 +4419,8 @@ ScenarioSubmit_OUTPUT_SCHEMA = _json.loads(r"""
                       "properties": {
                         "algorithm": {
                           "enum": [
-                            "sha256"
+                            "sha256",
+                            "blake3"
                           ],
                           "type": "string"
                         },
@@ -4437,6 +4537,9 @@ ScenarioSubmit_OUTPUT_SCHEMA = _json.loads(r"""
 
 class ScenarioTriggerRequest(TypedDict):
     """Schema for ScenarioTriggerRequest."""
+    #: Lease holder making the call; required while the run is leased. Constraints: Min length:
+    #: 1; Max length: 256.
+    lease_holder: NotRequired[str]
     #: Scenario identifier.
     scenario_id: str
     #: Trigger event payload.
@@ -4454,6 +4557,12 @@ ScenarioTrigger_INPUT_SCHEMA = _json.loads(r"""
   "$schema": "https://json-schema.org/draft/2020-12/schema",
   "additionalProperties": false,
   "properties": {
+    "lease_holder": {
+      "description": "Lease holder making the call; required while the run is leased.",
+      "maxLength": 256,
+      "minLength": 1,
+      "type": "string"
+    },
     "scenario_id": {
       "description": "Scenario identifier.",
       "type": "string"
@@ -4550,7 +4659,8 @@ ScenarioTrigger_INPUT_SCHEMA = _json.loads(r"""
                           "properties": {
                             "algorithm": {
                               "enum": [
-                                "sha256"
+                                "sha256",
+                                "blake3"
                               ],
                               "type": "string"
                             },
@@ -4911,7 +5021,8 @@ ScenarioTrigger_OUTPUT_SCHEMA = _json.loads(r"""
                 "properties": {
                   "algorithm": {
                     "enum": [
-                      "sha256"
+                      "sha256",
+                      "blake3"
                     ],
                     "type": "string"
                   },
@@ -5140,7 +5251,8 @@ ScenarioTrigger_OUTPUT_SCHEMA = _json.loads(r"""
                         "properties": {
                           "algorithm": {
                             "enum": [
-                              "sha256"
+                              "sha256",
+                              "blake3"
                             ],
                             "type": "string"
                           },
@@ -5244,7 +5356,8 @@ ScenarioTrigger_OUTPUT_SCHEMA = _json.loads(r"""
                   "properties": {
                     "algorithm": {
                       "enum": [
-                        "sha256"
+                        "sha256",
+                        "blake3"
                       ],
                       "type": "string"
                     },
@@ -5619,7 +5732,8 @@ EvidenceQuery_OUTPUT_SCHEMA = _json.loads(r"""
               "properties": {
                 "algorithm": {
                   "enum": [
-                    "sha256"
+                    "sha256",
+                    "blake3"
                   ],
                   "type": "string"
                 },
@@ -5779,6 +5893,8 @@ class RunpackExportRequest(TypedDict):
     """Schema for RunpackExportRequest."""
     #: Timestamp recorded in the manifest.
     generated_at: Dict[str, JsonValue]
+    #: Include evaluation audit records as an artifact (default false).
+    include_evaluation_audit: NotRequired[bool]
     #: Generate a verification report artifact.
     include_verification: bool
     #: Optional override for the manifest file name.
@@ -5844,6 +5960,10 @@ RunpackExport_INPUT_SCHEMA = _json.loads(r"""
         }
       ]
     },
+    "include_evaluation_audit": {
+      "description": "Include evaluation audit records as an artifact (default false).",
+      "type": "boolean"
+    },
     "include_verification": {
       "description": "Generate a verification report artifact.",
       "type": "boolean"
@@ -5919,6 +6039,14 @@ RunpackExport_OUTPUT_SCHEMA = _json.loads(r"""
                 "description": "Artifact identifier.",
                 "type": "string"
               },
+              "content_encoding": {
+                "description": "Encoding of the stored artifact bytes; hashes cover the decoded bytes.",
+                "enum": [
+                  "none",
+                  "gzip"
+                ],
+                "type": "string"
+              },
               "content_type": {
                 "oneOf": [
                   {
@@ -5935,7 +6063,8 @@ RunpackExport_OUTPUT_SCHEMA = _json.loads(r"""
                 "properties": {
                   "algorithm": {
                     "enum": [
-                      "sha256"
+                      "sha256",
+                      "blake3"
                     ],
                     "type": "string"
                   },
@@ -5961,6 +6090,7 @@ RunpackExport_OUTPUT_SCHEMA = _json.loads(r"""
                   "evidence_log",
                   "submission_log",
                   "tool_transcript",
+                  "evaluation_audit",
                   "verifier_report",
                   "custom"
                 ],
@@ -6025,7 +6155,8 @@ RunpackExport_OUTPUT_SCHEMA = _json.loads(r"""
         },
         "hash_algorithm": {
           "enum": [
-            "sha256"
+            "sha256",
+            "blake3"
           ],
           "type": "string"
         },
@@ -6041,7 +6172,8 @@ RunpackExport_OUTPUT_SCHEMA = _json.loads(r"""
                     "properties": {
                       "algorithm": {
                         "enum": [
-                          "sha256"
+                          "sha256",
+                          "blake3"
                         ],
                         "type": "string"
                       },
@@ -6074,7 +6206,8 @@ RunpackExport_OUTPUT_SCHEMA = _json.loads(r"""
               "properties": {
                 "algorithm": {
                   "enum": [
-                    "sha256"
+                    "sha256",
+                    "blake3"
                   ],
                   "type": "string"
                 },
@@ -6130,12 +6263,40 @@ RunpackExport_OUTPUT_SCHEMA = _json.loads(r"""
           ],
           "type": "object"
         },
+        "signature": {
+          "additionalProperties": false,
+          "properties": {
+            "key_id": {
+              "description": "Signing key identifier.",
+              "type": "string"
+            },
+            "scheme": {
+              "description": "Manifest signature scheme identifier.",
+              "type": "string"
+            },
+            "signature": {
+              "items": {
+                "maximum": 255,
+                "minimum": 0,
+                "type": "integer"
+              },
+              "type": "array"
+            }
+          },
+          "required": [
+            "scheme",
+            "key_id",
+            "signature"
+          ],
+          "type": "object"
+        },
         "spec_hash": {
           "additionalProperties": false,
           "properties": {
             "algorithm": {
               "enum": [
-                "sha256"
+                "sha256",
+                "blake3"
               ],
               "type": "string"
             },
@@ -6326,9 +6487,17 @@ class ProvidersListRequest(TypedDict):
     """Schema for ProvidersListRequest."""
     pass
 
+class ProviderSummary(TypedDict):
+    """Registered evidence provider and the checks it serves. Constraints: Title: ProviderSummary."""
+    checks: List[str]
+    #: Provider identifier.
+    provider_id: str
+    #: Provider transport type. Constraints: Allowed values: "builtin", "mcp".
+    transport: Literal["builtin", "mcp"]
+
 class ProvidersListResponse(TypedDict):
     """Schema for ProvidersListResponse."""
-    providers: List[Dict[str, JsonValue]]
+    providers: List[ProviderSummary]
 
 ProvidersList_INPUT_SCHEMA = _json.loads(r"""
 {
@@ -6342,39 +6511,46 @@ ProvidersList_INPUT_SCHEMA = _json.loads(r"""
 
 ProvidersList_OUTPUT_SCHEMA = _json.loads(r"""
 {
+  "$defs": {
+    "ProviderSummary": {
+      "additionalProperties": false,
+      "description": "Registered evidence provider and the checks it serves.",
+      "properties": {
+        "checks": {
+          "items": {
+            "description": "Check identifier.",
+            "type": "string"
+          },
+          "type": "array"
+        },
+        "provider_id": {
+          "description": "Provider identifier.",
+          "type": "string"
+        },
+        "transport": {
+          "description": "Provider transport type.",
+          "enum": [
+            "builtin",
+            "mcp"
+          ],
+          "type": "string"
+        }
+      },
+      "required": [
+        "provider_id",
+        "transport",
+        "checks"
+      ],
+      "title": "ProviderSummary",
+      "type": "object"
+    }
+  },
   "$schema": "https://json-schema.org/draft/2020-12/schema",
   "additionalProperties": false,
   "properties": {
     "providers": {
       "items": {
-        "additionalProperties": false,
-        "properties": {
-          "checks": {
-            "items": {
-              "description": "Check identifier.",
-              "type": "string"
-            },
-            "type": "array"
-          },
-          "provider_id": {
-            "description": "Provider identifier.",
-            "type": "string"
-          },
-          "transport": {
-            "description": "Provider transport type.",
-            "enum": [
-              "builtin",
-              "mcp"
-            ],
-            "type": "string"
-          }
-        },
-        "required": [
-          "provider_id",
-          "transport",
-          "checks"
-        ],
-        "type": "object"
+        "$ref": "#/$defs/ProviderSummary"
       },
       "type": "array"
     }
@@ -6626,7 +6802,8 @@ ProviderContractGet_OUTPUT_SCHEMA = _json.loads(r"""
       "properties": {
         "algorithm": {
           "enum": [
-            "sha256"
+            "sha256",
+            "blake3"
           ],
           "type": "string"
         },
@@ -6783,7 +6960,8 @@ ProviderCheckSchemaGet_OUTPUT_SCHEMA = _json.loads(r"""
       "properties": {
         "algorithm": {
           "enum": [
-            "sha256"
+            "sha256",
+            "blake3"
           ],
           "type": "string"
         },
@@ -7642,7 +7820,8 @@ ScenariosList_OUTPUT_SCHEMA = _json.loads(r"""
             "properties": {
               "algorithm": {
                 "enum": [
-                  "sha256"
+                  "sha256",
+                  "blake3"
                 ],
                 "type": "string"
               },
@@ -8133,85 +8312,2011 @@ DecisionGateDocsSearch_OUTPUT_SCHEMA = _json.loads(r"""
 }
 """)
 
-class GeneratedDecisionGateClient:
-    """Generated Decision Gate client methods. Implement `_call_tool`."""
-
-    def _call_tool(self, name: str, arguments: JsonValue) -> JsonValue:
-        raise NotImplementedError("_call_tool must be implemented by subclasses")
+class ScenariosStatusBulkRequest(TypedDict):
+    """Schema for ScenariosStatusBulkRequest."""
+    #: Namespace identifier. Constraints: Minimum: 1.
+    namespace_id: int
+    #: Runs to fetch status for, in result order. Constraints: Min items: 1; Max items: 100.
+    runs: List[Dict[str, JsonValue]]
+    #: Tenant identifier. Constraints: Minimum: 1.
+    tenant_id: int
 
-    def scenario_define(self, request: ScenarioDefineRequest) -> ScenarioDefineResponse:
-        """
-        Register a ScenarioSpec, validate it, and return the canonical hash used for integrity checks.
+class ScenariosStatusBulkResponse(TypedDict):
+    """Schema for ScenariosStatusBulkResponse."""
+    results: List[Dict[str, JsonValue]]
 
-        Notes:
-        - Use before starting runs; scenario_id becomes the stable handle for later calls.
-        - Validates stage/gate/condition IDs, RET trees, and condition references.
-        - Spec hash is deterministic; store it for audit and runpack integrity.
-        - Fails closed on invalid specs or duplicate scenario IDs.
+ScenariosStatusBulk_INPUT_SCHEMA = _json.loads(r"""
+{
+  "$schema": "https://json-schema.org/draft/2020-12/schema",
+  "additionalProperties": false,
+  "properties": {
+    "namespace_id": {
+      "description": "Namespace identifier.",
+      "minimum": 1,
+      "type": "integer"
+    },
+    "runs": {
+      "description": "Runs to fetch status for, in result order.",
+      "items": {
+        "additionalProperties": false,
+        "properties": {
+          "run_id": {
+            "description": "Run identifier.",
+            "type": "string"
+          },
+          "scenario_id": {
+            "description": "Scenario identifier.",
+            "type": "string"
+          }
+        },
+        "required": [
+          "scenario_id",
+          "run_id"
+        ],
+        "type": "object"
+      },
+      "maxItems": 100,
+      "minItems": 1,
+      "type": "array"
+    },
+    "tenant_id": {
+      "description": "Tenant identifier.",
+      "minimum": 1,
+      "type": "integer"
+    }
+  },
+  "required": [
+    "tenant_id",
+    "namespace_id",
+    "runs"
+  ],
+  "type": "object"
+}
+""")
 
-        Examples:
-        - Register the example scenario spec.
-          Input:
-            {
-              "spec": {
-                "conditions": [
-                  {
-                    "comparator": "equals",
-                    "condition_id": "env_is_prod",
-                    "expected": "production",
-                    "policy_tags": [],
-                    "query": {
-                      "check_id": "get",
-                      "params": {
-                        "key": "DEPLOY_ENV"
-                      },
-                      "provider_id": "env"
-                    }
+ScenariosStatusBulk_OUTPUT_SCHEMA = _json.loads(r"""
+{
+  "$schema": "https://json-schema.org/draft/2020-12/schema",
+  "additionalProperties": false,
+  "properties": {
+    "results": {
+      "items": {
+        "additionalProperties": false,
+        "properties": {
+          "found": {
+            "description": "Whether the run exists within the requested scope.",
+            "type": "boolean"
+          },
+          "run_id": {
+            "description": "Run identifier.",
+            "type": "string"
+          },
+          "scenario_id": {
+            "description": "Scenario identifier.",
+            "type": "string"
+          },
+          "status": {
+            "description": "Run status snapshot when found.",
+            "oneOf": [
+              {
+                "type": "null"
+              },
+              {
+                "additionalProperties": false,
+                "properties": {
+                  "current_stage_id": {
+                    "description": "Current stage identifier.",
+                    "type": "string"
                   },
-                  {
-                    "comparator": "equals",
-                    "condition_id": "after_freeze",
-                    "expected": true,
-                    "policy_tags": [],
-                    "query": {
-                      "check_id": "after",
-                      "params": {
-                        "timestamp": 1710000000000
-                      },
-                      "provider_id": "time"
-                    }
-                  }
-                ],
-                "default_tenant_id": null,
-                "namespace_id": 1,
-                "policies": [],
-                "scenario_id": "example-scenario",
-                "schemas": [],
-                "spec_version": "v1",
-                "stages": [
-                  {
-                    "advance_to": {
-                      "kind": "terminal"
+                  "issued_packet_ids": {
+                    "items": {
+                      "description": "Packet identifier.",
+                      "type": "string"
                     },
-                    "entry_packets": [
+                    "type": "array"
+                  },
+                  "last_decision": {
+                    "oneOf": [
                       {
-                        "content_type": "application/json",
-                        "expiry": null,
-                        "packet_id": "packet-hello",
-                        "payload": {
-                          "kind": "json",
-                          "value": {
-                            "message": "hello",
-                            "purpose": "scenario entry packet"
-                          }
-                        },
-                        "policy_tags": [],
-                        "schema_id": "schema-hello",
-                        "visibility_labels": [
-                          "public"
-                        ]
-                      }
-                    ],
+                        "type": "null"
+                      },
+                      {
+                        "additionalProperties": false,
+                        "properties": {
+                          "correlation_id": {
+                            "oneOf": [
+                              {
+                                "type": "null"
+                              },
+                              {
+                                "description": "Correlation identifier.",
+                                "type": "string"
+                              }
+                            ]
+                          },
+                          "decided_at": {
+                            "oneOf": [
+                              {
+                                "additionalProperties": false,
+                                "properties": {
+                                  "kind": {
+                                    "const": "unix_millis"
+                                  },
+                                  "value": {
+                                    "type": "integer"
+                                  }
+                                },
+                                "required": [
+                                  "kind",
+                                  "value"
+                                ],
+                                "type": "object"
+                              },
+                              {
+                                "additionalProperties": false,
+                                "properties": {
+                                  "kind": {
+                                    "const": "logical"
+                                  },
+                                  "value": {
+                                    "minimum": 0,
+                                    "type": "integer"
+                                  }
+                                },
+                                "required": [
+                                  "kind",
+                                  "value"
+                                ],
+                                "type": "object"
+                              }
+                            ]
+                          },
+                          "decision_id": {
+                            "description": "Decision identifier.",
+                            "type": "string"
+                          },
+                          "outcome": {
+                            "oneOf": [
+                              {
+                                "additionalProperties": false,
+                                "properties": {
+                                  "kind": {
+                                    "const": "start"
+                                  },
+                                  "stage_id": {
+                                    "description": "Initial stage identifier.",
+                                    "type": "string"
+                                  }
+                                },
+                                "required": [
+                                  "kind",
+                                  "stage_id"
+                                ],
+                                "type": "object"
+                              },
+                              {
+                                "additionalProperties": false,
+                                "properties": {
+                                  "kind": {
+                                    "const": "complete"
+                                  },
+                                  "stage_id": {
+                                    "description": "Terminal stage identifier.",
+                                    "type": "string"
+                                  }
+                                },
+                                "required": [
+                                  "kind",
+                                  "stage_id"
+                                ],
+                                "type": "object"
+                              },
+                              {
+                                "additionalProperties": false,
+                                "properties": {
+                                  "from_stage": {
+                                    "description": "Previous stage identifier.",
+                                    "type": "string"
+                                  },
+                                  "kind": {
+                                    "const": "advance"
+                                  },
+                                  "timeout": {
+                                    "type": "boolean"
+                                  },
+                                  "to_stage": {
+                                    "description": "Next stage identifier.",
+                                    "type": "string"
+                                  }
+                                },
+                                "required": [
+                                  "kind",
+                                  "from_stage",
+                                  "to_stage",
+                                  "timeout"
+                                ],
+                                "type": "object"
+                              },
+                              {
+                                "additionalProperties": false,
+                                "properties": {
+                                  "kind": {
+                                    "const": "hold"
+                                  },
+                                  "summary": {
+                                    "additionalProperties": false,
+                                    "properties": {
+                                      "policy_tags": {
+                                        "description": "Policy tags applied to the summary.",
+                                        "items": {
+                                          "type": "string"
+                                        },
+                                        "type": "array"
+                                      },
+                                      "retry_hint": {
+                                        "oneOf": [
+                                          {
+                                            "type": "null"
+                                          },
+                                          {
+                                            "description": "Optional retry hint.",
+                                            "type": "string"
+                                          }
+                                        ]
+                                      },
+                                      "status": {
+                                        "description": "Summary status.",
+                                        "type": "string"
+                                      },
+                                      "unmet_gates": {
+                                        "items": {
+                                          "description": "Gate identifier.",
+                                          "type": "string"
+                                        },
+                                        "type": "array"
+                                      }
+                                    },
+                                    "required": [
+                                      "status",
+                                      "unmet_gates",
+                                      "retry_hint",
+                                      "policy_tags"
+                                    ],
+                                    "type": "object"
+                                  }
+                                },
+                                "required": [
+                                  "kind",
+                                  "summary"
+                                ],
+                                "type": "object"
+                              },
+                              {
+                                "additionalProperties": false,
+                                "properties": {
+                                  "kind": {
+                                    "const": "fail"
+                                  },
+                                  "reason": {
+                                    "description": "Failure reason.",
+                                    "type": "string"
+                                  }
+                                },
+                                "required": [
+                                  "kind",
+                                  "reason"
+                                ],
+                                "type": "object"
+                              }
+                            ]
+                          },
+                          "seq": {
+                            "minimum": 0,
+                            "type": "integer"
+                          },
+                          "stage_id": {
+                            "description": "Stage identifier.",
+                            "type": "string"
+                          },
+                          "trigger_id": {
+                            "description": "Trigger identifier.",
+                            "type": "string"
+                          }
+                        },
+                        "required": [
+                          "decision_id",
+                          "seq",
+                          "trigger_id",
+                          "stage_id",
+                          "decided_at",
+                          "outcome",
+                          "correlation_id"
+                        ],
+                        "type": "object"
+                      }
+                    ]
+                  },
+                  "namespace_id": {
+                    "description": "Namespace identifier.",
+                    "minimum": 1,
+                    "type": "integer"
+                  },
+                  "run_id": {
+                    "description": "Run identifier.",
+                    "type": "string"
+                  },
+                  "safe_summary": {
+                    "oneOf": [
+                      {
+                        "type": "null"
+                      },
+                      {
+                        "additionalProperties": false,
+                        "properties": {
+                          "policy_tags": {
+                            "description": "Policy tags applied to the summary.",
+                            "items": {
+                              "type": "string"
+                            },
+                            "type": "array"
+                          },
+                          "retry_hint": {
+                            "oneOf": [
+                              {
+                                "type": "null"
+                              },
+                              {
+                                "description": "Optional retry hint.",
+                                "type": "string"
+                              }
+                            ]
+                          },
+                          "status": {
+                            "description": "Summary status.",
+                            "type": "string"
+                          },
+                          "unmet_gates": {
+                            "items": {
+                              "description": "Gate identifier.",
+                              "type": "string"
+                            },
+                            "type": "array"
+                          }
+                        },
+                        "required": [
+                          "status",
+                          "unmet_gates",
+                          "retry_hint",
+                          "policy_tags"
+                        ],
+                        "type": "object"
+                      }
+                    ]
+                  },
+                  "scenario_id": {
+                    "description": "Scenario identifier.",
+                    "type": "string"
+                  },
+                  "status": {
+                    "enum": [
+                      "active",
+                      "completed",
+                      "failed"
+                    ],
+                    "type": "string"
+                  },
+                  "version": {
+                    "description": "Run version; advances on each trigger or submission.",
+                    "minimum": 0,
+                    "type": "integer"
+                  }
+                },
+                "required": [
+                  "run_id",
+                  "scenario_id",
+                  "current_stage_id",
+                  "status",
+                  "last_decision",
+                  "issued_packet_ids",
+                  "safe_summary",
+                  "version"
+                ],
+                "type": "object"
+              }
+            ]
+          }
+        },
+        "required": [
+          "scenario_id",
+          "run_id",
+          "found",
+          "status"
+        ],
+        "type": "object"
+      },
+      "type": "array"
+    }
+  },
+  "required": [
+    "results"
+  ],
+  "type": "object"
+}
+""")
+
+class ScenarioLeaseAcquireRequest(TypedDict):
+    """Schema for ScenarioLeaseAcquireRequest."""
+    #: Lease acquisition request.
+    request: Dict[str, JsonValue]
+    #: Scenario identifier.
+    scenario_id: str
+
+class ScenarioLeaseAcquireResponse(TypedDict):
+    """Schema for ScenarioLeaseAcquireResponse."""
+    acquired_at: Dict[str, JsonValue]
+    expires_at: Dict[str, JsonValue]
+    #: Caller-chosen lease holder identifier. Constraints: Min length: 1; Max length: 256.
+    holder_id: str
+    #: Run identifier.
+    run_id: str
+
+ScenarioLeaseAcquire_INPUT_SCHEMA = _json.loads(r"""
+{
+  "$schema": "https://json-schema.org/draft/2020-12/schema",
+  "additionalProperties": false,
+  "properties": {
+    "request": {
+      "additionalProperties": false,
+      "description": "Lease acquisition request.",
+      "properties": {
+        "holder_id": {
+          "description": "Caller-chosen lease holder identifier.",
+          "maxLength": 256,
+          "minLength": 1,
+          "type": "string"
+        },
+        "namespace_id": {
+          "description": "Namespace identifier.",
+          "minimum": 1,
+          "type": "integer"
+        },
+        "requested_at": {
+          "oneOf": [
+            {
+              "additionalProperties": false,
+              "properties": {
+                "kind": {
+                  "const": "unix_millis"
+                },
+                "value": {
+                  "type": "integer"
+                }
+              },
+              "required": [
+                "kind",
+                "value"
+              ],
+              "type": "object"
+            },
+            {
+              "additionalProperties": false,
+              "properties": {
+                "kind": {
+                  "const": "logical"
+                },
+                "value": {
+                  "minimum": 0,
+                  "type": "integer"
+                }
+              },
+              "required": [
+                "kind",
+                "value"
+              ],
+              "type": "object"
+            }
+          ]
+        },
+        "run_id": {
+          "description": "Run identifier.",
+          "type": "string"
+        },
+        "tenant_id": {
+          "description": "Tenant identifier.",
+          "minimum": 1,
+          "type": "integer"
+        },
+        "ttl_ms": {
+          "description": "Lease duration in milliseconds of server time.",
+          "maximum": 3600000,
+          "minimum": 1,
+          "type": "integer"
+        }
+      },
+      "required": [
+        "tenant_id",
+        "namespace_id",
+        "run_id",
+        "holder_id",
+        "ttl_ms",
+        "requested_at"
+      ],
+      "type": "object"
+    },
+    "scenario_id": {
+      "description": "Scenario identifier.",
+      "type": "string"
+    }
+  },
+  "required": [
+    "scenario_id",
+    "request"
+  ],
+  "type": "object"
+}
+""")
+
+ScenarioLeaseAcquire_OUTPUT_SCHEMA = _json.loads(r"""
+{
+  "additionalProperties": false,
+  "properties": {
+    "acquired_at": {
+      "oneOf": [
+        {
+          "additionalProperties": false,
+          "properties": {
+            "kind": {
+              "const": "unix_millis"
+            },
+            "value": {
+              "type": "integer"
+            }
+          },
+          "required": [
+            "kind",
+            "value"
+          ],
+          "type": "object"
+        },
+        {
+          "additionalProperties": false,
+          "properties": {
+            "kind": {
+              "const": "logical"
+            },
+            "value": {
+              "minimum": 0,
+              "type": "integer"
+            }
+          },
+          "required": [
+            "kind",
+            "value"
+          ],
+          "type": "object"
+        }
+      ]
+    },
+    "expires_at": {
+      "oneOf": [
+        {
+          "additionalProperties": false,
+          "properties": {
+            "kind": {
+              "const": "unix_millis"
+            },
+            "value": {
+              "type": "integer"
+            }
+          },
+          "required": [
+            "kind",
+            "value"
+          ],
+          "type": "object"
+        },
+        {
+          "additionalProperties": false,
+          "properties": {
+            "kind": {
+              "const": "logical"
+            },
+            "value": {
+              "minimum": 0,
+              "type": "integer"
+            }
+          },
+          "required": [
+            "kind",
+            "value"
+          ],
+          "type": "object"
+        }
+      ]
+    },
+    "holder_id": {
+      "description": "Caller-chosen lease holder identifier.",
+      "maxLength": 256,
+      "minLength": 1,
+      "type": "string"
+    },
+    "run_id": {
+      "description": "Run identifier.",
+      "type": "string"
+    }
+  },
+  "required": [
+    "run_id",
+    "holder_id",
+    "acquired_at",
+    "expires_at"
+  ],
+  "type": "object"
+}
+""")
+
+class ScenarioLeaseReleaseRequest(TypedDict):
+    """Schema for ScenarioLeaseReleaseRequest."""
+    #: Lease release request.
+    request: Dict[str, JsonValue]
+    #: Scenario identifier.
+    scenario_id: str
+
+class ScenarioLeaseReleaseResponse(TypedDict):
+    """Schema for ScenarioLeaseReleaseResponse."""
+    #: Whether an active lease held by the caller was released.
+    released: bool
+
+ScenarioLeaseRelease_INPUT_SCHEMA = _json.loads(r"""
+{
+  "$schema": "https://json-schema.org/draft/2020-12/schema",
+  "additionalProperties": false,
+  "properties": {
+    "request": {
+      "additionalProperties": false,
+      "description": "Lease release request.",
+      "properties": {
+        "holder_id": {
+          "description": "Caller-chosen lease holder identifier.",
+          "maxLength": 256,
+          "minLength": 1,
+          "type": "string"
+        },
+        "namespace_id": {
+          "description": "Namespace identifier.",
+          "minimum": 1,
+          "type": "integer"
+        },
+        "requested_at": {
+          "oneOf": [
+            {
+              "additionalProperties": false,
+              "properties": {
+                "kind": {
+                  "const": "unix_millis"
+                },
+                "value": {
+                  "type": "integer"
+                }
+              },
+              "required": [
+                "kind",
+                "value"
+              ],
+              "type": "object"
+            },
+            {
+              "additionalProperties": false,
+              "properties": {
+                "kind": {
+                  "const": "logical"
+                },
+                "value": {
+                  "minimum": 0,
+                  "type": "integer"
+                }
+              },
+              "required": [
+                "kind",
+                "value"
+              ],
+              "type": "object"
+            }
+          ]
+        },
+        "run_id": {
+          "description": "Run identifier.",
+          "type": "string"
+        },
+        "tenant_id": {
+          "description": "Tenant identifier.",
+          "minimum": 1,
+          "type": "integer"
+        }
+      },
+      "required": [
+        "tenant_id",
+        "namespace_id",
+        "run_id",
+        "holder_id",
+        "requested_at"
+      ],
+      "type": "object"
+    },
+    "scenario_id": {
+      "description": "Scenario identifier.",
+      "type": "string"
+    }
+  },
+  "required": [
+    "scenario_id",
+    "request"
+  ],
+  "type": "object"
+}
+""")
+
+ScenarioLeaseRelease_OUTPUT_SCHEMA = _json.loads(r"""
+{
+  "additionalProperties": false,
+  "properties": {
+    "released": {
+      "description": "Whether an active lease held by the caller was released.",
+      "type": "boolean"
+    }
+  },
+  "required": [
+    "released"
+  ],
+  "type": "object"
+}
+""")
+
+class GeneratedDecisionGateClient:
+    """Generated Decision Gate client methods. Implement `_call_tool`."""
+
+    def _call_tool(self, name: str, arguments: JsonValue) -> JsonValue:
+        raise NotImplementedError("_call_tool must be implemented by subclasses")
+
+    def scenario_define(self, request: ScenarioDefineRequest) -> ScenarioDefineResponse:
+        """
+        Register a ScenarioSpec, validate it, and return the canonical hash used for integrity checks.
+
+        Notes:
+        - Use before starting runs; scenario_id becomes the stable handle for later calls.
+        - Validates stage/gate/condition IDs, RET trees, and condition references.
+        - Spec hash is deterministic; store it for audit and runpack integrity.
+        - Fails closed on invalid specs or duplicate scenario IDs.
+
+        Examples:
+        - Register the example scenario spec.
+          Input:
+            {
+              "spec": {
+                "conditions": [
+                  {
+                    "comparator": "equals",
+                    "condition_id": "env_is_prod",
+                    "expected": "production",
+                    "policy_tags": [],
+                    "query": {
+                      "check_id": "get",
+                      "params": {
+                        "key": "DEPLOY_ENV"
+                      },
+                      "provider_id": "env"
+                    }
+                  },
+                  {
+                    "comparator": "equals",
+                    "condition_id": "after_freeze",
+                    "expected": true,
+                    "policy_tags": [],
+                    "query": {
+                      "check_id": "after",
+                      "params": {
+                        "timestamp": 1710000000000
+                      },
+                      "provider_id": "time"
+                    }
+                  }
+                ],
+                "default_tenant_id": null,
+                "namespace_id": 1,
+                "policies": [],
+                "scenario_id": "example-scenario",
+                "schemas": [],
+                "spec_version": "v1",
+                "stages": [
+                  {
+                    "advance_to": {
+                      "kind": "terminal"
+                    },
+                    "entry_packets": [
+                      {
+                        "content_type": "application/json",
+                        "expiry": null,
+                        "packet_id": "packet-hello",
+                        "payload": {
+                          "kind": "json",
+                          "value": {
+                            "message": "hello",
+                            "purpose": "scenario entry packet"
+                          }
+                        },
+                        "policy_tags": [],
+                        "schema_id": "schema-hello",
+                        "visibility_labels": [
+                          "public"
+                        ]
+                      }
+                    ],
+                    "gates": [
+                      {
+                        "gate_id": "env_gate",
+                        "requirement": {
+                          "Condition": "env_is_prod"
+                        }
+                      },
+                      {
+                        "gate_id": "time_gate",
+                        "requirement": {
+                          "Condition": "after_freeze"
+                        }
+                      }
+                    ],
+                    "on_timeout": "fail",
+                    "stage_id": "main",
+                    "timeout": null
+                  }
+                ]
+              }
+            }
+          Output:
+            {
+              "scenario_id": "example-scenario",
+              "spec_hash": {
+                "algorithm": "sha256",
+                "value": "5c3a5b6bce0f4a2c9e22c4fa6a1e6d8d90b0f2dfed1b7f1e9b3d3b3d1f0c9b21"
+              }
+            }
+        """
+        return cast(ScenarioDefineResponse, self._call_tool("scenario_define", request))
+
+    def scenario_start(self, request: ScenarioStartRequest) -> ScenarioStartResponse:
+        """
+        Create a new run state for a scenario and optionally emit entry packets.
+
+        Notes:
+        - Requires RunConfig (tenant_id, run_id, scenario_id, dispatch_targets).
+        - Use started_at to record the caller-supplied start timestamp.
+        - If issue_entry_packets is true, entry packets are disclosed immediately.
+        - Fails closed if run_id already exists or scenario_id is unknown.
+
+        Examples:
+        - Start a run for the example scenario and issue entry packets.
+          Input:
+            {
+              "issue_entry_packets": true,
+              "run_config": {
+                "dispatch_targets": [
+                  {
+                    "agent_id": "agent-alpha",
+                    "kind": "agent"
+                  }
+                ],
+                "namespace_id": 1,
+                "policy_tags": [],
+                "run_id": "run-0001",
+                "scenario_id": "example-scenario",
+                "tenant_id": 1
+              },
+              "scenario_id": "example-scenario",
+              "started_at": {
+                "kind": "unix_millis",
+                "value": 1710000000000
+              }
+            }
+          Output:
+            {
+              "current_stage_id": "main",
+              "decisions": [],
+              "dispatch_targets": [
+                {
+                  "agent_id": "agent-alpha",
+                  "kind": "agent"
+                }
+              ],
+              "gate_evals": [],
+              "namespace_id": 1,
+              "packets": [],
+              "run_id": "run-0001",
+              "scenario_id": "example-scenario",
+              "spec_hash": {
+                "algorithm": "sha256",
+                "value": "5c3a5b6bce0f4a2c9e22c4fa6a1e6d8d90b0f2dfed1b7f1e9b3d3b3d1f0c9b21"
+              },
+              "stage_entered_at": {
+                "kind": "unix_millis",
+                "value": 1710000000000
+              },
+              "status": "active",
+              "submissions": [],
+              "tenant_id": 1,
+              "tool_calls": [],
+              "triggers": []
+            }
+        """
+        return cast(ScenarioStartResponse, self._call_tool("scenario_start", request))
+
+    def scenario_status(self, request: ScenarioStatusRequest) -> ScenarioStatusResponse:
+        """
+        Fetch a read-only run snapshot and safe summary without changing state.
+
+        Notes:
+        - Use for polling or UI state; does not evaluate gates.
+        - Safe summaries omit evidence values and may include retry hints.
+        - Returns issued packet IDs to help track disclosures.
+        - Set wait_for_change to long-poll until the run changes or max_wait_ms elapses.
+
+        Examples:
+        - Poll run status without advancing the run.
+          Input:
+            {
+              "request": {
+                "correlation_id": null,
+                "namespace_id": 1,
+                "requested_at": {
+                  "kind": "unix_millis",
+                  "value": 1710000000000
+                },
+                "run_id": "run-0001",
+                "tenant_id": 1
+              },
+              "scenario_id": "example-scenario"
+            }
+          Output:
+            {
+              "current_stage_id": "main",
+              "issued_packet_ids": [],
+              "last_decision": null,
+              "run_id": "run-0001",
+              "safe_summary": null,
+              "scenario_id": "example-scenario",
+              "status": "active",
+              "version": 0
+            }
+        - Long-poll run status until it changes or the wait elapses.
+          Input:
+            {
+              "request": {
+                "correlation_id": null,
+                "namespace_id": 1,
+                "requested_at": {
+                  "kind": "unix_millis",
+                  "value": 1710000000000
+                },
+                "run_id": "run-0001",
+                "tenant_id": 1
+              },
+              "scenario_id": "example-scenario",
+              "wait_for_change": {
+                "max_wait_ms": 10000
+              }
+            }
+          Output:
+            {
+              "current_stage_id": "main",
+              "issued_packet_ids": [],
+              "last_decision": null,
+              "run_id": "run-0001",
+              "safe_summary": null,
+              "scenario_id": "example-scenario",
+              "status": "active",
+              "version": 0
+            }
+        """
+        return cast(ScenarioStatusResponse, self._call_tool("scenario_status", request))
+
+    def scenario_next(self, request: ScenarioNextRequest) -> ScenarioNextResponse:
+        """
+        Evaluate gates in response to an agent-driven next request.
+
+        Notes:
+        - Idempotent by trigger_id; repeated calls return the same decision.
+        - Records decision, evidence, and packet disclosures in run state.
+        - Requires an active run; completed or failed runs do not advance.
+        - While the run is leased, only the lease holder may advance it; pass lease_holder.
+        - Optional feedback can include gate trace or evidence when permitted by server feedback policy.
+
+        Examples:
+        - Evaluate the next agent-driven step for a run.
+          Input:
+            {
+              "request": {
+                "agent_id": "agent-alpha",
+                "correlation_id": null,
+                "namespace_id": 1,
+                "run_id": "run-0001",
+                "tenant_id": 1,
+                "time": {
+                  "kind": "unix_millis",
+                  "value": 1710000000000
+                },
+                "trigger_id": "trigger-0001"
+              },
+              "scenario_id": "example-scenario"
+            }
+          Output:
+            {
+              "decision": {
+                "correlation_id": null,
+                "decided_at": {
+                  "kind": "unix_millis",
+                  "value": 1710000000000
+                },
+                "decision_id": "decision-0001",
+                "outcome": {
+                  "kind": "complete",
+                  "stage_id": "main"
+                },
+                "seq": 0,
+                "stage_id": "main",
+                "trigger_id": "trigger-0001"
+              },
+              "packets": [],
+              "status": "completed"
+            }
+        - Evaluate a run and request trace feedback.
+          Input:
+            {
+              "feedback": "trace",
+              "request": {
+                "agent_id": "agent-alpha",
+                "correlation_id": null,
+                "namespace_id": 1,
+                "run_id": "run-0001",
+                "tenant_id": 1,
+                "time": {
+                  "kind": "unix_millis",
+                  "value": 1710000000000
+                },
+                "trigger_id": "trigger-0001"
+              },
+              "scenario_id": "example-scenario"
+            }
+          Output:
+            {
+              "decision": {
+                "correlation_id": null,
+                "decided_at": {
+                  "kind": "unix_millis",
+                  "value": 1710000000000
+                },
+                "decision_id": "decision-0001",
+                "outcome": {
+                  "kind": "complete",
+                  "stage_id": "main"
+                },
+                "seq": 0,
+                "stage_id": "main",
+                "trigger_id": "trigger-0001"
+              },
+              "feedback": {
+                "gate_evaluations": [],
+                "level": "trace"
+              },
+              "packets": [],
+              "status": "completed"
+            }
+        """
+        return cast(ScenarioNextResponse, self._call_tool("scenario_next", request))
+
+    def scenario_submit(self, request: ScenarioSubmitRequest) -> ScenarioSubmitResponse:
+        """
+        Submit external artifacts into run state for audit and later evaluation.
+
+        Notes:
+        - Payload is hashed and stored as a submission record.
+        - Payload is persisted in run state/runpack logs; do not send raw secrets.
+        - Does not advance the run by itself.
+        - Use for artifacts the model or operator supplies.
+        - Set expected_version to the version from scenario_status to reject the submit with a conflict if the run advanced; re-read and retry.
+        - While the run is leased, only the lease holder may submit; pass lease_holder.
+
+        Examples:
+        - Submit an external artifact for audit and later evaluation.
+          Input:
+            {
+              "request": {
+                "content_type": "application/json",
+                "correlation_id": null,
+                "namespace_id": 1,
+                "payload": {
+                  "kind": "json",
+                  "value": {
+                    "artifact": "attestation",
+                    "status": "approved"
+                  }
+                },
+                "run_id": "run-0001",
+                "submission_id": "submission-0001",
+                "submitted_at": {
+                  "kind": "unix_millis",
+                  "value": 1710000000000
+                },
+                "tenant_id": 1
+              },
+              "scenario_id": "example-scenario"
+            }
+          Output:
+            {
+              "record": {
+                "content_hash": {
+                  "algorithm": "sha256",
+                  "value": "5c3a5b6bce0f4a2c9e22c4fa6a1e6d8d90b0f2dfed1b7f1e9b3d3b3d1f0c9b21"
+                },
+                "content_type": "application/json",
+                "correlation_id": null,
+                "payload": {
+                  "kind": "json",
+                  "value": {
+                    "artifact": "attestation",
+                    "status": "approved"
+                  }
+                },
+                "run_id": "run-0001",
+                "submission_id": "submission-0001",
+                "submitted_at": {
+                  "kind": "unix_millis",
+                  "value": 1710000000000
+                }
+              }
+            }
+        """
+        return cast(ScenarioSubmitResponse, self._call_tool("scenario_submit", request))
+
+    def scenario_trigger(self, request: ScenarioTriggerRequest) -> ScenarioTriggerResponse:
+        """
+        Submit a trigger event (scheduler/external) and evaluate the run.
+
+        Notes:
+        - Trigger time is supplied by the caller; no wall-clock reads.
+        - Records the trigger event and resulting decision.
+        - Payload is persisted in run state/runpack logs; do not send raw secrets.
+        - Use for time-based or external system triggers.
+        - While the run is leased, only the lease holder may trigger; pass lease_holder.
+
+        Examples:
+        - Advance a run from a scheduler or external trigger.
+          Input:
+            {
+              "scenario_id": "example-scenario",
+              "trigger": {
+                "correlation_id": null,
+                "kind": "tick",
+                "namespace_id": 1,
+                "payload": null,
+                "run_id": "run-0001",
+                "source_id": "scheduler-01",
+                "tenant_id": 1,
+                "time": {
+                  "kind": "unix_millis",
+                  "value": 1710000000000
+                },
+                "trigger_id": "trigger-0001"
+              }
+            }
+          Output:
+            {
+              "decision": {
+                "correlation_id": null,
+                "decided_at": {
+                  "kind": "unix_millis",
+                  "value": 1710000000000
+                },
+                "decision_id": "decision-0001",
+                "outcome": {
+                  "kind": "complete",
+                  "stage_id": "main"
+                },
+                "seq": 0,
+                "stage_id": "main",
+                "trigger_id": "trigger-0001"
+              },
+              "packets": [],
+              "status": "completed"
+            }
+        """
+        return cast(ScenarioTriggerResponse, self._call_tool("scenario_trigger", request))
+
+    def evidence_query(self, request: EvidenceQueryRequest) -> EvidenceQueryResponse:
+        """
+        Query an evidence provider with full run context and disclosure policy.
+
+        Notes:
+        - Disclosure policy may redact raw values; hashes/anchors still returned.
+        - Use for diagnostics or preflight checks; runtime uses the same provider logic.
+        - Requires provider_id, check_id, and full EvidenceContext.
+
+        Examples:
+        - Query an evidence provider using the run context.
+          Input:
+            {
+              "context": {
+                "correlation_id": null,
+                "namespace_id": 1,
+                "run_id": "run-0001",
+                "scenario_id": "example-scenario",
+                "stage_id": "main",
+                "tenant_id": 1,
+                "trigger_id": "trigger-0001",
+                "trigger_time": {
+                  "kind": "unix_millis",
+                  "value": 1710000000000
+                }
+              },
+              "query": {
+                "check_id": "get",
+                "params": {
+                  "key": "DEPLOY_ENV"
+                },
+                "provider_id": "env"
+              }
+            }
+          Output:
+            {
+              "result": {
+                "content_type": "text/plain",
+                "error": null,
+                "evidence_anchor": {
+                  "anchor_type": "env",
+                  "anchor_value": "DEPLOY_ENV"
+                },
+                "evidence_hash": {
+                  "algorithm": "sha256",
+                  "value": "5c3a5b6bce0f4a2c9e22c4fa6a1e6d8d90b0f2dfed1b7f1e9b3d3b3d1f0c9b21"
+                },
+                "evidence_ref": null,
+                "lane": "verified",
+                "signature": null,
+                "value": {
+                  "kind": "json",
+                  "value": "production"
+                }
+              }
+            }
+        """
+        return cast(EvidenceQueryResponse, self._call_tool("evidence_query", request))
+
+    def runpack_export(self, request: RunpackExportRequest) -> RunpackExportResponse:
+        """
+        Export deterministic runpack artifacts for offline verification.
+
+        Notes:
+        - Writes manifest and logs to output_dir; generated_at is recorded in the manifest.
+        - include_verification adds a verification report artifact.
+        - include_evaluation_audit adds evaluation_audit.json: spec, run config, evidence, and result hashes for every decision and submission.
+        - Export-time report.checked_files excludes verifier_report.json; offline runpack_verify checked_files includes it (+1 for the same runpack).
+        - Use after runs complete or for audit snapshots.
+
+        Examples:
+        - Export a runpack with manifest metadata.
+          Input:
+            {
+              "generated_at": {
+                "kind": "unix_millis",
+                "value": 1710000000000
+              },
+              "include_verification": false,
+              "manifest_name": "manifest.json",
+              "namespace_id": 1,
+              "output_dir": "/var/lib/decision-gate/runpacks/run-0001",
+              "run_id": "run-0001",
+              "scenario_id": "example-scenario",
+              "tenant_id": 1
+            }
+          Output:
+            {
+              "manifest": {
+                "artifacts": [
+                  {
+                    "artifact_id": "decision_log",
+                    "content_type": "application/json",
+                    "hash": {
+                      "algorithm": "sha256",
+                      "value": "5c3a5b6bce0f4a2c9e22c4fa6a1e6d8d90b0f2dfed1b7f1e9b3d3b3d1f0c9b21"
+                    },
+                    "kind": "decision_log",
+                    "path": "decision_log.json",
+                    "required": true
+                  }
+                ],
+                "generated_at": {
+                  "kind": "unix_millis",
+                  "value": 1710000000000
+                },
+                "hash_algorithm": "sha256",
+                "integrity": {
+                  "file_hashes": [
+                    {
+                      "hash": {
+                        "algorithm": "sha256",
+                        "value": "5c3a5b6bce0f4a2c9e22c4fa6a1e6d8d90b0f2dfed1b7f1e9b3d3b3d1f0c9b21"
+                      },
+                      "path": "decision_log.json"
+                    }
+                  ],
+                  "root_hash": {
+                    "algorithm": "sha256",
+                    "value": "5c3a5b6bce0f4a2c9e22c4fa6a1e6d8d90b0f2dfed1b7f1e9b3d3b3d1f0c9b21"
+                  }
+                },
+                "manifest_version": "v1",
+                "namespace_id": 1,
+                "run_id": "run-0001",
+                "scenario_id": "example-scenario",
+                "spec_hash": {
+                  "algorithm": "sha256",
+                  "value": "5c3a5b6bce0f4a2c9e22c4fa6a1e6d8d90b0f2dfed1b7f1e9b3d3b3d1f0c9b21"
+                },
+                "tenant_id": 1,
+                "verifier_mode": "offline_strict"
+              },
+              "report": null,
+              "storage_uri": null
+            }
+        """
+        return cast(RunpackExportResponse, self._call_tool("runpack_export", request))
+
+    def runpack_verify(self, request: RunpackVerifyRequest) -> RunpackVerifyResponse:
+        """
+        Verify a runpack manifest and artifacts offline.
+
+        Notes:
+        - Validates hashes, integrity root, and decision log structure.
+        - Fails closed on missing or tampered files.
+        - Use in CI or offline audit pipelines.
+
+        Examples:
+        - Verify a runpack manifest and artifacts offline.
+          Input:
+            {
+              "manifest_path": "manifest.json",
+              "runpack_dir": "/var/lib/decision-gate/runpacks/run-0001"
+            }
+          Output:
+            {
+              "report": {
+                "checked_files": 12,
+                "errors": [],
+                "status": "pass"
+              },
+              "status": "pass"
+            }
+        """
+        return cast(RunpackVerifyResponse, self._call_tool("runpack_verify", request))
+
+    def providers_list(self, request: ProvidersListRequest) -> ProvidersListResponse:
+        """
+        List registered evidence providers and capabilities summary.
+
+        Notes:
+        - Returns provider identifiers and transport metadata.
+        - Results are scoped by auth policy.
+
+        Examples:
+        - List registered evidence providers.
+          Input:
+            {}
+          Output:
+            {
+              "providers": [
+                {
+                  "checks": [
+                    "get"
+                  ],
+                  "provider_id": "env",
+                  "transport": "builtin"
+                }
+              ]
+            }
+        """
+        return cast(ProvidersListResponse, self._call_tool("providers_list", request))
+
+    def provider_contract_get(self, request: ProviderContractGetRequest) -> ProviderContractGetResponse:
+        """
+        Fetch the canonical provider contract JSON and hash for a provider.
+
+        Notes:
+        - Returns the provider contract as loaded by the MCP server.
+        - Includes a canonical hash for audit and reproducibility.
+        - Subject to provider disclosure policy and authz.
+
+        Examples:
+        - Fetch the contract JSON for a provider.
+          Input:
+            {
+              "provider_id": "json"
+            }
+          Output:
+            {
+              "contract": {
+                "checks": [],
+                "config_schema": {
+                  "additionalProperties": false,
+                  "type": "object"
+                },
+                "description": "Reads JSON or YAML files and evaluates JSONPath.",
+                "name": "JSON Provider",
+                "notes": [],
+                "provider_id": "json",
+                "transport": "builtin"
+              },
+              "contract_hash": {
+                "algorithm": "sha256",
+                "value": "5c3a5b6bce0f4a2c9e22c4fa6a1e6d8d90b0f2dfed1b7f1e9b3d3b3d1f0c9b21"
+              },
+              "provider_id": "json",
+              "source": "builtin",
+              "version": null
+            }
+        """
+        return cast(ProviderContractGetResponse, self._call_tool("provider_contract_get", request))
+
+    def provider_check_schema_get(self, request: ProviderCheckSchemaGetRequest) -> ProviderCheckSchemaGetResponse:
+        """
+        Fetch check schema details (params/result/comparators) for a provider.
+
+        Notes:
+        - Returns compiled schema metadata for a single check.
+        - Includes comparator allow-lists and check examples.
+        - Subject to provider disclosure policy and authz.
+
+        Examples:
+        - Fetch check schema details for a provider.
+          Input:
+            {
+              "check_id": "path",
+              "provider_id": "json"
+            }
+          Output:
+            {
+              "allowed_comparators": [
+                "equals",
+                "in_set",
+                "exists",
+                "not_exists"
+              ],
+              "anchor_types": [],
+              "check_id": "path",
+              "content_types": [
+                "application/json"
+              ],
+              "contract_hash": {
+                "algorithm": "sha256",
+                "value": "5c3a5b6bce0f4a2c9e22c4fa6a1e6d8d90b0f2dfed1b7f1e9b3d3b3d1f0c9b21"
+              },
+              "determinism": "external",
+              "examples": [],
+              "params_required": true,
+              "params_schema": {
+                "properties": {
+                  "file": {
+                    "type": "string"
+                  },
+                  "jsonpath": {
+                    "type": "string"
+                  }
+                },
+                "required": [
+                  "file"
+                ],
+                "type": "object"
+              },
+              "provider_id": "json",
+              "result_schema": {
+                "type": [
+                  "null",
+                  "string",
+                  "number",
+                  "boolean",
+                  "array",
+                  "object"
+                ]
+              }
+            }
+        """
+        return cast(ProviderCheckSchemaGetResponse, self._call_tool("provider_check_schema_get", request))
+
+    def schemas_register(self, request: SchemasRegisterRequest) -> SchemasRegisterResponse:
+        """
+        Register a data shape schema for a tenant and namespace.
+
+        Notes:
+        - Schemas are immutable; registering the same version twice fails.
+        - Provide created_at to record when the schema was authored.
+
+        Examples:
+        - Register a data shape schema.
+          Input:
+            {
+              "record": {
+                "created_at": {
+                  "kind": "unix_millis",
+                  "value": 1710000000000
+                },
+                "description": "Asserted payload schema.",
+                "namespace_id": 1,
+                "schema": {
+                  "additionalProperties": false,
+                  "properties": {
+                    "deploy_env": {
+                      "type": "string"
+                    }
+                  },
+                  "required": [
+                    "deploy_env"
+                  ],
+                  "type": "object"
+                },
+                "schema_id": "asserted_payload",
+                "tenant_id": 1,
+                "version": "v1"
+              }
+            }
+          Output:
+            {
+              "record": {
+                "created_at": {
+                  "kind": "unix_millis",
+                  "value": 1710000000000
+                },
+                "description": "Asserted payload schema.",
+                "namespace_id": 1,
+                "schema": {
+                  "additionalProperties": false,
+                  "properties": {
+                    "deploy_env": {
+                      "type": "string"
+                    }
+                  },
+                  "required": [
+                    "deploy_env"
+                  ],
+                  "type": "object"
+                },
+                "schema_id": "asserted_payload",
+                "tenant_id": 1,
+                "version": "v1"
+              }
+            }
+        """
+        return cast(SchemasRegisterResponse, self._call_tool("schemas_register", request))
+
+    def schemas_list(self, request: SchemasListRequest) -> SchemasListResponse:
+        """
+        List registered data shapes for a tenant and namespace.
+
+        Notes:
+        - Requires tenant_id and namespace_id.
+        - Supports pagination via cursor + limit.
+
+        Examples:
+        - List data shapes for a namespace.
+          Input:
+            {
+              "cursor": null,
+              "limit": 50,
+              "namespace_id": 1,
+              "tenant_id": 1
+            }
+          Output:
+            {
+              "items": [
+                {
+                  "created_at": {
+                    "kind": "unix_millis",
+                    "value": 1710000000000
+                  },
+                  "description": "Asserted payload schema.",
+                  "namespace_id": 1,
+                  "schema": {
+                    "additionalProperties": false,
+                    "properties": {
+                      "deploy_env": {
+                        "type": "string"
+                      }
+                    },
+                    "required": [
+                      "deploy_env"
+                    ],
+                    "type": "object"
+                  },
+                  "schema_id": "asserted_payload",
+                  "tenant_id": 1,
+                  "version": "v1"
+                }
+              ],
+              "next_token": null
+            }
+        """
+        return cast(SchemasListResponse, self._call_tool("schemas_list", request))
+
+    def schemas_get(self, request: SchemasGetRequest) -> SchemasGetResponse:
+        """
+        Fetch a specific data shape by identifier and version.
+
+        Notes:
+        - Requires tenant_id, namespace_id, schema_id, and version.
+        - Fails closed when schema is missing.
+
+        Examples:
+        - Fetch a data shape by identifier and version.
+          Input:
+            {
+              "namespace_id": 1,
+              "schema_id": "asserted_payload",
+              "tenant_id": 1,
+              "version": "v1"
+            }
+          Output:
+            {
+              "record": {
+                "created_at": {
+                  "kind": "unix_millis",
+                  "value": 1710000000000
+                },
+                "description": "Asserted payload schema.",
+                "namespace_id": 1,
+                "schema": {
+                  "additionalProperties": false,
+                  "properties": {
+                    "deploy_env": {
+                      "type": "string"
+                    }
+                  },
+                  "required": [
+                    "deploy_env"
+                  ],
+                  "type": "object"
+                },
+                "schema_id": "asserted_payload",
+                "tenant_id": 1,
+                "version": "v1"
+              }
+            }
+        """
+        return cast(SchemasGetResponse, self._call_tool("schemas_get", request))
+
+    def scenarios_list(self, request: ScenariosListRequest) -> ScenariosListResponse:
+        """
+        List registered scenarios for a tenant and namespace.
+
+        Notes:
+        - Requires tenant_id and namespace_id.
+        - Returns scenario identifiers and hashes.
+
+        Examples:
+        - List scenarios for a namespace.
+          Input:
+            {
+              "cursor": null,
+              "limit": 50,
+              "namespace_id": 1,
+              "tenant_id": 1
+            }
+          Output:
+            {
+              "items": [
+                {
+                  "namespace_id": 1,
+                  "scenario_id": "example-scenario",
+                  "spec_hash": {
+                    "algorithm": "sha256",
+                    "value": "5c3a5b6bce0f4a2c9e22c4fa6a1e6d8d90b0f2dfed1b7f1e9b3d3b3d1f0c9b21"
+                  }
+                }
+              ],
+              "next_token": null
+            }
+        """
+        return cast(ScenariosListResponse, self._call_tool("scenarios_list", request))
+
+    def precheck(self, request: PrecheckRequest) -> PrecheckResponse:
+        """
+        Evaluate a scenario against asserted data without mutating state.
+
+        Notes:
+        - Validates asserted data against a registered shape.
+        - Does not mutate run state; intended for simulation.
+
+        Examples:
+        - Precheck a scenario with asserted data.
+          Input:
+            {
+              "data_shape": {
+                "schema_id": "asserted_payload",
+                "version": "v1"
+              },
+              "namespace_id": 1,
+              "payload": {
+                "deploy_env": "production"
+              },
+              "scenario_id": "example-scenario",
+              "spec": null,
+              "stage_id": null,
+              "tenant_id": 1
+            }
+          Output:
+            {
+              "decision": {
+                "kind": "hold",
+                "summary": {
+                  "policy_tags": [],
+                  "retry_hint": "await_evidence",
+                  "status": "hold",
+                  "unmet_gates": [
+                    "ready"
+                  ]
+                }
+              },
+              "gate_evaluations": []
+            }
+        """
+        return cast(PrecheckResponse, self._call_tool("precheck", request))
+
+    def decision_gate_docs_search(self, request: DecisionGateDocsSearchRequest) -> DecisionGateDocsSearchResponse:
+        """
+        Search Decision Gate documentation for runtime guidance.
+
+        Notes:
+        - Use for quick lookups on evidence flow, comparators, and provider semantics.
+        - Returns ranked sections with role tags and suggested follow-ups.
+        - Search is deterministic and scoped to the configured doc catalog.
+
+        Examples:
+        - Search for evidence flow and trust lane guidance.
+          Input:
+            {
+              "max_sections": 2,
+              "query": "precheck vs live run trust lanes"
+            }
+          Output:
+            {
+              "docs_covered": [
+                {
+                  "doc_id": "evidence_flow_and_execution_model",
+                  "doc_role": "reasoning",
+                  "doc_title": "Evidence Flow + Execution Model"
+                }
+              ],
+              "sections": [
+                {
+                  "content": "...",
+                  "doc_id": "evidence_flow_and_execution_model",
+                  "doc_role": "reasoning",
+                  "doc_title": "Evidence Flow + Execution Model",
+                  "heading": "Core Data Flow",
+                  "rank": 0
+                }
+              ],
+              "suggested_followups": [
+                "Refine the query with comparator or provider keywords for targeted guidance."
+              ]
+            }
+        """
+        return cast(DecisionGateDocsSearchResponse, self._call_tool("decision_gate_docs_search", request))
+
+    def scenarios_status_bulk(self, request: ScenariosStatusBulkRequest) -> ScenariosStatusBulkResponse:
+        """
+        Fetch read-only status snapshots for several runs in one call.
+
+        Notes:
+        - Requires tenant_id and namespace_id; every run is resolved within that scope.
+        - Accepts up to 100 runs; results are returned in request order.
+        - Unknown scenarios or runs yield found=false without failing the call.
+        - Reads do not record tool calls or modify run state.
+
+        Examples:
+        - Fetch status for a known run and a missing run.
+          Input:
+            {
+              "namespace_id": 1,
+              "runs": [
+                {
+                  "run_id": "run-0001",
+                  "scenario_id": "example-scenario"
+                },
+                {
+                  "run_id": "run-missing",
+                  "scenario_id": "example-scenario"
+                }
+              ],
+              "tenant_id": 1
+            }
+          Output:
+            {
+              "results": [
+                {
+                  "found": true,
+                  "run_id": "run-0001",
+                  "scenario_id": "example-scenario",
+                  "status": {
+                    "current_stage_id": "main",
+                    "issued_packet_ids": [],
+                    "last_decision": null,
+                    "run_id": "run-0001",
+                    "safe_summary": null,
+                    "scenario_id": "example-scenario",
+                    "status": "active",
+                    "version": 0
+                  }
+                },
+                {
+                  "found": false,
+                  "run_id": "run-missing",
+                  "scenario_id": "example-scenario",
+                  "status": null
+                }
+              ]
+            }
+        """
+        return cast(ScenariosStatusBulkResponse, self._call_tool("scenarios_status_bulk", request))
+
+    def scenario_lease_acquire(self, request: ScenarioLeaseAcquireRequest) -> ScenarioLeaseAcquireResponse:
+        """
+        Acquire or renew an advisory lease so only one holder can advance, submit to, or trigger a run.
+
+        Notes:
+        - While the lease is active, scenario_next, scenario_submit, and scenario_trigger calls without the matching lease_holder fail with a conflict.
+        - The lease expires ttl_ms after the server receives the request; requested_at is recorded but never used to judge expiry. The holder renews by acquiring again.
+        - Fails with a conflict while another holder's lease is active.
+        - Leases live in server memory and are lost on restart.
+
+        Examples:
+        - Lease a run for 30 seconds before a series of submits.
+          Input:
+            {
+              "request": {
+                "holder_id": "agent-alpha",
+                "namespace_id": 1,
+                "requested_at": {
+                  "kind": "unix_millis",
+                  "value": 1710000000000
+                },
+                "run_id": "run-0001",
+                "tenant_id": 1,
+                "ttl_ms": 30000
+              },
+              "scenario_id": "example-scenario"
+            }
+          Output:
+            {
+              "acquired_at": {
+                "kind": "unix_millis",
+                "value": 1710000000000
+              },
+              "expires_at": {
+                "kind": "unix_millis",
+                "value": 1710000030000
+              },
+              "holder_id": "agent-alpha",
+              "run_id": "run-0001"
+            }
+        """
+        return cast(ScenarioLeaseAcquireResponse, self._call_tool("scenario_lease_acquire", request))
+
+    def scenario_lease_release(self, request: ScenarioLeaseReleaseRequest) -> ScenarioLeaseReleaseResponse:
+        """
+        Release an advisory run lease before it expires.
+
+        Notes:
+        - Returns released=false when the caller held no active lease.
+        - Fails with a conflict while another holder's lease is active.
+
+        Examples:
+        - Release a run lease once the holder is done.
+          Input:
+            {
+              "request": {
+                "holder_id": "agent-alpha",
+                "namespace_id": 1,
+                "requested_at": {
+                  "kind": "unix_millis",
+                  "value": 1710000000000
+                },
+                "run_id": "run-0001",
+                "tenant_id": 1
+              },
+              "scenario_id": "example-scenario"
+            }
+          Output:
+            {
+              "released": true
+            }
+        """
+        return cast(ScenarioLeaseReleaseResponse, self._call_tool("scenario_lease_release", request))
+
+class AsyncGeneratedDecisionGateClient:
+    """Generated asyncio Decision Gate client methods. Implement `_call_tool`."""
+
+    async def _call_tool(self, name: str, arguments: JsonValue) -> JsonValue:
+        raise NotImplementedError("_call_tool must be implemented by subclasses")
+
+    async def scenario_define(self, request: ScenarioDefineRequest) -> ScenarioDefineResponse:
+        """
+        Register a ScenarioSpec, validate it, and return the canonical hash used for integrity checks.
+
+        Notes:
+        - Use before starting runs; scenario_id becomes the stable handle for later calls.
+        - Validates stage/gate/condition IDs, RET trees, and condition references.
+        - Spec hash is deterministic; store it for audit and runpack integrity.
+        - Fails closed on invalid specs or duplicate scenario IDs.
+
+        Examples:
+        - Register the example scenario spec.
+          Input:
+            {
+              "spec": {
+                "conditions": [
+                  {
+                    "comparator": "equals",
+                    "condition_id": "env_is_prod",
+                    "expected": "production",
+                    "policy_tags": [],
+                    "query": {
+                      "check_id": "get",
+                      "params": {
+                        "key": "DEPLOY_ENV"
+                      },
+                      "provider_id": "env"
+                    }
+                  },
+                  {
+                    "comparator": "equals",
+                    "condition_id": "after_freeze",
+                    "expected": true,
+                    "policy_tags": [],
+                    "query": {
+                      "check_id": "after",
+                      "params": {
+                        "timestamp": 1710000000000
+                      },
+                      "provider_id": "time"
+                    }
+                  }
+                ],
+                "default_tenant_id": null,
+                "namespace_id": 1,
+                "policies": [],
+                "scenario_id": "example-scenario",
+                "schemas": [],
+                "spec_version": "v1",
+                "stages": [
+                  {
+                    "advance_to": {
+                      "kind": "terminal"
+                    },
+                    "entry_packets": [
+                      {
+                        "content_type": "application/json",
+                        "expiry": null,
+                        "packet_id": "packet-hello",
+                        "payload": {
+                          "kind": "json",
+                          "value": {
+                            "message": "hello",
+                            "purpose": "scenario entry packet"
+                          }
+                        },
+                        "policy_tags": [],
+                        "schema_id": "schema-hello",
+                        "visibility_labels": [
+                          "public"
+                        ]
+                      }
+                    ],
                     "gates": [
                       {
                         "gate_id": "env_gate",
@@ -8242,9 +10347,9 @@ class GeneratedDecisionGateClient:
               }
             }
         """
-        return cast(ScenarioDefineResponse, self._call_tool("scenario_define", request))
+        return cast(ScenarioDefineResponse, await self._call_tool("scenario_define", request))
 
-    def scenario_start(self, request: ScenarioStartRequest) -> ScenarioStartResponse:
+    async def scenario_start(self, request: ScenarioStartRequest) -> ScenarioStartResponse:
         """
         Create a new run state for a scenario and optionally emit entry packets.
 
@@ -8308,9 +10413,9 @@ class GeneratedDecisionGateClient:
               "triggers": []
             }
         """
-        return cast(ScenarioStartResponse, self._call_tool("scenario_start", request))
+        return cast(ScenarioStartResponse, await self._call_tool("scenario_start", request))
 
-    def scenario_status(self, request: ScenarioStatusRequest) -> ScenarioStatusResponse:
+    async def scenario_status(self, request: ScenarioStatusRequest) -> ScenarioStatusResponse:
         """
         Fetch a read-only run snapshot and safe summary without changing state.
 
@@ -8318,6 +10423,7 @@ class GeneratedDecisionGateClient:
         - Use for polling or UI state; does not evaluate gates.
         - Safe summaries omit evidence values and may include retry hints.
         - Returns issued packet IDs to help track disclosures.
+        - Set wait_for_change to long-poll until the run changes or max_wait_ms elapses.
 
         Examples:
         - Poll run status without advancing the run.
@@ -8343,12 +10449,42 @@ class GeneratedDecisionGateClient:
               "run_id": "run-0001",
               "safe_summary": null,
               "scenario_id": "example-scenario",
-              "status": "active"
+              "status": "active",
+              "version": 0
+            }
+        - Long-poll run status until it changes or the wait elapses.
+          Input:
+            {
+              "request": {
+                "correlation_id": null,
+                "namespace_id": 1,
+                "requested_at": {
+                  "kind": "unix_millis",
+                  "value": 1710000000000
+                },
+                "run_id": "run-0001",
+                "tenant_id": 1
+              },
+              "scenario_id": "example-scenario",
+              "wait_for_change": {
+                "max_wait_ms": 10000
+              }
+            }
+          Output:
+            {
+              "current_stage_id": "main",
+              "issued_packet_ids": [],
+              "last_decision": null,
+              "run_id": "run-0001",
+              "safe_summary": null,
+              "scenario_id": "example-scenario",
+              "status": "active",
+              "version": 0
             }
         """
-        return cast(ScenarioStatusResponse, self._call_tool("scenario_status", request))
+        return cast(ScenarioStatusResponse, await self._call_tool("scenario_status", request))
 
-    def scenario_next(self, request: ScenarioNextRequest) -> ScenarioNextResponse:
+    async def scenario_next(self, request: ScenarioNextRequest) -> ScenarioNextResponse:
         """
         Evaluate gates in response to an agent-driven next request.
 
@@ -8356,6 +10492,7 @@ class GeneratedDecisionGateClient:
         - Idempotent by trigger_id; repeated calls return the same decision.
         - Records decision, evidence, and packet disclosures in run state.
         - Requires an active run; completed or failed runs do not advance.
+        - While the run is leased, only the lease holder may advance it; pass lease_holder.
         - Optional feedback can include gate trace or evidence when permitted by server feedback policy.
 
         Examples:
@@ -8439,16 +10576,19 @@ class GeneratedDecisionGateClient:
               "status": "completed"
             }
         """
-        return cast(ScenarioNextResponse, self._call_tool("scenario_next", request))
+        return cast(ScenarioNextResponse, await self._call_tool("scenario_next", request))
 
-    def scenario_submit(self, request: ScenarioSubmitRequest) -> ScenarioSubmitResponse:
+    async def scenario_submit(self, request: ScenarioSubmitRequest) -> ScenarioSubmitResponse:
         """
         Submit external artifacts into run state for audit and later evaluation.
 
         Notes:
         - Payload is hashed and stored as a submission record.
+        - Payload is persisted in run state/runpack logs; do not send raw secrets.
         - Does not advance the run by itself.
         - Use for artifacts the model or operator supplies.
+        - Set expected_version to the version from scenario_status to reject the submit with a conflict if the run advanced; re-read and retry.
+        - While the run is leased, only the lease holder may submit; pass lease_holder.
 
         Examples:
         - Submit an external artifact for audit and later evaluation.
@@ -8500,16 +10640,18 @@ class GeneratedDecisionGateClient:
               }
             }
         """
-        return cast(ScenarioSubmitResponse, self._call_tool("scenario_submit", request))
+        return cast(ScenarioSubmitResponse, await self._call_tool("scenario_submit", request))
 
-    def scenario_trigger(self, request: ScenarioTriggerRequest) -> ScenarioTriggerResponse:
+    async def scenario_trigger(self, request: ScenarioTriggerRequest) -> ScenarioTriggerResponse:
         """
         Submit a trigger event (scheduler/external) and evaluate the run.
 
         Notes:
         - Trigger time is supplied by the caller; no wall-clock reads.
         - Records the trigger event and resulting decision.
+        - Payload is persisted in run state/runpack logs; do not send raw secrets.
         - Use for time-based or external system triggers.
+        - While the run is leased, only the lease holder may trigger; pass lease_holder.
 
         Examples:
         - Advance a run from a scheduler or external trigger.
@@ -8552,9 +10694,9 @@ class GeneratedDecisionGateClient:
               "status": "completed"
             }
         """
-        return cast(ScenarioTriggerResponse, self._call_tool("scenario_trigger", request))
+        return cast(ScenarioTriggerResponse, await self._call_tool("scenario_trigger", request))
 
-    def evidence_query(self, request: EvidenceQueryRequest) -> EvidenceQueryResponse:
+    async def evidence_query(self, request: EvidenceQueryRequest) -> EvidenceQueryResponse:
         """
         Query an evidence provider with full run context and disclosure policy.
 
@@ -8611,15 +10753,17 @@ class GeneratedDecisionGateClient:
               }
             }
         """
-        return cast(EvidenceQueryResponse, self._call_tool("evidence_query", request))
+        return cast(EvidenceQueryResponse, await self._call_tool("evidence_query", request))
 
-    def runpack_export(self, request: RunpackExportRequest) -> RunpackExportResponse:
+    async def runpack_export(self, request: RunpackExportRequest) -> RunpackExportResponse:
         """
         Export deterministic runpack artifacts for offline verification.
 
         Notes:
         - Writes manifest and logs to output_dir; generated_at is recorded in the manifest.
         - include_verification adds a verification report artifact.
+        - include_evaluation_audit adds evaluation_audit.json: spec, run config, evidence, and result hashes for every decision and submission.
+        - Export-time report.checked_files excludes verifier_report.json; offline runpack_verify checked_files includes it (+1 for the same runpack).
         - Use after runs complete or for audit snapshots.
 
         Examples:
@@ -8689,9 +10833,9 @@ class GeneratedDecisionGateClient:
               "storage_uri": null
             }
         """
-        return cast(RunpackExportResponse, self._call_tool("runpack_export", request))
+        return cast(RunpackExportResponse, await self._call_tool("runpack_export", request))
 
-    def runpack_verify(self, request: RunpackVerifyRequest) -> RunpackVerifyResponse:
+    async def runpack_verify(self, request: RunpackVerifyRequest) -> RunpackVerifyResponse:
         """
         Verify a runpack manifest and artifacts offline.
 
@@ -8717,9 +10861,9 @@ class GeneratedDecisionGateClient:
               "status": "pass"
             }
         """
-        return cast(RunpackVerifyResponse, self._call_tool("runpack_verify", request))
+        return cast(RunpackVerifyResponse, await self._call_tool("runpack_verify", request))
 
-    def providers_list(self, request: ProvidersListRequest) -> ProvidersListResponse:
+    async def providers_list(self, request: ProvidersListRequest) -> ProvidersListResponse:
         """
         List registered evidence providers and capabilities summary.
 
@@ -8744,9 +10888,9 @@ class GeneratedDecisionGateClient:
               ]
             }
         """
-        return cast(ProvidersListResponse, self._call_tool("providers_list", request))
+        return cast(ProvidersListResponse, await self._call_tool("providers_list", request))
 
-    def provider_contract_get(self, request: ProviderContractGetRequest) -> ProviderContractGetResponse:
+    async def provider_contract_get(self, request: ProviderContractGetRequest) -> ProviderContractGetResponse:
         """
         Fetch the canonical provider contract JSON and hash for a provider.
 
@@ -8784,9 +10928,9 @@ class GeneratedDecisionGateClient:
               "version": null
             }
         """
-        return cast(ProviderContractGetResponse, self._call_tool("provider_contract_get", request))
+        return cast(ProviderContractGetResponse, await self._call_tool("provider_contract_get", request))
 
-    def provider_check_schema_get(self, request: ProviderCheckSchemaGetRequest) -> ProviderCheckSchemaGetResponse:
+    async def provider_check_schema_get(self, request: ProviderCheckSchemaGetRequest) -> ProviderCheckSchemaGetResponse:
         """
         Fetch check schema details (params/result/comparators) for a provider.
 
@@ -8849,9 +10993,9 @@ class GeneratedDecisionGateClient:
               }
             }
         """
-        return cast(ProviderCheckSchemaGetResponse, self._call_tool("provider_check_schema_get", request))
+        return cast(ProviderCheckSchemaGetResponse, await self._call_tool("provider_check_schema_get", request))
 
-    def schemas_register(self, request: SchemasRegisterRequest) -> SchemasRegisterResponse:
+    async def schemas_register(self, request: SchemasRegisterRequest) -> SchemasRegisterResponse:
         """
         Register a data shape schema for a tenant and namespace.
 
@@ -8914,9 +11058,9 @@ class GeneratedDecisionGateClient:
               }
             }
         """
-        return cast(SchemasRegisterResponse, self._call_tool("schemas_register", request))
+        return cast(SchemasRegisterResponse, await self._call_tool("schemas_register", request))
 
-    def schemas_list(self, request: SchemasListRequest) -> SchemasListResponse:
+    async def schemas_list(self, request: SchemasListRequest) -> SchemasListResponse:
         """
         List registered data shapes for a tenant and namespace.
 
@@ -8963,9 +11107,9 @@ class GeneratedDecisionGateClient:
               "next_token": null
             }
         """
-        return cast(SchemasListResponse, self._call_tool("schemas_list", request))
+        return cast(SchemasListResponse, await self._call_tool("schemas_list", request))
 
-    def schemas_get(self, request: SchemasGetRequest) -> SchemasGetResponse:
+    async def schemas_get(self, request: SchemasGetRequest) -> SchemasGetResponse:
         """
         Fetch a specific data shape by identifier and version.
 
@@ -9009,9 +11153,9 @@ class GeneratedDecisionGateClient:
               }
             }
         """
-        return cast(SchemasGetResponse, self._call_tool("schemas_get", request))
+        return cast(SchemasGetResponse, await self._call_tool("schemas_get", request))
 
-    def scenarios_list(self, request: ScenariosListRequest) -> ScenariosListResponse:
+    async def scenarios_list(self, request: ScenariosListRequest) -> ScenariosListResponse:
         """
         List registered scenarios for a tenant and namespace.
 
@@ -9043,9 +11187,9 @@ class GeneratedDecisionGateClient:
               "next_token": null
             }
         """
-        return cast(ScenariosListResponse, self._call_tool("scenarios_list", request))
+        return cast(ScenariosListResponse, await self._call_tool("scenarios_list", request))
 
-    def precheck(self, request: PrecheckRequest) -> PrecheckResponse:
+    async def precheck(self, request: PrecheckRequest) -> PrecheckResponse:
         """
         Evaluate a scenario against asserted data without mutating state.
 
@@ -9086,9 +11230,9 @@ class GeneratedDecisionGateClient:
               "gate_evaluations": []
             }
         """
-        return cast(PrecheckResponse, self._call_tool("precheck", request))
+        return cast(PrecheckResponse, await self._call_tool("precheck", request))
 
-    def decision_gate_docs_search(self, request: DecisionGateDocsSearchRequest) -> DecisionGateDocsSearchResponse:
+    async def decision_gate_docs_search(self, request: DecisionGateDocsSearchRequest) -> DecisionGateDocsSearchResponse:
         """
         Search Decision Gate documentation for runtime guidance.
 
@@ -9128,7 +11272,137 @@ class GeneratedDecisionGateClient:
               ]
             }
         """
-        return cast(DecisionGateDocsSearchResponse, self._call_tool("decision_gate_docs_search", request))
+        return cast(DecisionGateDocsSearchResponse, await self._call_tool("decision_gate_docs_search", request))
+
+    async def scenarios_status_bulk(self, request: ScenariosStatusBulkRequest) -> ScenariosStatusBulkResponse:
+        """
+        Fetch read-only status snapshots for several runs in one call.
+
+        Notes:
+        - Requires tenant_id and namespace_id; every run is resolved within that scope.
+        - Accepts up to 100 runs; results are returned in request order.
+        - Unknown scenarios or runs yield found=false without failing the call.
+        - Reads do not record tool calls or modify run state.
+
+        Examples:
+        - Fetch status for a known run and a missing run.
+          Input:
+            {
+              "namespace_id": 1,
+              "runs": [
+                {
+                  "run_id": "run-0001",
+                  "scenario_id": "example-scenario"
+                },
+                {
+                  "run_id": "run-missing",
+                  "scenario_id": "example-scenario"
+                }
+              ],
+              "tenant_id": 1
+            }
+          Output:
+            {
+              "results": [
+                {
+                  "found": true,
+                  "run_id": "run-0001",
+                  "scenario_id": "example-scenario",
+                  "status": {
+                    "current_stage_id": "main",
+                    "issued_packet_ids": [],
+                    "last_decision": null,
+                    "run_id": "run-0001",
+                    "safe_summary": null,
+                    "scenario_id": "example-scenario",
+                    "status": "active",
+                    "version": 0
+                  }
+                },
+                {
+                  "found": false,
+                  "run_id": "run-missing",
+                  "scenario_id": "example-scenario",
+                  "status": null
+                }
+              ]
+            }
+        """
+        return cast(ScenariosStatusBulkResponse, await self._call_tool("scenarios_status_bulk", request))
+
+    async def scenario_lease_acquire(self, request: ScenarioLeaseAcquireRequest) -> ScenarioLeaseAcquireResponse:
+        """
+        Acquire or renew an advisory lease so only one holder can advance, submit to, or trigger a run.
+
+        Notes:
+        - While the lease is active, scenario_next, scenario_submit, and scenario_trigger calls without the matching lease_holder fail with a conflict.
+        - The lease expires ttl_ms after the server receives the request; requested_at is recorded but never used to judge expiry. The holder renews by acquiring again.
+        - Fails with a conflict while another holder's lease is active.
+        - Leases live in server memory and are lost on restart.
+
+        Examples:
+        - Lease a run for 30 seconds before a series of submits.
+          Input:
+            {
+              "request": {
+                "holder_id": "agent-alpha",
+                "namespace_id": 1,
+                "requested_at": {
+                  "kind": "unix_millis",
+                  "value": 1710000000000
+                },
+                "run_id": "run-0001",
+                "tenant_id": 1,
+                "ttl_ms": 30000
+              },
+              "scenario_id": "example-scenario"
+            }
+          Output:
+            {
+              "acquired_at": {
+                "kind": "unix_millis",
+                "value": 1710000000000
+              },
+              "expires_at": {
+                "kind": "unix_millis",
+                "value": 1710000030000
+              },
+              "holder_id": "agent-alpha",
+              "run_id": "run-0001"
+            }
+        """
+        return cast(ScenarioLeaseAcquireResponse, await self._call_tool("scenario_lease_acquire", request))
+
+    async def scenario_lease_release(self, request: ScenarioLeaseReleaseRequest) -> ScenarioLeaseReleaseResponse:
+        """
+        Release an advisory run lease before it expires.
+
+        Notes:
+        - Returns released=false when the caller held no active lease.
+        - Fails with a conflict while another holder's lease is active.
+
+        Examples:
+        - Release a run lease once the holder is done.
+          Input:
+            {
+              "request": {
+                "holder_id": "agent-alpha",
+                "namespace_id": 1,
+                "requested_at": {
+                  "kind": "unix_millis",
+                  "value": 1710000000000
+                },
+                "run_id": "run-0001",
+                "tenant_id": 1
+              },
+              "scenario_id": "example-scenario"
+            }
+          Output:
+            {
+              "released": true
+            }
+        """
+        return cast(ScenarioLeaseReleaseResponse, await self._call_tool("scenario_lease_release", request))
 
 class SchemaValidationError(ValueError):
     """Raised when payloads fail schema validation."""
@@ -9295,6 +11569,30 @@ def validate_decision_gate_docs_search_response(response: DecisionGateDocsSearch
     """Validate the response payload against the output schema."""
     validate_schema(response, DecisionGateDocsSearch_OUTPUT_SCHEMA)
 
+def validate_scenarios_status_bulk_request(request: ScenariosStatusBulkRequest) -> None:
+    """Validate the request payload against the input schema."""
+    validate_schema(request, ScenariosStatusBulk_INPUT_SCHEMA)
+
+def validate_scenarios_status_bulk_response(response: ScenariosStatusBulkResponse) -> None:
+    """Validate the response payload against the output schema."""
+    validate_schema(response, ScenariosStatusBulk_OUTPUT_SCHEMA)
+
+def validate_scenario_lease_acquire_request(request: ScenarioLeaseAcquireRequest) -> None:
+    """Validate the request payload against the input schema."""
+    validate_schema(request, ScenarioLeaseAcquire_INPUT_SCHEMA)
+
+def validate_scenario_lease_acquire_response(response: ScenarioLeaseAcquireResponse) -> None:
+    """Validate the response payload against the output schema."""
+    validate_schema(response, ScenarioLeaseAcquire_OUTPUT_SCHEMA)
+
+def validate_scenario_lease_release_request(request: ScenarioLeaseReleaseRequest) -> None:
+    """Validate the request payload against the input schema."""
+    validate_schema(request, ScenarioLeaseRelease_INPUT_SCHEMA)
+
+def validate_scenario_lease_release_response(response: ScenarioLeaseReleaseResponse) -> None:
+    """Validate the response payload against the output schema."""
+    validate_schema(response, ScenarioLeaseRelease_OUTPUT_SCHEMA)
+
 __all__ = [
     "JsonPrimitive",
     "JsonValue",
@@ -9302,6 +11600,7 @@ __all__ = [
     "TOOL_DESCRIPTIONS",
     "TOOL_NOTES",
     "GeneratedDecisionGateClient",
+    "AsyncGeneratedDecisionGateClient",
     "SchemaValidationError",
     "validate_schema",
     "ScenarioDefineRequest",
@@ -9359,6 +11658,7 @@ __all__ = [
     "validate_runpack_verify_request",
     "validate_runpack_verify_response",
     "ProvidersListRequest",
+    "ProviderSummary",
     "ProvidersListResponse",
     "ProvidersList_INPUT_SCHEMA",
     "ProvidersList_OUTPUT_SCHEMA",
@@ -9412,5 +11712,23 @@ __all__ = [
     "DecisionGateDocsSearch_OUTPUT_SCHEMA",
     "validate_decision_gate_docs_search_request",
     "validate_decision_gate_docs_search_response",
+    "ScenariosStatusBulkRequest",
+    "ScenariosStatusBulkResponse",
+    "ScenariosStatusBulk_INPUT_SCHEMA",
+    "ScenariosStatusBulk_OUTPUT_SCHEMA",
+    "validate_scenarios_status_bulk_request",
+    "validate_scenarios_status_bulk_response",
+    "ScenarioLeaseAcquireRequest",
+    "ScenarioLeaseAcquireResponse",
+    "ScenarioLeaseAcquire_INPUT_SCHEMA",
+    "ScenarioLeaseAcquire_OUTPUT_SCHEMA",
+    "validate_scenario_lease_acquire_request",
+    "validate_scenario_lease_acquire_response",
+    "ScenarioLeaseReleaseRequest",
+    "ScenarioLeaseReleaseResponse",
+    "ScenarioLeaseRelease_INPUT_SCHEMA",
+    "ScenarioLeaseRelease_OUTPUT_SCHEMA",
+    "validate_scenario_lease_release_request",
+    "validate_scenario_lease_release_response",
 ]
 
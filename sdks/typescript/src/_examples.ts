@@ -0,0 +1,170 @@
+// This file is @generated by decision-gate-sdk-gen. DO NOT EDIT.
+// Source: Docs/generated/decision-gate/tooling.json
+// fmt: off
+
+import type {
+  GeneratedDecisionGateClient,
+  ScenarioDefineRequest,
+  ScenarioStartRequest,
+  ScenarioSubmitRequest,
+  ScenarioStatusRequest,
+  ScenarioStatusResponse,
+} from "./_generated.js";
+
+export const LIFECYCLE_TOOL_ORDER = [
+  "scenario_define",
+  "scenario_start",
+  "scenario_submit",
+  "scenario_status",
+] as const;
+
+/** Runs a full scenario lifecycle using the tooling contract examples. */
+export async function runLifecycleExample(
+  client: GeneratedDecisionGateClient,
+): Promise<ScenarioStatusResponse> {
+  // Step 1: Register the example scenario spec.
+  const scenarioDefineRequest = {
+    "spec": {
+      "conditions": [
+        {
+          "comparator": "equals",
+          "condition_id": "env_is_prod",
+          "expected": "production",
+          "policy_tags": [],
+          "query": {
+            "check_id": "get",
+            "params": {
+              "key": "DEPLOY_ENV"
+            },
+            "provider_id": "env"
+          }
+        },
+        {
+          "comparator": "equals",
+          "condition_id": "after_freeze",
+          "expected": true,
+          "policy_tags": [],
+          "query": {
+            "check_id": "after",
+            "params": {
+              "timestamp": 1710000000000
+            },
+            "provider_id": "time"
+          }
+        }
+      ],
+      "default_tenant_id": null,
+      "namespace_id": 1,
+      "policies": [],
+      "scenario_id": "example-scenario",
+      "schemas": [],
+      "spec_version": "v1",
+      "stages": [
+        {
+          "advance_to": {
+            "kind": "terminal"
+          },
+          "entry_packets": [
+            {
+              "content_type": "application/json",
+              "expiry": null,
+              "packet_id": "packet-hello",
+              "payload": {
+                "kind": "json",
+                "value": {
+                  "message": "hello",
+                  "purpose": "scenario entry packet"
+                }
+              },
+              "policy_tags": [],
+              "schema_id": "schema-hello",
+              "visibility_labels": [
+                "public"
+              ]
+            }
+          ],
+          "gates": [
+            {
+              "gate_id": "env_gate",
+              "requirement": {
+                "Condition": "env_is_prod"
+              }
+            },
+            {
+              "gate_id": "time_gate",
+              "requirement": {
+                "Condition": "after_freeze"
+              }
+            }
+          ],
+          "on_timeout": "fail",
+          "stage_id": "main",
+          "timeout": null
+        }
+      ]
+    }
+  } as unknown as ScenarioDefineRequest;
+  await client.scenario_define(scenarioDefineRequest);
+  // Step 2: Start a run for the example scenario and issue entry packets.
+  const scenarioStartRequest = {
+    "issue_entry_packets": true,
+    "run_config": {
+      "dispatch_targets": [
+        {
+          "agent_id": "agent-alpha",
+          "kind": "agent"
+        }
+      ],
+      "namespace_id": 1,
+      "policy_tags": [],
+      "run_id": "run-0001",
+      "scenario_id": "example-scenario",
+      "tenant_id": 1
+    },
+    "scenario_id": "example-scenario",
+    "started_at": {
+      "kind": "unix_millis",
+      "value": 1710000000000
+    }
+  } as unknown as ScenarioStartRequest;
+  await client.scenario_start(scenarioStartRequest);
+  // Step 3: Submit an external artifact for audit and later evaluation.
+  const scenarioSubmitRequest = {
+    "request": {
+      "content_type": "application/json",
+      "correlation_id": null,
+      "namespace_id": 1,
+      "payload": {
+        "kind": "json",
+        "value": {
+          "artifact": "attestation",
+          "status": "approved"
+        }
+      },
+      "run_id": "run-0001",
+      "submission_id": "submission-0001",
+      "submitted_at": {
+        "kind": "unix_millis",
+        "value": 1710000000000
+      },
+      "tenant_id": 1
+    },
+    "scenario_id": "example-scenario"
+  } as unknown as ScenarioSubmitRequest;
+  await client.scenario_submit(scenarioSubmitRequest);
+  // Step 4: Poll run status without advancing the run.
+  const scenarioStatusRequest = {
+    "request": {
+      "correlation_id": null,
+      "namespace_id": 1,
+      "requested_at": {
+        "kind": "unix_millis",
+        "value": 1710000000000
+      },
+      "run_id": "run-0001",
+      "tenant_id": 1
+    },
+    "scenario_id": "example-scenario"
+  } as unknown as ScenarioStatusRequest;
+  return client.scenario_status(scenarioStatusRequest);
+}
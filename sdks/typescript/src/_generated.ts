@@ -24,6 +24,9 @@ export const TOOL_NAMES = [
   "scenarios_list",
   "precheck",
   "decision_gate_docs_search",
+  "scenarios_status_bulk",
+  "scenario_lease_acquire",
+  "scenario_lease_release",
 ] as const;
 
 export const TOOL_DESCRIPTIONS: Record<string, string> = {
@@ -45,6 +48,9 @@ export const TOOL_DESCRIPTIONS: Record<string, string> = {
   "scenarios_list": "List registered scenarios for a tenant and namespace.",
   "precheck": "Evaluate a scenario against asserted data without mutating state.",
   "decision_gate_docs_search": "Search Decision Gate documentation for runtime guidance.",
+  "scenarios_status_bulk": "Fetch read-only status snapshots for several runs in one call.",
+  "scenario_lease_acquire": "Acquire or renew an advisory lease so only one holder can advance, submit to, or trigger a run.",
+  "scenario_lease_release": "Release an advisory run lease before it expires.",
 };
 
 export const TOOL_NOTES: Record<string, string[]> = {
@@ -64,22 +70,29 @@ export const TOOL_NOTES: Record<string, string[]> = {
     "Use for polling or UI state; does not evaluate gates.",
     "Safe summaries omit evidence values and may include retry hints.",
     "Returns issued packet IDs to help track disclosures.",
+    "Set wait_for_change to long-poll until the run changes or max_wait_ms elapses.",
   ],
   "scenario_next": [
     "Idempotent by trigger_id; repeated calls return the same decision.",
     "Records decision, evidence, and packet disclosures in run state.",
     "Requires an active run; completed or failed runs do not advance.",
+    "While the run is leased, only the lease holder may advance it; pass lease_holder.",
     "Optional feedback can include gate trace or evidence when permitted by server feedback policy.",
   ],
   "scenario_submit": [
     "Payload is hashed and stored as a submission record.",
+    "Payload is persisted in run state/runpack logs; do not send raw secrets.",
     "Does not advance the run by itself.",
     "Use for artifacts the model or operator supplies.",
+    "Set expected_version to the version from scenario_status to reject the submit with a conflict if the run advanced; re-read and retry.",
+    "While the run is leased, only the lease holder may submit; pass lease_holder.",
   ],
   "scenario_trigger": [
     "Trigger time is supplied by the caller; no wall-clock reads.",
     "Records the trigger event and resulting decision.",
+    "Payload is persisted in run state/runpack logs; do not send raw secrets.",
     "Use for time-based or external system triggers.",
+    "While the run is leased, only the lease holder may trigger; pass lease_holder.",
   ],
   "evidence_query": [
     "Disclosure policy may redact raw values; hashes/anchors still returned.",
@@ -89,6 +102,8 @@ export const TOOL_NOTES: Record<string, string[]> = {
   "runpack_export": [
     "Writes manifest and logs to output_dir; generated_at is recorded in the manifest.",
     "include_verification adds a verification report artifact.",
+    "include_evaluation_audit adds evaluation_audit.json: spec, run config, evidence, and result hashes for every decision and submission.",
+    "Export-time report.checked_files excludes verifier_report.json; offline runpack_verify checked_files includes it (+1 for the same runpack).",
     "Use after runs complete or for audit snapshots.",
   ],
   "runpack_verify": [
@@ -135,6 +150,22 @@ export const TOOL_NOTES: Record<string, string[]> = {
     "Returns ranked sections with role tags and suggested follow-ups.",
     "Search is deterministic and scoped to the configured doc catalog.",
   ],
+  "scenarios_status_bulk": [
+    "Requires tenant_id and namespace_id; every run is resolved within that scope.",
+    "Accepts up to 100 runs; results are returned in request order.",
+    "Unknown scenarios or runs yield found=false without failing the call.",
+    "Reads do not record tool calls or modify run state.",
+  ],
+  "scenario_lease_acquire": [
+    "While the lease is active, scenario_next, scenario_submit, and scenario_trigger calls without the matching lease_holder fail with a conflict.",
+    "The lease expires ttl_ms after the server receives the request; requested_at is recorded but never used to judge expiry. The holder renews by acquiring again.",
+    "Fails with a conflict while another holder's lease is active.",
+    "Leases live in server memory and are lost on restart.",
+  ],
+  "scenario_lease_release": [
+    "Returns released=false when the caller held no active lease.",
+    "Fails with a conflict while another holder's lease is active.",
+  ],
 };
 
 export interface ScenarioDefineRequest {
@@ -176,7 +207,8 @@ export const ScenarioDefine_OUTPUT_SCHEMA = {
       "properties": {
         "algorithm": {
           "enum": [
-            "sha256"
+            "sha256",
+            "blake3"
           ],
           "type": "string"
         },
@@ -207,14 +239,29 @@ export interface ScenarioStartRequest {
   /** Scenario identifier. */
   scenario_id: string;
   /** Caller-supplied run start timestamp. */
-  started_at: Record<string, JsonValue>;
+  started_at: ScenarioStartRequestStartedAt;
+}
+
+export type ScenarioStartRequestStartedAt = ScenarioStartRequestStartedAtUnixMillis | ScenarioStartRequestStartedAtLogical;
+
+export interface ScenarioStartRequestStartedAtUnixMillis {
+  /** Constraints: Const: "unix_millis". */
+  kind: "unix_millis";
+  value: number;
+}
+
+export interface ScenarioStartRequestStartedAtLogical {
+  /** Constraints: Const: "logical". */
+  kind: "logical";
+  /** Constraints: Minimum: 0. */
+  value: number;
 }
 
 export interface ScenarioStartResponse {
   /** Current stage identifier. */
   current_stage_id: string;
   decisions: Array<Record<string, JsonValue>>;
-  dispatch_targets: Array<Record<string, JsonValue>>;
+  dispatch_targets: Array<ScenarioStartResponseDispatchTargets>;
   gate_evals: Array<Record<string, JsonValue>>;
   /** Namespace identifier. Constraints: Minimum: 1. */
   namespace_id: number;
@@ -224,7 +271,7 @@ export interface ScenarioStartResponse {
   /** Scenario identifier. */
   scenario_id: string;
   spec_hash: Record<string, JsonValue>;
-  stage_entered_at: Record<string, JsonValue>;
+  stage_entered_at: ScenarioStartResponseStageEnteredAt;
   /** Constraints: Allowed values: "active", "completed", "failed". */
   status: "active" | "completed" | "failed";
   submissions: Array<Record<string, JsonValue>>;
@@ -234,6 +281,53 @@ export interface ScenarioStartResponse {
   triggers: Array<Record<string, JsonValue>>;
 }
 
+export type ScenarioStartResponseDispatchTargets = ScenarioStartResponseDispatchTargetsAgent | ScenarioStartResponseDispatchTargetsSession | ScenarioStartResponseDispatchTargetsExternal | ScenarioStartResponseDispatchTargetsChannel;
+
+export interface ScenarioStartResponseDispatchTargetsAgent {
+  /** Agent identifier. */
+  agent_id: string;
+  /** Constraints: Const: "agent". */
+  kind: "agent";
+}
+
+export interface ScenarioStartResponseDispatchTargetsSession {
+  /** Constraints: Const: "session". */
+  kind: "session";
+  /** Session identifier. */
+  session_id: string;
+}
+
+export interface ScenarioStartResponseDispatchTargetsExternal {
+  /** Constraints: Const: "external". */
+  kind: "external";
+  /** External system name. */
+  system: string;
+  /** External system target. */
+  target: string;
+}
+
+export interface ScenarioStartResponseDispatchTargetsChannel {
+  /** Broadcast channel identifier. */
+  channel: string;
+  /** Constraints: Const: "channel". */
+  kind: "channel";
+}
+
+export type ScenarioStartResponseStageEnteredAt = ScenarioStartResponseStageEnteredAtUnixMillis | ScenarioStartResponseStageEnteredAtLogical;
+
+export interface ScenarioStartResponseStageEnteredAtUnixMillis {
+  /** Constraints: Const: "unix_millis". */
+  kind: "unix_millis";
+  value: number;
+}
+
+export interface ScenarioStartResponseStageEnteredAtLogical {
+  /** Constraints: Const: "logical". */
+  kind: "logical";
+  /** Constraints: Minimum: 0. */
+  value: number;
+}
+
 export const ScenarioStart_INPUT_SCHEMA = {
   "$schema": "https://json-schema.org/draft/2020-12/schema",
   "additionalProperties": false,
@@ -247,6 +341,7 @@ export const ScenarioStart_INPUT_SCHEMA = {
       "description": "Run configuration and dispatch targets.",
       "properties": {
         "dispatch_targets": {
+          "description": "Dispatch targets for disclosures (default: none).",
           "items": {
             "oneOf": [
               {
@@ -324,6 +419,7 @@ export const ScenarioStart_INPUT_SCHEMA = {
               }
             ]
           },
+          "maxItems": 64,
           "type": "array"
         },
         "namespace_id": {
@@ -332,10 +428,11 @@ export const ScenarioStart_INPUT_SCHEMA = {
           "type": "integer"
         },
         "policy_tags": {
-          "description": "Policy tags applied to the run.",
+          "description": "Policy tags applied to the run (default: none).",
           "items": {
             "type": "string"
           },
+          "maxItems": 64,
           "type": "array"
         },
         "run_id": {
@@ -355,9 +452,7 @@ export const ScenarioStart_INPUT_SCHEMA = {
       "required": [
         "tenant_id",
         "run_id",
-        "scenario_id",
-        "dispatch_targets",
-        "policy_tags"
+        "scenario_id"
       ],
       "type": "object"
     },
@@ -872,7 +967,8 @@ export const ScenarioStart_OUTPUT_SCHEMA = {
                           "properties": {
                             "algorithm": {
                               "enum": [
-                                "sha256"
+                                "sha256",
+                                "blake3"
                               ],
                               "type": "string"
                             },
@@ -1079,7 +1175,8 @@ export const ScenarioStart_OUTPUT_SCHEMA = {
                 "properties": {
                   "algorithm": {
                     "enum": [
-                      "sha256"
+                      "sha256",
+                      "blake3"
                     ],
                     "type": "string"
                   },
@@ -1308,7 +1405,8 @@ export const ScenarioStart_OUTPUT_SCHEMA = {
                         "properties": {
                           "algorithm": {
                             "enum": [
-                              "sha256"
+                              "sha256",
+                              "blake3"
                             ],
                             "type": "string"
                           },
@@ -1412,7 +1510,8 @@ export const ScenarioStart_OUTPUT_SCHEMA = {
                   "properties": {
                     "algorithm": {
                       "enum": [
-                        "sha256"
+                        "sha256",
+                        "blake3"
                       ],
                       "type": "string"
                     },
@@ -1540,7 +1639,8 @@ export const ScenarioStart_OUTPUT_SCHEMA = {
       "properties": {
         "algorithm": {
           "enum": [
-            "sha256"
+            "sha256",
+            "blake3"
           ],
           "type": "string"
         },
@@ -1609,7 +1709,8 @@ export const ScenarioStart_OUTPUT_SCHEMA = {
             "properties": {
               "algorithm": {
                 "enum": [
-                  "sha256"
+                  "sha256",
+                  "blake3"
                 ],
                 "type": "string"
               },
@@ -1697,7 +1798,8 @@ export const ScenarioStart_OUTPUT_SCHEMA = {
                         "properties": {
                           "algorithm": {
                             "enum": [
-                              "sha256"
+                              "sha256",
+                              "blake3"
                             ],
                             "type": "string"
                           },
@@ -1962,7 +2064,8 @@ export const ScenarioStart_OUTPUT_SCHEMA = {
             "properties": {
               "algorithm": {
                 "enum": [
-                  "sha256"
+                  "sha256",
+                  "blake3"
                 ],
                 "type": "string"
               },
@@ -1982,7 +2085,8 @@ export const ScenarioStart_OUTPUT_SCHEMA = {
             "properties": {
               "algorithm": {
                 "enum": [
-                  "sha256"
+                  "sha256",
+                  "blake3"
                 ],
                 "type": "string"
               },
@@ -2106,7 +2210,8 @@ export const ScenarioStart_OUTPUT_SCHEMA = {
                                 "properties": {
                                   "algorithm": {
                                     "enum": [
-                                      "sha256"
+                                      "sha256",
+                                      "blake3"
                                     ],
                                     "type": "string"
                                   },
@@ -2263,6 +2368,8 @@ export interface ScenarioStatusRequest {
   request: Record<string, JsonValue>;
   /** Scenario identifier. */
   scenario_id: string;
+  /** Optional long-poll options; the server holds the request until the run status changes. */
+  wait_for_change?: Record<string, JsonValue> | null;
 }
 
 export interface ScenarioStatusResponse {
@@ -2279,6 +2386,8 @@ export interface ScenarioStatusResponse {
   scenario_id: string;
   /** Constraints: Allowed values: "active", "completed", "failed". */
   status: "active" | "completed" | "failed";
+  /** Run version; advances on each trigger or submission. Constraints: Minimum: 0. */
+  version: number;
 }
 
 export const ScenarioStatus_INPUT_SCHEMA = {
@@ -2363,6 +2472,29 @@ export const ScenarioStatus_INPUT_SCHEMA = {
     "scenario_id": {
       "description": "Scenario identifier.",
       "type": "string"
+    },
+    "wait_for_change": {
+      "description": "Optional long-poll options; the server holds the request until the run status changes.",
+      "oneOf": [
+        {
+          "type": "null"
+        },
+        {
+          "additionalProperties": false,
+          "properties": {
+            "max_wait_ms": {
+              "description": "Maximum time to hold the request before returning the current status.",
+              "maximum": 30000,
+              "minimum": 1,
+              "type": "integer"
+            }
+          },
+          "required": [
+            "max_wait_ms"
+          ],
+          "type": "object"
+        }
+      ]
     }
   },
   "required": [
@@ -2675,6 +2807,11 @@ export const ScenarioStatus_OUTPUT_SCHEMA = {
         "failed"
       ],
       "type": "string"
+    },
+    "version": {
+      "description": "Run version; advances on each trigger or submission.",
+      "minimum": 0,
+      "type": "integer"
     }
   },
   "required": [
@@ -2684,7 +2821,8 @@ export const ScenarioStatus_OUTPUT_SCHEMA = {
     "status",
     "last_decision",
     "issued_packet_ids",
-    "safe_summary"
+    "safe_summary",
+    "version"
   ],
   "type": "object"
 } as const;
@@ -2746,6 +2884,12 @@ export const ScenarioNext_INPUT_SCHEMA = {
             }
           ]
         },
+        "lease_holder": {
+          "description": "Lease holder making the call; required while the run is leased.",
+          "maxLength": 256,
+          "minLength": 1,
+          "type": "string"
+        },
         "namespace_id": {
           "description": "Namespace identifier.",
           "minimum": 1,
@@ -3280,7 +3424,8 @@ export const ScenarioNext_OUTPUT_SCHEMA = {
                                       "properties": {
                                         "algorithm": {
                                           "enum": [
-                                            "sha256"
+                                            "sha256",
+                                            "blake3"
                                           ],
                                           "type": "string"
                                         },
@@ -3501,7 +3646,8 @@ export const ScenarioNext_OUTPUT_SCHEMA = {
                 "properties": {
                   "algorithm": {
                     "enum": [
-                      "sha256"
+                      "sha256",
+                      "blake3"
                     ],
                     "type": "string"
                   },
@@ -3730,7 +3876,8 @@ export const ScenarioNext_OUTPUT_SCHEMA = {
                         "properties": {
                           "algorithm": {
                             "enum": [
-                              "sha256"
+                              "sha256",
+                              "blake3"
                             ],
                             "type": "string"
                           },
@@ -3834,7 +3981,8 @@ export const ScenarioNext_OUTPUT_SCHEMA = {
                   "properties": {
                     "algorithm": {
                       "enum": [
-                        "sha256"
+                        "sha256",
+                        "blake3"
                       ],
                       "type": "string"
                     },
@@ -4000,6 +4148,17 @@ export const ScenarioSubmit_INPUT_SCHEMA = {
             }
           ]
         },
+        "expected_version": {
+          "description": "Run version read from scenario_status; conflicts if the run advanced.",
+          "minimum": 0,
+          "type": "integer"
+        },
+        "lease_holder": {
+          "description": "Lease holder making the call; required while the run is leased.",
+          "maxLength": 256,
+          "minLength": 1,
+          "type": "string"
+        },
         "namespace_id": {
           "description": "Namespace identifier.",
           "minimum": 1,
@@ -4063,7 +4222,8 @@ export const ScenarioSubmit_INPUT_SCHEMA = {
                       "properties": {
                         "algorithm": {
                           "enum": [
-                            "sha256"
+                            "sha256",
+                            "blake3"
                           ],
                           "type": "string"
                         },
@@ -4198,7 +4358,8 @@ export const ScenarioSubmit_OUTPUT_SCHEMA = {
           "properties": {
             "algorithm": {
               "enum": [
-                "sha256"
+                "sha256",
+                "blake3"
               ],
               "type": "string"
             },
@@ -4286,7 +4447,8 @@ export const ScenarioSubmit_OUTPUT_SCHEMA = {
                       "properties": {
                         "algorithm": {
                           "enum": [
-                            "sha256"
+                            "sha256",
+                            "blake3"
                           ],
                           "type": "string"
                         },
@@ -4401,6 +4563,9 @@ export const ScenarioSubmit_OUTPUT_SCHEMA = {
 } as const;
 
 export interface ScenarioTriggerRequest {
+  /** Lease holder making the call; required while the run is leased. Constraints: Min length: 1; Max */
+  /** length: 256. */
+  lease_holder?: string;
   /** Scenario identifier. */
   scenario_id: string;
   /** Trigger event payload. */
@@ -4418,6 +4583,12 @@ export const ScenarioTrigger_INPUT_SCHEMA = {
   "$schema": "https://json-schema.org/draft/2020-12/schema",
   "additionalProperties": false,
   "properties": {
+    "lease_holder": {
+      "description": "Lease holder making the call; required while the run is leased.",
+      "maxLength": 256,
+      "minLength": 1,
+      "type": "string"
+    },
     "scenario_id": {
       "description": "Scenario identifier.",
       "type": "string"
@@ -4514,7 +4685,8 @@ export const ScenarioTrigger_INPUT_SCHEMA = {
                           "properties": {
                             "algorithm": {
                               "enum": [
-                                "sha256"
+                                "sha256",
+                                "blake3"
                               ],
                               "type": "string"
                             },
@@ -4873,7 +5045,8 @@ export const ScenarioTrigger_OUTPUT_SCHEMA = {
                 "properties": {
                   "algorithm": {
                     "enum": [
-                      "sha256"
+                      "sha256",
+                      "blake3"
                     ],
                     "type": "string"
                   },
@@ -5102,7 +5275,8 @@ export const ScenarioTrigger_OUTPUT_SCHEMA = {
                         "properties": {
                           "algorithm": {
                             "enum": [
-                              "sha256"
+                              "sha256",
+                              "blake3"
                             ],
                             "type": "string"
                           },
@@ -5206,7 +5380,8 @@ export const ScenarioTrigger_OUTPUT_SCHEMA = {
                   "properties": {
                     "algorithm": {
                       "enum": [
-                        "sha256"
+                        "sha256",
+                        "blake3"
                       ],
                       "type": "string"
                     },
@@ -5577,7 +5752,8 @@ export const EvidenceQuery_OUTPUT_SCHEMA = {
               "properties": {
                 "algorithm": {
                   "enum": [
-                    "sha256"
+                    "sha256",
+                    "blake3"
                   ],
                   "type": "string"
                 },
@@ -5734,7 +5910,9 @@ export const EvidenceQuery_OUTPUT_SCHEMA = {
 
 export interface RunpackExportRequest {
   /** Timestamp recorded in the manifest. */
-  generated_at: Record<string, JsonValue>;
+  generated_at: RunpackExportRequestGeneratedAt;
+  /** Include evaluation audit records as an artifact (default false). */
+  include_evaluation_audit?: boolean;
   /** Generate a verification report artifact. */
   include_verification: boolean;
   /** Optional override for the manifest file name. */
@@ -5751,6 +5929,21 @@ export interface RunpackExportRequest {
   tenant_id: number;
 }
 
+export type RunpackExportRequestGeneratedAt = RunpackExportRequestGeneratedAtUnixMillis | RunpackExportRequestGeneratedAtLogical;
+
+export interface RunpackExportRequestGeneratedAtUnixMillis {
+  /** Constraints: Const: "unix_millis". */
+  kind: "unix_millis";
+  value: number;
+}
+
+export interface RunpackExportRequestGeneratedAtLogical {
+  /** Constraints: Const: "logical". */
+  kind: "logical";
+  /** Constraints: Minimum: 0. */
+  value: number;
+}
+
 export interface RunpackExportResponse {
   manifest: Record<string, JsonValue>;
   report: Record<string, JsonValue> | null;
@@ -5800,6 +5993,10 @@ export const RunpackExport_INPUT_SCHEMA = {
         }
       ]
     },
+    "include_evaluation_audit": {
+      "description": "Include evaluation audit records as an artifact (default false).",
+      "type": "boolean"
+    },
     "include_verification": {
       "description": "Generate a verification report artifact.",
       "type": "boolean"
@@ -5873,6 +6070,14 @@ export const RunpackExport_OUTPUT_SCHEMA = {
                 "description": "Artifact identifier.",
                 "type": "string"
               },
+              "content_encoding": {
+                "description": "Encoding of the stored artifact bytes; hashes cover the decoded bytes.",
+                "enum": [
+                  "none",
+                  "gzip"
+                ],
+                "type": "string"
+              },
               "content_type": {
                 "oneOf": [
                   {
@@ -5889,7 +6094,8 @@ export const RunpackExport_OUTPUT_SCHEMA = {
                 "properties": {
                   "algorithm": {
                     "enum": [
-                      "sha256"
+                      "sha256",
+                      "blake3"
                     ],
                     "type": "string"
                   },
@@ -5915,6 +6121,7 @@ export const RunpackExport_OUTPUT_SCHEMA = {
                   "evidence_log",
                   "submission_log",
                   "tool_transcript",
+                  "evaluation_audit",
                   "verifier_report",
                   "custom"
                 ],
@@ -5979,7 +6186,8 @@ export const RunpackExport_OUTPUT_SCHEMA = {
         },
         "hash_algorithm": {
           "enum": [
-            "sha256"
+            "sha256",
+            "blake3"
           ],
           "type": "string"
         },
@@ -5995,7 +6203,8 @@ export const RunpackExport_OUTPUT_SCHEMA = {
                     "properties": {
                       "algorithm": {
                         "enum": [
-                          "sha256"
+                          "sha256",
+                          "blake3"
                         ],
                         "type": "string"
                       },
@@ -6028,7 +6237,8 @@ export const RunpackExport_OUTPUT_SCHEMA = {
               "properties": {
                 "algorithm": {
                   "enum": [
-                    "sha256"
+                    "sha256",
+                    "blake3"
                   ],
                   "type": "string"
                 },
@@ -6084,12 +6294,40 @@ export const RunpackExport_OUTPUT_SCHEMA = {
           ],
           "type": "object"
         },
+        "signature": {
+          "additionalProperties": false,
+          "properties": {
+            "key_id": {
+              "description": "Signing key identifier.",
+              "type": "string"
+            },
+            "scheme": {
+              "description": "Manifest signature scheme identifier.",
+              "type": "string"
+            },
+            "signature": {
+              "items": {
+                "maximum": 255,
+                "minimum": 0,
+                "type": "integer"
+              },
+              "type": "array"
+            }
+          },
+          "required": [
+            "scheme",
+            "key_id",
+            "signature"
+          ],
+          "type": "object"
+        },
         "spec_hash": {
           "additionalProperties": false,
           "properties": {
             "algorithm": {
               "enum": [
-                "sha256"
+                "sha256",
+                "blake3"
               ],
               "type": "string"
             },
@@ -6275,8 +6513,17 @@ export interface ProvidersListRequest {
   [key: string]: never;
 }
 
+/** Registered evidence provider and the checks it serves. Constraints: Title: ProviderSummary. */
+export interface ProviderSummary {
+  checks: Array<string>;
+  /** Provider identifier. */
+  provider_id: string;
+  /** Provider transport type. Constraints: Allowed values: "builtin", "mcp". */
+  transport: "builtin" | "mcp";
+}
+
 export interface ProvidersListResponse {
-  providers: Array<Record<string, JsonValue>>;
+  providers: Array<ProviderSummary>;
 }
 
 export const ProvidersList_INPUT_SCHEMA = {
@@ -6288,39 +6535,46 @@ export const ProvidersList_INPUT_SCHEMA = {
 } as const;
 
 export const ProvidersList_OUTPUT_SCHEMA = {
+  "$defs": {
+    "ProviderSummary": {
+      "additionalProperties": false,
+      "description": "Registered evidence provider and the checks it serves.",
+      "properties": {
+        "checks": {
+          "items": {
+            "description": "Check identifier.",
+            "type": "string"
+          },
+          "type": "array"
+        },
+        "provider_id": {
+          "description": "Provider identifier.",
+          "type": "string"
+        },
+        "transport": {
+          "description": "Provider transport type.",
+          "enum": [
+            "builtin",
+            "mcp"
+          ],
+          "type": "string"
+        }
+      },
+      "required": [
+        "provider_id",
+        "transport",
+        "checks"
+      ],
+      "title": "ProviderSummary",
+      "type": "object"
+    }
+  },
   "$schema": "https://json-schema.org/draft/2020-12/schema",
   "additionalProperties": false,
   "properties": {
     "providers": {
       "items": {
-        "additionalProperties": false,
-        "properties": {
-          "checks": {
-            "items": {
-              "description": "Check identifier.",
-              "type": "string"
-            },
-            "type": "array"
-          },
-          "provider_id": {
-            "description": "Provider identifier.",
-            "type": "string"
-          },
-          "transport": {
-            "description": "Provider transport type.",
-            "enum": [
-              "builtin",
-              "mcp"
-            ],
-            "type": "string"
-          }
-        },
-        "required": [
-          "provider_id",
-          "transport",
-          "checks"
-        ],
-        "type": "object"
+        "$ref": "#/$defs/ProviderSummary"
       },
       "type": "array"
     }
@@ -6568,7 +6822,8 @@ export const ProviderContractGet_OUTPUT_SCHEMA = {
       "properties": {
         "algorithm": {
           "enum": [
-            "sha256"
+            "sha256",
+            "blake3"
           ],
           "type": "string"
         },
@@ -6721,7 +6976,8 @@ export const ProviderCheckSchemaGet_OUTPUT_SCHEMA = {
       "properties": {
         "algorithm": {
           "enum": [
-            "sha256"
+            "sha256",
+            "blake3"
           ],
           "type": "string"
         },
@@ -7564,7 +7820,8 @@ export const ScenariosList_OUTPUT_SCHEMA = {
             "properties": {
               "algorithm": {
                 "enum": [
-                  "sha256"
+                  "sha256",
+                  "blake3"
                 ],
                 "type": "string"
               },
@@ -7622,10 +7879,49 @@ export interface PrecheckRequest {
 }
 
 export interface PrecheckResponse {
-  decision: Record<string, JsonValue>;
+  decision: PrecheckResponseDecision;
   gate_evaluations: Array<Record<string, JsonValue>>;
 }
 
+export type PrecheckResponseDecision = PrecheckResponseDecisionStart | PrecheckResponseDecisionComplete | PrecheckResponseDecisionAdvance | PrecheckResponseDecisionHold | PrecheckResponseDecisionFail;
+
+export interface PrecheckResponseDecisionStart {
+  /** Constraints: Const: "start". */
+  kind: "start";
+  /** Initial stage identifier. */
+  stage_id: string;
+}
+
+export interface PrecheckResponseDecisionComplete {
+  /** Constraints: Const: "complete". */
+  kind: "complete";
+  /** Terminal stage identifier. */
+  stage_id: string;
+}
+
+export interface PrecheckResponseDecisionAdvance {
+  /** Previous stage identifier. */
+  from_stage: string;
+  /** Constraints: Const: "advance". */
+  kind: "advance";
+  timeout: boolean;
+  /** Next stage identifier. */
+  to_stage: string;
+}
+
+export interface PrecheckResponseDecisionHold {
+  /** Constraints: Const: "hold". */
+  kind: "hold";
+  summary: Record<string, JsonValue>;
+}
+
+export interface PrecheckResponseDecisionFail {
+  /** Constraints: Const: "fail". */
+  kind: "fail";
+  /** Failure reason. */
+  reason: string;
+}
+
 export const Precheck_INPUT_SCHEMA = {
   "$schema": "https://json-schema.org/draft/2020-12/schema",
   "additionalProperties": false,
@@ -8046,119 +8342,956 @@ export const DecisionGateDocsSearch_OUTPUT_SCHEMA = {
   "type": "object"
 } as const;
 
-export abstract class GeneratedDecisionGateClient {
-  protected abstract callTool<T>(name: string, arguments_: object): Promise<T>;
+export interface ScenariosStatusBulkRequest {
+  /** Namespace identifier. Constraints: Minimum: 1. */
+  namespace_id: number;
+  /** Runs to fetch status for, in result order. Constraints: Min items: 1; Max items: 100. */
+  runs: Array<Record<string, JsonValue>>;
+  /** Tenant identifier. Constraints: Minimum: 1. */
+  tenant_id: number;
+}
 
-  /**
-   * Register a ScenarioSpec, validate it, and return the canonical hash used for integrity checks.
-   *
-   * Notes:
-   * - Use before starting runs; scenario_id becomes the stable handle for later calls.
-   * - Validates stage/gate/condition IDs, RET trees, and condition references.
-   * - Spec hash is deterministic; store it for audit and runpack integrity.
-   * - Fails closed on invalid specs or duplicate scenario IDs.
-   *
-   * Examples:
-   * - Register the example scenario spec.
-   *   Input:
-   *   ```json
-   *   {
-   *     "spec": {
-   *       "conditions": [
-   *         {
-   *           "comparator": "equals",
-   *           "condition_id": "env_is_prod",
-   *           "expected": "production",
-   *           "policy_tags": [],
-   *           "query": {
-   *             "check_id": "get",
-   *             "params": {
-   *               "key": "DEPLOY_ENV"
-   *             },
-   *             "provider_id": "env"
-   *           }
-   *         },
-   *         {
-   *           "comparator": "equals",
-   *           "condition_id": "after_freeze",
-   *           "expected": true,
-   *           "policy_tags": [],
-   *           "query": {
-   *             "check_id": "after",
-   *             "params": {
-   *               "timestamp": 1710000000000
-   *             },
-   *             "provider_id": "time"
-   *           }
-   *         }
-   *       ],
-   *       "default_tenant_id": null,
-   *       "namespace_id": 1,
-   *       "policies": [],
-   *       "scenario_id": "example-scenario",
-   *       "schemas": [],
-   *       "spec_version": "v1",
-   *       "stages": [
-   *         {
-   *           "advance_to": {
-   *             "kind": "terminal"
-   *           },
-   *           "entry_packets": [
-   *             {
-   *               "content_type": "application/json",
-   *               "expiry": null,
-   *               "packet_id": "packet-hello",
-   *               "payload": {
-   *                 "kind": "json",
-   *                 "value": {
-   *                   "message": "hello",
-   *                   "purpose": "scenario entry packet"
-   *                 }
-   *               },
-   *               "policy_tags": [],
-   *               "schema_id": "schema-hello",
-   *               "visibility_labels": [
-   *                 "public"
-   *               ]
-   *             }
-   *           ],
-   *           "gates": [
-   *             {
-   *               "gate_id": "env_gate",
-   *               "requirement": {
-   *                 "Condition": "env_is_prod"
-   *               }
-   *             },
-   *             {
-   *               "gate_id": "time_gate",
-   *               "requirement": {
-   *                 "Condition": "after_freeze"
-   *               }
-   *             }
-   *           ],
-   *           "on_timeout": "fail",
-   *           "stage_id": "main",
-   *           "timeout": null
-   *         }
-   *       ]
-   *     }
-   *   }
-   *   ```
-   *   Output:
-   *   ```json
-   *   {
-   *     "scenario_id": "example-scenario",
-   *     "spec_hash": {
-   *       "algorithm": "sha256",
-   *       "value": "5c3a5b6bce0f4a2c9e22c4fa6a1e6d8d90b0f2dfed1b7f1e9b3d3b3d1f0c9b21"
-   *     }
-   *   }
+export interface ScenariosStatusBulkResponse {
+  results: Array<Record<string, JsonValue>>;
+}
+
+export const ScenariosStatusBulk_INPUT_SCHEMA = {
+  "$schema": "https://json-schema.org/draft/2020-12/schema",
+  "additionalProperties": false,
+  "properties": {
+    "namespace_id": {
+      "description": "Namespace identifier.",
+      "minimum": 1,
+      "type": "integer"
+    },
+    "runs": {
+      "description": "Runs to fetch status for, in result order.",
+      "items": {
+        "additionalProperties": false,
+        "properties": {
+          "run_id": {
+            "description": "Run identifier.",
+            "type": "string"
+          },
+          "scenario_id": {
+            "description": "Scenario identifier.",
+            "type": "string"
+          }
+        },
+        "required": [
+          "scenario_id",
+          "run_id"
+        ],
+        "type": "object"
+      },
+      "maxItems": 100,
+      "minItems": 1,
+      "type": "array"
+    },
+    "tenant_id": {
+      "description": "Tenant identifier.",
+      "minimum": 1,
+      "type": "integer"
+    }
+  },
+  "required": [
+    "tenant_id",
+    "namespace_id",
+    "runs"
+  ],
+  "type": "object"
+} as const;
+
+export const ScenariosStatusBulk_OUTPUT_SCHEMA = {
+  "$schema": "https://json-schema.org/draft/2020-12/schema",
+  "additionalProperties": false,
+  "properties": {
+    "results": {
+      "items": {
+        "additionalProperties": false,
+        "properties": {
+          "found": {
+            "description": "Whether the run exists within the requested scope.",
+            "type": "boolean"
+          },
+          "run_id": {
+            "description": "Run identifier.",
+            "type": "string"
+          },
+          "scenario_id": {
+            "description": "Scenario identifier.",
+            "type": "string"
+          },
+          "status": {
+            "description": "Run status snapshot when found.",
+            "oneOf": [
+              {
+                "type": "null"
+              },
+              {
+                "additionalProperties": false,
+                "properties": {
+                  "current_stage_id": {
+                    "description": "Current stage identifier.",
+                    "type": "string"
+                  },
+                  "issued_packet_ids": {
+                    "items": {
+                      "description": "Packet identifier.",
+                      "type": "string"
+                    },
+                    "type": "array"
+                  },
+                  "last_decision": {
+                    "oneOf": [
+                      {
+                        "type": "null"
+                      },
+                      {
+                        "additionalProperties": false,
+                        "properties": {
+                          "correlation_id": {
+                            "oneOf": [
+                              {
+                                "type": "null"
+                              },
+                              {
+                                "description": "Correlation identifier.",
+                                "type": "string"
+                              }
+                            ]
+                          },
+                          "decided_at": {
+                            "oneOf": [
+                              {
+                                "additionalProperties": false,
+                                "properties": {
+                                  "kind": {
+                                    "const": "unix_millis"
+                                  },
+                                  "value": {
+                                    "type": "integer"
+                                  }
+                                },
+                                "required": [
+                                  "kind",
+                                  "value"
+                                ],
+                                "type": "object"
+                              },
+                              {
+                                "additionalProperties": false,
+                                "properties": {
+                                  "kind": {
+                                    "const": "logical"
+                                  },
+                                  "value": {
+                                    "minimum": 0,
+                                    "type": "integer"
+                                  }
+                                },
+                                "required": [
+                                  "kind",
+                                  "value"
+                                ],
+                                "type": "object"
+                              }
+                            ]
+                          },
+                          "decision_id": {
+                            "description": "Decision identifier.",
+                            "type": "string"
+                          },
+                          "outcome": {
+                            "oneOf": [
+                              {
+                                "additionalProperties": false,
+                                "properties": {
+                                  "kind": {
+                                    "const": "start"
+                                  },
+                                  "stage_id": {
+                                    "description": "Initial stage identifier.",
+                                    "type": "string"
+                                  }
+                                },
+                                "required": [
+                                  "kind",
+                                  "stage_id"
+                                ],
+                                "type": "object"
+                              },
+                              {
+                                "additionalProperties": false,
+                                "properties": {
+                                  "kind": {
+                                    "const": "complete"
+                                  },
+                                  "stage_id": {
+                                    "description": "Terminal stage identifier.",
+                                    "type": "string"
+                                  }
+                                },
+                                "required": [
+                                  "kind",
+                                  "stage_id"
+                                ],
+                                "type": "object"
+                              },
+                              {
+                                "additionalProperties": false,
+                                "properties": {
+                                  "from_stage": {
+                                    "description": "Previous stage identifier.",
+                                    "type": "string"
+                                  },
+                                  "kind": {
+                                    "const": "advance"
+                                  },
+                                  "timeout": {
+                                    "type": "boolean"
+                                  },
+                                  "to_stage": {
+                                    "description": "Next stage identifier.",
+                                    "type": "string"
+                                  }
+                                },
+                                "required": [
+                                  "kind",
+                                  "from_stage",
+                                  "to_stage",
+                                  "timeout"
+                                ],
+                                "type": "object"
+                              },
+                              {
+                                "additionalProperties": false,
+                                "properties": {
+                                  "kind": {
+                                    "const": "hold"
+                                  },
+                                  "summary": {
+                                    "additionalProperties": false,
+                                    "properties": {
+                                      "policy_tags": {
+                                        "description": "Policy tags applied to the summary.",
+                                        "items": {
+                                          "type": "string"
+                                        },
+                                        "type": "array"
+                                      },
+                                      "retry_hint": {
+                                        "oneOf": [
+                                          {
+                                            "type": "null"
+                                          },
+                                          {
+                                            "description": "Optional retry hint.",
+                                            "type": "string"
+                                          }
+                                        ]
+                                      },
+                                      "status": {
+                                        "description": "Summary status.",
+                                        "type": "string"
+                                      },
+                                      "unmet_gates": {
+                                        "items": {
+                                          "description": "Gate identifier.",
+                                          "type": "string"
+                                        },
+                                        "type": "array"
+                                      }
+                                    },
+                                    "required": [
+                                      "status",
+                                      "unmet_gates",
+                                      "retry_hint",
+                                      "policy_tags"
+                                    ],
+                                    "type": "object"
+                                  }
+                                },
+                                "required": [
+                                  "kind",
+                                  "summary"
+                                ],
+                                "type": "object"
+                              },
+                              {
+                                "additionalProperties": false,
+                                "properties": {
+                                  "kind": {
+                                    "const": "fail"
+                                  },
+                                  "reason": {
+                                    "description": "Failure reason.",
+                                    "type": "string"
+                                  }
+                                },
+                                "required": [
+                                  "kind",
+                                  "reason"
+                                ],
+                                "type": "object"
+                              }
+                            ]
+                          },
+                          "seq": {
+                            "minimum": 0,
+                            "type": "integer"
+                          },
+                          "stage_id": {
+                            "description": "Stage identifier.",
+                            "type": "string"
+                          },
+                          "trigger_id": {
+                            "description": "Trigger identifier.",
+                            "type": "string"
+                          }
+                        },
+                        "required": [
+                          "decision_id",
+                          "seq",
+                          "trigger_id",
+                          "stage_id",
+                          "decided_at",
+                          "outcome",
+                          "correlation_id"
+                        ],
+                        "type": "object"
+                      }
+                    ]
+                  },
+                  "namespace_id": {
+                    "description": "Namespace identifier.",
+                    "minimum": 1,
+                    "type": "integer"
+                  },
+                  "run_id": {
+                    "description": "Run identifier.",
+                    "type": "string"
+                  },
+                  "safe_summary": {
+                    "oneOf": [
+                      {
+                        "type": "null"
+                      },
+                      {
+                        "additionalProperties": false,
+                        "properties": {
+                          "policy_tags": {
+                            "description": "Policy tags applied to the summary.",
+                            "items": {
+                              "type": "string"
+                            },
+                            "type": "array"
+                          },
+                          "retry_hint": {
+                            "oneOf": [
+                              {
+                                "type": "null"
+                              },
+                              {
+                                "description": "Optional retry hint.",
+                                "type": "string"
+                              }
+                            ]
+                          },
+                          "status": {
+                            "description": "Summary status.",
+                            "type": "string"
+                          },
+                          "unmet_gates": {
+                            "items": {
+                              "description": "Gate identifier.",
+                              "type": "string"
+                            },
+                            "type": "array"
+                          }
+                        },
+                        "required": [
+                          "status",
+                          "unmet_gates",
+                          "retry_hint",
+                          "policy_tags"
+                        ],
+                        "type": "object"
+                      }
+                    ]
+                  },
+                  "scenario_id": {
+                    "description": "Scenario identifier.",
+                    "type": "string"
+                  },
+                  "status": {
+                    "enum": [
+                      "active",
+                      "completed",
+                      "failed"
+                    ],
+                    "type": "string"
+                  },
+                  "version": {
+                    "description": "Run version; advances on each trigger or submission.",
+                    "minimum": 0,
+                    "type": "integer"
+                  }
+                },
+                "required": [
+                  "run_id",
+                  "scenario_id",
+                  "current_stage_id",
+                  "status",
+                  "last_decision",
+                  "issued_packet_ids",
+                  "safe_summary",
+                  "version"
+                ],
+                "type": "object"
+              }
+            ]
+          }
+        },
+        "required": [
+          "scenario_id",
+          "run_id",
+          "found",
+          "status"
+        ],
+        "type": "object"
+      },
+      "type": "array"
+    }
+  },
+  "required": [
+    "results"
+  ],
+  "type": "object"
+} as const;
+
+export interface ScenarioLeaseAcquireRequest {
+  /** Lease acquisition request. */
+  request: Record<string, JsonValue>;
+  /** Scenario identifier. */
+  scenario_id: string;
+}
+
+export interface ScenarioLeaseAcquireResponse {
+  acquired_at: ScenarioLeaseAcquireResponseAcquiredAt;
+  expires_at: ScenarioLeaseAcquireResponseExpiresAt;
+  /** Caller-chosen lease holder identifier. Constraints: Min length: 1; Max length: 256. */
+  holder_id: string;
+  /** Run identifier. */
+  run_id: string;
+}
+
+export type ScenarioLeaseAcquireResponseAcquiredAt = ScenarioLeaseAcquireResponseAcquiredAtUnixMillis | ScenarioLeaseAcquireResponseAcquiredAtLogical;
+
+export interface ScenarioLeaseAcquireResponseAcquiredAtUnixMillis {
+  /** Constraints: Const: "unix_millis". */
+  kind: "unix_millis";
+  value: number;
+}
+
+export interface ScenarioLeaseAcquireResponseAcquiredAtLogical {
+  /** Constraints: Const: "logical". */
+  kind: "logical";
+  /** Constraints: Minimum: 0. */
+  value: number;
+}
+
+export type ScenarioLeaseAcquireResponseExpiresAt = ScenarioLeaseAcquireResponseExpiresAtUnixMillis | ScenarioLeaseAcquireResponseExpiresAtLogical;
+
+export interface ScenarioLeaseAcquireResponseExpiresAtUnixMillis {
+  /** Constraints: Const: "unix_millis". */
+  kind: "unix_millis";
+  value: number;
+}
+
+export interface ScenarioLeaseAcquireResponseExpiresAtLogical {
+  /** Constraints: Const: "logical". */
+  kind: "logical";
+  /** Constraints: Minimum: 0. */
+  value: number;
+}
+
+export const ScenarioLeaseAcquire_INPUT_SCHEMA = {
+  "$schema": "https://json-schema.org/draft/2020-12/schema",
+  "additionalProperties": false,
+  "properties": {
+    "request": {
+      "additionalProperties": false,
+      "description": "Lease acquisition request.",
+      "properties": {
+        "holder_id": {
+          "description": "Caller-chosen lease holder identifier.",
+          "maxLength": 256,
+          "minLength": 1,
+          "type": "string"
+        },
+        "namespace_id": {
+          "description": "Namespace identifier.",
+          "minimum": 1,
+          "type": "integer"
+        },
+        "requested_at": {
+          "oneOf": [
+            {
+              "additionalProperties": false,
+              "properties": {
+                "kind": {
+                  "const": "unix_millis"
+                },
+                "value": {
+                  "type": "integer"
+                }
+              },
+              "required": [
+                "kind",
+                "value"
+              ],
+              "type": "object"
+            },
+            {
+              "additionalProperties": false,
+              "properties": {
+                "kind": {
+                  "const": "logical"
+                },
+                "value": {
+                  "minimum": 0,
+                  "type": "integer"
+                }
+              },
+              "required": [
+                "kind",
+                "value"
+              ],
+              "type": "object"
+            }
+          ]
+        },
+        "run_id": {
+          "description": "Run identifier.",
+          "type": "string"
+        },
+        "tenant_id": {
+          "description": "Tenant identifier.",
+          "minimum": 1,
+          "type": "integer"
+        },
+        "ttl_ms": {
+          "description": "Lease duration in milliseconds of server time.",
+          "maximum": 3600000,
+          "minimum": 1,
+          "type": "integer"
+        }
+      },
+      "required": [
+        "tenant_id",
+        "namespace_id",
+        "run_id",
+        "holder_id",
+        "ttl_ms",
+        "requested_at"
+      ],
+      "type": "object"
+    },
+    "scenario_id": {
+      "description": "Scenario identifier.",
+      "type": "string"
+    }
+  },
+  "required": [
+    "scenario_id",
+    "request"
+  ],
+  "type": "object"
+} as const;
+
+export const ScenarioLeaseAcquire_OUTPUT_SCHEMA = {
+  "additionalProperties": false,
+  "properties": {
+    "acquired_at": {
+      "oneOf": [
+        {
+          "additionalProperties": false,
+          "properties": {
+            "kind": {
+              "const": "unix_millis"
+            },
+            "value": {
+              "type": "integer"
+            }
+          },
+          "required": [
+            "kind",
+            "value"
+          ],
+          "type": "object"
+        },
+        {
+          "additionalProperties": false,
+          "properties": {
+            "kind": {
+              "const": "logical"
+            },
+            "value": {
+              "minimum": 0,
+              "type": "integer"
+            }
+          },
+          "required": [
+            "kind",
+            "value"
+          ],
+          "type": "object"
+        }
+      ]
+    },
+    "expires_at": {
+      "oneOf": [
+        {
+          "additionalProperties": false,
+          "properties": {
+            "kind": {
+              "const": "unix_millis"
+            },
+            "value": {
+              "type": "integer"
+            }
+          },
+          "required": [
+            "kind",
+            "value"
+          ],
+          "type": "object"
+        },
+        {
+          "additionalProperties": false,
+          "properties": {
+            "kind": {
+              "const": "logical"
+            },
+            "value": {
+              "minimum": 0,
+              "type": "integer"
+            }
+          },
+          "required": [
+            "kind",
+            "value"
+          ],
+          "type": "object"
+        }
+      ]
+    },
+    "holder_id": {
+      "description": "Caller-chosen lease holder identifier.",
+      "maxLength": 256,
+      "minLength": 1,
+      "type": "string"
+    },
+    "run_id": {
+      "description": "Run identifier.",
+      "type": "string"
+    }
+  },
+  "required": [
+    "run_id",
+    "holder_id",
+    "acquired_at",
+    "expires_at"
+  ],
+  "type": "object"
+} as const;
+
+export interface ScenarioLeaseReleaseRequest {
+  /** Lease release request. */
+  request: Record<string, JsonValue>;
+  /** Scenario identifier. */
+  scenario_id: string;
+}
+
+export interface ScenarioLeaseReleaseResponse {
+  /** Whether an active lease held by the caller was released. */
+  released: boolean;
+}
+
+export const ScenarioLeaseRelease_INPUT_SCHEMA = {
+  "$schema": "https://json-schema.org/draft/2020-12/schema",
+  "additionalProperties": false,
+  "properties": {
+    "request": {
+      "additionalProperties": false,
+      "description": "Lease release request.",
+      "properties": {
+        "holder_id": {
+          "description": "Caller-chosen lease holder identifier.",
+          "maxLength": 256,
+          "minLength": 1,
+          "type": "string"
+        },
+        "namespace_id": {
+          "description": "Namespace identifier.",
+          "minimum": 1,
+          "type": "integer"
+        },
+        "requested_at": {
+          "oneOf": [
+            {
+              "additionalProperties": false,
+              "properties": {
+                "kind": {
+                  "const": "unix_millis"
+                },
+                "value": {
+                  "type": "integer"
+                }
+              },
+              "required": [
+                "kind",
+                "value"
+              ],
+              "type": "object"
+            },
+            {
+              "additionalProperties": false,
+              "properties": {
+                "kind": {
+                  "const": "logical"
+                },
+                "value": {
+                  "minimum": 0,
+                  "type": "integer"
+                }
+              },
+              "required": [
+                "kind",
+                "value"
+              ],
+              "type": "object"
+            }
+          ]
+        },
+        "run_id": {
+          "description": "Run identifier.",
+          "type": "string"
+        },
+        "tenant_id": {
+          "description": "Tenant identifier.",
+          "minimum": 1,
+          "type": "integer"
+        }
+      },
+      "required": [
+        "tenant_id",
+        "namespace_id",
+        "run_id",
+        "holder_id",
+        "requested_at"
+      ],
+      "type": "object"
+    },
+    "scenario_id": {
+      "description": "Scenario identifier.",
+      "type": "string"
+    }
+  },
+  "required": [
+    "scenario_id",
+    "request"
+  ],
+  "type": "object"
+} as const;
+
+export const ScenarioLeaseRelease_OUTPUT_SCHEMA = {
+  "additionalProperties": false,
+  "properties": {
+    "released": {
+      "description": "Whether an active lease held by the caller was released.",
+      "type": "boolean"
+    }
+  },
+  "required": [
+    "released"
+  ],
+  "type": "object"
+} as const;
+
+export interface JsonRpcErrorData {
+  kind: string;
+  request_id?: string;
+  retry_after_ms?: number;
+  retryable: boolean;
+}
+
+export interface JsonRpcError {
+  code: number;
+  message: string;
+  data?: JsonRpcErrorData;
+}
+
+export type ToolResult<T> = { ok: true; value: T } | { ok: false; error: JsonRpcError };
+
+export type ScenarioDefineResult = ToolResult<ScenarioDefineResponse>;
+export type ScenarioStartResult = ToolResult<ScenarioStartResponse>;
+export type ScenarioStatusResult = ToolResult<ScenarioStatusResponse>;
+export type ScenarioNextResult = ToolResult<ScenarioNextResponse>;
+export type ScenarioSubmitResult = ToolResult<ScenarioSubmitResponse>;
+export type ScenarioTriggerResult = ToolResult<ScenarioTriggerResponse>;
+export type EvidenceQueryResult = ToolResult<EvidenceQueryResponse>;
+export type RunpackExportResult = ToolResult<RunpackExportResponse>;
+export type RunpackVerifyResult = ToolResult<RunpackVerifyResponse>;
+export type ProvidersListResult = ToolResult<ProvidersListResponse>;
+export type ProviderContractGetResult = ToolResult<ProviderContractGetResponse>;
+export type ProviderCheckSchemaGetResult = ToolResult<ProviderCheckSchemaGetResponse>;
+export type SchemasRegisterResult = ToolResult<SchemasRegisterResponse>;
+export type SchemasListResult = ToolResult<SchemasListResponse>;
+export type SchemasGetResult = ToolResult<SchemasGetResponse>;
+export type ScenariosListResult = ToolResult<ScenariosListResponse>;
+export type PrecheckResult = ToolResult<PrecheckResponse>;
+export type DecisionGateDocsSearchResult = ToolResult<DecisionGateDocsSearchResponse>;
+export type ScenariosStatusBulkResult = ToolResult<ScenariosStatusBulkResponse>;
+export type ScenarioLeaseAcquireResult = ToolResult<ScenarioLeaseAcquireResponse>;
+export type ScenarioLeaseReleaseResult = ToolResult<ScenarioLeaseReleaseResponse>;
+
+export abstract class GeneratedDecisionGateClient {
+  protected abstract callTool<T>(name: string, arguments_: object): Promise<T>;
+
+  /** Maps a thrown error to a JSON-RPC error; other errors are rethrown. */
+  protected jsonRpcErrorFrom(_error: unknown): JsonRpcError | undefined {
+    return undefined;
+  }
+
+  protected async callToolResult<T>(name: string, arguments_: object): Promise<ToolResult<T>> {
+    try {
+      return { ok: true, value: await this.callTool<T>(name, arguments_) };
+    } catch (error) {
+      const rpcError = this.jsonRpcErrorFrom(error);
+      if (rpcError === undefined) {
+        throw error;
+      }
+      return { ok: false, error: rpcError };
+    }
+  }
+
+  /**
+   * Register a ScenarioSpec, validate it, and return the canonical hash used for integrity checks.
+   *
+   * Notes:
+   * - Use before starting runs; scenario_id becomes the stable handle for later calls.
+   * - Validates stage/gate/condition IDs, RET trees, and condition references.
+   * - Spec hash is deterministic; store it for audit and runpack integrity.
+   * - Fails closed on invalid specs or duplicate scenario IDs.
+   *
+   * Examples:
+   * - Register the example scenario spec.
+   *   Input:
+   *   ```json
+   *   {
+   *     "spec": {
+   *       "conditions": [
+   *         {
+   *           "comparator": "equals",
+   *           "condition_id": "env_is_prod",
+   *           "expected": "production",
+   *           "policy_tags": [],
+   *           "query": {
+   *             "check_id": "get",
+   *             "params": {
+   *               "key": "DEPLOY_ENV"
+   *             },
+   *             "provider_id": "env"
+   *           }
+   *         },
+   *         {
+   *           "comparator": "equals",
+   *           "condition_id": "after_freeze",
+   *           "expected": true,
+   *           "policy_tags": [],
+   *           "query": {
+   *             "check_id": "after",
+   *             "params": {
+   *               "timestamp": 1710000000000
+   *             },
+   *             "provider_id": "time"
+   *           }
+   *         }
+   *       ],
+   *       "default_tenant_id": null,
+   *       "namespace_id": 1,
+   *       "policies": [],
+   *       "scenario_id": "example-scenario",
+   *       "schemas": [],
+   *       "spec_version": "v1",
+   *       "stages": [
+   *         {
+   *           "advance_to": {
+   *             "kind": "terminal"
+   *           },
+   *           "entry_packets": [
+   *             {
+   *               "content_type": "application/json",
+   *               "expiry": null,
+   *               "packet_id": "packet-hello",
+   *               "payload": {
+   *                 "kind": "json",
+   *                 "value": {
+   *                   "message": "hello",
+   *                   "purpose": "scenario entry packet"
+   *                 }
+   *               },
+   *               "policy_tags": [],
+   *               "schema_id": "schema-hello",
+   *               "visibility_labels": [
+   *                 "public"
+   *               ]
+   *             }
+   *           ],
+   *           "gates": [
+   *             {
+   *               "gate_id": "env_gate",
+   *               "requirement": {
+   *                 "Condition": "env_is_prod"
+   *               }
+   *             },
+   *             {
+   *               "gate_id": "time_gate",
+   *               "requirement": {
+   *                 "Condition": "after_freeze"
+   *               }
+   *             }
+   *           ],
+   *           "on_timeout": "fail",
+   *           "stage_id": "main",
+   *           "timeout": null
+   *         }
+   *       ]
+   *     }
+   *   }
+   *   ```
+   *   Output:
+   *   ```json
+   *   {
+   *     "scenario_id": "example-scenario",
+   *     "spec_hash": {
+   *       "algorithm": "sha256",
+   *       "value": "5c3a5b6bce0f4a2c9e22c4fa6a1e6d8d90b0f2dfed1b7f1e9b3d3b3d1f0c9b21"
+   *     }
+   *   }
    *   ```
    */
   public scenario_define(request: ScenarioDefineRequest): Promise<ScenarioDefineResponse> {
     return this.callTool<ScenarioDefineResponse>("scenario_define", request);
   }
 
+  /** Calls `scenario_define` and returns JSON-RPC errors as a result instead of throwing. */
+  public scenario_define_result(request: ScenarioDefineRequest): Promise<ScenarioDefineResult> {
+    return this.callToolResult<ScenarioDefineResponse>("scenario_define", request);
+  }
+
   /**
    * Create a new run state for a scenario and optionally emit entry packets.
    *
@@ -8230,6 +9363,11 @@ export abstract class GeneratedDecisionGateClient {
     return this.callTool<ScenarioStartResponse>("scenario_start", request);
   }
 
+  /** Calls `scenario_start` and returns JSON-RPC errors as a result instead of throwing. */
+  public scenario_start_result(request: ScenarioStartRequest): Promise<ScenarioStartResult> {
+    return this.callToolResult<ScenarioStartResponse>("scenario_start", request);
+  }
+
   /**
    * Fetch a read-only run snapshot and safe summary without changing state.
    *
@@ -8237,6 +9375,7 @@ export abstract class GeneratedDecisionGateClient {
    * - Use for polling or UI state; does not evaluate gates.
    * - Safe summaries omit evidence values and may include retry hints.
    * - Returns issued packet IDs to help track disclosures.
+   * - Set wait_for_change to long-poll until the run changes or max_wait_ms elapses.
    *
    * Examples:
    * - Poll run status without advancing the run.
@@ -8265,7 +9404,41 @@ export abstract class GeneratedDecisionGateClient {
    *     "run_id": "run-0001",
    *     "safe_summary": null,
    *     "scenario_id": "example-scenario",
-   *     "status": "active"
+   *     "status": "active",
+   *     "version": 0
+   *   }
+   *   ```
+   * - Long-poll run status until it changes or the wait elapses.
+   *   Input:
+   *   ```json
+   *   {
+   *     "request": {
+   *       "correlation_id": null,
+   *       "namespace_id": 1,
+   *       "requested_at": {
+   *         "kind": "unix_millis",
+   *         "value": 1710000000000
+   *       },
+   *       "run_id": "run-0001",
+   *       "tenant_id": 1
+   *     },
+   *     "scenario_id": "example-scenario",
+   *     "wait_for_change": {
+   *       "max_wait_ms": 10000
+   *     }
+   *   }
+   *   ```
+   *   Output:
+   *   ```json
+   *   {
+   *     "current_stage_id": "main",
+   *     "issued_packet_ids": [],
+   *     "last_decision": null,
+   *     "run_id": "run-0001",
+   *     "safe_summary": null,
+   *     "scenario_id": "example-scenario",
+   *     "status": "active",
+   *     "version": 0
    *   }
    *   ```
    */
@@ -8273,6 +9446,11 @@ export abstract class GeneratedDecisionGateClient {
     return this.callTool<ScenarioStatusResponse>("scenario_status", request);
   }
 
+  /** Calls `scenario_status` and returns JSON-RPC errors as a result instead of throwing. */
+  public scenario_status_result(request: ScenarioStatusRequest): Promise<ScenarioStatusResult> {
+    return this.callToolResult<ScenarioStatusResponse>("scenario_status", request);
+  }
+
   /**
    * Evaluate gates in response to an agent-driven next request.
    *
@@ -8280,6 +9458,7 @@ export abstract class GeneratedDecisionGateClient {
    * - Idempotent by trigger_id; repeated calls return the same decision.
    * - Records decision, evidence, and packet disclosures in run state.
    * - Requires an active run; completed or failed runs do not advance.
+   * - While the run is leased, only the lease holder may advance it; pass lease_holder.
    * - Optional feedback can include gate trace or evidence when permitted by server feedback policy.
    *
    * Examples:
@@ -8375,13 +9554,21 @@ export abstract class GeneratedDecisionGateClient {
     return this.callTool<ScenarioNextResponse>("scenario_next", request);
   }
 
+  /** Calls `scenario_next` and returns JSON-RPC errors as a result instead of throwing. */
+  public scenario_next_result(request: ScenarioNextRequest): Promise<ScenarioNextResult> {
+    return this.callToolResult<ScenarioNextResponse>("scenario_next", request);
+  }
+
   /**
    * Submit external artifacts into run state for audit and later evaluation.
    *
    * Notes:
    * - Payload is hashed and stored as a submission record.
+   * - Payload is persisted in run state/runpack logs; do not send raw secrets.
    * - Does not advance the run by itself.
    * - Use for artifacts the model or operator supplies.
+   * - Set expected_version to the version from scenario_status to reject the submit with a conflict if the run advanced; re-read and retry.
+   * - While the run is leased, only the lease holder may submit; pass lease_holder.
    *
    * Examples:
    * - Submit an external artifact for audit and later evaluation.
@@ -8441,13 +9628,20 @@ export abstract class GeneratedDecisionGateClient {
     return this.callTool<ScenarioSubmitResponse>("scenario_submit", request);
   }
 
+  /** Calls `scenario_submit` and returns JSON-RPC errors as a result instead of throwing. */
+  public scenario_submit_result(request: ScenarioSubmitRequest): Promise<ScenarioSubmitResult> {
+    return this.callToolResult<ScenarioSubmitResponse>("scenario_submit", request);
+  }
+
   /**
    * Submit a trigger event (scheduler/external) and evaluate the run.
    *
    * Notes:
    * - Trigger time is supplied by the caller; no wall-clock reads.
    * - Records the trigger event and resulting decision.
+   * - Payload is persisted in run state/runpack logs; do not send raw secrets.
    * - Use for time-based or external system triggers.
+   * - While the run is leased, only the lease holder may trigger; pass lease_holder.
    *
    * Examples:
    * - Advance a run from a scheduler or external trigger.
@@ -8498,6 +9692,11 @@ export abstract class GeneratedDecisionGateClient {
     return this.callTool<ScenarioTriggerResponse>("scenario_trigger", request);
   }
 
+  /** Calls `scenario_trigger` and returns JSON-RPC errors as a result instead of throwing. */
+  public scenario_trigger_result(request: ScenarioTriggerRequest): Promise<ScenarioTriggerResult> {
+    return this.callToolResult<ScenarioTriggerResponse>("scenario_trigger", request);
+  }
+
   /**
    * Query an evidence provider with full run context and disclosure policy.
    *
@@ -8562,12 +9761,19 @@ export abstract class GeneratedDecisionGateClient {
     return this.callTool<EvidenceQueryResponse>("evidence_query", request);
   }
 
+  /** Calls `evidence_query` and returns JSON-RPC errors as a result instead of throwing. */
+  public evidence_query_result(request: EvidenceQueryRequest): Promise<EvidenceQueryResult> {
+    return this.callToolResult<EvidenceQueryResponse>("evidence_query", request);
+  }
+
   /**
    * Export deterministic runpack artifacts for offline verification.
    *
    * Notes:
    * - Writes manifest and logs to output_dir; generated_at is recorded in the manifest.
    * - include_verification adds a verification report artifact.
+   * - include_evaluation_audit adds evaluation_audit.json: spec, run config, evidence, and result hashes for every decision and submission.
+   * - Export-time report.checked_files excludes verifier_report.json; offline runpack_verify checked_files includes it (+1 for the same runpack).
    * - Use after runs complete or for audit snapshots.
    *
    * Examples:
@@ -8645,6 +9851,11 @@ export abstract class GeneratedDecisionGateClient {
     return this.callTool<RunpackExportResponse>("runpack_export", request);
   }
 
+  /** Calls `runpack_export` and returns JSON-RPC errors as a result instead of throwing. */
+  public runpack_export_result(request: RunpackExportRequest): Promise<RunpackExportResult> {
+    return this.callToolResult<RunpackExportResponse>("runpack_export", request);
+  }
+
   /**
    * Verify a runpack manifest and artifacts offline.
    *
@@ -8678,6 +9889,11 @@ export abstract class GeneratedDecisionGateClient {
     return this.callTool<RunpackVerifyResponse>("runpack_verify", request);
   }
 
+  /** Calls `runpack_verify` and returns JSON-RPC errors as a result instead of throwing. */
+  public runpack_verify_result(request: RunpackVerifyRequest): Promise<RunpackVerifyResult> {
+    return this.callToolResult<RunpackVerifyResponse>("runpack_verify", request);
+  }
+
   /**
    * List registered evidence providers and capabilities summary.
    *
@@ -8710,6 +9926,11 @@ export abstract class GeneratedDecisionGateClient {
     return this.callTool<ProvidersListResponse>("providers_list", request);
   }
 
+  /** Calls `providers_list` and returns JSON-RPC errors as a result instead of throwing. */
+  public providers_list_result(request: ProvidersListRequest): Promise<ProvidersListResult> {
+    return this.callToolResult<ProvidersListResponse>("providers_list", request);
+  }
+
   /**
    * Fetch the canonical provider contract JSON and hash for a provider.
    *
@@ -8755,6 +9976,11 @@ export abstract class GeneratedDecisionGateClient {
     return this.callTool<ProviderContractGetResponse>("provider_contract_get", request);
   }
 
+  /** Calls `provider_contract_get` and returns JSON-RPC errors as a result instead of throwing. */
+  public provider_contract_get_result(request: ProviderContractGetRequest): Promise<ProviderContractGetResult> {
+    return this.callToolResult<ProviderContractGetResponse>("provider_contract_get", request);
+  }
+
   /**
    * Fetch check schema details (params/result/comparators) for a provider.
    *
@@ -8825,6 +10051,11 @@ export abstract class GeneratedDecisionGateClient {
     return this.callTool<ProviderCheckSchemaGetResponse>("provider_check_schema_get", request);
   }
 
+  /** Calls `provider_check_schema_get` and returns JSON-RPC errors as a result instead of throwing. */
+  public provider_check_schema_get_result(request: ProviderCheckSchemaGetRequest): Promise<ProviderCheckSchemaGetResult> {
+    return this.callToolResult<ProviderCheckSchemaGetResponse>("provider_check_schema_get", request);
+  }
+
   /**
    * Register a data shape schema for a tenant and namespace.
    *
@@ -8895,6 +10126,11 @@ export abstract class GeneratedDecisionGateClient {
     return this.callTool<SchemasRegisterResponse>("schemas_register", request);
   }
 
+  /** Calls `schemas_register` and returns JSON-RPC errors as a result instead of throwing. */
+  public schemas_register_result(request: SchemasRegisterRequest): Promise<SchemasRegisterResult> {
+    return this.callToolResult<SchemasRegisterResponse>("schemas_register", request);
+  }
+
   /**
    * List registered data shapes for a tenant and namespace.
    *
@@ -8949,6 +10185,11 @@ export abstract class GeneratedDecisionGateClient {
     return this.callTool<SchemasListResponse>("schemas_list", request);
   }
 
+  /** Calls `schemas_list` and returns JSON-RPC errors as a result instead of throwing. */
+  public schemas_list_result(request: SchemasListRequest): Promise<SchemasListResult> {
+    return this.callToolResult<SchemasListResponse>("schemas_list", request);
+  }
+
   /**
    * Fetch a specific data shape by identifier and version.
    *
@@ -9000,6 +10241,11 @@ export abstract class GeneratedDecisionGateClient {
     return this.callTool<SchemasGetResponse>("schemas_get", request);
   }
 
+  /** Calls `schemas_get` and returns JSON-RPC errors as a result instead of throwing. */
+  public schemas_get_result(request: SchemasGetRequest): Promise<SchemasGetResult> {
+    return this.callToolResult<SchemasGetResponse>("schemas_get", request);
+  }
+
   /**
    * List registered scenarios for a tenant and namespace.
    *
@@ -9039,6 +10285,11 @@ export abstract class GeneratedDecisionGateClient {
     return this.callTool<ScenariosListResponse>("scenarios_list", request);
   }
 
+  /** Calls `scenarios_list` and returns JSON-RPC errors as a result instead of throwing. */
+  public scenarios_list_result(request: ScenariosListRequest): Promise<ScenariosListResult> {
+    return this.callToolResult<ScenariosListResponse>("scenarios_list", request);
+  }
+
   /**
    * Evaluate a scenario against asserted data without mutating state.
    *
@@ -9087,6 +10338,11 @@ export abstract class GeneratedDecisionGateClient {
     return this.callTool<PrecheckResponse>("precheck", request);
   }
 
+  /** Calls `precheck` and returns JSON-RPC errors as a result instead of throwing. */
+  public precheck_result(request: PrecheckRequest): Promise<PrecheckResult> {
+    return this.callToolResult<PrecheckResponse>("precheck", request);
+  }
+
   /**
    * Search Decision Gate documentation for runtime guidance.
    *
@@ -9134,6 +10390,171 @@ export abstract class GeneratedDecisionGateClient {
     return this.callTool<DecisionGateDocsSearchResponse>("decision_gate_docs_search", request);
   }
 
+  /** Calls `decision_gate_docs_search` and returns JSON-RPC errors as a result instead of throwing. */
+  public decision_gate_docs_search_result(request: DecisionGateDocsSearchRequest): Promise<DecisionGateDocsSearchResult> {
+    return this.callToolResult<DecisionGateDocsSearchResponse>("decision_gate_docs_search", request);
+  }
+
+  /**
+   * Fetch read-only status snapshots for several runs in one call.
+   *
+   * Notes:
+   * - Requires tenant_id and namespace_id; every run is resolved within that scope.
+   * - Accepts up to 100 runs; results are returned in request order.
+   * - Unknown scenarios or runs yield found=false without failing the call.
+   * - Reads do not record tool calls or modify run state.
+   *
+   * Examples:
+   * - Fetch status for a known run and a missing run.
+   *   Input:
+   *   ```json
+   *   {
+   *     "namespace_id": 1,
+   *     "runs": [
+   *       {
+   *         "run_id": "run-0001",
+   *         "scenario_id": "example-scenario"
+   *       },
+   *       {
+   *         "run_id": "run-missing",
+   *         "scenario_id": "example-scenario"
+   *       }
+   *     ],
+   *     "tenant_id": 1
+   *   }
+   *   ```
+   *   Output:
+   *   ```json
+   *   {
+   *     "results": [
+   *       {
+   *         "found": true,
+   *         "run_id": "run-0001",
+   *         "scenario_id": "example-scenario",
+   *         "status": {
+   *           "current_stage_id": "main",
+   *           "issued_packet_ids": [],
+   *           "last_decision": null,
+   *           "run_id": "run-0001",
+   *           "safe_summary": null,
+   *           "scenario_id": "example-scenario",
+   *           "status": "active",
+   *           "version": 0
+   *         }
+   *       },
+   *       {
+   *         "found": false,
+   *         "run_id": "run-missing",
+   *         "scenario_id": "example-scenario",
+   *         "status": null
+   *       }
+   *     ]
+   *   }
+   *   ```
+   */
+  public scenarios_status_bulk(request: ScenariosStatusBulkRequest): Promise<ScenariosStatusBulkResponse> {
+    return this.callTool<ScenariosStatusBulkResponse>("scenarios_status_bulk", request);
+  }
+
+  /** Calls `scenarios_status_bulk` and returns JSON-RPC errors as a result instead of throwing. */
+  public scenarios_status_bulk_result(request: ScenariosStatusBulkRequest): Promise<ScenariosStatusBulkResult> {
+    return this.callToolResult<ScenariosStatusBulkResponse>("scenarios_status_bulk", request);
+  }
+
+  /**
+   * Acquire or renew an advisory lease so only one holder can advance, submit to, or trigger a run.
+   *
+   * Notes:
+   * - While the lease is active, scenario_next, scenario_submit, and scenario_trigger calls without the matching lease_holder fail with a conflict.
+   * - The lease expires ttl_ms after the server receives the request; requested_at is recorded but never used to judge expiry. The holder renews by acquiring again.
+   * - Fails with a conflict while another holder's lease is active.
+   * - Leases live in server memory and are lost on restart.
+   *
+   * Examples:
+   * - Lease a run for 30 seconds before a series of submits.
+   *   Input:
+   *   ```json
+   *   {
+   *     "request": {
+   *       "holder_id": "agent-alpha",
+   *       "namespace_id": 1,
+   *       "requested_at": {
+   *         "kind": "unix_millis",
+   *         "value": 1710000000000
+   *       },
+   *       "run_id": "run-0001",
+   *       "tenant_id": 1,
+   *       "ttl_ms": 30000
+   *     },
+   *     "scenario_id": "example-scenario"
+   *   }
+   *   ```
+   *   Output:
+   *   ```json
+   *   {
+   *     "acquired_at": {
+   *       "kind": "unix_millis",
+   *       "value": 1710000000000
+   *     },
+   *     "expires_at": {
+   *       "kind": "unix_millis",
+   *       "value": 1710000030000
+   *     },
+   *     "holder_id": "agent-alpha",
+   *     "run_id": "run-0001"
+   *   }
+   *   ```
+   */
+  public scenario_lease_acquire(request: ScenarioLeaseAcquireRequest): Promise<ScenarioLeaseAcquireResponse> {
+    return this.callTool<ScenarioLeaseAcquireResponse>("scenario_lease_acquire", request);
+  }
+
+  /** Calls `scenario_lease_acquire` and returns JSON-RPC errors as a result instead of throwing. */
+  public scenario_lease_acquire_result(request: ScenarioLeaseAcquireRequest): Promise<ScenarioLeaseAcquireResult> {
+    return this.callToolResult<ScenarioLeaseAcquireResponse>("scenario_lease_acquire", request);
+  }
+
+  /**
+   * Release an advisory run lease before it expires.
+   *
+   * Notes:
+   * - Returns released=false when the caller held no active lease.
+   * - Fails with a conflict while another holder's lease is active.
+   *
+   * Examples:
+   * - Release a run lease once the holder is done.
+   *   Input:
+   *   ```json
+   *   {
+   *     "request": {
+   *       "holder_id": "agent-alpha",
+   *       "namespace_id": 1,
+   *       "requested_at": {
+   *         "kind": "unix_millis",
+   *         "value": 1710000000000
+   *       },
+   *       "run_id": "run-0001",
+   *       "tenant_id": 1
+   *     },
+   *     "scenario_id": "example-scenario"
+   *   }
+   *   ```
+   *   Output:
+   *   ```json
+   *   {
+   *     "released": true
+   *   }
+   *   ```
+   */
+  public scenario_lease_release(request: ScenarioLeaseReleaseRequest): Promise<ScenarioLeaseReleaseResponse> {
+    return this.callTool<ScenarioLeaseReleaseResponse>("scenario_lease_release", request);
+  }
+
+  /** Calls `scenario_lease_release` and returns JSON-RPC errors as a result instead of throwing. */
+  public scenario_lease_release_result(request: ScenarioLeaseReleaseRequest): Promise<ScenarioLeaseReleaseResult> {
+    return this.callToolResult<ScenarioLeaseReleaseResponse>("scenario_lease_release", request);
+  }
+
 }
 export type SchemaValidator = (schema: unknown, payload: unknown) => void;
 
@@ -9454,3 +10875,51 @@ export async function validateDecisionGateDocsSearchResponseWithAjv(payload: Dec
   return validateSchemaWithAjv(DecisionGateDocsSearch_OUTPUT_SCHEMA, payload);
 }
 
+export function validateScenariosStatusBulkRequest(payload: ScenariosStatusBulkRequest, validator: SchemaValidator): void {
+  validateSchemaWith(validator, ScenariosStatusBulk_INPUT_SCHEMA, payload);
+}
+
+export function validateScenariosStatusBulkResponse(payload: ScenariosStatusBulkResponse, validator: SchemaValidator): void {
+  validateSchemaWith(validator, ScenariosStatusBulk_OUTPUT_SCHEMA, payload);
+}
+
+export async function validateScenariosStatusBulkRequestWithAjv(payload: ScenariosStatusBulkRequest): Promise<void> {
+  return validateSchemaWithAjv(ScenariosStatusBulk_INPUT_SCHEMA, payload);
+}
+
+export async function validateScenariosStatusBulkResponseWithAjv(payload: ScenariosStatusBulkResponse): Promise<void> {
+  return validateSchemaWithAjv(ScenariosStatusBulk_OUTPUT_SCHEMA, payload);
+}
+
+export function validateScenarioLeaseAcquireRequest(payload: ScenarioLeaseAcquireRequest, validator: SchemaValidator): void {
+  validateSchemaWith(validator, ScenarioLeaseAcquire_INPUT_SCHEMA, payload);
+}
+
+export function validateScenarioLeaseAcquireResponse(payload: ScenarioLeaseAcquireResponse, validator: SchemaValidator): void {
+  validateSchemaWith(validator, ScenarioLeaseAcquire_OUTPUT_SCHEMA, payload);
+}
+
+export async function validateScenarioLeaseAcquireRequestWithAjv(payload: ScenarioLeaseAcquireRequest): Promise<void> {
+  return validateSchemaWithAjv(ScenarioLeaseAcquire_INPUT_SCHEMA, payload);
+}
+
+export async function validateScenarioLeaseAcquireResponseWithAjv(payload: ScenarioLeaseAcquireResponse): Promise<void> {
+  return validateSchemaWithAjv(ScenarioLeaseAcquire_OUTPUT_SCHEMA, payload);
+}
+
+export function validateScenarioLeaseReleaseRequest(payload: ScenarioLeaseReleaseRequest, validator: SchemaValidator): void {
+  validateSchemaWith(validator, ScenarioLeaseRelease_INPUT_SCHEMA, payload);
+}
+
+export function validateScenarioLeaseReleaseResponse(payload: ScenarioLeaseReleaseResponse, validator: SchemaValidator): void {
+  validateSchemaWith(validator, ScenarioLeaseRelease_OUTPUT_SCHEMA, payload);
+}
+
+export async function validateScenarioLeaseReleaseRequestWithAjv(payload: ScenarioLeaseReleaseRequest): Promise<void> {
+  return validateSchemaWithAjv(ScenarioLeaseRelease_INPUT_SCHEMA, payload);
+}
+
+export async function validateScenarioLeaseReleaseResponseWithAjv(payload: ScenarioLeaseReleaseResponse): Promise<void> {
+  return validateSchemaWithAjv(ScenarioLeaseRelease_OUTPUT_SCHEMA, payload);
+}
+
@@ -6,7 +6,7 @@
 // Dependencies: fetch API, generated SDK surface
 // ============================================================================
 
-import { GeneratedDecisionGateClient } from "./_generated.js";
+import { GeneratedDecisionGateClient, type JsonRpcError, type JsonRpcErrorData } from "./_generated.js";
 import {
   DecisionGateProtocolError,
   DecisionGateRpcError,
@@ -135,6 +135,17 @@ export class DecisionGateClient extends GeneratedDecisionGateClient {
     return firstObj.json as T;
   }
 
+  protected jsonRpcErrorFrom(error: unknown): JsonRpcError | undefined {
+    if (!(error instanceof DecisionGateRpcError)) {
+      return undefined;
+    }
+    return {
+      code: error.code,
+      message: error.message,
+      data: error.data as JsonRpcErrorData | undefined,
+    };
+  }
+
   private nextRequestId(): number {
     this.requestId += 1;
     return this.requestId;
@@ -429,6 +429,8 @@ fn run_tools() -> BTreeSet<ToolName> {
         ToolName::ScenarioTrigger,
         ToolName::ScenarioNext,
         ToolName::ScenarioSubmit,
+        ToolName::ScenarioLeaseAcquire,
+        ToolName::ScenarioLeaseRelease,
         ToolName::Precheck,
     ])
 }
@@ -437,6 +439,7 @@ fn read_tools() -> BTreeSet<ToolName> {
     BTreeSet::from([
         ToolName::ScenarioStatus,
         ToolName::ScenariosList,
+        ToolName::ScenariosStatusBulk,
         ToolName::SchemasList,
         ToolName::SchemasGet,
         ToolName::ProvidersList,
@@ -460,6 +463,9 @@ fn all_tools() -> BTreeSet<ToolName> {
         ToolName::SchemasRegister,
         ToolName::SchemasGet,
         ToolName::ScenariosList,
+        ToolName::ScenariosStatusBulk,
+        ToolName::ScenarioLeaseAcquire,
+        ToolName::ScenarioLeaseRelease,
         ToolName::Precheck,
     ])
 }
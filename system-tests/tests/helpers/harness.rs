@@ -38,9 +38,11 @@ use decision_gate_mcp::config::NamespaceConfig;
 use decision_gate_mcp::config::PolicyConfig;
 use decision_gate_mcp::config::PrincipalConfig;
 use decision_gate_mcp::config::PrincipalRoleConfig;
+use decision_gate_mcp::config::PrincipalScopesConfig;
 use decision_gate_mcp::config::ProviderConfig;
 use decision_gate_mcp::config::ProviderTimeoutConfig;
 use decision_gate_mcp::config::ProviderType;
+use decision_gate_mcp::config::ProviderWarmUpMode;
 use decision_gate_mcp::config::RunStateStoreConfig;
 use decision_gate_mcp::config::SchemaRegistryConfig;
 use decision_gate_mcp::config::ServerAuditConfig;
@@ -50,6 +52,8 @@ use decision_gate_mcp::config::ServerConfig;
 use decision_gate_mcp::config::ServerFeedbackConfig;
 use decision_gate_mcp::config::ServerLimitsConfig;
 use decision_gate_mcp::config::ServerMode;
+use decision_gate_mcp::config::ServerOpenApiConfig;
+use decision_gate_mcp::config::ServerSseConfig;
 use decision_gate_mcp::config::ServerTlsConfig;
 use decision_gate_mcp::config::ServerTlsTermination;
 use decision_gate_mcp::config::ServerToolsConfig;
@@ -145,6 +149,7 @@ pub fn base_http_config(bind: &str) -> DecisionGateConfig {
             auth: Some(ServerAuthConfig {
                 mode: ServerAuthMode::LocalOnly,
                 bearer_tokens: Vec::new(),
+                jwt: None,
                 mtls_subjects: Vec::new(),
                 allowed_tools: Vec::new(),
                 principals: vec![
@@ -156,6 +161,9 @@ pub fn base_http_config(bind: &str) -> DecisionGateConfig {
             audit: ServerAuditConfig::default(),
             feedback: ServerFeedbackConfig::default(),
             tools: ServerToolsConfig::default(),
+            sse: ServerSseConfig::default(),
+            openapi: ServerOpenApiConfig::default(),
+            provider_warm_up: ProviderWarmUpMode::default(),
         },
         namespace: NamespaceConfig {
             allow_default: true,
@@ -185,6 +193,7 @@ pub fn base_http_config_with_bearer(bind: &str, token: &str) -> DecisionGateConf
     config.server.auth = Some(ServerAuthConfig {
         mode: ServerAuthMode::BearerToken,
         bearer_tokens: vec![token.to_string()],
+        jwt: None,
         mtls_subjects: Vec::new(),
         allowed_tools: Vec::new(),
         principals: vec![tenant_admin_principal(token_principal(token), 1, 1)],
@@ -204,6 +213,10 @@ pub fn base_http_config_with_tls(
         key_path: key_path.display().to_string(),
         client_ca_path: None,
         require_client_cert: true,
+        reload_interval_ms: None,
+        max_client_chain_depth: None,
+        client_required_ekus: Vec::new(),
+        ocsp_response_path: None,
     });
     config
 }
@@ -222,6 +235,10 @@ pub fn base_http_config_with_mtls_tls(
         key_path: key_path.display().to_string(),
         client_ca_path: Some(ca_path.display().to_string()),
         require_client_cert,
+        reload_interval_ms: None,
+        max_client_chain_depth: None,
+        client_required_ekus: Vec::new(),
+        ocsp_response_path: None,
     });
     config
 }
@@ -232,6 +249,7 @@ pub fn base_http_config_with_mtls(bind: &str, subject: &str) -> DecisionGateConf
     config.server.auth = Some(ServerAuthConfig {
         mode: ServerAuthMode::Mtls,
         bearer_tokens: Vec::new(),
+        jwt: None,
         mtls_subjects: vec![subject.to_string()],
         allowed_tools: Vec::new(),
         principals: vec![tenant_admin_principal(subject, 1, 1)],
@@ -252,6 +270,7 @@ pub fn base_sse_config(bind: &str) -> DecisionGateConfig {
             auth: Some(ServerAuthConfig {
                 mode: ServerAuthMode::LocalOnly,
                 bearer_tokens: Vec::new(),
+                jwt: None,
                 mtls_subjects: Vec::new(),
                 allowed_tools: Vec::new(),
                 principals: vec![
@@ -263,6 +282,9 @@ pub fn base_sse_config(bind: &str) -> DecisionGateConfig {
             audit: ServerAuditConfig::default(),
             feedback: ServerFeedbackConfig::default(),
             tools: ServerToolsConfig::default(),
+            sse: ServerSseConfig::default(),
+            openapi: ServerOpenApiConfig::default(),
+            provider_warm_up: ProviderWarmUpMode::default(),
         },
         namespace: NamespaceConfig {
             allow_default: true,
@@ -292,6 +314,7 @@ pub fn base_sse_config_with_bearer(bind: &str, token: &str) -> DecisionGateConfi
     config.server.auth = Some(ServerAuthConfig {
         mode: ServerAuthMode::BearerToken,
         bearer_tokens: vec![token.to_string()],
+        jwt: None,
         mtls_subjects: Vec::new(),
         allowed_tools: Vec::new(),
         principals: vec![tenant_admin_principal(token_principal(token), 1, 1)],
@@ -410,6 +433,7 @@ fn tenant_admin_principal(
     PrincipalConfig {
         subject: subject.into(),
         policy_class: Some("prod".to_string()),
+        scopes: PrincipalScopesConfig::default(),
         roles: vec![PrincipalRoleConfig {
             name: "TenantAdmin".to_string(),
             tenant_id: Some(tenant),
@@ -108,6 +108,19 @@ impl StdioMcpClient {
         self.transcript.lock().map_or_else(|_| Vec::new(), |entries| entries.clone())
     }
 
+    /// Sends SIGTERM to the server process and waits for it to exit.
+    #[cfg(unix)]
+    pub fn terminate(&mut self) -> Result<std::process::ExitStatus, String> {
+        let status = Command::new("kill")
+            .args(["-TERM", &self.child.id().to_string()])
+            .status()
+            .map_err(|err| format!("kill failed: {err}"))?;
+        if !status.success() {
+            return Err(format!("kill exited with {status}"));
+        }
+        self.child.wait().map_err(|err| format!("wait failed: {err}"))
+    }
+
     /// Issues a tools/list request.
     pub async fn list_tools(&self) -> Result<Vec<ToolDefinition>, String> {
         let response = self.send_request("tools/list", None).await?;
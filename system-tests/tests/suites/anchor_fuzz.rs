@@ -229,6 +229,7 @@ async fn run_case(
     let trigger_request = ScenarioTriggerRequest {
         scenario_id: define_output.scenario_id.clone(),
         trigger: fixture.trigger(None),
+        lease_holder: None,
     };
     let trigger_input = serde_json::to_value(&trigger_request)?;
     let trigger_result: decision_gate_core::runtime::TriggerResult =
@@ -253,6 +254,7 @@ async fn run_case(
         manifest_name: Some("manifest.json".to_string()),
         generated_at: Timestamp::Logical(4),
         include_verification: false,
+        include_evaluation_audit: false,
     };
     let export_input = serde_json::to_value(&export_request)?;
     let _exported: decision_gate_mcp::tools::RunpackExportResponse =
@@ -50,6 +50,7 @@ use decision_gate_mcp::config::NamespaceAuthorityConfig;
 use decision_gate_mcp::config::NamespaceAuthorityMode;
 use decision_gate_mcp::config::PrincipalConfig;
 use decision_gate_mcp::config::PrincipalRoleConfig;
+use decision_gate_mcp::config::PrincipalScopesConfig;
 use decision_gate_mcp::tools::ScenarioDefineRequest;
 use decision_gate_mcp::tools::ScenarioDefineResponse;
 use decision_gate_mcp::tools::ScenarioStartRequest;
@@ -127,6 +128,7 @@ async fn assetcore_anchor_missing_fails_closed() -> Result<(), Box<dyn std::erro
     let trigger_request = ScenarioTriggerRequest {
         scenario_id: define_output.scenario_id.clone(),
         trigger: fixture.trigger(None),
+        lease_holder: None,
     };
     let trigger_input = serde_json::to_value(&trigger_request)?;
     let trigger_result: TriggerResult =
@@ -216,6 +218,7 @@ async fn assetcore_correlation_id_passthrough() -> Result<(), Box<dyn std::error
     let trigger_request = ScenarioTriggerRequest {
         scenario_id: define_output.scenario_id.clone(),
         trigger: fixture.trigger(Some(correlation_id.clone())),
+        lease_holder: None,
     };
     let trigger_input = serde_json::to_value(&trigger_request)?;
     let trigger_result: TriggerResult =
@@ -390,6 +393,7 @@ async fn namespace_mismatch_rejected() -> Result<(), Box<dyn std::error::Error>>
             auth.principals.push(PrincipalConfig {
                 subject: "loopback".to_string(),
                 policy_class: Some("prod".to_string()),
+                scopes: PrincipalScopesConfig::default(),
                 roles: vec![role],
             });
         }
@@ -28,6 +28,7 @@ use decision_gate_core::core::hashing::HashAlgorithm;
 use decision_gate_core::core::hashing::hash_bytes;
 use decision_gate_mcp::config::PrincipalConfig;
 use decision_gate_mcp::config::PrincipalRoleConfig;
+use decision_gate_mcp::config::PrincipalScopesConfig;
 use decision_gate_mcp::config::ServerAuditConfig;
 use decision_gate_mcp::config::ServerAuthConfig;
 use decision_gate_mcp::config::ServerAuthMode;
@@ -71,11 +72,13 @@ async fn registry_security_audit_events() -> Result<(), Box<dyn std::error::Erro
     config.server.auth = Some(ServerAuthConfig {
         mode: ServerAuthMode::BearerToken,
         bearer_tokens: vec![allowed_token.clone(), denied_token.clone()],
+        jwt: None,
         mtls_subjects: Vec::new(),
         allowed_tools: Vec::new(),
         principals: vec![PrincipalConfig {
             subject: token_subject(&allowed_token),
             policy_class: Some("prod".to_string()),
+            scopes: PrincipalScopesConfig::default(),
             roles: vec![PrincipalRoleConfig {
                 name: "TenantAdmin".to_string(),
                 tenant_id: Some(tenant_id_one()),
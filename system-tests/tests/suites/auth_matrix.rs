@@ -152,6 +152,7 @@ async fn asc_auth_mapping_matrix() -> Result<(), Box<dyn std::error::Error>> {
     let trigger_request = ScenarioTriggerRequest {
         scenario_id: admin_define_output.scenario_id.clone(),
         trigger: admin_fixture.trigger_event("trigger-1", Timestamp::Logical(2)),
+        lease_holder: None,
     };
     admin
         .call_tool_typed::<decision_gate_core::runtime::TriggerResult>(
@@ -170,6 +171,7 @@ async fn asc_auth_mapping_matrix() -> Result<(), Box<dyn std::error::Error>> {
         manifest_name: Some("manifest.json".to_string()),
         generated_at: Timestamp::Logical(5),
         include_verification: true,
+        include_evaluation_audit: false,
     };
     admin
         .call_tool_typed::<decision_gate_mcp::tools::RunpackExportResponse>(
@@ -496,6 +498,7 @@ async fn exercise_mapping(
             let trigger_request = ScenarioTriggerRequest {
                 scenario_id: context.scenario_id.clone(),
                 trigger,
+                lease_holder: None,
             };
             client
                 .call_tool_typed::<decision_gate_core::runtime::TriggerResult>(
@@ -516,6 +519,7 @@ async fn exercise_mapping(
                     agent_id: "auth-matrix".to_string(),
                     time: Timestamp::Logical(5),
                     correlation_id: None,
+                    lease_holder: None,
                 },
                 feedback: None,
             };
@@ -541,6 +545,8 @@ async fn exercise_mapping(
                     content_type: "application/json".to_string(),
                     submitted_at: Timestamp::Logical(6),
                     correlation_id: None,
+                    expected_version: None,
+                    lease_holder: None,
                 },
             };
             client
@@ -561,6 +567,7 @@ async fn exercise_mapping(
                     requested_at: Timestamp::Logical(7),
                     correlation_id: None,
                 },
+                wait_for_change: None,
             };
             client
                 .call_tool_typed::<decision_gate_core::runtime::ScenarioStatus>(
@@ -581,6 +588,7 @@ async fn exercise_mapping(
                 manifest_name: Some("manifest.json".to_string()),
                 generated_at: Timestamp::Logical(8),
                 include_verification: true,
+                include_evaluation_audit: false,
             };
             client
                 .call_tool_typed::<decision_gate_mcp::tools::RunpackExportResponse>(
@@ -598,6 +606,7 @@ async fn exercise_mapping(
                 manifest_name: Some("manifest.json".to_string()),
                 generated_at: Timestamp::Logical(8),
                 include_verification: false,
+                include_evaluation_audit: false,
             };
             expect_unauthorized(
                 client.call_tool("runpack_export", serde_json::to_value(&export_request)?).await,
@@ -662,6 +671,7 @@ async fn exercise_mapping(
                 requested_at: Timestamp::Logical(9),
                 correlation_id: None,
             },
+            wait_for_change: None,
         };
         client
             .call_tool_typed::<decision_gate_core::runtime::ScenarioStatus>(
@@ -252,6 +252,7 @@ async fn cli_mcp_tool_wrappers_conformance() -> Result<(), Box<dyn std::error::E
             requested_at: Timestamp::Logical(2),
             correlation_id: None,
         },
+        wait_for_change: None,
     };
     let status_path = temp_dir.path().join("scenario_status.json");
     write_json_file(&status_path, &status_request)?;
@@ -277,6 +278,7 @@ async fn cli_mcp_tool_wrappers_conformance() -> Result<(), Box<dyn std::error::E
             agent_id: "agent-1".to_string(),
             time: Timestamp::Logical(3),
             correlation_id: None,
+            lease_holder: None,
         },
         feedback: None,
     };
@@ -319,6 +321,8 @@ async fn cli_mcp_tool_wrappers_conformance() -> Result<(), Box<dyn std::error::E
             content_type: "application/json".to_string(),
             submitted_at: Timestamp::Logical(5),
             correlation_id: None,
+            expected_version: None,
+            lease_holder: None,
         },
     };
     let submit_path = temp_dir.path().join("scenario_submit.json");
@@ -356,6 +360,7 @@ async fn cli_mcp_tool_wrappers_conformance() -> Result<(), Box<dyn std::error::E
     let trigger_request = ScenarioTriggerRequest {
         scenario_id: fixture.spec.scenario_id.clone(),
         trigger: trigger_for(&fixture, &run_trigger.run_id, "trigger-1", Timestamp::Logical(7)),
+        lease_holder: None,
     };
     let trigger_path = temp_dir.path().join("scenario_trigger.json");
     write_json_file(&trigger_path, &trigger_request)?;
@@ -569,6 +574,7 @@ async fn cli_mcp_tool_wrappers_conformance() -> Result<(), Box<dyn std::error::E
         manifest_name: Some("manifest.json".to_string()),
         generated_at: Timestamp::Logical(10),
         include_verification: false,
+        include_evaluation_audit: false,
     };
     let export_path = temp_dir.path().join("runpack_export.json");
     write_json_file(&export_path, &export_request)?;
@@ -25,6 +25,7 @@ use decision_gate_core::EvidenceContext;
 use decision_gate_core::EvidenceQuery;
 use decision_gate_core::PacketPayload;
 use decision_gate_core::ProviderId;
+use decision_gate_core::RunId;
 use decision_gate_core::StageId;
 use decision_gate_core::Timestamp;
 use decision_gate_core::TriggerEvent;
@@ -39,10 +40,12 @@ use decision_gate_mcp::tools::RunpackVerifyRequest;
 use decision_gate_mcp::tools::ScenarioDefineRequest;
 use decision_gate_mcp::tools::ScenarioDefineResponse;
 use decision_gate_mcp::tools::ScenarioNextRequest;
+use decision_gate_mcp::tools::ScenarioRunRef;
 use decision_gate_mcp::tools::ScenarioStartRequest;
 use decision_gate_mcp::tools::ScenarioStatusRequest;
 use decision_gate_mcp::tools::ScenarioSubmitRequest;
 use decision_gate_mcp::tools::ScenarioTriggerRequest;
+use decision_gate_mcp::tools::ScenariosStatusBulkRequest;
 use helpers::artifacts::TestReporter;
 use helpers::harness::allocate_bind_addr;
 use helpers::harness::base_http_config;
@@ -166,6 +169,7 @@ async fn schema_conformance_all_tools() -> Result<(), Box<dyn Error>> {
             requested_at: Timestamp::Logical(2),
             correlation_id: None,
         },
+        wait_for_change: None,
     };
     let status_input = serde_json::to_value(&status_request)?;
     let status_schema = tool_schema(&tool_schemas, ToolName::ScenarioStatus)?;
@@ -173,6 +177,26 @@ async fn schema_conformance_all_tools() -> Result<(), Box<dyn Error>> {
     let status_output = client.call_tool("scenario_status", status_input).await?;
     assert_valid(&status_schema.output, &status_output, "scenario_status output")?;
 
+    let bulk_request = ScenariosStatusBulkRequest {
+        tenant_id: run_config.tenant_id,
+        namespace_id: run_config.namespace_id,
+        runs: vec![
+            ScenarioRunRef {
+                scenario_id: define_response.scenario_id.clone(),
+                run_id: run_config.run_id.clone(),
+            },
+            ScenarioRunRef {
+                scenario_id: define_response.scenario_id.clone(),
+                run_id: RunId::new("run-missing"),
+            },
+        ],
+    };
+    let bulk_input = serde_json::to_value(&bulk_request)?;
+    let bulk_schema = tool_schema(&tool_schemas, ToolName::ScenariosStatusBulk)?;
+    assert_valid(&bulk_schema.input, &bulk_input, "scenarios_status_bulk input")?;
+    let bulk_output = client.call_tool("scenarios_status_bulk", bulk_input).await?;
+    assert_valid(&bulk_schema.output, &bulk_output, "scenarios_status_bulk output")?;
+
     let next_request = ScenarioNextRequest {
         scenario_id: define_response.scenario_id.clone(),
         request: NextRequest {
@@ -183,6 +207,7 @@ async fn schema_conformance_all_tools() -> Result<(), Box<dyn Error>> {
             agent_id: "agent-1".to_string(),
             time: Timestamp::Logical(3),
             correlation_id: None,
+            lease_holder: None,
         },
         feedback: None,
     };
@@ -205,6 +230,8 @@ async fn schema_conformance_all_tools() -> Result<(), Box<dyn Error>> {
             content_type: "application/json".to_string(),
             submitted_at: Timestamp::Logical(4),
             correlation_id: None,
+            expected_version: None,
+            lease_holder: None,
         },
     };
     let submit_input = serde_json::to_value(&submit_request)?;
@@ -226,6 +253,7 @@ async fn schema_conformance_all_tools() -> Result<(), Box<dyn Error>> {
             payload: None,
             correlation_id: None,
         },
+        lease_holder: None,
     };
     let trigger_input = serde_json::to_value(&trigger_request)?;
     let trigger_schema = tool_schema(&tool_schemas, ToolName::ScenarioTrigger)?;
@@ -269,6 +297,7 @@ async fn schema_conformance_all_tools() -> Result<(), Box<dyn Error>> {
         manifest_name: Some(manifest_name.clone()),
         generated_at: Timestamp::Logical(7),
         include_verification: true,
+        include_evaluation_audit: false,
     };
     let export_input = serde_json::to_value(&export_request)?;
     let export_schema = tool_schema(&tool_schemas, ToolName::RunpackExport)?;
@@ -165,6 +165,7 @@ async fn execute_fixture_run(
     let trigger_request = ScenarioTriggerRequest {
         scenario_id: define_output.scenario_id.clone(),
         trigger: trigger.clone(),
+        lease_holder: None,
     };
     let trigger_input = serde_json::to_value(&trigger_request)?;
     let trigger_result: decision_gate_core::runtime::TriggerResult =
@@ -179,6 +180,7 @@ async fn execute_fixture_run(
             requested_at: trigger.time,
             correlation_id: trigger.correlation_id.clone(),
         },
+        wait_for_change: None,
     };
     let status_input = serde_json::to_value(&status_request)?;
     let status: decision_gate_core::runtime::ScenarioStatus =
@@ -195,6 +197,7 @@ async fn execute_fixture_run(
         manifest_name: Some("manifest.json".to_string()),
         generated_at: Timestamp::Logical(10),
         include_verification: true,
+        include_evaluation_audit: false,
     };
     let export_input = serde_json::to_value(&export_request)?;
     let _exported: decision_gate_mcp::tools::RunpackExportResponse =
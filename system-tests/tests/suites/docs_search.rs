@@ -64,7 +64,7 @@ async fn docs_search_http_end_to_end() -> Result<(), Box<dyn std::error::Error>>
             "decision_gate_docs_search",
             json!({
                 "query": "",
-                "max_sections": 100
+                "max_sections": 10
             }),
         )
         .await?;
@@ -128,6 +128,7 @@ async fn export_runpack(
     let trigger_request = ScenarioTriggerRequest {
         scenario_id: define_output.scenario_id.clone(),
         trigger: scenario.fixture.trigger_event("trigger-1", Timestamp::Logical(2)),
+        lease_holder: None,
     };
     let trigger_input = serde_json::to_value(&trigger_request)?;
     let _trigger: decision_gate_core::runtime::TriggerResult =
@@ -144,6 +145,7 @@ async fn export_runpack(
         manifest_name: Some(MANIFEST_NAME.to_string()),
         generated_at: Timestamp::Logical(10),
         include_verification: true,
+        include_evaluation_audit: false,
     };
     let export_input = serde_json::to_value(&export_request)?;
     let _exported: decision_gate_mcp::tools::RunpackExportResponse =
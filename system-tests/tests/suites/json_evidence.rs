@@ -241,6 +241,7 @@ async fn json_evidence_playbook_templates_pass() -> Result<(), Box<dyn std::erro
     let trigger_request = ScenarioTriggerRequest {
         scenario_id: define_output.scenario_id,
         trigger,
+        lease_holder: None,
     };
     let trigger_input = serde_json::to_value(&trigger_request)?;
     let trigger_result: TriggerResult =
@@ -127,6 +127,7 @@ async fn http_tool_allowlist_enforced() -> Result<(), Box<dyn std::error::Error>
             requested_at: Timestamp::Logical(2),
             correlation_id: None,
         },
+        wait_for_change: None,
     };
     let status_input = serde_json::to_value(&status_request)?;
     let Err(err) = client.call_tool("scenario_status", status_input).await else {
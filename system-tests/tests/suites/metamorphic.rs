@@ -207,6 +207,7 @@ fn build_trigger_request(
             payload: None,
             correlation_id: None,
         },
+        lease_holder: None,
     }
 }
 
@@ -228,6 +229,7 @@ async fn export_runpack_to_dir(
         manifest_name: Some("manifest.json".to_string()),
         generated_at: Timestamp::Logical(10),
         include_verification,
+        include_evaluation_audit: false,
     };
     let export_input = serde_json::to_value(&export_request)?;
     let _exported: decision_gate_mcp::tools::RunpackExportResponse =
@@ -589,6 +591,7 @@ async fn run_flow(
     let trigger_request = ScenarioTriggerRequest {
         scenario_id: define_output.scenario_id.clone(),
         trigger: fixture.trigger_event("trigger-1", Timestamp::Logical(2)),
+        lease_holder: None,
     };
     let trigger_input = serde_json::to_value(&trigger_request)?;
     let _trigger: decision_gate_core::runtime::TriggerResult =
@@ -605,6 +608,7 @@ async fn run_flow(
         manifest_name: Some("manifest.json".to_string()),
         generated_at: Timestamp::Logical(10),
         include_verification: true,
+        include_evaluation_audit: false,
     };
     let export_input = serde_json::to_value(&export_request)?;
     let _exported: decision_gate_mcp::tools::RunpackExportResponse =
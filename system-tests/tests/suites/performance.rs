@@ -98,6 +98,7 @@ async fn performance_smoke() -> Result<(), Box<dyn std::error::Error>> {
                 payload: None,
                 correlation_id: None,
             },
+            lease_holder: None,
         };
         let trigger_input = serde_json::to_value(&trigger_request)?;
         let _trigger: decision_gate_core::runtime::TriggerResult =
@@ -101,6 +101,7 @@ async fn precheck_read_only_does_not_mutate_run_state() -> Result<(), Box<dyn st
             requested_at: Timestamp::Logical(3),
             correlation_id: None,
         },
+        wait_for_change: None,
     };
     let status_input = serde_json::to_value(&status_request)?;
     let status_before: ScenarioStatus =
@@ -131,6 +132,7 @@ async fn precheck_read_only_does_not_mutate_run_state() -> Result<(), Box<dyn st
             requested_at: Timestamp::Logical(4),
             correlation_id: None,
         },
+        wait_for_change: None,
     };
     let status_input = serde_json::to_value(&status_request)?;
     let status_after: ScenarioStatus =
@@ -325,6 +325,7 @@ async fn run_basic_scenario(
             agent_id: "agent-1".to_string(),
             time: Timestamp::Logical(2),
             correlation_id: None,
+            lease_holder: None,
         },
         feedback: None,
     };
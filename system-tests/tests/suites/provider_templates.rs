@@ -341,6 +341,7 @@ async fn run_template_with_server(
             payload: None,
             correlation_id: None,
         },
+        lease_holder: None,
     };
     let result: decision_gate_core::runtime::TriggerResult = client
         .call_tool_typed(
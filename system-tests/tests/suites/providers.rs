@@ -528,6 +528,7 @@ async fn provider_time_after() -> Result<(), Box<dyn std::error::Error>> {
             payload: None,
             correlation_id: None,
         },
+        lease_holder: None,
     };
     let trigger_input = serde_json::to_value(&trigger_request)?;
     let trigger_result: TriggerResult =
@@ -934,6 +935,7 @@ async fn json_provider_contains_array_succeeds() -> Result<(), Box<dyn std::erro
             payload: None,
             correlation_id: None,
         },
+        lease_holder: None,
     };
     let trigger_input = serde_json::to_value(&trigger_request)?;
     let trigger_result: TriggerResult =
@@ -1085,6 +1087,7 @@ async fn json_provider_anchor_policy_enforced() -> Result<(), Box<dyn std::error
             payload: None,
             correlation_id: None,
         },
+        lease_holder: None,
     };
     let trigger_input = serde_json::to_value(&trigger_request)?;
     let trigger_result: TriggerResult =
@@ -2784,6 +2787,7 @@ async fn federated_provider_echo() -> Result<(), Box<dyn std::error::Error>> {
             payload: None,
             correlation_id: None,
         },
+        lease_holder: None,
     };
     let trigger_input = serde_json::to_value(&trigger_request)?;
     let trigger_result: TriggerResult =
@@ -2820,6 +2824,7 @@ async fn federated_provider_timeout_enforced() -> Result<(), Box<dyn std::error:
     let timeouts = ProviderTimeoutConfig {
         connect_timeout_ms: 500,
         request_timeout_ms: 500,
+        query_timeout_ms: None,
     };
     let config = config_with_provider_timeouts(
         &bind,
@@ -2942,6 +2947,7 @@ async fn assetcore_interop_fixtures() -> Result<(), Box<dyn std::error::Error>>
     let trigger_request = ScenarioTriggerRequest {
         scenario_id: define_output.scenario_id.clone(),
         trigger: trigger.clone(),
+        lease_holder: None,
     };
     let trigger_input = serde_json::to_value(&trigger_request)?;
     let trigger_result: TriggerResult =
@@ -2961,6 +2967,7 @@ async fn assetcore_interop_fixtures() -> Result<(), Box<dyn std::error::Error>>
             requested_at: trigger.time,
             correlation_id: trigger.correlation_id.clone(),
         },
+        wait_for_change: None,
     };
     let status_input = serde_json::to_value(&status_request)?;
     let status: decision_gate_core::runtime::ScenarioStatus =
@@ -28,6 +28,7 @@ use decision_gate_core::core::hashing::HashAlgorithm;
 use decision_gate_core::core::hashing::hash_bytes;
 use decision_gate_mcp::config::PrincipalConfig;
 use decision_gate_mcp::config::PrincipalRoleConfig;
+use decision_gate_mcp::config::PrincipalScopesConfig;
 use decision_gate_mcp::config::SchemaRegistryType;
 use decision_gate_mcp::config::ServerAuthConfig;
 use decision_gate_mcp::config::ServerAuthMode;
@@ -84,6 +85,7 @@ async fn registry_acl_builtin_matrix() -> Result<(), Box<dyn std::error::Error>>
         principals.push(PrincipalConfig {
             subject: token_subject(&token),
             policy_class: Some(case.policy_class.to_string()),
+            scopes: PrincipalScopesConfig::default(),
             roles: case
                 .roles
                 .iter()
@@ -102,6 +104,7 @@ async fn registry_acl_builtin_matrix() -> Result<(), Box<dyn std::error::Error>>
     config.server.auth = Some(ServerAuthConfig {
         mode: ServerAuthMode::BearerToken,
         bearer_tokens,
+        jwt: None,
         mtls_subjects: Vec::new(),
         allowed_tools: Vec::new(),
         principals,
@@ -261,6 +264,7 @@ async fn registry_acl_principal_subject_mapping() -> Result<(), Box<dyn std::err
         config.server.auth = Some(ServerAuthConfig {
             mode: ServerAuthMode::LocalOnly,
             bearer_tokens: Vec::new(),
+            jwt: None,
             mtls_subjects: Vec::new(),
             allowed_tools: Vec::new(),
             principals: vec![principal(
@@ -290,6 +294,7 @@ async fn registry_acl_principal_subject_mapping() -> Result<(), Box<dyn std::err
         config.server.auth = Some(ServerAuthConfig {
             mode: ServerAuthMode::LocalOnly,
             bearer_tokens: Vec::new(),
+            jwt: None,
             mtls_subjects: Vec::new(),
             allowed_tools: Vec::new(),
             principals: vec![principal("stdio", "prod", &["TenantAdmin"], tenant_id, namespace_id)],
@@ -320,6 +325,7 @@ async fn registry_acl_principal_subject_mapping() -> Result<(), Box<dyn std::err
         config.server.auth = Some(ServerAuthConfig {
             mode: ServerAuthMode::BearerToken,
             bearer_tokens: vec![allowed_token.clone(), denied_token.clone()],
+            jwt: None,
             mtls_subjects: Vec::new(),
             allowed_tools: Vec::new(),
             principals: vec![principal(
@@ -359,6 +365,7 @@ async fn registry_acl_principal_subject_mapping() -> Result<(), Box<dyn std::err
         config.server.auth = Some(ServerAuthConfig {
             mode: ServerAuthMode::Mtls,
             bearer_tokens: Vec::new(),
+            jwt: None,
             mtls_subjects: vec!["CN=allowed".to_string(), "CN=denied".to_string()],
             allowed_tools: Vec::new(),
             principals: vec![principal(
@@ -580,6 +587,7 @@ fn principal(
     PrincipalConfig {
         subject: subject.to_string(),
         policy_class: Some(policy_class.to_string()),
+        scopes: PrincipalScopesConfig::default(),
         roles: roles
             .iter()
             .map(|name| PrincipalRoleConfig {
@@ -85,6 +85,7 @@ async fn idempotent_trigger() -> Result<(), Box<dyn std::error::Error>> {
     let trigger_request = ScenarioTriggerRequest {
         scenario_id: define_output.scenario_id.clone(),
         trigger: trigger_event.clone(),
+        lease_holder: None,
     };
     let trigger_input = serde_json::to_value(&trigger_request)?;
     let first: TriggerResult = client.call_tool_typed("scenario_trigger", trigger_input).await?;
@@ -92,6 +93,7 @@ async fn idempotent_trigger() -> Result<(), Box<dyn std::error::Error>> {
     let second_request = ScenarioTriggerRequest {
         scenario_id: define_output.scenario_id.clone(),
         trigger: trigger_event,
+        lease_holder: None,
     };
     let second_input = serde_json::to_value(&second_request)?;
     let second: TriggerResult = client.call_tool_typed("scenario_trigger", second_input).await?;
@@ -112,6 +114,7 @@ async fn idempotent_trigger() -> Result<(), Box<dyn std::error::Error>> {
         manifest_name: Some("manifest.json".to_string()),
         generated_at: Timestamp::Logical(3),
         include_verification: false,
+        include_evaluation_audit: false,
     };
     let export_input = serde_json::to_value(&export_request)?;
     let _exported: decision_gate_mcp::tools::RunpackExportResponse =
@@ -186,6 +189,8 @@ async fn idempotent_submission() -> Result<(), Box<dyn std::error::Error>> {
         content_type: "application/json".to_string(),
         submitted_at: Timestamp::Logical(2),
         correlation_id: None,
+        expected_version: None,
+        lease_holder: None,
     };
     let submit_request = ScenarioSubmitRequest {
         scenario_id: define_output.scenario_id.clone(),
@@ -216,6 +221,8 @@ async fn idempotent_submission() -> Result<(), Box<dyn std::error::Error>> {
             content_type: "application/json".to_string(),
             submitted_at: Timestamp::Logical(3),
             correlation_id: None,
+            expected_version: None,
+            lease_holder: None,
         },
     };
     let conflict_input = serde_json::to_value(&conflict_request)?;
@@ -237,6 +244,7 @@ async fn idempotent_submission() -> Result<(), Box<dyn std::error::Error>> {
         manifest_name: Some("manifest.json".to_string()),
         generated_at: Timestamp::Logical(4),
         include_verification: false,
+        include_evaluation_audit: false,
     };
     let export_input = serde_json::to_value(&export_request)?;
     let _exported: decision_gate_mcp::tools::RunpackExportResponse =
@@ -314,6 +322,7 @@ async fn timeout_policies() -> Result<(), Box<dyn std::error::Error>> {
             payload: None,
             correlation_id: None,
         },
+        lease_holder: None,
     };
     let fail_trigger_input = serde_json::to_value(&fail_trigger)?;
     let fail_result: TriggerResult =
@@ -365,6 +374,7 @@ async fn timeout_policies() -> Result<(), Box<dyn std::error::Error>> {
             payload: None,
             correlation_id: None,
         },
+        lease_holder: None,
     };
     let advance_trigger_input = serde_json::to_value(&advance_trigger)?;
     let advance_result: TriggerResult =
@@ -397,6 +407,7 @@ async fn timeout_policies() -> Result<(), Box<dyn std::error::Error>> {
             requested_at: Timestamp::Logical(11),
             correlation_id: None,
         },
+        wait_for_change: None,
     };
     let advance_status_input = serde_json::to_value(&advance_status)?;
     let advance_snapshot: ScenarioStatus =
@@ -440,6 +451,7 @@ async fn timeout_policies() -> Result<(), Box<dyn std::error::Error>> {
             payload: None,
             correlation_id: None,
         },
+        lease_holder: None,
     };
     let branch_trigger_input = serde_json::to_value(&branch_trigger)?;
     let branch_result: TriggerResult =
@@ -472,6 +484,7 @@ async fn timeout_policies() -> Result<(), Box<dyn std::error::Error>> {
             requested_at: Timestamp::Logical(11),
             correlation_id: None,
         },
+        wait_for_change: None,
     };
     let branch_status_input = serde_json::to_value(&branch_status)?;
     let branch_snapshot: ScenarioStatus =
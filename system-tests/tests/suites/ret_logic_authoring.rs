@@ -151,6 +151,7 @@ async fn authoring_ron_normalize_and_execute() -> Result<(), Box<dyn std::error:
             payload: None,
             correlation_id: None,
         },
+        lease_holder: None,
     };
     let trigger: decision_gate_core::runtime::TriggerResult =
         client.call_tool_typed("scenario_trigger", serde_json::to_value(&trigger_request)?).await?;
@@ -338,6 +339,7 @@ async fn authoring_dsl_evaluates_and_rejects_deep_inputs() -> Result<(), Box<dyn
             payload: None,
             correlation_id: None,
         },
+        lease_holder: None,
     };
     let trigger: decision_gate_core::runtime::TriggerResult =
         client.call_tool_typed("scenario_trigger", serde_json::to_value(&trigger_request)?).await?;
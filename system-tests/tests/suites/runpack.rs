@@ -126,6 +126,7 @@ async fn runpack_export_verify_happy_path() -> Result<(), Box<dyn std::error::Er
             payload: None,
             correlation_id: None,
         },
+        lease_holder: None,
     };
     let trigger_input = serde_json::to_value(&trigger_request)?;
     let _trigger: decision_gate_core::runtime::TriggerResult =
@@ -141,6 +142,7 @@ async fn runpack_export_verify_happy_path() -> Result<(), Box<dyn std::error::Er
         manifest_name: Some("manifest.json".to_string()),
         generated_at: Timestamp::Logical(3),
         include_verification: true,
+        include_evaluation_audit: false,
     };
     let export_input = serde_json::to_value(&export_request)?;
     let _exported: decision_gate_mcp::tools::RunpackExportResponse =
@@ -248,6 +250,7 @@ async fn runpack_export_object_store_roundtrip() -> Result<(), Box<dyn std::erro
             payload: None,
             correlation_id: None,
         },
+        lease_holder: None,
     };
     let trigger_input = serde_json::to_value(&trigger_request)?;
     let _trigger: decision_gate_core::runtime::TriggerResult =
@@ -262,6 +265,7 @@ async fn runpack_export_object_store_roundtrip() -> Result<(), Box<dyn std::erro
         manifest_name: Some("manifest.json".to_string()),
         generated_at: Timestamp::Logical(3),
         include_verification: true,
+        include_evaluation_audit: false,
     };
     let export_input = serde_json::to_value(&export_request)?;
     let exported: decision_gate_mcp::tools::RunpackExportResponse =
@@ -313,6 +317,7 @@ async fn runpack_export_object_store_roundtrip() -> Result<(), Box<dyn std::erro
         fixture.run_id.as_str(),
         match manifest.spec_hash.algorithm {
             decision_gate_core::hashing::HashAlgorithm::Sha256 => "sha256",
+            decision_gate_core::hashing::HashAlgorithm::Blake3 => "blake3",
         },
         manifest.spec_hash.value.as_str(),
     );
@@ -399,6 +404,7 @@ async fn runpack_tamper_detection() -> Result<(), Box<dyn std::error::Error>> {
             payload: None,
             correlation_id: None,
         },
+        lease_holder: None,
     };
     let trigger_input = serde_json::to_value(&trigger_request)?;
     let _trigger: decision_gate_core::runtime::TriggerResult =
@@ -414,6 +420,7 @@ async fn runpack_tamper_detection() -> Result<(), Box<dyn std::error::Error>> {
         manifest_name: Some("manifest.json".to_string()),
         generated_at: Timestamp::Logical(3),
         include_verification: false,
+        include_evaluation_audit: false,
     };
     let export_input = serde_json::to_value(&export_request)?;
     let _exported: decision_gate_mcp::tools::RunpackExportResponse =
@@ -497,6 +504,7 @@ async fn runpack_missing_manifest_fails() -> Result<(), Box<dyn std::error::Erro
             payload: None,
             correlation_id: None,
         },
+        lease_holder: None,
     };
     let trigger_input = serde_json::to_value(&trigger_request)?;
     let _trigger: decision_gate_core::runtime::TriggerResult =
@@ -512,6 +520,7 @@ async fn runpack_missing_manifest_fails() -> Result<(), Box<dyn std::error::Erro
         manifest_name: Some("manifest.json".to_string()),
         generated_at: Timestamp::Logical(3),
         include_verification: false,
+        include_evaluation_audit: false,
     };
     let export_input = serde_json::to_value(&export_request)?;
     let _exported: decision_gate_mcp::tools::RunpackExportResponse =
@@ -590,6 +599,7 @@ async fn runpack_missing_artifact_fails() -> Result<(), Box<dyn std::error::Erro
             payload: None,
             correlation_id: None,
         },
+        lease_holder: None,
     };
     let trigger_input = serde_json::to_value(&trigger_request)?;
     let _trigger: decision_gate_core::runtime::TriggerResult =
@@ -605,6 +615,7 @@ async fn runpack_missing_artifact_fails() -> Result<(), Box<dyn std::error::Erro
         manifest_name: Some("manifest.json".to_string()),
         generated_at: Timestamp::Logical(3),
         include_verification: false,
+        include_evaluation_audit: false,
     };
     let export_input = serde_json::to_value(&export_request)?;
     let _exported: decision_gate_mcp::tools::RunpackExportResponse =
@@ -702,6 +713,7 @@ async fn runpack_export_includes_security_context() -> Result<(), Box<dyn std::e
                 payload: None,
                 correlation_id: None,
             },
+            lease_holder: None,
         };
         client
             .call_tool_typed::<decision_gate_core::runtime::TriggerResult>(
@@ -721,6 +733,7 @@ async fn runpack_export_includes_security_context() -> Result<(), Box<dyn std::e
             manifest_name: Some("manifest.json".to_string()),
             generated_at: Timestamp::Logical(3),
             include_verification: false,
+            include_evaluation_audit: false,
         };
         client
             .call_tool_typed::<decision_gate_mcp::tools::RunpackExportResponse>(
@@ -795,6 +808,7 @@ async fn runpack_export_includes_security_context() -> Result<(), Box<dyn std::e
                 payload: None,
                 correlation_id: None,
             },
+            lease_holder: None,
         };
         client
             .call_tool_typed::<decision_gate_core::runtime::TriggerResult>(
@@ -814,6 +828,7 @@ async fn runpack_export_includes_security_context() -> Result<(), Box<dyn std::e
             manifest_name: Some("manifest.json".to_string()),
             generated_at: Timestamp::Logical(3),
             include_verification: false,
+            include_evaluation_audit: false,
         };
         client
             .call_tool_typed::<decision_gate_mcp::tools::RunpackExportResponse>(
@@ -115,7 +115,7 @@ async fn schema_registry_cursor_rejects_invalid_inputs() -> Result<(), Box<dyn s
         let Err(err) = client.call_tool("schemas_list", input).await else {
             return Err("expected invalid limit rejection".into());
         };
-        if !err.contains("limit must be between") {
+        if !err.contains("params: /limit:") {
             return Err(format!("unexpected limit error: {err}").into());
         }
     }
@@ -59,6 +59,8 @@ async fn sdk_gen_cli_generate_and_check() -> Result<(), Box<dyn std::error::Erro
     let python_out = temp_dir.path().join("python/_generated.py");
     let ts_out = temp_dir.path().join("typescript/_generated.ts");
     let openapi_out = temp_dir.path().join("openapi/decision-gate.json");
+    let python_example_out = temp_dir.path().join("python/_examples.py");
+    let ts_example_out = temp_dir.path().join("typescript/_examples.ts");
 
     let generate = run_sdk_gen(
         &binary,
@@ -72,6 +74,10 @@ async fn sdk_gen_cli_generate_and_check() -> Result<(), Box<dyn std::error::Erro
             ts_out.to_str().unwrap_or_default(),
             "--openapi-out",
             openapi_out.to_str().unwrap_or_default(),
+            "--python-example-out",
+            python_example_out.to_str().unwrap_or_default(),
+            "--typescript-example-out",
+            ts_example_out.to_str().unwrap_or_default(),
         ],
     )?;
     reporter
@@ -83,7 +89,12 @@ async fn sdk_gen_cli_generate_and_check() -> Result<(), Box<dyn std::error::Erro
     if !generate.status.success() {
         return Err("sdk-gen generate failed".into());
     }
-    if !python_out.exists() || !ts_out.exists() || !openapi_out.exists() {
+    if !python_out.exists()
+        || !ts_out.exists()
+        || !openapi_out.exists()
+        || !python_example_out.exists()
+        || !ts_example_out.exists()
+    {
         return Err("sdk-gen outputs missing after generate".into());
     }
 
@@ -99,6 +110,10 @@ async fn sdk_gen_cli_generate_and_check() -> Result<(), Box<dyn std::error::Erro
             ts_out.to_str().unwrap_or_default(),
             "--openapi-out",
             openapi_out.to_str().unwrap_or_default(),
+            "--python-example-out",
+            python_example_out.to_str().unwrap_or_default(),
+            "--typescript-example-out",
+            ts_example_out.to_str().unwrap_or_default(),
         ],
     )?;
     reporter
@@ -124,6 +139,10 @@ async fn sdk_gen_cli_generate_and_check() -> Result<(), Box<dyn std::error::Erro
             ts_out.to_str().unwrap_or_default(),
             "--openapi-out",
             openapi_out.to_str().unwrap_or_default(),
+            "--python-example-out",
+            python_example_out.to_str().unwrap_or_default(),
+            "--typescript-example-out",
+            ts_example_out.to_str().unwrap_or_default(),
         ],
     )?;
     reporter
@@ -150,6 +169,10 @@ async fn sdk_gen_cli_generate_and_check() -> Result<(), Box<dyn std::error::Erro
             ts_out.to_str().unwrap_or_default(),
             "--openapi-out",
             openapi_out.to_str().unwrap_or_default(),
+            "--python-example-out",
+            python_example_out.to_str().unwrap_or_default(),
+            "--typescript-example-out",
+            ts_example_out.to_str().unwrap_or_default(),
         ],
     )?;
     reporter
@@ -183,3 +206,91 @@ async fn sdk_gen_cli_generate_and_check() -> Result<(), Box<dyn std::error::Erro
     drop(reporter);
     Ok(())
 }
+
+#[tokio::test(flavor = "multi_thread")]
+async fn sdk_gen_cli_generate_if_changed_skips_unchanged_contract()
+-> Result<(), Box<dyn std::error::Error>> {
+    let mut reporter =
+        TestReporter::new("sdk_gen_cli_generate_if_changed_skips_unchanged_contract")?;
+    let Some(binary) = sdk_gen_binary() else {
+        reporter.finish(
+            "skip",
+            vec!["decision-gate-sdk-gen binary unavailable".to_string()],
+            vec!["summary.json".to_string(), "summary.md".to_string()],
+        )?;
+        drop(reporter);
+        return Ok(());
+    };
+    let temp_dir = TempDir::new()?;
+    let tooling_path = temp_dir.path().join("tooling.json");
+    fs::copy("Docs/generated/decision-gate/tooling.json", &tooling_path)?;
+    let stamp_path = temp_dir.path().join("sdk-gen.stamp");
+    let python_out = temp_dir.path().join("python/_generated.py");
+    let outputs = [
+        ("--python-out", python_out.clone()),
+        ("--typescript-out", temp_dir.path().join("typescript/_generated.ts")),
+        ("--openapi-out", temp_dir.path().join("openapi/decision-gate.json")),
+        ("--python-example-out", temp_dir.path().join("python/_examples.py")),
+        ("--typescript-example-out", temp_dir.path().join("typescript/_examples.ts")),
+    ];
+    let mut args = vec![
+        "generate".to_string(),
+        "--if-changed".to_string(),
+        "--tooling".to_string(),
+        tooling_path.display().to_string(),
+        "--stamp".to_string(),
+        stamp_path.display().to_string(),
+    ];
+    for (flag, path) in &outputs {
+        args.push((*flag).to_string());
+        args.push(path.display().to_string());
+    }
+    let args: Vec<&str> = args.iter().map(String::as_str).collect();
+
+    let first = run_sdk_gen(&binary, &args)?;
+    if !first.status.success() || !stamp_path.exists() || !python_out.exists() {
+        return Err("sdk-gen generate --if-changed should generate on first run".into());
+    }
+
+    fs::write(&python_out, "marker: unchanged contract must not regenerate")?;
+    let skipped = run_sdk_gen(&binary, &args)?;
+    let skipped_stdout = String::from_utf8_lossy(&skipped.stdout).to_string();
+    reporter.artifacts().write_text("sdk_gen.if_changed.skip.stdout.log", &skipped_stdout)?;
+    if !skipped.status.success() || !skipped_stdout.contains("up to date") {
+        return Err("sdk-gen generate --if-changed should skip an unchanged contract".into());
+    }
+    if fs::read_to_string(&python_out)? != "marker: unchanged contract must not regenerate" {
+        return Err("sdk-gen rewrote outputs for an unchanged contract".into());
+    }
+
+    let mut tooling = fs::read_to_string(&tooling_path)?;
+    tooling.push('\n');
+    fs::write(&tooling_path, tooling)?;
+    let changed = run_sdk_gen(&binary, &args)?;
+    let changed_stdout = String::from_utf8_lossy(&changed.stdout).to_string();
+    reporter.artifacts().write_text("sdk_gen.if_changed.regen.stdout.log", &changed_stdout)?;
+    if !changed.status.success() || changed_stdout.contains("up to date") {
+        return Err("sdk-gen generate --if-changed should regenerate a changed contract".into());
+    }
+    if !fs::read_to_string(&python_out)?.contains("@generated by decision-gate-sdk-gen") {
+        return Err("sdk-gen did not regenerate outputs for a changed contract".into());
+    }
+
+    reporter.artifacts().write_json("tool_transcript.json", &Vec::<serde_json::Value>::new())?;
+    reporter.finish(
+        "pass",
+        vec![
+            "sdk-gen --if-changed skips unchanged contracts and regenerates changed ones"
+                .to_string(),
+        ],
+        vec![
+            "summary.json".to_string(),
+            "summary.md".to_string(),
+            "tool_transcript.json".to_string(),
+            "sdk_gen.if_changed.skip.stdout.log".to_string(),
+            "sdk_gen.if_changed.regen.stdout.log".to_string(),
+        ],
+    )?;
+    drop(reporter);
+    Ok(())
+}
@@ -410,6 +410,7 @@ async fn policy_denies_dispatch_targets() -> Result<(), Box<dyn std::error::Erro
             payload: None,
             correlation_id: None,
         },
+        lease_holder: None,
     };
     let trigger_input = serde_json::to_value(&trigger_request)?;
     let trigger_result: decision_gate_core::runtime::TriggerResult =
@@ -441,6 +442,7 @@ async fn policy_denies_dispatch_targets() -> Result<(), Box<dyn std::error::Erro
             requested_at: Timestamp::Logical(3),
             correlation_id: None,
         },
+        wait_for_change: None,
     };
     let status_input = serde_json::to_value(&status_request)?;
     let status: decision_gate_core::runtime::ScenarioStatus =
@@ -604,6 +606,7 @@ async fn policy_error_fails_closed() -> Result<(), Box<dyn std::error::Error>> {
             payload: None,
             correlation_id: None,
         },
+        lease_holder: None,
     };
     let trigger_input = serde_json::to_value(&trigger_request)?;
     let trigger_result: decision_gate_core::runtime::TriggerResult =
@@ -85,6 +85,7 @@ async fn smoke_define_start_next_status() -> Result<(), Box<dyn std::error::Erro
             agent_id: "agent-1".to_string(),
             time: Timestamp::Logical(2),
             correlation_id: None,
+            lease_holder: None,
         },
         feedback: None,
     };
@@ -101,6 +102,7 @@ async fn smoke_define_start_next_status() -> Result<(), Box<dyn std::error::Erro
             requested_at: Timestamp::Logical(3),
             correlation_id: None,
         },
+        wait_for_change: None,
     };
     let status_input = serde_json::to_value(&status_request)?;
     let status: decision_gate_core::runtime::ScenarioStatus =
@@ -53,6 +53,10 @@ async fn sqlite_registry_and_runpack_persist_across_restart()
         journal_mode: decision_gate_store_sqlite::SqliteStoreMode::Wal,
         sync_mode: decision_gate_store_sqlite::SqliteSyncMode::Full,
         max_versions: None,
+        circuit_breaker: None,
+        cache: None,
+        retention: Vec::new(),
+        retention_interval_ms: 3_600_000,
     };
     config.schema_registry.registry_type = SchemaRegistryType::Sqlite;
     config.schema_registry.path = Some(registry_path.clone());
@@ -134,6 +138,7 @@ async fn sqlite_registry_and_runpack_persist_across_restart()
     let trigger_request = decision_gate_mcp::tools::ScenarioTriggerRequest {
         scenario_id: spec.scenario_id.clone(),
         trigger: fixture.trigger_event("trigger-1", Timestamp::Logical(3)),
+        lease_holder: None,
     };
     client
         .call_tool_typed::<decision_gate_core::runtime::TriggerResult>(
@@ -182,6 +187,7 @@ async fn sqlite_registry_and_runpack_persist_across_restart()
         manifest_name: Some("runpack.json".to_string()),
         generated_at: Timestamp::Logical(4),
         include_verification: true,
+        include_evaluation_audit: false,
     };
     let response: decision_gate_mcp::tools::RunpackExportResponse =
         client2.call_tool_typed("runpack_export", serde_json::to_value(&runpack_request)?).await?;
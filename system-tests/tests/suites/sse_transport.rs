@@ -135,6 +135,7 @@ async fn sse_transport_end_to_end() -> Result<(), Box<dyn std::error::Error>> {
     let trigger_request = ScenarioTriggerRequest {
         scenario_id: fixture.spec.scenario_id.clone(),
         trigger: fixture.trigger_event("trigger-1", Timestamp::Logical(2)),
+        lease_holder: None,
     };
     call_tool_over_sse(
         &base_url,
@@ -153,6 +154,7 @@ async fn sse_transport_end_to_end() -> Result<(), Box<dyn std::error::Error>> {
             requested_at: Timestamp::Logical(3),
             correlation_id: None,
         },
+        wait_for_change: None,
     };
     let status_output = call_tool_over_sse(
         &base_url,
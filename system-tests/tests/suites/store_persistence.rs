@@ -14,6 +14,11 @@
 //! - Inputs are treated as untrusted unless explicitly mocked.
 //! Security posture: system-test inputs are untrusted; see `Docs/security/threat_model.md`.
 
+#[cfg(unix)]
+use std::path::Path;
+#[cfg(unix)]
+use std::path::PathBuf;
+
 use decision_gate_core::RunState;
 use decision_gate_core::Timestamp;
 use decision_gate_core::runtime::StatusRequest;
@@ -27,7 +32,11 @@ use helpers::harness::allocate_bind_addr;
 use helpers::harness::base_http_config;
 use helpers::harness::spawn_mcp_server;
 use helpers::readiness::wait_for_server_ready;
+#[cfg(unix)]
+use helpers::readiness::wait_for_stdio_ready;
 use helpers::scenarios::ScenarioFixture;
+#[cfg(unix)]
+use helpers::stdio_client::StdioMcpClient;
 use tempfile::TempDir;
 
 use crate::helpers;
@@ -47,6 +56,10 @@ async fn sqlite_run_state_persists_across_restart() -> Result<(), Box<dyn std::e
         journal_mode: decision_gate_store_sqlite::SqliteStoreMode::Wal,
         sync_mode: decision_gate_store_sqlite::SqliteSyncMode::Full,
         max_versions: None,
+        circuit_breaker: None,
+        cache: None,
+        retention: Vec::new(),
+        retention_interval_ms: 3_600_000,
     };
 
     let server = spawn_mcp_server(config.clone()).await?;
@@ -103,6 +116,7 @@ async fn sqlite_run_state_persists_across_restart() -> Result<(), Box<dyn std::e
             requested_at: Timestamp::Logical(2),
             correlation_id: None,
         },
+        wait_for_change: None,
     };
     let status_input = serde_json::to_value(&status_request)?;
     let status: decision_gate_core::runtime::ScenarioStatus =
@@ -149,6 +163,10 @@ async fn sqlite_requires_redefine_after_restart() -> Result<(), Box<dyn std::err
         journal_mode: decision_gate_store_sqlite::SqliteStoreMode::Wal,
         sync_mode: decision_gate_store_sqlite::SqliteSyncMode::Full,
         max_versions: None,
+        circuit_breaker: None,
+        cache: None,
+        retention: Vec::new(),
+        retention_interval_ms: 3_600_000,
     };
 
     let server = spawn_mcp_server(config.clone()).await?;
@@ -194,6 +212,7 @@ async fn sqlite_requires_redefine_after_restart() -> Result<(), Box<dyn std::err
             requested_at: Timestamp::Logical(2),
             correlation_id: None,
         },
+        wait_for_change: None,
     };
     let status_input = serde_json::to_value(&status_request)?;
     let Err(_) = client2.call_tool("scenario_status", status_input).await else {
@@ -220,6 +239,7 @@ async fn sqlite_requires_redefine_after_restart() -> Result<(), Box<dyn std::err
             requested_at: Timestamp::Logical(3),
             correlation_id: None,
         },
+        wait_for_change: None,
     };
     let status_input = serde_json::to_value(&status_request)?;
     let status: decision_gate_core::runtime::ScenarioStatus =
@@ -249,3 +269,136 @@ async fn sqlite_requires_redefine_after_restart() -> Result<(), Box<dyn std::err
     server2.shutdown().await;
     Ok(())
 }
+
+#[cfg(unix)]
+fn write_back_stdio_config(db_path: &Path) -> String {
+    let db_path = db_path.to_string_lossy().replace('\\', "/");
+    format!(
+        r#"[server]
+transport = "stdio"
+mode = "strict"
+
+[server.auth]
+mode = "local_only"
+
+[[server.auth.principals]]
+subject = "stdio"
+policy_class = "prod"
+
+[[server.auth.principals.roles]]
+name = "TenantAdmin"
+tenant_id = 1
+namespace_id = 1
+
+[namespace]
+allow_default = true
+default_tenants = [1]
+
+[run_state_store]
+type = "sqlite"
+path = "{db_path}"
+
+[run_state_store.cache]
+mode = "write_back"
+flush_interval_ms = 60000
+
+[[providers]]
+name = "time"
+type = "builtin"
+"#
+    )
+}
+
+#[cfg(unix)]
+#[tokio::test(flavor = "multi_thread")]
+async fn sqlite_write_back_cache_flushes_on_sigterm() -> Result<(), Box<dyn std::error::Error>> {
+    let mut reporter = TestReporter::new("sqlite_write_back_cache_flushes_on_sigterm")?;
+    let temp = TempDir::new()?;
+    let db_path = temp.path().join("run_state.sqlite");
+    let config_path = temp.path().join("decision-gate.toml");
+    std::fs::write(&config_path, write_back_stdio_config(&db_path))?;
+
+    let stderr_path = reporter.artifacts().root().join("mcp.stderr.log");
+    let binary = PathBuf::from(env!("CARGO_BIN_EXE_decision_gate_stdio_server"));
+    let mut stdio = StdioMcpClient::spawn(&binary, &config_path, &stderr_path)?;
+    wait_for_stdio_ready(&stdio, std::time::Duration::from_secs(5)).await?;
+
+    let mut fixture = ScenarioFixture::time_after("write-back-scenario", "run-1", 0);
+    fixture.spec.default_tenant_id = Some(fixture.tenant_id);
+    let define_request = ScenarioDefineRequest {
+        spec: fixture.spec.clone(),
+    };
+    stdio.call_tool("scenario_define", serde_json::to_value(&define_request)?).await?;
+    let start_request = ScenarioStartRequest {
+        scenario_id: fixture.spec.scenario_id.clone(),
+        run_config: fixture.run_config(),
+        started_at: Timestamp::Logical(1),
+        issue_entry_packets: false,
+    };
+    stdio.call_tool("scenario_start", serde_json::to_value(&start_request)?).await?;
+
+    // The flush interval has not elapsed, so only the shutdown flush can persist the run.
+    let exit = stdio.terminate()?;
+    if !exit.success() {
+        return Err(format!("stdio server exited with {exit} after SIGTERM").into());
+    }
+
+    let bind = allocate_bind_addr()?.to_string();
+    let mut config = base_http_config(&bind);
+    config.run_state_store = RunStateStoreConfig {
+        store_type: RunStateStoreType::Sqlite,
+        path: Some(db_path),
+        busy_timeout_ms: 5_000,
+        journal_mode: decision_gate_store_sqlite::SqliteStoreMode::Wal,
+        sync_mode: decision_gate_store_sqlite::SqliteSyncMode::Full,
+        max_versions: None,
+        circuit_breaker: None,
+        cache: None,
+        retention: Vec::new(),
+        retention_interval_ms: 3_600_000,
+    };
+    let server = spawn_mcp_server(config).await?;
+    let client = server.client(std::time::Duration::from_secs(5))?;
+    wait_for_server_ready(&client, std::time::Duration::from_secs(5)).await?;
+    let define_input = serde_json::to_value(&define_request)?;
+    client
+        .call_tool_typed::<decision_gate_mcp::tools::ScenarioDefineResponse>(
+            "scenario_define",
+            define_input,
+        )
+        .await?;
+    let status_request = ScenarioStatusRequest {
+        scenario_id: fixture.spec.scenario_id.clone(),
+        request: StatusRequest {
+            run_id: fixture.run_id.clone(),
+            tenant_id: fixture.tenant_id,
+            namespace_id: fixture.namespace_id,
+            requested_at: Timestamp::Logical(2),
+            correlation_id: None,
+        },
+        wait_for_change: None,
+    };
+    let status_input = serde_json::to_value(&status_request)?;
+    let status: decision_gate_core::runtime::ScenarioStatus =
+        client.call_tool_typed("scenario_status", status_input).await?;
+    if status.run_id != fixture.run_id {
+        return Err(format!("expected flushed run {}", fixture.run_id.as_str()).into());
+    }
+
+    let mut transcript = stdio.transcript();
+    transcript.extend(client.transcript());
+    reporter.artifacts().write_json("tool_transcript.json", &transcript)?;
+    reporter.finish(
+        "pass",
+        vec!["write-back run state cache flushes on SIGTERM".to_string()],
+        vec![
+            "summary.json".to_string(),
+            "summary.md".to_string(),
+            "tool_transcript.json".to_string(),
+            "mcp.stderr.log".to_string(),
+        ],
+    )?;
+    drop(reporter);
+    server.shutdown().await;
+    Ok(())
+}
@@ -104,8 +104,11 @@ async fn server_tools_visibility_defaults_and_auth_separation()
     wait_for_server_ready(&client, Duration::from_secs(5)).await?;
 
     let tools = client.list_tools().await?;
-    if !tools.iter().any(|tool| tool.name == ToolName::DecisionGateDocsSearch) {
-        return Err("docs search should remain visible with auth allowlist".into());
+    if tools.iter().any(|tool| tool.name == ToolName::DecisionGateDocsSearch) {
+        return Err("docs search should be hidden outside the auth allowlist".into());
+    }
+    if !tools.iter().any(|tool| tool.name == ToolName::ScenarioDefine) {
+        return Err("auth allowlist should keep scenario_define visible".into());
     }
     let Err(err) = client
         .call_tool(
@@ -278,6 +278,7 @@ async fn run_scenario_http(
     let trigger_request = ScenarioTriggerRequest {
         scenario_id: define_output.scenario_id.clone(),
         trigger: trigger.clone(),
+        lease_holder: None,
     };
     let trigger_input = serde_json::to_value(&trigger_request)?;
     let trigger_result: decision_gate_core::runtime::TriggerResult =
@@ -292,6 +293,7 @@ async fn run_scenario_http(
             requested_at: Timestamp::Logical(3),
             correlation_id: None,
         },
+        wait_for_change: None,
     };
     let status_input = serde_json::to_value(&status_request)?;
     let status: decision_gate_core::runtime::ScenarioStatus =
@@ -325,6 +327,7 @@ async fn run_scenario_stdio(
     let trigger_request = ScenarioTriggerRequest {
         scenario_id: define_response.scenario_id.clone(),
         trigger: trigger.clone(),
+        lease_holder: None,
     };
     let trigger_input = serde_json::to_value(&trigger_request)?;
     let trigger_result: decision_gate_core::runtime::TriggerResult =
@@ -339,6 +342,7 @@ async fn run_scenario_stdio(
             requested_at: Timestamp::Logical(3),
             correlation_id: None,
         },
+        wait_for_change: None,
     };
     let status_input = serde_json::to_value(&status_request)?;
     let status: decision_gate_core::runtime::ScenarioStatus =
@@ -362,6 +366,7 @@ async fn export_runpack_http(
         manifest_name: Some("manifest.json".to_string()),
         generated_at: Timestamp::Logical(4),
         include_verification: true,
+        include_evaluation_audit: false,
     };
     let export_input = serde_json::to_value(&export_request)?;
     let _exported: decision_gate_mcp::tools::RunpackExportResponse =
@@ -384,6 +389,7 @@ async fn export_runpack_stdio(
         manifest_name: Some("manifest.json".to_string()),
         generated_at: Timestamp::Logical(4),
         include_verification: true,
+        include_evaluation_audit: false,
     };
     let export_input = serde_json::to_value(&export_request)?;
     let _exported: decision_gate_mcp::tools::RunpackExportResponse =